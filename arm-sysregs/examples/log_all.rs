@@ -13,20 +13,22 @@ use aarch64_rt::entry;
 #[cfg(not(any(test, feature = "fakes")))]
 use core::panic::PanicInfo;
 use log::info;
+#[cfg(any(test, feature = "fakes"))]
+extern crate std;
 
 #[cfg(all(target_arch = "aarch64", target_os = "none"))]
 entry!(entry);
-#[cfg_attr(any(test, feature = "fakes"), allow(unused))]
+#[cfg_attr(any(test, feature = "fakes"), allow(unused, clippy::empty_loop))]
 fn entry(_: u64, _: u64, _: u64, _: u64) -> ! {
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
     info!("actlr = {:?}", arm_sysregs::read_actlr());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
     info!("actlr2 = {:?}", arm_sysregs::read_actlr2());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
     info!("actlr_el1 = {:?}", arm_sysregs::read_actlr_el1());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
     info!("actlr_el2 = {:?}", arm_sysregs::read_actlr_el2());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
     info!("adfsr = {:?}", arm_sysregs::read_adfsr());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
     info!("afsr0_el1 = {:?}", arm_sysregs::read_afsr0_el1());
@@ -36,1034 +38,1780 @@ fn entry(_: u64, _: u64, _: u64, _: u64) -> ! {
     info!("afsr1_el1 = {:?}", arm_sysregs::read_afsr1_el1());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
     info!("afsr1_el2 = {:?}", arm_sysregs::read_afsr1_el2());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
     info!("aidr = {:?}", arm_sysregs::read_aidr());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
     info!("aifsr = {:?}", arm_sysregs::read_aifsr());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
     info!("amair0 = {:?}", arm_sysregs::read_amair0());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
     info!("amair1 = {:?}", arm_sysregs::read_amair1());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
     info!("amair_el1 = {:?}", arm_sysregs::read_amair_el1());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
     info!("amair_el2 = {:?}", arm_sysregs::read_amair_el2());
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("amcfgr = {:?}", arm_sysregs::read_amcfgr());
-    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
-    info!("amcfgr_el0 = {:?}", arm_sysregs::read_amcfgr_el0());
+    info!("amcfgr = {0:?}\n    {0}", arm_sysregs::read_amcfgr());
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    info!(
+        "amcfgr_el0 = {0:?}\n    {0}",
+        arm_sysregs::read_amcfgr_el0()
+    );
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("amcgcr = {:?}", arm_sysregs::read_amcgcr());
-    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
-    info!("amcgcr_el0 = {:?}", arm_sysregs::read_amcgcr_el0());
+    info!("amcgcr = {0:?}\n    {0}", arm_sysregs::read_amcgcr());
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    info!(
+        "amcgcr_el0 = {0:?}\n    {0}",
+        arm_sysregs::read_amcgcr_el0()
+    );
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("amcntenclr0 = {:?}", arm_sysregs::read_amcntenclr0());
-    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
     info!(
-        "amcntenclr0_el0 = {:?}",
+        "amcntenclr0 = {0:?}\n    {0}",
+        arm_sysregs::read_amcntenclr0()
+    );
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    info!(
+        "amcntenclr0_el0 = {0:?}\n    {0}",
         arm_sysregs::read_amcntenclr0_el0()
     );
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("amcntenclr1 = {:?}", arm_sysregs::read_amcntenclr1());
-    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
     info!(
-        "amcntenclr1_el0 = {:?}",
+        "amcntenclr1 = {0:?}\n    {0}",
+        arm_sysregs::read_amcntenclr1()
+    );
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    info!(
+        "amcntenclr1_el0 = {0:?}\n    {0}",
         arm_sysregs::read_amcntenclr1_el0()
     );
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("amcntenset0 = {:?}", arm_sysregs::read_amcntenset0());
-    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
     info!(
-        "amcntenset0_el0 = {:?}",
+        "amcntenset0 = {0:?}\n    {0}",
+        arm_sysregs::read_amcntenset0()
+    );
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    info!(
+        "amcntenset0_el0 = {0:?}\n    {0}",
         arm_sysregs::read_amcntenset0_el0()
     );
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("amcntenset1 = {:?}", arm_sysregs::read_amcntenset1());
-    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
     info!(
-        "amcntenset1_el0 = {:?}",
+        "amcntenset1 = {0:?}\n    {0}",
+        arm_sysregs::read_amcntenset1()
+    );
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    info!(
+        "amcntenset1_el0 = {0:?}\n    {0}",
         arm_sysregs::read_amcntenset1_el0()
     );
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("amcr = {:?}", arm_sysregs::read_amcr());
-    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
-    info!("amcr_el0 = {:?}", arm_sysregs::read_amcr_el0());
+    info!("amcr = {0:?}\n    {0}", arm_sysregs::read_amcr());
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    info!("amcr_el0 = {0:?}\n    {0}", arm_sysregs::read_amcr_el0());
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("amevcntr00 = {:?}", arm_sysregs::read_amevcntr00());
-    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
-    info!("amevcntr00_el0 = {:?}", arm_sysregs::read_amevcntr00_el0());
+    info!(
+        "amevcntr00 = {0:?}\n    {0}",
+        arm_sysregs::read_amevcntr00()
+    );
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    info!(
+        "amevcntr00_el0 = {0:?}\n    {0}",
+        arm_sysregs::read_amevcntr00_el0()
+    );
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("amevcntr01 = {:?}", arm_sysregs::read_amevcntr01());
-    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
-    info!("amevcntr01_el0 = {:?}", arm_sysregs::read_amevcntr01_el0());
+    info!(
+        "amevcntr01 = {0:?}\n    {0}",
+        arm_sysregs::read_amevcntr01()
+    );
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    info!(
+        "amevcntr01_el0 = {0:?}\n    {0}",
+        arm_sysregs::read_amevcntr01_el0()
+    );
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("amevcntr02 = {:?}", arm_sysregs::read_amevcntr02());
-    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
-    info!("amevcntr02_el0 = {:?}", arm_sysregs::read_amevcntr02_el0());
+    info!(
+        "amevcntr02 = {0:?}\n    {0}",
+        arm_sysregs::read_amevcntr02()
+    );
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    info!(
+        "amevcntr02_el0 = {0:?}\n    {0}",
+        arm_sysregs::read_amevcntr02_el0()
+    );
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("amevcntr03 = {:?}", arm_sysregs::read_amevcntr03());
-    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
-    info!("amevcntr03_el0 = {:?}", arm_sysregs::read_amevcntr03_el0());
-    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
-    info!("amevcntr10_el0 = {:?}", arm_sysregs::read_amevcntr10_el0());
-    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
     info!(
-        "amevcntr110_el0 = {:?}",
+        "amevcntr03 = {0:?}\n    {0}",
+        arm_sysregs::read_amevcntr03()
+    );
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    info!(
+        "amevcntr03_el0 = {0:?}\n    {0}",
+        arm_sysregs::read_amevcntr03_el0()
+    );
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    info!(
+        "amevcntr10_el0 = {0:?}\n    {0}",
+        arm_sysregs::read_amevcntr10_el0()
+    );
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    info!(
+        "amevcntr110_el0 = {0:?}\n    {0}",
         arm_sysregs::read_amevcntr110_el0()
     );
-    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
     info!(
-        "amevcntr111_el0 = {:?}",
+        "amevcntr111_el0 = {0:?}\n    {0}",
         arm_sysregs::read_amevcntr111_el0()
     );
-    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
     info!(
-        "amevcntr112_el0 = {:?}",
+        "amevcntr112_el0 = {0:?}\n    {0}",
         arm_sysregs::read_amevcntr112_el0()
     );
-    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
     info!(
-        "amevcntr113_el0 = {:?}",
+        "amevcntr113_el0 = {0:?}\n    {0}",
         arm_sysregs::read_amevcntr113_el0()
     );
-    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
     info!(
-        "amevcntr114_el0 = {:?}",
+        "amevcntr114_el0 = {0:?}\n    {0}",
         arm_sysregs::read_amevcntr114_el0()
     );
-    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
     info!(
-        "amevcntr115_el0 = {:?}",
+        "amevcntr115_el0 = {0:?}\n    {0}",
         arm_sysregs::read_amevcntr115_el0()
     );
-    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
-    info!("amevcntr11_el0 = {:?}", arm_sysregs::read_amevcntr11_el0());
-    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
-    info!("amevcntr12_el0 = {:?}", arm_sysregs::read_amevcntr12_el0());
-    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
-    info!("amevcntr13_el0 = {:?}", arm_sysregs::read_amevcntr13_el0());
-    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
-    info!("amevcntr14_el0 = {:?}", arm_sysregs::read_amevcntr14_el0());
-    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
-    info!("amevcntr15_el0 = {:?}", arm_sysregs::read_amevcntr15_el0());
-    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
-    info!("amevcntr16_el0 = {:?}", arm_sysregs::read_amevcntr16_el0());
-    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
-    info!("amevcntr17_el0 = {:?}", arm_sysregs::read_amevcntr17_el0());
-    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
-    info!("amevcntr18_el0 = {:?}", arm_sysregs::read_amevcntr18_el0());
-    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
-    info!("amevcntr19_el0 = {:?}", arm_sysregs::read_amevcntr19_el0());
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    info!(
+        "amevcntr11_el0 = {0:?}\n    {0}",
+        arm_sysregs::read_amevcntr11_el0()
+    );
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    info!(
+        "amevcntr12_el0 = {0:?}\n    {0}",
+        arm_sysregs::read_amevcntr12_el0()
+    );
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    info!(
+        "amevcntr13_el0 = {0:?}\n    {0}",
+        arm_sysregs::read_amevcntr13_el0()
+    );
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    info!(
+        "amevcntr14_el0 = {0:?}\n    {0}",
+        arm_sysregs::read_amevcntr14_el0()
+    );
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    info!(
+        "amevcntr15_el0 = {0:?}\n    {0}",
+        arm_sysregs::read_amevcntr15_el0()
+    );
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    info!(
+        "amevcntr16_el0 = {0:?}\n    {0}",
+        arm_sysregs::read_amevcntr16_el0()
+    );
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    info!(
+        "amevcntr17_el0 = {0:?}\n    {0}",
+        arm_sysregs::read_amevcntr17_el0()
+    );
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    info!(
+        "amevcntr18_el0 = {0:?}\n    {0}",
+        arm_sysregs::read_amevcntr18_el0()
+    );
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    info!(
+        "amevcntr19_el0 = {0:?}\n    {0}",
+        arm_sysregs::read_amevcntr19_el0()
+    );
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("amevtyper00 = {:?}", arm_sysregs::read_amevtyper00());
-    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
     info!(
-        "amevtyper00_el0 = {:?}",
+        "amevtyper00 = {0:?}\n    {0}",
+        arm_sysregs::read_amevtyper00()
+    );
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    info!(
+        "amevtyper00_el0 = {0:?}\n    {0}",
         arm_sysregs::read_amevtyper00_el0()
     );
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("amevtyper01 = {:?}", arm_sysregs::read_amevtyper01());
-    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
     info!(
-        "amevtyper01_el0 = {:?}",
+        "amevtyper01 = {0:?}\n    {0}",
+        arm_sysregs::read_amevtyper01()
+    );
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    info!(
+        "amevtyper01_el0 = {0:?}\n    {0}",
         arm_sysregs::read_amevtyper01_el0()
     );
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("amevtyper02 = {:?}", arm_sysregs::read_amevtyper02());
-    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
     info!(
-        "amevtyper02_el0 = {:?}",
+        "amevtyper02 = {0:?}\n    {0}",
+        arm_sysregs::read_amevtyper02()
+    );
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    info!(
+        "amevtyper02_el0 = {0:?}\n    {0}",
         arm_sysregs::read_amevtyper02_el0()
     );
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("amevtyper03 = {:?}", arm_sysregs::read_amevtyper03());
-    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
     info!(
-        "amevtyper03_el0 = {:?}",
+        "amevtyper03 = {0:?}\n    {0}",
+        arm_sysregs::read_amevtyper03()
+    );
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    info!(
+        "amevtyper03_el0 = {0:?}\n    {0}",
         arm_sysregs::read_amevtyper03_el0()
     );
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("amevtyper10 = {:?}", arm_sysregs::read_amevtyper10());
+    info!(
+        "amevtyper10 = {0:?}\n    {0}",
+        arm_sysregs::read_amevtyper10()
+    );
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("amevtyper11 = {:?}", arm_sysregs::read_amevtyper11());
+    info!(
+        "amevtyper11 = {0:?}\n    {0}",
+        arm_sysregs::read_amevtyper11()
+    );
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("amevtyper110 = {:?}", arm_sysregs::read_amevtyper110());
+    info!(
+        "amevtyper110 = {0:?}\n    {0}",
+        arm_sysregs::read_amevtyper110()
+    );
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("amevtyper111 = {:?}", arm_sysregs::read_amevtyper111());
+    info!(
+        "amevtyper111 = {0:?}\n    {0}",
+        arm_sysregs::read_amevtyper111()
+    );
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("amevtyper112 = {:?}", arm_sysregs::read_amevtyper112());
+    info!(
+        "amevtyper112 = {0:?}\n    {0}",
+        arm_sysregs::read_amevtyper112()
+    );
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("amevtyper113 = {:?}", arm_sysregs::read_amevtyper113());
+    info!(
+        "amevtyper113 = {0:?}\n    {0}",
+        arm_sysregs::read_amevtyper113()
+    );
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("amevtyper114 = {:?}", arm_sysregs::read_amevtyper114());
+    info!(
+        "amevtyper114 = {0:?}\n    {0}",
+        arm_sysregs::read_amevtyper114()
+    );
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("amevtyper115 = {:?}", arm_sysregs::read_amevtyper115());
+    info!(
+        "amevtyper115 = {0:?}\n    {0}",
+        arm_sysregs::read_amevtyper115()
+    );
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("amevtyper12 = {:?}", arm_sysregs::read_amevtyper12());
+    info!(
+        "amevtyper12 = {0:?}\n    {0}",
+        arm_sysregs::read_amevtyper12()
+    );
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("amevtyper13 = {:?}", arm_sysregs::read_amevtyper13());
+    info!(
+        "amevtyper13 = {0:?}\n    {0}",
+        arm_sysregs::read_amevtyper13()
+    );
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("amevtyper14 = {:?}", arm_sysregs::read_amevtyper14());
+    info!(
+        "amevtyper14 = {0:?}\n    {0}",
+        arm_sysregs::read_amevtyper14()
+    );
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("amevtyper15 = {:?}", arm_sysregs::read_amevtyper15());
+    info!(
+        "amevtyper15 = {0:?}\n    {0}",
+        arm_sysregs::read_amevtyper15()
+    );
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("amevtyper16 = {:?}", arm_sysregs::read_amevtyper16());
+    info!(
+        "amevtyper16 = {0:?}\n    {0}",
+        arm_sysregs::read_amevtyper16()
+    );
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("amevtyper17 = {:?}", arm_sysregs::read_amevtyper17());
+    info!(
+        "amevtyper17 = {0:?}\n    {0}",
+        arm_sysregs::read_amevtyper17()
+    );
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("amevtyper18 = {:?}", arm_sysregs::read_amevtyper18());
+    info!(
+        "amevtyper18 = {0:?}\n    {0}",
+        arm_sysregs::read_amevtyper18()
+    );
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("amevtyper19 = {:?}", arm_sysregs::read_amevtyper19());
+    info!(
+        "amevtyper19 = {0:?}\n    {0}",
+        arm_sysregs::read_amevtyper19()
+    );
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("amuserenr = {:?}", arm_sysregs::read_amuserenr());
-    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
-    info!("amuserenr_el0 = {:?}", arm_sysregs::read_amuserenr_el0());
+    info!("amuserenr = {0:?}\n    {0}", arm_sysregs::read_amuserenr());
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    info!(
+        "amuserenr_el0 = {0:?}\n    {0}",
+        arm_sysregs::read_amuserenr_el0()
+    );
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("apiakeyhi_el1 = {:?}", arm_sysregs::read_apiakeyhi_el1());
+    info!(
+        "apiakeyhi_el1 = {0:?}\n    {0}",
+        arm_sysregs::read_apiakeyhi_el1()
+    );
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("apiakeylo_el1 = {:?}", arm_sysregs::read_apiakeylo_el1());
+    info!(
+        "apiakeylo_el1 = {0:?}\n    {0}",
+        arm_sysregs::read_apiakeylo_el1()
+    );
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("brbcr_el2 = {:?}", arm_sysregs::read_brbcr_el2());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("ccsidr = {:?}", arm_sysregs::read_ccsidr());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("ccsidr2 = {:?}", arm_sysregs::read_ccsidr2());
+    info!("brbcr_el2 = {0:?}\n    {0}", arm_sysregs::read_brbcr_el2());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("ccsidr = {0:?}\n    {0}", arm_sysregs::read_ccsidr());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("ccsidr2 = {0:?}\n    {0}", arm_sysregs::read_ccsidr2());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("ccsidr_el1 = {:?}", arm_sysregs::read_ccsidr_el1());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("clidr = {:?}", arm_sysregs::read_clidr());
+    info!(
+        "ccsidr_el1 = {0:?}\n    {0}",
+        arm_sysregs::read_ccsidr_el1()
+    );
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("clidr = {0:?}\n    {0}", arm_sysregs::read_clidr());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("clidr_el1 = {:?}", arm_sysregs::read_clidr_el1());
+    info!("clidr_el1 = {0:?}\n    {0}", arm_sysregs::read_clidr_el1());
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("cntfrq = {:?}", arm_sysregs::read_cntfrq());
+    info!("cntfrq = {0:?}\n    {0}", arm_sysregs::read_cntfrq());
     #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
-    info!("cntfrq_el0 = {:?}", arm_sysregs::read_cntfrq_el0());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("cnthctl = {:?}", arm_sysregs::read_cnthctl());
+    info!(
+        "cntfrq_el0 = {0:?}\n    {0}",
+        arm_sysregs::read_cntfrq_el0()
+    );
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    info!("cnthctl = {0:?}\n    {0}", arm_sysregs::read_cnthctl());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("cnthctl_el2 = {:?}", arm_sysregs::read_cnthctl_el2());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("cnthps_ctl = {:?}", arm_sysregs::read_cnthps_ctl());
+    info!(
+        "cnthctl_el2 = {0:?}\n    {0}",
+        arm_sysregs::read_cnthctl_el2()
+    );
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!(
+        "cnthps_ctl = {0:?}\n    {0}",
+        arm_sysregs::read_cnthps_ctl()
+    );
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("cnthps_ctl_el2 = {:?}", arm_sysregs::read_cnthps_ctl_el2());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("cnthps_cval = {:?}", arm_sysregs::read_cnthps_cval());
+    info!(
+        "cnthps_ctl_el2 = {0:?}\n    {0}",
+        arm_sysregs::read_cnthps_ctl_el2()
+    );
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!(
+        "cnthps_cval = {0:?}\n    {0}",
+        arm_sysregs::read_cnthps_cval()
+    );
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
     info!(
-        "cnthps_cval_el2 = {:?}",
+        "cnthps_cval_el2 = {0:?}\n    {0}",
         arm_sysregs::read_cnthps_cval_el2()
     );
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("cnthps_tval = {:?}", arm_sysregs::read_cnthps_tval());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!(
+        "cnthps_tval = {0:?}\n    {0}",
+        arm_sysregs::read_cnthps_tval()
+    );
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
     info!(
-        "cnthps_tval_el2 = {:?}",
+        "cnthps_tval_el2 = {0:?}\n    {0}",
         arm_sysregs::read_cnthps_tval_el2()
     );
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("cnthp_ctl = {:?}", arm_sysregs::read_cnthp_ctl());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("cnthp_ctl = {0:?}\n    {0}", arm_sysregs::read_cnthp_ctl());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("cnthp_ctl_el2 = {:?}", arm_sysregs::read_cnthp_ctl_el2());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("cnthp_cval = {:?}", arm_sysregs::read_cnthp_cval());
+    info!(
+        "cnthp_ctl_el2 = {0:?}\n    {0}",
+        arm_sysregs::read_cnthp_ctl_el2()
+    );
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!(
+        "cnthp_cval = {0:?}\n    {0}",
+        arm_sysregs::read_cnthp_cval()
+    );
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("cnthp_cval_el2 = {:?}", arm_sysregs::read_cnthp_cval_el2());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("cnthp_tval = {:?}", arm_sysregs::read_cnthp_tval());
+    info!(
+        "cnthp_cval_el2 = {0:?}\n    {0}",
+        arm_sysregs::read_cnthp_cval_el2()
+    );
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!(
+        "cnthp_tval = {0:?}\n    {0}",
+        arm_sysregs::read_cnthp_tval()
+    );
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("cnthp_tval_el2 = {:?}", arm_sysregs::read_cnthp_tval_el2());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("cnthvs_ctl = {:?}", arm_sysregs::read_cnthvs_ctl());
+    info!(
+        "cnthp_tval_el2 = {0:?}\n    {0}",
+        arm_sysregs::read_cnthp_tval_el2()
+    );
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!(
+        "cnthvs_ctl = {0:?}\n    {0}",
+        arm_sysregs::read_cnthvs_ctl()
+    );
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("cnthvs_ctl_el2 = {:?}", arm_sysregs::read_cnthvs_ctl_el2());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("cnthvs_cval = {:?}", arm_sysregs::read_cnthvs_cval());
+    info!(
+        "cnthvs_ctl_el2 = {0:?}\n    {0}",
+        arm_sysregs::read_cnthvs_ctl_el2()
+    );
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!(
+        "cnthvs_cval = {0:?}\n    {0}",
+        arm_sysregs::read_cnthvs_cval()
+    );
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
     info!(
-        "cnthvs_cval_el2 = {:?}",
+        "cnthvs_cval_el2 = {0:?}\n    {0}",
         arm_sysregs::read_cnthvs_cval_el2()
     );
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("cnthvs_tval = {:?}", arm_sysregs::read_cnthvs_tval());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!(
+        "cnthvs_tval = {0:?}\n    {0}",
+        arm_sysregs::read_cnthvs_tval()
+    );
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
     info!(
-        "cnthvs_tval_el2 = {:?}",
+        "cnthvs_tval_el2 = {0:?}\n    {0}",
         arm_sysregs::read_cnthvs_tval_el2()
     );
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("cnthv_ctl = {:?}", arm_sysregs::read_cnthv_ctl());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("cnthv_ctl = {0:?}\n    {0}", arm_sysregs::read_cnthv_ctl());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("cnthv_ctl_el2 = {:?}", arm_sysregs::read_cnthv_ctl_el2());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("cnthv_cval = {:?}", arm_sysregs::read_cnthv_cval());
+    info!(
+        "cnthv_ctl_el2 = {0:?}\n    {0}",
+        arm_sysregs::read_cnthv_ctl_el2()
+    );
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!(
+        "cnthv_cval = {0:?}\n    {0}",
+        arm_sysregs::read_cnthv_cval()
+    );
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("cnthv_cval_el2 = {:?}", arm_sysregs::read_cnthv_cval_el2());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("cnthv_tval = {:?}", arm_sysregs::read_cnthv_tval());
+    info!(
+        "cnthv_cval_el2 = {0:?}\n    {0}",
+        arm_sysregs::read_cnthv_cval_el2()
+    );
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!(
+        "cnthv_tval = {0:?}\n    {0}",
+        arm_sysregs::read_cnthv_tval()
+    );
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("cnthv_tval_el2 = {:?}", arm_sysregs::read_cnthv_tval_el2());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("cntkctl = {:?}", arm_sysregs::read_cntkctl());
+    info!(
+        "cnthv_tval_el2 = {0:?}\n    {0}",
+        arm_sysregs::read_cnthv_tval_el2()
+    );
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("cntkctl = {0:?}\n    {0}", arm_sysregs::read_cntkctl());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("cntkctl_el1 = {:?}", arm_sysregs::read_cntkctl_el1());
+    info!(
+        "cntkctl_el1 = {0:?}\n    {0}",
+        arm_sysregs::read_cntkctl_el1()
+    );
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("cntpct = {:?}", arm_sysregs::read_cntpct());
+    info!("cntpct = {0:?}\n    {0}", arm_sysregs::read_cntpct());
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("cntpctss = {:?}", arm_sysregs::read_cntpctss());
+    info!("cntpctss = {0:?}\n    {0}", arm_sysregs::read_cntpctss());
     #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
-    info!("cntpctss_el0 = {:?}", arm_sysregs::read_cntpctss_el0());
+    info!(
+        "cntpctss_el0 = {0:?}\n    {0}",
+        arm_sysregs::read_cntpctss_el0()
+    );
     #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
-    info!("cntpct_el0 = {:?}", arm_sysregs::read_cntpct_el0());
+    info!(
+        "cntpct_el0 = {0:?}\n    {0}",
+        arm_sysregs::read_cntpct_el0()
+    );
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("cntpoff_el2 = {:?}", arm_sysregs::read_cntpoff_el2());
+    info!(
+        "cntpoff_el2 = {0:?}\n    {0}",
+        arm_sysregs::read_cntpoff_el2()
+    );
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("cntps_ctl_el1 = {:?}", arm_sysregs::read_cntps_ctl_el1());
+    info!(
+        "cntps_ctl_el1 = {0:?}\n    {0}",
+        arm_sysregs::read_cntps_ctl_el1()
+    );
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("cntps_cval_el1 = {:?}", arm_sysregs::read_cntps_cval_el1());
+    info!(
+        "cntps_cval_el1 = {0:?}\n    {0}",
+        arm_sysregs::read_cntps_cval_el1()
+    );
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("cntps_tval_el1 = {:?}", arm_sysregs::read_cntps_tval_el1());
+    info!(
+        "cntps_tval_el1 = {0:?}\n    {0}",
+        arm_sysregs::read_cntps_tval_el1()
+    );
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("cntp_ctl = {:?}", arm_sysregs::read_cntp_ctl());
+    info!("cntp_ctl = {0:?}\n    {0}", arm_sysregs::read_cntp_ctl());
     #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
-    info!("cntp_ctl_el0 = {:?}", arm_sysregs::read_cntp_ctl_el0());
+    info!(
+        "cntp_ctl_el0 = {0:?}\n    {0}",
+        arm_sysregs::read_cntp_ctl_el0()
+    );
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("cntp_cval = {:?}", arm_sysregs::read_cntp_cval());
+    info!("cntp_cval = {0:?}\n    {0}", arm_sysregs::read_cntp_cval());
     #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
-    info!("cntp_cval_el0 = {:?}", arm_sysregs::read_cntp_cval_el0());
+    info!(
+        "cntp_cval_el0 = {0:?}\n    {0}",
+        arm_sysregs::read_cntp_cval_el0()
+    );
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("cntp_tval = {:?}", arm_sysregs::read_cntp_tval());
+    info!("cntp_tval = {0:?}\n    {0}", arm_sysregs::read_cntp_tval());
     #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
-    info!("cntp_tval_el0 = {:?}", arm_sysregs::read_cntp_tval_el0());
+    info!(
+        "cntp_tval_el0 = {0:?}\n    {0}",
+        arm_sysregs::read_cntp_tval_el0()
+    );
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("cntvct = {:?}", arm_sysregs::read_cntvct());
+    info!("cntvct = {0:?}\n    {0}", arm_sysregs::read_cntvct());
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("cntvctss = {:?}", arm_sysregs::read_cntvctss());
+    info!("cntvctss = {0:?}\n    {0}", arm_sysregs::read_cntvctss());
     #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
-    info!("cntvctss_el0 = {:?}", arm_sysregs::read_cntvctss_el0());
+    info!(
+        "cntvctss_el0 = {0:?}\n    {0}",
+        arm_sysregs::read_cntvctss_el0()
+    );
     #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
-    info!("cntvct_el0 = {:?}", arm_sysregs::read_cntvct_el0());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("cntvoff = {:?}", arm_sysregs::read_cntvoff());
+    info!(
+        "cntvct_el0 = {0:?}\n    {0}",
+        arm_sysregs::read_cntvct_el0()
+    );
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    info!("cntvoff = {0:?}\n    {0}", arm_sysregs::read_cntvoff());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("cntvoff_el2 = {:?}", arm_sysregs::read_cntvoff_el2());
+    info!(
+        "cntvoff_el2 = {0:?}\n    {0}",
+        arm_sysregs::read_cntvoff_el2()
+    );
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("cntv_ctl = {:?}", arm_sysregs::read_cntv_ctl());
+    info!("cntv_ctl = {0:?}\n    {0}", arm_sysregs::read_cntv_ctl());
     #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
-    info!("cntv_ctl_el0 = {:?}", arm_sysregs::read_cntv_ctl_el0());
+    info!(
+        "cntv_ctl_el0 = {0:?}\n    {0}",
+        arm_sysregs::read_cntv_ctl_el0()
+    );
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("cntv_cval = {:?}", arm_sysregs::read_cntv_cval());
+    info!("cntv_cval = {0:?}\n    {0}", arm_sysregs::read_cntv_cval());
     #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
-    info!("cntv_cval_el0 = {:?}", arm_sysregs::read_cntv_cval_el0());
+    info!(
+        "cntv_cval_el0 = {0:?}\n    {0}",
+        arm_sysregs::read_cntv_cval_el0()
+    );
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("cntv_tval = {:?}", arm_sysregs::read_cntv_tval());
+    info!("cntv_tval = {0:?}\n    {0}", arm_sysregs::read_cntv_tval());
     #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
-    info!("cntv_tval_el0 = {:?}", arm_sysregs::read_cntv_tval_el0());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("contextidr = {:?}", arm_sysregs::read_contextidr());
+    info!(
+        "cntv_tval_el0 = {0:?}\n    {0}",
+        arm_sysregs::read_cntv_tval_el0()
+    );
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!(
+        "contextidr = {0:?}\n    {0}",
+        arm_sysregs::read_contextidr()
+    );
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("contextidr_el1 = {:?}", arm_sysregs::read_contextidr_el1());
+    info!(
+        "contextidr_el1 = {0:?}\n    {0}",
+        arm_sysregs::read_contextidr_el1()
+    );
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("contextidr_el2 = {:?}", arm_sysregs::read_contextidr_el2());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("cpacr = {:?}", arm_sysregs::read_cpacr());
+    info!(
+        "contextidr_el2 = {0:?}\n    {0}",
+        arm_sysregs::read_contextidr_el2()
+    );
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("cpacr = {0:?}\n    {0}", arm_sysregs::read_cpacr());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("cpacr_el1 = {:?}", arm_sysregs::read_cpacr_el1());
+    info!("cpacr_el1 = {0:?}\n    {0}", arm_sysregs::read_cpacr_el1());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("cptr_el2 = {:?}", arm_sysregs::read_cptr_el2());
+    info!("cptr_el2 = {0:?}\n    {0}", arm_sysregs::read_cptr_el2());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
-    info!("cptr_el3 = {:?}", arm_sysregs::read_cptr_el3());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("csselr = {:?}", arm_sysregs::read_csselr());
+    info!("cptr_el3 = {0:?}\n    {0}", arm_sysregs::read_cptr_el3());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("csselr = {0:?}\n    {0}", arm_sysregs::read_csselr());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("csselr_el1 = {:?}", arm_sysregs::read_csselr_el1());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("ctr = {:?}", arm_sysregs::read_ctr());
+    info!(
+        "csselr_el1 = {0:?}\n    {0}",
+        arm_sysregs::read_csselr_el1()
+    );
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("ctr = {0:?}\n    {0}", arm_sysregs::read_ctr());
     #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
-    info!("ctr_el0 = {:?}", arm_sysregs::read_ctr_el0());
+    info!("ctr_el0 = {0:?}\n    {0}", arm_sysregs::read_ctr_el0());
     #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
-    info!("currentel = {:?}", arm_sysregs::read_currentel());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("dacr = {:?}", arm_sysregs::read_dacr());
+    info!("currentel = {0:?}\n    {0}", arm_sysregs::read_currentel());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("dacr = {0:?}\n    {0}", arm_sysregs::read_dacr());
     #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
-    info!("daif = {:?}", arm_sysregs::read_daif());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("dbgauthstatus = {:?}", arm_sysregs::read_dbgauthstatus());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("dbgclaimclr = {:?}", arm_sysregs::read_dbgclaimclr());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("dbgclaimset = {:?}", arm_sysregs::read_dbgclaimset());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("dbgdccint = {:?}", arm_sysregs::read_dbgdccint());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("dbgdevid = {:?}", arm_sysregs::read_dbgdevid());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("dbgdevid1 = {:?}", arm_sysregs::read_dbgdevid1());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    info!("daif = {0:?}\n    {0}", arm_sysregs::read_daif());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!(
+        "dbgauthstatus = {0:?}\n    {0}",
+        arm_sysregs::read_dbgauthstatus()
+    );
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!(
+        "dbgclaimclr = {0:?}\n    {0}",
+        arm_sysregs::read_dbgclaimclr()
+    );
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!(
+        "dbgclaimset = {0:?}\n    {0}",
+        arm_sysregs::read_dbgclaimset()
+    );
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("dbgdccint = {0:?}\n    {0}", arm_sysregs::read_dbgdccint());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("dbgdevid = {0:?}\n    {0}", arm_sysregs::read_dbgdevid());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("dbgdevid1 = {0:?}\n    {0}", arm_sysregs::read_dbgdevid1());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
     info!("dbgdevid2 = {:?}", arm_sysregs::read_dbgdevid2());
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("dbgdidr = {:?}", arm_sysregs::read_dbgdidr());
+    info!("dbgdidr = {0:?}\n    {0}", arm_sysregs::read_dbgdidr());
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("dbgdrar = {:?}", arm_sysregs::read_dbgdrar());
+    info!("dbgdrar = {0:?}\n    {0}", arm_sysregs::read_dbgdrar());
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
     info!("dbgdsar = {:?}", arm_sysregs::read_dbgdsar());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!(
+        "dbgdscrext = {0:?}\n    {0}",
+        arm_sysregs::read_dbgdscrext()
+    );
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("dbgdscrext = {:?}", arm_sysregs::read_dbgdscrext());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("dbgdscrint = {:?}", arm_sysregs::read_dbgdscrint());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("dbgdtrrxext = {:?}", arm_sysregs::read_dbgdtrrxext());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("dbgdtrrxint = {:?}", arm_sysregs::read_dbgdtrrxint());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("dbgdtrtxext = {:?}", arm_sysregs::read_dbgdtrtxext());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("dbgosdlr = {:?}", arm_sysregs::read_dbgosdlr());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("dbgoseccr = {:?}", arm_sysregs::read_dbgoseccr());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("dbgoslsr = {:?}", arm_sysregs::read_dbgoslsr());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("dbgprcr = {:?}", arm_sysregs::read_dbgprcr());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("dbgvcr = {:?}", arm_sysregs::read_dbgvcr());
+    info!(
+        "dbgdscrint = {0:?}\n    {0}",
+        arm_sysregs::read_dbgdscrint()
+    );
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!(
+        "dbgdtrrxext = {0:?}\n    {0}",
+        arm_sysregs::read_dbgdtrrxext()
+    );
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    info!(
+        "dbgdtrrxint = {0:?}\n    {0}",
+        arm_sysregs::read_dbgdtrrxint()
+    );
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!(
+        "dbgdtrtxext = {0:?}\n    {0}",
+        arm_sysregs::read_dbgdtrtxext()
+    );
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("dbgosdlr = {0:?}\n    {0}", arm_sysregs::read_dbgosdlr());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("dbgoseccr = {0:?}\n    {0}", arm_sysregs::read_dbgoseccr());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("dbgoslsr = {0:?}\n    {0}", arm_sysregs::read_dbgoslsr());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("dbgprcr = {0:?}\n    {0}", arm_sysregs::read_dbgprcr());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("dbgvcr = {0:?}\n    {0}", arm_sysregs::read_dbgvcr());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
     info!("dbgwfar = {:?}", arm_sysregs::read_dbgwfar());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("dfar = {:?}", arm_sysregs::read_dfar());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("dfsr = {:?}", arm_sysregs::read_dfsr());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("disr = {:?}", arm_sysregs::read_disr());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("disr_el1 = {:?}", arm_sysregs::read_disr_el1());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("dfar = {0:?}\n    {0}", arm_sysregs::read_dfar());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("dfsr = {0:?}\n    {0}", arm_sysregs::read_dfsr());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("disr = {0:?}\n    {0}", arm_sysregs::read_disr());
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el1",
+        feature = "feat-ras"
+    ))]
+    info!("disr_el1 = {0:?}\n    {0}", arm_sysregs::read_disr_el1());
     #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
-    info!("dit = {:?}", arm_sysregs::read_dit());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("dlr = {:?}", arm_sysregs::read_dlr());
+    info!("dit = {0:?}\n    {0}", arm_sysregs::read_dit());
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("dspsr = {:?}", arm_sysregs::read_dspsr());
+    info!("dlr = {0:?}\n    {0}", arm_sysregs::read_dlr());
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("dspsr2 = {:?}", arm_sysregs::read_dspsr2());
+    info!("dspsr = {0:?}\n    {0}", arm_sysregs::read_dspsr());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("dspsr2 = {0:?}\n    {0}", arm_sysregs::read_dspsr2());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("elr_el1 = {:?}", arm_sysregs::read_elr_el1());
+    info!("elr_el1 = {0:?}\n    {0}", arm_sysregs::read_elr_el1());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("elr_el2 = {:?}", arm_sysregs::read_elr_el2());
+    info!("elr_el2 = {0:?}\n    {0}", arm_sysregs::read_elr_el2());
     #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
-    info!("elr_hyp = {:?}", arm_sysregs::read_elr_hyp());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("erridr = {:?}", arm_sysregs::read_erridr());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("errselr = {:?}", arm_sysregs::read_errselr());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("erxaddr = {:?}", arm_sysregs::read_erxaddr());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("erxaddr2 = {:?}", arm_sysregs::read_erxaddr2());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("erxctlr = {:?}", arm_sysregs::read_erxctlr());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("erxctlr2 = {:?}", arm_sysregs::read_erxctlr2());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("erxfr = {:?}", arm_sysregs::read_erxfr());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("erxfr2 = {:?}", arm_sysregs::read_erxfr2());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("erxmisc0 = {:?}", arm_sysregs::read_erxmisc0());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("erxmisc1 = {:?}", arm_sysregs::read_erxmisc1());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("erxmisc2 = {:?}", arm_sysregs::read_erxmisc2());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("erxmisc3 = {:?}", arm_sysregs::read_erxmisc3());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("erxmisc4 = {:?}", arm_sysregs::read_erxmisc4());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("erxmisc5 = {:?}", arm_sysregs::read_erxmisc5());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("erxmisc6 = {:?}", arm_sysregs::read_erxmisc6());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("erxmisc7 = {:?}", arm_sysregs::read_erxmisc7());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("erxstatus = {:?}", arm_sysregs::read_erxstatus());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("esr_el1 = {:?}", arm_sysregs::read_esr_el1());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("esr_el2 = {:?}", arm_sysregs::read_esr_el2());
+    info!("elr_hyp = {0:?}\n    {0}", arm_sysregs::read_elr_hyp());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("erridr = {0:?}\n    {0}", arm_sysregs::read_erridr());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("errselr = {0:?}\n    {0}", arm_sysregs::read_errselr());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("erxaddr = {0:?}\n    {0}", arm_sysregs::read_erxaddr());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("erxaddr2 = {0:?}\n    {0}", arm_sysregs::read_erxaddr2());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("erxctlr = {0:?}\n    {0}", arm_sysregs::read_erxctlr());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("erxctlr2 = {0:?}\n    {0}", arm_sysregs::read_erxctlr2());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("erxfr = {0:?}\n    {0}", arm_sysregs::read_erxfr());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("erxfr2 = {0:?}\n    {0}", arm_sysregs::read_erxfr2());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("erxmisc0 = {0:?}\n    {0}", arm_sysregs::read_erxmisc0());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("erxmisc1 = {0:?}\n    {0}", arm_sysregs::read_erxmisc1());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("erxmisc2 = {0:?}\n    {0}", arm_sysregs::read_erxmisc2());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("erxmisc3 = {0:?}\n    {0}", arm_sysregs::read_erxmisc3());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("erxmisc4 = {0:?}\n    {0}", arm_sysregs::read_erxmisc4());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("erxmisc5 = {0:?}\n    {0}", arm_sysregs::read_erxmisc5());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("erxmisc6 = {0:?}\n    {0}", arm_sysregs::read_erxmisc6());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("erxmisc7 = {0:?}\n    {0}", arm_sysregs::read_erxmisc7());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("erxstatus = {0:?}\n    {0}", arm_sysregs::read_erxstatus());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    info!("esr_el1 = {0:?}\n    {0}", arm_sysregs::read_esr_el1());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+    info!("esr_el2 = {0:?}\n    {0}", arm_sysregs::read_esr_el2());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
-    info!("esr_el3 = {:?}", arm_sysregs::read_esr_el3());
+    info!("esr_el3 = {0:?}\n    {0}", arm_sysregs::read_esr_el3());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("far_el1 = {:?}", arm_sysregs::read_far_el1());
+    info!("far_el1 = {0:?}\n    {0}", arm_sysregs::read_far_el1());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("far_el2 = {:?}", arm_sysregs::read_far_el2());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    info!("far_el2 = {0:?}\n    {0}", arm_sysregs::read_far_el2());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
     info!("fcseidr = {:?}", arm_sysregs::read_fcseidr());
     #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
-    info!("fpcr = {:?}", arm_sysregs::read_fpcr());
+    info!("fpcr = {0:?}\n    {0}", arm_sysregs::read_fpcr());
     #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
-    info!("fpmr = {:?}", arm_sysregs::read_fpmr());
+    info!("fpmr = {0:?}\n    {0}", arm_sysregs::read_fpmr());
     #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
-    info!("fpsr = {:?}", arm_sysregs::read_fpsr());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("gcr_el1 = {:?}", arm_sysregs::read_gcr_el1());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("gcscr_el1 = {:?}", arm_sysregs::read_gcscr_el1());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("gcscr_el2 = {:?}", arm_sysregs::read_gcscr_el2());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
-    info!("gpccr_el3 = {:?}", arm_sysregs::read_gpccr_el3());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
-    info!("gptbr_el3 = {:?}", arm_sysregs::read_gptbr_el3());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    info!("fpsr = {0:?}\n    {0}", arm_sysregs::read_fpsr());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    info!("gcr_el1 = {0:?}\n    {0}", arm_sysregs::read_gcr_el1());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    info!("gcscr_el1 = {0:?}\n    {0}", arm_sysregs::read_gcscr_el1());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+    info!("gcscr_el2 = {0:?}\n    {0}", arm_sysregs::read_gcscr_el2());
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el3",
+        feature = "feat-rme"
+    ))]
+    info!("gpccr_el3 = {0:?}\n    {0}", arm_sysregs::read_gpccr_el3());
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el3",
+        feature = "feat-rme"
+    ))]
+    info!("gptbr_el3 = {0:?}\n    {0}", arm_sysregs::read_gptbr_el3());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
     info!("hacr = {:?}", arm_sysregs::read_hacr());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
     info!("hacr_el2 = {:?}", arm_sysregs::read_hacr_el2());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
     info!("hactlr = {:?}", arm_sysregs::read_hactlr());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
     info!("hactlr2 = {:?}", arm_sysregs::read_hactlr2());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
     info!("hadfsr = {:?}", arm_sysregs::read_hadfsr());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("hafgrtr_el2 = {:?}", arm_sysregs::read_hafgrtr_el2());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    info!(
+        "hafgrtr_el2 = {0:?}\n    {0}",
+        arm_sysregs::read_hafgrtr_el2()
+    );
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
     info!("haifsr = {:?}", arm_sysregs::read_haifsr());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
     info!("hamair0 = {:?}", arm_sysregs::read_hamair0());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
     info!("hamair1 = {:?}", arm_sysregs::read_hamair1());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("hcptr = {:?}", arm_sysregs::read_hcptr());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("hcr = {:?}", arm_sysregs::read_hcr());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("hcr2 = {:?}", arm_sysregs::read_hcr2());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    info!("hcptr = {0:?}\n    {0}", arm_sysregs::read_hcptr());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    info!("hcr = {0:?}\n    {0}", arm_sysregs::read_hcr());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    info!("hcr2 = {0:?}\n    {0}", arm_sysregs::read_hcr2());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("hcrx_el2 = {:?}", arm_sysregs::read_hcrx_el2());
+    info!("hcrx_el2 = {0:?}\n    {0}", arm_sysregs::read_hcrx_el2());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("hcr_el2 = {:?}", arm_sysregs::read_hcr_el2());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("hdcr = {:?}", arm_sysregs::read_hdcr());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("hdfar = {:?}", arm_sysregs::read_hdfar());
+    info!("hcr_el2 = {0:?}\n    {0}", arm_sysregs::read_hcr_el2());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    info!("hdcr = {0:?}\n    {0}", arm_sysregs::read_hdcr());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    info!("hdfar = {0:?}\n    {0}", arm_sysregs::read_hdfar());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("hdfgrtr2_el2 = {:?}", arm_sysregs::read_hdfgrtr2_el2());
+    info!(
+        "hdfgrtr2_el2 = {0:?}\n    {0}",
+        arm_sysregs::read_hdfgrtr2_el2()
+    );
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("hdfgrtr_el2 = {:?}", arm_sysregs::read_hdfgrtr_el2());
+    info!(
+        "hdfgrtr_el2 = {0:?}\n    {0}",
+        arm_sysregs::read_hdfgrtr_el2()
+    );
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("hdfgwtr2_el2 = {:?}", arm_sysregs::read_hdfgwtr2_el2());
+    info!(
+        "hdfgwtr2_el2 = {0:?}\n    {0}",
+        arm_sysregs::read_hdfgwtr2_el2()
+    );
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("hdfgwtr_el2 = {:?}", arm_sysregs::read_hdfgwtr_el2());
+    info!(
+        "hdfgwtr_el2 = {0:?}\n    {0}",
+        arm_sysregs::read_hdfgwtr_el2()
+    );
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("hfgitr2_el2 = {:?}", arm_sysregs::read_hfgitr2_el2());
+    info!(
+        "hfgitr2_el2 = {0:?}\n    {0}",
+        arm_sysregs::read_hfgitr2_el2()
+    );
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("hfgitr_el2 = {:?}", arm_sysregs::read_hfgitr_el2());
+    info!(
+        "hfgitr_el2 = {0:?}\n    {0}",
+        arm_sysregs::read_hfgitr_el2()
+    );
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("hfgrtr2_el2 = {:?}", arm_sysregs::read_hfgrtr2_el2());
+    info!(
+        "hfgrtr2_el2 = {0:?}\n    {0}",
+        arm_sysregs::read_hfgrtr2_el2()
+    );
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("hfgrtr_el2 = {:?}", arm_sysregs::read_hfgrtr_el2());
+    info!(
+        "hfgrtr_el2 = {0:?}\n    {0}",
+        arm_sysregs::read_hfgrtr_el2()
+    );
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("hfgwtr2_el2 = {:?}", arm_sysregs::read_hfgwtr2_el2());
+    info!(
+        "hfgwtr2_el2 = {0:?}\n    {0}",
+        arm_sysregs::read_hfgwtr2_el2()
+    );
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("hfgwtr_el2 = {:?}", arm_sysregs::read_hfgwtr_el2());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("hifar = {:?}", arm_sysregs::read_hifar());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("hmair0 = {:?}", arm_sysregs::read_hmair0());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("hmair1 = {:?}", arm_sysregs::read_hmair1());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("hpfar = {:?}", arm_sysregs::read_hpfar());
+    info!(
+        "hfgwtr_el2 = {0:?}\n    {0}",
+        arm_sysregs::read_hfgwtr_el2()
+    );
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    info!("hifar = {0:?}\n    {0}", arm_sysregs::read_hifar());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    info!("hmair0 = {0:?}\n    {0}", arm_sysregs::read_hmair0());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    info!("hmair1 = {0:?}\n    {0}", arm_sysregs::read_hmair1());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    info!("hpfar = {0:?}\n    {0}", arm_sysregs::read_hpfar());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("hpfar_el2 = {:?}", arm_sysregs::read_hpfar_el2());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("hrmr = {:?}", arm_sysregs::read_hrmr());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("hsctlr = {:?}", arm_sysregs::read_hsctlr());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("hsr = {:?}", arm_sysregs::read_hsr());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    info!("hpfar_el2 = {0:?}\n    {0}", arm_sysregs::read_hpfar_el2());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    info!("hrmr = {0:?}\n    {0}", arm_sysregs::read_hrmr());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    info!("hsctlr = {0:?}\n    {0}", arm_sysregs::read_hsctlr());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    info!("hsr = {0:?}\n    {0}", arm_sysregs::read_hsr());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
     info!("hstr = {:?}", arm_sysregs::read_hstr());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
     info!("hstr_el2 = {:?}", arm_sysregs::read_hstr_el2());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("htcr = {:?}", arm_sysregs::read_htcr());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("htpidr = {:?}", arm_sysregs::read_htpidr());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("htrfcr = {:?}", arm_sysregs::read_htrfcr());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("httbr = {:?}", arm_sysregs::read_httbr());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("hvbar = {:?}", arm_sysregs::read_hvbar());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    info!("htcr = {0:?}\n    {0}", arm_sysregs::read_htcr());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    info!("htpidr = {0:?}\n    {0}", arm_sysregs::read_htpidr());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    info!("htrfcr = {0:?}\n    {0}", arm_sysregs::read_htrfcr());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    info!("httbr = {0:?}\n    {0}", arm_sysregs::read_httbr());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    info!("hvbar = {0:?}\n    {0}", arm_sysregs::read_hvbar());
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el1",
+        feature = "feat-gic"
+    ))]
     info!("icc_ap0r0_el1 = {:?}", arm_sysregs::read_icc_ap0r0_el1());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el1",
+        feature = "feat-gic"
+    ))]
     info!("icc_ap0r1_el1 = {:?}", arm_sysregs::read_icc_ap0r1_el1());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el1",
+        feature = "feat-gic"
+    ))]
     info!("icc_ap0r2_el1 = {:?}", arm_sysregs::read_icc_ap0r2_el1());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el1",
+        feature = "feat-gic"
+    ))]
     info!("icc_ap0r3_el1 = {:?}", arm_sysregs::read_icc_ap0r3_el1());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("icc_ap1r0_el1 = {:?}", arm_sysregs::read_icc_ap1r0_el1());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el1",
+        feature = "feat-gic"
+    ))]
+    info!(
+        "icc_ap1r0_el1 = {0:?}\n    {0}",
+        arm_sysregs::read_icc_ap1r0_el1()
+    );
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el1",
+        feature = "feat-gic"
+    ))]
     info!("icc_ap1r1_el1 = {:?}", arm_sysregs::read_icc_ap1r1_el1());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el1",
+        feature = "feat-gic"
+    ))]
     info!("icc_ap1r2_el1 = {:?}", arm_sysregs::read_icc_ap1r2_el1());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el1",
+        feature = "feat-gic"
+    ))]
     info!("icc_ap1r3_el1 = {:?}", arm_sysregs::read_icc_ap1r3_el1());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("icc_bpr0 = {:?}", arm_sysregs::read_icc_bpr0());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("icc_bpr0_el1 = {:?}", arm_sysregs::read_icc_bpr0_el1());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("icc_bpr1 = {:?}", arm_sysregs::read_icc_bpr1());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("icc_bpr1_el1 = {:?}", arm_sysregs::read_icc_bpr1_el1());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("icc_ctlr = {:?}", arm_sysregs::read_icc_ctlr());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("icc_ctlr_el1 = {:?}", arm_sysregs::read_icc_ctlr_el1());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
-    info!("icc_ctlr_el3 = {:?}", arm_sysregs::read_icc_ctlr_el3());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("icc_hppir0 = {:?}", arm_sysregs::read_icc_hppir0());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("icc_hppir0_el1 = {:?}", arm_sysregs::read_icc_hppir0_el1());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("icc_hppir1 = {:?}", arm_sysregs::read_icc_hppir1());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("icc_hppir1_el1 = {:?}", arm_sysregs::read_icc_hppir1_el1());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("icc_hsre = {:?}", arm_sysregs::read_icc_hsre());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("icc_iar0 = {:?}", arm_sysregs::read_icc_iar0());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("icc_iar0_el1 = {:?}", arm_sysregs::read_icc_iar0_el1());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("icc_iar1 = {:?}", arm_sysregs::read_icc_iar1());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("icc_iar1_el1 = {:?}", arm_sysregs::read_icc_iar1_el1());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("icc_igrpen0 = {:?}", arm_sysregs::read_icc_igrpen0());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("icc_bpr0 = {0:?}\n    {0}", arm_sysregs::read_icc_bpr0());
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el1",
+        feature = "feat-gic"
+    ))]
+    info!(
+        "icc_bpr0_el1 = {0:?}\n    {0}",
+        arm_sysregs::read_icc_bpr0_el1()
+    );
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("icc_bpr1 = {0:?}\n    {0}", arm_sysregs::read_icc_bpr1());
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el1",
+        feature = "feat-gic"
+    ))]
+    info!(
+        "icc_bpr1_el1 = {0:?}\n    {0}",
+        arm_sysregs::read_icc_bpr1_el1()
+    );
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("icc_ctlr = {0:?}\n    {0}", arm_sysregs::read_icc_ctlr());
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el1",
+        feature = "feat-gic"
+    ))]
     info!(
-        "icc_igrpen0_el1 = {:?}",
+        "icc_ctlr_el1 = {0:?}\n    {0}",
+        arm_sysregs::read_icc_ctlr_el1()
+    );
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el3",
+        feature = "feat-gic"
+    ))]
+    info!(
+        "icc_ctlr_el3 = {0:?}\n    {0}",
+        arm_sysregs::read_icc_ctlr_el3()
+    );
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!(
+        "icc_hppir0 = {0:?}\n    {0}",
+        arm_sysregs::read_icc_hppir0()
+    );
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el1",
+        feature = "feat-gic"
+    ))]
+    info!(
+        "icc_hppir0_el1 = {0:?}\n    {0}",
+        arm_sysregs::read_icc_hppir0_el1()
+    );
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!(
+        "icc_hppir1 = {0:?}\n    {0}",
+        arm_sysregs::read_icc_hppir1()
+    );
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el1",
+        feature = "feat-gic"
+    ))]
+    info!(
+        "icc_hppir1_el1 = {0:?}\n    {0}",
+        arm_sysregs::read_icc_hppir1_el1()
+    );
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    info!("icc_hsre = {0:?}\n    {0}", arm_sysregs::read_icc_hsre());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("icc_iar0 = {0:?}\n    {0}", arm_sysregs::read_icc_iar0());
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el1",
+        feature = "feat-gic"
+    ))]
+    info!(
+        "icc_iar0_el1 = {0:?}\n    {0}",
+        arm_sysregs::read_icc_iar0_el1()
+    );
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("icc_iar1 = {0:?}\n    {0}", arm_sysregs::read_icc_iar1());
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el1",
+        feature = "feat-gic"
+    ))]
+    info!(
+        "icc_iar1_el1 = {0:?}\n    {0}",
+        arm_sysregs::read_icc_iar1_el1()
+    );
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!(
+        "icc_igrpen0 = {0:?}\n    {0}",
+        arm_sysregs::read_icc_igrpen0()
+    );
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el1",
+        feature = "feat-gic"
+    ))]
+    info!(
+        "icc_igrpen0_el1 = {0:?}\n    {0}",
         arm_sysregs::read_icc_igrpen0_el1()
     );
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("icc_igrpen1 = {:?}", arm_sysregs::read_icc_igrpen1());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
     info!(
-        "icc_igrpen1_el1 = {:?}",
+        "icc_igrpen1 = {0:?}\n    {0}",
+        arm_sysregs::read_icc_igrpen1()
+    );
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el1",
+        feature = "feat-gic"
+    ))]
+    info!(
+        "icc_igrpen1_el1 = {0:?}\n    {0}",
         arm_sysregs::read_icc_igrpen1_el1()
     );
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el3",
+        feature = "feat-gic"
+    ))]
     info!(
-        "icc_igrpen1_el3 = {:?}",
+        "icc_igrpen1_el3 = {0:?}\n    {0}",
         arm_sysregs::read_icc_igrpen1_el3()
     );
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("icc_mctlr = {:?}", arm_sysregs::read_icc_mctlr());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("icc_mgrpen1 = {:?}", arm_sysregs::read_icc_mgrpen1());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("icc_msre = {:?}", arm_sysregs::read_icc_msre());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("icc_nmiar1_el1 = {:?}", arm_sysregs::read_icc_nmiar1_el1());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("icc_pmr = {:?}", arm_sysregs::read_icc_pmr());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("icc_pmr_el1 = {:?}", arm_sysregs::read_icc_pmr_el1());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("icc_rpr = {:?}", arm_sysregs::read_icc_rpr());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("icc_rpr_el1 = {:?}", arm_sysregs::read_icc_rpr_el1());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("icc_sre = {:?}", arm_sysregs::read_icc_sre());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("icc_sre_el1 = {:?}", arm_sysregs::read_icc_sre_el1());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("icc_sre_el2 = {:?}", arm_sysregs::read_icc_sre_el2());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
-    info!("icc_sre_el3 = {:?}", arm_sysregs::read_icc_sre_el3());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("ich_hcr_el2 = {:?}", arm_sysregs::read_ich_hcr_el2());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("ich_vmcr_el2 = {:?}", arm_sysregs::read_ich_vmcr_el2());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el3"))]
+    info!("icc_mctlr = {0:?}\n    {0}", arm_sysregs::read_icc_mctlr());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el3"))]
+    info!(
+        "icc_mgrpen1 = {0:?}\n    {0}",
+        arm_sysregs::read_icc_mgrpen1()
+    );
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el3"))]
+    info!("icc_msre = {0:?}\n    {0}", arm_sysregs::read_icc_msre());
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el1",
+        feature = "feat-gic"
+    ))]
+    info!(
+        "icc_nmiar1_el1 = {0:?}\n    {0}",
+        arm_sysregs::read_icc_nmiar1_el1()
+    );
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("icc_pmr = {0:?}\n    {0}", arm_sysregs::read_icc_pmr());
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el1",
+        feature = "feat-gic"
+    ))]
+    info!(
+        "icc_pmr_el1 = {0:?}\n    {0}",
+        arm_sysregs::read_icc_pmr_el1()
+    );
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("icc_rpr = {0:?}\n    {0}", arm_sysregs::read_icc_rpr());
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el1",
+        feature = "feat-gic"
+    ))]
+    info!(
+        "icc_rpr_el1 = {0:?}\n    {0}",
+        arm_sysregs::read_icc_rpr_el1()
+    );
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("icc_sre = {0:?}\n    {0}", arm_sysregs::read_icc_sre());
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el1",
+        feature = "feat-gic"
+    ))]
+    info!(
+        "icc_sre_el1 = {0:?}\n    {0}",
+        arm_sysregs::read_icc_sre_el1()
+    );
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el2",
+        feature = "feat-gic"
+    ))]
+    info!(
+        "icc_sre_el2 = {0:?}\n    {0}",
+        arm_sysregs::read_icc_sre_el2()
+    );
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el3",
+        feature = "feat-gic"
+    ))]
+    info!(
+        "icc_sre_el3 = {0:?}\n    {0}",
+        arm_sysregs::read_icc_sre_el3()
+    );
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el2",
+        feature = "feat-gic"
+    ))]
+    info!(
+        "ich_hcr_el2 = {0:?}\n    {0}",
+        arm_sysregs::read_ich_hcr_el2()
+    );
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el2",
+        feature = "feat-gic"
+    ))]
+    info!(
+        "ich_vmcr_el2 = {0:?}\n    {0}",
+        arm_sysregs::read_ich_vmcr_el2()
+    );
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
     info!(
-        "id_aa64dfr0_el1 = {:?}",
+        "id_aa64dfr0_el1 = {0:?}\n    {0}",
         arm_sysregs::read_id_aa64dfr0_el1()
     );
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
     info!(
-        "id_aa64dfr1_el1 = {:?}",
+        "id_aa64dfr1_el1 = {0:?}\n    {0}",
         arm_sysregs::read_id_aa64dfr1_el1()
     );
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
     info!(
-        "id_aa64isar1_el1 = {:?}",
+        "id_aa64isar1_el1 = {0:?}\n    {0}",
         arm_sysregs::read_id_aa64isar1_el1()
     );
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
     info!(
-        "id_aa64isar2_el1 = {:?}",
+        "id_aa64isar2_el1 = {0:?}\n    {0}",
         arm_sysregs::read_id_aa64isar2_el1()
     );
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
     info!(
-        "id_aa64mmfr0_el1 = {:?}",
+        "id_aa64mmfr0_el1 = {0:?}\n    {0}",
         arm_sysregs::read_id_aa64mmfr0_el1()
     );
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
     info!(
-        "id_aa64mmfr1_el1 = {:?}",
+        "id_aa64mmfr1_el1 = {0:?}\n    {0}",
         arm_sysregs::read_id_aa64mmfr1_el1()
     );
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
     info!(
-        "id_aa64mmfr2_el1 = {:?}",
+        "id_aa64mmfr2_el1 = {0:?}\n    {0}",
         arm_sysregs::read_id_aa64mmfr2_el1()
     );
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
     info!(
-        "id_aa64mmfr3_el1 = {:?}",
+        "id_aa64mmfr3_el1 = {0:?}\n    {0}",
         arm_sysregs::read_id_aa64mmfr3_el1()
     );
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
     info!(
-        "id_aa64mmfr4_el1 = {:?}",
+        "id_aa64mmfr4_el1 = {0:?}\n    {0}",
         arm_sysregs::read_id_aa64mmfr4_el1()
     );
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
     info!(
-        "id_aa64pfr0_el1 = {:?}",
+        "id_aa64pfr0_el1 = {0:?}\n    {0}",
         arm_sysregs::read_id_aa64pfr0_el1()
     );
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
     info!(
-        "id_aa64pfr1_el1 = {:?}",
+        "id_aa64pfr1_el1 = {0:?}\n    {0}",
         arm_sysregs::read_id_aa64pfr1_el1()
     );
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
     info!(
-        "id_aa64pfr2_el1 = {:?}",
+        "id_aa64pfr2_el1 = {0:?}\n    {0}",
         arm_sysregs::read_id_aa64pfr2_el1()
     );
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
     info!(
-        "id_aa64smfr0_el1 = {:?}",
+        "id_aa64smfr0_el1 = {0:?}\n    {0}",
         arm_sysregs::read_id_aa64smfr0_el1()
     );
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
     info!("id_afr0 = {:?}", arm_sysregs::read_id_afr0());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("id_dfr0 = {:?}", arm_sysregs::read_id_dfr0());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("id_dfr1 = {:?}", arm_sysregs::read_id_dfr1());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("id_isar0 = {:?}", arm_sysregs::read_id_isar0());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("id_isar1 = {:?}", arm_sysregs::read_id_isar1());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("id_isar2 = {:?}", arm_sysregs::read_id_isar2());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("id_isar3 = {:?}", arm_sysregs::read_id_isar3());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("id_isar4 = {:?}", arm_sysregs::read_id_isar4());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("id_isar5 = {:?}", arm_sysregs::read_id_isar5());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("id_isar6 = {:?}", arm_sysregs::read_id_isar6());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("id_mmfr0 = {:?}", arm_sysregs::read_id_mmfr0());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("id_mmfr1 = {:?}", arm_sysregs::read_id_mmfr1());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("id_mmfr2 = {:?}", arm_sysregs::read_id_mmfr2());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("id_mmfr3 = {:?}", arm_sysregs::read_id_mmfr3());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("id_mmfr4 = {:?}", arm_sysregs::read_id_mmfr4());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("id_mmfr5 = {:?}", arm_sysregs::read_id_mmfr5());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("id_pfr0 = {:?}", arm_sysregs::read_id_pfr0());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("id_pfr1 = {:?}", arm_sysregs::read_id_pfr1());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("id_pfr2 = {:?}", arm_sysregs::read_id_pfr2());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("ifar = {:?}", arm_sysregs::read_ifar());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("ifsr = {:?}", arm_sysregs::read_ifsr());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("isr = {:?}", arm_sysregs::read_isr());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("isr_el1 = {:?}", arm_sysregs::read_isr_el1());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("id_dfr0 = {0:?}\n    {0}", arm_sysregs::read_id_dfr0());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("id_dfr1 = {0:?}\n    {0}", arm_sysregs::read_id_dfr1());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("id_isar0 = {0:?}\n    {0}", arm_sysregs::read_id_isar0());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("id_isar1 = {0:?}\n    {0}", arm_sysregs::read_id_isar1());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("id_isar2 = {0:?}\n    {0}", arm_sysregs::read_id_isar2());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("id_isar3 = {0:?}\n    {0}", arm_sysregs::read_id_isar3());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("id_isar4 = {0:?}\n    {0}", arm_sysregs::read_id_isar4());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("id_isar5 = {0:?}\n    {0}", arm_sysregs::read_id_isar5());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("id_isar6 = {0:?}\n    {0}", arm_sysregs::read_id_isar6());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("id_mmfr0 = {0:?}\n    {0}", arm_sysregs::read_id_mmfr0());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("id_mmfr1 = {0:?}\n    {0}", arm_sysregs::read_id_mmfr1());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("id_mmfr2 = {0:?}\n    {0}", arm_sysregs::read_id_mmfr2());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("id_mmfr3 = {0:?}\n    {0}", arm_sysregs::read_id_mmfr3());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("id_mmfr4 = {0:?}\n    {0}", arm_sysregs::read_id_mmfr4());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("id_mmfr5 = {0:?}\n    {0}", arm_sysregs::read_id_mmfr5());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("id_pfr0 = {0:?}\n    {0}", arm_sysregs::read_id_pfr0());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("id_pfr1 = {0:?}\n    {0}", arm_sysregs::read_id_pfr1());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("id_pfr2 = {0:?}\n    {0}", arm_sysregs::read_id_pfr2());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("ifar = {0:?}\n    {0}", arm_sysregs::read_ifar());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("ifsr = {0:?}\n    {0}", arm_sysregs::read_ifsr());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("isr = {0:?}\n    {0}", arm_sysregs::read_isr());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    info!("isr_el1 = {0:?}\n    {0}", arm_sysregs::read_isr_el1());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
     info!("jidr = {:?}", arm_sysregs::read_jidr());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
     info!("jmcr = {:?}", arm_sysregs::read_jmcr());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
     info!("joscr = {:?}", arm_sysregs::read_joscr());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("mair0 = {:?}", arm_sysregs::read_mair0());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("mair1 = {:?}", arm_sysregs::read_mair1());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("mair0 = {0:?}\n    {0}", arm_sysregs::read_mair0());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("mair1 = {0:?}\n    {0}", arm_sysregs::read_mair1());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("mair_el1 = {:?}", arm_sysregs::read_mair_el1());
+    info!("mair_el1 = {0:?}\n    {0}", arm_sysregs::read_mair_el1());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("mair_el2 = {:?}", arm_sysregs::read_mair_el2());
+    info!("mair_el2 = {0:?}\n    {0}", arm_sysregs::read_mair_el2());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
-    info!("mair_el3 = {:?}", arm_sysregs::read_mair_el3());
+    info!("mair_el3 = {0:?}\n    {0}", arm_sysregs::read_mair_el3());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("mdccint_el1 = {:?}", arm_sysregs::read_mdccint_el1());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("mdcr_el2 = {:?}", arm_sysregs::read_mdcr_el2());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
-    info!("mdcr_el3 = {:?}", arm_sysregs::read_mdcr_el3());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("mdscr_el1 = {:?}", arm_sysregs::read_mdscr_el1());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("midr = {:?}", arm_sysregs::read_midr());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("midr_el1 = {:?}", arm_sysregs::read_midr_el1());
+    info!(
+        "mdccint_el1 = {0:?}\n    {0}",
+        arm_sysregs::read_mdccint_el1()
+    );
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("mpam2_el2 = {:?}", arm_sysregs::read_mpam2_el2());
+    info!("mdcr_el2 = {0:?}\n    {0}", arm_sysregs::read_mdcr_el2());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
-    info!("mpam3_el3 = {:?}", arm_sysregs::read_mpam3_el3());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("mpamhcr_el2 = {:?}", arm_sysregs::read_mpamhcr_el2());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("mpamidr_el1 = {:?}", arm_sysregs::read_mpamidr_el1());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("mpamvpm0_el2 = {:?}", arm_sysregs::read_mpamvpm0_el2());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("mpamvpm1_el2 = {:?}", arm_sysregs::read_mpamvpm1_el2());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("mpamvpm2_el2 = {:?}", arm_sysregs::read_mpamvpm2_el2());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("mpamvpm3_el2 = {:?}", arm_sysregs::read_mpamvpm3_el2());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("mpamvpm4_el2 = {:?}", arm_sysregs::read_mpamvpm4_el2());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("mpamvpm5_el2 = {:?}", arm_sysregs::read_mpamvpm5_el2());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("mpamvpm6_el2 = {:?}", arm_sysregs::read_mpamvpm6_el2());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("mpamvpm7_el2 = {:?}", arm_sysregs::read_mpamvpm7_el2());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("mpamvpmv_el2 = {:?}", arm_sysregs::read_mpamvpmv_el2());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("mpidr = {:?}", arm_sysregs::read_mpidr());
+    info!("mdcr_el3 = {0:?}\n    {0}", arm_sysregs::read_mdcr_el3());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    info!("mdscr_el1 = {0:?}\n    {0}", arm_sysregs::read_mdscr_el1());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("midr = {0:?}\n    {0}", arm_sysregs::read_midr());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    info!("midr_el1 = {0:?}\n    {0}", arm_sysregs::read_midr_el1());
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el2",
+        feature = "feat-mpam"
+    ))]
+    info!("mpam2_el2 = {0:?}\n    {0}", arm_sysregs::read_mpam2_el2());
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el3",
+        feature = "feat-mpam"
+    ))]
+    info!("mpam3_el3 = {0:?}\n    {0}", arm_sysregs::read_mpam3_el3());
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el2",
+        feature = "feat-mpam"
+    ))]
+    info!(
+        "mpamhcr_el2 = {0:?}\n    {0}",
+        arm_sysregs::read_mpamhcr_el2()
+    );
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el1",
+        feature = "feat-mpam"
+    ))]
+    info!(
+        "mpamidr_el1 = {0:?}\n    {0}",
+        arm_sysregs::read_mpamidr_el1()
+    );
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el2",
+        feature = "feat-mpam"
+    ))]
+    info!(
+        "mpamvpm0_el2 = {0:?}\n    {0}",
+        arm_sysregs::read_mpamvpm0_el2()
+    );
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el2",
+        feature = "feat-mpam"
+    ))]
+    info!(
+        "mpamvpm1_el2 = {0:?}\n    {0}",
+        arm_sysregs::read_mpamvpm1_el2()
+    );
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el2",
+        feature = "feat-mpam"
+    ))]
+    info!(
+        "mpamvpm2_el2 = {0:?}\n    {0}",
+        arm_sysregs::read_mpamvpm2_el2()
+    );
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el2",
+        feature = "feat-mpam"
+    ))]
+    info!(
+        "mpamvpm3_el2 = {0:?}\n    {0}",
+        arm_sysregs::read_mpamvpm3_el2()
+    );
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el2",
+        feature = "feat-mpam"
+    ))]
+    info!(
+        "mpamvpm4_el2 = {0:?}\n    {0}",
+        arm_sysregs::read_mpamvpm4_el2()
+    );
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el2",
+        feature = "feat-mpam"
+    ))]
+    info!(
+        "mpamvpm5_el2 = {0:?}\n    {0}",
+        arm_sysregs::read_mpamvpm5_el2()
+    );
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el2",
+        feature = "feat-mpam"
+    ))]
+    info!(
+        "mpamvpm6_el2 = {0:?}\n    {0}",
+        arm_sysregs::read_mpamvpm6_el2()
+    );
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el2",
+        feature = "feat-mpam"
+    ))]
+    info!(
+        "mpamvpm7_el2 = {0:?}\n    {0}",
+        arm_sysregs::read_mpamvpm7_el2()
+    );
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el2",
+        feature = "feat-mpam"
+    ))]
+    info!(
+        "mpamvpmv_el2 = {0:?}\n    {0}",
+        arm_sysregs::read_mpamvpmv_el2()
+    );
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("mpidr = {0:?}\n    {0}", arm_sysregs::read_mpidr());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("mpidr_el1 = {:?}", arm_sysregs::read_mpidr_el1());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("mvbar = {:?}", arm_sysregs::read_mvbar());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("nmrr = {:?}", arm_sysregs::read_nmrr());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("nsacr = {:?}", arm_sysregs::read_nsacr());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("par = {:?}", arm_sysregs::read_par());
+    info!("mpidr_el1 = {0:?}\n    {0}", arm_sysregs::read_mpidr_el1());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("mvbar = {0:?}\n    {0}", arm_sysregs::read_mvbar());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("nmrr = {0:?}\n    {0}", arm_sysregs::read_nmrr());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("nsacr = {0:?}\n    {0}", arm_sysregs::read_nsacr());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("par = {0:?}\n    {0}", arm_sysregs::read_par());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("par_el1 = {:?}", arm_sysregs::read_par_el1());
+    info!("par_el1 = {0:?}\n    {0}", arm_sysregs::read_par_el1());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("pfar_el1 = {:?}", arm_sysregs::read_pfar_el1());
+    info!("pfar_el1 = {0:?}\n    {0}", arm_sysregs::read_pfar_el1());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("pfar_el2 = {:?}", arm_sysregs::read_pfar_el2());
+    info!("pfar_el2 = {0:?}\n    {0}", arm_sysregs::read_pfar_el2());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("pire0_el1 = {:?}", arm_sysregs::read_pire0_el1());
+    info!("pire0_el1 = {0:?}\n    {0}", arm_sysregs::read_pire0_el1());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("pire0_el2 = {:?}", arm_sysregs::read_pire0_el2());
+    info!("pire0_el2 = {0:?}\n    {0}", arm_sysregs::read_pire0_el2());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("pir_el1 = {:?}", arm_sysregs::read_pir_el1());
+    info!("pir_el1 = {0:?}\n    {0}", arm_sysregs::read_pir_el1());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("pir_el2 = {:?}", arm_sysregs::read_pir_el2());
+    info!("pir_el2 = {0:?}\n    {0}", arm_sysregs::read_pir_el2());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
-    info!("pir_el3 = {:?}", arm_sysregs::read_pir_el3());
+    info!("pir_el3 = {0:?}\n    {0}", arm_sysregs::read_pir_el3());
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("pmccfiltr = {:?}", arm_sysregs::read_pmccfiltr());
+    info!("pmccfiltr = {0:?}\n    {0}", arm_sysregs::read_pmccfiltr());
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("pmccntr = {:?}", arm_sysregs::read_pmccntr());
+    info!("pmccntr = {0:?}\n    {0}", arm_sysregs::read_pmccntr());
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("pmceid0 = {:?}", arm_sysregs::read_pmceid0());
+    info!("pmceid0 = {0:?}\n    {0}", arm_sysregs::read_pmceid0());
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("pmceid1 = {:?}", arm_sysregs::read_pmceid1());
+    info!("pmceid1 = {0:?}\n    {0}", arm_sysregs::read_pmceid1());
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("pmceid2 = {:?}", arm_sysregs::read_pmceid2());
+    info!("pmceid2 = {0:?}\n    {0}", arm_sysregs::read_pmceid2());
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("pmceid3 = {:?}", arm_sysregs::read_pmceid3());
+    info!("pmceid3 = {0:?}\n    {0}", arm_sysregs::read_pmceid3());
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("pmcntenclr = {:?}", arm_sysregs::read_pmcntenclr());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("pmcntenset = {:?}", arm_sysregs::read_pmcntenset());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("pmcr = {:?}", arm_sysregs::read_pmcr());
-    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
-    info!("pmcr_el0 = {:?}", arm_sysregs::read_pmcr_el0());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("pmintenclr = {:?}", arm_sysregs::read_pmintenclr());
+    info!(
+        "pmcntenclr = {0:?}\n    {0}",
+        arm_sysregs::read_pmcntenclr()
+    );
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("pmintenset = {:?}", arm_sysregs::read_pmintenset());
+    info!(
+        "pmcntenset = {0:?}\n    {0}",
+        arm_sysregs::read_pmcntenset()
+    );
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("pmmir = {:?}", arm_sysregs::read_pmmir());
+    info!("pmcr = {0:?}\n    {0}", arm_sysregs::read_pmcr());
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-pmu"
+    ))]
+    info!("pmcr_el0 = {0:?}\n    {0}", arm_sysregs::read_pmcr_el0());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!(
+        "pmintenclr = {0:?}\n    {0}",
+        arm_sysregs::read_pmintenclr()
+    );
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!(
+        "pmintenset = {0:?}\n    {0}",
+        arm_sysregs::read_pmintenset()
+    );
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("pmmir = {0:?}\n    {0}", arm_sysregs::read_pmmir());
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("pmovsr = {:?}", arm_sysregs::read_pmovsr());
+    info!("pmovsr = {0:?}\n    {0}", arm_sysregs::read_pmovsr());
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("pmovsset = {:?}", arm_sysregs::read_pmovsset());
+    info!("pmovsset = {0:?}\n    {0}", arm_sysregs::read_pmovsset());
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("pmselr = {:?}", arm_sysregs::read_pmselr());
+    info!("pmselr = {0:?}\n    {0}", arm_sysregs::read_pmselr());
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("pmuserenr = {:?}", arm_sysregs::read_pmuserenr());
+    info!("pmuserenr = {0:?}\n    {0}", arm_sysregs::read_pmuserenr());
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("pmxevtyper = {:?}", arm_sysregs::read_pmxevtyper());
+    info!(
+        "pmxevtyper = {0:?}\n    {0}",
+        arm_sysregs::read_pmxevtyper()
+    );
     #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
-    info!("por_el0 = {:?}", arm_sysregs::read_por_el0());
+    info!("por_el0 = {0:?}\n    {0}", arm_sysregs::read_por_el0());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("por_el1 = {:?}", arm_sysregs::read_por_el1());
+    info!("por_el1 = {0:?}\n    {0}", arm_sysregs::read_por_el1());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("por_el2 = {:?}", arm_sysregs::read_por_el2());
+    info!("por_el2 = {0:?}\n    {0}", arm_sysregs::read_por_el2());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
-    info!("por_el3 = {:?}", arm_sysregs::read_por_el3());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("prrr = {:?}", arm_sysregs::read_prrr());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    info!("por_el3 = {0:?}\n    {0}", arm_sysregs::read_por_el3());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("prrr = {0:?}\n    {0}", arm_sysregs::read_prrr());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
     info!("revidr = {:?}", arm_sysregs::read_revidr());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("rgsr_el1 = {:?}", arm_sysregs::read_rgsr_el1());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("rmr = {:?}", arm_sysregs::read_rmr());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("rvbar = {:?}", arm_sysregs::read_rvbar());
+    info!("rgsr_el1 = {0:?}\n    {0}", arm_sysregs::read_rgsr_el1());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("rmr = {0:?}\n    {0}", arm_sysregs::read_rmr());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("rvbar = {0:?}\n    {0}", arm_sysregs::read_rvbar());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("s2pir_el2 = {:?}", arm_sysregs::read_s2pir_el2());
+    info!("s2pir_el2 = {0:?}\n    {0}", arm_sysregs::read_s2pir_el2());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("s2por_el1 = {:?}", arm_sysregs::read_s2por_el1());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("scr = {:?}", arm_sysregs::read_scr());
+    info!("s2por_el1 = {0:?}\n    {0}", arm_sysregs::read_s2por_el1());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("scr = {0:?}\n    {0}", arm_sysregs::read_scr());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
-    info!("scr_el3 = {:?}", arm_sysregs::read_scr_el3());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("sctlr = {:?}", arm_sysregs::read_sctlr());
+    info!("scr_el3 = {0:?}\n    {0}", arm_sysregs::read_scr_el3());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("sctlr = {0:?}\n    {0}", arm_sysregs::read_sctlr());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("sctlr2_el1 = {:?}", arm_sysregs::read_sctlr2_el1());
+    info!(
+        "sctlr2_el1 = {0:?}\n    {0}",
+        arm_sysregs::read_sctlr2_el1()
+    );
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("sctlr2_el2 = {:?}", arm_sysregs::read_sctlr2_el2());
+    info!(
+        "sctlr2_el2 = {0:?}\n    {0}",
+        arm_sysregs::read_sctlr2_el2()
+    );
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
-    info!("sctlr2_el3 = {:?}", arm_sysregs::read_sctlr2_el3());
+    info!(
+        "sctlr2_el3 = {0:?}\n    {0}",
+        arm_sysregs::read_sctlr2_el3()
+    );
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("sctlr_el1 = {:?}", arm_sysregs::read_sctlr_el1());
+    info!("sctlr_el1 = {0:?}\n    {0}", arm_sysregs::read_sctlr_el1());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("sctlr_el2 = {:?}", arm_sysregs::read_sctlr_el2());
+    info!("sctlr_el2 = {0:?}\n    {0}", arm_sysregs::read_sctlr_el2());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
-    info!("sctlr_el3 = {:?}", arm_sysregs::read_sctlr_el3());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("sdcr = {:?}", arm_sysregs::read_sdcr());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("sder = {:?}", arm_sysregs::read_sder());
+    info!("sctlr_el3 = {0:?}\n    {0}", arm_sysregs::read_sctlr_el3());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("sdcr = {0:?}\n    {0}", arm_sysregs::read_sdcr());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("sder = {0:?}\n    {0}", arm_sysregs::read_sder());
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el3",
+        feature = "feat-sve"
+    ))]
+    info!("smcr_el3 = {0:?}\n    {0}", arm_sysregs::read_smcr_el3());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    info!("spsr_el1 = {0:?}\n    {0}", arm_sysregs::read_spsr_el1());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+    info!("spsr_el2 = {0:?}\n    {0}", arm_sysregs::read_spsr_el2());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
-    info!("smcr_el3 = {:?}", arm_sysregs::read_smcr_el3());
+    info!("spsr_el3 = {0:?}\n    {0}", arm_sysregs::read_spsr_el3());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("spsr_el1 = {:?}", arm_sysregs::read_spsr_el1());
+    info!("sp_el1 = {0:?}\n    {0}", arm_sysregs::read_sp_el1());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("spsr_el2 = {:?}", arm_sysregs::read_spsr_el2());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
-    info!("spsr_el3 = {:?}", arm_sysregs::read_spsr_el3());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("sp_el1 = {:?}", arm_sysregs::read_sp_el1());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("sp_el2 = {:?}", arm_sysregs::read_sp_el2());
-    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
-    info!("svcr = {:?}", arm_sysregs::read_svcr());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    info!("sp_el2 = {0:?}\n    {0}", arm_sysregs::read_sp_el2());
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-sve"
+    ))]
+    info!("svcr = {0:?}\n    {0}", arm_sysregs::read_svcr());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
     info!("tcmtr = {:?}", arm_sysregs::read_tcmtr());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("tcr2_el1 = {:?}", arm_sysregs::read_tcr2_el1());
+    info!("tcr2_el1 = {0:?}\n    {0}", arm_sysregs::read_tcr2_el1());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("tcr2_el2 = {:?}", arm_sysregs::read_tcr2_el2());
+    info!("tcr2_el2 = {0:?}\n    {0}", arm_sysregs::read_tcr2_el2());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("tcr_el1 = {:?}", arm_sysregs::read_tcr_el1());
+    info!("tcr_el1 = {0:?}\n    {0}", arm_sysregs::read_tcr_el1());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("tcr_el2 = {:?}", arm_sysregs::read_tcr_el2());
+    info!("tcr_el2 = {0:?}\n    {0}", arm_sysregs::read_tcr_el2());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
-    info!("tcr_el3 = {:?}", arm_sysregs::read_tcr_el3());
+    info!("tcr_el3 = {0:?}\n    {0}", arm_sysregs::read_tcr_el3());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("tfsre0_el1 = {:?}", arm_sysregs::read_tfsre0_el1());
+    info!(
+        "tfsre0_el1 = {0:?}\n    {0}",
+        arm_sysregs::read_tfsre0_el1()
+    );
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("tfsr_el1 = {:?}", arm_sysregs::read_tfsr_el1());
+    info!("tfsr_el1 = {0:?}\n    {0}", arm_sysregs::read_tfsr_el1());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("tfsr_el2 = {:?}", arm_sysregs::read_tfsr_el2());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("tlbtr = {:?}", arm_sysregs::read_tlbtr());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("tpidrprw = {:?}", arm_sysregs::read_tpidrprw());
+    info!("tfsr_el2 = {0:?}\n    {0}", arm_sysregs::read_tfsr_el2());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("tlbtr = {0:?}\n    {0}", arm_sysregs::read_tlbtr());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("tpidrprw = {0:?}\n    {0}", arm_sysregs::read_tpidrprw());
     #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
-    info!("tpidrro_el0 = {:?}", arm_sysregs::read_tpidrro_el0());
+    info!(
+        "tpidrro_el0 = {0:?}\n    {0}",
+        arm_sysregs::read_tpidrro_el0()
+    );
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("tpidruro = {:?}", arm_sysregs::read_tpidruro());
+    info!("tpidruro = {0:?}\n    {0}", arm_sysregs::read_tpidruro());
     #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("tpidrurw = {:?}", arm_sysregs::read_tpidrurw());
+    info!("tpidrurw = {0:?}\n    {0}", arm_sysregs::read_tpidrurw());
     #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
-    info!("tpidr_el0 = {:?}", arm_sysregs::read_tpidr_el0());
+    info!("tpidr_el0 = {0:?}\n    {0}", arm_sysregs::read_tpidr_el0());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("tpidr_el1 = {:?}", arm_sysregs::read_tpidr_el1());
+    info!("tpidr_el1 = {0:?}\n    {0}", arm_sysregs::read_tpidr_el1());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("tpidr_el2 = {:?}", arm_sysregs::read_tpidr_el2());
+    info!("tpidr_el2 = {0:?}\n    {0}", arm_sysregs::read_tpidr_el2());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
-    info!("tpidr_el3 = {:?}", arm_sysregs::read_tpidr_el3());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("trfcr = {:?}", arm_sysregs::read_trfcr());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("ttbcr = {:?}", arm_sysregs::read_ttbcr());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("ttbcr2 = {:?}", arm_sysregs::read_ttbcr2());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("ttbr0 = {:?}", arm_sysregs::read_ttbr0());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("ttbr0_el1 = {:?}", arm_sysregs::read_ttbr0_el1());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("ttbr0_el2 = {:?}", arm_sysregs::read_ttbr0_el2());
+    info!("tpidr_el3 = {0:?}\n    {0}", arm_sysregs::read_tpidr_el3());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("trfcr = {0:?}\n    {0}", arm_sysregs::read_trfcr());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("ttbcr = {0:?}\n    {0}", arm_sysregs::read_ttbcr());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("ttbcr2 = {0:?}\n    {0}", arm_sysregs::read_ttbcr2());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("ttbr0 = {0:?}\n    {0}", arm_sysregs::read_ttbr0());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    info!("ttbr0_el1 = {0:?}\n    {0}", arm_sysregs::read_ttbr0_el1());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+    info!("ttbr0_el2 = {0:?}\n    {0}", arm_sysregs::read_ttbr0_el2());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
-    info!("ttbr0_el3 = {:?}", arm_sysregs::read_ttbr0_el3());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("ttbr1 = {:?}", arm_sysregs::read_ttbr1());
+    info!("ttbr0_el3 = {0:?}\n    {0}", arm_sysregs::read_ttbr0_el3());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("ttbr1 = {0:?}\n    {0}", arm_sysregs::read_ttbr1());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("ttbr1_el1 = {:?}", arm_sysregs::read_ttbr1_el1());
+    info!("ttbr1_el1 = {0:?}\n    {0}", arm_sysregs::read_ttbr1_el1());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("ttbr1_el2 = {:?}", arm_sysregs::read_ttbr1_el2());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("vbar = {:?}", arm_sysregs::read_vbar());
+    info!("ttbr1_el2 = {0:?}\n    {0}", arm_sysregs::read_ttbr1_el2());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("vbar = {0:?}\n    {0}", arm_sysregs::read_vbar());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
-    info!("vbar_el1 = {:?}", arm_sysregs::read_vbar_el1());
+    info!("vbar_el1 = {0:?}\n    {0}", arm_sysregs::read_vbar_el1());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("vbar_el2 = {:?}", arm_sysregs::read_vbar_el2());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("vdfsr = {:?}", arm_sysregs::read_vdfsr());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("vdisr = {:?}", arm_sysregs::read_vdisr());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("vdisr_el2 = {:?}", arm_sysregs::read_vdisr_el2());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("vmpidr = {:?}", arm_sysregs::read_vmpidr());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("vmpidr_el2 = {:?}", arm_sysregs::read_vmpidr_el2());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("vpidr = {:?}", arm_sysregs::read_vpidr());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("vpidr_el2 = {:?}", arm_sysregs::read_vpidr_el2());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("vsesr_el2 = {:?}", arm_sysregs::read_vsesr_el2());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("vtcr = {:?}", arm_sysregs::read_vtcr());
+    info!("vbar_el2 = {0:?}\n    {0}", arm_sysregs::read_vbar_el2());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    info!("vdfsr = {0:?}\n    {0}", arm_sysregs::read_vdfsr());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("vdisr = {0:?}\n    {0}", arm_sysregs::read_vdisr());
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el2",
+        feature = "feat-ras"
+    ))]
+    info!("vdisr_el2 = {0:?}\n    {0}", arm_sysregs::read_vdisr_el2());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("vmpidr = {0:?}\n    {0}", arm_sysregs::read_vmpidr());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("vtcr_el2 = {:?}", arm_sysregs::read_vtcr_el2());
-    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
-    info!("vttbr = {:?}", arm_sysregs::read_vttbr());
+    info!(
+        "vmpidr_el2 = {0:?}\n    {0}",
+        arm_sysregs::read_vmpidr_el2()
+    );
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    info!("vpidr = {0:?}\n    {0}", arm_sysregs::read_vpidr());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+    info!("vpidr_el2 = {0:?}\n    {0}", arm_sysregs::read_vpidr_el2());
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el2",
+        feature = "feat-ras"
+    ))]
+    info!("vsesr_el2 = {0:?}\n    {0}", arm_sysregs::read_vsesr_el2());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    info!("vtcr = {0:?}\n    {0}", arm_sysregs::read_vtcr());
     #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
-    info!("vttbr_el2 = {:?}", arm_sysregs::read_vttbr_el2());
-    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
-    info!("zcr_el3 = {:?}", arm_sysregs::read_zcr_el3());
+    info!("vtcr_el2 = {0:?}\n    {0}", arm_sysregs::read_vtcr_el2());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    info!("vttbr = {0:?}\n    {0}", arm_sysregs::read_vttbr());
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+    info!("vttbr_el2 = {0:?}\n    {0}", arm_sysregs::read_vttbr_el2());
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el3",
+        feature = "feat-sve"
+    ))]
+    info!("zcr_el3 = {0:?}\n    {0}", arm_sysregs::read_zcr_el3());
     loop {}
 }
 
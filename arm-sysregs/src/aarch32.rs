@@ -15,6 +15,10 @@ macro_rules! read_sysreg {
             #[doc = "` system register."]
             #[inline(always)]
             pub fn [< read_ $sysreg >]() -> $type {
+                #[cfg(feature = "backend")]
+                if let Some(value) = $crate::backend::read_installed($crate::SysregId::[< $sysreg:camel >]) {
+                    return value as $type;
+                }
                 let value: $type;
                 // SAFETY: The macro call site's author (see below) has determined that it is
                 // always safe to read the given `$sysreg.`
@@ -37,6 +41,10 @@ macro_rules! read_sysreg {
             $(#[$attributes])*
             #[inline(always)]
             pub unsafe fn [< read_ $sysreg >]() -> $type {
+                #[cfg(feature = "backend")]
+                if let Some(value) = $crate::backend::read_installed($crate::SysregId::[< $sysreg:camel >]) {
+                    return value as $type;
+                }
                 let value: $type;
                 // SAFETY: The caller promises that it is safe to read the given `$sysreg`.
                 unsafe {
@@ -57,6 +65,10 @@ macro_rules! read_sysreg {
             #[doc = "` system register."]
             #[inline(always)]
             pub fn [< read_ $sysreg >]() -> $bitflags_type {
+                #[cfg(feature = "backend")]
+                if let Some(value) = $crate::backend::read_installed($crate::SysregId::[< $sysreg:camel >]) {
+                    return <$bitflags_type>::from_bits_retain(value as $type);
+                }
                 let value: $type;
                 // SAFETY: The macro call site's author (see below) has determined that it is
                 // always safe to read the given `$sysreg.`
@@ -79,6 +91,10 @@ macro_rules! read_sysreg {
             $(#[$attributes])*
             #[inline(always)]
             pub unsafe fn [< read_ $sysreg >]() -> $bitflags_type {
+                #[cfg(feature = "backend")]
+                if let Some(value) = $crate::backend::read_installed($crate::SysregId::[< $sysreg:camel >]) {
+                    return <$bitflags_type>::from_bits_retain(value as $type);
+                }
                 let value: $type;
                 // SAFETY: The caller promises that it is safe to read the given `$sysreg`.
                 unsafe {
@@ -99,6 +115,10 @@ macro_rules! read_sysreg {
             #[doc = "` system register."]
             #[inline(always)]
             pub fn [< read_ $sysreg >]() -> u32 {
+                #[cfg(feature = "backend")]
+                if let Some(value) = $crate::backend::read_installed($crate::SysregId::[< $sysreg:camel >]) {
+                    return value as u32;
+                }
                 let value: u32;
                 // SAFETY: The macro call site's author (see below) has determined that it is
                 // always safe to read the given `$sysreg.`
@@ -129,6 +149,10 @@ macro_rules! read_sysreg {
             $(#[$attributes])*
             #[inline(always)]
             pub unsafe fn [< read_ $sysreg >]() -> u32 {
+                #[cfg(feature = "backend")]
+                if let Some(value) = $crate::backend::read_installed($crate::SysregId::[< $sysreg:camel >]) {
+                    return value as u32;
+                }
                 let value: u32;
                 // SAFETY: The caller promises that it is safe to read the given `$sysreg`.
                 unsafe {
@@ -157,6 +181,10 @@ macro_rules! read_sysreg {
             #[doc = "` system register."]
             #[inline(always)]
             pub fn [< read_ $sysreg >]() -> $bitflags_type {
+                #[cfg(feature = "backend")]
+                if let Some(value) = $crate::backend::read_installed($crate::SysregId::[< $sysreg:camel >]) {
+                    return <$bitflags_type>::from_bits_retain(value as u32);
+                }
                 let value: u32;
                 // SAFETY: The macro call site's author (see below) has determined that it is
                 // always safe to read the given `$sysreg.`
@@ -187,6 +215,10 @@ macro_rules! read_sysreg {
             $(#[$attributes])*
             #[inline(always)]
             pub unsafe fn [< read_ $sysreg >]() -> $bitflags_type {
+                #[cfg(feature = "backend")]
+                if let Some(value) = $crate::backend::read_installed($crate::SysregId::[< $sysreg:camel >]) {
+                    return <$bitflags_type>::from_bits_retain(value as u32);
+                }
                 let value: u32;
                 // SAFETY: The caller promises that it is safe to read the given `$sysreg`.
                 unsafe {
@@ -215,6 +247,10 @@ macro_rules! read_sysreg {
             #[doc = "` system register."]
             #[inline(always)]
             pub fn [< read_ $sysreg >]() -> u64 {
+                #[cfg(feature = "backend")]
+                if let Some(value) = $crate::backend::read_installed($crate::SysregId::[< $sysreg:camel >]) {
+                    return value as u64;
+                }
                 let value_low: u32;
                 let value_high: u32;
                 // SAFETY: The macro call site's author (see below) has determined that it is
@@ -246,6 +282,10 @@ macro_rules! read_sysreg {
             $(#[$attributes])*
             #[inline(always)]
             pub unsafe fn [< read_ $sysreg >]() -> u64 {
+                #[cfg(feature = "backend")]
+                if let Some(value) = $crate::backend::read_installed($crate::SysregId::[< $sysreg:camel >]) {
+                    return value as u64;
+                }
                 let value_low: u32;
                 let value_high: u32;
                 // SAFETY: The caller promises that it is safe to read the given `$sysreg`.
@@ -275,6 +315,10 @@ macro_rules! read_sysreg {
             #[doc = "` system register."]
             #[inline(always)]
             pub fn [< read_ $sysreg >]() -> $bitflags_type {
+                #[cfg(feature = "backend")]
+                if let Some(value) = $crate::backend::read_installed($crate::SysregId::[< $sysreg:camel >]) {
+                    return <$bitflags_type>::from_bits_retain(value as u64);
+                }
                 let value_low: u32;
                 let value_high: u32;
                 // SAFETY: The macro call site's author (see below) has determined that it is
@@ -306,6 +350,10 @@ macro_rules! read_sysreg {
             $(#[$attributes])*
             #[inline(always)]
             pub unsafe fn [< read_ $sysreg >]() -> $bitflags_type {
+                #[cfg(feature = "backend")]
+                if let Some(value) = $crate::backend::read_installed($crate::SysregId::[< $sysreg:camel >]) {
+                    return <$bitflags_type>::from_bits_retain(value as u64);
+                }
                 let value_low: u32;
                 let value_high: u32;
                 // SAFETY: The caller promises that it is safe to read the given `$sysreg`.
@@ -345,6 +393,10 @@ macro_rules! write_sysreg {
             #[doc = "` system register."]
             #[inline(always)]
             pub fn [< write_ $sysreg >](value: $type) {
+                #[cfg(feature = "backend")]
+                if $crate::backend::write_installed($crate::SysregId::[< $sysreg:camel >], value as u64) {
+                    return;
+                }
                 // SAFETY: The macro call site's author (see below) has determined that it is safe
                 // to write any value to the given `$sysreg.`
                 unsafe {
@@ -368,6 +420,10 @@ macro_rules! write_sysreg {
             $(#[$attributes])*
             #[inline(always)]
             pub unsafe fn [< write_ $sysreg >](value: $type) {
+                #[cfg(feature = "backend")]
+                if $crate::backend::write_installed($crate::SysregId::[< $sysreg:camel >], value as u64) {
+                    return;
+                }
                 // SAFETY: The caller promises that it is safe to write `value` to the given `$sysreg`.
                 unsafe {
                     core::arch::asm!(
@@ -387,6 +443,10 @@ macro_rules! write_sysreg {
             #[inline(always)]
             pub fn [< write_ $sysreg >](value: $bitflags_type) {
                 let value: $type = value.bits();
+                #[cfg(feature = "backend")]
+                if $crate::backend::write_installed($crate::SysregId::[< $sysreg:camel >], value as u64) {
+                    return;
+                }
                 // SAFETY: The macro call site's author (see below) has determined that it is safe
                 // to write any value to the given `$sysreg.`
                 unsafe {
@@ -411,6 +471,10 @@ macro_rules! write_sysreg {
             #[inline(always)]
             pub unsafe fn [< write_ $sysreg >](value: $bitflags_type) {
                 let value: $type = value.bits();
+                #[cfg(feature = "backend")]
+                if $crate::backend::write_installed($crate::SysregId::[< $sysreg:camel >], value as u64) {
+                    return;
+                }
                 // SAFETY: The caller promises that it is safe to write `value` to the given `$sysreg`.
                 unsafe {
                     core::arch::asm!(
@@ -429,6 +493,10 @@ macro_rules! write_sysreg {
             #[doc = "` system register."]
             #[inline(always)]
             pub fn [< write_ $sysreg >](value: u32) {
+                #[cfg(feature = "backend")]
+                if $crate::backend::write_installed($crate::SysregId::[< $sysreg:camel >], value as u64) {
+                    return;
+                }
                 // SAFETY: The macro call site's author (see below) has determined that it is safe
                 // to write any value to the given `$sysreg.`
                 unsafe {
@@ -460,6 +528,10 @@ macro_rules! write_sysreg {
             $(#[$attributes])*
             #[inline(always)]
             pub unsafe fn [< write_ $sysreg >](value: u32) {
+                #[cfg(feature = "backend")]
+                if $crate::backend::write_installed($crate::SysregId::[< $sysreg:camel >], value as u64) {
+                    return;
+                }
                 // SAFETY: The caller promises that it is safe to write `value` to the given `$sysreg`.
                 unsafe {
                     core::arch::asm!(
@@ -487,6 +559,10 @@ macro_rules! write_sysreg {
             #[inline(always)]
             pub fn [< write_ $sysreg >](value: $bitflags_type) {
                 let value: u32 = value.bits();
+                #[cfg(feature = "backend")]
+                if $crate::backend::write_installed($crate::SysregId::[< $sysreg:camel >], value as u64) {
+                    return;
+                }
                 // SAFETY: The macro call site's author (see below) has determined that it is safe
                 // to write any value to the given `$sysreg.`
                 unsafe {
@@ -519,6 +595,10 @@ macro_rules! write_sysreg {
             #[inline(always)]
             pub unsafe fn [< write_ $sysreg >](value: $bitflags_type) {
                 let value: u32 = value.bits();
+                #[cfg(feature = "backend")]
+                if $crate::backend::write_installed($crate::SysregId::[< $sysreg:camel >], value as u64) {
+                    return;
+                }
                 // SAFETY: The caller promises that it is safe to write `value` to the given `$sysreg`.
                 unsafe {
                     core::arch::asm!(
@@ -545,6 +625,10 @@ macro_rules! write_sysreg {
             #[doc = "` system register."]
             #[inline(always)]
             pub fn [< write_ $sysreg >](value: u64) {
+                #[cfg(feature = "backend")]
+                if $crate::backend::write_installed($crate::SysregId::[< $sysreg:camel >], value as u64) {
+                    return;
+                }
                 let value_low = value as u32;
                 let value_high = (value >> 32) as u32;
                 // SAFETY: The macro call site's author (see below) has determined that it is safe
@@ -578,6 +662,10 @@ macro_rules! write_sysreg {
             $(#[$attributes])*
             #[inline(always)]
             pub unsafe fn [< write_ $sysreg >](value: u64) {
+                #[cfg(feature = "backend")]
+                if $crate::backend::write_installed($crate::SysregId::[< $sysreg:camel >], value as u64) {
+                    return;
+                }
                 let value_low = value as u32;
                 let value_high = (value >> 32) as u32;
                 // SAFETY: The caller promises that it is safe to write `value` to the given `$sysreg`.
@@ -607,6 +695,10 @@ macro_rules! write_sysreg {
             #[inline(always)]
             pub fn [< write_ $sysreg >](value: $bitflags_type) {
                 let value: u64 = value.bits();
+                #[cfg(feature = "backend")]
+                if $crate::backend::write_installed($crate::SysregId::[< $sysreg:camel >], value as u64) {
+                    return;
+                }
                 let value_low = value as u32;
                 let value_high = (value >> 32) as u32;
                 // SAFETY: The macro call site's author (see below) has determined that it is safe
@@ -641,6 +733,10 @@ macro_rules! write_sysreg {
             #[inline(always)]
             pub unsafe fn [< write_ $sysreg >](value: $bitflags_type) {
                 let value: u64 = value.bits();
+                #[cfg(feature = "backend")]
+                if $crate::backend::write_installed($crate::SysregId::[< $sysreg:camel >], value as u64) {
+                    return;
+                }
                 let value_low = value as u32;
                 let value_high = (value >> 32) as u32;
                 // SAFETY: The caller promises that it is safe to write `value` to the given `$sysreg`.
@@ -15,6 +15,10 @@ macro_rules! read_sysreg {
             #[doc = "` system register."]
             #[inline(always)]
             pub fn [< read_ $sysreg >]() -> $type {
+                #[cfg(feature = "backend")]
+                if let Some(value) = $crate::backend::read_installed($crate::SysregId::[< $sysreg:camel >]) {
+                    return value as $type;
+                }
                 let value;
                 // SAFETY: The macro call site's author (see below) has determined that it is
                 // always safe to read the given `$sysreg.`
@@ -37,6 +41,10 @@ macro_rules! read_sysreg {
             $(#[$attributes])*
             #[inline(always)]
             pub unsafe fn [< read_ $sysreg >]() -> $type {
+                #[cfg(feature = "backend")]
+                if let Some(value) = $crate::backend::read_installed($crate::SysregId::[< $sysreg:camel >]) {
+                    return value as $type;
+                }
                 let value;
                 // SAFETY: The caller promises that it is safe to read the given `$sysreg`.
                 unsafe {
@@ -57,6 +65,10 @@ macro_rules! read_sysreg {
             #[doc = "` system register."]
             #[inline(always)]
             pub fn [< read_ $sysreg >]() -> $bitflags_type {
+                #[cfg(feature = "backend")]
+                if let Some(value) = $crate::backend::read_installed($crate::SysregId::[< $sysreg:camel >]) {
+                    return <$bitflags_type>::from_bits_retain(value as $type);
+                }
                 let value: $type;
                 // SAFETY: The macro call site's author (see below) has determined that it is
                 // always safe to read the given `$sysreg.`
@@ -79,6 +91,10 @@ macro_rules! read_sysreg {
             $(#[$attributes])*
             #[inline(always)]
             pub unsafe fn [< read_ $sysreg >]() -> $bitflags_type {
+                #[cfg(feature = "backend")]
+                if let Some(value) = $crate::backend::read_installed($crate::SysregId::[< $sysreg:camel >]) {
+                    return <$bitflags_type>::from_bits_retain(value as $type);
+                }
                 let value: $type;
                 // SAFETY: The caller promises that it is safe to read the given `$sysreg`.
                 unsafe {
@@ -121,6 +137,10 @@ macro_rules! write_sysreg {
             #[doc = "` system register."]
             #[inline(always)]
             pub fn [< write_ $sysreg >](value: $type) {
+                #[cfg(feature = "backend")]
+                if $crate::backend::write_installed($crate::SysregId::[< $sysreg:camel >], value as u64) {
+                    return;
+                }
                 // SAFETY: The macro call site's author (see below) has determined that it is safe
                 // to write any value to the given `$sysreg.`
                 unsafe {
@@ -144,6 +164,10 @@ macro_rules! write_sysreg {
             $(#[$attributes])*
             #[inline(always)]
             pub unsafe fn [< write_ $sysreg >](value: $type) {
+                #[cfg(feature = "backend")]
+                if $crate::backend::write_installed($crate::SysregId::[< $sysreg:camel >], value as u64) {
+                    return;
+                }
                 // SAFETY: The caller promises that it is safe to write `value` to the given `$sysreg`.
                 unsafe {
                     core::arch::asm!(
@@ -163,6 +187,10 @@ macro_rules! write_sysreg {
             #[inline(always)]
             pub fn [< write_ $sysreg >](value: $bitflags_type) {
                 let value: $type = value.bits();
+                #[cfg(feature = "backend")]
+                if $crate::backend::write_installed($crate::SysregId::[< $sysreg:camel >], value as u64) {
+                    return;
+                }
                 // SAFETY: The macro call site's author (see below) has determined that it is safe
                 // to write any value to the given `$sysreg.`
                 unsafe {
@@ -187,6 +215,10 @@ macro_rules! write_sysreg {
             #[inline(always)]
             pub unsafe fn [< write_ $sysreg >](value: $bitflags_type) {
                 let value: $type = value.bits();
+                #[cfg(feature = "backend")]
+                if $crate::backend::write_installed($crate::SysregId::[< $sysreg:camel >], value as u64) {
+                    return;
+                }
                 // SAFETY: The caller promises that it is safe to write `value` to the given `$sysreg`.
                 unsafe {
                     core::arch::asm!(
@@ -217,3 +249,60 @@ macro_rules! write_sysreg {
         $crate::write_sysreg!($(#[$attributes])* $sysreg : $sysreg, $type : $bitflags_type $(, $fake_sysregs)?);
     };
 }
+
+/// Reads the given system registers in a single `asm!` block, returning a tuple of their values.
+///
+/// This must be called in an `unsafe` block if any of the registers is unsafe to read. Each
+/// register needs a general-purpose register to hold its value, so only a limited number can be
+/// read at once.
+#[cfg(not(any(test, feature = "fakes", feature = "backend")))]
+#[macro_export]
+macro_rules! read_many {
+    ($($sysreg:ident),+ $(,)?) => {{
+        // Check that the accessors exist, and require an `unsafe` block if any of them does.
+        if false {
+            $( $crate::_paste::paste! { $crate::[< read_ $sysreg >]() }; )+
+        }
+        $( let $sysreg: u64; )+
+        // SAFETY: The check above ensures that the caller promises that it is safe to read any of
+        // the registers which aren't always safe to read.
+        #[allow(unused_unsafe)]
+        unsafe {
+            core::arch::asm!(
+                $( concat!("mrs {}, ", $crate::_sysreg_info!(asm_name $sysreg)), )+
+                $( out(reg) $sysreg, )+
+                options(nomem, nostack, preserves_flags),
+            );
+        }
+        ($( $crate::_sysreg_info!(from_u64 $sysreg, $sysreg), )+)
+    }};
+}
+
+/// Writes the given values to the given system registers in a single `asm!` block, e.g.
+/// `write_many!(sctlr_el1 = sctlr, tcr_el1 = tcr)`.
+///
+/// This must be called in an `unsafe` block if any of the registers is unsafe to write. Each value
+/// needs a general-purpose register, so only a limited number of registers can be written at once.
+#[cfg(not(any(test, feature = "fakes", feature = "backend")))]
+#[macro_export]
+macro_rules! write_many {
+    ($($sysreg:ident = $value:expr),+ $(,)?) => {{
+        let ($( $sysreg, )+) = ($( $value, )+);
+        // Check that the accessors exist and take values of the given types, and require an
+        // `unsafe` block if any of them does.
+        if false {
+            $( $crate::_paste::paste! { $crate::[< write_ $sysreg >]($sysreg) }; )+
+        }
+        $( let $sysreg: u64 = $crate::_sysreg_info!(to_u64 $sysreg, $sysreg); )+
+        // SAFETY: The check above ensures that the caller promises that it is safe to write the
+        // given values to any of the registers which aren't always safe to write.
+        #[allow(unused_unsafe)]
+        unsafe {
+            core::arch::asm!(
+                $( concat!("msr ", $crate::_sysreg_info!(asm_name $sysreg), ", {}"), )+
+                $( in(reg) $sysreg, )+
+                options(nostack, preserves_flags),
+            );
+        }
+    }};
+}
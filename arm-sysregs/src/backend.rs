@@ -0,0 +1,138 @@
+// SPDX-FileCopyrightText: Copyright The arm-sysregs Contributors.
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Pluggable backends for system register accesses.
+//!
+//! A [`SysregBackend`] handles untyped reads and writes of system registers identified by
+//! [`SysregId`], for example to emulate them in a VMM or forward them to a debugger. It can be used
+//! in two ways:
+//!
+//! - [`Sysregs`] wraps a backend and provides typed accessors for every system register, in the
+//!   same form as the top-level `read_*` and `write_*` functions.
+//! - [`set_backend`] installs a global backend, which the top-level `read_*` and `write_*`
+//!   functions then use instead of accessing the system registers directly. Until a backend is
+//!   installed they use inline assembly as normal.
+
+mod generated;
+
+pub use self::generated::SysregId;
+use core::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+use spin::Once;
+
+/// An implementation of system register accesses.
+pub trait SysregBackend {
+    /// Returns the value of the given system register, zero-extended to 64 bits.
+    fn read(&self, id: SysregId) -> u64;
+
+    /// Writes `value` to the given system register.
+    ///
+    /// For registers narrower than 64 bits the upper bits of `value` should be ignored.
+    fn write(&self, id: SysregId, value: u64);
+}
+
+impl<T: SysregBackend + ?Sized> SysregBackend for &T {
+    fn read(&self, id: SysregId) -> u64 {
+        (**self).read(id)
+    }
+
+    fn write(&self, id: SysregId, value: u64) {
+        (**self).write(id, value);
+    }
+}
+
+/// Typed accessors for all system registers, implemented by the wrapped [`SysregBackend`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Sysregs<B>(pub B);
+
+static BACKEND: Once<&'static (dyn SysregBackend + Sync)> = Once::new();
+
+/// Installs the global backend to be used by the top-level `read_*` and `write_*` functions instead
+/// of inline assembly.
+///
+/// This may only be called once. Returns an error if a backend has already been installed.
+pub fn set_backend(backend: &'static (dyn SysregBackend + Sync)) -> Result<(), SetBackendError> {
+    let mut installed = false;
+    BACKEND.call_once(|| {
+        installed = true;
+        backend
+    });
+    if installed {
+        Ok(())
+    } else {
+        Err(SetBackendError)
+    }
+}
+
+/// Returns the global backend, if one has been installed.
+pub fn backend() -> Option<&'static (dyn SysregBackend + Sync)> {
+    BACKEND.get().copied()
+}
+
+/// Reads the given system register from the global backend, if one has been installed.
+#[doc(hidden)]
+#[inline(always)]
+pub fn read_installed(id: SysregId) -> Option<u64> {
+    Some(backend()?.read(id))
+}
+
+/// Writes the given system register via the global backend, if one has been installed.
+///
+/// Returns whether a backend was installed.
+#[doc(hidden)]
+#[inline(always)]
+pub fn write_installed(id: SysregId, value: u64) -> bool {
+    if let Some(backend) = backend() {
+        backend.write(id, value);
+        true
+    } else {
+        false
+    }
+}
+
+/// An error returned by [`set_backend`] if a backend has already been installed.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SetBackendError;
+
+impl Display for SetBackendError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("A system register backend has already been installed")
+    }
+}
+
+impl Error for SetBackendError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Amcfgr, Tpidrurw, fake::FakeSystem};
+
+    #[test]
+    fn typed_accessors() {
+        let system = FakeSystem::new();
+        let sysregs = Sysregs(&system);
+        sysregs.write_tpidrurw(Tpidrurw::from_bits_retain(0x1234_5678));
+        assert_eq!(system.lock().unwrap().tpidrurw.bits(), 0x1234_5678);
+        assert_eq!(system.read(SysregId::Tpidrurw), 0x1234_5678);
+
+        system.write(SysregId::Amcfgr, 0xffff_ffff_0100_0012);
+        assert_eq!(
+            sysregs.read_amcfgr(),
+            Amcfgr::HDBG | Amcfgr::from_bits_retain(0x12)
+        );
+    }
+
+    #[test]
+    fn global_backend() {
+        static SYSTEM: FakeSystem = FakeSystem::new();
+
+        assert!(read_installed(SysregId::Tpidrurw).is_none());
+        set_backend(&SYSTEM).unwrap();
+        assert_eq!(set_backend(&SYSTEM), Err(SetBackendError));
+
+        assert!(write_installed(SysregId::Tpidrurw, 42));
+        assert_eq!(read_installed(SysregId::Tpidrurw), Some(42));
+    }
+}
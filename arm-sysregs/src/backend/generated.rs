@@ -0,0 +1,6475 @@
+// SPDX-FileCopyrightText: Copyright The arm-sysregs Contributors.
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated, do not edit manually.
+
+use super::{SysregBackend, Sysregs};
+#[cfg(all(feature = "el1", feature = "feat-ras"))]
+use crate::DisrEl1;
+#[cfg(all(feature = "el3", feature = "feat-mpam"))]
+use crate::Mpam3El3;
+#[cfg(all(feature = "el1", feature = "feat-mpam"))]
+use crate::MpamidrEl1;
+#[cfg(feature = "feat-pmu")]
+use crate::PmcrEl0;
+#[cfg(feature = "feat-sve")]
+use crate::Svcr;
+use crate::{
+    Amcfgr, Amcgcr, Amcntenclr0, Amcntenclr1, Amcntenset0, Amcntenset1, Amcr, Amevcntr00,
+    Amevcntr01, Amevcntr02, Amevcntr03, Amevtyper00, Amevtyper01, Amevtyper02, Amevtyper03,
+    Amevtyper10, Amevtyper11, Amevtyper12, Amevtyper13, Amevtyper14, Amevtyper15, Amevtyper16,
+    Amevtyper17, Amevtyper18, Amevtyper19, Amevtyper110, Amevtyper111, Amevtyper112, Amevtyper113,
+    Amevtyper114, Amevtyper115, Amuserenr, Cntfrq, CntfrqEl0, CntpCtl, CntpCtlEl0, CntpCval,
+    CntpCvalEl0, CntpTval, CntpTvalEl0, Cntpct, CntpctEl0, Cntpctss, CntpctssEl0, CntvCtl,
+    CntvCtlEl0, CntvCval, CntvCvalEl0, CntvTval, CntvTvalEl0, Cntvct, CntvctEl0, Cntvctss,
+    CntvctssEl0, CtrEl0, Currentel, Daif, Dbgdidr, Dbgdrar, Dbgdscrint, Dbgdtrrxint, Dbgdtrtxint,
+    Dit, Dlr, Dspsr, Fpcr, Fpmr, Fpsr, Pmccfiltr, Pmccntr, Pmceid0, Pmceid1, Pmceid2, Pmceid3,
+    Pmcntenclr, Pmcntenset, Pmcr, Pmovsr, Pmovsset, Pmselr, Pmswinc, Pmuserenr, Pmxevtyper, PorEl0,
+    TpidrEl0, TpidrroEl0, Tpidruro, Tpidrurw,
+};
+#[cfg(feature = "feat-amu")]
+use crate::{
+    AmcfgrEl0, AmcgcrEl0, Amcntenclr0El0, Amcntenclr1El0, Amcntenset0El0, Amcntenset1El0, AmcrEl0,
+    Amevcntr00El0, Amevcntr01El0, Amevcntr02El0, Amevcntr03El0, Amevcntr10El0, Amevcntr11El0,
+    Amevcntr12El0, Amevcntr13El0, Amevcntr14El0, Amevcntr15El0, Amevcntr16El0, Amevcntr17El0,
+    Amevcntr18El0, Amevcntr19El0, Amevcntr110El0, Amevcntr111El0, Amevcntr112El0, Amevcntr113El0,
+    Amevcntr114El0, Amevcntr115El0, Amevtyper00El0, Amevtyper01El0, Amevtyper02El0, Amevtyper03El0,
+    AmuserenrEl0,
+};
+#[cfg(feature = "el1")]
+use crate::{
+    ApiakeyhiEl1, ApiakeyloEl1, Ccsidr, Ccsidr2, CcsidrEl1, Clidr, ClidrEl1, CnthpCtl, CnthpCval,
+    CnthpTval, CnthpsCtl, CnthpsCval, CnthpsTval, CnthvCtl, CnthvCval, CnthvTval, CnthvsCtl,
+    CnthvsCval, CnthvsTval, Cntkctl, CntkctlEl1, CntpsCtlEl1, CntpsCvalEl1, CntpsTvalEl1,
+    Contextidr, ContextidrEl1, Cpacr, CpacrEl1, Csselr, CsselrEl1, Ctr, Dacr, Dbgauthstatus,
+    Dbgclaimclr, Dbgclaimset, Dbgdccint, Dbgdevid, Dbgdevid1, Dbgdscrext, Dbgdtrrxext, Dbgdtrtxext,
+    Dbgosdlr, Dbgoseccr, Dbgoslar, Dbgoslsr, Dbgprcr, Dbgvcr, Dfar, Dfsr, Disr, Dspsr2, ElrEl1,
+    Erridr, Errselr, Erxaddr, Erxaddr2, Erxctlr, Erxctlr2, Erxfr, Erxfr2, Erxmisc0, Erxmisc1,
+    Erxmisc2, Erxmisc3, Erxmisc4, Erxmisc5, Erxmisc6, Erxmisc7, Erxstatus, EsrEl1, FarEl1, GcrEl1,
+    GcscrEl1, IccAsgi1r, IccBpr0, IccBpr1, IccCtlr, IccDir, IccEoir0, IccEoir1, IccHppir0,
+    IccHppir1, IccIar0, IccIar1, IccIgrpen0, IccIgrpen1, IccPmr, IccRpr, IccSgi0r, IccSgi1r,
+    IccSre, IdAa64dfr0El1, IdAa64dfr1El1, IdAa64isar1El1, IdAa64isar2El1, IdAa64mmfr0El1,
+    IdAa64mmfr1El1, IdAa64mmfr2El1, IdAa64mmfr3El1, IdAa64mmfr4El1, IdAa64pfr0El1, IdAa64pfr1El1,
+    IdAa64pfr2El1, IdAa64smfr0El1, IdDfr0, IdDfr1, IdIsar0, IdIsar1, IdIsar2, IdIsar3, IdIsar4,
+    IdIsar5, IdIsar6, IdMmfr0, IdMmfr1, IdMmfr2, IdMmfr3, IdMmfr4, IdMmfr5, IdPfr0, IdPfr1, IdPfr2,
+    Ifar, Ifsr, Isr, IsrEl1, Mair0, Mair1, MairEl1, MdccintEl1, MdscrEl1, Midr, MidrEl1, Mpidr,
+    MpidrEl1, Mvbar, Nmrr, Nsacr, Par, ParEl1, PfarEl1, PirEl1, Pire0El1, Pmintenclr, Pmintenset,
+    Pmmir, PorEl1, Prrr, RgsrEl1, Rmr, Rvbar, S2porEl1, Scr, Sctlr, Sctlr2El1, SctlrEl1, Sdcr,
+    Sder, SpEl1, SpsrEl1, Tcr2El1, TcrEl1, TfsrEl1, Tfsre0El1, Tlbtr, TpidrEl1, Tpidrprw, Trfcr,
+    Ttbcr, Ttbcr2, Ttbr0, Ttbr0El1, Ttbr1, Ttbr1El1, Vbar, VbarEl1, Vdisr, Vmpidr, Vpidr,
+};
+#[cfg(feature = "el2")]
+use crate::{
+    BrbcrEl2, Cnthctl, CnthctlEl2, CnthpCtlEl2, CnthpCvalEl2, CnthpTvalEl2, CnthpsCtlEl2,
+    CnthpsCvalEl2, CnthpsTvalEl2, CnthvCtlEl2, CnthvCvalEl2, CnthvTvalEl2, CnthvsCtlEl2,
+    CnthvsCvalEl2, CnthvsTvalEl2, CntpoffEl2, Cntvoff, CntvoffEl2, ContextidrEl2, CptrEl2, ElrEl2,
+    ElrHyp, EsrEl2, FarEl2, GcscrEl2, HafgrtrEl2, Hcptr, Hcr, Hcr2, HcrEl2, HcrxEl2, Hdcr, Hdfar,
+    Hdfgrtr2El2, HdfgrtrEl2, Hdfgwtr2El2, HdfgwtrEl2, Hfgitr2El2, HfgitrEl2, Hfgrtr2El2, HfgrtrEl2,
+    Hfgwtr2El2, HfgwtrEl2, Hifar, Hmair0, Hmair1, Hpfar, HpfarEl2, Hrmr, Hsctlr, Hsr, Htcr, Htpidr,
+    Htrfcr, Httbr, Hvbar, IccHsre, MairEl2, MdcrEl2, PfarEl2, PirEl2, Pire0El2, PorEl2, S2pirEl2,
+    Sctlr2El2, SctlrEl2, SpEl2, SpsrEl2, Tcr2El2, TcrEl2, TfsrEl2, TpidrEl2, Ttbr0El2, Ttbr1El2,
+    VbarEl2, Vdfsr, VmpidrEl2, VpidrEl2, Vtcr, VtcrEl2, Vttbr, VttbrEl2,
+};
+#[cfg(feature = "el3")]
+use crate::{
+    CptrEl3, EsrEl3, IccMctlr, IccMgrpen1, IccMsre, MairEl3, MdcrEl3, PirEl3, PorEl3, ScrEl3,
+    Sctlr2El3, SctlrEl3, SpsrEl3, TcrEl3, TpidrEl3, Ttbr0El3,
+};
+#[cfg(all(feature = "el3", feature = "feat-rme"))]
+use crate::{GpccrEl3, GptbrEl3};
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+use crate::{
+    IccAp1r0El1, IccAsgi1rEl1, IccBpr0El1, IccBpr1El1, IccCtlrEl1, IccDirEl1, IccEoir0El1,
+    IccEoir1El1, IccHppir0El1, IccHppir1El1, IccIar0El1, IccIar1El1, IccIgrpen0El1, IccIgrpen1El1,
+    IccNmiar1El1, IccPmrEl1, IccRprEl1, IccSgi0rEl1, IccSgi1rEl1, IccSreEl1,
+};
+#[cfg(all(feature = "el3", feature = "feat-gic"))]
+use crate::{IccCtlrEl3, IccIgrpen1El3, IccSreEl3};
+#[cfg(all(feature = "el2", feature = "feat-gic"))]
+use crate::{IccSreEl2, IchHcrEl2, IchVmcrEl2};
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+use crate::{
+    Mpam2El2, MpamhcrEl2, Mpamvpm0El2, Mpamvpm1El2, Mpamvpm2El2, Mpamvpm3El2, Mpamvpm4El2,
+    Mpamvpm5El2, Mpamvpm6El2, Mpamvpm7El2, MpamvpmvEl2,
+};
+#[cfg(all(feature = "el3", feature = "feat-sve"))]
+use crate::{SmcrEl3, ZcrEl3};
+#[cfg(all(feature = "el2", feature = "feat-ras"))]
+use crate::{VdisrEl2, VsesrEl2};
+
+/// Identifies a system register.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum SysregId {
+    #[cfg(feature = "el1")]
+    /// The `ACTLR` system register.
+    Actlr,
+    #[cfg(feature = "el1")]
+    /// The `ACTLR2` system register.
+    Actlr2,
+    #[cfg(feature = "el1")]
+    /// The `ACTLR_EL1` system register.
+    ActlrEl1,
+    #[cfg(feature = "el2")]
+    /// The `ACTLR_EL2` system register.
+    ActlrEl2,
+    #[cfg(feature = "el1")]
+    /// The `ADFSR` system register.
+    Adfsr,
+    #[cfg(feature = "el1")]
+    /// The `AFSR0_EL1` system register.
+    Afsr0El1,
+    #[cfg(feature = "el2")]
+    /// The `AFSR0_EL2` system register.
+    Afsr0El2,
+    #[cfg(feature = "el1")]
+    /// The `AFSR1_EL1` system register.
+    Afsr1El1,
+    #[cfg(feature = "el2")]
+    /// The `AFSR1_EL2` system register.
+    Afsr1El2,
+    #[cfg(feature = "el1")]
+    /// The `AIDR` system register.
+    Aidr,
+    #[cfg(feature = "el1")]
+    /// The `AIFSR` system register.
+    Aifsr,
+    #[cfg(feature = "el1")]
+    /// The `AMAIR0` system register.
+    Amair0,
+    #[cfg(feature = "el1")]
+    /// The `AMAIR1` system register.
+    Amair1,
+    #[cfg(feature = "el1")]
+    /// The `AMAIR_EL1` system register.
+    AmairEl1,
+    #[cfg(feature = "el2")]
+    /// The `AMAIR_EL2` system register.
+    AmairEl2,
+    /// The `AMCFGR` system register.
+    Amcfgr,
+    #[cfg(feature = "feat-amu")]
+    /// The `AMCFGR_EL0` system register.
+    AmcfgrEl0,
+    /// The `AMCGCR` system register.
+    Amcgcr,
+    #[cfg(feature = "feat-amu")]
+    /// The `AMCGCR_EL0` system register.
+    AmcgcrEl0,
+    /// The `AMCNTENCLR0` system register.
+    Amcntenclr0,
+    #[cfg(feature = "feat-amu")]
+    /// The `AMCNTENCLR0_EL0` system register.
+    Amcntenclr0El0,
+    /// The `AMCNTENCLR1` system register.
+    Amcntenclr1,
+    #[cfg(feature = "feat-amu")]
+    /// The `AMCNTENCLR1_EL0` system register.
+    Amcntenclr1El0,
+    /// The `AMCNTENSET0` system register.
+    Amcntenset0,
+    #[cfg(feature = "feat-amu")]
+    /// The `AMCNTENSET0_EL0` system register.
+    Amcntenset0El0,
+    /// The `AMCNTENSET1` system register.
+    Amcntenset1,
+    #[cfg(feature = "feat-amu")]
+    /// The `AMCNTENSET1_EL0` system register.
+    Amcntenset1El0,
+    /// The `AMCR` system register.
+    Amcr,
+    #[cfg(feature = "feat-amu")]
+    /// The `AMCR_EL0` system register.
+    AmcrEl0,
+    /// The `AMEVCNTR00` system register.
+    Amevcntr00,
+    #[cfg(feature = "feat-amu")]
+    /// The `AMEVCNTR00_EL0` system register.
+    Amevcntr00El0,
+    /// The `AMEVCNTR01` system register.
+    Amevcntr01,
+    #[cfg(feature = "feat-amu")]
+    /// The `AMEVCNTR01_EL0` system register.
+    Amevcntr01El0,
+    /// The `AMEVCNTR02` system register.
+    Amevcntr02,
+    #[cfg(feature = "feat-amu")]
+    /// The `AMEVCNTR02_EL0` system register.
+    Amevcntr02El0,
+    /// The `AMEVCNTR03` system register.
+    Amevcntr03,
+    #[cfg(feature = "feat-amu")]
+    /// The `AMEVCNTR03_EL0` system register.
+    Amevcntr03El0,
+    #[cfg(feature = "feat-amu")]
+    /// The `AMEVCNTR10_EL0` system register.
+    Amevcntr10El0,
+    #[cfg(feature = "feat-amu")]
+    /// The `AMEVCNTR110_EL0` system register.
+    Amevcntr110El0,
+    #[cfg(feature = "feat-amu")]
+    /// The `AMEVCNTR111_EL0` system register.
+    Amevcntr111El0,
+    #[cfg(feature = "feat-amu")]
+    /// The `AMEVCNTR112_EL0` system register.
+    Amevcntr112El0,
+    #[cfg(feature = "feat-amu")]
+    /// The `AMEVCNTR113_EL0` system register.
+    Amevcntr113El0,
+    #[cfg(feature = "feat-amu")]
+    /// The `AMEVCNTR114_EL0` system register.
+    Amevcntr114El0,
+    #[cfg(feature = "feat-amu")]
+    /// The `AMEVCNTR115_EL0` system register.
+    Amevcntr115El0,
+    #[cfg(feature = "feat-amu")]
+    /// The `AMEVCNTR11_EL0` system register.
+    Amevcntr11El0,
+    #[cfg(feature = "feat-amu")]
+    /// The `AMEVCNTR12_EL0` system register.
+    Amevcntr12El0,
+    #[cfg(feature = "feat-amu")]
+    /// The `AMEVCNTR13_EL0` system register.
+    Amevcntr13El0,
+    #[cfg(feature = "feat-amu")]
+    /// The `AMEVCNTR14_EL0` system register.
+    Amevcntr14El0,
+    #[cfg(feature = "feat-amu")]
+    /// The `AMEVCNTR15_EL0` system register.
+    Amevcntr15El0,
+    #[cfg(feature = "feat-amu")]
+    /// The `AMEVCNTR16_EL0` system register.
+    Amevcntr16El0,
+    #[cfg(feature = "feat-amu")]
+    /// The `AMEVCNTR17_EL0` system register.
+    Amevcntr17El0,
+    #[cfg(feature = "feat-amu")]
+    /// The `AMEVCNTR18_EL0` system register.
+    Amevcntr18El0,
+    #[cfg(feature = "feat-amu")]
+    /// The `AMEVCNTR19_EL0` system register.
+    Amevcntr19El0,
+    /// The `AMEVTYPER00` system register.
+    Amevtyper00,
+    #[cfg(feature = "feat-amu")]
+    /// The `AMEVTYPER00_EL0` system register.
+    Amevtyper00El0,
+    /// The `AMEVTYPER01` system register.
+    Amevtyper01,
+    #[cfg(feature = "feat-amu")]
+    /// The `AMEVTYPER01_EL0` system register.
+    Amevtyper01El0,
+    /// The `AMEVTYPER02` system register.
+    Amevtyper02,
+    #[cfg(feature = "feat-amu")]
+    /// The `AMEVTYPER02_EL0` system register.
+    Amevtyper02El0,
+    /// The `AMEVTYPER03` system register.
+    Amevtyper03,
+    #[cfg(feature = "feat-amu")]
+    /// The `AMEVTYPER03_EL0` system register.
+    Amevtyper03El0,
+    /// The `AMEVTYPER10` system register.
+    Amevtyper10,
+    /// The `AMEVTYPER11` system register.
+    Amevtyper11,
+    /// The `AMEVTYPER110` system register.
+    Amevtyper110,
+    /// The `AMEVTYPER111` system register.
+    Amevtyper111,
+    /// The `AMEVTYPER112` system register.
+    Amevtyper112,
+    /// The `AMEVTYPER113` system register.
+    Amevtyper113,
+    /// The `AMEVTYPER114` system register.
+    Amevtyper114,
+    /// The `AMEVTYPER115` system register.
+    Amevtyper115,
+    /// The `AMEVTYPER12` system register.
+    Amevtyper12,
+    /// The `AMEVTYPER13` system register.
+    Amevtyper13,
+    /// The `AMEVTYPER14` system register.
+    Amevtyper14,
+    /// The `AMEVTYPER15` system register.
+    Amevtyper15,
+    /// The `AMEVTYPER16` system register.
+    Amevtyper16,
+    /// The `AMEVTYPER17` system register.
+    Amevtyper17,
+    /// The `AMEVTYPER18` system register.
+    Amevtyper18,
+    /// The `AMEVTYPER19` system register.
+    Amevtyper19,
+    /// The `AMUSERENR` system register.
+    Amuserenr,
+    #[cfg(feature = "feat-amu")]
+    /// The `AMUSERENR_EL0` system register.
+    AmuserenrEl0,
+    #[cfg(feature = "el1")]
+    /// The `APIAKeyHi_EL1` system register.
+    ApiakeyhiEl1,
+    #[cfg(feature = "el1")]
+    /// The `APIAKeyLo_EL1` system register.
+    ApiakeyloEl1,
+    #[cfg(feature = "el2")]
+    /// The `BRBCR_EL2` system register.
+    BrbcrEl2,
+    #[cfg(feature = "el1")]
+    /// The `CCSIDR` system register.
+    Ccsidr,
+    #[cfg(feature = "el1")]
+    /// The `CCSIDR2` system register.
+    Ccsidr2,
+    #[cfg(feature = "el1")]
+    /// The `CCSIDR_EL1` system register.
+    CcsidrEl1,
+    #[cfg(feature = "el1")]
+    /// The `CLIDR` system register.
+    Clidr,
+    #[cfg(feature = "el1")]
+    /// The `CLIDR_EL1` system register.
+    ClidrEl1,
+    /// The `CNTFRQ` system register.
+    Cntfrq,
+    /// The `CNTFRQ_EL0` system register.
+    CntfrqEl0,
+    #[cfg(feature = "el2")]
+    /// The `CNTHCTL` system register.
+    Cnthctl,
+    #[cfg(feature = "el2")]
+    /// The `CNTHCTL_EL2` system register.
+    CnthctlEl2,
+    #[cfg(feature = "el1")]
+    /// The `CNTHPS_CTL` system register.
+    CnthpsCtl,
+    #[cfg(feature = "el2")]
+    /// The `CNTHPS_CTL_EL2` system register.
+    CnthpsCtlEl2,
+    #[cfg(feature = "el1")]
+    /// The `CNTHPS_CVAL` system register.
+    CnthpsCval,
+    #[cfg(feature = "el2")]
+    /// The `CNTHPS_CVAL_EL2` system register.
+    CnthpsCvalEl2,
+    #[cfg(feature = "el1")]
+    /// The `CNTHPS_TVAL` system register.
+    CnthpsTval,
+    #[cfg(feature = "el2")]
+    /// The `CNTHPS_TVAL_EL2` system register.
+    CnthpsTvalEl2,
+    #[cfg(feature = "el1")]
+    /// The `CNTHP_CTL` system register.
+    CnthpCtl,
+    #[cfg(feature = "el2")]
+    /// The `CNTHP_CTL_EL2` system register.
+    CnthpCtlEl2,
+    #[cfg(feature = "el1")]
+    /// The `CNTHP_CVAL` system register.
+    CnthpCval,
+    #[cfg(feature = "el2")]
+    /// The `CNTHP_CVAL_EL2` system register.
+    CnthpCvalEl2,
+    #[cfg(feature = "el1")]
+    /// The `CNTHP_TVAL` system register.
+    CnthpTval,
+    #[cfg(feature = "el2")]
+    /// The `CNTHP_TVAL_EL2` system register.
+    CnthpTvalEl2,
+    #[cfg(feature = "el1")]
+    /// The `CNTHVS_CTL` system register.
+    CnthvsCtl,
+    #[cfg(feature = "el2")]
+    /// The `CNTHVS_CTL_EL2` system register.
+    CnthvsCtlEl2,
+    #[cfg(feature = "el1")]
+    /// The `CNTHVS_CVAL` system register.
+    CnthvsCval,
+    #[cfg(feature = "el2")]
+    /// The `CNTHVS_CVAL_EL2` system register.
+    CnthvsCvalEl2,
+    #[cfg(feature = "el1")]
+    /// The `CNTHVS_TVAL` system register.
+    CnthvsTval,
+    #[cfg(feature = "el2")]
+    /// The `CNTHVS_TVAL_EL2` system register.
+    CnthvsTvalEl2,
+    #[cfg(feature = "el1")]
+    /// The `CNTHV_CTL` system register.
+    CnthvCtl,
+    #[cfg(feature = "el2")]
+    /// The `CNTHV_CTL_EL2` system register.
+    CnthvCtlEl2,
+    #[cfg(feature = "el1")]
+    /// The `CNTHV_CVAL` system register.
+    CnthvCval,
+    #[cfg(feature = "el2")]
+    /// The `CNTHV_CVAL_EL2` system register.
+    CnthvCvalEl2,
+    #[cfg(feature = "el1")]
+    /// The `CNTHV_TVAL` system register.
+    CnthvTval,
+    #[cfg(feature = "el2")]
+    /// The `CNTHV_TVAL_EL2` system register.
+    CnthvTvalEl2,
+    #[cfg(feature = "el1")]
+    /// The `CNTKCTL` system register.
+    Cntkctl,
+    #[cfg(feature = "el1")]
+    /// The `CNTKCTL_EL1` system register.
+    CntkctlEl1,
+    /// The `CNTPCT` system register.
+    Cntpct,
+    /// The `CNTPCTSS` system register.
+    Cntpctss,
+    /// The `CNTPCTSS_EL0` system register.
+    CntpctssEl0,
+    /// The `CNTPCT_EL0` system register.
+    CntpctEl0,
+    #[cfg(feature = "el2")]
+    /// The `CNTPOFF_EL2` system register.
+    CntpoffEl2,
+    #[cfg(feature = "el1")]
+    /// The `CNTPS_CTL_EL1` system register.
+    CntpsCtlEl1,
+    #[cfg(feature = "el1")]
+    /// The `CNTPS_CVAL_EL1` system register.
+    CntpsCvalEl1,
+    #[cfg(feature = "el1")]
+    /// The `CNTPS_TVAL_EL1` system register.
+    CntpsTvalEl1,
+    /// The `CNTP_CTL` system register.
+    CntpCtl,
+    /// The `CNTP_CTL_EL0` system register.
+    CntpCtlEl0,
+    /// The `CNTP_CVAL` system register.
+    CntpCval,
+    /// The `CNTP_CVAL_EL0` system register.
+    CntpCvalEl0,
+    /// The `CNTP_TVAL` system register.
+    CntpTval,
+    /// The `CNTP_TVAL_EL0` system register.
+    CntpTvalEl0,
+    /// The `CNTVCT` system register.
+    Cntvct,
+    /// The `CNTVCTSS` system register.
+    Cntvctss,
+    /// The `CNTVCTSS_EL0` system register.
+    CntvctssEl0,
+    /// The `CNTVCT_EL0` system register.
+    CntvctEl0,
+    #[cfg(feature = "el2")]
+    /// The `CNTVOFF` system register.
+    Cntvoff,
+    #[cfg(feature = "el2")]
+    /// The `CNTVOFF_EL2` system register.
+    CntvoffEl2,
+    /// The `CNTV_CTL` system register.
+    CntvCtl,
+    /// The `CNTV_CTL_EL0` system register.
+    CntvCtlEl0,
+    /// The `CNTV_CVAL` system register.
+    CntvCval,
+    /// The `CNTV_CVAL_EL0` system register.
+    CntvCvalEl0,
+    /// The `CNTV_TVAL` system register.
+    CntvTval,
+    /// The `CNTV_TVAL_EL0` system register.
+    CntvTvalEl0,
+    #[cfg(feature = "el1")]
+    /// The `CONTEXTIDR` system register.
+    Contextidr,
+    #[cfg(feature = "el1")]
+    /// The `CONTEXTIDR_EL1` system register.
+    ContextidrEl1,
+    #[cfg(feature = "el2")]
+    /// The `CONTEXTIDR_EL2` system register.
+    ContextidrEl2,
+    #[cfg(feature = "el1")]
+    /// The `CPACR` system register.
+    Cpacr,
+    #[cfg(feature = "el1")]
+    /// The `CPACR_EL1` system register.
+    CpacrEl1,
+    #[cfg(feature = "el2")]
+    /// The `CPTR_EL2` system register.
+    CptrEl2,
+    #[cfg(feature = "el3")]
+    /// The `CPTR_EL3` system register.
+    CptrEl3,
+    #[cfg(feature = "el1")]
+    /// The `CSSELR` system register.
+    Csselr,
+    #[cfg(feature = "el1")]
+    /// The `CSSELR_EL1` system register.
+    CsselrEl1,
+    #[cfg(feature = "el1")]
+    /// The `CTR` system register.
+    Ctr,
+    /// The `CTR_EL0` system register.
+    CtrEl0,
+    /// The `CurrentEL` system register.
+    Currentel,
+    #[cfg(feature = "el1")]
+    /// The `DACR` system register.
+    Dacr,
+    /// The `DAIF` system register.
+    Daif,
+    #[cfg(feature = "el1")]
+    /// The `DBGAUTHSTATUS` system register.
+    Dbgauthstatus,
+    #[cfg(feature = "el1")]
+    /// The `DBGCLAIMCLR` system register.
+    Dbgclaimclr,
+    #[cfg(feature = "el1")]
+    /// The `DBGCLAIMSET` system register.
+    Dbgclaimset,
+    #[cfg(feature = "el1")]
+    /// The `DBGDCCINT` system register.
+    Dbgdccint,
+    #[cfg(feature = "el1")]
+    /// The `DBGDEVID` system register.
+    Dbgdevid,
+    #[cfg(feature = "el1")]
+    /// The `DBGDEVID1` system register.
+    Dbgdevid1,
+    #[cfg(feature = "el1")]
+    /// The `DBGDEVID2` system register.
+    Dbgdevid2,
+    /// The `DBGDIDR` system register.
+    Dbgdidr,
+    /// The `DBGDRAR` system register.
+    Dbgdrar,
+    /// The `DBGDSAR` system register.
+    Dbgdsar,
+    #[cfg(feature = "el1")]
+    /// The `DBGDSCRext` system register.
+    Dbgdscrext,
+    /// The `DBGDSCRint` system register.
+    Dbgdscrint,
+    #[cfg(feature = "el1")]
+    /// The `DBGDTRRXext` system register.
+    Dbgdtrrxext,
+    /// The `DBGDTRRXint` system register.
+    Dbgdtrrxint,
+    #[cfg(feature = "el1")]
+    /// The `DBGDTRTXext` system register.
+    Dbgdtrtxext,
+    /// The `DBGDTRTXint` system register.
+    Dbgdtrtxint,
+    #[cfg(feature = "el1")]
+    /// The `DBGOSDLR` system register.
+    Dbgosdlr,
+    #[cfg(feature = "el1")]
+    /// The `DBGOSECCR` system register.
+    Dbgoseccr,
+    #[cfg(feature = "el1")]
+    /// The `DBGOSLAR` system register.
+    Dbgoslar,
+    #[cfg(feature = "el1")]
+    /// The `DBGOSLSR` system register.
+    Dbgoslsr,
+    #[cfg(feature = "el1")]
+    /// The `DBGPRCR` system register.
+    Dbgprcr,
+    #[cfg(feature = "el1")]
+    /// The `DBGVCR` system register.
+    Dbgvcr,
+    #[cfg(feature = "el1")]
+    /// The `DBGWFAR` system register.
+    Dbgwfar,
+    #[cfg(feature = "el1")]
+    /// The `DFAR` system register.
+    Dfar,
+    #[cfg(feature = "el1")]
+    /// The `DFSR` system register.
+    Dfsr,
+    #[cfg(feature = "el1")]
+    /// The `DISR` system register.
+    Disr,
+    #[cfg(all(feature = "el1", feature = "feat-ras"))]
+    /// The `DISR_EL1` system register.
+    DisrEl1,
+    /// The `DIT` system register.
+    Dit,
+    /// The `DLR` system register.
+    Dlr,
+    /// The `DSPSR` system register.
+    Dspsr,
+    #[cfg(feature = "el1")]
+    /// The `DSPSR2` system register.
+    Dspsr2,
+    #[cfg(feature = "el1")]
+    /// The `ELR_EL1` system register.
+    ElrEl1,
+    #[cfg(feature = "el2")]
+    /// The `ELR_EL2` system register.
+    ElrEl2,
+    #[cfg(feature = "el2")]
+    /// The `ELR_hyp` system register.
+    ElrHyp,
+    #[cfg(feature = "el1")]
+    /// The `ERRIDR` system register.
+    Erridr,
+    #[cfg(feature = "el1")]
+    /// The `ERRSELR` system register.
+    Errselr,
+    #[cfg(feature = "el1")]
+    /// The `ERXADDR` system register.
+    Erxaddr,
+    #[cfg(feature = "el1")]
+    /// The `ERXADDR2` system register.
+    Erxaddr2,
+    #[cfg(feature = "el1")]
+    /// The `ERXCTLR` system register.
+    Erxctlr,
+    #[cfg(feature = "el1")]
+    /// The `ERXCTLR2` system register.
+    Erxctlr2,
+    #[cfg(feature = "el1")]
+    /// The `ERXFR` system register.
+    Erxfr,
+    #[cfg(feature = "el1")]
+    /// The `ERXFR2` system register.
+    Erxfr2,
+    #[cfg(feature = "el1")]
+    /// The `ERXMISC0` system register.
+    Erxmisc0,
+    #[cfg(feature = "el1")]
+    /// The `ERXMISC1` system register.
+    Erxmisc1,
+    #[cfg(feature = "el1")]
+    /// The `ERXMISC2` system register.
+    Erxmisc2,
+    #[cfg(feature = "el1")]
+    /// The `ERXMISC3` system register.
+    Erxmisc3,
+    #[cfg(feature = "el1")]
+    /// The `ERXMISC4` system register.
+    Erxmisc4,
+    #[cfg(feature = "el1")]
+    /// The `ERXMISC5` system register.
+    Erxmisc5,
+    #[cfg(feature = "el1")]
+    /// The `ERXMISC6` system register.
+    Erxmisc6,
+    #[cfg(feature = "el1")]
+    /// The `ERXMISC7` system register.
+    Erxmisc7,
+    #[cfg(feature = "el1")]
+    /// The `ERXSTATUS` system register.
+    Erxstatus,
+    #[cfg(feature = "el1")]
+    /// The `ESR_EL1` system register.
+    EsrEl1,
+    #[cfg(feature = "el2")]
+    /// The `ESR_EL2` system register.
+    EsrEl2,
+    #[cfg(feature = "el3")]
+    /// The `ESR_EL3` system register.
+    EsrEl3,
+    #[cfg(feature = "el1")]
+    /// The `FAR_EL1` system register.
+    FarEl1,
+    #[cfg(feature = "el2")]
+    /// The `FAR_EL2` system register.
+    FarEl2,
+    #[cfg(feature = "el1")]
+    /// The `FCSEIDR` system register.
+    Fcseidr,
+    /// The `FPCR` system register.
+    Fpcr,
+    /// The `FPMR` system register.
+    Fpmr,
+    /// The `FPSR` system register.
+    Fpsr,
+    #[cfg(feature = "el1")]
+    /// The `GCR_EL1` system register.
+    GcrEl1,
+    #[cfg(feature = "el1")]
+    /// The `GCSCR_EL1` system register.
+    GcscrEl1,
+    #[cfg(feature = "el2")]
+    /// The `GCSCR_EL2` system register.
+    GcscrEl2,
+    #[cfg(all(feature = "el3", feature = "feat-rme"))]
+    /// The `GPCCR_EL3` system register.
+    GpccrEl3,
+    #[cfg(all(feature = "el3", feature = "feat-rme"))]
+    /// The `GPTBR_EL3` system register.
+    GptbrEl3,
+    #[cfg(feature = "el2")]
+    /// The `HACR` system register.
+    Hacr,
+    #[cfg(feature = "el2")]
+    /// The `HACR_EL2` system register.
+    HacrEl2,
+    #[cfg(feature = "el2")]
+    /// The `HACTLR` system register.
+    Hactlr,
+    #[cfg(feature = "el2")]
+    /// The `HACTLR2` system register.
+    Hactlr2,
+    #[cfg(feature = "el2")]
+    /// The `HADFSR` system register.
+    Hadfsr,
+    #[cfg(feature = "el2")]
+    /// The `HAFGRTR_EL2` system register.
+    HafgrtrEl2,
+    #[cfg(feature = "el2")]
+    /// The `HAIFSR` system register.
+    Haifsr,
+    #[cfg(feature = "el2")]
+    /// The `HAMAIR0` system register.
+    Hamair0,
+    #[cfg(feature = "el2")]
+    /// The `HAMAIR1` system register.
+    Hamair1,
+    #[cfg(feature = "el2")]
+    /// The `HCPTR` system register.
+    Hcptr,
+    #[cfg(feature = "el2")]
+    /// The `HCR` system register.
+    Hcr,
+    #[cfg(feature = "el2")]
+    /// The `HCR2` system register.
+    Hcr2,
+    #[cfg(feature = "el2")]
+    /// The `HCRX_EL2` system register.
+    HcrxEl2,
+    #[cfg(feature = "el2")]
+    /// The `HCR_EL2` system register.
+    HcrEl2,
+    #[cfg(feature = "el2")]
+    /// The `HDCR` system register.
+    Hdcr,
+    #[cfg(feature = "el2")]
+    /// The `HDFAR` system register.
+    Hdfar,
+    #[cfg(feature = "el2")]
+    /// The `HDFGRTR2_EL2` system register.
+    Hdfgrtr2El2,
+    #[cfg(feature = "el2")]
+    /// The `HDFGRTR_EL2` system register.
+    HdfgrtrEl2,
+    #[cfg(feature = "el2")]
+    /// The `HDFGWTR2_EL2` system register.
+    Hdfgwtr2El2,
+    #[cfg(feature = "el2")]
+    /// The `HDFGWTR_EL2` system register.
+    HdfgwtrEl2,
+    #[cfg(feature = "el2")]
+    /// The `HFGITR2_EL2` system register.
+    Hfgitr2El2,
+    #[cfg(feature = "el2")]
+    /// The `HFGITR_EL2` system register.
+    HfgitrEl2,
+    #[cfg(feature = "el2")]
+    /// The `HFGRTR2_EL2` system register.
+    Hfgrtr2El2,
+    #[cfg(feature = "el2")]
+    /// The `HFGRTR_EL2` system register.
+    HfgrtrEl2,
+    #[cfg(feature = "el2")]
+    /// The `HFGWTR2_EL2` system register.
+    Hfgwtr2El2,
+    #[cfg(feature = "el2")]
+    /// The `HFGWTR_EL2` system register.
+    HfgwtrEl2,
+    #[cfg(feature = "el2")]
+    /// The `HIFAR` system register.
+    Hifar,
+    #[cfg(feature = "el2")]
+    /// The `HMAIR0` system register.
+    Hmair0,
+    #[cfg(feature = "el2")]
+    /// The `HMAIR1` system register.
+    Hmair1,
+    #[cfg(feature = "el2")]
+    /// The `HPFAR` system register.
+    Hpfar,
+    #[cfg(feature = "el2")]
+    /// The `HPFAR_EL2` system register.
+    HpfarEl2,
+    #[cfg(feature = "el2")]
+    /// The `HRMR` system register.
+    Hrmr,
+    #[cfg(feature = "el2")]
+    /// The `HSCTLR` system register.
+    Hsctlr,
+    #[cfg(feature = "el2")]
+    /// The `HSR` system register.
+    Hsr,
+    #[cfg(feature = "el2")]
+    /// The `HSTR` system register.
+    Hstr,
+    #[cfg(feature = "el2")]
+    /// The `HSTR_EL2` system register.
+    HstrEl2,
+    #[cfg(feature = "el2")]
+    /// The `HTCR` system register.
+    Htcr,
+    #[cfg(feature = "el2")]
+    /// The `HTPIDR` system register.
+    Htpidr,
+    #[cfg(feature = "el2")]
+    /// The `HTRFCR` system register.
+    Htrfcr,
+    #[cfg(feature = "el2")]
+    /// The `HTTBR` system register.
+    Httbr,
+    #[cfg(feature = "el2")]
+    /// The `HVBAR` system register.
+    Hvbar,
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// The `ICC_AP0R0_EL1` system register.
+    IccAp0r0El1,
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// The `ICC_AP0R1_EL1` system register.
+    IccAp0r1El1,
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// The `ICC_AP0R2_EL1` system register.
+    IccAp0r2El1,
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// The `ICC_AP0R3_EL1` system register.
+    IccAp0r3El1,
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// The `ICC_AP1R0_EL1` system register.
+    IccAp1r0El1,
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// The `ICC_AP1R1_EL1` system register.
+    IccAp1r1El1,
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// The `ICC_AP1R2_EL1` system register.
+    IccAp1r2El1,
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// The `ICC_AP1R3_EL1` system register.
+    IccAp1r3El1,
+    #[cfg(feature = "el1")]
+    /// The `ICC_ASGI1R` system register.
+    IccAsgi1r,
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// The `ICC_ASGI1R_EL1` system register.
+    IccAsgi1rEl1,
+    #[cfg(feature = "el1")]
+    /// The `ICC_BPR0` system register.
+    IccBpr0,
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// The `ICC_BPR0_EL1` system register.
+    IccBpr0El1,
+    #[cfg(feature = "el1")]
+    /// The `ICC_BPR1` system register.
+    IccBpr1,
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// The `ICC_BPR1_EL1` system register.
+    IccBpr1El1,
+    #[cfg(feature = "el1")]
+    /// The `ICC_CTLR` system register.
+    IccCtlr,
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// The `ICC_CTLR_EL1` system register.
+    IccCtlrEl1,
+    #[cfg(all(feature = "el3", feature = "feat-gic"))]
+    /// The `ICC_CTLR_EL3` system register.
+    IccCtlrEl3,
+    #[cfg(feature = "el1")]
+    /// The `ICC_DIR` system register.
+    IccDir,
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// The `ICC_DIR_EL1` system register.
+    IccDirEl1,
+    #[cfg(feature = "el1")]
+    /// The `ICC_EOIR0` system register.
+    IccEoir0,
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// The `ICC_EOIR0_EL1` system register.
+    IccEoir0El1,
+    #[cfg(feature = "el1")]
+    /// The `ICC_EOIR1` system register.
+    IccEoir1,
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// The `ICC_EOIR1_EL1` system register.
+    IccEoir1El1,
+    #[cfg(feature = "el1")]
+    /// The `ICC_HPPIR0` system register.
+    IccHppir0,
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// The `ICC_HPPIR0_EL1` system register.
+    IccHppir0El1,
+    #[cfg(feature = "el1")]
+    /// The `ICC_HPPIR1` system register.
+    IccHppir1,
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// The `ICC_HPPIR1_EL1` system register.
+    IccHppir1El1,
+    #[cfg(feature = "el2")]
+    /// The `ICC_HSRE` system register.
+    IccHsre,
+    #[cfg(feature = "el1")]
+    /// The `ICC_IAR0` system register.
+    IccIar0,
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// The `ICC_IAR0_EL1` system register.
+    IccIar0El1,
+    #[cfg(feature = "el1")]
+    /// The `ICC_IAR1` system register.
+    IccIar1,
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// The `ICC_IAR1_EL1` system register.
+    IccIar1El1,
+    #[cfg(feature = "el1")]
+    /// The `ICC_IGRPEN0` system register.
+    IccIgrpen0,
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// The `ICC_IGRPEN0_EL1` system register.
+    IccIgrpen0El1,
+    #[cfg(feature = "el1")]
+    /// The `ICC_IGRPEN1` system register.
+    IccIgrpen1,
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// The `ICC_IGRPEN1_EL1` system register.
+    IccIgrpen1El1,
+    #[cfg(all(feature = "el3", feature = "feat-gic"))]
+    /// The `ICC_IGRPEN1_EL3` system register.
+    IccIgrpen1El3,
+    #[cfg(feature = "el3")]
+    /// The `ICC_MCTLR` system register.
+    IccMctlr,
+    #[cfg(feature = "el3")]
+    /// The `ICC_MGRPEN1` system register.
+    IccMgrpen1,
+    #[cfg(feature = "el3")]
+    /// The `ICC_MSRE` system register.
+    IccMsre,
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// The `ICC_NMIAR1_EL1` system register.
+    IccNmiar1El1,
+    #[cfg(feature = "el1")]
+    /// The `ICC_PMR` system register.
+    IccPmr,
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// The `ICC_PMR_EL1` system register.
+    IccPmrEl1,
+    #[cfg(feature = "el1")]
+    /// The `ICC_RPR` system register.
+    IccRpr,
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// The `ICC_RPR_EL1` system register.
+    IccRprEl1,
+    #[cfg(feature = "el1")]
+    /// The `ICC_SGI0R` system register.
+    IccSgi0r,
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// The `ICC_SGI0R_EL1` system register.
+    IccSgi0rEl1,
+    #[cfg(feature = "el1")]
+    /// The `ICC_SGI1R` system register.
+    IccSgi1r,
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// The `ICC_SGI1R_EL1` system register.
+    IccSgi1rEl1,
+    #[cfg(feature = "el1")]
+    /// The `ICC_SRE` system register.
+    IccSre,
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// The `ICC_SRE_EL1` system register.
+    IccSreEl1,
+    #[cfg(all(feature = "el2", feature = "feat-gic"))]
+    /// The `ICC_SRE_EL2` system register.
+    IccSreEl2,
+    #[cfg(all(feature = "el3", feature = "feat-gic"))]
+    /// The `ICC_SRE_EL3` system register.
+    IccSreEl3,
+    #[cfg(all(feature = "el2", feature = "feat-gic"))]
+    /// The `ICH_HCR_EL2` system register.
+    IchHcrEl2,
+    #[cfg(all(feature = "el2", feature = "feat-gic"))]
+    /// The `ICH_VMCR_EL2` system register.
+    IchVmcrEl2,
+    #[cfg(feature = "el1")]
+    /// The `ID_AA64DFR0_EL1` system register.
+    IdAa64dfr0El1,
+    #[cfg(feature = "el1")]
+    /// The `ID_AA64DFR1_EL1` system register.
+    IdAa64dfr1El1,
+    #[cfg(feature = "el1")]
+    /// The `ID_AA64ISAR1_EL1` system register.
+    IdAa64isar1El1,
+    #[cfg(feature = "el1")]
+    /// The `ID_AA64ISAR2_EL1` system register.
+    IdAa64isar2El1,
+    #[cfg(feature = "el1")]
+    /// The `ID_AA64MMFR0_EL1` system register.
+    IdAa64mmfr0El1,
+    #[cfg(feature = "el1")]
+    /// The `ID_AA64MMFR1_EL1` system register.
+    IdAa64mmfr1El1,
+    #[cfg(feature = "el1")]
+    /// The `ID_AA64MMFR2_EL1` system register.
+    IdAa64mmfr2El1,
+    #[cfg(feature = "el1")]
+    /// The `ID_AA64MMFR3_EL1` system register.
+    IdAa64mmfr3El1,
+    #[cfg(feature = "el1")]
+    /// The `ID_AA64MMFR4_EL1` system register.
+    IdAa64mmfr4El1,
+    #[cfg(feature = "el1")]
+    /// The `ID_AA64PFR0_EL1` system register.
+    IdAa64pfr0El1,
+    #[cfg(feature = "el1")]
+    /// The `ID_AA64PFR1_EL1` system register.
+    IdAa64pfr1El1,
+    #[cfg(feature = "el1")]
+    /// The `ID_AA64PFR2_EL1` system register.
+    IdAa64pfr2El1,
+    #[cfg(feature = "el1")]
+    /// The `ID_AA64SMFR0_EL1` system register.
+    IdAa64smfr0El1,
+    #[cfg(feature = "el1")]
+    /// The `ID_AFR0` system register.
+    IdAfr0,
+    #[cfg(feature = "el1")]
+    /// The `ID_DFR0` system register.
+    IdDfr0,
+    #[cfg(feature = "el1")]
+    /// The `ID_DFR1` system register.
+    IdDfr1,
+    #[cfg(feature = "el1")]
+    /// The `ID_ISAR0` system register.
+    IdIsar0,
+    #[cfg(feature = "el1")]
+    /// The `ID_ISAR1` system register.
+    IdIsar1,
+    #[cfg(feature = "el1")]
+    /// The `ID_ISAR2` system register.
+    IdIsar2,
+    #[cfg(feature = "el1")]
+    /// The `ID_ISAR3` system register.
+    IdIsar3,
+    #[cfg(feature = "el1")]
+    /// The `ID_ISAR4` system register.
+    IdIsar4,
+    #[cfg(feature = "el1")]
+    /// The `ID_ISAR5` system register.
+    IdIsar5,
+    #[cfg(feature = "el1")]
+    /// The `ID_ISAR6` system register.
+    IdIsar6,
+    #[cfg(feature = "el1")]
+    /// The `ID_MMFR0` system register.
+    IdMmfr0,
+    #[cfg(feature = "el1")]
+    /// The `ID_MMFR1` system register.
+    IdMmfr1,
+    #[cfg(feature = "el1")]
+    /// The `ID_MMFR2` system register.
+    IdMmfr2,
+    #[cfg(feature = "el1")]
+    /// The `ID_MMFR3` system register.
+    IdMmfr3,
+    #[cfg(feature = "el1")]
+    /// The `ID_MMFR4` system register.
+    IdMmfr4,
+    #[cfg(feature = "el1")]
+    /// The `ID_MMFR5` system register.
+    IdMmfr5,
+    #[cfg(feature = "el1")]
+    /// The `ID_PFR0` system register.
+    IdPfr0,
+    #[cfg(feature = "el1")]
+    /// The `ID_PFR1` system register.
+    IdPfr1,
+    #[cfg(feature = "el1")]
+    /// The `ID_PFR2` system register.
+    IdPfr2,
+    #[cfg(feature = "el1")]
+    /// The `IFAR` system register.
+    Ifar,
+    #[cfg(feature = "el1")]
+    /// The `IFSR` system register.
+    Ifsr,
+    #[cfg(feature = "el1")]
+    /// The `ISR` system register.
+    Isr,
+    #[cfg(feature = "el1")]
+    /// The `ISR_EL1` system register.
+    IsrEl1,
+    #[cfg(feature = "el1")]
+    /// The `JIDR` system register.
+    Jidr,
+    #[cfg(feature = "el1")]
+    /// The `JMCR` system register.
+    Jmcr,
+    #[cfg(feature = "el1")]
+    /// The `JOSCR` system register.
+    Joscr,
+    #[cfg(feature = "el1")]
+    /// The `MAIR0` system register.
+    Mair0,
+    #[cfg(feature = "el1")]
+    /// The `MAIR1` system register.
+    Mair1,
+    #[cfg(feature = "el1")]
+    /// The `MAIR_EL1` system register.
+    MairEl1,
+    #[cfg(feature = "el2")]
+    /// The `MAIR_EL2` system register.
+    MairEl2,
+    #[cfg(feature = "el3")]
+    /// The `MAIR_EL3` system register.
+    MairEl3,
+    #[cfg(feature = "el1")]
+    /// The `MDCCINT_EL1` system register.
+    MdccintEl1,
+    #[cfg(feature = "el2")]
+    /// The `MDCR_EL2` system register.
+    MdcrEl2,
+    #[cfg(feature = "el3")]
+    /// The `MDCR_EL3` system register.
+    MdcrEl3,
+    #[cfg(feature = "el1")]
+    /// The `MDSCR_EL1` system register.
+    MdscrEl1,
+    #[cfg(feature = "el1")]
+    /// The `MIDR` system register.
+    Midr,
+    #[cfg(feature = "el1")]
+    /// The `MIDR_EL1` system register.
+    MidrEl1,
+    #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+    /// The `MPAM2_EL2` system register.
+    Mpam2El2,
+    #[cfg(all(feature = "el3", feature = "feat-mpam"))]
+    /// The `MPAM3_EL3` system register.
+    Mpam3El3,
+    #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+    /// The `MPAMHCR_EL2` system register.
+    MpamhcrEl2,
+    #[cfg(all(feature = "el1", feature = "feat-mpam"))]
+    /// The `MPAMIDR_EL1` system register.
+    MpamidrEl1,
+    #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+    /// The `MPAMVPM0_EL2` system register.
+    Mpamvpm0El2,
+    #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+    /// The `MPAMVPM1_EL2` system register.
+    Mpamvpm1El2,
+    #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+    /// The `MPAMVPM2_EL2` system register.
+    Mpamvpm2El2,
+    #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+    /// The `MPAMVPM3_EL2` system register.
+    Mpamvpm3El2,
+    #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+    /// The `MPAMVPM4_EL2` system register.
+    Mpamvpm4El2,
+    #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+    /// The `MPAMVPM5_EL2` system register.
+    Mpamvpm5El2,
+    #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+    /// The `MPAMVPM6_EL2` system register.
+    Mpamvpm6El2,
+    #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+    /// The `MPAMVPM7_EL2` system register.
+    Mpamvpm7El2,
+    #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+    /// The `MPAMVPMV_EL2` system register.
+    MpamvpmvEl2,
+    #[cfg(feature = "el1")]
+    /// The `MPIDR` system register.
+    Mpidr,
+    #[cfg(feature = "el1")]
+    /// The `MPIDR_EL1` system register.
+    MpidrEl1,
+    #[cfg(feature = "el1")]
+    /// The `MVBAR` system register.
+    Mvbar,
+    #[cfg(feature = "el1")]
+    /// The `NMRR` system register.
+    Nmrr,
+    #[cfg(feature = "el1")]
+    /// The `NSACR` system register.
+    Nsacr,
+    #[cfg(feature = "el1")]
+    /// The `PAR` system register.
+    Par,
+    #[cfg(feature = "el1")]
+    /// The `PAR_EL1` system register.
+    ParEl1,
+    #[cfg(feature = "el1")]
+    /// The `PFAR_EL1` system register.
+    PfarEl1,
+    #[cfg(feature = "el2")]
+    /// The `PFAR_EL2` system register.
+    PfarEl2,
+    #[cfg(feature = "el1")]
+    /// The `PIRE0_EL1` system register.
+    Pire0El1,
+    #[cfg(feature = "el2")]
+    /// The `PIRE0_EL2` system register.
+    Pire0El2,
+    #[cfg(feature = "el1")]
+    /// The `PIR_EL1` system register.
+    PirEl1,
+    #[cfg(feature = "el2")]
+    /// The `PIR_EL2` system register.
+    PirEl2,
+    #[cfg(feature = "el3")]
+    /// The `PIR_EL3` system register.
+    PirEl3,
+    /// The `PMCCFILTR` system register.
+    Pmccfiltr,
+    /// The `PMCCNTR` system register.
+    Pmccntr,
+    /// The `PMCEID0` system register.
+    Pmceid0,
+    /// The `PMCEID1` system register.
+    Pmceid1,
+    /// The `PMCEID2` system register.
+    Pmceid2,
+    /// The `PMCEID3` system register.
+    Pmceid3,
+    /// The `PMCNTENCLR` system register.
+    Pmcntenclr,
+    /// The `PMCNTENSET` system register.
+    Pmcntenset,
+    /// The `PMCR` system register.
+    Pmcr,
+    #[cfg(feature = "feat-pmu")]
+    /// The `PMCR_EL0` system register.
+    PmcrEl0,
+    #[cfg(feature = "el1")]
+    /// The `PMINTENCLR` system register.
+    Pmintenclr,
+    #[cfg(feature = "el1")]
+    /// The `PMINTENSET` system register.
+    Pmintenset,
+    #[cfg(feature = "el1")]
+    /// The `PMMIR` system register.
+    Pmmir,
+    /// The `PMOVSR` system register.
+    Pmovsr,
+    /// The `PMOVSSET` system register.
+    Pmovsset,
+    /// The `PMSELR` system register.
+    Pmselr,
+    /// The `PMSWINC` system register.
+    Pmswinc,
+    /// The `PMUSERENR` system register.
+    Pmuserenr,
+    /// The `PMXEVTYPER` system register.
+    Pmxevtyper,
+    /// The `POR_EL0` system register.
+    PorEl0,
+    #[cfg(feature = "el1")]
+    /// The `POR_EL1` system register.
+    PorEl1,
+    #[cfg(feature = "el2")]
+    /// The `POR_EL2` system register.
+    PorEl2,
+    #[cfg(feature = "el3")]
+    /// The `POR_EL3` system register.
+    PorEl3,
+    #[cfg(feature = "el1")]
+    /// The `PRRR` system register.
+    Prrr,
+    #[cfg(feature = "el1")]
+    /// The `REVIDR` system register.
+    Revidr,
+    #[cfg(feature = "el1")]
+    /// The `RGSR_EL1` system register.
+    RgsrEl1,
+    #[cfg(feature = "el1")]
+    /// The `RMR` system register.
+    Rmr,
+    #[cfg(feature = "el1")]
+    /// The `RVBAR` system register.
+    Rvbar,
+    #[cfg(feature = "el2")]
+    /// The `S2PIR_EL2` system register.
+    S2pirEl2,
+    #[cfg(feature = "el1")]
+    /// The `S2POR_EL1` system register.
+    S2porEl1,
+    #[cfg(feature = "el1")]
+    /// The `SCR` system register.
+    Scr,
+    #[cfg(feature = "el3")]
+    /// The `SCR_EL3` system register.
+    ScrEl3,
+    #[cfg(feature = "el1")]
+    /// The `SCTLR` system register.
+    Sctlr,
+    #[cfg(feature = "el1")]
+    /// The `SCTLR2_EL1` system register.
+    Sctlr2El1,
+    #[cfg(feature = "el2")]
+    /// The `SCTLR2_EL2` system register.
+    Sctlr2El2,
+    #[cfg(feature = "el3")]
+    /// The `SCTLR2_EL3` system register.
+    Sctlr2El3,
+    #[cfg(feature = "el1")]
+    /// The `SCTLR_EL1` system register.
+    SctlrEl1,
+    #[cfg(feature = "el2")]
+    /// The `SCTLR_EL2` system register.
+    SctlrEl2,
+    #[cfg(feature = "el3")]
+    /// The `SCTLR_EL3` system register.
+    SctlrEl3,
+    #[cfg(feature = "el1")]
+    /// The `SDCR` system register.
+    Sdcr,
+    #[cfg(feature = "el1")]
+    /// The `SDER` system register.
+    Sder,
+    #[cfg(all(feature = "el3", feature = "feat-sve"))]
+    /// The `SMCR_EL3` system register.
+    SmcrEl3,
+    #[cfg(feature = "el1")]
+    /// The `SPSR_EL1` system register.
+    SpsrEl1,
+    #[cfg(feature = "el2")]
+    /// The `SPSR_EL2` system register.
+    SpsrEl2,
+    #[cfg(feature = "el3")]
+    /// The `SPSR_EL3` system register.
+    SpsrEl3,
+    #[cfg(feature = "el1")]
+    /// The `SP_EL1` system register.
+    SpEl1,
+    #[cfg(feature = "el2")]
+    /// The `SP_EL2` system register.
+    SpEl2,
+    #[cfg(feature = "feat-sve")]
+    /// The `SVCR` system register.
+    Svcr,
+    #[cfg(feature = "el1")]
+    /// The `TCMTR` system register.
+    Tcmtr,
+    #[cfg(feature = "el1")]
+    /// The `TCR2_EL1` system register.
+    Tcr2El1,
+    #[cfg(feature = "el2")]
+    /// The `TCR2_EL2` system register.
+    Tcr2El2,
+    #[cfg(feature = "el1")]
+    /// The `TCR_EL1` system register.
+    TcrEl1,
+    #[cfg(feature = "el2")]
+    /// The `TCR_EL2` system register.
+    TcrEl2,
+    #[cfg(feature = "el3")]
+    /// The `TCR_EL3` system register.
+    TcrEl3,
+    #[cfg(feature = "el1")]
+    /// The `TFSRE0_EL1` system register.
+    Tfsre0El1,
+    #[cfg(feature = "el1")]
+    /// The `TFSR_EL1` system register.
+    TfsrEl1,
+    #[cfg(feature = "el2")]
+    /// The `TFSR_EL2` system register.
+    TfsrEl2,
+    #[cfg(feature = "el1")]
+    /// The `TLBTR` system register.
+    Tlbtr,
+    #[cfg(feature = "el1")]
+    /// The `TPIDRPRW` system register.
+    Tpidrprw,
+    /// The `TPIDRRO_EL0` system register.
+    TpidrroEl0,
+    /// The `TPIDRURO` system register.
+    Tpidruro,
+    /// The `TPIDRURW` system register.
+    Tpidrurw,
+    /// The `TPIDR_EL0` system register.
+    TpidrEl0,
+    #[cfg(feature = "el1")]
+    /// The `TPIDR_EL1` system register.
+    TpidrEl1,
+    #[cfg(feature = "el2")]
+    /// The `TPIDR_EL2` system register.
+    TpidrEl2,
+    #[cfg(feature = "el3")]
+    /// The `TPIDR_EL3` system register.
+    TpidrEl3,
+    #[cfg(feature = "el1")]
+    /// The `TRFCR` system register.
+    Trfcr,
+    #[cfg(feature = "el1")]
+    /// The `TTBCR` system register.
+    Ttbcr,
+    #[cfg(feature = "el1")]
+    /// The `TTBCR2` system register.
+    Ttbcr2,
+    #[cfg(feature = "el1")]
+    /// The `TTBR0` system register.
+    Ttbr0,
+    #[cfg(feature = "el1")]
+    /// The `TTBR0_EL1` system register.
+    Ttbr0El1,
+    #[cfg(feature = "el2")]
+    /// The `TTBR0_EL2` system register.
+    Ttbr0El2,
+    #[cfg(feature = "el3")]
+    /// The `TTBR0_EL3` system register.
+    Ttbr0El3,
+    #[cfg(feature = "el1")]
+    /// The `TTBR1` system register.
+    Ttbr1,
+    #[cfg(feature = "el1")]
+    /// The `TTBR1_EL1` system register.
+    Ttbr1El1,
+    #[cfg(feature = "el2")]
+    /// The `TTBR1_EL2` system register.
+    Ttbr1El2,
+    #[cfg(feature = "el1")]
+    /// The `VBAR` system register.
+    Vbar,
+    #[cfg(feature = "el1")]
+    /// The `VBAR_EL1` system register.
+    VbarEl1,
+    #[cfg(feature = "el2")]
+    /// The `VBAR_EL2` system register.
+    VbarEl2,
+    #[cfg(feature = "el2")]
+    /// The `VDFSR` system register.
+    Vdfsr,
+    #[cfg(feature = "el1")]
+    /// The `VDISR` system register.
+    Vdisr,
+    #[cfg(all(feature = "el2", feature = "feat-ras"))]
+    /// The `VDISR_EL2` system register.
+    VdisrEl2,
+    #[cfg(feature = "el1")]
+    /// The `VMPIDR` system register.
+    Vmpidr,
+    #[cfg(feature = "el2")]
+    /// The `VMPIDR_EL2` system register.
+    VmpidrEl2,
+    #[cfg(feature = "el1")]
+    /// The `VPIDR` system register.
+    Vpidr,
+    #[cfg(feature = "el2")]
+    /// The `VPIDR_EL2` system register.
+    VpidrEl2,
+    #[cfg(all(feature = "el2", feature = "feat-ras"))]
+    /// The `VSESR_EL2` system register.
+    VsesrEl2,
+    #[cfg(feature = "el2")]
+    /// The `VTCR` system register.
+    Vtcr,
+    #[cfg(feature = "el2")]
+    /// The `VTCR_EL2` system register.
+    VtcrEl2,
+    #[cfg(feature = "el2")]
+    /// The `VTTBR` system register.
+    Vttbr,
+    #[cfg(feature = "el2")]
+    /// The `VTTBR_EL2` system register.
+    VttbrEl2,
+    #[cfg(all(feature = "el3", feature = "feat-sve"))]
+    /// The `ZCR_EL3` system register.
+    ZcrEl3,
+}
+
+impl<B: SysregBackend> Sysregs<B> {
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ACTLR` system register.
+    pub fn read_actlr(&self) -> u32 {
+        self.0.read(SysregId::Actlr) as u32
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `ACTLR` system register.
+    pub fn write_actlr(&self, value: u32) {
+        self.0.write(SysregId::Actlr, u64::from(value));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ACTLR2` system register.
+    pub fn read_actlr2(&self) -> u32 {
+        self.0.read(SysregId::Actlr2) as u32
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `ACTLR2` system register.
+    pub fn write_actlr2(&self, value: u32) {
+        self.0.write(SysregId::Actlr2, u64::from(value));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ACTLR_EL1` system register.
+    pub fn read_actlr_el1(&self) -> u64 {
+        self.0.read(SysregId::ActlrEl1)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `ACTLR_EL1` system register.
+    pub fn write_actlr_el1(&self, value: u64) {
+        self.0.write(SysregId::ActlrEl1, value);
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `ACTLR_EL2` system register.
+    pub fn read_actlr_el2(&self) -> u64 {
+        self.0.read(SysregId::ActlrEl2)
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `ACTLR_EL2` system register.
+    pub fn write_actlr_el2(&self, value: u64) {
+        self.0.write(SysregId::ActlrEl2, value);
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ADFSR` system register.
+    pub fn read_adfsr(&self) -> u32 {
+        self.0.read(SysregId::Adfsr) as u32
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `ADFSR` system register.
+    pub fn write_adfsr(&self, value: u32) {
+        self.0.write(SysregId::Adfsr, u64::from(value));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `AFSR0_EL1` system register.
+    pub fn read_afsr0_el1(&self) -> u64 {
+        self.0.read(SysregId::Afsr0El1)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `AFSR0_EL1` system register.
+    pub fn write_afsr0_el1(&self, value: u64) {
+        self.0.write(SysregId::Afsr0El1, value);
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `AFSR0_EL2` system register.
+    pub fn read_afsr0_el2(&self) -> u64 {
+        self.0.read(SysregId::Afsr0El2)
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `AFSR0_EL2` system register.
+    pub fn write_afsr0_el2(&self, value: u64) {
+        self.0.write(SysregId::Afsr0El2, value);
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `AFSR1_EL1` system register.
+    pub fn read_afsr1_el1(&self) -> u64 {
+        self.0.read(SysregId::Afsr1El1)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `AFSR1_EL1` system register.
+    pub fn write_afsr1_el1(&self, value: u64) {
+        self.0.write(SysregId::Afsr1El1, value);
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `AFSR1_EL2` system register.
+    pub fn read_afsr1_el2(&self) -> u64 {
+        self.0.read(SysregId::Afsr1El2)
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `AFSR1_EL2` system register.
+    pub fn write_afsr1_el2(&self, value: u64) {
+        self.0.write(SysregId::Afsr1El2, value);
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `AIDR` system register.
+    pub fn read_aidr(&self) -> u32 {
+        self.0.read(SysregId::Aidr) as u32
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `AIFSR` system register.
+    pub fn read_aifsr(&self) -> u32 {
+        self.0.read(SysregId::Aifsr) as u32
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `AIFSR` system register.
+    pub fn write_aifsr(&self, value: u32) {
+        self.0.write(SysregId::Aifsr, u64::from(value));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `AMAIR0` system register.
+    pub fn read_amair0(&self) -> u32 {
+        self.0.read(SysregId::Amair0) as u32
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `AMAIR0` system register.
+    pub fn write_amair0(&self, value: u32) {
+        self.0.write(SysregId::Amair0, u64::from(value));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `AMAIR1` system register.
+    pub fn read_amair1(&self) -> u32 {
+        self.0.read(SysregId::Amair1) as u32
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `AMAIR1` system register.
+    pub fn write_amair1(&self, value: u32) {
+        self.0.write(SysregId::Amair1, u64::from(value));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `AMAIR_EL1` system register.
+    pub fn read_amair_el1(&self) -> u64 {
+        self.0.read(SysregId::AmairEl1)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `AMAIR_EL1` system register.
+    pub fn write_amair_el1(&self, value: u64) {
+        self.0.write(SysregId::AmairEl1, value);
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `AMAIR_EL2` system register.
+    pub fn read_amair_el2(&self) -> u64 {
+        self.0.read(SysregId::AmairEl2)
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `AMAIR_EL2` system register.
+    pub fn write_amair_el2(&self, value: u64) {
+        self.0.write(SysregId::AmairEl2, value);
+    }
+
+    /// Returns the value of the `AMCFGR` system register.
+    pub fn read_amcfgr(&self) -> Amcfgr {
+        Amcfgr::from_bits_retain(self.0.read(SysregId::Amcfgr) as u32)
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Returns the value of the `AMCFGR_EL0` system register.
+    pub fn read_amcfgr_el0(&self) -> AmcfgrEl0 {
+        AmcfgrEl0::from_bits_retain(self.0.read(SysregId::AmcfgrEl0))
+    }
+
+    /// Returns the value of the `AMCGCR` system register.
+    pub fn read_amcgcr(&self) -> Amcgcr {
+        Amcgcr::from_bits_retain(self.0.read(SysregId::Amcgcr) as u32)
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Returns the value of the `AMCGCR_EL0` system register.
+    pub fn read_amcgcr_el0(&self) -> AmcgcrEl0 {
+        AmcgcrEl0::from_bits_retain(self.0.read(SysregId::AmcgcrEl0))
+    }
+
+    /// Returns the value of the `AMCNTENCLR0` system register.
+    pub fn read_amcntenclr0(&self) -> Amcntenclr0 {
+        Amcntenclr0::from_bits_retain(self.0.read(SysregId::Amcntenclr0) as u32)
+    }
+
+    /// Writes `value` to the `AMCNTENCLR0` system register.
+    pub fn write_amcntenclr0(&self, value: Amcntenclr0) {
+        self.0.write(SysregId::Amcntenclr0, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Returns the value of the `AMCNTENCLR0_EL0` system register.
+    pub fn read_amcntenclr0_el0(&self) -> Amcntenclr0El0 {
+        Amcntenclr0El0::from_bits_retain(self.0.read(SysregId::Amcntenclr0El0))
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Writes `value` to the `AMCNTENCLR0_EL0` system register.
+    pub fn write_amcntenclr0_el0(&self, value: Amcntenclr0El0) {
+        self.0.write(SysregId::Amcntenclr0El0, value.bits());
+    }
+
+    /// Returns the value of the `AMCNTENCLR1` system register.
+    pub fn read_amcntenclr1(&self) -> Amcntenclr1 {
+        Amcntenclr1::from_bits_retain(self.0.read(SysregId::Amcntenclr1) as u32)
+    }
+
+    /// Writes `value` to the `AMCNTENCLR1` system register.
+    pub fn write_amcntenclr1(&self, value: Amcntenclr1) {
+        self.0.write(SysregId::Amcntenclr1, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Returns the value of the `AMCNTENCLR1_EL0` system register.
+    pub fn read_amcntenclr1_el0(&self) -> Amcntenclr1El0 {
+        Amcntenclr1El0::from_bits_retain(self.0.read(SysregId::Amcntenclr1El0))
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Writes `value` to the `AMCNTENCLR1_EL0` system register.
+    pub fn write_amcntenclr1_el0(&self, value: Amcntenclr1El0) {
+        self.0.write(SysregId::Amcntenclr1El0, value.bits());
+    }
+
+    /// Returns the value of the `AMCNTENSET0` system register.
+    pub fn read_amcntenset0(&self) -> Amcntenset0 {
+        Amcntenset0::from_bits_retain(self.0.read(SysregId::Amcntenset0) as u32)
+    }
+
+    /// Writes `value` to the `AMCNTENSET0` system register.
+    pub fn write_amcntenset0(&self, value: Amcntenset0) {
+        self.0.write(SysregId::Amcntenset0, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Returns the value of the `AMCNTENSET0_EL0` system register.
+    pub fn read_amcntenset0_el0(&self) -> Amcntenset0El0 {
+        Amcntenset0El0::from_bits_retain(self.0.read(SysregId::Amcntenset0El0))
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Writes `value` to the `AMCNTENSET0_EL0` system register.
+    pub fn write_amcntenset0_el0(&self, value: Amcntenset0El0) {
+        self.0.write(SysregId::Amcntenset0El0, value.bits());
+    }
+
+    /// Returns the value of the `AMCNTENSET1` system register.
+    pub fn read_amcntenset1(&self) -> Amcntenset1 {
+        Amcntenset1::from_bits_retain(self.0.read(SysregId::Amcntenset1) as u32)
+    }
+
+    /// Writes `value` to the `AMCNTENSET1` system register.
+    pub fn write_amcntenset1(&self, value: Amcntenset1) {
+        self.0.write(SysregId::Amcntenset1, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Returns the value of the `AMCNTENSET1_EL0` system register.
+    pub fn read_amcntenset1_el0(&self) -> Amcntenset1El0 {
+        Amcntenset1El0::from_bits_retain(self.0.read(SysregId::Amcntenset1El0))
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Writes `value` to the `AMCNTENSET1_EL0` system register.
+    pub fn write_amcntenset1_el0(&self, value: Amcntenset1El0) {
+        self.0.write(SysregId::Amcntenset1El0, value.bits());
+    }
+
+    /// Returns the value of the `AMCR` system register.
+    pub fn read_amcr(&self) -> Amcr {
+        Amcr::from_bits_retain(self.0.read(SysregId::Amcr) as u32)
+    }
+
+    /// Writes `value` to the `AMCR` system register.
+    pub fn write_amcr(&self, value: Amcr) {
+        self.0.write(SysregId::Amcr, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Returns the value of the `AMCR_EL0` system register.
+    pub fn read_amcr_el0(&self) -> AmcrEl0 {
+        AmcrEl0::from_bits_retain(self.0.read(SysregId::AmcrEl0))
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Writes `value` to the `AMCR_EL0` system register.
+    pub fn write_amcr_el0(&self, value: AmcrEl0) {
+        self.0.write(SysregId::AmcrEl0, value.bits());
+    }
+
+    /// Returns the value of the `AMEVCNTR00` system register.
+    pub fn read_amevcntr00(&self) -> Amevcntr00 {
+        Amevcntr00::from_bits_retain(self.0.read(SysregId::Amevcntr00))
+    }
+
+    /// Writes `value` to the `AMEVCNTR00` system register.
+    pub fn write_amevcntr00(&self, value: Amevcntr00) {
+        self.0.write(SysregId::Amevcntr00, value.bits());
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Returns the value of the `AMEVCNTR00_EL0` system register.
+    pub fn read_amevcntr00_el0(&self) -> Amevcntr00El0 {
+        Amevcntr00El0::from_bits_retain(self.0.read(SysregId::Amevcntr00El0))
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Writes `value` to the `AMEVCNTR00_EL0` system register.
+    pub fn write_amevcntr00_el0(&self, value: Amevcntr00El0) {
+        self.0.write(SysregId::Amevcntr00El0, value.bits());
+    }
+
+    /// Returns the value of the `AMEVCNTR01` system register.
+    pub fn read_amevcntr01(&self) -> Amevcntr01 {
+        Amevcntr01::from_bits_retain(self.0.read(SysregId::Amevcntr01))
+    }
+
+    /// Writes `value` to the `AMEVCNTR01` system register.
+    pub fn write_amevcntr01(&self, value: Amevcntr01) {
+        self.0.write(SysregId::Amevcntr01, value.bits());
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Returns the value of the `AMEVCNTR01_EL0` system register.
+    pub fn read_amevcntr01_el0(&self) -> Amevcntr01El0 {
+        Amevcntr01El0::from_bits_retain(self.0.read(SysregId::Amevcntr01El0))
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Writes `value` to the `AMEVCNTR01_EL0` system register.
+    pub fn write_amevcntr01_el0(&self, value: Amevcntr01El0) {
+        self.0.write(SysregId::Amevcntr01El0, value.bits());
+    }
+
+    /// Returns the value of the `AMEVCNTR02` system register.
+    pub fn read_amevcntr02(&self) -> Amevcntr02 {
+        Amevcntr02::from_bits_retain(self.0.read(SysregId::Amevcntr02))
+    }
+
+    /// Writes `value` to the `AMEVCNTR02` system register.
+    pub fn write_amevcntr02(&self, value: Amevcntr02) {
+        self.0.write(SysregId::Amevcntr02, value.bits());
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Returns the value of the `AMEVCNTR02_EL0` system register.
+    pub fn read_amevcntr02_el0(&self) -> Amevcntr02El0 {
+        Amevcntr02El0::from_bits_retain(self.0.read(SysregId::Amevcntr02El0))
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Writes `value` to the `AMEVCNTR02_EL0` system register.
+    pub fn write_amevcntr02_el0(&self, value: Amevcntr02El0) {
+        self.0.write(SysregId::Amevcntr02El0, value.bits());
+    }
+
+    /// Returns the value of the `AMEVCNTR03` system register.
+    pub fn read_amevcntr03(&self) -> Amevcntr03 {
+        Amevcntr03::from_bits_retain(self.0.read(SysregId::Amevcntr03))
+    }
+
+    /// Writes `value` to the `AMEVCNTR03` system register.
+    pub fn write_amevcntr03(&self, value: Amevcntr03) {
+        self.0.write(SysregId::Amevcntr03, value.bits());
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Returns the value of the `AMEVCNTR03_EL0` system register.
+    pub fn read_amevcntr03_el0(&self) -> Amevcntr03El0 {
+        Amevcntr03El0::from_bits_retain(self.0.read(SysregId::Amevcntr03El0))
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Writes `value` to the `AMEVCNTR03_EL0` system register.
+    pub fn write_amevcntr03_el0(&self, value: Amevcntr03El0) {
+        self.0.write(SysregId::Amevcntr03El0, value.bits());
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Returns the value of the `AMEVCNTR10_EL0` system register.
+    pub fn read_amevcntr10_el0(&self) -> Amevcntr10El0 {
+        Amevcntr10El0::from_bits_retain(self.0.read(SysregId::Amevcntr10El0))
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Writes `value` to the `AMEVCNTR10_EL0` system register.
+    pub fn write_amevcntr10_el0(&self, value: Amevcntr10El0) {
+        self.0.write(SysregId::Amevcntr10El0, value.bits());
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Returns the value of the `AMEVCNTR110_EL0` system register.
+    pub fn read_amevcntr110_el0(&self) -> Amevcntr110El0 {
+        Amevcntr110El0::from_bits_retain(self.0.read(SysregId::Amevcntr110El0))
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Writes `value` to the `AMEVCNTR110_EL0` system register.
+    pub fn write_amevcntr110_el0(&self, value: Amevcntr110El0) {
+        self.0.write(SysregId::Amevcntr110El0, value.bits());
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Returns the value of the `AMEVCNTR111_EL0` system register.
+    pub fn read_amevcntr111_el0(&self) -> Amevcntr111El0 {
+        Amevcntr111El0::from_bits_retain(self.0.read(SysregId::Amevcntr111El0))
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Writes `value` to the `AMEVCNTR111_EL0` system register.
+    pub fn write_amevcntr111_el0(&self, value: Amevcntr111El0) {
+        self.0.write(SysregId::Amevcntr111El0, value.bits());
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Returns the value of the `AMEVCNTR112_EL0` system register.
+    pub fn read_amevcntr112_el0(&self) -> Amevcntr112El0 {
+        Amevcntr112El0::from_bits_retain(self.0.read(SysregId::Amevcntr112El0))
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Writes `value` to the `AMEVCNTR112_EL0` system register.
+    pub fn write_amevcntr112_el0(&self, value: Amevcntr112El0) {
+        self.0.write(SysregId::Amevcntr112El0, value.bits());
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Returns the value of the `AMEVCNTR113_EL0` system register.
+    pub fn read_amevcntr113_el0(&self) -> Amevcntr113El0 {
+        Amevcntr113El0::from_bits_retain(self.0.read(SysregId::Amevcntr113El0))
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Writes `value` to the `AMEVCNTR113_EL0` system register.
+    pub fn write_amevcntr113_el0(&self, value: Amevcntr113El0) {
+        self.0.write(SysregId::Amevcntr113El0, value.bits());
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Returns the value of the `AMEVCNTR114_EL0` system register.
+    pub fn read_amevcntr114_el0(&self) -> Amevcntr114El0 {
+        Amevcntr114El0::from_bits_retain(self.0.read(SysregId::Amevcntr114El0))
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Writes `value` to the `AMEVCNTR114_EL0` system register.
+    pub fn write_amevcntr114_el0(&self, value: Amevcntr114El0) {
+        self.0.write(SysregId::Amevcntr114El0, value.bits());
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Returns the value of the `AMEVCNTR115_EL0` system register.
+    pub fn read_amevcntr115_el0(&self) -> Amevcntr115El0 {
+        Amevcntr115El0::from_bits_retain(self.0.read(SysregId::Amevcntr115El0))
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Writes `value` to the `AMEVCNTR115_EL0` system register.
+    pub fn write_amevcntr115_el0(&self, value: Amevcntr115El0) {
+        self.0.write(SysregId::Amevcntr115El0, value.bits());
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Returns the value of the `AMEVCNTR11_EL0` system register.
+    pub fn read_amevcntr11_el0(&self) -> Amevcntr11El0 {
+        Amevcntr11El0::from_bits_retain(self.0.read(SysregId::Amevcntr11El0))
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Writes `value` to the `AMEVCNTR11_EL0` system register.
+    pub fn write_amevcntr11_el0(&self, value: Amevcntr11El0) {
+        self.0.write(SysregId::Amevcntr11El0, value.bits());
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Returns the value of the `AMEVCNTR12_EL0` system register.
+    pub fn read_amevcntr12_el0(&self) -> Amevcntr12El0 {
+        Amevcntr12El0::from_bits_retain(self.0.read(SysregId::Amevcntr12El0))
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Writes `value` to the `AMEVCNTR12_EL0` system register.
+    pub fn write_amevcntr12_el0(&self, value: Amevcntr12El0) {
+        self.0.write(SysregId::Amevcntr12El0, value.bits());
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Returns the value of the `AMEVCNTR13_EL0` system register.
+    pub fn read_amevcntr13_el0(&self) -> Amevcntr13El0 {
+        Amevcntr13El0::from_bits_retain(self.0.read(SysregId::Amevcntr13El0))
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Writes `value` to the `AMEVCNTR13_EL0` system register.
+    pub fn write_amevcntr13_el0(&self, value: Amevcntr13El0) {
+        self.0.write(SysregId::Amevcntr13El0, value.bits());
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Returns the value of the `AMEVCNTR14_EL0` system register.
+    pub fn read_amevcntr14_el0(&self) -> Amevcntr14El0 {
+        Amevcntr14El0::from_bits_retain(self.0.read(SysregId::Amevcntr14El0))
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Writes `value` to the `AMEVCNTR14_EL0` system register.
+    pub fn write_amevcntr14_el0(&self, value: Amevcntr14El0) {
+        self.0.write(SysregId::Amevcntr14El0, value.bits());
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Returns the value of the `AMEVCNTR15_EL0` system register.
+    pub fn read_amevcntr15_el0(&self) -> Amevcntr15El0 {
+        Amevcntr15El0::from_bits_retain(self.0.read(SysregId::Amevcntr15El0))
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Writes `value` to the `AMEVCNTR15_EL0` system register.
+    pub fn write_amevcntr15_el0(&self, value: Amevcntr15El0) {
+        self.0.write(SysregId::Amevcntr15El0, value.bits());
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Returns the value of the `AMEVCNTR16_EL0` system register.
+    pub fn read_amevcntr16_el0(&self) -> Amevcntr16El0 {
+        Amevcntr16El0::from_bits_retain(self.0.read(SysregId::Amevcntr16El0))
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Writes `value` to the `AMEVCNTR16_EL0` system register.
+    pub fn write_amevcntr16_el0(&self, value: Amevcntr16El0) {
+        self.0.write(SysregId::Amevcntr16El0, value.bits());
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Returns the value of the `AMEVCNTR17_EL0` system register.
+    pub fn read_amevcntr17_el0(&self) -> Amevcntr17El0 {
+        Amevcntr17El0::from_bits_retain(self.0.read(SysregId::Amevcntr17El0))
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Writes `value` to the `AMEVCNTR17_EL0` system register.
+    pub fn write_amevcntr17_el0(&self, value: Amevcntr17El0) {
+        self.0.write(SysregId::Amevcntr17El0, value.bits());
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Returns the value of the `AMEVCNTR18_EL0` system register.
+    pub fn read_amevcntr18_el0(&self) -> Amevcntr18El0 {
+        Amevcntr18El0::from_bits_retain(self.0.read(SysregId::Amevcntr18El0))
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Writes `value` to the `AMEVCNTR18_EL0` system register.
+    pub fn write_amevcntr18_el0(&self, value: Amevcntr18El0) {
+        self.0.write(SysregId::Amevcntr18El0, value.bits());
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Returns the value of the `AMEVCNTR19_EL0` system register.
+    pub fn read_amevcntr19_el0(&self) -> Amevcntr19El0 {
+        Amevcntr19El0::from_bits_retain(self.0.read(SysregId::Amevcntr19El0))
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Writes `value` to the `AMEVCNTR19_EL0` system register.
+    pub fn write_amevcntr19_el0(&self, value: Amevcntr19El0) {
+        self.0.write(SysregId::Amevcntr19El0, value.bits());
+    }
+
+    /// Returns the value of the `AMEVTYPER00` system register.
+    pub fn read_amevtyper00(&self) -> Amevtyper00 {
+        Amevtyper00::from_bits_retain(self.0.read(SysregId::Amevtyper00) as u32)
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Returns the value of the `AMEVTYPER00_EL0` system register.
+    pub fn read_amevtyper00_el0(&self) -> Amevtyper00El0 {
+        Amevtyper00El0::from_bits_retain(self.0.read(SysregId::Amevtyper00El0))
+    }
+
+    /// Returns the value of the `AMEVTYPER01` system register.
+    pub fn read_amevtyper01(&self) -> Amevtyper01 {
+        Amevtyper01::from_bits_retain(self.0.read(SysregId::Amevtyper01) as u32)
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Returns the value of the `AMEVTYPER01_EL0` system register.
+    pub fn read_amevtyper01_el0(&self) -> Amevtyper01El0 {
+        Amevtyper01El0::from_bits_retain(self.0.read(SysregId::Amevtyper01El0))
+    }
+
+    /// Returns the value of the `AMEVTYPER02` system register.
+    pub fn read_amevtyper02(&self) -> Amevtyper02 {
+        Amevtyper02::from_bits_retain(self.0.read(SysregId::Amevtyper02) as u32)
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Returns the value of the `AMEVTYPER02_EL0` system register.
+    pub fn read_amevtyper02_el0(&self) -> Amevtyper02El0 {
+        Amevtyper02El0::from_bits_retain(self.0.read(SysregId::Amevtyper02El0))
+    }
+
+    /// Returns the value of the `AMEVTYPER03` system register.
+    pub fn read_amevtyper03(&self) -> Amevtyper03 {
+        Amevtyper03::from_bits_retain(self.0.read(SysregId::Amevtyper03) as u32)
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Returns the value of the `AMEVTYPER03_EL0` system register.
+    pub fn read_amevtyper03_el0(&self) -> Amevtyper03El0 {
+        Amevtyper03El0::from_bits_retain(self.0.read(SysregId::Amevtyper03El0))
+    }
+
+    /// Returns the value of the `AMEVTYPER10` system register.
+    pub fn read_amevtyper10(&self) -> Amevtyper10 {
+        Amevtyper10::from_bits_retain(self.0.read(SysregId::Amevtyper10) as u32)
+    }
+
+    /// Writes `value` to the `AMEVTYPER10` system register.
+    pub fn write_amevtyper10(&self, value: Amevtyper10) {
+        self.0.write(SysregId::Amevtyper10, u64::from(value.bits()));
+    }
+
+    /// Returns the value of the `AMEVTYPER11` system register.
+    pub fn read_amevtyper11(&self) -> Amevtyper11 {
+        Amevtyper11::from_bits_retain(self.0.read(SysregId::Amevtyper11) as u32)
+    }
+
+    /// Writes `value` to the `AMEVTYPER11` system register.
+    pub fn write_amevtyper11(&self, value: Amevtyper11) {
+        self.0.write(SysregId::Amevtyper11, u64::from(value.bits()));
+    }
+
+    /// Returns the value of the `AMEVTYPER110` system register.
+    pub fn read_amevtyper110(&self) -> Amevtyper110 {
+        Amevtyper110::from_bits_retain(self.0.read(SysregId::Amevtyper110) as u32)
+    }
+
+    /// Writes `value` to the `AMEVTYPER110` system register.
+    pub fn write_amevtyper110(&self, value: Amevtyper110) {
+        self.0
+            .write(SysregId::Amevtyper110, u64::from(value.bits()));
+    }
+
+    /// Returns the value of the `AMEVTYPER111` system register.
+    pub fn read_amevtyper111(&self) -> Amevtyper111 {
+        Amevtyper111::from_bits_retain(self.0.read(SysregId::Amevtyper111) as u32)
+    }
+
+    /// Writes `value` to the `AMEVTYPER111` system register.
+    pub fn write_amevtyper111(&self, value: Amevtyper111) {
+        self.0
+            .write(SysregId::Amevtyper111, u64::from(value.bits()));
+    }
+
+    /// Returns the value of the `AMEVTYPER112` system register.
+    pub fn read_amevtyper112(&self) -> Amevtyper112 {
+        Amevtyper112::from_bits_retain(self.0.read(SysregId::Amevtyper112) as u32)
+    }
+
+    /// Writes `value` to the `AMEVTYPER112` system register.
+    pub fn write_amevtyper112(&self, value: Amevtyper112) {
+        self.0
+            .write(SysregId::Amevtyper112, u64::from(value.bits()));
+    }
+
+    /// Returns the value of the `AMEVTYPER113` system register.
+    pub fn read_amevtyper113(&self) -> Amevtyper113 {
+        Amevtyper113::from_bits_retain(self.0.read(SysregId::Amevtyper113) as u32)
+    }
+
+    /// Writes `value` to the `AMEVTYPER113` system register.
+    pub fn write_amevtyper113(&self, value: Amevtyper113) {
+        self.0
+            .write(SysregId::Amevtyper113, u64::from(value.bits()));
+    }
+
+    /// Returns the value of the `AMEVTYPER114` system register.
+    pub fn read_amevtyper114(&self) -> Amevtyper114 {
+        Amevtyper114::from_bits_retain(self.0.read(SysregId::Amevtyper114) as u32)
+    }
+
+    /// Writes `value` to the `AMEVTYPER114` system register.
+    pub fn write_amevtyper114(&self, value: Amevtyper114) {
+        self.0
+            .write(SysregId::Amevtyper114, u64::from(value.bits()));
+    }
+
+    /// Returns the value of the `AMEVTYPER115` system register.
+    pub fn read_amevtyper115(&self) -> Amevtyper115 {
+        Amevtyper115::from_bits_retain(self.0.read(SysregId::Amevtyper115) as u32)
+    }
+
+    /// Writes `value` to the `AMEVTYPER115` system register.
+    pub fn write_amevtyper115(&self, value: Amevtyper115) {
+        self.0
+            .write(SysregId::Amevtyper115, u64::from(value.bits()));
+    }
+
+    /// Returns the value of the `AMEVTYPER12` system register.
+    pub fn read_amevtyper12(&self) -> Amevtyper12 {
+        Amevtyper12::from_bits_retain(self.0.read(SysregId::Amevtyper12) as u32)
+    }
+
+    /// Writes `value` to the `AMEVTYPER12` system register.
+    pub fn write_amevtyper12(&self, value: Amevtyper12) {
+        self.0.write(SysregId::Amevtyper12, u64::from(value.bits()));
+    }
+
+    /// Returns the value of the `AMEVTYPER13` system register.
+    pub fn read_amevtyper13(&self) -> Amevtyper13 {
+        Amevtyper13::from_bits_retain(self.0.read(SysregId::Amevtyper13) as u32)
+    }
+
+    /// Writes `value` to the `AMEVTYPER13` system register.
+    pub fn write_amevtyper13(&self, value: Amevtyper13) {
+        self.0.write(SysregId::Amevtyper13, u64::from(value.bits()));
+    }
+
+    /// Returns the value of the `AMEVTYPER14` system register.
+    pub fn read_amevtyper14(&self) -> Amevtyper14 {
+        Amevtyper14::from_bits_retain(self.0.read(SysregId::Amevtyper14) as u32)
+    }
+
+    /// Writes `value` to the `AMEVTYPER14` system register.
+    pub fn write_amevtyper14(&self, value: Amevtyper14) {
+        self.0.write(SysregId::Amevtyper14, u64::from(value.bits()));
+    }
+
+    /// Returns the value of the `AMEVTYPER15` system register.
+    pub fn read_amevtyper15(&self) -> Amevtyper15 {
+        Amevtyper15::from_bits_retain(self.0.read(SysregId::Amevtyper15) as u32)
+    }
+
+    /// Writes `value` to the `AMEVTYPER15` system register.
+    pub fn write_amevtyper15(&self, value: Amevtyper15) {
+        self.0.write(SysregId::Amevtyper15, u64::from(value.bits()));
+    }
+
+    /// Returns the value of the `AMEVTYPER16` system register.
+    pub fn read_amevtyper16(&self) -> Amevtyper16 {
+        Amevtyper16::from_bits_retain(self.0.read(SysregId::Amevtyper16) as u32)
+    }
+
+    /// Writes `value` to the `AMEVTYPER16` system register.
+    pub fn write_amevtyper16(&self, value: Amevtyper16) {
+        self.0.write(SysregId::Amevtyper16, u64::from(value.bits()));
+    }
+
+    /// Returns the value of the `AMEVTYPER17` system register.
+    pub fn read_amevtyper17(&self) -> Amevtyper17 {
+        Amevtyper17::from_bits_retain(self.0.read(SysregId::Amevtyper17) as u32)
+    }
+
+    /// Writes `value` to the `AMEVTYPER17` system register.
+    pub fn write_amevtyper17(&self, value: Amevtyper17) {
+        self.0.write(SysregId::Amevtyper17, u64::from(value.bits()));
+    }
+
+    /// Returns the value of the `AMEVTYPER18` system register.
+    pub fn read_amevtyper18(&self) -> Amevtyper18 {
+        Amevtyper18::from_bits_retain(self.0.read(SysregId::Amevtyper18) as u32)
+    }
+
+    /// Writes `value` to the `AMEVTYPER18` system register.
+    pub fn write_amevtyper18(&self, value: Amevtyper18) {
+        self.0.write(SysregId::Amevtyper18, u64::from(value.bits()));
+    }
+
+    /// Returns the value of the `AMEVTYPER19` system register.
+    pub fn read_amevtyper19(&self) -> Amevtyper19 {
+        Amevtyper19::from_bits_retain(self.0.read(SysregId::Amevtyper19) as u32)
+    }
+
+    /// Writes `value` to the `AMEVTYPER19` system register.
+    pub fn write_amevtyper19(&self, value: Amevtyper19) {
+        self.0.write(SysregId::Amevtyper19, u64::from(value.bits()));
+    }
+
+    /// Returns the value of the `AMUSERENR` system register.
+    pub fn read_amuserenr(&self) -> Amuserenr {
+        Amuserenr::from_bits_retain(self.0.read(SysregId::Amuserenr) as u32)
+    }
+
+    /// Writes `value` to the `AMUSERENR` system register.
+    pub fn write_amuserenr(&self, value: Amuserenr) {
+        self.0.write(SysregId::Amuserenr, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Returns the value of the `AMUSERENR_EL0` system register.
+    pub fn read_amuserenr_el0(&self) -> AmuserenrEl0 {
+        AmuserenrEl0::from_bits_retain(self.0.read(SysregId::AmuserenrEl0))
+    }
+
+    #[cfg(feature = "feat-amu")]
+    /// Writes `value` to the `AMUSERENR_EL0` system register.
+    pub fn write_amuserenr_el0(&self, value: AmuserenrEl0) {
+        self.0.write(SysregId::AmuserenrEl0, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `APIAKeyHi_EL1` system register.
+    pub fn read_apiakeyhi_el1(&self) -> ApiakeyhiEl1 {
+        ApiakeyhiEl1::from_bits_retain(self.0.read(SysregId::ApiakeyhiEl1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `APIAKeyHi_EL1` system register.
+    pub fn write_apiakeyhi_el1(&self, value: ApiakeyhiEl1) {
+        self.0.write(SysregId::ApiakeyhiEl1, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `APIAKeyLo_EL1` system register.
+    pub fn read_apiakeylo_el1(&self) -> ApiakeyloEl1 {
+        ApiakeyloEl1::from_bits_retain(self.0.read(SysregId::ApiakeyloEl1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `APIAKeyLo_EL1` system register.
+    pub fn write_apiakeylo_el1(&self, value: ApiakeyloEl1) {
+        self.0.write(SysregId::ApiakeyloEl1, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `BRBCR_EL2` system register.
+    pub fn read_brbcr_el2(&self) -> BrbcrEl2 {
+        BrbcrEl2::from_bits_retain(self.0.read(SysregId::BrbcrEl2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `BRBCR_EL2` system register.
+    pub fn write_brbcr_el2(&self, value: BrbcrEl2) {
+        self.0.write(SysregId::BrbcrEl2, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `CCSIDR` system register.
+    pub fn read_ccsidr(&self) -> Ccsidr {
+        Ccsidr::from_bits_retain(self.0.read(SysregId::Ccsidr) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `CCSIDR2` system register.
+    pub fn read_ccsidr2(&self) -> Ccsidr2 {
+        Ccsidr2::from_bits_retain(self.0.read(SysregId::Ccsidr2) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `CCSIDR_EL1` system register.
+    pub fn read_ccsidr_el1(&self) -> CcsidrEl1 {
+        CcsidrEl1::from_bits_retain(self.0.read(SysregId::CcsidrEl1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `CLIDR` system register.
+    pub fn read_clidr(&self) -> Clidr {
+        Clidr::from_bits_retain(self.0.read(SysregId::Clidr) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `CLIDR_EL1` system register.
+    pub fn read_clidr_el1(&self) -> ClidrEl1 {
+        ClidrEl1::from_bits_retain(self.0.read(SysregId::ClidrEl1))
+    }
+
+    /// Returns the value of the `CNTFRQ` system register.
+    pub fn read_cntfrq(&self) -> Cntfrq {
+        Cntfrq::from_bits_retain(self.0.read(SysregId::Cntfrq) as u32)
+    }
+
+    /// Writes `value` to the `CNTFRQ` system register.
+    pub fn write_cntfrq(&self, value: Cntfrq) {
+        self.0.write(SysregId::Cntfrq, u64::from(value.bits()));
+    }
+
+    /// Returns the value of the `CNTFRQ_EL0` system register.
+    pub fn read_cntfrq_el0(&self) -> CntfrqEl0 {
+        CntfrqEl0::from_bits_retain(self.0.read(SysregId::CntfrqEl0))
+    }
+
+    /// Writes `value` to the `CNTFRQ_EL0` system register.
+    pub fn write_cntfrq_el0(&self, value: CntfrqEl0) {
+        self.0.write(SysregId::CntfrqEl0, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `CNTHCTL` system register.
+    pub fn read_cnthctl(&self) -> Cnthctl {
+        Cnthctl::from_bits_retain(self.0.read(SysregId::Cnthctl) as u32)
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `CNTHCTL` system register.
+    pub fn write_cnthctl(&self, value: Cnthctl) {
+        self.0.write(SysregId::Cnthctl, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `CNTHCTL_EL2` system register.
+    pub fn read_cnthctl_el2(&self) -> CnthctlEl2 {
+        CnthctlEl2::from_bits_retain(self.0.read(SysregId::CnthctlEl2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `CNTHCTL_EL2` system register.
+    pub fn write_cnthctl_el2(&self, value: CnthctlEl2) {
+        self.0.write(SysregId::CnthctlEl2, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `CNTHPS_CTL` system register.
+    pub fn read_cnthps_ctl(&self) -> CnthpsCtl {
+        CnthpsCtl::from_bits_retain(self.0.read(SysregId::CnthpsCtl) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `CNTHPS_CTL` system register.
+    pub fn write_cnthps_ctl(&self, value: CnthpsCtl) {
+        self.0.write(SysregId::CnthpsCtl, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `CNTHPS_CTL_EL2` system register.
+    pub fn read_cnthps_ctl_el2(&self) -> CnthpsCtlEl2 {
+        CnthpsCtlEl2::from_bits_retain(self.0.read(SysregId::CnthpsCtlEl2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `CNTHPS_CTL_EL2` system register.
+    pub fn write_cnthps_ctl_el2(&self, value: CnthpsCtlEl2) {
+        self.0.write(SysregId::CnthpsCtlEl2, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `CNTHPS_CVAL` system register.
+    pub fn read_cnthps_cval(&self) -> CnthpsCval {
+        CnthpsCval::from_bits_retain(self.0.read(SysregId::CnthpsCval))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `CNTHPS_CVAL` system register.
+    pub fn write_cnthps_cval(&self, value: CnthpsCval) {
+        self.0.write(SysregId::CnthpsCval, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `CNTHPS_CVAL_EL2` system register.
+    pub fn read_cnthps_cval_el2(&self) -> CnthpsCvalEl2 {
+        CnthpsCvalEl2::from_bits_retain(self.0.read(SysregId::CnthpsCvalEl2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `CNTHPS_CVAL_EL2` system register.
+    pub fn write_cnthps_cval_el2(&self, value: CnthpsCvalEl2) {
+        self.0.write(SysregId::CnthpsCvalEl2, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `CNTHPS_TVAL` system register.
+    pub fn read_cnthps_tval(&self) -> CnthpsTval {
+        CnthpsTval::from_bits_retain(self.0.read(SysregId::CnthpsTval) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `CNTHPS_TVAL` system register.
+    pub fn write_cnthps_tval(&self, value: CnthpsTval) {
+        self.0.write(SysregId::CnthpsTval, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `CNTHPS_TVAL_EL2` system register.
+    pub fn read_cnthps_tval_el2(&self) -> CnthpsTvalEl2 {
+        CnthpsTvalEl2::from_bits_retain(self.0.read(SysregId::CnthpsTvalEl2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `CNTHPS_TVAL_EL2` system register.
+    pub fn write_cnthps_tval_el2(&self, value: CnthpsTvalEl2) {
+        self.0.write(SysregId::CnthpsTvalEl2, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `CNTHP_CTL` system register.
+    pub fn read_cnthp_ctl(&self) -> CnthpCtl {
+        CnthpCtl::from_bits_retain(self.0.read(SysregId::CnthpCtl) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `CNTHP_CTL` system register.
+    pub fn write_cnthp_ctl(&self, value: CnthpCtl) {
+        self.0.write(SysregId::CnthpCtl, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `CNTHP_CTL_EL2` system register.
+    pub fn read_cnthp_ctl_el2(&self) -> CnthpCtlEl2 {
+        CnthpCtlEl2::from_bits_retain(self.0.read(SysregId::CnthpCtlEl2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `CNTHP_CTL_EL2` system register.
+    pub fn write_cnthp_ctl_el2(&self, value: CnthpCtlEl2) {
+        self.0.write(SysregId::CnthpCtlEl2, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `CNTHP_CVAL` system register.
+    pub fn read_cnthp_cval(&self) -> CnthpCval {
+        CnthpCval::from_bits_retain(self.0.read(SysregId::CnthpCval))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `CNTHP_CVAL` system register.
+    pub fn write_cnthp_cval(&self, value: CnthpCval) {
+        self.0.write(SysregId::CnthpCval, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `CNTHP_CVAL_EL2` system register.
+    pub fn read_cnthp_cval_el2(&self) -> CnthpCvalEl2 {
+        CnthpCvalEl2::from_bits_retain(self.0.read(SysregId::CnthpCvalEl2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `CNTHP_CVAL_EL2` system register.
+    pub fn write_cnthp_cval_el2(&self, value: CnthpCvalEl2) {
+        self.0.write(SysregId::CnthpCvalEl2, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `CNTHP_TVAL` system register.
+    pub fn read_cnthp_tval(&self) -> CnthpTval {
+        CnthpTval::from_bits_retain(self.0.read(SysregId::CnthpTval) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `CNTHP_TVAL` system register.
+    pub fn write_cnthp_tval(&self, value: CnthpTval) {
+        self.0.write(SysregId::CnthpTval, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `CNTHP_TVAL_EL2` system register.
+    pub fn read_cnthp_tval_el2(&self) -> CnthpTvalEl2 {
+        CnthpTvalEl2::from_bits_retain(self.0.read(SysregId::CnthpTvalEl2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `CNTHP_TVAL_EL2` system register.
+    pub fn write_cnthp_tval_el2(&self, value: CnthpTvalEl2) {
+        self.0.write(SysregId::CnthpTvalEl2, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `CNTHVS_CTL` system register.
+    pub fn read_cnthvs_ctl(&self) -> CnthvsCtl {
+        CnthvsCtl::from_bits_retain(self.0.read(SysregId::CnthvsCtl) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `CNTHVS_CTL` system register.
+    pub fn write_cnthvs_ctl(&self, value: CnthvsCtl) {
+        self.0.write(SysregId::CnthvsCtl, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `CNTHVS_CTL_EL2` system register.
+    pub fn read_cnthvs_ctl_el2(&self) -> CnthvsCtlEl2 {
+        CnthvsCtlEl2::from_bits_retain(self.0.read(SysregId::CnthvsCtlEl2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `CNTHVS_CTL_EL2` system register.
+    pub fn write_cnthvs_ctl_el2(&self, value: CnthvsCtlEl2) {
+        self.0.write(SysregId::CnthvsCtlEl2, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `CNTHVS_CVAL` system register.
+    pub fn read_cnthvs_cval(&self) -> CnthvsCval {
+        CnthvsCval::from_bits_retain(self.0.read(SysregId::CnthvsCval))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `CNTHVS_CVAL` system register.
+    pub fn write_cnthvs_cval(&self, value: CnthvsCval) {
+        self.0.write(SysregId::CnthvsCval, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `CNTHVS_CVAL_EL2` system register.
+    pub fn read_cnthvs_cval_el2(&self) -> CnthvsCvalEl2 {
+        CnthvsCvalEl2::from_bits_retain(self.0.read(SysregId::CnthvsCvalEl2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `CNTHVS_CVAL_EL2` system register.
+    pub fn write_cnthvs_cval_el2(&self, value: CnthvsCvalEl2) {
+        self.0.write(SysregId::CnthvsCvalEl2, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `CNTHVS_TVAL` system register.
+    pub fn read_cnthvs_tval(&self) -> CnthvsTval {
+        CnthvsTval::from_bits_retain(self.0.read(SysregId::CnthvsTval) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `CNTHVS_TVAL` system register.
+    pub fn write_cnthvs_tval(&self, value: CnthvsTval) {
+        self.0.write(SysregId::CnthvsTval, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `CNTHVS_TVAL_EL2` system register.
+    pub fn read_cnthvs_tval_el2(&self) -> CnthvsTvalEl2 {
+        CnthvsTvalEl2::from_bits_retain(self.0.read(SysregId::CnthvsTvalEl2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `CNTHVS_TVAL_EL2` system register.
+    pub fn write_cnthvs_tval_el2(&self, value: CnthvsTvalEl2) {
+        self.0.write(SysregId::CnthvsTvalEl2, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `CNTHV_CTL` system register.
+    pub fn read_cnthv_ctl(&self) -> CnthvCtl {
+        CnthvCtl::from_bits_retain(self.0.read(SysregId::CnthvCtl) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `CNTHV_CTL` system register.
+    pub fn write_cnthv_ctl(&self, value: CnthvCtl) {
+        self.0.write(SysregId::CnthvCtl, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `CNTHV_CTL_EL2` system register.
+    pub fn read_cnthv_ctl_el2(&self) -> CnthvCtlEl2 {
+        CnthvCtlEl2::from_bits_retain(self.0.read(SysregId::CnthvCtlEl2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `CNTHV_CTL_EL2` system register.
+    pub fn write_cnthv_ctl_el2(&self, value: CnthvCtlEl2) {
+        self.0.write(SysregId::CnthvCtlEl2, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `CNTHV_CVAL` system register.
+    pub fn read_cnthv_cval(&self) -> CnthvCval {
+        CnthvCval::from_bits_retain(self.0.read(SysregId::CnthvCval))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `CNTHV_CVAL` system register.
+    pub fn write_cnthv_cval(&self, value: CnthvCval) {
+        self.0.write(SysregId::CnthvCval, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `CNTHV_CVAL_EL2` system register.
+    pub fn read_cnthv_cval_el2(&self) -> CnthvCvalEl2 {
+        CnthvCvalEl2::from_bits_retain(self.0.read(SysregId::CnthvCvalEl2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `CNTHV_CVAL_EL2` system register.
+    pub fn write_cnthv_cval_el2(&self, value: CnthvCvalEl2) {
+        self.0.write(SysregId::CnthvCvalEl2, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `CNTHV_TVAL` system register.
+    pub fn read_cnthv_tval(&self) -> CnthvTval {
+        CnthvTval::from_bits_retain(self.0.read(SysregId::CnthvTval) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `CNTHV_TVAL` system register.
+    pub fn write_cnthv_tval(&self, value: CnthvTval) {
+        self.0.write(SysregId::CnthvTval, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `CNTHV_TVAL_EL2` system register.
+    pub fn read_cnthv_tval_el2(&self) -> CnthvTvalEl2 {
+        CnthvTvalEl2::from_bits_retain(self.0.read(SysregId::CnthvTvalEl2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `CNTHV_TVAL_EL2` system register.
+    pub fn write_cnthv_tval_el2(&self, value: CnthvTvalEl2) {
+        self.0.write(SysregId::CnthvTvalEl2, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `CNTKCTL` system register.
+    pub fn read_cntkctl(&self) -> Cntkctl {
+        Cntkctl::from_bits_retain(self.0.read(SysregId::Cntkctl) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `CNTKCTL` system register.
+    pub fn write_cntkctl(&self, value: Cntkctl) {
+        self.0.write(SysregId::Cntkctl, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `CNTKCTL_EL1` system register.
+    pub fn read_cntkctl_el1(&self) -> CntkctlEl1 {
+        CntkctlEl1::from_bits_retain(self.0.read(SysregId::CntkctlEl1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `CNTKCTL_EL1` system register.
+    pub fn write_cntkctl_el1(&self, value: CntkctlEl1) {
+        self.0.write(SysregId::CntkctlEl1, value.bits());
+    }
+
+    /// Returns the value of the `CNTPCT` system register.
+    pub fn read_cntpct(&self) -> Cntpct {
+        Cntpct::from_bits_retain(self.0.read(SysregId::Cntpct))
+    }
+
+    /// Returns the value of the `CNTPCTSS` system register.
+    pub fn read_cntpctss(&self) -> Cntpctss {
+        Cntpctss::from_bits_retain(self.0.read(SysregId::Cntpctss))
+    }
+
+    /// Returns the value of the `CNTPCTSS_EL0` system register.
+    pub fn read_cntpctss_el0(&self) -> CntpctssEl0 {
+        CntpctssEl0::from_bits_retain(self.0.read(SysregId::CntpctssEl0))
+    }
+
+    /// Returns the value of the `CNTPCT_EL0` system register.
+    pub fn read_cntpct_el0(&self) -> CntpctEl0 {
+        CntpctEl0::from_bits_retain(self.0.read(SysregId::CntpctEl0))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `CNTPOFF_EL2` system register.
+    pub fn read_cntpoff_el2(&self) -> CntpoffEl2 {
+        CntpoffEl2::from_bits_retain(self.0.read(SysregId::CntpoffEl2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `CNTPOFF_EL2` system register.
+    pub fn write_cntpoff_el2(&self, value: CntpoffEl2) {
+        self.0.write(SysregId::CntpoffEl2, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `CNTPS_CTL_EL1` system register.
+    pub fn read_cntps_ctl_el1(&self) -> CntpsCtlEl1 {
+        CntpsCtlEl1::from_bits_retain(self.0.read(SysregId::CntpsCtlEl1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `CNTPS_CTL_EL1` system register.
+    pub fn write_cntps_ctl_el1(&self, value: CntpsCtlEl1) {
+        self.0.write(SysregId::CntpsCtlEl1, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `CNTPS_CVAL_EL1` system register.
+    pub fn read_cntps_cval_el1(&self) -> CntpsCvalEl1 {
+        CntpsCvalEl1::from_bits_retain(self.0.read(SysregId::CntpsCvalEl1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `CNTPS_CVAL_EL1` system register.
+    pub fn write_cntps_cval_el1(&self, value: CntpsCvalEl1) {
+        self.0.write(SysregId::CntpsCvalEl1, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `CNTPS_TVAL_EL1` system register.
+    pub fn read_cntps_tval_el1(&self) -> CntpsTvalEl1 {
+        CntpsTvalEl1::from_bits_retain(self.0.read(SysregId::CntpsTvalEl1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `CNTPS_TVAL_EL1` system register.
+    pub fn write_cntps_tval_el1(&self, value: CntpsTvalEl1) {
+        self.0.write(SysregId::CntpsTvalEl1, value.bits());
+    }
+
+    /// Returns the value of the `CNTP_CTL` system register.
+    pub fn read_cntp_ctl(&self) -> CntpCtl {
+        CntpCtl::from_bits_retain(self.0.read(SysregId::CntpCtl) as u32)
+    }
+
+    /// Writes `value` to the `CNTP_CTL` system register.
+    pub fn write_cntp_ctl(&self, value: CntpCtl) {
+        self.0.write(SysregId::CntpCtl, u64::from(value.bits()));
+    }
+
+    /// Returns the value of the `CNTP_CTL_EL0` system register.
+    pub fn read_cntp_ctl_el0(&self) -> CntpCtlEl0 {
+        CntpCtlEl0::from_bits_retain(self.0.read(SysregId::CntpCtlEl0))
+    }
+
+    /// Writes `value` to the `CNTP_CTL_EL0` system register.
+    pub fn write_cntp_ctl_el0(&self, value: CntpCtlEl0) {
+        self.0.write(SysregId::CntpCtlEl0, value.bits());
+    }
+
+    /// Returns the value of the `CNTP_CVAL` system register.
+    pub fn read_cntp_cval(&self) -> CntpCval {
+        CntpCval::from_bits_retain(self.0.read(SysregId::CntpCval))
+    }
+
+    /// Writes `value` to the `CNTP_CVAL` system register.
+    pub fn write_cntp_cval(&self, value: CntpCval) {
+        self.0.write(SysregId::CntpCval, value.bits());
+    }
+
+    /// Returns the value of the `CNTP_CVAL_EL0` system register.
+    pub fn read_cntp_cval_el0(&self) -> CntpCvalEl0 {
+        CntpCvalEl0::from_bits_retain(self.0.read(SysregId::CntpCvalEl0))
+    }
+
+    /// Writes `value` to the `CNTP_CVAL_EL0` system register.
+    pub fn write_cntp_cval_el0(&self, value: CntpCvalEl0) {
+        self.0.write(SysregId::CntpCvalEl0, value.bits());
+    }
+
+    /// Returns the value of the `CNTP_TVAL` system register.
+    pub fn read_cntp_tval(&self) -> CntpTval {
+        CntpTval::from_bits_retain(self.0.read(SysregId::CntpTval) as u32)
+    }
+
+    /// Writes `value` to the `CNTP_TVAL` system register.
+    pub fn write_cntp_tval(&self, value: CntpTval) {
+        self.0.write(SysregId::CntpTval, u64::from(value.bits()));
+    }
+
+    /// Returns the value of the `CNTP_TVAL_EL0` system register.
+    pub fn read_cntp_tval_el0(&self) -> CntpTvalEl0 {
+        CntpTvalEl0::from_bits_retain(self.0.read(SysregId::CntpTvalEl0))
+    }
+
+    /// Writes `value` to the `CNTP_TVAL_EL0` system register.
+    pub fn write_cntp_tval_el0(&self, value: CntpTvalEl0) {
+        self.0.write(SysregId::CntpTvalEl0, value.bits());
+    }
+
+    /// Returns the value of the `CNTVCT` system register.
+    pub fn read_cntvct(&self) -> Cntvct {
+        Cntvct::from_bits_retain(self.0.read(SysregId::Cntvct))
+    }
+
+    /// Returns the value of the `CNTVCTSS` system register.
+    pub fn read_cntvctss(&self) -> Cntvctss {
+        Cntvctss::from_bits_retain(self.0.read(SysregId::Cntvctss))
+    }
+
+    /// Returns the value of the `CNTVCTSS_EL0` system register.
+    pub fn read_cntvctss_el0(&self) -> CntvctssEl0 {
+        CntvctssEl0::from_bits_retain(self.0.read(SysregId::CntvctssEl0))
+    }
+
+    /// Returns the value of the `CNTVCT_EL0` system register.
+    pub fn read_cntvct_el0(&self) -> CntvctEl0 {
+        CntvctEl0::from_bits_retain(self.0.read(SysregId::CntvctEl0))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `CNTVOFF` system register.
+    pub fn read_cntvoff(&self) -> Cntvoff {
+        Cntvoff::from_bits_retain(self.0.read(SysregId::Cntvoff))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `CNTVOFF` system register.
+    pub fn write_cntvoff(&self, value: Cntvoff) {
+        self.0.write(SysregId::Cntvoff, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `CNTVOFF_EL2` system register.
+    pub fn read_cntvoff_el2(&self) -> CntvoffEl2 {
+        CntvoffEl2::from_bits_retain(self.0.read(SysregId::CntvoffEl2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `CNTVOFF_EL2` system register.
+    pub fn write_cntvoff_el2(&self, value: CntvoffEl2) {
+        self.0.write(SysregId::CntvoffEl2, value.bits());
+    }
+
+    /// Returns the value of the `CNTV_CTL` system register.
+    pub fn read_cntv_ctl(&self) -> CntvCtl {
+        CntvCtl::from_bits_retain(self.0.read(SysregId::CntvCtl) as u32)
+    }
+
+    /// Writes `value` to the `CNTV_CTL` system register.
+    pub fn write_cntv_ctl(&self, value: CntvCtl) {
+        self.0.write(SysregId::CntvCtl, u64::from(value.bits()));
+    }
+
+    /// Returns the value of the `CNTV_CTL_EL0` system register.
+    pub fn read_cntv_ctl_el0(&self) -> CntvCtlEl0 {
+        CntvCtlEl0::from_bits_retain(self.0.read(SysregId::CntvCtlEl0))
+    }
+
+    /// Writes `value` to the `CNTV_CTL_EL0` system register.
+    pub fn write_cntv_ctl_el0(&self, value: CntvCtlEl0) {
+        self.0.write(SysregId::CntvCtlEl0, value.bits());
+    }
+
+    /// Returns the value of the `CNTV_CVAL` system register.
+    pub fn read_cntv_cval(&self) -> CntvCval {
+        CntvCval::from_bits_retain(self.0.read(SysregId::CntvCval))
+    }
+
+    /// Writes `value` to the `CNTV_CVAL` system register.
+    pub fn write_cntv_cval(&self, value: CntvCval) {
+        self.0.write(SysregId::CntvCval, value.bits());
+    }
+
+    /// Returns the value of the `CNTV_CVAL_EL0` system register.
+    pub fn read_cntv_cval_el0(&self) -> CntvCvalEl0 {
+        CntvCvalEl0::from_bits_retain(self.0.read(SysregId::CntvCvalEl0))
+    }
+
+    /// Writes `value` to the `CNTV_CVAL_EL0` system register.
+    pub fn write_cntv_cval_el0(&self, value: CntvCvalEl0) {
+        self.0.write(SysregId::CntvCvalEl0, value.bits());
+    }
+
+    /// Returns the value of the `CNTV_TVAL` system register.
+    pub fn read_cntv_tval(&self) -> CntvTval {
+        CntvTval::from_bits_retain(self.0.read(SysregId::CntvTval) as u32)
+    }
+
+    /// Writes `value` to the `CNTV_TVAL` system register.
+    pub fn write_cntv_tval(&self, value: CntvTval) {
+        self.0.write(SysregId::CntvTval, u64::from(value.bits()));
+    }
+
+    /// Returns the value of the `CNTV_TVAL_EL0` system register.
+    pub fn read_cntv_tval_el0(&self) -> CntvTvalEl0 {
+        CntvTvalEl0::from_bits_retain(self.0.read(SysregId::CntvTvalEl0))
+    }
+
+    /// Writes `value` to the `CNTV_TVAL_EL0` system register.
+    pub fn write_cntv_tval_el0(&self, value: CntvTvalEl0) {
+        self.0.write(SysregId::CntvTvalEl0, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `CONTEXTIDR` system register.
+    pub fn read_contextidr(&self) -> Contextidr {
+        Contextidr::from_bits_retain(self.0.read(SysregId::Contextidr) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `CONTEXTIDR` system register.
+    pub fn write_contextidr(&self, value: Contextidr) {
+        self.0.write(SysregId::Contextidr, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `CONTEXTIDR_EL1` system register.
+    pub fn read_contextidr_el1(&self) -> ContextidrEl1 {
+        ContextidrEl1::from_bits_retain(self.0.read(SysregId::ContextidrEl1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `CONTEXTIDR_EL1` system register.
+    pub fn write_contextidr_el1(&self, value: ContextidrEl1) {
+        self.0.write(SysregId::ContextidrEl1, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `CONTEXTIDR_EL2` system register.
+    pub fn read_contextidr_el2(&self) -> ContextidrEl2 {
+        ContextidrEl2::from_bits_retain(self.0.read(SysregId::ContextidrEl2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `CONTEXTIDR_EL2` system register.
+    pub fn write_contextidr_el2(&self, value: ContextidrEl2) {
+        self.0.write(SysregId::ContextidrEl2, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `CPACR` system register.
+    pub fn read_cpacr(&self) -> Cpacr {
+        Cpacr::from_bits_retain(self.0.read(SysregId::Cpacr) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `CPACR` system register.
+    pub fn write_cpacr(&self, value: Cpacr) {
+        self.0.write(SysregId::Cpacr, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `CPACR_EL1` system register.
+    pub fn read_cpacr_el1(&self) -> CpacrEl1 {
+        CpacrEl1::from_bits_retain(self.0.read(SysregId::CpacrEl1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `CPACR_EL1` system register.
+    pub fn write_cpacr_el1(&self, value: CpacrEl1) {
+        self.0.write(SysregId::CpacrEl1, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `CPTR_EL2` system register.
+    pub fn read_cptr_el2(&self) -> CptrEl2 {
+        CptrEl2::from_bits_retain(self.0.read(SysregId::CptrEl2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `CPTR_EL2` system register.
+    pub fn write_cptr_el2(&self, value: CptrEl2) {
+        self.0.write(SysregId::CptrEl2, value.bits());
+    }
+
+    #[cfg(feature = "el3")]
+    /// Returns the value of the `CPTR_EL3` system register.
+    pub fn read_cptr_el3(&self) -> CptrEl3 {
+        CptrEl3::from_bits_retain(self.0.read(SysregId::CptrEl3))
+    }
+
+    #[cfg(feature = "el3")]
+    /// Writes `value` to the `CPTR_EL3` system register.
+    pub fn write_cptr_el3(&self, value: CptrEl3) {
+        self.0.write(SysregId::CptrEl3, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `CSSELR` system register.
+    pub fn read_csselr(&self) -> Csselr {
+        Csselr::from_bits_retain(self.0.read(SysregId::Csselr) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `CSSELR` system register.
+    pub fn write_csselr(&self, value: Csselr) {
+        self.0.write(SysregId::Csselr, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `CSSELR_EL1` system register.
+    pub fn read_csselr_el1(&self) -> CsselrEl1 {
+        CsselrEl1::from_bits_retain(self.0.read(SysregId::CsselrEl1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `CSSELR_EL1` system register.
+    pub fn write_csselr_el1(&self, value: CsselrEl1) {
+        self.0.write(SysregId::CsselrEl1, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `CTR` system register.
+    pub fn read_ctr(&self) -> Ctr {
+        Ctr::from_bits_retain(self.0.read(SysregId::Ctr) as u32)
+    }
+
+    /// Returns the value of the `CTR_EL0` system register.
+    pub fn read_ctr_el0(&self) -> CtrEl0 {
+        CtrEl0::from_bits_retain(self.0.read(SysregId::CtrEl0))
+    }
+
+    /// Returns the value of the `CurrentEL` system register.
+    pub fn read_currentel(&self) -> Currentel {
+        Currentel::from_bits_retain(self.0.read(SysregId::Currentel))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `DACR` system register.
+    pub fn read_dacr(&self) -> Dacr {
+        Dacr::from_bits_retain(self.0.read(SysregId::Dacr) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `DACR` system register.
+    pub fn write_dacr(&self, value: Dacr) {
+        self.0.write(SysregId::Dacr, u64::from(value.bits()));
+    }
+
+    /// Returns the value of the `DAIF` system register.
+    pub fn read_daif(&self) -> Daif {
+        Daif::from_bits_retain(self.0.read(SysregId::Daif))
+    }
+
+    /// Writes `value` to the `DAIF` system register.
+    pub fn write_daif(&self, value: Daif) {
+        self.0.write(SysregId::Daif, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `DBGAUTHSTATUS` system register.
+    pub fn read_dbgauthstatus(&self) -> Dbgauthstatus {
+        Dbgauthstatus::from_bits_retain(self.0.read(SysregId::Dbgauthstatus) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `DBGCLAIMCLR` system register.
+    pub fn read_dbgclaimclr(&self) -> Dbgclaimclr {
+        Dbgclaimclr::from_bits_retain(self.0.read(SysregId::Dbgclaimclr) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `DBGCLAIMCLR` system register.
+    pub fn write_dbgclaimclr(&self, value: Dbgclaimclr) {
+        self.0.write(SysregId::Dbgclaimclr, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `DBGCLAIMSET` system register.
+    pub fn read_dbgclaimset(&self) -> Dbgclaimset {
+        Dbgclaimset::from_bits_retain(self.0.read(SysregId::Dbgclaimset) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `DBGCLAIMSET` system register.
+    pub fn write_dbgclaimset(&self, value: Dbgclaimset) {
+        self.0.write(SysregId::Dbgclaimset, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `DBGDCCINT` system register.
+    pub fn read_dbgdccint(&self) -> Dbgdccint {
+        Dbgdccint::from_bits_retain(self.0.read(SysregId::Dbgdccint) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `DBGDCCINT` system register.
+    pub fn write_dbgdccint(&self, value: Dbgdccint) {
+        self.0.write(SysregId::Dbgdccint, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `DBGDEVID` system register.
+    pub fn read_dbgdevid(&self) -> Dbgdevid {
+        Dbgdevid::from_bits_retain(self.0.read(SysregId::Dbgdevid) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `DBGDEVID1` system register.
+    pub fn read_dbgdevid1(&self) -> Dbgdevid1 {
+        Dbgdevid1::from_bits_retain(self.0.read(SysregId::Dbgdevid1) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `DBGDEVID2` system register.
+    pub fn read_dbgdevid2(&self) -> u32 {
+        self.0.read(SysregId::Dbgdevid2) as u32
+    }
+
+    /// Returns the value of the `DBGDIDR` system register.
+    pub fn read_dbgdidr(&self) -> Dbgdidr {
+        Dbgdidr::from_bits_retain(self.0.read(SysregId::Dbgdidr) as u32)
+    }
+
+    /// Returns the value of the `DBGDRAR` system register.
+    pub fn read_dbgdrar(&self) -> Dbgdrar {
+        Dbgdrar::from_bits_retain(self.0.read(SysregId::Dbgdrar))
+    }
+
+    /// Returns the value of the `DBGDSAR` system register.
+    pub fn read_dbgdsar(&self) -> u64 {
+        self.0.read(SysregId::Dbgdsar)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `DBGDSCRext` system register.
+    pub fn read_dbgdscrext(&self) -> Dbgdscrext {
+        Dbgdscrext::from_bits_retain(self.0.read(SysregId::Dbgdscrext) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `DBGDSCRext` system register.
+    pub fn write_dbgdscrext(&self, value: Dbgdscrext) {
+        self.0.write(SysregId::Dbgdscrext, u64::from(value.bits()));
+    }
+
+    /// Returns the value of the `DBGDSCRint` system register.
+    pub fn read_dbgdscrint(&self) -> Dbgdscrint {
+        Dbgdscrint::from_bits_retain(self.0.read(SysregId::Dbgdscrint) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `DBGDTRRXext` system register.
+    pub fn read_dbgdtrrxext(&self) -> Dbgdtrrxext {
+        Dbgdtrrxext::from_bits_retain(self.0.read(SysregId::Dbgdtrrxext) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `DBGDTRRXext` system register.
+    pub fn write_dbgdtrrxext(&self, value: Dbgdtrrxext) {
+        self.0.write(SysregId::Dbgdtrrxext, u64::from(value.bits()));
+    }
+
+    /// Returns the value of the `DBGDTRRXint` system register.
+    pub fn read_dbgdtrrxint(&self) -> Dbgdtrrxint {
+        Dbgdtrrxint::from_bits_retain(self.0.read(SysregId::Dbgdtrrxint) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `DBGDTRTXext` system register.
+    pub fn read_dbgdtrtxext(&self) -> Dbgdtrtxext {
+        Dbgdtrtxext::from_bits_retain(self.0.read(SysregId::Dbgdtrtxext) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `DBGDTRTXext` system register.
+    pub fn write_dbgdtrtxext(&self, value: Dbgdtrtxext) {
+        self.0.write(SysregId::Dbgdtrtxext, u64::from(value.bits()));
+    }
+
+    /// Writes `value` to the `DBGDTRTXint` system register.
+    pub fn write_dbgdtrtxint(&self, value: Dbgdtrtxint) {
+        self.0.write(SysregId::Dbgdtrtxint, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `DBGOSDLR` system register.
+    pub fn read_dbgosdlr(&self) -> Dbgosdlr {
+        Dbgosdlr::from_bits_retain(self.0.read(SysregId::Dbgosdlr) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `DBGOSDLR` system register.
+    pub fn write_dbgosdlr(&self, value: Dbgosdlr) {
+        self.0.write(SysregId::Dbgosdlr, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `DBGOSECCR` system register.
+    pub fn read_dbgoseccr(&self) -> Dbgoseccr {
+        Dbgoseccr::from_bits_retain(self.0.read(SysregId::Dbgoseccr) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `DBGOSECCR` system register.
+    pub fn write_dbgoseccr(&self, value: Dbgoseccr) {
+        self.0.write(SysregId::Dbgoseccr, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `DBGOSLAR` system register.
+    pub fn write_dbgoslar(&self, value: Dbgoslar) {
+        self.0.write(SysregId::Dbgoslar, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `DBGOSLSR` system register.
+    pub fn read_dbgoslsr(&self) -> Dbgoslsr {
+        Dbgoslsr::from_bits_retain(self.0.read(SysregId::Dbgoslsr) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `DBGPRCR` system register.
+    pub fn read_dbgprcr(&self) -> Dbgprcr {
+        Dbgprcr::from_bits_retain(self.0.read(SysregId::Dbgprcr) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `DBGPRCR` system register.
+    pub fn write_dbgprcr(&self, value: Dbgprcr) {
+        self.0.write(SysregId::Dbgprcr, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `DBGVCR` system register.
+    pub fn read_dbgvcr(&self) -> Dbgvcr {
+        Dbgvcr::from_bits_retain(self.0.read(SysregId::Dbgvcr) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `DBGVCR` system register.
+    pub fn write_dbgvcr(&self, value: Dbgvcr) {
+        self.0.write(SysregId::Dbgvcr, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `DBGWFAR` system register.
+    pub fn read_dbgwfar(&self) -> u32 {
+        self.0.read(SysregId::Dbgwfar) as u32
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `DBGWFAR` system register.
+    pub fn write_dbgwfar(&self, value: u32) {
+        self.0.write(SysregId::Dbgwfar, u64::from(value));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `DFAR` system register.
+    pub fn read_dfar(&self) -> Dfar {
+        Dfar::from_bits_retain(self.0.read(SysregId::Dfar) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `DFAR` system register.
+    pub fn write_dfar(&self, value: Dfar) {
+        self.0.write(SysregId::Dfar, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `DFSR` system register.
+    pub fn read_dfsr(&self) -> Dfsr {
+        Dfsr::from_bits_retain(self.0.read(SysregId::Dfsr) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `DFSR` system register.
+    pub fn write_dfsr(&self, value: Dfsr) {
+        self.0.write(SysregId::Dfsr, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `DISR` system register.
+    pub fn read_disr(&self) -> Disr {
+        Disr::from_bits_retain(self.0.read(SysregId::Disr) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `DISR` system register.
+    pub fn write_disr(&self, value: Disr) {
+        self.0.write(SysregId::Disr, u64::from(value.bits()));
+    }
+
+    #[cfg(all(feature = "el1", feature = "feat-ras"))]
+    /// Returns the value of the `DISR_EL1` system register.
+    pub fn read_disr_el1(&self) -> DisrEl1 {
+        DisrEl1::from_bits_retain(self.0.read(SysregId::DisrEl1))
+    }
+
+    #[cfg(all(feature = "el1", feature = "feat-ras"))]
+    /// Writes `value` to the `DISR_EL1` system register.
+    pub fn write_disr_el1(&self, value: DisrEl1) {
+        self.0.write(SysregId::DisrEl1, value.bits());
+    }
+
+    /// Returns the value of the `DIT` system register.
+    pub fn read_dit(&self) -> Dit {
+        Dit::from_bits_retain(self.0.read(SysregId::Dit))
+    }
+
+    /// Writes `value` to the `DIT` system register.
+    pub fn write_dit(&self, value: Dit) {
+        self.0.write(SysregId::Dit, value.bits());
+    }
+
+    /// Returns the value of the `DLR` system register.
+    pub fn read_dlr(&self) -> Dlr {
+        Dlr::from_bits_retain(self.0.read(SysregId::Dlr) as u32)
+    }
+
+    /// Writes `value` to the `DLR` system register.
+    pub fn write_dlr(&self, value: Dlr) {
+        self.0.write(SysregId::Dlr, u64::from(value.bits()));
+    }
+
+    /// Returns the value of the `DSPSR` system register.
+    pub fn read_dspsr(&self) -> Dspsr {
+        Dspsr::from_bits_retain(self.0.read(SysregId::Dspsr) as u32)
+    }
+
+    /// Writes `value` to the `DSPSR` system register.
+    pub fn write_dspsr(&self, value: Dspsr) {
+        self.0.write(SysregId::Dspsr, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `DSPSR2` system register.
+    pub fn read_dspsr2(&self) -> Dspsr2 {
+        Dspsr2::from_bits_retain(self.0.read(SysregId::Dspsr2) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `DSPSR2` system register.
+    pub fn write_dspsr2(&self, value: Dspsr2) {
+        self.0.write(SysregId::Dspsr2, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ELR_EL1` system register.
+    pub fn read_elr_el1(&self) -> ElrEl1 {
+        ElrEl1::from_bits_retain(self.0.read(SysregId::ElrEl1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `ELR_EL1` system register.
+    pub fn write_elr_el1(&self, value: ElrEl1) {
+        self.0.write(SysregId::ElrEl1, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `ELR_EL2` system register.
+    pub fn read_elr_el2(&self) -> ElrEl2 {
+        ElrEl2::from_bits_retain(self.0.read(SysregId::ElrEl2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `ELR_EL2` system register.
+    pub fn write_elr_el2(&self, value: ElrEl2) {
+        self.0.write(SysregId::ElrEl2, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `ELR_hyp` system register.
+    pub fn read_elr_hyp(&self) -> ElrHyp {
+        ElrHyp::from_bits_retain(self.0.read(SysregId::ElrHyp) as u32)
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `ELR_hyp` system register.
+    pub fn write_elr_hyp(&self, value: ElrHyp) {
+        self.0.write(SysregId::ElrHyp, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ERRIDR` system register.
+    pub fn read_erridr(&self) -> Erridr {
+        Erridr::from_bits_retain(self.0.read(SysregId::Erridr) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ERRSELR` system register.
+    pub fn read_errselr(&self) -> Errselr {
+        Errselr::from_bits_retain(self.0.read(SysregId::Errselr) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `ERRSELR` system register.
+    pub fn write_errselr(&self, value: Errselr) {
+        self.0.write(SysregId::Errselr, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ERXADDR` system register.
+    pub fn read_erxaddr(&self) -> Erxaddr {
+        Erxaddr::from_bits_retain(self.0.read(SysregId::Erxaddr) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `ERXADDR` system register.
+    pub fn write_erxaddr(&self, value: Erxaddr) {
+        self.0.write(SysregId::Erxaddr, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ERXADDR2` system register.
+    pub fn read_erxaddr2(&self) -> Erxaddr2 {
+        Erxaddr2::from_bits_retain(self.0.read(SysregId::Erxaddr2) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `ERXADDR2` system register.
+    pub fn write_erxaddr2(&self, value: Erxaddr2) {
+        self.0.write(SysregId::Erxaddr2, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ERXCTLR` system register.
+    pub fn read_erxctlr(&self) -> Erxctlr {
+        Erxctlr::from_bits_retain(self.0.read(SysregId::Erxctlr) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `ERXCTLR` system register.
+    pub fn write_erxctlr(&self, value: Erxctlr) {
+        self.0.write(SysregId::Erxctlr, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ERXCTLR2` system register.
+    pub fn read_erxctlr2(&self) -> Erxctlr2 {
+        Erxctlr2::from_bits_retain(self.0.read(SysregId::Erxctlr2) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `ERXCTLR2` system register.
+    pub fn write_erxctlr2(&self, value: Erxctlr2) {
+        self.0.write(SysregId::Erxctlr2, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ERXFR` system register.
+    pub fn read_erxfr(&self) -> Erxfr {
+        Erxfr::from_bits_retain(self.0.read(SysregId::Erxfr) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ERXFR2` system register.
+    pub fn read_erxfr2(&self) -> Erxfr2 {
+        Erxfr2::from_bits_retain(self.0.read(SysregId::Erxfr2) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ERXMISC0` system register.
+    pub fn read_erxmisc0(&self) -> Erxmisc0 {
+        Erxmisc0::from_bits_retain(self.0.read(SysregId::Erxmisc0) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `ERXMISC0` system register.
+    pub fn write_erxmisc0(&self, value: Erxmisc0) {
+        self.0.write(SysregId::Erxmisc0, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ERXMISC1` system register.
+    pub fn read_erxmisc1(&self) -> Erxmisc1 {
+        Erxmisc1::from_bits_retain(self.0.read(SysregId::Erxmisc1) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `ERXMISC1` system register.
+    pub fn write_erxmisc1(&self, value: Erxmisc1) {
+        self.0.write(SysregId::Erxmisc1, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ERXMISC2` system register.
+    pub fn read_erxmisc2(&self) -> Erxmisc2 {
+        Erxmisc2::from_bits_retain(self.0.read(SysregId::Erxmisc2) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `ERXMISC2` system register.
+    pub fn write_erxmisc2(&self, value: Erxmisc2) {
+        self.0.write(SysregId::Erxmisc2, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ERXMISC3` system register.
+    pub fn read_erxmisc3(&self) -> Erxmisc3 {
+        Erxmisc3::from_bits_retain(self.0.read(SysregId::Erxmisc3) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `ERXMISC3` system register.
+    pub fn write_erxmisc3(&self, value: Erxmisc3) {
+        self.0.write(SysregId::Erxmisc3, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ERXMISC4` system register.
+    pub fn read_erxmisc4(&self) -> Erxmisc4 {
+        Erxmisc4::from_bits_retain(self.0.read(SysregId::Erxmisc4) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `ERXMISC4` system register.
+    pub fn write_erxmisc4(&self, value: Erxmisc4) {
+        self.0.write(SysregId::Erxmisc4, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ERXMISC5` system register.
+    pub fn read_erxmisc5(&self) -> Erxmisc5 {
+        Erxmisc5::from_bits_retain(self.0.read(SysregId::Erxmisc5) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `ERXMISC5` system register.
+    pub fn write_erxmisc5(&self, value: Erxmisc5) {
+        self.0.write(SysregId::Erxmisc5, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ERXMISC6` system register.
+    pub fn read_erxmisc6(&self) -> Erxmisc6 {
+        Erxmisc6::from_bits_retain(self.0.read(SysregId::Erxmisc6) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `ERXMISC6` system register.
+    pub fn write_erxmisc6(&self, value: Erxmisc6) {
+        self.0.write(SysregId::Erxmisc6, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ERXMISC7` system register.
+    pub fn read_erxmisc7(&self) -> Erxmisc7 {
+        Erxmisc7::from_bits_retain(self.0.read(SysregId::Erxmisc7) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `ERXMISC7` system register.
+    pub fn write_erxmisc7(&self, value: Erxmisc7) {
+        self.0.write(SysregId::Erxmisc7, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ERXSTATUS` system register.
+    pub fn read_erxstatus(&self) -> Erxstatus {
+        Erxstatus::from_bits_retain(self.0.read(SysregId::Erxstatus) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `ERXSTATUS` system register.
+    pub fn write_erxstatus(&self, value: Erxstatus) {
+        self.0.write(SysregId::Erxstatus, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ESR_EL1` system register.
+    pub fn read_esr_el1(&self) -> EsrEl1 {
+        EsrEl1::from_bits_retain(self.0.read(SysregId::EsrEl1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `ESR_EL1` system register.
+    pub fn write_esr_el1(&self, value: EsrEl1) {
+        self.0.write(SysregId::EsrEl1, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `ESR_EL2` system register.
+    pub fn read_esr_el2(&self) -> EsrEl2 {
+        EsrEl2::from_bits_retain(self.0.read(SysregId::EsrEl2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `ESR_EL2` system register.
+    pub fn write_esr_el2(&self, value: EsrEl2) {
+        self.0.write(SysregId::EsrEl2, value.bits());
+    }
+
+    #[cfg(feature = "el3")]
+    /// Returns the value of the `ESR_EL3` system register.
+    pub fn read_esr_el3(&self) -> EsrEl3 {
+        EsrEl3::from_bits_retain(self.0.read(SysregId::EsrEl3))
+    }
+
+    #[cfg(feature = "el3")]
+    /// Writes `value` to the `ESR_EL3` system register.
+    pub fn write_esr_el3(&self, value: EsrEl3) {
+        self.0.write(SysregId::EsrEl3, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `FAR_EL1` system register.
+    pub fn read_far_el1(&self) -> FarEl1 {
+        FarEl1::from_bits_retain(self.0.read(SysregId::FarEl1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `FAR_EL1` system register.
+    pub fn write_far_el1(&self, value: FarEl1) {
+        self.0.write(SysregId::FarEl1, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `FAR_EL2` system register.
+    pub fn read_far_el2(&self) -> FarEl2 {
+        FarEl2::from_bits_retain(self.0.read(SysregId::FarEl2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `FAR_EL2` system register.
+    pub fn write_far_el2(&self, value: FarEl2) {
+        self.0.write(SysregId::FarEl2, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `FCSEIDR` system register.
+    pub fn read_fcseidr(&self) -> u32 {
+        self.0.read(SysregId::Fcseidr) as u32
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `FCSEIDR` system register.
+    pub fn write_fcseidr(&self, value: u32) {
+        self.0.write(SysregId::Fcseidr, u64::from(value));
+    }
+
+    /// Returns the value of the `FPCR` system register.
+    pub fn read_fpcr(&self) -> Fpcr {
+        Fpcr::from_bits_retain(self.0.read(SysregId::Fpcr))
+    }
+
+    /// Writes `value` to the `FPCR` system register.
+    pub fn write_fpcr(&self, value: Fpcr) {
+        self.0.write(SysregId::Fpcr, value.bits());
+    }
+
+    /// Returns the value of the `FPMR` system register.
+    pub fn read_fpmr(&self) -> Fpmr {
+        Fpmr::from_bits_retain(self.0.read(SysregId::Fpmr))
+    }
+
+    /// Writes `value` to the `FPMR` system register.
+    pub fn write_fpmr(&self, value: Fpmr) {
+        self.0.write(SysregId::Fpmr, value.bits());
+    }
+
+    /// Returns the value of the `FPSR` system register.
+    pub fn read_fpsr(&self) -> Fpsr {
+        Fpsr::from_bits_retain(self.0.read(SysregId::Fpsr))
+    }
+
+    /// Writes `value` to the `FPSR` system register.
+    pub fn write_fpsr(&self, value: Fpsr) {
+        self.0.write(SysregId::Fpsr, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `GCR_EL1` system register.
+    pub fn read_gcr_el1(&self) -> GcrEl1 {
+        GcrEl1::from_bits_retain(self.0.read(SysregId::GcrEl1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `GCR_EL1` system register.
+    pub fn write_gcr_el1(&self, value: GcrEl1) {
+        self.0.write(SysregId::GcrEl1, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `GCSCR_EL1` system register.
+    pub fn read_gcscr_el1(&self) -> GcscrEl1 {
+        GcscrEl1::from_bits_retain(self.0.read(SysregId::GcscrEl1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `GCSCR_EL1` system register.
+    pub fn write_gcscr_el1(&self, value: GcscrEl1) {
+        self.0.write(SysregId::GcscrEl1, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `GCSCR_EL2` system register.
+    pub fn read_gcscr_el2(&self) -> GcscrEl2 {
+        GcscrEl2::from_bits_retain(self.0.read(SysregId::GcscrEl2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `GCSCR_EL2` system register.
+    pub fn write_gcscr_el2(&self, value: GcscrEl2) {
+        self.0.write(SysregId::GcscrEl2, value.bits());
+    }
+
+    #[cfg(all(feature = "el3", feature = "feat-rme"))]
+    /// Returns the value of the `GPCCR_EL3` system register.
+    pub fn read_gpccr_el3(&self) -> GpccrEl3 {
+        GpccrEl3::from_bits_retain(self.0.read(SysregId::GpccrEl3))
+    }
+
+    #[cfg(all(feature = "el3", feature = "feat-rme"))]
+    /// Writes `value` to the `GPCCR_EL3` system register.
+    pub fn write_gpccr_el3(&self, value: GpccrEl3) {
+        self.0.write(SysregId::GpccrEl3, value.bits());
+    }
+
+    #[cfg(all(feature = "el3", feature = "feat-rme"))]
+    /// Returns the value of the `GPTBR_EL3` system register.
+    pub fn read_gptbr_el3(&self) -> GptbrEl3 {
+        GptbrEl3::from_bits_retain(self.0.read(SysregId::GptbrEl3))
+    }
+
+    #[cfg(all(feature = "el3", feature = "feat-rme"))]
+    /// Writes `value` to the `GPTBR_EL3` system register.
+    pub fn write_gptbr_el3(&self, value: GptbrEl3) {
+        self.0.write(SysregId::GptbrEl3, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `HACR` system register.
+    pub fn read_hacr(&self) -> u32 {
+        self.0.read(SysregId::Hacr) as u32
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `HACR` system register.
+    pub fn write_hacr(&self, value: u32) {
+        self.0.write(SysregId::Hacr, u64::from(value));
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `HACR_EL2` system register.
+    pub fn read_hacr_el2(&self) -> u64 {
+        self.0.read(SysregId::HacrEl2)
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `HACR_EL2` system register.
+    pub fn write_hacr_el2(&self, value: u64) {
+        self.0.write(SysregId::HacrEl2, value);
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `HACTLR` system register.
+    pub fn read_hactlr(&self) -> u32 {
+        self.0.read(SysregId::Hactlr) as u32
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `HACTLR` system register.
+    pub fn write_hactlr(&self, value: u32) {
+        self.0.write(SysregId::Hactlr, u64::from(value));
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `HACTLR2` system register.
+    pub fn read_hactlr2(&self) -> u32 {
+        self.0.read(SysregId::Hactlr2) as u32
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `HACTLR2` system register.
+    pub fn write_hactlr2(&self, value: u32) {
+        self.0.write(SysregId::Hactlr2, u64::from(value));
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `HADFSR` system register.
+    pub fn read_hadfsr(&self) -> u32 {
+        self.0.read(SysregId::Hadfsr) as u32
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `HADFSR` system register.
+    pub fn write_hadfsr(&self, value: u32) {
+        self.0.write(SysregId::Hadfsr, u64::from(value));
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `HAFGRTR_EL2` system register.
+    pub fn read_hafgrtr_el2(&self) -> HafgrtrEl2 {
+        HafgrtrEl2::from_bits_retain(self.0.read(SysregId::HafgrtrEl2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `HAFGRTR_EL2` system register.
+    pub fn write_hafgrtr_el2(&self, value: HafgrtrEl2) {
+        self.0.write(SysregId::HafgrtrEl2, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `HAIFSR` system register.
+    pub fn read_haifsr(&self) -> u32 {
+        self.0.read(SysregId::Haifsr) as u32
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `HAIFSR` system register.
+    pub fn write_haifsr(&self, value: u32) {
+        self.0.write(SysregId::Haifsr, u64::from(value));
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `HAMAIR0` system register.
+    pub fn read_hamair0(&self) -> u32 {
+        self.0.read(SysregId::Hamair0) as u32
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `HAMAIR0` system register.
+    pub fn write_hamair0(&self, value: u32) {
+        self.0.write(SysregId::Hamair0, u64::from(value));
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `HAMAIR1` system register.
+    pub fn read_hamair1(&self) -> u32 {
+        self.0.read(SysregId::Hamair1) as u32
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `HAMAIR1` system register.
+    pub fn write_hamair1(&self, value: u32) {
+        self.0.write(SysregId::Hamair1, u64::from(value));
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `HCPTR` system register.
+    pub fn read_hcptr(&self) -> Hcptr {
+        Hcptr::from_bits_retain(self.0.read(SysregId::Hcptr) as u32)
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `HCPTR` system register.
+    pub fn write_hcptr(&self, value: Hcptr) {
+        self.0.write(SysregId::Hcptr, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `HCR` system register.
+    pub fn read_hcr(&self) -> Hcr {
+        Hcr::from_bits_retain(self.0.read(SysregId::Hcr) as u32)
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `HCR` system register.
+    pub fn write_hcr(&self, value: Hcr) {
+        self.0.write(SysregId::Hcr, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `HCR2` system register.
+    pub fn read_hcr2(&self) -> Hcr2 {
+        Hcr2::from_bits_retain(self.0.read(SysregId::Hcr2) as u32)
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `HCR2` system register.
+    pub fn write_hcr2(&self, value: Hcr2) {
+        self.0.write(SysregId::Hcr2, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `HCRX_EL2` system register.
+    pub fn read_hcrx_el2(&self) -> HcrxEl2 {
+        HcrxEl2::from_bits_retain(self.0.read(SysregId::HcrxEl2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `HCRX_EL2` system register.
+    pub fn write_hcrx_el2(&self, value: HcrxEl2) {
+        self.0.write(SysregId::HcrxEl2, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `HCR_EL2` system register.
+    pub fn read_hcr_el2(&self) -> HcrEl2 {
+        HcrEl2::from_bits_retain(self.0.read(SysregId::HcrEl2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `HCR_EL2` system register.
+    pub fn write_hcr_el2(&self, value: HcrEl2) {
+        self.0.write(SysregId::HcrEl2, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `HDCR` system register.
+    pub fn read_hdcr(&self) -> Hdcr {
+        Hdcr::from_bits_retain(self.0.read(SysregId::Hdcr) as u32)
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `HDCR` system register.
+    pub fn write_hdcr(&self, value: Hdcr) {
+        self.0.write(SysregId::Hdcr, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `HDFAR` system register.
+    pub fn read_hdfar(&self) -> Hdfar {
+        Hdfar::from_bits_retain(self.0.read(SysregId::Hdfar) as u32)
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `HDFAR` system register.
+    pub fn write_hdfar(&self, value: Hdfar) {
+        self.0.write(SysregId::Hdfar, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `HDFGRTR2_EL2` system register.
+    pub fn read_hdfgrtr2_el2(&self) -> Hdfgrtr2El2 {
+        Hdfgrtr2El2::from_bits_retain(self.0.read(SysregId::Hdfgrtr2El2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `HDFGRTR2_EL2` system register.
+    pub fn write_hdfgrtr2_el2(&self, value: Hdfgrtr2El2) {
+        self.0.write(SysregId::Hdfgrtr2El2, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `HDFGRTR_EL2` system register.
+    pub fn read_hdfgrtr_el2(&self) -> HdfgrtrEl2 {
+        HdfgrtrEl2::from_bits_retain(self.0.read(SysregId::HdfgrtrEl2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `HDFGRTR_EL2` system register.
+    pub fn write_hdfgrtr_el2(&self, value: HdfgrtrEl2) {
+        self.0.write(SysregId::HdfgrtrEl2, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `HDFGWTR2_EL2` system register.
+    pub fn read_hdfgwtr2_el2(&self) -> Hdfgwtr2El2 {
+        Hdfgwtr2El2::from_bits_retain(self.0.read(SysregId::Hdfgwtr2El2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `HDFGWTR2_EL2` system register.
+    pub fn write_hdfgwtr2_el2(&self, value: Hdfgwtr2El2) {
+        self.0.write(SysregId::Hdfgwtr2El2, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `HDFGWTR_EL2` system register.
+    pub fn read_hdfgwtr_el2(&self) -> HdfgwtrEl2 {
+        HdfgwtrEl2::from_bits_retain(self.0.read(SysregId::HdfgwtrEl2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `HDFGWTR_EL2` system register.
+    pub fn write_hdfgwtr_el2(&self, value: HdfgwtrEl2) {
+        self.0.write(SysregId::HdfgwtrEl2, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `HFGITR2_EL2` system register.
+    pub fn read_hfgitr2_el2(&self) -> Hfgitr2El2 {
+        Hfgitr2El2::from_bits_retain(self.0.read(SysregId::Hfgitr2El2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `HFGITR2_EL2` system register.
+    pub fn write_hfgitr2_el2(&self, value: Hfgitr2El2) {
+        self.0.write(SysregId::Hfgitr2El2, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `HFGITR_EL2` system register.
+    pub fn read_hfgitr_el2(&self) -> HfgitrEl2 {
+        HfgitrEl2::from_bits_retain(self.0.read(SysregId::HfgitrEl2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `HFGITR_EL2` system register.
+    pub fn write_hfgitr_el2(&self, value: HfgitrEl2) {
+        self.0.write(SysregId::HfgitrEl2, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `HFGRTR2_EL2` system register.
+    pub fn read_hfgrtr2_el2(&self) -> Hfgrtr2El2 {
+        Hfgrtr2El2::from_bits_retain(self.0.read(SysregId::Hfgrtr2El2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `HFGRTR2_EL2` system register.
+    pub fn write_hfgrtr2_el2(&self, value: Hfgrtr2El2) {
+        self.0.write(SysregId::Hfgrtr2El2, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `HFGRTR_EL2` system register.
+    pub fn read_hfgrtr_el2(&self) -> HfgrtrEl2 {
+        HfgrtrEl2::from_bits_retain(self.0.read(SysregId::HfgrtrEl2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `HFGRTR_EL2` system register.
+    pub fn write_hfgrtr_el2(&self, value: HfgrtrEl2) {
+        self.0.write(SysregId::HfgrtrEl2, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `HFGWTR2_EL2` system register.
+    pub fn read_hfgwtr2_el2(&self) -> Hfgwtr2El2 {
+        Hfgwtr2El2::from_bits_retain(self.0.read(SysregId::Hfgwtr2El2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `HFGWTR2_EL2` system register.
+    pub fn write_hfgwtr2_el2(&self, value: Hfgwtr2El2) {
+        self.0.write(SysregId::Hfgwtr2El2, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `HFGWTR_EL2` system register.
+    pub fn read_hfgwtr_el2(&self) -> HfgwtrEl2 {
+        HfgwtrEl2::from_bits_retain(self.0.read(SysregId::HfgwtrEl2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `HFGWTR_EL2` system register.
+    pub fn write_hfgwtr_el2(&self, value: HfgwtrEl2) {
+        self.0.write(SysregId::HfgwtrEl2, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `HIFAR` system register.
+    pub fn read_hifar(&self) -> Hifar {
+        Hifar::from_bits_retain(self.0.read(SysregId::Hifar) as u32)
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `HIFAR` system register.
+    pub fn write_hifar(&self, value: Hifar) {
+        self.0.write(SysregId::Hifar, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `HMAIR0` system register.
+    pub fn read_hmair0(&self) -> Hmair0 {
+        Hmair0::from_bits_retain(self.0.read(SysregId::Hmair0) as u32)
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `HMAIR0` system register.
+    pub fn write_hmair0(&self, value: Hmair0) {
+        self.0.write(SysregId::Hmair0, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `HMAIR1` system register.
+    pub fn read_hmair1(&self) -> Hmair1 {
+        Hmair1::from_bits_retain(self.0.read(SysregId::Hmair1) as u32)
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `HMAIR1` system register.
+    pub fn write_hmair1(&self, value: Hmair1) {
+        self.0.write(SysregId::Hmair1, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `HPFAR` system register.
+    pub fn read_hpfar(&self) -> Hpfar {
+        Hpfar::from_bits_retain(self.0.read(SysregId::Hpfar) as u32)
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `HPFAR` system register.
+    pub fn write_hpfar(&self, value: Hpfar) {
+        self.0.write(SysregId::Hpfar, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `HPFAR_EL2` system register.
+    pub fn read_hpfar_el2(&self) -> HpfarEl2 {
+        HpfarEl2::from_bits_retain(self.0.read(SysregId::HpfarEl2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `HPFAR_EL2` system register.
+    pub fn write_hpfar_el2(&self, value: HpfarEl2) {
+        self.0.write(SysregId::HpfarEl2, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `HRMR` system register.
+    pub fn read_hrmr(&self) -> Hrmr {
+        Hrmr::from_bits_retain(self.0.read(SysregId::Hrmr) as u32)
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `HRMR` system register.
+    pub fn write_hrmr(&self, value: Hrmr) {
+        self.0.write(SysregId::Hrmr, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `HSCTLR` system register.
+    pub fn read_hsctlr(&self) -> Hsctlr {
+        Hsctlr::from_bits_retain(self.0.read(SysregId::Hsctlr) as u32)
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `HSCTLR` system register.
+    pub fn write_hsctlr(&self, value: Hsctlr) {
+        self.0.write(SysregId::Hsctlr, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `HSR` system register.
+    pub fn read_hsr(&self) -> Hsr {
+        Hsr::from_bits_retain(self.0.read(SysregId::Hsr) as u32)
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `HSR` system register.
+    pub fn write_hsr(&self, value: Hsr) {
+        self.0.write(SysregId::Hsr, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `HSTR` system register.
+    pub fn read_hstr(&self) -> u32 {
+        self.0.read(SysregId::Hstr) as u32
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `HSTR` system register.
+    pub fn write_hstr(&self, value: u32) {
+        self.0.write(SysregId::Hstr, u64::from(value));
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `HSTR_EL2` system register.
+    pub fn read_hstr_el2(&self) -> u64 {
+        self.0.read(SysregId::HstrEl2)
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `HSTR_EL2` system register.
+    pub fn write_hstr_el2(&self, value: u64) {
+        self.0.write(SysregId::HstrEl2, value);
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `HTCR` system register.
+    pub fn read_htcr(&self) -> Htcr {
+        Htcr::from_bits_retain(self.0.read(SysregId::Htcr) as u32)
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `HTCR` system register.
+    pub fn write_htcr(&self, value: Htcr) {
+        self.0.write(SysregId::Htcr, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `HTPIDR` system register.
+    pub fn read_htpidr(&self) -> Htpidr {
+        Htpidr::from_bits_retain(self.0.read(SysregId::Htpidr) as u32)
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `HTPIDR` system register.
+    pub fn write_htpidr(&self, value: Htpidr) {
+        self.0.write(SysregId::Htpidr, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `HTRFCR` system register.
+    pub fn read_htrfcr(&self) -> Htrfcr {
+        Htrfcr::from_bits_retain(self.0.read(SysregId::Htrfcr) as u32)
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `HTRFCR` system register.
+    pub fn write_htrfcr(&self, value: Htrfcr) {
+        self.0.write(SysregId::Htrfcr, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `HTTBR` system register.
+    pub fn read_httbr(&self) -> Httbr {
+        Httbr::from_bits_retain(self.0.read(SysregId::Httbr))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `HTTBR` system register.
+    pub fn write_httbr(&self, value: Httbr) {
+        self.0.write(SysregId::Httbr, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `HVBAR` system register.
+    pub fn read_hvbar(&self) -> Hvbar {
+        Hvbar::from_bits_retain(self.0.read(SysregId::Hvbar) as u32)
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `HVBAR` system register.
+    pub fn write_hvbar(&self, value: Hvbar) {
+        self.0.write(SysregId::Hvbar, u64::from(value.bits()));
+    }
+
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// Returns the value of the `ICC_AP0R0_EL1` system register.
+    pub fn read_icc_ap0r0_el1(&self) -> u64 {
+        self.0.read(SysregId::IccAp0r0El1)
+    }
+
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// Writes `value` to the `ICC_AP0R0_EL1` system register.
+    pub fn write_icc_ap0r0_el1(&self, value: u64) {
+        self.0.write(SysregId::IccAp0r0El1, value);
+    }
+
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// Returns the value of the `ICC_AP0R1_EL1` system register.
+    pub fn read_icc_ap0r1_el1(&self) -> u64 {
+        self.0.read(SysregId::IccAp0r1El1)
+    }
+
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// Writes `value` to the `ICC_AP0R1_EL1` system register.
+    pub fn write_icc_ap0r1_el1(&self, value: u64) {
+        self.0.write(SysregId::IccAp0r1El1, value);
+    }
+
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// Returns the value of the `ICC_AP0R2_EL1` system register.
+    pub fn read_icc_ap0r2_el1(&self) -> u64 {
+        self.0.read(SysregId::IccAp0r2El1)
+    }
+
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// Writes `value` to the `ICC_AP0R2_EL1` system register.
+    pub fn write_icc_ap0r2_el1(&self, value: u64) {
+        self.0.write(SysregId::IccAp0r2El1, value);
+    }
+
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// Returns the value of the `ICC_AP0R3_EL1` system register.
+    pub fn read_icc_ap0r3_el1(&self) -> u64 {
+        self.0.read(SysregId::IccAp0r3El1)
+    }
+
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// Writes `value` to the `ICC_AP0R3_EL1` system register.
+    pub fn write_icc_ap0r3_el1(&self, value: u64) {
+        self.0.write(SysregId::IccAp0r3El1, value);
+    }
+
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// Returns the value of the `ICC_AP1R0_EL1` system register.
+    pub fn read_icc_ap1r0_el1(&self) -> IccAp1r0El1 {
+        IccAp1r0El1::from_bits_retain(self.0.read(SysregId::IccAp1r0El1))
+    }
+
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// Writes `value` to the `ICC_AP1R0_EL1` system register.
+    pub fn write_icc_ap1r0_el1(&self, value: IccAp1r0El1) {
+        self.0.write(SysregId::IccAp1r0El1, value.bits());
+    }
+
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// Returns the value of the `ICC_AP1R1_EL1` system register.
+    pub fn read_icc_ap1r1_el1(&self) -> u64 {
+        self.0.read(SysregId::IccAp1r1El1)
+    }
+
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// Writes `value` to the `ICC_AP1R1_EL1` system register.
+    pub fn write_icc_ap1r1_el1(&self, value: u64) {
+        self.0.write(SysregId::IccAp1r1El1, value);
+    }
+
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// Returns the value of the `ICC_AP1R2_EL1` system register.
+    pub fn read_icc_ap1r2_el1(&self) -> u64 {
+        self.0.read(SysregId::IccAp1r2El1)
+    }
+
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// Writes `value` to the `ICC_AP1R2_EL1` system register.
+    pub fn write_icc_ap1r2_el1(&self, value: u64) {
+        self.0.write(SysregId::IccAp1r2El1, value);
+    }
+
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// Returns the value of the `ICC_AP1R3_EL1` system register.
+    pub fn read_icc_ap1r3_el1(&self) -> u64 {
+        self.0.read(SysregId::IccAp1r3El1)
+    }
+
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// Writes `value` to the `ICC_AP1R3_EL1` system register.
+    pub fn write_icc_ap1r3_el1(&self, value: u64) {
+        self.0.write(SysregId::IccAp1r3El1, value);
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `ICC_ASGI1R` system register.
+    pub fn write_icc_asgi1r(&self, value: IccAsgi1r) {
+        self.0.write(SysregId::IccAsgi1r, value.bits());
+    }
+
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// Writes `value` to the `ICC_ASGI1R_EL1` system register.
+    pub fn write_icc_asgi1r_el1(&self, value: IccAsgi1rEl1) {
+        self.0.write(SysregId::IccAsgi1rEl1, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ICC_BPR0` system register.
+    pub fn read_icc_bpr0(&self) -> IccBpr0 {
+        IccBpr0::from_bits_retain(self.0.read(SysregId::IccBpr0) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `ICC_BPR0` system register.
+    pub fn write_icc_bpr0(&self, value: IccBpr0) {
+        self.0.write(SysregId::IccBpr0, u64::from(value.bits()));
+    }
+
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// Returns the value of the `ICC_BPR0_EL1` system register.
+    pub fn read_icc_bpr0_el1(&self) -> IccBpr0El1 {
+        IccBpr0El1::from_bits_retain(self.0.read(SysregId::IccBpr0El1))
+    }
+
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// Writes `value` to the `ICC_BPR0_EL1` system register.
+    pub fn write_icc_bpr0_el1(&self, value: IccBpr0El1) {
+        self.0.write(SysregId::IccBpr0El1, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ICC_BPR1` system register.
+    pub fn read_icc_bpr1(&self) -> IccBpr1 {
+        IccBpr1::from_bits_retain(self.0.read(SysregId::IccBpr1) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `ICC_BPR1` system register.
+    pub fn write_icc_bpr1(&self, value: IccBpr1) {
+        self.0.write(SysregId::IccBpr1, u64::from(value.bits()));
+    }
+
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// Returns the value of the `ICC_BPR1_EL1` system register.
+    pub fn read_icc_bpr1_el1(&self) -> IccBpr1El1 {
+        IccBpr1El1::from_bits_retain(self.0.read(SysregId::IccBpr1El1))
+    }
+
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// Writes `value` to the `ICC_BPR1_EL1` system register.
+    pub fn write_icc_bpr1_el1(&self, value: IccBpr1El1) {
+        self.0.write(SysregId::IccBpr1El1, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ICC_CTLR` system register.
+    pub fn read_icc_ctlr(&self) -> IccCtlr {
+        IccCtlr::from_bits_retain(self.0.read(SysregId::IccCtlr) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `ICC_CTLR` system register.
+    pub fn write_icc_ctlr(&self, value: IccCtlr) {
+        self.0.write(SysregId::IccCtlr, u64::from(value.bits()));
+    }
+
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// Returns the value of the `ICC_CTLR_EL1` system register.
+    pub fn read_icc_ctlr_el1(&self) -> IccCtlrEl1 {
+        IccCtlrEl1::from_bits_retain(self.0.read(SysregId::IccCtlrEl1))
+    }
+
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// Writes `value` to the `ICC_CTLR_EL1` system register.
+    pub fn write_icc_ctlr_el1(&self, value: IccCtlrEl1) {
+        self.0.write(SysregId::IccCtlrEl1, value.bits());
+    }
+
+    #[cfg(all(feature = "el3", feature = "feat-gic"))]
+    /// Returns the value of the `ICC_CTLR_EL3` system register.
+    pub fn read_icc_ctlr_el3(&self) -> IccCtlrEl3 {
+        IccCtlrEl3::from_bits_retain(self.0.read(SysregId::IccCtlrEl3))
+    }
+
+    #[cfg(all(feature = "el3", feature = "feat-gic"))]
+    /// Writes `value` to the `ICC_CTLR_EL3` system register.
+    pub fn write_icc_ctlr_el3(&self, value: IccCtlrEl3) {
+        self.0.write(SysregId::IccCtlrEl3, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `ICC_DIR` system register.
+    pub fn write_icc_dir(&self, value: IccDir) {
+        self.0.write(SysregId::IccDir, u64::from(value.bits()));
+    }
+
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// Writes `value` to the `ICC_DIR_EL1` system register.
+    pub fn write_icc_dir_el1(&self, value: IccDirEl1) {
+        self.0.write(SysregId::IccDirEl1, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `ICC_EOIR0` system register.
+    pub fn write_icc_eoir0(&self, value: IccEoir0) {
+        self.0.write(SysregId::IccEoir0, u64::from(value.bits()));
+    }
+
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// Writes `value` to the `ICC_EOIR0_EL1` system register.
+    pub fn write_icc_eoir0_el1(&self, value: IccEoir0El1) {
+        self.0.write(SysregId::IccEoir0El1, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `ICC_EOIR1` system register.
+    pub fn write_icc_eoir1(&self, value: IccEoir1) {
+        self.0.write(SysregId::IccEoir1, u64::from(value.bits()));
+    }
+
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// Writes `value` to the `ICC_EOIR1_EL1` system register.
+    pub fn write_icc_eoir1_el1(&self, value: IccEoir1El1) {
+        self.0.write(SysregId::IccEoir1El1, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ICC_HPPIR0` system register.
+    pub fn read_icc_hppir0(&self) -> IccHppir0 {
+        IccHppir0::from_bits_retain(self.0.read(SysregId::IccHppir0) as u32)
+    }
+
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// Returns the value of the `ICC_HPPIR0_EL1` system register.
+    pub fn read_icc_hppir0_el1(&self) -> IccHppir0El1 {
+        IccHppir0El1::from_bits_retain(self.0.read(SysregId::IccHppir0El1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ICC_HPPIR1` system register.
+    pub fn read_icc_hppir1(&self) -> IccHppir1 {
+        IccHppir1::from_bits_retain(self.0.read(SysregId::IccHppir1) as u32)
+    }
+
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// Returns the value of the `ICC_HPPIR1_EL1` system register.
+    pub fn read_icc_hppir1_el1(&self) -> IccHppir1El1 {
+        IccHppir1El1::from_bits_retain(self.0.read(SysregId::IccHppir1El1))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `ICC_HSRE` system register.
+    pub fn read_icc_hsre(&self) -> IccHsre {
+        IccHsre::from_bits_retain(self.0.read(SysregId::IccHsre) as u32)
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `ICC_HSRE` system register.
+    pub fn write_icc_hsre(&self, value: IccHsre) {
+        self.0.write(SysregId::IccHsre, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ICC_IAR0` system register.
+    pub fn read_icc_iar0(&self) -> IccIar0 {
+        IccIar0::from_bits_retain(self.0.read(SysregId::IccIar0) as u32)
+    }
+
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// Returns the value of the `ICC_IAR0_EL1` system register.
+    pub fn read_icc_iar0_el1(&self) -> IccIar0El1 {
+        IccIar0El1::from_bits_retain(self.0.read(SysregId::IccIar0El1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ICC_IAR1` system register.
+    pub fn read_icc_iar1(&self) -> IccIar1 {
+        IccIar1::from_bits_retain(self.0.read(SysregId::IccIar1) as u32)
+    }
+
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// Returns the value of the `ICC_IAR1_EL1` system register.
+    pub fn read_icc_iar1_el1(&self) -> IccIar1El1 {
+        IccIar1El1::from_bits_retain(self.0.read(SysregId::IccIar1El1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ICC_IGRPEN0` system register.
+    pub fn read_icc_igrpen0(&self) -> IccIgrpen0 {
+        IccIgrpen0::from_bits_retain(self.0.read(SysregId::IccIgrpen0) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `ICC_IGRPEN0` system register.
+    pub fn write_icc_igrpen0(&self, value: IccIgrpen0) {
+        self.0.write(SysregId::IccIgrpen0, u64::from(value.bits()));
+    }
+
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// Returns the value of the `ICC_IGRPEN0_EL1` system register.
+    pub fn read_icc_igrpen0_el1(&self) -> IccIgrpen0El1 {
+        IccIgrpen0El1::from_bits_retain(self.0.read(SysregId::IccIgrpen0El1))
+    }
+
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// Writes `value` to the `ICC_IGRPEN0_EL1` system register.
+    pub fn write_icc_igrpen0_el1(&self, value: IccIgrpen0El1) {
+        self.0.write(SysregId::IccIgrpen0El1, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ICC_IGRPEN1` system register.
+    pub fn read_icc_igrpen1(&self) -> IccIgrpen1 {
+        IccIgrpen1::from_bits_retain(self.0.read(SysregId::IccIgrpen1) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `ICC_IGRPEN1` system register.
+    pub fn write_icc_igrpen1(&self, value: IccIgrpen1) {
+        self.0.write(SysregId::IccIgrpen1, u64::from(value.bits()));
+    }
+
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// Returns the value of the `ICC_IGRPEN1_EL1` system register.
+    pub fn read_icc_igrpen1_el1(&self) -> IccIgrpen1El1 {
+        IccIgrpen1El1::from_bits_retain(self.0.read(SysregId::IccIgrpen1El1))
+    }
+
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// Writes `value` to the `ICC_IGRPEN1_EL1` system register.
+    pub fn write_icc_igrpen1_el1(&self, value: IccIgrpen1El1) {
+        self.0.write(SysregId::IccIgrpen1El1, value.bits());
+    }
+
+    #[cfg(all(feature = "el3", feature = "feat-gic"))]
+    /// Returns the value of the `ICC_IGRPEN1_EL3` system register.
+    pub fn read_icc_igrpen1_el3(&self) -> IccIgrpen1El3 {
+        IccIgrpen1El3::from_bits_retain(self.0.read(SysregId::IccIgrpen1El3))
+    }
+
+    #[cfg(all(feature = "el3", feature = "feat-gic"))]
+    /// Writes `value` to the `ICC_IGRPEN1_EL3` system register.
+    pub fn write_icc_igrpen1_el3(&self, value: IccIgrpen1El3) {
+        self.0.write(SysregId::IccIgrpen1El3, value.bits());
+    }
+
+    #[cfg(feature = "el3")]
+    /// Returns the value of the `ICC_MCTLR` system register.
+    pub fn read_icc_mctlr(&self) -> IccMctlr {
+        IccMctlr::from_bits_retain(self.0.read(SysregId::IccMctlr) as u32)
+    }
+
+    #[cfg(feature = "el3")]
+    /// Writes `value` to the `ICC_MCTLR` system register.
+    pub fn write_icc_mctlr(&self, value: IccMctlr) {
+        self.0.write(SysregId::IccMctlr, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el3")]
+    /// Returns the value of the `ICC_MGRPEN1` system register.
+    pub fn read_icc_mgrpen1(&self) -> IccMgrpen1 {
+        IccMgrpen1::from_bits_retain(self.0.read(SysregId::IccMgrpen1) as u32)
+    }
+
+    #[cfg(feature = "el3")]
+    /// Writes `value` to the `ICC_MGRPEN1` system register.
+    pub fn write_icc_mgrpen1(&self, value: IccMgrpen1) {
+        self.0.write(SysregId::IccMgrpen1, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el3")]
+    /// Returns the value of the `ICC_MSRE` system register.
+    pub fn read_icc_msre(&self) -> IccMsre {
+        IccMsre::from_bits_retain(self.0.read(SysregId::IccMsre) as u32)
+    }
+
+    #[cfg(feature = "el3")]
+    /// Writes `value` to the `ICC_MSRE` system register.
+    pub fn write_icc_msre(&self, value: IccMsre) {
+        self.0.write(SysregId::IccMsre, u64::from(value.bits()));
+    }
+
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// Returns the value of the `ICC_NMIAR1_EL1` system register.
+    pub fn read_icc_nmiar1_el1(&self) -> IccNmiar1El1 {
+        IccNmiar1El1::from_bits_retain(self.0.read(SysregId::IccNmiar1El1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ICC_PMR` system register.
+    pub fn read_icc_pmr(&self) -> IccPmr {
+        IccPmr::from_bits_retain(self.0.read(SysregId::IccPmr) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `ICC_PMR` system register.
+    pub fn write_icc_pmr(&self, value: IccPmr) {
+        self.0.write(SysregId::IccPmr, u64::from(value.bits()));
+    }
+
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// Returns the value of the `ICC_PMR_EL1` system register.
+    pub fn read_icc_pmr_el1(&self) -> IccPmrEl1 {
+        IccPmrEl1::from_bits_retain(self.0.read(SysregId::IccPmrEl1))
+    }
+
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// Writes `value` to the `ICC_PMR_EL1` system register.
+    pub fn write_icc_pmr_el1(&self, value: IccPmrEl1) {
+        self.0.write(SysregId::IccPmrEl1, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ICC_RPR` system register.
+    pub fn read_icc_rpr(&self) -> IccRpr {
+        IccRpr::from_bits_retain(self.0.read(SysregId::IccRpr) as u32)
+    }
+
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// Returns the value of the `ICC_RPR_EL1` system register.
+    pub fn read_icc_rpr_el1(&self) -> IccRprEl1 {
+        IccRprEl1::from_bits_retain(self.0.read(SysregId::IccRprEl1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `ICC_SGI0R` system register.
+    pub fn write_icc_sgi0r(&self, value: IccSgi0r) {
+        self.0.write(SysregId::IccSgi0r, value.bits());
+    }
+
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// Writes `value` to the `ICC_SGI0R_EL1` system register.
+    pub fn write_icc_sgi0r_el1(&self, value: IccSgi0rEl1) {
+        self.0.write(SysregId::IccSgi0rEl1, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `ICC_SGI1R` system register.
+    pub fn write_icc_sgi1r(&self, value: IccSgi1r) {
+        self.0.write(SysregId::IccSgi1r, value.bits());
+    }
+
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// Writes `value` to the `ICC_SGI1R_EL1` system register.
+    pub fn write_icc_sgi1r_el1(&self, value: IccSgi1rEl1) {
+        self.0.write(SysregId::IccSgi1rEl1, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ICC_SRE` system register.
+    pub fn read_icc_sre(&self) -> IccSre {
+        IccSre::from_bits_retain(self.0.read(SysregId::IccSre) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `ICC_SRE` system register.
+    pub fn write_icc_sre(&self, value: IccSre) {
+        self.0.write(SysregId::IccSre, u64::from(value.bits()));
+    }
+
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// Returns the value of the `ICC_SRE_EL1` system register.
+    pub fn read_icc_sre_el1(&self) -> IccSreEl1 {
+        IccSreEl1::from_bits_retain(self.0.read(SysregId::IccSreEl1))
+    }
+
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
+    /// Writes `value` to the `ICC_SRE_EL1` system register.
+    pub fn write_icc_sre_el1(&self, value: IccSreEl1) {
+        self.0.write(SysregId::IccSreEl1, value.bits());
+    }
+
+    #[cfg(all(feature = "el2", feature = "feat-gic"))]
+    /// Returns the value of the `ICC_SRE_EL2` system register.
+    pub fn read_icc_sre_el2(&self) -> IccSreEl2 {
+        IccSreEl2::from_bits_retain(self.0.read(SysregId::IccSreEl2))
+    }
+
+    #[cfg(all(feature = "el2", feature = "feat-gic"))]
+    /// Writes `value` to the `ICC_SRE_EL2` system register.
+    pub fn write_icc_sre_el2(&self, value: IccSreEl2) {
+        self.0.write(SysregId::IccSreEl2, value.bits());
+    }
+
+    #[cfg(all(feature = "el3", feature = "feat-gic"))]
+    /// Returns the value of the `ICC_SRE_EL3` system register.
+    pub fn read_icc_sre_el3(&self) -> IccSreEl3 {
+        IccSreEl3::from_bits_retain(self.0.read(SysregId::IccSreEl3))
+    }
+
+    #[cfg(all(feature = "el3", feature = "feat-gic"))]
+    /// Writes `value` to the `ICC_SRE_EL3` system register.
+    pub fn write_icc_sre_el3(&self, value: IccSreEl3) {
+        self.0.write(SysregId::IccSreEl3, value.bits());
+    }
+
+    #[cfg(all(feature = "el2", feature = "feat-gic"))]
+    /// Returns the value of the `ICH_HCR_EL2` system register.
+    pub fn read_ich_hcr_el2(&self) -> IchHcrEl2 {
+        IchHcrEl2::from_bits_retain(self.0.read(SysregId::IchHcrEl2))
+    }
+
+    #[cfg(all(feature = "el2", feature = "feat-gic"))]
+    /// Writes `value` to the `ICH_HCR_EL2` system register.
+    pub fn write_ich_hcr_el2(&self, value: IchHcrEl2) {
+        self.0.write(SysregId::IchHcrEl2, value.bits());
+    }
+
+    #[cfg(all(feature = "el2", feature = "feat-gic"))]
+    /// Returns the value of the `ICH_VMCR_EL2` system register.
+    pub fn read_ich_vmcr_el2(&self) -> IchVmcrEl2 {
+        IchVmcrEl2::from_bits_retain(self.0.read(SysregId::IchVmcrEl2))
+    }
+
+    #[cfg(all(feature = "el2", feature = "feat-gic"))]
+    /// Writes `value` to the `ICH_VMCR_EL2` system register.
+    pub fn write_ich_vmcr_el2(&self, value: IchVmcrEl2) {
+        self.0.write(SysregId::IchVmcrEl2, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ID_AA64DFR0_EL1` system register.
+    pub fn read_id_aa64dfr0_el1(&self) -> IdAa64dfr0El1 {
+        IdAa64dfr0El1::from_bits_retain(self.0.read(SysregId::IdAa64dfr0El1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ID_AA64DFR1_EL1` system register.
+    pub fn read_id_aa64dfr1_el1(&self) -> IdAa64dfr1El1 {
+        IdAa64dfr1El1::from_bits_retain(self.0.read(SysregId::IdAa64dfr1El1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ID_AA64ISAR1_EL1` system register.
+    pub fn read_id_aa64isar1_el1(&self) -> IdAa64isar1El1 {
+        IdAa64isar1El1::from_bits_retain(self.0.read(SysregId::IdAa64isar1El1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ID_AA64ISAR2_EL1` system register.
+    pub fn read_id_aa64isar2_el1(&self) -> IdAa64isar2El1 {
+        IdAa64isar2El1::from_bits_retain(self.0.read(SysregId::IdAa64isar2El1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ID_AA64MMFR0_EL1` system register.
+    pub fn read_id_aa64mmfr0_el1(&self) -> IdAa64mmfr0El1 {
+        IdAa64mmfr0El1::from_bits_retain(self.0.read(SysregId::IdAa64mmfr0El1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ID_AA64MMFR1_EL1` system register.
+    pub fn read_id_aa64mmfr1_el1(&self) -> IdAa64mmfr1El1 {
+        IdAa64mmfr1El1::from_bits_retain(self.0.read(SysregId::IdAa64mmfr1El1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ID_AA64MMFR2_EL1` system register.
+    pub fn read_id_aa64mmfr2_el1(&self) -> IdAa64mmfr2El1 {
+        IdAa64mmfr2El1::from_bits_retain(self.0.read(SysregId::IdAa64mmfr2El1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ID_AA64MMFR3_EL1` system register.
+    pub fn read_id_aa64mmfr3_el1(&self) -> IdAa64mmfr3El1 {
+        IdAa64mmfr3El1::from_bits_retain(self.0.read(SysregId::IdAa64mmfr3El1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ID_AA64MMFR4_EL1` system register.
+    pub fn read_id_aa64mmfr4_el1(&self) -> IdAa64mmfr4El1 {
+        IdAa64mmfr4El1::from_bits_retain(self.0.read(SysregId::IdAa64mmfr4El1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ID_AA64PFR0_EL1` system register.
+    pub fn read_id_aa64pfr0_el1(&self) -> IdAa64pfr0El1 {
+        IdAa64pfr0El1::from_bits_retain(self.0.read(SysregId::IdAa64pfr0El1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ID_AA64PFR1_EL1` system register.
+    pub fn read_id_aa64pfr1_el1(&self) -> IdAa64pfr1El1 {
+        IdAa64pfr1El1::from_bits_retain(self.0.read(SysregId::IdAa64pfr1El1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ID_AA64PFR2_EL1` system register.
+    pub fn read_id_aa64pfr2_el1(&self) -> IdAa64pfr2El1 {
+        IdAa64pfr2El1::from_bits_retain(self.0.read(SysregId::IdAa64pfr2El1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ID_AA64SMFR0_EL1` system register.
+    pub fn read_id_aa64smfr0_el1(&self) -> IdAa64smfr0El1 {
+        IdAa64smfr0El1::from_bits_retain(self.0.read(SysregId::IdAa64smfr0El1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ID_AFR0` system register.
+    pub fn read_id_afr0(&self) -> u32 {
+        self.0.read(SysregId::IdAfr0) as u32
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ID_DFR0` system register.
+    pub fn read_id_dfr0(&self) -> IdDfr0 {
+        IdDfr0::from_bits_retain(self.0.read(SysregId::IdDfr0) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ID_DFR1` system register.
+    pub fn read_id_dfr1(&self) -> IdDfr1 {
+        IdDfr1::from_bits_retain(self.0.read(SysregId::IdDfr1) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ID_ISAR0` system register.
+    pub fn read_id_isar0(&self) -> IdIsar0 {
+        IdIsar0::from_bits_retain(self.0.read(SysregId::IdIsar0) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ID_ISAR1` system register.
+    pub fn read_id_isar1(&self) -> IdIsar1 {
+        IdIsar1::from_bits_retain(self.0.read(SysregId::IdIsar1) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ID_ISAR2` system register.
+    pub fn read_id_isar2(&self) -> IdIsar2 {
+        IdIsar2::from_bits_retain(self.0.read(SysregId::IdIsar2) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ID_ISAR3` system register.
+    pub fn read_id_isar3(&self) -> IdIsar3 {
+        IdIsar3::from_bits_retain(self.0.read(SysregId::IdIsar3) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ID_ISAR4` system register.
+    pub fn read_id_isar4(&self) -> IdIsar4 {
+        IdIsar4::from_bits_retain(self.0.read(SysregId::IdIsar4) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ID_ISAR5` system register.
+    pub fn read_id_isar5(&self) -> IdIsar5 {
+        IdIsar5::from_bits_retain(self.0.read(SysregId::IdIsar5) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ID_ISAR6` system register.
+    pub fn read_id_isar6(&self) -> IdIsar6 {
+        IdIsar6::from_bits_retain(self.0.read(SysregId::IdIsar6) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ID_MMFR0` system register.
+    pub fn read_id_mmfr0(&self) -> IdMmfr0 {
+        IdMmfr0::from_bits_retain(self.0.read(SysregId::IdMmfr0) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ID_MMFR1` system register.
+    pub fn read_id_mmfr1(&self) -> IdMmfr1 {
+        IdMmfr1::from_bits_retain(self.0.read(SysregId::IdMmfr1) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ID_MMFR2` system register.
+    pub fn read_id_mmfr2(&self) -> IdMmfr2 {
+        IdMmfr2::from_bits_retain(self.0.read(SysregId::IdMmfr2) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ID_MMFR3` system register.
+    pub fn read_id_mmfr3(&self) -> IdMmfr3 {
+        IdMmfr3::from_bits_retain(self.0.read(SysregId::IdMmfr3) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ID_MMFR4` system register.
+    pub fn read_id_mmfr4(&self) -> IdMmfr4 {
+        IdMmfr4::from_bits_retain(self.0.read(SysregId::IdMmfr4) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ID_MMFR5` system register.
+    pub fn read_id_mmfr5(&self) -> IdMmfr5 {
+        IdMmfr5::from_bits_retain(self.0.read(SysregId::IdMmfr5) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ID_PFR0` system register.
+    pub fn read_id_pfr0(&self) -> IdPfr0 {
+        IdPfr0::from_bits_retain(self.0.read(SysregId::IdPfr0) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ID_PFR1` system register.
+    pub fn read_id_pfr1(&self) -> IdPfr1 {
+        IdPfr1::from_bits_retain(self.0.read(SysregId::IdPfr1) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ID_PFR2` system register.
+    pub fn read_id_pfr2(&self) -> IdPfr2 {
+        IdPfr2::from_bits_retain(self.0.read(SysregId::IdPfr2) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `IFAR` system register.
+    pub fn read_ifar(&self) -> Ifar {
+        Ifar::from_bits_retain(self.0.read(SysregId::Ifar) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `IFAR` system register.
+    pub fn write_ifar(&self, value: Ifar) {
+        self.0.write(SysregId::Ifar, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `IFSR` system register.
+    pub fn read_ifsr(&self) -> Ifsr {
+        Ifsr::from_bits_retain(self.0.read(SysregId::Ifsr) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `IFSR` system register.
+    pub fn write_ifsr(&self, value: Ifsr) {
+        self.0.write(SysregId::Ifsr, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ISR` system register.
+    pub fn read_isr(&self) -> Isr {
+        Isr::from_bits_retain(self.0.read(SysregId::Isr) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `ISR_EL1` system register.
+    pub fn read_isr_el1(&self) -> IsrEl1 {
+        IsrEl1::from_bits_retain(self.0.read(SysregId::IsrEl1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `JIDR` system register.
+    pub fn read_jidr(&self) -> u32 {
+        self.0.read(SysregId::Jidr) as u32
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `JMCR` system register.
+    pub fn read_jmcr(&self) -> u32 {
+        self.0.read(SysregId::Jmcr) as u32
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `JMCR` system register.
+    pub fn write_jmcr(&self, value: u32) {
+        self.0.write(SysregId::Jmcr, u64::from(value));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `JOSCR` system register.
+    pub fn read_joscr(&self) -> u32 {
+        self.0.read(SysregId::Joscr) as u32
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `JOSCR` system register.
+    pub fn write_joscr(&self, value: u32) {
+        self.0.write(SysregId::Joscr, u64::from(value));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `MAIR0` system register.
+    pub fn read_mair0(&self) -> Mair0 {
+        Mair0::from_bits_retain(self.0.read(SysregId::Mair0) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `MAIR0` system register.
+    pub fn write_mair0(&self, value: Mair0) {
+        self.0.write(SysregId::Mair0, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `MAIR1` system register.
+    pub fn read_mair1(&self) -> Mair1 {
+        Mair1::from_bits_retain(self.0.read(SysregId::Mair1) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `MAIR1` system register.
+    pub fn write_mair1(&self, value: Mair1) {
+        self.0.write(SysregId::Mair1, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `MAIR_EL1` system register.
+    pub fn read_mair_el1(&self) -> MairEl1 {
+        MairEl1::from_bits_retain(self.0.read(SysregId::MairEl1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `MAIR_EL1` system register.
+    pub fn write_mair_el1(&self, value: MairEl1) {
+        self.0.write(SysregId::MairEl1, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `MAIR_EL2` system register.
+    pub fn read_mair_el2(&self) -> MairEl2 {
+        MairEl2::from_bits_retain(self.0.read(SysregId::MairEl2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `MAIR_EL2` system register.
+    pub fn write_mair_el2(&self, value: MairEl2) {
+        self.0.write(SysregId::MairEl2, value.bits());
+    }
+
+    #[cfg(feature = "el3")]
+    /// Returns the value of the `MAIR_EL3` system register.
+    pub fn read_mair_el3(&self) -> MairEl3 {
+        MairEl3::from_bits_retain(self.0.read(SysregId::MairEl3))
+    }
+
+    #[cfg(feature = "el3")]
+    /// Writes `value` to the `MAIR_EL3` system register.
+    pub fn write_mair_el3(&self, value: MairEl3) {
+        self.0.write(SysregId::MairEl3, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `MDCCINT_EL1` system register.
+    pub fn read_mdccint_el1(&self) -> MdccintEl1 {
+        MdccintEl1::from_bits_retain(self.0.read(SysregId::MdccintEl1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `MDCCINT_EL1` system register.
+    pub fn write_mdccint_el1(&self, value: MdccintEl1) {
+        self.0.write(SysregId::MdccintEl1, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `MDCR_EL2` system register.
+    pub fn read_mdcr_el2(&self) -> MdcrEl2 {
+        MdcrEl2::from_bits_retain(self.0.read(SysregId::MdcrEl2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `MDCR_EL2` system register.
+    pub fn write_mdcr_el2(&self, value: MdcrEl2) {
+        self.0.write(SysregId::MdcrEl2, value.bits());
+    }
+
+    #[cfg(feature = "el3")]
+    /// Returns the value of the `MDCR_EL3` system register.
+    pub fn read_mdcr_el3(&self) -> MdcrEl3 {
+        MdcrEl3::from_bits_retain(self.0.read(SysregId::MdcrEl3))
+    }
+
+    #[cfg(feature = "el3")]
+    /// Writes `value` to the `MDCR_EL3` system register.
+    pub fn write_mdcr_el3(&self, value: MdcrEl3) {
+        self.0.write(SysregId::MdcrEl3, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `MDSCR_EL1` system register.
+    pub fn read_mdscr_el1(&self) -> MdscrEl1 {
+        MdscrEl1::from_bits_retain(self.0.read(SysregId::MdscrEl1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `MDSCR_EL1` system register.
+    pub fn write_mdscr_el1(&self, value: MdscrEl1) {
+        self.0.write(SysregId::MdscrEl1, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `MIDR` system register.
+    pub fn read_midr(&self) -> Midr {
+        Midr::from_bits_retain(self.0.read(SysregId::Midr) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `MIDR_EL1` system register.
+    pub fn read_midr_el1(&self) -> MidrEl1 {
+        MidrEl1::from_bits_retain(self.0.read(SysregId::MidrEl1))
+    }
+
+    #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+    /// Returns the value of the `MPAM2_EL2` system register.
+    pub fn read_mpam2_el2(&self) -> Mpam2El2 {
+        Mpam2El2::from_bits_retain(self.0.read(SysregId::Mpam2El2))
+    }
+
+    #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+    /// Writes `value` to the `MPAM2_EL2` system register.
+    pub fn write_mpam2_el2(&self, value: Mpam2El2) {
+        self.0.write(SysregId::Mpam2El2, value.bits());
+    }
+
+    #[cfg(all(feature = "el3", feature = "feat-mpam"))]
+    /// Returns the value of the `MPAM3_EL3` system register.
+    pub fn read_mpam3_el3(&self) -> Mpam3El3 {
+        Mpam3El3::from_bits_retain(self.0.read(SysregId::Mpam3El3))
+    }
+
+    #[cfg(all(feature = "el3", feature = "feat-mpam"))]
+    /// Writes `value` to the `MPAM3_EL3` system register.
+    pub fn write_mpam3_el3(&self, value: Mpam3El3) {
+        self.0.write(SysregId::Mpam3El3, value.bits());
+    }
+
+    #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+    /// Returns the value of the `MPAMHCR_EL2` system register.
+    pub fn read_mpamhcr_el2(&self) -> MpamhcrEl2 {
+        MpamhcrEl2::from_bits_retain(self.0.read(SysregId::MpamhcrEl2))
+    }
+
+    #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+    /// Writes `value` to the `MPAMHCR_EL2` system register.
+    pub fn write_mpamhcr_el2(&self, value: MpamhcrEl2) {
+        self.0.write(SysregId::MpamhcrEl2, value.bits());
+    }
+
+    #[cfg(all(feature = "el1", feature = "feat-mpam"))]
+    /// Returns the value of the `MPAMIDR_EL1` system register.
+    pub fn read_mpamidr_el1(&self) -> MpamidrEl1 {
+        MpamidrEl1::from_bits_retain(self.0.read(SysregId::MpamidrEl1))
+    }
+
+    #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+    /// Returns the value of the `MPAMVPM0_EL2` system register.
+    pub fn read_mpamvpm0_el2(&self) -> Mpamvpm0El2 {
+        Mpamvpm0El2::from_bits_retain(self.0.read(SysregId::Mpamvpm0El2))
+    }
+
+    #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+    /// Writes `value` to the `MPAMVPM0_EL2` system register.
+    pub fn write_mpamvpm0_el2(&self, value: Mpamvpm0El2) {
+        self.0.write(SysregId::Mpamvpm0El2, value.bits());
+    }
+
+    #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+    /// Returns the value of the `MPAMVPM1_EL2` system register.
+    pub fn read_mpamvpm1_el2(&self) -> Mpamvpm1El2 {
+        Mpamvpm1El2::from_bits_retain(self.0.read(SysregId::Mpamvpm1El2))
+    }
+
+    #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+    /// Writes `value` to the `MPAMVPM1_EL2` system register.
+    pub fn write_mpamvpm1_el2(&self, value: Mpamvpm1El2) {
+        self.0.write(SysregId::Mpamvpm1El2, value.bits());
+    }
+
+    #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+    /// Returns the value of the `MPAMVPM2_EL2` system register.
+    pub fn read_mpamvpm2_el2(&self) -> Mpamvpm2El2 {
+        Mpamvpm2El2::from_bits_retain(self.0.read(SysregId::Mpamvpm2El2))
+    }
+
+    #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+    /// Writes `value` to the `MPAMVPM2_EL2` system register.
+    pub fn write_mpamvpm2_el2(&self, value: Mpamvpm2El2) {
+        self.0.write(SysregId::Mpamvpm2El2, value.bits());
+    }
+
+    #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+    /// Returns the value of the `MPAMVPM3_EL2` system register.
+    pub fn read_mpamvpm3_el2(&self) -> Mpamvpm3El2 {
+        Mpamvpm3El2::from_bits_retain(self.0.read(SysregId::Mpamvpm3El2))
+    }
+
+    #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+    /// Writes `value` to the `MPAMVPM3_EL2` system register.
+    pub fn write_mpamvpm3_el2(&self, value: Mpamvpm3El2) {
+        self.0.write(SysregId::Mpamvpm3El2, value.bits());
+    }
+
+    #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+    /// Returns the value of the `MPAMVPM4_EL2` system register.
+    pub fn read_mpamvpm4_el2(&self) -> Mpamvpm4El2 {
+        Mpamvpm4El2::from_bits_retain(self.0.read(SysregId::Mpamvpm4El2))
+    }
+
+    #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+    /// Writes `value` to the `MPAMVPM4_EL2` system register.
+    pub fn write_mpamvpm4_el2(&self, value: Mpamvpm4El2) {
+        self.0.write(SysregId::Mpamvpm4El2, value.bits());
+    }
+
+    #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+    /// Returns the value of the `MPAMVPM5_EL2` system register.
+    pub fn read_mpamvpm5_el2(&self) -> Mpamvpm5El2 {
+        Mpamvpm5El2::from_bits_retain(self.0.read(SysregId::Mpamvpm5El2))
+    }
+
+    #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+    /// Writes `value` to the `MPAMVPM5_EL2` system register.
+    pub fn write_mpamvpm5_el2(&self, value: Mpamvpm5El2) {
+        self.0.write(SysregId::Mpamvpm5El2, value.bits());
+    }
+
+    #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+    /// Returns the value of the `MPAMVPM6_EL2` system register.
+    pub fn read_mpamvpm6_el2(&self) -> Mpamvpm6El2 {
+        Mpamvpm6El2::from_bits_retain(self.0.read(SysregId::Mpamvpm6El2))
+    }
+
+    #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+    /// Writes `value` to the `MPAMVPM6_EL2` system register.
+    pub fn write_mpamvpm6_el2(&self, value: Mpamvpm6El2) {
+        self.0.write(SysregId::Mpamvpm6El2, value.bits());
+    }
+
+    #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+    /// Returns the value of the `MPAMVPM7_EL2` system register.
+    pub fn read_mpamvpm7_el2(&self) -> Mpamvpm7El2 {
+        Mpamvpm7El2::from_bits_retain(self.0.read(SysregId::Mpamvpm7El2))
+    }
+
+    #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+    /// Writes `value` to the `MPAMVPM7_EL2` system register.
+    pub fn write_mpamvpm7_el2(&self, value: Mpamvpm7El2) {
+        self.0.write(SysregId::Mpamvpm7El2, value.bits());
+    }
+
+    #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+    /// Returns the value of the `MPAMVPMV_EL2` system register.
+    pub fn read_mpamvpmv_el2(&self) -> MpamvpmvEl2 {
+        MpamvpmvEl2::from_bits_retain(self.0.read(SysregId::MpamvpmvEl2))
+    }
+
+    #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+    /// Writes `value` to the `MPAMVPMV_EL2` system register.
+    pub fn write_mpamvpmv_el2(&self, value: MpamvpmvEl2) {
+        self.0.write(SysregId::MpamvpmvEl2, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `MPIDR` system register.
+    pub fn read_mpidr(&self) -> Mpidr {
+        Mpidr::from_bits_retain(self.0.read(SysregId::Mpidr) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `MPIDR_EL1` system register.
+    pub fn read_mpidr_el1(&self) -> MpidrEl1 {
+        MpidrEl1::from_bits_retain(self.0.read(SysregId::MpidrEl1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `MVBAR` system register.
+    pub fn read_mvbar(&self) -> Mvbar {
+        Mvbar::from_bits_retain(self.0.read(SysregId::Mvbar) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `MVBAR` system register.
+    pub fn write_mvbar(&self, value: Mvbar) {
+        self.0.write(SysregId::Mvbar, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `NMRR` system register.
+    pub fn read_nmrr(&self) -> Nmrr {
+        Nmrr::from_bits_retain(self.0.read(SysregId::Nmrr) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `NMRR` system register.
+    pub fn write_nmrr(&self, value: Nmrr) {
+        self.0.write(SysregId::Nmrr, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `NSACR` system register.
+    pub fn read_nsacr(&self) -> Nsacr {
+        Nsacr::from_bits_retain(self.0.read(SysregId::Nsacr) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `NSACR` system register.
+    pub fn write_nsacr(&self, value: Nsacr) {
+        self.0.write(SysregId::Nsacr, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `PAR` system register.
+    pub fn read_par(&self) -> Par {
+        Par::from_bits_retain(self.0.read(SysregId::Par))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `PAR` system register.
+    pub fn write_par(&self, value: Par) {
+        self.0.write(SysregId::Par, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `PAR_EL1` system register.
+    pub fn read_par_el1(&self) -> ParEl1 {
+        ParEl1::from_bits_retain(self.0.read(SysregId::ParEl1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `PAR_EL1` system register.
+    pub fn write_par_el1(&self, value: ParEl1) {
+        self.0.write(SysregId::ParEl1, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `PFAR_EL1` system register.
+    pub fn read_pfar_el1(&self) -> PfarEl1 {
+        PfarEl1::from_bits_retain(self.0.read(SysregId::PfarEl1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `PFAR_EL1` system register.
+    pub fn write_pfar_el1(&self, value: PfarEl1) {
+        self.0.write(SysregId::PfarEl1, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `PFAR_EL2` system register.
+    pub fn read_pfar_el2(&self) -> PfarEl2 {
+        PfarEl2::from_bits_retain(self.0.read(SysregId::PfarEl2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `PFAR_EL2` system register.
+    pub fn write_pfar_el2(&self, value: PfarEl2) {
+        self.0.write(SysregId::PfarEl2, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `PIRE0_EL1` system register.
+    pub fn read_pire0_el1(&self) -> Pire0El1 {
+        Pire0El1::from_bits_retain(self.0.read(SysregId::Pire0El1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `PIRE0_EL1` system register.
+    pub fn write_pire0_el1(&self, value: Pire0El1) {
+        self.0.write(SysregId::Pire0El1, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `PIRE0_EL2` system register.
+    pub fn read_pire0_el2(&self) -> Pire0El2 {
+        Pire0El2::from_bits_retain(self.0.read(SysregId::Pire0El2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `PIRE0_EL2` system register.
+    pub fn write_pire0_el2(&self, value: Pire0El2) {
+        self.0.write(SysregId::Pire0El2, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `PIR_EL1` system register.
+    pub fn read_pir_el1(&self) -> PirEl1 {
+        PirEl1::from_bits_retain(self.0.read(SysregId::PirEl1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `PIR_EL1` system register.
+    pub fn write_pir_el1(&self, value: PirEl1) {
+        self.0.write(SysregId::PirEl1, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `PIR_EL2` system register.
+    pub fn read_pir_el2(&self) -> PirEl2 {
+        PirEl2::from_bits_retain(self.0.read(SysregId::PirEl2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `PIR_EL2` system register.
+    pub fn write_pir_el2(&self, value: PirEl2) {
+        self.0.write(SysregId::PirEl2, value.bits());
+    }
+
+    #[cfg(feature = "el3")]
+    /// Returns the value of the `PIR_EL3` system register.
+    pub fn read_pir_el3(&self) -> PirEl3 {
+        PirEl3::from_bits_retain(self.0.read(SysregId::PirEl3))
+    }
+
+    #[cfg(feature = "el3")]
+    /// Writes `value` to the `PIR_EL3` system register.
+    pub fn write_pir_el3(&self, value: PirEl3) {
+        self.0.write(SysregId::PirEl3, value.bits());
+    }
+
+    /// Returns the value of the `PMCCFILTR` system register.
+    pub fn read_pmccfiltr(&self) -> Pmccfiltr {
+        Pmccfiltr::from_bits_retain(self.0.read(SysregId::Pmccfiltr) as u32)
+    }
+
+    /// Writes `value` to the `PMCCFILTR` system register.
+    pub fn write_pmccfiltr(&self, value: Pmccfiltr) {
+        self.0.write(SysregId::Pmccfiltr, u64::from(value.bits()));
+    }
+
+    /// Returns the value of the `PMCCNTR` system register.
+    pub fn read_pmccntr(&self) -> Pmccntr {
+        Pmccntr::from_bits_retain(self.0.read(SysregId::Pmccntr))
+    }
+
+    /// Writes `value` to the `PMCCNTR` system register.
+    pub fn write_pmccntr(&self, value: Pmccntr) {
+        self.0.write(SysregId::Pmccntr, value.bits());
+    }
+
+    /// Returns the value of the `PMCEID0` system register.
+    pub fn read_pmceid0(&self) -> Pmceid0 {
+        Pmceid0::from_bits_retain(self.0.read(SysregId::Pmceid0) as u32)
+    }
+
+    /// Returns the value of the `PMCEID1` system register.
+    pub fn read_pmceid1(&self) -> Pmceid1 {
+        Pmceid1::from_bits_retain(self.0.read(SysregId::Pmceid1) as u32)
+    }
+
+    /// Returns the value of the `PMCEID2` system register.
+    pub fn read_pmceid2(&self) -> Pmceid2 {
+        Pmceid2::from_bits_retain(self.0.read(SysregId::Pmceid2) as u32)
+    }
+
+    /// Returns the value of the `PMCEID3` system register.
+    pub fn read_pmceid3(&self) -> Pmceid3 {
+        Pmceid3::from_bits_retain(self.0.read(SysregId::Pmceid3) as u32)
+    }
+
+    /// Returns the value of the `PMCNTENCLR` system register.
+    pub fn read_pmcntenclr(&self) -> Pmcntenclr {
+        Pmcntenclr::from_bits_retain(self.0.read(SysregId::Pmcntenclr) as u32)
+    }
+
+    /// Writes `value` to the `PMCNTENCLR` system register.
+    pub fn write_pmcntenclr(&self, value: Pmcntenclr) {
+        self.0.write(SysregId::Pmcntenclr, u64::from(value.bits()));
+    }
+
+    /// Returns the value of the `PMCNTENSET` system register.
+    pub fn read_pmcntenset(&self) -> Pmcntenset {
+        Pmcntenset::from_bits_retain(self.0.read(SysregId::Pmcntenset) as u32)
+    }
+
+    /// Writes `value` to the `PMCNTENSET` system register.
+    pub fn write_pmcntenset(&self, value: Pmcntenset) {
+        self.0.write(SysregId::Pmcntenset, u64::from(value.bits()));
+    }
+
+    /// Returns the value of the `PMCR` system register.
+    pub fn read_pmcr(&self) -> Pmcr {
+        Pmcr::from_bits_retain(self.0.read(SysregId::Pmcr) as u32)
+    }
+
+    /// Writes `value` to the `PMCR` system register.
+    pub fn write_pmcr(&self, value: Pmcr) {
+        self.0.write(SysregId::Pmcr, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "feat-pmu")]
+    /// Returns the value of the `PMCR_EL0` system register.
+    pub fn read_pmcr_el0(&self) -> PmcrEl0 {
+        PmcrEl0::from_bits_retain(self.0.read(SysregId::PmcrEl0))
+    }
+
+    #[cfg(feature = "feat-pmu")]
+    /// Writes `value` to the `PMCR_EL0` system register.
+    pub fn write_pmcr_el0(&self, value: PmcrEl0) {
+        self.0.write(SysregId::PmcrEl0, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `PMINTENCLR` system register.
+    pub fn read_pmintenclr(&self) -> Pmintenclr {
+        Pmintenclr::from_bits_retain(self.0.read(SysregId::Pmintenclr) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `PMINTENCLR` system register.
+    pub fn write_pmintenclr(&self, value: Pmintenclr) {
+        self.0.write(SysregId::Pmintenclr, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `PMINTENSET` system register.
+    pub fn read_pmintenset(&self) -> Pmintenset {
+        Pmintenset::from_bits_retain(self.0.read(SysregId::Pmintenset) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `PMINTENSET` system register.
+    pub fn write_pmintenset(&self, value: Pmintenset) {
+        self.0.write(SysregId::Pmintenset, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `PMMIR` system register.
+    pub fn read_pmmir(&self) -> Pmmir {
+        Pmmir::from_bits_retain(self.0.read(SysregId::Pmmir) as u32)
+    }
+
+    /// Returns the value of the `PMOVSR` system register.
+    pub fn read_pmovsr(&self) -> Pmovsr {
+        Pmovsr::from_bits_retain(self.0.read(SysregId::Pmovsr) as u32)
+    }
+
+    /// Writes `value` to the `PMOVSR` system register.
+    pub fn write_pmovsr(&self, value: Pmovsr) {
+        self.0.write(SysregId::Pmovsr, u64::from(value.bits()));
+    }
+
+    /// Returns the value of the `PMOVSSET` system register.
+    pub fn read_pmovsset(&self) -> Pmovsset {
+        Pmovsset::from_bits_retain(self.0.read(SysregId::Pmovsset) as u32)
+    }
+
+    /// Writes `value` to the `PMOVSSET` system register.
+    pub fn write_pmovsset(&self, value: Pmovsset) {
+        self.0.write(SysregId::Pmovsset, u64::from(value.bits()));
+    }
+
+    /// Returns the value of the `PMSELR` system register.
+    pub fn read_pmselr(&self) -> Pmselr {
+        Pmselr::from_bits_retain(self.0.read(SysregId::Pmselr) as u32)
+    }
+
+    /// Writes `value` to the `PMSELR` system register.
+    pub fn write_pmselr(&self, value: Pmselr) {
+        self.0.write(SysregId::Pmselr, u64::from(value.bits()));
+    }
+
+    /// Writes `value` to the `PMSWINC` system register.
+    pub fn write_pmswinc(&self, value: Pmswinc) {
+        self.0.write(SysregId::Pmswinc, u64::from(value.bits()));
+    }
+
+    /// Returns the value of the `PMUSERENR` system register.
+    pub fn read_pmuserenr(&self) -> Pmuserenr {
+        Pmuserenr::from_bits_retain(self.0.read(SysregId::Pmuserenr) as u32)
+    }
+
+    /// Writes `value` to the `PMUSERENR` system register.
+    pub fn write_pmuserenr(&self, value: Pmuserenr) {
+        self.0.write(SysregId::Pmuserenr, u64::from(value.bits()));
+    }
+
+    /// Returns the value of the `PMXEVTYPER` system register.
+    pub fn read_pmxevtyper(&self) -> Pmxevtyper {
+        Pmxevtyper::from_bits_retain(self.0.read(SysregId::Pmxevtyper) as u32)
+    }
+
+    /// Writes `value` to the `PMXEVTYPER` system register.
+    pub fn write_pmxevtyper(&self, value: Pmxevtyper) {
+        self.0.write(SysregId::Pmxevtyper, u64::from(value.bits()));
+    }
+
+    /// Returns the value of the `POR_EL0` system register.
+    pub fn read_por_el0(&self) -> PorEl0 {
+        PorEl0::from_bits_retain(self.0.read(SysregId::PorEl0))
+    }
+
+    /// Writes `value` to the `POR_EL0` system register.
+    pub fn write_por_el0(&self, value: PorEl0) {
+        self.0.write(SysregId::PorEl0, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `POR_EL1` system register.
+    pub fn read_por_el1(&self) -> PorEl1 {
+        PorEl1::from_bits_retain(self.0.read(SysregId::PorEl1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `POR_EL1` system register.
+    pub fn write_por_el1(&self, value: PorEl1) {
+        self.0.write(SysregId::PorEl1, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `POR_EL2` system register.
+    pub fn read_por_el2(&self) -> PorEl2 {
+        PorEl2::from_bits_retain(self.0.read(SysregId::PorEl2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `POR_EL2` system register.
+    pub fn write_por_el2(&self, value: PorEl2) {
+        self.0.write(SysregId::PorEl2, value.bits());
+    }
+
+    #[cfg(feature = "el3")]
+    /// Returns the value of the `POR_EL3` system register.
+    pub fn read_por_el3(&self) -> PorEl3 {
+        PorEl3::from_bits_retain(self.0.read(SysregId::PorEl3))
+    }
+
+    #[cfg(feature = "el3")]
+    /// Writes `value` to the `POR_EL3` system register.
+    pub fn write_por_el3(&self, value: PorEl3) {
+        self.0.write(SysregId::PorEl3, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `PRRR` system register.
+    pub fn read_prrr(&self) -> Prrr {
+        Prrr::from_bits_retain(self.0.read(SysregId::Prrr) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `PRRR` system register.
+    pub fn write_prrr(&self, value: Prrr) {
+        self.0.write(SysregId::Prrr, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `REVIDR` system register.
+    pub fn read_revidr(&self) -> u32 {
+        self.0.read(SysregId::Revidr) as u32
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `RGSR_EL1` system register.
+    pub fn read_rgsr_el1(&self) -> RgsrEl1 {
+        RgsrEl1::from_bits_retain(self.0.read(SysregId::RgsrEl1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `RGSR_EL1` system register.
+    pub fn write_rgsr_el1(&self, value: RgsrEl1) {
+        self.0.write(SysregId::RgsrEl1, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `RMR` system register.
+    pub fn read_rmr(&self) -> Rmr {
+        Rmr::from_bits_retain(self.0.read(SysregId::Rmr) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `RMR` system register.
+    pub fn write_rmr(&self, value: Rmr) {
+        self.0.write(SysregId::Rmr, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `RVBAR` system register.
+    pub fn read_rvbar(&self) -> Rvbar {
+        Rvbar::from_bits_retain(self.0.read(SysregId::Rvbar) as u32)
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `S2PIR_EL2` system register.
+    pub fn read_s2pir_el2(&self) -> S2pirEl2 {
+        S2pirEl2::from_bits_retain(self.0.read(SysregId::S2pirEl2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `S2PIR_EL2` system register.
+    pub fn write_s2pir_el2(&self, value: S2pirEl2) {
+        self.0.write(SysregId::S2pirEl2, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `S2POR_EL1` system register.
+    pub fn read_s2por_el1(&self) -> S2porEl1 {
+        S2porEl1::from_bits_retain(self.0.read(SysregId::S2porEl1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `S2POR_EL1` system register.
+    pub fn write_s2por_el1(&self, value: S2porEl1) {
+        self.0.write(SysregId::S2porEl1, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `SCR` system register.
+    pub fn read_scr(&self) -> Scr {
+        Scr::from_bits_retain(self.0.read(SysregId::Scr) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `SCR` system register.
+    pub fn write_scr(&self, value: Scr) {
+        self.0.write(SysregId::Scr, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el3")]
+    /// Returns the value of the `SCR_EL3` system register.
+    pub fn read_scr_el3(&self) -> ScrEl3 {
+        ScrEl3::from_bits_retain(self.0.read(SysregId::ScrEl3))
+    }
+
+    #[cfg(feature = "el3")]
+    /// Writes `value` to the `SCR_EL3` system register.
+    pub fn write_scr_el3(&self, value: ScrEl3) {
+        self.0.write(SysregId::ScrEl3, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `SCTLR` system register.
+    pub fn read_sctlr(&self) -> Sctlr {
+        Sctlr::from_bits_retain(self.0.read(SysregId::Sctlr) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `SCTLR` system register.
+    pub fn write_sctlr(&self, value: Sctlr) {
+        self.0.write(SysregId::Sctlr, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `SCTLR2_EL1` system register.
+    pub fn read_sctlr2_el1(&self) -> Sctlr2El1 {
+        Sctlr2El1::from_bits_retain(self.0.read(SysregId::Sctlr2El1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `SCTLR2_EL1` system register.
+    pub fn write_sctlr2_el1(&self, value: Sctlr2El1) {
+        self.0.write(SysregId::Sctlr2El1, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `SCTLR2_EL2` system register.
+    pub fn read_sctlr2_el2(&self) -> Sctlr2El2 {
+        Sctlr2El2::from_bits_retain(self.0.read(SysregId::Sctlr2El2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `SCTLR2_EL2` system register.
+    pub fn write_sctlr2_el2(&self, value: Sctlr2El2) {
+        self.0.write(SysregId::Sctlr2El2, value.bits());
+    }
+
+    #[cfg(feature = "el3")]
+    /// Returns the value of the `SCTLR2_EL3` system register.
+    pub fn read_sctlr2_el3(&self) -> Sctlr2El3 {
+        Sctlr2El3::from_bits_retain(self.0.read(SysregId::Sctlr2El3))
+    }
+
+    #[cfg(feature = "el3")]
+    /// Writes `value` to the `SCTLR2_EL3` system register.
+    pub fn write_sctlr2_el3(&self, value: Sctlr2El3) {
+        self.0.write(SysregId::Sctlr2El3, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `SCTLR_EL1` system register.
+    pub fn read_sctlr_el1(&self) -> SctlrEl1 {
+        SctlrEl1::from_bits_retain(self.0.read(SysregId::SctlrEl1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `SCTLR_EL1` system register.
+    pub fn write_sctlr_el1(&self, value: SctlrEl1) {
+        self.0.write(SysregId::SctlrEl1, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `SCTLR_EL2` system register.
+    pub fn read_sctlr_el2(&self) -> SctlrEl2 {
+        SctlrEl2::from_bits_retain(self.0.read(SysregId::SctlrEl2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `SCTLR_EL2` system register.
+    pub fn write_sctlr_el2(&self, value: SctlrEl2) {
+        self.0.write(SysregId::SctlrEl2, value.bits());
+    }
+
+    #[cfg(feature = "el3")]
+    /// Returns the value of the `SCTLR_EL3` system register.
+    pub fn read_sctlr_el3(&self) -> SctlrEl3 {
+        SctlrEl3::from_bits_retain(self.0.read(SysregId::SctlrEl3))
+    }
+
+    #[cfg(feature = "el3")]
+    /// Writes `value` to the `SCTLR_EL3` system register.
+    pub fn write_sctlr_el3(&self, value: SctlrEl3) {
+        self.0.write(SysregId::SctlrEl3, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `SDCR` system register.
+    pub fn read_sdcr(&self) -> Sdcr {
+        Sdcr::from_bits_retain(self.0.read(SysregId::Sdcr) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `SDCR` system register.
+    pub fn write_sdcr(&self, value: Sdcr) {
+        self.0.write(SysregId::Sdcr, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `SDER` system register.
+    pub fn read_sder(&self) -> Sder {
+        Sder::from_bits_retain(self.0.read(SysregId::Sder) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `SDER` system register.
+    pub fn write_sder(&self, value: Sder) {
+        self.0.write(SysregId::Sder, u64::from(value.bits()));
+    }
+
+    #[cfg(all(feature = "el3", feature = "feat-sve"))]
+    /// Returns the value of the `SMCR_EL3` system register.
+    pub fn read_smcr_el3(&self) -> SmcrEl3 {
+        SmcrEl3::from_bits_retain(self.0.read(SysregId::SmcrEl3))
+    }
+
+    #[cfg(all(feature = "el3", feature = "feat-sve"))]
+    /// Writes `value` to the `SMCR_EL3` system register.
+    pub fn write_smcr_el3(&self, value: SmcrEl3) {
+        self.0.write(SysregId::SmcrEl3, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `SPSR_EL1` system register.
+    pub fn read_spsr_el1(&self) -> SpsrEl1 {
+        SpsrEl1::from_bits_retain(self.0.read(SysregId::SpsrEl1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `SPSR_EL1` system register.
+    pub fn write_spsr_el1(&self, value: SpsrEl1) {
+        self.0.write(SysregId::SpsrEl1, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `SPSR_EL2` system register.
+    pub fn read_spsr_el2(&self) -> SpsrEl2 {
+        SpsrEl2::from_bits_retain(self.0.read(SysregId::SpsrEl2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `SPSR_EL2` system register.
+    pub fn write_spsr_el2(&self, value: SpsrEl2) {
+        self.0.write(SysregId::SpsrEl2, value.bits());
+    }
+
+    #[cfg(feature = "el3")]
+    /// Returns the value of the `SPSR_EL3` system register.
+    pub fn read_spsr_el3(&self) -> SpsrEl3 {
+        SpsrEl3::from_bits_retain(self.0.read(SysregId::SpsrEl3))
+    }
+
+    #[cfg(feature = "el3")]
+    /// Writes `value` to the `SPSR_EL3` system register.
+    pub fn write_spsr_el3(&self, value: SpsrEl3) {
+        self.0.write(SysregId::SpsrEl3, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `SP_EL1` system register.
+    pub fn read_sp_el1(&self) -> SpEl1 {
+        SpEl1::from_bits_retain(self.0.read(SysregId::SpEl1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `SP_EL1` system register.
+    pub fn write_sp_el1(&self, value: SpEl1) {
+        self.0.write(SysregId::SpEl1, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `SP_EL2` system register.
+    pub fn read_sp_el2(&self) -> SpEl2 {
+        SpEl2::from_bits_retain(self.0.read(SysregId::SpEl2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `SP_EL2` system register.
+    pub fn write_sp_el2(&self, value: SpEl2) {
+        self.0.write(SysregId::SpEl2, value.bits());
+    }
+
+    #[cfg(feature = "feat-sve")]
+    /// Returns the value of the `SVCR` system register.
+    pub fn read_svcr(&self) -> Svcr {
+        Svcr::from_bits_retain(self.0.read(SysregId::Svcr))
+    }
+
+    #[cfg(feature = "feat-sve")]
+    /// Writes `value` to the `SVCR` system register.
+    pub fn write_svcr(&self, value: Svcr) {
+        self.0.write(SysregId::Svcr, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `TCMTR` system register.
+    pub fn read_tcmtr(&self) -> u32 {
+        self.0.read(SysregId::Tcmtr) as u32
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `TCR2_EL1` system register.
+    pub fn read_tcr2_el1(&self) -> Tcr2El1 {
+        Tcr2El1::from_bits_retain(self.0.read(SysregId::Tcr2El1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `TCR2_EL1` system register.
+    pub fn write_tcr2_el1(&self, value: Tcr2El1) {
+        self.0.write(SysregId::Tcr2El1, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `TCR2_EL2` system register.
+    pub fn read_tcr2_el2(&self) -> Tcr2El2 {
+        Tcr2El2::from_bits_retain(self.0.read(SysregId::Tcr2El2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `TCR2_EL2` system register.
+    pub fn write_tcr2_el2(&self, value: Tcr2El2) {
+        self.0.write(SysregId::Tcr2El2, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `TCR_EL1` system register.
+    pub fn read_tcr_el1(&self) -> TcrEl1 {
+        TcrEl1::from_bits_retain(self.0.read(SysregId::TcrEl1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `TCR_EL1` system register.
+    pub fn write_tcr_el1(&self, value: TcrEl1) {
+        self.0.write(SysregId::TcrEl1, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `TCR_EL2` system register.
+    pub fn read_tcr_el2(&self) -> TcrEl2 {
+        TcrEl2::from_bits_retain(self.0.read(SysregId::TcrEl2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `TCR_EL2` system register.
+    pub fn write_tcr_el2(&self, value: TcrEl2) {
+        self.0.write(SysregId::TcrEl2, value.bits());
+    }
+
+    #[cfg(feature = "el3")]
+    /// Returns the value of the `TCR_EL3` system register.
+    pub fn read_tcr_el3(&self) -> TcrEl3 {
+        TcrEl3::from_bits_retain(self.0.read(SysregId::TcrEl3))
+    }
+
+    #[cfg(feature = "el3")]
+    /// Writes `value` to the `TCR_EL3` system register.
+    pub fn write_tcr_el3(&self, value: TcrEl3) {
+        self.0.write(SysregId::TcrEl3, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `TFSRE0_EL1` system register.
+    pub fn read_tfsre0_el1(&self) -> Tfsre0El1 {
+        Tfsre0El1::from_bits_retain(self.0.read(SysregId::Tfsre0El1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `TFSRE0_EL1` system register.
+    pub fn write_tfsre0_el1(&self, value: Tfsre0El1) {
+        self.0.write(SysregId::Tfsre0El1, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `TFSR_EL1` system register.
+    pub fn read_tfsr_el1(&self) -> TfsrEl1 {
+        TfsrEl1::from_bits_retain(self.0.read(SysregId::TfsrEl1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `TFSR_EL1` system register.
+    pub fn write_tfsr_el1(&self, value: TfsrEl1) {
+        self.0.write(SysregId::TfsrEl1, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `TFSR_EL2` system register.
+    pub fn read_tfsr_el2(&self) -> TfsrEl2 {
+        TfsrEl2::from_bits_retain(self.0.read(SysregId::TfsrEl2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `TFSR_EL2` system register.
+    pub fn write_tfsr_el2(&self, value: TfsrEl2) {
+        self.0.write(SysregId::TfsrEl2, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `TLBTR` system register.
+    pub fn read_tlbtr(&self) -> Tlbtr {
+        Tlbtr::from_bits_retain(self.0.read(SysregId::Tlbtr) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `TPIDRPRW` system register.
+    pub fn read_tpidrprw(&self) -> Tpidrprw {
+        Tpidrprw::from_bits_retain(self.0.read(SysregId::Tpidrprw) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `TPIDRPRW` system register.
+    pub fn write_tpidrprw(&self, value: Tpidrprw) {
+        self.0.write(SysregId::Tpidrprw, u64::from(value.bits()));
+    }
+
+    /// Returns the value of the `TPIDRRO_EL0` system register.
+    pub fn read_tpidrro_el0(&self) -> TpidrroEl0 {
+        TpidrroEl0::from_bits_retain(self.0.read(SysregId::TpidrroEl0))
+    }
+
+    /// Writes `value` to the `TPIDRRO_EL0` system register.
+    pub fn write_tpidrro_el0(&self, value: TpidrroEl0) {
+        self.0.write(SysregId::TpidrroEl0, value.bits());
+    }
+
+    /// Returns the value of the `TPIDRURO` system register.
+    pub fn read_tpidruro(&self) -> Tpidruro {
+        Tpidruro::from_bits_retain(self.0.read(SysregId::Tpidruro) as u32)
+    }
+
+    /// Writes `value` to the `TPIDRURO` system register.
+    pub fn write_tpidruro(&self, value: Tpidruro) {
+        self.0.write(SysregId::Tpidruro, u64::from(value.bits()));
+    }
+
+    /// Returns the value of the `TPIDRURW` system register.
+    pub fn read_tpidrurw(&self) -> Tpidrurw {
+        Tpidrurw::from_bits_retain(self.0.read(SysregId::Tpidrurw) as u32)
+    }
+
+    /// Writes `value` to the `TPIDRURW` system register.
+    pub fn write_tpidrurw(&self, value: Tpidrurw) {
+        self.0.write(SysregId::Tpidrurw, u64::from(value.bits()));
+    }
+
+    /// Returns the value of the `TPIDR_EL0` system register.
+    pub fn read_tpidr_el0(&self) -> TpidrEl0 {
+        TpidrEl0::from_bits_retain(self.0.read(SysregId::TpidrEl0))
+    }
+
+    /// Writes `value` to the `TPIDR_EL0` system register.
+    pub fn write_tpidr_el0(&self, value: TpidrEl0) {
+        self.0.write(SysregId::TpidrEl0, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `TPIDR_EL1` system register.
+    pub fn read_tpidr_el1(&self) -> TpidrEl1 {
+        TpidrEl1::from_bits_retain(self.0.read(SysregId::TpidrEl1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `TPIDR_EL1` system register.
+    pub fn write_tpidr_el1(&self, value: TpidrEl1) {
+        self.0.write(SysregId::TpidrEl1, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `TPIDR_EL2` system register.
+    pub fn read_tpidr_el2(&self) -> TpidrEl2 {
+        TpidrEl2::from_bits_retain(self.0.read(SysregId::TpidrEl2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `TPIDR_EL2` system register.
+    pub fn write_tpidr_el2(&self, value: TpidrEl2) {
+        self.0.write(SysregId::TpidrEl2, value.bits());
+    }
+
+    #[cfg(feature = "el3")]
+    /// Returns the value of the `TPIDR_EL3` system register.
+    pub fn read_tpidr_el3(&self) -> TpidrEl3 {
+        TpidrEl3::from_bits_retain(self.0.read(SysregId::TpidrEl3))
+    }
+
+    #[cfg(feature = "el3")]
+    /// Writes `value` to the `TPIDR_EL3` system register.
+    pub fn write_tpidr_el3(&self, value: TpidrEl3) {
+        self.0.write(SysregId::TpidrEl3, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `TRFCR` system register.
+    pub fn read_trfcr(&self) -> Trfcr {
+        Trfcr::from_bits_retain(self.0.read(SysregId::Trfcr) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `TRFCR` system register.
+    pub fn write_trfcr(&self, value: Trfcr) {
+        self.0.write(SysregId::Trfcr, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `TTBCR` system register.
+    pub fn read_ttbcr(&self) -> Ttbcr {
+        Ttbcr::from_bits_retain(self.0.read(SysregId::Ttbcr) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `TTBCR` system register.
+    pub fn write_ttbcr(&self, value: Ttbcr) {
+        self.0.write(SysregId::Ttbcr, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `TTBCR2` system register.
+    pub fn read_ttbcr2(&self) -> Ttbcr2 {
+        Ttbcr2::from_bits_retain(self.0.read(SysregId::Ttbcr2) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `TTBCR2` system register.
+    pub fn write_ttbcr2(&self, value: Ttbcr2) {
+        self.0.write(SysregId::Ttbcr2, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `TTBR0` system register.
+    pub fn read_ttbr0(&self) -> Ttbr0 {
+        Ttbr0::from_bits_retain(self.0.read(SysregId::Ttbr0))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `TTBR0` system register.
+    pub fn write_ttbr0(&self, value: Ttbr0) {
+        self.0.write(SysregId::Ttbr0, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `TTBR0_EL1` system register.
+    pub fn read_ttbr0_el1(&self) -> Ttbr0El1 {
+        Ttbr0El1::from_bits_retain(self.0.read(SysregId::Ttbr0El1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `TTBR0_EL1` system register.
+    pub fn write_ttbr0_el1(&self, value: Ttbr0El1) {
+        self.0.write(SysregId::Ttbr0El1, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `TTBR0_EL2` system register.
+    pub fn read_ttbr0_el2(&self) -> Ttbr0El2 {
+        Ttbr0El2::from_bits_retain(self.0.read(SysregId::Ttbr0El2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `TTBR0_EL2` system register.
+    pub fn write_ttbr0_el2(&self, value: Ttbr0El2) {
+        self.0.write(SysregId::Ttbr0El2, value.bits());
+    }
+
+    #[cfg(feature = "el3")]
+    /// Returns the value of the `TTBR0_EL3` system register.
+    pub fn read_ttbr0_el3(&self) -> Ttbr0El3 {
+        Ttbr0El3::from_bits_retain(self.0.read(SysregId::Ttbr0El3))
+    }
+
+    #[cfg(feature = "el3")]
+    /// Writes `value` to the `TTBR0_EL3` system register.
+    pub fn write_ttbr0_el3(&self, value: Ttbr0El3) {
+        self.0.write(SysregId::Ttbr0El3, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `TTBR1` system register.
+    pub fn read_ttbr1(&self) -> Ttbr1 {
+        Ttbr1::from_bits_retain(self.0.read(SysregId::Ttbr1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `TTBR1` system register.
+    pub fn write_ttbr1(&self, value: Ttbr1) {
+        self.0.write(SysregId::Ttbr1, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `TTBR1_EL1` system register.
+    pub fn read_ttbr1_el1(&self) -> Ttbr1El1 {
+        Ttbr1El1::from_bits_retain(self.0.read(SysregId::Ttbr1El1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `TTBR1_EL1` system register.
+    pub fn write_ttbr1_el1(&self, value: Ttbr1El1) {
+        self.0.write(SysregId::Ttbr1El1, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `TTBR1_EL2` system register.
+    pub fn read_ttbr1_el2(&self) -> Ttbr1El2 {
+        Ttbr1El2::from_bits_retain(self.0.read(SysregId::Ttbr1El2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `TTBR1_EL2` system register.
+    pub fn write_ttbr1_el2(&self, value: Ttbr1El2) {
+        self.0.write(SysregId::Ttbr1El2, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `VBAR` system register.
+    pub fn read_vbar(&self) -> Vbar {
+        Vbar::from_bits_retain(self.0.read(SysregId::Vbar) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `VBAR` system register.
+    pub fn write_vbar(&self, value: Vbar) {
+        self.0.write(SysregId::Vbar, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `VBAR_EL1` system register.
+    pub fn read_vbar_el1(&self) -> VbarEl1 {
+        VbarEl1::from_bits_retain(self.0.read(SysregId::VbarEl1))
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `VBAR_EL1` system register.
+    pub fn write_vbar_el1(&self, value: VbarEl1) {
+        self.0.write(SysregId::VbarEl1, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `VBAR_EL2` system register.
+    pub fn read_vbar_el2(&self) -> VbarEl2 {
+        VbarEl2::from_bits_retain(self.0.read(SysregId::VbarEl2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `VBAR_EL2` system register.
+    pub fn write_vbar_el2(&self, value: VbarEl2) {
+        self.0.write(SysregId::VbarEl2, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `VDFSR` system register.
+    pub fn read_vdfsr(&self) -> Vdfsr {
+        Vdfsr::from_bits_retain(self.0.read(SysregId::Vdfsr) as u32)
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `VDFSR` system register.
+    pub fn write_vdfsr(&self, value: Vdfsr) {
+        self.0.write(SysregId::Vdfsr, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `VDISR` system register.
+    pub fn read_vdisr(&self) -> Vdisr {
+        Vdisr::from_bits_retain(self.0.read(SysregId::Vdisr) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `VDISR` system register.
+    pub fn write_vdisr(&self, value: Vdisr) {
+        self.0.write(SysregId::Vdisr, u64::from(value.bits()));
+    }
+
+    #[cfg(all(feature = "el2", feature = "feat-ras"))]
+    /// Returns the value of the `VDISR_EL2` system register.
+    pub fn read_vdisr_el2(&self) -> VdisrEl2 {
+        VdisrEl2::from_bits_retain(self.0.read(SysregId::VdisrEl2))
+    }
+
+    #[cfg(all(feature = "el2", feature = "feat-ras"))]
+    /// Writes `value` to the `VDISR_EL2` system register.
+    pub fn write_vdisr_el2(&self, value: VdisrEl2) {
+        self.0.write(SysregId::VdisrEl2, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `VMPIDR` system register.
+    pub fn read_vmpidr(&self) -> Vmpidr {
+        Vmpidr::from_bits_retain(self.0.read(SysregId::Vmpidr) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `VMPIDR` system register.
+    pub fn write_vmpidr(&self, value: Vmpidr) {
+        self.0.write(SysregId::Vmpidr, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `VMPIDR_EL2` system register.
+    pub fn read_vmpidr_el2(&self) -> VmpidrEl2 {
+        VmpidrEl2::from_bits_retain(self.0.read(SysregId::VmpidrEl2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `VMPIDR_EL2` system register.
+    pub fn write_vmpidr_el2(&self, value: VmpidrEl2) {
+        self.0.write(SysregId::VmpidrEl2, value.bits());
+    }
+
+    #[cfg(feature = "el1")]
+    /// Returns the value of the `VPIDR` system register.
+    pub fn read_vpidr(&self) -> Vpidr {
+        Vpidr::from_bits_retain(self.0.read(SysregId::Vpidr) as u32)
+    }
+
+    #[cfg(feature = "el1")]
+    /// Writes `value` to the `VPIDR` system register.
+    pub fn write_vpidr(&self, value: Vpidr) {
+        self.0.write(SysregId::Vpidr, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `VPIDR_EL2` system register.
+    pub fn read_vpidr_el2(&self) -> VpidrEl2 {
+        VpidrEl2::from_bits_retain(self.0.read(SysregId::VpidrEl2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `VPIDR_EL2` system register.
+    pub fn write_vpidr_el2(&self, value: VpidrEl2) {
+        self.0.write(SysregId::VpidrEl2, value.bits());
+    }
+
+    #[cfg(all(feature = "el2", feature = "feat-ras"))]
+    /// Returns the value of the `VSESR_EL2` system register.
+    pub fn read_vsesr_el2(&self) -> VsesrEl2 {
+        VsesrEl2::from_bits_retain(self.0.read(SysregId::VsesrEl2))
+    }
+
+    #[cfg(all(feature = "el2", feature = "feat-ras"))]
+    /// Writes `value` to the `VSESR_EL2` system register.
+    pub fn write_vsesr_el2(&self, value: VsesrEl2) {
+        self.0.write(SysregId::VsesrEl2, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `VTCR` system register.
+    pub fn read_vtcr(&self) -> Vtcr {
+        Vtcr::from_bits_retain(self.0.read(SysregId::Vtcr) as u32)
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `VTCR` system register.
+    pub fn write_vtcr(&self, value: Vtcr) {
+        self.0.write(SysregId::Vtcr, u64::from(value.bits()));
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `VTCR_EL2` system register.
+    pub fn read_vtcr_el2(&self) -> VtcrEl2 {
+        VtcrEl2::from_bits_retain(self.0.read(SysregId::VtcrEl2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `VTCR_EL2` system register.
+    pub fn write_vtcr_el2(&self, value: VtcrEl2) {
+        self.0.write(SysregId::VtcrEl2, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `VTTBR` system register.
+    pub fn read_vttbr(&self) -> Vttbr {
+        Vttbr::from_bits_retain(self.0.read(SysregId::Vttbr))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `VTTBR` system register.
+    pub fn write_vttbr(&self, value: Vttbr) {
+        self.0.write(SysregId::Vttbr, value.bits());
+    }
+
+    #[cfg(feature = "el2")]
+    /// Returns the value of the `VTTBR_EL2` system register.
+    pub fn read_vttbr_el2(&self) -> VttbrEl2 {
+        VttbrEl2::from_bits_retain(self.0.read(SysregId::VttbrEl2))
+    }
+
+    #[cfg(feature = "el2")]
+    /// Writes `value` to the `VTTBR_EL2` system register.
+    pub fn write_vttbr_el2(&self, value: VttbrEl2) {
+        self.0.write(SysregId::VttbrEl2, value.bits());
+    }
+
+    #[cfg(all(feature = "el3", feature = "feat-sve"))]
+    /// Returns the value of the `ZCR_EL3` system register.
+    pub fn read_zcr_el3(&self) -> ZcrEl3 {
+        ZcrEl3::from_bits_retain(self.0.read(SysregId::ZcrEl3))
+    }
+
+    #[cfg(all(feature = "el3", feature = "feat-sve"))]
+    /// Writes `value` to the `ZCR_EL3` system register.
+    pub fn write_zcr_el3(&self, value: ZcrEl3) {
+        self.0.write(SysregId::ZcrEl3, value.bits());
+    }
+}
@@ -2,11 +2,30 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
 //! Fake implementations of system register getters and setters for unit tests.
+//!
+//! With the `fakes-no-std` feature these don't depend on `std`, so that the same tests can run on
+//! bare-metal test runners; they still need `alloc`. There are no threads in that case, so the
+//! selected PE applies to the whole program rather than to the current thread.
 
 mod generated;
+mod sync;
 
 pub use self::generated::SystemRegisters;
-use std::sync::Mutex;
+use self::generated::{register_exception_level, register_implemented};
+pub use self::sync::{LockResult, current_pe};
+use self::sync::{Mutex, MutexGuard, lock_ignoring_poison, lock_map, replace_current_pe};
+use crate::{CpuFeatures, ExceptionLevel};
+#[cfg(feature = "backend")]
+use crate::{SysregId, backend::SysregBackend};
+use alloc::{borrow::ToOwned, collections::BTreeMap, string::String, vec::Vec};
+use bitflags::Flags;
+use core::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    mem,
+    ops::{Deref, DerefMut},
+    str::FromStr,
+};
 
 /// Generates a public function named `read_$sysreg` to read the fake system register `$sysreg` of
 /// type `$type`.
@@ -18,7 +37,10 @@ macro_rules! read_sysreg {
             #[doc = stringify!($sysreg)]
             #[doc = "` system register."]
             pub fn [< read_ $sysreg >]() -> $type {
-                $fake_sysregs.lock().unwrap().$sysreg
+                $fake_sysregs
+                    .lock_checked(stringify!($sysreg), false)
+                    .map(|sysregs| sysregs.$sysreg)
+                    .unwrap_or_default()
             }
         }
     };
@@ -29,7 +51,10 @@ macro_rules! read_sysreg {
             #[doc = "` system register."]
             $(#[$attributes])*
             pub unsafe fn [< read_ $sysreg >]() -> $type {
-                $fake_sysregs.lock().unwrap().$sysreg
+                $fake_sysregs
+                    .lock_checked(stringify!($sysreg), false)
+                    .map(|sysregs| sysregs.$sysreg)
+                    .unwrap_or_default()
             }
         }
     };
@@ -39,7 +64,10 @@ macro_rules! read_sysreg {
             #[doc = stringify!($sysreg)]
             #[doc = "` system register."]
             pub fn [< read_ $sysreg >]() -> $bitflags_type {
-                $fake_sysregs.lock().unwrap().$sysreg
+                $fake_sysregs
+                    .lock_checked(stringify!($sysreg), false)
+                    .map(|sysregs| sysregs.$sysreg)
+                    .unwrap_or_default()
             }
         }
     };
@@ -50,7 +78,10 @@ macro_rules! read_sysreg {
             #[doc = "` system register."]
             $(#[$attributes])*
             pub unsafe fn [< read_ $sysreg >]() -> $bitflags_type {
-                $fake_sysregs.lock().unwrap().$sysreg
+                $fake_sysregs
+                    .lock_checked(stringify!($sysreg), false)
+                    .map(|sysregs| sysregs.$sysreg)
+                    .unwrap_or_default()
             }
         }
     };
@@ -66,7 +97,9 @@ macro_rules! write_sysreg {
             #[doc = stringify!($sysreg)]
             #[doc = "` system register."]
             pub fn [< write_ $sysreg >](value: $type) {
-                $fake_sysregs.lock().unwrap().$sysreg = value;
+                if let Some(mut sysregs) = $fake_sysregs.lock_checked(stringify!($sysreg), true) {
+                    sysregs.$sysreg = value;
+                }
             }
         }
     };
@@ -80,7 +113,9 @@ macro_rules! write_sysreg {
             #[doc = "` system register."]
             $(#[$attributes])*
             pub unsafe fn [< write_ $sysreg >](value: $type) {
-                $fake_sysregs.lock().unwrap().$sysreg = value;
+                if let Some(mut sysregs) = $fake_sysregs.lock_checked(stringify!($sysreg), true) {
+                    sysregs.$sysreg = value;
+                }
             }
         }
     };
@@ -90,7 +125,9 @@ macro_rules! write_sysreg {
             #[doc = stringify!($sysreg)]
             #[doc = "` system register."]
             pub fn [< write_ $sysreg >](value: $bitflags_type) {
-                $fake_sysregs.lock().unwrap().$sysreg = value;
+                if let Some(mut sysregs) = $fake_sysregs.lock_checked(stringify!($sysreg), true) {
+                    sysregs.$sysreg = value;
+                }
             }
         }
     };
@@ -104,20 +141,304 @@ macro_rules! write_sysreg {
             #[doc = "` system register."]
             $(#[$attributes])*
             pub unsafe fn [< write_ $sysreg >](value: $bitflags_type) {
-                $fake_sysregs.lock().unwrap().$sysreg = value;
+                if let Some(mut sysregs) = $fake_sysregs.lock_checked(stringify!($sysreg), true) {
+                    sysregs.$sysreg = value;
+                }
             }
         }
     };
 }
 
-/// Values of fake system registers.
-pub static SYSREGS: Mutex<SystemRegisters> = Mutex::new(SystemRegisters::new());
+/// Values of fake system registers for all simulated PEs.
+pub static SYSREGS: FakeSystem = FakeSystem::new();
+
+/// The MPIDR value of the PE which is current on threads which haven't selected another one.
+pub const BOOT_PE: u64 = 0;
+
+/// Selects the simulated PE with the given MPIDR value for all further system register accesses on
+/// the current thread.
+pub fn set_current_pe(mpidr: u64) {
+    replace_current_pe(mpidr);
+}
+
+/// Calls `f` with the simulated PE with the given MPIDR value selected on the current thread, then
+/// restores the previously selected PE.
+pub fn with_current_pe<T>(mpidr: u64, f: impl FnOnce() -> T) -> T {
+    struct Restore(u64);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            set_current_pe(self.0);
+        }
+    }
+
+    let _restore = Restore(replace_current_pe(mpidr));
+    f()
+}
+
+/// A set of simulated PEs, each with its own fake system registers.
+///
+/// Each PE is identified by the value of its `MPIDR_EL1` register, and is created with its initial
+/// register values the first time it is accessed. Registers marked as shared by the generator, such
+/// as the physical counter, have a single value across all PEs; all others are banked per PE.
+#[derive(Debug)]
+pub struct FakeSystem {
+    pes: Mutex<BTreeMap<u64, Pe>>,
+}
+
+impl FakeSystem {
+    /// Creates a new system with no PEs.
+    pub const fn new() -> Self {
+        Self {
+            pes: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    /// Locks the system registers of the PE selected on the current thread.
+    ///
+    /// See [`set_current_pe`] and [`with_current_pe`] to select a PE.
+    pub fn lock(&self) -> LockResult<PeRegistersGuard<'_>> {
+        self.lock_pe(current_pe())
+    }
+
+    /// Locks the system registers of the PE with the given MPIDR value, regardless of which PE is
+    /// selected on the current thread.
+    pub fn lock_pe(&self, mpidr: u64) -> LockResult<PeRegistersGuard<'_>> {
+        lock_map(&self.pes, |pes| PeRegistersGuard::new(pes, mpidr))
+    }
+
+    /// Locks the map of PEs, ignoring poisoning as it is always left in a consistent state.
+    fn lock_pes(&self) -> MutexGuard<'_, BTreeMap<u64, Pe>> {
+        lock_ignoring_poison(&self.pes)
+    }
+
+    /// Locks the system registers of the current PE to read or write the register with the given
+    /// lowercase name, checking the access against the PE's [`AccessPolicy`].
+    ///
+    /// Returns `None` if the access isn't allowed and the policy records an [`UndefinedTrap`]
+    /// rather than panicking.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the access isn't allowed and the policy doesn't record it.
+    pub fn lock_checked(
+        &self,
+        register: &'static str,
+        write: bool,
+    ) -> Option<PeRegistersGuard<'_>> {
+        let mut sysregs = self.lock().unwrap();
+        let policy = &sysregs.pe().policy;
+        if policy.allows(register) {
+            return Some(sysregs);
+        }
+        let trap = UndefinedTrap {
+            register,
+            write,
+            exception_level: policy.exception_level,
+        };
+        if policy.record_undefined {
+            sysregs.pe_mut().undefined.push(trap);
+            None
+        } else {
+            // Release the lock first so that the panic doesn't poison it for other tests.
+            drop(sysregs);
+            panic!("{trap}");
+        }
+    }
+
+    /// Returns the MPIDR values of all PEs which have been accessed so far, in ascending order.
+    pub fn pes(&self) -> Vec<u64> {
+        self.lock_pes().keys().copied().collect()
+    }
+
+    /// Removes all PEs, so that each starts again from its initial state when next accessed.
+    pub fn reset(&self) {
+        self.lock_pes().clear();
+    }
+}
+
+/// Accesses the fake system registers of the PE selected on the current thread, without checking
+/// its [`AccessPolicy`].
+#[cfg(feature = "backend")]
+impl SysregBackend for FakeSystem {
+    fn read(&self, id: SysregId) -> u64 {
+        self.lock().unwrap().read_by_id(id)
+    }
+
+    fn write(&self, id: SysregId, value: u64) {
+        self.lock().unwrap().write_by_id(id, value);
+    }
+}
+
+impl Default for FakeSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The state of a single simulated PE.
+#[derive(Debug)]
+struct Pe {
+    sysregs: SystemRegisters,
+    policy: AccessPolicy,
+    undefined: Vec<UndefinedTrap>,
+}
+
+/// Restrictions on the fake system registers which a simulated PE may access.
+///
+/// Accesses which aren't allowed would take an Undefined Instruction exception on real hardware. By
+/// default all accesses are allowed.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct AccessPolicy {
+    /// The exception level which the PE is currently running at, or `None` to allow access to the
+    /// registers of all exception levels.
+    pub exception_level: Option<ExceptionLevel>,
+    /// The optional features implemented by the PE, or `None` to treat all registers which depend
+    /// on optional features as implemented.
+    ///
+    /// Registers which depend on conditions other than features are always treated as implemented.
+    pub implemented: Option<CpuFeatures>,
+    /// Whether to record accesses which aren't allowed as an [`UndefinedTrap`] rather than panicking.
+    ///
+    /// Reads which aren't allowed then return 0, and writes are ignored.
+    pub record_undefined: bool,
+}
+
+impl AccessPolicy {
+    /// Returns whether the register with the given lowercase name may be accessed.
+    fn allows(&self, register: &str) -> bool {
+        let Some(exception_level) = register_exception_level(register) else {
+            return true;
+        };
+        self.exception_level
+            .is_none_or(|current| current >= exception_level)
+            && self
+                .implemented
+                .as_ref()
+                .is_none_or(|features| register_implemented(register, features))
+    }
+}
+
+/// A simulated Undefined Instruction exception caused by a fake system register access which wasn't
+/// allowed by the PE's [`AccessPolicy`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UndefinedTrap {
+    /// The lowercase name of the register.
+    pub register: &'static str,
+    /// Whether the access was a write rather than a read.
+    pub write: bool,
+    /// The simulated exception level at the time of the access.
+    pub exception_level: Option<ExceptionLevel>,
+}
+
+impl Display for UndefinedTrap {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Undefined instruction: {} of {}",
+            if self.write { "write" } else { "read" },
+            self.register
+        )?;
+        if let Some(exception_level) = self.exception_level {
+            write!(f, " at {exception_level:?}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A lock on the fake system registers of a single PE.
+///
+/// When the guard is dropped, any changes to shared registers are propagated to all other PEs.
+#[derive(Debug)]
+pub struct PeRegistersGuard<'a> {
+    pes: MutexGuard<'a, BTreeMap<u64, Pe>>,
+    pe: u64,
+}
+
+impl<'a> PeRegistersGuard<'a> {
+    fn new(mut pes: MutexGuard<'a, BTreeMap<u64, Pe>>, mpidr: u64) -> Self {
+        if !pes.contains_key(&mpidr) {
+            let mut sysregs = SystemRegisters::new();
+            if let Some(other) = pes.values().next() {
+                sysregs.copy_shared_from(&other.sysregs);
+            }
+            #[cfg(feature = "el1")]
+            sysregs.set_mpidr(mpidr);
+            pes.insert(
+                mpidr,
+                Pe {
+                    sysregs,
+                    policy: AccessPolicy::default(),
+                    undefined: Vec::new(),
+                },
+            );
+        }
+        Self { pes, pe: mpidr }
+    }
+
+    fn pe(&self) -> &Pe {
+        &self.pes[&self.pe]
+    }
+
+    fn pe_mut(&mut self) -> &mut Pe {
+        self.pes.get_mut(&self.pe).unwrap()
+    }
+
+    /// Returns the policy restricting which registers the PE may access.
+    pub fn access_policy(&self) -> &AccessPolicy {
+        &self.pe().policy
+    }
+
+    /// Sets the policy restricting which registers the PE may access.
+    pub fn set_access_policy(&mut self, policy: AccessPolicy) {
+        self.pe_mut().policy = policy;
+    }
+
+    /// Returns and clears the list of Undefined Instruction exceptions recorded on the PE.
+    pub fn take_undefined_traps(&mut self) -> Vec<UndefinedTrap> {
+        mem::take(&mut self.pe_mut().undefined)
+    }
+}
+
+impl Deref for PeRegistersGuard<'_> {
+    type Target = SystemRegisters;
+
+    fn deref(&self) -> &SystemRegisters {
+        &self.pe().sysregs
+    }
+}
+
+impl DerefMut for PeRegistersGuard<'_> {
+    fn deref_mut(&mut self) -> &mut SystemRegisters {
+        &mut self.pe_mut().sysregs
+    }
+}
+
+impl Drop for PeRegistersGuard<'_> {
+    fn drop(&mut self) {
+        if self.pes.len() > 1 {
+            let pe = self.pes.remove(&self.pe).unwrap();
+            for other in self.pes.values_mut() {
+                other.sysregs.copy_shared_from(&pe.sysregs);
+            }
+            self.pes.insert(self.pe, pe);
+        }
+    }
+}
 
 impl SystemRegisters {
     /// Resets the fake system registers to their initial state.
     pub fn reset(&mut self) {
         *self = Self::new();
     }
+
+    /// Sets the MPIDR registers to the given value.
+    #[cfg(feature = "el1")]
+    fn set_mpidr(&mut self, mpidr: u64) {
+        self.mpidr_el1 = crate::MpidrEl1::from_bits_retain(mpidr);
+        // The AArch32 `MPIDR` is mapped to the lower 32 bits of `MPIDR_EL1`.
+        self.mpidr = crate::Mpidr::from_bits_retain(mpidr as u32);
+    }
 }
 
 impl Default for SystemRegisters {
@@ -125,3 +446,444 @@ impl Default for SystemRegisters {
         Self::new()
     }
 }
+
+/// Parses a dump of system register values, such as the output of the `log_all` example.
+///
+/// Each line of the dump should be of the form `name = value`, where `name` is the lowercase name
+/// of the system register and `value` is either an integer (decimal, or hexadecimal or binary with
+/// a `0x` or `0b` prefix) or the `Debug` representation of the register value. Anything before the
+/// register name on a line, such as a log level or timestamp, is ignored, as are lines without a
+/// ` = `, such as the field values which `log_all` prints after each register. Registers not
+/// mentioned in the dump keep their initial value.
+impl FromStr for SystemRegisters {
+    type Err = DumpParseError;
+
+    fn from_str(dump: &str) -> Result<Self, Self::Err> {
+        let mut sysregs = Self::new();
+        for (index, line) in dump.lines().enumerate() {
+            let Some((name, value)) = line.split_once(" = ") else {
+                continue;
+            };
+            let name = name.split_whitespace().last().unwrap_or_default();
+            sysregs.set_dump_value(index + 1, name, value.trim())?;
+        }
+        Ok(sysregs)
+    }
+}
+
+/// Formats the fake system registers as a dump which can be parsed back with [`FromStr`].
+impl Display for SystemRegisters {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.fmt_dump(f)
+    }
+}
+
+/// An error parsing a dump of system register values.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DumpParseError {
+    /// The line refers to a system register which doesn't exist or isn't enabled.
+    UnknownRegister {
+        /// The 1-based line number in the dump.
+        line: usize,
+        /// The name of the system register.
+        name: String,
+    },
+    /// The value on the line couldn't be parsed for the system register.
+    InvalidValue {
+        /// The 1-based line number in the dump.
+        line: usize,
+        /// The value which couldn't be parsed.
+        value: String,
+    },
+}
+
+impl Display for DumpParseError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::UnknownRegister { line, name } => {
+                write!(f, "Unknown system register {name:?} on line {line}")
+            }
+            Self::InvalidValue { line, value } => {
+                write!(f, "Invalid system register value {value:?} on line {line}")
+            }
+        }
+    }
+}
+
+impl Error for DumpParseError {}
+
+/// Parses an integer in decimal, or in hexadecimal or binary with a `0x` or `0b` prefix.
+fn parse_integer(value: &str) -> Option<u64> {
+    let value = value.replace('_', "");
+    if let Some(hex) = value.strip_prefix("0x") {
+        u64::from_str_radix(hex, 16).ok()
+    } else if let Some(binary) = value.strip_prefix("0b") {
+        u64::from_str_radix(binary, 2).ok()
+    } else {
+        value.parse().ok()
+    }
+}
+
+/// Parses a raw integer system register value from a dump.
+fn parse_dump_int<T: TryFrom<u64>>(line: usize, value: &str) -> Result<T, DumpParseError> {
+    parse_integer(value)
+        .and_then(|bits| bits.try_into().ok())
+        .ok_or_else(|| DumpParseError::InvalidValue {
+            line,
+            value: value.to_owned(),
+        })
+}
+
+/// Parses a system register value from a dump, either as a raw integer or in the `Debug` format
+/// generated by `bitflags!`, e.g. `MpidrEl1(MT | U | 0x1200345678)`.
+fn parse_dump_flags<F: Flags>(line: usize, value: &str) -> Result<F, DumpParseError>
+where
+    F::Bits: TryFrom<u64>,
+{
+    let invalid = || DumpParseError::InvalidValue {
+        line,
+        value: value.to_owned(),
+    };
+    let Some(flags) = value
+        .strip_suffix(')')
+        .and_then(|value| value.split_once('('))
+        .map(|(_, flags)| flags)
+    else {
+        return parse_dump_int(line, value).map(F::from_bits_retain);
+    };
+    let mut result = F::empty();
+    for part in flags.split('|').map(str::trim) {
+        if let Some(flag) = F::from_name(part) {
+            result.insert(flag);
+        } else {
+            result.insert(F::from_bits_retain(
+                parse_integer(part)
+                    .and_then(|bits| bits.try_into().ok())
+                    .ok_or_else(invalid)?,
+            ));
+        }
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(all(feature = "el1", feature = "feat-amu"))]
+    #[test]
+    fn parse_dump() {
+        let sysregs = "\
+INFO: actlr = 42
+amcfgr = Amcfgr(HDBG | 0x12)
+    Amcfgr { N: 18, Size: 0, HDBG: true, NCG: 0 }
+amcfgr_el0 = AmcfgrEl0(0x0)
+amcgcr = 0x1234
+"
+        .parse::<SystemRegisters>()
+        .unwrap();
+        assert_eq!(sysregs.actlr, 42);
+        assert_eq!(sysregs.amcfgr.bits(), 0x0100_0012);
+        assert_eq!(sysregs.amcfgr_el0.bits(), 0);
+        assert_eq!(sysregs.amcgcr.bits(), 0x1234);
+        assert_eq!(sysregs.amcr.bits(), 0);
+    }
+
+    #[cfg(feature = "el1")]
+    #[test]
+    fn parse_dump_errors() {
+        assert_eq!(
+            "actlr = 1\nfoo = 1".parse::<SystemRegisters>(),
+            Err(DumpParseError::UnknownRegister {
+                line: 2,
+                name: "foo".to_owned(),
+            })
+        );
+        assert_eq!(
+            "amcfgr = Amcfgr(FOO)".parse::<SystemRegisters>(),
+            Err(DumpParseError::InvalidValue {
+                line: 1,
+                value: "Amcfgr(FOO)".to_owned(),
+            })
+        );
+        assert_eq!(
+            "actlr = 0x100000000".parse::<SystemRegisters>(),
+            Err(DumpParseError::InvalidValue {
+                line: 1,
+                value: "0x100000000".to_owned(),
+            })
+        );
+    }
+
+    #[cfg(feature = "el1")]
+    #[test]
+    fn banked_and_shared_registers() {
+        let system = FakeSystem::new();
+        system.lock_pe(0).unwrap().actlr = 1;
+        system.lock_pe(0).unwrap().cntpct = crate::Cntpct::from_bits_retain(42);
+        assert_eq!(system.lock_pe(0x101).unwrap().actlr, 0);
+        assert_eq!(system.lock_pe(0x101).unwrap().cntpct.bits(), 42);
+        assert_eq!(system.lock_pe(0x101).unwrap().mpidr.bits(), 0x101);
+
+        system.lock_pe(0x101).unwrap().cntpct = crate::Cntpct::from_bits_retain(43);
+        assert_eq!(system.lock_pe(0).unwrap().cntpct.bits(), 43);
+        assert_eq!(system.pes(), [0, 0x101]);
+
+        system.reset();
+        assert_eq!(system.pes(), [0u64; 0]);
+    }
+
+    #[cfg(feature = "el1")]
+    #[test]
+    fn current_pe_per_thread() {
+        const SECONDARY: u64 = 0x8000_0203;
+
+        std::thread::spawn(|| {
+            set_current_pe(SECONDARY);
+            assert_eq!(crate::read_mpidr().bits(), 0x8000_0203);
+            SYSREGS.lock().unwrap().actlr = 0x1234;
+        })
+        .join()
+        .unwrap();
+
+        assert_eq!(current_pe(), BOOT_PE);
+        assert_eq!(SYSREGS.lock_pe(SECONDARY).unwrap().actlr, 0x1234);
+        with_current_pe(SECONDARY, || {
+            assert_eq!(current_pe(), SECONDARY);
+            assert_eq!(crate::read_actlr(), 0x1234);
+        });
+        assert_eq!(current_pe(), BOOT_PE);
+    }
+
+    #[cfg(feature = "el3")]
+    #[test]
+    fn exception_level_enforcement() {
+        with_current_pe(0x300, || {
+            SYSREGS.lock().unwrap().set_access_policy(AccessPolicy {
+                exception_level: Some(ExceptionLevel::El2),
+                ..Default::default()
+            });
+            crate::read_hcr_el2();
+            crate::read_mpidr_el1();
+            let panic = std::panic::catch_unwind(crate::read_scr_el3).unwrap_err();
+            assert_eq!(
+                panic.downcast_ref::<String>().unwrap(),
+                "Undefined instruction: read of scr_el3 at El2"
+            );
+            // The failed access must not poison the lock.
+            SYSREGS.lock().unwrap();
+        });
+    }
+
+    #[cfg(feature = "el1")]
+    #[test]
+    fn aarch32_exception_level() {
+        with_current_pe(0x304, || {
+            SYSREGS.lock().unwrap().set_access_policy(AccessPolicy {
+                exception_level: Some(ExceptionLevel::El0),
+                ..Default::default()
+            });
+            crate::read_tpidruro();
+            let panic = std::panic::catch_unwind(crate::read_sctlr).unwrap_err();
+            assert_eq!(
+                panic.downcast_ref::<String>().unwrap(),
+                "Undefined instruction: read of sctlr at El0"
+            );
+        });
+    }
+
+    #[cfg(feature = "el1")]
+    #[test]
+    fn read_write_many() {
+        with_current_pe(0x303, || {
+            // SAFETY: The registers are fake.
+            unsafe {
+                crate::write_many!(
+                    sctlr_el1 = crate::SctlrEl1::M,
+                    contextidr_el1 = crate::ContextidrEl1::from_bits_retain(42),
+                );
+            }
+            let (sctlr, contextidr, mpidr) =
+                crate::read_many!(sctlr_el1, contextidr_el1, mpidr_el1);
+            assert_eq!(sctlr, crate::SctlrEl1::M);
+            assert_eq!(contextidr.bits(), 42);
+            assert_eq!(mpidr.bits(), 0x303);
+        });
+    }
+
+    #[cfg(all(feature = "el1", feature = "feat-amu"))]
+    #[test]
+    fn feature_enforcement() {
+        with_current_pe(0x301, || {
+            SYSREGS.lock().unwrap().amcfgr_el0 = crate::AmcfgrEl0::from_bits_retain(42);
+            SYSREGS.lock().unwrap().set_access_policy(AccessPolicy {
+                implemented: Some(crate::CpuFeatures::new()),
+                record_undefined: true,
+                ..Default::default()
+            });
+            assert_eq!(crate::read_actlr(), 0);
+            assert_eq!(crate::read_amcfgr_el0().bits(), 0);
+            assert_eq!(
+                SYSREGS.lock().unwrap().take_undefined_traps(),
+                [UndefinedTrap {
+                    register: "amcfgr_el0",
+                    write: false,
+                    exception_level: None,
+                }]
+            );
+
+            SYSREGS.lock().unwrap().set_access_policy(AccessPolicy {
+                implemented: Some(crate::CpuFeatures::new().with(crate::Feature::Amuv1)),
+                ..Default::default()
+            });
+            assert_eq!(crate::read_amcfgr_el0().bits(), 42);
+        });
+    }
+
+    #[cfg(feature = "feat-amu")]
+    #[test]
+    fn indexed_accessors() {
+        with_current_pe(0x302, || {
+            crate::write_amevcntr1_el0(3, crate::Amevcntr13El0::from_bits_retain(42));
+            assert_eq!(SYSREGS.lock().unwrap().amevcntr13_el0.bits(), 42);
+            assert_eq!(crate::read_amevcntr1_el0(3).unwrap().bits(), 42);
+            assert_eq!(crate::read_amevcntr1_el0(2).unwrap().bits(), 0);
+            assert_eq!(crate::read_amevcntr1_el0(16), None);
+
+            let panic = std::panic::catch_unwind(|| {
+                crate::write_amevcntr1_el0(16, crate::Amevcntr10El0::empty());
+            })
+            .unwrap_err();
+            assert_eq!(
+                panic.downcast_ref::<String>().unwrap(),
+                "Invalid index 16 for AMEVCNTR1<n>_EL0"
+            );
+        });
+    }
+
+    #[cfg(feature = "el1")]
+    #[test]
+    fn dump_round_trip() {
+        let mut sysregs = SystemRegisters::new();
+        sysregs.actlr = 0xdead_beef;
+        sysregs.amcfgr = crate::Amcfgr::HDBG | crate::Amcfgr::from_bits_retain(0x12);
+        let dump = sysregs.to_string();
+        assert!(dump.contains("actlr = 0xdeadbeef\n"));
+        assert!(dump.contains("amcfgr = 0x1000012\n"));
+        assert_eq!(dump.parse::<SystemRegisters>().unwrap(), sysregs);
+    }
+
+    #[cfg(all(feature = "el1", feature = "feat-amu"))]
+    #[test]
+    fn diff_system_registers() {
+        use crate::FieldChange;
+
+        let old = SystemRegisters::new();
+        let mut new = old.clone();
+        new.actlr = 0x42;
+        new.amcfgr = crate::Amcfgr::HDBG;
+        assert_eq!(
+            old.diff(&new),
+            [
+                FieldChange {
+                    register: "ACTLR",
+                    field: None,
+                    old: 0,
+                    new: 0x42,
+                },
+                FieldChange {
+                    register: "AMCFGR",
+                    field: Some("HDBG"),
+                    old: 0,
+                    new: 1,
+                },
+            ]
+        );
+        assert!(new.diff(&new).is_empty());
+
+        // `Amcntenset0El0` is an alias of `Amcntenclr0El0`, but changes should still be reported
+        // with the right register name.
+        assert_eq!(
+            crate::Amcntenset0El0::empty()
+                .diff(crate::Amcntenset0El0::P1, "AMCNTENSET0_EL0")
+                .collect::<Vec<_>>(),
+            [FieldChange {
+                register: "AMCNTENSET0_EL0",
+                field: Some("P1"),
+                old: 0,
+                new: 1,
+            }]
+        );
+        let mut new = old.clone();
+        new.amcntenset0_el0 = crate::Amcntenset0El0::P1;
+        assert_eq!(
+            old.diff(&new),
+            [FieldChange {
+                register: "AMCNTENSET0_EL0",
+                field: Some("P1"),
+                old: 0,
+                new: 1,
+            }]
+        );
+    }
+
+    #[cfg(feature = "feat-amu")]
+    #[test]
+    fn snapshot_capture_features() {
+        with_current_pe(0x401, || {
+            SYSREGS.lock().unwrap().amcfgr_el0 = crate::AmcfgrEl0::HDBG;
+            SYSREGS.lock().unwrap().set_access_policy(AccessPolicy {
+                implemented: Some(crate::CpuFeatures::new()),
+                ..Default::default()
+            });
+
+            // Registers for unimplemented features must not be read, or the access would panic.
+            let snapshot = crate::RegisterSnapshot::capture(&crate::CpuFeatures::new());
+            assert_eq!(snapshot.amcfgr_el0, crate::AmcfgrEl0::empty());
+
+            SYSREGS
+                .lock()
+                .unwrap()
+                .set_access_policy(AccessPolicy::default());
+            let snapshot = crate::RegisterSnapshot::capture(
+                &crate::CpuFeatures::new().with(crate::Feature::Amuv1),
+            );
+            assert_eq!(snapshot.amcfgr_el0, crate::AmcfgrEl0::HDBG);
+        });
+    }
+
+    #[cfg(all(feature = "el1", feature = "serde"))]
+    #[test]
+    fn snapshot_serde_round_trip() {
+        with_current_pe(0x400, || {
+            SYSREGS.lock().unwrap().actlr = 0xdead_beef;
+            SYSREGS.lock().unwrap().amcfgr = crate::Amcfgr::HDBG;
+
+            let snapshot = crate::RegisterSnapshot::capture(&crate::CpuFeatures::new());
+            assert_eq!(snapshot.version, crate::RegisterSnapshot::VERSION);
+            assert_eq!(snapshot.actlr, 0xdead_beef);
+
+            let json = serde_json::to_string(&snapshot).unwrap();
+            assert!(json.contains("\"actlr\":3735928559"));
+            assert!(json.contains("\"amcfgr\":16777216"));
+            assert_eq!(
+                serde_json::from_str::<crate::RegisterSnapshot>(&json).unwrap(),
+                snapshot
+            );
+
+            let sysregs = SYSREGS.lock().unwrap().clone();
+            let json = serde_json::to_string(&sysregs).unwrap();
+            assert_eq!(
+                serde_json::from_str::<SystemRegisters>(&json).unwrap(),
+                sysregs
+            );
+        });
+
+        let partial = serde_json::from_str::<crate::RegisterSnapshot>(r#"{"actlr":42}"#).unwrap();
+        assert_eq!(partial.actlr, 42);
+        assert_eq!(partial.version, 0);
+
+        let newer = format!(r#"{{"version":{}}}"#, crate::RegisterSnapshot::VERSION + 1);
+        assert!(serde_json::from_str::<crate::RegisterSnapshot>(&newer).is_err());
+    }
+}
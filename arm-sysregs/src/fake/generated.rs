@@ -3,77 +3,115 @@
 
 // This file is generated, do not edit manually.
 
+use super::{DumpParseError, parse_dump_flags, parse_dump_int};
+#[cfg(all(feature = "el1", feature = "feat-ras"))]
+use crate::DisrEl1;
+#[cfg(all(feature = "el3", feature = "feat-mpam"))]
+use crate::Mpam3El3;
+#[cfg(all(feature = "el1", feature = "feat-mpam"))]
+use crate::MpamidrEl1;
+#[cfg(feature = "feat-pmu")]
+use crate::PmcrEl0;
+#[cfg(feature = "feat-sve")]
+use crate::Svcr;
+#[cfg(feature = "backend")]
+use crate::SysregId;
 use crate::{
-    Amcfgr, AmcfgrEl0, Amcgcr, AmcgcrEl0, Amcntenclr0, Amcntenclr0El0, Amcntenclr1, Amcntenclr1El0,
-    Amcntenset0, Amcntenset0El0, Amcntenset1, Amcntenset1El0, Amcr, AmcrEl0, Amevcntr00,
-    Amevcntr00El0, Amevcntr01, Amevcntr01El0, Amevcntr02, Amevcntr02El0, Amevcntr03, Amevcntr03El0,
-    Amevcntr10El0, Amevcntr11El0, Amevcntr12El0, Amevcntr13El0, Amevcntr14El0, Amevcntr15El0,
-    Amevcntr16El0, Amevcntr17El0, Amevcntr18El0, Amevcntr19El0, Amevcntr110El0, Amevcntr111El0,
-    Amevcntr112El0, Amevcntr113El0, Amevcntr114El0, Amevcntr115El0, Amevtyper00, Amevtyper00El0,
-    Amevtyper01, Amevtyper01El0, Amevtyper02, Amevtyper02El0, Amevtyper03, Amevtyper03El0,
+    Amcfgr, Amcgcr, Amcntenclr0, Amcntenclr1, Amcntenset0, Amcntenset1, Amcr, Amevcntr00,
+    Amevcntr01, Amevcntr02, Amevcntr03, Amevtyper00, Amevtyper01, Amevtyper02, Amevtyper03,
     Amevtyper10, Amevtyper11, Amevtyper12, Amevtyper13, Amevtyper14, Amevtyper15, Amevtyper16,
     Amevtyper17, Amevtyper18, Amevtyper19, Amevtyper110, Amevtyper111, Amevtyper112, Amevtyper113,
-    Amevtyper114, Amevtyper115, Amuserenr, AmuserenrEl0, Ccsidr, Ccsidr2, Clidr, Cntfrq, CntfrqEl0,
-    Cnthctl, CnthpCtl, CnthpCval, CnthpTval, CnthpsCtl, CnthpsCval, CnthpsTval, CnthvCtl,
-    CnthvCval, CnthvTval, CnthvsCtl, CnthvsCval, CnthvsTval, Cntkctl, CntpCtl, CntpCtlEl0,
-    CntpCval, CntpCvalEl0, CntpTval, CntpTvalEl0, Cntpct, CntpctEl0, Cntpctss, CntpctssEl0,
-    CntvCtl, CntvCtlEl0, CntvCval, CntvCvalEl0, CntvTval, CntvTvalEl0, Cntvct, CntvctEl0, Cntvctss,
-    CntvctssEl0, Cntvoff, Contextidr, Cpacr, Csselr, Ctr, CtrEl0, Currentel, Dacr, Daif,
-    Dbgauthstatus, Dbgclaimclr, Dbgclaimset, Dbgdccint, Dbgdevid, Dbgdevid1, Dbgdidr, Dbgdrar,
-    Dbgdscrext, Dbgdscrint, Dbgdtrrxext, Dbgdtrrxint, Dbgdtrtxext, Dbgdtrtxint, Dbgosdlr,
-    Dbgoseccr, Dbgoslar, Dbgoslsr, Dbgprcr, Dbgvcr, Dfar, Dfsr, Disr, Dit, Dlr, Dspsr, Dspsr2,
-    Erridr, Errselr, Erxaddr, Erxaddr2, Erxctlr, Erxctlr2, Erxfr, Erxfr2, Erxmisc0, Erxmisc1,
-    Erxmisc2, Erxmisc3, Erxmisc4, Erxmisc5, Erxmisc6, Erxmisc7, Erxstatus, Fpcr, Fpmr, Fpsr, Hcptr,
-    Hcr, Hcr2, Hdcr, Hdfar, Hifar, Hmair0, Hmair1, Hpfar, Hrmr, Hsctlr, Hsr, Htcr, Htpidr, Htrfcr,
-    Httbr, Hvbar, IccAsgi1r, IccBpr0, IccBpr1, IccCtlr, IccDir, IccEoir0, IccEoir1, IccHppir0,
-    IccHppir1, IccHsre, IccIar0, IccIar1, IccIgrpen0, IccIgrpen1, IccMctlr, IccMgrpen1, IccMsre,
-    IccPmr, IccRpr, IccSgi0r, IccSgi1r, IccSre, IdDfr0, IdDfr1, IdIsar0, IdIsar1, IdIsar2, IdIsar3,
-    IdIsar4, IdIsar5, IdIsar6, IdMmfr0, IdMmfr1, IdMmfr2, IdMmfr3, IdMmfr4, IdMmfr5, IdPfr0,
-    IdPfr1, IdPfr2, Ifar, Ifsr, Isr, Mair0, Mair1, Midr, Mpidr, Mvbar, Nmrr, Nsacr, Par, Pmccfiltr,
-    Pmccntr, Pmceid0, Pmceid1, Pmceid2, Pmceid3, Pmcntenclr, Pmcntenset, Pmcr, PmcrEl0, Pmintenclr,
-    Pmintenset, Pmmir, Pmovsr, Pmovsset, Pmselr, Pmswinc, Pmuserenr, Pmxevtyper, PorEl0, Prrr, Rmr,
-    Rvbar, Scr, Sctlr, Sdcr, Sder, Svcr, Tlbtr, TpidrEl0, Tpidrprw, TpidrroEl0, Tpidruro, Tpidrurw,
-    Trfcr, Ttbcr, Ttbcr2, Ttbr0, Ttbr1, Vbar, Vdfsr, Vdisr, Vmpidr, Vpidr, Vtcr, Vttbr,
+    Amevtyper114, Amevtyper115, Amuserenr, Cntfrq, CntfrqEl0, CntpCtl, CntpCtlEl0, CntpCval,
+    CntpCvalEl0, CntpTval, CntpTvalEl0, Cntpct, CntpctEl0, Cntpctss, CntpctssEl0, CntvCtl,
+    CntvCtlEl0, CntvCval, CntvCvalEl0, CntvTval, CntvTvalEl0, Cntvct, CntvctEl0, Cntvctss,
+    CntvctssEl0, CtrEl0, Currentel, Daif, Dbgdidr, Dbgdrar, Dbgdscrint, Dbgdtrrxint, Dbgdtrtxint,
+    Dit, Dlr, Dspsr, Fpcr, Fpmr, Fpsr, Pmccfiltr, Pmccntr, Pmceid0, Pmceid1, Pmceid2, Pmceid3,
+    Pmcntenclr, Pmcntenset, Pmcr, Pmovsr, Pmovsset, Pmselr, Pmswinc, Pmuserenr, Pmxevtyper, PorEl0,
+    TpidrEl0, TpidrroEl0, Tpidruro, Tpidrurw,
+};
+#[cfg(feature = "feat-amu")]
+use crate::{
+    AmcfgrEl0, AmcgcrEl0, Amcntenclr0El0, Amcntenclr1El0, Amcntenset0El0, Amcntenset1El0, AmcrEl0,
+    Amevcntr00El0, Amevcntr01El0, Amevcntr02El0, Amevcntr03El0, Amevcntr10El0, Amevcntr11El0,
+    Amevcntr12El0, Amevcntr13El0, Amevcntr14El0, Amevcntr15El0, Amevcntr16El0, Amevcntr17El0,
+    Amevcntr18El0, Amevcntr19El0, Amevcntr110El0, Amevcntr111El0, Amevcntr112El0, Amevcntr113El0,
+    Amevcntr114El0, Amevcntr115El0, Amevtyper00El0, Amevtyper01El0, Amevtyper02El0, Amevtyper03El0,
+    AmuserenrEl0,
 };
 #[cfg(feature = "el1")]
 use crate::{
-    ApiakeyhiEl1, ApiakeyloEl1, CcsidrEl1, ClidrEl1, CntkctlEl1, CntpsCtlEl1, CntpsCvalEl1,
-    CntpsTvalEl1, ContextidrEl1, CpacrEl1, CsselrEl1, DisrEl1, ElrEl1, EsrEl1, FarEl1, GcrEl1,
-    GcscrEl1, IccAp1r0El1, IccAsgi1rEl1, IccBpr0El1, IccBpr1El1, IccCtlrEl1, IccDirEl1,
-    IccEoir0El1, IccEoir1El1, IccHppir0El1, IccHppir1El1, IccIar0El1, IccIar1El1, IccIgrpen0El1,
-    IccIgrpen1El1, IccNmiar1El1, IccPmrEl1, IccRprEl1, IccSgi0rEl1, IccSgi1rEl1, IccSreEl1,
-    IdAa64dfr0El1, IdAa64dfr1El1, IdAa64isar1El1, IdAa64isar2El1, IdAa64mmfr0El1, IdAa64mmfr1El1,
-    IdAa64mmfr2El1, IdAa64mmfr3El1, IdAa64mmfr4El1, IdAa64pfr0El1, IdAa64pfr1El1, IdAa64pfr2El1,
-    IdAa64smfr0El1, IsrEl1, MairEl1, MdccintEl1, MdscrEl1, MidrEl1, MpamidrEl1, MpidrEl1, ParEl1,
-    PfarEl1, PirEl1, Pire0El1, PorEl1, RgsrEl1, S2porEl1, Sctlr2El1, SctlrEl1, SpEl1, SpsrEl1,
-    Tcr2El1, TcrEl1, TfsrEl1, Tfsre0El1, TpidrEl1, Ttbr0El1, Ttbr1El1, VbarEl1,
+    ApiakeyhiEl1, ApiakeyloEl1, Ccsidr, Ccsidr2, CcsidrEl1, Clidr, ClidrEl1, CnthpCtl, CnthpCval,
+    CnthpTval, CnthpsCtl, CnthpsCval, CnthpsTval, CnthvCtl, CnthvCval, CnthvTval, CnthvsCtl,
+    CnthvsCval, CnthvsTval, Cntkctl, CntkctlEl1, CntpsCtlEl1, CntpsCvalEl1, CntpsTvalEl1,
+    Contextidr, ContextidrEl1, Cpacr, CpacrEl1, Csselr, CsselrEl1, Ctr, Dacr, Dbgauthstatus,
+    Dbgclaimclr, Dbgclaimset, Dbgdccint, Dbgdevid, Dbgdevid1, Dbgdscrext, Dbgdtrrxext, Dbgdtrtxext,
+    Dbgosdlr, Dbgoseccr, Dbgoslar, Dbgoslsr, Dbgprcr, Dbgvcr, Dfar, Dfsr, Disr, Dspsr2, ElrEl1,
+    Erridr, Errselr, Erxaddr, Erxaddr2, Erxctlr, Erxctlr2, Erxfr, Erxfr2, Erxmisc0, Erxmisc1,
+    Erxmisc2, Erxmisc3, Erxmisc4, Erxmisc5, Erxmisc6, Erxmisc7, Erxstatus, EsrEl1, FarEl1, GcrEl1,
+    GcscrEl1, IccAsgi1r, IccBpr0, IccBpr1, IccCtlr, IccDir, IccEoir0, IccEoir1, IccHppir0,
+    IccHppir1, IccIar0, IccIar1, IccIgrpen0, IccIgrpen1, IccPmr, IccRpr, IccSgi0r, IccSgi1r,
+    IccSre, IdAa64dfr0El1, IdAa64dfr1El1, IdAa64isar1El1, IdAa64isar2El1, IdAa64mmfr0El1,
+    IdAa64mmfr1El1, IdAa64mmfr2El1, IdAa64mmfr3El1, IdAa64mmfr4El1, IdAa64pfr0El1, IdAa64pfr1El1,
+    IdAa64pfr2El1, IdAa64smfr0El1, IdDfr0, IdDfr1, IdIsar0, IdIsar1, IdIsar2, IdIsar3, IdIsar4,
+    IdIsar5, IdIsar6, IdMmfr0, IdMmfr1, IdMmfr2, IdMmfr3, IdMmfr4, IdMmfr5, IdPfr0, IdPfr1, IdPfr2,
+    Ifar, Ifsr, Isr, IsrEl1, Mair0, Mair1, MairEl1, MdccintEl1, MdscrEl1, Midr, MidrEl1, Mpidr,
+    MpidrEl1, Mvbar, Nmrr, Nsacr, Par, ParEl1, PfarEl1, PirEl1, Pire0El1, Pmintenclr, Pmintenset,
+    Pmmir, PorEl1, Prrr, RgsrEl1, Rmr, Rvbar, S2porEl1, Scr, Sctlr, Sctlr2El1, SctlrEl1, Sdcr,
+    Sder, SpEl1, SpsrEl1, Tcr2El1, TcrEl1, TfsrEl1, Tfsre0El1, Tlbtr, TpidrEl1, Tpidrprw, Trfcr,
+    Ttbcr, Ttbcr2, Ttbr0, Ttbr0El1, Ttbr1, Ttbr1El1, Vbar, VbarEl1, Vdisr, Vmpidr, Vpidr,
 };
 #[cfg(feature = "el2")]
 use crate::{
-    BrbcrEl2, CnthctlEl2, CnthpCtlEl2, CnthpCvalEl2, CnthpTvalEl2, CnthpsCtlEl2, CnthpsCvalEl2,
-    CnthpsTvalEl2, CnthvCtlEl2, CnthvCvalEl2, CnthvTvalEl2, CnthvsCtlEl2, CnthvsCvalEl2,
-    CnthvsTvalEl2, CntpoffEl2, CntvoffEl2, ContextidrEl2, CptrEl2, ElrEl2, ElrHyp, EsrEl2, FarEl2,
-    GcscrEl2, HafgrtrEl2, HcrEl2, HcrxEl2, Hdfgrtr2El2, HdfgrtrEl2, Hdfgwtr2El2, HdfgwtrEl2,
-    Hfgitr2El2, HfgitrEl2, Hfgrtr2El2, HfgrtrEl2, Hfgwtr2El2, HfgwtrEl2, HpfarEl2, IccSreEl2,
-    IchHcrEl2, IchVmcrEl2, MairEl2, MdcrEl2, Mpam2El2, MpamhcrEl2, Mpamvpm0El2, Mpamvpm1El2,
-    Mpamvpm2El2, Mpamvpm3El2, Mpamvpm4El2, Mpamvpm5El2, Mpamvpm6El2, Mpamvpm7El2, MpamvpmvEl2,
-    PfarEl2, PirEl2, Pire0El2, PorEl2, S2pirEl2, Sctlr2El2, SctlrEl2, SpEl2, SpsrEl2, Tcr2El2,
-    TcrEl2, TfsrEl2, TpidrEl2, Ttbr0El2, Ttbr1El2, VbarEl2, VdisrEl2, VmpidrEl2, VpidrEl2,
-    VsesrEl2, VtcrEl2, VttbrEl2,
+    BrbcrEl2, Cnthctl, CnthctlEl2, CnthpCtlEl2, CnthpCvalEl2, CnthpTvalEl2, CnthpsCtlEl2,
+    CnthpsCvalEl2, CnthpsTvalEl2, CnthvCtlEl2, CnthvCvalEl2, CnthvTvalEl2, CnthvsCtlEl2,
+    CnthvsCvalEl2, CnthvsTvalEl2, CntpoffEl2, Cntvoff, CntvoffEl2, ContextidrEl2, CptrEl2, ElrEl2,
+    ElrHyp, EsrEl2, FarEl2, GcscrEl2, HafgrtrEl2, Hcptr, Hcr, Hcr2, HcrEl2, HcrxEl2, Hdcr, Hdfar,
+    Hdfgrtr2El2, HdfgrtrEl2, Hdfgwtr2El2, HdfgwtrEl2, Hfgitr2El2, HfgitrEl2, Hfgrtr2El2, HfgrtrEl2,
+    Hfgwtr2El2, HfgwtrEl2, Hifar, Hmair0, Hmair1, Hpfar, HpfarEl2, Hrmr, Hsctlr, Hsr, Htcr, Htpidr,
+    Htrfcr, Httbr, Hvbar, IccHsre, MairEl2, MdcrEl2, PfarEl2, PirEl2, Pire0El2, PorEl2, S2pirEl2,
+    Sctlr2El2, SctlrEl2, SpEl2, SpsrEl2, Tcr2El2, TcrEl2, TfsrEl2, TpidrEl2, Ttbr0El2, Ttbr1El2,
+    VbarEl2, Vdfsr, VmpidrEl2, VpidrEl2, Vtcr, VtcrEl2, Vttbr, VttbrEl2,
 };
 #[cfg(feature = "el3")]
 use crate::{
-    CptrEl3, EsrEl3, GpccrEl3, GptbrEl3, IccCtlrEl3, IccIgrpen1El3, IccSreEl3, MairEl3, MdcrEl3,
-    Mpam3El3, PirEl3, PorEl3, ScrEl3, Sctlr2El3, SctlrEl3, SmcrEl3, SpsrEl3, TcrEl3, TpidrEl3,
-    Ttbr0El3, ZcrEl3,
+    CptrEl3, EsrEl3, IccMctlr, IccMgrpen1, IccMsre, MairEl3, MdcrEl3, PirEl3, PorEl3, ScrEl3,
+    Sctlr2El3, SctlrEl3, SpsrEl3, TcrEl3, TpidrEl3, Ttbr0El3,
+};
+use crate::{CpuFeatures, ExceptionLevel, Feature, FieldChange};
+#[cfg(all(feature = "el3", feature = "feat-rme"))]
+use crate::{GpccrEl3, GptbrEl3};
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+use crate::{
+    IccAp1r0El1, IccAsgi1rEl1, IccBpr0El1, IccBpr1El1, IccCtlrEl1, IccDirEl1, IccEoir0El1,
+    IccEoir1El1, IccHppir0El1, IccHppir1El1, IccIar0El1, IccIar1El1, IccIgrpen0El1, IccIgrpen1El1,
+    IccNmiar1El1, IccPmrEl1, IccRprEl1, IccSgi0rEl1, IccSgi1rEl1, IccSreEl1,
+};
+#[cfg(all(feature = "el3", feature = "feat-gic"))]
+use crate::{IccCtlrEl3, IccIgrpen1El3, IccSreEl3};
+#[cfg(all(feature = "el2", feature = "feat-gic"))]
+use crate::{IccSreEl2, IchHcrEl2, IchVmcrEl2};
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+use crate::{
+    Mpam2El2, MpamhcrEl2, Mpamvpm0El2, Mpamvpm1El2, Mpamvpm2El2, Mpamvpm3El2, Mpamvpm4El2,
+    Mpamvpm5El2, Mpamvpm6El2, Mpamvpm7El2, MpamvpmvEl2,
 };
+#[cfg(all(feature = "el3", feature = "feat-sve"))]
+use crate::{SmcrEl3, ZcrEl3};
+#[cfg(all(feature = "el2", feature = "feat-ras"))]
+use crate::{VdisrEl2, VsesrEl2};
+use alloc::vec::Vec;
+use core::fmt::{self, Formatter};
 
 /// A set of fake system registers.
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 #[non_exhaustive]
 pub struct SystemRegisters {
+    #[cfg(feature = "el1")]
     /// Fake value for the `ACTLR` system register.
     pub actlr: u32,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ACTLR2` system register.
     pub actlr2: u32,
     #[cfg(feature = "el1")]
@@ -82,6 +120,7 @@ pub struct SystemRegisters {
     #[cfg(feature = "el2")]
     /// Fake value for the `ACTLR_EL2` system register.
     pub actlr_el2: u64,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ADFSR` system register.
     pub adfsr: u32,
     #[cfg(feature = "el1")]
@@ -96,12 +135,16 @@ pub struct SystemRegisters {
     #[cfg(feature = "el2")]
     /// Fake value for the `AFSR1_EL2` system register.
     pub afsr1_el2: u64,
+    #[cfg(feature = "el1")]
     /// Fake value for the `AIDR` system register.
     pub aidr: u32,
+    #[cfg(feature = "el1")]
     /// Fake value for the `AIFSR` system register.
     pub aifsr: u32,
+    #[cfg(feature = "el1")]
     /// Fake value for the `AMAIR0` system register.
     pub amair0: u32,
+    #[cfg(feature = "el1")]
     /// Fake value for the `AMAIR1` system register.
     pub amair1: u32,
     #[cfg(feature = "el1")]
@@ -112,94 +155,125 @@ pub struct SystemRegisters {
     pub amair_el2: u64,
     /// Fake value for the `AMCFGR` system register.
     pub amcfgr: Amcfgr,
+    #[cfg(feature = "feat-amu")]
     /// Fake value for the `AMCFGR_EL0` system register.
     pub amcfgr_el0: AmcfgrEl0,
     /// Fake value for the `AMCGCR` system register.
     pub amcgcr: Amcgcr,
+    #[cfg(feature = "feat-amu")]
     /// Fake value for the `AMCGCR_EL0` system register.
     pub amcgcr_el0: AmcgcrEl0,
     /// Fake value for the `AMCNTENCLR0` system register.
     pub amcntenclr0: Amcntenclr0,
+    #[cfg(feature = "feat-amu")]
     /// Fake value for the `AMCNTENCLR0_EL0` system register.
     pub amcntenclr0_el0: Amcntenclr0El0,
     /// Fake value for the `AMCNTENCLR1` system register.
     pub amcntenclr1: Amcntenclr1,
+    #[cfg(feature = "feat-amu")]
     /// Fake value for the `AMCNTENCLR1_EL0` system register.
     pub amcntenclr1_el0: Amcntenclr1El0,
     /// Fake value for the `AMCNTENSET0` system register.
     pub amcntenset0: Amcntenset0,
+    #[cfg(feature = "feat-amu")]
     /// Fake value for the `AMCNTENSET0_EL0` system register.
     pub amcntenset0_el0: Amcntenset0El0,
     /// Fake value for the `AMCNTENSET1` system register.
     pub amcntenset1: Amcntenset1,
+    #[cfg(feature = "feat-amu")]
     /// Fake value for the `AMCNTENSET1_EL0` system register.
     pub amcntenset1_el0: Amcntenset1El0,
     /// Fake value for the `AMCR` system register.
     pub amcr: Amcr,
+    #[cfg(feature = "feat-amu")]
     /// Fake value for the `AMCR_EL0` system register.
     pub amcr_el0: AmcrEl0,
     /// Fake value for the `AMEVCNTR00` system register.
     pub amevcntr00: Amevcntr00,
+    #[cfg(feature = "feat-amu")]
     /// Fake value for the `AMEVCNTR00_EL0` system register.
     pub amevcntr00_el0: Amevcntr00El0,
     /// Fake value for the `AMEVCNTR01` system register.
     pub amevcntr01: Amevcntr01,
+    #[cfg(feature = "feat-amu")]
     /// Fake value for the `AMEVCNTR01_EL0` system register.
     pub amevcntr01_el0: Amevcntr01El0,
     /// Fake value for the `AMEVCNTR02` system register.
     pub amevcntr02: Amevcntr02,
+    #[cfg(feature = "feat-amu")]
     /// Fake value for the `AMEVCNTR02_EL0` system register.
     pub amevcntr02_el0: Amevcntr02El0,
     /// Fake value for the `AMEVCNTR03` system register.
     pub amevcntr03: Amevcntr03,
+    #[cfg(feature = "feat-amu")]
     /// Fake value for the `AMEVCNTR03_EL0` system register.
     pub amevcntr03_el0: Amevcntr03El0,
+    #[cfg(feature = "feat-amu")]
     /// Fake value for the `AMEVCNTR10_EL0` system register.
     pub amevcntr10_el0: Amevcntr10El0,
+    #[cfg(feature = "feat-amu")]
     /// Fake value for the `AMEVCNTR110_EL0` system register.
     pub amevcntr110_el0: Amevcntr110El0,
+    #[cfg(feature = "feat-amu")]
     /// Fake value for the `AMEVCNTR111_EL0` system register.
     pub amevcntr111_el0: Amevcntr111El0,
+    #[cfg(feature = "feat-amu")]
     /// Fake value for the `AMEVCNTR112_EL0` system register.
     pub amevcntr112_el0: Amevcntr112El0,
+    #[cfg(feature = "feat-amu")]
     /// Fake value for the `AMEVCNTR113_EL0` system register.
     pub amevcntr113_el0: Amevcntr113El0,
+    #[cfg(feature = "feat-amu")]
     /// Fake value for the `AMEVCNTR114_EL0` system register.
     pub amevcntr114_el0: Amevcntr114El0,
+    #[cfg(feature = "feat-amu")]
     /// Fake value for the `AMEVCNTR115_EL0` system register.
     pub amevcntr115_el0: Amevcntr115El0,
+    #[cfg(feature = "feat-amu")]
     /// Fake value for the `AMEVCNTR11_EL0` system register.
     pub amevcntr11_el0: Amevcntr11El0,
+    #[cfg(feature = "feat-amu")]
     /// Fake value for the `AMEVCNTR12_EL0` system register.
     pub amevcntr12_el0: Amevcntr12El0,
+    #[cfg(feature = "feat-amu")]
     /// Fake value for the `AMEVCNTR13_EL0` system register.
     pub amevcntr13_el0: Amevcntr13El0,
+    #[cfg(feature = "feat-amu")]
     /// Fake value for the `AMEVCNTR14_EL0` system register.
     pub amevcntr14_el0: Amevcntr14El0,
+    #[cfg(feature = "feat-amu")]
     /// Fake value for the `AMEVCNTR15_EL0` system register.
     pub amevcntr15_el0: Amevcntr15El0,
+    #[cfg(feature = "feat-amu")]
     /// Fake value for the `AMEVCNTR16_EL0` system register.
     pub amevcntr16_el0: Amevcntr16El0,
+    #[cfg(feature = "feat-amu")]
     /// Fake value for the `AMEVCNTR17_EL0` system register.
     pub amevcntr17_el0: Amevcntr17El0,
+    #[cfg(feature = "feat-amu")]
     /// Fake value for the `AMEVCNTR18_EL0` system register.
     pub amevcntr18_el0: Amevcntr18El0,
+    #[cfg(feature = "feat-amu")]
     /// Fake value for the `AMEVCNTR19_EL0` system register.
     pub amevcntr19_el0: Amevcntr19El0,
     /// Fake value for the `AMEVTYPER00` system register.
     pub amevtyper00: Amevtyper00,
+    #[cfg(feature = "feat-amu")]
     /// Fake value for the `AMEVTYPER00_EL0` system register.
     pub amevtyper00_el0: Amevtyper00El0,
     /// Fake value for the `AMEVTYPER01` system register.
     pub amevtyper01: Amevtyper01,
+    #[cfg(feature = "feat-amu")]
     /// Fake value for the `AMEVTYPER01_EL0` system register.
     pub amevtyper01_el0: Amevtyper01El0,
     /// Fake value for the `AMEVTYPER02` system register.
     pub amevtyper02: Amevtyper02,
+    #[cfg(feature = "feat-amu")]
     /// Fake value for the `AMEVTYPER02_EL0` system register.
     pub amevtyper02_el0: Amevtyper02El0,
     /// Fake value for the `AMEVTYPER03` system register.
     pub amevtyper03: Amevtyper03,
+    #[cfg(feature = "feat-amu")]
     /// Fake value for the `AMEVTYPER03_EL0` system register.
     pub amevtyper03_el0: Amevtyper03El0,
     /// Fake value for the `AMEVTYPER10` system register.
@@ -236,6 +310,7 @@ pub struct SystemRegisters {
     pub amevtyper19: Amevtyper19,
     /// Fake value for the `AMUSERENR` system register.
     pub amuserenr: Amuserenr,
+    #[cfg(feature = "feat-amu")]
     /// Fake value for the `AMUSERENR_EL0` system register.
     pub amuserenr_el0: AmuserenrEl0,
     #[cfg(feature = "el1")]
@@ -247,13 +322,16 @@ pub struct SystemRegisters {
     #[cfg(feature = "el2")]
     /// Fake value for the `BRBCR_EL2` system register.
     pub brbcr_el2: BrbcrEl2,
+    #[cfg(feature = "el1")]
     /// Fake value for the `CCSIDR` system register.
     pub ccsidr: Ccsidr,
+    #[cfg(feature = "el1")]
     /// Fake value for the `CCSIDR2` system register.
     pub ccsidr2: Ccsidr2,
     #[cfg(feature = "el1")]
     /// Fake value for the `CCSIDR_EL1` system register.
     pub ccsidr_el1: CcsidrEl1,
+    #[cfg(feature = "el1")]
     /// Fake value for the `CLIDR` system register.
     pub clidr: Clidr,
     #[cfg(feature = "el1")]
@@ -263,71 +341,85 @@ pub struct SystemRegisters {
     pub cntfrq: Cntfrq,
     /// Fake value for the `CNTFRQ_EL0` system register.
     pub cntfrq_el0: CntfrqEl0,
+    #[cfg(feature = "el2")]
     /// Fake value for the `CNTHCTL` system register.
     pub cnthctl: Cnthctl,
     #[cfg(feature = "el2")]
     /// Fake value for the `CNTHCTL_EL2` system register.
     pub cnthctl_el2: CnthctlEl2,
+    #[cfg(feature = "el1")]
     /// Fake value for the `CNTHPS_CTL` system register.
     pub cnthps_ctl: CnthpsCtl,
     #[cfg(feature = "el2")]
     /// Fake value for the `CNTHPS_CTL_EL2` system register.
     pub cnthps_ctl_el2: CnthpsCtlEl2,
+    #[cfg(feature = "el1")]
     /// Fake value for the `CNTHPS_CVAL` system register.
     pub cnthps_cval: CnthpsCval,
     #[cfg(feature = "el2")]
     /// Fake value for the `CNTHPS_CVAL_EL2` system register.
     pub cnthps_cval_el2: CnthpsCvalEl2,
+    #[cfg(feature = "el1")]
     /// Fake value for the `CNTHPS_TVAL` system register.
     pub cnthps_tval: CnthpsTval,
     #[cfg(feature = "el2")]
     /// Fake value for the `CNTHPS_TVAL_EL2` system register.
     pub cnthps_tval_el2: CnthpsTvalEl2,
+    #[cfg(feature = "el1")]
     /// Fake value for the `CNTHP_CTL` system register.
     pub cnthp_ctl: CnthpCtl,
     #[cfg(feature = "el2")]
     /// Fake value for the `CNTHP_CTL_EL2` system register.
     pub cnthp_ctl_el2: CnthpCtlEl2,
+    #[cfg(feature = "el1")]
     /// Fake value for the `CNTHP_CVAL` system register.
     pub cnthp_cval: CnthpCval,
     #[cfg(feature = "el2")]
     /// Fake value for the `CNTHP_CVAL_EL2` system register.
     pub cnthp_cval_el2: CnthpCvalEl2,
+    #[cfg(feature = "el1")]
     /// Fake value for the `CNTHP_TVAL` system register.
     pub cnthp_tval: CnthpTval,
     #[cfg(feature = "el2")]
     /// Fake value for the `CNTHP_TVAL_EL2` system register.
     pub cnthp_tval_el2: CnthpTvalEl2,
+    #[cfg(feature = "el1")]
     /// Fake value for the `CNTHVS_CTL` system register.
     pub cnthvs_ctl: CnthvsCtl,
     #[cfg(feature = "el2")]
     /// Fake value for the `CNTHVS_CTL_EL2` system register.
     pub cnthvs_ctl_el2: CnthvsCtlEl2,
+    #[cfg(feature = "el1")]
     /// Fake value for the `CNTHVS_CVAL` system register.
     pub cnthvs_cval: CnthvsCval,
     #[cfg(feature = "el2")]
     /// Fake value for the `CNTHVS_CVAL_EL2` system register.
     pub cnthvs_cval_el2: CnthvsCvalEl2,
+    #[cfg(feature = "el1")]
     /// Fake value for the `CNTHVS_TVAL` system register.
     pub cnthvs_tval: CnthvsTval,
     #[cfg(feature = "el2")]
     /// Fake value for the `CNTHVS_TVAL_EL2` system register.
     pub cnthvs_tval_el2: CnthvsTvalEl2,
+    #[cfg(feature = "el1")]
     /// Fake value for the `CNTHV_CTL` system register.
     pub cnthv_ctl: CnthvCtl,
     #[cfg(feature = "el2")]
     /// Fake value for the `CNTHV_CTL_EL2` system register.
     pub cnthv_ctl_el2: CnthvCtlEl2,
+    #[cfg(feature = "el1")]
     /// Fake value for the `CNTHV_CVAL` system register.
     pub cnthv_cval: CnthvCval,
     #[cfg(feature = "el2")]
     /// Fake value for the `CNTHV_CVAL_EL2` system register.
     pub cnthv_cval_el2: CnthvCvalEl2,
+    #[cfg(feature = "el1")]
     /// Fake value for the `CNTHV_TVAL` system register.
     pub cnthv_tval: CnthvTval,
     #[cfg(feature = "el2")]
     /// Fake value for the `CNTHV_TVAL_EL2` system register.
     pub cnthv_tval_el2: CnthvTvalEl2,
+    #[cfg(feature = "el1")]
     /// Fake value for the `CNTKCTL` system register.
     pub cntkctl: Cntkctl,
     #[cfg(feature = "el1")]
@@ -373,6 +465,7 @@ pub struct SystemRegisters {
     pub cntvctss_el0: CntvctssEl0,
     /// Fake value for the `CNTVCT_EL0` system register.
     pub cntvct_el0: CntvctEl0,
+    #[cfg(feature = "el2")]
     /// Fake value for the `CNTVOFF` system register.
     pub cntvoff: Cntvoff,
     #[cfg(feature = "el2")]
@@ -390,6 +483,7 @@ pub struct SystemRegisters {
     pub cntv_tval: CntvTval,
     /// Fake value for the `CNTV_TVAL_EL0` system register.
     pub cntv_tval_el0: CntvTvalEl0,
+    #[cfg(feature = "el1")]
     /// Fake value for the `CONTEXTIDR` system register.
     pub contextidr: Contextidr,
     #[cfg(feature = "el1")]
@@ -398,6 +492,7 @@ pub struct SystemRegisters {
     #[cfg(feature = "el2")]
     /// Fake value for the `CONTEXTIDR_EL2` system register.
     pub contextidr_el2: ContextidrEl2,
+    #[cfg(feature = "el1")]
     /// Fake value for the `CPACR` system register.
     pub cpacr: Cpacr,
     #[cfg(feature = "el1")]
@@ -409,33 +504,43 @@ pub struct SystemRegisters {
     #[cfg(feature = "el3")]
     /// Fake value for the `CPTR_EL3` system register.
     pub cptr_el3: CptrEl3,
+    #[cfg(feature = "el1")]
     /// Fake value for the `CSSELR` system register.
     pub csselr: Csselr,
     #[cfg(feature = "el1")]
     /// Fake value for the `CSSELR_EL1` system register.
     pub csselr_el1: CsselrEl1,
+    #[cfg(feature = "el1")]
     /// Fake value for the `CTR` system register.
     pub ctr: Ctr,
     /// Fake value for the `CTR_EL0` system register.
     pub ctr_el0: CtrEl0,
     /// Fake value for the `CurrentEL` system register.
     pub currentel: Currentel,
+    #[cfg(feature = "el1")]
     /// Fake value for the `DACR` system register.
     pub dacr: Dacr,
     /// Fake value for the `DAIF` system register.
     pub daif: Daif,
+    #[cfg(feature = "el1")]
     /// Fake value for the `DBGAUTHSTATUS` system register.
     pub dbgauthstatus: Dbgauthstatus,
+    #[cfg(feature = "el1")]
     /// Fake value for the `DBGCLAIMCLR` system register.
     pub dbgclaimclr: Dbgclaimclr,
+    #[cfg(feature = "el1")]
     /// Fake value for the `DBGCLAIMSET` system register.
     pub dbgclaimset: Dbgclaimset,
+    #[cfg(feature = "el1")]
     /// Fake value for the `DBGDCCINT` system register.
     pub dbgdccint: Dbgdccint,
+    #[cfg(feature = "el1")]
     /// Fake value for the `DBGDEVID` system register.
     pub dbgdevid: Dbgdevid,
+    #[cfg(feature = "el1")]
     /// Fake value for the `DBGDEVID1` system register.
     pub dbgdevid1: Dbgdevid1,
+    #[cfg(feature = "el1")]
     /// Fake value for the `DBGDEVID2` system register.
     pub dbgdevid2: u32,
     /// Fake value for the `DBGDIDR` system register.
@@ -444,39 +549,52 @@ pub struct SystemRegisters {
     pub dbgdrar: Dbgdrar,
     /// Fake value for the `DBGDSAR` system register.
     pub dbgdsar: u64,
+    #[cfg(feature = "el1")]
     /// Fake value for the `DBGDSCRext` system register.
     pub dbgdscrext: Dbgdscrext,
     /// Fake value for the `DBGDSCRint` system register.
     pub dbgdscrint: Dbgdscrint,
+    #[cfg(feature = "el1")]
     /// Fake value for the `DBGDTRRXext` system register.
     pub dbgdtrrxext: Dbgdtrrxext,
     /// Fake value for the `DBGDTRRXint` system register.
     pub dbgdtrrxint: Dbgdtrrxint,
+    #[cfg(feature = "el1")]
     /// Fake value for the `DBGDTRTXext` system register.
     pub dbgdtrtxext: Dbgdtrtxext,
     /// Fake value for the `DBGDTRTXint` system register.
     pub dbgdtrtxint: Dbgdtrtxint,
+    #[cfg(feature = "el1")]
     /// Fake value for the `DBGOSDLR` system register.
     pub dbgosdlr: Dbgosdlr,
+    #[cfg(feature = "el1")]
     /// Fake value for the `DBGOSECCR` system register.
     pub dbgoseccr: Dbgoseccr,
+    #[cfg(feature = "el1")]
     /// Fake value for the `DBGOSLAR` system register.
     pub dbgoslar: Dbgoslar,
+    #[cfg(feature = "el1")]
     /// Fake value for the `DBGOSLSR` system register.
     pub dbgoslsr: Dbgoslsr,
+    #[cfg(feature = "el1")]
     /// Fake value for the `DBGPRCR` system register.
     pub dbgprcr: Dbgprcr,
+    #[cfg(feature = "el1")]
     /// Fake value for the `DBGVCR` system register.
     pub dbgvcr: Dbgvcr,
+    #[cfg(feature = "el1")]
     /// Fake value for the `DBGWFAR` system register.
     pub dbgwfar: u32,
+    #[cfg(feature = "el1")]
     /// Fake value for the `DFAR` system register.
     pub dfar: Dfar,
+    #[cfg(feature = "el1")]
     /// Fake value for the `DFSR` system register.
     pub dfsr: Dfsr,
+    #[cfg(feature = "el1")]
     /// Fake value for the `DISR` system register.
     pub disr: Disr,
-    #[cfg(feature = "el1")]
+    #[cfg(all(feature = "el1", feature = "feat-ras"))]
     /// Fake value for the `DISR_EL1` system register.
     pub disr_el1: DisrEl1,
     /// Fake value for the `DIT` system register.
@@ -485,6 +603,7 @@ pub struct SystemRegisters {
     pub dlr: Dlr,
     /// Fake value for the `DSPSR` system register.
     pub dspsr: Dspsr,
+    #[cfg(feature = "el1")]
     /// Fake value for the `DSPSR2` system register.
     pub dspsr2: Dspsr2,
     #[cfg(feature = "el1")]
@@ -496,38 +615,55 @@ pub struct SystemRegisters {
     #[cfg(feature = "el2")]
     /// Fake value for the `ELR_hyp` system register.
     pub elr_hyp: ElrHyp,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ERRIDR` system register.
     pub erridr: Erridr,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ERRSELR` system register.
     pub errselr: Errselr,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ERXADDR` system register.
     pub erxaddr: Erxaddr,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ERXADDR2` system register.
     pub erxaddr2: Erxaddr2,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ERXCTLR` system register.
     pub erxctlr: Erxctlr,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ERXCTLR2` system register.
     pub erxctlr2: Erxctlr2,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ERXFR` system register.
     pub erxfr: Erxfr,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ERXFR2` system register.
     pub erxfr2: Erxfr2,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ERXMISC0` system register.
     pub erxmisc0: Erxmisc0,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ERXMISC1` system register.
     pub erxmisc1: Erxmisc1,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ERXMISC2` system register.
     pub erxmisc2: Erxmisc2,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ERXMISC3` system register.
     pub erxmisc3: Erxmisc3,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ERXMISC4` system register.
     pub erxmisc4: Erxmisc4,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ERXMISC5` system register.
     pub erxmisc5: Erxmisc5,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ERXMISC6` system register.
     pub erxmisc6: Erxmisc6,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ERXMISC7` system register.
     pub erxmisc7: Erxmisc7,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ERXSTATUS` system register.
     pub erxstatus: Erxstatus,
     #[cfg(feature = "el1")]
@@ -545,6 +681,7 @@ pub struct SystemRegisters {
     #[cfg(feature = "el2")]
     /// Fake value for the `FAR_EL2` system register.
     pub far_el2: FarEl2,
+    #[cfg(feature = "el1")]
     /// Fake value for the `FCSEIDR` system register.
     pub fcseidr: u32,
     /// Fake value for the `FPCR` system register.
@@ -562,36 +699,46 @@ pub struct SystemRegisters {
     #[cfg(feature = "el2")]
     /// Fake value for the `GCSCR_EL2` system register.
     pub gcscr_el2: GcscrEl2,
-    #[cfg(feature = "el3")]
+    #[cfg(all(feature = "el3", feature = "feat-rme"))]
     /// Fake value for the `GPCCR_EL3` system register.
     pub gpccr_el3: GpccrEl3,
-    #[cfg(feature = "el3")]
+    #[cfg(all(feature = "el3", feature = "feat-rme"))]
     /// Fake value for the `GPTBR_EL3` system register.
     pub gptbr_el3: GptbrEl3,
+    #[cfg(feature = "el2")]
     /// Fake value for the `HACR` system register.
     pub hacr: u32,
     #[cfg(feature = "el2")]
     /// Fake value for the `HACR_EL2` system register.
     pub hacr_el2: u64,
+    #[cfg(feature = "el2")]
     /// Fake value for the `HACTLR` system register.
     pub hactlr: u32,
+    #[cfg(feature = "el2")]
     /// Fake value for the `HACTLR2` system register.
     pub hactlr2: u32,
+    #[cfg(feature = "el2")]
     /// Fake value for the `HADFSR` system register.
     pub hadfsr: u32,
     #[cfg(feature = "el2")]
     /// Fake value for the `HAFGRTR_EL2` system register.
     pub hafgrtr_el2: HafgrtrEl2,
+    #[cfg(feature = "el2")]
     /// Fake value for the `HAIFSR` system register.
     pub haifsr: u32,
+    #[cfg(feature = "el2")]
     /// Fake value for the `HAMAIR0` system register.
     pub hamair0: u32,
+    #[cfg(feature = "el2")]
     /// Fake value for the `HAMAIR1` system register.
     pub hamair1: u32,
+    #[cfg(feature = "el2")]
     /// Fake value for the `HCPTR` system register.
     pub hcptr: Hcptr,
+    #[cfg(feature = "el2")]
     /// Fake value for the `HCR` system register.
     pub hcr: Hcr,
+    #[cfg(feature = "el2")]
     /// Fake value for the `HCR2` system register.
     pub hcr2: Hcr2,
     #[cfg(feature = "el2")]
@@ -600,8 +747,10 @@ pub struct SystemRegisters {
     #[cfg(feature = "el2")]
     /// Fake value for the `HCR_EL2` system register.
     pub hcr_el2: HcrEl2,
+    #[cfg(feature = "el2")]
     /// Fake value for the `HDCR` system register.
     pub hdcr: Hdcr,
+    #[cfg(feature = "el2")]
     /// Fake value for the `HDFAR` system register.
     pub hdfar: Hdfar,
     #[cfg(feature = "el2")]
@@ -634,179 +783,214 @@ pub struct SystemRegisters {
     #[cfg(feature = "el2")]
     /// Fake value for the `HFGWTR_EL2` system register.
     pub hfgwtr_el2: HfgwtrEl2,
+    #[cfg(feature = "el2")]
     /// Fake value for the `HIFAR` system register.
     pub hifar: Hifar,
+    #[cfg(feature = "el2")]
     /// Fake value for the `HMAIR0` system register.
     pub hmair0: Hmair0,
+    #[cfg(feature = "el2")]
     /// Fake value for the `HMAIR1` system register.
     pub hmair1: Hmair1,
+    #[cfg(feature = "el2")]
     /// Fake value for the `HPFAR` system register.
     pub hpfar: Hpfar,
     #[cfg(feature = "el2")]
     /// Fake value for the `HPFAR_EL2` system register.
     pub hpfar_el2: HpfarEl2,
+    #[cfg(feature = "el2")]
     /// Fake value for the `HRMR` system register.
     pub hrmr: Hrmr,
+    #[cfg(feature = "el2")]
     /// Fake value for the `HSCTLR` system register.
     pub hsctlr: Hsctlr,
+    #[cfg(feature = "el2")]
     /// Fake value for the `HSR` system register.
     pub hsr: Hsr,
+    #[cfg(feature = "el2")]
     /// Fake value for the `HSTR` system register.
     pub hstr: u32,
     #[cfg(feature = "el2")]
     /// Fake value for the `HSTR_EL2` system register.
     pub hstr_el2: u64,
+    #[cfg(feature = "el2")]
     /// Fake value for the `HTCR` system register.
     pub htcr: Htcr,
+    #[cfg(feature = "el2")]
     /// Fake value for the `HTPIDR` system register.
     pub htpidr: Htpidr,
+    #[cfg(feature = "el2")]
     /// Fake value for the `HTRFCR` system register.
     pub htrfcr: Htrfcr,
+    #[cfg(feature = "el2")]
     /// Fake value for the `HTTBR` system register.
     pub httbr: Httbr,
+    #[cfg(feature = "el2")]
     /// Fake value for the `HVBAR` system register.
     pub hvbar: Hvbar,
-    #[cfg(feature = "el1")]
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
     /// Fake value for the `ICC_AP0R0_EL1` system register.
     pub icc_ap0r0_el1: u64,
-    #[cfg(feature = "el1")]
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
     /// Fake value for the `ICC_AP0R1_EL1` system register.
     pub icc_ap0r1_el1: u64,
-    #[cfg(feature = "el1")]
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
     /// Fake value for the `ICC_AP0R2_EL1` system register.
     pub icc_ap0r2_el1: u64,
-    #[cfg(feature = "el1")]
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
     /// Fake value for the `ICC_AP0R3_EL1` system register.
     pub icc_ap0r3_el1: u64,
-    #[cfg(feature = "el1")]
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
     /// Fake value for the `ICC_AP1R0_EL1` system register.
     pub icc_ap1r0_el1: IccAp1r0El1,
-    #[cfg(feature = "el1")]
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
     /// Fake value for the `ICC_AP1R1_EL1` system register.
     pub icc_ap1r1_el1: u64,
-    #[cfg(feature = "el1")]
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
     /// Fake value for the `ICC_AP1R2_EL1` system register.
     pub icc_ap1r2_el1: u64,
-    #[cfg(feature = "el1")]
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
     /// Fake value for the `ICC_AP1R3_EL1` system register.
     pub icc_ap1r3_el1: u64,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ICC_ASGI1R` system register.
     pub icc_asgi1r: IccAsgi1r,
-    #[cfg(feature = "el1")]
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
     /// Fake value for the `ICC_ASGI1R_EL1` system register.
     pub icc_asgi1r_el1: IccAsgi1rEl1,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ICC_BPR0` system register.
     pub icc_bpr0: IccBpr0,
-    #[cfg(feature = "el1")]
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
     /// Fake value for the `ICC_BPR0_EL1` system register.
     pub icc_bpr0_el1: IccBpr0El1,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ICC_BPR1` system register.
     pub icc_bpr1: IccBpr1,
-    #[cfg(feature = "el1")]
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
     /// Fake value for the `ICC_BPR1_EL1` system register.
     pub icc_bpr1_el1: IccBpr1El1,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ICC_CTLR` system register.
     pub icc_ctlr: IccCtlr,
-    #[cfg(feature = "el1")]
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
     /// Fake value for the `ICC_CTLR_EL1` system register.
     pub icc_ctlr_el1: IccCtlrEl1,
-    #[cfg(feature = "el3")]
+    #[cfg(all(feature = "el3", feature = "feat-gic"))]
     /// Fake value for the `ICC_CTLR_EL3` system register.
     pub icc_ctlr_el3: IccCtlrEl3,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ICC_DIR` system register.
     pub icc_dir: IccDir,
-    #[cfg(feature = "el1")]
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
     /// Fake value for the `ICC_DIR_EL1` system register.
     pub icc_dir_el1: IccDirEl1,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ICC_EOIR0` system register.
     pub icc_eoir0: IccEoir0,
-    #[cfg(feature = "el1")]
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
     /// Fake value for the `ICC_EOIR0_EL1` system register.
     pub icc_eoir0_el1: IccEoir0El1,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ICC_EOIR1` system register.
     pub icc_eoir1: IccEoir1,
-    #[cfg(feature = "el1")]
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
     /// Fake value for the `ICC_EOIR1_EL1` system register.
     pub icc_eoir1_el1: IccEoir1El1,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ICC_HPPIR0` system register.
     pub icc_hppir0: IccHppir0,
-    #[cfg(feature = "el1")]
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
     /// Fake value for the `ICC_HPPIR0_EL1` system register.
     pub icc_hppir0_el1: IccHppir0El1,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ICC_HPPIR1` system register.
     pub icc_hppir1: IccHppir1,
-    #[cfg(feature = "el1")]
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
     /// Fake value for the `ICC_HPPIR1_EL1` system register.
     pub icc_hppir1_el1: IccHppir1El1,
+    #[cfg(feature = "el2")]
     /// Fake value for the `ICC_HSRE` system register.
     pub icc_hsre: IccHsre,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ICC_IAR0` system register.
     pub icc_iar0: IccIar0,
-    #[cfg(feature = "el1")]
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
     /// Fake value for the `ICC_IAR0_EL1` system register.
     pub icc_iar0_el1: IccIar0El1,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ICC_IAR1` system register.
     pub icc_iar1: IccIar1,
-    #[cfg(feature = "el1")]
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
     /// Fake value for the `ICC_IAR1_EL1` system register.
     pub icc_iar1_el1: IccIar1El1,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ICC_IGRPEN0` system register.
     pub icc_igrpen0: IccIgrpen0,
-    #[cfg(feature = "el1")]
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
     /// Fake value for the `ICC_IGRPEN0_EL1` system register.
     pub icc_igrpen0_el1: IccIgrpen0El1,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ICC_IGRPEN1` system register.
     pub icc_igrpen1: IccIgrpen1,
-    #[cfg(feature = "el1")]
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
     /// Fake value for the `ICC_IGRPEN1_EL1` system register.
     pub icc_igrpen1_el1: IccIgrpen1El1,
-    #[cfg(feature = "el3")]
+    #[cfg(all(feature = "el3", feature = "feat-gic"))]
     /// Fake value for the `ICC_IGRPEN1_EL3` system register.
     pub icc_igrpen1_el3: IccIgrpen1El3,
+    #[cfg(feature = "el3")]
     /// Fake value for the `ICC_MCTLR` system register.
     pub icc_mctlr: IccMctlr,
+    #[cfg(feature = "el3")]
     /// Fake value for the `ICC_MGRPEN1` system register.
     pub icc_mgrpen1: IccMgrpen1,
+    #[cfg(feature = "el3")]
     /// Fake value for the `ICC_MSRE` system register.
     pub icc_msre: IccMsre,
-    #[cfg(feature = "el1")]
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
     /// Fake value for the `ICC_NMIAR1_EL1` system register.
     pub icc_nmiar1_el1: IccNmiar1El1,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ICC_PMR` system register.
     pub icc_pmr: IccPmr,
-    #[cfg(feature = "el1")]
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
     /// Fake value for the `ICC_PMR_EL1` system register.
     pub icc_pmr_el1: IccPmrEl1,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ICC_RPR` system register.
     pub icc_rpr: IccRpr,
-    #[cfg(feature = "el1")]
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
     /// Fake value for the `ICC_RPR_EL1` system register.
     pub icc_rpr_el1: IccRprEl1,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ICC_SGI0R` system register.
     pub icc_sgi0r: IccSgi0r,
-    #[cfg(feature = "el1")]
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
     /// Fake value for the `ICC_SGI0R_EL1` system register.
     pub icc_sgi0r_el1: IccSgi0rEl1,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ICC_SGI1R` system register.
     pub icc_sgi1r: IccSgi1r,
-    #[cfg(feature = "el1")]
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
     /// Fake value for the `ICC_SGI1R_EL1` system register.
     pub icc_sgi1r_el1: IccSgi1rEl1,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ICC_SRE` system register.
     pub icc_sre: IccSre,
-    #[cfg(feature = "el1")]
+    #[cfg(all(feature = "el1", feature = "feat-gic"))]
     /// Fake value for the `ICC_SRE_EL1` system register.
     pub icc_sre_el1: IccSreEl1,
-    #[cfg(feature = "el2")]
+    #[cfg(all(feature = "el2", feature = "feat-gic"))]
     /// Fake value for the `ICC_SRE_EL2` system register.
     pub icc_sre_el2: IccSreEl2,
-    #[cfg(feature = "el3")]
+    #[cfg(all(feature = "el3", feature = "feat-gic"))]
     /// Fake value for the `ICC_SRE_EL3` system register.
     pub icc_sre_el3: IccSreEl3,
-    #[cfg(feature = "el2")]
+    #[cfg(all(feature = "el2", feature = "feat-gic"))]
     /// Fake value for the `ICH_HCR_EL2` system register.
     pub ich_hcr_el2: IchHcrEl2,
-    #[cfg(feature = "el2")]
+    #[cfg(all(feature = "el2", feature = "feat-gic"))]
     /// Fake value for the `ICH_VMCR_EL2` system register.
     pub ich_vmcr_el2: IchVmcrEl2,
     #[cfg(feature = "el1")]
@@ -848,61 +1032,88 @@ pub struct SystemRegisters {
     #[cfg(feature = "el1")]
     /// Fake value for the `ID_AA64SMFR0_EL1` system register.
     pub id_aa64smfr0_el1: IdAa64smfr0El1,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ID_AFR0` system register.
     pub id_afr0: u32,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ID_DFR0` system register.
     pub id_dfr0: IdDfr0,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ID_DFR1` system register.
     pub id_dfr1: IdDfr1,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ID_ISAR0` system register.
     pub id_isar0: IdIsar0,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ID_ISAR1` system register.
     pub id_isar1: IdIsar1,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ID_ISAR2` system register.
     pub id_isar2: IdIsar2,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ID_ISAR3` system register.
     pub id_isar3: IdIsar3,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ID_ISAR4` system register.
     pub id_isar4: IdIsar4,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ID_ISAR5` system register.
     pub id_isar5: IdIsar5,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ID_ISAR6` system register.
     pub id_isar6: IdIsar6,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ID_MMFR0` system register.
     pub id_mmfr0: IdMmfr0,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ID_MMFR1` system register.
     pub id_mmfr1: IdMmfr1,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ID_MMFR2` system register.
     pub id_mmfr2: IdMmfr2,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ID_MMFR3` system register.
     pub id_mmfr3: IdMmfr3,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ID_MMFR4` system register.
     pub id_mmfr4: IdMmfr4,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ID_MMFR5` system register.
     pub id_mmfr5: IdMmfr5,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ID_PFR0` system register.
     pub id_pfr0: IdPfr0,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ID_PFR1` system register.
     pub id_pfr1: IdPfr1,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ID_PFR2` system register.
     pub id_pfr2: IdPfr2,
+    #[cfg(feature = "el1")]
     /// Fake value for the `IFAR` system register.
     pub ifar: Ifar,
+    #[cfg(feature = "el1")]
     /// Fake value for the `IFSR` system register.
     pub ifsr: Ifsr,
+    #[cfg(feature = "el1")]
     /// Fake value for the `ISR` system register.
     pub isr: Isr,
     #[cfg(feature = "el1")]
     /// Fake value for the `ISR_EL1` system register.
     pub isr_el1: IsrEl1,
+    #[cfg(feature = "el1")]
     /// Fake value for the `JIDR` system register.
     pub jidr: u32,
+    #[cfg(feature = "el1")]
     /// Fake value for the `JMCR` system register.
     pub jmcr: u32,
+    #[cfg(feature = "el1")]
     /// Fake value for the `JOSCR` system register.
     pub joscr: u32,
+    #[cfg(feature = "el1")]
     /// Fake value for the `MAIR0` system register.
     pub mair0: Mair0,
+    #[cfg(feature = "el1")]
     /// Fake value for the `MAIR1` system register.
     pub mair1: Mair1,
     #[cfg(feature = "el1")]
@@ -926,61 +1137,67 @@ pub struct SystemRegisters {
     #[cfg(feature = "el1")]
     /// Fake value for the `MDSCR_EL1` system register.
     pub mdscr_el1: MdscrEl1,
+    #[cfg(feature = "el1")]
     /// Fake value for the `MIDR` system register.
     pub midr: Midr,
     #[cfg(feature = "el1")]
     /// Fake value for the `MIDR_EL1` system register.
     pub midr_el1: MidrEl1,
-    #[cfg(feature = "el2")]
+    #[cfg(all(feature = "el2", feature = "feat-mpam"))]
     /// Fake value for the `MPAM2_EL2` system register.
     pub mpam2_el2: Mpam2El2,
-    #[cfg(feature = "el3")]
+    #[cfg(all(feature = "el3", feature = "feat-mpam"))]
     /// Fake value for the `MPAM3_EL3` system register.
     pub mpam3_el3: Mpam3El3,
-    #[cfg(feature = "el2")]
+    #[cfg(all(feature = "el2", feature = "feat-mpam"))]
     /// Fake value for the `MPAMHCR_EL2` system register.
     pub mpamhcr_el2: MpamhcrEl2,
-    #[cfg(feature = "el1")]
+    #[cfg(all(feature = "el1", feature = "feat-mpam"))]
     /// Fake value for the `MPAMIDR_EL1` system register.
     pub mpamidr_el1: MpamidrEl1,
-    #[cfg(feature = "el2")]
+    #[cfg(all(feature = "el2", feature = "feat-mpam"))]
     /// Fake value for the `MPAMVPM0_EL2` system register.
     pub mpamvpm0_el2: Mpamvpm0El2,
-    #[cfg(feature = "el2")]
+    #[cfg(all(feature = "el2", feature = "feat-mpam"))]
     /// Fake value for the `MPAMVPM1_EL2` system register.
     pub mpamvpm1_el2: Mpamvpm1El2,
-    #[cfg(feature = "el2")]
+    #[cfg(all(feature = "el2", feature = "feat-mpam"))]
     /// Fake value for the `MPAMVPM2_EL2` system register.
     pub mpamvpm2_el2: Mpamvpm2El2,
-    #[cfg(feature = "el2")]
+    #[cfg(all(feature = "el2", feature = "feat-mpam"))]
     /// Fake value for the `MPAMVPM3_EL2` system register.
     pub mpamvpm3_el2: Mpamvpm3El2,
-    #[cfg(feature = "el2")]
+    #[cfg(all(feature = "el2", feature = "feat-mpam"))]
     /// Fake value for the `MPAMVPM4_EL2` system register.
     pub mpamvpm4_el2: Mpamvpm4El2,
-    #[cfg(feature = "el2")]
+    #[cfg(all(feature = "el2", feature = "feat-mpam"))]
     /// Fake value for the `MPAMVPM5_EL2` system register.
     pub mpamvpm5_el2: Mpamvpm5El2,
-    #[cfg(feature = "el2")]
+    #[cfg(all(feature = "el2", feature = "feat-mpam"))]
     /// Fake value for the `MPAMVPM6_EL2` system register.
     pub mpamvpm6_el2: Mpamvpm6El2,
-    #[cfg(feature = "el2")]
+    #[cfg(all(feature = "el2", feature = "feat-mpam"))]
     /// Fake value for the `MPAMVPM7_EL2` system register.
     pub mpamvpm7_el2: Mpamvpm7El2,
-    #[cfg(feature = "el2")]
+    #[cfg(all(feature = "el2", feature = "feat-mpam"))]
     /// Fake value for the `MPAMVPMV_EL2` system register.
     pub mpamvpmv_el2: MpamvpmvEl2,
+    #[cfg(feature = "el1")]
     /// Fake value for the `MPIDR` system register.
     pub mpidr: Mpidr,
     #[cfg(feature = "el1")]
     /// Fake value for the `MPIDR_EL1` system register.
     pub mpidr_el1: MpidrEl1,
+    #[cfg(feature = "el1")]
     /// Fake value for the `MVBAR` system register.
     pub mvbar: Mvbar,
+    #[cfg(feature = "el1")]
     /// Fake value for the `NMRR` system register.
     pub nmrr: Nmrr,
+    #[cfg(feature = "el1")]
     /// Fake value for the `NSACR` system register.
     pub nsacr: Nsacr,
+    #[cfg(feature = "el1")]
     /// Fake value for the `PAR` system register.
     pub par: Par,
     #[cfg(feature = "el1")]
@@ -1025,12 +1242,16 @@ pub struct SystemRegisters {
     pub pmcntenset: Pmcntenset,
     /// Fake value for the `PMCR` system register.
     pub pmcr: Pmcr,
+    #[cfg(feature = "feat-pmu")]
     /// Fake value for the `PMCR_EL0` system register.
     pub pmcr_el0: PmcrEl0,
+    #[cfg(feature = "el1")]
     /// Fake value for the `PMINTENCLR` system register.
     pub pmintenclr: Pmintenclr,
+    #[cfg(feature = "el1")]
     /// Fake value for the `PMINTENSET` system register.
     pub pmintenset: Pmintenset,
+    #[cfg(feature = "el1")]
     /// Fake value for the `PMMIR` system register.
     pub pmmir: Pmmir,
     /// Fake value for the `PMOVSR` system register.
@@ -1056,15 +1277,19 @@ pub struct SystemRegisters {
     #[cfg(feature = "el3")]
     /// Fake value for the `POR_EL3` system register.
     pub por_el3: PorEl3,
+    #[cfg(feature = "el1")]
     /// Fake value for the `PRRR` system register.
     pub prrr: Prrr,
+    #[cfg(feature = "el1")]
     /// Fake value for the `REVIDR` system register.
     pub revidr: u32,
     #[cfg(feature = "el1")]
     /// Fake value for the `RGSR_EL1` system register.
     pub rgsr_el1: RgsrEl1,
+    #[cfg(feature = "el1")]
     /// Fake value for the `RMR` system register.
     pub rmr: Rmr,
+    #[cfg(feature = "el1")]
     /// Fake value for the `RVBAR` system register.
     pub rvbar: Rvbar,
     #[cfg(feature = "el2")]
@@ -1073,11 +1298,13 @@ pub struct SystemRegisters {
     #[cfg(feature = "el1")]
     /// Fake value for the `S2POR_EL1` system register.
     pub s2por_el1: S2porEl1,
+    #[cfg(feature = "el1")]
     /// Fake value for the `SCR` system register.
     pub scr: Scr,
     #[cfg(feature = "el3")]
     /// Fake value for the `SCR_EL3` system register.
     pub scr_el3: ScrEl3,
+    #[cfg(feature = "el1")]
     /// Fake value for the `SCTLR` system register.
     pub sctlr: Sctlr,
     #[cfg(feature = "el1")]
@@ -1098,11 +1325,13 @@ pub struct SystemRegisters {
     #[cfg(feature = "el3")]
     /// Fake value for the `SCTLR_EL3` system register.
     pub sctlr_el3: SctlrEl3,
+    #[cfg(feature = "el1")]
     /// Fake value for the `SDCR` system register.
     pub sdcr: Sdcr,
+    #[cfg(feature = "el1")]
     /// Fake value for the `SDER` system register.
     pub sder: Sder,
-    #[cfg(feature = "el3")]
+    #[cfg(all(feature = "el3", feature = "feat-sve"))]
     /// Fake value for the `SMCR_EL3` system register.
     pub smcr_el3: SmcrEl3,
     #[cfg(feature = "el1")]
@@ -1120,8 +1349,10 @@ pub struct SystemRegisters {
     #[cfg(feature = "el2")]
     /// Fake value for the `SP_EL2` system register.
     pub sp_el2: SpEl2,
+    #[cfg(feature = "feat-sve")]
     /// Fake value for the `SVCR` system register.
     pub svcr: Svcr,
+    #[cfg(feature = "el1")]
     /// Fake value for the `TCMTR` system register.
     pub tcmtr: u32,
     #[cfg(feature = "el1")]
@@ -1148,8 +1379,10 @@ pub struct SystemRegisters {
     #[cfg(feature = "el2")]
     /// Fake value for the `TFSR_EL2` system register.
     pub tfsr_el2: TfsrEl2,
+    #[cfg(feature = "el1")]
     /// Fake value for the `TLBTR` system register.
     pub tlbtr: Tlbtr,
+    #[cfg(feature = "el1")]
     /// Fake value for the `TPIDRPRW` system register.
     pub tpidrprw: Tpidrprw,
     /// Fake value for the `TPIDRRO_EL0` system register.
@@ -1169,12 +1402,16 @@ pub struct SystemRegisters {
     #[cfg(feature = "el3")]
     /// Fake value for the `TPIDR_EL3` system register.
     pub tpidr_el3: TpidrEl3,
+    #[cfg(feature = "el1")]
     /// Fake value for the `TRFCR` system register.
     pub trfcr: Trfcr,
+    #[cfg(feature = "el1")]
     /// Fake value for the `TTBCR` system register.
     pub ttbcr: Ttbcr,
+    #[cfg(feature = "el1")]
     /// Fake value for the `TTBCR2` system register.
     pub ttbcr2: Ttbcr2,
+    #[cfg(feature = "el1")]
     /// Fake value for the `TTBR0` system register.
     pub ttbr0: Ttbr0,
     #[cfg(feature = "el1")]
@@ -1186,6 +1423,7 @@ pub struct SystemRegisters {
     #[cfg(feature = "el3")]
     /// Fake value for the `TTBR0_EL3` system register.
     pub ttbr0_el3: Ttbr0El3,
+    #[cfg(feature = "el1")]
     /// Fake value for the `TTBR1` system register.
     pub ttbr1: Ttbr1,
     #[cfg(feature = "el1")]
@@ -1194,6 +1432,7 @@ pub struct SystemRegisters {
     #[cfg(feature = "el2")]
     /// Fake value for the `TTBR1_EL2` system register.
     pub ttbr1_el2: Ttbr1El2,
+    #[cfg(feature = "el1")]
     /// Fake value for the `VBAR` system register.
     pub vbar: Vbar,
     #[cfg(feature = "el1")]
@@ -1202,37 +1441,43 @@ pub struct SystemRegisters {
     #[cfg(feature = "el2")]
     /// Fake value for the `VBAR_EL2` system register.
     pub vbar_el2: VbarEl2,
+    #[cfg(feature = "el2")]
     /// Fake value for the `VDFSR` system register.
     pub vdfsr: Vdfsr,
+    #[cfg(feature = "el1")]
     /// Fake value for the `VDISR` system register.
     pub vdisr: Vdisr,
-    #[cfg(feature = "el2")]
+    #[cfg(all(feature = "el2", feature = "feat-ras"))]
     /// Fake value for the `VDISR_EL2` system register.
     pub vdisr_el2: VdisrEl2,
+    #[cfg(feature = "el1")]
     /// Fake value for the `VMPIDR` system register.
     pub vmpidr: Vmpidr,
     #[cfg(feature = "el2")]
     /// Fake value for the `VMPIDR_EL2` system register.
     pub vmpidr_el2: VmpidrEl2,
+    #[cfg(feature = "el1")]
     /// Fake value for the `VPIDR` system register.
     pub vpidr: Vpidr,
     #[cfg(feature = "el2")]
     /// Fake value for the `VPIDR_EL2` system register.
     pub vpidr_el2: VpidrEl2,
-    #[cfg(feature = "el2")]
+    #[cfg(all(feature = "el2", feature = "feat-ras"))]
     /// Fake value for the `VSESR_EL2` system register.
     pub vsesr_el2: VsesrEl2,
+    #[cfg(feature = "el2")]
     /// Fake value for the `VTCR` system register.
     pub vtcr: Vtcr,
     #[cfg(feature = "el2")]
     /// Fake value for the `VTCR_EL2` system register.
     pub vtcr_el2: VtcrEl2,
+    #[cfg(feature = "el2")]
     /// Fake value for the `VTTBR` system register.
     pub vttbr: Vttbr,
     #[cfg(feature = "el2")]
     /// Fake value for the `VTTBR_EL2` system register.
     pub vttbr_el2: VttbrEl2,
-    #[cfg(feature = "el3")]
+    #[cfg(all(feature = "el3", feature = "feat-sve"))]
     /// Fake value for the `ZCR_EL3` system register.
     pub zcr_el3: ZcrEl3,
 }
@@ -1240,12 +1485,15 @@ pub struct SystemRegisters {
 impl SystemRegisters {
     pub(crate) const fn new() -> Self {
         Self {
+            #[cfg(feature = "el1")]
             actlr: 0,
+            #[cfg(feature = "el1")]
             actlr2: 0,
             #[cfg(feature = "el1")]
             actlr_el1: 0,
             #[cfg(feature = "el2")]
             actlr_el2: 0,
+            #[cfg(feature = "el1")]
             adfsr: 0,
             #[cfg(feature = "el1")]
             afsr0_el1: 0,
@@ -1255,59 +1503,94 @@ impl SystemRegisters {
             afsr1_el1: 0,
             #[cfg(feature = "el2")]
             afsr1_el2: 0,
+            #[cfg(feature = "el1")]
             aidr: 0,
+            #[cfg(feature = "el1")]
             aifsr: 0,
+            #[cfg(feature = "el1")]
             amair0: 0,
+            #[cfg(feature = "el1")]
             amair1: 0,
             #[cfg(feature = "el1")]
             amair_el1: 0,
             #[cfg(feature = "el2")]
             amair_el2: 0,
             amcfgr: Amcfgr::empty(),
+            #[cfg(feature = "feat-amu")]
             amcfgr_el0: AmcfgrEl0::empty(),
             amcgcr: Amcgcr::empty(),
+            #[cfg(feature = "feat-amu")]
             amcgcr_el0: AmcgcrEl0::empty(),
             amcntenclr0: Amcntenclr0::empty(),
+            #[cfg(feature = "feat-amu")]
             amcntenclr0_el0: Amcntenclr0El0::empty(),
             amcntenclr1: Amcntenclr1::empty(),
+            #[cfg(feature = "feat-amu")]
             amcntenclr1_el0: Amcntenclr1El0::empty(),
             amcntenset0: Amcntenset0::empty(),
+            #[cfg(feature = "feat-amu")]
             amcntenset0_el0: Amcntenset0El0::empty(),
             amcntenset1: Amcntenset1::empty(),
+            #[cfg(feature = "feat-amu")]
             amcntenset1_el0: Amcntenset1El0::empty(),
             amcr: Amcr::empty(),
+            #[cfg(feature = "feat-amu")]
             amcr_el0: AmcrEl0::empty(),
             amevcntr00: Amevcntr00::empty(),
+            #[cfg(feature = "feat-amu")]
             amevcntr00_el0: Amevcntr00El0::empty(),
             amevcntr01: Amevcntr01::empty(),
+            #[cfg(feature = "feat-amu")]
             amevcntr01_el0: Amevcntr01El0::empty(),
             amevcntr02: Amevcntr02::empty(),
+            #[cfg(feature = "feat-amu")]
             amevcntr02_el0: Amevcntr02El0::empty(),
             amevcntr03: Amevcntr03::empty(),
+            #[cfg(feature = "feat-amu")]
             amevcntr03_el0: Amevcntr03El0::empty(),
+            #[cfg(feature = "feat-amu")]
             amevcntr10_el0: Amevcntr10El0::empty(),
+            #[cfg(feature = "feat-amu")]
             amevcntr110_el0: Amevcntr110El0::empty(),
+            #[cfg(feature = "feat-amu")]
             amevcntr111_el0: Amevcntr111El0::empty(),
+            #[cfg(feature = "feat-amu")]
             amevcntr112_el0: Amevcntr112El0::empty(),
+            #[cfg(feature = "feat-amu")]
             amevcntr113_el0: Amevcntr113El0::empty(),
+            #[cfg(feature = "feat-amu")]
             amevcntr114_el0: Amevcntr114El0::empty(),
+            #[cfg(feature = "feat-amu")]
             amevcntr115_el0: Amevcntr115El0::empty(),
+            #[cfg(feature = "feat-amu")]
             amevcntr11_el0: Amevcntr11El0::empty(),
+            #[cfg(feature = "feat-amu")]
             amevcntr12_el0: Amevcntr12El0::empty(),
+            #[cfg(feature = "feat-amu")]
             amevcntr13_el0: Amevcntr13El0::empty(),
+            #[cfg(feature = "feat-amu")]
             amevcntr14_el0: Amevcntr14El0::empty(),
+            #[cfg(feature = "feat-amu")]
             amevcntr15_el0: Amevcntr15El0::empty(),
+            #[cfg(feature = "feat-amu")]
             amevcntr16_el0: Amevcntr16El0::empty(),
+            #[cfg(feature = "feat-amu")]
             amevcntr17_el0: Amevcntr17El0::empty(),
+            #[cfg(feature = "feat-amu")]
             amevcntr18_el0: Amevcntr18El0::empty(),
+            #[cfg(feature = "feat-amu")]
             amevcntr19_el0: Amevcntr19El0::empty(),
             amevtyper00: Amevtyper00::empty(),
+            #[cfg(feature = "feat-amu")]
             amevtyper00_el0: Amevtyper00El0::empty(),
             amevtyper01: Amevtyper01::empty(),
+            #[cfg(feature = "feat-amu")]
             amevtyper01_el0: Amevtyper01El0::empty(),
             amevtyper02: Amevtyper02::empty(),
+            #[cfg(feature = "feat-amu")]
             amevtyper02_el0: Amevtyper02El0::empty(),
             amevtyper03: Amevtyper03::empty(),
+            #[cfg(feature = "feat-amu")]
             amevtyper03_el0: Amevtyper03El0::empty(),
             amevtyper10: Amevtyper10::empty(),
             amevtyper11: Amevtyper11::empty(),
@@ -1326,6 +1609,7 @@ impl SystemRegisters {
             amevtyper18: Amevtyper18::empty(),
             amevtyper19: Amevtyper19::empty(),
             amuserenr: Amuserenr::empty(),
+            #[cfg(feature = "feat-amu")]
             amuserenr_el0: AmuserenrEl0::empty(),
             #[cfg(feature = "el1")]
             apiakeyhi_el1: ApiakeyhiEl1::empty(),
@@ -1333,54 +1617,71 @@ impl SystemRegisters {
             apiakeylo_el1: ApiakeyloEl1::empty(),
             #[cfg(feature = "el2")]
             brbcr_el2: BrbcrEl2::empty(),
+            #[cfg(feature = "el1")]
             ccsidr: Ccsidr::empty(),
+            #[cfg(feature = "el1")]
             ccsidr2: Ccsidr2::empty(),
             #[cfg(feature = "el1")]
             ccsidr_el1: CcsidrEl1::empty(),
+            #[cfg(feature = "el1")]
             clidr: Clidr::empty(),
             #[cfg(feature = "el1")]
             clidr_el1: ClidrEl1::empty(),
             cntfrq: Cntfrq::empty(),
             cntfrq_el0: CntfrqEl0::empty(),
+            #[cfg(feature = "el2")]
             cnthctl: Cnthctl::empty(),
             #[cfg(feature = "el2")]
             cnthctl_el2: CnthctlEl2::empty(),
+            #[cfg(feature = "el1")]
             cnthps_ctl: CnthpsCtl::empty(),
             #[cfg(feature = "el2")]
             cnthps_ctl_el2: CnthpsCtlEl2::empty(),
+            #[cfg(feature = "el1")]
             cnthps_cval: CnthpsCval::empty(),
             #[cfg(feature = "el2")]
             cnthps_cval_el2: CnthpsCvalEl2::empty(),
+            #[cfg(feature = "el1")]
             cnthps_tval: CnthpsTval::empty(),
             #[cfg(feature = "el2")]
             cnthps_tval_el2: CnthpsTvalEl2::empty(),
+            #[cfg(feature = "el1")]
             cnthp_ctl: CnthpCtl::empty(),
             #[cfg(feature = "el2")]
             cnthp_ctl_el2: CnthpCtlEl2::empty(),
+            #[cfg(feature = "el1")]
             cnthp_cval: CnthpCval::empty(),
             #[cfg(feature = "el2")]
             cnthp_cval_el2: CnthpCvalEl2::empty(),
+            #[cfg(feature = "el1")]
             cnthp_tval: CnthpTval::empty(),
             #[cfg(feature = "el2")]
             cnthp_tval_el2: CnthpTvalEl2::empty(),
+            #[cfg(feature = "el1")]
             cnthvs_ctl: CnthvsCtl::empty(),
             #[cfg(feature = "el2")]
             cnthvs_ctl_el2: CnthvsCtlEl2::empty(),
+            #[cfg(feature = "el1")]
             cnthvs_cval: CnthvsCval::empty(),
             #[cfg(feature = "el2")]
             cnthvs_cval_el2: CnthvsCvalEl2::empty(),
+            #[cfg(feature = "el1")]
             cnthvs_tval: CnthvsTval::empty(),
             #[cfg(feature = "el2")]
             cnthvs_tval_el2: CnthvsTvalEl2::empty(),
+            #[cfg(feature = "el1")]
             cnthv_ctl: CnthvCtl::empty(),
             #[cfg(feature = "el2")]
             cnthv_ctl_el2: CnthvCtlEl2::empty(),
+            #[cfg(feature = "el1")]
             cnthv_cval: CnthvCval::empty(),
             #[cfg(feature = "el2")]
             cnthv_cval_el2: CnthvCvalEl2::empty(),
+            #[cfg(feature = "el1")]
             cnthv_tval: CnthvTval::empty(),
             #[cfg(feature = "el2")]
             cnthv_tval_el2: CnthvTvalEl2::empty(),
+            #[cfg(feature = "el1")]
             cntkctl: Cntkctl::empty(),
             #[cfg(feature = "el1")]
             cntkctl_el1: CntkctlEl1::empty(),
@@ -1406,6 +1707,7 @@ impl SystemRegisters {
             cntvctss: Cntvctss::empty(),
             cntvctss_el0: CntvctssEl0::empty(),
             cntvct_el0: CntvctEl0::empty(),
+            #[cfg(feature = "el2")]
             cntvoff: Cntvoff::empty(),
             #[cfg(feature = "el2")]
             cntvoff_el2: CntvoffEl2::empty(),
@@ -1415,11 +1717,13 @@ impl SystemRegisters {
             cntv_cval_el0: CntvCvalEl0::empty(),
             cntv_tval: CntvTval::empty(),
             cntv_tval_el0: CntvTvalEl0::empty(),
+            #[cfg(feature = "el1")]
             contextidr: Contextidr::empty(),
             #[cfg(feature = "el1")]
             contextidr_el1: ContextidrEl1::empty(),
             #[cfg(feature = "el2")]
             contextidr_el2: ContextidrEl2::empty(),
+            #[cfg(feature = "el1")]
             cpacr: Cpacr::empty(),
             #[cfg(feature = "el1")]
             cpacr_el1: CpacrEl1::empty(),
@@ -1427,45 +1731,69 @@ impl SystemRegisters {
             cptr_el2: CptrEl2::empty(),
             #[cfg(feature = "el3")]
             cptr_el3: CptrEl3::empty(),
+            #[cfg(feature = "el1")]
             csselr: Csselr::empty(),
             #[cfg(feature = "el1")]
             csselr_el1: CsselrEl1::empty(),
+            #[cfg(feature = "el1")]
             ctr: Ctr::empty(),
             ctr_el0: CtrEl0::empty(),
             currentel: Currentel::empty(),
+            #[cfg(feature = "el1")]
             dacr: Dacr::empty(),
             daif: Daif::empty(),
+            #[cfg(feature = "el1")]
             dbgauthstatus: Dbgauthstatus::empty(),
+            #[cfg(feature = "el1")]
             dbgclaimclr: Dbgclaimclr::empty(),
+            #[cfg(feature = "el1")]
             dbgclaimset: Dbgclaimset::empty(),
+            #[cfg(feature = "el1")]
             dbgdccint: Dbgdccint::empty(),
+            #[cfg(feature = "el1")]
             dbgdevid: Dbgdevid::empty(),
+            #[cfg(feature = "el1")]
             dbgdevid1: Dbgdevid1::empty(),
+            #[cfg(feature = "el1")]
             dbgdevid2: 0,
             dbgdidr: Dbgdidr::empty(),
             dbgdrar: Dbgdrar::empty(),
             dbgdsar: 0,
+            #[cfg(feature = "el1")]
             dbgdscrext: Dbgdscrext::empty(),
             dbgdscrint: Dbgdscrint::empty(),
+            #[cfg(feature = "el1")]
             dbgdtrrxext: Dbgdtrrxext::empty(),
             dbgdtrrxint: Dbgdtrrxint::empty(),
+            #[cfg(feature = "el1")]
             dbgdtrtxext: Dbgdtrtxext::empty(),
             dbgdtrtxint: Dbgdtrtxint::empty(),
+            #[cfg(feature = "el1")]
             dbgosdlr: Dbgosdlr::empty(),
+            #[cfg(feature = "el1")]
             dbgoseccr: Dbgoseccr::empty(),
+            #[cfg(feature = "el1")]
             dbgoslar: Dbgoslar::empty(),
+            #[cfg(feature = "el1")]
             dbgoslsr: Dbgoslsr::empty(),
+            #[cfg(feature = "el1")]
             dbgprcr: Dbgprcr::empty(),
+            #[cfg(feature = "el1")]
             dbgvcr: Dbgvcr::empty(),
+            #[cfg(feature = "el1")]
             dbgwfar: 0,
+            #[cfg(feature = "el1")]
             dfar: Dfar::empty(),
+            #[cfg(feature = "el1")]
             dfsr: Dfsr::empty(),
-            disr: Disr::empty(),
             #[cfg(feature = "el1")]
+            disr: Disr::empty(),
+            #[cfg(all(feature = "el1", feature = "feat-ras"))]
             disr_el1: DisrEl1::empty(),
             dit: Dit::empty(),
             dlr: Dlr::empty(),
             dspsr: Dspsr::empty(),
+            #[cfg(feature = "el1")]
             dspsr2: Dspsr2::empty(),
             #[cfg(feature = "el1")]
             elr_el1: ElrEl1::empty(),
@@ -1473,22 +1801,39 @@ impl SystemRegisters {
             elr_el2: ElrEl2::empty(),
             #[cfg(feature = "el2")]
             elr_hyp: ElrHyp::empty(),
+            #[cfg(feature = "el1")]
             erridr: Erridr::empty(),
+            #[cfg(feature = "el1")]
             errselr: Errselr::empty(),
+            #[cfg(feature = "el1")]
             erxaddr: Erxaddr::empty(),
+            #[cfg(feature = "el1")]
             erxaddr2: Erxaddr2::empty(),
+            #[cfg(feature = "el1")]
             erxctlr: Erxctlr::empty(),
+            #[cfg(feature = "el1")]
             erxctlr2: Erxctlr2::empty(),
+            #[cfg(feature = "el1")]
             erxfr: Erxfr::empty(),
+            #[cfg(feature = "el1")]
             erxfr2: Erxfr2::empty(),
+            #[cfg(feature = "el1")]
             erxmisc0: Erxmisc0::empty(),
+            #[cfg(feature = "el1")]
             erxmisc1: Erxmisc1::empty(),
+            #[cfg(feature = "el1")]
             erxmisc2: Erxmisc2::empty(),
+            #[cfg(feature = "el1")]
             erxmisc3: Erxmisc3::empty(),
+            #[cfg(feature = "el1")]
             erxmisc4: Erxmisc4::empty(),
+            #[cfg(feature = "el1")]
             erxmisc5: Erxmisc5::empty(),
+            #[cfg(feature = "el1")]
             erxmisc6: Erxmisc6::empty(),
+            #[cfg(feature = "el1")]
             erxmisc7: Erxmisc7::empty(),
+            #[cfg(feature = "el1")]
             erxstatus: Erxstatus::empty(),
             #[cfg(feature = "el1")]
             esr_el1: EsrEl1::empty(),
@@ -1500,6 +1845,7 @@ impl SystemRegisters {
             far_el1: FarEl1::empty(),
             #[cfg(feature = "el2")]
             far_el2: FarEl2::empty(),
+            #[cfg(feature = "el1")]
             fcseidr: 0,
             fpcr: Fpcr::empty(),
             fpmr: Fpmr::empty(),
@@ -1510,29 +1856,41 @@ impl SystemRegisters {
             gcscr_el1: GcscrEl1::empty(),
             #[cfg(feature = "el2")]
             gcscr_el2: GcscrEl2::empty(),
-            #[cfg(feature = "el3")]
+            #[cfg(all(feature = "el3", feature = "feat-rme"))]
             gpccr_el3: GpccrEl3::empty(),
-            #[cfg(feature = "el3")]
+            #[cfg(all(feature = "el3", feature = "feat-rme"))]
             gptbr_el3: GptbrEl3::empty(),
+            #[cfg(feature = "el2")]
             hacr: 0,
             #[cfg(feature = "el2")]
             hacr_el2: 0,
+            #[cfg(feature = "el2")]
             hactlr: 0,
+            #[cfg(feature = "el2")]
             hactlr2: 0,
+            #[cfg(feature = "el2")]
             hadfsr: 0,
             #[cfg(feature = "el2")]
             hafgrtr_el2: HafgrtrEl2::empty(),
+            #[cfg(feature = "el2")]
             haifsr: 0,
+            #[cfg(feature = "el2")]
             hamair0: 0,
+            #[cfg(feature = "el2")]
             hamair1: 0,
+            #[cfg(feature = "el2")]
             hcptr: Hcptr::empty(),
+            #[cfg(feature = "el2")]
             hcr: Hcr::empty(),
+            #[cfg(feature = "el2")]
             hcr2: Hcr2::empty(),
             #[cfg(feature = "el2")]
             hcrx_el2: HcrxEl2::empty(),
             #[cfg(feature = "el2")]
             hcr_el2: HcrEl2::empty(),
+            #[cfg(feature = "el2")]
             hdcr: Hdcr::empty(),
+            #[cfg(feature = "el2")]
             hdfar: Hdfar::empty(),
             #[cfg(feature = "el2")]
             hdfgrtr2_el2: Hdfgrtr2El2::empty(),
@@ -1554,110 +1912,145 @@ impl SystemRegisters {
             hfgwtr2_el2: Hfgwtr2El2::empty(),
             #[cfg(feature = "el2")]
             hfgwtr_el2: HfgwtrEl2::empty(),
+            #[cfg(feature = "el2")]
             hifar: Hifar::empty(),
+            #[cfg(feature = "el2")]
             hmair0: Hmair0::empty(),
+            #[cfg(feature = "el2")]
             hmair1: Hmair1::empty(),
+            #[cfg(feature = "el2")]
             hpfar: Hpfar::empty(),
             #[cfg(feature = "el2")]
             hpfar_el2: HpfarEl2::empty(),
+            #[cfg(feature = "el2")]
             hrmr: Hrmr::empty(),
+            #[cfg(feature = "el2")]
             hsctlr: Hsctlr::empty(),
+            #[cfg(feature = "el2")]
             hsr: Hsr::empty(),
+            #[cfg(feature = "el2")]
             hstr: 0,
             #[cfg(feature = "el2")]
             hstr_el2: 0,
+            #[cfg(feature = "el2")]
             htcr: Htcr::empty(),
+            #[cfg(feature = "el2")]
             htpidr: Htpidr::empty(),
+            #[cfg(feature = "el2")]
             htrfcr: Htrfcr::empty(),
+            #[cfg(feature = "el2")]
             httbr: Httbr::empty(),
+            #[cfg(feature = "el2")]
             hvbar: Hvbar::empty(),
-            #[cfg(feature = "el1")]
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
             icc_ap0r0_el1: 0,
-            #[cfg(feature = "el1")]
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
             icc_ap0r1_el1: 0,
-            #[cfg(feature = "el1")]
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
             icc_ap0r2_el1: 0,
-            #[cfg(feature = "el1")]
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
             icc_ap0r3_el1: 0,
-            #[cfg(feature = "el1")]
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
             icc_ap1r0_el1: IccAp1r0El1::empty(),
-            #[cfg(feature = "el1")]
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
             icc_ap1r1_el1: 0,
-            #[cfg(feature = "el1")]
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
             icc_ap1r2_el1: 0,
-            #[cfg(feature = "el1")]
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
             icc_ap1r3_el1: 0,
-            icc_asgi1r: IccAsgi1r::empty(),
             #[cfg(feature = "el1")]
+            icc_asgi1r: IccAsgi1r::empty(),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
             icc_asgi1r_el1: IccAsgi1rEl1::empty(),
-            icc_bpr0: IccBpr0::empty(),
             #[cfg(feature = "el1")]
+            icc_bpr0: IccBpr0::empty(),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
             icc_bpr0_el1: IccBpr0El1::empty(),
-            icc_bpr1: IccBpr1::empty(),
             #[cfg(feature = "el1")]
+            icc_bpr1: IccBpr1::empty(),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
             icc_bpr1_el1: IccBpr1El1::empty(),
-            icc_ctlr: IccCtlr::empty(),
             #[cfg(feature = "el1")]
+            icc_ctlr: IccCtlr::empty(),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
             icc_ctlr_el1: IccCtlrEl1::empty(),
-            #[cfg(feature = "el3")]
+            #[cfg(all(feature = "el3", feature = "feat-gic"))]
             icc_ctlr_el3: IccCtlrEl3::empty(),
-            icc_dir: IccDir::empty(),
             #[cfg(feature = "el1")]
+            icc_dir: IccDir::empty(),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
             icc_dir_el1: IccDirEl1::empty(),
-            icc_eoir0: IccEoir0::empty(),
             #[cfg(feature = "el1")]
+            icc_eoir0: IccEoir0::empty(),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
             icc_eoir0_el1: IccEoir0El1::empty(),
-            icc_eoir1: IccEoir1::empty(),
             #[cfg(feature = "el1")]
+            icc_eoir1: IccEoir1::empty(),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
             icc_eoir1_el1: IccEoir1El1::empty(),
-            icc_hppir0: IccHppir0::empty(),
             #[cfg(feature = "el1")]
+            icc_hppir0: IccHppir0::empty(),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
             icc_hppir0_el1: IccHppir0El1::empty(),
-            icc_hppir1: IccHppir1::empty(),
             #[cfg(feature = "el1")]
+            icc_hppir1: IccHppir1::empty(),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
             icc_hppir1_el1: IccHppir1El1::empty(),
+            #[cfg(feature = "el2")]
             icc_hsre: IccHsre::empty(),
-            icc_iar0: IccIar0::empty(),
             #[cfg(feature = "el1")]
+            icc_iar0: IccIar0::empty(),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
             icc_iar0_el1: IccIar0El1::empty(),
-            icc_iar1: IccIar1::empty(),
             #[cfg(feature = "el1")]
+            icc_iar1: IccIar1::empty(),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
             icc_iar1_el1: IccIar1El1::empty(),
-            icc_igrpen0: IccIgrpen0::empty(),
             #[cfg(feature = "el1")]
+            icc_igrpen0: IccIgrpen0::empty(),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
             icc_igrpen0_el1: IccIgrpen0El1::empty(),
-            icc_igrpen1: IccIgrpen1::empty(),
             #[cfg(feature = "el1")]
+            icc_igrpen1: IccIgrpen1::empty(),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
             icc_igrpen1_el1: IccIgrpen1El1::empty(),
-            #[cfg(feature = "el3")]
+            #[cfg(all(feature = "el3", feature = "feat-gic"))]
             icc_igrpen1_el3: IccIgrpen1El3::empty(),
+            #[cfg(feature = "el3")]
             icc_mctlr: IccMctlr::empty(),
+            #[cfg(feature = "el3")]
             icc_mgrpen1: IccMgrpen1::empty(),
+            #[cfg(feature = "el3")]
             icc_msre: IccMsre::empty(),
-            #[cfg(feature = "el1")]
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
             icc_nmiar1_el1: IccNmiar1El1::empty(),
-            icc_pmr: IccPmr::empty(),
             #[cfg(feature = "el1")]
+            icc_pmr: IccPmr::empty(),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
             icc_pmr_el1: IccPmrEl1::empty(),
-            icc_rpr: IccRpr::empty(),
             #[cfg(feature = "el1")]
+            icc_rpr: IccRpr::empty(),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
             icc_rpr_el1: IccRprEl1::empty(),
-            icc_sgi0r: IccSgi0r::empty(),
             #[cfg(feature = "el1")]
+            icc_sgi0r: IccSgi0r::empty(),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
             icc_sgi0r_el1: IccSgi0rEl1::empty(),
-            icc_sgi1r: IccSgi1r::empty(),
             #[cfg(feature = "el1")]
+            icc_sgi1r: IccSgi1r::empty(),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
             icc_sgi1r_el1: IccSgi1rEl1::empty(),
-            icc_sre: IccSre::empty(),
             #[cfg(feature = "el1")]
+            icc_sre: IccSre::empty(),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
             icc_sre_el1: IccSreEl1::empty(),
-            #[cfg(feature = "el2")]
+            #[cfg(all(feature = "el2", feature = "feat-gic"))]
             icc_sre_el2: IccSreEl2::empty(),
-            #[cfg(feature = "el3")]
+            #[cfg(all(feature = "el3", feature = "feat-gic"))]
             icc_sre_el3: IccSreEl3::empty(),
-            #[cfg(feature = "el2")]
+            #[cfg(all(feature = "el2", feature = "feat-gic"))]
             ich_hcr_el2: IchHcrEl2::empty(),
-            #[cfg(feature = "el2")]
+            #[cfg(all(feature = "el2", feature = "feat-gic"))]
             ich_vmcr_el2: IchVmcrEl2::empty(),
             #[cfg(feature = "el1")]
             id_aa64dfr0_el1: IdAa64dfr0El1::empty(),
@@ -1685,34 +2078,61 @@ impl SystemRegisters {
             id_aa64pfr2_el1: IdAa64pfr2El1::empty(),
             #[cfg(feature = "el1")]
             id_aa64smfr0_el1: IdAa64smfr0El1::empty(),
+            #[cfg(feature = "el1")]
             id_afr0: 0,
+            #[cfg(feature = "el1")]
             id_dfr0: IdDfr0::empty(),
+            #[cfg(feature = "el1")]
             id_dfr1: IdDfr1::empty(),
+            #[cfg(feature = "el1")]
             id_isar0: IdIsar0::empty(),
+            #[cfg(feature = "el1")]
             id_isar1: IdIsar1::empty(),
+            #[cfg(feature = "el1")]
             id_isar2: IdIsar2::empty(),
+            #[cfg(feature = "el1")]
             id_isar3: IdIsar3::empty(),
+            #[cfg(feature = "el1")]
             id_isar4: IdIsar4::empty(),
+            #[cfg(feature = "el1")]
             id_isar5: IdIsar5::empty(),
+            #[cfg(feature = "el1")]
             id_isar6: IdIsar6::empty(),
+            #[cfg(feature = "el1")]
             id_mmfr0: IdMmfr0::empty(),
+            #[cfg(feature = "el1")]
             id_mmfr1: IdMmfr1::empty(),
+            #[cfg(feature = "el1")]
             id_mmfr2: IdMmfr2::empty(),
+            #[cfg(feature = "el1")]
             id_mmfr3: IdMmfr3::empty(),
+            #[cfg(feature = "el1")]
             id_mmfr4: IdMmfr4::empty(),
+            #[cfg(feature = "el1")]
             id_mmfr5: IdMmfr5::empty(),
+            #[cfg(feature = "el1")]
             id_pfr0: IdPfr0::empty(),
+            #[cfg(feature = "el1")]
             id_pfr1: IdPfr1::empty(),
+            #[cfg(feature = "el1")]
             id_pfr2: IdPfr2::empty(),
+            #[cfg(feature = "el1")]
             ifar: Ifar::empty(),
+            #[cfg(feature = "el1")]
             ifsr: Ifsr::empty(),
+            #[cfg(feature = "el1")]
             isr: Isr::empty(),
             #[cfg(feature = "el1")]
             isr_el1: IsrEl1::empty(),
+            #[cfg(feature = "el1")]
             jidr: 0,
+            #[cfg(feature = "el1")]
             jmcr: 0,
+            #[cfg(feature = "el1")]
             joscr: 0,
+            #[cfg(feature = "el1")]
             mair0: Mair0::empty(),
+            #[cfg(feature = "el1")]
             mair1: Mair1::empty(),
             #[cfg(feature = "el1")]
             mair_el1: MairEl1::empty(),
@@ -1728,41 +2148,47 @@ impl SystemRegisters {
             mdcr_el3: MdcrEl3::empty(),
             #[cfg(feature = "el1")]
             mdscr_el1: MdscrEl1::empty(),
+            #[cfg(feature = "el1")]
             midr: Midr::empty(),
             #[cfg(feature = "el1")]
             midr_el1: MidrEl1::empty(),
-            #[cfg(feature = "el2")]
+            #[cfg(all(feature = "el2", feature = "feat-mpam"))]
             mpam2_el2: Mpam2El2::empty(),
-            #[cfg(feature = "el3")]
+            #[cfg(all(feature = "el3", feature = "feat-mpam"))]
             mpam3_el3: Mpam3El3::empty(),
-            #[cfg(feature = "el2")]
+            #[cfg(all(feature = "el2", feature = "feat-mpam"))]
             mpamhcr_el2: MpamhcrEl2::empty(),
-            #[cfg(feature = "el1")]
+            #[cfg(all(feature = "el1", feature = "feat-mpam"))]
             mpamidr_el1: MpamidrEl1::empty(),
-            #[cfg(feature = "el2")]
+            #[cfg(all(feature = "el2", feature = "feat-mpam"))]
             mpamvpm0_el2: Mpamvpm0El2::empty(),
-            #[cfg(feature = "el2")]
+            #[cfg(all(feature = "el2", feature = "feat-mpam"))]
             mpamvpm1_el2: Mpamvpm1El2::empty(),
-            #[cfg(feature = "el2")]
+            #[cfg(all(feature = "el2", feature = "feat-mpam"))]
             mpamvpm2_el2: Mpamvpm2El2::empty(),
-            #[cfg(feature = "el2")]
+            #[cfg(all(feature = "el2", feature = "feat-mpam"))]
             mpamvpm3_el2: Mpamvpm3El2::empty(),
-            #[cfg(feature = "el2")]
+            #[cfg(all(feature = "el2", feature = "feat-mpam"))]
             mpamvpm4_el2: Mpamvpm4El2::empty(),
-            #[cfg(feature = "el2")]
+            #[cfg(all(feature = "el2", feature = "feat-mpam"))]
             mpamvpm5_el2: Mpamvpm5El2::empty(),
-            #[cfg(feature = "el2")]
+            #[cfg(all(feature = "el2", feature = "feat-mpam"))]
             mpamvpm6_el2: Mpamvpm6El2::empty(),
-            #[cfg(feature = "el2")]
+            #[cfg(all(feature = "el2", feature = "feat-mpam"))]
             mpamvpm7_el2: Mpamvpm7El2::empty(),
-            #[cfg(feature = "el2")]
+            #[cfg(all(feature = "el2", feature = "feat-mpam"))]
             mpamvpmv_el2: MpamvpmvEl2::empty(),
+            #[cfg(feature = "el1")]
             mpidr: Mpidr::empty(),
             #[cfg(feature = "el1")]
             mpidr_el1: MpidrEl1::empty(),
+            #[cfg(feature = "el1")]
             mvbar: Mvbar::empty(),
+            #[cfg(feature = "el1")]
             nmrr: Nmrr::empty(),
+            #[cfg(feature = "el1")]
             nsacr: Nsacr::empty(),
+            #[cfg(feature = "el1")]
             par: Par::empty(),
             #[cfg(feature = "el1")]
             par_el1: ParEl1::empty(),
@@ -1789,9 +2215,13 @@ impl SystemRegisters {
             pmcntenclr: Pmcntenclr::empty(),
             pmcntenset: Pmcntenset::empty(),
             pmcr: Pmcr::empty(),
+            #[cfg(feature = "feat-pmu")]
             pmcr_el0: PmcrEl0::empty(),
+            #[cfg(feature = "el1")]
             pmintenclr: Pmintenclr::empty(),
+            #[cfg(feature = "el1")]
             pmintenset: Pmintenset::empty(),
+            #[cfg(feature = "el1")]
             pmmir: Pmmir::empty(),
             pmovsr: Pmovsr::empty(),
             pmovsset: Pmovsset::empty(),
@@ -1806,19 +2236,25 @@ impl SystemRegisters {
             por_el2: PorEl2::empty(),
             #[cfg(feature = "el3")]
             por_el3: PorEl3::empty(),
+            #[cfg(feature = "el1")]
             prrr: Prrr::empty(),
+            #[cfg(feature = "el1")]
             revidr: 0,
             #[cfg(feature = "el1")]
             rgsr_el1: RgsrEl1::empty(),
+            #[cfg(feature = "el1")]
             rmr: Rmr::empty(),
+            #[cfg(feature = "el1")]
             rvbar: Rvbar::empty(),
             #[cfg(feature = "el2")]
             s2pir_el2: S2pirEl2::empty(),
             #[cfg(feature = "el1")]
             s2por_el1: S2porEl1::empty(),
+            #[cfg(feature = "el1")]
             scr: Scr::empty(),
             #[cfg(feature = "el3")]
             scr_el3: ScrEl3::empty(),
+            #[cfg(feature = "el1")]
             sctlr: Sctlr::empty(),
             #[cfg(feature = "el1")]
             sctlr2_el1: Sctlr2El1::empty(),
@@ -1832,9 +2268,11 @@ impl SystemRegisters {
             sctlr_el2: SctlrEl2::empty(),
             #[cfg(feature = "el3")]
             sctlr_el3: SctlrEl3::empty(),
+            #[cfg(feature = "el1")]
             sdcr: Sdcr::empty(),
+            #[cfg(feature = "el1")]
             sder: Sder::empty(),
-            #[cfg(feature = "el3")]
+            #[cfg(all(feature = "el3", feature = "feat-sve"))]
             smcr_el3: SmcrEl3::empty(),
             #[cfg(feature = "el1")]
             spsr_el1: SpsrEl1::empty(),
@@ -1846,7 +2284,9 @@ impl SystemRegisters {
             sp_el1: SpEl1::empty(),
             #[cfg(feature = "el2")]
             sp_el2: SpEl2::empty(),
+            #[cfg(feature = "feat-sve")]
             svcr: Svcr::empty(),
+            #[cfg(feature = "el1")]
             tcmtr: 0,
             #[cfg(feature = "el1")]
             tcr2_el1: Tcr2El1::empty(),
@@ -1864,7 +2304,9 @@ impl SystemRegisters {
             tfsr_el1: TfsrEl1::empty(),
             #[cfg(feature = "el2")]
             tfsr_el2: TfsrEl2::empty(),
+            #[cfg(feature = "el1")]
             tlbtr: Tlbtr::empty(),
+            #[cfg(feature = "el1")]
             tpidrprw: Tpidrprw::empty(),
             tpidrro_el0: TpidrroEl0::empty(),
             tpidruro: Tpidruro::empty(),
@@ -1876,9 +2318,13 @@ impl SystemRegisters {
             tpidr_el2: TpidrEl2::empty(),
             #[cfg(feature = "el3")]
             tpidr_el3: TpidrEl3::empty(),
+            #[cfg(feature = "el1")]
             trfcr: Trfcr::empty(),
+            #[cfg(feature = "el1")]
             ttbcr: Ttbcr::empty(),
+            #[cfg(feature = "el1")]
             ttbcr2: Ttbcr2::empty(),
+            #[cfg(feature = "el1")]
             ttbr0: Ttbr0::empty(),
             #[cfg(feature = "el1")]
             ttbr0_el1: Ttbr0El1::empty(),
@@ -1886,36 +2332,6267 @@ impl SystemRegisters {
             ttbr0_el2: Ttbr0El2::empty(),
             #[cfg(feature = "el3")]
             ttbr0_el3: Ttbr0El3::empty(),
+            #[cfg(feature = "el1")]
             ttbr1: Ttbr1::empty(),
             #[cfg(feature = "el1")]
             ttbr1_el1: Ttbr1El1::empty(),
             #[cfg(feature = "el2")]
             ttbr1_el2: Ttbr1El2::empty(),
+            #[cfg(feature = "el1")]
             vbar: Vbar::empty(),
             #[cfg(feature = "el1")]
             vbar_el1: VbarEl1::empty(),
             #[cfg(feature = "el2")]
             vbar_el2: VbarEl2::empty(),
+            #[cfg(feature = "el2")]
             vdfsr: Vdfsr::empty(),
+            #[cfg(feature = "el1")]
             vdisr: Vdisr::empty(),
-            #[cfg(feature = "el2")]
+            #[cfg(all(feature = "el2", feature = "feat-ras"))]
             vdisr_el2: VdisrEl2::empty(),
+            #[cfg(feature = "el1")]
             vmpidr: Vmpidr::empty(),
             #[cfg(feature = "el2")]
             vmpidr_el2: VmpidrEl2::empty(),
+            #[cfg(feature = "el1")]
             vpidr: Vpidr::empty(),
             #[cfg(feature = "el2")]
             vpidr_el2: VpidrEl2::empty(),
-            #[cfg(feature = "el2")]
+            #[cfg(all(feature = "el2", feature = "feat-ras"))]
             vsesr_el2: VsesrEl2::empty(),
+            #[cfg(feature = "el2")]
             vtcr: Vtcr::empty(),
             #[cfg(feature = "el2")]
             vtcr_el2: VtcrEl2::empty(),
+            #[cfg(feature = "el2")]
             vttbr: Vttbr::empty(),
             #[cfg(feature = "el2")]
             vttbr_el2: VttbrEl2::empty(),
-            #[cfg(feature = "el3")]
+            #[cfg(all(feature = "el3", feature = "feat-sve"))]
             zcr_el3: ZcrEl3::empty(),
         }
     }
+
+    /// Sets the fake register with the given name to the given value from a dump.
+    pub(crate) fn set_dump_value(
+        &mut self,
+        line: usize,
+        name: &str,
+        value: &str,
+    ) -> Result<(), DumpParseError> {
+        match name {
+            #[cfg(feature = "el1")]
+            "actlr" => self.actlr = parse_dump_int(line, value)?,
+            #[cfg(feature = "el1")]
+            "actlr2" => self.actlr2 = parse_dump_int(line, value)?,
+            #[cfg(feature = "el1")]
+            "actlr_el1" => self.actlr_el1 = parse_dump_int(line, value)?,
+            #[cfg(feature = "el2")]
+            "actlr_el2" => self.actlr_el2 = parse_dump_int(line, value)?,
+            #[cfg(feature = "el1")]
+            "adfsr" => self.adfsr = parse_dump_int(line, value)?,
+            #[cfg(feature = "el1")]
+            "afsr0_el1" => self.afsr0_el1 = parse_dump_int(line, value)?,
+            #[cfg(feature = "el2")]
+            "afsr0_el2" => self.afsr0_el2 = parse_dump_int(line, value)?,
+            #[cfg(feature = "el1")]
+            "afsr1_el1" => self.afsr1_el1 = parse_dump_int(line, value)?,
+            #[cfg(feature = "el2")]
+            "afsr1_el2" => self.afsr1_el2 = parse_dump_int(line, value)?,
+            #[cfg(feature = "el1")]
+            "aidr" => self.aidr = parse_dump_int(line, value)?,
+            #[cfg(feature = "el1")]
+            "aifsr" => self.aifsr = parse_dump_int(line, value)?,
+            #[cfg(feature = "el1")]
+            "amair0" => self.amair0 = parse_dump_int(line, value)?,
+            #[cfg(feature = "el1")]
+            "amair1" => self.amair1 = parse_dump_int(line, value)?,
+            #[cfg(feature = "el1")]
+            "amair_el1" => self.amair_el1 = parse_dump_int(line, value)?,
+            #[cfg(feature = "el2")]
+            "amair_el2" => self.amair_el2 = parse_dump_int(line, value)?,
+            "amcfgr" => self.amcfgr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "feat-amu")]
+            "amcfgr_el0" => self.amcfgr_el0 = parse_dump_flags(line, value)?,
+            "amcgcr" => self.amcgcr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "feat-amu")]
+            "amcgcr_el0" => self.amcgcr_el0 = parse_dump_flags(line, value)?,
+            "amcntenclr0" => self.amcntenclr0 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "feat-amu")]
+            "amcntenclr0_el0" => self.amcntenclr0_el0 = parse_dump_flags(line, value)?,
+            "amcntenclr1" => self.amcntenclr1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "feat-amu")]
+            "amcntenclr1_el0" => self.amcntenclr1_el0 = parse_dump_flags(line, value)?,
+            "amcntenset0" => self.amcntenset0 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "feat-amu")]
+            "amcntenset0_el0" => self.amcntenset0_el0 = parse_dump_flags(line, value)?,
+            "amcntenset1" => self.amcntenset1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "feat-amu")]
+            "amcntenset1_el0" => self.amcntenset1_el0 = parse_dump_flags(line, value)?,
+            "amcr" => self.amcr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "feat-amu")]
+            "amcr_el0" => self.amcr_el0 = parse_dump_flags(line, value)?,
+            "amevcntr00" => self.amevcntr00 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "feat-amu")]
+            "amevcntr00_el0" => self.amevcntr00_el0 = parse_dump_flags(line, value)?,
+            "amevcntr01" => self.amevcntr01 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "feat-amu")]
+            "amevcntr01_el0" => self.amevcntr01_el0 = parse_dump_flags(line, value)?,
+            "amevcntr02" => self.amevcntr02 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "feat-amu")]
+            "amevcntr02_el0" => self.amevcntr02_el0 = parse_dump_flags(line, value)?,
+            "amevcntr03" => self.amevcntr03 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "feat-amu")]
+            "amevcntr03_el0" => self.amevcntr03_el0 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "feat-amu")]
+            "amevcntr10_el0" => self.amevcntr10_el0 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "feat-amu")]
+            "amevcntr110_el0" => self.amevcntr110_el0 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "feat-amu")]
+            "amevcntr111_el0" => self.amevcntr111_el0 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "feat-amu")]
+            "amevcntr112_el0" => self.amevcntr112_el0 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "feat-amu")]
+            "amevcntr113_el0" => self.amevcntr113_el0 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "feat-amu")]
+            "amevcntr114_el0" => self.amevcntr114_el0 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "feat-amu")]
+            "amevcntr115_el0" => self.amevcntr115_el0 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "feat-amu")]
+            "amevcntr11_el0" => self.amevcntr11_el0 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "feat-amu")]
+            "amevcntr12_el0" => self.amevcntr12_el0 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "feat-amu")]
+            "amevcntr13_el0" => self.amevcntr13_el0 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "feat-amu")]
+            "amevcntr14_el0" => self.amevcntr14_el0 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "feat-amu")]
+            "amevcntr15_el0" => self.amevcntr15_el0 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "feat-amu")]
+            "amevcntr16_el0" => self.amevcntr16_el0 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "feat-amu")]
+            "amevcntr17_el0" => self.amevcntr17_el0 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "feat-amu")]
+            "amevcntr18_el0" => self.amevcntr18_el0 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "feat-amu")]
+            "amevcntr19_el0" => self.amevcntr19_el0 = parse_dump_flags(line, value)?,
+            "amevtyper00" => self.amevtyper00 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "feat-amu")]
+            "amevtyper00_el0" => self.amevtyper00_el0 = parse_dump_flags(line, value)?,
+            "amevtyper01" => self.amevtyper01 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "feat-amu")]
+            "amevtyper01_el0" => self.amevtyper01_el0 = parse_dump_flags(line, value)?,
+            "amevtyper02" => self.amevtyper02 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "feat-amu")]
+            "amevtyper02_el0" => self.amevtyper02_el0 = parse_dump_flags(line, value)?,
+            "amevtyper03" => self.amevtyper03 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "feat-amu")]
+            "amevtyper03_el0" => self.amevtyper03_el0 = parse_dump_flags(line, value)?,
+            "amevtyper10" => self.amevtyper10 = parse_dump_flags(line, value)?,
+            "amevtyper11" => self.amevtyper11 = parse_dump_flags(line, value)?,
+            "amevtyper110" => self.amevtyper110 = parse_dump_flags(line, value)?,
+            "amevtyper111" => self.amevtyper111 = parse_dump_flags(line, value)?,
+            "amevtyper112" => self.amevtyper112 = parse_dump_flags(line, value)?,
+            "amevtyper113" => self.amevtyper113 = parse_dump_flags(line, value)?,
+            "amevtyper114" => self.amevtyper114 = parse_dump_flags(line, value)?,
+            "amevtyper115" => self.amevtyper115 = parse_dump_flags(line, value)?,
+            "amevtyper12" => self.amevtyper12 = parse_dump_flags(line, value)?,
+            "amevtyper13" => self.amevtyper13 = parse_dump_flags(line, value)?,
+            "amevtyper14" => self.amevtyper14 = parse_dump_flags(line, value)?,
+            "amevtyper15" => self.amevtyper15 = parse_dump_flags(line, value)?,
+            "amevtyper16" => self.amevtyper16 = parse_dump_flags(line, value)?,
+            "amevtyper17" => self.amevtyper17 = parse_dump_flags(line, value)?,
+            "amevtyper18" => self.amevtyper18 = parse_dump_flags(line, value)?,
+            "amevtyper19" => self.amevtyper19 = parse_dump_flags(line, value)?,
+            "amuserenr" => self.amuserenr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "feat-amu")]
+            "amuserenr_el0" => self.amuserenr_el0 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "apiakeyhi_el1" => self.apiakeyhi_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "apiakeylo_el1" => self.apiakeylo_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "brbcr_el2" => self.brbcr_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "ccsidr" => self.ccsidr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "ccsidr2" => self.ccsidr2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "ccsidr_el1" => self.ccsidr_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "clidr" => self.clidr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "clidr_el1" => self.clidr_el1 = parse_dump_flags(line, value)?,
+            "cntfrq" => self.cntfrq = parse_dump_flags(line, value)?,
+            "cntfrq_el0" => self.cntfrq_el0 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "cnthctl" => self.cnthctl = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "cnthctl_el2" => self.cnthctl_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "cnthps_ctl" => self.cnthps_ctl = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "cnthps_ctl_el2" => self.cnthps_ctl_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "cnthps_cval" => self.cnthps_cval = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "cnthps_cval_el2" => self.cnthps_cval_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "cnthps_tval" => self.cnthps_tval = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "cnthps_tval_el2" => self.cnthps_tval_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "cnthp_ctl" => self.cnthp_ctl = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "cnthp_ctl_el2" => self.cnthp_ctl_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "cnthp_cval" => self.cnthp_cval = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "cnthp_cval_el2" => self.cnthp_cval_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "cnthp_tval" => self.cnthp_tval = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "cnthp_tval_el2" => self.cnthp_tval_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "cnthvs_ctl" => self.cnthvs_ctl = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "cnthvs_ctl_el2" => self.cnthvs_ctl_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "cnthvs_cval" => self.cnthvs_cval = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "cnthvs_cval_el2" => self.cnthvs_cval_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "cnthvs_tval" => self.cnthvs_tval = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "cnthvs_tval_el2" => self.cnthvs_tval_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "cnthv_ctl" => self.cnthv_ctl = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "cnthv_ctl_el2" => self.cnthv_ctl_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "cnthv_cval" => self.cnthv_cval = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "cnthv_cval_el2" => self.cnthv_cval_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "cnthv_tval" => self.cnthv_tval = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "cnthv_tval_el2" => self.cnthv_tval_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "cntkctl" => self.cntkctl = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "cntkctl_el1" => self.cntkctl_el1 = parse_dump_flags(line, value)?,
+            "cntpct" => self.cntpct = parse_dump_flags(line, value)?,
+            "cntpctss" => self.cntpctss = parse_dump_flags(line, value)?,
+            "cntpctss_el0" => self.cntpctss_el0 = parse_dump_flags(line, value)?,
+            "cntpct_el0" => self.cntpct_el0 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "cntpoff_el2" => self.cntpoff_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "cntps_ctl_el1" => self.cntps_ctl_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "cntps_cval_el1" => self.cntps_cval_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "cntps_tval_el1" => self.cntps_tval_el1 = parse_dump_flags(line, value)?,
+            "cntp_ctl" => self.cntp_ctl = parse_dump_flags(line, value)?,
+            "cntp_ctl_el0" => self.cntp_ctl_el0 = parse_dump_flags(line, value)?,
+            "cntp_cval" => self.cntp_cval = parse_dump_flags(line, value)?,
+            "cntp_cval_el0" => self.cntp_cval_el0 = parse_dump_flags(line, value)?,
+            "cntp_tval" => self.cntp_tval = parse_dump_flags(line, value)?,
+            "cntp_tval_el0" => self.cntp_tval_el0 = parse_dump_flags(line, value)?,
+            "cntvct" => self.cntvct = parse_dump_flags(line, value)?,
+            "cntvctss" => self.cntvctss = parse_dump_flags(line, value)?,
+            "cntvctss_el0" => self.cntvctss_el0 = parse_dump_flags(line, value)?,
+            "cntvct_el0" => self.cntvct_el0 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "cntvoff" => self.cntvoff = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "cntvoff_el2" => self.cntvoff_el2 = parse_dump_flags(line, value)?,
+            "cntv_ctl" => self.cntv_ctl = parse_dump_flags(line, value)?,
+            "cntv_ctl_el0" => self.cntv_ctl_el0 = parse_dump_flags(line, value)?,
+            "cntv_cval" => self.cntv_cval = parse_dump_flags(line, value)?,
+            "cntv_cval_el0" => self.cntv_cval_el0 = parse_dump_flags(line, value)?,
+            "cntv_tval" => self.cntv_tval = parse_dump_flags(line, value)?,
+            "cntv_tval_el0" => self.cntv_tval_el0 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "contextidr" => self.contextidr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "contextidr_el1" => self.contextidr_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "contextidr_el2" => self.contextidr_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "cpacr" => self.cpacr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "cpacr_el1" => self.cpacr_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "cptr_el2" => self.cptr_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el3")]
+            "cptr_el3" => self.cptr_el3 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "csselr" => self.csselr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "csselr_el1" => self.csselr_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "ctr" => self.ctr = parse_dump_flags(line, value)?,
+            "ctr_el0" => self.ctr_el0 = parse_dump_flags(line, value)?,
+            "currentel" => self.currentel = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "dacr" => self.dacr = parse_dump_flags(line, value)?,
+            "daif" => self.daif = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "dbgauthstatus" => self.dbgauthstatus = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "dbgclaimclr" => self.dbgclaimclr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "dbgclaimset" => self.dbgclaimset = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "dbgdccint" => self.dbgdccint = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "dbgdevid" => self.dbgdevid = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "dbgdevid1" => self.dbgdevid1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "dbgdevid2" => self.dbgdevid2 = parse_dump_int(line, value)?,
+            "dbgdidr" => self.dbgdidr = parse_dump_flags(line, value)?,
+            "dbgdrar" => self.dbgdrar = parse_dump_flags(line, value)?,
+            "dbgdsar" => self.dbgdsar = parse_dump_int(line, value)?,
+            #[cfg(feature = "el1")]
+            "dbgdscrext" => self.dbgdscrext = parse_dump_flags(line, value)?,
+            "dbgdscrint" => self.dbgdscrint = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "dbgdtrrxext" => self.dbgdtrrxext = parse_dump_flags(line, value)?,
+            "dbgdtrrxint" => self.dbgdtrrxint = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "dbgdtrtxext" => self.dbgdtrtxext = parse_dump_flags(line, value)?,
+            "dbgdtrtxint" => self.dbgdtrtxint = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "dbgosdlr" => self.dbgosdlr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "dbgoseccr" => self.dbgoseccr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "dbgoslar" => self.dbgoslar = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "dbgoslsr" => self.dbgoslsr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "dbgprcr" => self.dbgprcr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "dbgvcr" => self.dbgvcr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "dbgwfar" => self.dbgwfar = parse_dump_int(line, value)?,
+            #[cfg(feature = "el1")]
+            "dfar" => self.dfar = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "dfsr" => self.dfsr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "disr" => self.disr = parse_dump_flags(line, value)?,
+            #[cfg(all(feature = "el1", feature = "feat-ras"))]
+            "disr_el1" => self.disr_el1 = parse_dump_flags(line, value)?,
+            "dit" => self.dit = parse_dump_flags(line, value)?,
+            "dlr" => self.dlr = parse_dump_flags(line, value)?,
+            "dspsr" => self.dspsr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "dspsr2" => self.dspsr2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "elr_el1" => self.elr_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "elr_el2" => self.elr_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "elr_hyp" => self.elr_hyp = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "erridr" => self.erridr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "errselr" => self.errselr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "erxaddr" => self.erxaddr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "erxaddr2" => self.erxaddr2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "erxctlr" => self.erxctlr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "erxctlr2" => self.erxctlr2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "erxfr" => self.erxfr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "erxfr2" => self.erxfr2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "erxmisc0" => self.erxmisc0 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "erxmisc1" => self.erxmisc1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "erxmisc2" => self.erxmisc2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "erxmisc3" => self.erxmisc3 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "erxmisc4" => self.erxmisc4 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "erxmisc5" => self.erxmisc5 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "erxmisc6" => self.erxmisc6 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "erxmisc7" => self.erxmisc7 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "erxstatus" => self.erxstatus = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "esr_el1" => self.esr_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "esr_el2" => self.esr_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el3")]
+            "esr_el3" => self.esr_el3 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "far_el1" => self.far_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "far_el2" => self.far_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "fcseidr" => self.fcseidr = parse_dump_int(line, value)?,
+            "fpcr" => self.fpcr = parse_dump_flags(line, value)?,
+            "fpmr" => self.fpmr = parse_dump_flags(line, value)?,
+            "fpsr" => self.fpsr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "gcr_el1" => self.gcr_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "gcscr_el1" => self.gcscr_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "gcscr_el2" => self.gcscr_el2 = parse_dump_flags(line, value)?,
+            #[cfg(all(feature = "el3", feature = "feat-rme"))]
+            "gpccr_el3" => self.gpccr_el3 = parse_dump_flags(line, value)?,
+            #[cfg(all(feature = "el3", feature = "feat-rme"))]
+            "gptbr_el3" => self.gptbr_el3 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "hacr" => self.hacr = parse_dump_int(line, value)?,
+            #[cfg(feature = "el2")]
+            "hacr_el2" => self.hacr_el2 = parse_dump_int(line, value)?,
+            #[cfg(feature = "el2")]
+            "hactlr" => self.hactlr = parse_dump_int(line, value)?,
+            #[cfg(feature = "el2")]
+            "hactlr2" => self.hactlr2 = parse_dump_int(line, value)?,
+            #[cfg(feature = "el2")]
+            "hadfsr" => self.hadfsr = parse_dump_int(line, value)?,
+            #[cfg(feature = "el2")]
+            "hafgrtr_el2" => self.hafgrtr_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "haifsr" => self.haifsr = parse_dump_int(line, value)?,
+            #[cfg(feature = "el2")]
+            "hamair0" => self.hamair0 = parse_dump_int(line, value)?,
+            #[cfg(feature = "el2")]
+            "hamair1" => self.hamair1 = parse_dump_int(line, value)?,
+            #[cfg(feature = "el2")]
+            "hcptr" => self.hcptr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "hcr" => self.hcr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "hcr2" => self.hcr2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "hcrx_el2" => self.hcrx_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "hcr_el2" => self.hcr_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "hdcr" => self.hdcr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "hdfar" => self.hdfar = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "hdfgrtr2_el2" => self.hdfgrtr2_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "hdfgrtr_el2" => self.hdfgrtr_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "hdfgwtr2_el2" => self.hdfgwtr2_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "hdfgwtr_el2" => self.hdfgwtr_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "hfgitr2_el2" => self.hfgitr2_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "hfgitr_el2" => self.hfgitr_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "hfgrtr2_el2" => self.hfgrtr2_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "hfgrtr_el2" => self.hfgrtr_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "hfgwtr2_el2" => self.hfgwtr2_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "hfgwtr_el2" => self.hfgwtr_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "hifar" => self.hifar = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "hmair0" => self.hmair0 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "hmair1" => self.hmair1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "hpfar" => self.hpfar = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "hpfar_el2" => self.hpfar_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "hrmr" => self.hrmr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "hsctlr" => self.hsctlr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "hsr" => self.hsr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "hstr" => self.hstr = parse_dump_int(line, value)?,
+            #[cfg(feature = "el2")]
+            "hstr_el2" => self.hstr_el2 = parse_dump_int(line, value)?,
+            #[cfg(feature = "el2")]
+            "htcr" => self.htcr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "htpidr" => self.htpidr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "htrfcr" => self.htrfcr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "httbr" => self.httbr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "hvbar" => self.hvbar = parse_dump_flags(line, value)?,
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            "icc_ap0r0_el1" => self.icc_ap0r0_el1 = parse_dump_int(line, value)?,
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            "icc_ap0r1_el1" => self.icc_ap0r1_el1 = parse_dump_int(line, value)?,
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            "icc_ap0r2_el1" => self.icc_ap0r2_el1 = parse_dump_int(line, value)?,
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            "icc_ap0r3_el1" => self.icc_ap0r3_el1 = parse_dump_int(line, value)?,
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            "icc_ap1r0_el1" => self.icc_ap1r0_el1 = parse_dump_flags(line, value)?,
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            "icc_ap1r1_el1" => self.icc_ap1r1_el1 = parse_dump_int(line, value)?,
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            "icc_ap1r2_el1" => self.icc_ap1r2_el1 = parse_dump_int(line, value)?,
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            "icc_ap1r3_el1" => self.icc_ap1r3_el1 = parse_dump_int(line, value)?,
+            #[cfg(feature = "el1")]
+            "icc_asgi1r" => self.icc_asgi1r = parse_dump_flags(line, value)?,
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            "icc_asgi1r_el1" => self.icc_asgi1r_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "icc_bpr0" => self.icc_bpr0 = parse_dump_flags(line, value)?,
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            "icc_bpr0_el1" => self.icc_bpr0_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "icc_bpr1" => self.icc_bpr1 = parse_dump_flags(line, value)?,
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            "icc_bpr1_el1" => self.icc_bpr1_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "icc_ctlr" => self.icc_ctlr = parse_dump_flags(line, value)?,
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            "icc_ctlr_el1" => self.icc_ctlr_el1 = parse_dump_flags(line, value)?,
+            #[cfg(all(feature = "el3", feature = "feat-gic"))]
+            "icc_ctlr_el3" => self.icc_ctlr_el3 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "icc_dir" => self.icc_dir = parse_dump_flags(line, value)?,
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            "icc_dir_el1" => self.icc_dir_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "icc_eoir0" => self.icc_eoir0 = parse_dump_flags(line, value)?,
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            "icc_eoir0_el1" => self.icc_eoir0_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "icc_eoir1" => self.icc_eoir1 = parse_dump_flags(line, value)?,
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            "icc_eoir1_el1" => self.icc_eoir1_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "icc_hppir0" => self.icc_hppir0 = parse_dump_flags(line, value)?,
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            "icc_hppir0_el1" => self.icc_hppir0_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "icc_hppir1" => self.icc_hppir1 = parse_dump_flags(line, value)?,
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            "icc_hppir1_el1" => self.icc_hppir1_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "icc_hsre" => self.icc_hsre = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "icc_iar0" => self.icc_iar0 = parse_dump_flags(line, value)?,
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            "icc_iar0_el1" => self.icc_iar0_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "icc_iar1" => self.icc_iar1 = parse_dump_flags(line, value)?,
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            "icc_iar1_el1" => self.icc_iar1_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "icc_igrpen0" => self.icc_igrpen0 = parse_dump_flags(line, value)?,
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            "icc_igrpen0_el1" => self.icc_igrpen0_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "icc_igrpen1" => self.icc_igrpen1 = parse_dump_flags(line, value)?,
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            "icc_igrpen1_el1" => self.icc_igrpen1_el1 = parse_dump_flags(line, value)?,
+            #[cfg(all(feature = "el3", feature = "feat-gic"))]
+            "icc_igrpen1_el3" => self.icc_igrpen1_el3 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el3")]
+            "icc_mctlr" => self.icc_mctlr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el3")]
+            "icc_mgrpen1" => self.icc_mgrpen1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el3")]
+            "icc_msre" => self.icc_msre = parse_dump_flags(line, value)?,
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            "icc_nmiar1_el1" => self.icc_nmiar1_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "icc_pmr" => self.icc_pmr = parse_dump_flags(line, value)?,
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            "icc_pmr_el1" => self.icc_pmr_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "icc_rpr" => self.icc_rpr = parse_dump_flags(line, value)?,
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            "icc_rpr_el1" => self.icc_rpr_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "icc_sgi0r" => self.icc_sgi0r = parse_dump_flags(line, value)?,
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            "icc_sgi0r_el1" => self.icc_sgi0r_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "icc_sgi1r" => self.icc_sgi1r = parse_dump_flags(line, value)?,
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            "icc_sgi1r_el1" => self.icc_sgi1r_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "icc_sre" => self.icc_sre = parse_dump_flags(line, value)?,
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            "icc_sre_el1" => self.icc_sre_el1 = parse_dump_flags(line, value)?,
+            #[cfg(all(feature = "el2", feature = "feat-gic"))]
+            "icc_sre_el2" => self.icc_sre_el2 = parse_dump_flags(line, value)?,
+            #[cfg(all(feature = "el3", feature = "feat-gic"))]
+            "icc_sre_el3" => self.icc_sre_el3 = parse_dump_flags(line, value)?,
+            #[cfg(all(feature = "el2", feature = "feat-gic"))]
+            "ich_hcr_el2" => self.ich_hcr_el2 = parse_dump_flags(line, value)?,
+            #[cfg(all(feature = "el2", feature = "feat-gic"))]
+            "ich_vmcr_el2" => self.ich_vmcr_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "id_aa64dfr0_el1" => self.id_aa64dfr0_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "id_aa64dfr1_el1" => self.id_aa64dfr1_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "id_aa64isar1_el1" => self.id_aa64isar1_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "id_aa64isar2_el1" => self.id_aa64isar2_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "id_aa64mmfr0_el1" => self.id_aa64mmfr0_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "id_aa64mmfr1_el1" => self.id_aa64mmfr1_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "id_aa64mmfr2_el1" => self.id_aa64mmfr2_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "id_aa64mmfr3_el1" => self.id_aa64mmfr3_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "id_aa64mmfr4_el1" => self.id_aa64mmfr4_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "id_aa64pfr0_el1" => self.id_aa64pfr0_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "id_aa64pfr1_el1" => self.id_aa64pfr1_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "id_aa64pfr2_el1" => self.id_aa64pfr2_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "id_aa64smfr0_el1" => self.id_aa64smfr0_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "id_afr0" => self.id_afr0 = parse_dump_int(line, value)?,
+            #[cfg(feature = "el1")]
+            "id_dfr0" => self.id_dfr0 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "id_dfr1" => self.id_dfr1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "id_isar0" => self.id_isar0 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "id_isar1" => self.id_isar1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "id_isar2" => self.id_isar2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "id_isar3" => self.id_isar3 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "id_isar4" => self.id_isar4 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "id_isar5" => self.id_isar5 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "id_isar6" => self.id_isar6 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "id_mmfr0" => self.id_mmfr0 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "id_mmfr1" => self.id_mmfr1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "id_mmfr2" => self.id_mmfr2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "id_mmfr3" => self.id_mmfr3 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "id_mmfr4" => self.id_mmfr4 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "id_mmfr5" => self.id_mmfr5 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "id_pfr0" => self.id_pfr0 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "id_pfr1" => self.id_pfr1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "id_pfr2" => self.id_pfr2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "ifar" => self.ifar = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "ifsr" => self.ifsr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "isr" => self.isr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "isr_el1" => self.isr_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "jidr" => self.jidr = parse_dump_int(line, value)?,
+            #[cfg(feature = "el1")]
+            "jmcr" => self.jmcr = parse_dump_int(line, value)?,
+            #[cfg(feature = "el1")]
+            "joscr" => self.joscr = parse_dump_int(line, value)?,
+            #[cfg(feature = "el1")]
+            "mair0" => self.mair0 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "mair1" => self.mair1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "mair_el1" => self.mair_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "mair_el2" => self.mair_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el3")]
+            "mair_el3" => self.mair_el3 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "mdccint_el1" => self.mdccint_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "mdcr_el2" => self.mdcr_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el3")]
+            "mdcr_el3" => self.mdcr_el3 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "mdscr_el1" => self.mdscr_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "midr" => self.midr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "midr_el1" => self.midr_el1 = parse_dump_flags(line, value)?,
+            #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+            "mpam2_el2" => self.mpam2_el2 = parse_dump_flags(line, value)?,
+            #[cfg(all(feature = "el3", feature = "feat-mpam"))]
+            "mpam3_el3" => self.mpam3_el3 = parse_dump_flags(line, value)?,
+            #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+            "mpamhcr_el2" => self.mpamhcr_el2 = parse_dump_flags(line, value)?,
+            #[cfg(all(feature = "el1", feature = "feat-mpam"))]
+            "mpamidr_el1" => self.mpamidr_el1 = parse_dump_flags(line, value)?,
+            #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+            "mpamvpm0_el2" => self.mpamvpm0_el2 = parse_dump_flags(line, value)?,
+            #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+            "mpamvpm1_el2" => self.mpamvpm1_el2 = parse_dump_flags(line, value)?,
+            #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+            "mpamvpm2_el2" => self.mpamvpm2_el2 = parse_dump_flags(line, value)?,
+            #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+            "mpamvpm3_el2" => self.mpamvpm3_el2 = parse_dump_flags(line, value)?,
+            #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+            "mpamvpm4_el2" => self.mpamvpm4_el2 = parse_dump_flags(line, value)?,
+            #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+            "mpamvpm5_el2" => self.mpamvpm5_el2 = parse_dump_flags(line, value)?,
+            #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+            "mpamvpm6_el2" => self.mpamvpm6_el2 = parse_dump_flags(line, value)?,
+            #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+            "mpamvpm7_el2" => self.mpamvpm7_el2 = parse_dump_flags(line, value)?,
+            #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+            "mpamvpmv_el2" => self.mpamvpmv_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "mpidr" => self.mpidr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "mpidr_el1" => self.mpidr_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "mvbar" => self.mvbar = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "nmrr" => self.nmrr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "nsacr" => self.nsacr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "par" => self.par = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "par_el1" => self.par_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "pfar_el1" => self.pfar_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "pfar_el2" => self.pfar_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "pire0_el1" => self.pire0_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "pire0_el2" => self.pire0_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "pir_el1" => self.pir_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "pir_el2" => self.pir_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el3")]
+            "pir_el3" => self.pir_el3 = parse_dump_flags(line, value)?,
+            "pmccfiltr" => self.pmccfiltr = parse_dump_flags(line, value)?,
+            "pmccntr" => self.pmccntr = parse_dump_flags(line, value)?,
+            "pmceid0" => self.pmceid0 = parse_dump_flags(line, value)?,
+            "pmceid1" => self.pmceid1 = parse_dump_flags(line, value)?,
+            "pmceid2" => self.pmceid2 = parse_dump_flags(line, value)?,
+            "pmceid3" => self.pmceid3 = parse_dump_flags(line, value)?,
+            "pmcntenclr" => self.pmcntenclr = parse_dump_flags(line, value)?,
+            "pmcntenset" => self.pmcntenset = parse_dump_flags(line, value)?,
+            "pmcr" => self.pmcr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "feat-pmu")]
+            "pmcr_el0" => self.pmcr_el0 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "pmintenclr" => self.pmintenclr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "pmintenset" => self.pmintenset = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "pmmir" => self.pmmir = parse_dump_flags(line, value)?,
+            "pmovsr" => self.pmovsr = parse_dump_flags(line, value)?,
+            "pmovsset" => self.pmovsset = parse_dump_flags(line, value)?,
+            "pmselr" => self.pmselr = parse_dump_flags(line, value)?,
+            "pmswinc" => self.pmswinc = parse_dump_flags(line, value)?,
+            "pmuserenr" => self.pmuserenr = parse_dump_flags(line, value)?,
+            "pmxevtyper" => self.pmxevtyper = parse_dump_flags(line, value)?,
+            "por_el0" => self.por_el0 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "por_el1" => self.por_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "por_el2" => self.por_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el3")]
+            "por_el3" => self.por_el3 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "prrr" => self.prrr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "revidr" => self.revidr = parse_dump_int(line, value)?,
+            #[cfg(feature = "el1")]
+            "rgsr_el1" => self.rgsr_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "rmr" => self.rmr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "rvbar" => self.rvbar = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "s2pir_el2" => self.s2pir_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "s2por_el1" => self.s2por_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "scr" => self.scr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el3")]
+            "scr_el3" => self.scr_el3 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "sctlr" => self.sctlr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "sctlr2_el1" => self.sctlr2_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "sctlr2_el2" => self.sctlr2_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el3")]
+            "sctlr2_el3" => self.sctlr2_el3 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "sctlr_el1" => self.sctlr_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "sctlr_el2" => self.sctlr_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el3")]
+            "sctlr_el3" => self.sctlr_el3 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "sdcr" => self.sdcr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "sder" => self.sder = parse_dump_flags(line, value)?,
+            #[cfg(all(feature = "el3", feature = "feat-sve"))]
+            "smcr_el3" => self.smcr_el3 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "spsr_el1" => self.spsr_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "spsr_el2" => self.spsr_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el3")]
+            "spsr_el3" => self.spsr_el3 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "sp_el1" => self.sp_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "sp_el2" => self.sp_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "feat-sve")]
+            "svcr" => self.svcr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "tcmtr" => self.tcmtr = parse_dump_int(line, value)?,
+            #[cfg(feature = "el1")]
+            "tcr2_el1" => self.tcr2_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "tcr2_el2" => self.tcr2_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "tcr_el1" => self.tcr_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "tcr_el2" => self.tcr_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el3")]
+            "tcr_el3" => self.tcr_el3 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "tfsre0_el1" => self.tfsre0_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "tfsr_el1" => self.tfsr_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "tfsr_el2" => self.tfsr_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "tlbtr" => self.tlbtr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "tpidrprw" => self.tpidrprw = parse_dump_flags(line, value)?,
+            "tpidrro_el0" => self.tpidrro_el0 = parse_dump_flags(line, value)?,
+            "tpidruro" => self.tpidruro = parse_dump_flags(line, value)?,
+            "tpidrurw" => self.tpidrurw = parse_dump_flags(line, value)?,
+            "tpidr_el0" => self.tpidr_el0 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "tpidr_el1" => self.tpidr_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "tpidr_el2" => self.tpidr_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el3")]
+            "tpidr_el3" => self.tpidr_el3 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "trfcr" => self.trfcr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "ttbcr" => self.ttbcr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "ttbcr2" => self.ttbcr2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "ttbr0" => self.ttbr0 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "ttbr0_el1" => self.ttbr0_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "ttbr0_el2" => self.ttbr0_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el3")]
+            "ttbr0_el3" => self.ttbr0_el3 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "ttbr1" => self.ttbr1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "ttbr1_el1" => self.ttbr1_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "ttbr1_el2" => self.ttbr1_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "vbar" => self.vbar = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "vbar_el1" => self.vbar_el1 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "vbar_el2" => self.vbar_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "vdfsr" => self.vdfsr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "vdisr" => self.vdisr = parse_dump_flags(line, value)?,
+            #[cfg(all(feature = "el2", feature = "feat-ras"))]
+            "vdisr_el2" => self.vdisr_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "vmpidr" => self.vmpidr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "vmpidr_el2" => self.vmpidr_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el1")]
+            "vpidr" => self.vpidr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "vpidr_el2" => self.vpidr_el2 = parse_dump_flags(line, value)?,
+            #[cfg(all(feature = "el2", feature = "feat-ras"))]
+            "vsesr_el2" => self.vsesr_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "vtcr" => self.vtcr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "vtcr_el2" => self.vtcr_el2 = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "vttbr" => self.vttbr = parse_dump_flags(line, value)?,
+            #[cfg(feature = "el2")]
+            "vttbr_el2" => self.vttbr_el2 = parse_dump_flags(line, value)?,
+            #[cfg(all(feature = "el3", feature = "feat-sve"))]
+            "zcr_el3" => self.zcr_el3 = parse_dump_flags(line, value)?,
+            _ => {
+                return Err(DumpParseError::UnknownRegister {
+                    line,
+                    name: name.into(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the values of all fake registers, one `name = value` pair per line.
+    pub(crate) fn fmt_dump(&self, f: &mut Formatter) -> fmt::Result {
+        #[cfg(feature = "el1")]
+        writeln!(f, "actlr = {:#x}", self.actlr)?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "actlr2 = {:#x}", self.actlr2)?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "actlr_el1 = {:#x}", self.actlr_el1)?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "actlr_el2 = {:#x}", self.actlr_el2)?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "adfsr = {:#x}", self.adfsr)?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "afsr0_el1 = {:#x}", self.afsr0_el1)?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "afsr0_el2 = {:#x}", self.afsr0_el2)?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "afsr1_el1 = {:#x}", self.afsr1_el1)?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "afsr1_el2 = {:#x}", self.afsr1_el2)?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "aidr = {:#x}", self.aidr)?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "aifsr = {:#x}", self.aifsr)?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "amair0 = {:#x}", self.amair0)?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "amair1 = {:#x}", self.amair1)?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "amair_el1 = {:#x}", self.amair_el1)?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "amair_el2 = {:#x}", self.amair_el2)?;
+        writeln!(f, "amcfgr = {:#x}", self.amcfgr.bits())?;
+        #[cfg(feature = "feat-amu")]
+        writeln!(f, "amcfgr_el0 = {:#x}", self.amcfgr_el0.bits())?;
+        writeln!(f, "amcgcr = {:#x}", self.amcgcr.bits())?;
+        #[cfg(feature = "feat-amu")]
+        writeln!(f, "amcgcr_el0 = {:#x}", self.amcgcr_el0.bits())?;
+        writeln!(f, "amcntenclr0 = {:#x}", self.amcntenclr0.bits())?;
+        #[cfg(feature = "feat-amu")]
+        writeln!(f, "amcntenclr0_el0 = {:#x}", self.amcntenclr0_el0.bits())?;
+        writeln!(f, "amcntenclr1 = {:#x}", self.amcntenclr1.bits())?;
+        #[cfg(feature = "feat-amu")]
+        writeln!(f, "amcntenclr1_el0 = {:#x}", self.amcntenclr1_el0.bits())?;
+        writeln!(f, "amcntenset0 = {:#x}", self.amcntenset0.bits())?;
+        #[cfg(feature = "feat-amu")]
+        writeln!(f, "amcntenset0_el0 = {:#x}", self.amcntenset0_el0.bits())?;
+        writeln!(f, "amcntenset1 = {:#x}", self.amcntenset1.bits())?;
+        #[cfg(feature = "feat-amu")]
+        writeln!(f, "amcntenset1_el0 = {:#x}", self.amcntenset1_el0.bits())?;
+        writeln!(f, "amcr = {:#x}", self.amcr.bits())?;
+        #[cfg(feature = "feat-amu")]
+        writeln!(f, "amcr_el0 = {:#x}", self.amcr_el0.bits())?;
+        writeln!(f, "amevcntr00 = {:#x}", self.amevcntr00.bits())?;
+        #[cfg(feature = "feat-amu")]
+        writeln!(f, "amevcntr00_el0 = {:#x}", self.amevcntr00_el0.bits())?;
+        writeln!(f, "amevcntr01 = {:#x}", self.amevcntr01.bits())?;
+        #[cfg(feature = "feat-amu")]
+        writeln!(f, "amevcntr01_el0 = {:#x}", self.amevcntr01_el0.bits())?;
+        writeln!(f, "amevcntr02 = {:#x}", self.amevcntr02.bits())?;
+        #[cfg(feature = "feat-amu")]
+        writeln!(f, "amevcntr02_el0 = {:#x}", self.amevcntr02_el0.bits())?;
+        writeln!(f, "amevcntr03 = {:#x}", self.amevcntr03.bits())?;
+        #[cfg(feature = "feat-amu")]
+        writeln!(f, "amevcntr03_el0 = {:#x}", self.amevcntr03_el0.bits())?;
+        #[cfg(feature = "feat-amu")]
+        writeln!(f, "amevcntr10_el0 = {:#x}", self.amevcntr10_el0.bits())?;
+        #[cfg(feature = "feat-amu")]
+        writeln!(f, "amevcntr110_el0 = {:#x}", self.amevcntr110_el0.bits())?;
+        #[cfg(feature = "feat-amu")]
+        writeln!(f, "amevcntr111_el0 = {:#x}", self.amevcntr111_el0.bits())?;
+        #[cfg(feature = "feat-amu")]
+        writeln!(f, "amevcntr112_el0 = {:#x}", self.amevcntr112_el0.bits())?;
+        #[cfg(feature = "feat-amu")]
+        writeln!(f, "amevcntr113_el0 = {:#x}", self.amevcntr113_el0.bits())?;
+        #[cfg(feature = "feat-amu")]
+        writeln!(f, "amevcntr114_el0 = {:#x}", self.amevcntr114_el0.bits())?;
+        #[cfg(feature = "feat-amu")]
+        writeln!(f, "amevcntr115_el0 = {:#x}", self.amevcntr115_el0.bits())?;
+        #[cfg(feature = "feat-amu")]
+        writeln!(f, "amevcntr11_el0 = {:#x}", self.amevcntr11_el0.bits())?;
+        #[cfg(feature = "feat-amu")]
+        writeln!(f, "amevcntr12_el0 = {:#x}", self.amevcntr12_el0.bits())?;
+        #[cfg(feature = "feat-amu")]
+        writeln!(f, "amevcntr13_el0 = {:#x}", self.amevcntr13_el0.bits())?;
+        #[cfg(feature = "feat-amu")]
+        writeln!(f, "amevcntr14_el0 = {:#x}", self.amevcntr14_el0.bits())?;
+        #[cfg(feature = "feat-amu")]
+        writeln!(f, "amevcntr15_el0 = {:#x}", self.amevcntr15_el0.bits())?;
+        #[cfg(feature = "feat-amu")]
+        writeln!(f, "amevcntr16_el0 = {:#x}", self.amevcntr16_el0.bits())?;
+        #[cfg(feature = "feat-amu")]
+        writeln!(f, "amevcntr17_el0 = {:#x}", self.amevcntr17_el0.bits())?;
+        #[cfg(feature = "feat-amu")]
+        writeln!(f, "amevcntr18_el0 = {:#x}", self.amevcntr18_el0.bits())?;
+        #[cfg(feature = "feat-amu")]
+        writeln!(f, "amevcntr19_el0 = {:#x}", self.amevcntr19_el0.bits())?;
+        writeln!(f, "amevtyper00 = {:#x}", self.amevtyper00.bits())?;
+        #[cfg(feature = "feat-amu")]
+        writeln!(f, "amevtyper00_el0 = {:#x}", self.amevtyper00_el0.bits())?;
+        writeln!(f, "amevtyper01 = {:#x}", self.amevtyper01.bits())?;
+        #[cfg(feature = "feat-amu")]
+        writeln!(f, "amevtyper01_el0 = {:#x}", self.amevtyper01_el0.bits())?;
+        writeln!(f, "amevtyper02 = {:#x}", self.amevtyper02.bits())?;
+        #[cfg(feature = "feat-amu")]
+        writeln!(f, "amevtyper02_el0 = {:#x}", self.amevtyper02_el0.bits())?;
+        writeln!(f, "amevtyper03 = {:#x}", self.amevtyper03.bits())?;
+        #[cfg(feature = "feat-amu")]
+        writeln!(f, "amevtyper03_el0 = {:#x}", self.amevtyper03_el0.bits())?;
+        writeln!(f, "amevtyper10 = {:#x}", self.amevtyper10.bits())?;
+        writeln!(f, "amevtyper11 = {:#x}", self.amevtyper11.bits())?;
+        writeln!(f, "amevtyper110 = {:#x}", self.amevtyper110.bits())?;
+        writeln!(f, "amevtyper111 = {:#x}", self.amevtyper111.bits())?;
+        writeln!(f, "amevtyper112 = {:#x}", self.amevtyper112.bits())?;
+        writeln!(f, "amevtyper113 = {:#x}", self.amevtyper113.bits())?;
+        writeln!(f, "amevtyper114 = {:#x}", self.amevtyper114.bits())?;
+        writeln!(f, "amevtyper115 = {:#x}", self.amevtyper115.bits())?;
+        writeln!(f, "amevtyper12 = {:#x}", self.amevtyper12.bits())?;
+        writeln!(f, "amevtyper13 = {:#x}", self.amevtyper13.bits())?;
+        writeln!(f, "amevtyper14 = {:#x}", self.amevtyper14.bits())?;
+        writeln!(f, "amevtyper15 = {:#x}", self.amevtyper15.bits())?;
+        writeln!(f, "amevtyper16 = {:#x}", self.amevtyper16.bits())?;
+        writeln!(f, "amevtyper17 = {:#x}", self.amevtyper17.bits())?;
+        writeln!(f, "amevtyper18 = {:#x}", self.amevtyper18.bits())?;
+        writeln!(f, "amevtyper19 = {:#x}", self.amevtyper19.bits())?;
+        writeln!(f, "amuserenr = {:#x}", self.amuserenr.bits())?;
+        #[cfg(feature = "feat-amu")]
+        writeln!(f, "amuserenr_el0 = {:#x}", self.amuserenr_el0.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "apiakeyhi_el1 = {:#x}", self.apiakeyhi_el1.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "apiakeylo_el1 = {:#x}", self.apiakeylo_el1.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "brbcr_el2 = {:#x}", self.brbcr_el2.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "ccsidr = {:#x}", self.ccsidr.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "ccsidr2 = {:#x}", self.ccsidr2.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "ccsidr_el1 = {:#x}", self.ccsidr_el1.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "clidr = {:#x}", self.clidr.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "clidr_el1 = {:#x}", self.clidr_el1.bits())?;
+        writeln!(f, "cntfrq = {:#x}", self.cntfrq.bits())?;
+        writeln!(f, "cntfrq_el0 = {:#x}", self.cntfrq_el0.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "cnthctl = {:#x}", self.cnthctl.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "cnthctl_el2 = {:#x}", self.cnthctl_el2.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "cnthps_ctl = {:#x}", self.cnthps_ctl.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "cnthps_ctl_el2 = {:#x}", self.cnthps_ctl_el2.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "cnthps_cval = {:#x}", self.cnthps_cval.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "cnthps_cval_el2 = {:#x}", self.cnthps_cval_el2.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "cnthps_tval = {:#x}", self.cnthps_tval.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "cnthps_tval_el2 = {:#x}", self.cnthps_tval_el2.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "cnthp_ctl = {:#x}", self.cnthp_ctl.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "cnthp_ctl_el2 = {:#x}", self.cnthp_ctl_el2.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "cnthp_cval = {:#x}", self.cnthp_cval.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "cnthp_cval_el2 = {:#x}", self.cnthp_cval_el2.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "cnthp_tval = {:#x}", self.cnthp_tval.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "cnthp_tval_el2 = {:#x}", self.cnthp_tval_el2.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "cnthvs_ctl = {:#x}", self.cnthvs_ctl.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "cnthvs_ctl_el2 = {:#x}", self.cnthvs_ctl_el2.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "cnthvs_cval = {:#x}", self.cnthvs_cval.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "cnthvs_cval_el2 = {:#x}", self.cnthvs_cval_el2.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "cnthvs_tval = {:#x}", self.cnthvs_tval.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "cnthvs_tval_el2 = {:#x}", self.cnthvs_tval_el2.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "cnthv_ctl = {:#x}", self.cnthv_ctl.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "cnthv_ctl_el2 = {:#x}", self.cnthv_ctl_el2.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "cnthv_cval = {:#x}", self.cnthv_cval.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "cnthv_cval_el2 = {:#x}", self.cnthv_cval_el2.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "cnthv_tval = {:#x}", self.cnthv_tval.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "cnthv_tval_el2 = {:#x}", self.cnthv_tval_el2.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "cntkctl = {:#x}", self.cntkctl.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "cntkctl_el1 = {:#x}", self.cntkctl_el1.bits())?;
+        writeln!(f, "cntpct = {:#x}", self.cntpct.bits())?;
+        writeln!(f, "cntpctss = {:#x}", self.cntpctss.bits())?;
+        writeln!(f, "cntpctss_el0 = {:#x}", self.cntpctss_el0.bits())?;
+        writeln!(f, "cntpct_el0 = {:#x}", self.cntpct_el0.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "cntpoff_el2 = {:#x}", self.cntpoff_el2.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "cntps_ctl_el1 = {:#x}", self.cntps_ctl_el1.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "cntps_cval_el1 = {:#x}", self.cntps_cval_el1.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "cntps_tval_el1 = {:#x}", self.cntps_tval_el1.bits())?;
+        writeln!(f, "cntp_ctl = {:#x}", self.cntp_ctl.bits())?;
+        writeln!(f, "cntp_ctl_el0 = {:#x}", self.cntp_ctl_el0.bits())?;
+        writeln!(f, "cntp_cval = {:#x}", self.cntp_cval.bits())?;
+        writeln!(f, "cntp_cval_el0 = {:#x}", self.cntp_cval_el0.bits())?;
+        writeln!(f, "cntp_tval = {:#x}", self.cntp_tval.bits())?;
+        writeln!(f, "cntp_tval_el0 = {:#x}", self.cntp_tval_el0.bits())?;
+        writeln!(f, "cntvct = {:#x}", self.cntvct.bits())?;
+        writeln!(f, "cntvctss = {:#x}", self.cntvctss.bits())?;
+        writeln!(f, "cntvctss_el0 = {:#x}", self.cntvctss_el0.bits())?;
+        writeln!(f, "cntvct_el0 = {:#x}", self.cntvct_el0.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "cntvoff = {:#x}", self.cntvoff.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "cntvoff_el2 = {:#x}", self.cntvoff_el2.bits())?;
+        writeln!(f, "cntv_ctl = {:#x}", self.cntv_ctl.bits())?;
+        writeln!(f, "cntv_ctl_el0 = {:#x}", self.cntv_ctl_el0.bits())?;
+        writeln!(f, "cntv_cval = {:#x}", self.cntv_cval.bits())?;
+        writeln!(f, "cntv_cval_el0 = {:#x}", self.cntv_cval_el0.bits())?;
+        writeln!(f, "cntv_tval = {:#x}", self.cntv_tval.bits())?;
+        writeln!(f, "cntv_tval_el0 = {:#x}", self.cntv_tval_el0.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "contextidr = {:#x}", self.contextidr.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "contextidr_el1 = {:#x}", self.contextidr_el1.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "contextidr_el2 = {:#x}", self.contextidr_el2.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "cpacr = {:#x}", self.cpacr.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "cpacr_el1 = {:#x}", self.cpacr_el1.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "cptr_el2 = {:#x}", self.cptr_el2.bits())?;
+        #[cfg(feature = "el3")]
+        writeln!(f, "cptr_el3 = {:#x}", self.cptr_el3.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "csselr = {:#x}", self.csselr.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "csselr_el1 = {:#x}", self.csselr_el1.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "ctr = {:#x}", self.ctr.bits())?;
+        writeln!(f, "ctr_el0 = {:#x}", self.ctr_el0.bits())?;
+        writeln!(f, "currentel = {:#x}", self.currentel.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "dacr = {:#x}", self.dacr.bits())?;
+        writeln!(f, "daif = {:#x}", self.daif.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "dbgauthstatus = {:#x}", self.dbgauthstatus.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "dbgclaimclr = {:#x}", self.dbgclaimclr.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "dbgclaimset = {:#x}", self.dbgclaimset.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "dbgdccint = {:#x}", self.dbgdccint.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "dbgdevid = {:#x}", self.dbgdevid.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "dbgdevid1 = {:#x}", self.dbgdevid1.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "dbgdevid2 = {:#x}", self.dbgdevid2)?;
+        writeln!(f, "dbgdidr = {:#x}", self.dbgdidr.bits())?;
+        writeln!(f, "dbgdrar = {:#x}", self.dbgdrar.bits())?;
+        writeln!(f, "dbgdsar = {:#x}", self.dbgdsar)?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "dbgdscrext = {:#x}", self.dbgdscrext.bits())?;
+        writeln!(f, "dbgdscrint = {:#x}", self.dbgdscrint.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "dbgdtrrxext = {:#x}", self.dbgdtrrxext.bits())?;
+        writeln!(f, "dbgdtrrxint = {:#x}", self.dbgdtrrxint.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "dbgdtrtxext = {:#x}", self.dbgdtrtxext.bits())?;
+        writeln!(f, "dbgdtrtxint = {:#x}", self.dbgdtrtxint.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "dbgosdlr = {:#x}", self.dbgosdlr.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "dbgoseccr = {:#x}", self.dbgoseccr.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "dbgoslar = {:#x}", self.dbgoslar.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "dbgoslsr = {:#x}", self.dbgoslsr.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "dbgprcr = {:#x}", self.dbgprcr.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "dbgvcr = {:#x}", self.dbgvcr.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "dbgwfar = {:#x}", self.dbgwfar)?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "dfar = {:#x}", self.dfar.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "dfsr = {:#x}", self.dfsr.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "disr = {:#x}", self.disr.bits())?;
+        #[cfg(all(feature = "el1", feature = "feat-ras"))]
+        writeln!(f, "disr_el1 = {:#x}", self.disr_el1.bits())?;
+        writeln!(f, "dit = {:#x}", self.dit.bits())?;
+        writeln!(f, "dlr = {:#x}", self.dlr.bits())?;
+        writeln!(f, "dspsr = {:#x}", self.dspsr.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "dspsr2 = {:#x}", self.dspsr2.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "elr_el1 = {:#x}", self.elr_el1.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "elr_el2 = {:#x}", self.elr_el2.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "elr_hyp = {:#x}", self.elr_hyp.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "erridr = {:#x}", self.erridr.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "errselr = {:#x}", self.errselr.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "erxaddr = {:#x}", self.erxaddr.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "erxaddr2 = {:#x}", self.erxaddr2.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "erxctlr = {:#x}", self.erxctlr.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "erxctlr2 = {:#x}", self.erxctlr2.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "erxfr = {:#x}", self.erxfr.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "erxfr2 = {:#x}", self.erxfr2.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "erxmisc0 = {:#x}", self.erxmisc0.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "erxmisc1 = {:#x}", self.erxmisc1.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "erxmisc2 = {:#x}", self.erxmisc2.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "erxmisc3 = {:#x}", self.erxmisc3.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "erxmisc4 = {:#x}", self.erxmisc4.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "erxmisc5 = {:#x}", self.erxmisc5.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "erxmisc6 = {:#x}", self.erxmisc6.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "erxmisc7 = {:#x}", self.erxmisc7.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "erxstatus = {:#x}", self.erxstatus.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "esr_el1 = {:#x}", self.esr_el1.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "esr_el2 = {:#x}", self.esr_el2.bits())?;
+        #[cfg(feature = "el3")]
+        writeln!(f, "esr_el3 = {:#x}", self.esr_el3.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "far_el1 = {:#x}", self.far_el1.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "far_el2 = {:#x}", self.far_el2.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "fcseidr = {:#x}", self.fcseidr)?;
+        writeln!(f, "fpcr = {:#x}", self.fpcr.bits())?;
+        writeln!(f, "fpmr = {:#x}", self.fpmr.bits())?;
+        writeln!(f, "fpsr = {:#x}", self.fpsr.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "gcr_el1 = {:#x}", self.gcr_el1.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "gcscr_el1 = {:#x}", self.gcscr_el1.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "gcscr_el2 = {:#x}", self.gcscr_el2.bits())?;
+        #[cfg(all(feature = "el3", feature = "feat-rme"))]
+        writeln!(f, "gpccr_el3 = {:#x}", self.gpccr_el3.bits())?;
+        #[cfg(all(feature = "el3", feature = "feat-rme"))]
+        writeln!(f, "gptbr_el3 = {:#x}", self.gptbr_el3.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "hacr = {:#x}", self.hacr)?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "hacr_el2 = {:#x}", self.hacr_el2)?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "hactlr = {:#x}", self.hactlr)?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "hactlr2 = {:#x}", self.hactlr2)?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "hadfsr = {:#x}", self.hadfsr)?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "hafgrtr_el2 = {:#x}", self.hafgrtr_el2.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "haifsr = {:#x}", self.haifsr)?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "hamair0 = {:#x}", self.hamair0)?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "hamair1 = {:#x}", self.hamair1)?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "hcptr = {:#x}", self.hcptr.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "hcr = {:#x}", self.hcr.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "hcr2 = {:#x}", self.hcr2.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "hcrx_el2 = {:#x}", self.hcrx_el2.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "hcr_el2 = {:#x}", self.hcr_el2.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "hdcr = {:#x}", self.hdcr.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "hdfar = {:#x}", self.hdfar.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "hdfgrtr2_el2 = {:#x}", self.hdfgrtr2_el2.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "hdfgrtr_el2 = {:#x}", self.hdfgrtr_el2.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "hdfgwtr2_el2 = {:#x}", self.hdfgwtr2_el2.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "hdfgwtr_el2 = {:#x}", self.hdfgwtr_el2.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "hfgitr2_el2 = {:#x}", self.hfgitr2_el2.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "hfgitr_el2 = {:#x}", self.hfgitr_el2.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "hfgrtr2_el2 = {:#x}", self.hfgrtr2_el2.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "hfgrtr_el2 = {:#x}", self.hfgrtr_el2.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "hfgwtr2_el2 = {:#x}", self.hfgwtr2_el2.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "hfgwtr_el2 = {:#x}", self.hfgwtr_el2.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "hifar = {:#x}", self.hifar.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "hmair0 = {:#x}", self.hmair0.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "hmair1 = {:#x}", self.hmair1.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "hpfar = {:#x}", self.hpfar.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "hpfar_el2 = {:#x}", self.hpfar_el2.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "hrmr = {:#x}", self.hrmr.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "hsctlr = {:#x}", self.hsctlr.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "hsr = {:#x}", self.hsr.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "hstr = {:#x}", self.hstr)?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "hstr_el2 = {:#x}", self.hstr_el2)?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "htcr = {:#x}", self.htcr.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "htpidr = {:#x}", self.htpidr.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "htrfcr = {:#x}", self.htrfcr.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "httbr = {:#x}", self.httbr.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "hvbar = {:#x}", self.hvbar.bits())?;
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        writeln!(f, "icc_ap0r0_el1 = {:#x}", self.icc_ap0r0_el1)?;
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        writeln!(f, "icc_ap0r1_el1 = {:#x}", self.icc_ap0r1_el1)?;
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        writeln!(f, "icc_ap0r2_el1 = {:#x}", self.icc_ap0r2_el1)?;
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        writeln!(f, "icc_ap0r3_el1 = {:#x}", self.icc_ap0r3_el1)?;
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        writeln!(f, "icc_ap1r0_el1 = {:#x}", self.icc_ap1r0_el1.bits())?;
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        writeln!(f, "icc_ap1r1_el1 = {:#x}", self.icc_ap1r1_el1)?;
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        writeln!(f, "icc_ap1r2_el1 = {:#x}", self.icc_ap1r2_el1)?;
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        writeln!(f, "icc_ap1r3_el1 = {:#x}", self.icc_ap1r3_el1)?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "icc_asgi1r = {:#x}", self.icc_asgi1r.bits())?;
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        writeln!(f, "icc_asgi1r_el1 = {:#x}", self.icc_asgi1r_el1.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "icc_bpr0 = {:#x}", self.icc_bpr0.bits())?;
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        writeln!(f, "icc_bpr0_el1 = {:#x}", self.icc_bpr0_el1.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "icc_bpr1 = {:#x}", self.icc_bpr1.bits())?;
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        writeln!(f, "icc_bpr1_el1 = {:#x}", self.icc_bpr1_el1.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "icc_ctlr = {:#x}", self.icc_ctlr.bits())?;
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        writeln!(f, "icc_ctlr_el1 = {:#x}", self.icc_ctlr_el1.bits())?;
+        #[cfg(all(feature = "el3", feature = "feat-gic"))]
+        writeln!(f, "icc_ctlr_el3 = {:#x}", self.icc_ctlr_el3.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "icc_dir = {:#x}", self.icc_dir.bits())?;
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        writeln!(f, "icc_dir_el1 = {:#x}", self.icc_dir_el1.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "icc_eoir0 = {:#x}", self.icc_eoir0.bits())?;
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        writeln!(f, "icc_eoir0_el1 = {:#x}", self.icc_eoir0_el1.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "icc_eoir1 = {:#x}", self.icc_eoir1.bits())?;
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        writeln!(f, "icc_eoir1_el1 = {:#x}", self.icc_eoir1_el1.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "icc_hppir0 = {:#x}", self.icc_hppir0.bits())?;
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        writeln!(f, "icc_hppir0_el1 = {:#x}", self.icc_hppir0_el1.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "icc_hppir1 = {:#x}", self.icc_hppir1.bits())?;
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        writeln!(f, "icc_hppir1_el1 = {:#x}", self.icc_hppir1_el1.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "icc_hsre = {:#x}", self.icc_hsre.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "icc_iar0 = {:#x}", self.icc_iar0.bits())?;
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        writeln!(f, "icc_iar0_el1 = {:#x}", self.icc_iar0_el1.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "icc_iar1 = {:#x}", self.icc_iar1.bits())?;
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        writeln!(f, "icc_iar1_el1 = {:#x}", self.icc_iar1_el1.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "icc_igrpen0 = {:#x}", self.icc_igrpen0.bits())?;
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        writeln!(f, "icc_igrpen0_el1 = {:#x}", self.icc_igrpen0_el1.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "icc_igrpen1 = {:#x}", self.icc_igrpen1.bits())?;
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        writeln!(f, "icc_igrpen1_el1 = {:#x}", self.icc_igrpen1_el1.bits())?;
+        #[cfg(all(feature = "el3", feature = "feat-gic"))]
+        writeln!(f, "icc_igrpen1_el3 = {:#x}", self.icc_igrpen1_el3.bits())?;
+        #[cfg(feature = "el3")]
+        writeln!(f, "icc_mctlr = {:#x}", self.icc_mctlr.bits())?;
+        #[cfg(feature = "el3")]
+        writeln!(f, "icc_mgrpen1 = {:#x}", self.icc_mgrpen1.bits())?;
+        #[cfg(feature = "el3")]
+        writeln!(f, "icc_msre = {:#x}", self.icc_msre.bits())?;
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        writeln!(f, "icc_nmiar1_el1 = {:#x}", self.icc_nmiar1_el1.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "icc_pmr = {:#x}", self.icc_pmr.bits())?;
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        writeln!(f, "icc_pmr_el1 = {:#x}", self.icc_pmr_el1.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "icc_rpr = {:#x}", self.icc_rpr.bits())?;
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        writeln!(f, "icc_rpr_el1 = {:#x}", self.icc_rpr_el1.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "icc_sgi0r = {:#x}", self.icc_sgi0r.bits())?;
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        writeln!(f, "icc_sgi0r_el1 = {:#x}", self.icc_sgi0r_el1.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "icc_sgi1r = {:#x}", self.icc_sgi1r.bits())?;
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        writeln!(f, "icc_sgi1r_el1 = {:#x}", self.icc_sgi1r_el1.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "icc_sre = {:#x}", self.icc_sre.bits())?;
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        writeln!(f, "icc_sre_el1 = {:#x}", self.icc_sre_el1.bits())?;
+        #[cfg(all(feature = "el2", feature = "feat-gic"))]
+        writeln!(f, "icc_sre_el2 = {:#x}", self.icc_sre_el2.bits())?;
+        #[cfg(all(feature = "el3", feature = "feat-gic"))]
+        writeln!(f, "icc_sre_el3 = {:#x}", self.icc_sre_el3.bits())?;
+        #[cfg(all(feature = "el2", feature = "feat-gic"))]
+        writeln!(f, "ich_hcr_el2 = {:#x}", self.ich_hcr_el2.bits())?;
+        #[cfg(all(feature = "el2", feature = "feat-gic"))]
+        writeln!(f, "ich_vmcr_el2 = {:#x}", self.ich_vmcr_el2.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "id_aa64dfr0_el1 = {:#x}", self.id_aa64dfr0_el1.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "id_aa64dfr1_el1 = {:#x}", self.id_aa64dfr1_el1.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "id_aa64isar1_el1 = {:#x}", self.id_aa64isar1_el1.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "id_aa64isar2_el1 = {:#x}", self.id_aa64isar2_el1.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "id_aa64mmfr0_el1 = {:#x}", self.id_aa64mmfr0_el1.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "id_aa64mmfr1_el1 = {:#x}", self.id_aa64mmfr1_el1.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "id_aa64mmfr2_el1 = {:#x}", self.id_aa64mmfr2_el1.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "id_aa64mmfr3_el1 = {:#x}", self.id_aa64mmfr3_el1.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "id_aa64mmfr4_el1 = {:#x}", self.id_aa64mmfr4_el1.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "id_aa64pfr0_el1 = {:#x}", self.id_aa64pfr0_el1.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "id_aa64pfr1_el1 = {:#x}", self.id_aa64pfr1_el1.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "id_aa64pfr2_el1 = {:#x}", self.id_aa64pfr2_el1.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "id_aa64smfr0_el1 = {:#x}", self.id_aa64smfr0_el1.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "id_afr0 = {:#x}", self.id_afr0)?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "id_dfr0 = {:#x}", self.id_dfr0.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "id_dfr1 = {:#x}", self.id_dfr1.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "id_isar0 = {:#x}", self.id_isar0.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "id_isar1 = {:#x}", self.id_isar1.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "id_isar2 = {:#x}", self.id_isar2.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "id_isar3 = {:#x}", self.id_isar3.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "id_isar4 = {:#x}", self.id_isar4.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "id_isar5 = {:#x}", self.id_isar5.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "id_isar6 = {:#x}", self.id_isar6.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "id_mmfr0 = {:#x}", self.id_mmfr0.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "id_mmfr1 = {:#x}", self.id_mmfr1.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "id_mmfr2 = {:#x}", self.id_mmfr2.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "id_mmfr3 = {:#x}", self.id_mmfr3.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "id_mmfr4 = {:#x}", self.id_mmfr4.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "id_mmfr5 = {:#x}", self.id_mmfr5.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "id_pfr0 = {:#x}", self.id_pfr0.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "id_pfr1 = {:#x}", self.id_pfr1.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "id_pfr2 = {:#x}", self.id_pfr2.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "ifar = {:#x}", self.ifar.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "ifsr = {:#x}", self.ifsr.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "isr = {:#x}", self.isr.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "isr_el1 = {:#x}", self.isr_el1.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "jidr = {:#x}", self.jidr)?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "jmcr = {:#x}", self.jmcr)?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "joscr = {:#x}", self.joscr)?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "mair0 = {:#x}", self.mair0.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "mair1 = {:#x}", self.mair1.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "mair_el1 = {:#x}", self.mair_el1.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "mair_el2 = {:#x}", self.mair_el2.bits())?;
+        #[cfg(feature = "el3")]
+        writeln!(f, "mair_el3 = {:#x}", self.mair_el3.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "mdccint_el1 = {:#x}", self.mdccint_el1.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "mdcr_el2 = {:#x}", self.mdcr_el2.bits())?;
+        #[cfg(feature = "el3")]
+        writeln!(f, "mdcr_el3 = {:#x}", self.mdcr_el3.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "mdscr_el1 = {:#x}", self.mdscr_el1.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "midr = {:#x}", self.midr.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "midr_el1 = {:#x}", self.midr_el1.bits())?;
+        #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+        writeln!(f, "mpam2_el2 = {:#x}", self.mpam2_el2.bits())?;
+        #[cfg(all(feature = "el3", feature = "feat-mpam"))]
+        writeln!(f, "mpam3_el3 = {:#x}", self.mpam3_el3.bits())?;
+        #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+        writeln!(f, "mpamhcr_el2 = {:#x}", self.mpamhcr_el2.bits())?;
+        #[cfg(all(feature = "el1", feature = "feat-mpam"))]
+        writeln!(f, "mpamidr_el1 = {:#x}", self.mpamidr_el1.bits())?;
+        #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+        writeln!(f, "mpamvpm0_el2 = {:#x}", self.mpamvpm0_el2.bits())?;
+        #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+        writeln!(f, "mpamvpm1_el2 = {:#x}", self.mpamvpm1_el2.bits())?;
+        #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+        writeln!(f, "mpamvpm2_el2 = {:#x}", self.mpamvpm2_el2.bits())?;
+        #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+        writeln!(f, "mpamvpm3_el2 = {:#x}", self.mpamvpm3_el2.bits())?;
+        #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+        writeln!(f, "mpamvpm4_el2 = {:#x}", self.mpamvpm4_el2.bits())?;
+        #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+        writeln!(f, "mpamvpm5_el2 = {:#x}", self.mpamvpm5_el2.bits())?;
+        #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+        writeln!(f, "mpamvpm6_el2 = {:#x}", self.mpamvpm6_el2.bits())?;
+        #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+        writeln!(f, "mpamvpm7_el2 = {:#x}", self.mpamvpm7_el2.bits())?;
+        #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+        writeln!(f, "mpamvpmv_el2 = {:#x}", self.mpamvpmv_el2.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "mpidr = {:#x}", self.mpidr.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "mpidr_el1 = {:#x}", self.mpidr_el1.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "mvbar = {:#x}", self.mvbar.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "nmrr = {:#x}", self.nmrr.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "nsacr = {:#x}", self.nsacr.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "par = {:#x}", self.par.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "par_el1 = {:#x}", self.par_el1.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "pfar_el1 = {:#x}", self.pfar_el1.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "pfar_el2 = {:#x}", self.pfar_el2.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "pire0_el1 = {:#x}", self.pire0_el1.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "pire0_el2 = {:#x}", self.pire0_el2.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "pir_el1 = {:#x}", self.pir_el1.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "pir_el2 = {:#x}", self.pir_el2.bits())?;
+        #[cfg(feature = "el3")]
+        writeln!(f, "pir_el3 = {:#x}", self.pir_el3.bits())?;
+        writeln!(f, "pmccfiltr = {:#x}", self.pmccfiltr.bits())?;
+        writeln!(f, "pmccntr = {:#x}", self.pmccntr.bits())?;
+        writeln!(f, "pmceid0 = {:#x}", self.pmceid0.bits())?;
+        writeln!(f, "pmceid1 = {:#x}", self.pmceid1.bits())?;
+        writeln!(f, "pmceid2 = {:#x}", self.pmceid2.bits())?;
+        writeln!(f, "pmceid3 = {:#x}", self.pmceid3.bits())?;
+        writeln!(f, "pmcntenclr = {:#x}", self.pmcntenclr.bits())?;
+        writeln!(f, "pmcntenset = {:#x}", self.pmcntenset.bits())?;
+        writeln!(f, "pmcr = {:#x}", self.pmcr.bits())?;
+        #[cfg(feature = "feat-pmu")]
+        writeln!(f, "pmcr_el0 = {:#x}", self.pmcr_el0.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "pmintenclr = {:#x}", self.pmintenclr.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "pmintenset = {:#x}", self.pmintenset.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "pmmir = {:#x}", self.pmmir.bits())?;
+        writeln!(f, "pmovsr = {:#x}", self.pmovsr.bits())?;
+        writeln!(f, "pmovsset = {:#x}", self.pmovsset.bits())?;
+        writeln!(f, "pmselr = {:#x}", self.pmselr.bits())?;
+        writeln!(f, "pmswinc = {:#x}", self.pmswinc.bits())?;
+        writeln!(f, "pmuserenr = {:#x}", self.pmuserenr.bits())?;
+        writeln!(f, "pmxevtyper = {:#x}", self.pmxevtyper.bits())?;
+        writeln!(f, "por_el0 = {:#x}", self.por_el0.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "por_el1 = {:#x}", self.por_el1.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "por_el2 = {:#x}", self.por_el2.bits())?;
+        #[cfg(feature = "el3")]
+        writeln!(f, "por_el3 = {:#x}", self.por_el3.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "prrr = {:#x}", self.prrr.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "revidr = {:#x}", self.revidr)?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "rgsr_el1 = {:#x}", self.rgsr_el1.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "rmr = {:#x}", self.rmr.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "rvbar = {:#x}", self.rvbar.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "s2pir_el2 = {:#x}", self.s2pir_el2.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "s2por_el1 = {:#x}", self.s2por_el1.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "scr = {:#x}", self.scr.bits())?;
+        #[cfg(feature = "el3")]
+        writeln!(f, "scr_el3 = {:#x}", self.scr_el3.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "sctlr = {:#x}", self.sctlr.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "sctlr2_el1 = {:#x}", self.sctlr2_el1.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "sctlr2_el2 = {:#x}", self.sctlr2_el2.bits())?;
+        #[cfg(feature = "el3")]
+        writeln!(f, "sctlr2_el3 = {:#x}", self.sctlr2_el3.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "sctlr_el1 = {:#x}", self.sctlr_el1.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "sctlr_el2 = {:#x}", self.sctlr_el2.bits())?;
+        #[cfg(feature = "el3")]
+        writeln!(f, "sctlr_el3 = {:#x}", self.sctlr_el3.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "sdcr = {:#x}", self.sdcr.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "sder = {:#x}", self.sder.bits())?;
+        #[cfg(all(feature = "el3", feature = "feat-sve"))]
+        writeln!(f, "smcr_el3 = {:#x}", self.smcr_el3.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "spsr_el1 = {:#x}", self.spsr_el1.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "spsr_el2 = {:#x}", self.spsr_el2.bits())?;
+        #[cfg(feature = "el3")]
+        writeln!(f, "spsr_el3 = {:#x}", self.spsr_el3.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "sp_el1 = {:#x}", self.sp_el1.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "sp_el2 = {:#x}", self.sp_el2.bits())?;
+        #[cfg(feature = "feat-sve")]
+        writeln!(f, "svcr = {:#x}", self.svcr.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "tcmtr = {:#x}", self.tcmtr)?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "tcr2_el1 = {:#x}", self.tcr2_el1.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "tcr2_el2 = {:#x}", self.tcr2_el2.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "tcr_el1 = {:#x}", self.tcr_el1.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "tcr_el2 = {:#x}", self.tcr_el2.bits())?;
+        #[cfg(feature = "el3")]
+        writeln!(f, "tcr_el3 = {:#x}", self.tcr_el3.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "tfsre0_el1 = {:#x}", self.tfsre0_el1.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "tfsr_el1 = {:#x}", self.tfsr_el1.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "tfsr_el2 = {:#x}", self.tfsr_el2.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "tlbtr = {:#x}", self.tlbtr.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "tpidrprw = {:#x}", self.tpidrprw.bits())?;
+        writeln!(f, "tpidrro_el0 = {:#x}", self.tpidrro_el0.bits())?;
+        writeln!(f, "tpidruro = {:#x}", self.tpidruro.bits())?;
+        writeln!(f, "tpidrurw = {:#x}", self.tpidrurw.bits())?;
+        writeln!(f, "tpidr_el0 = {:#x}", self.tpidr_el0.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "tpidr_el1 = {:#x}", self.tpidr_el1.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "tpidr_el2 = {:#x}", self.tpidr_el2.bits())?;
+        #[cfg(feature = "el3")]
+        writeln!(f, "tpidr_el3 = {:#x}", self.tpidr_el3.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "trfcr = {:#x}", self.trfcr.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "ttbcr = {:#x}", self.ttbcr.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "ttbcr2 = {:#x}", self.ttbcr2.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "ttbr0 = {:#x}", self.ttbr0.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "ttbr0_el1 = {:#x}", self.ttbr0_el1.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "ttbr0_el2 = {:#x}", self.ttbr0_el2.bits())?;
+        #[cfg(feature = "el3")]
+        writeln!(f, "ttbr0_el3 = {:#x}", self.ttbr0_el3.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "ttbr1 = {:#x}", self.ttbr1.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "ttbr1_el1 = {:#x}", self.ttbr1_el1.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "ttbr1_el2 = {:#x}", self.ttbr1_el2.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "vbar = {:#x}", self.vbar.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "vbar_el1 = {:#x}", self.vbar_el1.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "vbar_el2 = {:#x}", self.vbar_el2.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "vdfsr = {:#x}", self.vdfsr.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "vdisr = {:#x}", self.vdisr.bits())?;
+        #[cfg(all(feature = "el2", feature = "feat-ras"))]
+        writeln!(f, "vdisr_el2 = {:#x}", self.vdisr_el2.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "vmpidr = {:#x}", self.vmpidr.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "vmpidr_el2 = {:#x}", self.vmpidr_el2.bits())?;
+        #[cfg(feature = "el1")]
+        writeln!(f, "vpidr = {:#x}", self.vpidr.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "vpidr_el2 = {:#x}", self.vpidr_el2.bits())?;
+        #[cfg(all(feature = "el2", feature = "feat-ras"))]
+        writeln!(f, "vsesr_el2 = {:#x}", self.vsesr_el2.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "vtcr = {:#x}", self.vtcr.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "vtcr_el2 = {:#x}", self.vtcr_el2.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "vttbr = {:#x}", self.vttbr.bits())?;
+        #[cfg(feature = "el2")]
+        writeln!(f, "vttbr_el2 = {:#x}", self.vttbr_el2.bits())?;
+        #[cfg(all(feature = "el3", feature = "feat-sve"))]
+        writeln!(f, "zcr_el3 = {:#x}", self.zcr_el3.bits())?;
+        Ok(())
+    }
+
+    /// Copies the values of all registers shared between PEs from `other`.
+    pub(crate) fn copy_shared_from(&mut self, other: &Self) {
+        self.cntpct = other.cntpct;
+        self.cntpctss = other.cntpctss;
+        self.cntpctss_el0 = other.cntpctss_el0;
+        self.cntpct_el0 = other.cntpct_el0;
+    }
+
+    /// Returns the changes to the values of all registers and their fields between `self` and
+    /// `other`.
+    ///
+    /// Registers with named fields are compared field by field, others by their whole value.
+    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        #[cfg(feature = "el1")]
+        if self.actlr != other.actlr {
+            changes.push(FieldChange {
+                register: "ACTLR",
+                field: None,
+                old: u64::from(self.actlr),
+                new: u64::from(other.actlr),
+            });
+        }
+        #[cfg(feature = "el1")]
+        if self.actlr2 != other.actlr2 {
+            changes.push(FieldChange {
+                register: "ACTLR2",
+                field: None,
+                old: u64::from(self.actlr2),
+                new: u64::from(other.actlr2),
+            });
+        }
+        #[cfg(feature = "el1")]
+        if self.actlr_el1 != other.actlr_el1 {
+            changes.push(FieldChange {
+                register: "ACTLR_EL1",
+                field: None,
+                old: self.actlr_el1,
+                new: other.actlr_el1,
+            });
+        }
+        #[cfg(feature = "el2")]
+        if self.actlr_el2 != other.actlr_el2 {
+            changes.push(FieldChange {
+                register: "ACTLR_EL2",
+                field: None,
+                old: self.actlr_el2,
+                new: other.actlr_el2,
+            });
+        }
+        #[cfg(feature = "el1")]
+        if self.adfsr != other.adfsr {
+            changes.push(FieldChange {
+                register: "ADFSR",
+                field: None,
+                old: u64::from(self.adfsr),
+                new: u64::from(other.adfsr),
+            });
+        }
+        #[cfg(feature = "el1")]
+        if self.afsr0_el1 != other.afsr0_el1 {
+            changes.push(FieldChange {
+                register: "AFSR0_EL1",
+                field: None,
+                old: self.afsr0_el1,
+                new: other.afsr0_el1,
+            });
+        }
+        #[cfg(feature = "el2")]
+        if self.afsr0_el2 != other.afsr0_el2 {
+            changes.push(FieldChange {
+                register: "AFSR0_EL2",
+                field: None,
+                old: self.afsr0_el2,
+                new: other.afsr0_el2,
+            });
+        }
+        #[cfg(feature = "el1")]
+        if self.afsr1_el1 != other.afsr1_el1 {
+            changes.push(FieldChange {
+                register: "AFSR1_EL1",
+                field: None,
+                old: self.afsr1_el1,
+                new: other.afsr1_el1,
+            });
+        }
+        #[cfg(feature = "el2")]
+        if self.afsr1_el2 != other.afsr1_el2 {
+            changes.push(FieldChange {
+                register: "AFSR1_EL2",
+                field: None,
+                old: self.afsr1_el2,
+                new: other.afsr1_el2,
+            });
+        }
+        #[cfg(feature = "el1")]
+        if self.aidr != other.aidr {
+            changes.push(FieldChange {
+                register: "AIDR",
+                field: None,
+                old: u64::from(self.aidr),
+                new: u64::from(other.aidr),
+            });
+        }
+        #[cfg(feature = "el1")]
+        if self.aifsr != other.aifsr {
+            changes.push(FieldChange {
+                register: "AIFSR",
+                field: None,
+                old: u64::from(self.aifsr),
+                new: u64::from(other.aifsr),
+            });
+        }
+        #[cfg(feature = "el1")]
+        if self.amair0 != other.amair0 {
+            changes.push(FieldChange {
+                register: "AMAIR0",
+                field: None,
+                old: u64::from(self.amair0),
+                new: u64::from(other.amair0),
+            });
+        }
+        #[cfg(feature = "el1")]
+        if self.amair1 != other.amair1 {
+            changes.push(FieldChange {
+                register: "AMAIR1",
+                field: None,
+                old: u64::from(self.amair1),
+                new: u64::from(other.amair1),
+            });
+        }
+        #[cfg(feature = "el1")]
+        if self.amair_el1 != other.amair_el1 {
+            changes.push(FieldChange {
+                register: "AMAIR_EL1",
+                field: None,
+                old: self.amair_el1,
+                new: other.amair_el1,
+            });
+        }
+        #[cfg(feature = "el2")]
+        if self.amair_el2 != other.amair_el2 {
+            changes.push(FieldChange {
+                register: "AMAIR_EL2",
+                field: None,
+                old: self.amair_el2,
+                new: other.amair_el2,
+            });
+        }
+        changes.extend(self.amcfgr.diff(other.amcfgr, "AMCFGR"));
+        #[cfg(feature = "feat-amu")]
+        changes.extend(self.amcfgr_el0.diff(other.amcfgr_el0, "AMCFGR_EL0"));
+        changes.extend(self.amcgcr.diff(other.amcgcr, "AMCGCR"));
+        #[cfg(feature = "feat-amu")]
+        changes.extend(self.amcgcr_el0.diff(other.amcgcr_el0, "AMCGCR_EL0"));
+        changes.extend(self.amcntenclr0.diff(other.amcntenclr0, "AMCNTENCLR0"));
+        #[cfg(feature = "feat-amu")]
+        changes.extend(
+            self.amcntenclr0_el0
+                .diff(other.amcntenclr0_el0, "AMCNTENCLR0_EL0"),
+        );
+        changes.extend(self.amcntenclr1.diff(other.amcntenclr1, "AMCNTENCLR1"));
+        #[cfg(feature = "feat-amu")]
+        changes.extend(
+            self.amcntenclr1_el0
+                .diff(other.amcntenclr1_el0, "AMCNTENCLR1_EL0"),
+        );
+        changes.extend(self.amcntenset0.diff(other.amcntenset0, "AMCNTENSET0"));
+        #[cfg(feature = "feat-amu")]
+        changes.extend(
+            self.amcntenset0_el0
+                .diff(other.amcntenset0_el0, "AMCNTENSET0_EL0"),
+        );
+        changes.extend(self.amcntenset1.diff(other.amcntenset1, "AMCNTENSET1"));
+        #[cfg(feature = "feat-amu")]
+        changes.extend(
+            self.amcntenset1_el0
+                .diff(other.amcntenset1_el0, "AMCNTENSET1_EL0"),
+        );
+        changes.extend(self.amcr.diff(other.amcr, "AMCR"));
+        #[cfg(feature = "feat-amu")]
+        changes.extend(self.amcr_el0.diff(other.amcr_el0, "AMCR_EL0"));
+        changes.extend(self.amevcntr00.diff(other.amevcntr00, "AMEVCNTR00"));
+        #[cfg(feature = "feat-amu")]
+        changes.extend(
+            self.amevcntr00_el0
+                .diff(other.amevcntr00_el0, "AMEVCNTR00_EL0"),
+        );
+        changes.extend(self.amevcntr01.diff(other.amevcntr01, "AMEVCNTR01"));
+        #[cfg(feature = "feat-amu")]
+        changes.extend(
+            self.amevcntr01_el0
+                .diff(other.amevcntr01_el0, "AMEVCNTR01_EL0"),
+        );
+        changes.extend(self.amevcntr02.diff(other.amevcntr02, "AMEVCNTR02"));
+        #[cfg(feature = "feat-amu")]
+        changes.extend(
+            self.amevcntr02_el0
+                .diff(other.amevcntr02_el0, "AMEVCNTR02_EL0"),
+        );
+        changes.extend(self.amevcntr03.diff(other.amevcntr03, "AMEVCNTR03"));
+        #[cfg(feature = "feat-amu")]
+        changes.extend(
+            self.amevcntr03_el0
+                .diff(other.amevcntr03_el0, "AMEVCNTR03_EL0"),
+        );
+        #[cfg(feature = "feat-amu")]
+        changes.extend(
+            self.amevcntr10_el0
+                .diff(other.amevcntr10_el0, "AMEVCNTR10_EL0"),
+        );
+        #[cfg(feature = "feat-amu")]
+        changes.extend(
+            self.amevcntr110_el0
+                .diff(other.amevcntr110_el0, "AMEVCNTR110_EL0"),
+        );
+        #[cfg(feature = "feat-amu")]
+        changes.extend(
+            self.amevcntr111_el0
+                .diff(other.amevcntr111_el0, "AMEVCNTR111_EL0"),
+        );
+        #[cfg(feature = "feat-amu")]
+        changes.extend(
+            self.amevcntr112_el0
+                .diff(other.amevcntr112_el0, "AMEVCNTR112_EL0"),
+        );
+        #[cfg(feature = "feat-amu")]
+        changes.extend(
+            self.amevcntr113_el0
+                .diff(other.amevcntr113_el0, "AMEVCNTR113_EL0"),
+        );
+        #[cfg(feature = "feat-amu")]
+        changes.extend(
+            self.amevcntr114_el0
+                .diff(other.amevcntr114_el0, "AMEVCNTR114_EL0"),
+        );
+        #[cfg(feature = "feat-amu")]
+        changes.extend(
+            self.amevcntr115_el0
+                .diff(other.amevcntr115_el0, "AMEVCNTR115_EL0"),
+        );
+        #[cfg(feature = "feat-amu")]
+        changes.extend(
+            self.amevcntr11_el0
+                .diff(other.amevcntr11_el0, "AMEVCNTR11_EL0"),
+        );
+        #[cfg(feature = "feat-amu")]
+        changes.extend(
+            self.amevcntr12_el0
+                .diff(other.amevcntr12_el0, "AMEVCNTR12_EL0"),
+        );
+        #[cfg(feature = "feat-amu")]
+        changes.extend(
+            self.amevcntr13_el0
+                .diff(other.amevcntr13_el0, "AMEVCNTR13_EL0"),
+        );
+        #[cfg(feature = "feat-amu")]
+        changes.extend(
+            self.amevcntr14_el0
+                .diff(other.amevcntr14_el0, "AMEVCNTR14_EL0"),
+        );
+        #[cfg(feature = "feat-amu")]
+        changes.extend(
+            self.amevcntr15_el0
+                .diff(other.amevcntr15_el0, "AMEVCNTR15_EL0"),
+        );
+        #[cfg(feature = "feat-amu")]
+        changes.extend(
+            self.amevcntr16_el0
+                .diff(other.amevcntr16_el0, "AMEVCNTR16_EL0"),
+        );
+        #[cfg(feature = "feat-amu")]
+        changes.extend(
+            self.amevcntr17_el0
+                .diff(other.amevcntr17_el0, "AMEVCNTR17_EL0"),
+        );
+        #[cfg(feature = "feat-amu")]
+        changes.extend(
+            self.amevcntr18_el0
+                .diff(other.amevcntr18_el0, "AMEVCNTR18_EL0"),
+        );
+        #[cfg(feature = "feat-amu")]
+        changes.extend(
+            self.amevcntr19_el0
+                .diff(other.amevcntr19_el0, "AMEVCNTR19_EL0"),
+        );
+        changes.extend(self.amevtyper00.diff(other.amevtyper00, "AMEVTYPER00"));
+        #[cfg(feature = "feat-amu")]
+        changes.extend(
+            self.amevtyper00_el0
+                .diff(other.amevtyper00_el0, "AMEVTYPER00_EL0"),
+        );
+        changes.extend(self.amevtyper01.diff(other.amevtyper01, "AMEVTYPER01"));
+        #[cfg(feature = "feat-amu")]
+        changes.extend(
+            self.amevtyper01_el0
+                .diff(other.amevtyper01_el0, "AMEVTYPER01_EL0"),
+        );
+        changes.extend(self.amevtyper02.diff(other.amevtyper02, "AMEVTYPER02"));
+        #[cfg(feature = "feat-amu")]
+        changes.extend(
+            self.amevtyper02_el0
+                .diff(other.amevtyper02_el0, "AMEVTYPER02_EL0"),
+        );
+        changes.extend(self.amevtyper03.diff(other.amevtyper03, "AMEVTYPER03"));
+        #[cfg(feature = "feat-amu")]
+        changes.extend(
+            self.amevtyper03_el0
+                .diff(other.amevtyper03_el0, "AMEVTYPER03_EL0"),
+        );
+        changes.extend(self.amevtyper10.diff(other.amevtyper10, "AMEVTYPER10"));
+        changes.extend(self.amevtyper11.diff(other.amevtyper11, "AMEVTYPER11"));
+        changes.extend(self.amevtyper110.diff(other.amevtyper110, "AMEVTYPER110"));
+        changes.extend(self.amevtyper111.diff(other.amevtyper111, "AMEVTYPER111"));
+        changes.extend(self.amevtyper112.diff(other.amevtyper112, "AMEVTYPER112"));
+        changes.extend(self.amevtyper113.diff(other.amevtyper113, "AMEVTYPER113"));
+        changes.extend(self.amevtyper114.diff(other.amevtyper114, "AMEVTYPER114"));
+        changes.extend(self.amevtyper115.diff(other.amevtyper115, "AMEVTYPER115"));
+        changes.extend(self.amevtyper12.diff(other.amevtyper12, "AMEVTYPER12"));
+        changes.extend(self.amevtyper13.diff(other.amevtyper13, "AMEVTYPER13"));
+        changes.extend(self.amevtyper14.diff(other.amevtyper14, "AMEVTYPER14"));
+        changes.extend(self.amevtyper15.diff(other.amevtyper15, "AMEVTYPER15"));
+        changes.extend(self.amevtyper16.diff(other.amevtyper16, "AMEVTYPER16"));
+        changes.extend(self.amevtyper17.diff(other.amevtyper17, "AMEVTYPER17"));
+        changes.extend(self.amevtyper18.diff(other.amevtyper18, "AMEVTYPER18"));
+        changes.extend(self.amevtyper19.diff(other.amevtyper19, "AMEVTYPER19"));
+        changes.extend(self.amuserenr.diff(other.amuserenr, "AMUSERENR"));
+        #[cfg(feature = "feat-amu")]
+        changes.extend(
+            self.amuserenr_el0
+                .diff(other.amuserenr_el0, "AMUSERENR_EL0"),
+        );
+        #[cfg(feature = "el1")]
+        changes.extend(
+            self.apiakeyhi_el1
+                .diff(other.apiakeyhi_el1, "APIAKeyHi_EL1"),
+        );
+        #[cfg(feature = "el1")]
+        changes.extend(
+            self.apiakeylo_el1
+                .diff(other.apiakeylo_el1, "APIAKeyLo_EL1"),
+        );
+        #[cfg(feature = "el2")]
+        changes.extend(self.brbcr_el2.diff(other.brbcr_el2, "BRBCR_EL2"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.ccsidr.diff(other.ccsidr, "CCSIDR"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.ccsidr2.diff(other.ccsidr2, "CCSIDR2"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.ccsidr_el1.diff(other.ccsidr_el1, "CCSIDR_EL1"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.clidr.diff(other.clidr, "CLIDR"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.clidr_el1.diff(other.clidr_el1, "CLIDR_EL1"));
+        changes.extend(self.cntfrq.diff(other.cntfrq, "CNTFRQ"));
+        changes.extend(self.cntfrq_el0.diff(other.cntfrq_el0, "CNTFRQ_EL0"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.cnthctl.diff(other.cnthctl, "CNTHCTL"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.cnthctl_el2.diff(other.cnthctl_el2, "CNTHCTL_EL2"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.cnthps_ctl.diff(other.cnthps_ctl, "CNTHPS_CTL"));
+        #[cfg(feature = "el2")]
+        changes.extend(
+            self.cnthps_ctl_el2
+                .diff(other.cnthps_ctl_el2, "CNTHPS_CTL_EL2"),
+        );
+        #[cfg(feature = "el1")]
+        changes.extend(self.cnthps_cval.diff(other.cnthps_cval, "CNTHPS_CVAL"));
+        #[cfg(feature = "el2")]
+        changes.extend(
+            self.cnthps_cval_el2
+                .diff(other.cnthps_cval_el2, "CNTHPS_CVAL_EL2"),
+        );
+        #[cfg(feature = "el1")]
+        changes.extend(self.cnthps_tval.diff(other.cnthps_tval, "CNTHPS_TVAL"));
+        #[cfg(feature = "el2")]
+        changes.extend(
+            self.cnthps_tval_el2
+                .diff(other.cnthps_tval_el2, "CNTHPS_TVAL_EL2"),
+        );
+        #[cfg(feature = "el1")]
+        changes.extend(self.cnthp_ctl.diff(other.cnthp_ctl, "CNTHP_CTL"));
+        #[cfg(feature = "el2")]
+        changes.extend(
+            self.cnthp_ctl_el2
+                .diff(other.cnthp_ctl_el2, "CNTHP_CTL_EL2"),
+        );
+        #[cfg(feature = "el1")]
+        changes.extend(self.cnthp_cval.diff(other.cnthp_cval, "CNTHP_CVAL"));
+        #[cfg(feature = "el2")]
+        changes.extend(
+            self.cnthp_cval_el2
+                .diff(other.cnthp_cval_el2, "CNTHP_CVAL_EL2"),
+        );
+        #[cfg(feature = "el1")]
+        changes.extend(self.cnthp_tval.diff(other.cnthp_tval, "CNTHP_TVAL"));
+        #[cfg(feature = "el2")]
+        changes.extend(
+            self.cnthp_tval_el2
+                .diff(other.cnthp_tval_el2, "CNTHP_TVAL_EL2"),
+        );
+        #[cfg(feature = "el1")]
+        changes.extend(self.cnthvs_ctl.diff(other.cnthvs_ctl, "CNTHVS_CTL"));
+        #[cfg(feature = "el2")]
+        changes.extend(
+            self.cnthvs_ctl_el2
+                .diff(other.cnthvs_ctl_el2, "CNTHVS_CTL_EL2"),
+        );
+        #[cfg(feature = "el1")]
+        changes.extend(self.cnthvs_cval.diff(other.cnthvs_cval, "CNTHVS_CVAL"));
+        #[cfg(feature = "el2")]
+        changes.extend(
+            self.cnthvs_cval_el2
+                .diff(other.cnthvs_cval_el2, "CNTHVS_CVAL_EL2"),
+        );
+        #[cfg(feature = "el1")]
+        changes.extend(self.cnthvs_tval.diff(other.cnthvs_tval, "CNTHVS_TVAL"));
+        #[cfg(feature = "el2")]
+        changes.extend(
+            self.cnthvs_tval_el2
+                .diff(other.cnthvs_tval_el2, "CNTHVS_TVAL_EL2"),
+        );
+        #[cfg(feature = "el1")]
+        changes.extend(self.cnthv_ctl.diff(other.cnthv_ctl, "CNTHV_CTL"));
+        #[cfg(feature = "el2")]
+        changes.extend(
+            self.cnthv_ctl_el2
+                .diff(other.cnthv_ctl_el2, "CNTHV_CTL_EL2"),
+        );
+        #[cfg(feature = "el1")]
+        changes.extend(self.cnthv_cval.diff(other.cnthv_cval, "CNTHV_CVAL"));
+        #[cfg(feature = "el2")]
+        changes.extend(
+            self.cnthv_cval_el2
+                .diff(other.cnthv_cval_el2, "CNTHV_CVAL_EL2"),
+        );
+        #[cfg(feature = "el1")]
+        changes.extend(self.cnthv_tval.diff(other.cnthv_tval, "CNTHV_TVAL"));
+        #[cfg(feature = "el2")]
+        changes.extend(
+            self.cnthv_tval_el2
+                .diff(other.cnthv_tval_el2, "CNTHV_TVAL_EL2"),
+        );
+        #[cfg(feature = "el1")]
+        changes.extend(self.cntkctl.diff(other.cntkctl, "CNTKCTL"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.cntkctl_el1.diff(other.cntkctl_el1, "CNTKCTL_EL1"));
+        changes.extend(self.cntpct.diff(other.cntpct, "CNTPCT"));
+        changes.extend(self.cntpctss.diff(other.cntpctss, "CNTPCTSS"));
+        changes.extend(self.cntpctss_el0.diff(other.cntpctss_el0, "CNTPCTSS_EL0"));
+        changes.extend(self.cntpct_el0.diff(other.cntpct_el0, "CNTPCT_EL0"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.cntpoff_el2.diff(other.cntpoff_el2, "CNTPOFF_EL2"));
+        #[cfg(feature = "el1")]
+        changes.extend(
+            self.cntps_ctl_el1
+                .diff(other.cntps_ctl_el1, "CNTPS_CTL_EL1"),
+        );
+        #[cfg(feature = "el1")]
+        changes.extend(
+            self.cntps_cval_el1
+                .diff(other.cntps_cval_el1, "CNTPS_CVAL_EL1"),
+        );
+        #[cfg(feature = "el1")]
+        changes.extend(
+            self.cntps_tval_el1
+                .diff(other.cntps_tval_el1, "CNTPS_TVAL_EL1"),
+        );
+        changes.extend(self.cntp_ctl.diff(other.cntp_ctl, "CNTP_CTL"));
+        changes.extend(self.cntp_ctl_el0.diff(other.cntp_ctl_el0, "CNTP_CTL_EL0"));
+        changes.extend(self.cntp_cval.diff(other.cntp_cval, "CNTP_CVAL"));
+        changes.extend(
+            self.cntp_cval_el0
+                .diff(other.cntp_cval_el0, "CNTP_CVAL_EL0"),
+        );
+        changes.extend(self.cntp_tval.diff(other.cntp_tval, "CNTP_TVAL"));
+        changes.extend(
+            self.cntp_tval_el0
+                .diff(other.cntp_tval_el0, "CNTP_TVAL_EL0"),
+        );
+        changes.extend(self.cntvct.diff(other.cntvct, "CNTVCT"));
+        changes.extend(self.cntvctss.diff(other.cntvctss, "CNTVCTSS"));
+        changes.extend(self.cntvctss_el0.diff(other.cntvctss_el0, "CNTVCTSS_EL0"));
+        changes.extend(self.cntvct_el0.diff(other.cntvct_el0, "CNTVCT_EL0"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.cntvoff.diff(other.cntvoff, "CNTVOFF"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.cntvoff_el2.diff(other.cntvoff_el2, "CNTVOFF_EL2"));
+        changes.extend(self.cntv_ctl.diff(other.cntv_ctl, "CNTV_CTL"));
+        changes.extend(self.cntv_ctl_el0.diff(other.cntv_ctl_el0, "CNTV_CTL_EL0"));
+        changes.extend(self.cntv_cval.diff(other.cntv_cval, "CNTV_CVAL"));
+        changes.extend(
+            self.cntv_cval_el0
+                .diff(other.cntv_cval_el0, "CNTV_CVAL_EL0"),
+        );
+        changes.extend(self.cntv_tval.diff(other.cntv_tval, "CNTV_TVAL"));
+        changes.extend(
+            self.cntv_tval_el0
+                .diff(other.cntv_tval_el0, "CNTV_TVAL_EL0"),
+        );
+        #[cfg(feature = "el1")]
+        changes.extend(self.contextidr.diff(other.contextidr, "CONTEXTIDR"));
+        #[cfg(feature = "el1")]
+        changes.extend(
+            self.contextidr_el1
+                .diff(other.contextidr_el1, "CONTEXTIDR_EL1"),
+        );
+        #[cfg(feature = "el2")]
+        changes.extend(
+            self.contextidr_el2
+                .diff(other.contextidr_el2, "CONTEXTIDR_EL2"),
+        );
+        #[cfg(feature = "el1")]
+        changes.extend(self.cpacr.diff(other.cpacr, "CPACR"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.cpacr_el1.diff(other.cpacr_el1, "CPACR_EL1"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.cptr_el2.diff(other.cptr_el2, "CPTR_EL2"));
+        #[cfg(feature = "el3")]
+        changes.extend(self.cptr_el3.diff(other.cptr_el3, "CPTR_EL3"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.csselr.diff(other.csselr, "CSSELR"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.csselr_el1.diff(other.csselr_el1, "CSSELR_EL1"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.ctr.diff(other.ctr, "CTR"));
+        changes.extend(self.ctr_el0.diff(other.ctr_el0, "CTR_EL0"));
+        changes.extend(self.currentel.diff(other.currentel, "CurrentEL"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.dacr.diff(other.dacr, "DACR"));
+        changes.extend(self.daif.diff(other.daif, "DAIF"));
+        #[cfg(feature = "el1")]
+        changes.extend(
+            self.dbgauthstatus
+                .diff(other.dbgauthstatus, "DBGAUTHSTATUS"),
+        );
+        #[cfg(feature = "el1")]
+        changes.extend(self.dbgclaimclr.diff(other.dbgclaimclr, "DBGCLAIMCLR"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.dbgclaimset.diff(other.dbgclaimset, "DBGCLAIMSET"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.dbgdccint.diff(other.dbgdccint, "DBGDCCINT"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.dbgdevid.diff(other.dbgdevid, "DBGDEVID"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.dbgdevid1.diff(other.dbgdevid1, "DBGDEVID1"));
+        #[cfg(feature = "el1")]
+        if self.dbgdevid2 != other.dbgdevid2 {
+            changes.push(FieldChange {
+                register: "DBGDEVID2",
+                field: None,
+                old: u64::from(self.dbgdevid2),
+                new: u64::from(other.dbgdevid2),
+            });
+        }
+        changes.extend(self.dbgdidr.diff(other.dbgdidr, "DBGDIDR"));
+        changes.extend(self.dbgdrar.diff(other.dbgdrar, "DBGDRAR"));
+        if self.dbgdsar != other.dbgdsar {
+            changes.push(FieldChange {
+                register: "DBGDSAR",
+                field: None,
+                old: self.dbgdsar,
+                new: other.dbgdsar,
+            });
+        }
+        #[cfg(feature = "el1")]
+        changes.extend(self.dbgdscrext.diff(other.dbgdscrext, "DBGDSCRext"));
+        changes.extend(self.dbgdscrint.diff(other.dbgdscrint, "DBGDSCRint"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.dbgdtrrxext.diff(other.dbgdtrrxext, "DBGDTRRXext"));
+        changes.extend(self.dbgdtrrxint.diff(other.dbgdtrrxint, "DBGDTRRXint"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.dbgdtrtxext.diff(other.dbgdtrtxext, "DBGDTRTXext"));
+        changes.extend(self.dbgdtrtxint.diff(other.dbgdtrtxint, "DBGDTRTXint"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.dbgosdlr.diff(other.dbgosdlr, "DBGOSDLR"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.dbgoseccr.diff(other.dbgoseccr, "DBGOSECCR"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.dbgoslar.diff(other.dbgoslar, "DBGOSLAR"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.dbgoslsr.diff(other.dbgoslsr, "DBGOSLSR"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.dbgprcr.diff(other.dbgprcr, "DBGPRCR"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.dbgvcr.diff(other.dbgvcr, "DBGVCR"));
+        #[cfg(feature = "el1")]
+        if self.dbgwfar != other.dbgwfar {
+            changes.push(FieldChange {
+                register: "DBGWFAR",
+                field: None,
+                old: u64::from(self.dbgwfar),
+                new: u64::from(other.dbgwfar),
+            });
+        }
+        #[cfg(feature = "el1")]
+        changes.extend(self.dfar.diff(other.dfar, "DFAR"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.dfsr.diff(other.dfsr, "DFSR"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.disr.diff(other.disr, "DISR"));
+        #[cfg(all(feature = "el1", feature = "feat-ras"))]
+        changes.extend(self.disr_el1.diff(other.disr_el1, "DISR_EL1"));
+        changes.extend(self.dit.diff(other.dit, "DIT"));
+        changes.extend(self.dlr.diff(other.dlr, "DLR"));
+        changes.extend(self.dspsr.diff(other.dspsr, "DSPSR"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.dspsr2.diff(other.dspsr2, "DSPSR2"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.elr_el1.diff(other.elr_el1, "ELR_EL1"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.elr_el2.diff(other.elr_el2, "ELR_EL2"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.elr_hyp.diff(other.elr_hyp, "ELR_hyp"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.erridr.diff(other.erridr, "ERRIDR"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.errselr.diff(other.errselr, "ERRSELR"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.erxaddr.diff(other.erxaddr, "ERXADDR"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.erxaddr2.diff(other.erxaddr2, "ERXADDR2"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.erxctlr.diff(other.erxctlr, "ERXCTLR"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.erxctlr2.diff(other.erxctlr2, "ERXCTLR2"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.erxfr.diff(other.erxfr, "ERXFR"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.erxfr2.diff(other.erxfr2, "ERXFR2"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.erxmisc0.diff(other.erxmisc0, "ERXMISC0"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.erxmisc1.diff(other.erxmisc1, "ERXMISC1"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.erxmisc2.diff(other.erxmisc2, "ERXMISC2"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.erxmisc3.diff(other.erxmisc3, "ERXMISC3"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.erxmisc4.diff(other.erxmisc4, "ERXMISC4"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.erxmisc5.diff(other.erxmisc5, "ERXMISC5"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.erxmisc6.diff(other.erxmisc6, "ERXMISC6"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.erxmisc7.diff(other.erxmisc7, "ERXMISC7"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.erxstatus.diff(other.erxstatus, "ERXSTATUS"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.esr_el1.diff(other.esr_el1, "ESR_EL1"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.esr_el2.diff(other.esr_el2, "ESR_EL2"));
+        #[cfg(feature = "el3")]
+        changes.extend(self.esr_el3.diff(other.esr_el3, "ESR_EL3"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.far_el1.diff(other.far_el1, "FAR_EL1"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.far_el2.diff(other.far_el2, "FAR_EL2"));
+        #[cfg(feature = "el1")]
+        if self.fcseidr != other.fcseidr {
+            changes.push(FieldChange {
+                register: "FCSEIDR",
+                field: None,
+                old: u64::from(self.fcseidr),
+                new: u64::from(other.fcseidr),
+            });
+        }
+        changes.extend(self.fpcr.diff(other.fpcr, "FPCR"));
+        changes.extend(self.fpmr.diff(other.fpmr, "FPMR"));
+        changes.extend(self.fpsr.diff(other.fpsr, "FPSR"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.gcr_el1.diff(other.gcr_el1, "GCR_EL1"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.gcscr_el1.diff(other.gcscr_el1, "GCSCR_EL1"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.gcscr_el2.diff(other.gcscr_el2, "GCSCR_EL2"));
+        #[cfg(all(feature = "el3", feature = "feat-rme"))]
+        changes.extend(self.gpccr_el3.diff(other.gpccr_el3, "GPCCR_EL3"));
+        #[cfg(all(feature = "el3", feature = "feat-rme"))]
+        changes.extend(self.gptbr_el3.diff(other.gptbr_el3, "GPTBR_EL3"));
+        #[cfg(feature = "el2")]
+        if self.hacr != other.hacr {
+            changes.push(FieldChange {
+                register: "HACR",
+                field: None,
+                old: u64::from(self.hacr),
+                new: u64::from(other.hacr),
+            });
+        }
+        #[cfg(feature = "el2")]
+        if self.hacr_el2 != other.hacr_el2 {
+            changes.push(FieldChange {
+                register: "HACR_EL2",
+                field: None,
+                old: self.hacr_el2,
+                new: other.hacr_el2,
+            });
+        }
+        #[cfg(feature = "el2")]
+        if self.hactlr != other.hactlr {
+            changes.push(FieldChange {
+                register: "HACTLR",
+                field: None,
+                old: u64::from(self.hactlr),
+                new: u64::from(other.hactlr),
+            });
+        }
+        #[cfg(feature = "el2")]
+        if self.hactlr2 != other.hactlr2 {
+            changes.push(FieldChange {
+                register: "HACTLR2",
+                field: None,
+                old: u64::from(self.hactlr2),
+                new: u64::from(other.hactlr2),
+            });
+        }
+        #[cfg(feature = "el2")]
+        if self.hadfsr != other.hadfsr {
+            changes.push(FieldChange {
+                register: "HADFSR",
+                field: None,
+                old: u64::from(self.hadfsr),
+                new: u64::from(other.hadfsr),
+            });
+        }
+        #[cfg(feature = "el2")]
+        changes.extend(self.hafgrtr_el2.diff(other.hafgrtr_el2, "HAFGRTR_EL2"));
+        #[cfg(feature = "el2")]
+        if self.haifsr != other.haifsr {
+            changes.push(FieldChange {
+                register: "HAIFSR",
+                field: None,
+                old: u64::from(self.haifsr),
+                new: u64::from(other.haifsr),
+            });
+        }
+        #[cfg(feature = "el2")]
+        if self.hamair0 != other.hamair0 {
+            changes.push(FieldChange {
+                register: "HAMAIR0",
+                field: None,
+                old: u64::from(self.hamair0),
+                new: u64::from(other.hamair0),
+            });
+        }
+        #[cfg(feature = "el2")]
+        if self.hamair1 != other.hamair1 {
+            changes.push(FieldChange {
+                register: "HAMAIR1",
+                field: None,
+                old: u64::from(self.hamair1),
+                new: u64::from(other.hamair1),
+            });
+        }
+        #[cfg(feature = "el2")]
+        changes.extend(self.hcptr.diff(other.hcptr, "HCPTR"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.hcr.diff(other.hcr, "HCR"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.hcr2.diff(other.hcr2, "HCR2"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.hcrx_el2.diff(other.hcrx_el2, "HCRX_EL2"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.hcr_el2.diff(other.hcr_el2, "HCR_EL2"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.hdcr.diff(other.hdcr, "HDCR"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.hdfar.diff(other.hdfar, "HDFAR"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.hdfgrtr2_el2.diff(other.hdfgrtr2_el2, "HDFGRTR2_EL2"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.hdfgrtr_el2.diff(other.hdfgrtr_el2, "HDFGRTR_EL2"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.hdfgwtr2_el2.diff(other.hdfgwtr2_el2, "HDFGWTR2_EL2"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.hdfgwtr_el2.diff(other.hdfgwtr_el2, "HDFGWTR_EL2"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.hfgitr2_el2.diff(other.hfgitr2_el2, "HFGITR2_EL2"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.hfgitr_el2.diff(other.hfgitr_el2, "HFGITR_EL2"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.hfgrtr2_el2.diff(other.hfgrtr2_el2, "HFGRTR2_EL2"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.hfgrtr_el2.diff(other.hfgrtr_el2, "HFGRTR_EL2"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.hfgwtr2_el2.diff(other.hfgwtr2_el2, "HFGWTR2_EL2"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.hfgwtr_el2.diff(other.hfgwtr_el2, "HFGWTR_EL2"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.hifar.diff(other.hifar, "HIFAR"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.hmair0.diff(other.hmair0, "HMAIR0"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.hmair1.diff(other.hmair1, "HMAIR1"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.hpfar.diff(other.hpfar, "HPFAR"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.hpfar_el2.diff(other.hpfar_el2, "HPFAR_EL2"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.hrmr.diff(other.hrmr, "HRMR"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.hsctlr.diff(other.hsctlr, "HSCTLR"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.hsr.diff(other.hsr, "HSR"));
+        #[cfg(feature = "el2")]
+        if self.hstr != other.hstr {
+            changes.push(FieldChange {
+                register: "HSTR",
+                field: None,
+                old: u64::from(self.hstr),
+                new: u64::from(other.hstr),
+            });
+        }
+        #[cfg(feature = "el2")]
+        if self.hstr_el2 != other.hstr_el2 {
+            changes.push(FieldChange {
+                register: "HSTR_EL2",
+                field: None,
+                old: self.hstr_el2,
+                new: other.hstr_el2,
+            });
+        }
+        #[cfg(feature = "el2")]
+        changes.extend(self.htcr.diff(other.htcr, "HTCR"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.htpidr.diff(other.htpidr, "HTPIDR"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.htrfcr.diff(other.htrfcr, "HTRFCR"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.httbr.diff(other.httbr, "HTTBR"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.hvbar.diff(other.hvbar, "HVBAR"));
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        if self.icc_ap0r0_el1 != other.icc_ap0r0_el1 {
+            changes.push(FieldChange {
+                register: "ICC_AP0R0_EL1",
+                field: None,
+                old: self.icc_ap0r0_el1,
+                new: other.icc_ap0r0_el1,
+            });
+        }
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        if self.icc_ap0r1_el1 != other.icc_ap0r1_el1 {
+            changes.push(FieldChange {
+                register: "ICC_AP0R1_EL1",
+                field: None,
+                old: self.icc_ap0r1_el1,
+                new: other.icc_ap0r1_el1,
+            });
+        }
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        if self.icc_ap0r2_el1 != other.icc_ap0r2_el1 {
+            changes.push(FieldChange {
+                register: "ICC_AP0R2_EL1",
+                field: None,
+                old: self.icc_ap0r2_el1,
+                new: other.icc_ap0r2_el1,
+            });
+        }
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        if self.icc_ap0r3_el1 != other.icc_ap0r3_el1 {
+            changes.push(FieldChange {
+                register: "ICC_AP0R3_EL1",
+                field: None,
+                old: self.icc_ap0r3_el1,
+                new: other.icc_ap0r3_el1,
+            });
+        }
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        changes.extend(
+            self.icc_ap1r0_el1
+                .diff(other.icc_ap1r0_el1, "ICC_AP1R0_EL1"),
+        );
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        if self.icc_ap1r1_el1 != other.icc_ap1r1_el1 {
+            changes.push(FieldChange {
+                register: "ICC_AP1R1_EL1",
+                field: None,
+                old: self.icc_ap1r1_el1,
+                new: other.icc_ap1r1_el1,
+            });
+        }
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        if self.icc_ap1r2_el1 != other.icc_ap1r2_el1 {
+            changes.push(FieldChange {
+                register: "ICC_AP1R2_EL1",
+                field: None,
+                old: self.icc_ap1r2_el1,
+                new: other.icc_ap1r2_el1,
+            });
+        }
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        if self.icc_ap1r3_el1 != other.icc_ap1r3_el1 {
+            changes.push(FieldChange {
+                register: "ICC_AP1R3_EL1",
+                field: None,
+                old: self.icc_ap1r3_el1,
+                new: other.icc_ap1r3_el1,
+            });
+        }
+        #[cfg(feature = "el1")]
+        changes.extend(self.icc_asgi1r.diff(other.icc_asgi1r, "ICC_ASGI1R"));
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        changes.extend(
+            self.icc_asgi1r_el1
+                .diff(other.icc_asgi1r_el1, "ICC_ASGI1R_EL1"),
+        );
+        #[cfg(feature = "el1")]
+        changes.extend(self.icc_bpr0.diff(other.icc_bpr0, "ICC_BPR0"));
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        changes.extend(self.icc_bpr0_el1.diff(other.icc_bpr0_el1, "ICC_BPR0_EL1"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.icc_bpr1.diff(other.icc_bpr1, "ICC_BPR1"));
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        changes.extend(self.icc_bpr1_el1.diff(other.icc_bpr1_el1, "ICC_BPR1_EL1"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.icc_ctlr.diff(other.icc_ctlr, "ICC_CTLR"));
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        changes.extend(self.icc_ctlr_el1.diff(other.icc_ctlr_el1, "ICC_CTLR_EL1"));
+        #[cfg(all(feature = "el3", feature = "feat-gic"))]
+        changes.extend(self.icc_ctlr_el3.diff(other.icc_ctlr_el3, "ICC_CTLR_EL3"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.icc_dir.diff(other.icc_dir, "ICC_DIR"));
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        changes.extend(self.icc_dir_el1.diff(other.icc_dir_el1, "ICC_DIR_EL1"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.icc_eoir0.diff(other.icc_eoir0, "ICC_EOIR0"));
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        changes.extend(
+            self.icc_eoir0_el1
+                .diff(other.icc_eoir0_el1, "ICC_EOIR0_EL1"),
+        );
+        #[cfg(feature = "el1")]
+        changes.extend(self.icc_eoir1.diff(other.icc_eoir1, "ICC_EOIR1"));
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        changes.extend(
+            self.icc_eoir1_el1
+                .diff(other.icc_eoir1_el1, "ICC_EOIR1_EL1"),
+        );
+        #[cfg(feature = "el1")]
+        changes.extend(self.icc_hppir0.diff(other.icc_hppir0, "ICC_HPPIR0"));
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        changes.extend(
+            self.icc_hppir0_el1
+                .diff(other.icc_hppir0_el1, "ICC_HPPIR0_EL1"),
+        );
+        #[cfg(feature = "el1")]
+        changes.extend(self.icc_hppir1.diff(other.icc_hppir1, "ICC_HPPIR1"));
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        changes.extend(
+            self.icc_hppir1_el1
+                .diff(other.icc_hppir1_el1, "ICC_HPPIR1_EL1"),
+        );
+        #[cfg(feature = "el2")]
+        changes.extend(self.icc_hsre.diff(other.icc_hsre, "ICC_HSRE"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.icc_iar0.diff(other.icc_iar0, "ICC_IAR0"));
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        changes.extend(self.icc_iar0_el1.diff(other.icc_iar0_el1, "ICC_IAR0_EL1"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.icc_iar1.diff(other.icc_iar1, "ICC_IAR1"));
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        changes.extend(self.icc_iar1_el1.diff(other.icc_iar1_el1, "ICC_IAR1_EL1"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.icc_igrpen0.diff(other.icc_igrpen0, "ICC_IGRPEN0"));
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        changes.extend(
+            self.icc_igrpen0_el1
+                .diff(other.icc_igrpen0_el1, "ICC_IGRPEN0_EL1"),
+        );
+        #[cfg(feature = "el1")]
+        changes.extend(self.icc_igrpen1.diff(other.icc_igrpen1, "ICC_IGRPEN1"));
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        changes.extend(
+            self.icc_igrpen1_el1
+                .diff(other.icc_igrpen1_el1, "ICC_IGRPEN1_EL1"),
+        );
+        #[cfg(all(feature = "el3", feature = "feat-gic"))]
+        changes.extend(
+            self.icc_igrpen1_el3
+                .diff(other.icc_igrpen1_el3, "ICC_IGRPEN1_EL3"),
+        );
+        #[cfg(feature = "el3")]
+        changes.extend(self.icc_mctlr.diff(other.icc_mctlr, "ICC_MCTLR"));
+        #[cfg(feature = "el3")]
+        changes.extend(self.icc_mgrpen1.diff(other.icc_mgrpen1, "ICC_MGRPEN1"));
+        #[cfg(feature = "el3")]
+        changes.extend(self.icc_msre.diff(other.icc_msre, "ICC_MSRE"));
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        changes.extend(
+            self.icc_nmiar1_el1
+                .diff(other.icc_nmiar1_el1, "ICC_NMIAR1_EL1"),
+        );
+        #[cfg(feature = "el1")]
+        changes.extend(self.icc_pmr.diff(other.icc_pmr, "ICC_PMR"));
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        changes.extend(self.icc_pmr_el1.diff(other.icc_pmr_el1, "ICC_PMR_EL1"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.icc_rpr.diff(other.icc_rpr, "ICC_RPR"));
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        changes.extend(self.icc_rpr_el1.diff(other.icc_rpr_el1, "ICC_RPR_EL1"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.icc_sgi0r.diff(other.icc_sgi0r, "ICC_SGI0R"));
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        changes.extend(
+            self.icc_sgi0r_el1
+                .diff(other.icc_sgi0r_el1, "ICC_SGI0R_EL1"),
+        );
+        #[cfg(feature = "el1")]
+        changes.extend(self.icc_sgi1r.diff(other.icc_sgi1r, "ICC_SGI1R"));
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        changes.extend(
+            self.icc_sgi1r_el1
+                .diff(other.icc_sgi1r_el1, "ICC_SGI1R_EL1"),
+        );
+        #[cfg(feature = "el1")]
+        changes.extend(self.icc_sre.diff(other.icc_sre, "ICC_SRE"));
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        changes.extend(self.icc_sre_el1.diff(other.icc_sre_el1, "ICC_SRE_EL1"));
+        #[cfg(all(feature = "el2", feature = "feat-gic"))]
+        changes.extend(self.icc_sre_el2.diff(other.icc_sre_el2, "ICC_SRE_EL2"));
+        #[cfg(all(feature = "el3", feature = "feat-gic"))]
+        changes.extend(self.icc_sre_el3.diff(other.icc_sre_el3, "ICC_SRE_EL3"));
+        #[cfg(all(feature = "el2", feature = "feat-gic"))]
+        changes.extend(self.ich_hcr_el2.diff(other.ich_hcr_el2, "ICH_HCR_EL2"));
+        #[cfg(all(feature = "el2", feature = "feat-gic"))]
+        changes.extend(self.ich_vmcr_el2.diff(other.ich_vmcr_el2, "ICH_VMCR_EL2"));
+        #[cfg(feature = "el1")]
+        changes.extend(
+            self.id_aa64dfr0_el1
+                .diff(other.id_aa64dfr0_el1, "ID_AA64DFR0_EL1"),
+        );
+        #[cfg(feature = "el1")]
+        changes.extend(
+            self.id_aa64dfr1_el1
+                .diff(other.id_aa64dfr1_el1, "ID_AA64DFR1_EL1"),
+        );
+        #[cfg(feature = "el1")]
+        changes.extend(
+            self.id_aa64isar1_el1
+                .diff(other.id_aa64isar1_el1, "ID_AA64ISAR1_EL1"),
+        );
+        #[cfg(feature = "el1")]
+        changes.extend(
+            self.id_aa64isar2_el1
+                .diff(other.id_aa64isar2_el1, "ID_AA64ISAR2_EL1"),
+        );
+        #[cfg(feature = "el1")]
+        changes.extend(
+            self.id_aa64mmfr0_el1
+                .diff(other.id_aa64mmfr0_el1, "ID_AA64MMFR0_EL1"),
+        );
+        #[cfg(feature = "el1")]
+        changes.extend(
+            self.id_aa64mmfr1_el1
+                .diff(other.id_aa64mmfr1_el1, "ID_AA64MMFR1_EL1"),
+        );
+        #[cfg(feature = "el1")]
+        changes.extend(
+            self.id_aa64mmfr2_el1
+                .diff(other.id_aa64mmfr2_el1, "ID_AA64MMFR2_EL1"),
+        );
+        #[cfg(feature = "el1")]
+        changes.extend(
+            self.id_aa64mmfr3_el1
+                .diff(other.id_aa64mmfr3_el1, "ID_AA64MMFR3_EL1"),
+        );
+        #[cfg(feature = "el1")]
+        changes.extend(
+            self.id_aa64mmfr4_el1
+                .diff(other.id_aa64mmfr4_el1, "ID_AA64MMFR4_EL1"),
+        );
+        #[cfg(feature = "el1")]
+        changes.extend(
+            self.id_aa64pfr0_el1
+                .diff(other.id_aa64pfr0_el1, "ID_AA64PFR0_EL1"),
+        );
+        #[cfg(feature = "el1")]
+        changes.extend(
+            self.id_aa64pfr1_el1
+                .diff(other.id_aa64pfr1_el1, "ID_AA64PFR1_EL1"),
+        );
+        #[cfg(feature = "el1")]
+        changes.extend(
+            self.id_aa64pfr2_el1
+                .diff(other.id_aa64pfr2_el1, "ID_AA64PFR2_EL1"),
+        );
+        #[cfg(feature = "el1")]
+        changes.extend(
+            self.id_aa64smfr0_el1
+                .diff(other.id_aa64smfr0_el1, "ID_AA64SMFR0_EL1"),
+        );
+        #[cfg(feature = "el1")]
+        if self.id_afr0 != other.id_afr0 {
+            changes.push(FieldChange {
+                register: "ID_AFR0",
+                field: None,
+                old: u64::from(self.id_afr0),
+                new: u64::from(other.id_afr0),
+            });
+        }
+        #[cfg(feature = "el1")]
+        changes.extend(self.id_dfr0.diff(other.id_dfr0, "ID_DFR0"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.id_dfr1.diff(other.id_dfr1, "ID_DFR1"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.id_isar0.diff(other.id_isar0, "ID_ISAR0"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.id_isar1.diff(other.id_isar1, "ID_ISAR1"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.id_isar2.diff(other.id_isar2, "ID_ISAR2"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.id_isar3.diff(other.id_isar3, "ID_ISAR3"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.id_isar4.diff(other.id_isar4, "ID_ISAR4"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.id_isar5.diff(other.id_isar5, "ID_ISAR5"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.id_isar6.diff(other.id_isar6, "ID_ISAR6"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.id_mmfr0.diff(other.id_mmfr0, "ID_MMFR0"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.id_mmfr1.diff(other.id_mmfr1, "ID_MMFR1"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.id_mmfr2.diff(other.id_mmfr2, "ID_MMFR2"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.id_mmfr3.diff(other.id_mmfr3, "ID_MMFR3"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.id_mmfr4.diff(other.id_mmfr4, "ID_MMFR4"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.id_mmfr5.diff(other.id_mmfr5, "ID_MMFR5"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.id_pfr0.diff(other.id_pfr0, "ID_PFR0"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.id_pfr1.diff(other.id_pfr1, "ID_PFR1"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.id_pfr2.diff(other.id_pfr2, "ID_PFR2"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.ifar.diff(other.ifar, "IFAR"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.ifsr.diff(other.ifsr, "IFSR"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.isr.diff(other.isr, "ISR"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.isr_el1.diff(other.isr_el1, "ISR_EL1"));
+        #[cfg(feature = "el1")]
+        if self.jidr != other.jidr {
+            changes.push(FieldChange {
+                register: "JIDR",
+                field: None,
+                old: u64::from(self.jidr),
+                new: u64::from(other.jidr),
+            });
+        }
+        #[cfg(feature = "el1")]
+        if self.jmcr != other.jmcr {
+            changes.push(FieldChange {
+                register: "JMCR",
+                field: None,
+                old: u64::from(self.jmcr),
+                new: u64::from(other.jmcr),
+            });
+        }
+        #[cfg(feature = "el1")]
+        if self.joscr != other.joscr {
+            changes.push(FieldChange {
+                register: "JOSCR",
+                field: None,
+                old: u64::from(self.joscr),
+                new: u64::from(other.joscr),
+            });
+        }
+        #[cfg(feature = "el1")]
+        changes.extend(self.mair0.diff(other.mair0, "MAIR0"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.mair1.diff(other.mair1, "MAIR1"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.mair_el1.diff(other.mair_el1, "MAIR_EL1"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.mair_el2.diff(other.mair_el2, "MAIR_EL2"));
+        #[cfg(feature = "el3")]
+        changes.extend(self.mair_el3.diff(other.mair_el3, "MAIR_EL3"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.mdccint_el1.diff(other.mdccint_el1, "MDCCINT_EL1"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.mdcr_el2.diff(other.mdcr_el2, "MDCR_EL2"));
+        #[cfg(feature = "el3")]
+        changes.extend(self.mdcr_el3.diff(other.mdcr_el3, "MDCR_EL3"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.mdscr_el1.diff(other.mdscr_el1, "MDSCR_EL1"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.midr.diff(other.midr, "MIDR"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.midr_el1.diff(other.midr_el1, "MIDR_EL1"));
+        #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+        changes.extend(self.mpam2_el2.diff(other.mpam2_el2, "MPAM2_EL2"));
+        #[cfg(all(feature = "el3", feature = "feat-mpam"))]
+        changes.extend(self.mpam3_el3.diff(other.mpam3_el3, "MPAM3_EL3"));
+        #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+        changes.extend(self.mpamhcr_el2.diff(other.mpamhcr_el2, "MPAMHCR_EL2"));
+        #[cfg(all(feature = "el1", feature = "feat-mpam"))]
+        changes.extend(self.mpamidr_el1.diff(other.mpamidr_el1, "MPAMIDR_EL1"));
+        #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+        changes.extend(self.mpamvpm0_el2.diff(other.mpamvpm0_el2, "MPAMVPM0_EL2"));
+        #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+        changes.extend(self.mpamvpm1_el2.diff(other.mpamvpm1_el2, "MPAMVPM1_EL2"));
+        #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+        changes.extend(self.mpamvpm2_el2.diff(other.mpamvpm2_el2, "MPAMVPM2_EL2"));
+        #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+        changes.extend(self.mpamvpm3_el2.diff(other.mpamvpm3_el2, "MPAMVPM3_EL2"));
+        #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+        changes.extend(self.mpamvpm4_el2.diff(other.mpamvpm4_el2, "MPAMVPM4_EL2"));
+        #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+        changes.extend(self.mpamvpm5_el2.diff(other.mpamvpm5_el2, "MPAMVPM5_EL2"));
+        #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+        changes.extend(self.mpamvpm6_el2.diff(other.mpamvpm6_el2, "MPAMVPM6_EL2"));
+        #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+        changes.extend(self.mpamvpm7_el2.diff(other.mpamvpm7_el2, "MPAMVPM7_EL2"));
+        #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+        changes.extend(self.mpamvpmv_el2.diff(other.mpamvpmv_el2, "MPAMVPMV_EL2"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.mpidr.diff(other.mpidr, "MPIDR"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.mpidr_el1.diff(other.mpidr_el1, "MPIDR_EL1"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.mvbar.diff(other.mvbar, "MVBAR"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.nmrr.diff(other.nmrr, "NMRR"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.nsacr.diff(other.nsacr, "NSACR"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.par.diff(other.par, "PAR"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.par_el1.diff(other.par_el1, "PAR_EL1"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.pfar_el1.diff(other.pfar_el1, "PFAR_EL1"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.pfar_el2.diff(other.pfar_el2, "PFAR_EL2"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.pire0_el1.diff(other.pire0_el1, "PIRE0_EL1"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.pire0_el2.diff(other.pire0_el2, "PIRE0_EL2"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.pir_el1.diff(other.pir_el1, "PIR_EL1"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.pir_el2.diff(other.pir_el2, "PIR_EL2"));
+        #[cfg(feature = "el3")]
+        changes.extend(self.pir_el3.diff(other.pir_el3, "PIR_EL3"));
+        changes.extend(self.pmccfiltr.diff(other.pmccfiltr, "PMCCFILTR"));
+        changes.extend(self.pmccntr.diff(other.pmccntr, "PMCCNTR"));
+        changes.extend(self.pmceid0.diff(other.pmceid0, "PMCEID0"));
+        changes.extend(self.pmceid1.diff(other.pmceid1, "PMCEID1"));
+        changes.extend(self.pmceid2.diff(other.pmceid2, "PMCEID2"));
+        changes.extend(self.pmceid3.diff(other.pmceid3, "PMCEID3"));
+        changes.extend(self.pmcntenclr.diff(other.pmcntenclr, "PMCNTENCLR"));
+        changes.extend(self.pmcntenset.diff(other.pmcntenset, "PMCNTENSET"));
+        changes.extend(self.pmcr.diff(other.pmcr, "PMCR"));
+        #[cfg(feature = "feat-pmu")]
+        changes.extend(self.pmcr_el0.diff(other.pmcr_el0, "PMCR_EL0"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.pmintenclr.diff(other.pmintenclr, "PMINTENCLR"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.pmintenset.diff(other.pmintenset, "PMINTENSET"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.pmmir.diff(other.pmmir, "PMMIR"));
+        changes.extend(self.pmovsr.diff(other.pmovsr, "PMOVSR"));
+        changes.extend(self.pmovsset.diff(other.pmovsset, "PMOVSSET"));
+        changes.extend(self.pmselr.diff(other.pmselr, "PMSELR"));
+        changes.extend(self.pmswinc.diff(other.pmswinc, "PMSWINC"));
+        changes.extend(self.pmuserenr.diff(other.pmuserenr, "PMUSERENR"));
+        changes.extend(self.pmxevtyper.diff(other.pmxevtyper, "PMXEVTYPER"));
+        changes.extend(self.por_el0.diff(other.por_el0, "POR_EL0"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.por_el1.diff(other.por_el1, "POR_EL1"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.por_el2.diff(other.por_el2, "POR_EL2"));
+        #[cfg(feature = "el3")]
+        changes.extend(self.por_el3.diff(other.por_el3, "POR_EL3"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.prrr.diff(other.prrr, "PRRR"));
+        #[cfg(feature = "el1")]
+        if self.revidr != other.revidr {
+            changes.push(FieldChange {
+                register: "REVIDR",
+                field: None,
+                old: u64::from(self.revidr),
+                new: u64::from(other.revidr),
+            });
+        }
+        #[cfg(feature = "el1")]
+        changes.extend(self.rgsr_el1.diff(other.rgsr_el1, "RGSR_EL1"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.rmr.diff(other.rmr, "RMR"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.rvbar.diff(other.rvbar, "RVBAR"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.s2pir_el2.diff(other.s2pir_el2, "S2PIR_EL2"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.s2por_el1.diff(other.s2por_el1, "S2POR_EL1"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.scr.diff(other.scr, "SCR"));
+        #[cfg(feature = "el3")]
+        changes.extend(self.scr_el3.diff(other.scr_el3, "SCR_EL3"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.sctlr.diff(other.sctlr, "SCTLR"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.sctlr2_el1.diff(other.sctlr2_el1, "SCTLR2_EL1"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.sctlr2_el2.diff(other.sctlr2_el2, "SCTLR2_EL2"));
+        #[cfg(feature = "el3")]
+        changes.extend(self.sctlr2_el3.diff(other.sctlr2_el3, "SCTLR2_EL3"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.sctlr_el1.diff(other.sctlr_el1, "SCTLR_EL1"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.sctlr_el2.diff(other.sctlr_el2, "SCTLR_EL2"));
+        #[cfg(feature = "el3")]
+        changes.extend(self.sctlr_el3.diff(other.sctlr_el3, "SCTLR_EL3"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.sdcr.diff(other.sdcr, "SDCR"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.sder.diff(other.sder, "SDER"));
+        #[cfg(all(feature = "el3", feature = "feat-sve"))]
+        changes.extend(self.smcr_el3.diff(other.smcr_el3, "SMCR_EL3"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.spsr_el1.diff(other.spsr_el1, "SPSR_EL1"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.spsr_el2.diff(other.spsr_el2, "SPSR_EL2"));
+        #[cfg(feature = "el3")]
+        changes.extend(self.spsr_el3.diff(other.spsr_el3, "SPSR_EL3"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.sp_el1.diff(other.sp_el1, "SP_EL1"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.sp_el2.diff(other.sp_el2, "SP_EL2"));
+        #[cfg(feature = "feat-sve")]
+        changes.extend(self.svcr.diff(other.svcr, "SVCR"));
+        #[cfg(feature = "el1")]
+        if self.tcmtr != other.tcmtr {
+            changes.push(FieldChange {
+                register: "TCMTR",
+                field: None,
+                old: u64::from(self.tcmtr),
+                new: u64::from(other.tcmtr),
+            });
+        }
+        #[cfg(feature = "el1")]
+        changes.extend(self.tcr2_el1.diff(other.tcr2_el1, "TCR2_EL1"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.tcr2_el2.diff(other.tcr2_el2, "TCR2_EL2"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.tcr_el1.diff(other.tcr_el1, "TCR_EL1"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.tcr_el2.diff(other.tcr_el2, "TCR_EL2"));
+        #[cfg(feature = "el3")]
+        changes.extend(self.tcr_el3.diff(other.tcr_el3, "TCR_EL3"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.tfsre0_el1.diff(other.tfsre0_el1, "TFSRE0_EL1"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.tfsr_el1.diff(other.tfsr_el1, "TFSR_EL1"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.tfsr_el2.diff(other.tfsr_el2, "TFSR_EL2"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.tlbtr.diff(other.tlbtr, "TLBTR"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.tpidrprw.diff(other.tpidrprw, "TPIDRPRW"));
+        changes.extend(self.tpidrro_el0.diff(other.tpidrro_el0, "TPIDRRO_EL0"));
+        changes.extend(self.tpidruro.diff(other.tpidruro, "TPIDRURO"));
+        changes.extend(self.tpidrurw.diff(other.tpidrurw, "TPIDRURW"));
+        changes.extend(self.tpidr_el0.diff(other.tpidr_el0, "TPIDR_EL0"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.tpidr_el1.diff(other.tpidr_el1, "TPIDR_EL1"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.tpidr_el2.diff(other.tpidr_el2, "TPIDR_EL2"));
+        #[cfg(feature = "el3")]
+        changes.extend(self.tpidr_el3.diff(other.tpidr_el3, "TPIDR_EL3"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.trfcr.diff(other.trfcr, "TRFCR"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.ttbcr.diff(other.ttbcr, "TTBCR"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.ttbcr2.diff(other.ttbcr2, "TTBCR2"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.ttbr0.diff(other.ttbr0, "TTBR0"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.ttbr0_el1.diff(other.ttbr0_el1, "TTBR0_EL1"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.ttbr0_el2.diff(other.ttbr0_el2, "TTBR0_EL2"));
+        #[cfg(feature = "el3")]
+        changes.extend(self.ttbr0_el3.diff(other.ttbr0_el3, "TTBR0_EL3"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.ttbr1.diff(other.ttbr1, "TTBR1"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.ttbr1_el1.diff(other.ttbr1_el1, "TTBR1_EL1"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.ttbr1_el2.diff(other.ttbr1_el2, "TTBR1_EL2"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.vbar.diff(other.vbar, "VBAR"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.vbar_el1.diff(other.vbar_el1, "VBAR_EL1"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.vbar_el2.diff(other.vbar_el2, "VBAR_EL2"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.vdfsr.diff(other.vdfsr, "VDFSR"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.vdisr.diff(other.vdisr, "VDISR"));
+        #[cfg(all(feature = "el2", feature = "feat-ras"))]
+        changes.extend(self.vdisr_el2.diff(other.vdisr_el2, "VDISR_EL2"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.vmpidr.diff(other.vmpidr, "VMPIDR"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.vmpidr_el2.diff(other.vmpidr_el2, "VMPIDR_EL2"));
+        #[cfg(feature = "el1")]
+        changes.extend(self.vpidr.diff(other.vpidr, "VPIDR"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.vpidr_el2.diff(other.vpidr_el2, "VPIDR_EL2"));
+        #[cfg(all(feature = "el2", feature = "feat-ras"))]
+        changes.extend(self.vsesr_el2.diff(other.vsesr_el2, "VSESR_EL2"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.vtcr.diff(other.vtcr, "VTCR"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.vtcr_el2.diff(other.vtcr_el2, "VTCR_EL2"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.vttbr.diff(other.vttbr, "VTTBR"));
+        #[cfg(feature = "el2")]
+        changes.extend(self.vttbr_el2.diff(other.vttbr_el2, "VTTBR_EL2"));
+        #[cfg(all(feature = "el3", feature = "feat-sve"))]
+        changes.extend(self.zcr_el3.diff(other.zcr_el3, "ZCR_EL3"));
+        changes
+    }
+}
+
+/// Returns the lowest exception level at which the fake register with the given name is accessible.
+pub(crate) fn register_exception_level(name: &str) -> Option<ExceptionLevel> {
+    Some(match name {
+        #[cfg(feature = "el1")]
+        "actlr" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "actlr2" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "actlr_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el2")]
+        "actlr_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el1")]
+        "adfsr" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "afsr0_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el2")]
+        "afsr0_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el1")]
+        "afsr1_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el2")]
+        "afsr1_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el1")]
+        "aidr" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "aifsr" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "amair0" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "amair1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "amair_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el2")]
+        "amair_el2" => ExceptionLevel::El2,
+        "amcfgr" => ExceptionLevel::El0,
+        #[cfg(feature = "feat-amu")]
+        "amcfgr_el0" => ExceptionLevel::El0,
+        "amcgcr" => ExceptionLevel::El0,
+        #[cfg(feature = "feat-amu")]
+        "amcgcr_el0" => ExceptionLevel::El0,
+        "amcntenclr0" => ExceptionLevel::El0,
+        #[cfg(feature = "feat-amu")]
+        "amcntenclr0_el0" => ExceptionLevel::El0,
+        "amcntenclr1" => ExceptionLevel::El0,
+        #[cfg(feature = "feat-amu")]
+        "amcntenclr1_el0" => ExceptionLevel::El0,
+        "amcntenset0" => ExceptionLevel::El0,
+        #[cfg(feature = "feat-amu")]
+        "amcntenset0_el0" => ExceptionLevel::El0,
+        "amcntenset1" => ExceptionLevel::El0,
+        #[cfg(feature = "feat-amu")]
+        "amcntenset1_el0" => ExceptionLevel::El0,
+        "amcr" => ExceptionLevel::El0,
+        #[cfg(feature = "feat-amu")]
+        "amcr_el0" => ExceptionLevel::El0,
+        "amevcntr00" => ExceptionLevel::El0,
+        #[cfg(feature = "feat-amu")]
+        "amevcntr00_el0" => ExceptionLevel::El0,
+        "amevcntr01" => ExceptionLevel::El0,
+        #[cfg(feature = "feat-amu")]
+        "amevcntr01_el0" => ExceptionLevel::El0,
+        "amevcntr02" => ExceptionLevel::El0,
+        #[cfg(feature = "feat-amu")]
+        "amevcntr02_el0" => ExceptionLevel::El0,
+        "amevcntr03" => ExceptionLevel::El0,
+        #[cfg(feature = "feat-amu")]
+        "amevcntr03_el0" => ExceptionLevel::El0,
+        #[cfg(feature = "feat-amu")]
+        "amevcntr10_el0" => ExceptionLevel::El0,
+        #[cfg(feature = "feat-amu")]
+        "amevcntr110_el0" => ExceptionLevel::El0,
+        #[cfg(feature = "feat-amu")]
+        "amevcntr111_el0" => ExceptionLevel::El0,
+        #[cfg(feature = "feat-amu")]
+        "amevcntr112_el0" => ExceptionLevel::El0,
+        #[cfg(feature = "feat-amu")]
+        "amevcntr113_el0" => ExceptionLevel::El0,
+        #[cfg(feature = "feat-amu")]
+        "amevcntr114_el0" => ExceptionLevel::El0,
+        #[cfg(feature = "feat-amu")]
+        "amevcntr115_el0" => ExceptionLevel::El0,
+        #[cfg(feature = "feat-amu")]
+        "amevcntr11_el0" => ExceptionLevel::El0,
+        #[cfg(feature = "feat-amu")]
+        "amevcntr12_el0" => ExceptionLevel::El0,
+        #[cfg(feature = "feat-amu")]
+        "amevcntr13_el0" => ExceptionLevel::El0,
+        #[cfg(feature = "feat-amu")]
+        "amevcntr14_el0" => ExceptionLevel::El0,
+        #[cfg(feature = "feat-amu")]
+        "amevcntr15_el0" => ExceptionLevel::El0,
+        #[cfg(feature = "feat-amu")]
+        "amevcntr16_el0" => ExceptionLevel::El0,
+        #[cfg(feature = "feat-amu")]
+        "amevcntr17_el0" => ExceptionLevel::El0,
+        #[cfg(feature = "feat-amu")]
+        "amevcntr18_el0" => ExceptionLevel::El0,
+        #[cfg(feature = "feat-amu")]
+        "amevcntr19_el0" => ExceptionLevel::El0,
+        "amevtyper00" => ExceptionLevel::El0,
+        #[cfg(feature = "feat-amu")]
+        "amevtyper00_el0" => ExceptionLevel::El0,
+        "amevtyper01" => ExceptionLevel::El0,
+        #[cfg(feature = "feat-amu")]
+        "amevtyper01_el0" => ExceptionLevel::El0,
+        "amevtyper02" => ExceptionLevel::El0,
+        #[cfg(feature = "feat-amu")]
+        "amevtyper02_el0" => ExceptionLevel::El0,
+        "amevtyper03" => ExceptionLevel::El0,
+        #[cfg(feature = "feat-amu")]
+        "amevtyper03_el0" => ExceptionLevel::El0,
+        "amevtyper10" => ExceptionLevel::El0,
+        "amevtyper11" => ExceptionLevel::El0,
+        "amevtyper110" => ExceptionLevel::El0,
+        "amevtyper111" => ExceptionLevel::El0,
+        "amevtyper112" => ExceptionLevel::El0,
+        "amevtyper113" => ExceptionLevel::El0,
+        "amevtyper114" => ExceptionLevel::El0,
+        "amevtyper115" => ExceptionLevel::El0,
+        "amevtyper12" => ExceptionLevel::El0,
+        "amevtyper13" => ExceptionLevel::El0,
+        "amevtyper14" => ExceptionLevel::El0,
+        "amevtyper15" => ExceptionLevel::El0,
+        "amevtyper16" => ExceptionLevel::El0,
+        "amevtyper17" => ExceptionLevel::El0,
+        "amevtyper18" => ExceptionLevel::El0,
+        "amevtyper19" => ExceptionLevel::El0,
+        "amuserenr" => ExceptionLevel::El0,
+        #[cfg(feature = "feat-amu")]
+        "amuserenr_el0" => ExceptionLevel::El0,
+        #[cfg(feature = "el1")]
+        "apiakeyhi_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "apiakeylo_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el2")]
+        "brbcr_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el1")]
+        "ccsidr" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "ccsidr2" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "ccsidr_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "clidr" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "clidr_el1" => ExceptionLevel::El1,
+        "cntfrq" => ExceptionLevel::El0,
+        "cntfrq_el0" => ExceptionLevel::El0,
+        #[cfg(feature = "el2")]
+        "cnthctl" => ExceptionLevel::El2,
+        #[cfg(feature = "el2")]
+        "cnthctl_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el1")]
+        "cnthps_ctl" => ExceptionLevel::El1,
+        #[cfg(feature = "el2")]
+        "cnthps_ctl_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el1")]
+        "cnthps_cval" => ExceptionLevel::El1,
+        #[cfg(feature = "el2")]
+        "cnthps_cval_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el1")]
+        "cnthps_tval" => ExceptionLevel::El1,
+        #[cfg(feature = "el2")]
+        "cnthps_tval_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el1")]
+        "cnthp_ctl" => ExceptionLevel::El1,
+        #[cfg(feature = "el2")]
+        "cnthp_ctl_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el1")]
+        "cnthp_cval" => ExceptionLevel::El1,
+        #[cfg(feature = "el2")]
+        "cnthp_cval_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el1")]
+        "cnthp_tval" => ExceptionLevel::El1,
+        #[cfg(feature = "el2")]
+        "cnthp_tval_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el1")]
+        "cnthvs_ctl" => ExceptionLevel::El1,
+        #[cfg(feature = "el2")]
+        "cnthvs_ctl_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el1")]
+        "cnthvs_cval" => ExceptionLevel::El1,
+        #[cfg(feature = "el2")]
+        "cnthvs_cval_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el1")]
+        "cnthvs_tval" => ExceptionLevel::El1,
+        #[cfg(feature = "el2")]
+        "cnthvs_tval_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el1")]
+        "cnthv_ctl" => ExceptionLevel::El1,
+        #[cfg(feature = "el2")]
+        "cnthv_ctl_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el1")]
+        "cnthv_cval" => ExceptionLevel::El1,
+        #[cfg(feature = "el2")]
+        "cnthv_cval_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el1")]
+        "cnthv_tval" => ExceptionLevel::El1,
+        #[cfg(feature = "el2")]
+        "cnthv_tval_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el1")]
+        "cntkctl" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "cntkctl_el1" => ExceptionLevel::El1,
+        "cntpct" => ExceptionLevel::El0,
+        "cntpctss" => ExceptionLevel::El0,
+        "cntpctss_el0" => ExceptionLevel::El0,
+        "cntpct_el0" => ExceptionLevel::El0,
+        #[cfg(feature = "el2")]
+        "cntpoff_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el1")]
+        "cntps_ctl_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "cntps_cval_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "cntps_tval_el1" => ExceptionLevel::El1,
+        "cntp_ctl" => ExceptionLevel::El0,
+        "cntp_ctl_el0" => ExceptionLevel::El0,
+        "cntp_cval" => ExceptionLevel::El0,
+        "cntp_cval_el0" => ExceptionLevel::El0,
+        "cntp_tval" => ExceptionLevel::El0,
+        "cntp_tval_el0" => ExceptionLevel::El0,
+        "cntvct" => ExceptionLevel::El0,
+        "cntvctss" => ExceptionLevel::El0,
+        "cntvctss_el0" => ExceptionLevel::El0,
+        "cntvct_el0" => ExceptionLevel::El0,
+        #[cfg(feature = "el2")]
+        "cntvoff" => ExceptionLevel::El2,
+        #[cfg(feature = "el2")]
+        "cntvoff_el2" => ExceptionLevel::El2,
+        "cntv_ctl" => ExceptionLevel::El0,
+        "cntv_ctl_el0" => ExceptionLevel::El0,
+        "cntv_cval" => ExceptionLevel::El0,
+        "cntv_cval_el0" => ExceptionLevel::El0,
+        "cntv_tval" => ExceptionLevel::El0,
+        "cntv_tval_el0" => ExceptionLevel::El0,
+        #[cfg(feature = "el1")]
+        "contextidr" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "contextidr_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el2")]
+        "contextidr_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el1")]
+        "cpacr" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "cpacr_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el2")]
+        "cptr_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el3")]
+        "cptr_el3" => ExceptionLevel::El3,
+        #[cfg(feature = "el1")]
+        "csselr" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "csselr_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "ctr" => ExceptionLevel::El1,
+        "ctr_el0" => ExceptionLevel::El0,
+        "currentel" => ExceptionLevel::El0,
+        #[cfg(feature = "el1")]
+        "dacr" => ExceptionLevel::El1,
+        "daif" => ExceptionLevel::El0,
+        #[cfg(feature = "el1")]
+        "dbgauthstatus" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "dbgclaimclr" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "dbgclaimset" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "dbgdccint" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "dbgdevid" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "dbgdevid1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "dbgdevid2" => ExceptionLevel::El1,
+        "dbgdidr" => ExceptionLevel::El0,
+        "dbgdrar" => ExceptionLevel::El0,
+        "dbgdsar" => ExceptionLevel::El0,
+        #[cfg(feature = "el1")]
+        "dbgdscrext" => ExceptionLevel::El1,
+        "dbgdscrint" => ExceptionLevel::El0,
+        #[cfg(feature = "el1")]
+        "dbgdtrrxext" => ExceptionLevel::El1,
+        "dbgdtrrxint" => ExceptionLevel::El0,
+        #[cfg(feature = "el1")]
+        "dbgdtrtxext" => ExceptionLevel::El1,
+        "dbgdtrtxint" => ExceptionLevel::El0,
+        #[cfg(feature = "el1")]
+        "dbgosdlr" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "dbgoseccr" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "dbgoslar" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "dbgoslsr" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "dbgprcr" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "dbgvcr" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "dbgwfar" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "dfar" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "dfsr" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "disr" => ExceptionLevel::El1,
+        #[cfg(all(feature = "el1", feature = "feat-ras"))]
+        "disr_el1" => ExceptionLevel::El1,
+        "dit" => ExceptionLevel::El0,
+        "dlr" => ExceptionLevel::El0,
+        "dspsr" => ExceptionLevel::El0,
+        #[cfg(feature = "el1")]
+        "dspsr2" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "elr_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el2")]
+        "elr_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el2")]
+        "elr_hyp" => ExceptionLevel::El2,
+        #[cfg(feature = "el1")]
+        "erridr" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "errselr" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "erxaddr" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "erxaddr2" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "erxctlr" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "erxctlr2" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "erxfr" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "erxfr2" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "erxmisc0" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "erxmisc1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "erxmisc2" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "erxmisc3" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "erxmisc4" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "erxmisc5" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "erxmisc6" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "erxmisc7" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "erxstatus" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "esr_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el2")]
+        "esr_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el3")]
+        "esr_el3" => ExceptionLevel::El3,
+        #[cfg(feature = "el1")]
+        "far_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el2")]
+        "far_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el1")]
+        "fcseidr" => ExceptionLevel::El1,
+        "fpcr" => ExceptionLevel::El0,
+        "fpmr" => ExceptionLevel::El0,
+        "fpsr" => ExceptionLevel::El0,
+        #[cfg(feature = "el1")]
+        "gcr_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "gcscr_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el2")]
+        "gcscr_el2" => ExceptionLevel::El2,
+        #[cfg(all(feature = "el3", feature = "feat-rme"))]
+        "gpccr_el3" => ExceptionLevel::El3,
+        #[cfg(all(feature = "el3", feature = "feat-rme"))]
+        "gptbr_el3" => ExceptionLevel::El3,
+        #[cfg(feature = "el2")]
+        "hacr" => ExceptionLevel::El2,
+        #[cfg(feature = "el2")]
+        "hacr_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el2")]
+        "hactlr" => ExceptionLevel::El2,
+        #[cfg(feature = "el2")]
+        "hactlr2" => ExceptionLevel::El2,
+        #[cfg(feature = "el2")]
+        "hadfsr" => ExceptionLevel::El2,
+        #[cfg(feature = "el2")]
+        "hafgrtr_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el2")]
+        "haifsr" => ExceptionLevel::El2,
+        #[cfg(feature = "el2")]
+        "hamair0" => ExceptionLevel::El2,
+        #[cfg(feature = "el2")]
+        "hamair1" => ExceptionLevel::El2,
+        #[cfg(feature = "el2")]
+        "hcptr" => ExceptionLevel::El2,
+        #[cfg(feature = "el2")]
+        "hcr" => ExceptionLevel::El2,
+        #[cfg(feature = "el2")]
+        "hcr2" => ExceptionLevel::El2,
+        #[cfg(feature = "el2")]
+        "hcrx_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el2")]
+        "hcr_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el2")]
+        "hdcr" => ExceptionLevel::El2,
+        #[cfg(feature = "el2")]
+        "hdfar" => ExceptionLevel::El2,
+        #[cfg(feature = "el2")]
+        "hdfgrtr2_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el2")]
+        "hdfgrtr_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el2")]
+        "hdfgwtr2_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el2")]
+        "hdfgwtr_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el2")]
+        "hfgitr2_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el2")]
+        "hfgitr_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el2")]
+        "hfgrtr2_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el2")]
+        "hfgrtr_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el2")]
+        "hfgwtr2_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el2")]
+        "hfgwtr_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el2")]
+        "hifar" => ExceptionLevel::El2,
+        #[cfg(feature = "el2")]
+        "hmair0" => ExceptionLevel::El2,
+        #[cfg(feature = "el2")]
+        "hmair1" => ExceptionLevel::El2,
+        #[cfg(feature = "el2")]
+        "hpfar" => ExceptionLevel::El2,
+        #[cfg(feature = "el2")]
+        "hpfar_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el2")]
+        "hrmr" => ExceptionLevel::El2,
+        #[cfg(feature = "el2")]
+        "hsctlr" => ExceptionLevel::El2,
+        #[cfg(feature = "el2")]
+        "hsr" => ExceptionLevel::El2,
+        #[cfg(feature = "el2")]
+        "hstr" => ExceptionLevel::El2,
+        #[cfg(feature = "el2")]
+        "hstr_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el2")]
+        "htcr" => ExceptionLevel::El2,
+        #[cfg(feature = "el2")]
+        "htpidr" => ExceptionLevel::El2,
+        #[cfg(feature = "el2")]
+        "htrfcr" => ExceptionLevel::El2,
+        #[cfg(feature = "el2")]
+        "httbr" => ExceptionLevel::El2,
+        #[cfg(feature = "el2")]
+        "hvbar" => ExceptionLevel::El2,
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_ap0r0_el1" => ExceptionLevel::El1,
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_ap0r1_el1" => ExceptionLevel::El1,
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_ap0r2_el1" => ExceptionLevel::El1,
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_ap0r3_el1" => ExceptionLevel::El1,
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_ap1r0_el1" => ExceptionLevel::El1,
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_ap1r1_el1" => ExceptionLevel::El1,
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_ap1r2_el1" => ExceptionLevel::El1,
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_ap1r3_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "icc_asgi1r" => ExceptionLevel::El1,
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_asgi1r_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "icc_bpr0" => ExceptionLevel::El1,
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_bpr0_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "icc_bpr1" => ExceptionLevel::El1,
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_bpr1_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "icc_ctlr" => ExceptionLevel::El1,
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_ctlr_el1" => ExceptionLevel::El1,
+        #[cfg(all(feature = "el3", feature = "feat-gic"))]
+        "icc_ctlr_el3" => ExceptionLevel::El3,
+        #[cfg(feature = "el1")]
+        "icc_dir" => ExceptionLevel::El1,
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_dir_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "icc_eoir0" => ExceptionLevel::El1,
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_eoir0_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "icc_eoir1" => ExceptionLevel::El1,
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_eoir1_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "icc_hppir0" => ExceptionLevel::El1,
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_hppir0_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "icc_hppir1" => ExceptionLevel::El1,
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_hppir1_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el2")]
+        "icc_hsre" => ExceptionLevel::El2,
+        #[cfg(feature = "el1")]
+        "icc_iar0" => ExceptionLevel::El1,
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_iar0_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "icc_iar1" => ExceptionLevel::El1,
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_iar1_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "icc_igrpen0" => ExceptionLevel::El1,
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_igrpen0_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "icc_igrpen1" => ExceptionLevel::El1,
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_igrpen1_el1" => ExceptionLevel::El1,
+        #[cfg(all(feature = "el3", feature = "feat-gic"))]
+        "icc_igrpen1_el3" => ExceptionLevel::El3,
+        #[cfg(feature = "el3")]
+        "icc_mctlr" => ExceptionLevel::El3,
+        #[cfg(feature = "el3")]
+        "icc_mgrpen1" => ExceptionLevel::El3,
+        #[cfg(feature = "el3")]
+        "icc_msre" => ExceptionLevel::El3,
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_nmiar1_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "icc_pmr" => ExceptionLevel::El1,
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_pmr_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "icc_rpr" => ExceptionLevel::El1,
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_rpr_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "icc_sgi0r" => ExceptionLevel::El1,
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_sgi0r_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "icc_sgi1r" => ExceptionLevel::El1,
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_sgi1r_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "icc_sre" => ExceptionLevel::El1,
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_sre_el1" => ExceptionLevel::El1,
+        #[cfg(all(feature = "el2", feature = "feat-gic"))]
+        "icc_sre_el2" => ExceptionLevel::El2,
+        #[cfg(all(feature = "el3", feature = "feat-gic"))]
+        "icc_sre_el3" => ExceptionLevel::El3,
+        #[cfg(all(feature = "el2", feature = "feat-gic"))]
+        "ich_hcr_el2" => ExceptionLevel::El2,
+        #[cfg(all(feature = "el2", feature = "feat-gic"))]
+        "ich_vmcr_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el1")]
+        "id_aa64dfr0_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "id_aa64dfr1_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "id_aa64isar1_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "id_aa64isar2_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "id_aa64mmfr0_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "id_aa64mmfr1_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "id_aa64mmfr2_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "id_aa64mmfr3_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "id_aa64mmfr4_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "id_aa64pfr0_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "id_aa64pfr1_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "id_aa64pfr2_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "id_aa64smfr0_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "id_afr0" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "id_dfr0" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "id_dfr1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "id_isar0" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "id_isar1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "id_isar2" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "id_isar3" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "id_isar4" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "id_isar5" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "id_isar6" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "id_mmfr0" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "id_mmfr1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "id_mmfr2" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "id_mmfr3" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "id_mmfr4" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "id_mmfr5" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "id_pfr0" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "id_pfr1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "id_pfr2" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "ifar" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "ifsr" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "isr" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "isr_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "jidr" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "jmcr" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "joscr" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "mair0" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "mair1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "mair_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el2")]
+        "mair_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el3")]
+        "mair_el3" => ExceptionLevel::El3,
+        #[cfg(feature = "el1")]
+        "mdccint_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el2")]
+        "mdcr_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el3")]
+        "mdcr_el3" => ExceptionLevel::El3,
+        #[cfg(feature = "el1")]
+        "mdscr_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "midr" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "midr_el1" => ExceptionLevel::El1,
+        #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+        "mpam2_el2" => ExceptionLevel::El2,
+        #[cfg(all(feature = "el3", feature = "feat-mpam"))]
+        "mpam3_el3" => ExceptionLevel::El3,
+        #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+        "mpamhcr_el2" => ExceptionLevel::El2,
+        #[cfg(all(feature = "el1", feature = "feat-mpam"))]
+        "mpamidr_el1" => ExceptionLevel::El1,
+        #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+        "mpamvpm0_el2" => ExceptionLevel::El2,
+        #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+        "mpamvpm1_el2" => ExceptionLevel::El2,
+        #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+        "mpamvpm2_el2" => ExceptionLevel::El2,
+        #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+        "mpamvpm3_el2" => ExceptionLevel::El2,
+        #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+        "mpamvpm4_el2" => ExceptionLevel::El2,
+        #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+        "mpamvpm5_el2" => ExceptionLevel::El2,
+        #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+        "mpamvpm6_el2" => ExceptionLevel::El2,
+        #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+        "mpamvpm7_el2" => ExceptionLevel::El2,
+        #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+        "mpamvpmv_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el1")]
+        "mpidr" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "mpidr_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "mvbar" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "nmrr" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "nsacr" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "par" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "par_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "pfar_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el2")]
+        "pfar_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el1")]
+        "pire0_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el2")]
+        "pire0_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el1")]
+        "pir_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el2")]
+        "pir_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el3")]
+        "pir_el3" => ExceptionLevel::El3,
+        "pmccfiltr" => ExceptionLevel::El0,
+        "pmccntr" => ExceptionLevel::El0,
+        "pmceid0" => ExceptionLevel::El0,
+        "pmceid1" => ExceptionLevel::El0,
+        "pmceid2" => ExceptionLevel::El0,
+        "pmceid3" => ExceptionLevel::El0,
+        "pmcntenclr" => ExceptionLevel::El0,
+        "pmcntenset" => ExceptionLevel::El0,
+        "pmcr" => ExceptionLevel::El0,
+        #[cfg(feature = "feat-pmu")]
+        "pmcr_el0" => ExceptionLevel::El0,
+        #[cfg(feature = "el1")]
+        "pmintenclr" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "pmintenset" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "pmmir" => ExceptionLevel::El1,
+        "pmovsr" => ExceptionLevel::El0,
+        "pmovsset" => ExceptionLevel::El0,
+        "pmselr" => ExceptionLevel::El0,
+        "pmswinc" => ExceptionLevel::El0,
+        "pmuserenr" => ExceptionLevel::El0,
+        "pmxevtyper" => ExceptionLevel::El0,
+        "por_el0" => ExceptionLevel::El0,
+        #[cfg(feature = "el1")]
+        "por_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el2")]
+        "por_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el3")]
+        "por_el3" => ExceptionLevel::El3,
+        #[cfg(feature = "el1")]
+        "prrr" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "revidr" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "rgsr_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "rmr" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "rvbar" => ExceptionLevel::El1,
+        #[cfg(feature = "el2")]
+        "s2pir_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el1")]
+        "s2por_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "scr" => ExceptionLevel::El1,
+        #[cfg(feature = "el3")]
+        "scr_el3" => ExceptionLevel::El3,
+        #[cfg(feature = "el1")]
+        "sctlr" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "sctlr2_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el2")]
+        "sctlr2_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el3")]
+        "sctlr2_el3" => ExceptionLevel::El3,
+        #[cfg(feature = "el1")]
+        "sctlr_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el2")]
+        "sctlr_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el3")]
+        "sctlr_el3" => ExceptionLevel::El3,
+        #[cfg(feature = "el1")]
+        "sdcr" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "sder" => ExceptionLevel::El1,
+        #[cfg(all(feature = "el3", feature = "feat-sve"))]
+        "smcr_el3" => ExceptionLevel::El3,
+        #[cfg(feature = "el1")]
+        "spsr_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el2")]
+        "spsr_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el3")]
+        "spsr_el3" => ExceptionLevel::El3,
+        #[cfg(feature = "el1")]
+        "sp_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el2")]
+        "sp_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "feat-sve")]
+        "svcr" => ExceptionLevel::El0,
+        #[cfg(feature = "el1")]
+        "tcmtr" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "tcr2_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el2")]
+        "tcr2_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el1")]
+        "tcr_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el2")]
+        "tcr_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el3")]
+        "tcr_el3" => ExceptionLevel::El3,
+        #[cfg(feature = "el1")]
+        "tfsre0_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "tfsr_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el2")]
+        "tfsr_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el1")]
+        "tlbtr" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "tpidrprw" => ExceptionLevel::El1,
+        "tpidrro_el0" => ExceptionLevel::El0,
+        "tpidruro" => ExceptionLevel::El0,
+        "tpidrurw" => ExceptionLevel::El0,
+        "tpidr_el0" => ExceptionLevel::El0,
+        #[cfg(feature = "el1")]
+        "tpidr_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el2")]
+        "tpidr_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el3")]
+        "tpidr_el3" => ExceptionLevel::El3,
+        #[cfg(feature = "el1")]
+        "trfcr" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "ttbcr" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "ttbcr2" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "ttbr0" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "ttbr0_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el2")]
+        "ttbr0_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el3")]
+        "ttbr0_el3" => ExceptionLevel::El3,
+        #[cfg(feature = "el1")]
+        "ttbr1" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "ttbr1_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el2")]
+        "ttbr1_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el1")]
+        "vbar" => ExceptionLevel::El1,
+        #[cfg(feature = "el1")]
+        "vbar_el1" => ExceptionLevel::El1,
+        #[cfg(feature = "el2")]
+        "vbar_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el2")]
+        "vdfsr" => ExceptionLevel::El2,
+        #[cfg(feature = "el1")]
+        "vdisr" => ExceptionLevel::El1,
+        #[cfg(all(feature = "el2", feature = "feat-ras"))]
+        "vdisr_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el1")]
+        "vmpidr" => ExceptionLevel::El1,
+        #[cfg(feature = "el2")]
+        "vmpidr_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el1")]
+        "vpidr" => ExceptionLevel::El1,
+        #[cfg(feature = "el2")]
+        "vpidr_el2" => ExceptionLevel::El2,
+        #[cfg(all(feature = "el2", feature = "feat-ras"))]
+        "vsesr_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el2")]
+        "vtcr" => ExceptionLevel::El2,
+        #[cfg(feature = "el2")]
+        "vtcr_el2" => ExceptionLevel::El2,
+        #[cfg(feature = "el2")]
+        "vttbr" => ExceptionLevel::El2,
+        #[cfg(feature = "el2")]
+        "vttbr_el2" => ExceptionLevel::El2,
+        #[cfg(all(feature = "el3", feature = "feat-sve"))]
+        "zcr_el3" => ExceptionLevel::El3,
+        _ => return None,
+    })
+}
+
+/// Returns whether the fake register with the given name is implemented by a PE with the given
+/// features.
+///
+/// Registers which depend on conditions other than features are always treated as implemented.
+pub(crate) fn register_implemented(name: &str, features: &CpuFeatures) -> bool {
+    match name {
+        #[cfg(feature = "feat-amu")]
+        "amcfgr_el0" => features.contains(Feature::Amuv1),
+        #[cfg(feature = "feat-amu")]
+        "amcgcr_el0" => features.contains(Feature::Amuv1),
+        #[cfg(feature = "feat-amu")]
+        "amcntenclr0_el0" => features.contains(Feature::Amuv1),
+        #[cfg(feature = "feat-amu")]
+        "amcntenclr1_el0" => features.contains(Feature::Amuv1),
+        #[cfg(feature = "feat-amu")]
+        "amcntenset0_el0" => features.contains(Feature::Amuv1),
+        #[cfg(feature = "feat-amu")]
+        "amcntenset1_el0" => features.contains(Feature::Amuv1),
+        #[cfg(feature = "feat-amu")]
+        "amcr_el0" => features.contains(Feature::Amuv1),
+        #[cfg(feature = "feat-amu")]
+        "amevcntr00_el0" => features.contains(Feature::Amuv1),
+        #[cfg(feature = "feat-amu")]
+        "amevcntr01_el0" => features.contains(Feature::Amuv1),
+        #[cfg(feature = "feat-amu")]
+        "amevcntr02_el0" => features.contains(Feature::Amuv1),
+        #[cfg(feature = "feat-amu")]
+        "amevcntr03_el0" => features.contains(Feature::Amuv1),
+        #[cfg(feature = "feat-amu")]
+        "amevcntr10_el0" => features.contains(Feature::Amuv1),
+        #[cfg(feature = "feat-amu")]
+        "amevcntr110_el0" => features.contains(Feature::Amuv1),
+        #[cfg(feature = "feat-amu")]
+        "amevcntr111_el0" => features.contains(Feature::Amuv1),
+        #[cfg(feature = "feat-amu")]
+        "amevcntr112_el0" => features.contains(Feature::Amuv1),
+        #[cfg(feature = "feat-amu")]
+        "amevcntr113_el0" => features.contains(Feature::Amuv1),
+        #[cfg(feature = "feat-amu")]
+        "amevcntr114_el0" => features.contains(Feature::Amuv1),
+        #[cfg(feature = "feat-amu")]
+        "amevcntr115_el0" => features.contains(Feature::Amuv1),
+        #[cfg(feature = "feat-amu")]
+        "amevcntr11_el0" => features.contains(Feature::Amuv1),
+        #[cfg(feature = "feat-amu")]
+        "amevcntr12_el0" => features.contains(Feature::Amuv1),
+        #[cfg(feature = "feat-amu")]
+        "amevcntr13_el0" => features.contains(Feature::Amuv1),
+        #[cfg(feature = "feat-amu")]
+        "amevcntr14_el0" => features.contains(Feature::Amuv1),
+        #[cfg(feature = "feat-amu")]
+        "amevcntr15_el0" => features.contains(Feature::Amuv1),
+        #[cfg(feature = "feat-amu")]
+        "amevcntr16_el0" => features.contains(Feature::Amuv1),
+        #[cfg(feature = "feat-amu")]
+        "amevcntr17_el0" => features.contains(Feature::Amuv1),
+        #[cfg(feature = "feat-amu")]
+        "amevcntr18_el0" => features.contains(Feature::Amuv1),
+        #[cfg(feature = "feat-amu")]
+        "amevcntr19_el0" => features.contains(Feature::Amuv1),
+        #[cfg(feature = "feat-amu")]
+        "amevtyper00_el0" => features.contains(Feature::Amuv1),
+        #[cfg(feature = "feat-amu")]
+        "amevtyper01_el0" => features.contains(Feature::Amuv1),
+        #[cfg(feature = "feat-amu")]
+        "amevtyper02_el0" => features.contains(Feature::Amuv1),
+        #[cfg(feature = "feat-amu")]
+        "amevtyper03_el0" => features.contains(Feature::Amuv1),
+        #[cfg(feature = "feat-amu")]
+        "amuserenr_el0" => features.contains(Feature::Amuv1),
+        #[cfg(feature = "el1")]
+        "apiakeyhi_el1" => features.contains(Feature::Pauth),
+        #[cfg(feature = "el1")]
+        "apiakeylo_el1" => features.contains(Feature::Pauth),
+        #[cfg(feature = "el2")]
+        "brbcr_el2" => features.contains(Feature::Brbe),
+        #[cfg(all(feature = "el1", feature = "feat-ras"))]
+        "disr_el1" => features.contains(Feature::Ras),
+        "dit" => features.contains(Feature::Dit),
+        "fpmr" => features.contains(Feature::Fpmr),
+        #[cfg(feature = "el1")]
+        "gcr_el1" => features.contains(Feature::Mte2),
+        #[cfg(feature = "el1")]
+        "gcscr_el1" => features.contains(Feature::Gcs),
+        #[cfg(feature = "el2")]
+        "gcscr_el2" => features.contains(Feature::Gcs),
+        #[cfg(all(feature = "el3", feature = "feat-rme"))]
+        "gpccr_el3" => features.contains(Feature::Rme),
+        #[cfg(all(feature = "el3", feature = "feat-rme"))]
+        "gptbr_el3" => features.contains(Feature::Rme),
+        #[cfg(feature = "el2")]
+        "hcrx_el2" => features.contains(Feature::Hcx),
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_ap0r0_el1" => features.contains(Feature::Gicv3),
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_ap0r1_el1" => features.contains(Feature::Gicv3),
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_ap0r2_el1" => features.contains(Feature::Gicv3),
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_ap0r3_el1" => features.contains(Feature::Gicv3),
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_ap1r0_el1" => features.contains(Feature::Gicv3),
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_ap1r1_el1" => features.contains(Feature::Gicv3),
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_ap1r2_el1" => features.contains(Feature::Gicv3),
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_ap1r3_el1" => features.contains(Feature::Gicv3),
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_asgi1r_el1" => features.contains(Feature::Gicv3),
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_bpr0_el1" => features.contains(Feature::Gicv3),
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_bpr1_el1" => features.contains(Feature::Gicv3),
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_ctlr_el1" => features.contains(Feature::Gicv3),
+        #[cfg(all(feature = "el3", feature = "feat-gic"))]
+        "icc_ctlr_el3" => features.contains(Feature::Gicv3),
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_dir_el1" => features.contains(Feature::Gicv3),
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_eoir0_el1" => features.contains(Feature::Gicv3),
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_eoir1_el1" => features.contains(Feature::Gicv3),
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_hppir0_el1" => features.contains(Feature::Gicv3),
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_hppir1_el1" => features.contains(Feature::Gicv3),
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_iar0_el1" => features.contains(Feature::Gicv3),
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_iar1_el1" => features.contains(Feature::Gicv3),
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_igrpen0_el1" => features.contains(Feature::Gicv3),
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_igrpen1_el1" => features.contains(Feature::Gicv3),
+        #[cfg(all(feature = "el3", feature = "feat-gic"))]
+        "icc_igrpen1_el3" => features.contains(Feature::Gicv3),
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_nmiar1_el1" => features.contains(Feature::Gicv3),
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_pmr_el1" => features.contains(Feature::Gicv3),
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_rpr_el1" => features.contains(Feature::Gicv3),
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_sgi0r_el1" => features.contains(Feature::Gicv3),
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_sgi1r_el1" => features.contains(Feature::Gicv3),
+        #[cfg(all(feature = "el1", feature = "feat-gic"))]
+        "icc_sre_el1" => features.contains(Feature::Gicv3),
+        #[cfg(all(feature = "el2", feature = "feat-gic"))]
+        "icc_sre_el2" => features.contains(Feature::Gicv3),
+        #[cfg(all(feature = "el3", feature = "feat-gic"))]
+        "icc_sre_el3" => features.contains(Feature::Gicv3),
+        #[cfg(all(feature = "el2", feature = "feat-gic"))]
+        "ich_hcr_el2" => features.contains(Feature::Gicv3),
+        #[cfg(all(feature = "el2", feature = "feat-gic"))]
+        "ich_vmcr_el2" => features.contains(Feature::Gicv3),
+        #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+        "mpam2_el2" => features.contains(Feature::Mpam),
+        #[cfg(all(feature = "el3", feature = "feat-mpam"))]
+        "mpam3_el3" => features.contains(Feature::Mpam),
+        #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+        "mpamhcr_el2" => features.contains(Feature::Mpam),
+        #[cfg(all(feature = "el1", feature = "feat-mpam"))]
+        "mpamidr_el1" => features.contains(Feature::Mpam),
+        #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+        "mpamvpm0_el2" => features.contains(Feature::Mpam),
+        #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+        "mpamvpm1_el2" => features.contains(Feature::Mpam),
+        #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+        "mpamvpm2_el2" => features.contains(Feature::Mpam),
+        #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+        "mpamvpm3_el2" => features.contains(Feature::Mpam),
+        #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+        "mpamvpm4_el2" => features.contains(Feature::Mpam),
+        #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+        "mpamvpm5_el2" => features.contains(Feature::Mpam),
+        #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+        "mpamvpm6_el2" => features.contains(Feature::Mpam),
+        #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+        "mpamvpm7_el2" => features.contains(Feature::Mpam),
+        #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+        "mpamvpmv_el2" => features.contains(Feature::Mpam),
+        #[cfg(feature = "el1")]
+        "pfar_el1" => features.contains(Feature::Pfar),
+        #[cfg(feature = "el2")]
+        "pfar_el2" => features.contains(Feature::Pfar),
+        #[cfg(feature = "el1")]
+        "pire0_el1" => features.contains(Feature::S1pie),
+        #[cfg(feature = "el2")]
+        "pire0_el2" => features.contains(Feature::S1pie),
+        #[cfg(feature = "el1")]
+        "pir_el1" => features.contains(Feature::S1pie),
+        #[cfg(feature = "el2")]
+        "pir_el2" => features.contains(Feature::S1pie),
+        #[cfg(feature = "el3")]
+        "pir_el3" => features.contains(Feature::S1pie),
+        #[cfg(feature = "feat-pmu")]
+        "pmcr_el0" => features.contains(Feature::Pmuv3),
+        "por_el0" => features.contains(Feature::S1poe),
+        #[cfg(feature = "el1")]
+        "por_el1" => features.contains(Feature::S1poe),
+        #[cfg(feature = "el2")]
+        "por_el2" => features.contains(Feature::S1poe),
+        #[cfg(feature = "el3")]
+        "por_el3" => features.contains(Feature::S1poe),
+        #[cfg(feature = "el1")]
+        "rgsr_el1" => features.contains(Feature::Mte2),
+        #[cfg(feature = "el2")]
+        "s2pir_el2" => features.contains(Feature::S2pie),
+        #[cfg(feature = "el1")]
+        "s2por_el1" => features.contains(Feature::S2poe),
+        #[cfg(feature = "el1")]
+        "sctlr2_el1" => features.contains(Feature::Sctlr2),
+        #[cfg(feature = "el2")]
+        "sctlr2_el2" => features.contains(Feature::Sctlr2),
+        #[cfg(feature = "el3")]
+        "sctlr2_el3" => features.contains(Feature::Sctlr2),
+        #[cfg(all(feature = "el3", feature = "feat-sve"))]
+        "smcr_el3" => features.contains(Feature::Sme),
+        #[cfg(feature = "feat-sve")]
+        "svcr" => features.contains(Feature::Sme),
+        #[cfg(feature = "el1")]
+        "tcr2_el1" => features.contains(Feature::Tcr2),
+        #[cfg(feature = "el1")]
+        "tfsre0_el1" => features.contains(Feature::Mte2),
+        #[cfg(feature = "el1")]
+        "tfsr_el1" => features.contains(Feature::Mte2),
+        #[cfg(feature = "el2")]
+        "tfsr_el2" => features.contains(Feature::Mte2),
+        #[cfg(all(feature = "el2", feature = "feat-ras"))]
+        "vdisr_el2" => features.contains(Feature::Ras),
+        #[cfg(all(feature = "el2", feature = "feat-ras"))]
+        "vsesr_el2" => features.contains(Feature::Ras),
+        #[cfg(all(feature = "el3", feature = "feat-sve"))]
+        "zcr_el3" => features.contains(Feature::Sve),
+        _ => true,
+    }
+}
+
+#[cfg(feature = "backend")]
+impl SystemRegisters {
+    /// Returns the value of the fake register with the given ID, zero-extended to 64 bits.
+    pub(crate) fn read_by_id(&self, id: SysregId) -> u64 {
+        match id {
+            #[cfg(feature = "el1")]
+            SysregId::Actlr => u64::from(self.actlr),
+            #[cfg(feature = "el1")]
+            SysregId::Actlr2 => u64::from(self.actlr2),
+            #[cfg(feature = "el1")]
+            SysregId::ActlrEl1 => self.actlr_el1,
+            #[cfg(feature = "el2")]
+            SysregId::ActlrEl2 => self.actlr_el2,
+            #[cfg(feature = "el1")]
+            SysregId::Adfsr => u64::from(self.adfsr),
+            #[cfg(feature = "el1")]
+            SysregId::Afsr0El1 => self.afsr0_el1,
+            #[cfg(feature = "el2")]
+            SysregId::Afsr0El2 => self.afsr0_el2,
+            #[cfg(feature = "el1")]
+            SysregId::Afsr1El1 => self.afsr1_el1,
+            #[cfg(feature = "el2")]
+            SysregId::Afsr1El2 => self.afsr1_el2,
+            #[cfg(feature = "el1")]
+            SysregId::Aidr => u64::from(self.aidr),
+            #[cfg(feature = "el1")]
+            SysregId::Aifsr => u64::from(self.aifsr),
+            #[cfg(feature = "el1")]
+            SysregId::Amair0 => u64::from(self.amair0),
+            #[cfg(feature = "el1")]
+            SysregId::Amair1 => u64::from(self.amair1),
+            #[cfg(feature = "el1")]
+            SysregId::AmairEl1 => self.amair_el1,
+            #[cfg(feature = "el2")]
+            SysregId::AmairEl2 => self.amair_el2,
+            SysregId::Amcfgr => u64::from(self.amcfgr.bits()),
+            #[cfg(feature = "feat-amu")]
+            SysregId::AmcfgrEl0 => self.amcfgr_el0.bits(),
+            SysregId::Amcgcr => u64::from(self.amcgcr.bits()),
+            #[cfg(feature = "feat-amu")]
+            SysregId::AmcgcrEl0 => self.amcgcr_el0.bits(),
+            SysregId::Amcntenclr0 => u64::from(self.amcntenclr0.bits()),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amcntenclr0El0 => self.amcntenclr0_el0.bits(),
+            SysregId::Amcntenclr1 => u64::from(self.amcntenclr1.bits()),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amcntenclr1El0 => self.amcntenclr1_el0.bits(),
+            SysregId::Amcntenset0 => u64::from(self.amcntenset0.bits()),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amcntenset0El0 => self.amcntenset0_el0.bits(),
+            SysregId::Amcntenset1 => u64::from(self.amcntenset1.bits()),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amcntenset1El0 => self.amcntenset1_el0.bits(),
+            SysregId::Amcr => u64::from(self.amcr.bits()),
+            #[cfg(feature = "feat-amu")]
+            SysregId::AmcrEl0 => self.amcr_el0.bits(),
+            SysregId::Amevcntr00 => self.amevcntr00.bits(),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amevcntr00El0 => self.amevcntr00_el0.bits(),
+            SysregId::Amevcntr01 => self.amevcntr01.bits(),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amevcntr01El0 => self.amevcntr01_el0.bits(),
+            SysregId::Amevcntr02 => self.amevcntr02.bits(),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amevcntr02El0 => self.amevcntr02_el0.bits(),
+            SysregId::Amevcntr03 => self.amevcntr03.bits(),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amevcntr03El0 => self.amevcntr03_el0.bits(),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amevcntr10El0 => self.amevcntr10_el0.bits(),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amevcntr110El0 => self.amevcntr110_el0.bits(),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amevcntr111El0 => self.amevcntr111_el0.bits(),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amevcntr112El0 => self.amevcntr112_el0.bits(),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amevcntr113El0 => self.amevcntr113_el0.bits(),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amevcntr114El0 => self.amevcntr114_el0.bits(),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amevcntr115El0 => self.amevcntr115_el0.bits(),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amevcntr11El0 => self.amevcntr11_el0.bits(),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amevcntr12El0 => self.amevcntr12_el0.bits(),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amevcntr13El0 => self.amevcntr13_el0.bits(),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amevcntr14El0 => self.amevcntr14_el0.bits(),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amevcntr15El0 => self.amevcntr15_el0.bits(),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amevcntr16El0 => self.amevcntr16_el0.bits(),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amevcntr17El0 => self.amevcntr17_el0.bits(),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amevcntr18El0 => self.amevcntr18_el0.bits(),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amevcntr19El0 => self.amevcntr19_el0.bits(),
+            SysregId::Amevtyper00 => u64::from(self.amevtyper00.bits()),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amevtyper00El0 => self.amevtyper00_el0.bits(),
+            SysregId::Amevtyper01 => u64::from(self.amevtyper01.bits()),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amevtyper01El0 => self.amevtyper01_el0.bits(),
+            SysregId::Amevtyper02 => u64::from(self.amevtyper02.bits()),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amevtyper02El0 => self.amevtyper02_el0.bits(),
+            SysregId::Amevtyper03 => u64::from(self.amevtyper03.bits()),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amevtyper03El0 => self.amevtyper03_el0.bits(),
+            SysregId::Amevtyper10 => u64::from(self.amevtyper10.bits()),
+            SysregId::Amevtyper11 => u64::from(self.amevtyper11.bits()),
+            SysregId::Amevtyper110 => u64::from(self.amevtyper110.bits()),
+            SysregId::Amevtyper111 => u64::from(self.amevtyper111.bits()),
+            SysregId::Amevtyper112 => u64::from(self.amevtyper112.bits()),
+            SysregId::Amevtyper113 => u64::from(self.amevtyper113.bits()),
+            SysregId::Amevtyper114 => u64::from(self.amevtyper114.bits()),
+            SysregId::Amevtyper115 => u64::from(self.amevtyper115.bits()),
+            SysregId::Amevtyper12 => u64::from(self.amevtyper12.bits()),
+            SysregId::Amevtyper13 => u64::from(self.amevtyper13.bits()),
+            SysregId::Amevtyper14 => u64::from(self.amevtyper14.bits()),
+            SysregId::Amevtyper15 => u64::from(self.amevtyper15.bits()),
+            SysregId::Amevtyper16 => u64::from(self.amevtyper16.bits()),
+            SysregId::Amevtyper17 => u64::from(self.amevtyper17.bits()),
+            SysregId::Amevtyper18 => u64::from(self.amevtyper18.bits()),
+            SysregId::Amevtyper19 => u64::from(self.amevtyper19.bits()),
+            SysregId::Amuserenr => u64::from(self.amuserenr.bits()),
+            #[cfg(feature = "feat-amu")]
+            SysregId::AmuserenrEl0 => self.amuserenr_el0.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::ApiakeyhiEl1 => self.apiakeyhi_el1.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::ApiakeyloEl1 => self.apiakeylo_el1.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::BrbcrEl2 => self.brbcr_el2.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::Ccsidr => u64::from(self.ccsidr.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Ccsidr2 => u64::from(self.ccsidr2.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::CcsidrEl1 => self.ccsidr_el1.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::Clidr => u64::from(self.clidr.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::ClidrEl1 => self.clidr_el1.bits(),
+            SysregId::Cntfrq => u64::from(self.cntfrq.bits()),
+            SysregId::CntfrqEl0 => self.cntfrq_el0.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::Cnthctl => u64::from(self.cnthctl.bits()),
+            #[cfg(feature = "el2")]
+            SysregId::CnthctlEl2 => self.cnthctl_el2.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::CnthpsCtl => u64::from(self.cnthps_ctl.bits()),
+            #[cfg(feature = "el2")]
+            SysregId::CnthpsCtlEl2 => self.cnthps_ctl_el2.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::CnthpsCval => self.cnthps_cval.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::CnthpsCvalEl2 => self.cnthps_cval_el2.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::CnthpsTval => u64::from(self.cnthps_tval.bits()),
+            #[cfg(feature = "el2")]
+            SysregId::CnthpsTvalEl2 => self.cnthps_tval_el2.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::CnthpCtl => u64::from(self.cnthp_ctl.bits()),
+            #[cfg(feature = "el2")]
+            SysregId::CnthpCtlEl2 => self.cnthp_ctl_el2.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::CnthpCval => self.cnthp_cval.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::CnthpCvalEl2 => self.cnthp_cval_el2.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::CnthpTval => u64::from(self.cnthp_tval.bits()),
+            #[cfg(feature = "el2")]
+            SysregId::CnthpTvalEl2 => self.cnthp_tval_el2.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::CnthvsCtl => u64::from(self.cnthvs_ctl.bits()),
+            #[cfg(feature = "el2")]
+            SysregId::CnthvsCtlEl2 => self.cnthvs_ctl_el2.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::CnthvsCval => self.cnthvs_cval.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::CnthvsCvalEl2 => self.cnthvs_cval_el2.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::CnthvsTval => u64::from(self.cnthvs_tval.bits()),
+            #[cfg(feature = "el2")]
+            SysregId::CnthvsTvalEl2 => self.cnthvs_tval_el2.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::CnthvCtl => u64::from(self.cnthv_ctl.bits()),
+            #[cfg(feature = "el2")]
+            SysregId::CnthvCtlEl2 => self.cnthv_ctl_el2.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::CnthvCval => self.cnthv_cval.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::CnthvCvalEl2 => self.cnthv_cval_el2.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::CnthvTval => u64::from(self.cnthv_tval.bits()),
+            #[cfg(feature = "el2")]
+            SysregId::CnthvTvalEl2 => self.cnthv_tval_el2.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::Cntkctl => u64::from(self.cntkctl.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::CntkctlEl1 => self.cntkctl_el1.bits(),
+            SysregId::Cntpct => self.cntpct.bits(),
+            SysregId::Cntpctss => self.cntpctss.bits(),
+            SysregId::CntpctssEl0 => self.cntpctss_el0.bits(),
+            SysregId::CntpctEl0 => self.cntpct_el0.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::CntpoffEl2 => self.cntpoff_el2.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::CntpsCtlEl1 => self.cntps_ctl_el1.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::CntpsCvalEl1 => self.cntps_cval_el1.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::CntpsTvalEl1 => self.cntps_tval_el1.bits(),
+            SysregId::CntpCtl => u64::from(self.cntp_ctl.bits()),
+            SysregId::CntpCtlEl0 => self.cntp_ctl_el0.bits(),
+            SysregId::CntpCval => self.cntp_cval.bits(),
+            SysregId::CntpCvalEl0 => self.cntp_cval_el0.bits(),
+            SysregId::CntpTval => u64::from(self.cntp_tval.bits()),
+            SysregId::CntpTvalEl0 => self.cntp_tval_el0.bits(),
+            SysregId::Cntvct => self.cntvct.bits(),
+            SysregId::Cntvctss => self.cntvctss.bits(),
+            SysregId::CntvctssEl0 => self.cntvctss_el0.bits(),
+            SysregId::CntvctEl0 => self.cntvct_el0.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::Cntvoff => self.cntvoff.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::CntvoffEl2 => self.cntvoff_el2.bits(),
+            SysregId::CntvCtl => u64::from(self.cntv_ctl.bits()),
+            SysregId::CntvCtlEl0 => self.cntv_ctl_el0.bits(),
+            SysregId::CntvCval => self.cntv_cval.bits(),
+            SysregId::CntvCvalEl0 => self.cntv_cval_el0.bits(),
+            SysregId::CntvTval => u64::from(self.cntv_tval.bits()),
+            SysregId::CntvTvalEl0 => self.cntv_tval_el0.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::Contextidr => u64::from(self.contextidr.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::ContextidrEl1 => self.contextidr_el1.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::ContextidrEl2 => self.contextidr_el2.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::Cpacr => u64::from(self.cpacr.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::CpacrEl1 => self.cpacr_el1.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::CptrEl2 => self.cptr_el2.bits(),
+            #[cfg(feature = "el3")]
+            SysregId::CptrEl3 => self.cptr_el3.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::Csselr => u64::from(self.csselr.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::CsselrEl1 => self.csselr_el1.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::Ctr => u64::from(self.ctr.bits()),
+            SysregId::CtrEl0 => self.ctr_el0.bits(),
+            SysregId::Currentel => self.currentel.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::Dacr => u64::from(self.dacr.bits()),
+            SysregId::Daif => self.daif.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::Dbgauthstatus => u64::from(self.dbgauthstatus.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Dbgclaimclr => u64::from(self.dbgclaimclr.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Dbgclaimset => u64::from(self.dbgclaimset.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Dbgdccint => u64::from(self.dbgdccint.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Dbgdevid => u64::from(self.dbgdevid.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Dbgdevid1 => u64::from(self.dbgdevid1.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Dbgdevid2 => u64::from(self.dbgdevid2),
+            SysregId::Dbgdidr => u64::from(self.dbgdidr.bits()),
+            SysregId::Dbgdrar => self.dbgdrar.bits(),
+            SysregId::Dbgdsar => self.dbgdsar,
+            #[cfg(feature = "el1")]
+            SysregId::Dbgdscrext => u64::from(self.dbgdscrext.bits()),
+            SysregId::Dbgdscrint => u64::from(self.dbgdscrint.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Dbgdtrrxext => u64::from(self.dbgdtrrxext.bits()),
+            SysregId::Dbgdtrrxint => u64::from(self.dbgdtrrxint.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Dbgdtrtxext => u64::from(self.dbgdtrtxext.bits()),
+            SysregId::Dbgdtrtxint => u64::from(self.dbgdtrtxint.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Dbgosdlr => u64::from(self.dbgosdlr.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Dbgoseccr => u64::from(self.dbgoseccr.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Dbgoslar => u64::from(self.dbgoslar.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Dbgoslsr => u64::from(self.dbgoslsr.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Dbgprcr => u64::from(self.dbgprcr.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Dbgvcr => u64::from(self.dbgvcr.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Dbgwfar => u64::from(self.dbgwfar),
+            #[cfg(feature = "el1")]
+            SysregId::Dfar => u64::from(self.dfar.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Dfsr => u64::from(self.dfsr.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Disr => u64::from(self.disr.bits()),
+            #[cfg(all(feature = "el1", feature = "feat-ras"))]
+            SysregId::DisrEl1 => self.disr_el1.bits(),
+            SysregId::Dit => self.dit.bits(),
+            SysregId::Dlr => u64::from(self.dlr.bits()),
+            SysregId::Dspsr => u64::from(self.dspsr.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Dspsr2 => u64::from(self.dspsr2.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::ElrEl1 => self.elr_el1.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::ElrEl2 => self.elr_el2.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::ElrHyp => u64::from(self.elr_hyp.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Erridr => u64::from(self.erridr.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Errselr => u64::from(self.errselr.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Erxaddr => u64::from(self.erxaddr.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Erxaddr2 => u64::from(self.erxaddr2.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Erxctlr => u64::from(self.erxctlr.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Erxctlr2 => u64::from(self.erxctlr2.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Erxfr => u64::from(self.erxfr.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Erxfr2 => u64::from(self.erxfr2.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Erxmisc0 => u64::from(self.erxmisc0.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Erxmisc1 => u64::from(self.erxmisc1.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Erxmisc2 => u64::from(self.erxmisc2.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Erxmisc3 => u64::from(self.erxmisc3.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Erxmisc4 => u64::from(self.erxmisc4.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Erxmisc5 => u64::from(self.erxmisc5.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Erxmisc6 => u64::from(self.erxmisc6.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Erxmisc7 => u64::from(self.erxmisc7.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Erxstatus => u64::from(self.erxstatus.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::EsrEl1 => self.esr_el1.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::EsrEl2 => self.esr_el2.bits(),
+            #[cfg(feature = "el3")]
+            SysregId::EsrEl3 => self.esr_el3.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::FarEl1 => self.far_el1.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::FarEl2 => self.far_el2.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::Fcseidr => u64::from(self.fcseidr),
+            SysregId::Fpcr => self.fpcr.bits(),
+            SysregId::Fpmr => self.fpmr.bits(),
+            SysregId::Fpsr => self.fpsr.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::GcrEl1 => self.gcr_el1.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::GcscrEl1 => self.gcscr_el1.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::GcscrEl2 => self.gcscr_el2.bits(),
+            #[cfg(all(feature = "el3", feature = "feat-rme"))]
+            SysregId::GpccrEl3 => self.gpccr_el3.bits(),
+            #[cfg(all(feature = "el3", feature = "feat-rme"))]
+            SysregId::GptbrEl3 => self.gptbr_el3.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::Hacr => u64::from(self.hacr),
+            #[cfg(feature = "el2")]
+            SysregId::HacrEl2 => self.hacr_el2,
+            #[cfg(feature = "el2")]
+            SysregId::Hactlr => u64::from(self.hactlr),
+            #[cfg(feature = "el2")]
+            SysregId::Hactlr2 => u64::from(self.hactlr2),
+            #[cfg(feature = "el2")]
+            SysregId::Hadfsr => u64::from(self.hadfsr),
+            #[cfg(feature = "el2")]
+            SysregId::HafgrtrEl2 => self.hafgrtr_el2.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::Haifsr => u64::from(self.haifsr),
+            #[cfg(feature = "el2")]
+            SysregId::Hamair0 => u64::from(self.hamair0),
+            #[cfg(feature = "el2")]
+            SysregId::Hamair1 => u64::from(self.hamair1),
+            #[cfg(feature = "el2")]
+            SysregId::Hcptr => u64::from(self.hcptr.bits()),
+            #[cfg(feature = "el2")]
+            SysregId::Hcr => u64::from(self.hcr.bits()),
+            #[cfg(feature = "el2")]
+            SysregId::Hcr2 => u64::from(self.hcr2.bits()),
+            #[cfg(feature = "el2")]
+            SysregId::HcrxEl2 => self.hcrx_el2.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::HcrEl2 => self.hcr_el2.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::Hdcr => u64::from(self.hdcr.bits()),
+            #[cfg(feature = "el2")]
+            SysregId::Hdfar => u64::from(self.hdfar.bits()),
+            #[cfg(feature = "el2")]
+            SysregId::Hdfgrtr2El2 => self.hdfgrtr2_el2.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::HdfgrtrEl2 => self.hdfgrtr_el2.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::Hdfgwtr2El2 => self.hdfgwtr2_el2.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::HdfgwtrEl2 => self.hdfgwtr_el2.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::Hfgitr2El2 => self.hfgitr2_el2.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::HfgitrEl2 => self.hfgitr_el2.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::Hfgrtr2El2 => self.hfgrtr2_el2.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::HfgrtrEl2 => self.hfgrtr_el2.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::Hfgwtr2El2 => self.hfgwtr2_el2.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::HfgwtrEl2 => self.hfgwtr_el2.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::Hifar => u64::from(self.hifar.bits()),
+            #[cfg(feature = "el2")]
+            SysregId::Hmair0 => u64::from(self.hmair0.bits()),
+            #[cfg(feature = "el2")]
+            SysregId::Hmair1 => u64::from(self.hmair1.bits()),
+            #[cfg(feature = "el2")]
+            SysregId::Hpfar => u64::from(self.hpfar.bits()),
+            #[cfg(feature = "el2")]
+            SysregId::HpfarEl2 => self.hpfar_el2.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::Hrmr => u64::from(self.hrmr.bits()),
+            #[cfg(feature = "el2")]
+            SysregId::Hsctlr => u64::from(self.hsctlr.bits()),
+            #[cfg(feature = "el2")]
+            SysregId::Hsr => u64::from(self.hsr.bits()),
+            #[cfg(feature = "el2")]
+            SysregId::Hstr => u64::from(self.hstr),
+            #[cfg(feature = "el2")]
+            SysregId::HstrEl2 => self.hstr_el2,
+            #[cfg(feature = "el2")]
+            SysregId::Htcr => u64::from(self.htcr.bits()),
+            #[cfg(feature = "el2")]
+            SysregId::Htpidr => u64::from(self.htpidr.bits()),
+            #[cfg(feature = "el2")]
+            SysregId::Htrfcr => u64::from(self.htrfcr.bits()),
+            #[cfg(feature = "el2")]
+            SysregId::Httbr => self.httbr.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::Hvbar => u64::from(self.hvbar.bits()),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccAp0r0El1 => self.icc_ap0r0_el1,
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccAp0r1El1 => self.icc_ap0r1_el1,
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccAp0r2El1 => self.icc_ap0r2_el1,
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccAp0r3El1 => self.icc_ap0r3_el1,
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccAp1r0El1 => self.icc_ap1r0_el1.bits(),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccAp1r1El1 => self.icc_ap1r1_el1,
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccAp1r2El1 => self.icc_ap1r2_el1,
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccAp1r3El1 => self.icc_ap1r3_el1,
+            #[cfg(feature = "el1")]
+            SysregId::IccAsgi1r => self.icc_asgi1r.bits(),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccAsgi1rEl1 => self.icc_asgi1r_el1.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::IccBpr0 => u64::from(self.icc_bpr0.bits()),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccBpr0El1 => self.icc_bpr0_el1.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::IccBpr1 => u64::from(self.icc_bpr1.bits()),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccBpr1El1 => self.icc_bpr1_el1.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::IccCtlr => u64::from(self.icc_ctlr.bits()),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccCtlrEl1 => self.icc_ctlr_el1.bits(),
+            #[cfg(all(feature = "el3", feature = "feat-gic"))]
+            SysregId::IccCtlrEl3 => self.icc_ctlr_el3.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::IccDir => u64::from(self.icc_dir.bits()),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccDirEl1 => self.icc_dir_el1.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::IccEoir0 => u64::from(self.icc_eoir0.bits()),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccEoir0El1 => self.icc_eoir0_el1.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::IccEoir1 => u64::from(self.icc_eoir1.bits()),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccEoir1El1 => self.icc_eoir1_el1.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::IccHppir0 => u64::from(self.icc_hppir0.bits()),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccHppir0El1 => self.icc_hppir0_el1.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::IccHppir1 => u64::from(self.icc_hppir1.bits()),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccHppir1El1 => self.icc_hppir1_el1.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::IccHsre => u64::from(self.icc_hsre.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::IccIar0 => u64::from(self.icc_iar0.bits()),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccIar0El1 => self.icc_iar0_el1.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::IccIar1 => u64::from(self.icc_iar1.bits()),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccIar1El1 => self.icc_iar1_el1.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::IccIgrpen0 => u64::from(self.icc_igrpen0.bits()),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccIgrpen0El1 => self.icc_igrpen0_el1.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::IccIgrpen1 => u64::from(self.icc_igrpen1.bits()),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccIgrpen1El1 => self.icc_igrpen1_el1.bits(),
+            #[cfg(all(feature = "el3", feature = "feat-gic"))]
+            SysregId::IccIgrpen1El3 => self.icc_igrpen1_el3.bits(),
+            #[cfg(feature = "el3")]
+            SysregId::IccMctlr => u64::from(self.icc_mctlr.bits()),
+            #[cfg(feature = "el3")]
+            SysregId::IccMgrpen1 => u64::from(self.icc_mgrpen1.bits()),
+            #[cfg(feature = "el3")]
+            SysregId::IccMsre => u64::from(self.icc_msre.bits()),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccNmiar1El1 => self.icc_nmiar1_el1.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::IccPmr => u64::from(self.icc_pmr.bits()),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccPmrEl1 => self.icc_pmr_el1.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::IccRpr => u64::from(self.icc_rpr.bits()),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccRprEl1 => self.icc_rpr_el1.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::IccSgi0r => self.icc_sgi0r.bits(),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccSgi0rEl1 => self.icc_sgi0r_el1.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::IccSgi1r => self.icc_sgi1r.bits(),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccSgi1rEl1 => self.icc_sgi1r_el1.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::IccSre => u64::from(self.icc_sre.bits()),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccSreEl1 => self.icc_sre_el1.bits(),
+            #[cfg(all(feature = "el2", feature = "feat-gic"))]
+            SysregId::IccSreEl2 => self.icc_sre_el2.bits(),
+            #[cfg(all(feature = "el3", feature = "feat-gic"))]
+            SysregId::IccSreEl3 => self.icc_sre_el3.bits(),
+            #[cfg(all(feature = "el2", feature = "feat-gic"))]
+            SysregId::IchHcrEl2 => self.ich_hcr_el2.bits(),
+            #[cfg(all(feature = "el2", feature = "feat-gic"))]
+            SysregId::IchVmcrEl2 => self.ich_vmcr_el2.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::IdAa64dfr0El1 => self.id_aa64dfr0_el1.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::IdAa64dfr1El1 => self.id_aa64dfr1_el1.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::IdAa64isar1El1 => self.id_aa64isar1_el1.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::IdAa64isar2El1 => self.id_aa64isar2_el1.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::IdAa64mmfr0El1 => self.id_aa64mmfr0_el1.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::IdAa64mmfr1El1 => self.id_aa64mmfr1_el1.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::IdAa64mmfr2El1 => self.id_aa64mmfr2_el1.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::IdAa64mmfr3El1 => self.id_aa64mmfr3_el1.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::IdAa64mmfr4El1 => self.id_aa64mmfr4_el1.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::IdAa64pfr0El1 => self.id_aa64pfr0_el1.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::IdAa64pfr1El1 => self.id_aa64pfr1_el1.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::IdAa64pfr2El1 => self.id_aa64pfr2_el1.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::IdAa64smfr0El1 => self.id_aa64smfr0_el1.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::IdAfr0 => u64::from(self.id_afr0),
+            #[cfg(feature = "el1")]
+            SysregId::IdDfr0 => u64::from(self.id_dfr0.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::IdDfr1 => u64::from(self.id_dfr1.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::IdIsar0 => u64::from(self.id_isar0.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::IdIsar1 => u64::from(self.id_isar1.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::IdIsar2 => u64::from(self.id_isar2.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::IdIsar3 => u64::from(self.id_isar3.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::IdIsar4 => u64::from(self.id_isar4.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::IdIsar5 => u64::from(self.id_isar5.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::IdIsar6 => u64::from(self.id_isar6.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::IdMmfr0 => u64::from(self.id_mmfr0.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::IdMmfr1 => u64::from(self.id_mmfr1.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::IdMmfr2 => u64::from(self.id_mmfr2.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::IdMmfr3 => u64::from(self.id_mmfr3.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::IdMmfr4 => u64::from(self.id_mmfr4.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::IdMmfr5 => u64::from(self.id_mmfr5.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::IdPfr0 => u64::from(self.id_pfr0.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::IdPfr1 => u64::from(self.id_pfr1.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::IdPfr2 => u64::from(self.id_pfr2.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Ifar => u64::from(self.ifar.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Ifsr => u64::from(self.ifsr.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Isr => u64::from(self.isr.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::IsrEl1 => self.isr_el1.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::Jidr => u64::from(self.jidr),
+            #[cfg(feature = "el1")]
+            SysregId::Jmcr => u64::from(self.jmcr),
+            #[cfg(feature = "el1")]
+            SysregId::Joscr => u64::from(self.joscr),
+            #[cfg(feature = "el1")]
+            SysregId::Mair0 => u64::from(self.mair0.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Mair1 => u64::from(self.mair1.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::MairEl1 => self.mair_el1.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::MairEl2 => self.mair_el2.bits(),
+            #[cfg(feature = "el3")]
+            SysregId::MairEl3 => self.mair_el3.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::MdccintEl1 => self.mdccint_el1.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::MdcrEl2 => self.mdcr_el2.bits(),
+            #[cfg(feature = "el3")]
+            SysregId::MdcrEl3 => self.mdcr_el3.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::MdscrEl1 => self.mdscr_el1.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::Midr => u64::from(self.midr.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::MidrEl1 => self.midr_el1.bits(),
+            #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+            SysregId::Mpam2El2 => self.mpam2_el2.bits(),
+            #[cfg(all(feature = "el3", feature = "feat-mpam"))]
+            SysregId::Mpam3El3 => self.mpam3_el3.bits(),
+            #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+            SysregId::MpamhcrEl2 => self.mpamhcr_el2.bits(),
+            #[cfg(all(feature = "el1", feature = "feat-mpam"))]
+            SysregId::MpamidrEl1 => self.mpamidr_el1.bits(),
+            #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+            SysregId::Mpamvpm0El2 => self.mpamvpm0_el2.bits(),
+            #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+            SysregId::Mpamvpm1El2 => self.mpamvpm1_el2.bits(),
+            #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+            SysregId::Mpamvpm2El2 => self.mpamvpm2_el2.bits(),
+            #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+            SysregId::Mpamvpm3El2 => self.mpamvpm3_el2.bits(),
+            #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+            SysregId::Mpamvpm4El2 => self.mpamvpm4_el2.bits(),
+            #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+            SysregId::Mpamvpm5El2 => self.mpamvpm5_el2.bits(),
+            #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+            SysregId::Mpamvpm6El2 => self.mpamvpm6_el2.bits(),
+            #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+            SysregId::Mpamvpm7El2 => self.mpamvpm7_el2.bits(),
+            #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+            SysregId::MpamvpmvEl2 => self.mpamvpmv_el2.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::Mpidr => u64::from(self.mpidr.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::MpidrEl1 => self.mpidr_el1.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::Mvbar => u64::from(self.mvbar.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Nmrr => u64::from(self.nmrr.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Nsacr => u64::from(self.nsacr.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Par => self.par.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::ParEl1 => self.par_el1.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::PfarEl1 => self.pfar_el1.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::PfarEl2 => self.pfar_el2.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::Pire0El1 => self.pire0_el1.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::Pire0El2 => self.pire0_el2.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::PirEl1 => self.pir_el1.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::PirEl2 => self.pir_el2.bits(),
+            #[cfg(feature = "el3")]
+            SysregId::PirEl3 => self.pir_el3.bits(),
+            SysregId::Pmccfiltr => u64::from(self.pmccfiltr.bits()),
+            SysregId::Pmccntr => self.pmccntr.bits(),
+            SysregId::Pmceid0 => u64::from(self.pmceid0.bits()),
+            SysregId::Pmceid1 => u64::from(self.pmceid1.bits()),
+            SysregId::Pmceid2 => u64::from(self.pmceid2.bits()),
+            SysregId::Pmceid3 => u64::from(self.pmceid3.bits()),
+            SysregId::Pmcntenclr => u64::from(self.pmcntenclr.bits()),
+            SysregId::Pmcntenset => u64::from(self.pmcntenset.bits()),
+            SysregId::Pmcr => u64::from(self.pmcr.bits()),
+            #[cfg(feature = "feat-pmu")]
+            SysregId::PmcrEl0 => self.pmcr_el0.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::Pmintenclr => u64::from(self.pmintenclr.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Pmintenset => u64::from(self.pmintenset.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Pmmir => u64::from(self.pmmir.bits()),
+            SysregId::Pmovsr => u64::from(self.pmovsr.bits()),
+            SysregId::Pmovsset => u64::from(self.pmovsset.bits()),
+            SysregId::Pmselr => u64::from(self.pmselr.bits()),
+            SysregId::Pmswinc => u64::from(self.pmswinc.bits()),
+            SysregId::Pmuserenr => u64::from(self.pmuserenr.bits()),
+            SysregId::Pmxevtyper => u64::from(self.pmxevtyper.bits()),
+            SysregId::PorEl0 => self.por_el0.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::PorEl1 => self.por_el1.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::PorEl2 => self.por_el2.bits(),
+            #[cfg(feature = "el3")]
+            SysregId::PorEl3 => self.por_el3.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::Prrr => u64::from(self.prrr.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Revidr => u64::from(self.revidr),
+            #[cfg(feature = "el1")]
+            SysregId::RgsrEl1 => self.rgsr_el1.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::Rmr => u64::from(self.rmr.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Rvbar => u64::from(self.rvbar.bits()),
+            #[cfg(feature = "el2")]
+            SysregId::S2pirEl2 => self.s2pir_el2.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::S2porEl1 => self.s2por_el1.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::Scr => u64::from(self.scr.bits()),
+            #[cfg(feature = "el3")]
+            SysregId::ScrEl3 => self.scr_el3.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::Sctlr => u64::from(self.sctlr.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Sctlr2El1 => self.sctlr2_el1.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::Sctlr2El2 => self.sctlr2_el2.bits(),
+            #[cfg(feature = "el3")]
+            SysregId::Sctlr2El3 => self.sctlr2_el3.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::SctlrEl1 => self.sctlr_el1.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::SctlrEl2 => self.sctlr_el2.bits(),
+            #[cfg(feature = "el3")]
+            SysregId::SctlrEl3 => self.sctlr_el3.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::Sdcr => u64::from(self.sdcr.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Sder => u64::from(self.sder.bits()),
+            #[cfg(all(feature = "el3", feature = "feat-sve"))]
+            SysregId::SmcrEl3 => self.smcr_el3.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::SpsrEl1 => self.spsr_el1.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::SpsrEl2 => self.spsr_el2.bits(),
+            #[cfg(feature = "el3")]
+            SysregId::SpsrEl3 => self.spsr_el3.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::SpEl1 => self.sp_el1.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::SpEl2 => self.sp_el2.bits(),
+            #[cfg(feature = "feat-sve")]
+            SysregId::Svcr => self.svcr.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::Tcmtr => u64::from(self.tcmtr),
+            #[cfg(feature = "el1")]
+            SysregId::Tcr2El1 => self.tcr2_el1.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::Tcr2El2 => self.tcr2_el2.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::TcrEl1 => self.tcr_el1.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::TcrEl2 => self.tcr_el2.bits(),
+            #[cfg(feature = "el3")]
+            SysregId::TcrEl3 => self.tcr_el3.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::Tfsre0El1 => self.tfsre0_el1.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::TfsrEl1 => self.tfsr_el1.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::TfsrEl2 => self.tfsr_el2.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::Tlbtr => u64::from(self.tlbtr.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Tpidrprw => u64::from(self.tpidrprw.bits()),
+            SysregId::TpidrroEl0 => self.tpidrro_el0.bits(),
+            SysregId::Tpidruro => u64::from(self.tpidruro.bits()),
+            SysregId::Tpidrurw => u64::from(self.tpidrurw.bits()),
+            SysregId::TpidrEl0 => self.tpidr_el0.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::TpidrEl1 => self.tpidr_el1.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::TpidrEl2 => self.tpidr_el2.bits(),
+            #[cfg(feature = "el3")]
+            SysregId::TpidrEl3 => self.tpidr_el3.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::Trfcr => u64::from(self.trfcr.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Ttbcr => u64::from(self.ttbcr.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Ttbcr2 => u64::from(self.ttbcr2.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Ttbr0 => self.ttbr0.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::Ttbr0El1 => self.ttbr0_el1.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::Ttbr0El2 => self.ttbr0_el2.bits(),
+            #[cfg(feature = "el3")]
+            SysregId::Ttbr0El3 => self.ttbr0_el3.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::Ttbr1 => self.ttbr1.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::Ttbr1El1 => self.ttbr1_el1.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::Ttbr1El2 => self.ttbr1_el2.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::Vbar => u64::from(self.vbar.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::VbarEl1 => self.vbar_el1.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::VbarEl2 => self.vbar_el2.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::Vdfsr => u64::from(self.vdfsr.bits()),
+            #[cfg(feature = "el1")]
+            SysregId::Vdisr => u64::from(self.vdisr.bits()),
+            #[cfg(all(feature = "el2", feature = "feat-ras"))]
+            SysregId::VdisrEl2 => self.vdisr_el2.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::Vmpidr => u64::from(self.vmpidr.bits()),
+            #[cfg(feature = "el2")]
+            SysregId::VmpidrEl2 => self.vmpidr_el2.bits(),
+            #[cfg(feature = "el1")]
+            SysregId::Vpidr => u64::from(self.vpidr.bits()),
+            #[cfg(feature = "el2")]
+            SysregId::VpidrEl2 => self.vpidr_el2.bits(),
+            #[cfg(all(feature = "el2", feature = "feat-ras"))]
+            SysregId::VsesrEl2 => self.vsesr_el2.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::Vtcr => u64::from(self.vtcr.bits()),
+            #[cfg(feature = "el2")]
+            SysregId::VtcrEl2 => self.vtcr_el2.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::Vttbr => self.vttbr.bits(),
+            #[cfg(feature = "el2")]
+            SysregId::VttbrEl2 => self.vttbr_el2.bits(),
+            #[cfg(all(feature = "el3", feature = "feat-sve"))]
+            SysregId::ZcrEl3 => self.zcr_el3.bits(),
+        }
+    }
+
+    /// Sets the fake register with the given ID, truncating the value to its width.
+    pub(crate) fn write_by_id(&mut self, id: SysregId, value: u64) {
+        match id {
+            #[cfg(feature = "el1")]
+            SysregId::Actlr => self.actlr = value as u32,
+            #[cfg(feature = "el1")]
+            SysregId::Actlr2 => self.actlr2 = value as u32,
+            #[cfg(feature = "el1")]
+            SysregId::ActlrEl1 => self.actlr_el1 = value,
+            #[cfg(feature = "el2")]
+            SysregId::ActlrEl2 => self.actlr_el2 = value,
+            #[cfg(feature = "el1")]
+            SysregId::Adfsr => self.adfsr = value as u32,
+            #[cfg(feature = "el1")]
+            SysregId::Afsr0El1 => self.afsr0_el1 = value,
+            #[cfg(feature = "el2")]
+            SysregId::Afsr0El2 => self.afsr0_el2 = value,
+            #[cfg(feature = "el1")]
+            SysregId::Afsr1El1 => self.afsr1_el1 = value,
+            #[cfg(feature = "el2")]
+            SysregId::Afsr1El2 => self.afsr1_el2 = value,
+            #[cfg(feature = "el1")]
+            SysregId::Aidr => self.aidr = value as u32,
+            #[cfg(feature = "el1")]
+            SysregId::Aifsr => self.aifsr = value as u32,
+            #[cfg(feature = "el1")]
+            SysregId::Amair0 => self.amair0 = value as u32,
+            #[cfg(feature = "el1")]
+            SysregId::Amair1 => self.amair1 = value as u32,
+            #[cfg(feature = "el1")]
+            SysregId::AmairEl1 => self.amair_el1 = value,
+            #[cfg(feature = "el2")]
+            SysregId::AmairEl2 => self.amair_el2 = value,
+            SysregId::Amcfgr => self.amcfgr = Amcfgr::from_bits_retain(value as u32),
+            #[cfg(feature = "feat-amu")]
+            SysregId::AmcfgrEl0 => self.amcfgr_el0 = AmcfgrEl0::from_bits_retain(value),
+            SysregId::Amcgcr => self.amcgcr = Amcgcr::from_bits_retain(value as u32),
+            #[cfg(feature = "feat-amu")]
+            SysregId::AmcgcrEl0 => self.amcgcr_el0 = AmcgcrEl0::from_bits_retain(value),
+            SysregId::Amcntenclr0 => self.amcntenclr0 = Amcntenclr0::from_bits_retain(value as u32),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amcntenclr0El0 => {
+                self.amcntenclr0_el0 = Amcntenclr0El0::from_bits_retain(value)
+            }
+            SysregId::Amcntenclr1 => self.amcntenclr1 = Amcntenclr1::from_bits_retain(value as u32),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amcntenclr1El0 => {
+                self.amcntenclr1_el0 = Amcntenclr1El0::from_bits_retain(value)
+            }
+            SysregId::Amcntenset0 => self.amcntenset0 = Amcntenset0::from_bits_retain(value as u32),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amcntenset0El0 => {
+                self.amcntenset0_el0 = Amcntenset0El0::from_bits_retain(value)
+            }
+            SysregId::Amcntenset1 => self.amcntenset1 = Amcntenset1::from_bits_retain(value as u32),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amcntenset1El0 => {
+                self.amcntenset1_el0 = Amcntenset1El0::from_bits_retain(value)
+            }
+            SysregId::Amcr => self.amcr = Amcr::from_bits_retain(value as u32),
+            #[cfg(feature = "feat-amu")]
+            SysregId::AmcrEl0 => self.amcr_el0 = AmcrEl0::from_bits_retain(value),
+            SysregId::Amevcntr00 => self.amevcntr00 = Amevcntr00::from_bits_retain(value),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amevcntr00El0 => self.amevcntr00_el0 = Amevcntr00El0::from_bits_retain(value),
+            SysregId::Amevcntr01 => self.amevcntr01 = Amevcntr01::from_bits_retain(value),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amevcntr01El0 => self.amevcntr01_el0 = Amevcntr01El0::from_bits_retain(value),
+            SysregId::Amevcntr02 => self.amevcntr02 = Amevcntr02::from_bits_retain(value),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amevcntr02El0 => self.amevcntr02_el0 = Amevcntr02El0::from_bits_retain(value),
+            SysregId::Amevcntr03 => self.amevcntr03 = Amevcntr03::from_bits_retain(value),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amevcntr03El0 => self.amevcntr03_el0 = Amevcntr03El0::from_bits_retain(value),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amevcntr10El0 => self.amevcntr10_el0 = Amevcntr10El0::from_bits_retain(value),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amevcntr110El0 => {
+                self.amevcntr110_el0 = Amevcntr110El0::from_bits_retain(value)
+            }
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amevcntr111El0 => {
+                self.amevcntr111_el0 = Amevcntr111El0::from_bits_retain(value)
+            }
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amevcntr112El0 => {
+                self.amevcntr112_el0 = Amevcntr112El0::from_bits_retain(value)
+            }
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amevcntr113El0 => {
+                self.amevcntr113_el0 = Amevcntr113El0::from_bits_retain(value)
+            }
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amevcntr114El0 => {
+                self.amevcntr114_el0 = Amevcntr114El0::from_bits_retain(value)
+            }
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amevcntr115El0 => {
+                self.amevcntr115_el0 = Amevcntr115El0::from_bits_retain(value)
+            }
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amevcntr11El0 => self.amevcntr11_el0 = Amevcntr11El0::from_bits_retain(value),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amevcntr12El0 => self.amevcntr12_el0 = Amevcntr12El0::from_bits_retain(value),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amevcntr13El0 => self.amevcntr13_el0 = Amevcntr13El0::from_bits_retain(value),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amevcntr14El0 => self.amevcntr14_el0 = Amevcntr14El0::from_bits_retain(value),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amevcntr15El0 => self.amevcntr15_el0 = Amevcntr15El0::from_bits_retain(value),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amevcntr16El0 => self.amevcntr16_el0 = Amevcntr16El0::from_bits_retain(value),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amevcntr17El0 => self.amevcntr17_el0 = Amevcntr17El0::from_bits_retain(value),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amevcntr18El0 => self.amevcntr18_el0 = Amevcntr18El0::from_bits_retain(value),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amevcntr19El0 => self.amevcntr19_el0 = Amevcntr19El0::from_bits_retain(value),
+            SysregId::Amevtyper00 => self.amevtyper00 = Amevtyper00::from_bits_retain(value as u32),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amevtyper00El0 => {
+                self.amevtyper00_el0 = Amevtyper00El0::from_bits_retain(value)
+            }
+            SysregId::Amevtyper01 => self.amevtyper01 = Amevtyper01::from_bits_retain(value as u32),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amevtyper01El0 => {
+                self.amevtyper01_el0 = Amevtyper01El0::from_bits_retain(value)
+            }
+            SysregId::Amevtyper02 => self.amevtyper02 = Amevtyper02::from_bits_retain(value as u32),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amevtyper02El0 => {
+                self.amevtyper02_el0 = Amevtyper02El0::from_bits_retain(value)
+            }
+            SysregId::Amevtyper03 => self.amevtyper03 = Amevtyper03::from_bits_retain(value as u32),
+            #[cfg(feature = "feat-amu")]
+            SysregId::Amevtyper03El0 => {
+                self.amevtyper03_el0 = Amevtyper03El0::from_bits_retain(value)
+            }
+            SysregId::Amevtyper10 => self.amevtyper10 = Amevtyper10::from_bits_retain(value as u32),
+            SysregId::Amevtyper11 => self.amevtyper11 = Amevtyper11::from_bits_retain(value as u32),
+            SysregId::Amevtyper110 => {
+                self.amevtyper110 = Amevtyper110::from_bits_retain(value as u32)
+            }
+            SysregId::Amevtyper111 => {
+                self.amevtyper111 = Amevtyper111::from_bits_retain(value as u32)
+            }
+            SysregId::Amevtyper112 => {
+                self.amevtyper112 = Amevtyper112::from_bits_retain(value as u32)
+            }
+            SysregId::Amevtyper113 => {
+                self.amevtyper113 = Amevtyper113::from_bits_retain(value as u32)
+            }
+            SysregId::Amevtyper114 => {
+                self.amevtyper114 = Amevtyper114::from_bits_retain(value as u32)
+            }
+            SysregId::Amevtyper115 => {
+                self.amevtyper115 = Amevtyper115::from_bits_retain(value as u32)
+            }
+            SysregId::Amevtyper12 => self.amevtyper12 = Amevtyper12::from_bits_retain(value as u32),
+            SysregId::Amevtyper13 => self.amevtyper13 = Amevtyper13::from_bits_retain(value as u32),
+            SysregId::Amevtyper14 => self.amevtyper14 = Amevtyper14::from_bits_retain(value as u32),
+            SysregId::Amevtyper15 => self.amevtyper15 = Amevtyper15::from_bits_retain(value as u32),
+            SysregId::Amevtyper16 => self.amevtyper16 = Amevtyper16::from_bits_retain(value as u32),
+            SysregId::Amevtyper17 => self.amevtyper17 = Amevtyper17::from_bits_retain(value as u32),
+            SysregId::Amevtyper18 => self.amevtyper18 = Amevtyper18::from_bits_retain(value as u32),
+            SysregId::Amevtyper19 => self.amevtyper19 = Amevtyper19::from_bits_retain(value as u32),
+            SysregId::Amuserenr => self.amuserenr = Amuserenr::from_bits_retain(value as u32),
+            #[cfg(feature = "feat-amu")]
+            SysregId::AmuserenrEl0 => self.amuserenr_el0 = AmuserenrEl0::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::ApiakeyhiEl1 => self.apiakeyhi_el1 = ApiakeyhiEl1::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::ApiakeyloEl1 => self.apiakeylo_el1 = ApiakeyloEl1::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::BrbcrEl2 => self.brbcr_el2 = BrbcrEl2::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::Ccsidr => self.ccsidr = Ccsidr::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Ccsidr2 => self.ccsidr2 = Ccsidr2::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::CcsidrEl1 => self.ccsidr_el1 = CcsidrEl1::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::Clidr => self.clidr = Clidr::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::ClidrEl1 => self.clidr_el1 = ClidrEl1::from_bits_retain(value),
+            SysregId::Cntfrq => self.cntfrq = Cntfrq::from_bits_retain(value as u32),
+            SysregId::CntfrqEl0 => self.cntfrq_el0 = CntfrqEl0::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::Cnthctl => self.cnthctl = Cnthctl::from_bits_retain(value as u32),
+            #[cfg(feature = "el2")]
+            SysregId::CnthctlEl2 => self.cnthctl_el2 = CnthctlEl2::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::CnthpsCtl => self.cnthps_ctl = CnthpsCtl::from_bits_retain(value as u32),
+            #[cfg(feature = "el2")]
+            SysregId::CnthpsCtlEl2 => self.cnthps_ctl_el2 = CnthpsCtlEl2::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::CnthpsCval => self.cnthps_cval = CnthpsCval::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::CnthpsCvalEl2 => {
+                self.cnthps_cval_el2 = CnthpsCvalEl2::from_bits_retain(value)
+            }
+            #[cfg(feature = "el1")]
+            SysregId::CnthpsTval => self.cnthps_tval = CnthpsTval::from_bits_retain(value as u32),
+            #[cfg(feature = "el2")]
+            SysregId::CnthpsTvalEl2 => {
+                self.cnthps_tval_el2 = CnthpsTvalEl2::from_bits_retain(value)
+            }
+            #[cfg(feature = "el1")]
+            SysregId::CnthpCtl => self.cnthp_ctl = CnthpCtl::from_bits_retain(value as u32),
+            #[cfg(feature = "el2")]
+            SysregId::CnthpCtlEl2 => self.cnthp_ctl_el2 = CnthpCtlEl2::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::CnthpCval => self.cnthp_cval = CnthpCval::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::CnthpCvalEl2 => self.cnthp_cval_el2 = CnthpCvalEl2::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::CnthpTval => self.cnthp_tval = CnthpTval::from_bits_retain(value as u32),
+            #[cfg(feature = "el2")]
+            SysregId::CnthpTvalEl2 => self.cnthp_tval_el2 = CnthpTvalEl2::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::CnthvsCtl => self.cnthvs_ctl = CnthvsCtl::from_bits_retain(value as u32),
+            #[cfg(feature = "el2")]
+            SysregId::CnthvsCtlEl2 => self.cnthvs_ctl_el2 = CnthvsCtlEl2::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::CnthvsCval => self.cnthvs_cval = CnthvsCval::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::CnthvsCvalEl2 => {
+                self.cnthvs_cval_el2 = CnthvsCvalEl2::from_bits_retain(value)
+            }
+            #[cfg(feature = "el1")]
+            SysregId::CnthvsTval => self.cnthvs_tval = CnthvsTval::from_bits_retain(value as u32),
+            #[cfg(feature = "el2")]
+            SysregId::CnthvsTvalEl2 => {
+                self.cnthvs_tval_el2 = CnthvsTvalEl2::from_bits_retain(value)
+            }
+            #[cfg(feature = "el1")]
+            SysregId::CnthvCtl => self.cnthv_ctl = CnthvCtl::from_bits_retain(value as u32),
+            #[cfg(feature = "el2")]
+            SysregId::CnthvCtlEl2 => self.cnthv_ctl_el2 = CnthvCtlEl2::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::CnthvCval => self.cnthv_cval = CnthvCval::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::CnthvCvalEl2 => self.cnthv_cval_el2 = CnthvCvalEl2::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::CnthvTval => self.cnthv_tval = CnthvTval::from_bits_retain(value as u32),
+            #[cfg(feature = "el2")]
+            SysregId::CnthvTvalEl2 => self.cnthv_tval_el2 = CnthvTvalEl2::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::Cntkctl => self.cntkctl = Cntkctl::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::CntkctlEl1 => self.cntkctl_el1 = CntkctlEl1::from_bits_retain(value),
+            SysregId::Cntpct => self.cntpct = Cntpct::from_bits_retain(value),
+            SysregId::Cntpctss => self.cntpctss = Cntpctss::from_bits_retain(value),
+            SysregId::CntpctssEl0 => self.cntpctss_el0 = CntpctssEl0::from_bits_retain(value),
+            SysregId::CntpctEl0 => self.cntpct_el0 = CntpctEl0::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::CntpoffEl2 => self.cntpoff_el2 = CntpoffEl2::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::CntpsCtlEl1 => self.cntps_ctl_el1 = CntpsCtlEl1::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::CntpsCvalEl1 => self.cntps_cval_el1 = CntpsCvalEl1::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::CntpsTvalEl1 => self.cntps_tval_el1 = CntpsTvalEl1::from_bits_retain(value),
+            SysregId::CntpCtl => self.cntp_ctl = CntpCtl::from_bits_retain(value as u32),
+            SysregId::CntpCtlEl0 => self.cntp_ctl_el0 = CntpCtlEl0::from_bits_retain(value),
+            SysregId::CntpCval => self.cntp_cval = CntpCval::from_bits_retain(value),
+            SysregId::CntpCvalEl0 => self.cntp_cval_el0 = CntpCvalEl0::from_bits_retain(value),
+            SysregId::CntpTval => self.cntp_tval = CntpTval::from_bits_retain(value as u32),
+            SysregId::CntpTvalEl0 => self.cntp_tval_el0 = CntpTvalEl0::from_bits_retain(value),
+            SysregId::Cntvct => self.cntvct = Cntvct::from_bits_retain(value),
+            SysregId::Cntvctss => self.cntvctss = Cntvctss::from_bits_retain(value),
+            SysregId::CntvctssEl0 => self.cntvctss_el0 = CntvctssEl0::from_bits_retain(value),
+            SysregId::CntvctEl0 => self.cntvct_el0 = CntvctEl0::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::Cntvoff => self.cntvoff = Cntvoff::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::CntvoffEl2 => self.cntvoff_el2 = CntvoffEl2::from_bits_retain(value),
+            SysregId::CntvCtl => self.cntv_ctl = CntvCtl::from_bits_retain(value as u32),
+            SysregId::CntvCtlEl0 => self.cntv_ctl_el0 = CntvCtlEl0::from_bits_retain(value),
+            SysregId::CntvCval => self.cntv_cval = CntvCval::from_bits_retain(value),
+            SysregId::CntvCvalEl0 => self.cntv_cval_el0 = CntvCvalEl0::from_bits_retain(value),
+            SysregId::CntvTval => self.cntv_tval = CntvTval::from_bits_retain(value as u32),
+            SysregId::CntvTvalEl0 => self.cntv_tval_el0 = CntvTvalEl0::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::Contextidr => self.contextidr = Contextidr::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::ContextidrEl1 => self.contextidr_el1 = ContextidrEl1::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::ContextidrEl2 => self.contextidr_el2 = ContextidrEl2::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::Cpacr => self.cpacr = Cpacr::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::CpacrEl1 => self.cpacr_el1 = CpacrEl1::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::CptrEl2 => self.cptr_el2 = CptrEl2::from_bits_retain(value),
+            #[cfg(feature = "el3")]
+            SysregId::CptrEl3 => self.cptr_el3 = CptrEl3::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::Csselr => self.csselr = Csselr::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::CsselrEl1 => self.csselr_el1 = CsselrEl1::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::Ctr => self.ctr = Ctr::from_bits_retain(value as u32),
+            SysregId::CtrEl0 => self.ctr_el0 = CtrEl0::from_bits_retain(value),
+            SysregId::Currentel => self.currentel = Currentel::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::Dacr => self.dacr = Dacr::from_bits_retain(value as u32),
+            SysregId::Daif => self.daif = Daif::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::Dbgauthstatus => {
+                self.dbgauthstatus = Dbgauthstatus::from_bits_retain(value as u32)
+            }
+            #[cfg(feature = "el1")]
+            SysregId::Dbgclaimclr => self.dbgclaimclr = Dbgclaimclr::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Dbgclaimset => self.dbgclaimset = Dbgclaimset::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Dbgdccint => self.dbgdccint = Dbgdccint::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Dbgdevid => self.dbgdevid = Dbgdevid::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Dbgdevid1 => self.dbgdevid1 = Dbgdevid1::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Dbgdevid2 => self.dbgdevid2 = value as u32,
+            SysregId::Dbgdidr => self.dbgdidr = Dbgdidr::from_bits_retain(value as u32),
+            SysregId::Dbgdrar => self.dbgdrar = Dbgdrar::from_bits_retain(value),
+            SysregId::Dbgdsar => self.dbgdsar = value,
+            #[cfg(feature = "el1")]
+            SysregId::Dbgdscrext => self.dbgdscrext = Dbgdscrext::from_bits_retain(value as u32),
+            SysregId::Dbgdscrint => self.dbgdscrint = Dbgdscrint::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Dbgdtrrxext => self.dbgdtrrxext = Dbgdtrrxext::from_bits_retain(value as u32),
+            SysregId::Dbgdtrrxint => self.dbgdtrrxint = Dbgdtrrxint::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Dbgdtrtxext => self.dbgdtrtxext = Dbgdtrtxext::from_bits_retain(value as u32),
+            SysregId::Dbgdtrtxint => self.dbgdtrtxint = Dbgdtrtxint::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Dbgosdlr => self.dbgosdlr = Dbgosdlr::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Dbgoseccr => self.dbgoseccr = Dbgoseccr::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Dbgoslar => self.dbgoslar = Dbgoslar::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Dbgoslsr => self.dbgoslsr = Dbgoslsr::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Dbgprcr => self.dbgprcr = Dbgprcr::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Dbgvcr => self.dbgvcr = Dbgvcr::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Dbgwfar => self.dbgwfar = value as u32,
+            #[cfg(feature = "el1")]
+            SysregId::Dfar => self.dfar = Dfar::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Dfsr => self.dfsr = Dfsr::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Disr => self.disr = Disr::from_bits_retain(value as u32),
+            #[cfg(all(feature = "el1", feature = "feat-ras"))]
+            SysregId::DisrEl1 => self.disr_el1 = DisrEl1::from_bits_retain(value),
+            SysregId::Dit => self.dit = Dit::from_bits_retain(value),
+            SysregId::Dlr => self.dlr = Dlr::from_bits_retain(value as u32),
+            SysregId::Dspsr => self.dspsr = Dspsr::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Dspsr2 => self.dspsr2 = Dspsr2::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::ElrEl1 => self.elr_el1 = ElrEl1::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::ElrEl2 => self.elr_el2 = ElrEl2::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::ElrHyp => self.elr_hyp = ElrHyp::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Erridr => self.erridr = Erridr::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Errselr => self.errselr = Errselr::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Erxaddr => self.erxaddr = Erxaddr::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Erxaddr2 => self.erxaddr2 = Erxaddr2::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Erxctlr => self.erxctlr = Erxctlr::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Erxctlr2 => self.erxctlr2 = Erxctlr2::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Erxfr => self.erxfr = Erxfr::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Erxfr2 => self.erxfr2 = Erxfr2::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Erxmisc0 => self.erxmisc0 = Erxmisc0::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Erxmisc1 => self.erxmisc1 = Erxmisc1::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Erxmisc2 => self.erxmisc2 = Erxmisc2::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Erxmisc3 => self.erxmisc3 = Erxmisc3::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Erxmisc4 => self.erxmisc4 = Erxmisc4::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Erxmisc5 => self.erxmisc5 = Erxmisc5::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Erxmisc6 => self.erxmisc6 = Erxmisc6::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Erxmisc7 => self.erxmisc7 = Erxmisc7::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Erxstatus => self.erxstatus = Erxstatus::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::EsrEl1 => self.esr_el1 = EsrEl1::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::EsrEl2 => self.esr_el2 = EsrEl2::from_bits_retain(value),
+            #[cfg(feature = "el3")]
+            SysregId::EsrEl3 => self.esr_el3 = EsrEl3::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::FarEl1 => self.far_el1 = FarEl1::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::FarEl2 => self.far_el2 = FarEl2::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::Fcseidr => self.fcseidr = value as u32,
+            SysregId::Fpcr => self.fpcr = Fpcr::from_bits_retain(value),
+            SysregId::Fpmr => self.fpmr = Fpmr::from_bits_retain(value),
+            SysregId::Fpsr => self.fpsr = Fpsr::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::GcrEl1 => self.gcr_el1 = GcrEl1::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::GcscrEl1 => self.gcscr_el1 = GcscrEl1::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::GcscrEl2 => self.gcscr_el2 = GcscrEl2::from_bits_retain(value),
+            #[cfg(all(feature = "el3", feature = "feat-rme"))]
+            SysregId::GpccrEl3 => self.gpccr_el3 = GpccrEl3::from_bits_retain(value),
+            #[cfg(all(feature = "el3", feature = "feat-rme"))]
+            SysregId::GptbrEl3 => self.gptbr_el3 = GptbrEl3::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::Hacr => self.hacr = value as u32,
+            #[cfg(feature = "el2")]
+            SysregId::HacrEl2 => self.hacr_el2 = value,
+            #[cfg(feature = "el2")]
+            SysregId::Hactlr => self.hactlr = value as u32,
+            #[cfg(feature = "el2")]
+            SysregId::Hactlr2 => self.hactlr2 = value as u32,
+            #[cfg(feature = "el2")]
+            SysregId::Hadfsr => self.hadfsr = value as u32,
+            #[cfg(feature = "el2")]
+            SysregId::HafgrtrEl2 => self.hafgrtr_el2 = HafgrtrEl2::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::Haifsr => self.haifsr = value as u32,
+            #[cfg(feature = "el2")]
+            SysregId::Hamair0 => self.hamair0 = value as u32,
+            #[cfg(feature = "el2")]
+            SysregId::Hamair1 => self.hamair1 = value as u32,
+            #[cfg(feature = "el2")]
+            SysregId::Hcptr => self.hcptr = Hcptr::from_bits_retain(value as u32),
+            #[cfg(feature = "el2")]
+            SysregId::Hcr => self.hcr = Hcr::from_bits_retain(value as u32),
+            #[cfg(feature = "el2")]
+            SysregId::Hcr2 => self.hcr2 = Hcr2::from_bits_retain(value as u32),
+            #[cfg(feature = "el2")]
+            SysregId::HcrxEl2 => self.hcrx_el2 = HcrxEl2::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::HcrEl2 => self.hcr_el2 = HcrEl2::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::Hdcr => self.hdcr = Hdcr::from_bits_retain(value as u32),
+            #[cfg(feature = "el2")]
+            SysregId::Hdfar => self.hdfar = Hdfar::from_bits_retain(value as u32),
+            #[cfg(feature = "el2")]
+            SysregId::Hdfgrtr2El2 => self.hdfgrtr2_el2 = Hdfgrtr2El2::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::HdfgrtrEl2 => self.hdfgrtr_el2 = HdfgrtrEl2::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::Hdfgwtr2El2 => self.hdfgwtr2_el2 = Hdfgwtr2El2::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::HdfgwtrEl2 => self.hdfgwtr_el2 = HdfgwtrEl2::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::Hfgitr2El2 => self.hfgitr2_el2 = Hfgitr2El2::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::HfgitrEl2 => self.hfgitr_el2 = HfgitrEl2::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::Hfgrtr2El2 => self.hfgrtr2_el2 = Hfgrtr2El2::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::HfgrtrEl2 => self.hfgrtr_el2 = HfgrtrEl2::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::Hfgwtr2El2 => self.hfgwtr2_el2 = Hfgwtr2El2::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::HfgwtrEl2 => self.hfgwtr_el2 = HfgwtrEl2::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::Hifar => self.hifar = Hifar::from_bits_retain(value as u32),
+            #[cfg(feature = "el2")]
+            SysregId::Hmair0 => self.hmair0 = Hmair0::from_bits_retain(value as u32),
+            #[cfg(feature = "el2")]
+            SysregId::Hmair1 => self.hmair1 = Hmair1::from_bits_retain(value as u32),
+            #[cfg(feature = "el2")]
+            SysregId::Hpfar => self.hpfar = Hpfar::from_bits_retain(value as u32),
+            #[cfg(feature = "el2")]
+            SysregId::HpfarEl2 => self.hpfar_el2 = HpfarEl2::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::Hrmr => self.hrmr = Hrmr::from_bits_retain(value as u32),
+            #[cfg(feature = "el2")]
+            SysregId::Hsctlr => self.hsctlr = Hsctlr::from_bits_retain(value as u32),
+            #[cfg(feature = "el2")]
+            SysregId::Hsr => self.hsr = Hsr::from_bits_retain(value as u32),
+            #[cfg(feature = "el2")]
+            SysregId::Hstr => self.hstr = value as u32,
+            #[cfg(feature = "el2")]
+            SysregId::HstrEl2 => self.hstr_el2 = value,
+            #[cfg(feature = "el2")]
+            SysregId::Htcr => self.htcr = Htcr::from_bits_retain(value as u32),
+            #[cfg(feature = "el2")]
+            SysregId::Htpidr => self.htpidr = Htpidr::from_bits_retain(value as u32),
+            #[cfg(feature = "el2")]
+            SysregId::Htrfcr => self.htrfcr = Htrfcr::from_bits_retain(value as u32),
+            #[cfg(feature = "el2")]
+            SysregId::Httbr => self.httbr = Httbr::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::Hvbar => self.hvbar = Hvbar::from_bits_retain(value as u32),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccAp0r0El1 => self.icc_ap0r0_el1 = value,
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccAp0r1El1 => self.icc_ap0r1_el1 = value,
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccAp0r2El1 => self.icc_ap0r2_el1 = value,
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccAp0r3El1 => self.icc_ap0r3_el1 = value,
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccAp1r0El1 => self.icc_ap1r0_el1 = IccAp1r0El1::from_bits_retain(value),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccAp1r1El1 => self.icc_ap1r1_el1 = value,
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccAp1r2El1 => self.icc_ap1r2_el1 = value,
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccAp1r3El1 => self.icc_ap1r3_el1 = value,
+            #[cfg(feature = "el1")]
+            SysregId::IccAsgi1r => self.icc_asgi1r = IccAsgi1r::from_bits_retain(value),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccAsgi1rEl1 => self.icc_asgi1r_el1 = IccAsgi1rEl1::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::IccBpr0 => self.icc_bpr0 = IccBpr0::from_bits_retain(value as u32),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccBpr0El1 => self.icc_bpr0_el1 = IccBpr0El1::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::IccBpr1 => self.icc_bpr1 = IccBpr1::from_bits_retain(value as u32),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccBpr1El1 => self.icc_bpr1_el1 = IccBpr1El1::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::IccCtlr => self.icc_ctlr = IccCtlr::from_bits_retain(value as u32),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccCtlrEl1 => self.icc_ctlr_el1 = IccCtlrEl1::from_bits_retain(value),
+            #[cfg(all(feature = "el3", feature = "feat-gic"))]
+            SysregId::IccCtlrEl3 => self.icc_ctlr_el3 = IccCtlrEl3::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::IccDir => self.icc_dir = IccDir::from_bits_retain(value as u32),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccDirEl1 => self.icc_dir_el1 = IccDirEl1::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::IccEoir0 => self.icc_eoir0 = IccEoir0::from_bits_retain(value as u32),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccEoir0El1 => self.icc_eoir0_el1 = IccEoir0El1::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::IccEoir1 => self.icc_eoir1 = IccEoir1::from_bits_retain(value as u32),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccEoir1El1 => self.icc_eoir1_el1 = IccEoir1El1::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::IccHppir0 => self.icc_hppir0 = IccHppir0::from_bits_retain(value as u32),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccHppir0El1 => self.icc_hppir0_el1 = IccHppir0El1::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::IccHppir1 => self.icc_hppir1 = IccHppir1::from_bits_retain(value as u32),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccHppir1El1 => self.icc_hppir1_el1 = IccHppir1El1::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::IccHsre => self.icc_hsre = IccHsre::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::IccIar0 => self.icc_iar0 = IccIar0::from_bits_retain(value as u32),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccIar0El1 => self.icc_iar0_el1 = IccIar0El1::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::IccIar1 => self.icc_iar1 = IccIar1::from_bits_retain(value as u32),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccIar1El1 => self.icc_iar1_el1 = IccIar1El1::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::IccIgrpen0 => self.icc_igrpen0 = IccIgrpen0::from_bits_retain(value as u32),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccIgrpen0El1 => {
+                self.icc_igrpen0_el1 = IccIgrpen0El1::from_bits_retain(value)
+            }
+            #[cfg(feature = "el1")]
+            SysregId::IccIgrpen1 => self.icc_igrpen1 = IccIgrpen1::from_bits_retain(value as u32),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccIgrpen1El1 => {
+                self.icc_igrpen1_el1 = IccIgrpen1El1::from_bits_retain(value)
+            }
+            #[cfg(all(feature = "el3", feature = "feat-gic"))]
+            SysregId::IccIgrpen1El3 => {
+                self.icc_igrpen1_el3 = IccIgrpen1El3::from_bits_retain(value)
+            }
+            #[cfg(feature = "el3")]
+            SysregId::IccMctlr => self.icc_mctlr = IccMctlr::from_bits_retain(value as u32),
+            #[cfg(feature = "el3")]
+            SysregId::IccMgrpen1 => self.icc_mgrpen1 = IccMgrpen1::from_bits_retain(value as u32),
+            #[cfg(feature = "el3")]
+            SysregId::IccMsre => self.icc_msre = IccMsre::from_bits_retain(value as u32),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccNmiar1El1 => self.icc_nmiar1_el1 = IccNmiar1El1::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::IccPmr => self.icc_pmr = IccPmr::from_bits_retain(value as u32),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccPmrEl1 => self.icc_pmr_el1 = IccPmrEl1::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::IccRpr => self.icc_rpr = IccRpr::from_bits_retain(value as u32),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccRprEl1 => self.icc_rpr_el1 = IccRprEl1::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::IccSgi0r => self.icc_sgi0r = IccSgi0r::from_bits_retain(value),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccSgi0rEl1 => self.icc_sgi0r_el1 = IccSgi0rEl1::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::IccSgi1r => self.icc_sgi1r = IccSgi1r::from_bits_retain(value),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccSgi1rEl1 => self.icc_sgi1r_el1 = IccSgi1rEl1::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::IccSre => self.icc_sre = IccSre::from_bits_retain(value as u32),
+            #[cfg(all(feature = "el1", feature = "feat-gic"))]
+            SysregId::IccSreEl1 => self.icc_sre_el1 = IccSreEl1::from_bits_retain(value),
+            #[cfg(all(feature = "el2", feature = "feat-gic"))]
+            SysregId::IccSreEl2 => self.icc_sre_el2 = IccSreEl2::from_bits_retain(value),
+            #[cfg(all(feature = "el3", feature = "feat-gic"))]
+            SysregId::IccSreEl3 => self.icc_sre_el3 = IccSreEl3::from_bits_retain(value),
+            #[cfg(all(feature = "el2", feature = "feat-gic"))]
+            SysregId::IchHcrEl2 => self.ich_hcr_el2 = IchHcrEl2::from_bits_retain(value),
+            #[cfg(all(feature = "el2", feature = "feat-gic"))]
+            SysregId::IchVmcrEl2 => self.ich_vmcr_el2 = IchVmcrEl2::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::IdAa64dfr0El1 => {
+                self.id_aa64dfr0_el1 = IdAa64dfr0El1::from_bits_retain(value)
+            }
+            #[cfg(feature = "el1")]
+            SysregId::IdAa64dfr1El1 => {
+                self.id_aa64dfr1_el1 = IdAa64dfr1El1::from_bits_retain(value)
+            }
+            #[cfg(feature = "el1")]
+            SysregId::IdAa64isar1El1 => {
+                self.id_aa64isar1_el1 = IdAa64isar1El1::from_bits_retain(value)
+            }
+            #[cfg(feature = "el1")]
+            SysregId::IdAa64isar2El1 => {
+                self.id_aa64isar2_el1 = IdAa64isar2El1::from_bits_retain(value)
+            }
+            #[cfg(feature = "el1")]
+            SysregId::IdAa64mmfr0El1 => {
+                self.id_aa64mmfr0_el1 = IdAa64mmfr0El1::from_bits_retain(value)
+            }
+            #[cfg(feature = "el1")]
+            SysregId::IdAa64mmfr1El1 => {
+                self.id_aa64mmfr1_el1 = IdAa64mmfr1El1::from_bits_retain(value)
+            }
+            #[cfg(feature = "el1")]
+            SysregId::IdAa64mmfr2El1 => {
+                self.id_aa64mmfr2_el1 = IdAa64mmfr2El1::from_bits_retain(value)
+            }
+            #[cfg(feature = "el1")]
+            SysregId::IdAa64mmfr3El1 => {
+                self.id_aa64mmfr3_el1 = IdAa64mmfr3El1::from_bits_retain(value)
+            }
+            #[cfg(feature = "el1")]
+            SysregId::IdAa64mmfr4El1 => {
+                self.id_aa64mmfr4_el1 = IdAa64mmfr4El1::from_bits_retain(value)
+            }
+            #[cfg(feature = "el1")]
+            SysregId::IdAa64pfr0El1 => {
+                self.id_aa64pfr0_el1 = IdAa64pfr0El1::from_bits_retain(value)
+            }
+            #[cfg(feature = "el1")]
+            SysregId::IdAa64pfr1El1 => {
+                self.id_aa64pfr1_el1 = IdAa64pfr1El1::from_bits_retain(value)
+            }
+            #[cfg(feature = "el1")]
+            SysregId::IdAa64pfr2El1 => {
+                self.id_aa64pfr2_el1 = IdAa64pfr2El1::from_bits_retain(value)
+            }
+            #[cfg(feature = "el1")]
+            SysregId::IdAa64smfr0El1 => {
+                self.id_aa64smfr0_el1 = IdAa64smfr0El1::from_bits_retain(value)
+            }
+            #[cfg(feature = "el1")]
+            SysregId::IdAfr0 => self.id_afr0 = value as u32,
+            #[cfg(feature = "el1")]
+            SysregId::IdDfr0 => self.id_dfr0 = IdDfr0::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::IdDfr1 => self.id_dfr1 = IdDfr1::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::IdIsar0 => self.id_isar0 = IdIsar0::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::IdIsar1 => self.id_isar1 = IdIsar1::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::IdIsar2 => self.id_isar2 = IdIsar2::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::IdIsar3 => self.id_isar3 = IdIsar3::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::IdIsar4 => self.id_isar4 = IdIsar4::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::IdIsar5 => self.id_isar5 = IdIsar5::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::IdIsar6 => self.id_isar6 = IdIsar6::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::IdMmfr0 => self.id_mmfr0 = IdMmfr0::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::IdMmfr1 => self.id_mmfr1 = IdMmfr1::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::IdMmfr2 => self.id_mmfr2 = IdMmfr2::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::IdMmfr3 => self.id_mmfr3 = IdMmfr3::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::IdMmfr4 => self.id_mmfr4 = IdMmfr4::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::IdMmfr5 => self.id_mmfr5 = IdMmfr5::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::IdPfr0 => self.id_pfr0 = IdPfr0::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::IdPfr1 => self.id_pfr1 = IdPfr1::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::IdPfr2 => self.id_pfr2 = IdPfr2::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Ifar => self.ifar = Ifar::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Ifsr => self.ifsr = Ifsr::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Isr => self.isr = Isr::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::IsrEl1 => self.isr_el1 = IsrEl1::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::Jidr => self.jidr = value as u32,
+            #[cfg(feature = "el1")]
+            SysregId::Jmcr => self.jmcr = value as u32,
+            #[cfg(feature = "el1")]
+            SysregId::Joscr => self.joscr = value as u32,
+            #[cfg(feature = "el1")]
+            SysregId::Mair0 => self.mair0 = Mair0::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Mair1 => self.mair1 = Mair1::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::MairEl1 => self.mair_el1 = MairEl1::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::MairEl2 => self.mair_el2 = MairEl2::from_bits_retain(value),
+            #[cfg(feature = "el3")]
+            SysregId::MairEl3 => self.mair_el3 = MairEl3::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::MdccintEl1 => self.mdccint_el1 = MdccintEl1::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::MdcrEl2 => self.mdcr_el2 = MdcrEl2::from_bits_retain(value),
+            #[cfg(feature = "el3")]
+            SysregId::MdcrEl3 => self.mdcr_el3 = MdcrEl3::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::MdscrEl1 => self.mdscr_el1 = MdscrEl1::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::Midr => self.midr = Midr::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::MidrEl1 => self.midr_el1 = MidrEl1::from_bits_retain(value),
+            #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+            SysregId::Mpam2El2 => self.mpam2_el2 = Mpam2El2::from_bits_retain(value),
+            #[cfg(all(feature = "el3", feature = "feat-mpam"))]
+            SysregId::Mpam3El3 => self.mpam3_el3 = Mpam3El3::from_bits_retain(value),
+            #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+            SysregId::MpamhcrEl2 => self.mpamhcr_el2 = MpamhcrEl2::from_bits_retain(value),
+            #[cfg(all(feature = "el1", feature = "feat-mpam"))]
+            SysregId::MpamidrEl1 => self.mpamidr_el1 = MpamidrEl1::from_bits_retain(value),
+            #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+            SysregId::Mpamvpm0El2 => self.mpamvpm0_el2 = Mpamvpm0El2::from_bits_retain(value),
+            #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+            SysregId::Mpamvpm1El2 => self.mpamvpm1_el2 = Mpamvpm1El2::from_bits_retain(value),
+            #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+            SysregId::Mpamvpm2El2 => self.mpamvpm2_el2 = Mpamvpm2El2::from_bits_retain(value),
+            #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+            SysregId::Mpamvpm3El2 => self.mpamvpm3_el2 = Mpamvpm3El2::from_bits_retain(value),
+            #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+            SysregId::Mpamvpm4El2 => self.mpamvpm4_el2 = Mpamvpm4El2::from_bits_retain(value),
+            #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+            SysregId::Mpamvpm5El2 => self.mpamvpm5_el2 = Mpamvpm5El2::from_bits_retain(value),
+            #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+            SysregId::Mpamvpm6El2 => self.mpamvpm6_el2 = Mpamvpm6El2::from_bits_retain(value),
+            #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+            SysregId::Mpamvpm7El2 => self.mpamvpm7_el2 = Mpamvpm7El2::from_bits_retain(value),
+            #[cfg(all(feature = "el2", feature = "feat-mpam"))]
+            SysregId::MpamvpmvEl2 => self.mpamvpmv_el2 = MpamvpmvEl2::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::Mpidr => self.mpidr = Mpidr::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::MpidrEl1 => self.mpidr_el1 = MpidrEl1::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::Mvbar => self.mvbar = Mvbar::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Nmrr => self.nmrr = Nmrr::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Nsacr => self.nsacr = Nsacr::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Par => self.par = Par::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::ParEl1 => self.par_el1 = ParEl1::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::PfarEl1 => self.pfar_el1 = PfarEl1::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::PfarEl2 => self.pfar_el2 = PfarEl2::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::Pire0El1 => self.pire0_el1 = Pire0El1::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::Pire0El2 => self.pire0_el2 = Pire0El2::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::PirEl1 => self.pir_el1 = PirEl1::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::PirEl2 => self.pir_el2 = PirEl2::from_bits_retain(value),
+            #[cfg(feature = "el3")]
+            SysregId::PirEl3 => self.pir_el3 = PirEl3::from_bits_retain(value),
+            SysregId::Pmccfiltr => self.pmccfiltr = Pmccfiltr::from_bits_retain(value as u32),
+            SysregId::Pmccntr => self.pmccntr = Pmccntr::from_bits_retain(value),
+            SysregId::Pmceid0 => self.pmceid0 = Pmceid0::from_bits_retain(value as u32),
+            SysregId::Pmceid1 => self.pmceid1 = Pmceid1::from_bits_retain(value as u32),
+            SysregId::Pmceid2 => self.pmceid2 = Pmceid2::from_bits_retain(value as u32),
+            SysregId::Pmceid3 => self.pmceid3 = Pmceid3::from_bits_retain(value as u32),
+            SysregId::Pmcntenclr => self.pmcntenclr = Pmcntenclr::from_bits_retain(value as u32),
+            SysregId::Pmcntenset => self.pmcntenset = Pmcntenset::from_bits_retain(value as u32),
+            SysregId::Pmcr => self.pmcr = Pmcr::from_bits_retain(value as u32),
+            #[cfg(feature = "feat-pmu")]
+            SysregId::PmcrEl0 => self.pmcr_el0 = PmcrEl0::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::Pmintenclr => self.pmintenclr = Pmintenclr::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Pmintenset => self.pmintenset = Pmintenset::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Pmmir => self.pmmir = Pmmir::from_bits_retain(value as u32),
+            SysregId::Pmovsr => self.pmovsr = Pmovsr::from_bits_retain(value as u32),
+            SysregId::Pmovsset => self.pmovsset = Pmovsset::from_bits_retain(value as u32),
+            SysregId::Pmselr => self.pmselr = Pmselr::from_bits_retain(value as u32),
+            SysregId::Pmswinc => self.pmswinc = Pmswinc::from_bits_retain(value as u32),
+            SysregId::Pmuserenr => self.pmuserenr = Pmuserenr::from_bits_retain(value as u32),
+            SysregId::Pmxevtyper => self.pmxevtyper = Pmxevtyper::from_bits_retain(value as u32),
+            SysregId::PorEl0 => self.por_el0 = PorEl0::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::PorEl1 => self.por_el1 = PorEl1::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::PorEl2 => self.por_el2 = PorEl2::from_bits_retain(value),
+            #[cfg(feature = "el3")]
+            SysregId::PorEl3 => self.por_el3 = PorEl3::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::Prrr => self.prrr = Prrr::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Revidr => self.revidr = value as u32,
+            #[cfg(feature = "el1")]
+            SysregId::RgsrEl1 => self.rgsr_el1 = RgsrEl1::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::Rmr => self.rmr = Rmr::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Rvbar => self.rvbar = Rvbar::from_bits_retain(value as u32),
+            #[cfg(feature = "el2")]
+            SysregId::S2pirEl2 => self.s2pir_el2 = S2pirEl2::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::S2porEl1 => self.s2por_el1 = S2porEl1::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::Scr => self.scr = Scr::from_bits_retain(value as u32),
+            #[cfg(feature = "el3")]
+            SysregId::ScrEl3 => self.scr_el3 = ScrEl3::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::Sctlr => self.sctlr = Sctlr::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Sctlr2El1 => self.sctlr2_el1 = Sctlr2El1::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::Sctlr2El2 => self.sctlr2_el2 = Sctlr2El2::from_bits_retain(value),
+            #[cfg(feature = "el3")]
+            SysregId::Sctlr2El3 => self.sctlr2_el3 = Sctlr2El3::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::SctlrEl1 => self.sctlr_el1 = SctlrEl1::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::SctlrEl2 => self.sctlr_el2 = SctlrEl2::from_bits_retain(value),
+            #[cfg(feature = "el3")]
+            SysregId::SctlrEl3 => self.sctlr_el3 = SctlrEl3::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::Sdcr => self.sdcr = Sdcr::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Sder => self.sder = Sder::from_bits_retain(value as u32),
+            #[cfg(all(feature = "el3", feature = "feat-sve"))]
+            SysregId::SmcrEl3 => self.smcr_el3 = SmcrEl3::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::SpsrEl1 => self.spsr_el1 = SpsrEl1::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::SpsrEl2 => self.spsr_el2 = SpsrEl2::from_bits_retain(value),
+            #[cfg(feature = "el3")]
+            SysregId::SpsrEl3 => self.spsr_el3 = SpsrEl3::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::SpEl1 => self.sp_el1 = SpEl1::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::SpEl2 => self.sp_el2 = SpEl2::from_bits_retain(value),
+            #[cfg(feature = "feat-sve")]
+            SysregId::Svcr => self.svcr = Svcr::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::Tcmtr => self.tcmtr = value as u32,
+            #[cfg(feature = "el1")]
+            SysregId::Tcr2El1 => self.tcr2_el1 = Tcr2El1::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::Tcr2El2 => self.tcr2_el2 = Tcr2El2::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::TcrEl1 => self.tcr_el1 = TcrEl1::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::TcrEl2 => self.tcr_el2 = TcrEl2::from_bits_retain(value),
+            #[cfg(feature = "el3")]
+            SysregId::TcrEl3 => self.tcr_el3 = TcrEl3::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::Tfsre0El1 => self.tfsre0_el1 = Tfsre0El1::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::TfsrEl1 => self.tfsr_el1 = TfsrEl1::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::TfsrEl2 => self.tfsr_el2 = TfsrEl2::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::Tlbtr => self.tlbtr = Tlbtr::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Tpidrprw => self.tpidrprw = Tpidrprw::from_bits_retain(value as u32),
+            SysregId::TpidrroEl0 => self.tpidrro_el0 = TpidrroEl0::from_bits_retain(value),
+            SysregId::Tpidruro => self.tpidruro = Tpidruro::from_bits_retain(value as u32),
+            SysregId::Tpidrurw => self.tpidrurw = Tpidrurw::from_bits_retain(value as u32),
+            SysregId::TpidrEl0 => self.tpidr_el0 = TpidrEl0::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::TpidrEl1 => self.tpidr_el1 = TpidrEl1::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::TpidrEl2 => self.tpidr_el2 = TpidrEl2::from_bits_retain(value),
+            #[cfg(feature = "el3")]
+            SysregId::TpidrEl3 => self.tpidr_el3 = TpidrEl3::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::Trfcr => self.trfcr = Trfcr::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Ttbcr => self.ttbcr = Ttbcr::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Ttbcr2 => self.ttbcr2 = Ttbcr2::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Ttbr0 => self.ttbr0 = Ttbr0::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::Ttbr0El1 => self.ttbr0_el1 = Ttbr0El1::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::Ttbr0El2 => self.ttbr0_el2 = Ttbr0El2::from_bits_retain(value),
+            #[cfg(feature = "el3")]
+            SysregId::Ttbr0El3 => self.ttbr0_el3 = Ttbr0El3::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::Ttbr1 => self.ttbr1 = Ttbr1::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::Ttbr1El1 => self.ttbr1_el1 = Ttbr1El1::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::Ttbr1El2 => self.ttbr1_el2 = Ttbr1El2::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::Vbar => self.vbar = Vbar::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::VbarEl1 => self.vbar_el1 = VbarEl1::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::VbarEl2 => self.vbar_el2 = VbarEl2::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::Vdfsr => self.vdfsr = Vdfsr::from_bits_retain(value as u32),
+            #[cfg(feature = "el1")]
+            SysregId::Vdisr => self.vdisr = Vdisr::from_bits_retain(value as u32),
+            #[cfg(all(feature = "el2", feature = "feat-ras"))]
+            SysregId::VdisrEl2 => self.vdisr_el2 = VdisrEl2::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::Vmpidr => self.vmpidr = Vmpidr::from_bits_retain(value as u32),
+            #[cfg(feature = "el2")]
+            SysregId::VmpidrEl2 => self.vmpidr_el2 = VmpidrEl2::from_bits_retain(value),
+            #[cfg(feature = "el1")]
+            SysregId::Vpidr => self.vpidr = Vpidr::from_bits_retain(value as u32),
+            #[cfg(feature = "el2")]
+            SysregId::VpidrEl2 => self.vpidr_el2 = VpidrEl2::from_bits_retain(value),
+            #[cfg(all(feature = "el2", feature = "feat-ras"))]
+            SysregId::VsesrEl2 => self.vsesr_el2 = VsesrEl2::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::Vtcr => self.vtcr = Vtcr::from_bits_retain(value as u32),
+            #[cfg(feature = "el2")]
+            SysregId::VtcrEl2 => self.vtcr_el2 = VtcrEl2::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::Vttbr => self.vttbr = Vttbr::from_bits_retain(value),
+            #[cfg(feature = "el2")]
+            SysregId::VttbrEl2 => self.vttbr_el2 = VttbrEl2::from_bits_retain(value),
+            #[cfg(all(feature = "el3", feature = "feat-sve"))]
+            SysregId::ZcrEl3 => self.zcr_el3 = ZcrEl3::from_bits_retain(value),
+        }
+    }
 }
@@ -0,0 +1,87 @@
+// SPDX-FileCopyrightText: Copyright The arm-sysregs Contributors.
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Locking and tracking of the current PE for the fake system registers.
+//!
+//! These use `std` unless the `fakes-no-std` feature is enabled, in which case they use spin locks
+//! and a single current PE for the whole program instead. Unit tests of this crate always use
+//! `std`, as they run on several threads which each select their own PE.
+
+pub use self::imp::*;
+
+#[cfg(not(all(feature = "fakes-no-std", not(test))))]
+mod imp {
+    use crate::fake::BOOT_PE;
+    pub use std::sync::{LockResult, Mutex, MutexGuard};
+    use std::{cell::Cell, sync::PoisonError};
+
+    thread_local! {
+        static CURRENT_PE: Cell<u64> = const { Cell::new(BOOT_PE) };
+    }
+
+    /// Selects the simulated PE with the given MPIDR value, and returns the previously selected
+    /// one.
+    pub fn replace_current_pe(mpidr: u64) -> u64 {
+        CURRENT_PE.replace(mpidr)
+    }
+
+    /// Returns the MPIDR value of the simulated PE which the current thread is running on.
+    pub fn current_pe() -> u64 {
+        CURRENT_PE.get()
+    }
+
+    /// Locks the given mutex and converts the guard with `f`, keeping any poisoning.
+    pub fn lock_map<'a, T, U>(
+        mutex: &'a Mutex<T>,
+        f: impl FnOnce(MutexGuard<'a, T>) -> U,
+    ) -> LockResult<U> {
+        match mutex.lock() {
+            Ok(guard) => Ok(f(guard)),
+            Err(e) => Err(PoisonError::new(f(e.into_inner()))),
+        }
+    }
+
+    /// Locks the given mutex, ignoring poisoning.
+    pub fn lock_ignoring_poison<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+        mutex.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(all(feature = "fakes-no-std", not(test)))]
+mod imp {
+    use crate::fake::BOOT_PE;
+    use core::{convert::Infallible, mem};
+    pub use spin::{Mutex, MutexGuard};
+
+    /// The result of locking the fake system registers.
+    ///
+    /// Spin locks can't be poisoned, so this never fails.
+    pub type LockResult<T> = Result<T, Infallible>;
+
+    // A mutex rather than an `AtomicU64`, as not all targets have 64-bit atomics.
+    static CURRENT_PE: Mutex<u64> = Mutex::new(BOOT_PE);
+
+    /// Selects the simulated PE with the given MPIDR value, and returns the previously selected
+    /// one.
+    pub fn replace_current_pe(mpidr: u64) -> u64 {
+        mem::replace(&mut *CURRENT_PE.lock(), mpidr)
+    }
+
+    /// Returns the MPIDR value of the simulated PE which the program is running on.
+    pub fn current_pe() -> u64 {
+        *CURRENT_PE.lock()
+    }
+
+    /// Locks the given mutex and converts the guard with `f`.
+    pub fn lock_map<'a, T, U>(
+        mutex: &'a Mutex<T>,
+        f: impl FnOnce(MutexGuard<'a, T>) -> U,
+    ) -> LockResult<U> {
+        Ok(f(mutex.lock()))
+    }
+
+    /// Locks the given mutex.
+    pub fn lock_ignoring_poison<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+        mutex.lock()
+    }
+}
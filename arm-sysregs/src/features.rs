@@ -0,0 +1,104 @@
+// SPDX-FileCopyrightText: Copyright The arm-sysregs Contributors.
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Sets of optional architecture features.
+
+mod generated;
+
+pub use self::generated::Feature;
+use core::fmt::{self, Debug, Formatter};
+
+/// A set of optional architecture features which are implemented by a CPU.
+///
+/// Fields of some registers are RES0 unless the feature they belong to is implemented, so this is
+/// used to check and sanitise register values for a particular CPU.
+#[derive(Clone, Copy, Default, Eq, PartialEq)]
+pub struct CpuFeatures {
+    implemented: [u64; Feature::COUNT.div_ceil(64)],
+}
+
+impl CpuFeatures {
+    /// Returns an empty set of features.
+    pub const fn new() -> Self {
+        Self {
+            implemented: [0; Feature::COUNT.div_ceil(64)],
+        }
+    }
+
+    /// Returns whether the given feature is in the set.
+    pub const fn contains(&self, feature: Feature) -> bool {
+        let index = feature as usize;
+        self.implemented[index / 64] & (1 << (index % 64)) != 0
+    }
+
+    /// Adds the given feature to the set.
+    pub const fn insert(&mut self, feature: Feature) {
+        let index = feature as usize;
+        self.implemented[index / 64] |= 1 << (index % 64);
+    }
+
+    /// Removes the given feature from the set.
+    pub const fn remove(&mut self, feature: Feature) {
+        let index = feature as usize;
+        self.implemented[index / 64] &= !(1 << (index % 64));
+    }
+
+    /// Returns a copy of the set with the given feature added.
+    pub const fn with(mut self, feature: Feature) -> Self {
+        self.insert(feature);
+        self
+    }
+
+    /// Returns an iterator over the features in the set, in order.
+    pub fn iter(&self) -> impl Iterator<Item = Feature> + '_ {
+        Feature::ALL
+            .into_iter()
+            .filter(|&feature| self.contains(feature))
+    }
+}
+
+impl Debug for CpuFeatures {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl Extend<Feature> for CpuFeatures {
+    fn extend<T: IntoIterator<Item = Feature>>(&mut self, iter: T) {
+        for feature in iter {
+            self.insert(feature);
+        }
+    }
+}
+
+impl FromIterator<Feature> for CpuFeatures {
+    fn from_iter<T: IntoIterator<Item = Feature>>(iter: T) -> Self {
+        let mut features = Self::new();
+        features.extend(iter);
+        features
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_remove() {
+        let mut features = CpuFeatures::new();
+        assert!(!features.contains(Feature::Pauth));
+        features.insert(Feature::Pauth);
+        features.insert(Feature::Bti);
+        assert!(features.contains(Feature::Pauth));
+        assert_eq!(
+            features.iter().collect::<Vec<_>>(),
+            [Feature::Bti, Feature::Pauth]
+        );
+        assert_eq!(format!("{features:?}"), "{Bti, Pauth}");
+
+        features.remove(Feature::Pauth);
+        assert!(!features.contains(Feature::Pauth));
+        assert_eq!(features, [Feature::Bti].into_iter().collect());
+        assert_eq!(Feature::Bti.to_string(), "FEAT_BTI");
+    }
+}
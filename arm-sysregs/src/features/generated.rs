@@ -0,0 +1,126 @@
+// SPDX-FileCopyrightText: Copyright The arm-sysregs Contributors.
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated, do not edit manually.
+
+use core::fmt::{self, Display, Formatter};
+
+/// An optional architecture feature which registers or register fields may depend on.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[non_exhaustive]
+pub enum Feature {
+    /// `FEAT_AMUv1`.
+    Amuv1,
+    /// `FEAT_BRBE`.
+    Brbe,
+    /// `FEAT_BTI`.
+    Bti,
+    /// `FEAT_DIT`.
+    Dit,
+    /// `FEAT_FPMR`.
+    Fpmr,
+    /// `FEAT_GCS`.
+    Gcs,
+    /// `FEAT_GICv3`.
+    Gicv3,
+    /// `FEAT_HCX`.
+    Hcx,
+    /// `FEAT_MPAM`.
+    Mpam,
+    /// `FEAT_MTE2`.
+    Mte2,
+    /// `FEAT_PAuth`.
+    Pauth,
+    /// `FEAT_PFAR`.
+    Pfar,
+    /// `FEAT_PMUv3`.
+    Pmuv3,
+    /// `FEAT_RAS`.
+    Ras,
+    /// `FEAT_RME`.
+    Rme,
+    /// `FEAT_S1PIE`.
+    S1pie,
+    /// `FEAT_S1POE`.
+    S1poe,
+    /// `FEAT_S2PIE`.
+    S2pie,
+    /// `FEAT_S2POE`.
+    S2poe,
+    /// `FEAT_SCTLR2`.
+    Sctlr2,
+    /// `FEAT_SME`.
+    Sme,
+    /// `FEAT_SVE`.
+    Sve,
+    /// `FEAT_TCR2`.
+    Tcr2,
+}
+
+impl Feature {
+    /// The number of features.
+    pub const COUNT: usize = 23;
+
+    /// All features, in order.
+    pub const ALL: [Self; Self::COUNT] = [
+        Self::Amuv1,
+        Self::Brbe,
+        Self::Bti,
+        Self::Dit,
+        Self::Fpmr,
+        Self::Gcs,
+        Self::Gicv3,
+        Self::Hcx,
+        Self::Mpam,
+        Self::Mte2,
+        Self::Pauth,
+        Self::Pfar,
+        Self::Pmuv3,
+        Self::Ras,
+        Self::Rme,
+        Self::S1pie,
+        Self::S1poe,
+        Self::S2pie,
+        Self::S2poe,
+        Self::Sctlr2,
+        Self::Sme,
+        Self::Sve,
+        Self::Tcr2,
+    ];
+
+    /// Returns the architectural name of the feature, e.g. `FEAT_PAuth`.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Amuv1 => "FEAT_AMUv1",
+            Self::Brbe => "FEAT_BRBE",
+            Self::Bti => "FEAT_BTI",
+            Self::Dit => "FEAT_DIT",
+            Self::Fpmr => "FEAT_FPMR",
+            Self::Gcs => "FEAT_GCS",
+            Self::Gicv3 => "FEAT_GICv3",
+            Self::Hcx => "FEAT_HCX",
+            Self::Mpam => "FEAT_MPAM",
+            Self::Mte2 => "FEAT_MTE2",
+            Self::Pauth => "FEAT_PAuth",
+            Self::Pfar => "FEAT_PFAR",
+            Self::Pmuv3 => "FEAT_PMUv3",
+            Self::Ras => "FEAT_RAS",
+            Self::Rme => "FEAT_RME",
+            Self::S1pie => "FEAT_S1PIE",
+            Self::S1poe => "FEAT_S1POE",
+            Self::S2pie => "FEAT_S2PIE",
+            Self::S2poe => "FEAT_S2POE",
+            Self::Sctlr2 => "FEAT_SCTLR2",
+            Self::Sme => "FEAT_SME",
+            Self::Sve => "FEAT_SVE",
+            Self::Tcr2 => "FEAT_TCR2",
+        }
+    }
+}
+
+impl Display for Feature {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
@@ -0,0 +1,251 @@
+// SPDX-FileCopyrightText: Copyright The arm-sysregs Contributors.
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Generic access to the fields of system register values.
+
+use crate::{FieldOverflow, InvalidFieldValue};
+use bitflags::Flags;
+use core::{
+    fmt::{self, Debug, Formatter},
+    marker::PhantomData,
+};
+
+/// A field of the system register type `R`, `WIDTH` bits wide starting at bit `SHIFT`, whose value
+/// is represented as a `T`.
+///
+/// Each register type has a constant of this type for each of its fields, such as
+/// `SctlrEl1::EE_FIELD`, which can be passed to the methods of [`FieldAccess`].
+pub struct Field<R, T, const SHIFT: u32, const WIDTH: u32> {
+    register: &'static str,
+    name: &'static str,
+    _type: PhantomData<fn(R) -> T>,
+}
+
+impl<R, T, const SHIFT: u32, const WIDTH: u32> Field<R, T, SHIFT, WIDTH> {
+    /// Mask for the field, before it is shifted into place.
+    pub const MASK: u64 = if WIDTH == 64 {
+        u64::MAX
+    } else {
+        (1 << WIDTH) - 1
+    };
+
+    /// Creates a new descriptor for the field with the given name in the given system register,
+    /// which are used in errors.
+    pub const fn new(register: &'static str, name: &'static str) -> Self {
+        Self {
+            register,
+            name,
+            _type: PhantomData,
+        }
+    }
+
+    /// Returns the name of the system register, e.g. `SCTLR_EL1`.
+    pub const fn register(self) -> &'static str {
+        self.register
+    }
+
+    /// Returns the name of the field, e.g. `EE`.
+    pub const fn name(self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the offset of the field's least significant bit.
+    pub const fn shift(self) -> u32 {
+        SHIFT
+    }
+
+    /// Returns the width of the field in bits.
+    pub const fn width(self) -> u32 {
+        WIDTH
+    }
+
+    /// Returns the mask for the field, before it is shifted into place.
+    pub const fn mask(self) -> u64 {
+        Self::MASK
+    }
+
+    /// Returns the raw value of the field from the given raw register value.
+    pub const fn extract(self, bits: u64) -> u64 {
+        (bits >> SHIFT) & Self::MASK
+    }
+
+    /// Returns the given raw register value with the field set to the given raw value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value doesn't fit in the field.
+    pub const fn insert(self, bits: u64, value: u64) -> u64 {
+        assert!(value & Self::MASK == value);
+        (bits & !(Self::MASK << SHIFT)) | (value << SHIFT)
+    }
+
+    /// Returns the given raw register value with the field set to the given raw value, or an error
+    /// if the value doesn't fit in the field.
+    pub const fn try_insert(self, bits: u64, value: u64) -> Result<u64, FieldOverflow> {
+        if value & Self::MASK != value {
+            return Err(FieldOverflow {
+                register: self.register,
+                field: self.name,
+                index: None,
+                value,
+            });
+        }
+        Ok(self.insert(bits, value))
+    }
+
+    /// Returns an error for the given raw value not being valid for the field type.
+    pub(crate) const fn invalid_value(self, value: u64) -> InvalidFieldValue {
+        InvalidFieldValue {
+            register: self.register,
+            field: self.name,
+            index: None,
+            value,
+        }
+    }
+}
+
+impl<R, T, const SHIFT: u32, const WIDTH: u32> Clone for Field<R, T, SHIFT, WIDTH> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<R, T, const SHIFT: u32, const WIDTH: u32> Copy for Field<R, T, SHIFT, WIDTH> {}
+
+impl<R, T, const SHIFT: u32, const WIDTH: u32> Debug for Field<R, T, SHIFT, WIDTH> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Field({}.{}, {SHIFT}..{})",
+            self.register,
+            self.name,
+            SHIFT + WIDTH
+        )
+    }
+}
+
+/// A type which can represent the value of a register field.
+pub trait FieldValue: Sized {
+    /// Converts the raw field value to this type, or returns `None` if it isn't valid.
+    fn from_field_bits(bits: u64) -> Option<Self>;
+
+    /// Returns the raw field value.
+    fn to_field_bits(self) -> u64;
+}
+
+impl FieldValue for bool {
+    fn from_field_bits(bits: u64) -> Option<Self> {
+        Some(bits != 0)
+    }
+
+    fn to_field_bits(self) -> u64 {
+        self.into()
+    }
+}
+
+macro_rules! impl_field_value {
+    ($($type:ty),*) => {
+        $(
+            impl FieldValue for $type {
+                fn from_field_bits(bits: u64) -> Option<Self> {
+                    bits.try_into().ok()
+                }
+
+                fn to_field_bits(self) -> u64 {
+                    self.into()
+                }
+            }
+        )*
+    };
+}
+
+impl_field_value!(u8, u16, u32, u64);
+
+/// Generic methods to access the fields of a register value with [`Field`] constants.
+///
+/// This is implemented for all register types.
+pub trait FieldAccess: Flags + Copy
+where
+    Self::Bits: Into<u64> + TryFrom<u64>,
+{
+    /// Returns the value of the given field.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the field doesn't contain a valid value for its type.
+    fn get<T: FieldValue, const SHIFT: u32, const WIDTH: u32>(
+        self,
+        field: Field<Self, T, SHIFT, WIDTH>,
+    ) -> T {
+        T::from_field_bits(field.extract(self.bits().into())).expect("Invalid field value")
+    }
+
+    /// Sets the value of the given field.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value doesn't fit in the field.
+    fn set_field<T: FieldValue, const SHIFT: u32, const WIDTH: u32>(
+        &mut self,
+        field: Field<Self, T, SHIFT, WIDTH>,
+        value: T,
+    ) {
+        let bits = field.insert(self.bits().into(), value.to_field_bits());
+        // The field is within the register, so this can only fail if it was already out of range.
+        let Ok(bits) = bits.try_into() else {
+            panic!("Field out of range for register");
+        };
+        *self = Self::from_bits_retain(bits);
+    }
+
+    /// Returns a copy with the given field set to the given value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value doesn't fit in the field.
+    fn with<T: FieldValue, const SHIFT: u32, const WIDTH: u32>(
+        mut self,
+        field: Field<Self, T, SHIFT, WIDTH>,
+        value: T,
+    ) -> Self {
+        self.set_field(field, value);
+        self
+    }
+
+    /// Returns the value of the given field, or an error if it isn't valid for the field type.
+    fn try_get<T: FieldValue, const SHIFT: u32, const WIDTH: u32>(
+        self,
+        field: Field<Self, T, SHIFT, WIDTH>,
+    ) -> Result<T, InvalidFieldValue> {
+        let bits = field.extract(self.bits().into());
+        T::from_field_bits(bits).ok_or(field.invalid_value(bits))
+    }
+
+    /// Sets the value of the given field, or returns an error if the value doesn't fit.
+    fn try_set_field<T: FieldValue, const SHIFT: u32, const WIDTH: u32>(
+        &mut self,
+        field: Field<Self, T, SHIFT, WIDTH>,
+        value: T,
+    ) -> Result<(), FieldOverflow> {
+        let bits = field.try_insert(self.bits().into(), value.to_field_bits())?;
+        // The field is within the register, so this can only fail if it was already out of range.
+        let Ok(bits) = bits.try_into() else {
+            panic!("Field out of range for register");
+        };
+        *self = Self::from_bits_retain(bits);
+        Ok(())
+    }
+
+    /// Returns a copy with the given field set to the given value, or an error if the value doesn't
+    /// fit.
+    fn try_with<T: FieldValue, const SHIFT: u32, const WIDTH: u32>(
+        mut self,
+        field: Field<Self, T, SHIFT, WIDTH>,
+        value: T,
+    ) -> Result<Self, FieldOverflow> {
+        self.try_set_field(field, value)?;
+        Ok(self)
+    }
+}
+
+impl<R: Flags + Copy> FieldAccess for R where R::Bits: Into<u64> + TryFrom<u64> {}
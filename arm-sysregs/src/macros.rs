@@ -51,3 +51,39 @@ macro_rules! read_write_sysreg {
         }
     };
 }
+
+/// Reads the given system registers, returning a tuple of their values.
+///
+/// This must be called in an `unsafe` block if any of the registers is unsafe to read. On AArch64
+/// without fakes or the backend, all the registers are read in a single `asm!` block.
+#[cfg(any(
+    test,
+    feature = "fakes",
+    feature = "backend",
+    not(target_arch = "aarch64")
+))]
+#[macro_export]
+macro_rules! read_many {
+    ($($sysreg:ident),+ $(,)?) => {
+        ($( $crate::_paste::paste! { $crate::[< read_ $sysreg >]() }, )+)
+    };
+}
+
+/// Writes the given values to the given system registers, e.g.
+/// `write_many!(sctlr_el1 = sctlr, tcr_el1 = tcr)`.
+///
+/// This must be called in an `unsafe` block if any of the registers is unsafe to write. On AArch64
+/// without fakes or the backend, all the registers are written in a single `asm!` block.
+#[cfg(any(
+    test,
+    feature = "fakes",
+    feature = "backend",
+    not(target_arch = "aarch64")
+))]
+#[macro_export]
+macro_rules! write_many {
+    ($($sysreg:ident = $value:expr),+ $(,)?) => {{
+        let ($( $sysreg, )+) = ($( $value, )+);
+        $( $crate::_paste::paste! { $crate::[< write_ $sysreg >]($sysreg) }; )+
+    }};
+}
@@ -3,6 +3,9 @@
 
 //! Manually implemented methods for system register types.
 
+use crate::FieldValue;
+#[cfg(all(feature = "el3", feature = "feat-sve"))]
+use crate::SmcrEl3;
 #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
 use crate::read_mpidr_el1;
 #[cfg(feature = "el1")]
@@ -14,9 +17,13 @@ use crate::{
 #[cfg(feature = "el2")]
 use crate::{EsrEl2, SpsrEl2};
 #[cfg(feature = "el3")]
-use crate::{EsrEl3, MdcrEl3, SmcrEl3, SpsrEl3};
+use crate::{EsrEl3, MdcrEl3, SpsrEl3};
 #[cfg(feature = "el1")]
-use core::fmt::{self, Debug, Formatter};
+use core::fmt::Debug;
+use core::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 
 #[cfg(feature = "el1")]
@@ -25,7 +32,7 @@ impl ClidrEl1 {
     pub fn icb_level(self) -> Option<CacheLevel> {
         let icb = self.icb();
         if icb != 0 {
-            Some(CacheLevel(icb as u8))
+            Some(CacheLevel(icb))
         } else {
             None
         }
@@ -33,7 +40,7 @@ impl ClidrEl1 {
 
     /// Returns Cache Type [1-7] fields.
     pub fn cache_type(self, level: CacheLevel) -> CacheType {
-        self.ctype(level.level().into()).try_into().unwrap()
+        self.ctype(level.level().into())
     }
 }
 
@@ -68,7 +75,7 @@ impl EsrEl1 {
 #[cfg(feature = "el1")]
 impl Debug for EsrEl1 {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "EsrEl1({:#x})", self.0)
+        write!(f, "EsrEl1({:#x})", self.bits())
     }
 }
 
@@ -81,7 +88,7 @@ impl EsrEl2 {
 #[cfg(feature = "el2")]
 impl Debug for EsrEl2 {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "EsrEl2({:#x})", self.0)
+        write!(f, "EsrEl2({:#x})", self.bits())
     }
 }
 
@@ -94,7 +101,7 @@ impl EsrEl3 {
 #[cfg(feature = "el3")]
 impl Debug for EsrEl3 {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "EsrEl3({:#x})", self.0)
+        write!(f, "EsrEl3({:#x})", self.bits())
     }
 }
 
@@ -376,11 +383,11 @@ impl MpidrEl1 {
     }
 }
 
-#[cfg(feature = "el3")]
+#[cfg(all(feature = "el3", feature = "feat-sve"))]
 impl SmcrEl3 {
     /// Build SMCR_EL3 register value from given SSVE vector length.
     pub fn from_ssve_vector_len(vector_length: u64) -> Self {
-        Self::from_bits_retain(((vector_length - 1) / 128) & Self::LEN_MASK)
+        Self::from_bits_retain(((vector_length - 1) / 128) & Self::LEN_FIELD.mask())
     }
 }
 
@@ -449,24 +456,76 @@ impl SpsrEl3 {
     }
 }
 
-/// Cache type enum.
-#[derive(Clone, Copy, Debug, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
-#[repr(u8)]
-pub enum CacheType {
-    /// No cache.
-    NoCache = 0b000,
-    /// Instruction cache only.
-    InstructionOnly = 0b001,
-    /// Data cache only.
-    DataOnly = 0b010,
-    /// Separate instruction and data caches.
-    SeparateInstructionAndData = 0b011,
-    /// Unified cache.
-    Unified = 0b100,
+/// Defines a `u8` enum for the values of a register field, along with `const fn` `from_raw` and
+/// `to_raw` methods to convert from and to raw field values and a [`FieldValue`] implementation,
+/// all derived from the one list of variants.
+macro_rules! field_value_enum {
+    (
+        $(#[$attr:meta])*
+        $vis:vis enum $name:ident {
+            $($(#[$variant_attr:meta])* $variant:ident = $value:expr,)*
+        }
+    ) => {
+        $(#[$attr])*
+        #[repr(u8)]
+        $vis enum $name {
+            $($(#[$variant_attr])* $variant = $value,)*
+        }
+
+        impl $name {
+            #[doc = concat!(
+                "Converts a raw field value to a `",
+                stringify!($name),
+                "`, or returns `None` if it isn't valid."
+            )]
+            pub const fn from_raw(value: u8) -> Option<Self> {
+                $(
+                    if value == Self::$variant as u8 {
+                        return Some(Self::$variant);
+                    }
+                )*
+                None
+            }
+
+            /// Returns the raw field value.
+            pub const fn to_raw(self) -> u8 {
+                self as u8
+            }
+        }
+
+        impl FieldValue for $name {
+            fn from_field_bits(bits: u64) -> Option<Self> {
+                Self::from_raw(bits.try_into().ok()?)
+            }
+
+            fn to_field_bits(self) -> u64 {
+                self.to_raw().into()
+            }
+        }
+    };
+}
+
+field_value_enum! {
+    /// Cache type enum.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    pub enum CacheType {
+        /// No cache.
+        NoCache = 0b000,
+        /// Instruction cache only.
+        InstructionOnly = 0b001,
+        /// Data cache only.
+        DataOnly = 0b010,
+        /// Separate instruction and data caches.
+        SeparateInstructionAndData = 0b011,
+        /// Unified cache.
+        Unified = 0b100,
+    }
 }
 
 /// Wrapper type for describing cache level in a human readable format, e.g. L3 cache = `CacheLevel(3)`
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct CacheLevel(pub(crate) u8);
 
 impl CacheLevel {
@@ -496,6 +555,7 @@ impl From<CacheLevel> for u64 {
 
 /// An AArch64 exception level.
 #[derive(Copy, Clone, Debug, Eq, Ord, PartialEq, PartialOrd, TryFromPrimitive, IntoPrimitive)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[repr(u8)]
 pub enum ExceptionLevel {
     /// Exception level 0.
@@ -510,6 +570,7 @@ pub enum ExceptionLevel {
 
 /// Values for SPSEL.
 #[derive(Copy, Clone, Debug, Eq, Ord, PartialEq, PartialOrd, TryFromPrimitive, IntoPrimitive)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[repr(u8)]
 pub enum StackPointer {
     /// Use SP_EL0.
@@ -518,30 +579,177 @@ pub enum StackPointer {
     ElX = 1,
 }
 
-/// Allowed Shareability attributes.
-#[derive(Copy, Clone, Debug, Eq, Ord, PartialEq, PartialOrd, TryFromPrimitive, IntoPrimitive)]
-#[repr(u8)]
-pub enum Shareability {
-    /// Non-shareable.
-    Non = 0b00,
-    /// Outer-shareable.
-    Outer = 0b10,
-    /// Inner-shareable.
-    Inner = 0b11,
+field_value_enum! {
+    /// Allowed Shareability attributes.
+    #[derive(Copy, Clone, Debug, Eq, Ord, PartialEq, PartialOrd, TryFromPrimitive, IntoPrimitive)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    pub enum Shareability {
+        /// Non-shareable.
+        Non = 0b00,
+        /// Outer-shareable.
+        Outer = 0b10,
+        /// Inner-shareable.
+        Inner = 0b11,
+    }
 }
 
-/// Allowed Cacheability attributes.
-#[derive(Copy, Clone, Debug, Eq, Ord, PartialEq, PartialOrd, TryFromPrimitive, IntoPrimitive)]
-#[repr(u8)]
-pub enum Cacheability {
-    /// Normal memory, Non-cacheable.
-    Non = 0b00,
-    /// Normal memory, Write-Back Read-Allocate Write-Allocate Cacheable.
-    WriteBackAllocate = 0b01,
-    /// Normal memory, Write-Through Read-Allocate No Write-Allocate Cacheable.
-    WriteThrough = 0b10,
-    /// Normal memory, Write-Back Read-Allocate No Write-Allocate Cacheable.
-    WriteBackNoAllocate = 0b11,
+field_value_enum! {
+    /// Allowed Cacheability attributes.
+    #[derive(Copy, Clone, Debug, Eq, Ord, PartialEq, PartialOrd, TryFromPrimitive, IntoPrimitive)]
+    #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+    pub enum Cacheability {
+        /// Normal memory, Non-cacheable.
+        Non = 0b00,
+        /// Normal memory, Write-Back Read-Allocate Write-Allocate Cacheable.
+        WriteBackAllocate = 0b01,
+        /// Normal memory, Write-Through Read-Allocate No Write-Allocate Cacheable.
+        WriteThrough = 0b10,
+        /// Normal memory, Write-Back Read-Allocate No Write-Allocate Cacheable.
+        WriteBackNoAllocate = 0b11,
+    }
+}
+
+/// The value of a register field with a custom type, for formatting with `defmt`: either a valid
+/// value of the type, or the raw value if it isn't valid for the type.
+#[cfg(feature = "defmt")]
+pub(crate) enum DefmtField<T> {
+    Valid(T),
+    Invalid(u64),
+}
+
+#[cfg(feature = "defmt")]
+impl<T> DefmtField<T> {
+    /// Returns the given converted value if there is one, or otherwise the raw value.
+    pub(crate) fn new(raw: u64, value: Option<T>) -> Self {
+        match value {
+            Some(value) => Self::Valid(value),
+            None => Self::Invalid(raw),
+        }
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<T: defmt::Format> defmt::Format for DefmtField<T> {
+    fn format(&self, f: defmt::Formatter) {
+        match self {
+            Self::Valid(value) => value.format(f),
+            Self::Invalid(raw) => defmt::write!(f, "{=u64}", raw),
+        }
+    }
+}
+
+/// A change in the value of a system register or one of its fields, as returned by the `diff`
+/// method of each register type.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct FieldChange {
+    /// The name of the system register, e.g. `MPIDR_EL1`.
+    pub register: &'static str,
+    /// The name of the field which changed, or `None` for the bits outside all named fields, which
+    /// is all of them if the register has no named fields.
+    pub field: Option<&'static str>,
+    /// The old value of the field, or of the bits outside all named fields.
+    pub old: u64,
+    /// The new value of the field, or of the bits outside all named fields.
+    pub new: u64,
+}
+
+impl Display for FieldChange {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(self.register)?;
+        if let Some(field) = self.field {
+            write!(f, ".{field}")?;
+        }
+        write!(f, ": {:#x} -> {:#x}", self.old, self.new)
+    }
+}
+
+/// A field which is set although the CPU doesn't implement the features it depends on, as returned
+/// by the `validate` method of each register type.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct UnimplementedField {
+    /// The name of the system register, e.g. `SCTLR_EL1`.
+    pub register: &'static str,
+    /// The name of the field, with the array index if any.
+    pub field: &'static str,
+    /// The features which the field depends on, e.g. `FEAT_PAuth`.
+    pub requires: &'static str,
+}
+
+impl Display for UnimplementedField {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}.{} is set but requires {}",
+            self.register, self.field, self.requires
+        )
+    }
+}
+
+/// An error returned by the `try_set_*` and `try_with_*` methods of register types if the given
+/// value doesn't fit in the field, or the given index of an array field is out of range.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct FieldOverflow {
+    /// The name of the system register, e.g. `AMCFGR`.
+    pub register: &'static str,
+    /// The name of the field, without any array index.
+    pub field: &'static str,
+    /// The index of the field, if it is one of an array of fields.
+    ///
+    /// This may be out of range for the array, if that is the reason for the error.
+    pub index: Option<u32>,
+    /// The value which didn't fit.
+    pub value: u64,
+}
+
+impl Display for FieldOverflow {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Value {:#x} doesn't fit in ", self.value)?;
+        write_field_name(f, self.register, self.field, self.index)
+    }
+}
+
+impl Error for FieldOverflow {}
+
+/// An error returned by the `try_*` getters of register types if a field doesn't contain a valid
+/// value for its type, or the given index of an array field is out of range.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct InvalidFieldValue {
+    /// The name of the system register, e.g. `CLIDR_EL1`.
+    pub register: &'static str,
+    /// The name of the field, without any array index.
+    pub field: &'static str,
+    /// The index of the field, if it is one of an array of fields.
+    ///
+    /// This may be out of range for the array, if that is the reason for the error.
+    pub index: Option<u32>,
+    /// The raw value of the field, or 0 if the index is out of range.
+    pub value: u64,
+}
+
+impl Display for InvalidFieldValue {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Invalid value {:#x} for ", self.value)?;
+        write_field_name(f, self.register, self.field, self.index)
+    }
+}
+
+impl Error for InvalidFieldValue {}
+
+fn write_field_name(
+    f: &mut Formatter,
+    register: &str,
+    field: &str,
+    index: Option<u32>,
+) -> fmt::Result {
+    write!(f, "{register}.{field}")?;
+    if let Some(index) = index {
+        write!(f, "{index}")?;
+    }
+    Ok(())
 }
 
 #[cfg(test)]
@@ -563,6 +771,368 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(feature = "el1")]
+    fn display_mpidr_el1() {
+        assert_eq!(
+            MpidrEl1::from_bits_retain(0x12_4134_5678).to_string(),
+            "MpidrEl1 { Aff0: 120, Aff1: 86, Aff2: 52, MT: 1, U: 1, Aff3: 18 }"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "el1")]
+    fn display_clidr_el1() {
+        let clidr = ClidrEl1::from_bits_retain(0b111_100_001);
+        let display = clidr.to_string();
+        assert!(
+            display.starts_with(
+                "ClidrEl1 { Ctype1: InstructionOnly, Ctype2: Unified, Ctype3: 7, Ctype4: NoCache"
+            ),
+            "{display}"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "el1")]
+    fn diff_mpidr_el1() {
+        let old = MpidrEl1::from_bits_retain(0x12_4134_5678);
+        let new = MpidrEl1::from_bits_retain(0x1012_0134_5679);
+        let changes = old.diff(new, "MPIDR_EL1").collect::<Vec<_>>();
+        assert_eq!(
+            changes,
+            [
+                FieldChange {
+                    register: "MPIDR_EL1",
+                    field: Some("Aff0"),
+                    old: 0x78,
+                    new: 0x79,
+                },
+                FieldChange {
+                    register: "MPIDR_EL1",
+                    field: Some("U"),
+                    old: 1,
+                    new: 0,
+                },
+                FieldChange {
+                    register: "MPIDR_EL1",
+                    field: None,
+                    old: 0,
+                    new: 0x1000_0000_0000,
+                },
+            ]
+        );
+        assert_eq!(changes[0].to_string(), "MPIDR_EL1.Aff0: 0x78 -> 0x79");
+        assert_eq!(changes[2].to_string(), "MPIDR_EL1: 0x0 -> 0x100000000000");
+        assert_eq!(old.diff(old, "MPIDR_EL1").count(), 0);
+    }
+
+    #[test]
+    fn try_set_amcfgr() {
+        let mut amcfgr = crate::Amcfgr::empty().try_with_n(0x12).unwrap();
+        assert_eq!(amcfgr.n(), 0x12);
+        let error = amcfgr.try_set_size(0x40).unwrap_err();
+        assert_eq!(
+            error,
+            crate::FieldOverflow {
+                register: "AMCFGR",
+                field: "SIZE",
+                index: None,
+                value: 0x40,
+            }
+        );
+        assert_eq!(error.to_string(), "Value 0x40 doesn't fit in AMCFGR.SIZE");
+        assert_eq!(amcfgr.bits(), 0x12);
+    }
+
+    #[test]
+    #[cfg(feature = "el1")]
+    fn try_ctype_clidr_el1() {
+        let clidr = ClidrEl1::from_bits_retain(0b111_100_001);
+        assert_eq!(clidr.try_ctype(2), Ok(CacheType::Unified));
+        let error = clidr.try_ctype(3).unwrap_err();
+        assert_eq!(
+            error,
+            InvalidFieldValue {
+                register: "CLIDR_EL1",
+                field: "Ctype",
+                index: Some(3),
+                value: 7,
+            }
+        );
+        assert_eq!(error.to_string(), "Invalid value 0x7 for CLIDR_EL1.Ctype3");
+        assert_eq!(
+            clidr.try_ctype(99),
+            Err(InvalidFieldValue {
+                register: "CLIDR_EL1",
+                field: "Ctype",
+                index: Some(99),
+                value: 0,
+            })
+        );
+
+        let mut clidr = ClidrEl1::empty();
+        assert_eq!(
+            clidr.try_set_ctype(99, CacheType::Unified),
+            Err(FieldOverflow {
+                register: "CLIDR_EL1",
+                field: "Ctype",
+                index: Some(99),
+                value: 4,
+            })
+        );
+        assert_eq!(clidr, ClidrEl1::empty());
+    }
+
+    #[test]
+    #[cfg(feature = "el1")]
+    fn typed_setters_clidr_el1() {
+        let clidr = ClidrEl1::empty()
+            .with_ctype(1, CacheType::SeparateInstructionAndData)
+            .with_ctype(2, CacheType::Unified);
+        assert_eq!(clidr.bits(), 0b100_011);
+        assert_eq!(clidr.ctype(1), CacheType::SeparateInstructionAndData);
+        assert_eq!(clidr.ctype(2), CacheType::Unified);
+        assert_eq!(clidr.ctype(3), CacheType::NoCache);
+    }
+
+    #[test]
+    #[cfg(all(feature = "el3", feature = "feat-rme"))]
+    fn const_typed_accessors_gpccr_el3() {
+        use crate::GpccrEl3;
+
+        const GPCCR: GpccrEl3 = GpccrEl3::empty()
+            .with_sh(Shareability::Inner)
+            .with_irgn(Cacheability::WriteBackAllocate);
+        const SH: Shareability = GPCCR.sh();
+        assert_eq!(SH, Shareability::Inner);
+        assert_eq!(GPCCR.irgn(), Cacheability::WriteBackAllocate);
+        assert_eq!(
+            GpccrEl3::empty().with_sh(Shareability::Outer).try_sh(),
+            Ok(Shareability::Outer)
+        );
+        assert!(
+            GpccrEl3::from_bits_retain(1 << GpccrEl3::SH_FIELD.shift())
+                .try_sh()
+                .is_err()
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "el3", feature = "feat-rme"))]
+    fn typed_setters_gpccr_el3() {
+        use crate::GpccrEl3;
+        let mut gpccr = GpccrEl3::empty().with_irgn(Cacheability::WriteBackAllocate);
+        gpccr.set_orgn(Cacheability::WriteThrough);
+        gpccr.set_sh(Shareability::Inner);
+        assert_eq!(gpccr.irgn(), Cacheability::WriteBackAllocate);
+        assert_eq!(gpccr.orgn(), Cacheability::WriteThrough);
+        assert_eq!(gpccr.sh(), Shareability::Inner);
+    }
+
+    #[test]
+    #[cfg(feature = "el1")]
+    fn sanitize_mpidr_el1() {
+        let mpidr = MpidrEl1::from_bits_retain(0xff00_0000_2200_0001);
+        assert!(!mpidr.is_valid(None));
+        let sanitized = mpidr.sanitize(None);
+        assert_eq!(sanitized.bits(), 0x8000_0001);
+        assert!(sanitized.is_valid(None));
+    }
+
+    #[test]
+    #[cfg(feature = "el1")]
+    fn sanitize_sctlr_el1_features() {
+        use crate::{CpuFeatures, Feature, SctlrEl1};
+
+        let sctlr = SctlrEl1::M | SctlrEl1::ENIA | SctlrEl1::BT0;
+        assert!(sctlr.is_valid(None));
+        assert_eq!(sctlr.sanitize(None), sctlr);
+
+        let features = CpuFeatures::new().with(Feature::Pauth);
+        assert!(!sctlr.is_valid(Some(&features)));
+        assert_eq!(
+            sctlr.sanitize(Some(&features)),
+            SctlrEl1::M | SctlrEl1::ENIA
+        );
+
+        let features = features.with(Feature::Bti);
+        assert!(sctlr.is_valid(Some(&features)));
+    }
+
+    #[test]
+    #[cfg(feature = "el1")]
+    fn par_el1_layouts() {
+        use crate::{ParEl1, ParEl1Fault, ParEl1Success};
+
+        let par = ParEl1::F.union(ParEl1::from_bits_retain(
+            0x10 << ParEl1Fault::FST_FIELD.shift(),
+        ));
+        assert_eq!(par.as_success(), None);
+        let fault = par.as_fault().unwrap();
+        assert_eq!(fault.fst(), 0x10);
+        assert_eq!(ParEl1::from(fault), par);
+
+        let par = ParEl1::from_bits_retain(0x1234_5000);
+        assert_eq!(par.as_fault(), None);
+        let success = par.as_success().unwrap();
+        assert_eq!(success.pa_47_12(), 0x12345);
+        assert_eq!(ParEl1::from(ParEl1Success::empty()), ParEl1::empty());
+        assert_eq!(ParEl1Fault::RES1_MASK, 1 << 11);
+    }
+
+    #[test]
+    #[cfg(feature = "el1")]
+    fn esr_el1_layouts() {
+        use crate::{EsrEl1, EsrEl1DataAbort};
+
+        // A 32-bit load from the same EL into X3 which faulted at level 3.
+        let esr = EsrEl1::from_bits_retain(0x9780_0007 | 3 << EsrEl1DataAbort::SRT_FIELD.shift());
+        assert_eq!(esr.as_svc(), None);
+        assert_eq!(esr.as_data_abort_lower(), None);
+        let data_abort = esr.as_data_abort().unwrap();
+        assert!(data_abort.contains(EsrEl1DataAbort::ISV));
+        assert_eq!(data_abort.sas(), 2);
+        assert_eq!(data_abort.srt(), 3);
+        assert!(!data_abort.contains(EsrEl1DataAbort::WNR));
+        assert_eq!(data_abort.dfsc(), 0x07);
+        assert_eq!(EsrEl1::from(data_abort), esr);
+
+        let svc = EsrEl1::from_bits_retain(0x5600_0042).as_svc().unwrap();
+        assert_eq!(svc.imm16(), 0x42);
+    }
+
+    #[test]
+    #[cfg(feature = "el2")]
+    fn cptr_el2_e2h_layouts() {
+        use crate::{CptrEl2, CptrEl2E2h0, CptrEl2E2h1};
+
+        let nvhe = CptrEl2::from(CptrEl2E2h0::TTA | CptrEl2E2h0::RES1);
+        assert_eq!(nvhe.bits(), 0x0010_22ff);
+        assert!(nvhe.e2h0().contains(CptrEl2E2h0::TTA));
+        assert!(!nvhe.e2h1().contains(CptrEl2E2h1::TTA));
+
+        let vhe = CptrEl2::from(CptrEl2E2h1::TTA.with_zen(0b11));
+        assert_eq!(vhe.bits(), 0x1003_0000);
+        assert_eq!(vhe.e2h1().zen(), 0b11);
+        assert!(!vhe.e2h0().contains(CptrEl2E2h0::TTA));
+    }
+
+    #[test]
+    #[cfg(feature = "el1")]
+    fn generic_field_access() {
+        use crate::{FieldAccess, MpidrEl1};
+
+        let mpidr = MpidrEl1::empty()
+            .with(MpidrEl1::AFF0_FIELD, 0x12)
+            .with(MpidrEl1::MT_FIELD, true);
+        assert_eq!(mpidr.bits(), 0x0100_0012);
+        assert_eq!(mpidr.get(MpidrEl1::AFF0_FIELD), 0x12);
+        assert_eq!(mpidr.aff0(), 0x12);
+        assert!(mpidr.get(MpidrEl1::MT_FIELD));
+        assert_eq!(MpidrEl1::AFF1_FIELD.shift(), 8);
+        assert_eq!(MpidrEl1::AFF1_FIELD.mask(), 0xff);
+
+        assert_eq!(MpidrEl1::AFF1_FIELD.register(), "MPIDR_EL1");
+        assert_eq!(MpidrEl1::AFF1_FIELD.name(), "Aff1");
+
+        let mut mpidr = mpidr;
+        mpidr.set_field(MpidrEl1::AFF0_FIELD, 0x34);
+        assert_eq!(mpidr.aff0(), 0x34);
+        assert_eq!(
+            mpidr
+                .try_with(MpidrEl1::AFF0_FIELD, 0x56)
+                .map(MpidrEl1::aff0),
+            Ok(0x56)
+        );
+        assert_eq!(mpidr.try_set_aff0(0x78).map(|()| mpidr.aff0()), Ok(0x78));
+
+        const MPIDR: Result<MpidrEl1, FieldOverflow> = MpidrEl1::empty().try_with_aff0(0x12);
+        assert_eq!(MPIDR.map(MpidrEl1::aff0), Ok(0x12));
+    }
+
+    #[test]
+    #[cfg(all(feature = "el3", feature = "feat-rme"))]
+    fn generic_field_access_custom_type() {
+        use crate::{FieldAccess, GpccrEl3};
+
+        let gpccr = GpccrEl3::empty().with(GpccrEl3::SH_FIELD, Shareability::Outer);
+        assert_eq!(gpccr.sh(), Shareability::Outer);
+        assert_eq!(gpccr.get(GpccrEl3::SH_FIELD), Shareability::Outer);
+        assert_eq!(
+            GpccrEl3::SH_FIELD.extract(0b01 << GpccrEl3::SH_FIELD.shift()),
+            0b01
+        );
+        assert_eq!(
+            GpccrEl3::from_bits_retain(0b01 << GpccrEl3::SH_FIELD.shift())
+                .try_get(GpccrEl3::SH_FIELD),
+            Err(InvalidFieldValue {
+                register: "GPCCR_EL3",
+                field: "SH",
+                index: None,
+                value: 0b01,
+            })
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(feature = "el1")]
+    fn generic_field_access_overflow() {
+        use crate::{Field, FieldAccess, MpidrEl1};
+
+        MpidrEl1::empty().with(Field::<MpidrEl1, u8, 0, 4>::new("MPIDR_EL1", "Aff0"), 0x10);
+    }
+
+    #[test]
+    #[cfg(feature = "el1")]
+    fn validate_sctlr_el1() {
+        use crate::{CpuFeatures, Feature, SctlrEl1, UnimplementedField};
+
+        let sctlr = SctlrEl1::M | SctlrEl1::ENIA;
+        let unimplemented = sctlr.validate(&CpuFeatures::new()).collect::<Vec<_>>();
+        assert_eq!(
+            unimplemented,
+            [UnimplementedField {
+                register: "SCTLR_EL1",
+                field: "EnIA",
+                requires: "FEAT_PAuth",
+            }]
+        );
+        assert_eq!(
+            unimplemented[0].to_string(),
+            "SCTLR_EL1.EnIA is set but requires FEAT_PAuth"
+        );
+        assert_eq!(
+            sctlr
+                .validate(&CpuFeatures::new().with(Feature::Pauth))
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "el1")]
+    fn validate_tcr2_el1() {
+        use crate::{CpuFeatures, Feature, Tcr2El1};
+
+        let tcr2 = Tcr2El1::PIE;
+        assert_eq!(
+            tcr2.validate(&CpuFeatures::new())
+                .map(|field| field.requires)
+                .collect::<Vec<_>>(),
+            ["FEAT_TCR2 && FEAT_S1PIE"]
+        );
+        assert_eq!(
+            tcr2.validate(&CpuFeatures::new().with(Feature::Tcr2))
+                .count(),
+            1
+        );
+        let features = [Feature::Tcr2, Feature::S1pie].into_iter().collect();
+        assert_eq!(tcr2.validate(&features).count(), 0);
+        assert_eq!(Tcr2El1::empty().validate(&CpuFeatures::new()).count(), 0);
+    }
+
     #[cfg(feature = "el1")]
     #[test]
     fn debug_spsr_el1() {
@@ -0,0 +1,44 @@
+// SPDX-FileCopyrightText: Copyright The arm-sysregs Contributors.
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! System register types and accessors, in a module for each family of registers.
+
+// This file is generated, do not edit manually.
+
+// Depending on the enabled features, some families might not have any registers.
+#![allow(unused_imports)]
+
+mod aarch32;
+mod amu;
+mod control;
+mod debug;
+mod exception;
+mod gic;
+mod id;
+mod info;
+mod mmu;
+mod mpam;
+mod mte;
+mod pauth;
+mod pmu;
+mod ras;
+mod snapshot;
+mod sve;
+mod timer;
+
+pub use aarch32::*;
+pub use amu::*;
+pub use control::*;
+pub use debug::*;
+pub use exception::*;
+pub use gic::*;
+pub use id::*;
+pub use mmu::*;
+pub use mpam::*;
+pub use mte::*;
+pub use pauth::*;
+pub use pmu::*;
+pub use ras::*;
+pub use snapshot::RegisterSnapshot;
+pub use sve::*;
+pub use timer::*;
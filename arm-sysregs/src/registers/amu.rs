@@ -0,0 +1,2042 @@
+// SPDX-FileCopyrightText: Copyright The arm-sysregs Contributors.
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Activity monitors system registers.
+
+// This file is generated, do not edit manually.
+
+// Depending on the enabled features, the module might not use all of these imports.
+#![allow(unused_imports)]
+
+use crate::*;
+use bitflags::bitflags;
+use core::fmt::{self, Display, Formatter};
+
+#[cfg(feature = "feat-amu")]
+bitflags! {
+    /// `AMCFGR_EL0` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct AmcfgrEl0: u64 {
+        /// `HDBG` bit.
+        const HDBG = 1 << 24;
+    }
+}
+
+#[cfg(feature = "feat-amu")]
+impl AmcfgrEl0 {
+    /// Descriptor of the `N` field, for use with [`FieldAccess`].
+    pub const N_FIELD: Field<Self, u8, 0, 8> = Field::new("AMCFGR_EL0", "N");
+    /// Descriptor of the `SIZE` field, for use with [`FieldAccess`].
+    pub const SIZE_FIELD: Field<Self, u8, 8, 6> = Field::new("AMCFGR_EL0", "SIZE");
+    /// Descriptor of the `HDBG` field, for use with [`FieldAccess`].
+    pub const HDBG_FIELD: Field<Self, bool, 24, 1> = Field::new("AMCFGR_EL0", "HDBG");
+    /// Descriptor of the `NCG` field, for use with [`FieldAccess`].
+    pub const NCG_FIELD: Field<Self, u8, 28, 4> = Field::new("AMCFGR_EL0", "NCG");
+
+    /// Returns the value of the `N` field.
+    pub const fn n(self) -> u8 {
+        Self::N_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `N` field.
+    pub const fn set_n(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::N_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `N` field set to the given value.
+    pub const fn with_n(mut self, value: u8) -> Self {
+        self.set_n(value);
+        self
+    }
+
+    /// Sets the value of the `N` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_n(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_n(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `N` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_n(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::N_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SIZE` field.
+    pub const fn size(self) -> u8 {
+        Self::SIZE_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SIZE` field.
+    pub const fn set_size(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SIZE_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SIZE` field set to the given value.
+    pub const fn with_size(mut self, value: u8) -> Self {
+        self.set_size(value);
+        self
+    }
+
+    /// Sets the value of the `SIZE` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_size(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_size(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SIZE` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_size(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SIZE_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `NCG` field.
+    pub const fn ncg(self) -> u8 {
+        Self::NCG_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `NCG` field.
+    pub const fn set_ncg(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::NCG_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `NCG` field set to the given value.
+    pub const fn with_ncg(mut self, value: u8) -> Self {
+        self.set_ncg(value);
+        self
+    }
+
+    /// Sets the value of the `NCG` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ncg(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ncg(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `NCG` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ncg(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::NCG_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "feat-amu")]
+impl Display for AmcfgrEl0 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("AmcfgrEl0");
+        let bits = self.bits();
+        s.field("N", &Self::N_FIELD.extract(bits));
+        s.field("SIZE", &Self::SIZE_FIELD.extract(bits));
+        s.field("HDBG", &Self::HDBG_FIELD.extract(bits));
+        s.field("NCG", &Self::NCG_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "feat-amu")]
+impl defmt::Format for AmcfgrEl0 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "AmcfgrEl0 {{ N: {=u64}, SIZE: {=u64}, HDBG: {=u64}, NCG: {=u64} }}",
+            Self::N_FIELD.extract(self.bits()),
+            Self::SIZE_FIELD.extract(self.bits()),
+            Self::HDBG_FIELD.extract(self.bits()),
+            Self::NCG_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "feat-amu")]
+impl serde::Serialize for AmcfgrEl0 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "feat-amu")]
+impl<'de> serde::Deserialize<'de> for AmcfgrEl0 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "feat-amu")]
+impl AmcfgrEl0 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("N"),
+                old: Self::N_FIELD.extract(self.bits()),
+                new: Self::N_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SIZE"),
+                old: Self::SIZE_FIELD.extract(self.bits()),
+                new: Self::SIZE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HDBG"),
+                old: Self::HDBG_FIELD.extract(self.bits()),
+                new: Self::HDBG_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("NCG"),
+                old: Self::NCG_FIELD.extract(self.bits()),
+                new: Self::NCG_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_0000_1110_1111_1111_1100_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_0000_1110_1111_1111_1100_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "feat-amu")]
+impl AmcfgrEl0 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "feat-amu")]
+impl AmcfgrEl0 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                Self::N_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Amuv1),
+                UnimplementedField {
+                    register: "AMCFGR_EL0",
+                    field: "N",
+                    requires: "FEAT_AMUv1",
+                },
+            ),
+            (
+                Self::SIZE_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Amuv1),
+                UnimplementedField {
+                    register: "AMCFGR_EL0",
+                    field: "SIZE",
+                    requires: "FEAT_AMUv1",
+                },
+            ),
+            (
+                Self::HDBG_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Amuv1),
+                UnimplementedField {
+                    register: "AMCFGR_EL0",
+                    field: "HDBG",
+                    requires: "FEAT_AMUv1",
+                },
+            ),
+            (
+                Self::NCG_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Amuv1),
+                UnimplementedField {
+                    register: "AMCFGR_EL0",
+                    field: "NCG",
+                    requires: "FEAT_AMUv1",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(feature = "feat-amu")]
+bitflags! {
+    /// `AMCGCR_EL0` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct AmcgcrEl0: u64 {
+    }
+}
+
+#[cfg(feature = "feat-amu")]
+impl AmcgcrEl0 {
+    /// Descriptor of the `CG0NC` field, for use with [`FieldAccess`].
+    pub const CG0NC_FIELD: Field<Self, u8, 0, 8> = Field::new("AMCGCR_EL0", "CG0NC");
+    /// Descriptor of the `CG1NC` field, for use with [`FieldAccess`].
+    pub const CG1NC_FIELD: Field<Self, u8, 8, 8> = Field::new("AMCGCR_EL0", "CG1NC");
+
+    /// Returns the value of the `CG0NC` field.
+    pub const fn cg0nc(self) -> u8 {
+        Self::CG0NC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `CG0NC` field.
+    pub const fn set_cg0nc(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::CG0NC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `CG0NC` field set to the given value.
+    pub const fn with_cg0nc(mut self, value: u8) -> Self {
+        self.set_cg0nc(value);
+        self
+    }
+
+    /// Sets the value of the `CG0NC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_cg0nc(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_cg0nc(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `CG0NC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_cg0nc(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::CG0NC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `CG1NC` field.
+    pub const fn cg1nc(self) -> u8 {
+        Self::CG1NC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `CG1NC` field.
+    pub const fn set_cg1nc(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::CG1NC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `CG1NC` field set to the given value.
+    pub const fn with_cg1nc(mut self, value: u8) -> Self {
+        self.set_cg1nc(value);
+        self
+    }
+
+    /// Sets the value of the `CG1NC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_cg1nc(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_cg1nc(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `CG1NC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_cg1nc(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::CG1NC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "feat-amu")]
+impl Display for AmcgcrEl0 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("AmcgcrEl0");
+        let bits = self.bits();
+        s.field("CG0NC", &Self::CG0NC_FIELD.extract(bits));
+        s.field("CG1NC", &Self::CG1NC_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "feat-amu")]
+impl defmt::Format for AmcgcrEl0 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "AmcgcrEl0 {{ CG0NC: {=u64}, CG1NC: {=u64} }}",
+            Self::CG0NC_FIELD.extract(self.bits()),
+            Self::CG1NC_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "feat-amu")]
+impl serde::Serialize for AmcgcrEl0 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "feat-amu")]
+impl<'de> serde::Deserialize<'de> for AmcgcrEl0 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "feat-amu")]
+impl AmcgcrEl0 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("CG0NC"),
+                old: Self::CG0NC_FIELD.extract(self.bits()),
+                new: Self::CG0NC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CG1NC"),
+                old: Self::CG1NC_FIELD.extract(self.bits()),
+                new: Self::CG1NC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "feat-amu")]
+impl AmcgcrEl0 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "feat-amu")]
+impl AmcgcrEl0 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                Self::CG0NC_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Amuv1),
+                UnimplementedField {
+                    register: "AMCGCR_EL0",
+                    field: "CG0NC",
+                    requires: "FEAT_AMUv1",
+                },
+            ),
+            (
+                Self::CG1NC_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Amuv1),
+                UnimplementedField {
+                    register: "AMCGCR_EL0",
+                    field: "CG1NC",
+                    requires: "FEAT_AMUv1",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(feature = "feat-amu")]
+bitflags! {
+    /// `AMCNTENCLR0_EL0` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct Amcntenclr0El0: u64 {
+        /// `P<n>` bit 0.
+        const P0 = 1 << 0;
+        /// `P<n>` bit 1.
+        const P1 = 1 << 1;
+        /// `P<n>` bit 2.
+        const P2 = 1 << 2;
+        /// `P<n>` bit 3.
+        const P3 = 1 << 3;
+    }
+}
+
+#[cfg(feature = "feat-amu")]
+impl Amcntenclr0El0 {
+    /// Offset of the `P<n>` field.
+    pub const P_SHIFT: u32 = 0;
+}
+
+#[cfg(feature = "feat-amu")]
+impl Display for Amcntenclr0El0 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("Amcntenclr0El0");
+        let bits = self.bits();
+        s.field("P0", &((bits >> Self::P_SHIFT) & 1));
+        s.field("P1", &((bits >> (Self::P_SHIFT + 1)) & 1));
+        s.field("P2", &((bits >> (Self::P_SHIFT + 2)) & 1));
+        s.field("P3", &((bits >> (Self::P_SHIFT + 3)) & 1));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "feat-amu")]
+impl defmt::Format for Amcntenclr0El0 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "Amcntenclr0El0 {{ P0: {=u64}, P1: {=u64}, P2: {=u64}, P3: {=u64} }}",
+            (self.bits() >> Self::P_SHIFT) & 1,
+            (self.bits() >> (Self::P_SHIFT + 1)) & 1,
+            (self.bits() >> (Self::P_SHIFT + 2)) & 1,
+            (self.bits() >> (Self::P_SHIFT + 3)) & 1
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "feat-amu")]
+impl serde::Serialize for Amcntenclr0El0 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "feat-amu")]
+impl<'de> serde::Deserialize<'de> for Amcntenclr0El0 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "feat-amu")]
+impl Amcntenclr0El0 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("P0"),
+                old: (self.bits() >> Self::P_SHIFT) & 1,
+                new: (other.bits() >> Self::P_SHIFT) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("P1"),
+                old: (self.bits() >> (Self::P_SHIFT + 1)) & 1,
+                new: (other.bits() >> (Self::P_SHIFT + 1)) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("P2"),
+                old: (self.bits() >> (Self::P_SHIFT + 2)) & 1,
+                new: (other.bits() >> (Self::P_SHIFT + 2)) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("P3"),
+                old: (self.bits() >> (Self::P_SHIFT + 3)) & 1,
+                new: (other.bits() >> (Self::P_SHIFT + 3)) & 1,
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "feat-amu")]
+impl Amcntenclr0El0 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "feat-amu")]
+impl Amcntenclr0El0 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                (self.bits() >> Self::P_SHIFT) & 1 != 0 && !features.contains(Feature::Amuv1),
+                UnimplementedField {
+                    register: "AMCNTENCLR0_EL0",
+                    field: "P0",
+                    requires: "FEAT_AMUv1",
+                },
+            ),
+            (
+                (self.bits() >> (Self::P_SHIFT + 1)) & 1 != 0 && !features.contains(Feature::Amuv1),
+                UnimplementedField {
+                    register: "AMCNTENCLR0_EL0",
+                    field: "P1",
+                    requires: "FEAT_AMUv1",
+                },
+            ),
+            (
+                (self.bits() >> (Self::P_SHIFT + 2)) & 1 != 0 && !features.contains(Feature::Amuv1),
+                UnimplementedField {
+                    register: "AMCNTENCLR0_EL0",
+                    field: "P2",
+                    requires: "FEAT_AMUv1",
+                },
+            ),
+            (
+                (self.bits() >> (Self::P_SHIFT + 3)) & 1 != 0 && !features.contains(Feature::Amuv1),
+                UnimplementedField {
+                    register: "AMCNTENCLR0_EL0",
+                    field: "P3",
+                    requires: "FEAT_AMUv1",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(feature = "feat-amu")]
+bitflags! {
+    /// `AMCNTENCLR1_EL0` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct Amcntenclr1El0: u64 {
+        /// `P<n>` bit 0.
+        const P0 = 1 << 0;
+        /// `P<n>` bit 1.
+        const P1 = 1 << 1;
+        /// `P<n>` bit 2.
+        const P2 = 1 << 2;
+        /// `P<n>` bit 3.
+        const P3 = 1 << 3;
+        /// `P<n>` bit 4.
+        const P4 = 1 << 4;
+        /// `P<n>` bit 5.
+        const P5 = 1 << 5;
+        /// `P<n>` bit 6.
+        const P6 = 1 << 6;
+        /// `P<n>` bit 7.
+        const P7 = 1 << 7;
+        /// `P<n>` bit 8.
+        const P8 = 1 << 8;
+        /// `P<n>` bit 9.
+        const P9 = 1 << 9;
+        /// `P<n>` bit 10.
+        const P10 = 1 << 10;
+        /// `P<n>` bit 11.
+        const P11 = 1 << 11;
+        /// `P<n>` bit 12.
+        const P12 = 1 << 12;
+        /// `P<n>` bit 13.
+        const P13 = 1 << 13;
+        /// `P<n>` bit 14.
+        const P14 = 1 << 14;
+        /// `P<n>` bit 15.
+        const P15 = 1 << 15;
+    }
+}
+
+#[cfg(feature = "feat-amu")]
+impl Amcntenclr1El0 {
+    /// Offset of the `P<n>` field.
+    pub const P_SHIFT: u32 = 0;
+}
+
+#[cfg(feature = "feat-amu")]
+impl Display for Amcntenclr1El0 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("Amcntenclr1El0");
+        let bits = self.bits();
+        s.field("P0", &((bits >> Self::P_SHIFT) & 1));
+        s.field("P1", &((bits >> (Self::P_SHIFT + 1)) & 1));
+        s.field("P2", &((bits >> (Self::P_SHIFT + 2)) & 1));
+        s.field("P3", &((bits >> (Self::P_SHIFT + 3)) & 1));
+        s.field("P4", &((bits >> (Self::P_SHIFT + 4)) & 1));
+        s.field("P5", &((bits >> (Self::P_SHIFT + 5)) & 1));
+        s.field("P6", &((bits >> (Self::P_SHIFT + 6)) & 1));
+        s.field("P7", &((bits >> (Self::P_SHIFT + 7)) & 1));
+        s.field("P8", &((bits >> (Self::P_SHIFT + 8)) & 1));
+        s.field("P9", &((bits >> (Self::P_SHIFT + 9)) & 1));
+        s.field("P10", &((bits >> (Self::P_SHIFT + 10)) & 1));
+        s.field("P11", &((bits >> (Self::P_SHIFT + 11)) & 1));
+        s.field("P12", &((bits >> (Self::P_SHIFT + 12)) & 1));
+        s.field("P13", &((bits >> (Self::P_SHIFT + 13)) & 1));
+        s.field("P14", &((bits >> (Self::P_SHIFT + 14)) & 1));
+        s.field("P15", &((bits >> (Self::P_SHIFT + 15)) & 1));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "feat-amu")]
+impl defmt::Format for Amcntenclr1El0 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "Amcntenclr1El0 {{ P0: {=u64}, P1: {=u64}, P2: {=u64}, P3: {=u64}, P4: {=u64}, P5: {=u64}, P6: {=u64}, P7: {=u64}, P8: {=u64}, P9: {=u64}, P10: {=u64}, P11: {=u64}, P12: {=u64}, P13: {=u64}, P14: {=u64}, P15: {=u64} }}",
+            (self.bits() >> Self::P_SHIFT) & 1,
+            (self.bits() >> (Self::P_SHIFT + 1)) & 1,
+            (self.bits() >> (Self::P_SHIFT + 2)) & 1,
+            (self.bits() >> (Self::P_SHIFT + 3)) & 1,
+            (self.bits() >> (Self::P_SHIFT + 4)) & 1,
+            (self.bits() >> (Self::P_SHIFT + 5)) & 1,
+            (self.bits() >> (Self::P_SHIFT + 6)) & 1,
+            (self.bits() >> (Self::P_SHIFT + 7)) & 1,
+            (self.bits() >> (Self::P_SHIFT + 8)) & 1,
+            (self.bits() >> (Self::P_SHIFT + 9)) & 1,
+            (self.bits() >> (Self::P_SHIFT + 10)) & 1,
+            (self.bits() >> (Self::P_SHIFT + 11)) & 1,
+            (self.bits() >> (Self::P_SHIFT + 12)) & 1,
+            (self.bits() >> (Self::P_SHIFT + 13)) & 1,
+            (self.bits() >> (Self::P_SHIFT + 14)) & 1,
+            (self.bits() >> (Self::P_SHIFT + 15)) & 1
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "feat-amu")]
+impl serde::Serialize for Amcntenclr1El0 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "feat-amu")]
+impl<'de> serde::Deserialize<'de> for Amcntenclr1El0 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "feat-amu")]
+impl Amcntenclr1El0 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("P0"),
+                old: (self.bits() >> Self::P_SHIFT) & 1,
+                new: (other.bits() >> Self::P_SHIFT) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("P1"),
+                old: (self.bits() >> (Self::P_SHIFT + 1)) & 1,
+                new: (other.bits() >> (Self::P_SHIFT + 1)) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("P2"),
+                old: (self.bits() >> (Self::P_SHIFT + 2)) & 1,
+                new: (other.bits() >> (Self::P_SHIFT + 2)) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("P3"),
+                old: (self.bits() >> (Self::P_SHIFT + 3)) & 1,
+                new: (other.bits() >> (Self::P_SHIFT + 3)) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("P4"),
+                old: (self.bits() >> (Self::P_SHIFT + 4)) & 1,
+                new: (other.bits() >> (Self::P_SHIFT + 4)) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("P5"),
+                old: (self.bits() >> (Self::P_SHIFT + 5)) & 1,
+                new: (other.bits() >> (Self::P_SHIFT + 5)) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("P6"),
+                old: (self.bits() >> (Self::P_SHIFT + 6)) & 1,
+                new: (other.bits() >> (Self::P_SHIFT + 6)) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("P7"),
+                old: (self.bits() >> (Self::P_SHIFT + 7)) & 1,
+                new: (other.bits() >> (Self::P_SHIFT + 7)) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("P8"),
+                old: (self.bits() >> (Self::P_SHIFT + 8)) & 1,
+                new: (other.bits() >> (Self::P_SHIFT + 8)) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("P9"),
+                old: (self.bits() >> (Self::P_SHIFT + 9)) & 1,
+                new: (other.bits() >> (Self::P_SHIFT + 9)) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("P10"),
+                old: (self.bits() >> (Self::P_SHIFT + 10)) & 1,
+                new: (other.bits() >> (Self::P_SHIFT + 10)) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("P11"),
+                old: (self.bits() >> (Self::P_SHIFT + 11)) & 1,
+                new: (other.bits() >> (Self::P_SHIFT + 11)) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("P12"),
+                old: (self.bits() >> (Self::P_SHIFT + 12)) & 1,
+                new: (other.bits() >> (Self::P_SHIFT + 12)) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("P13"),
+                old: (self.bits() >> (Self::P_SHIFT + 13)) & 1,
+                new: (other.bits() >> (Self::P_SHIFT + 13)) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("P14"),
+                old: (self.bits() >> (Self::P_SHIFT + 14)) & 1,
+                new: (other.bits() >> (Self::P_SHIFT + 14)) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("P15"),
+                old: (self.bits() >> (Self::P_SHIFT + 15)) & 1,
+                new: (other.bits() >> (Self::P_SHIFT + 15)) & 1,
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "feat-amu")]
+impl Amcntenclr1El0 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "feat-amu")]
+impl Amcntenclr1El0 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                (self.bits() >> Self::P_SHIFT) & 1 != 0 && !features.contains(Feature::Amuv1),
+                UnimplementedField {
+                    register: "AMCNTENCLR1_EL0",
+                    field: "P0",
+                    requires: "FEAT_AMUv1",
+                },
+            ),
+            (
+                (self.bits() >> (Self::P_SHIFT + 1)) & 1 != 0 && !features.contains(Feature::Amuv1),
+                UnimplementedField {
+                    register: "AMCNTENCLR1_EL0",
+                    field: "P1",
+                    requires: "FEAT_AMUv1",
+                },
+            ),
+            (
+                (self.bits() >> (Self::P_SHIFT + 2)) & 1 != 0 && !features.contains(Feature::Amuv1),
+                UnimplementedField {
+                    register: "AMCNTENCLR1_EL0",
+                    field: "P2",
+                    requires: "FEAT_AMUv1",
+                },
+            ),
+            (
+                (self.bits() >> (Self::P_SHIFT + 3)) & 1 != 0 && !features.contains(Feature::Amuv1),
+                UnimplementedField {
+                    register: "AMCNTENCLR1_EL0",
+                    field: "P3",
+                    requires: "FEAT_AMUv1",
+                },
+            ),
+            (
+                (self.bits() >> (Self::P_SHIFT + 4)) & 1 != 0 && !features.contains(Feature::Amuv1),
+                UnimplementedField {
+                    register: "AMCNTENCLR1_EL0",
+                    field: "P4",
+                    requires: "FEAT_AMUv1",
+                },
+            ),
+            (
+                (self.bits() >> (Self::P_SHIFT + 5)) & 1 != 0 && !features.contains(Feature::Amuv1),
+                UnimplementedField {
+                    register: "AMCNTENCLR1_EL0",
+                    field: "P5",
+                    requires: "FEAT_AMUv1",
+                },
+            ),
+            (
+                (self.bits() >> (Self::P_SHIFT + 6)) & 1 != 0 && !features.contains(Feature::Amuv1),
+                UnimplementedField {
+                    register: "AMCNTENCLR1_EL0",
+                    field: "P6",
+                    requires: "FEAT_AMUv1",
+                },
+            ),
+            (
+                (self.bits() >> (Self::P_SHIFT + 7)) & 1 != 0 && !features.contains(Feature::Amuv1),
+                UnimplementedField {
+                    register: "AMCNTENCLR1_EL0",
+                    field: "P7",
+                    requires: "FEAT_AMUv1",
+                },
+            ),
+            (
+                (self.bits() >> (Self::P_SHIFT + 8)) & 1 != 0 && !features.contains(Feature::Amuv1),
+                UnimplementedField {
+                    register: "AMCNTENCLR1_EL0",
+                    field: "P8",
+                    requires: "FEAT_AMUv1",
+                },
+            ),
+            (
+                (self.bits() >> (Self::P_SHIFT + 9)) & 1 != 0 && !features.contains(Feature::Amuv1),
+                UnimplementedField {
+                    register: "AMCNTENCLR1_EL0",
+                    field: "P9",
+                    requires: "FEAT_AMUv1",
+                },
+            ),
+            (
+                (self.bits() >> (Self::P_SHIFT + 10)) & 1 != 0
+                    && !features.contains(Feature::Amuv1),
+                UnimplementedField {
+                    register: "AMCNTENCLR1_EL0",
+                    field: "P10",
+                    requires: "FEAT_AMUv1",
+                },
+            ),
+            (
+                (self.bits() >> (Self::P_SHIFT + 11)) & 1 != 0
+                    && !features.contains(Feature::Amuv1),
+                UnimplementedField {
+                    register: "AMCNTENCLR1_EL0",
+                    field: "P11",
+                    requires: "FEAT_AMUv1",
+                },
+            ),
+            (
+                (self.bits() >> (Self::P_SHIFT + 12)) & 1 != 0
+                    && !features.contains(Feature::Amuv1),
+                UnimplementedField {
+                    register: "AMCNTENCLR1_EL0",
+                    field: "P12",
+                    requires: "FEAT_AMUv1",
+                },
+            ),
+            (
+                (self.bits() >> (Self::P_SHIFT + 13)) & 1 != 0
+                    && !features.contains(Feature::Amuv1),
+                UnimplementedField {
+                    register: "AMCNTENCLR1_EL0",
+                    field: "P13",
+                    requires: "FEAT_AMUv1",
+                },
+            ),
+            (
+                (self.bits() >> (Self::P_SHIFT + 14)) & 1 != 0
+                    && !features.contains(Feature::Amuv1),
+                UnimplementedField {
+                    register: "AMCNTENCLR1_EL0",
+                    field: "P14",
+                    requires: "FEAT_AMUv1",
+                },
+            ),
+            (
+                (self.bits() >> (Self::P_SHIFT + 15)) & 1 != 0
+                    && !features.contains(Feature::Amuv1),
+                UnimplementedField {
+                    register: "AMCNTENCLR1_EL0",
+                    field: "P15",
+                    requires: "FEAT_AMUv1",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(feature = "feat-amu")]
+/// `AMCNTENSET0_EL0` system register value.
+pub type Amcntenset0El0 = Amcntenclr0El0;
+
+#[cfg(feature = "feat-amu")]
+/// `AMCNTENSET1_EL0` system register value.
+pub type Amcntenset1El0 = Amcntenclr1El0;
+
+#[cfg(feature = "feat-amu")]
+bitflags! {
+    /// `AMCR_EL0` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct AmcrEl0: u64 {
+        /// `HDBG` bit.
+        const HDBG = 1 << 10;
+        /// `CG1RZ` bit.
+        const CG1RZ = 1 << 17;
+    }
+}
+
+#[cfg(feature = "feat-amu")]
+impl AmcrEl0 {
+    /// Descriptor of the `HDBG` field, for use with [`FieldAccess`].
+    pub const HDBG_FIELD: Field<Self, bool, 10, 1> = Field::new("AMCR_EL0", "HDBG");
+    /// Descriptor of the `CG1RZ` field, for use with [`FieldAccess`].
+    pub const CG1RZ_FIELD: Field<Self, bool, 17, 1> = Field::new("AMCR_EL0", "CG1RZ");
+}
+
+#[cfg(feature = "feat-amu")]
+impl Display for AmcrEl0 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("AmcrEl0");
+        let bits = self.bits();
+        s.field("HDBG", &Self::HDBG_FIELD.extract(bits));
+        s.field("CG1RZ", &Self::CG1RZ_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "feat-amu")]
+impl defmt::Format for AmcrEl0 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "AmcrEl0 {{ HDBG: {=u64}, CG1RZ: {=u64} }}",
+            Self::HDBG_FIELD.extract(self.bits()),
+            Self::CG1RZ_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "feat-amu")]
+impl serde::Serialize for AmcrEl0 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "feat-amu")]
+impl<'de> serde::Deserialize<'de> for AmcrEl0 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "feat-amu")]
+impl AmcrEl0 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("HDBG"),
+                old: Self::HDBG_FIELD.extract(self.bits()),
+                new: Self::HDBG_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CG1RZ"),
+                old: Self::CG1RZ_FIELD.extract(self.bits()),
+                new: Self::CG1RZ_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1101_1111_1011_1111_1111,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1101_1111_1011_1111_1111,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "feat-amu")]
+impl AmcrEl0 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "feat-amu")]
+impl AmcrEl0 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                Self::HDBG_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Amuv1),
+                UnimplementedField {
+                    register: "AMCR_EL0",
+                    field: "HDBG",
+                    requires: "FEAT_AMUv1",
+                },
+            ),
+            (
+                Self::CG1RZ_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Amuv1),
+                UnimplementedField {
+                    register: "AMCR_EL0",
+                    field: "CG1RZ",
+                    requires: "FEAT_AMUv1",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(feature = "feat-amu")]
+bitflags! {
+    /// `AMEVCNTR00_EL0` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct Amevcntr00El0: u64 {
+    }
+}
+
+#[cfg(feature = "feat-amu")]
+impl Amevcntr00El0 {
+    /// Descriptor of the `ACNT` field, for use with [`FieldAccess`].
+    pub const ACNT_FIELD: Field<Self, u64, 0, 64> = Field::new("AMEVCNTR00_EL0", "ACNT");
+
+    /// Returns the value of the `ACNT` field.
+    pub const fn acnt(self) -> u64 {
+        Self::ACNT_FIELD.extract(self.bits())
+    }
+
+    /// Sets the value of the `ACNT` field.
+    pub const fn set_acnt(&mut self, value: u64) {
+        *self = Self::from_bits_retain(Self::ACNT_FIELD.insert(self.bits(), value));
+    }
+
+    /// Returns a copy with the `ACNT` field set to the given value.
+    pub const fn with_acnt(mut self, value: u64) -> Self {
+        self.set_acnt(value);
+        self
+    }
+
+    /// Sets the value of the `ACNT` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_acnt(&mut self, value: u64) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_acnt(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ACNT` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_acnt(self, value: u64) -> Result<Self, FieldOverflow> {
+        match Self::ACNT_FIELD.try_insert(self.bits(), value) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "feat-amu")]
+impl Display for Amevcntr00El0 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("Amevcntr00El0");
+        let bits = self.bits();
+        s.field(
+            "ACNT",
+            &format_args!("{:#x}", Self::ACNT_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "feat-amu")]
+impl defmt::Format for Amevcntr00El0 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "Amevcntr00El0 {{ ACNT: {=u64:#x} }}",
+            Self::ACNT_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "feat-amu")]
+impl serde::Serialize for Amevcntr00El0 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "feat-amu")]
+impl<'de> serde::Deserialize<'de> for Amevcntr00El0 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "feat-amu")]
+impl Amevcntr00El0 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [FieldChange {
+            register,
+            field: Some("ACNT"),
+            old: Self::ACNT_FIELD.extract(self.bits()),
+            new: Self::ACNT_FIELD.extract(other.bits()),
+        }]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "feat-amu")]
+impl Amevcntr00El0 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "feat-amu")]
+impl Amevcntr00El0 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [(
+            Self::ACNT_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Amuv1),
+            UnimplementedField {
+                register: "AMEVCNTR00_EL0",
+                field: "ACNT",
+                requires: "FEAT_AMUv1",
+            },
+        )]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(feature = "feat-amu")]
+/// `AMEVCNTR01_EL0` system register value.
+pub type Amevcntr01El0 = Amevcntr00El0;
+
+#[cfg(feature = "feat-amu")]
+/// `AMEVCNTR02_EL0` system register value.
+pub type Amevcntr02El0 = Amevcntr00El0;
+
+#[cfg(feature = "feat-amu")]
+/// `AMEVCNTR03_EL0` system register value.
+pub type Amevcntr03El0 = Amevcntr00El0;
+
+#[cfg(feature = "feat-amu")]
+/// `AMEVCNTR10_EL0` system register value.
+pub type Amevcntr10El0 = Amevcntr00El0;
+
+#[cfg(feature = "feat-amu")]
+/// `AMEVCNTR110_EL0` system register value.
+pub type Amevcntr110El0 = Amevcntr00El0;
+
+#[cfg(feature = "feat-amu")]
+/// `AMEVCNTR111_EL0` system register value.
+pub type Amevcntr111El0 = Amevcntr00El0;
+
+#[cfg(feature = "feat-amu")]
+/// `AMEVCNTR112_EL0` system register value.
+pub type Amevcntr112El0 = Amevcntr00El0;
+
+#[cfg(feature = "feat-amu")]
+/// `AMEVCNTR113_EL0` system register value.
+pub type Amevcntr113El0 = Amevcntr00El0;
+
+#[cfg(feature = "feat-amu")]
+/// `AMEVCNTR114_EL0` system register value.
+pub type Amevcntr114El0 = Amevcntr00El0;
+
+#[cfg(feature = "feat-amu")]
+/// `AMEVCNTR115_EL0` system register value.
+pub type Amevcntr115El0 = Amevcntr00El0;
+
+#[cfg(feature = "feat-amu")]
+/// `AMEVCNTR11_EL0` system register value.
+pub type Amevcntr11El0 = Amevcntr00El0;
+
+#[cfg(feature = "feat-amu")]
+/// `AMEVCNTR12_EL0` system register value.
+pub type Amevcntr12El0 = Amevcntr00El0;
+
+#[cfg(feature = "feat-amu")]
+/// `AMEVCNTR13_EL0` system register value.
+pub type Amevcntr13El0 = Amevcntr00El0;
+
+#[cfg(feature = "feat-amu")]
+/// `AMEVCNTR14_EL0` system register value.
+pub type Amevcntr14El0 = Amevcntr00El0;
+
+#[cfg(feature = "feat-amu")]
+/// `AMEVCNTR15_EL0` system register value.
+pub type Amevcntr15El0 = Amevcntr00El0;
+
+#[cfg(feature = "feat-amu")]
+/// `AMEVCNTR16_EL0` system register value.
+pub type Amevcntr16El0 = Amevcntr00El0;
+
+#[cfg(feature = "feat-amu")]
+/// `AMEVCNTR17_EL0` system register value.
+pub type Amevcntr17El0 = Amevcntr00El0;
+
+#[cfg(feature = "feat-amu")]
+/// `AMEVCNTR18_EL0` system register value.
+pub type Amevcntr18El0 = Amevcntr00El0;
+
+#[cfg(feature = "feat-amu")]
+/// `AMEVCNTR19_EL0` system register value.
+pub type Amevcntr19El0 = Amevcntr00El0;
+
+#[cfg(feature = "feat-amu")]
+bitflags! {
+    /// `AMEVTYPER00_EL0` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct Amevtyper00El0: u64 {
+    }
+}
+
+#[cfg(feature = "feat-amu")]
+impl Amevtyper00El0 {
+    /// Descriptor of the `evtCount` field, for use with [`FieldAccess`].
+    pub const EVTCOUNT_FIELD: Field<Self, u16, 0, 16> = Field::new("AMEVTYPER00_EL0", "evtCount");
+
+    /// Returns the value of the `evtCount` field.
+    pub const fn evtcount(self) -> u16 {
+        Self::EVTCOUNT_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `evtCount` field.
+    pub const fn set_evtcount(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::EVTCOUNT_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `evtCount` field set to the given value.
+    pub const fn with_evtcount(mut self, value: u16) -> Self {
+        self.set_evtcount(value);
+        self
+    }
+
+    /// Sets the value of the `evtCount` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_evtcount(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_evtcount(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `evtCount` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_evtcount(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::EVTCOUNT_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "feat-amu")]
+impl Display for Amevtyper00El0 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("Amevtyper00El0");
+        let bits = self.bits();
+        s.field(
+            "evtCount",
+            &format_args!("{:#x}", Self::EVTCOUNT_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "feat-amu")]
+impl defmt::Format for Amevtyper00El0 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "Amevtyper00El0 {{ evtCount: {=u64:#x} }}",
+            Self::EVTCOUNT_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "feat-amu")]
+impl serde::Serialize for Amevtyper00El0 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "feat-amu")]
+impl<'de> serde::Deserialize<'de> for Amevtyper00El0 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "feat-amu")]
+impl Amevtyper00El0 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("evtCount"),
+                old: Self::EVTCOUNT_FIELD.extract(self.bits()),
+                new: Self::EVTCOUNT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "feat-amu")]
+impl Amevtyper00El0 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "feat-amu")]
+impl Amevtyper00El0 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [(
+            Self::EVTCOUNT_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Amuv1),
+            UnimplementedField {
+                register: "AMEVTYPER00_EL0",
+                field: "evtCount",
+                requires: "FEAT_AMUv1",
+            },
+        )]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(feature = "feat-amu")]
+/// `AMEVTYPER01_EL0` system register value.
+pub type Amevtyper01El0 = Amevtyper00El0;
+
+#[cfg(feature = "feat-amu")]
+/// `AMEVTYPER02_EL0` system register value.
+pub type Amevtyper02El0 = Amevtyper00El0;
+
+#[cfg(feature = "feat-amu")]
+/// `AMEVTYPER03_EL0` system register value.
+pub type Amevtyper03El0 = Amevtyper00El0;
+
+#[cfg(feature = "feat-amu")]
+bitflags! {
+    /// `AMUSERENR_EL0` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct AmuserenrEl0: u64 {
+        /// `EN` bit.
+        const EN = 1 << 0;
+    }
+}
+
+#[cfg(feature = "feat-amu")]
+impl AmuserenrEl0 {
+    /// Descriptor of the `EN` field, for use with [`FieldAccess`].
+    pub const EN_FIELD: Field<Self, bool, 0, 1> = Field::new("AMUSERENR_EL0", "EN");
+}
+
+#[cfg(feature = "feat-amu")]
+impl Display for AmuserenrEl0 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("AmuserenrEl0");
+        let bits = self.bits();
+        s.field("EN", &Self::EN_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "feat-amu")]
+impl defmt::Format for AmuserenrEl0 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "AmuserenrEl0 {{ EN: {=u64} }}",
+            Self::EN_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "feat-amu")]
+impl serde::Serialize for AmuserenrEl0 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "feat-amu")]
+impl<'de> serde::Deserialize<'de> for AmuserenrEl0 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "feat-amu")]
+impl AmuserenrEl0 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("EN"),
+                old: Self::EN_FIELD.extract(self.bits()),
+                new: Self::EN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1110,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1110,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "feat-amu")]
+impl AmuserenrEl0 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "feat-amu")]
+impl AmuserenrEl0 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [(
+            Self::EN_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Amuv1),
+            UnimplementedField {
+                register: "AMUSERENR_EL0",
+                field: "EN",
+                requires: "FEAT_AMUv1",
+            },
+        )]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "feat-amu"
+))]
+read_sysreg!(amcfgr_el0: s3_3_c13_c2_1, u64: AmcfgrEl0, safe, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "feat-amu"
+))]
+read_sysreg!(amcgcr_el0: s3_3_c13_c2_2, u64: AmcgcrEl0, safe, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "feat-amu"
+))]
+read_write_sysreg!(amcntenclr0_el0: s3_3_c13_c2_4, u64: Amcntenclr0El0, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "feat-amu"
+))]
+read_write_sysreg!(amcntenclr1_el0: s3_3_c13_c3_0, u64: Amcntenclr1El0, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "feat-amu"
+))]
+read_write_sysreg!(amcntenset0_el0: s3_3_c13_c2_5, u64: Amcntenset0El0, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "feat-amu"
+))]
+read_write_sysreg!(amcntenset1_el0: s3_3_c13_c3_1, u64: Amcntenset1El0, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "feat-amu"
+))]
+read_write_sysreg!(amcr_el0: s3_3_c13_c2_0, u64: AmcrEl0, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "feat-amu"
+))]
+read_write_sysreg!(amevcntr00_el0: s3_3_c13_c4_0, u64: Amevcntr00El0, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "feat-amu"
+))]
+read_write_sysreg!(amevcntr01_el0: s3_3_c13_c4_1, u64: Amevcntr01El0, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "feat-amu"
+))]
+read_write_sysreg!(amevcntr02_el0: s3_3_c13_c4_2, u64: Amevcntr02El0, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "feat-amu"
+))]
+read_write_sysreg!(amevcntr03_el0: s3_3_c13_c4_3, u64: Amevcntr03El0, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "feat-amu"
+))]
+read_write_sysreg!(amevcntr10_el0: s3_3_c13_c12_0, u64: Amevcntr10El0, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "feat-amu"
+))]
+read_write_sysreg!(amevcntr110_el0: s3_3_c13_c13_2, u64: Amevcntr110El0, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "feat-amu"
+))]
+read_write_sysreg!(amevcntr111_el0: s3_3_c13_c13_3, u64: Amevcntr111El0, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "feat-amu"
+))]
+read_write_sysreg!(amevcntr112_el0: s3_3_c13_c13_4, u64: Amevcntr112El0, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "feat-amu"
+))]
+read_write_sysreg!(amevcntr113_el0: s3_3_c13_c13_5, u64: Amevcntr113El0, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "feat-amu"
+))]
+read_write_sysreg!(amevcntr114_el0: s3_3_c13_c13_6, u64: Amevcntr114El0, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "feat-amu"
+))]
+read_write_sysreg!(amevcntr115_el0: s3_3_c13_c13_7, u64: Amevcntr115El0, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "feat-amu"
+))]
+read_write_sysreg!(amevcntr11_el0: s3_3_c13_c12_1, u64: Amevcntr11El0, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "feat-amu"
+))]
+read_write_sysreg!(amevcntr12_el0: s3_3_c13_c12_2, u64: Amevcntr12El0, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "feat-amu"
+))]
+read_write_sysreg!(amevcntr13_el0: s3_3_c13_c12_3, u64: Amevcntr13El0, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "feat-amu"
+))]
+read_write_sysreg!(amevcntr14_el0: s3_3_c13_c12_4, u64: Amevcntr14El0, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "feat-amu"
+))]
+read_write_sysreg!(amevcntr15_el0: s3_3_c13_c12_5, u64: Amevcntr15El0, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "feat-amu"
+))]
+read_write_sysreg!(amevcntr16_el0: s3_3_c13_c12_6, u64: Amevcntr16El0, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "feat-amu"
+))]
+read_write_sysreg!(amevcntr17_el0: s3_3_c13_c12_7, u64: Amevcntr17El0, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "feat-amu"
+))]
+read_write_sysreg!(amevcntr18_el0: s3_3_c13_c13_0, u64: Amevcntr18El0, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "feat-amu"
+))]
+read_write_sysreg!(amevcntr19_el0: s3_3_c13_c13_1, u64: Amevcntr19El0, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "feat-amu"
+))]
+read_sysreg!(amevtyper00_el0: s3_3_c13_c6_0, u64: Amevtyper00El0, safe, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "feat-amu"
+))]
+read_sysreg!(amevtyper01_el0: s3_3_c13_c6_1, u64: Amevtyper01El0, safe, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "feat-amu"
+))]
+read_sysreg!(amevtyper02_el0: s3_3_c13_c6_2, u64: Amevtyper02El0, safe, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "feat-amu"
+))]
+read_sysreg!(amevtyper03_el0: s3_3_c13_c6_3, u64: Amevtyper03El0, safe, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "feat-amu"
+))]
+read_write_sysreg!(amuserenr_el0: s3_3_c13_c2_3, u64: AmuserenrEl0, safe_read, safe_write, fake::SYSREGS);
+
+/// Returns the value of the `AMEVCNTR0<n>_EL0` system register with the given index, or `None` if
+/// there is no such register.
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "feat-amu"
+))]
+pub fn read_amevcntr0_el0(n: usize) -> Option<Amevcntr00El0> {
+    match n {
+        0 => Some(read_amevcntr00_el0()),
+        1 => Some(read_amevcntr01_el0()),
+        2 => Some(read_amevcntr02_el0()),
+        3 => Some(read_amevcntr03_el0()),
+        _ => None,
+    }
+}
+
+/// Writes `value` to the `AMEVCNTR0<n>_EL0` system register with the given index.
+///
+/// # Panics
+///
+/// Panics if there is no register with the given index.
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "feat-amu"
+))]
+pub fn write_amevcntr0_el0(n: usize, value: Amevcntr00El0) {
+    match n {
+        0 => write_amevcntr00_el0(value),
+        1 => write_amevcntr01_el0(value),
+        2 => write_amevcntr02_el0(value),
+        3 => write_amevcntr03_el0(value),
+        _ => panic!("Invalid index {n} for AMEVCNTR0<n>_EL0"),
+    }
+}
+
+/// Returns the value of the `AMEVCNTR1<n>_EL0` system register with the given index, or `None` if
+/// there is no such register.
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "feat-amu"
+))]
+pub fn read_amevcntr1_el0(n: usize) -> Option<Amevcntr00El0> {
+    match n {
+        0 => Some(read_amevcntr10_el0()),
+        1 => Some(read_amevcntr11_el0()),
+        2 => Some(read_amevcntr12_el0()),
+        3 => Some(read_amevcntr13_el0()),
+        4 => Some(read_amevcntr14_el0()),
+        5 => Some(read_amevcntr15_el0()),
+        6 => Some(read_amevcntr16_el0()),
+        7 => Some(read_amevcntr17_el0()),
+        8 => Some(read_amevcntr18_el0()),
+        9 => Some(read_amevcntr19_el0()),
+        10 => Some(read_amevcntr110_el0()),
+        11 => Some(read_amevcntr111_el0()),
+        12 => Some(read_amevcntr112_el0()),
+        13 => Some(read_amevcntr113_el0()),
+        14 => Some(read_amevcntr114_el0()),
+        15 => Some(read_amevcntr115_el0()),
+        _ => None,
+    }
+}
+
+/// Writes `value` to the `AMEVCNTR1<n>_EL0` system register with the given index.
+///
+/// # Panics
+///
+/// Panics if there is no register with the given index.
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "feat-amu"
+))]
+pub fn write_amevcntr1_el0(n: usize, value: Amevcntr00El0) {
+    match n {
+        0 => write_amevcntr10_el0(value),
+        1 => write_amevcntr11_el0(value),
+        2 => write_amevcntr12_el0(value),
+        3 => write_amevcntr13_el0(value),
+        4 => write_amevcntr14_el0(value),
+        5 => write_amevcntr15_el0(value),
+        6 => write_amevcntr16_el0(value),
+        7 => write_amevcntr17_el0(value),
+        8 => write_amevcntr18_el0(value),
+        9 => write_amevcntr19_el0(value),
+        10 => write_amevcntr110_el0(value),
+        11 => write_amevcntr111_el0(value),
+        12 => write_amevcntr112_el0(value),
+        13 => write_amevcntr113_el0(value),
+        14 => write_amevcntr114_el0(value),
+        15 => write_amevcntr115_el0(value),
+        _ => panic!("Invalid index {n} for AMEVCNTR1<n>_EL0"),
+    }
+}
+
+/// Returns the value of the `AMEVTYPER0<n>_EL0` system register with the given index, or `None` if
+/// there is no such register.
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "feat-amu"
+))]
+pub fn read_amevtyper0_el0(n: usize) -> Option<Amevtyper00El0> {
+    match n {
+        0 => Some(read_amevtyper00_el0()),
+        1 => Some(read_amevtyper01_el0()),
+        2 => Some(read_amevtyper02_el0()),
+        3 => Some(read_amevtyper03_el0()),
+        _ => None,
+    }
+}
@@ -0,0 +1,17448 @@
+// SPDX-FileCopyrightText: Copyright The arm-sysregs Contributors.
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Other system registers.
+
+// This file is generated, do not edit manually.
+
+// Depending on the enabled features, the module might not use all of these imports.
+#![allow(unused_imports)]
+
+use crate::*;
+use bitflags::bitflags;
+use core::fmt::{self, Display, Formatter};
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `CONTEXTIDR_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct ContextidrEl1: u64 {
+    }
+}
+
+#[cfg(feature = "el1")]
+impl ContextidrEl1 {
+    /// Descriptor of the `PROCID` field, for use with [`FieldAccess`].
+    pub const PROCID_FIELD: Field<Self, u32, 0, 32> = Field::new("CONTEXTIDR_EL1", "PROCID");
+
+    /// Returns the value of the `PROCID` field.
+    pub const fn procid(self) -> u32 {
+        Self::PROCID_FIELD.extract(self.bits()) as u32
+    }
+
+    /// Sets the value of the `PROCID` field.
+    pub const fn set_procid(&mut self, value: u32) {
+        *self = Self::from_bits_retain(Self::PROCID_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PROCID` field set to the given value.
+    pub const fn with_procid(mut self, value: u32) -> Self {
+        self.set_procid(value);
+        self
+    }
+
+    /// Sets the value of the `PROCID` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_procid(&mut self, value: u32) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_procid(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PROCID` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_procid(self, value: u32) -> Result<Self, FieldOverflow> {
+        match Self::PROCID_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for ContextidrEl1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("ContextidrEl1");
+        let bits = self.bits();
+        s.field(
+            "PROCID",
+            &format_args!("{:#x}", Self::PROCID_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for ContextidrEl1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "ContextidrEl1 {{ PROCID: {=u64:#x} }}",
+            Self::PROCID_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for ContextidrEl1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for ContextidrEl1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl ContextidrEl1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("PROCID"),
+                old: Self::PROCID_FIELD.extract(self.bits()),
+                new: Self::PROCID_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_0000_0000_0000_0000_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_0000_0000_0000_0000_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl ContextidrEl1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl ContextidrEl1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `CONTEXTIDR_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct ContextidrEl2: u64 {
+    }
+}
+
+#[cfg(feature = "el2")]
+impl ContextidrEl2 {
+    /// Descriptor of the `PROCID` field, for use with [`FieldAccess`].
+    pub const PROCID_FIELD: Field<Self, u32, 0, 32> = Field::new("CONTEXTIDR_EL2", "PROCID");
+
+    /// Returns the value of the `PROCID` field.
+    pub const fn procid(self) -> u32 {
+        Self::PROCID_FIELD.extract(self.bits()) as u32
+    }
+
+    /// Sets the value of the `PROCID` field.
+    pub const fn set_procid(&mut self, value: u32) {
+        *self = Self::from_bits_retain(Self::PROCID_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PROCID` field set to the given value.
+    pub const fn with_procid(mut self, value: u32) -> Self {
+        self.set_procid(value);
+        self
+    }
+
+    /// Sets the value of the `PROCID` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_procid(&mut self, value: u32) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_procid(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PROCID` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_procid(self, value: u32) -> Result<Self, FieldOverflow> {
+        match Self::PROCID_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Display for ContextidrEl2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("ContextidrEl2");
+        let bits = self.bits();
+        s.field(
+            "PROCID",
+            &format_args!("{:#x}", Self::PROCID_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for ContextidrEl2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "ContextidrEl2 {{ PROCID: {=u64:#x} }}",
+            Self::PROCID_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for ContextidrEl2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for ContextidrEl2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl ContextidrEl2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("PROCID"),
+                old: Self::PROCID_FIELD.extract(self.bits()),
+                new: Self::PROCID_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_0000_0000_0000_0000_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_0000_0000_0000_0000_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl ContextidrEl2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl ContextidrEl2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `CPACR_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct CpacrEl1: u64 {
+        /// `TTA` bit.
+        const TTA = 1 << 28;
+        /// `E0POE` bit.
+        const E0POE = 1 << 29;
+        /// `TAM` bit.
+        const TAM = 1 << 30;
+        /// `TCPAC` bit.
+        const TCPAC = 1 << 31;
+        /// `E0TP0E` bit.
+        const E0TP0E = 1 << 32;
+        /// `E0TP1E` bit.
+        const E0TP1E = 1 << 33;
+    }
+}
+
+#[cfg(feature = "el1")]
+impl CpacrEl1 {
+    /// Descriptor of the `ZEN` field, for use with [`FieldAccess`].
+    pub const ZEN_FIELD: Field<Self, u8, 16, 2> = Field::new("CPACR_EL1", "ZEN");
+    /// Descriptor of the `FPEN` field, for use with [`FieldAccess`].
+    pub const FPEN_FIELD: Field<Self, u8, 20, 2> = Field::new("CPACR_EL1", "FPEN");
+    /// Descriptor of the `SMEN` field, for use with [`FieldAccess`].
+    pub const SMEN_FIELD: Field<Self, u8, 24, 2> = Field::new("CPACR_EL1", "SMEN");
+    /// Descriptor of the `TTA` field, for use with [`FieldAccess`].
+    pub const TTA_FIELD: Field<Self, bool, 28, 1> = Field::new("CPACR_EL1", "TTA");
+    /// Descriptor of the `E0POE` field, for use with [`FieldAccess`].
+    pub const E0POE_FIELD: Field<Self, bool, 29, 1> = Field::new("CPACR_EL1", "E0POE");
+    /// Descriptor of the `TAM` field, for use with [`FieldAccess`].
+    pub const TAM_FIELD: Field<Self, bool, 30, 1> = Field::new("CPACR_EL1", "TAM");
+    /// Descriptor of the `TCPAC` field, for use with [`FieldAccess`].
+    pub const TCPAC_FIELD: Field<Self, bool, 31, 1> = Field::new("CPACR_EL1", "TCPAC");
+    /// Descriptor of the `E0TP0E` field, for use with [`FieldAccess`].
+    pub const E0TP0E_FIELD: Field<Self, bool, 32, 1> = Field::new("CPACR_EL1", "E0TP0E");
+    /// Descriptor of the `E0TP1E` field, for use with [`FieldAccess`].
+    pub const E0TP1E_FIELD: Field<Self, bool, 33, 1> = Field::new("CPACR_EL1", "E0TP1E");
+
+    /// Returns the value of the `ZEN` field.
+    pub const fn zen(self) -> u8 {
+        Self::ZEN_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `ZEN` field.
+    pub const fn set_zen(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::ZEN_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ZEN` field set to the given value.
+    pub const fn with_zen(mut self, value: u8) -> Self {
+        self.set_zen(value);
+        self
+    }
+
+    /// Sets the value of the `ZEN` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_zen(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_zen(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ZEN` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_zen(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::ZEN_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `FPEN` field.
+    pub const fn fpen(self) -> u8 {
+        Self::FPEN_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `FPEN` field.
+    pub const fn set_fpen(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::FPEN_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `FPEN` field set to the given value.
+    pub const fn with_fpen(mut self, value: u8) -> Self {
+        self.set_fpen(value);
+        self
+    }
+
+    /// Sets the value of the `FPEN` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_fpen(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_fpen(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `FPEN` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_fpen(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::FPEN_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SMEN` field.
+    pub const fn smen(self) -> u8 {
+        Self::SMEN_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SMEN` field.
+    pub const fn set_smen(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SMEN_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SMEN` field set to the given value.
+    pub const fn with_smen(mut self, value: u8) -> Self {
+        self.set_smen(value);
+        self
+    }
+
+    /// Sets the value of the `SMEN` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_smen(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_smen(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SMEN` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_smen(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SMEN_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for CpacrEl1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("CpacrEl1");
+        let bits = self.bits();
+        s.field("ZEN", &Self::ZEN_FIELD.extract(bits));
+        s.field("FPEN", &Self::FPEN_FIELD.extract(bits));
+        s.field("SMEN", &Self::SMEN_FIELD.extract(bits));
+        s.field("TTA", &Self::TTA_FIELD.extract(bits));
+        s.field("E0POE", &Self::E0POE_FIELD.extract(bits));
+        s.field("TAM", &Self::TAM_FIELD.extract(bits));
+        s.field("TCPAC", &Self::TCPAC_FIELD.extract(bits));
+        s.field("E0TP0E", &Self::E0TP0E_FIELD.extract(bits));
+        s.field("E0TP1E", &Self::E0TP1E_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for CpacrEl1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "CpacrEl1 {{ ZEN: {=u64}, FPEN: {=u64}, SMEN: {=u64}, TTA: {=u64}, E0POE: {=u64}, TAM: {=u64}, TCPAC: {=u64}, E0TP0E: {=u64}, E0TP1E: {=u64} }}",
+            Self::ZEN_FIELD.extract(self.bits()),
+            Self::FPEN_FIELD.extract(self.bits()),
+            Self::SMEN_FIELD.extract(self.bits()),
+            Self::TTA_FIELD.extract(self.bits()),
+            Self::E0POE_FIELD.extract(self.bits()),
+            Self::TAM_FIELD.extract(self.bits()),
+            Self::TCPAC_FIELD.extract(self.bits()),
+            Self::E0TP0E_FIELD.extract(self.bits()),
+            Self::E0TP1E_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for CpacrEl1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for CpacrEl1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl CpacrEl1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("ZEN"),
+                old: Self::ZEN_FIELD.extract(self.bits()),
+                new: Self::ZEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FPEN"),
+                old: Self::FPEN_FIELD.extract(self.bits()),
+                new: Self::FPEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SMEN"),
+                old: Self::SMEN_FIELD.extract(self.bits()),
+                new: Self::SMEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TTA"),
+                old: Self::TTA_FIELD.extract(self.bits()),
+                new: Self::TTA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("E0POE"),
+                old: Self::E0POE_FIELD.extract(self.bits()),
+                new: Self::E0POE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TAM"),
+                old: Self::TAM_FIELD.extract(self.bits()),
+                new: Self::TAM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TCPAC"),
+                old: Self::TCPAC_FIELD.extract(self.bits()),
+                new: Self::TCPAC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("E0TP0E"),
+                old: Self::E0TP0E_FIELD.extract(self.bits()),
+                new: Self::E0TP0E_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("E0TP1E"),
+                old: Self::E0TP1E_FIELD.extract(self.bits()),
+                new: Self::E0TP1E_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1100_0000_1100_1100_1100_1111_1111_1111_1111,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1100_0000_1100_1100_1100_1111_1111_1111_1111,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl CpacrEl1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl CpacrEl1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `CPTR_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct CptrEl2: u64 {
+        /// `TZ` bit.
+        const TZ = 1 << 8;
+        /// `TFP` bit.
+        const TFP = 1 << 10;
+        /// `TSM` bit.
+        const TSM = 1 << 12;
+        /// `E0POE` bit.
+        const E0POE = 1 << 29;
+        /// `TAM` bit.
+        const TAM = 1 << 30;
+        /// `TCPAC` bit.
+        const TCPAC = 1 << 31;
+        /// `E0TP0E` bit.
+        const E0TP0E = 1 << 32;
+        /// `E0TP1E` bit.
+        const E0TP1E = 1 << 33;
+    }
+}
+
+#[cfg(feature = "el2")]
+impl CptrEl2 {
+    /// Descriptor of the `TZ` field, for use with [`FieldAccess`].
+    pub const TZ_FIELD: Field<Self, bool, 8, 1> = Field::new("CPTR_EL2", "TZ");
+    /// Descriptor of the `TFP` field, for use with [`FieldAccess`].
+    pub const TFP_FIELD: Field<Self, bool, 10, 1> = Field::new("CPTR_EL2", "TFP");
+    /// Descriptor of the `TSM` field, for use with [`FieldAccess`].
+    pub const TSM_FIELD: Field<Self, bool, 12, 1> = Field::new("CPTR_EL2", "TSM");
+    /// Descriptor of the `ZEN` field, for use with [`FieldAccess`].
+    pub const ZEN_FIELD: Field<Self, u8, 16, 2> = Field::new("CPTR_EL2", "ZEN");
+    /// Descriptor of the `FPEN` field, for use with [`FieldAccess`].
+    pub const FPEN_FIELD: Field<Self, u8, 20, 2> = Field::new("CPTR_EL2", "FPEN");
+    /// Descriptor of the `SMEN` field, for use with [`FieldAccess`].
+    pub const SMEN_FIELD: Field<Self, u8, 24, 2> = Field::new("CPTR_EL2", "SMEN");
+    /// Descriptor of the `E0POE` field, for use with [`FieldAccess`].
+    pub const E0POE_FIELD: Field<Self, bool, 29, 1> = Field::new("CPTR_EL2", "E0POE");
+    /// Descriptor of the `TAM` field, for use with [`FieldAccess`].
+    pub const TAM_FIELD: Field<Self, bool, 30, 1> = Field::new("CPTR_EL2", "TAM");
+    /// Descriptor of the `TCPAC` field, for use with [`FieldAccess`].
+    pub const TCPAC_FIELD: Field<Self, bool, 31, 1> = Field::new("CPTR_EL2", "TCPAC");
+    /// Descriptor of the `E0TP0E` field, for use with [`FieldAccess`].
+    pub const E0TP0E_FIELD: Field<Self, bool, 32, 1> = Field::new("CPTR_EL2", "E0TP0E");
+    /// Descriptor of the `E0TP1E` field, for use with [`FieldAccess`].
+    pub const E0TP1E_FIELD: Field<Self, bool, 33, 1> = Field::new("CPTR_EL2", "E0TP1E");
+
+    /// Returns the value of the `ZEN` field.
+    pub const fn zen(self) -> u8 {
+        Self::ZEN_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `ZEN` field.
+    pub const fn set_zen(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::ZEN_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ZEN` field set to the given value.
+    pub const fn with_zen(mut self, value: u8) -> Self {
+        self.set_zen(value);
+        self
+    }
+
+    /// Sets the value of the `ZEN` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_zen(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_zen(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ZEN` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_zen(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::ZEN_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `FPEN` field.
+    pub const fn fpen(self) -> u8 {
+        Self::FPEN_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `FPEN` field.
+    pub const fn set_fpen(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::FPEN_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `FPEN` field set to the given value.
+    pub const fn with_fpen(mut self, value: u8) -> Self {
+        self.set_fpen(value);
+        self
+    }
+
+    /// Sets the value of the `FPEN` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_fpen(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_fpen(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `FPEN` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_fpen(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::FPEN_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SMEN` field.
+    pub const fn smen(self) -> u8 {
+        Self::SMEN_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SMEN` field.
+    pub const fn set_smen(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SMEN_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SMEN` field set to the given value.
+    pub const fn with_smen(mut self, value: u8) -> Self {
+        self.set_smen(value);
+        self
+    }
+
+    /// Sets the value of the `SMEN` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_smen(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_smen(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SMEN` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_smen(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SMEN_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Display for CptrEl2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("CptrEl2");
+        let bits = self.bits();
+        s.field("TZ", &Self::TZ_FIELD.extract(bits));
+        s.field("TFP", &Self::TFP_FIELD.extract(bits));
+        s.field("TSM", &Self::TSM_FIELD.extract(bits));
+        s.field("ZEN", &Self::ZEN_FIELD.extract(bits));
+        s.field("FPEN", &Self::FPEN_FIELD.extract(bits));
+        s.field("SMEN", &Self::SMEN_FIELD.extract(bits));
+        s.field("E0POE", &Self::E0POE_FIELD.extract(bits));
+        s.field("TAM", &Self::TAM_FIELD.extract(bits));
+        s.field("TCPAC", &Self::TCPAC_FIELD.extract(bits));
+        s.field("E0TP0E", &Self::E0TP0E_FIELD.extract(bits));
+        s.field("E0TP1E", &Self::E0TP1E_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for CptrEl2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "CptrEl2 {{ TZ: {=u64}, TFP: {=u64}, TSM: {=u64}, ZEN: {=u64}, FPEN: {=u64}, SMEN: {=u64}, E0POE: {=u64}, TAM: {=u64}, TCPAC: {=u64}, E0TP0E: {=u64}, E0TP1E: {=u64} }}",
+            Self::TZ_FIELD.extract(self.bits()),
+            Self::TFP_FIELD.extract(self.bits()),
+            Self::TSM_FIELD.extract(self.bits()),
+            Self::ZEN_FIELD.extract(self.bits()),
+            Self::FPEN_FIELD.extract(self.bits()),
+            Self::SMEN_FIELD.extract(self.bits()),
+            Self::E0POE_FIELD.extract(self.bits()),
+            Self::TAM_FIELD.extract(self.bits()),
+            Self::TCPAC_FIELD.extract(self.bits()),
+            Self::E0TP0E_FIELD.extract(self.bits()),
+            Self::E0TP1E_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for CptrEl2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for CptrEl2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl CptrEl2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("TZ"),
+                old: Self::TZ_FIELD.extract(self.bits()),
+                new: Self::TZ_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TFP"),
+                old: Self::TFP_FIELD.extract(self.bits()),
+                new: Self::TFP_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TSM"),
+                old: Self::TSM_FIELD.extract(self.bits()),
+                new: Self::TSM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ZEN"),
+                old: Self::ZEN_FIELD.extract(self.bits()),
+                new: Self::ZEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FPEN"),
+                old: Self::FPEN_FIELD.extract(self.bits()),
+                new: Self::FPEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SMEN"),
+                old: Self::SMEN_FIELD.extract(self.bits()),
+                new: Self::SMEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("E0POE"),
+                old: Self::E0POE_FIELD.extract(self.bits()),
+                new: Self::E0POE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TAM"),
+                old: Self::TAM_FIELD.extract(self.bits()),
+                new: Self::TAM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TCPAC"),
+                old: Self::TCPAC_FIELD.extract(self.bits()),
+                new: Self::TCPAC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("E0TP0E"),
+                old: Self::E0TP0E_FIELD.extract(self.bits()),
+                new: Self::E0TP0E_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("E0TP1E"),
+                old: Self::E0TP1E_FIELD.extract(self.bits()),
+                new: Self::E0TP1E_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1100_0001_1100_1100_1100_1110_1010_1111_1111,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1100_0001_1100_1100_1100_1110_1010_1111_1111,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl CptrEl2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl CptrEl2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `CPTR_EL2` system register value, when `HCR_EL2.E2H` is 0.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct CptrEl2E2h0: u64 {
+        /// RES1 bits in the `CPTR_EL2` register.
+        const RES1 = 0b10_0010_1111_1111;
+        /// `TZ` bit.
+        const TZ = 1 << 8;
+        /// `TFP` bit.
+        const TFP = 1 << 10;
+        /// `TSM` bit.
+        const TSM = 1 << 12;
+        /// `TTA` bit.
+        const TTA = 1 << 20;
+        /// `TAM` bit.
+        const TAM = 1 << 30;
+        /// `TCPAC` bit.
+        const TCPAC = 1 << 31;
+    }
+}
+
+#[cfg(feature = "el2")]
+impl CptrEl2E2h0 {
+    /// Descriptor of the `TZ` field, for use with [`FieldAccess`].
+    pub const TZ_FIELD: Field<Self, bool, 8, 1> = Field::new("CPTR_EL2", "TZ");
+    /// Descriptor of the `TFP` field, for use with [`FieldAccess`].
+    pub const TFP_FIELD: Field<Self, bool, 10, 1> = Field::new("CPTR_EL2", "TFP");
+    /// Descriptor of the `TSM` field, for use with [`FieldAccess`].
+    pub const TSM_FIELD: Field<Self, bool, 12, 1> = Field::new("CPTR_EL2", "TSM");
+    /// Descriptor of the `TTA` field, for use with [`FieldAccess`].
+    pub const TTA_FIELD: Field<Self, bool, 20, 1> = Field::new("CPTR_EL2", "TTA");
+    /// Descriptor of the `TAM` field, for use with [`FieldAccess`].
+    pub const TAM_FIELD: Field<Self, bool, 30, 1> = Field::new("CPTR_EL2", "TAM");
+    /// Descriptor of the `TCPAC` field, for use with [`FieldAccess`].
+    pub const TCPAC_FIELD: Field<Self, bool, 31, 1> = Field::new("CPTR_EL2", "TCPAC");
+}
+
+#[cfg(feature = "el2")]
+impl Display for CptrEl2E2h0 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("CptrEl2E2h0");
+        let bits = self.bits();
+        s.field("TZ", &Self::TZ_FIELD.extract(bits));
+        s.field("TFP", &Self::TFP_FIELD.extract(bits));
+        s.field("TSM", &Self::TSM_FIELD.extract(bits));
+        s.field("TTA", &Self::TTA_FIELD.extract(bits));
+        s.field("TAM", &Self::TAM_FIELD.extract(bits));
+        s.field("TCPAC", &Self::TCPAC_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for CptrEl2E2h0 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "CptrEl2E2h0 {{ TZ: {=u64}, TFP: {=u64}, TSM: {=u64}, TTA: {=u64}, TAM: {=u64}, TCPAC: {=u64} }}",
+            Self::TZ_FIELD.extract(self.bits()),
+            Self::TFP_FIELD.extract(self.bits()),
+            Self::TSM_FIELD.extract(self.bits()),
+            Self::TTA_FIELD.extract(self.bits()),
+            Self::TAM_FIELD.extract(self.bits()),
+            Self::TCPAC_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for CptrEl2E2h0 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for CptrEl2E2h0 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl CptrEl2E2h0 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("TZ"),
+                old: Self::TZ_FIELD.extract(self.bits()),
+                new: Self::TZ_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TFP"),
+                old: Self::TFP_FIELD.extract(self.bits()),
+                new: Self::TFP_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TSM"),
+                old: Self::TSM_FIELD.extract(self.bits()),
+                new: Self::TSM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TTA"),
+                old: Self::TTA_FIELD.extract(self.bits()),
+                new: Self::TTA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TAM"),
+                old: Self::TAM_FIELD.extract(self.bits()),
+                new: Self::TAM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TCPAC"),
+                old: Self::TCPAC_FIELD.extract(self.bits()),
+                new: Self::TCPAC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_0011_1111_1110_1111_1110_1010_1111_1111,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_0011_1111_1110_1111_1110_1010_1111_1111,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl CptrEl2E2h0 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b10_0010_1111_1111;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl CptrEl2E2h0 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `CPTR_EL2` system register value, when `HCR_EL2.E2H` is 1.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct CptrEl2E2h1: u64 {
+        /// `TTA` bit.
+        const TTA = 1 << 28;
+        /// `E0POE` bit.
+        const E0POE = 1 << 29;
+        /// `TAM` bit.
+        const TAM = 1 << 30;
+        /// `TCPAC` bit.
+        const TCPAC = 1 << 31;
+        /// `E0TP0E` bit.
+        const E0TP0E = 1 << 32;
+        /// `E0TP1E` bit.
+        const E0TP1E = 1 << 33;
+    }
+}
+
+#[cfg(feature = "el2")]
+impl CptrEl2E2h1 {
+    /// Descriptor of the `ZEN` field, for use with [`FieldAccess`].
+    pub const ZEN_FIELD: Field<Self, u8, 16, 2> = Field::new("CPTR_EL2", "ZEN");
+    /// Descriptor of the `FPEN` field, for use with [`FieldAccess`].
+    pub const FPEN_FIELD: Field<Self, u8, 20, 2> = Field::new("CPTR_EL2", "FPEN");
+    /// Descriptor of the `SMEN` field, for use with [`FieldAccess`].
+    pub const SMEN_FIELD: Field<Self, u8, 24, 2> = Field::new("CPTR_EL2", "SMEN");
+    /// Descriptor of the `TTA` field, for use with [`FieldAccess`].
+    pub const TTA_FIELD: Field<Self, bool, 28, 1> = Field::new("CPTR_EL2", "TTA");
+    /// Descriptor of the `E0POE` field, for use with [`FieldAccess`].
+    pub const E0POE_FIELD: Field<Self, bool, 29, 1> = Field::new("CPTR_EL2", "E0POE");
+    /// Descriptor of the `TAM` field, for use with [`FieldAccess`].
+    pub const TAM_FIELD: Field<Self, bool, 30, 1> = Field::new("CPTR_EL2", "TAM");
+    /// Descriptor of the `TCPAC` field, for use with [`FieldAccess`].
+    pub const TCPAC_FIELD: Field<Self, bool, 31, 1> = Field::new("CPTR_EL2", "TCPAC");
+    /// Descriptor of the `E0TP0E` field, for use with [`FieldAccess`].
+    pub const E0TP0E_FIELD: Field<Self, bool, 32, 1> = Field::new("CPTR_EL2", "E0TP0E");
+    /// Descriptor of the `E0TP1E` field, for use with [`FieldAccess`].
+    pub const E0TP1E_FIELD: Field<Self, bool, 33, 1> = Field::new("CPTR_EL2", "E0TP1E");
+
+    /// Returns the value of the `ZEN` field.
+    pub const fn zen(self) -> u8 {
+        Self::ZEN_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `ZEN` field.
+    pub const fn set_zen(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::ZEN_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ZEN` field set to the given value.
+    pub const fn with_zen(mut self, value: u8) -> Self {
+        self.set_zen(value);
+        self
+    }
+
+    /// Sets the value of the `ZEN` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_zen(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_zen(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ZEN` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_zen(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::ZEN_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `FPEN` field.
+    pub const fn fpen(self) -> u8 {
+        Self::FPEN_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `FPEN` field.
+    pub const fn set_fpen(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::FPEN_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `FPEN` field set to the given value.
+    pub const fn with_fpen(mut self, value: u8) -> Self {
+        self.set_fpen(value);
+        self
+    }
+
+    /// Sets the value of the `FPEN` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_fpen(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_fpen(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `FPEN` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_fpen(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::FPEN_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SMEN` field.
+    pub const fn smen(self) -> u8 {
+        Self::SMEN_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SMEN` field.
+    pub const fn set_smen(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SMEN_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SMEN` field set to the given value.
+    pub const fn with_smen(mut self, value: u8) -> Self {
+        self.set_smen(value);
+        self
+    }
+
+    /// Sets the value of the `SMEN` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_smen(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_smen(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SMEN` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_smen(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SMEN_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Display for CptrEl2E2h1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("CptrEl2E2h1");
+        let bits = self.bits();
+        s.field("ZEN", &Self::ZEN_FIELD.extract(bits));
+        s.field("FPEN", &Self::FPEN_FIELD.extract(bits));
+        s.field("SMEN", &Self::SMEN_FIELD.extract(bits));
+        s.field("TTA", &Self::TTA_FIELD.extract(bits));
+        s.field("E0POE", &Self::E0POE_FIELD.extract(bits));
+        s.field("TAM", &Self::TAM_FIELD.extract(bits));
+        s.field("TCPAC", &Self::TCPAC_FIELD.extract(bits));
+        s.field("E0TP0E", &Self::E0TP0E_FIELD.extract(bits));
+        s.field("E0TP1E", &Self::E0TP1E_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for CptrEl2E2h1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "CptrEl2E2h1 {{ ZEN: {=u64}, FPEN: {=u64}, SMEN: {=u64}, TTA: {=u64}, E0POE: {=u64}, TAM: {=u64}, TCPAC: {=u64}, E0TP0E: {=u64}, E0TP1E: {=u64} }}",
+            Self::ZEN_FIELD.extract(self.bits()),
+            Self::FPEN_FIELD.extract(self.bits()),
+            Self::SMEN_FIELD.extract(self.bits()),
+            Self::TTA_FIELD.extract(self.bits()),
+            Self::E0POE_FIELD.extract(self.bits()),
+            Self::TAM_FIELD.extract(self.bits()),
+            Self::TCPAC_FIELD.extract(self.bits()),
+            Self::E0TP0E_FIELD.extract(self.bits()),
+            Self::E0TP1E_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for CptrEl2E2h1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for CptrEl2E2h1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl CptrEl2E2h1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("ZEN"),
+                old: Self::ZEN_FIELD.extract(self.bits()),
+                new: Self::ZEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FPEN"),
+                old: Self::FPEN_FIELD.extract(self.bits()),
+                new: Self::FPEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SMEN"),
+                old: Self::SMEN_FIELD.extract(self.bits()),
+                new: Self::SMEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TTA"),
+                old: Self::TTA_FIELD.extract(self.bits()),
+                new: Self::TTA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("E0POE"),
+                old: Self::E0POE_FIELD.extract(self.bits()),
+                new: Self::E0POE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TAM"),
+                old: Self::TAM_FIELD.extract(self.bits()),
+                new: Self::TAM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TCPAC"),
+                old: Self::TCPAC_FIELD.extract(self.bits()),
+                new: Self::TCPAC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("E0TP0E"),
+                old: Self::E0TP0E_FIELD.extract(self.bits()),
+                new: Self::E0TP0E_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("E0TP1E"),
+                old: Self::E0TP1E_FIELD.extract(self.bits()),
+                new: Self::E0TP1E_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1100_0000_1100_1100_1100_1111_1111_1111_1111,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1100_0000_1100_1100_1100_1111_1111_1111_1111,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl CptrEl2E2h1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl CptrEl2E2h1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl CptrEl2 {
+    /// Returns the value as a `CptrEl2E2h0`, for use when `HCR_EL2.E2H` is 0.
+    pub const fn e2h0(self) -> CptrEl2E2h0 {
+        CptrEl2E2h0::from_bits_retain(self.bits())
+    }
+
+    /// Returns the value as a `CptrEl2E2h1`, for use when `HCR_EL2.E2H` is 1.
+    pub const fn e2h1(self) -> CptrEl2E2h1 {
+        CptrEl2E2h1::from_bits_retain(self.bits())
+    }
+}
+
+#[cfg(feature = "el2")]
+impl From<CptrEl2E2h0> for CptrEl2 {
+    fn from(value: CptrEl2E2h0) -> Self {
+        Self::from_bits_retain(value.bits())
+    }
+}
+
+#[cfg(feature = "el2")]
+impl From<CptrEl2E2h1> for CptrEl2 {
+    fn from(value: CptrEl2E2h1) -> Self {
+        Self::from_bits_retain(value.bits())
+    }
+}
+
+#[cfg(feature = "el3")]
+bitflags! {
+    /// `CPTR_EL3` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct CptrEl3: u64 {
+        /// Do not trap execution of SVE instructions.
+        const EZ = 1 << 8;
+        /// Trap Advanced SIMD instructions execution.
+        const TFP = 1 << 10;
+        /// When FEAT_SME is implemented, do not trap SME instructions and system registers accesses.
+        const ESM = 1 << 12;
+        /// Trap trace system register accesses.
+        const TTA = 1 << 20;
+        /// When FEAT_AMUv1 implemented trap accesses from EL2/EL1/EL0 to AMU registers.
+        const TAM = 1 << 30;
+        /// Trap EL2 accesses to CPTR_EL2/HCPTR, and EL2/EL1 accesses to CPACR_EL1/CPACR.
+        const TCPAC = 1 << 31;
+    }
+}
+
+#[cfg(feature = "el3")]
+impl CptrEl3 {
+    /// Descriptor of the `EZ` field, for use with [`FieldAccess`].
+    pub const EZ_FIELD: Field<Self, bool, 8, 1> = Field::new("CPTR_EL3", "EZ");
+    /// Descriptor of the `TFP` field, for use with [`FieldAccess`].
+    pub const TFP_FIELD: Field<Self, bool, 10, 1> = Field::new("CPTR_EL3", "TFP");
+    /// Descriptor of the `ESM` field, for use with [`FieldAccess`].
+    pub const ESM_FIELD: Field<Self, bool, 12, 1> = Field::new("CPTR_EL3", "ESM");
+    /// Descriptor of the `TTA` field, for use with [`FieldAccess`].
+    pub const TTA_FIELD: Field<Self, bool, 20, 1> = Field::new("CPTR_EL3", "TTA");
+    /// Descriptor of the `TAM` field, for use with [`FieldAccess`].
+    pub const TAM_FIELD: Field<Self, bool, 30, 1> = Field::new("CPTR_EL3", "TAM");
+    /// Descriptor of the `TCPAC` field, for use with [`FieldAccess`].
+    pub const TCPAC_FIELD: Field<Self, bool, 31, 1> = Field::new("CPTR_EL3", "TCPAC");
+}
+
+#[cfg(feature = "el3")]
+impl Display for CptrEl3 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("CptrEl3");
+        let bits = self.bits();
+        s.field("EZ", &Self::EZ_FIELD.extract(bits));
+        s.field("TFP", &Self::TFP_FIELD.extract(bits));
+        s.field("ESM", &Self::ESM_FIELD.extract(bits));
+        s.field("TTA", &Self::TTA_FIELD.extract(bits));
+        s.field("TAM", &Self::TAM_FIELD.extract(bits));
+        s.field("TCPAC", &Self::TCPAC_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el3")]
+impl defmt::Format for CptrEl3 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "CptrEl3 {{ EZ: {=u64}, TFP: {=u64}, ESM: {=u64}, TTA: {=u64}, TAM: {=u64}, TCPAC: {=u64} }}",
+            Self::EZ_FIELD.extract(self.bits()),
+            Self::TFP_FIELD.extract(self.bits()),
+            Self::ESM_FIELD.extract(self.bits()),
+            Self::TTA_FIELD.extract(self.bits()),
+            Self::TAM_FIELD.extract(self.bits()),
+            Self::TCPAC_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el3")]
+impl serde::Serialize for CptrEl3 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el3")]
+impl<'de> serde::Deserialize<'de> for CptrEl3 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el3")]
+impl CptrEl3 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("EZ"),
+                old: Self::EZ_FIELD.extract(self.bits()),
+                new: Self::EZ_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TFP"),
+                old: Self::TFP_FIELD.extract(self.bits()),
+                new: Self::TFP_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ESM"),
+                old: Self::ESM_FIELD.extract(self.bits()),
+                new: Self::ESM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TTA"),
+                old: Self::TTA_FIELD.extract(self.bits()),
+                new: Self::TTA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TAM"),
+                old: Self::TAM_FIELD.extract(self.bits()),
+                new: Self::TAM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TCPAC"),
+                old: Self::TCPAC_FIELD.extract(self.bits()),
+                new: Self::TCPAC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_0011_1111_1110_1111_1110_1010_1111_1111,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_0011_1111_1110_1111_1110_1010_1111_1111,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el3")]
+impl CptrEl3 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el3")]
+impl CptrEl3 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+bitflags! {
+    /// `CurrentEL` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct Currentel: u64 {
+    }
+}
+
+impl Currentel {
+    /// Descriptor of the `EL` field, for use with [`FieldAccess`].
+    pub const EL_FIELD: Field<Self, u8, 2, 2> = Field::new("CurrentEL", "EL");
+
+    /// Returns the value of the `EL` field.
+    pub const fn el(self) -> u8 {
+        Self::EL_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `EL` field.
+    pub const fn set_el(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::EL_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `EL` field set to the given value.
+    pub const fn with_el(mut self, value: u8) -> Self {
+        self.set_el(value);
+        self
+    }
+
+    /// Sets the value of the `EL` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_el(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_el(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `EL` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_el(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::EL_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl Display for Currentel {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("Currentel");
+        let bits = self.bits();
+        s.field("EL", &Self::EL_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for Currentel {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "Currentel {{ EL: {=u64} }}",
+            Self::EL_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Currentel {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Currentel {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+impl Currentel {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("EL"),
+                old: Self::EL_FIELD.extract(self.bits()),
+                new: Self::EL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_0011,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_0011,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+impl Currentel {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+impl Currentel {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+bitflags! {
+    /// `DAIF` system register value.
+    ///
+    /// Interrupt Mask Bits
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct Daif: u64 {
+        /// `F` bit.
+        const F = 1 << 6;
+        /// `I` bit.
+        const I = 1 << 7;
+        /// `A` bit.
+        const A = 1 << 8;
+        /// `D` bit.
+        const D = 1 << 9;
+    }
+}
+
+impl Daif {
+    /// Descriptor of the `F` field, for use with [`FieldAccess`].
+    pub const F_FIELD: Field<Self, bool, 6, 1> = Field::new("DAIF", "F");
+    /// Descriptor of the `I` field, for use with [`FieldAccess`].
+    pub const I_FIELD: Field<Self, bool, 7, 1> = Field::new("DAIF", "I");
+    /// Descriptor of the `A` field, for use with [`FieldAccess`].
+    pub const A_FIELD: Field<Self, bool, 8, 1> = Field::new("DAIF", "A");
+    /// Descriptor of the `D` field, for use with [`FieldAccess`].
+    pub const D_FIELD: Field<Self, bool, 9, 1> = Field::new("DAIF", "D");
+}
+
+impl Display for Daif {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("Daif");
+        let bits = self.bits();
+        s.field("F", &Self::F_FIELD.extract(bits));
+        s.field("I", &Self::I_FIELD.extract(bits));
+        s.field("A", &Self::A_FIELD.extract(bits));
+        s.field("D", &Self::D_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for Daif {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "Daif {{ F: {=u64}, I: {=u64}, A: {=u64}, D: {=u64} }}",
+            Self::F_FIELD.extract(self.bits()),
+            Self::I_FIELD.extract(self.bits()),
+            Self::A_FIELD.extract(self.bits()),
+            Self::D_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Daif {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Daif {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+impl Daif {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("F"),
+                old: Self::F_FIELD.extract(self.bits()),
+                new: Self::F_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("I"),
+                old: Self::I_FIELD.extract(self.bits()),
+                new: Self::I_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("A"),
+                old: Self::A_FIELD.extract(self.bits()),
+                new: Self::A_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("D"),
+                old: Self::D_FIELD.extract(self.bits()),
+                new: Self::D_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1100_0011_1111,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1100_0011_1111,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+impl Daif {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+impl Daif {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+bitflags! {
+    /// `DIT` system register value.
+    ///
+    /// Data Independent Timing.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct Dit: u64 {
+        /// Enable data independent timing.
+        const DIT = 1 << 24;
+    }
+}
+
+impl Dit {
+    /// Descriptor of the `DIT` field, for use with [`FieldAccess`].
+    pub const DIT_FIELD: Field<Self, bool, 24, 1> = Field::new("DIT", "DIT");
+}
+
+impl Display for Dit {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("Dit");
+        let bits = self.bits();
+        s.field("DIT", &Self::DIT_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for Dit {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "Dit {{ DIT: {=u64} }}",
+            Self::DIT_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Dit {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Dit {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+impl Dit {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("DIT"),
+                old: Self::DIT_FIELD.extract(self.bits()),
+                new: Self::DIT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1110_1111_1111_1111_1111_1111_1111,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1110_1111_1111_1111_1111_1111_1111,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+impl Dit {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+impl Dit {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [(
+            Self::DIT_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Dit),
+            UnimplementedField {
+                register: "DIT",
+                field: "DIT",
+                requires: "FEAT_DIT",
+            },
+        )]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+bitflags! {
+    /// `FPCR` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct Fpcr: u64 {
+        /// `FIZ` bit.
+        const FIZ = 1 << 0;
+        /// `AH` bit.
+        const AH = 1 << 1;
+        /// `NEP` bit.
+        const NEP = 1 << 2;
+        /// `IOE` bit.
+        const IOE = 1 << 8;
+        /// `DZE` bit.
+        const DZE = 1 << 9;
+        /// `OFE` bit.
+        const OFE = 1 << 10;
+        /// `UFE` bit.
+        const UFE = 1 << 11;
+        /// `IXE` bit.
+        const IXE = 1 << 12;
+        /// `EBF` bit.
+        const EBF = 1 << 13;
+        /// `IDE` bit.
+        const IDE = 1 << 15;
+        /// `FZ16` bit.
+        const FZ16 = 1 << 19;
+        /// `FZ` bit.
+        const FZ = 1 << 24;
+        /// `DN` bit.
+        const DN = 1 << 25;
+        /// `AHP` bit.
+        const AHP = 1 << 26;
+    }
+}
+
+impl Fpcr {
+    /// Descriptor of the `FIZ` field, for use with [`FieldAccess`].
+    pub const FIZ_FIELD: Field<Self, bool, 0, 1> = Field::new("FPCR", "FIZ");
+    /// Descriptor of the `AH` field, for use with [`FieldAccess`].
+    pub const AH_FIELD: Field<Self, bool, 1, 1> = Field::new("FPCR", "AH");
+    /// Descriptor of the `NEP` field, for use with [`FieldAccess`].
+    pub const NEP_FIELD: Field<Self, bool, 2, 1> = Field::new("FPCR", "NEP");
+    /// Descriptor of the `IOE` field, for use with [`FieldAccess`].
+    pub const IOE_FIELD: Field<Self, bool, 8, 1> = Field::new("FPCR", "IOE");
+    /// Descriptor of the `DZE` field, for use with [`FieldAccess`].
+    pub const DZE_FIELD: Field<Self, bool, 9, 1> = Field::new("FPCR", "DZE");
+    /// Descriptor of the `OFE` field, for use with [`FieldAccess`].
+    pub const OFE_FIELD: Field<Self, bool, 10, 1> = Field::new("FPCR", "OFE");
+    /// Descriptor of the `UFE` field, for use with [`FieldAccess`].
+    pub const UFE_FIELD: Field<Self, bool, 11, 1> = Field::new("FPCR", "UFE");
+    /// Descriptor of the `IXE` field, for use with [`FieldAccess`].
+    pub const IXE_FIELD: Field<Self, bool, 12, 1> = Field::new("FPCR", "IXE");
+    /// Descriptor of the `EBF` field, for use with [`FieldAccess`].
+    pub const EBF_FIELD: Field<Self, bool, 13, 1> = Field::new("FPCR", "EBF");
+    /// Descriptor of the `IDE` field, for use with [`FieldAccess`].
+    pub const IDE_FIELD: Field<Self, bool, 15, 1> = Field::new("FPCR", "IDE");
+    /// Descriptor of the `Len` field, for use with [`FieldAccess`].
+    pub const LEN_FIELD: Field<Self, u8, 16, 3> = Field::new("FPCR", "Len");
+    /// Descriptor of the `FZ16` field, for use with [`FieldAccess`].
+    pub const FZ16_FIELD: Field<Self, bool, 19, 1> = Field::new("FPCR", "FZ16");
+    /// Descriptor of the `Stride` field, for use with [`FieldAccess`].
+    pub const STRIDE_FIELD: Field<Self, u8, 20, 2> = Field::new("FPCR", "Stride");
+    /// Descriptor of the `RMode` field, for use with [`FieldAccess`].
+    pub const RMODE_FIELD: Field<Self, u8, 22, 2> = Field::new("FPCR", "RMode");
+    /// Descriptor of the `FZ` field, for use with [`FieldAccess`].
+    pub const FZ_FIELD: Field<Self, bool, 24, 1> = Field::new("FPCR", "FZ");
+    /// Descriptor of the `DN` field, for use with [`FieldAccess`].
+    pub const DN_FIELD: Field<Self, bool, 25, 1> = Field::new("FPCR", "DN");
+    /// Descriptor of the `AHP` field, for use with [`FieldAccess`].
+    pub const AHP_FIELD: Field<Self, bool, 26, 1> = Field::new("FPCR", "AHP");
+
+    /// Returns the value of the `Len` field.
+    #[allow(clippy::len_without_is_empty)]
+    pub const fn len(self) -> u8 {
+        Self::LEN_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `Len` field.
+    pub const fn set_len(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::LEN_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `Len` field set to the given value.
+    pub const fn with_len(mut self, value: u8) -> Self {
+        self.set_len(value);
+        self
+    }
+
+    /// Sets the value of the `Len` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_len(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_len(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `Len` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_len(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::LEN_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `Stride` field.
+    pub const fn stride(self) -> u8 {
+        Self::STRIDE_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `Stride` field.
+    pub const fn set_stride(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::STRIDE_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `Stride` field set to the given value.
+    pub const fn with_stride(mut self, value: u8) -> Self {
+        self.set_stride(value);
+        self
+    }
+
+    /// Sets the value of the `Stride` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_stride(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_stride(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `Stride` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_stride(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::STRIDE_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `RMode` field.
+    pub const fn rmode(self) -> u8 {
+        Self::RMODE_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `RMode` field.
+    pub const fn set_rmode(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::RMODE_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `RMode` field set to the given value.
+    pub const fn with_rmode(mut self, value: u8) -> Self {
+        self.set_rmode(value);
+        self
+    }
+
+    /// Sets the value of the `RMode` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_rmode(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_rmode(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `RMode` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_rmode(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::RMODE_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl Display for Fpcr {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("Fpcr");
+        let bits = self.bits();
+        s.field("FIZ", &Self::FIZ_FIELD.extract(bits));
+        s.field("AH", &Self::AH_FIELD.extract(bits));
+        s.field("NEP", &Self::NEP_FIELD.extract(bits));
+        s.field("IOE", &Self::IOE_FIELD.extract(bits));
+        s.field("DZE", &Self::DZE_FIELD.extract(bits));
+        s.field("OFE", &Self::OFE_FIELD.extract(bits));
+        s.field("UFE", &Self::UFE_FIELD.extract(bits));
+        s.field("IXE", &Self::IXE_FIELD.extract(bits));
+        s.field("EBF", &Self::EBF_FIELD.extract(bits));
+        s.field("IDE", &Self::IDE_FIELD.extract(bits));
+        s.field("Len", &Self::LEN_FIELD.extract(bits));
+        s.field("FZ16", &Self::FZ16_FIELD.extract(bits));
+        s.field("Stride", &Self::STRIDE_FIELD.extract(bits));
+        s.field("RMode", &Self::RMODE_FIELD.extract(bits));
+        s.field("FZ", &Self::FZ_FIELD.extract(bits));
+        s.field("DN", &Self::DN_FIELD.extract(bits));
+        s.field("AHP", &Self::AHP_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for Fpcr {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "Fpcr {{ FIZ: {=u64}, AH: {=u64}, NEP: {=u64}, IOE: {=u64}, DZE: {=u64}, OFE: {=u64}, UFE: {=u64}, IXE: {=u64}, EBF: {=u64}, IDE: {=u64}, Len: {=u64}, FZ16: {=u64}, Stride: {=u64}, RMode: {=u64}, FZ: {=u64}, DN: {=u64}, AHP: {=u64} }}",
+            Self::FIZ_FIELD.extract(self.bits()),
+            Self::AH_FIELD.extract(self.bits()),
+            Self::NEP_FIELD.extract(self.bits()),
+            Self::IOE_FIELD.extract(self.bits()),
+            Self::DZE_FIELD.extract(self.bits()),
+            Self::OFE_FIELD.extract(self.bits()),
+            Self::UFE_FIELD.extract(self.bits()),
+            Self::IXE_FIELD.extract(self.bits()),
+            Self::EBF_FIELD.extract(self.bits()),
+            Self::IDE_FIELD.extract(self.bits()),
+            Self::LEN_FIELD.extract(self.bits()),
+            Self::FZ16_FIELD.extract(self.bits()),
+            Self::STRIDE_FIELD.extract(self.bits()),
+            Self::RMODE_FIELD.extract(self.bits()),
+            Self::FZ_FIELD.extract(self.bits()),
+            Self::DN_FIELD.extract(self.bits()),
+            Self::AHP_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Fpcr {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Fpcr {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+impl Fpcr {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("FIZ"),
+                old: Self::FIZ_FIELD.extract(self.bits()),
+                new: Self::FIZ_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("AH"),
+                old: Self::AH_FIELD.extract(self.bits()),
+                new: Self::AH_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("NEP"),
+                old: Self::NEP_FIELD.extract(self.bits()),
+                new: Self::NEP_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IOE"),
+                old: Self::IOE_FIELD.extract(self.bits()),
+                new: Self::IOE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DZE"),
+                old: Self::DZE_FIELD.extract(self.bits()),
+                new: Self::DZE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("OFE"),
+                old: Self::OFE_FIELD.extract(self.bits()),
+                new: Self::OFE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("UFE"),
+                old: Self::UFE_FIELD.extract(self.bits()),
+                new: Self::UFE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IXE"),
+                old: Self::IXE_FIELD.extract(self.bits()),
+                new: Self::IXE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EBF"),
+                old: Self::EBF_FIELD.extract(self.bits()),
+                new: Self::EBF_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IDE"),
+                old: Self::IDE_FIELD.extract(self.bits()),
+                new: Self::IDE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("Len"),
+                old: Self::LEN_FIELD.extract(self.bits()),
+                new: Self::LEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FZ16"),
+                old: Self::FZ16_FIELD.extract(self.bits()),
+                new: Self::FZ16_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("Stride"),
+                old: Self::STRIDE_FIELD.extract(self.bits()),
+                new: Self::STRIDE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("RMode"),
+                old: Self::RMODE_FIELD.extract(self.bits()),
+                new: Self::RMODE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FZ"),
+                old: Self::FZ_FIELD.extract(self.bits()),
+                new: Self::FZ_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DN"),
+                old: Self::DN_FIELD.extract(self.bits()),
+                new: Self::DN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("AHP"),
+                old: Self::AHP_FIELD.extract(self.bits()),
+                new: Self::AHP_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1000_0000_0000_0100_0000_1111_1000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1000_0000_0000_0100_0000_1111_1000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+impl Fpcr {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+impl Fpcr {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+bitflags! {
+    /// `FPMR` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct Fpmr: u64 {
+        /// `OSM` bit.
+        const OSM = 1 << 14;
+        /// `OSC` bit.
+        const OSC = 1 << 15;
+    }
+}
+
+impl Fpmr {
+    /// Descriptor of the `F8S1` field, for use with [`FieldAccess`].
+    pub const F8S1_FIELD: Field<Self, u8, 0, 3> = Field::new("FPMR", "F8S1");
+    /// Descriptor of the `F8S2` field, for use with [`FieldAccess`].
+    pub const F8S2_FIELD: Field<Self, u8, 3, 3> = Field::new("FPMR", "F8S2");
+    /// Descriptor of the `F8D` field, for use with [`FieldAccess`].
+    pub const F8D_FIELD: Field<Self, u8, 6, 3> = Field::new("FPMR", "F8D");
+    /// Descriptor of the `OSM` field, for use with [`FieldAccess`].
+    pub const OSM_FIELD: Field<Self, bool, 14, 1> = Field::new("FPMR", "OSM");
+    /// Descriptor of the `OSC` field, for use with [`FieldAccess`].
+    pub const OSC_FIELD: Field<Self, bool, 15, 1> = Field::new("FPMR", "OSC");
+    /// Descriptor of the `LSCALE` field, for use with [`FieldAccess`].
+    pub const LSCALE_FIELD: Field<Self, u8, 16, 7> = Field::new("FPMR", "LSCALE");
+    /// Descriptor of the `NSCALE` field, for use with [`FieldAccess`].
+    pub const NSCALE_FIELD: Field<Self, u8, 24, 8> = Field::new("FPMR", "NSCALE");
+    /// Descriptor of the `LSCALE2` field, for use with [`FieldAccess`].
+    pub const LSCALE2_FIELD: Field<Self, u8, 32, 6> = Field::new("FPMR", "LSCALE2");
+
+    /// Returns the value of the `F8S1` field.
+    pub const fn f8s1(self) -> u8 {
+        Self::F8S1_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `F8S1` field.
+    pub const fn set_f8s1(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::F8S1_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `F8S1` field set to the given value.
+    pub const fn with_f8s1(mut self, value: u8) -> Self {
+        self.set_f8s1(value);
+        self
+    }
+
+    /// Sets the value of the `F8S1` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_f8s1(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_f8s1(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `F8S1` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_f8s1(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::F8S1_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `F8S2` field.
+    pub const fn f8s2(self) -> u8 {
+        Self::F8S2_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `F8S2` field.
+    pub const fn set_f8s2(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::F8S2_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `F8S2` field set to the given value.
+    pub const fn with_f8s2(mut self, value: u8) -> Self {
+        self.set_f8s2(value);
+        self
+    }
+
+    /// Sets the value of the `F8S2` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_f8s2(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_f8s2(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `F8S2` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_f8s2(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::F8S2_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `F8D` field.
+    pub const fn f8d(self) -> u8 {
+        Self::F8D_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `F8D` field.
+    pub const fn set_f8d(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::F8D_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `F8D` field set to the given value.
+    pub const fn with_f8d(mut self, value: u8) -> Self {
+        self.set_f8d(value);
+        self
+    }
+
+    /// Sets the value of the `F8D` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_f8d(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_f8d(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `F8D` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_f8d(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::F8D_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `LSCALE` field.
+    pub const fn lscale(self) -> u8 {
+        Self::LSCALE_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `LSCALE` field.
+    pub const fn set_lscale(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::LSCALE_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `LSCALE` field set to the given value.
+    pub const fn with_lscale(mut self, value: u8) -> Self {
+        self.set_lscale(value);
+        self
+    }
+
+    /// Sets the value of the `LSCALE` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_lscale(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_lscale(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `LSCALE` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_lscale(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::LSCALE_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `NSCALE` field.
+    pub const fn nscale(self) -> u8 {
+        Self::NSCALE_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `NSCALE` field.
+    pub const fn set_nscale(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::NSCALE_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `NSCALE` field set to the given value.
+    pub const fn with_nscale(mut self, value: u8) -> Self {
+        self.set_nscale(value);
+        self
+    }
+
+    /// Sets the value of the `NSCALE` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_nscale(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_nscale(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `NSCALE` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_nscale(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::NSCALE_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `LSCALE2` field.
+    pub const fn lscale2(self) -> u8 {
+        Self::LSCALE2_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `LSCALE2` field.
+    pub const fn set_lscale2(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::LSCALE2_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `LSCALE2` field set to the given value.
+    pub const fn with_lscale2(mut self, value: u8) -> Self {
+        self.set_lscale2(value);
+        self
+    }
+
+    /// Sets the value of the `LSCALE2` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_lscale2(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_lscale2(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `LSCALE2` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_lscale2(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::LSCALE2_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl Display for Fpmr {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("Fpmr");
+        let bits = self.bits();
+        s.field("F8S1", &Self::F8S1_FIELD.extract(bits));
+        s.field("F8S2", &Self::F8S2_FIELD.extract(bits));
+        s.field("F8D", &Self::F8D_FIELD.extract(bits));
+        s.field("OSM", &Self::OSM_FIELD.extract(bits));
+        s.field("OSC", &Self::OSC_FIELD.extract(bits));
+        s.field("LSCALE", &Self::LSCALE_FIELD.extract(bits));
+        s.field("NSCALE", &Self::NSCALE_FIELD.extract(bits));
+        s.field("LSCALE2", &Self::LSCALE2_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for Fpmr {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "Fpmr {{ F8S1: {=u64}, F8S2: {=u64}, F8D: {=u64}, OSM: {=u64}, OSC: {=u64}, LSCALE: {=u64}, NSCALE: {=u64}, LSCALE2: {=u64} }}",
+            Self::F8S1_FIELD.extract(self.bits()),
+            Self::F8S2_FIELD.extract(self.bits()),
+            Self::F8D_FIELD.extract(self.bits()),
+            Self::OSM_FIELD.extract(self.bits()),
+            Self::OSC_FIELD.extract(self.bits()),
+            Self::LSCALE_FIELD.extract(self.bits()),
+            Self::NSCALE_FIELD.extract(self.bits()),
+            Self::LSCALE2_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Fpmr {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Fpmr {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+impl Fpmr {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("F8S1"),
+                old: Self::F8S1_FIELD.extract(self.bits()),
+                new: Self::F8S1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("F8S2"),
+                old: Self::F8S2_FIELD.extract(self.bits()),
+                new: Self::F8S2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("F8D"),
+                old: Self::F8D_FIELD.extract(self.bits()),
+                new: Self::F8D_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("OSM"),
+                old: Self::OSM_FIELD.extract(self.bits()),
+                new: Self::OSM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("OSC"),
+                old: Self::OSC_FIELD.extract(self.bits()),
+                new: Self::OSC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("LSCALE"),
+                old: Self::LSCALE_FIELD.extract(self.bits()),
+                new: Self::LSCALE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("NSCALE"),
+                old: Self::NSCALE_FIELD.extract(self.bits()),
+                new: Self::NSCALE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("LSCALE2"),
+                old: Self::LSCALE2_FIELD.extract(self.bits()),
+                new: Self::LSCALE2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1100_0000_0000_0000_1000_0000_0011_1110_0000_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1100_0000_0000_0000_1000_0000_0011_1110_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+impl Fpmr {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+impl Fpmr {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                Self::F8S1_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Fpmr),
+                UnimplementedField {
+                    register: "FPMR",
+                    field: "F8S1",
+                    requires: "FEAT_FPMR",
+                },
+            ),
+            (
+                Self::F8S2_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Fpmr),
+                UnimplementedField {
+                    register: "FPMR",
+                    field: "F8S2",
+                    requires: "FEAT_FPMR",
+                },
+            ),
+            (
+                Self::F8D_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Fpmr),
+                UnimplementedField {
+                    register: "FPMR",
+                    field: "F8D",
+                    requires: "FEAT_FPMR",
+                },
+            ),
+            (
+                Self::OSM_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Fpmr),
+                UnimplementedField {
+                    register: "FPMR",
+                    field: "OSM",
+                    requires: "FEAT_FPMR",
+                },
+            ),
+            (
+                Self::OSC_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Fpmr),
+                UnimplementedField {
+                    register: "FPMR",
+                    field: "OSC",
+                    requires: "FEAT_FPMR",
+                },
+            ),
+            (
+                Self::LSCALE_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Fpmr),
+                UnimplementedField {
+                    register: "FPMR",
+                    field: "LSCALE",
+                    requires: "FEAT_FPMR",
+                },
+            ),
+            (
+                Self::NSCALE_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Fpmr),
+                UnimplementedField {
+                    register: "FPMR",
+                    field: "NSCALE",
+                    requires: "FEAT_FPMR",
+                },
+            ),
+            (
+                Self::LSCALE2_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Fpmr),
+                UnimplementedField {
+                    register: "FPMR",
+                    field: "LSCALE2",
+                    requires: "FEAT_FPMR",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+bitflags! {
+    /// `FPSR` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct Fpsr: u64 {
+        /// `IOC` bit.
+        const IOC = 1 << 0;
+        /// `DZC` bit.
+        const DZC = 1 << 1;
+        /// `OFC` bit.
+        const OFC = 1 << 2;
+        /// `UFC` bit.
+        const UFC = 1 << 3;
+        /// `IXC` bit.
+        const IXC = 1 << 4;
+        /// `IDC` bit.
+        const IDC = 1 << 7;
+        /// `QC` bit.
+        const QC = 1 << 27;
+        /// `V` bit.
+        const V = 1 << 28;
+        /// `C` bit.
+        const C = 1 << 29;
+        /// `Z` bit.
+        const Z = 1 << 30;
+        /// `N` bit.
+        const N = 1 << 31;
+    }
+}
+
+impl Fpsr {
+    /// Descriptor of the `IOC` field, for use with [`FieldAccess`].
+    pub const IOC_FIELD: Field<Self, bool, 0, 1> = Field::new("FPSR", "IOC");
+    /// Descriptor of the `DZC` field, for use with [`FieldAccess`].
+    pub const DZC_FIELD: Field<Self, bool, 1, 1> = Field::new("FPSR", "DZC");
+    /// Descriptor of the `OFC` field, for use with [`FieldAccess`].
+    pub const OFC_FIELD: Field<Self, bool, 2, 1> = Field::new("FPSR", "OFC");
+    /// Descriptor of the `UFC` field, for use with [`FieldAccess`].
+    pub const UFC_FIELD: Field<Self, bool, 3, 1> = Field::new("FPSR", "UFC");
+    /// Descriptor of the `IXC` field, for use with [`FieldAccess`].
+    pub const IXC_FIELD: Field<Self, bool, 4, 1> = Field::new("FPSR", "IXC");
+    /// Descriptor of the `IDC` field, for use with [`FieldAccess`].
+    pub const IDC_FIELD: Field<Self, bool, 7, 1> = Field::new("FPSR", "IDC");
+    /// Descriptor of the `QC` field, for use with [`FieldAccess`].
+    pub const QC_FIELD: Field<Self, bool, 27, 1> = Field::new("FPSR", "QC");
+    /// Descriptor of the `V` field, for use with [`FieldAccess`].
+    pub const V_FIELD: Field<Self, bool, 28, 1> = Field::new("FPSR", "V");
+    /// Descriptor of the `C` field, for use with [`FieldAccess`].
+    pub const C_FIELD: Field<Self, bool, 29, 1> = Field::new("FPSR", "C");
+    /// Descriptor of the `Z` field, for use with [`FieldAccess`].
+    pub const Z_FIELD: Field<Self, bool, 30, 1> = Field::new("FPSR", "Z");
+    /// Descriptor of the `N` field, for use with [`FieldAccess`].
+    pub const N_FIELD: Field<Self, bool, 31, 1> = Field::new("FPSR", "N");
+}
+
+impl Display for Fpsr {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("Fpsr");
+        let bits = self.bits();
+        s.field("IOC", &Self::IOC_FIELD.extract(bits));
+        s.field("DZC", &Self::DZC_FIELD.extract(bits));
+        s.field("OFC", &Self::OFC_FIELD.extract(bits));
+        s.field("UFC", &Self::UFC_FIELD.extract(bits));
+        s.field("IXC", &Self::IXC_FIELD.extract(bits));
+        s.field("IDC", &Self::IDC_FIELD.extract(bits));
+        s.field("QC", &Self::QC_FIELD.extract(bits));
+        s.field("V", &Self::V_FIELD.extract(bits));
+        s.field("C", &Self::C_FIELD.extract(bits));
+        s.field("Z", &Self::Z_FIELD.extract(bits));
+        s.field("N", &Self::N_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for Fpsr {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "Fpsr {{ IOC: {=u64}, DZC: {=u64}, OFC: {=u64}, UFC: {=u64}, IXC: {=u64}, IDC: {=u64}, QC: {=u64}, V: {=u64}, C: {=u64}, Z: {=u64}, N: {=u64} }}",
+            Self::IOC_FIELD.extract(self.bits()),
+            Self::DZC_FIELD.extract(self.bits()),
+            Self::OFC_FIELD.extract(self.bits()),
+            Self::UFC_FIELD.extract(self.bits()),
+            Self::IXC_FIELD.extract(self.bits()),
+            Self::IDC_FIELD.extract(self.bits()),
+            Self::QC_FIELD.extract(self.bits()),
+            Self::V_FIELD.extract(self.bits()),
+            Self::C_FIELD.extract(self.bits()),
+            Self::Z_FIELD.extract(self.bits()),
+            Self::N_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Fpsr {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Fpsr {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+impl Fpsr {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("IOC"),
+                old: Self::IOC_FIELD.extract(self.bits()),
+                new: Self::IOC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DZC"),
+                old: Self::DZC_FIELD.extract(self.bits()),
+                new: Self::DZC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("OFC"),
+                old: Self::OFC_FIELD.extract(self.bits()),
+                new: Self::OFC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("UFC"),
+                old: Self::UFC_FIELD.extract(self.bits()),
+                new: Self::UFC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IXC"),
+                old: Self::IXC_FIELD.extract(self.bits()),
+                new: Self::IXC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IDC"),
+                old: Self::IDC_FIELD.extract(self.bits()),
+                new: Self::IDC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("QC"),
+                old: Self::QC_FIELD.extract(self.bits()),
+                new: Self::QC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("V"),
+                old: Self::V_FIELD.extract(self.bits()),
+                new: Self::V_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("C"),
+                old: Self::C_FIELD.extract(self.bits()),
+                new: Self::C_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("Z"),
+                old: Self::Z_FIELD.extract(self.bits()),
+                new: Self::Z_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("N"),
+                old: Self::N_FIELD.extract(self.bits()),
+                new: Self::N_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_0000_0111_1111_1111_1111_1111_0110_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_0000_0111_1111_1111_1111_1111_0110_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+impl Fpsr {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+impl Fpsr {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `GCSCR_EL1` system register value.
+    ///
+    /// Guarded Control Stack Control register.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct GcscrEl1: u64 {
+        /// `PCRSEL` bit.
+        const PCRSEL = 1 << 0;
+        /// `RVCHKEN` bit.
+        const RVCHKEN = 1 << 5;
+        /// Exception state lock enable.
+        const EXLOCKEN = 1 << 6;
+        /// `PUSHMEn` bit.
+        const PUSHMEN = 1 << 8;
+        /// `STREn` bit.
+        const STREN = 1 << 9;
+    }
+}
+
+#[cfg(feature = "el1")]
+impl GcscrEl1 {
+    /// Descriptor of the `PCRSEL` field, for use with [`FieldAccess`].
+    pub const PCRSEL_FIELD: Field<Self, bool, 0, 1> = Field::new("GCSCR_EL1", "PCRSEL");
+    /// Descriptor of the `RVCHKEN` field, for use with [`FieldAccess`].
+    pub const RVCHKEN_FIELD: Field<Self, bool, 5, 1> = Field::new("GCSCR_EL1", "RVCHKEN");
+    /// Descriptor of the `EXLOCKEN` field, for use with [`FieldAccess`].
+    pub const EXLOCKEN_FIELD: Field<Self, bool, 6, 1> = Field::new("GCSCR_EL1", "EXLOCKEN");
+    /// Descriptor of the `PUSHMEn` field, for use with [`FieldAccess`].
+    pub const PUSHMEN_FIELD: Field<Self, bool, 8, 1> = Field::new("GCSCR_EL1", "PUSHMEn");
+    /// Descriptor of the `STREn` field, for use with [`FieldAccess`].
+    pub const STREN_FIELD: Field<Self, bool, 9, 1> = Field::new("GCSCR_EL1", "STREn");
+}
+
+#[cfg(feature = "el1")]
+impl Display for GcscrEl1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("GcscrEl1");
+        let bits = self.bits();
+        s.field("PCRSEL", &Self::PCRSEL_FIELD.extract(bits));
+        s.field("RVCHKEN", &Self::RVCHKEN_FIELD.extract(bits));
+        s.field("EXLOCKEN", &Self::EXLOCKEN_FIELD.extract(bits));
+        s.field("PUSHMEn", &Self::PUSHMEN_FIELD.extract(bits));
+        s.field("STREn", &Self::STREN_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for GcscrEl1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "GcscrEl1 {{ PCRSEL: {=u64}, RVCHKEN: {=u64}, EXLOCKEN: {=u64}, PUSHMEn: {=u64}, STREn: {=u64} }}",
+            Self::PCRSEL_FIELD.extract(self.bits()),
+            Self::RVCHKEN_FIELD.extract(self.bits()),
+            Self::EXLOCKEN_FIELD.extract(self.bits()),
+            Self::PUSHMEN_FIELD.extract(self.bits()),
+            Self::STREN_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for GcscrEl1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for GcscrEl1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl GcscrEl1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("PCRSEL"),
+                old: Self::PCRSEL_FIELD.extract(self.bits()),
+                new: Self::PCRSEL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("RVCHKEN"),
+                old: Self::RVCHKEN_FIELD.extract(self.bits()),
+                new: Self::RVCHKEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EXLOCKEN"),
+                old: Self::EXLOCKEN_FIELD.extract(self.bits()),
+                new: Self::EXLOCKEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PUSHMEn"),
+                old: Self::PUSHMEN_FIELD.extract(self.bits()),
+                new: Self::PUSHMEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("STREn"),
+                old: Self::STREN_FIELD.extract(self.bits()),
+                new: Self::STREN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1100_1001_1110,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1100_1001_1110,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl GcscrEl1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl GcscrEl1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                Self::PCRSEL_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gcs),
+                UnimplementedField {
+                    register: "GCSCR_EL1",
+                    field: "PCRSEL",
+                    requires: "FEAT_GCS",
+                },
+            ),
+            (
+                Self::RVCHKEN_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gcs),
+                UnimplementedField {
+                    register: "GCSCR_EL1",
+                    field: "RVCHKEN",
+                    requires: "FEAT_GCS",
+                },
+            ),
+            (
+                Self::EXLOCKEN_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gcs),
+                UnimplementedField {
+                    register: "GCSCR_EL1",
+                    field: "EXLOCKEN",
+                    requires: "FEAT_GCS",
+                },
+            ),
+            (
+                Self::PUSHMEN_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gcs),
+                UnimplementedField {
+                    register: "GCSCR_EL1",
+                    field: "PUSHMEn",
+                    requires: "FEAT_GCS",
+                },
+            ),
+            (
+                Self::STREN_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gcs),
+                UnimplementedField {
+                    register: "GCSCR_EL1",
+                    field: "STREn",
+                    requires: "FEAT_GCS",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `GCSCR_EL2` system register value.
+    ///
+    /// Guarded Control Stack Control register.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct GcscrEl2: u64 {
+        /// `PCRSEL` bit.
+        const PCRSEL = 1 << 0;
+        /// `RVCHKEN` bit.
+        const RVCHKEN = 1 << 5;
+        /// Exception state lock enable.
+        const EXLOCKEN = 1 << 6;
+        /// `PUSHMEn` bit.
+        const PUSHMEN = 1 << 8;
+        /// `STREn` bit.
+        const STREN = 1 << 9;
+    }
+}
+
+#[cfg(feature = "el2")]
+impl GcscrEl2 {
+    /// Descriptor of the `PCRSEL` field, for use with [`FieldAccess`].
+    pub const PCRSEL_FIELD: Field<Self, bool, 0, 1> = Field::new("GCSCR_EL2", "PCRSEL");
+    /// Descriptor of the `RVCHKEN` field, for use with [`FieldAccess`].
+    pub const RVCHKEN_FIELD: Field<Self, bool, 5, 1> = Field::new("GCSCR_EL2", "RVCHKEN");
+    /// Descriptor of the `EXLOCKEN` field, for use with [`FieldAccess`].
+    pub const EXLOCKEN_FIELD: Field<Self, bool, 6, 1> = Field::new("GCSCR_EL2", "EXLOCKEN");
+    /// Descriptor of the `PUSHMEn` field, for use with [`FieldAccess`].
+    pub const PUSHMEN_FIELD: Field<Self, bool, 8, 1> = Field::new("GCSCR_EL2", "PUSHMEn");
+    /// Descriptor of the `STREn` field, for use with [`FieldAccess`].
+    pub const STREN_FIELD: Field<Self, bool, 9, 1> = Field::new("GCSCR_EL2", "STREn");
+}
+
+#[cfg(feature = "el2")]
+impl Display for GcscrEl2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("GcscrEl2");
+        let bits = self.bits();
+        s.field("PCRSEL", &Self::PCRSEL_FIELD.extract(bits));
+        s.field("RVCHKEN", &Self::RVCHKEN_FIELD.extract(bits));
+        s.field("EXLOCKEN", &Self::EXLOCKEN_FIELD.extract(bits));
+        s.field("PUSHMEn", &Self::PUSHMEN_FIELD.extract(bits));
+        s.field("STREn", &Self::STREN_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for GcscrEl2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "GcscrEl2 {{ PCRSEL: {=u64}, RVCHKEN: {=u64}, EXLOCKEN: {=u64}, PUSHMEn: {=u64}, STREn: {=u64} }}",
+            Self::PCRSEL_FIELD.extract(self.bits()),
+            Self::RVCHKEN_FIELD.extract(self.bits()),
+            Self::EXLOCKEN_FIELD.extract(self.bits()),
+            Self::PUSHMEN_FIELD.extract(self.bits()),
+            Self::STREN_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for GcscrEl2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for GcscrEl2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl GcscrEl2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("PCRSEL"),
+                old: Self::PCRSEL_FIELD.extract(self.bits()),
+                new: Self::PCRSEL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("RVCHKEN"),
+                old: Self::RVCHKEN_FIELD.extract(self.bits()),
+                new: Self::RVCHKEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EXLOCKEN"),
+                old: Self::EXLOCKEN_FIELD.extract(self.bits()),
+                new: Self::EXLOCKEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PUSHMEn"),
+                old: Self::PUSHMEN_FIELD.extract(self.bits()),
+                new: Self::PUSHMEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("STREn"),
+                old: Self::STREN_FIELD.extract(self.bits()),
+                new: Self::STREN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1100_1001_1110,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1100_1001_1110,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl GcscrEl2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl GcscrEl2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                Self::PCRSEL_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gcs),
+                UnimplementedField {
+                    register: "GCSCR_EL2",
+                    field: "PCRSEL",
+                    requires: "FEAT_GCS",
+                },
+            ),
+            (
+                Self::RVCHKEN_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gcs),
+                UnimplementedField {
+                    register: "GCSCR_EL2",
+                    field: "RVCHKEN",
+                    requires: "FEAT_GCS",
+                },
+            ),
+            (
+                Self::EXLOCKEN_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gcs),
+                UnimplementedField {
+                    register: "GCSCR_EL2",
+                    field: "EXLOCKEN",
+                    requires: "FEAT_GCS",
+                },
+            ),
+            (
+                Self::PUSHMEN_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gcs),
+                UnimplementedField {
+                    register: "GCSCR_EL2",
+                    field: "PUSHMEn",
+                    requires: "FEAT_GCS",
+                },
+            ),
+            (
+                Self::STREN_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gcs),
+                UnimplementedField {
+                    register: "GCSCR_EL2",
+                    field: "STREn",
+                    requires: "FEAT_GCS",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `HAFGRTR_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct HafgrtrEl2: u64 {
+        /// `AMEVCNTR0<x>_EL0` bit 0.
+        const AMEVCNTR00_EL0 = 1 << 1;
+        /// `AMEVCNTR0<x>_EL0` bit 1.
+        const AMEVCNTR01_EL0 = 1 << 2;
+        /// `AMEVCNTR0<x>_EL0` bit 2.
+        const AMEVCNTR02_EL0 = 1 << 3;
+        /// `AMEVCNTR0<x>_EL0` bit 3.
+        const AMEVCNTR03_EL0 = 1 << 4;
+    }
+}
+
+#[cfg(feature = "el2")]
+impl HafgrtrEl2 {
+    /// Offset of the `AMEVCNTR0<x>_EL0` field.
+    pub const AMEVCNTR0_EL0_SHIFT: u32 = 1;
+}
+
+#[cfg(feature = "el2")]
+impl Display for HafgrtrEl2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("HafgrtrEl2");
+        let bits = self.bits();
+        s.field("AMEVCNTR00_EL0", &((bits >> Self::AMEVCNTR0_EL0_SHIFT) & 1));
+        s.field(
+            "AMEVCNTR01_EL0",
+            &((bits >> (Self::AMEVCNTR0_EL0_SHIFT + 1)) & 1),
+        );
+        s.field(
+            "AMEVCNTR02_EL0",
+            &((bits >> (Self::AMEVCNTR0_EL0_SHIFT + 2)) & 1),
+        );
+        s.field(
+            "AMEVCNTR03_EL0",
+            &((bits >> (Self::AMEVCNTR0_EL0_SHIFT + 3)) & 1),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for HafgrtrEl2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "HafgrtrEl2 {{ AMEVCNTR00_EL0: {=u64}, AMEVCNTR01_EL0: {=u64}, AMEVCNTR02_EL0: {=u64}, AMEVCNTR03_EL0: {=u64} }}",
+            (self.bits() >> Self::AMEVCNTR0_EL0_SHIFT) & 1,
+            (self.bits() >> (Self::AMEVCNTR0_EL0_SHIFT + 1)) & 1,
+            (self.bits() >> (Self::AMEVCNTR0_EL0_SHIFT + 2)) & 1,
+            (self.bits() >> (Self::AMEVCNTR0_EL0_SHIFT + 3)) & 1
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for HafgrtrEl2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for HafgrtrEl2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl HafgrtrEl2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("AMEVCNTR00_EL0"),
+                old: (self.bits() >> Self::AMEVCNTR0_EL0_SHIFT) & 1,
+                new: (other.bits() >> Self::AMEVCNTR0_EL0_SHIFT) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("AMEVCNTR01_EL0"),
+                old: (self.bits() >> (Self::AMEVCNTR0_EL0_SHIFT + 1)) & 1,
+                new: (other.bits() >> (Self::AMEVCNTR0_EL0_SHIFT + 1)) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("AMEVCNTR02_EL0"),
+                old: (self.bits() >> (Self::AMEVCNTR0_EL0_SHIFT + 2)) & 1,
+                new: (other.bits() >> (Self::AMEVCNTR0_EL0_SHIFT + 2)) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("AMEVCNTR03_EL0"),
+                old: (self.bits() >> (Self::AMEVCNTR0_EL0_SHIFT + 3)) & 1,
+                new: (other.bits() >> (Self::AMEVCNTR0_EL0_SHIFT + 3)) & 1,
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1110_0001,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1110_0001,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl HafgrtrEl2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl HafgrtrEl2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `HCRX_EL2` system register value.
+    ///
+    /// Extended Hypervisor Configuration Register.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct HcrxEl2: u64 {
+        /// Do not trap execution of an ST64BV0 instruction at EL0 or EL1 to EL2.
+        const ENAS0 = 1 << 0;
+        /// Do not trap execution of an LD64B or ST64B instruction at EL0 or EL1 to EL2.
+        const ENALS = 1 << 1;
+        /// Do not trap execution of an ST64BV instruction at EL0 or EL1 to EL2.
+        const ENASR = 1 << 2;
+        /// Determines the behavior of TLBI instructions affected by the XS attribute.
+        const FNXS = 1 << 3;
+        /// Determines if the fine-grained traps in HFGITR_EL2 also apply to the corresponding TLBI maintenance instructions with the nXS qualifier.
+        const FGTNXS = 1 << 4;
+        /// Controls mapping of the value of SMPRI_EL1.Priority for streaming execution priority at EL0 or EL1.
+        const SMPME = 1 << 5;
+        /// Traps MSR writes of ALLINT at EL1 using AArch64 to EL2.
+        const TALLINT = 1 << 6;
+        /// Enables signaling of virtual IRQ interrupts with Superpriority.
+        const VINMI = 1 << 7;
+        /// Enables signaling of virtual FIQ interrupts with Superpriority.
+        const VFNMI = 1 << 8;
+        /// Controls the required permissions for cache maintenance instructions at EL1 or EL0.
+        const CMOW = 1 << 9;
+        /// Controls Memory Copy and Memory Set exceptions generated from EL1.
+        const MCE2 = 1 << 10;
+        /// Enables execution of Memory Set and Memory Copy instructions at EL1 or EL0.
+        const MSCEN = 1 << 11;
+        /// `TCR2En` bit.
+        const TCR2EN = 1 << 14;
+        /// `SCTLR2En` bit.
+        const SCTLR2EN = 1 << 15;
+        /// `PTTWI` bit.
+        const PTTWI = 1 << 16;
+        /// `D128En` bit.
+        const D128EN = 1 << 17;
+        /// `EnSNERR` bit.
+        const ENSNERR = 1 << 18;
+        /// `TMEA` bit.
+        const TMEA = 1 << 19;
+        /// `EnSDERR` bit.
+        const ENSDERR = 1 << 20;
+        /// `EnIDCP128` bit.
+        const ENIDCP128 = 1 << 21;
+        /// `GCSEn` bit.
+        const GCSEN = 1 << 22;
+        /// `EnFPM` bit.
+        const ENFPM = 1 << 23;
+        /// `PACMEn` bit.
+        const PACMEN = 1 << 24;
+        /// `VTLBIDEn` bit.
+        const VTLBIDEN = 1 << 25;
+        /// `SRMASKEn` bit.
+        const SRMASKEN = 1 << 26;
+        /// `NVTGE` bit.
+        const NVTGE = 1 << 27;
+        /// `POE2En` bit.
+        const POE2EN = 1 << 29;
+        /// `TPLIMEn` bit.
+        const TPLIMEN = 1 << 30;
+        /// `FDIT` bit.
+        const FDIT = 1 << 31;
+        /// `NVnTTLB` bit.
+        const NVNTTLB = 1 << 32;
+        /// `NVnTTLBIS` bit.
+        const NVNTTLBIS = 1 << 33;
+        /// `NVnTTLBOS` bit.
+        const NVNTTLBOS = 1 << 34;
+        /// `VTLBIDOSEn` bit.
+        const VTLBIDOSEN = 1 << 35;
+        /// `FNB` bit.
+        const FNB = 1 << 36;
+        /// `VTE` bit.
+        const VTE = 1 << 37;
+        /// `VTAO` bit.
+        const VTAO = 1 << 38;
+        /// `VTCO` bit.
+        const VTCO = 1 << 39;
+    }
+}
+
+#[cfg(feature = "el2")]
+impl HcrxEl2 {
+    /// Descriptor of the `EnAS0` field, for use with [`FieldAccess`].
+    pub const ENAS0_FIELD: Field<Self, bool, 0, 1> = Field::new("HCRX_EL2", "EnAS0");
+    /// Descriptor of the `EnALS` field, for use with [`FieldAccess`].
+    pub const ENALS_FIELD: Field<Self, bool, 1, 1> = Field::new("HCRX_EL2", "EnALS");
+    /// Descriptor of the `EnASR` field, for use with [`FieldAccess`].
+    pub const ENASR_FIELD: Field<Self, bool, 2, 1> = Field::new("HCRX_EL2", "EnASR");
+    /// Descriptor of the `FnXS` field, for use with [`FieldAccess`].
+    pub const FNXS_FIELD: Field<Self, bool, 3, 1> = Field::new("HCRX_EL2", "FnXS");
+    /// Descriptor of the `FGTnXS` field, for use with [`FieldAccess`].
+    pub const FGTNXS_FIELD: Field<Self, bool, 4, 1> = Field::new("HCRX_EL2", "FGTnXS");
+    /// Descriptor of the `SMPME` field, for use with [`FieldAccess`].
+    pub const SMPME_FIELD: Field<Self, bool, 5, 1> = Field::new("HCRX_EL2", "SMPME");
+    /// Descriptor of the `TALLINT` field, for use with [`FieldAccess`].
+    pub const TALLINT_FIELD: Field<Self, bool, 6, 1> = Field::new("HCRX_EL2", "TALLINT");
+    /// Descriptor of the `VINMI` field, for use with [`FieldAccess`].
+    pub const VINMI_FIELD: Field<Self, bool, 7, 1> = Field::new("HCRX_EL2", "VINMI");
+    /// Descriptor of the `VFNMI` field, for use with [`FieldAccess`].
+    pub const VFNMI_FIELD: Field<Self, bool, 8, 1> = Field::new("HCRX_EL2", "VFNMI");
+    /// Descriptor of the `CMOW` field, for use with [`FieldAccess`].
+    pub const CMOW_FIELD: Field<Self, bool, 9, 1> = Field::new("HCRX_EL2", "CMOW");
+    /// Descriptor of the `MCE2` field, for use with [`FieldAccess`].
+    pub const MCE2_FIELD: Field<Self, bool, 10, 1> = Field::new("HCRX_EL2", "MCE2");
+    /// Descriptor of the `MSCEn` field, for use with [`FieldAccess`].
+    pub const MSCEN_FIELD: Field<Self, bool, 11, 1> = Field::new("HCRX_EL2", "MSCEn");
+    /// Descriptor of the `TCR2En` field, for use with [`FieldAccess`].
+    pub const TCR2EN_FIELD: Field<Self, bool, 14, 1> = Field::new("HCRX_EL2", "TCR2En");
+    /// Descriptor of the `SCTLR2En` field, for use with [`FieldAccess`].
+    pub const SCTLR2EN_FIELD: Field<Self, bool, 15, 1> = Field::new("HCRX_EL2", "SCTLR2En");
+    /// Descriptor of the `PTTWI` field, for use with [`FieldAccess`].
+    pub const PTTWI_FIELD: Field<Self, bool, 16, 1> = Field::new("HCRX_EL2", "PTTWI");
+    /// Descriptor of the `D128En` field, for use with [`FieldAccess`].
+    pub const D128EN_FIELD: Field<Self, bool, 17, 1> = Field::new("HCRX_EL2", "D128En");
+    /// Descriptor of the `EnSNERR` field, for use with [`FieldAccess`].
+    pub const ENSNERR_FIELD: Field<Self, bool, 18, 1> = Field::new("HCRX_EL2", "EnSNERR");
+    /// Descriptor of the `TMEA` field, for use with [`FieldAccess`].
+    pub const TMEA_FIELD: Field<Self, bool, 19, 1> = Field::new("HCRX_EL2", "TMEA");
+    /// Descriptor of the `EnSDERR` field, for use with [`FieldAccess`].
+    pub const ENSDERR_FIELD: Field<Self, bool, 20, 1> = Field::new("HCRX_EL2", "EnSDERR");
+    /// Descriptor of the `EnIDCP128` field, for use with [`FieldAccess`].
+    pub const ENIDCP128_FIELD: Field<Self, bool, 21, 1> = Field::new("HCRX_EL2", "EnIDCP128");
+    /// Descriptor of the `GCSEn` field, for use with [`FieldAccess`].
+    pub const GCSEN_FIELD: Field<Self, bool, 22, 1> = Field::new("HCRX_EL2", "GCSEn");
+    /// Descriptor of the `EnFPM` field, for use with [`FieldAccess`].
+    pub const ENFPM_FIELD: Field<Self, bool, 23, 1> = Field::new("HCRX_EL2", "EnFPM");
+    /// Descriptor of the `PACMEn` field, for use with [`FieldAccess`].
+    pub const PACMEN_FIELD: Field<Self, bool, 24, 1> = Field::new("HCRX_EL2", "PACMEn");
+    /// Descriptor of the `VTLBIDEn` field, for use with [`FieldAccess`].
+    pub const VTLBIDEN_FIELD: Field<Self, bool, 25, 1> = Field::new("HCRX_EL2", "VTLBIDEn");
+    /// Descriptor of the `SRMASKEn` field, for use with [`FieldAccess`].
+    pub const SRMASKEN_FIELD: Field<Self, bool, 26, 1> = Field::new("HCRX_EL2", "SRMASKEn");
+    /// Descriptor of the `NVTGE` field, for use with [`FieldAccess`].
+    pub const NVTGE_FIELD: Field<Self, bool, 27, 1> = Field::new("HCRX_EL2", "NVTGE");
+    /// Descriptor of the `POE2En` field, for use with [`FieldAccess`].
+    pub const POE2EN_FIELD: Field<Self, bool, 29, 1> = Field::new("HCRX_EL2", "POE2En");
+    /// Descriptor of the `TPLIMEn` field, for use with [`FieldAccess`].
+    pub const TPLIMEN_FIELD: Field<Self, bool, 30, 1> = Field::new("HCRX_EL2", "TPLIMEn");
+    /// Descriptor of the `FDIT` field, for use with [`FieldAccess`].
+    pub const FDIT_FIELD: Field<Self, bool, 31, 1> = Field::new("HCRX_EL2", "FDIT");
+    /// Descriptor of the `NVnTTLB` field, for use with [`FieldAccess`].
+    pub const NVNTTLB_FIELD: Field<Self, bool, 32, 1> = Field::new("HCRX_EL2", "NVnTTLB");
+    /// Descriptor of the `NVnTTLBIS` field, for use with [`FieldAccess`].
+    pub const NVNTTLBIS_FIELD: Field<Self, bool, 33, 1> = Field::new("HCRX_EL2", "NVnTTLBIS");
+    /// Descriptor of the `NVnTTLBOS` field, for use with [`FieldAccess`].
+    pub const NVNTTLBOS_FIELD: Field<Self, bool, 34, 1> = Field::new("HCRX_EL2", "NVnTTLBOS");
+    /// Descriptor of the `VTLBIDOSEn` field, for use with [`FieldAccess`].
+    pub const VTLBIDOSEN_FIELD: Field<Self, bool, 35, 1> = Field::new("HCRX_EL2", "VTLBIDOSEn");
+    /// Descriptor of the `FNB` field, for use with [`FieldAccess`].
+    pub const FNB_FIELD: Field<Self, bool, 36, 1> = Field::new("HCRX_EL2", "FNB");
+    /// Descriptor of the `VTE` field, for use with [`FieldAccess`].
+    pub const VTE_FIELD: Field<Self, bool, 37, 1> = Field::new("HCRX_EL2", "VTE");
+    /// Descriptor of the `VTAO` field, for use with [`FieldAccess`].
+    pub const VTAO_FIELD: Field<Self, bool, 38, 1> = Field::new("HCRX_EL2", "VTAO");
+    /// Descriptor of the `VTCO` field, for use with [`FieldAccess`].
+    pub const VTCO_FIELD: Field<Self, bool, 39, 1> = Field::new("HCRX_EL2", "VTCO");
+}
+
+#[cfg(feature = "el2")]
+impl Display for HcrxEl2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("HcrxEl2");
+        let bits = self.bits();
+        s.field("EnAS0", &Self::ENAS0_FIELD.extract(bits));
+        s.field("EnALS", &Self::ENALS_FIELD.extract(bits));
+        s.field("EnASR", &Self::ENASR_FIELD.extract(bits));
+        s.field("FnXS", &Self::FNXS_FIELD.extract(bits));
+        s.field("FGTnXS", &Self::FGTNXS_FIELD.extract(bits));
+        s.field("SMPME", &Self::SMPME_FIELD.extract(bits));
+        s.field("TALLINT", &Self::TALLINT_FIELD.extract(bits));
+        s.field("VINMI", &Self::VINMI_FIELD.extract(bits));
+        s.field("VFNMI", &Self::VFNMI_FIELD.extract(bits));
+        s.field("CMOW", &Self::CMOW_FIELD.extract(bits));
+        s.field("MCE2", &Self::MCE2_FIELD.extract(bits));
+        s.field("MSCEn", &Self::MSCEN_FIELD.extract(bits));
+        s.field("TCR2En", &Self::TCR2EN_FIELD.extract(bits));
+        s.field("SCTLR2En", &Self::SCTLR2EN_FIELD.extract(bits));
+        s.field("PTTWI", &Self::PTTWI_FIELD.extract(bits));
+        s.field("D128En", &Self::D128EN_FIELD.extract(bits));
+        s.field("EnSNERR", &Self::ENSNERR_FIELD.extract(bits));
+        s.field("TMEA", &Self::TMEA_FIELD.extract(bits));
+        s.field("EnSDERR", &Self::ENSDERR_FIELD.extract(bits));
+        s.field("EnIDCP128", &Self::ENIDCP128_FIELD.extract(bits));
+        s.field("GCSEn", &Self::GCSEN_FIELD.extract(bits));
+        s.field("EnFPM", &Self::ENFPM_FIELD.extract(bits));
+        s.field("PACMEn", &Self::PACMEN_FIELD.extract(bits));
+        s.field("VTLBIDEn", &Self::VTLBIDEN_FIELD.extract(bits));
+        s.field("SRMASKEn", &Self::SRMASKEN_FIELD.extract(bits));
+        s.field("NVTGE", &Self::NVTGE_FIELD.extract(bits));
+        s.field("POE2En", &Self::POE2EN_FIELD.extract(bits));
+        s.field("TPLIMEn", &Self::TPLIMEN_FIELD.extract(bits));
+        s.field("FDIT", &Self::FDIT_FIELD.extract(bits));
+        s.field("NVnTTLB", &Self::NVNTTLB_FIELD.extract(bits));
+        s.field("NVnTTLBIS", &Self::NVNTTLBIS_FIELD.extract(bits));
+        s.field("NVnTTLBOS", &Self::NVNTTLBOS_FIELD.extract(bits));
+        s.field("VTLBIDOSEn", &Self::VTLBIDOSEN_FIELD.extract(bits));
+        s.field("FNB", &Self::FNB_FIELD.extract(bits));
+        s.field("VTE", &Self::VTE_FIELD.extract(bits));
+        s.field("VTAO", &Self::VTAO_FIELD.extract(bits));
+        s.field("VTCO", &Self::VTCO_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for HcrxEl2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "HcrxEl2 {{ EnAS0: {=u64}, EnALS: {=u64}, EnASR: {=u64}, FnXS: {=u64}, FGTnXS: {=u64}, SMPME: {=u64}, TALLINT: {=u64}, VINMI: {=u64}, VFNMI: {=u64}, CMOW: {=u64}, MCE2: {=u64}, MSCEn: {=u64}, TCR2En: {=u64}, SCTLR2En: {=u64}, PTTWI: {=u64}, D128En: {=u64}, EnSNERR: {=u64}, TMEA: {=u64}, EnSDERR: {=u64}, EnIDCP128: {=u64}, GCSEn: {=u64}, EnFPM: {=u64}, PACMEn: {=u64}, VTLBIDEn: {=u64}, SRMASKEn: {=u64}, NVTGE: {=u64}, POE2En: {=u64}, TPLIMEn: {=u64}, FDIT: {=u64}, NVnTTLB: {=u64}, NVnTTLBIS: {=u64}, NVnTTLBOS: {=u64}, VTLBIDOSEn: {=u64}, FNB: {=u64}, VTE: {=u64}, VTAO: {=u64}, VTCO: {=u64} }}",
+            Self::ENAS0_FIELD.extract(self.bits()),
+            Self::ENALS_FIELD.extract(self.bits()),
+            Self::ENASR_FIELD.extract(self.bits()),
+            Self::FNXS_FIELD.extract(self.bits()),
+            Self::FGTNXS_FIELD.extract(self.bits()),
+            Self::SMPME_FIELD.extract(self.bits()),
+            Self::TALLINT_FIELD.extract(self.bits()),
+            Self::VINMI_FIELD.extract(self.bits()),
+            Self::VFNMI_FIELD.extract(self.bits()),
+            Self::CMOW_FIELD.extract(self.bits()),
+            Self::MCE2_FIELD.extract(self.bits()),
+            Self::MSCEN_FIELD.extract(self.bits()),
+            Self::TCR2EN_FIELD.extract(self.bits()),
+            Self::SCTLR2EN_FIELD.extract(self.bits()),
+            Self::PTTWI_FIELD.extract(self.bits()),
+            Self::D128EN_FIELD.extract(self.bits()),
+            Self::ENSNERR_FIELD.extract(self.bits()),
+            Self::TMEA_FIELD.extract(self.bits()),
+            Self::ENSDERR_FIELD.extract(self.bits()),
+            Self::ENIDCP128_FIELD.extract(self.bits()),
+            Self::GCSEN_FIELD.extract(self.bits()),
+            Self::ENFPM_FIELD.extract(self.bits()),
+            Self::PACMEN_FIELD.extract(self.bits()),
+            Self::VTLBIDEN_FIELD.extract(self.bits()),
+            Self::SRMASKEN_FIELD.extract(self.bits()),
+            Self::NVTGE_FIELD.extract(self.bits()),
+            Self::POE2EN_FIELD.extract(self.bits()),
+            Self::TPLIMEN_FIELD.extract(self.bits()),
+            Self::FDIT_FIELD.extract(self.bits()),
+            Self::NVNTTLB_FIELD.extract(self.bits()),
+            Self::NVNTTLBIS_FIELD.extract(self.bits()),
+            Self::NVNTTLBOS_FIELD.extract(self.bits()),
+            Self::VTLBIDOSEN_FIELD.extract(self.bits()),
+            Self::FNB_FIELD.extract(self.bits()),
+            Self::VTE_FIELD.extract(self.bits()),
+            Self::VTAO_FIELD.extract(self.bits()),
+            Self::VTCO_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for HcrxEl2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for HcrxEl2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl HcrxEl2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("EnAS0"),
+                old: Self::ENAS0_FIELD.extract(self.bits()),
+                new: Self::ENAS0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnALS"),
+                old: Self::ENALS_FIELD.extract(self.bits()),
+                new: Self::ENALS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnASR"),
+                old: Self::ENASR_FIELD.extract(self.bits()),
+                new: Self::ENASR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FnXS"),
+                old: Self::FNXS_FIELD.extract(self.bits()),
+                new: Self::FNXS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FGTnXS"),
+                old: Self::FGTNXS_FIELD.extract(self.bits()),
+                new: Self::FGTNXS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SMPME"),
+                old: Self::SMPME_FIELD.extract(self.bits()),
+                new: Self::SMPME_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TALLINT"),
+                old: Self::TALLINT_FIELD.extract(self.bits()),
+                new: Self::TALLINT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VINMI"),
+                old: Self::VINMI_FIELD.extract(self.bits()),
+                new: Self::VINMI_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VFNMI"),
+                old: Self::VFNMI_FIELD.extract(self.bits()),
+                new: Self::VFNMI_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CMOW"),
+                old: Self::CMOW_FIELD.extract(self.bits()),
+                new: Self::CMOW_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("MCE2"),
+                old: Self::MCE2_FIELD.extract(self.bits()),
+                new: Self::MCE2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("MSCEn"),
+                old: Self::MSCEN_FIELD.extract(self.bits()),
+                new: Self::MSCEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TCR2En"),
+                old: Self::TCR2EN_FIELD.extract(self.bits()),
+                new: Self::TCR2EN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SCTLR2En"),
+                old: Self::SCTLR2EN_FIELD.extract(self.bits()),
+                new: Self::SCTLR2EN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PTTWI"),
+                old: Self::PTTWI_FIELD.extract(self.bits()),
+                new: Self::PTTWI_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("D128En"),
+                old: Self::D128EN_FIELD.extract(self.bits()),
+                new: Self::D128EN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnSNERR"),
+                old: Self::ENSNERR_FIELD.extract(self.bits()),
+                new: Self::ENSNERR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TMEA"),
+                old: Self::TMEA_FIELD.extract(self.bits()),
+                new: Self::TMEA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnSDERR"),
+                old: Self::ENSDERR_FIELD.extract(self.bits()),
+                new: Self::ENSDERR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnIDCP128"),
+                old: Self::ENIDCP128_FIELD.extract(self.bits()),
+                new: Self::ENIDCP128_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("GCSEn"),
+                old: Self::GCSEN_FIELD.extract(self.bits()),
+                new: Self::GCSEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnFPM"),
+                old: Self::ENFPM_FIELD.extract(self.bits()),
+                new: Self::ENFPM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PACMEn"),
+                old: Self::PACMEN_FIELD.extract(self.bits()),
+                new: Self::PACMEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VTLBIDEn"),
+                old: Self::VTLBIDEN_FIELD.extract(self.bits()),
+                new: Self::VTLBIDEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SRMASKEn"),
+                old: Self::SRMASKEN_FIELD.extract(self.bits()),
+                new: Self::SRMASKEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("NVTGE"),
+                old: Self::NVTGE_FIELD.extract(self.bits()),
+                new: Self::NVTGE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("POE2En"),
+                old: Self::POE2EN_FIELD.extract(self.bits()),
+                new: Self::POE2EN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TPLIMEn"),
+                old: Self::TPLIMEN_FIELD.extract(self.bits()),
+                new: Self::TPLIMEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FDIT"),
+                old: Self::FDIT_FIELD.extract(self.bits()),
+                new: Self::FDIT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("NVnTTLB"),
+                old: Self::NVNTTLB_FIELD.extract(self.bits()),
+                new: Self::NVNTTLB_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("NVnTTLBIS"),
+                old: Self::NVNTTLBIS_FIELD.extract(self.bits()),
+                new: Self::NVNTTLBIS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("NVnTTLBOS"),
+                old: Self::NVNTTLBOS_FIELD.extract(self.bits()),
+                new: Self::NVNTTLBOS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VTLBIDOSEn"),
+                old: Self::VTLBIDOSEN_FIELD.extract(self.bits()),
+                new: Self::VTLBIDOSEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FNB"),
+                old: Self::FNB_FIELD.extract(self.bits()),
+                new: Self::FNB_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VTE"),
+                old: Self::VTE_FIELD.extract(self.bits()),
+                new: Self::VTE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VTAO"),
+                old: Self::VTAO_FIELD.extract(self.bits()),
+                new: Self::VTAO_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VTCO"),
+                old: Self::VTCO_FIELD.extract(self.bits()),
+                new: Self::VTCO_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_0000_0000_0001_0000_0000_0000_0011_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_0000_0000_0001_0000_0000_0000_0011_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl HcrxEl2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl HcrxEl2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                Self::ENAS0_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Hcx),
+                UnimplementedField {
+                    register: "HCRX_EL2",
+                    field: "EnAS0",
+                    requires: "FEAT_HCX",
+                },
+            ),
+            (
+                Self::ENALS_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Hcx),
+                UnimplementedField {
+                    register: "HCRX_EL2",
+                    field: "EnALS",
+                    requires: "FEAT_HCX",
+                },
+            ),
+            (
+                Self::ENASR_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Hcx),
+                UnimplementedField {
+                    register: "HCRX_EL2",
+                    field: "EnASR",
+                    requires: "FEAT_HCX",
+                },
+            ),
+            (
+                Self::FNXS_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Hcx),
+                UnimplementedField {
+                    register: "HCRX_EL2",
+                    field: "FnXS",
+                    requires: "FEAT_HCX",
+                },
+            ),
+            (
+                Self::FGTNXS_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Hcx),
+                UnimplementedField {
+                    register: "HCRX_EL2",
+                    field: "FGTnXS",
+                    requires: "FEAT_HCX",
+                },
+            ),
+            (
+                Self::SMPME_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Hcx),
+                UnimplementedField {
+                    register: "HCRX_EL2",
+                    field: "SMPME",
+                    requires: "FEAT_HCX",
+                },
+            ),
+            (
+                Self::TALLINT_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Hcx),
+                UnimplementedField {
+                    register: "HCRX_EL2",
+                    field: "TALLINT",
+                    requires: "FEAT_HCX",
+                },
+            ),
+            (
+                Self::VINMI_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Hcx),
+                UnimplementedField {
+                    register: "HCRX_EL2",
+                    field: "VINMI",
+                    requires: "FEAT_HCX",
+                },
+            ),
+            (
+                Self::VFNMI_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Hcx),
+                UnimplementedField {
+                    register: "HCRX_EL2",
+                    field: "VFNMI",
+                    requires: "FEAT_HCX",
+                },
+            ),
+            (
+                Self::CMOW_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Hcx),
+                UnimplementedField {
+                    register: "HCRX_EL2",
+                    field: "CMOW",
+                    requires: "FEAT_HCX",
+                },
+            ),
+            (
+                Self::MCE2_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Hcx),
+                UnimplementedField {
+                    register: "HCRX_EL2",
+                    field: "MCE2",
+                    requires: "FEAT_HCX",
+                },
+            ),
+            (
+                Self::MSCEN_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Hcx),
+                UnimplementedField {
+                    register: "HCRX_EL2",
+                    field: "MSCEn",
+                    requires: "FEAT_HCX",
+                },
+            ),
+            (
+                Self::TCR2EN_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Hcx),
+                UnimplementedField {
+                    register: "HCRX_EL2",
+                    field: "TCR2En",
+                    requires: "FEAT_HCX",
+                },
+            ),
+            (
+                Self::SCTLR2EN_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Hcx),
+                UnimplementedField {
+                    register: "HCRX_EL2",
+                    field: "SCTLR2En",
+                    requires: "FEAT_HCX",
+                },
+            ),
+            (
+                Self::PTTWI_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Hcx),
+                UnimplementedField {
+                    register: "HCRX_EL2",
+                    field: "PTTWI",
+                    requires: "FEAT_HCX",
+                },
+            ),
+            (
+                Self::D128EN_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Hcx),
+                UnimplementedField {
+                    register: "HCRX_EL2",
+                    field: "D128En",
+                    requires: "FEAT_HCX",
+                },
+            ),
+            (
+                Self::ENSNERR_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Hcx),
+                UnimplementedField {
+                    register: "HCRX_EL2",
+                    field: "EnSNERR",
+                    requires: "FEAT_HCX",
+                },
+            ),
+            (
+                Self::TMEA_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Hcx),
+                UnimplementedField {
+                    register: "HCRX_EL2",
+                    field: "TMEA",
+                    requires: "FEAT_HCX",
+                },
+            ),
+            (
+                Self::ENSDERR_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Hcx),
+                UnimplementedField {
+                    register: "HCRX_EL2",
+                    field: "EnSDERR",
+                    requires: "FEAT_HCX",
+                },
+            ),
+            (
+                Self::ENIDCP128_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Hcx),
+                UnimplementedField {
+                    register: "HCRX_EL2",
+                    field: "EnIDCP128",
+                    requires: "FEAT_HCX",
+                },
+            ),
+            (
+                Self::GCSEN_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Hcx),
+                UnimplementedField {
+                    register: "HCRX_EL2",
+                    field: "GCSEn",
+                    requires: "FEAT_HCX",
+                },
+            ),
+            (
+                Self::ENFPM_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Hcx),
+                UnimplementedField {
+                    register: "HCRX_EL2",
+                    field: "EnFPM",
+                    requires: "FEAT_HCX",
+                },
+            ),
+            (
+                Self::PACMEN_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Hcx),
+                UnimplementedField {
+                    register: "HCRX_EL2",
+                    field: "PACMEn",
+                    requires: "FEAT_HCX",
+                },
+            ),
+            (
+                Self::VTLBIDEN_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Hcx),
+                UnimplementedField {
+                    register: "HCRX_EL2",
+                    field: "VTLBIDEn",
+                    requires: "FEAT_HCX",
+                },
+            ),
+            (
+                Self::SRMASKEN_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Hcx),
+                UnimplementedField {
+                    register: "HCRX_EL2",
+                    field: "SRMASKEn",
+                    requires: "FEAT_HCX",
+                },
+            ),
+            (
+                Self::NVTGE_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Hcx),
+                UnimplementedField {
+                    register: "HCRX_EL2",
+                    field: "NVTGE",
+                    requires: "FEAT_HCX",
+                },
+            ),
+            (
+                Self::POE2EN_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Hcx),
+                UnimplementedField {
+                    register: "HCRX_EL2",
+                    field: "POE2En",
+                    requires: "FEAT_HCX",
+                },
+            ),
+            (
+                Self::TPLIMEN_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Hcx),
+                UnimplementedField {
+                    register: "HCRX_EL2",
+                    field: "TPLIMEn",
+                    requires: "FEAT_HCX",
+                },
+            ),
+            (
+                Self::FDIT_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Hcx),
+                UnimplementedField {
+                    register: "HCRX_EL2",
+                    field: "FDIT",
+                    requires: "FEAT_HCX",
+                },
+            ),
+            (
+                Self::NVNTTLB_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Hcx),
+                UnimplementedField {
+                    register: "HCRX_EL2",
+                    field: "NVnTTLB",
+                    requires: "FEAT_HCX",
+                },
+            ),
+            (
+                Self::NVNTTLBIS_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Hcx),
+                UnimplementedField {
+                    register: "HCRX_EL2",
+                    field: "NVnTTLBIS",
+                    requires: "FEAT_HCX",
+                },
+            ),
+            (
+                Self::NVNTTLBOS_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Hcx),
+                UnimplementedField {
+                    register: "HCRX_EL2",
+                    field: "NVnTTLBOS",
+                    requires: "FEAT_HCX",
+                },
+            ),
+            (
+                Self::VTLBIDOSEN_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Hcx),
+                UnimplementedField {
+                    register: "HCRX_EL2",
+                    field: "VTLBIDOSEn",
+                    requires: "FEAT_HCX",
+                },
+            ),
+            (
+                Self::FNB_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Hcx),
+                UnimplementedField {
+                    register: "HCRX_EL2",
+                    field: "FNB",
+                    requires: "FEAT_HCX",
+                },
+            ),
+            (
+                Self::VTE_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Hcx),
+                UnimplementedField {
+                    register: "HCRX_EL2",
+                    field: "VTE",
+                    requires: "FEAT_HCX",
+                },
+            ),
+            (
+                Self::VTAO_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Hcx),
+                UnimplementedField {
+                    register: "HCRX_EL2",
+                    field: "VTAO",
+                    requires: "FEAT_HCX",
+                },
+            ),
+            (
+                Self::VTCO_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Hcx),
+                UnimplementedField {
+                    register: "HCRX_EL2",
+                    field: "VTCO",
+                    requires: "FEAT_HCX",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `HCR_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct HcrEl2: u64 {
+        /// `VM` bit.
+        const VM = 1 << 0;
+        /// `SWIO` bit.
+        const SWIO = 1 << 1;
+        /// `PTW` bit.
+        const PTW = 1 << 2;
+        /// `FMO` bit.
+        const FMO = 1 << 3;
+        /// `IMO` bit.
+        const IMO = 1 << 4;
+        /// `AMO` bit.
+        const AMO = 1 << 5;
+        /// `VF` bit.
+        const VF = 1 << 6;
+        /// `VI` bit.
+        const VI = 1 << 7;
+        /// `VSE` bit.
+        const VSE = 1 << 8;
+        /// `FB` bit.
+        const FB = 1 << 9;
+        /// `DC` bit.
+        const DC = 1 << 12;
+        /// `TWI` bit.
+        const TWI = 1 << 13;
+        /// `TWE` bit.
+        const TWE = 1 << 14;
+        /// `TID0` bit.
+        const TID0 = 1 << 15;
+        /// `TID1` bit.
+        const TID1 = 1 << 16;
+        /// `TID2` bit.
+        const TID2 = 1 << 17;
+        /// `TID3` bit.
+        const TID3 = 1 << 18;
+        /// `TSC` bit.
+        const TSC = 1 << 19;
+        /// `TIDCP` bit.
+        const TIDCP = 1 << 20;
+        /// `TACR` bit.
+        const TACR = 1 << 21;
+        /// `TSW` bit.
+        const TSW = 1 << 22;
+        /// `TPCP` bit.
+        const TPCP = 1 << 23;
+        /// `TPU` bit.
+        const TPU = 1 << 24;
+        /// `TTLB` bit.
+        const TTLB = 1 << 25;
+        /// `TVM` bit.
+        const TVM = 1 << 26;
+        /// Trap general exceptions to EL2.
+        const TGE = 1 << 27;
+        /// `TDZ` bit.
+        const TDZ = 1 << 28;
+        /// `HCD` bit.
+        const HCD = 1 << 29;
+        /// `TRVM` bit.
+        const TRVM = 1 << 30;
+        /// `RW` bit.
+        const RW = 1 << 31;
+        /// `CD` bit.
+        const CD = 1 << 32;
+        /// `ID` bit.
+        const ID = 1 << 33;
+        /// `E2H` bit.
+        const E2H = 1 << 34;
+        /// `TLOR` bit.
+        const TLOR = 1 << 35;
+        /// `TERR` bit.
+        const TERR = 1 << 36;
+        /// `TEA` bit.
+        const TEA = 1 << 37;
+        /// `APK` bit.
+        const APK = 1 << 40;
+        /// `API` bit.
+        const API = 1 << 41;
+        /// `NV` bit.
+        const NV = 1 << 42;
+        /// `NV1` bit.
+        const NV1 = 1 << 43;
+        /// `AT` bit.
+        const AT = 1 << 44;
+        /// `NV2` bit.
+        const NV2 = 1 << 45;
+        /// `FWB` bit.
+        const FWB = 1 << 46;
+        /// `FIEN` bit.
+        const FIEN = 1 << 47;
+        /// `GPF` bit.
+        const GPF = 1 << 48;
+        /// `TID4` bit.
+        const TID4 = 1 << 49;
+        /// `TICAB` bit.
+        const TICAB = 1 << 50;
+        /// `AMVOFFEN` bit.
+        const AMVOFFEN = 1 << 51;
+        /// `TOCU` bit.
+        const TOCU = 1 << 52;
+        /// `EnSCXT` bit.
+        const ENSCXT = 1 << 53;
+        /// `TTLBIS` bit.
+        const TTLBIS = 1 << 54;
+        /// `TTLBOS` bit.
+        const TTLBOS = 1 << 55;
+        /// `ATA` bit.
+        const ATA = 1 << 56;
+        /// `DCT` bit.
+        const DCT = 1 << 57;
+        /// `TID5` bit.
+        const TID5 = 1 << 58;
+        /// `TWEDEn` bit.
+        const TWEDEN = 1 << 59;
+    }
+}
+
+#[cfg(feature = "el2")]
+impl HcrEl2 {
+    /// Descriptor of the `VM` field, for use with [`FieldAccess`].
+    pub const VM_FIELD: Field<Self, bool, 0, 1> = Field::new("HCR_EL2", "VM");
+    /// Descriptor of the `SWIO` field, for use with [`FieldAccess`].
+    pub const SWIO_FIELD: Field<Self, bool, 1, 1> = Field::new("HCR_EL2", "SWIO");
+    /// Descriptor of the `PTW` field, for use with [`FieldAccess`].
+    pub const PTW_FIELD: Field<Self, bool, 2, 1> = Field::new("HCR_EL2", "PTW");
+    /// Descriptor of the `FMO` field, for use with [`FieldAccess`].
+    pub const FMO_FIELD: Field<Self, bool, 3, 1> = Field::new("HCR_EL2", "FMO");
+    /// Descriptor of the `IMO` field, for use with [`FieldAccess`].
+    pub const IMO_FIELD: Field<Self, bool, 4, 1> = Field::new("HCR_EL2", "IMO");
+    /// Descriptor of the `AMO` field, for use with [`FieldAccess`].
+    pub const AMO_FIELD: Field<Self, bool, 5, 1> = Field::new("HCR_EL2", "AMO");
+    /// Descriptor of the `VF` field, for use with [`FieldAccess`].
+    pub const VF_FIELD: Field<Self, bool, 6, 1> = Field::new("HCR_EL2", "VF");
+    /// Descriptor of the `VI` field, for use with [`FieldAccess`].
+    pub const VI_FIELD: Field<Self, bool, 7, 1> = Field::new("HCR_EL2", "VI");
+    /// Descriptor of the `VSE` field, for use with [`FieldAccess`].
+    pub const VSE_FIELD: Field<Self, bool, 8, 1> = Field::new("HCR_EL2", "VSE");
+    /// Descriptor of the `FB` field, for use with [`FieldAccess`].
+    pub const FB_FIELD: Field<Self, bool, 9, 1> = Field::new("HCR_EL2", "FB");
+    /// Descriptor of the `BSU` field, for use with [`FieldAccess`].
+    pub const BSU_FIELD: Field<Self, u8, 10, 2> = Field::new("HCR_EL2", "BSU");
+    /// Descriptor of the `DC` field, for use with [`FieldAccess`].
+    pub const DC_FIELD: Field<Self, bool, 12, 1> = Field::new("HCR_EL2", "DC");
+    /// Descriptor of the `TWI` field, for use with [`FieldAccess`].
+    pub const TWI_FIELD: Field<Self, bool, 13, 1> = Field::new("HCR_EL2", "TWI");
+    /// Descriptor of the `TWE` field, for use with [`FieldAccess`].
+    pub const TWE_FIELD: Field<Self, bool, 14, 1> = Field::new("HCR_EL2", "TWE");
+    /// Descriptor of the `TID0` field, for use with [`FieldAccess`].
+    pub const TID0_FIELD: Field<Self, bool, 15, 1> = Field::new("HCR_EL2", "TID0");
+    /// Descriptor of the `TID1` field, for use with [`FieldAccess`].
+    pub const TID1_FIELD: Field<Self, bool, 16, 1> = Field::new("HCR_EL2", "TID1");
+    /// Descriptor of the `TID2` field, for use with [`FieldAccess`].
+    pub const TID2_FIELD: Field<Self, bool, 17, 1> = Field::new("HCR_EL2", "TID2");
+    /// Descriptor of the `TID3` field, for use with [`FieldAccess`].
+    pub const TID3_FIELD: Field<Self, bool, 18, 1> = Field::new("HCR_EL2", "TID3");
+    /// Descriptor of the `TSC` field, for use with [`FieldAccess`].
+    pub const TSC_FIELD: Field<Self, bool, 19, 1> = Field::new("HCR_EL2", "TSC");
+    /// Descriptor of the `TIDCP` field, for use with [`FieldAccess`].
+    pub const TIDCP_FIELD: Field<Self, bool, 20, 1> = Field::new("HCR_EL2", "TIDCP");
+    /// Descriptor of the `TACR` field, for use with [`FieldAccess`].
+    pub const TACR_FIELD: Field<Self, bool, 21, 1> = Field::new("HCR_EL2", "TACR");
+    /// Descriptor of the `TSW` field, for use with [`FieldAccess`].
+    pub const TSW_FIELD: Field<Self, bool, 22, 1> = Field::new("HCR_EL2", "TSW");
+    /// Descriptor of the `TPCP` field, for use with [`FieldAccess`].
+    pub const TPCP_FIELD: Field<Self, bool, 23, 1> = Field::new("HCR_EL2", "TPCP");
+    /// Descriptor of the `TPU` field, for use with [`FieldAccess`].
+    pub const TPU_FIELD: Field<Self, bool, 24, 1> = Field::new("HCR_EL2", "TPU");
+    /// Descriptor of the `TTLB` field, for use with [`FieldAccess`].
+    pub const TTLB_FIELD: Field<Self, bool, 25, 1> = Field::new("HCR_EL2", "TTLB");
+    /// Descriptor of the `TVM` field, for use with [`FieldAccess`].
+    pub const TVM_FIELD: Field<Self, bool, 26, 1> = Field::new("HCR_EL2", "TVM");
+    /// Descriptor of the `TGE` field, for use with [`FieldAccess`].
+    pub const TGE_FIELD: Field<Self, bool, 27, 1> = Field::new("HCR_EL2", "TGE");
+    /// Descriptor of the `TDZ` field, for use with [`FieldAccess`].
+    pub const TDZ_FIELD: Field<Self, bool, 28, 1> = Field::new("HCR_EL2", "TDZ");
+    /// Descriptor of the `HCD` field, for use with [`FieldAccess`].
+    pub const HCD_FIELD: Field<Self, bool, 29, 1> = Field::new("HCR_EL2", "HCD");
+    /// Descriptor of the `TRVM` field, for use with [`FieldAccess`].
+    pub const TRVM_FIELD: Field<Self, bool, 30, 1> = Field::new("HCR_EL2", "TRVM");
+    /// Descriptor of the `RW` field, for use with [`FieldAccess`].
+    pub const RW_FIELD: Field<Self, bool, 31, 1> = Field::new("HCR_EL2", "RW");
+    /// Descriptor of the `CD` field, for use with [`FieldAccess`].
+    pub const CD_FIELD: Field<Self, bool, 32, 1> = Field::new("HCR_EL2", "CD");
+    /// Descriptor of the `ID` field, for use with [`FieldAccess`].
+    pub const ID_FIELD: Field<Self, bool, 33, 1> = Field::new("HCR_EL2", "ID");
+    /// Descriptor of the `E2H` field, for use with [`FieldAccess`].
+    pub const E2H_FIELD: Field<Self, bool, 34, 1> = Field::new("HCR_EL2", "E2H");
+    /// Descriptor of the `TLOR` field, for use with [`FieldAccess`].
+    pub const TLOR_FIELD: Field<Self, bool, 35, 1> = Field::new("HCR_EL2", "TLOR");
+    /// Descriptor of the `TERR` field, for use with [`FieldAccess`].
+    pub const TERR_FIELD: Field<Self, bool, 36, 1> = Field::new("HCR_EL2", "TERR");
+    /// Descriptor of the `TEA` field, for use with [`FieldAccess`].
+    pub const TEA_FIELD: Field<Self, bool, 37, 1> = Field::new("HCR_EL2", "TEA");
+    /// Descriptor of the `APK` field, for use with [`FieldAccess`].
+    pub const APK_FIELD: Field<Self, bool, 40, 1> = Field::new("HCR_EL2", "APK");
+    /// Descriptor of the `API` field, for use with [`FieldAccess`].
+    pub const API_FIELD: Field<Self, bool, 41, 1> = Field::new("HCR_EL2", "API");
+    /// Descriptor of the `NV` field, for use with [`FieldAccess`].
+    pub const NV_FIELD: Field<Self, bool, 42, 1> = Field::new("HCR_EL2", "NV");
+    /// Descriptor of the `NV1` field, for use with [`FieldAccess`].
+    pub const NV1_FIELD: Field<Self, bool, 43, 1> = Field::new("HCR_EL2", "NV1");
+    /// Descriptor of the `AT` field, for use with [`FieldAccess`].
+    pub const AT_FIELD: Field<Self, bool, 44, 1> = Field::new("HCR_EL2", "AT");
+    /// Descriptor of the `NV2` field, for use with [`FieldAccess`].
+    pub const NV2_FIELD: Field<Self, bool, 45, 1> = Field::new("HCR_EL2", "NV2");
+    /// Descriptor of the `FWB` field, for use with [`FieldAccess`].
+    pub const FWB_FIELD: Field<Self, bool, 46, 1> = Field::new("HCR_EL2", "FWB");
+    /// Descriptor of the `FIEN` field, for use with [`FieldAccess`].
+    pub const FIEN_FIELD: Field<Self, bool, 47, 1> = Field::new("HCR_EL2", "FIEN");
+    /// Descriptor of the `GPF` field, for use with [`FieldAccess`].
+    pub const GPF_FIELD: Field<Self, bool, 48, 1> = Field::new("HCR_EL2", "GPF");
+    /// Descriptor of the `TID4` field, for use with [`FieldAccess`].
+    pub const TID4_FIELD: Field<Self, bool, 49, 1> = Field::new("HCR_EL2", "TID4");
+    /// Descriptor of the `TICAB` field, for use with [`FieldAccess`].
+    pub const TICAB_FIELD: Field<Self, bool, 50, 1> = Field::new("HCR_EL2", "TICAB");
+    /// Descriptor of the `AMVOFFEN` field, for use with [`FieldAccess`].
+    pub const AMVOFFEN_FIELD: Field<Self, bool, 51, 1> = Field::new("HCR_EL2", "AMVOFFEN");
+    /// Descriptor of the `TOCU` field, for use with [`FieldAccess`].
+    pub const TOCU_FIELD: Field<Self, bool, 52, 1> = Field::new("HCR_EL2", "TOCU");
+    /// Descriptor of the `EnSCXT` field, for use with [`FieldAccess`].
+    pub const ENSCXT_FIELD: Field<Self, bool, 53, 1> = Field::new("HCR_EL2", "EnSCXT");
+    /// Descriptor of the `TTLBIS` field, for use with [`FieldAccess`].
+    pub const TTLBIS_FIELD: Field<Self, bool, 54, 1> = Field::new("HCR_EL2", "TTLBIS");
+    /// Descriptor of the `TTLBOS` field, for use with [`FieldAccess`].
+    pub const TTLBOS_FIELD: Field<Self, bool, 55, 1> = Field::new("HCR_EL2", "TTLBOS");
+    /// Descriptor of the `ATA` field, for use with [`FieldAccess`].
+    pub const ATA_FIELD: Field<Self, bool, 56, 1> = Field::new("HCR_EL2", "ATA");
+    /// Descriptor of the `DCT` field, for use with [`FieldAccess`].
+    pub const DCT_FIELD: Field<Self, bool, 57, 1> = Field::new("HCR_EL2", "DCT");
+    /// Descriptor of the `TID5` field, for use with [`FieldAccess`].
+    pub const TID5_FIELD: Field<Self, bool, 58, 1> = Field::new("HCR_EL2", "TID5");
+    /// Descriptor of the `TWEDEn` field, for use with [`FieldAccess`].
+    pub const TWEDEN_FIELD: Field<Self, bool, 59, 1> = Field::new("HCR_EL2", "TWEDEn");
+    /// Descriptor of the `TWEDEL` field, for use with [`FieldAccess`].
+    pub const TWEDEL_FIELD: Field<Self, u8, 60, 4> = Field::new("HCR_EL2", "TWEDEL");
+
+    /// Returns the value of the `BSU` field.
+    pub const fn bsu(self) -> u8 {
+        Self::BSU_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `BSU` field.
+    pub const fn set_bsu(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::BSU_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `BSU` field set to the given value.
+    pub const fn with_bsu(mut self, value: u8) -> Self {
+        self.set_bsu(value);
+        self
+    }
+
+    /// Sets the value of the `BSU` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_bsu(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_bsu(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `BSU` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_bsu(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::BSU_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `TWEDEL` field.
+    pub const fn twedel(self) -> u8 {
+        Self::TWEDEL_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `TWEDEL` field.
+    pub const fn set_twedel(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::TWEDEL_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `TWEDEL` field set to the given value.
+    pub const fn with_twedel(mut self, value: u8) -> Self {
+        self.set_twedel(value);
+        self
+    }
+
+    /// Sets the value of the `TWEDEL` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_twedel(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_twedel(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `TWEDEL` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_twedel(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::TWEDEL_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Display for HcrEl2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("HcrEl2");
+        let bits = self.bits();
+        s.field("VM", &Self::VM_FIELD.extract(bits));
+        s.field("SWIO", &Self::SWIO_FIELD.extract(bits));
+        s.field("PTW", &Self::PTW_FIELD.extract(bits));
+        s.field("FMO", &Self::FMO_FIELD.extract(bits));
+        s.field("IMO", &Self::IMO_FIELD.extract(bits));
+        s.field("AMO", &Self::AMO_FIELD.extract(bits));
+        s.field("VF", &Self::VF_FIELD.extract(bits));
+        s.field("VI", &Self::VI_FIELD.extract(bits));
+        s.field("VSE", &Self::VSE_FIELD.extract(bits));
+        s.field("FB", &Self::FB_FIELD.extract(bits));
+        s.field("BSU", &Self::BSU_FIELD.extract(bits));
+        s.field("DC", &Self::DC_FIELD.extract(bits));
+        s.field("TWI", &Self::TWI_FIELD.extract(bits));
+        s.field("TWE", &Self::TWE_FIELD.extract(bits));
+        s.field("TID0", &Self::TID0_FIELD.extract(bits));
+        s.field("TID1", &Self::TID1_FIELD.extract(bits));
+        s.field("TID2", &Self::TID2_FIELD.extract(bits));
+        s.field("TID3", &Self::TID3_FIELD.extract(bits));
+        s.field("TSC", &Self::TSC_FIELD.extract(bits));
+        s.field("TIDCP", &Self::TIDCP_FIELD.extract(bits));
+        s.field("TACR", &Self::TACR_FIELD.extract(bits));
+        s.field("TSW", &Self::TSW_FIELD.extract(bits));
+        s.field("TPCP", &Self::TPCP_FIELD.extract(bits));
+        s.field("TPU", &Self::TPU_FIELD.extract(bits));
+        s.field("TTLB", &Self::TTLB_FIELD.extract(bits));
+        s.field("TVM", &Self::TVM_FIELD.extract(bits));
+        s.field("TGE", &Self::TGE_FIELD.extract(bits));
+        s.field("TDZ", &Self::TDZ_FIELD.extract(bits));
+        s.field("HCD", &Self::HCD_FIELD.extract(bits));
+        s.field("TRVM", &Self::TRVM_FIELD.extract(bits));
+        s.field("RW", &Self::RW_FIELD.extract(bits));
+        s.field("CD", &Self::CD_FIELD.extract(bits));
+        s.field("ID", &Self::ID_FIELD.extract(bits));
+        s.field("E2H", &Self::E2H_FIELD.extract(bits));
+        s.field("TLOR", &Self::TLOR_FIELD.extract(bits));
+        s.field("TERR", &Self::TERR_FIELD.extract(bits));
+        s.field("TEA", &Self::TEA_FIELD.extract(bits));
+        s.field("APK", &Self::APK_FIELD.extract(bits));
+        s.field("API", &Self::API_FIELD.extract(bits));
+        s.field("NV", &Self::NV_FIELD.extract(bits));
+        s.field("NV1", &Self::NV1_FIELD.extract(bits));
+        s.field("AT", &Self::AT_FIELD.extract(bits));
+        s.field("NV2", &Self::NV2_FIELD.extract(bits));
+        s.field("FWB", &Self::FWB_FIELD.extract(bits));
+        s.field("FIEN", &Self::FIEN_FIELD.extract(bits));
+        s.field("GPF", &Self::GPF_FIELD.extract(bits));
+        s.field("TID4", &Self::TID4_FIELD.extract(bits));
+        s.field("TICAB", &Self::TICAB_FIELD.extract(bits));
+        s.field("AMVOFFEN", &Self::AMVOFFEN_FIELD.extract(bits));
+        s.field("TOCU", &Self::TOCU_FIELD.extract(bits));
+        s.field("EnSCXT", &Self::ENSCXT_FIELD.extract(bits));
+        s.field("TTLBIS", &Self::TTLBIS_FIELD.extract(bits));
+        s.field("TTLBOS", &Self::TTLBOS_FIELD.extract(bits));
+        s.field("ATA", &Self::ATA_FIELD.extract(bits));
+        s.field("DCT", &Self::DCT_FIELD.extract(bits));
+        s.field("TID5", &Self::TID5_FIELD.extract(bits));
+        s.field("TWEDEn", &Self::TWEDEN_FIELD.extract(bits));
+        s.field("TWEDEL", &Self::TWEDEL_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for HcrEl2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "HcrEl2 {{ VM: {=u64}, SWIO: {=u64}, PTW: {=u64}, FMO: {=u64}, IMO: {=u64}, AMO: {=u64}, VF: {=u64}, VI: {=u64}, VSE: {=u64}, FB: {=u64}, BSU: {=u64}, DC: {=u64}, TWI: {=u64}, TWE: {=u64}, TID0: {=u64}, TID1: {=u64}, TID2: {=u64}, TID3: {=u64}, TSC: {=u64}, TIDCP: {=u64}, TACR: {=u64}, TSW: {=u64}, TPCP: {=u64}, TPU: {=u64}, TTLB: {=u64}, TVM: {=u64}, TGE: {=u64}, TDZ: {=u64}, HCD: {=u64}, TRVM: {=u64}, RW: {=u64}, CD: {=u64}, ID: {=u64}, E2H: {=u64}, TLOR: {=u64}, TERR: {=u64}, TEA: {=u64}, APK: {=u64}, API: {=u64}, NV: {=u64}, NV1: {=u64}, AT: {=u64}, NV2: {=u64}, FWB: {=u64}, FIEN: {=u64}, GPF: {=u64}, TID4: {=u64}, TICAB: {=u64}, AMVOFFEN: {=u64}, TOCU: {=u64}, EnSCXT: {=u64}, TTLBIS: {=u64}, TTLBOS: {=u64}, ATA: {=u64}, DCT: {=u64}, TID5: {=u64}, TWEDEn: {=u64}, TWEDEL: {=u64} }}",
+            Self::VM_FIELD.extract(self.bits()),
+            Self::SWIO_FIELD.extract(self.bits()),
+            Self::PTW_FIELD.extract(self.bits()),
+            Self::FMO_FIELD.extract(self.bits()),
+            Self::IMO_FIELD.extract(self.bits()),
+            Self::AMO_FIELD.extract(self.bits()),
+            Self::VF_FIELD.extract(self.bits()),
+            Self::VI_FIELD.extract(self.bits()),
+            Self::VSE_FIELD.extract(self.bits()),
+            Self::FB_FIELD.extract(self.bits()),
+            Self::BSU_FIELD.extract(self.bits()),
+            Self::DC_FIELD.extract(self.bits()),
+            Self::TWI_FIELD.extract(self.bits()),
+            Self::TWE_FIELD.extract(self.bits()),
+            Self::TID0_FIELD.extract(self.bits()),
+            Self::TID1_FIELD.extract(self.bits()),
+            Self::TID2_FIELD.extract(self.bits()),
+            Self::TID3_FIELD.extract(self.bits()),
+            Self::TSC_FIELD.extract(self.bits()),
+            Self::TIDCP_FIELD.extract(self.bits()),
+            Self::TACR_FIELD.extract(self.bits()),
+            Self::TSW_FIELD.extract(self.bits()),
+            Self::TPCP_FIELD.extract(self.bits()),
+            Self::TPU_FIELD.extract(self.bits()),
+            Self::TTLB_FIELD.extract(self.bits()),
+            Self::TVM_FIELD.extract(self.bits()),
+            Self::TGE_FIELD.extract(self.bits()),
+            Self::TDZ_FIELD.extract(self.bits()),
+            Self::HCD_FIELD.extract(self.bits()),
+            Self::TRVM_FIELD.extract(self.bits()),
+            Self::RW_FIELD.extract(self.bits()),
+            Self::CD_FIELD.extract(self.bits()),
+            Self::ID_FIELD.extract(self.bits()),
+            Self::E2H_FIELD.extract(self.bits()),
+            Self::TLOR_FIELD.extract(self.bits()),
+            Self::TERR_FIELD.extract(self.bits()),
+            Self::TEA_FIELD.extract(self.bits()),
+            Self::APK_FIELD.extract(self.bits()),
+            Self::API_FIELD.extract(self.bits()),
+            Self::NV_FIELD.extract(self.bits()),
+            Self::NV1_FIELD.extract(self.bits()),
+            Self::AT_FIELD.extract(self.bits()),
+            Self::NV2_FIELD.extract(self.bits()),
+            Self::FWB_FIELD.extract(self.bits()),
+            Self::FIEN_FIELD.extract(self.bits()),
+            Self::GPF_FIELD.extract(self.bits()),
+            Self::TID4_FIELD.extract(self.bits()),
+            Self::TICAB_FIELD.extract(self.bits()),
+            Self::AMVOFFEN_FIELD.extract(self.bits()),
+            Self::TOCU_FIELD.extract(self.bits()),
+            Self::ENSCXT_FIELD.extract(self.bits()),
+            Self::TTLBIS_FIELD.extract(self.bits()),
+            Self::TTLBOS_FIELD.extract(self.bits()),
+            Self::ATA_FIELD.extract(self.bits()),
+            Self::DCT_FIELD.extract(self.bits()),
+            Self::TID5_FIELD.extract(self.bits()),
+            Self::TWEDEN_FIELD.extract(self.bits()),
+            Self::TWEDEL_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for HcrEl2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for HcrEl2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl HcrEl2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("VM"),
+                old: Self::VM_FIELD.extract(self.bits()),
+                new: Self::VM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SWIO"),
+                old: Self::SWIO_FIELD.extract(self.bits()),
+                new: Self::SWIO_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PTW"),
+                old: Self::PTW_FIELD.extract(self.bits()),
+                new: Self::PTW_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FMO"),
+                old: Self::FMO_FIELD.extract(self.bits()),
+                new: Self::FMO_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IMO"),
+                old: Self::IMO_FIELD.extract(self.bits()),
+                new: Self::IMO_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("AMO"),
+                old: Self::AMO_FIELD.extract(self.bits()),
+                new: Self::AMO_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VF"),
+                old: Self::VF_FIELD.extract(self.bits()),
+                new: Self::VF_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VI"),
+                old: Self::VI_FIELD.extract(self.bits()),
+                new: Self::VI_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VSE"),
+                old: Self::VSE_FIELD.extract(self.bits()),
+                new: Self::VSE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FB"),
+                old: Self::FB_FIELD.extract(self.bits()),
+                new: Self::FB_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("BSU"),
+                old: Self::BSU_FIELD.extract(self.bits()),
+                new: Self::BSU_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DC"),
+                old: Self::DC_FIELD.extract(self.bits()),
+                new: Self::DC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TWI"),
+                old: Self::TWI_FIELD.extract(self.bits()),
+                new: Self::TWI_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TWE"),
+                old: Self::TWE_FIELD.extract(self.bits()),
+                new: Self::TWE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TID0"),
+                old: Self::TID0_FIELD.extract(self.bits()),
+                new: Self::TID0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TID1"),
+                old: Self::TID1_FIELD.extract(self.bits()),
+                new: Self::TID1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TID2"),
+                old: Self::TID2_FIELD.extract(self.bits()),
+                new: Self::TID2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TID3"),
+                old: Self::TID3_FIELD.extract(self.bits()),
+                new: Self::TID3_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TSC"),
+                old: Self::TSC_FIELD.extract(self.bits()),
+                new: Self::TSC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TIDCP"),
+                old: Self::TIDCP_FIELD.extract(self.bits()),
+                new: Self::TIDCP_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TACR"),
+                old: Self::TACR_FIELD.extract(self.bits()),
+                new: Self::TACR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TSW"),
+                old: Self::TSW_FIELD.extract(self.bits()),
+                new: Self::TSW_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TPCP"),
+                old: Self::TPCP_FIELD.extract(self.bits()),
+                new: Self::TPCP_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TPU"),
+                old: Self::TPU_FIELD.extract(self.bits()),
+                new: Self::TPU_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TTLB"),
+                old: Self::TTLB_FIELD.extract(self.bits()),
+                new: Self::TTLB_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TVM"),
+                old: Self::TVM_FIELD.extract(self.bits()),
+                new: Self::TVM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TGE"),
+                old: Self::TGE_FIELD.extract(self.bits()),
+                new: Self::TGE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TDZ"),
+                old: Self::TDZ_FIELD.extract(self.bits()),
+                new: Self::TDZ_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HCD"),
+                old: Self::HCD_FIELD.extract(self.bits()),
+                new: Self::HCD_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TRVM"),
+                old: Self::TRVM_FIELD.extract(self.bits()),
+                new: Self::TRVM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("RW"),
+                old: Self::RW_FIELD.extract(self.bits()),
+                new: Self::RW_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CD"),
+                old: Self::CD_FIELD.extract(self.bits()),
+                new: Self::CD_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ID"),
+                old: Self::ID_FIELD.extract(self.bits()),
+                new: Self::ID_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("E2H"),
+                old: Self::E2H_FIELD.extract(self.bits()),
+                new: Self::E2H_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TLOR"),
+                old: Self::TLOR_FIELD.extract(self.bits()),
+                new: Self::TLOR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TERR"),
+                old: Self::TERR_FIELD.extract(self.bits()),
+                new: Self::TERR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TEA"),
+                old: Self::TEA_FIELD.extract(self.bits()),
+                new: Self::TEA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("APK"),
+                old: Self::APK_FIELD.extract(self.bits()),
+                new: Self::APK_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("API"),
+                old: Self::API_FIELD.extract(self.bits()),
+                new: Self::API_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("NV"),
+                old: Self::NV_FIELD.extract(self.bits()),
+                new: Self::NV_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("NV1"),
+                old: Self::NV1_FIELD.extract(self.bits()),
+                new: Self::NV1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("AT"),
+                old: Self::AT_FIELD.extract(self.bits()),
+                new: Self::AT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("NV2"),
+                old: Self::NV2_FIELD.extract(self.bits()),
+                new: Self::NV2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FWB"),
+                old: Self::FWB_FIELD.extract(self.bits()),
+                new: Self::FWB_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FIEN"),
+                old: Self::FIEN_FIELD.extract(self.bits()),
+                new: Self::FIEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("GPF"),
+                old: Self::GPF_FIELD.extract(self.bits()),
+                new: Self::GPF_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TID4"),
+                old: Self::TID4_FIELD.extract(self.bits()),
+                new: Self::TID4_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TICAB"),
+                old: Self::TICAB_FIELD.extract(self.bits()),
+                new: Self::TICAB_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("AMVOFFEN"),
+                old: Self::AMVOFFEN_FIELD.extract(self.bits()),
+                new: Self::AMVOFFEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TOCU"),
+                old: Self::TOCU_FIELD.extract(self.bits()),
+                new: Self::TOCU_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnSCXT"),
+                old: Self::ENSCXT_FIELD.extract(self.bits()),
+                new: Self::ENSCXT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TTLBIS"),
+                old: Self::TTLBIS_FIELD.extract(self.bits()),
+                new: Self::TTLBIS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TTLBOS"),
+                old: Self::TTLBOS_FIELD.extract(self.bits()),
+                new: Self::TTLBOS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ATA"),
+                old: Self::ATA_FIELD.extract(self.bits()),
+                new: Self::ATA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DCT"),
+                old: Self::DCT_FIELD.extract(self.bits()),
+                new: Self::DCT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TID5"),
+                old: Self::TID5_FIELD.extract(self.bits()),
+                new: Self::TID5_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TWEDEn"),
+                old: Self::TWEDEN_FIELD.extract(self.bits()),
+                new: Self::TWEDEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TWEDEL"),
+                old: Self::TWEDEL_FIELD.extract(self.bits()),
+                new: Self::TWEDEL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1100_0000_0000_0000_0000_0000_0000_0000_0000_0000,
+                new: other.bits() & 0b1100_0000_0000_0000_0000_0000_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl HcrEl2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl HcrEl2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `HDFGRTR2_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct Hdfgrtr2El2: u64 {
+        /// `nPMECR_EL1` bit.
+        const NPMECR_EL1 = 1 << 0;
+        /// `nPMICNTR_EL0` bit.
+        const NPMICNTR_EL0 = 1 << 2;
+        /// `nPMICFILTR_EL0` bit.
+        const NPMICFILTR_EL0 = 1 << 3;
+        /// `nPMUACR_EL1` bit.
+        const NPMUACR_EL1 = 1 << 4;
+        /// `nMDSELR_EL1` bit.
+        const NMDSELR_EL1 = 1 << 5;
+        /// `nPMSSDATA` bit.
+        const NPMSSDATA = 1 << 6;
+        /// `nPMSSCR_EL1` bit.
+        const NPMSSCR_EL1 = 1 << 7;
+        /// `nSPMEVCNTRn_EL0` bit.
+        const NSPMEVCNTRN_EL0 = 1 << 8;
+        /// `nSPMEVTYPERn_EL0` bit.
+        const NSPMEVTYPERN_EL0 = 1 << 9;
+        /// `nSPMSELR_EL0` bit.
+        const NSPMSELR_EL0 = 1 << 10;
+        /// `nSPMCNTEN` bit.
+        const NSPMCNTEN = 1 << 11;
+        /// `nSPMINTEN` bit.
+        const NSPMINTEN = 1 << 12;
+        /// `nSPMOVS` bit.
+        const NSPMOVS = 1 << 13;
+        /// `nSPMCR_EL0` bit.
+        const NSPMCR_EL0 = 1 << 14;
+        /// `nSPMACCESSR_EL1` bit.
+        const NSPMACCESSR_EL1 = 1 << 15;
+        /// `nSPMSCR_EL1` bit.
+        const NSPMSCR_EL1 = 1 << 16;
+        /// `nSPMID` bit.
+        const NSPMID = 1 << 17;
+        /// `nSPMDEVAFF_EL1` bit.
+        const NSPMDEVAFF_EL1 = 1 << 18;
+        /// `nPMSDSFR_EL1` bit.
+        const NPMSDSFR_EL1 = 1 << 19;
+        /// `nTRCITECR_EL1` bit.
+        const NTRCITECR_EL1 = 1 << 20;
+        /// `nTRBMPAM_EL1` bit.
+        const NTRBMPAM_EL1 = 1 << 22;
+        /// `nMDSTEPOP_EL1` bit.
+        const NMDSTEPOP_EL1 = 1 << 23;
+        /// `nPMBMAR_EL1` bit.
+        const NPMBMAR_EL1 = 1 << 24;
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Hdfgrtr2El2 {
+    /// Descriptor of the `nPMECR_EL1` field, for use with [`FieldAccess`].
+    pub const NPMECR_EL1_FIELD: Field<Self, bool, 0, 1> = Field::new("HDFGRTR2_EL2", "nPMECR_EL1");
+    /// Descriptor of the `nPMICNTR_EL0` field, for use with [`FieldAccess`].
+    pub const NPMICNTR_EL0_FIELD: Field<Self, bool, 2, 1> =
+        Field::new("HDFGRTR2_EL2", "nPMICNTR_EL0");
+    /// Descriptor of the `nPMICFILTR_EL0` field, for use with [`FieldAccess`].
+    pub const NPMICFILTR_EL0_FIELD: Field<Self, bool, 3, 1> =
+        Field::new("HDFGRTR2_EL2", "nPMICFILTR_EL0");
+    /// Descriptor of the `nPMUACR_EL1` field, for use with [`FieldAccess`].
+    pub const NPMUACR_EL1_FIELD: Field<Self, bool, 4, 1> =
+        Field::new("HDFGRTR2_EL2", "nPMUACR_EL1");
+    /// Descriptor of the `nMDSELR_EL1` field, for use with [`FieldAccess`].
+    pub const NMDSELR_EL1_FIELD: Field<Self, bool, 5, 1> =
+        Field::new("HDFGRTR2_EL2", "nMDSELR_EL1");
+    /// Descriptor of the `nPMSSDATA` field, for use with [`FieldAccess`].
+    pub const NPMSSDATA_FIELD: Field<Self, bool, 6, 1> = Field::new("HDFGRTR2_EL2", "nPMSSDATA");
+    /// Descriptor of the `nPMSSCR_EL1` field, for use with [`FieldAccess`].
+    pub const NPMSSCR_EL1_FIELD: Field<Self, bool, 7, 1> =
+        Field::new("HDFGRTR2_EL2", "nPMSSCR_EL1");
+    /// Descriptor of the `nSPMEVCNTRn_EL0` field, for use with [`FieldAccess`].
+    pub const NSPMEVCNTRN_EL0_FIELD: Field<Self, bool, 8, 1> =
+        Field::new("HDFGRTR2_EL2", "nSPMEVCNTRn_EL0");
+    /// Descriptor of the `nSPMEVTYPERn_EL0` field, for use with [`FieldAccess`].
+    pub const NSPMEVTYPERN_EL0_FIELD: Field<Self, bool, 9, 1> =
+        Field::new("HDFGRTR2_EL2", "nSPMEVTYPERn_EL0");
+    /// Descriptor of the `nSPMSELR_EL0` field, for use with [`FieldAccess`].
+    pub const NSPMSELR_EL0_FIELD: Field<Self, bool, 10, 1> =
+        Field::new("HDFGRTR2_EL2", "nSPMSELR_EL0");
+    /// Descriptor of the `nSPMCNTEN` field, for use with [`FieldAccess`].
+    pub const NSPMCNTEN_FIELD: Field<Self, bool, 11, 1> = Field::new("HDFGRTR2_EL2", "nSPMCNTEN");
+    /// Descriptor of the `nSPMINTEN` field, for use with [`FieldAccess`].
+    pub const NSPMINTEN_FIELD: Field<Self, bool, 12, 1> = Field::new("HDFGRTR2_EL2", "nSPMINTEN");
+    /// Descriptor of the `nSPMOVS` field, for use with [`FieldAccess`].
+    pub const NSPMOVS_FIELD: Field<Self, bool, 13, 1> = Field::new("HDFGRTR2_EL2", "nSPMOVS");
+    /// Descriptor of the `nSPMCR_EL0` field, for use with [`FieldAccess`].
+    pub const NSPMCR_EL0_FIELD: Field<Self, bool, 14, 1> = Field::new("HDFGRTR2_EL2", "nSPMCR_EL0");
+    /// Descriptor of the `nSPMACCESSR_EL1` field, for use with [`FieldAccess`].
+    pub const NSPMACCESSR_EL1_FIELD: Field<Self, bool, 15, 1> =
+        Field::new("HDFGRTR2_EL2", "nSPMACCESSR_EL1");
+    /// Descriptor of the `nSPMSCR_EL1` field, for use with [`FieldAccess`].
+    pub const NSPMSCR_EL1_FIELD: Field<Self, bool, 16, 1> =
+        Field::new("HDFGRTR2_EL2", "nSPMSCR_EL1");
+    /// Descriptor of the `nSPMID` field, for use with [`FieldAccess`].
+    pub const NSPMID_FIELD: Field<Self, bool, 17, 1> = Field::new("HDFGRTR2_EL2", "nSPMID");
+    /// Descriptor of the `nSPMDEVAFF_EL1` field, for use with [`FieldAccess`].
+    pub const NSPMDEVAFF_EL1_FIELD: Field<Self, bool, 18, 1> =
+        Field::new("HDFGRTR2_EL2", "nSPMDEVAFF_EL1");
+    /// Descriptor of the `nPMSDSFR_EL1` field, for use with [`FieldAccess`].
+    pub const NPMSDSFR_EL1_FIELD: Field<Self, bool, 19, 1> =
+        Field::new("HDFGRTR2_EL2", "nPMSDSFR_EL1");
+    /// Descriptor of the `nTRCITECR_EL1` field, for use with [`FieldAccess`].
+    pub const NTRCITECR_EL1_FIELD: Field<Self, bool, 20, 1> =
+        Field::new("HDFGRTR2_EL2", "nTRCITECR_EL1");
+    /// Descriptor of the `nTRBMPAM_EL1` field, for use with [`FieldAccess`].
+    pub const NTRBMPAM_EL1_FIELD: Field<Self, bool, 22, 1> =
+        Field::new("HDFGRTR2_EL2", "nTRBMPAM_EL1");
+    /// Descriptor of the `nMDSTEPOP_EL1` field, for use with [`FieldAccess`].
+    pub const NMDSTEPOP_EL1_FIELD: Field<Self, bool, 23, 1> =
+        Field::new("HDFGRTR2_EL2", "nMDSTEPOP_EL1");
+    /// Descriptor of the `nPMBMAR_EL1` field, for use with [`FieldAccess`].
+    pub const NPMBMAR_EL1_FIELD: Field<Self, bool, 24, 1> =
+        Field::new("HDFGRTR2_EL2", "nPMBMAR_EL1");
+}
+
+#[cfg(feature = "el2")]
+impl Display for Hdfgrtr2El2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("Hdfgrtr2El2");
+        let bits = self.bits();
+        s.field("nPMECR_EL1", &Self::NPMECR_EL1_FIELD.extract(bits));
+        s.field("nPMICNTR_EL0", &Self::NPMICNTR_EL0_FIELD.extract(bits));
+        s.field("nPMICFILTR_EL0", &Self::NPMICFILTR_EL0_FIELD.extract(bits));
+        s.field("nPMUACR_EL1", &Self::NPMUACR_EL1_FIELD.extract(bits));
+        s.field("nMDSELR_EL1", &Self::NMDSELR_EL1_FIELD.extract(bits));
+        s.field("nPMSSDATA", &Self::NPMSSDATA_FIELD.extract(bits));
+        s.field("nPMSSCR_EL1", &Self::NPMSSCR_EL1_FIELD.extract(bits));
+        s.field(
+            "nSPMEVCNTRn_EL0",
+            &Self::NSPMEVCNTRN_EL0_FIELD.extract(bits),
+        );
+        s.field(
+            "nSPMEVTYPERn_EL0",
+            &Self::NSPMEVTYPERN_EL0_FIELD.extract(bits),
+        );
+        s.field("nSPMSELR_EL0", &Self::NSPMSELR_EL0_FIELD.extract(bits));
+        s.field("nSPMCNTEN", &Self::NSPMCNTEN_FIELD.extract(bits));
+        s.field("nSPMINTEN", &Self::NSPMINTEN_FIELD.extract(bits));
+        s.field("nSPMOVS", &Self::NSPMOVS_FIELD.extract(bits));
+        s.field("nSPMCR_EL0", &Self::NSPMCR_EL0_FIELD.extract(bits));
+        s.field(
+            "nSPMACCESSR_EL1",
+            &Self::NSPMACCESSR_EL1_FIELD.extract(bits),
+        );
+        s.field("nSPMSCR_EL1", &Self::NSPMSCR_EL1_FIELD.extract(bits));
+        s.field("nSPMID", &Self::NSPMID_FIELD.extract(bits));
+        s.field("nSPMDEVAFF_EL1", &Self::NSPMDEVAFF_EL1_FIELD.extract(bits));
+        s.field("nPMSDSFR_EL1", &Self::NPMSDSFR_EL1_FIELD.extract(bits));
+        s.field("nTRCITECR_EL1", &Self::NTRCITECR_EL1_FIELD.extract(bits));
+        s.field("nTRBMPAM_EL1", &Self::NTRBMPAM_EL1_FIELD.extract(bits));
+        s.field("nMDSTEPOP_EL1", &Self::NMDSTEPOP_EL1_FIELD.extract(bits));
+        s.field("nPMBMAR_EL1", &Self::NPMBMAR_EL1_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for Hdfgrtr2El2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "Hdfgrtr2El2 {{ nPMECR_EL1: {=u64}, nPMICNTR_EL0: {=u64}, nPMICFILTR_EL0: {=u64}, nPMUACR_EL1: {=u64}, nMDSELR_EL1: {=u64}, nPMSSDATA: {=u64}, nPMSSCR_EL1: {=u64}, nSPMEVCNTRn_EL0: {=u64}, nSPMEVTYPERn_EL0: {=u64}, nSPMSELR_EL0: {=u64}, nSPMCNTEN: {=u64}, nSPMINTEN: {=u64}, nSPMOVS: {=u64}, nSPMCR_EL0: {=u64}, nSPMACCESSR_EL1: {=u64}, nSPMSCR_EL1: {=u64}, nSPMID: {=u64}, nSPMDEVAFF_EL1: {=u64}, nPMSDSFR_EL1: {=u64}, nTRCITECR_EL1: {=u64}, nTRBMPAM_EL1: {=u64}, nMDSTEPOP_EL1: {=u64}, nPMBMAR_EL1: {=u64} }}",
+            Self::NPMECR_EL1_FIELD.extract(self.bits()),
+            Self::NPMICNTR_EL0_FIELD.extract(self.bits()),
+            Self::NPMICFILTR_EL0_FIELD.extract(self.bits()),
+            Self::NPMUACR_EL1_FIELD.extract(self.bits()),
+            Self::NMDSELR_EL1_FIELD.extract(self.bits()),
+            Self::NPMSSDATA_FIELD.extract(self.bits()),
+            Self::NPMSSCR_EL1_FIELD.extract(self.bits()),
+            Self::NSPMEVCNTRN_EL0_FIELD.extract(self.bits()),
+            Self::NSPMEVTYPERN_EL0_FIELD.extract(self.bits()),
+            Self::NSPMSELR_EL0_FIELD.extract(self.bits()),
+            Self::NSPMCNTEN_FIELD.extract(self.bits()),
+            Self::NSPMINTEN_FIELD.extract(self.bits()),
+            Self::NSPMOVS_FIELD.extract(self.bits()),
+            Self::NSPMCR_EL0_FIELD.extract(self.bits()),
+            Self::NSPMACCESSR_EL1_FIELD.extract(self.bits()),
+            Self::NSPMSCR_EL1_FIELD.extract(self.bits()),
+            Self::NSPMID_FIELD.extract(self.bits()),
+            Self::NSPMDEVAFF_EL1_FIELD.extract(self.bits()),
+            Self::NPMSDSFR_EL1_FIELD.extract(self.bits()),
+            Self::NTRCITECR_EL1_FIELD.extract(self.bits()),
+            Self::NTRBMPAM_EL1_FIELD.extract(self.bits()),
+            Self::NMDSTEPOP_EL1_FIELD.extract(self.bits()),
+            Self::NPMBMAR_EL1_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for Hdfgrtr2El2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for Hdfgrtr2El2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Hdfgrtr2El2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("nPMECR_EL1"),
+                old: Self::NPMECR_EL1_FIELD.extract(self.bits()),
+                new: Self::NPMECR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nPMICNTR_EL0"),
+                old: Self::NPMICNTR_EL0_FIELD.extract(self.bits()),
+                new: Self::NPMICNTR_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nPMICFILTR_EL0"),
+                old: Self::NPMICFILTR_EL0_FIELD.extract(self.bits()),
+                new: Self::NPMICFILTR_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nPMUACR_EL1"),
+                old: Self::NPMUACR_EL1_FIELD.extract(self.bits()),
+                new: Self::NPMUACR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nMDSELR_EL1"),
+                old: Self::NMDSELR_EL1_FIELD.extract(self.bits()),
+                new: Self::NMDSELR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nPMSSDATA"),
+                old: Self::NPMSSDATA_FIELD.extract(self.bits()),
+                new: Self::NPMSSDATA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nPMSSCR_EL1"),
+                old: Self::NPMSSCR_EL1_FIELD.extract(self.bits()),
+                new: Self::NPMSSCR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nSPMEVCNTRn_EL0"),
+                old: Self::NSPMEVCNTRN_EL0_FIELD.extract(self.bits()),
+                new: Self::NSPMEVCNTRN_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nSPMEVTYPERn_EL0"),
+                old: Self::NSPMEVTYPERN_EL0_FIELD.extract(self.bits()),
+                new: Self::NSPMEVTYPERN_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nSPMSELR_EL0"),
+                old: Self::NSPMSELR_EL0_FIELD.extract(self.bits()),
+                new: Self::NSPMSELR_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nSPMCNTEN"),
+                old: Self::NSPMCNTEN_FIELD.extract(self.bits()),
+                new: Self::NSPMCNTEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nSPMINTEN"),
+                old: Self::NSPMINTEN_FIELD.extract(self.bits()),
+                new: Self::NSPMINTEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nSPMOVS"),
+                old: Self::NSPMOVS_FIELD.extract(self.bits()),
+                new: Self::NSPMOVS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nSPMCR_EL0"),
+                old: Self::NSPMCR_EL0_FIELD.extract(self.bits()),
+                new: Self::NSPMCR_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nSPMACCESSR_EL1"),
+                old: Self::NSPMACCESSR_EL1_FIELD.extract(self.bits()),
+                new: Self::NSPMACCESSR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nSPMSCR_EL1"),
+                old: Self::NSPMSCR_EL1_FIELD.extract(self.bits()),
+                new: Self::NSPMSCR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nSPMID"),
+                old: Self::NSPMID_FIELD.extract(self.bits()),
+                new: Self::NSPMID_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nSPMDEVAFF_EL1"),
+                old: Self::NSPMDEVAFF_EL1_FIELD.extract(self.bits()),
+                new: Self::NSPMDEVAFF_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nPMSDSFR_EL1"),
+                old: Self::NPMSDSFR_EL1_FIELD.extract(self.bits()),
+                new: Self::NPMSDSFR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nTRCITECR_EL1"),
+                old: Self::NTRCITECR_EL1_FIELD.extract(self.bits()),
+                new: Self::NTRCITECR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nTRBMPAM_EL1"),
+                old: Self::NTRBMPAM_EL1_FIELD.extract(self.bits()),
+                new: Self::NTRBMPAM_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nMDSTEPOP_EL1"),
+                old: Self::NMDSTEPOP_EL1_FIELD.extract(self.bits()),
+                new: Self::NMDSTEPOP_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nPMBMAR_EL1"),
+                old: Self::NPMBMAR_EL1_FIELD.extract(self.bits()),
+                new: Self::NPMBMAR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1110_0010_0000_0000_0000_0000_0010,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1110_0010_0000_0000_0000_0000_0010,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Hdfgrtr2El2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Hdfgrtr2El2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `HDFGRTR_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct HdfgrtrEl2: u64 {
+        /// `DBGBCRn_EL1` bit.
+        const DBGBCRN_EL1 = 1 << 0;
+        /// `DBGBVRn_EL1` bit.
+        const DBGBVRN_EL1 = 1 << 1;
+        /// `DBGWCRn_EL1` bit.
+        const DBGWCRN_EL1 = 1 << 2;
+        /// `DBGWVRn_EL1` bit.
+        const DBGWVRN_EL1 = 1 << 3;
+        /// `MDSCR_EL1` bit.
+        const MDSCR_EL1 = 1 << 4;
+        /// `DBGCLAIM` bit.
+        const DBGCLAIM = 1 << 5;
+        /// `DBGAUTHSTATUS_EL1` bit.
+        const DBGAUTHSTATUS_EL1 = 1 << 6;
+        /// `DBGPRCR_EL1` bit.
+        const DBGPRCR_EL1 = 1 << 7;
+        /// `OSLSR_EL1` bit.
+        const OSLSR_EL1 = 1 << 9;
+        /// `OSECCR_EL1` bit.
+        const OSECCR_EL1 = 1 << 10;
+        /// `OSDLR_EL1` bit.
+        const OSDLR_EL1 = 1 << 11;
+        /// `PMEVCNTRn_EL0` bit.
+        const PMEVCNTRN_EL0 = 1 << 12;
+        /// `PMEVTYPERn_EL0` bit.
+        const PMEVTYPERN_EL0 = 1 << 13;
+        /// `PMCCFILTR_EL0` bit.
+        const PMCCFILTR_EL0 = 1 << 14;
+        /// `PMCCNTR_EL0` bit.
+        const PMCCNTR_EL0 = 1 << 15;
+        /// `PMCNTEN` bit.
+        const PMCNTEN = 1 << 16;
+        /// `PMINTEN` bit.
+        const PMINTEN = 1 << 17;
+        /// `PMOVS` bit.
+        const PMOVS = 1 << 18;
+        /// `PMSELR_EL0` bit.
+        const PMSELR_EL0 = 1 << 19;
+        /// `PMMIR_EL1` bit.
+        const PMMIR_EL1 = 1 << 22;
+        /// `PMBLIMITR_EL1` bit.
+        const PMBLIMITR_EL1 = 1 << 23;
+        /// `PMBPTR_EL1` bit.
+        const PMBPTR_EL1 = 1 << 24;
+        /// `PMBSR_EL1` bit.
+        const PMBSR_EL1 = 1 << 25;
+        /// `PMSCR_EL1` bit.
+        const PMSCR_EL1 = 1 << 26;
+        /// `PMSEVFR_EL1` bit.
+        const PMSEVFR_EL1 = 1 << 27;
+        /// `PMSFCR_EL1` bit.
+        const PMSFCR_EL1 = 1 << 28;
+        /// `PMSICR_EL1` bit.
+        const PMSICR_EL1 = 1 << 29;
+        /// `PMSIDR_EL1` bit.
+        const PMSIDR_EL1 = 1 << 30;
+        /// `PMSIRR_EL1` bit.
+        const PMSIRR_EL1 = 1 << 31;
+        /// `PMSLATFR_EL1` bit.
+        const PMSLATFR_EL1 = 1 << 32;
+        /// `TRC` bit.
+        const TRC = 1 << 33;
+        /// `TRCAUTHSTATUS` bit.
+        const TRCAUTHSTATUS = 1 << 34;
+        /// `TRCAUXCTLR` bit.
+        const TRCAUXCTLR = 1 << 35;
+        /// `TRCCLAIM` bit.
+        const TRCCLAIM = 1 << 36;
+        /// `TRCCNTVRn` bit.
+        const TRCCNTVRN = 1 << 37;
+        /// `TRCID` bit.
+        const TRCID = 1 << 40;
+        /// `TRCIMSPECn` bit.
+        const TRCIMSPECN = 1 << 41;
+        /// `TRCOSLSR` bit.
+        const TRCOSLSR = 1 << 43;
+        /// `TRCPRGCTLR` bit.
+        const TRCPRGCTLR = 1 << 44;
+        /// `TRCSEQSTR` bit.
+        const TRCSEQSTR = 1 << 45;
+        /// `TRCSSCSRn` bit.
+        const TRCSSCSRN = 1 << 46;
+        /// `TRCSTATR` bit.
+        const TRCSTATR = 1 << 47;
+        /// `TRCVICTLR` bit.
+        const TRCVICTLR = 1 << 48;
+        /// `TRBBASER_EL1` bit.
+        const TRBBASER_EL1 = 1 << 50;
+        /// `TRBIDR_EL1` bit.
+        const TRBIDR_EL1 = 1 << 51;
+        /// `TRBLIMITR_EL1` bit.
+        const TRBLIMITR_EL1 = 1 << 52;
+        /// `TRBMAR_EL1` bit.
+        const TRBMAR_EL1 = 1 << 53;
+        /// `TRBPTR_EL1` bit.
+        const TRBPTR_EL1 = 1 << 54;
+        /// `TRBSR_EL1` bit.
+        const TRBSR_EL1 = 1 << 55;
+        /// `TRBTRG_EL1` bit.
+        const TRBTRG_EL1 = 1 << 56;
+        /// `PMUSERENR_EL0` bit.
+        const PMUSERENR_EL0 = 1 << 57;
+        /// `PMCEIDn_EL0` bit.
+        const PMCEIDN_EL0 = 1 << 58;
+        /// `nBRBIDR` bit.
+        const NBRBIDR = 1 << 59;
+        /// `nBRBCTL` bit.
+        const NBRBCTL = 1 << 60;
+        /// `nBRBDATA` bit.
+        const NBRBDATA = 1 << 61;
+        /// `nPMSNEVFR_EL1` bit.
+        const NPMSNEVFR_EL1 = 1 << 62;
+        /// `PMBIDR_EL1` bit.
+        const PMBIDR_EL1 = 1 << 63;
+    }
+}
+
+#[cfg(feature = "el2")]
+impl HdfgrtrEl2 {
+    /// Descriptor of the `DBGBCRn_EL1` field, for use with [`FieldAccess`].
+    pub const DBGBCRN_EL1_FIELD: Field<Self, bool, 0, 1> = Field::new("HDFGRTR_EL2", "DBGBCRn_EL1");
+    /// Descriptor of the `DBGBVRn_EL1` field, for use with [`FieldAccess`].
+    pub const DBGBVRN_EL1_FIELD: Field<Self, bool, 1, 1> = Field::new("HDFGRTR_EL2", "DBGBVRn_EL1");
+    /// Descriptor of the `DBGWCRn_EL1` field, for use with [`FieldAccess`].
+    pub const DBGWCRN_EL1_FIELD: Field<Self, bool, 2, 1> = Field::new("HDFGRTR_EL2", "DBGWCRn_EL1");
+    /// Descriptor of the `DBGWVRn_EL1` field, for use with [`FieldAccess`].
+    pub const DBGWVRN_EL1_FIELD: Field<Self, bool, 3, 1> = Field::new("HDFGRTR_EL2", "DBGWVRn_EL1");
+    /// Descriptor of the `MDSCR_EL1` field, for use with [`FieldAccess`].
+    pub const MDSCR_EL1_FIELD: Field<Self, bool, 4, 1> = Field::new("HDFGRTR_EL2", "MDSCR_EL1");
+    /// Descriptor of the `DBGCLAIM` field, for use with [`FieldAccess`].
+    pub const DBGCLAIM_FIELD: Field<Self, bool, 5, 1> = Field::new("HDFGRTR_EL2", "DBGCLAIM");
+    /// Descriptor of the `DBGAUTHSTATUS_EL1` field, for use with [`FieldAccess`].
+    pub const DBGAUTHSTATUS_EL1_FIELD: Field<Self, bool, 6, 1> =
+        Field::new("HDFGRTR_EL2", "DBGAUTHSTATUS_EL1");
+    /// Descriptor of the `DBGPRCR_EL1` field, for use with [`FieldAccess`].
+    pub const DBGPRCR_EL1_FIELD: Field<Self, bool, 7, 1> = Field::new("HDFGRTR_EL2", "DBGPRCR_EL1");
+    /// Descriptor of the `OSLSR_EL1` field, for use with [`FieldAccess`].
+    pub const OSLSR_EL1_FIELD: Field<Self, bool, 9, 1> = Field::new("HDFGRTR_EL2", "OSLSR_EL1");
+    /// Descriptor of the `OSECCR_EL1` field, for use with [`FieldAccess`].
+    pub const OSECCR_EL1_FIELD: Field<Self, bool, 10, 1> = Field::new("HDFGRTR_EL2", "OSECCR_EL1");
+    /// Descriptor of the `OSDLR_EL1` field, for use with [`FieldAccess`].
+    pub const OSDLR_EL1_FIELD: Field<Self, bool, 11, 1> = Field::new("HDFGRTR_EL2", "OSDLR_EL1");
+    /// Descriptor of the `PMEVCNTRn_EL0` field, for use with [`FieldAccess`].
+    pub const PMEVCNTRN_EL0_FIELD: Field<Self, bool, 12, 1> =
+        Field::new("HDFGRTR_EL2", "PMEVCNTRn_EL0");
+    /// Descriptor of the `PMEVTYPERn_EL0` field, for use with [`FieldAccess`].
+    pub const PMEVTYPERN_EL0_FIELD: Field<Self, bool, 13, 1> =
+        Field::new("HDFGRTR_EL2", "PMEVTYPERn_EL0");
+    /// Descriptor of the `PMCCFILTR_EL0` field, for use with [`FieldAccess`].
+    pub const PMCCFILTR_EL0_FIELD: Field<Self, bool, 14, 1> =
+        Field::new("HDFGRTR_EL2", "PMCCFILTR_EL0");
+    /// Descriptor of the `PMCCNTR_EL0` field, for use with [`FieldAccess`].
+    pub const PMCCNTR_EL0_FIELD: Field<Self, bool, 15, 1> =
+        Field::new("HDFGRTR_EL2", "PMCCNTR_EL0");
+    /// Descriptor of the `PMCNTEN` field, for use with [`FieldAccess`].
+    pub const PMCNTEN_FIELD: Field<Self, bool, 16, 1> = Field::new("HDFGRTR_EL2", "PMCNTEN");
+    /// Descriptor of the `PMINTEN` field, for use with [`FieldAccess`].
+    pub const PMINTEN_FIELD: Field<Self, bool, 17, 1> = Field::new("HDFGRTR_EL2", "PMINTEN");
+    /// Descriptor of the `PMOVS` field, for use with [`FieldAccess`].
+    pub const PMOVS_FIELD: Field<Self, bool, 18, 1> = Field::new("HDFGRTR_EL2", "PMOVS");
+    /// Descriptor of the `PMSELR_EL0` field, for use with [`FieldAccess`].
+    pub const PMSELR_EL0_FIELD: Field<Self, bool, 19, 1> = Field::new("HDFGRTR_EL2", "PMSELR_EL0");
+    /// Descriptor of the `PMMIR_EL1` field, for use with [`FieldAccess`].
+    pub const PMMIR_EL1_FIELD: Field<Self, bool, 22, 1> = Field::new("HDFGRTR_EL2", "PMMIR_EL1");
+    /// Descriptor of the `PMBLIMITR_EL1` field, for use with [`FieldAccess`].
+    pub const PMBLIMITR_EL1_FIELD: Field<Self, bool, 23, 1> =
+        Field::new("HDFGRTR_EL2", "PMBLIMITR_EL1");
+    /// Descriptor of the `PMBPTR_EL1` field, for use with [`FieldAccess`].
+    pub const PMBPTR_EL1_FIELD: Field<Self, bool, 24, 1> = Field::new("HDFGRTR_EL2", "PMBPTR_EL1");
+    /// Descriptor of the `PMBSR_EL1` field, for use with [`FieldAccess`].
+    pub const PMBSR_EL1_FIELD: Field<Self, bool, 25, 1> = Field::new("HDFGRTR_EL2", "PMBSR_EL1");
+    /// Descriptor of the `PMSCR_EL1` field, for use with [`FieldAccess`].
+    pub const PMSCR_EL1_FIELD: Field<Self, bool, 26, 1> = Field::new("HDFGRTR_EL2", "PMSCR_EL1");
+    /// Descriptor of the `PMSEVFR_EL1` field, for use with [`FieldAccess`].
+    pub const PMSEVFR_EL1_FIELD: Field<Self, bool, 27, 1> =
+        Field::new("HDFGRTR_EL2", "PMSEVFR_EL1");
+    /// Descriptor of the `PMSFCR_EL1` field, for use with [`FieldAccess`].
+    pub const PMSFCR_EL1_FIELD: Field<Self, bool, 28, 1> = Field::new("HDFGRTR_EL2", "PMSFCR_EL1");
+    /// Descriptor of the `PMSICR_EL1` field, for use with [`FieldAccess`].
+    pub const PMSICR_EL1_FIELD: Field<Self, bool, 29, 1> = Field::new("HDFGRTR_EL2", "PMSICR_EL1");
+    /// Descriptor of the `PMSIDR_EL1` field, for use with [`FieldAccess`].
+    pub const PMSIDR_EL1_FIELD: Field<Self, bool, 30, 1> = Field::new("HDFGRTR_EL2", "PMSIDR_EL1");
+    /// Descriptor of the `PMSIRR_EL1` field, for use with [`FieldAccess`].
+    pub const PMSIRR_EL1_FIELD: Field<Self, bool, 31, 1> = Field::new("HDFGRTR_EL2", "PMSIRR_EL1");
+    /// Descriptor of the `PMSLATFR_EL1` field, for use with [`FieldAccess`].
+    pub const PMSLATFR_EL1_FIELD: Field<Self, bool, 32, 1> =
+        Field::new("HDFGRTR_EL2", "PMSLATFR_EL1");
+    /// Descriptor of the `TRC` field, for use with [`FieldAccess`].
+    pub const TRC_FIELD: Field<Self, bool, 33, 1> = Field::new("HDFGRTR_EL2", "TRC");
+    /// Descriptor of the `TRCAUTHSTATUS` field, for use with [`FieldAccess`].
+    pub const TRCAUTHSTATUS_FIELD: Field<Self, bool, 34, 1> =
+        Field::new("HDFGRTR_EL2", "TRCAUTHSTATUS");
+    /// Descriptor of the `TRCAUXCTLR` field, for use with [`FieldAccess`].
+    pub const TRCAUXCTLR_FIELD: Field<Self, bool, 35, 1> = Field::new("HDFGRTR_EL2", "TRCAUXCTLR");
+    /// Descriptor of the `TRCCLAIM` field, for use with [`FieldAccess`].
+    pub const TRCCLAIM_FIELD: Field<Self, bool, 36, 1> = Field::new("HDFGRTR_EL2", "TRCCLAIM");
+    /// Descriptor of the `TRCCNTVRn` field, for use with [`FieldAccess`].
+    pub const TRCCNTVRN_FIELD: Field<Self, bool, 37, 1> = Field::new("HDFGRTR_EL2", "TRCCNTVRn");
+    /// Descriptor of the `TRCID` field, for use with [`FieldAccess`].
+    pub const TRCID_FIELD: Field<Self, bool, 40, 1> = Field::new("HDFGRTR_EL2", "TRCID");
+    /// Descriptor of the `TRCIMSPECn` field, for use with [`FieldAccess`].
+    pub const TRCIMSPECN_FIELD: Field<Self, bool, 41, 1> = Field::new("HDFGRTR_EL2", "TRCIMSPECn");
+    /// Descriptor of the `TRCOSLSR` field, for use with [`FieldAccess`].
+    pub const TRCOSLSR_FIELD: Field<Self, bool, 43, 1> = Field::new("HDFGRTR_EL2", "TRCOSLSR");
+    /// Descriptor of the `TRCPRGCTLR` field, for use with [`FieldAccess`].
+    pub const TRCPRGCTLR_FIELD: Field<Self, bool, 44, 1> = Field::new("HDFGRTR_EL2", "TRCPRGCTLR");
+    /// Descriptor of the `TRCSEQSTR` field, for use with [`FieldAccess`].
+    pub const TRCSEQSTR_FIELD: Field<Self, bool, 45, 1> = Field::new("HDFGRTR_EL2", "TRCSEQSTR");
+    /// Descriptor of the `TRCSSCSRn` field, for use with [`FieldAccess`].
+    pub const TRCSSCSRN_FIELD: Field<Self, bool, 46, 1> = Field::new("HDFGRTR_EL2", "TRCSSCSRn");
+    /// Descriptor of the `TRCSTATR` field, for use with [`FieldAccess`].
+    pub const TRCSTATR_FIELD: Field<Self, bool, 47, 1> = Field::new("HDFGRTR_EL2", "TRCSTATR");
+    /// Descriptor of the `TRCVICTLR` field, for use with [`FieldAccess`].
+    pub const TRCVICTLR_FIELD: Field<Self, bool, 48, 1> = Field::new("HDFGRTR_EL2", "TRCVICTLR");
+    /// Descriptor of the `TRBBASER_EL1` field, for use with [`FieldAccess`].
+    pub const TRBBASER_EL1_FIELD: Field<Self, bool, 50, 1> =
+        Field::new("HDFGRTR_EL2", "TRBBASER_EL1");
+    /// Descriptor of the `TRBIDR_EL1` field, for use with [`FieldAccess`].
+    pub const TRBIDR_EL1_FIELD: Field<Self, bool, 51, 1> = Field::new("HDFGRTR_EL2", "TRBIDR_EL1");
+    /// Descriptor of the `TRBLIMITR_EL1` field, for use with [`FieldAccess`].
+    pub const TRBLIMITR_EL1_FIELD: Field<Self, bool, 52, 1> =
+        Field::new("HDFGRTR_EL2", "TRBLIMITR_EL1");
+    /// Descriptor of the `TRBMAR_EL1` field, for use with [`FieldAccess`].
+    pub const TRBMAR_EL1_FIELD: Field<Self, bool, 53, 1> = Field::new("HDFGRTR_EL2", "TRBMAR_EL1");
+    /// Descriptor of the `TRBPTR_EL1` field, for use with [`FieldAccess`].
+    pub const TRBPTR_EL1_FIELD: Field<Self, bool, 54, 1> = Field::new("HDFGRTR_EL2", "TRBPTR_EL1");
+    /// Descriptor of the `TRBSR_EL1` field, for use with [`FieldAccess`].
+    pub const TRBSR_EL1_FIELD: Field<Self, bool, 55, 1> = Field::new("HDFGRTR_EL2", "TRBSR_EL1");
+    /// Descriptor of the `TRBTRG_EL1` field, for use with [`FieldAccess`].
+    pub const TRBTRG_EL1_FIELD: Field<Self, bool, 56, 1> = Field::new("HDFGRTR_EL2", "TRBTRG_EL1");
+    /// Descriptor of the `PMUSERENR_EL0` field, for use with [`FieldAccess`].
+    pub const PMUSERENR_EL0_FIELD: Field<Self, bool, 57, 1> =
+        Field::new("HDFGRTR_EL2", "PMUSERENR_EL0");
+    /// Descriptor of the `PMCEIDn_EL0` field, for use with [`FieldAccess`].
+    pub const PMCEIDN_EL0_FIELD: Field<Self, bool, 58, 1> =
+        Field::new("HDFGRTR_EL2", "PMCEIDn_EL0");
+    /// Descriptor of the `nBRBIDR` field, for use with [`FieldAccess`].
+    pub const NBRBIDR_FIELD: Field<Self, bool, 59, 1> = Field::new("HDFGRTR_EL2", "nBRBIDR");
+    /// Descriptor of the `nBRBCTL` field, for use with [`FieldAccess`].
+    pub const NBRBCTL_FIELD: Field<Self, bool, 60, 1> = Field::new("HDFGRTR_EL2", "nBRBCTL");
+    /// Descriptor of the `nBRBDATA` field, for use with [`FieldAccess`].
+    pub const NBRBDATA_FIELD: Field<Self, bool, 61, 1> = Field::new("HDFGRTR_EL2", "nBRBDATA");
+    /// Descriptor of the `nPMSNEVFR_EL1` field, for use with [`FieldAccess`].
+    pub const NPMSNEVFR_EL1_FIELD: Field<Self, bool, 62, 1> =
+        Field::new("HDFGRTR_EL2", "nPMSNEVFR_EL1");
+    /// Descriptor of the `PMBIDR_EL1` field, for use with [`FieldAccess`].
+    pub const PMBIDR_EL1_FIELD: Field<Self, bool, 63, 1> = Field::new("HDFGRTR_EL2", "PMBIDR_EL1");
+}
+
+#[cfg(feature = "el2")]
+impl Display for HdfgrtrEl2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("HdfgrtrEl2");
+        let bits = self.bits();
+        s.field("DBGBCRn_EL1", &Self::DBGBCRN_EL1_FIELD.extract(bits));
+        s.field("DBGBVRn_EL1", &Self::DBGBVRN_EL1_FIELD.extract(bits));
+        s.field("DBGWCRn_EL1", &Self::DBGWCRN_EL1_FIELD.extract(bits));
+        s.field("DBGWVRn_EL1", &Self::DBGWVRN_EL1_FIELD.extract(bits));
+        s.field("MDSCR_EL1", &Self::MDSCR_EL1_FIELD.extract(bits));
+        s.field("DBGCLAIM", &Self::DBGCLAIM_FIELD.extract(bits));
+        s.field(
+            "DBGAUTHSTATUS_EL1",
+            &Self::DBGAUTHSTATUS_EL1_FIELD.extract(bits),
+        );
+        s.field("DBGPRCR_EL1", &Self::DBGPRCR_EL1_FIELD.extract(bits));
+        s.field("OSLSR_EL1", &Self::OSLSR_EL1_FIELD.extract(bits));
+        s.field("OSECCR_EL1", &Self::OSECCR_EL1_FIELD.extract(bits));
+        s.field("OSDLR_EL1", &Self::OSDLR_EL1_FIELD.extract(bits));
+        s.field("PMEVCNTRn_EL0", &Self::PMEVCNTRN_EL0_FIELD.extract(bits));
+        s.field("PMEVTYPERn_EL0", &Self::PMEVTYPERN_EL0_FIELD.extract(bits));
+        s.field("PMCCFILTR_EL0", &Self::PMCCFILTR_EL0_FIELD.extract(bits));
+        s.field("PMCCNTR_EL0", &Self::PMCCNTR_EL0_FIELD.extract(bits));
+        s.field("PMCNTEN", &Self::PMCNTEN_FIELD.extract(bits));
+        s.field("PMINTEN", &Self::PMINTEN_FIELD.extract(bits));
+        s.field("PMOVS", &Self::PMOVS_FIELD.extract(bits));
+        s.field("PMSELR_EL0", &Self::PMSELR_EL0_FIELD.extract(bits));
+        s.field("PMMIR_EL1", &Self::PMMIR_EL1_FIELD.extract(bits));
+        s.field("PMBLIMITR_EL1", &Self::PMBLIMITR_EL1_FIELD.extract(bits));
+        s.field("PMBPTR_EL1", &Self::PMBPTR_EL1_FIELD.extract(bits));
+        s.field("PMBSR_EL1", &Self::PMBSR_EL1_FIELD.extract(bits));
+        s.field("PMSCR_EL1", &Self::PMSCR_EL1_FIELD.extract(bits));
+        s.field("PMSEVFR_EL1", &Self::PMSEVFR_EL1_FIELD.extract(bits));
+        s.field("PMSFCR_EL1", &Self::PMSFCR_EL1_FIELD.extract(bits));
+        s.field("PMSICR_EL1", &Self::PMSICR_EL1_FIELD.extract(bits));
+        s.field("PMSIDR_EL1", &Self::PMSIDR_EL1_FIELD.extract(bits));
+        s.field("PMSIRR_EL1", &Self::PMSIRR_EL1_FIELD.extract(bits));
+        s.field("PMSLATFR_EL1", &Self::PMSLATFR_EL1_FIELD.extract(bits));
+        s.field("TRC", &Self::TRC_FIELD.extract(bits));
+        s.field("TRCAUTHSTATUS", &Self::TRCAUTHSTATUS_FIELD.extract(bits));
+        s.field("TRCAUXCTLR", &Self::TRCAUXCTLR_FIELD.extract(bits));
+        s.field("TRCCLAIM", &Self::TRCCLAIM_FIELD.extract(bits));
+        s.field("TRCCNTVRn", &Self::TRCCNTVRN_FIELD.extract(bits));
+        s.field("TRCID", &Self::TRCID_FIELD.extract(bits));
+        s.field("TRCIMSPECn", &Self::TRCIMSPECN_FIELD.extract(bits));
+        s.field("TRCOSLSR", &Self::TRCOSLSR_FIELD.extract(bits));
+        s.field("TRCPRGCTLR", &Self::TRCPRGCTLR_FIELD.extract(bits));
+        s.field("TRCSEQSTR", &Self::TRCSEQSTR_FIELD.extract(bits));
+        s.field("TRCSSCSRn", &Self::TRCSSCSRN_FIELD.extract(bits));
+        s.field("TRCSTATR", &Self::TRCSTATR_FIELD.extract(bits));
+        s.field("TRCVICTLR", &Self::TRCVICTLR_FIELD.extract(bits));
+        s.field("TRBBASER_EL1", &Self::TRBBASER_EL1_FIELD.extract(bits));
+        s.field("TRBIDR_EL1", &Self::TRBIDR_EL1_FIELD.extract(bits));
+        s.field("TRBLIMITR_EL1", &Self::TRBLIMITR_EL1_FIELD.extract(bits));
+        s.field("TRBMAR_EL1", &Self::TRBMAR_EL1_FIELD.extract(bits));
+        s.field("TRBPTR_EL1", &Self::TRBPTR_EL1_FIELD.extract(bits));
+        s.field("TRBSR_EL1", &Self::TRBSR_EL1_FIELD.extract(bits));
+        s.field("TRBTRG_EL1", &Self::TRBTRG_EL1_FIELD.extract(bits));
+        s.field("PMUSERENR_EL0", &Self::PMUSERENR_EL0_FIELD.extract(bits));
+        s.field("PMCEIDn_EL0", &Self::PMCEIDN_EL0_FIELD.extract(bits));
+        s.field("nBRBIDR", &Self::NBRBIDR_FIELD.extract(bits));
+        s.field("nBRBCTL", &Self::NBRBCTL_FIELD.extract(bits));
+        s.field("nBRBDATA", &Self::NBRBDATA_FIELD.extract(bits));
+        s.field("nPMSNEVFR_EL1", &Self::NPMSNEVFR_EL1_FIELD.extract(bits));
+        s.field("PMBIDR_EL1", &Self::PMBIDR_EL1_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for HdfgrtrEl2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "HdfgrtrEl2 {{ DBGBCRn_EL1: {=u64}, DBGBVRn_EL1: {=u64}, DBGWCRn_EL1: {=u64}, DBGWVRn_EL1: {=u64}, MDSCR_EL1: {=u64}, DBGCLAIM: {=u64}, DBGAUTHSTATUS_EL1: {=u64}, DBGPRCR_EL1: {=u64}, OSLSR_EL1: {=u64}, OSECCR_EL1: {=u64}, OSDLR_EL1: {=u64}, PMEVCNTRn_EL0: {=u64}, PMEVTYPERn_EL0: {=u64}, PMCCFILTR_EL0: {=u64}, PMCCNTR_EL0: {=u64}, PMCNTEN: {=u64}, PMINTEN: {=u64}, PMOVS: {=u64}, PMSELR_EL0: {=u64}, PMMIR_EL1: {=u64}, PMBLIMITR_EL1: {=u64}, PMBPTR_EL1: {=u64}, PMBSR_EL1: {=u64}, PMSCR_EL1: {=u64}, PMSEVFR_EL1: {=u64}, PMSFCR_EL1: {=u64}, PMSICR_EL1: {=u64}, PMSIDR_EL1: {=u64}, PMSIRR_EL1: {=u64}, PMSLATFR_EL1: {=u64}, TRC: {=u64}, TRCAUTHSTATUS: {=u64}, TRCAUXCTLR: {=u64}, TRCCLAIM: {=u64}, TRCCNTVRn: {=u64}, TRCID: {=u64}, TRCIMSPECn: {=u64}, TRCOSLSR: {=u64}, TRCPRGCTLR: {=u64}, TRCSEQSTR: {=u64}, TRCSSCSRn: {=u64}, TRCSTATR: {=u64}, TRCVICTLR: {=u64}, TRBBASER_EL1: {=u64}, TRBIDR_EL1: {=u64}, TRBLIMITR_EL1: {=u64}, TRBMAR_EL1: {=u64}, TRBPTR_EL1: {=u64}, TRBSR_EL1: {=u64}, TRBTRG_EL1: {=u64}, PMUSERENR_EL0: {=u64}, PMCEIDn_EL0: {=u64}, nBRBIDR: {=u64}, nBRBCTL: {=u64}, nBRBDATA: {=u64}, nPMSNEVFR_EL1: {=u64}, PMBIDR_EL1: {=u64} }}",
+            Self::DBGBCRN_EL1_FIELD.extract(self.bits()),
+            Self::DBGBVRN_EL1_FIELD.extract(self.bits()),
+            Self::DBGWCRN_EL1_FIELD.extract(self.bits()),
+            Self::DBGWVRN_EL1_FIELD.extract(self.bits()),
+            Self::MDSCR_EL1_FIELD.extract(self.bits()),
+            Self::DBGCLAIM_FIELD.extract(self.bits()),
+            Self::DBGAUTHSTATUS_EL1_FIELD.extract(self.bits()),
+            Self::DBGPRCR_EL1_FIELD.extract(self.bits()),
+            Self::OSLSR_EL1_FIELD.extract(self.bits()),
+            Self::OSECCR_EL1_FIELD.extract(self.bits()),
+            Self::OSDLR_EL1_FIELD.extract(self.bits()),
+            Self::PMEVCNTRN_EL0_FIELD.extract(self.bits()),
+            Self::PMEVTYPERN_EL0_FIELD.extract(self.bits()),
+            Self::PMCCFILTR_EL0_FIELD.extract(self.bits()),
+            Self::PMCCNTR_EL0_FIELD.extract(self.bits()),
+            Self::PMCNTEN_FIELD.extract(self.bits()),
+            Self::PMINTEN_FIELD.extract(self.bits()),
+            Self::PMOVS_FIELD.extract(self.bits()),
+            Self::PMSELR_EL0_FIELD.extract(self.bits()),
+            Self::PMMIR_EL1_FIELD.extract(self.bits()),
+            Self::PMBLIMITR_EL1_FIELD.extract(self.bits()),
+            Self::PMBPTR_EL1_FIELD.extract(self.bits()),
+            Self::PMBSR_EL1_FIELD.extract(self.bits()),
+            Self::PMSCR_EL1_FIELD.extract(self.bits()),
+            Self::PMSEVFR_EL1_FIELD.extract(self.bits()),
+            Self::PMSFCR_EL1_FIELD.extract(self.bits()),
+            Self::PMSICR_EL1_FIELD.extract(self.bits()),
+            Self::PMSIDR_EL1_FIELD.extract(self.bits()),
+            Self::PMSIRR_EL1_FIELD.extract(self.bits()),
+            Self::PMSLATFR_EL1_FIELD.extract(self.bits()),
+            Self::TRC_FIELD.extract(self.bits()),
+            Self::TRCAUTHSTATUS_FIELD.extract(self.bits()),
+            Self::TRCAUXCTLR_FIELD.extract(self.bits()),
+            Self::TRCCLAIM_FIELD.extract(self.bits()),
+            Self::TRCCNTVRN_FIELD.extract(self.bits()),
+            Self::TRCID_FIELD.extract(self.bits()),
+            Self::TRCIMSPECN_FIELD.extract(self.bits()),
+            Self::TRCOSLSR_FIELD.extract(self.bits()),
+            Self::TRCPRGCTLR_FIELD.extract(self.bits()),
+            Self::TRCSEQSTR_FIELD.extract(self.bits()),
+            Self::TRCSSCSRN_FIELD.extract(self.bits()),
+            Self::TRCSTATR_FIELD.extract(self.bits()),
+            Self::TRCVICTLR_FIELD.extract(self.bits()),
+            Self::TRBBASER_EL1_FIELD.extract(self.bits()),
+            Self::TRBIDR_EL1_FIELD.extract(self.bits()),
+            Self::TRBLIMITR_EL1_FIELD.extract(self.bits()),
+            Self::TRBMAR_EL1_FIELD.extract(self.bits()),
+            Self::TRBPTR_EL1_FIELD.extract(self.bits()),
+            Self::TRBSR_EL1_FIELD.extract(self.bits()),
+            Self::TRBTRG_EL1_FIELD.extract(self.bits()),
+            Self::PMUSERENR_EL0_FIELD.extract(self.bits()),
+            Self::PMCEIDN_EL0_FIELD.extract(self.bits()),
+            Self::NBRBIDR_FIELD.extract(self.bits()),
+            Self::NBRBCTL_FIELD.extract(self.bits()),
+            Self::NBRBDATA_FIELD.extract(self.bits()),
+            Self::NPMSNEVFR_EL1_FIELD.extract(self.bits()),
+            Self::PMBIDR_EL1_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for HdfgrtrEl2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for HdfgrtrEl2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl HdfgrtrEl2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("DBGBCRn_EL1"),
+                old: Self::DBGBCRN_EL1_FIELD.extract(self.bits()),
+                new: Self::DBGBCRN_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DBGBVRn_EL1"),
+                old: Self::DBGBVRN_EL1_FIELD.extract(self.bits()),
+                new: Self::DBGBVRN_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DBGWCRn_EL1"),
+                old: Self::DBGWCRN_EL1_FIELD.extract(self.bits()),
+                new: Self::DBGWCRN_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DBGWVRn_EL1"),
+                old: Self::DBGWVRN_EL1_FIELD.extract(self.bits()),
+                new: Self::DBGWVRN_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("MDSCR_EL1"),
+                old: Self::MDSCR_EL1_FIELD.extract(self.bits()),
+                new: Self::MDSCR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DBGCLAIM"),
+                old: Self::DBGCLAIM_FIELD.extract(self.bits()),
+                new: Self::DBGCLAIM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DBGAUTHSTATUS_EL1"),
+                old: Self::DBGAUTHSTATUS_EL1_FIELD.extract(self.bits()),
+                new: Self::DBGAUTHSTATUS_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DBGPRCR_EL1"),
+                old: Self::DBGPRCR_EL1_FIELD.extract(self.bits()),
+                new: Self::DBGPRCR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("OSLSR_EL1"),
+                old: Self::OSLSR_EL1_FIELD.extract(self.bits()),
+                new: Self::OSLSR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("OSECCR_EL1"),
+                old: Self::OSECCR_EL1_FIELD.extract(self.bits()),
+                new: Self::OSECCR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("OSDLR_EL1"),
+                old: Self::OSDLR_EL1_FIELD.extract(self.bits()),
+                new: Self::OSDLR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMEVCNTRn_EL0"),
+                old: Self::PMEVCNTRN_EL0_FIELD.extract(self.bits()),
+                new: Self::PMEVCNTRN_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMEVTYPERn_EL0"),
+                old: Self::PMEVTYPERN_EL0_FIELD.extract(self.bits()),
+                new: Self::PMEVTYPERN_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMCCFILTR_EL0"),
+                old: Self::PMCCFILTR_EL0_FIELD.extract(self.bits()),
+                new: Self::PMCCFILTR_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMCCNTR_EL0"),
+                old: Self::PMCCNTR_EL0_FIELD.extract(self.bits()),
+                new: Self::PMCCNTR_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMCNTEN"),
+                old: Self::PMCNTEN_FIELD.extract(self.bits()),
+                new: Self::PMCNTEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMINTEN"),
+                old: Self::PMINTEN_FIELD.extract(self.bits()),
+                new: Self::PMINTEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMOVS"),
+                old: Self::PMOVS_FIELD.extract(self.bits()),
+                new: Self::PMOVS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMSELR_EL0"),
+                old: Self::PMSELR_EL0_FIELD.extract(self.bits()),
+                new: Self::PMSELR_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMMIR_EL1"),
+                old: Self::PMMIR_EL1_FIELD.extract(self.bits()),
+                new: Self::PMMIR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMBLIMITR_EL1"),
+                old: Self::PMBLIMITR_EL1_FIELD.extract(self.bits()),
+                new: Self::PMBLIMITR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMBPTR_EL1"),
+                old: Self::PMBPTR_EL1_FIELD.extract(self.bits()),
+                new: Self::PMBPTR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMBSR_EL1"),
+                old: Self::PMBSR_EL1_FIELD.extract(self.bits()),
+                new: Self::PMBSR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMSCR_EL1"),
+                old: Self::PMSCR_EL1_FIELD.extract(self.bits()),
+                new: Self::PMSCR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMSEVFR_EL1"),
+                old: Self::PMSEVFR_EL1_FIELD.extract(self.bits()),
+                new: Self::PMSEVFR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMSFCR_EL1"),
+                old: Self::PMSFCR_EL1_FIELD.extract(self.bits()),
+                new: Self::PMSFCR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMSICR_EL1"),
+                old: Self::PMSICR_EL1_FIELD.extract(self.bits()),
+                new: Self::PMSICR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMSIDR_EL1"),
+                old: Self::PMSIDR_EL1_FIELD.extract(self.bits()),
+                new: Self::PMSIDR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMSIRR_EL1"),
+                old: Self::PMSIRR_EL1_FIELD.extract(self.bits()),
+                new: Self::PMSIRR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMSLATFR_EL1"),
+                old: Self::PMSLATFR_EL1_FIELD.extract(self.bits()),
+                new: Self::PMSLATFR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TRC"),
+                old: Self::TRC_FIELD.extract(self.bits()),
+                new: Self::TRC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TRCAUTHSTATUS"),
+                old: Self::TRCAUTHSTATUS_FIELD.extract(self.bits()),
+                new: Self::TRCAUTHSTATUS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TRCAUXCTLR"),
+                old: Self::TRCAUXCTLR_FIELD.extract(self.bits()),
+                new: Self::TRCAUXCTLR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TRCCLAIM"),
+                old: Self::TRCCLAIM_FIELD.extract(self.bits()),
+                new: Self::TRCCLAIM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TRCCNTVRn"),
+                old: Self::TRCCNTVRN_FIELD.extract(self.bits()),
+                new: Self::TRCCNTVRN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TRCID"),
+                old: Self::TRCID_FIELD.extract(self.bits()),
+                new: Self::TRCID_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TRCIMSPECn"),
+                old: Self::TRCIMSPECN_FIELD.extract(self.bits()),
+                new: Self::TRCIMSPECN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TRCOSLSR"),
+                old: Self::TRCOSLSR_FIELD.extract(self.bits()),
+                new: Self::TRCOSLSR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TRCPRGCTLR"),
+                old: Self::TRCPRGCTLR_FIELD.extract(self.bits()),
+                new: Self::TRCPRGCTLR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TRCSEQSTR"),
+                old: Self::TRCSEQSTR_FIELD.extract(self.bits()),
+                new: Self::TRCSEQSTR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TRCSSCSRn"),
+                old: Self::TRCSSCSRN_FIELD.extract(self.bits()),
+                new: Self::TRCSSCSRN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TRCSTATR"),
+                old: Self::TRCSTATR_FIELD.extract(self.bits()),
+                new: Self::TRCSTATR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TRCVICTLR"),
+                old: Self::TRCVICTLR_FIELD.extract(self.bits()),
+                new: Self::TRCVICTLR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TRBBASER_EL1"),
+                old: Self::TRBBASER_EL1_FIELD.extract(self.bits()),
+                new: Self::TRBBASER_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TRBIDR_EL1"),
+                old: Self::TRBIDR_EL1_FIELD.extract(self.bits()),
+                new: Self::TRBIDR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TRBLIMITR_EL1"),
+                old: Self::TRBLIMITR_EL1_FIELD.extract(self.bits()),
+                new: Self::TRBLIMITR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TRBMAR_EL1"),
+                old: Self::TRBMAR_EL1_FIELD.extract(self.bits()),
+                new: Self::TRBMAR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TRBPTR_EL1"),
+                old: Self::TRBPTR_EL1_FIELD.extract(self.bits()),
+                new: Self::TRBPTR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TRBSR_EL1"),
+                old: Self::TRBSR_EL1_FIELD.extract(self.bits()),
+                new: Self::TRBSR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TRBTRG_EL1"),
+                old: Self::TRBTRG_EL1_FIELD.extract(self.bits()),
+                new: Self::TRBTRG_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMUSERENR_EL0"),
+                old: Self::PMUSERENR_EL0_FIELD.extract(self.bits()),
+                new: Self::PMUSERENR_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMCEIDn_EL0"),
+                old: Self::PMCEIDN_EL0_FIELD.extract(self.bits()),
+                new: Self::PMCEIDN_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nBRBIDR"),
+                old: Self::NBRBIDR_FIELD.extract(self.bits()),
+                new: Self::NBRBIDR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nBRBCTL"),
+                old: Self::NBRBCTL_FIELD.extract(self.bits()),
+                new: Self::NBRBCTL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nBRBDATA"),
+                old: Self::NBRBDATA_FIELD.extract(self.bits()),
+                new: Self::NBRBDATA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nPMSNEVFR_EL1"),
+                old: Self::NPMSNEVFR_EL1_FIELD.extract(self.bits()),
+                new: Self::NPMSNEVFR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMBIDR_EL1"),
+                old: Self::PMBIDR_EL1_FIELD.extract(self.bits()),
+                new: Self::PMBIDR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b10_0000_0100_1100_0000_0000_0000_0011_0000_0000_0001_0000_0000,
+                new: other.bits()
+                    & 0b10_0000_0100_1100_0000_0000_0000_0011_0000_0000_0001_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl HdfgrtrEl2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl HdfgrtrEl2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `HDFGWTR2_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct Hdfgwtr2El2: u64 {
+        /// `nPMECR_EL1` bit.
+        const NPMECR_EL1 = 1 << 0;
+        /// `nPMICNTR_EL0` bit.
+        const NPMICNTR_EL0 = 1 << 2;
+        /// `nPMICFILTR_EL0` bit.
+        const NPMICFILTR_EL0 = 1 << 3;
+        /// `nPMUACR_EL1` bit.
+        const NPMUACR_EL1 = 1 << 4;
+        /// `nMDSELR_EL1` bit.
+        const NMDSELR_EL1 = 1 << 5;
+        /// `nPMSSCR_EL1` bit.
+        const NPMSSCR_EL1 = 1 << 7;
+        /// `nSPMEVCNTRn_EL0` bit.
+        const NSPMEVCNTRN_EL0 = 1 << 8;
+        /// `nSPMEVTYPERn_EL0` bit.
+        const NSPMEVTYPERN_EL0 = 1 << 9;
+        /// `nSPMSELR_EL0` bit.
+        const NSPMSELR_EL0 = 1 << 10;
+        /// `nSPMCNTEN` bit.
+        const NSPMCNTEN = 1 << 11;
+        /// `nSPMINTEN` bit.
+        const NSPMINTEN = 1 << 12;
+        /// `nSPMOVS` bit.
+        const NSPMOVS = 1 << 13;
+        /// `nSPMCR_EL0` bit.
+        const NSPMCR_EL0 = 1 << 14;
+        /// `nSPMACCESSR_EL1` bit.
+        const NSPMACCESSR_EL1 = 1 << 15;
+        /// `nSPMSCR_EL1` bit.
+        const NSPMSCR_EL1 = 1 << 16;
+        /// `nPMSDSFR_EL1` bit.
+        const NPMSDSFR_EL1 = 1 << 19;
+        /// `nTRCITECR_EL1` bit.
+        const NTRCITECR_EL1 = 1 << 20;
+        /// `nPMZR_EL0` bit.
+        const NPMZR_EL0 = 1 << 21;
+        /// `nTRBMPAM_EL1` bit.
+        const NTRBMPAM_EL1 = 1 << 22;
+        /// `nMDSTEPOP_EL1` bit.
+        const NMDSTEPOP_EL1 = 1 << 23;
+        /// `nPMBMAR_EL1` bit.
+        const NPMBMAR_EL1 = 1 << 24;
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Hdfgwtr2El2 {
+    /// Descriptor of the `nPMECR_EL1` field, for use with [`FieldAccess`].
+    pub const NPMECR_EL1_FIELD: Field<Self, bool, 0, 1> = Field::new("HDFGWTR2_EL2", "nPMECR_EL1");
+    /// Descriptor of the `nPMICNTR_EL0` field, for use with [`FieldAccess`].
+    pub const NPMICNTR_EL0_FIELD: Field<Self, bool, 2, 1> =
+        Field::new("HDFGWTR2_EL2", "nPMICNTR_EL0");
+    /// Descriptor of the `nPMICFILTR_EL0` field, for use with [`FieldAccess`].
+    pub const NPMICFILTR_EL0_FIELD: Field<Self, bool, 3, 1> =
+        Field::new("HDFGWTR2_EL2", "nPMICFILTR_EL0");
+    /// Descriptor of the `nPMUACR_EL1` field, for use with [`FieldAccess`].
+    pub const NPMUACR_EL1_FIELD: Field<Self, bool, 4, 1> =
+        Field::new("HDFGWTR2_EL2", "nPMUACR_EL1");
+    /// Descriptor of the `nMDSELR_EL1` field, for use with [`FieldAccess`].
+    pub const NMDSELR_EL1_FIELD: Field<Self, bool, 5, 1> =
+        Field::new("HDFGWTR2_EL2", "nMDSELR_EL1");
+    /// Descriptor of the `nPMSSCR_EL1` field, for use with [`FieldAccess`].
+    pub const NPMSSCR_EL1_FIELD: Field<Self, bool, 7, 1> =
+        Field::new("HDFGWTR2_EL2", "nPMSSCR_EL1");
+    /// Descriptor of the `nSPMEVCNTRn_EL0` field, for use with [`FieldAccess`].
+    pub const NSPMEVCNTRN_EL0_FIELD: Field<Self, bool, 8, 1> =
+        Field::new("HDFGWTR2_EL2", "nSPMEVCNTRn_EL0");
+    /// Descriptor of the `nSPMEVTYPERn_EL0` field, for use with [`FieldAccess`].
+    pub const NSPMEVTYPERN_EL0_FIELD: Field<Self, bool, 9, 1> =
+        Field::new("HDFGWTR2_EL2", "nSPMEVTYPERn_EL0");
+    /// Descriptor of the `nSPMSELR_EL0` field, for use with [`FieldAccess`].
+    pub const NSPMSELR_EL0_FIELD: Field<Self, bool, 10, 1> =
+        Field::new("HDFGWTR2_EL2", "nSPMSELR_EL0");
+    /// Descriptor of the `nSPMCNTEN` field, for use with [`FieldAccess`].
+    pub const NSPMCNTEN_FIELD: Field<Self, bool, 11, 1> = Field::new("HDFGWTR2_EL2", "nSPMCNTEN");
+    /// Descriptor of the `nSPMINTEN` field, for use with [`FieldAccess`].
+    pub const NSPMINTEN_FIELD: Field<Self, bool, 12, 1> = Field::new("HDFGWTR2_EL2", "nSPMINTEN");
+    /// Descriptor of the `nSPMOVS` field, for use with [`FieldAccess`].
+    pub const NSPMOVS_FIELD: Field<Self, bool, 13, 1> = Field::new("HDFGWTR2_EL2", "nSPMOVS");
+    /// Descriptor of the `nSPMCR_EL0` field, for use with [`FieldAccess`].
+    pub const NSPMCR_EL0_FIELD: Field<Self, bool, 14, 1> = Field::new("HDFGWTR2_EL2", "nSPMCR_EL0");
+    /// Descriptor of the `nSPMACCESSR_EL1` field, for use with [`FieldAccess`].
+    pub const NSPMACCESSR_EL1_FIELD: Field<Self, bool, 15, 1> =
+        Field::new("HDFGWTR2_EL2", "nSPMACCESSR_EL1");
+    /// Descriptor of the `nSPMSCR_EL1` field, for use with [`FieldAccess`].
+    pub const NSPMSCR_EL1_FIELD: Field<Self, bool, 16, 1> =
+        Field::new("HDFGWTR2_EL2", "nSPMSCR_EL1");
+    /// Descriptor of the `nPMSDSFR_EL1` field, for use with [`FieldAccess`].
+    pub const NPMSDSFR_EL1_FIELD: Field<Self, bool, 19, 1> =
+        Field::new("HDFGWTR2_EL2", "nPMSDSFR_EL1");
+    /// Descriptor of the `nTRCITECR_EL1` field, for use with [`FieldAccess`].
+    pub const NTRCITECR_EL1_FIELD: Field<Self, bool, 20, 1> =
+        Field::new("HDFGWTR2_EL2", "nTRCITECR_EL1");
+    /// Descriptor of the `nPMZR_EL0` field, for use with [`FieldAccess`].
+    pub const NPMZR_EL0_FIELD: Field<Self, bool, 21, 1> = Field::new("HDFGWTR2_EL2", "nPMZR_EL0");
+    /// Descriptor of the `nTRBMPAM_EL1` field, for use with [`FieldAccess`].
+    pub const NTRBMPAM_EL1_FIELD: Field<Self, bool, 22, 1> =
+        Field::new("HDFGWTR2_EL2", "nTRBMPAM_EL1");
+    /// Descriptor of the `nMDSTEPOP_EL1` field, for use with [`FieldAccess`].
+    pub const NMDSTEPOP_EL1_FIELD: Field<Self, bool, 23, 1> =
+        Field::new("HDFGWTR2_EL2", "nMDSTEPOP_EL1");
+    /// Descriptor of the `nPMBMAR_EL1` field, for use with [`FieldAccess`].
+    pub const NPMBMAR_EL1_FIELD: Field<Self, bool, 24, 1> =
+        Field::new("HDFGWTR2_EL2", "nPMBMAR_EL1");
+}
+
+#[cfg(feature = "el2")]
+impl Display for Hdfgwtr2El2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("Hdfgwtr2El2");
+        let bits = self.bits();
+        s.field("nPMECR_EL1", &Self::NPMECR_EL1_FIELD.extract(bits));
+        s.field("nPMICNTR_EL0", &Self::NPMICNTR_EL0_FIELD.extract(bits));
+        s.field("nPMICFILTR_EL0", &Self::NPMICFILTR_EL0_FIELD.extract(bits));
+        s.field("nPMUACR_EL1", &Self::NPMUACR_EL1_FIELD.extract(bits));
+        s.field("nMDSELR_EL1", &Self::NMDSELR_EL1_FIELD.extract(bits));
+        s.field("nPMSSCR_EL1", &Self::NPMSSCR_EL1_FIELD.extract(bits));
+        s.field(
+            "nSPMEVCNTRn_EL0",
+            &Self::NSPMEVCNTRN_EL0_FIELD.extract(bits),
+        );
+        s.field(
+            "nSPMEVTYPERn_EL0",
+            &Self::NSPMEVTYPERN_EL0_FIELD.extract(bits),
+        );
+        s.field("nSPMSELR_EL0", &Self::NSPMSELR_EL0_FIELD.extract(bits));
+        s.field("nSPMCNTEN", &Self::NSPMCNTEN_FIELD.extract(bits));
+        s.field("nSPMINTEN", &Self::NSPMINTEN_FIELD.extract(bits));
+        s.field("nSPMOVS", &Self::NSPMOVS_FIELD.extract(bits));
+        s.field("nSPMCR_EL0", &Self::NSPMCR_EL0_FIELD.extract(bits));
+        s.field(
+            "nSPMACCESSR_EL1",
+            &Self::NSPMACCESSR_EL1_FIELD.extract(bits),
+        );
+        s.field("nSPMSCR_EL1", &Self::NSPMSCR_EL1_FIELD.extract(bits));
+        s.field("nPMSDSFR_EL1", &Self::NPMSDSFR_EL1_FIELD.extract(bits));
+        s.field("nTRCITECR_EL1", &Self::NTRCITECR_EL1_FIELD.extract(bits));
+        s.field("nPMZR_EL0", &Self::NPMZR_EL0_FIELD.extract(bits));
+        s.field("nTRBMPAM_EL1", &Self::NTRBMPAM_EL1_FIELD.extract(bits));
+        s.field("nMDSTEPOP_EL1", &Self::NMDSTEPOP_EL1_FIELD.extract(bits));
+        s.field("nPMBMAR_EL1", &Self::NPMBMAR_EL1_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for Hdfgwtr2El2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "Hdfgwtr2El2 {{ nPMECR_EL1: {=u64}, nPMICNTR_EL0: {=u64}, nPMICFILTR_EL0: {=u64}, nPMUACR_EL1: {=u64}, nMDSELR_EL1: {=u64}, nPMSSCR_EL1: {=u64}, nSPMEVCNTRn_EL0: {=u64}, nSPMEVTYPERn_EL0: {=u64}, nSPMSELR_EL0: {=u64}, nSPMCNTEN: {=u64}, nSPMINTEN: {=u64}, nSPMOVS: {=u64}, nSPMCR_EL0: {=u64}, nSPMACCESSR_EL1: {=u64}, nSPMSCR_EL1: {=u64}, nPMSDSFR_EL1: {=u64}, nTRCITECR_EL1: {=u64}, nPMZR_EL0: {=u64}, nTRBMPAM_EL1: {=u64}, nMDSTEPOP_EL1: {=u64}, nPMBMAR_EL1: {=u64} }}",
+            Self::NPMECR_EL1_FIELD.extract(self.bits()),
+            Self::NPMICNTR_EL0_FIELD.extract(self.bits()),
+            Self::NPMICFILTR_EL0_FIELD.extract(self.bits()),
+            Self::NPMUACR_EL1_FIELD.extract(self.bits()),
+            Self::NMDSELR_EL1_FIELD.extract(self.bits()),
+            Self::NPMSSCR_EL1_FIELD.extract(self.bits()),
+            Self::NSPMEVCNTRN_EL0_FIELD.extract(self.bits()),
+            Self::NSPMEVTYPERN_EL0_FIELD.extract(self.bits()),
+            Self::NSPMSELR_EL0_FIELD.extract(self.bits()),
+            Self::NSPMCNTEN_FIELD.extract(self.bits()),
+            Self::NSPMINTEN_FIELD.extract(self.bits()),
+            Self::NSPMOVS_FIELD.extract(self.bits()),
+            Self::NSPMCR_EL0_FIELD.extract(self.bits()),
+            Self::NSPMACCESSR_EL1_FIELD.extract(self.bits()),
+            Self::NSPMSCR_EL1_FIELD.extract(self.bits()),
+            Self::NPMSDSFR_EL1_FIELD.extract(self.bits()),
+            Self::NTRCITECR_EL1_FIELD.extract(self.bits()),
+            Self::NPMZR_EL0_FIELD.extract(self.bits()),
+            Self::NTRBMPAM_EL1_FIELD.extract(self.bits()),
+            Self::NMDSTEPOP_EL1_FIELD.extract(self.bits()),
+            Self::NPMBMAR_EL1_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for Hdfgwtr2El2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for Hdfgwtr2El2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Hdfgwtr2El2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("nPMECR_EL1"),
+                old: Self::NPMECR_EL1_FIELD.extract(self.bits()),
+                new: Self::NPMECR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nPMICNTR_EL0"),
+                old: Self::NPMICNTR_EL0_FIELD.extract(self.bits()),
+                new: Self::NPMICNTR_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nPMICFILTR_EL0"),
+                old: Self::NPMICFILTR_EL0_FIELD.extract(self.bits()),
+                new: Self::NPMICFILTR_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nPMUACR_EL1"),
+                old: Self::NPMUACR_EL1_FIELD.extract(self.bits()),
+                new: Self::NPMUACR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nMDSELR_EL1"),
+                old: Self::NMDSELR_EL1_FIELD.extract(self.bits()),
+                new: Self::NMDSELR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nPMSSCR_EL1"),
+                old: Self::NPMSSCR_EL1_FIELD.extract(self.bits()),
+                new: Self::NPMSSCR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nSPMEVCNTRn_EL0"),
+                old: Self::NSPMEVCNTRN_EL0_FIELD.extract(self.bits()),
+                new: Self::NSPMEVCNTRN_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nSPMEVTYPERn_EL0"),
+                old: Self::NSPMEVTYPERN_EL0_FIELD.extract(self.bits()),
+                new: Self::NSPMEVTYPERN_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nSPMSELR_EL0"),
+                old: Self::NSPMSELR_EL0_FIELD.extract(self.bits()),
+                new: Self::NSPMSELR_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nSPMCNTEN"),
+                old: Self::NSPMCNTEN_FIELD.extract(self.bits()),
+                new: Self::NSPMCNTEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nSPMINTEN"),
+                old: Self::NSPMINTEN_FIELD.extract(self.bits()),
+                new: Self::NSPMINTEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nSPMOVS"),
+                old: Self::NSPMOVS_FIELD.extract(self.bits()),
+                new: Self::NSPMOVS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nSPMCR_EL0"),
+                old: Self::NSPMCR_EL0_FIELD.extract(self.bits()),
+                new: Self::NSPMCR_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nSPMACCESSR_EL1"),
+                old: Self::NSPMACCESSR_EL1_FIELD.extract(self.bits()),
+                new: Self::NSPMACCESSR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nSPMSCR_EL1"),
+                old: Self::NSPMSCR_EL1_FIELD.extract(self.bits()),
+                new: Self::NSPMSCR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nPMSDSFR_EL1"),
+                old: Self::NPMSDSFR_EL1_FIELD.extract(self.bits()),
+                new: Self::NPMSDSFR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nTRCITECR_EL1"),
+                old: Self::NTRCITECR_EL1_FIELD.extract(self.bits()),
+                new: Self::NTRCITECR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nPMZR_EL0"),
+                old: Self::NPMZR_EL0_FIELD.extract(self.bits()),
+                new: Self::NPMZR_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nTRBMPAM_EL1"),
+                old: Self::NTRBMPAM_EL1_FIELD.extract(self.bits()),
+                new: Self::NTRBMPAM_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nMDSTEPOP_EL1"),
+                old: Self::NMDSTEPOP_EL1_FIELD.extract(self.bits()),
+                new: Self::NMDSTEPOP_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nPMBMAR_EL1"),
+                old: Self::NPMBMAR_EL1_FIELD.extract(self.bits()),
+                new: Self::NPMBMAR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1110_0000_0110_0000_0000_0100_0010,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1110_0000_0110_0000_0000_0100_0010,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Hdfgwtr2El2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Hdfgwtr2El2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `HDFGWTR_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct HdfgwtrEl2: u64 {
+        /// `DBGBCRn_EL1` bit.
+        const DBGBCRN_EL1 = 1 << 0;
+        /// `DBGBVRn_EL1` bit.
+        const DBGBVRN_EL1 = 1 << 1;
+        /// `DBGWCRn_EL1` bit.
+        const DBGWCRN_EL1 = 1 << 2;
+        /// `DBGWVRn_EL1` bit.
+        const DBGWVRN_EL1 = 1 << 3;
+        /// `MDSCR_EL1` bit.
+        const MDSCR_EL1 = 1 << 4;
+        /// `DBGCLAIM` bit.
+        const DBGCLAIM = 1 << 5;
+        /// `DBGPRCR_EL1` bit.
+        const DBGPRCR_EL1 = 1 << 7;
+        /// `OSLAR_EL1` bit.
+        const OSLAR_EL1 = 1 << 8;
+        /// `OSECCR_EL1` bit.
+        const OSECCR_EL1 = 1 << 10;
+        /// `OSDLR_EL1` bit.
+        const OSDLR_EL1 = 1 << 11;
+        /// `PMEVCNTRn_EL0` bit.
+        const PMEVCNTRN_EL0 = 1 << 12;
+        /// `PMEVTYPERn_EL0` bit.
+        const PMEVTYPERN_EL0 = 1 << 13;
+        /// `PMCCFILTR_EL0` bit.
+        const PMCCFILTR_EL0 = 1 << 14;
+        /// `PMCCNTR_EL0` bit.
+        const PMCCNTR_EL0 = 1 << 15;
+        /// `PMCNTEN` bit.
+        const PMCNTEN = 1 << 16;
+        /// `PMINTEN` bit.
+        const PMINTEN = 1 << 17;
+        /// `PMOVS` bit.
+        const PMOVS = 1 << 18;
+        /// `PMSELR_EL0` bit.
+        const PMSELR_EL0 = 1 << 19;
+        /// `PMSWINC_EL0` bit.
+        const PMSWINC_EL0 = 1 << 20;
+        /// `PMCR_EL0` bit.
+        const PMCR_EL0 = 1 << 21;
+        /// `PMBLIMITR_EL1` bit.
+        const PMBLIMITR_EL1 = 1 << 23;
+        /// `PMBPTR_EL1` bit.
+        const PMBPTR_EL1 = 1 << 24;
+        /// `PMBSR_EL1` bit.
+        const PMBSR_EL1 = 1 << 25;
+        /// `PMSCR_EL1` bit.
+        const PMSCR_EL1 = 1 << 26;
+        /// `PMSEVFR_EL1` bit.
+        const PMSEVFR_EL1 = 1 << 27;
+        /// `PMSFCR_EL1` bit.
+        const PMSFCR_EL1 = 1 << 28;
+        /// `PMSICR_EL1` bit.
+        const PMSICR_EL1 = 1 << 29;
+        /// `PMSIRR_EL1` bit.
+        const PMSIRR_EL1 = 1 << 31;
+        /// `PMSLATFR_EL1` bit.
+        const PMSLATFR_EL1 = 1 << 32;
+        /// `TRC` bit.
+        const TRC = 1 << 33;
+        /// `TRCAUXCTLR` bit.
+        const TRCAUXCTLR = 1 << 35;
+        /// `TRCCLAIM` bit.
+        const TRCCLAIM = 1 << 36;
+        /// `TRCCNTVRn` bit.
+        const TRCCNTVRN = 1 << 37;
+        /// `TRCIMSPECn` bit.
+        const TRCIMSPECN = 1 << 41;
+        /// `TRCOSLAR` bit.
+        const TRCOSLAR = 1 << 42;
+        /// `TRCPRGCTLR` bit.
+        const TRCPRGCTLR = 1 << 44;
+        /// `TRCSEQSTR` bit.
+        const TRCSEQSTR = 1 << 45;
+        /// `TRCSSCSRn` bit.
+        const TRCSSCSRN = 1 << 46;
+        /// `TRCVICTLR` bit.
+        const TRCVICTLR = 1 << 48;
+        /// `TRFCR_EL1` bit.
+        const TRFCR_EL1 = 1 << 49;
+        /// `TRBBASER_EL1` bit.
+        const TRBBASER_EL1 = 1 << 50;
+        /// `TRBLIMITR_EL1` bit.
+        const TRBLIMITR_EL1 = 1 << 52;
+        /// `TRBMAR_EL1` bit.
+        const TRBMAR_EL1 = 1 << 53;
+        /// `TRBPTR_EL1` bit.
+        const TRBPTR_EL1 = 1 << 54;
+        /// `TRBSR_EL1` bit.
+        const TRBSR_EL1 = 1 << 55;
+        /// `TRBTRG_EL1` bit.
+        const TRBTRG_EL1 = 1 << 56;
+        /// `PMUSERENR_EL0` bit.
+        const PMUSERENR_EL0 = 1 << 57;
+        /// `nBRBCTL` bit.
+        const NBRBCTL = 1 << 60;
+        /// `nBRBDATA` bit.
+        const NBRBDATA = 1 << 61;
+        /// `nPMSNEVFR_EL1` bit.
+        const NPMSNEVFR_EL1 = 1 << 62;
+    }
+}
+
+#[cfg(feature = "el2")]
+impl HdfgwtrEl2 {
+    /// Descriptor of the `DBGBCRn_EL1` field, for use with [`FieldAccess`].
+    pub const DBGBCRN_EL1_FIELD: Field<Self, bool, 0, 1> = Field::new("HDFGWTR_EL2", "DBGBCRn_EL1");
+    /// Descriptor of the `DBGBVRn_EL1` field, for use with [`FieldAccess`].
+    pub const DBGBVRN_EL1_FIELD: Field<Self, bool, 1, 1> = Field::new("HDFGWTR_EL2", "DBGBVRn_EL1");
+    /// Descriptor of the `DBGWCRn_EL1` field, for use with [`FieldAccess`].
+    pub const DBGWCRN_EL1_FIELD: Field<Self, bool, 2, 1> = Field::new("HDFGWTR_EL2", "DBGWCRn_EL1");
+    /// Descriptor of the `DBGWVRn_EL1` field, for use with [`FieldAccess`].
+    pub const DBGWVRN_EL1_FIELD: Field<Self, bool, 3, 1> = Field::new("HDFGWTR_EL2", "DBGWVRn_EL1");
+    /// Descriptor of the `MDSCR_EL1` field, for use with [`FieldAccess`].
+    pub const MDSCR_EL1_FIELD: Field<Self, bool, 4, 1> = Field::new("HDFGWTR_EL2", "MDSCR_EL1");
+    /// Descriptor of the `DBGCLAIM` field, for use with [`FieldAccess`].
+    pub const DBGCLAIM_FIELD: Field<Self, bool, 5, 1> = Field::new("HDFGWTR_EL2", "DBGCLAIM");
+    /// Descriptor of the `DBGPRCR_EL1` field, for use with [`FieldAccess`].
+    pub const DBGPRCR_EL1_FIELD: Field<Self, bool, 7, 1> = Field::new("HDFGWTR_EL2", "DBGPRCR_EL1");
+    /// Descriptor of the `OSLAR_EL1` field, for use with [`FieldAccess`].
+    pub const OSLAR_EL1_FIELD: Field<Self, bool, 8, 1> = Field::new("HDFGWTR_EL2", "OSLAR_EL1");
+    /// Descriptor of the `OSECCR_EL1` field, for use with [`FieldAccess`].
+    pub const OSECCR_EL1_FIELD: Field<Self, bool, 10, 1> = Field::new("HDFGWTR_EL2", "OSECCR_EL1");
+    /// Descriptor of the `OSDLR_EL1` field, for use with [`FieldAccess`].
+    pub const OSDLR_EL1_FIELD: Field<Self, bool, 11, 1> = Field::new("HDFGWTR_EL2", "OSDLR_EL1");
+    /// Descriptor of the `PMEVCNTRn_EL0` field, for use with [`FieldAccess`].
+    pub const PMEVCNTRN_EL0_FIELD: Field<Self, bool, 12, 1> =
+        Field::new("HDFGWTR_EL2", "PMEVCNTRn_EL0");
+    /// Descriptor of the `PMEVTYPERn_EL0` field, for use with [`FieldAccess`].
+    pub const PMEVTYPERN_EL0_FIELD: Field<Self, bool, 13, 1> =
+        Field::new("HDFGWTR_EL2", "PMEVTYPERn_EL0");
+    /// Descriptor of the `PMCCFILTR_EL0` field, for use with [`FieldAccess`].
+    pub const PMCCFILTR_EL0_FIELD: Field<Self, bool, 14, 1> =
+        Field::new("HDFGWTR_EL2", "PMCCFILTR_EL0");
+    /// Descriptor of the `PMCCNTR_EL0` field, for use with [`FieldAccess`].
+    pub const PMCCNTR_EL0_FIELD: Field<Self, bool, 15, 1> =
+        Field::new("HDFGWTR_EL2", "PMCCNTR_EL0");
+    /// Descriptor of the `PMCNTEN` field, for use with [`FieldAccess`].
+    pub const PMCNTEN_FIELD: Field<Self, bool, 16, 1> = Field::new("HDFGWTR_EL2", "PMCNTEN");
+    /// Descriptor of the `PMINTEN` field, for use with [`FieldAccess`].
+    pub const PMINTEN_FIELD: Field<Self, bool, 17, 1> = Field::new("HDFGWTR_EL2", "PMINTEN");
+    /// Descriptor of the `PMOVS` field, for use with [`FieldAccess`].
+    pub const PMOVS_FIELD: Field<Self, bool, 18, 1> = Field::new("HDFGWTR_EL2", "PMOVS");
+    /// Descriptor of the `PMSELR_EL0` field, for use with [`FieldAccess`].
+    pub const PMSELR_EL0_FIELD: Field<Self, bool, 19, 1> = Field::new("HDFGWTR_EL2", "PMSELR_EL0");
+    /// Descriptor of the `PMSWINC_EL0` field, for use with [`FieldAccess`].
+    pub const PMSWINC_EL0_FIELD: Field<Self, bool, 20, 1> =
+        Field::new("HDFGWTR_EL2", "PMSWINC_EL0");
+    /// Descriptor of the `PMCR_EL0` field, for use with [`FieldAccess`].
+    pub const PMCR_EL0_FIELD: Field<Self, bool, 21, 1> = Field::new("HDFGWTR_EL2", "PMCR_EL0");
+    /// Descriptor of the `PMBLIMITR_EL1` field, for use with [`FieldAccess`].
+    pub const PMBLIMITR_EL1_FIELD: Field<Self, bool, 23, 1> =
+        Field::new("HDFGWTR_EL2", "PMBLIMITR_EL1");
+    /// Descriptor of the `PMBPTR_EL1` field, for use with [`FieldAccess`].
+    pub const PMBPTR_EL1_FIELD: Field<Self, bool, 24, 1> = Field::new("HDFGWTR_EL2", "PMBPTR_EL1");
+    /// Descriptor of the `PMBSR_EL1` field, for use with [`FieldAccess`].
+    pub const PMBSR_EL1_FIELD: Field<Self, bool, 25, 1> = Field::new("HDFGWTR_EL2", "PMBSR_EL1");
+    /// Descriptor of the `PMSCR_EL1` field, for use with [`FieldAccess`].
+    pub const PMSCR_EL1_FIELD: Field<Self, bool, 26, 1> = Field::new("HDFGWTR_EL2", "PMSCR_EL1");
+    /// Descriptor of the `PMSEVFR_EL1` field, for use with [`FieldAccess`].
+    pub const PMSEVFR_EL1_FIELD: Field<Self, bool, 27, 1> =
+        Field::new("HDFGWTR_EL2", "PMSEVFR_EL1");
+    /// Descriptor of the `PMSFCR_EL1` field, for use with [`FieldAccess`].
+    pub const PMSFCR_EL1_FIELD: Field<Self, bool, 28, 1> = Field::new("HDFGWTR_EL2", "PMSFCR_EL1");
+    /// Descriptor of the `PMSICR_EL1` field, for use with [`FieldAccess`].
+    pub const PMSICR_EL1_FIELD: Field<Self, bool, 29, 1> = Field::new("HDFGWTR_EL2", "PMSICR_EL1");
+    /// Descriptor of the `PMSIRR_EL1` field, for use with [`FieldAccess`].
+    pub const PMSIRR_EL1_FIELD: Field<Self, bool, 31, 1> = Field::new("HDFGWTR_EL2", "PMSIRR_EL1");
+    /// Descriptor of the `PMSLATFR_EL1` field, for use with [`FieldAccess`].
+    pub const PMSLATFR_EL1_FIELD: Field<Self, bool, 32, 1> =
+        Field::new("HDFGWTR_EL2", "PMSLATFR_EL1");
+    /// Descriptor of the `TRC` field, for use with [`FieldAccess`].
+    pub const TRC_FIELD: Field<Self, bool, 33, 1> = Field::new("HDFGWTR_EL2", "TRC");
+    /// Descriptor of the `TRCAUXCTLR` field, for use with [`FieldAccess`].
+    pub const TRCAUXCTLR_FIELD: Field<Self, bool, 35, 1> = Field::new("HDFGWTR_EL2", "TRCAUXCTLR");
+    /// Descriptor of the `TRCCLAIM` field, for use with [`FieldAccess`].
+    pub const TRCCLAIM_FIELD: Field<Self, bool, 36, 1> = Field::new("HDFGWTR_EL2", "TRCCLAIM");
+    /// Descriptor of the `TRCCNTVRn` field, for use with [`FieldAccess`].
+    pub const TRCCNTVRN_FIELD: Field<Self, bool, 37, 1> = Field::new("HDFGWTR_EL2", "TRCCNTVRn");
+    /// Descriptor of the `TRCIMSPECn` field, for use with [`FieldAccess`].
+    pub const TRCIMSPECN_FIELD: Field<Self, bool, 41, 1> = Field::new("HDFGWTR_EL2", "TRCIMSPECn");
+    /// Descriptor of the `TRCOSLAR` field, for use with [`FieldAccess`].
+    pub const TRCOSLAR_FIELD: Field<Self, bool, 42, 1> = Field::new("HDFGWTR_EL2", "TRCOSLAR");
+    /// Descriptor of the `TRCPRGCTLR` field, for use with [`FieldAccess`].
+    pub const TRCPRGCTLR_FIELD: Field<Self, bool, 44, 1> = Field::new("HDFGWTR_EL2", "TRCPRGCTLR");
+    /// Descriptor of the `TRCSEQSTR` field, for use with [`FieldAccess`].
+    pub const TRCSEQSTR_FIELD: Field<Self, bool, 45, 1> = Field::new("HDFGWTR_EL2", "TRCSEQSTR");
+    /// Descriptor of the `TRCSSCSRn` field, for use with [`FieldAccess`].
+    pub const TRCSSCSRN_FIELD: Field<Self, bool, 46, 1> = Field::new("HDFGWTR_EL2", "TRCSSCSRn");
+    /// Descriptor of the `TRCVICTLR` field, for use with [`FieldAccess`].
+    pub const TRCVICTLR_FIELD: Field<Self, bool, 48, 1> = Field::new("HDFGWTR_EL2", "TRCVICTLR");
+    /// Descriptor of the `TRFCR_EL1` field, for use with [`FieldAccess`].
+    pub const TRFCR_EL1_FIELD: Field<Self, bool, 49, 1> = Field::new("HDFGWTR_EL2", "TRFCR_EL1");
+    /// Descriptor of the `TRBBASER_EL1` field, for use with [`FieldAccess`].
+    pub const TRBBASER_EL1_FIELD: Field<Self, bool, 50, 1> =
+        Field::new("HDFGWTR_EL2", "TRBBASER_EL1");
+    /// Descriptor of the `TRBLIMITR_EL1` field, for use with [`FieldAccess`].
+    pub const TRBLIMITR_EL1_FIELD: Field<Self, bool, 52, 1> =
+        Field::new("HDFGWTR_EL2", "TRBLIMITR_EL1");
+    /// Descriptor of the `TRBMAR_EL1` field, for use with [`FieldAccess`].
+    pub const TRBMAR_EL1_FIELD: Field<Self, bool, 53, 1> = Field::new("HDFGWTR_EL2", "TRBMAR_EL1");
+    /// Descriptor of the `TRBPTR_EL1` field, for use with [`FieldAccess`].
+    pub const TRBPTR_EL1_FIELD: Field<Self, bool, 54, 1> = Field::new("HDFGWTR_EL2", "TRBPTR_EL1");
+    /// Descriptor of the `TRBSR_EL1` field, for use with [`FieldAccess`].
+    pub const TRBSR_EL1_FIELD: Field<Self, bool, 55, 1> = Field::new("HDFGWTR_EL2", "TRBSR_EL1");
+    /// Descriptor of the `TRBTRG_EL1` field, for use with [`FieldAccess`].
+    pub const TRBTRG_EL1_FIELD: Field<Self, bool, 56, 1> = Field::new("HDFGWTR_EL2", "TRBTRG_EL1");
+    /// Descriptor of the `PMUSERENR_EL0` field, for use with [`FieldAccess`].
+    pub const PMUSERENR_EL0_FIELD: Field<Self, bool, 57, 1> =
+        Field::new("HDFGWTR_EL2", "PMUSERENR_EL0");
+    /// Descriptor of the `nBRBCTL` field, for use with [`FieldAccess`].
+    pub const NBRBCTL_FIELD: Field<Self, bool, 60, 1> = Field::new("HDFGWTR_EL2", "nBRBCTL");
+    /// Descriptor of the `nBRBDATA` field, for use with [`FieldAccess`].
+    pub const NBRBDATA_FIELD: Field<Self, bool, 61, 1> = Field::new("HDFGWTR_EL2", "nBRBDATA");
+    /// Descriptor of the `nPMSNEVFR_EL1` field, for use with [`FieldAccess`].
+    pub const NPMSNEVFR_EL1_FIELD: Field<Self, bool, 62, 1> =
+        Field::new("HDFGWTR_EL2", "nPMSNEVFR_EL1");
+}
+
+#[cfg(feature = "el2")]
+impl Display for HdfgwtrEl2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("HdfgwtrEl2");
+        let bits = self.bits();
+        s.field("DBGBCRn_EL1", &Self::DBGBCRN_EL1_FIELD.extract(bits));
+        s.field("DBGBVRn_EL1", &Self::DBGBVRN_EL1_FIELD.extract(bits));
+        s.field("DBGWCRn_EL1", &Self::DBGWCRN_EL1_FIELD.extract(bits));
+        s.field("DBGWVRn_EL1", &Self::DBGWVRN_EL1_FIELD.extract(bits));
+        s.field("MDSCR_EL1", &Self::MDSCR_EL1_FIELD.extract(bits));
+        s.field("DBGCLAIM", &Self::DBGCLAIM_FIELD.extract(bits));
+        s.field("DBGPRCR_EL1", &Self::DBGPRCR_EL1_FIELD.extract(bits));
+        s.field("OSLAR_EL1", &Self::OSLAR_EL1_FIELD.extract(bits));
+        s.field("OSECCR_EL1", &Self::OSECCR_EL1_FIELD.extract(bits));
+        s.field("OSDLR_EL1", &Self::OSDLR_EL1_FIELD.extract(bits));
+        s.field("PMEVCNTRn_EL0", &Self::PMEVCNTRN_EL0_FIELD.extract(bits));
+        s.field("PMEVTYPERn_EL0", &Self::PMEVTYPERN_EL0_FIELD.extract(bits));
+        s.field("PMCCFILTR_EL0", &Self::PMCCFILTR_EL0_FIELD.extract(bits));
+        s.field("PMCCNTR_EL0", &Self::PMCCNTR_EL0_FIELD.extract(bits));
+        s.field("PMCNTEN", &Self::PMCNTEN_FIELD.extract(bits));
+        s.field("PMINTEN", &Self::PMINTEN_FIELD.extract(bits));
+        s.field("PMOVS", &Self::PMOVS_FIELD.extract(bits));
+        s.field("PMSELR_EL0", &Self::PMSELR_EL0_FIELD.extract(bits));
+        s.field("PMSWINC_EL0", &Self::PMSWINC_EL0_FIELD.extract(bits));
+        s.field("PMCR_EL0", &Self::PMCR_EL0_FIELD.extract(bits));
+        s.field("PMBLIMITR_EL1", &Self::PMBLIMITR_EL1_FIELD.extract(bits));
+        s.field("PMBPTR_EL1", &Self::PMBPTR_EL1_FIELD.extract(bits));
+        s.field("PMBSR_EL1", &Self::PMBSR_EL1_FIELD.extract(bits));
+        s.field("PMSCR_EL1", &Self::PMSCR_EL1_FIELD.extract(bits));
+        s.field("PMSEVFR_EL1", &Self::PMSEVFR_EL1_FIELD.extract(bits));
+        s.field("PMSFCR_EL1", &Self::PMSFCR_EL1_FIELD.extract(bits));
+        s.field("PMSICR_EL1", &Self::PMSICR_EL1_FIELD.extract(bits));
+        s.field("PMSIRR_EL1", &Self::PMSIRR_EL1_FIELD.extract(bits));
+        s.field("PMSLATFR_EL1", &Self::PMSLATFR_EL1_FIELD.extract(bits));
+        s.field("TRC", &Self::TRC_FIELD.extract(bits));
+        s.field("TRCAUXCTLR", &Self::TRCAUXCTLR_FIELD.extract(bits));
+        s.field("TRCCLAIM", &Self::TRCCLAIM_FIELD.extract(bits));
+        s.field("TRCCNTVRn", &Self::TRCCNTVRN_FIELD.extract(bits));
+        s.field("TRCIMSPECn", &Self::TRCIMSPECN_FIELD.extract(bits));
+        s.field("TRCOSLAR", &Self::TRCOSLAR_FIELD.extract(bits));
+        s.field("TRCPRGCTLR", &Self::TRCPRGCTLR_FIELD.extract(bits));
+        s.field("TRCSEQSTR", &Self::TRCSEQSTR_FIELD.extract(bits));
+        s.field("TRCSSCSRn", &Self::TRCSSCSRN_FIELD.extract(bits));
+        s.field("TRCVICTLR", &Self::TRCVICTLR_FIELD.extract(bits));
+        s.field("TRFCR_EL1", &Self::TRFCR_EL1_FIELD.extract(bits));
+        s.field("TRBBASER_EL1", &Self::TRBBASER_EL1_FIELD.extract(bits));
+        s.field("TRBLIMITR_EL1", &Self::TRBLIMITR_EL1_FIELD.extract(bits));
+        s.field("TRBMAR_EL1", &Self::TRBMAR_EL1_FIELD.extract(bits));
+        s.field("TRBPTR_EL1", &Self::TRBPTR_EL1_FIELD.extract(bits));
+        s.field("TRBSR_EL1", &Self::TRBSR_EL1_FIELD.extract(bits));
+        s.field("TRBTRG_EL1", &Self::TRBTRG_EL1_FIELD.extract(bits));
+        s.field("PMUSERENR_EL0", &Self::PMUSERENR_EL0_FIELD.extract(bits));
+        s.field("nBRBCTL", &Self::NBRBCTL_FIELD.extract(bits));
+        s.field("nBRBDATA", &Self::NBRBDATA_FIELD.extract(bits));
+        s.field("nPMSNEVFR_EL1", &Self::NPMSNEVFR_EL1_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for HdfgwtrEl2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "HdfgwtrEl2 {{ DBGBCRn_EL1: {=u64}, DBGBVRn_EL1: {=u64}, DBGWCRn_EL1: {=u64}, DBGWVRn_EL1: {=u64}, MDSCR_EL1: {=u64}, DBGCLAIM: {=u64}, DBGPRCR_EL1: {=u64}, OSLAR_EL1: {=u64}, OSECCR_EL1: {=u64}, OSDLR_EL1: {=u64}, PMEVCNTRn_EL0: {=u64}, PMEVTYPERn_EL0: {=u64}, PMCCFILTR_EL0: {=u64}, PMCCNTR_EL0: {=u64}, PMCNTEN: {=u64}, PMINTEN: {=u64}, PMOVS: {=u64}, PMSELR_EL0: {=u64}, PMSWINC_EL0: {=u64}, PMCR_EL0: {=u64}, PMBLIMITR_EL1: {=u64}, PMBPTR_EL1: {=u64}, PMBSR_EL1: {=u64}, PMSCR_EL1: {=u64}, PMSEVFR_EL1: {=u64}, PMSFCR_EL1: {=u64}, PMSICR_EL1: {=u64}, PMSIRR_EL1: {=u64}, PMSLATFR_EL1: {=u64}, TRC: {=u64}, TRCAUXCTLR: {=u64}, TRCCLAIM: {=u64}, TRCCNTVRn: {=u64}, TRCIMSPECn: {=u64}, TRCOSLAR: {=u64}, TRCPRGCTLR: {=u64}, TRCSEQSTR: {=u64}, TRCSSCSRn: {=u64}, TRCVICTLR: {=u64}, TRFCR_EL1: {=u64}, TRBBASER_EL1: {=u64}, TRBLIMITR_EL1: {=u64}, TRBMAR_EL1: {=u64}, TRBPTR_EL1: {=u64}, TRBSR_EL1: {=u64}, TRBTRG_EL1: {=u64}, PMUSERENR_EL0: {=u64}, nBRBCTL: {=u64}, nBRBDATA: {=u64}, nPMSNEVFR_EL1: {=u64} }}",
+            Self::DBGBCRN_EL1_FIELD.extract(self.bits()),
+            Self::DBGBVRN_EL1_FIELD.extract(self.bits()),
+            Self::DBGWCRN_EL1_FIELD.extract(self.bits()),
+            Self::DBGWVRN_EL1_FIELD.extract(self.bits()),
+            Self::MDSCR_EL1_FIELD.extract(self.bits()),
+            Self::DBGCLAIM_FIELD.extract(self.bits()),
+            Self::DBGPRCR_EL1_FIELD.extract(self.bits()),
+            Self::OSLAR_EL1_FIELD.extract(self.bits()),
+            Self::OSECCR_EL1_FIELD.extract(self.bits()),
+            Self::OSDLR_EL1_FIELD.extract(self.bits()),
+            Self::PMEVCNTRN_EL0_FIELD.extract(self.bits()),
+            Self::PMEVTYPERN_EL0_FIELD.extract(self.bits()),
+            Self::PMCCFILTR_EL0_FIELD.extract(self.bits()),
+            Self::PMCCNTR_EL0_FIELD.extract(self.bits()),
+            Self::PMCNTEN_FIELD.extract(self.bits()),
+            Self::PMINTEN_FIELD.extract(self.bits()),
+            Self::PMOVS_FIELD.extract(self.bits()),
+            Self::PMSELR_EL0_FIELD.extract(self.bits()),
+            Self::PMSWINC_EL0_FIELD.extract(self.bits()),
+            Self::PMCR_EL0_FIELD.extract(self.bits()),
+            Self::PMBLIMITR_EL1_FIELD.extract(self.bits()),
+            Self::PMBPTR_EL1_FIELD.extract(self.bits()),
+            Self::PMBSR_EL1_FIELD.extract(self.bits()),
+            Self::PMSCR_EL1_FIELD.extract(self.bits()),
+            Self::PMSEVFR_EL1_FIELD.extract(self.bits()),
+            Self::PMSFCR_EL1_FIELD.extract(self.bits()),
+            Self::PMSICR_EL1_FIELD.extract(self.bits()),
+            Self::PMSIRR_EL1_FIELD.extract(self.bits()),
+            Self::PMSLATFR_EL1_FIELD.extract(self.bits()),
+            Self::TRC_FIELD.extract(self.bits()),
+            Self::TRCAUXCTLR_FIELD.extract(self.bits()),
+            Self::TRCCLAIM_FIELD.extract(self.bits()),
+            Self::TRCCNTVRN_FIELD.extract(self.bits()),
+            Self::TRCIMSPECN_FIELD.extract(self.bits()),
+            Self::TRCOSLAR_FIELD.extract(self.bits()),
+            Self::TRCPRGCTLR_FIELD.extract(self.bits()),
+            Self::TRCSEQSTR_FIELD.extract(self.bits()),
+            Self::TRCSSCSRN_FIELD.extract(self.bits()),
+            Self::TRCVICTLR_FIELD.extract(self.bits()),
+            Self::TRFCR_EL1_FIELD.extract(self.bits()),
+            Self::TRBBASER_EL1_FIELD.extract(self.bits()),
+            Self::TRBLIMITR_EL1_FIELD.extract(self.bits()),
+            Self::TRBMAR_EL1_FIELD.extract(self.bits()),
+            Self::TRBPTR_EL1_FIELD.extract(self.bits()),
+            Self::TRBSR_EL1_FIELD.extract(self.bits()),
+            Self::TRBTRG_EL1_FIELD.extract(self.bits()),
+            Self::PMUSERENR_EL0_FIELD.extract(self.bits()),
+            Self::NBRBCTL_FIELD.extract(self.bits()),
+            Self::NBRBDATA_FIELD.extract(self.bits()),
+            Self::NPMSNEVFR_EL1_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for HdfgwtrEl2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for HdfgwtrEl2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl HdfgwtrEl2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("DBGBCRn_EL1"),
+                old: Self::DBGBCRN_EL1_FIELD.extract(self.bits()),
+                new: Self::DBGBCRN_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DBGBVRn_EL1"),
+                old: Self::DBGBVRN_EL1_FIELD.extract(self.bits()),
+                new: Self::DBGBVRN_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DBGWCRn_EL1"),
+                old: Self::DBGWCRN_EL1_FIELD.extract(self.bits()),
+                new: Self::DBGWCRN_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DBGWVRn_EL1"),
+                old: Self::DBGWVRN_EL1_FIELD.extract(self.bits()),
+                new: Self::DBGWVRN_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("MDSCR_EL1"),
+                old: Self::MDSCR_EL1_FIELD.extract(self.bits()),
+                new: Self::MDSCR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DBGCLAIM"),
+                old: Self::DBGCLAIM_FIELD.extract(self.bits()),
+                new: Self::DBGCLAIM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DBGPRCR_EL1"),
+                old: Self::DBGPRCR_EL1_FIELD.extract(self.bits()),
+                new: Self::DBGPRCR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("OSLAR_EL1"),
+                old: Self::OSLAR_EL1_FIELD.extract(self.bits()),
+                new: Self::OSLAR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("OSECCR_EL1"),
+                old: Self::OSECCR_EL1_FIELD.extract(self.bits()),
+                new: Self::OSECCR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("OSDLR_EL1"),
+                old: Self::OSDLR_EL1_FIELD.extract(self.bits()),
+                new: Self::OSDLR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMEVCNTRn_EL0"),
+                old: Self::PMEVCNTRN_EL0_FIELD.extract(self.bits()),
+                new: Self::PMEVCNTRN_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMEVTYPERn_EL0"),
+                old: Self::PMEVTYPERN_EL0_FIELD.extract(self.bits()),
+                new: Self::PMEVTYPERN_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMCCFILTR_EL0"),
+                old: Self::PMCCFILTR_EL0_FIELD.extract(self.bits()),
+                new: Self::PMCCFILTR_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMCCNTR_EL0"),
+                old: Self::PMCCNTR_EL0_FIELD.extract(self.bits()),
+                new: Self::PMCCNTR_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMCNTEN"),
+                old: Self::PMCNTEN_FIELD.extract(self.bits()),
+                new: Self::PMCNTEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMINTEN"),
+                old: Self::PMINTEN_FIELD.extract(self.bits()),
+                new: Self::PMINTEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMOVS"),
+                old: Self::PMOVS_FIELD.extract(self.bits()),
+                new: Self::PMOVS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMSELR_EL0"),
+                old: Self::PMSELR_EL0_FIELD.extract(self.bits()),
+                new: Self::PMSELR_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMSWINC_EL0"),
+                old: Self::PMSWINC_EL0_FIELD.extract(self.bits()),
+                new: Self::PMSWINC_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMCR_EL0"),
+                old: Self::PMCR_EL0_FIELD.extract(self.bits()),
+                new: Self::PMCR_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMBLIMITR_EL1"),
+                old: Self::PMBLIMITR_EL1_FIELD.extract(self.bits()),
+                new: Self::PMBLIMITR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMBPTR_EL1"),
+                old: Self::PMBPTR_EL1_FIELD.extract(self.bits()),
+                new: Self::PMBPTR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMBSR_EL1"),
+                old: Self::PMBSR_EL1_FIELD.extract(self.bits()),
+                new: Self::PMBSR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMSCR_EL1"),
+                old: Self::PMSCR_EL1_FIELD.extract(self.bits()),
+                new: Self::PMSCR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMSEVFR_EL1"),
+                old: Self::PMSEVFR_EL1_FIELD.extract(self.bits()),
+                new: Self::PMSEVFR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMSFCR_EL1"),
+                old: Self::PMSFCR_EL1_FIELD.extract(self.bits()),
+                new: Self::PMSFCR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMSICR_EL1"),
+                old: Self::PMSICR_EL1_FIELD.extract(self.bits()),
+                new: Self::PMSICR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMSIRR_EL1"),
+                old: Self::PMSIRR_EL1_FIELD.extract(self.bits()),
+                new: Self::PMSIRR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMSLATFR_EL1"),
+                old: Self::PMSLATFR_EL1_FIELD.extract(self.bits()),
+                new: Self::PMSLATFR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TRC"),
+                old: Self::TRC_FIELD.extract(self.bits()),
+                new: Self::TRC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TRCAUXCTLR"),
+                old: Self::TRCAUXCTLR_FIELD.extract(self.bits()),
+                new: Self::TRCAUXCTLR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TRCCLAIM"),
+                old: Self::TRCCLAIM_FIELD.extract(self.bits()),
+                new: Self::TRCCLAIM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TRCCNTVRn"),
+                old: Self::TRCCNTVRN_FIELD.extract(self.bits()),
+                new: Self::TRCCNTVRN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TRCIMSPECn"),
+                old: Self::TRCIMSPECN_FIELD.extract(self.bits()),
+                new: Self::TRCIMSPECN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TRCOSLAR"),
+                old: Self::TRCOSLAR_FIELD.extract(self.bits()),
+                new: Self::TRCOSLAR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TRCPRGCTLR"),
+                old: Self::TRCPRGCTLR_FIELD.extract(self.bits()),
+                new: Self::TRCPRGCTLR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TRCSEQSTR"),
+                old: Self::TRCSEQSTR_FIELD.extract(self.bits()),
+                new: Self::TRCSEQSTR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TRCSSCSRn"),
+                old: Self::TRCSSCSRN_FIELD.extract(self.bits()),
+                new: Self::TRCSSCSRN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TRCVICTLR"),
+                old: Self::TRCVICTLR_FIELD.extract(self.bits()),
+                new: Self::TRCVICTLR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TRFCR_EL1"),
+                old: Self::TRFCR_EL1_FIELD.extract(self.bits()),
+                new: Self::TRFCR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TRBBASER_EL1"),
+                old: Self::TRBBASER_EL1_FIELD.extract(self.bits()),
+                new: Self::TRBBASER_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TRBLIMITR_EL1"),
+                old: Self::TRBLIMITR_EL1_FIELD.extract(self.bits()),
+                new: Self::TRBLIMITR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TRBMAR_EL1"),
+                old: Self::TRBMAR_EL1_FIELD.extract(self.bits()),
+                new: Self::TRBMAR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TRBPTR_EL1"),
+                old: Self::TRBPTR_EL1_FIELD.extract(self.bits()),
+                new: Self::TRBPTR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TRBSR_EL1"),
+                old: Self::TRBSR_EL1_FIELD.extract(self.bits()),
+                new: Self::TRBSR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TRBTRG_EL1"),
+                old: Self::TRBTRG_EL1_FIELD.extract(self.bits()),
+                new: Self::TRBTRG_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMUSERENR_EL0"),
+                old: Self::PMUSERENR_EL0_FIELD.extract(self.bits()),
+                new: Self::PMUSERENR_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nBRBCTL"),
+                old: Self::NBRBCTL_FIELD.extract(self.bits()),
+                new: Self::NBRBCTL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nBRBDATA"),
+                old: Self::NBRBDATA_FIELD.extract(self.bits()),
+                new: Self::NBRBDATA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nPMSNEVFR_EL1"),
+                old: Self::NPMSNEVFR_EL1_FIELD.extract(self.bits()),
+                new: Self::NPMSNEVFR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1000_1100_0000_1000_1000_1001_1100_0100_0100_0000_0100_0000_0000_0010_0100_0000,
+                new: other.bits() & 0b1000_1100_0000_1000_1000_1001_1100_0100_0100_0000_0100_0000_0000_0010_0100_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl HdfgwtrEl2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl HdfgwtrEl2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `HFGITR2_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct Hfgitr2El2: u64 {
+        /// `TSBCSYNC` bit.
+        const TSBCSYNC = 1 << 0;
+        /// `nDCCIVAPS` bit.
+        const NDCCIVAPS = 1 << 1;
+        /// `PLBIPERME1OS` bit.
+        const PLBIPERME1OS = 1 << 2;
+        /// `PLBIASIDE1OS` bit.
+        const PLBIASIDE1OS = 1 << 3;
+        /// `PLBIVMALLE1OS` bit.
+        const PLBIVMALLE1OS = 1 << 4;
+        /// `PLBIPERME1IS` bit.
+        const PLBIPERME1IS = 1 << 5;
+        /// `PLBIASIDE1IS` bit.
+        const PLBIASIDE1IS = 1 << 6;
+        /// `PLBIVMALLE1IS` bit.
+        const PLBIVMALLE1IS = 1 << 7;
+        /// `PLBIPERME1` bit.
+        const PLBIPERME1 = 1 << 8;
+        /// `PLBIASIDE1` bit.
+        const PLBIASIDE1 = 1 << 9;
+        /// `PLBIVMALLE1` bit.
+        const PLBIVMALLE1 = 1 << 10;
+        /// `PLBIPERMAE1OS` bit.
+        const PLBIPERMAE1OS = 1 << 11;
+        /// `PLBIPERMAE1IS` bit.
+        const PLBIPERMAE1IS = 1 << 12;
+        /// `PLBIPERMAE1` bit.
+        const PLBIPERMAE1 = 1 << 13;
+        /// `DCGBVA` bit.
+        const DCGBVA = 1 << 14;
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Hfgitr2El2 {
+    /// Descriptor of the `TSBCSYNC` field, for use with [`FieldAccess`].
+    pub const TSBCSYNC_FIELD: Field<Self, bool, 0, 1> = Field::new("HFGITR2_EL2", "TSBCSYNC");
+    /// Descriptor of the `nDCCIVAPS` field, for use with [`FieldAccess`].
+    pub const NDCCIVAPS_FIELD: Field<Self, bool, 1, 1> = Field::new("HFGITR2_EL2", "nDCCIVAPS");
+    /// Descriptor of the `PLBIPERME1OS` field, for use with [`FieldAccess`].
+    pub const PLBIPERME1OS_FIELD: Field<Self, bool, 2, 1> =
+        Field::new("HFGITR2_EL2", "PLBIPERME1OS");
+    /// Descriptor of the `PLBIASIDE1OS` field, for use with [`FieldAccess`].
+    pub const PLBIASIDE1OS_FIELD: Field<Self, bool, 3, 1> =
+        Field::new("HFGITR2_EL2", "PLBIASIDE1OS");
+    /// Descriptor of the `PLBIVMALLE1OS` field, for use with [`FieldAccess`].
+    pub const PLBIVMALLE1OS_FIELD: Field<Self, bool, 4, 1> =
+        Field::new("HFGITR2_EL2", "PLBIVMALLE1OS");
+    /// Descriptor of the `PLBIPERME1IS` field, for use with [`FieldAccess`].
+    pub const PLBIPERME1IS_FIELD: Field<Self, bool, 5, 1> =
+        Field::new("HFGITR2_EL2", "PLBIPERME1IS");
+    /// Descriptor of the `PLBIASIDE1IS` field, for use with [`FieldAccess`].
+    pub const PLBIASIDE1IS_FIELD: Field<Self, bool, 6, 1> =
+        Field::new("HFGITR2_EL2", "PLBIASIDE1IS");
+    /// Descriptor of the `PLBIVMALLE1IS` field, for use with [`FieldAccess`].
+    pub const PLBIVMALLE1IS_FIELD: Field<Self, bool, 7, 1> =
+        Field::new("HFGITR2_EL2", "PLBIVMALLE1IS");
+    /// Descriptor of the `PLBIPERME1` field, for use with [`FieldAccess`].
+    pub const PLBIPERME1_FIELD: Field<Self, bool, 8, 1> = Field::new("HFGITR2_EL2", "PLBIPERME1");
+    /// Descriptor of the `PLBIASIDE1` field, for use with [`FieldAccess`].
+    pub const PLBIASIDE1_FIELD: Field<Self, bool, 9, 1> = Field::new("HFGITR2_EL2", "PLBIASIDE1");
+    /// Descriptor of the `PLBIVMALLE1` field, for use with [`FieldAccess`].
+    pub const PLBIVMALLE1_FIELD: Field<Self, bool, 10, 1> =
+        Field::new("HFGITR2_EL2", "PLBIVMALLE1");
+    /// Descriptor of the `PLBIPERMAE1OS` field, for use with [`FieldAccess`].
+    pub const PLBIPERMAE1OS_FIELD: Field<Self, bool, 11, 1> =
+        Field::new("HFGITR2_EL2", "PLBIPERMAE1OS");
+    /// Descriptor of the `PLBIPERMAE1IS` field, for use with [`FieldAccess`].
+    pub const PLBIPERMAE1IS_FIELD: Field<Self, bool, 12, 1> =
+        Field::new("HFGITR2_EL2", "PLBIPERMAE1IS");
+    /// Descriptor of the `PLBIPERMAE1` field, for use with [`FieldAccess`].
+    pub const PLBIPERMAE1_FIELD: Field<Self, bool, 13, 1> =
+        Field::new("HFGITR2_EL2", "PLBIPERMAE1");
+    /// Descriptor of the `DCGBVA` field, for use with [`FieldAccess`].
+    pub const DCGBVA_FIELD: Field<Self, bool, 14, 1> = Field::new("HFGITR2_EL2", "DCGBVA");
+}
+
+#[cfg(feature = "el2")]
+impl Display for Hfgitr2El2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("Hfgitr2El2");
+        let bits = self.bits();
+        s.field("TSBCSYNC", &Self::TSBCSYNC_FIELD.extract(bits));
+        s.field("nDCCIVAPS", &Self::NDCCIVAPS_FIELD.extract(bits));
+        s.field("PLBIPERME1OS", &Self::PLBIPERME1OS_FIELD.extract(bits));
+        s.field("PLBIASIDE1OS", &Self::PLBIASIDE1OS_FIELD.extract(bits));
+        s.field("PLBIVMALLE1OS", &Self::PLBIVMALLE1OS_FIELD.extract(bits));
+        s.field("PLBIPERME1IS", &Self::PLBIPERME1IS_FIELD.extract(bits));
+        s.field("PLBIASIDE1IS", &Self::PLBIASIDE1IS_FIELD.extract(bits));
+        s.field("PLBIVMALLE1IS", &Self::PLBIVMALLE1IS_FIELD.extract(bits));
+        s.field("PLBIPERME1", &Self::PLBIPERME1_FIELD.extract(bits));
+        s.field("PLBIASIDE1", &Self::PLBIASIDE1_FIELD.extract(bits));
+        s.field("PLBIVMALLE1", &Self::PLBIVMALLE1_FIELD.extract(bits));
+        s.field("PLBIPERMAE1OS", &Self::PLBIPERMAE1OS_FIELD.extract(bits));
+        s.field("PLBIPERMAE1IS", &Self::PLBIPERMAE1IS_FIELD.extract(bits));
+        s.field("PLBIPERMAE1", &Self::PLBIPERMAE1_FIELD.extract(bits));
+        s.field("DCGBVA", &Self::DCGBVA_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for Hfgitr2El2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "Hfgitr2El2 {{ TSBCSYNC: {=u64}, nDCCIVAPS: {=u64}, PLBIPERME1OS: {=u64}, PLBIASIDE1OS: {=u64}, PLBIVMALLE1OS: {=u64}, PLBIPERME1IS: {=u64}, PLBIASIDE1IS: {=u64}, PLBIVMALLE1IS: {=u64}, PLBIPERME1: {=u64}, PLBIASIDE1: {=u64}, PLBIVMALLE1: {=u64}, PLBIPERMAE1OS: {=u64}, PLBIPERMAE1IS: {=u64}, PLBIPERMAE1: {=u64}, DCGBVA: {=u64} }}",
+            Self::TSBCSYNC_FIELD.extract(self.bits()),
+            Self::NDCCIVAPS_FIELD.extract(self.bits()),
+            Self::PLBIPERME1OS_FIELD.extract(self.bits()),
+            Self::PLBIASIDE1OS_FIELD.extract(self.bits()),
+            Self::PLBIVMALLE1OS_FIELD.extract(self.bits()),
+            Self::PLBIPERME1IS_FIELD.extract(self.bits()),
+            Self::PLBIASIDE1IS_FIELD.extract(self.bits()),
+            Self::PLBIVMALLE1IS_FIELD.extract(self.bits()),
+            Self::PLBIPERME1_FIELD.extract(self.bits()),
+            Self::PLBIASIDE1_FIELD.extract(self.bits()),
+            Self::PLBIVMALLE1_FIELD.extract(self.bits()),
+            Self::PLBIPERMAE1OS_FIELD.extract(self.bits()),
+            Self::PLBIPERMAE1IS_FIELD.extract(self.bits()),
+            Self::PLBIPERMAE1_FIELD.extract(self.bits()),
+            Self::DCGBVA_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for Hfgitr2El2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for Hfgitr2El2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Hfgitr2El2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("TSBCSYNC"),
+                old: Self::TSBCSYNC_FIELD.extract(self.bits()),
+                new: Self::TSBCSYNC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nDCCIVAPS"),
+                old: Self::NDCCIVAPS_FIELD.extract(self.bits()),
+                new: Self::NDCCIVAPS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PLBIPERME1OS"),
+                old: Self::PLBIPERME1OS_FIELD.extract(self.bits()),
+                new: Self::PLBIPERME1OS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PLBIASIDE1OS"),
+                old: Self::PLBIASIDE1OS_FIELD.extract(self.bits()),
+                new: Self::PLBIASIDE1OS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PLBIVMALLE1OS"),
+                old: Self::PLBIVMALLE1OS_FIELD.extract(self.bits()),
+                new: Self::PLBIVMALLE1OS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PLBIPERME1IS"),
+                old: Self::PLBIPERME1IS_FIELD.extract(self.bits()),
+                new: Self::PLBIPERME1IS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PLBIASIDE1IS"),
+                old: Self::PLBIASIDE1IS_FIELD.extract(self.bits()),
+                new: Self::PLBIASIDE1IS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PLBIVMALLE1IS"),
+                old: Self::PLBIVMALLE1IS_FIELD.extract(self.bits()),
+                new: Self::PLBIVMALLE1IS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PLBIPERME1"),
+                old: Self::PLBIPERME1_FIELD.extract(self.bits()),
+                new: Self::PLBIPERME1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PLBIASIDE1"),
+                old: Self::PLBIASIDE1_FIELD.extract(self.bits()),
+                new: Self::PLBIASIDE1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PLBIVMALLE1"),
+                old: Self::PLBIVMALLE1_FIELD.extract(self.bits()),
+                new: Self::PLBIVMALLE1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PLBIPERMAE1OS"),
+                old: Self::PLBIPERMAE1OS_FIELD.extract(self.bits()),
+                new: Self::PLBIPERMAE1OS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PLBIPERMAE1IS"),
+                old: Self::PLBIPERMAE1IS_FIELD.extract(self.bits()),
+                new: Self::PLBIPERMAE1IS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PLBIPERMAE1"),
+                old: Self::PLBIPERMAE1_FIELD.extract(self.bits()),
+                new: Self::PLBIPERMAE1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DCGBVA"),
+                old: Self::DCGBVA_FIELD.extract(self.bits()),
+                new: Self::DCGBVA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Hfgitr2El2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Hfgitr2El2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `HFGITR_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct HfgitrEl2: u64 {
+        /// `ICIALLUIS` bit.
+        const ICIALLUIS = 1 << 0;
+        /// `ICIALLU` bit.
+        const ICIALLU = 1 << 1;
+        /// `ICIVAU` bit.
+        const ICIVAU = 1 << 2;
+        /// `DCIVAC` bit.
+        const DCIVAC = 1 << 3;
+        /// `DCISW` bit.
+        const DCISW = 1 << 4;
+        /// `DCCSW` bit.
+        const DCCSW = 1 << 5;
+        /// `DCCISW` bit.
+        const DCCISW = 1 << 6;
+        /// `DCCVAU` bit.
+        const DCCVAU = 1 << 7;
+        /// `DCCVAP` bit.
+        const DCCVAP = 1 << 8;
+        /// `DCCVADP` bit.
+        const DCCVADP = 1 << 9;
+        /// `DCCIVAC` bit.
+        const DCCIVAC = 1 << 10;
+        /// `DCZVA` bit.
+        const DCZVA = 1 << 11;
+        /// `ATS1E1R` bit.
+        const ATS1E1R = 1 << 12;
+        /// `ATS1E1W` bit.
+        const ATS1E1W = 1 << 13;
+        /// `ATS1E0R` bit.
+        const ATS1E0R = 1 << 14;
+        /// `ATS1E0W` bit.
+        const ATS1E0W = 1 << 15;
+        /// `ATS1E1RP` bit.
+        const ATS1E1RP = 1 << 16;
+        /// `ATS1E1WP` bit.
+        const ATS1E1WP = 1 << 17;
+        /// `TLBIVMALLE1OS` bit.
+        const TLBIVMALLE1OS = 1 << 18;
+        /// `TLBIVAE1OS` bit.
+        const TLBIVAE1OS = 1 << 19;
+        /// `TLBIASIDE1OS` bit.
+        const TLBIASIDE1OS = 1 << 20;
+        /// `TLBIVAAE1OS` bit.
+        const TLBIVAAE1OS = 1 << 21;
+        /// `TLBIVALE1OS` bit.
+        const TLBIVALE1OS = 1 << 22;
+        /// `TLBIVAALE1OS` bit.
+        const TLBIVAALE1OS = 1 << 23;
+        /// `TLBIRVAE1OS` bit.
+        const TLBIRVAE1OS = 1 << 24;
+        /// `TLBIRVAAE1OS` bit.
+        const TLBIRVAAE1OS = 1 << 25;
+        /// `TLBIRVALE1OS` bit.
+        const TLBIRVALE1OS = 1 << 26;
+        /// `TLBIRVAALE1OS` bit.
+        const TLBIRVAALE1OS = 1 << 27;
+        /// `TLBIVMALLE1IS` bit.
+        const TLBIVMALLE1IS = 1 << 28;
+        /// `TLBIVAE1IS` bit.
+        const TLBIVAE1IS = 1 << 29;
+        /// `TLBIASIDE1IS` bit.
+        const TLBIASIDE1IS = 1 << 30;
+        /// `TLBIVAAE1IS` bit.
+        const TLBIVAAE1IS = 1 << 31;
+        /// `TLBIVALE1IS` bit.
+        const TLBIVALE1IS = 1 << 32;
+        /// `TLBIVAALE1IS` bit.
+        const TLBIVAALE1IS = 1 << 33;
+        /// `TLBIRVAE1IS` bit.
+        const TLBIRVAE1IS = 1 << 34;
+        /// `TLBIRVAAE1IS` bit.
+        const TLBIRVAAE1IS = 1 << 35;
+        /// `TLBIRVALE1IS` bit.
+        const TLBIRVALE1IS = 1 << 36;
+        /// `TLBIRVAALE1IS` bit.
+        const TLBIRVAALE1IS = 1 << 37;
+        /// `TLBIRVAE1` bit.
+        const TLBIRVAE1 = 1 << 38;
+        /// `TLBIRVAAE1` bit.
+        const TLBIRVAAE1 = 1 << 39;
+        /// `TLBIRVALE1` bit.
+        const TLBIRVALE1 = 1 << 40;
+        /// `TLBIRVAALE1` bit.
+        const TLBIRVAALE1 = 1 << 41;
+        /// `TLBIVMALLE1` bit.
+        const TLBIVMALLE1 = 1 << 42;
+        /// `TLBIVAE1` bit.
+        const TLBIVAE1 = 1 << 43;
+        /// `TLBIASIDE1` bit.
+        const TLBIASIDE1 = 1 << 44;
+        /// `TLBIVAAE1` bit.
+        const TLBIVAAE1 = 1 << 45;
+        /// `TLBIVALE1` bit.
+        const TLBIVALE1 = 1 << 46;
+        /// `TLBIVAALE1` bit.
+        const TLBIVAALE1 = 1 << 47;
+        /// `CFPRCTX` bit.
+        const CFPRCTX = 1 << 48;
+        /// `DVPRCTX` bit.
+        const DVPRCTX = 1 << 49;
+        /// `CPPRCTX` bit.
+        const CPPRCTX = 1 << 50;
+        /// `ERET` bit.
+        const ERET = 1 << 51;
+        /// `SVC_EL0` bit.
+        const SVC_EL0 = 1 << 52;
+        /// `SVC_EL1` bit.
+        const SVC_EL1 = 1 << 53;
+        /// `DCCVAC` bit.
+        const DCCVAC = 1 << 54;
+        /// `nBRBINJ` bit.
+        const NBRBINJ = 1 << 55;
+        /// `nBRBIALL` bit.
+        const NBRBIALL = 1 << 56;
+        /// `nGCSPUSHM_EL1` bit.
+        const NGCSPUSHM_EL1 = 1 << 57;
+        /// `nGCSSTR_EL1` bit.
+        const NGCSSTR_EL1 = 1 << 58;
+        /// `nGCSEPP` bit.
+        const NGCSEPP = 1 << 59;
+        /// `COSPRCTX` bit.
+        const COSPRCTX = 1 << 60;
+        /// `ATS1E1A` bit.
+        const ATS1E1A = 1 << 62;
+        /// `PSBCSYNC` bit.
+        const PSBCSYNC = 1 << 63;
+    }
+}
+
+#[cfg(feature = "el2")]
+impl HfgitrEl2 {
+    /// Descriptor of the `ICIALLUIS` field, for use with [`FieldAccess`].
+    pub const ICIALLUIS_FIELD: Field<Self, bool, 0, 1> = Field::new("HFGITR_EL2", "ICIALLUIS");
+    /// Descriptor of the `ICIALLU` field, for use with [`FieldAccess`].
+    pub const ICIALLU_FIELD: Field<Self, bool, 1, 1> = Field::new("HFGITR_EL2", "ICIALLU");
+    /// Descriptor of the `ICIVAU` field, for use with [`FieldAccess`].
+    pub const ICIVAU_FIELD: Field<Self, bool, 2, 1> = Field::new("HFGITR_EL2", "ICIVAU");
+    /// Descriptor of the `DCIVAC` field, for use with [`FieldAccess`].
+    pub const DCIVAC_FIELD: Field<Self, bool, 3, 1> = Field::new("HFGITR_EL2", "DCIVAC");
+    /// Descriptor of the `DCISW` field, for use with [`FieldAccess`].
+    pub const DCISW_FIELD: Field<Self, bool, 4, 1> = Field::new("HFGITR_EL2", "DCISW");
+    /// Descriptor of the `DCCSW` field, for use with [`FieldAccess`].
+    pub const DCCSW_FIELD: Field<Self, bool, 5, 1> = Field::new("HFGITR_EL2", "DCCSW");
+    /// Descriptor of the `DCCISW` field, for use with [`FieldAccess`].
+    pub const DCCISW_FIELD: Field<Self, bool, 6, 1> = Field::new("HFGITR_EL2", "DCCISW");
+    /// Descriptor of the `DCCVAU` field, for use with [`FieldAccess`].
+    pub const DCCVAU_FIELD: Field<Self, bool, 7, 1> = Field::new("HFGITR_EL2", "DCCVAU");
+    /// Descriptor of the `DCCVAP` field, for use with [`FieldAccess`].
+    pub const DCCVAP_FIELD: Field<Self, bool, 8, 1> = Field::new("HFGITR_EL2", "DCCVAP");
+    /// Descriptor of the `DCCVADP` field, for use with [`FieldAccess`].
+    pub const DCCVADP_FIELD: Field<Self, bool, 9, 1> = Field::new("HFGITR_EL2", "DCCVADP");
+    /// Descriptor of the `DCCIVAC` field, for use with [`FieldAccess`].
+    pub const DCCIVAC_FIELD: Field<Self, bool, 10, 1> = Field::new("HFGITR_EL2", "DCCIVAC");
+    /// Descriptor of the `DCZVA` field, for use with [`FieldAccess`].
+    pub const DCZVA_FIELD: Field<Self, bool, 11, 1> = Field::new("HFGITR_EL2", "DCZVA");
+    /// Descriptor of the `ATS1E1R` field, for use with [`FieldAccess`].
+    pub const ATS1E1R_FIELD: Field<Self, bool, 12, 1> = Field::new("HFGITR_EL2", "ATS1E1R");
+    /// Descriptor of the `ATS1E1W` field, for use with [`FieldAccess`].
+    pub const ATS1E1W_FIELD: Field<Self, bool, 13, 1> = Field::new("HFGITR_EL2", "ATS1E1W");
+    /// Descriptor of the `ATS1E0R` field, for use with [`FieldAccess`].
+    pub const ATS1E0R_FIELD: Field<Self, bool, 14, 1> = Field::new("HFGITR_EL2", "ATS1E0R");
+    /// Descriptor of the `ATS1E0W` field, for use with [`FieldAccess`].
+    pub const ATS1E0W_FIELD: Field<Self, bool, 15, 1> = Field::new("HFGITR_EL2", "ATS1E0W");
+    /// Descriptor of the `ATS1E1RP` field, for use with [`FieldAccess`].
+    pub const ATS1E1RP_FIELD: Field<Self, bool, 16, 1> = Field::new("HFGITR_EL2", "ATS1E1RP");
+    /// Descriptor of the `ATS1E1WP` field, for use with [`FieldAccess`].
+    pub const ATS1E1WP_FIELD: Field<Self, bool, 17, 1> = Field::new("HFGITR_EL2", "ATS1E1WP");
+    /// Descriptor of the `TLBIVMALLE1OS` field, for use with [`FieldAccess`].
+    pub const TLBIVMALLE1OS_FIELD: Field<Self, bool, 18, 1> =
+        Field::new("HFGITR_EL2", "TLBIVMALLE1OS");
+    /// Descriptor of the `TLBIVAE1OS` field, for use with [`FieldAccess`].
+    pub const TLBIVAE1OS_FIELD: Field<Self, bool, 19, 1> = Field::new("HFGITR_EL2", "TLBIVAE1OS");
+    /// Descriptor of the `TLBIASIDE1OS` field, for use with [`FieldAccess`].
+    pub const TLBIASIDE1OS_FIELD: Field<Self, bool, 20, 1> =
+        Field::new("HFGITR_EL2", "TLBIASIDE1OS");
+    /// Descriptor of the `TLBIVAAE1OS` field, for use with [`FieldAccess`].
+    pub const TLBIVAAE1OS_FIELD: Field<Self, bool, 21, 1> = Field::new("HFGITR_EL2", "TLBIVAAE1OS");
+    /// Descriptor of the `TLBIVALE1OS` field, for use with [`FieldAccess`].
+    pub const TLBIVALE1OS_FIELD: Field<Self, bool, 22, 1> = Field::new("HFGITR_EL2", "TLBIVALE1OS");
+    /// Descriptor of the `TLBIVAALE1OS` field, for use with [`FieldAccess`].
+    pub const TLBIVAALE1OS_FIELD: Field<Self, bool, 23, 1> =
+        Field::new("HFGITR_EL2", "TLBIVAALE1OS");
+    /// Descriptor of the `TLBIRVAE1OS` field, for use with [`FieldAccess`].
+    pub const TLBIRVAE1OS_FIELD: Field<Self, bool, 24, 1> = Field::new("HFGITR_EL2", "TLBIRVAE1OS");
+    /// Descriptor of the `TLBIRVAAE1OS` field, for use with [`FieldAccess`].
+    pub const TLBIRVAAE1OS_FIELD: Field<Self, bool, 25, 1> =
+        Field::new("HFGITR_EL2", "TLBIRVAAE1OS");
+    /// Descriptor of the `TLBIRVALE1OS` field, for use with [`FieldAccess`].
+    pub const TLBIRVALE1OS_FIELD: Field<Self, bool, 26, 1> =
+        Field::new("HFGITR_EL2", "TLBIRVALE1OS");
+    /// Descriptor of the `TLBIRVAALE1OS` field, for use with [`FieldAccess`].
+    pub const TLBIRVAALE1OS_FIELD: Field<Self, bool, 27, 1> =
+        Field::new("HFGITR_EL2", "TLBIRVAALE1OS");
+    /// Descriptor of the `TLBIVMALLE1IS` field, for use with [`FieldAccess`].
+    pub const TLBIVMALLE1IS_FIELD: Field<Self, bool, 28, 1> =
+        Field::new("HFGITR_EL2", "TLBIVMALLE1IS");
+    /// Descriptor of the `TLBIVAE1IS` field, for use with [`FieldAccess`].
+    pub const TLBIVAE1IS_FIELD: Field<Self, bool, 29, 1> = Field::new("HFGITR_EL2", "TLBIVAE1IS");
+    /// Descriptor of the `TLBIASIDE1IS` field, for use with [`FieldAccess`].
+    pub const TLBIASIDE1IS_FIELD: Field<Self, bool, 30, 1> =
+        Field::new("HFGITR_EL2", "TLBIASIDE1IS");
+    /// Descriptor of the `TLBIVAAE1IS` field, for use with [`FieldAccess`].
+    pub const TLBIVAAE1IS_FIELD: Field<Self, bool, 31, 1> = Field::new("HFGITR_EL2", "TLBIVAAE1IS");
+    /// Descriptor of the `TLBIVALE1IS` field, for use with [`FieldAccess`].
+    pub const TLBIVALE1IS_FIELD: Field<Self, bool, 32, 1> = Field::new("HFGITR_EL2", "TLBIVALE1IS");
+    /// Descriptor of the `TLBIVAALE1IS` field, for use with [`FieldAccess`].
+    pub const TLBIVAALE1IS_FIELD: Field<Self, bool, 33, 1> =
+        Field::new("HFGITR_EL2", "TLBIVAALE1IS");
+    /// Descriptor of the `TLBIRVAE1IS` field, for use with [`FieldAccess`].
+    pub const TLBIRVAE1IS_FIELD: Field<Self, bool, 34, 1> = Field::new("HFGITR_EL2", "TLBIRVAE1IS");
+    /// Descriptor of the `TLBIRVAAE1IS` field, for use with [`FieldAccess`].
+    pub const TLBIRVAAE1IS_FIELD: Field<Self, bool, 35, 1> =
+        Field::new("HFGITR_EL2", "TLBIRVAAE1IS");
+    /// Descriptor of the `TLBIRVALE1IS` field, for use with [`FieldAccess`].
+    pub const TLBIRVALE1IS_FIELD: Field<Self, bool, 36, 1> =
+        Field::new("HFGITR_EL2", "TLBIRVALE1IS");
+    /// Descriptor of the `TLBIRVAALE1IS` field, for use with [`FieldAccess`].
+    pub const TLBIRVAALE1IS_FIELD: Field<Self, bool, 37, 1> =
+        Field::new("HFGITR_EL2", "TLBIRVAALE1IS");
+    /// Descriptor of the `TLBIRVAE1` field, for use with [`FieldAccess`].
+    pub const TLBIRVAE1_FIELD: Field<Self, bool, 38, 1> = Field::new("HFGITR_EL2", "TLBIRVAE1");
+    /// Descriptor of the `TLBIRVAAE1` field, for use with [`FieldAccess`].
+    pub const TLBIRVAAE1_FIELD: Field<Self, bool, 39, 1> = Field::new("HFGITR_EL2", "TLBIRVAAE1");
+    /// Descriptor of the `TLBIRVALE1` field, for use with [`FieldAccess`].
+    pub const TLBIRVALE1_FIELD: Field<Self, bool, 40, 1> = Field::new("HFGITR_EL2", "TLBIRVALE1");
+    /// Descriptor of the `TLBIRVAALE1` field, for use with [`FieldAccess`].
+    pub const TLBIRVAALE1_FIELD: Field<Self, bool, 41, 1> = Field::new("HFGITR_EL2", "TLBIRVAALE1");
+    /// Descriptor of the `TLBIVMALLE1` field, for use with [`FieldAccess`].
+    pub const TLBIVMALLE1_FIELD: Field<Self, bool, 42, 1> = Field::new("HFGITR_EL2", "TLBIVMALLE1");
+    /// Descriptor of the `TLBIVAE1` field, for use with [`FieldAccess`].
+    pub const TLBIVAE1_FIELD: Field<Self, bool, 43, 1> = Field::new("HFGITR_EL2", "TLBIVAE1");
+    /// Descriptor of the `TLBIASIDE1` field, for use with [`FieldAccess`].
+    pub const TLBIASIDE1_FIELD: Field<Self, bool, 44, 1> = Field::new("HFGITR_EL2", "TLBIASIDE1");
+    /// Descriptor of the `TLBIVAAE1` field, for use with [`FieldAccess`].
+    pub const TLBIVAAE1_FIELD: Field<Self, bool, 45, 1> = Field::new("HFGITR_EL2", "TLBIVAAE1");
+    /// Descriptor of the `TLBIVALE1` field, for use with [`FieldAccess`].
+    pub const TLBIVALE1_FIELD: Field<Self, bool, 46, 1> = Field::new("HFGITR_EL2", "TLBIVALE1");
+    /// Descriptor of the `TLBIVAALE1` field, for use with [`FieldAccess`].
+    pub const TLBIVAALE1_FIELD: Field<Self, bool, 47, 1> = Field::new("HFGITR_EL2", "TLBIVAALE1");
+    /// Descriptor of the `CFPRCTX` field, for use with [`FieldAccess`].
+    pub const CFPRCTX_FIELD: Field<Self, bool, 48, 1> = Field::new("HFGITR_EL2", "CFPRCTX");
+    /// Descriptor of the `DVPRCTX` field, for use with [`FieldAccess`].
+    pub const DVPRCTX_FIELD: Field<Self, bool, 49, 1> = Field::new("HFGITR_EL2", "DVPRCTX");
+    /// Descriptor of the `CPPRCTX` field, for use with [`FieldAccess`].
+    pub const CPPRCTX_FIELD: Field<Self, bool, 50, 1> = Field::new("HFGITR_EL2", "CPPRCTX");
+    /// Descriptor of the `ERET` field, for use with [`FieldAccess`].
+    pub const ERET_FIELD: Field<Self, bool, 51, 1> = Field::new("HFGITR_EL2", "ERET");
+    /// Descriptor of the `SVC_EL0` field, for use with [`FieldAccess`].
+    pub const SVC_EL0_FIELD: Field<Self, bool, 52, 1> = Field::new("HFGITR_EL2", "SVC_EL0");
+    /// Descriptor of the `SVC_EL1` field, for use with [`FieldAccess`].
+    pub const SVC_EL1_FIELD: Field<Self, bool, 53, 1> = Field::new("HFGITR_EL2", "SVC_EL1");
+    /// Descriptor of the `DCCVAC` field, for use with [`FieldAccess`].
+    pub const DCCVAC_FIELD: Field<Self, bool, 54, 1> = Field::new("HFGITR_EL2", "DCCVAC");
+    /// Descriptor of the `nBRBINJ` field, for use with [`FieldAccess`].
+    pub const NBRBINJ_FIELD: Field<Self, bool, 55, 1> = Field::new("HFGITR_EL2", "nBRBINJ");
+    /// Descriptor of the `nBRBIALL` field, for use with [`FieldAccess`].
+    pub const NBRBIALL_FIELD: Field<Self, bool, 56, 1> = Field::new("HFGITR_EL2", "nBRBIALL");
+    /// Descriptor of the `nGCSPUSHM_EL1` field, for use with [`FieldAccess`].
+    pub const NGCSPUSHM_EL1_FIELD: Field<Self, bool, 57, 1> =
+        Field::new("HFGITR_EL2", "nGCSPUSHM_EL1");
+    /// Descriptor of the `nGCSSTR_EL1` field, for use with [`FieldAccess`].
+    pub const NGCSSTR_EL1_FIELD: Field<Self, bool, 58, 1> = Field::new("HFGITR_EL2", "nGCSSTR_EL1");
+    /// Descriptor of the `nGCSEPP` field, for use with [`FieldAccess`].
+    pub const NGCSEPP_FIELD: Field<Self, bool, 59, 1> = Field::new("HFGITR_EL2", "nGCSEPP");
+    /// Descriptor of the `COSPRCTX` field, for use with [`FieldAccess`].
+    pub const COSPRCTX_FIELD: Field<Self, bool, 60, 1> = Field::new("HFGITR_EL2", "COSPRCTX");
+    /// Descriptor of the `ATS1E1A` field, for use with [`FieldAccess`].
+    pub const ATS1E1A_FIELD: Field<Self, bool, 62, 1> = Field::new("HFGITR_EL2", "ATS1E1A");
+    /// Descriptor of the `PSBCSYNC` field, for use with [`FieldAccess`].
+    pub const PSBCSYNC_FIELD: Field<Self, bool, 63, 1> = Field::new("HFGITR_EL2", "PSBCSYNC");
+}
+
+#[cfg(feature = "el2")]
+impl Display for HfgitrEl2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("HfgitrEl2");
+        let bits = self.bits();
+        s.field("ICIALLUIS", &Self::ICIALLUIS_FIELD.extract(bits));
+        s.field("ICIALLU", &Self::ICIALLU_FIELD.extract(bits));
+        s.field("ICIVAU", &Self::ICIVAU_FIELD.extract(bits));
+        s.field("DCIVAC", &Self::DCIVAC_FIELD.extract(bits));
+        s.field("DCISW", &Self::DCISW_FIELD.extract(bits));
+        s.field("DCCSW", &Self::DCCSW_FIELD.extract(bits));
+        s.field("DCCISW", &Self::DCCISW_FIELD.extract(bits));
+        s.field("DCCVAU", &Self::DCCVAU_FIELD.extract(bits));
+        s.field("DCCVAP", &Self::DCCVAP_FIELD.extract(bits));
+        s.field("DCCVADP", &Self::DCCVADP_FIELD.extract(bits));
+        s.field("DCCIVAC", &Self::DCCIVAC_FIELD.extract(bits));
+        s.field("DCZVA", &Self::DCZVA_FIELD.extract(bits));
+        s.field("ATS1E1R", &Self::ATS1E1R_FIELD.extract(bits));
+        s.field("ATS1E1W", &Self::ATS1E1W_FIELD.extract(bits));
+        s.field("ATS1E0R", &Self::ATS1E0R_FIELD.extract(bits));
+        s.field("ATS1E0W", &Self::ATS1E0W_FIELD.extract(bits));
+        s.field("ATS1E1RP", &Self::ATS1E1RP_FIELD.extract(bits));
+        s.field("ATS1E1WP", &Self::ATS1E1WP_FIELD.extract(bits));
+        s.field("TLBIVMALLE1OS", &Self::TLBIVMALLE1OS_FIELD.extract(bits));
+        s.field("TLBIVAE1OS", &Self::TLBIVAE1OS_FIELD.extract(bits));
+        s.field("TLBIASIDE1OS", &Self::TLBIASIDE1OS_FIELD.extract(bits));
+        s.field("TLBIVAAE1OS", &Self::TLBIVAAE1OS_FIELD.extract(bits));
+        s.field("TLBIVALE1OS", &Self::TLBIVALE1OS_FIELD.extract(bits));
+        s.field("TLBIVAALE1OS", &Self::TLBIVAALE1OS_FIELD.extract(bits));
+        s.field("TLBIRVAE1OS", &Self::TLBIRVAE1OS_FIELD.extract(bits));
+        s.field("TLBIRVAAE1OS", &Self::TLBIRVAAE1OS_FIELD.extract(bits));
+        s.field("TLBIRVALE1OS", &Self::TLBIRVALE1OS_FIELD.extract(bits));
+        s.field("TLBIRVAALE1OS", &Self::TLBIRVAALE1OS_FIELD.extract(bits));
+        s.field("TLBIVMALLE1IS", &Self::TLBIVMALLE1IS_FIELD.extract(bits));
+        s.field("TLBIVAE1IS", &Self::TLBIVAE1IS_FIELD.extract(bits));
+        s.field("TLBIASIDE1IS", &Self::TLBIASIDE1IS_FIELD.extract(bits));
+        s.field("TLBIVAAE1IS", &Self::TLBIVAAE1IS_FIELD.extract(bits));
+        s.field("TLBIVALE1IS", &Self::TLBIVALE1IS_FIELD.extract(bits));
+        s.field("TLBIVAALE1IS", &Self::TLBIVAALE1IS_FIELD.extract(bits));
+        s.field("TLBIRVAE1IS", &Self::TLBIRVAE1IS_FIELD.extract(bits));
+        s.field("TLBIRVAAE1IS", &Self::TLBIRVAAE1IS_FIELD.extract(bits));
+        s.field("TLBIRVALE1IS", &Self::TLBIRVALE1IS_FIELD.extract(bits));
+        s.field("TLBIRVAALE1IS", &Self::TLBIRVAALE1IS_FIELD.extract(bits));
+        s.field("TLBIRVAE1", &Self::TLBIRVAE1_FIELD.extract(bits));
+        s.field("TLBIRVAAE1", &Self::TLBIRVAAE1_FIELD.extract(bits));
+        s.field("TLBIRVALE1", &Self::TLBIRVALE1_FIELD.extract(bits));
+        s.field("TLBIRVAALE1", &Self::TLBIRVAALE1_FIELD.extract(bits));
+        s.field("TLBIVMALLE1", &Self::TLBIVMALLE1_FIELD.extract(bits));
+        s.field("TLBIVAE1", &Self::TLBIVAE1_FIELD.extract(bits));
+        s.field("TLBIASIDE1", &Self::TLBIASIDE1_FIELD.extract(bits));
+        s.field("TLBIVAAE1", &Self::TLBIVAAE1_FIELD.extract(bits));
+        s.field("TLBIVALE1", &Self::TLBIVALE1_FIELD.extract(bits));
+        s.field("TLBIVAALE1", &Self::TLBIVAALE1_FIELD.extract(bits));
+        s.field("CFPRCTX", &Self::CFPRCTX_FIELD.extract(bits));
+        s.field("DVPRCTX", &Self::DVPRCTX_FIELD.extract(bits));
+        s.field("CPPRCTX", &Self::CPPRCTX_FIELD.extract(bits));
+        s.field("ERET", &Self::ERET_FIELD.extract(bits));
+        s.field("SVC_EL0", &Self::SVC_EL0_FIELD.extract(bits));
+        s.field("SVC_EL1", &Self::SVC_EL1_FIELD.extract(bits));
+        s.field("DCCVAC", &Self::DCCVAC_FIELD.extract(bits));
+        s.field("nBRBINJ", &Self::NBRBINJ_FIELD.extract(bits));
+        s.field("nBRBIALL", &Self::NBRBIALL_FIELD.extract(bits));
+        s.field("nGCSPUSHM_EL1", &Self::NGCSPUSHM_EL1_FIELD.extract(bits));
+        s.field("nGCSSTR_EL1", &Self::NGCSSTR_EL1_FIELD.extract(bits));
+        s.field("nGCSEPP", &Self::NGCSEPP_FIELD.extract(bits));
+        s.field("COSPRCTX", &Self::COSPRCTX_FIELD.extract(bits));
+        s.field("ATS1E1A", &Self::ATS1E1A_FIELD.extract(bits));
+        s.field("PSBCSYNC", &Self::PSBCSYNC_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for HfgitrEl2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "HfgitrEl2 {{ ICIALLUIS: {=u64}, ICIALLU: {=u64}, ICIVAU: {=u64}, DCIVAC: {=u64}, DCISW: {=u64}, DCCSW: {=u64}, DCCISW: {=u64}, DCCVAU: {=u64}, DCCVAP: {=u64}, DCCVADP: {=u64}, DCCIVAC: {=u64}, DCZVA: {=u64}, ATS1E1R: {=u64}, ATS1E1W: {=u64}, ATS1E0R: {=u64}, ATS1E0W: {=u64}, ATS1E1RP: {=u64}, ATS1E1WP: {=u64}, TLBIVMALLE1OS: {=u64}, TLBIVAE1OS: {=u64}, TLBIASIDE1OS: {=u64}, TLBIVAAE1OS: {=u64}, TLBIVALE1OS: {=u64}, TLBIVAALE1OS: {=u64}, TLBIRVAE1OS: {=u64}, TLBIRVAAE1OS: {=u64}, TLBIRVALE1OS: {=u64}, TLBIRVAALE1OS: {=u64}, TLBIVMALLE1IS: {=u64}, TLBIVAE1IS: {=u64}, TLBIASIDE1IS: {=u64}, TLBIVAAE1IS: {=u64}, TLBIVALE1IS: {=u64}, TLBIVAALE1IS: {=u64}, TLBIRVAE1IS: {=u64}, TLBIRVAAE1IS: {=u64}, TLBIRVALE1IS: {=u64}, TLBIRVAALE1IS: {=u64}, TLBIRVAE1: {=u64}, TLBIRVAAE1: {=u64}, TLBIRVALE1: {=u64}, TLBIRVAALE1: {=u64}, TLBIVMALLE1: {=u64}, TLBIVAE1: {=u64}, TLBIASIDE1: {=u64}, TLBIVAAE1: {=u64}, TLBIVALE1: {=u64}, TLBIVAALE1: {=u64}, CFPRCTX: {=u64}, DVPRCTX: {=u64}, CPPRCTX: {=u64}, ERET: {=u64}, SVC_EL0: {=u64}, SVC_EL1: {=u64}, DCCVAC: {=u64}, nBRBINJ: {=u64}, nBRBIALL: {=u64}, nGCSPUSHM_EL1: {=u64}, nGCSSTR_EL1: {=u64}, nGCSEPP: {=u64}, COSPRCTX: {=u64}, ATS1E1A: {=u64}, PSBCSYNC: {=u64} }}",
+            Self::ICIALLUIS_FIELD.extract(self.bits()),
+            Self::ICIALLU_FIELD.extract(self.bits()),
+            Self::ICIVAU_FIELD.extract(self.bits()),
+            Self::DCIVAC_FIELD.extract(self.bits()),
+            Self::DCISW_FIELD.extract(self.bits()),
+            Self::DCCSW_FIELD.extract(self.bits()),
+            Self::DCCISW_FIELD.extract(self.bits()),
+            Self::DCCVAU_FIELD.extract(self.bits()),
+            Self::DCCVAP_FIELD.extract(self.bits()),
+            Self::DCCVADP_FIELD.extract(self.bits()),
+            Self::DCCIVAC_FIELD.extract(self.bits()),
+            Self::DCZVA_FIELD.extract(self.bits()),
+            Self::ATS1E1R_FIELD.extract(self.bits()),
+            Self::ATS1E1W_FIELD.extract(self.bits()),
+            Self::ATS1E0R_FIELD.extract(self.bits()),
+            Self::ATS1E0W_FIELD.extract(self.bits()),
+            Self::ATS1E1RP_FIELD.extract(self.bits()),
+            Self::ATS1E1WP_FIELD.extract(self.bits()),
+            Self::TLBIVMALLE1OS_FIELD.extract(self.bits()),
+            Self::TLBIVAE1OS_FIELD.extract(self.bits()),
+            Self::TLBIASIDE1OS_FIELD.extract(self.bits()),
+            Self::TLBIVAAE1OS_FIELD.extract(self.bits()),
+            Self::TLBIVALE1OS_FIELD.extract(self.bits()),
+            Self::TLBIVAALE1OS_FIELD.extract(self.bits()),
+            Self::TLBIRVAE1OS_FIELD.extract(self.bits()),
+            Self::TLBIRVAAE1OS_FIELD.extract(self.bits()),
+            Self::TLBIRVALE1OS_FIELD.extract(self.bits()),
+            Self::TLBIRVAALE1OS_FIELD.extract(self.bits()),
+            Self::TLBIVMALLE1IS_FIELD.extract(self.bits()),
+            Self::TLBIVAE1IS_FIELD.extract(self.bits()),
+            Self::TLBIASIDE1IS_FIELD.extract(self.bits()),
+            Self::TLBIVAAE1IS_FIELD.extract(self.bits()),
+            Self::TLBIVALE1IS_FIELD.extract(self.bits()),
+            Self::TLBIVAALE1IS_FIELD.extract(self.bits()),
+            Self::TLBIRVAE1IS_FIELD.extract(self.bits()),
+            Self::TLBIRVAAE1IS_FIELD.extract(self.bits()),
+            Self::TLBIRVALE1IS_FIELD.extract(self.bits()),
+            Self::TLBIRVAALE1IS_FIELD.extract(self.bits()),
+            Self::TLBIRVAE1_FIELD.extract(self.bits()),
+            Self::TLBIRVAAE1_FIELD.extract(self.bits()),
+            Self::TLBIRVALE1_FIELD.extract(self.bits()),
+            Self::TLBIRVAALE1_FIELD.extract(self.bits()),
+            Self::TLBIVMALLE1_FIELD.extract(self.bits()),
+            Self::TLBIVAE1_FIELD.extract(self.bits()),
+            Self::TLBIASIDE1_FIELD.extract(self.bits()),
+            Self::TLBIVAAE1_FIELD.extract(self.bits()),
+            Self::TLBIVALE1_FIELD.extract(self.bits()),
+            Self::TLBIVAALE1_FIELD.extract(self.bits()),
+            Self::CFPRCTX_FIELD.extract(self.bits()),
+            Self::DVPRCTX_FIELD.extract(self.bits()),
+            Self::CPPRCTX_FIELD.extract(self.bits()),
+            Self::ERET_FIELD.extract(self.bits()),
+            Self::SVC_EL0_FIELD.extract(self.bits()),
+            Self::SVC_EL1_FIELD.extract(self.bits()),
+            Self::DCCVAC_FIELD.extract(self.bits()),
+            Self::NBRBINJ_FIELD.extract(self.bits()),
+            Self::NBRBIALL_FIELD.extract(self.bits()),
+            Self::NGCSPUSHM_EL1_FIELD.extract(self.bits()),
+            Self::NGCSSTR_EL1_FIELD.extract(self.bits()),
+            Self::NGCSEPP_FIELD.extract(self.bits()),
+            Self::COSPRCTX_FIELD.extract(self.bits()),
+            Self::ATS1E1A_FIELD.extract(self.bits()),
+            Self::PSBCSYNC_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for HfgitrEl2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for HfgitrEl2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl HfgitrEl2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("ICIALLUIS"),
+                old: Self::ICIALLUIS_FIELD.extract(self.bits()),
+                new: Self::ICIALLUIS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ICIALLU"),
+                old: Self::ICIALLU_FIELD.extract(self.bits()),
+                new: Self::ICIALLU_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ICIVAU"),
+                old: Self::ICIVAU_FIELD.extract(self.bits()),
+                new: Self::ICIVAU_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DCIVAC"),
+                old: Self::DCIVAC_FIELD.extract(self.bits()),
+                new: Self::DCIVAC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DCISW"),
+                old: Self::DCISW_FIELD.extract(self.bits()),
+                new: Self::DCISW_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DCCSW"),
+                old: Self::DCCSW_FIELD.extract(self.bits()),
+                new: Self::DCCSW_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DCCISW"),
+                old: Self::DCCISW_FIELD.extract(self.bits()),
+                new: Self::DCCISW_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DCCVAU"),
+                old: Self::DCCVAU_FIELD.extract(self.bits()),
+                new: Self::DCCVAU_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DCCVAP"),
+                old: Self::DCCVAP_FIELD.extract(self.bits()),
+                new: Self::DCCVAP_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DCCVADP"),
+                old: Self::DCCVADP_FIELD.extract(self.bits()),
+                new: Self::DCCVADP_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DCCIVAC"),
+                old: Self::DCCIVAC_FIELD.extract(self.bits()),
+                new: Self::DCCIVAC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DCZVA"),
+                old: Self::DCZVA_FIELD.extract(self.bits()),
+                new: Self::DCZVA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ATS1E1R"),
+                old: Self::ATS1E1R_FIELD.extract(self.bits()),
+                new: Self::ATS1E1R_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ATS1E1W"),
+                old: Self::ATS1E1W_FIELD.extract(self.bits()),
+                new: Self::ATS1E1W_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ATS1E0R"),
+                old: Self::ATS1E0R_FIELD.extract(self.bits()),
+                new: Self::ATS1E0R_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ATS1E0W"),
+                old: Self::ATS1E0W_FIELD.extract(self.bits()),
+                new: Self::ATS1E0W_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ATS1E1RP"),
+                old: Self::ATS1E1RP_FIELD.extract(self.bits()),
+                new: Self::ATS1E1RP_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ATS1E1WP"),
+                old: Self::ATS1E1WP_FIELD.extract(self.bits()),
+                new: Self::ATS1E1WP_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TLBIVMALLE1OS"),
+                old: Self::TLBIVMALLE1OS_FIELD.extract(self.bits()),
+                new: Self::TLBIVMALLE1OS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TLBIVAE1OS"),
+                old: Self::TLBIVAE1OS_FIELD.extract(self.bits()),
+                new: Self::TLBIVAE1OS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TLBIASIDE1OS"),
+                old: Self::TLBIASIDE1OS_FIELD.extract(self.bits()),
+                new: Self::TLBIASIDE1OS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TLBIVAAE1OS"),
+                old: Self::TLBIVAAE1OS_FIELD.extract(self.bits()),
+                new: Self::TLBIVAAE1OS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TLBIVALE1OS"),
+                old: Self::TLBIVALE1OS_FIELD.extract(self.bits()),
+                new: Self::TLBIVALE1OS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TLBIVAALE1OS"),
+                old: Self::TLBIVAALE1OS_FIELD.extract(self.bits()),
+                new: Self::TLBIVAALE1OS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TLBIRVAE1OS"),
+                old: Self::TLBIRVAE1OS_FIELD.extract(self.bits()),
+                new: Self::TLBIRVAE1OS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TLBIRVAAE1OS"),
+                old: Self::TLBIRVAAE1OS_FIELD.extract(self.bits()),
+                new: Self::TLBIRVAAE1OS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TLBIRVALE1OS"),
+                old: Self::TLBIRVALE1OS_FIELD.extract(self.bits()),
+                new: Self::TLBIRVALE1OS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TLBIRVAALE1OS"),
+                old: Self::TLBIRVAALE1OS_FIELD.extract(self.bits()),
+                new: Self::TLBIRVAALE1OS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TLBIVMALLE1IS"),
+                old: Self::TLBIVMALLE1IS_FIELD.extract(self.bits()),
+                new: Self::TLBIVMALLE1IS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TLBIVAE1IS"),
+                old: Self::TLBIVAE1IS_FIELD.extract(self.bits()),
+                new: Self::TLBIVAE1IS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TLBIASIDE1IS"),
+                old: Self::TLBIASIDE1IS_FIELD.extract(self.bits()),
+                new: Self::TLBIASIDE1IS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TLBIVAAE1IS"),
+                old: Self::TLBIVAAE1IS_FIELD.extract(self.bits()),
+                new: Self::TLBIVAAE1IS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TLBIVALE1IS"),
+                old: Self::TLBIVALE1IS_FIELD.extract(self.bits()),
+                new: Self::TLBIVALE1IS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TLBIVAALE1IS"),
+                old: Self::TLBIVAALE1IS_FIELD.extract(self.bits()),
+                new: Self::TLBIVAALE1IS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TLBIRVAE1IS"),
+                old: Self::TLBIRVAE1IS_FIELD.extract(self.bits()),
+                new: Self::TLBIRVAE1IS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TLBIRVAAE1IS"),
+                old: Self::TLBIRVAAE1IS_FIELD.extract(self.bits()),
+                new: Self::TLBIRVAAE1IS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TLBIRVALE1IS"),
+                old: Self::TLBIRVALE1IS_FIELD.extract(self.bits()),
+                new: Self::TLBIRVALE1IS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TLBIRVAALE1IS"),
+                old: Self::TLBIRVAALE1IS_FIELD.extract(self.bits()),
+                new: Self::TLBIRVAALE1IS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TLBIRVAE1"),
+                old: Self::TLBIRVAE1_FIELD.extract(self.bits()),
+                new: Self::TLBIRVAE1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TLBIRVAAE1"),
+                old: Self::TLBIRVAAE1_FIELD.extract(self.bits()),
+                new: Self::TLBIRVAAE1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TLBIRVALE1"),
+                old: Self::TLBIRVALE1_FIELD.extract(self.bits()),
+                new: Self::TLBIRVALE1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TLBIRVAALE1"),
+                old: Self::TLBIRVAALE1_FIELD.extract(self.bits()),
+                new: Self::TLBIRVAALE1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TLBIVMALLE1"),
+                old: Self::TLBIVMALLE1_FIELD.extract(self.bits()),
+                new: Self::TLBIVMALLE1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TLBIVAE1"),
+                old: Self::TLBIVAE1_FIELD.extract(self.bits()),
+                new: Self::TLBIVAE1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TLBIASIDE1"),
+                old: Self::TLBIASIDE1_FIELD.extract(self.bits()),
+                new: Self::TLBIASIDE1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TLBIVAAE1"),
+                old: Self::TLBIVAAE1_FIELD.extract(self.bits()),
+                new: Self::TLBIVAAE1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TLBIVALE1"),
+                old: Self::TLBIVALE1_FIELD.extract(self.bits()),
+                new: Self::TLBIVALE1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TLBIVAALE1"),
+                old: Self::TLBIVAALE1_FIELD.extract(self.bits()),
+                new: Self::TLBIVAALE1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CFPRCTX"),
+                old: Self::CFPRCTX_FIELD.extract(self.bits()),
+                new: Self::CFPRCTX_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DVPRCTX"),
+                old: Self::DVPRCTX_FIELD.extract(self.bits()),
+                new: Self::DVPRCTX_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CPPRCTX"),
+                old: Self::CPPRCTX_FIELD.extract(self.bits()),
+                new: Self::CPPRCTX_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ERET"),
+                old: Self::ERET_FIELD.extract(self.bits()),
+                new: Self::ERET_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SVC_EL0"),
+                old: Self::SVC_EL0_FIELD.extract(self.bits()),
+                new: Self::SVC_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SVC_EL1"),
+                old: Self::SVC_EL1_FIELD.extract(self.bits()),
+                new: Self::SVC_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DCCVAC"),
+                old: Self::DCCVAC_FIELD.extract(self.bits()),
+                new: Self::DCCVAC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nBRBINJ"),
+                old: Self::NBRBINJ_FIELD.extract(self.bits()),
+                new: Self::NBRBINJ_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nBRBIALL"),
+                old: Self::NBRBIALL_FIELD.extract(self.bits()),
+                new: Self::NBRBIALL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nGCSPUSHM_EL1"),
+                old: Self::NGCSPUSHM_EL1_FIELD.extract(self.bits()),
+                new: Self::NGCSPUSHM_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nGCSSTR_EL1"),
+                old: Self::NGCSSTR_EL1_FIELD.extract(self.bits()),
+                new: Self::NGCSSTR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nGCSEPP"),
+                old: Self::NGCSEPP_FIELD.extract(self.bits()),
+                new: Self::NGCSEPP_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("COSPRCTX"),
+                old: Self::COSPRCTX_FIELD.extract(self.bits()),
+                new: Self::COSPRCTX_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ATS1E1A"),
+                old: Self::ATS1E1A_FIELD.extract(self.bits()),
+                new: Self::ATS1E1A_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PSBCSYNC"),
+                old: Self::PSBCSYNC_FIELD.extract(self.bits()),
+                new: Self::PSBCSYNC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b10_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000,
+                new: other.bits() & 0b10_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl HfgitrEl2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl HfgitrEl2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `HFGRTR2_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct Hfgrtr2El2: u64 {
+        /// `nPFAR_EL1` bit.
+        const NPFAR_EL1 = 1 << 0;
+        /// `nERXGSR_EL1` bit.
+        const NERXGSR_EL1 = 1 << 1;
+        /// `nRCWSMASK_EL1` bit.
+        const NRCWSMASK_EL1 = 1 << 2;
+        /// `nCPACRMASK_EL1` bit.
+        const NCPACRMASK_EL1 = 1 << 3;
+        /// `nSCTLRMASK_EL1` bit.
+        const NSCTLRMASK_EL1 = 1 << 4;
+        /// `nSCTLR2MASK_EL1` bit.
+        const NSCTLR2MASK_EL1 = 1 << 5;
+        /// `nTCRMASK_EL1` bit.
+        const NTCRMASK_EL1 = 1 << 6;
+        /// `nTCR2MASK_EL1` bit.
+        const NTCR2MASK_EL1 = 1 << 7;
+        /// `nCPACRALIAS_EL1` bit.
+        const NCPACRALIAS_EL1 = 1 << 8;
+        /// `nSCTLRALIAS_EL1` bit.
+        const NSCTLRALIAS_EL1 = 1 << 9;
+        /// `nSCTLR2ALIAS_EL1` bit.
+        const NSCTLR2ALIAS_EL1 = 1 << 10;
+        /// `nTCRALIAS_EL1` bit.
+        const NTCRALIAS_EL1 = 1 << 11;
+        /// `nTCR2ALIAS_EL1` bit.
+        const NTCR2ALIAS_EL1 = 1 << 12;
+        /// `nACTLRMASK_EL1` bit.
+        const NACTLRMASK_EL1 = 1 << 13;
+        /// `nACTLRALIAS_EL1` bit.
+        const NACTLRALIAS_EL1 = 1 << 14;
+        /// `nTINDEX_EL0` bit.
+        const NTINDEX_EL0 = 1 << 15;
+        /// `nTINDEX_EL1` bit.
+        const NTINDEX_EL1 = 1 << 16;
+        /// `nSTINDEX_EL1` bit.
+        const NSTINDEX_EL1 = 1 << 17;
+        /// `nTTTBRP_EL1` bit.
+        const NTTTBRP_EL1 = 1 << 20;
+        /// `nTTTBRU_EL1` bit.
+        const NTTTBRU_EL1 = 1 << 21;
+        /// `nIRTBRP_EL1` bit.
+        const NIRTBRP_EL1 = 1 << 22;
+        /// `nIRTBRU_EL1` bit.
+        const NIRTBRU_EL1 = 1 << 23;
+        /// `nDPOTBR1_EL1` bit.
+        const NDPOTBR1_EL1 = 1 << 24;
+        /// `nDPOTBR0_EL1` bit.
+        const NDPOTBR0_EL1 = 1 << 25;
+        /// `nTPMIN1_EL1` bit.
+        const NTPMIN1_EL1 = 1 << 26;
+        /// `nTPMIN0_EL1` bit.
+        const NTPMIN0_EL1 = 1 << 27;
+        /// `nTPMIN1_EL0` bit.
+        const NTPMIN1_EL0 = 1 << 28;
+        /// `nTPMIN0_EL0` bit.
+        const NTPMIN0_EL0 = 1 << 29;
+        /// `nTLBIDIDR_EL1` bit.
+        const NTLBIDIDR_EL1 = 1 << 30;
+        /// `TFSR_EL1` bit.
+        const TFSR_EL1 = 1 << 33;
+        /// `RGSR_EL1` bit.
+        const RGSR_EL1 = 1 << 34;
+        /// `GCR_EL1` bit.
+        const GCR_EL1 = 1 << 35;
+        /// `nTPIDR3_EL0` bit.
+        const NTPIDR3_EL0 = 1 << 36;
+        /// `nTPIDR3_EL1` bit.
+        const NTPIDR3_EL1 = 1 << 37;
+        /// `nLDSTT_EL1` bit.
+        const NLDSTT_EL1 = 1 << 38;
+        /// `ACTLR_EL1` bit.
+        const ACTLR_EL1 = 1 << 39;
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Hfgrtr2El2 {
+    /// Descriptor of the `nPFAR_EL1` field, for use with [`FieldAccess`].
+    pub const NPFAR_EL1_FIELD: Field<Self, bool, 0, 1> = Field::new("HFGRTR2_EL2", "nPFAR_EL1");
+    /// Descriptor of the `nERXGSR_EL1` field, for use with [`FieldAccess`].
+    pub const NERXGSR_EL1_FIELD: Field<Self, bool, 1, 1> = Field::new("HFGRTR2_EL2", "nERXGSR_EL1");
+    /// Descriptor of the `nRCWSMASK_EL1` field, for use with [`FieldAccess`].
+    pub const NRCWSMASK_EL1_FIELD: Field<Self, bool, 2, 1> =
+        Field::new("HFGRTR2_EL2", "nRCWSMASK_EL1");
+    /// Descriptor of the `nCPACRMASK_EL1` field, for use with [`FieldAccess`].
+    pub const NCPACRMASK_EL1_FIELD: Field<Self, bool, 3, 1> =
+        Field::new("HFGRTR2_EL2", "nCPACRMASK_EL1");
+    /// Descriptor of the `nSCTLRMASK_EL1` field, for use with [`FieldAccess`].
+    pub const NSCTLRMASK_EL1_FIELD: Field<Self, bool, 4, 1> =
+        Field::new("HFGRTR2_EL2", "nSCTLRMASK_EL1");
+    /// Descriptor of the `nSCTLR2MASK_EL1` field, for use with [`FieldAccess`].
+    pub const NSCTLR2MASK_EL1_FIELD: Field<Self, bool, 5, 1> =
+        Field::new("HFGRTR2_EL2", "nSCTLR2MASK_EL1");
+    /// Descriptor of the `nTCRMASK_EL1` field, for use with [`FieldAccess`].
+    pub const NTCRMASK_EL1_FIELD: Field<Self, bool, 6, 1> =
+        Field::new("HFGRTR2_EL2", "nTCRMASK_EL1");
+    /// Descriptor of the `nTCR2MASK_EL1` field, for use with [`FieldAccess`].
+    pub const NTCR2MASK_EL1_FIELD: Field<Self, bool, 7, 1> =
+        Field::new("HFGRTR2_EL2", "nTCR2MASK_EL1");
+    /// Descriptor of the `nCPACRALIAS_EL1` field, for use with [`FieldAccess`].
+    pub const NCPACRALIAS_EL1_FIELD: Field<Self, bool, 8, 1> =
+        Field::new("HFGRTR2_EL2", "nCPACRALIAS_EL1");
+    /// Descriptor of the `nSCTLRALIAS_EL1` field, for use with [`FieldAccess`].
+    pub const NSCTLRALIAS_EL1_FIELD: Field<Self, bool, 9, 1> =
+        Field::new("HFGRTR2_EL2", "nSCTLRALIAS_EL1");
+    /// Descriptor of the `nSCTLR2ALIAS_EL1` field, for use with [`FieldAccess`].
+    pub const NSCTLR2ALIAS_EL1_FIELD: Field<Self, bool, 10, 1> =
+        Field::new("HFGRTR2_EL2", "nSCTLR2ALIAS_EL1");
+    /// Descriptor of the `nTCRALIAS_EL1` field, for use with [`FieldAccess`].
+    pub const NTCRALIAS_EL1_FIELD: Field<Self, bool, 11, 1> =
+        Field::new("HFGRTR2_EL2", "nTCRALIAS_EL1");
+    /// Descriptor of the `nTCR2ALIAS_EL1` field, for use with [`FieldAccess`].
+    pub const NTCR2ALIAS_EL1_FIELD: Field<Self, bool, 12, 1> =
+        Field::new("HFGRTR2_EL2", "nTCR2ALIAS_EL1");
+    /// Descriptor of the `nACTLRMASK_EL1` field, for use with [`FieldAccess`].
+    pub const NACTLRMASK_EL1_FIELD: Field<Self, bool, 13, 1> =
+        Field::new("HFGRTR2_EL2", "nACTLRMASK_EL1");
+    /// Descriptor of the `nACTLRALIAS_EL1` field, for use with [`FieldAccess`].
+    pub const NACTLRALIAS_EL1_FIELD: Field<Self, bool, 14, 1> =
+        Field::new("HFGRTR2_EL2", "nACTLRALIAS_EL1");
+    /// Descriptor of the `nTINDEX_EL0` field, for use with [`FieldAccess`].
+    pub const NTINDEX_EL0_FIELD: Field<Self, bool, 15, 1> =
+        Field::new("HFGRTR2_EL2", "nTINDEX_EL0");
+    /// Descriptor of the `nTINDEX_EL1` field, for use with [`FieldAccess`].
+    pub const NTINDEX_EL1_FIELD: Field<Self, bool, 16, 1> =
+        Field::new("HFGRTR2_EL2", "nTINDEX_EL1");
+    /// Descriptor of the `nSTINDEX_EL1` field, for use with [`FieldAccess`].
+    pub const NSTINDEX_EL1_FIELD: Field<Self, bool, 17, 1> =
+        Field::new("HFGRTR2_EL2", "nSTINDEX_EL1");
+    /// Descriptor of the `nFGDTn_EL1` field, for use with [`FieldAccess`].
+    pub const NFGDTN_EL1_FIELD: Field<Self, u8, 18, 2> = Field::new("HFGRTR2_EL2", "nFGDTn_EL1");
+    /// Descriptor of the `nTTTBRP_EL1` field, for use with [`FieldAccess`].
+    pub const NTTTBRP_EL1_FIELD: Field<Self, bool, 20, 1> =
+        Field::new("HFGRTR2_EL2", "nTTTBRP_EL1");
+    /// Descriptor of the `nTTTBRU_EL1` field, for use with [`FieldAccess`].
+    pub const NTTTBRU_EL1_FIELD: Field<Self, bool, 21, 1> =
+        Field::new("HFGRTR2_EL2", "nTTTBRU_EL1");
+    /// Descriptor of the `nIRTBRP_EL1` field, for use with [`FieldAccess`].
+    pub const NIRTBRP_EL1_FIELD: Field<Self, bool, 22, 1> =
+        Field::new("HFGRTR2_EL2", "nIRTBRP_EL1");
+    /// Descriptor of the `nIRTBRU_EL1` field, for use with [`FieldAccess`].
+    pub const NIRTBRU_EL1_FIELD: Field<Self, bool, 23, 1> =
+        Field::new("HFGRTR2_EL2", "nIRTBRU_EL1");
+    /// Descriptor of the `nDPOTBR1_EL1` field, for use with [`FieldAccess`].
+    pub const NDPOTBR1_EL1_FIELD: Field<Self, bool, 24, 1> =
+        Field::new("HFGRTR2_EL2", "nDPOTBR1_EL1");
+    /// Descriptor of the `nDPOTBR0_EL1` field, for use with [`FieldAccess`].
+    pub const NDPOTBR0_EL1_FIELD: Field<Self, bool, 25, 1> =
+        Field::new("HFGRTR2_EL2", "nDPOTBR0_EL1");
+    /// Descriptor of the `nTPMIN1_EL1` field, for use with [`FieldAccess`].
+    pub const NTPMIN1_EL1_FIELD: Field<Self, bool, 26, 1> =
+        Field::new("HFGRTR2_EL2", "nTPMIN1_EL1");
+    /// Descriptor of the `nTPMIN0_EL1` field, for use with [`FieldAccess`].
+    pub const NTPMIN0_EL1_FIELD: Field<Self, bool, 27, 1> =
+        Field::new("HFGRTR2_EL2", "nTPMIN0_EL1");
+    /// Descriptor of the `nTPMIN1_EL0` field, for use with [`FieldAccess`].
+    pub const NTPMIN1_EL0_FIELD: Field<Self, bool, 28, 1> =
+        Field::new("HFGRTR2_EL2", "nTPMIN1_EL0");
+    /// Descriptor of the `nTPMIN0_EL0` field, for use with [`FieldAccess`].
+    pub const NTPMIN0_EL0_FIELD: Field<Self, bool, 29, 1> =
+        Field::new("HFGRTR2_EL2", "nTPMIN0_EL0");
+    /// Descriptor of the `nTLBIDIDR_EL1` field, for use with [`FieldAccess`].
+    pub const NTLBIDIDR_EL1_FIELD: Field<Self, bool, 30, 1> =
+        Field::new("HFGRTR2_EL2", "nTLBIDIDR_EL1");
+    /// Descriptor of the `nAFGDTn_EL1` field, for use with [`FieldAccess`].
+    pub const NAFGDTN_EL1_FIELD: Field<Self, u8, 31, 2> = Field::new("HFGRTR2_EL2", "nAFGDTn_EL1");
+    /// Descriptor of the `TFSR_EL1` field, for use with [`FieldAccess`].
+    pub const TFSR_EL1_FIELD: Field<Self, bool, 33, 1> = Field::new("HFGRTR2_EL2", "TFSR_EL1");
+    /// Descriptor of the `RGSR_EL1` field, for use with [`FieldAccess`].
+    pub const RGSR_EL1_FIELD: Field<Self, bool, 34, 1> = Field::new("HFGRTR2_EL2", "RGSR_EL1");
+    /// Descriptor of the `GCR_EL1` field, for use with [`FieldAccess`].
+    pub const GCR_EL1_FIELD: Field<Self, bool, 35, 1> = Field::new("HFGRTR2_EL2", "GCR_EL1");
+    /// Descriptor of the `nTPIDR3_EL0` field, for use with [`FieldAccess`].
+    pub const NTPIDR3_EL0_FIELD: Field<Self, bool, 36, 1> =
+        Field::new("HFGRTR2_EL2", "nTPIDR3_EL0");
+    /// Descriptor of the `nTPIDR3_EL1` field, for use with [`FieldAccess`].
+    pub const NTPIDR3_EL1_FIELD: Field<Self, bool, 37, 1> =
+        Field::new("HFGRTR2_EL2", "nTPIDR3_EL1");
+    /// Descriptor of the `nLDSTT_EL1` field, for use with [`FieldAccess`].
+    pub const NLDSTT_EL1_FIELD: Field<Self, bool, 38, 1> = Field::new("HFGRTR2_EL2", "nLDSTT_EL1");
+    /// Descriptor of the `ACTLR_EL1` field, for use with [`FieldAccess`].
+    pub const ACTLR_EL1_FIELD: Field<Self, bool, 39, 1> = Field::new("HFGRTR2_EL2", "ACTLR_EL1");
+
+    /// Returns the value of the `nFGDTn_EL1` field.
+    pub const fn nfgdtn_el1(self) -> u8 {
+        Self::NFGDTN_EL1_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `nFGDTn_EL1` field.
+    pub const fn set_nfgdtn_el1(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::NFGDTN_EL1_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `nFGDTn_EL1` field set to the given value.
+    pub const fn with_nfgdtn_el1(mut self, value: u8) -> Self {
+        self.set_nfgdtn_el1(value);
+        self
+    }
+
+    /// Sets the value of the `nFGDTn_EL1` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_nfgdtn_el1(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_nfgdtn_el1(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `nFGDTn_EL1` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_nfgdtn_el1(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::NFGDTN_EL1_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `nAFGDTn_EL1` field.
+    pub const fn nafgdtn_el1(self) -> u8 {
+        Self::NAFGDTN_EL1_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `nAFGDTn_EL1` field.
+    pub const fn set_nafgdtn_el1(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::NAFGDTN_EL1_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `nAFGDTn_EL1` field set to the given value.
+    pub const fn with_nafgdtn_el1(mut self, value: u8) -> Self {
+        self.set_nafgdtn_el1(value);
+        self
+    }
+
+    /// Sets the value of the `nAFGDTn_EL1` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_nafgdtn_el1(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_nafgdtn_el1(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `nAFGDTn_EL1` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_nafgdtn_el1(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::NAFGDTN_EL1_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Display for Hfgrtr2El2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("Hfgrtr2El2");
+        let bits = self.bits();
+        s.field("nPFAR_EL1", &Self::NPFAR_EL1_FIELD.extract(bits));
+        s.field("nERXGSR_EL1", &Self::NERXGSR_EL1_FIELD.extract(bits));
+        s.field("nRCWSMASK_EL1", &Self::NRCWSMASK_EL1_FIELD.extract(bits));
+        s.field("nCPACRMASK_EL1", &Self::NCPACRMASK_EL1_FIELD.extract(bits));
+        s.field("nSCTLRMASK_EL1", &Self::NSCTLRMASK_EL1_FIELD.extract(bits));
+        s.field(
+            "nSCTLR2MASK_EL1",
+            &Self::NSCTLR2MASK_EL1_FIELD.extract(bits),
+        );
+        s.field("nTCRMASK_EL1", &Self::NTCRMASK_EL1_FIELD.extract(bits));
+        s.field("nTCR2MASK_EL1", &Self::NTCR2MASK_EL1_FIELD.extract(bits));
+        s.field(
+            "nCPACRALIAS_EL1",
+            &Self::NCPACRALIAS_EL1_FIELD.extract(bits),
+        );
+        s.field(
+            "nSCTLRALIAS_EL1",
+            &Self::NSCTLRALIAS_EL1_FIELD.extract(bits),
+        );
+        s.field(
+            "nSCTLR2ALIAS_EL1",
+            &Self::NSCTLR2ALIAS_EL1_FIELD.extract(bits),
+        );
+        s.field("nTCRALIAS_EL1", &Self::NTCRALIAS_EL1_FIELD.extract(bits));
+        s.field("nTCR2ALIAS_EL1", &Self::NTCR2ALIAS_EL1_FIELD.extract(bits));
+        s.field("nACTLRMASK_EL1", &Self::NACTLRMASK_EL1_FIELD.extract(bits));
+        s.field(
+            "nACTLRALIAS_EL1",
+            &Self::NACTLRALIAS_EL1_FIELD.extract(bits),
+        );
+        s.field("nTINDEX_EL0", &Self::NTINDEX_EL0_FIELD.extract(bits));
+        s.field("nTINDEX_EL1", &Self::NTINDEX_EL1_FIELD.extract(bits));
+        s.field("nSTINDEX_EL1", &Self::NSTINDEX_EL1_FIELD.extract(bits));
+        s.field("nFGDTn_EL1", &Self::NFGDTN_EL1_FIELD.extract(bits));
+        s.field("nTTTBRP_EL1", &Self::NTTTBRP_EL1_FIELD.extract(bits));
+        s.field("nTTTBRU_EL1", &Self::NTTTBRU_EL1_FIELD.extract(bits));
+        s.field("nIRTBRP_EL1", &Self::NIRTBRP_EL1_FIELD.extract(bits));
+        s.field("nIRTBRU_EL1", &Self::NIRTBRU_EL1_FIELD.extract(bits));
+        s.field("nDPOTBR1_EL1", &Self::NDPOTBR1_EL1_FIELD.extract(bits));
+        s.field("nDPOTBR0_EL1", &Self::NDPOTBR0_EL1_FIELD.extract(bits));
+        s.field("nTPMIN1_EL1", &Self::NTPMIN1_EL1_FIELD.extract(bits));
+        s.field("nTPMIN0_EL1", &Self::NTPMIN0_EL1_FIELD.extract(bits));
+        s.field("nTPMIN1_EL0", &Self::NTPMIN1_EL0_FIELD.extract(bits));
+        s.field("nTPMIN0_EL0", &Self::NTPMIN0_EL0_FIELD.extract(bits));
+        s.field("nTLBIDIDR_EL1", &Self::NTLBIDIDR_EL1_FIELD.extract(bits));
+        s.field("nAFGDTn_EL1", &Self::NAFGDTN_EL1_FIELD.extract(bits));
+        s.field("TFSR_EL1", &Self::TFSR_EL1_FIELD.extract(bits));
+        s.field("RGSR_EL1", &Self::RGSR_EL1_FIELD.extract(bits));
+        s.field("GCR_EL1", &Self::GCR_EL1_FIELD.extract(bits));
+        s.field("nTPIDR3_EL0", &Self::NTPIDR3_EL0_FIELD.extract(bits));
+        s.field("nTPIDR3_EL1", &Self::NTPIDR3_EL1_FIELD.extract(bits));
+        s.field("nLDSTT_EL1", &Self::NLDSTT_EL1_FIELD.extract(bits));
+        s.field("ACTLR_EL1", &Self::ACTLR_EL1_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for Hfgrtr2El2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "Hfgrtr2El2 {{ nPFAR_EL1: {=u64}, nERXGSR_EL1: {=u64}, nRCWSMASK_EL1: {=u64}, nCPACRMASK_EL1: {=u64}, nSCTLRMASK_EL1: {=u64}, nSCTLR2MASK_EL1: {=u64}, nTCRMASK_EL1: {=u64}, nTCR2MASK_EL1: {=u64}, nCPACRALIAS_EL1: {=u64}, nSCTLRALIAS_EL1: {=u64}, nSCTLR2ALIAS_EL1: {=u64}, nTCRALIAS_EL1: {=u64}, nTCR2ALIAS_EL1: {=u64}, nACTLRMASK_EL1: {=u64}, nACTLRALIAS_EL1: {=u64}, nTINDEX_EL0: {=u64}, nTINDEX_EL1: {=u64}, nSTINDEX_EL1: {=u64}, nFGDTn_EL1: {=u64}, nTTTBRP_EL1: {=u64}, nTTTBRU_EL1: {=u64}, nIRTBRP_EL1: {=u64}, nIRTBRU_EL1: {=u64}, nDPOTBR1_EL1: {=u64}, nDPOTBR0_EL1: {=u64}, nTPMIN1_EL1: {=u64}, nTPMIN0_EL1: {=u64}, nTPMIN1_EL0: {=u64}, nTPMIN0_EL0: {=u64}, nTLBIDIDR_EL1: {=u64}, nAFGDTn_EL1: {=u64}, TFSR_EL1: {=u64}, RGSR_EL1: {=u64}, GCR_EL1: {=u64}, nTPIDR3_EL0: {=u64}, nTPIDR3_EL1: {=u64}, nLDSTT_EL1: {=u64}, ACTLR_EL1: {=u64} }}",
+            Self::NPFAR_EL1_FIELD.extract(self.bits()),
+            Self::NERXGSR_EL1_FIELD.extract(self.bits()),
+            Self::NRCWSMASK_EL1_FIELD.extract(self.bits()),
+            Self::NCPACRMASK_EL1_FIELD.extract(self.bits()),
+            Self::NSCTLRMASK_EL1_FIELD.extract(self.bits()),
+            Self::NSCTLR2MASK_EL1_FIELD.extract(self.bits()),
+            Self::NTCRMASK_EL1_FIELD.extract(self.bits()),
+            Self::NTCR2MASK_EL1_FIELD.extract(self.bits()),
+            Self::NCPACRALIAS_EL1_FIELD.extract(self.bits()),
+            Self::NSCTLRALIAS_EL1_FIELD.extract(self.bits()),
+            Self::NSCTLR2ALIAS_EL1_FIELD.extract(self.bits()),
+            Self::NTCRALIAS_EL1_FIELD.extract(self.bits()),
+            Self::NTCR2ALIAS_EL1_FIELD.extract(self.bits()),
+            Self::NACTLRMASK_EL1_FIELD.extract(self.bits()),
+            Self::NACTLRALIAS_EL1_FIELD.extract(self.bits()),
+            Self::NTINDEX_EL0_FIELD.extract(self.bits()),
+            Self::NTINDEX_EL1_FIELD.extract(self.bits()),
+            Self::NSTINDEX_EL1_FIELD.extract(self.bits()),
+            Self::NFGDTN_EL1_FIELD.extract(self.bits()),
+            Self::NTTTBRP_EL1_FIELD.extract(self.bits()),
+            Self::NTTTBRU_EL1_FIELD.extract(self.bits()),
+            Self::NIRTBRP_EL1_FIELD.extract(self.bits()),
+            Self::NIRTBRU_EL1_FIELD.extract(self.bits()),
+            Self::NDPOTBR1_EL1_FIELD.extract(self.bits()),
+            Self::NDPOTBR0_EL1_FIELD.extract(self.bits()),
+            Self::NTPMIN1_EL1_FIELD.extract(self.bits()),
+            Self::NTPMIN0_EL1_FIELD.extract(self.bits()),
+            Self::NTPMIN1_EL0_FIELD.extract(self.bits()),
+            Self::NTPMIN0_EL0_FIELD.extract(self.bits()),
+            Self::NTLBIDIDR_EL1_FIELD.extract(self.bits()),
+            Self::NAFGDTN_EL1_FIELD.extract(self.bits()),
+            Self::TFSR_EL1_FIELD.extract(self.bits()),
+            Self::RGSR_EL1_FIELD.extract(self.bits()),
+            Self::GCR_EL1_FIELD.extract(self.bits()),
+            Self::NTPIDR3_EL0_FIELD.extract(self.bits()),
+            Self::NTPIDR3_EL1_FIELD.extract(self.bits()),
+            Self::NLDSTT_EL1_FIELD.extract(self.bits()),
+            Self::ACTLR_EL1_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for Hfgrtr2El2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for Hfgrtr2El2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Hfgrtr2El2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("nPFAR_EL1"),
+                old: Self::NPFAR_EL1_FIELD.extract(self.bits()),
+                new: Self::NPFAR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nERXGSR_EL1"),
+                old: Self::NERXGSR_EL1_FIELD.extract(self.bits()),
+                new: Self::NERXGSR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nRCWSMASK_EL1"),
+                old: Self::NRCWSMASK_EL1_FIELD.extract(self.bits()),
+                new: Self::NRCWSMASK_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nCPACRMASK_EL1"),
+                old: Self::NCPACRMASK_EL1_FIELD.extract(self.bits()),
+                new: Self::NCPACRMASK_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nSCTLRMASK_EL1"),
+                old: Self::NSCTLRMASK_EL1_FIELD.extract(self.bits()),
+                new: Self::NSCTLRMASK_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nSCTLR2MASK_EL1"),
+                old: Self::NSCTLR2MASK_EL1_FIELD.extract(self.bits()),
+                new: Self::NSCTLR2MASK_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nTCRMASK_EL1"),
+                old: Self::NTCRMASK_EL1_FIELD.extract(self.bits()),
+                new: Self::NTCRMASK_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nTCR2MASK_EL1"),
+                old: Self::NTCR2MASK_EL1_FIELD.extract(self.bits()),
+                new: Self::NTCR2MASK_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nCPACRALIAS_EL1"),
+                old: Self::NCPACRALIAS_EL1_FIELD.extract(self.bits()),
+                new: Self::NCPACRALIAS_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nSCTLRALIAS_EL1"),
+                old: Self::NSCTLRALIAS_EL1_FIELD.extract(self.bits()),
+                new: Self::NSCTLRALIAS_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nSCTLR2ALIAS_EL1"),
+                old: Self::NSCTLR2ALIAS_EL1_FIELD.extract(self.bits()),
+                new: Self::NSCTLR2ALIAS_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nTCRALIAS_EL1"),
+                old: Self::NTCRALIAS_EL1_FIELD.extract(self.bits()),
+                new: Self::NTCRALIAS_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nTCR2ALIAS_EL1"),
+                old: Self::NTCR2ALIAS_EL1_FIELD.extract(self.bits()),
+                new: Self::NTCR2ALIAS_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nACTLRMASK_EL1"),
+                old: Self::NACTLRMASK_EL1_FIELD.extract(self.bits()),
+                new: Self::NACTLRMASK_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nACTLRALIAS_EL1"),
+                old: Self::NACTLRALIAS_EL1_FIELD.extract(self.bits()),
+                new: Self::NACTLRALIAS_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nTINDEX_EL0"),
+                old: Self::NTINDEX_EL0_FIELD.extract(self.bits()),
+                new: Self::NTINDEX_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nTINDEX_EL1"),
+                old: Self::NTINDEX_EL1_FIELD.extract(self.bits()),
+                new: Self::NTINDEX_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nSTINDEX_EL1"),
+                old: Self::NSTINDEX_EL1_FIELD.extract(self.bits()),
+                new: Self::NSTINDEX_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nFGDTn_EL1"),
+                old: Self::NFGDTN_EL1_FIELD.extract(self.bits()),
+                new: Self::NFGDTN_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nTTTBRP_EL1"),
+                old: Self::NTTTBRP_EL1_FIELD.extract(self.bits()),
+                new: Self::NTTTBRP_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nTTTBRU_EL1"),
+                old: Self::NTTTBRU_EL1_FIELD.extract(self.bits()),
+                new: Self::NTTTBRU_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nIRTBRP_EL1"),
+                old: Self::NIRTBRP_EL1_FIELD.extract(self.bits()),
+                new: Self::NIRTBRP_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nIRTBRU_EL1"),
+                old: Self::NIRTBRU_EL1_FIELD.extract(self.bits()),
+                new: Self::NIRTBRU_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nDPOTBR1_EL1"),
+                old: Self::NDPOTBR1_EL1_FIELD.extract(self.bits()),
+                new: Self::NDPOTBR1_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nDPOTBR0_EL1"),
+                old: Self::NDPOTBR0_EL1_FIELD.extract(self.bits()),
+                new: Self::NDPOTBR0_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nTPMIN1_EL1"),
+                old: Self::NTPMIN1_EL1_FIELD.extract(self.bits()),
+                new: Self::NTPMIN1_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nTPMIN0_EL1"),
+                old: Self::NTPMIN0_EL1_FIELD.extract(self.bits()),
+                new: Self::NTPMIN0_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nTPMIN1_EL0"),
+                old: Self::NTPMIN1_EL0_FIELD.extract(self.bits()),
+                new: Self::NTPMIN1_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nTPMIN0_EL0"),
+                old: Self::NTPMIN0_EL0_FIELD.extract(self.bits()),
+                new: Self::NTPMIN0_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nTLBIDIDR_EL1"),
+                old: Self::NTLBIDIDR_EL1_FIELD.extract(self.bits()),
+                new: Self::NTLBIDIDR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nAFGDTn_EL1"),
+                old: Self::NAFGDTN_EL1_FIELD.extract(self.bits()),
+                new: Self::NAFGDTN_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TFSR_EL1"),
+                old: Self::TFSR_EL1_FIELD.extract(self.bits()),
+                new: Self::TFSR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("RGSR_EL1"),
+                old: Self::RGSR_EL1_FIELD.extract(self.bits()),
+                new: Self::RGSR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("GCR_EL1"),
+                old: Self::GCR_EL1_FIELD.extract(self.bits()),
+                new: Self::GCR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nTPIDR3_EL0"),
+                old: Self::NTPIDR3_EL0_FIELD.extract(self.bits()),
+                new: Self::NTPIDR3_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nTPIDR3_EL1"),
+                old: Self::NTPIDR3_EL1_FIELD.extract(self.bits()),
+                new: Self::NTPIDR3_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nLDSTT_EL1"),
+                old: Self::NLDSTT_EL1_FIELD.extract(self.bits()),
+                new: Self::NLDSTT_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ACTLR_EL1"),
+                old: Self::ACTLR_EL1_FIELD.extract(self.bits()),
+                new: Self::ACTLR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Hfgrtr2El2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Hfgrtr2El2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `HFGRTR_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct HfgrtrEl2: u64 {
+        /// `AFSR0_EL1` bit.
+        const AFSR0_EL1 = 1 << 0;
+        /// `AFSR1_EL1` bit.
+        const AFSR1_EL1 = 1 << 1;
+        /// `AIDR_EL1` bit.
+        const AIDR_EL1 = 1 << 2;
+        /// `AMAIR_EL1` bit.
+        const AMAIR_EL1 = 1 << 3;
+        /// `APDAKey` bit.
+        const APDAKEY = 1 << 4;
+        /// `APDBKey` bit.
+        const APDBKEY = 1 << 5;
+        /// `APGAKey` bit.
+        const APGAKEY = 1 << 6;
+        /// `APIAKey` bit.
+        const APIAKEY = 1 << 7;
+        /// `APIBKey` bit.
+        const APIBKEY = 1 << 8;
+        /// `CCSIDR_EL1` bit.
+        const CCSIDR_EL1 = 1 << 9;
+        /// `CLIDR_EL1` bit.
+        const CLIDR_EL1 = 1 << 10;
+        /// `CONTEXTIDR_EL1` bit.
+        const CONTEXTIDR_EL1 = 1 << 11;
+        /// `CPACR_EL1` bit.
+        const CPACR_EL1 = 1 << 12;
+        /// `CSSELR_EL1` bit.
+        const CSSELR_EL1 = 1 << 13;
+        /// `CTR_EL0` bit.
+        const CTR_EL0 = 1 << 14;
+        /// `DCZID_EL0` bit.
+        const DCZID_EL0 = 1 << 15;
+        /// `ESR_EL1` bit.
+        const ESR_EL1 = 1 << 16;
+        /// `FAR_EL1` bit.
+        const FAR_EL1 = 1 << 17;
+        /// `ISR_EL1` bit.
+        const ISR_EL1 = 1 << 18;
+        /// `LORC_EL1` bit.
+        const LORC_EL1 = 1 << 19;
+        /// `LOREA_EL1` bit.
+        const LOREA_EL1 = 1 << 20;
+        /// `LORID_EL1` bit.
+        const LORID_EL1 = 1 << 21;
+        /// `LORN_EL1` bit.
+        const LORN_EL1 = 1 << 22;
+        /// `LORSA_EL1` bit.
+        const LORSA_EL1 = 1 << 23;
+        /// `MAIR_EL1` bit.
+        const MAIR_EL1 = 1 << 24;
+        /// `MIDR_EL1` bit.
+        const MIDR_EL1 = 1 << 25;
+        /// `MPIDR_EL1` bit.
+        const MPIDR_EL1 = 1 << 26;
+        /// `PAR_EL1` bit.
+        const PAR_EL1 = 1 << 27;
+        /// `REVIDR_EL1` bit.
+        const REVIDR_EL1 = 1 << 28;
+        /// `SCTLR_EL1` bit.
+        const SCTLR_EL1 = 1 << 29;
+        /// `SCXTNUM_EL1` bit.
+        const SCXTNUM_EL1 = 1 << 30;
+        /// `SCXTNUM_EL0` bit.
+        const SCXTNUM_EL0 = 1 << 31;
+        /// `TCR_EL1` bit.
+        const TCR_EL1 = 1 << 32;
+        /// `TPIDR_EL1` bit.
+        const TPIDR_EL1 = 1 << 33;
+        /// `TPIDRRO_EL0` bit.
+        const TPIDRRO_EL0 = 1 << 34;
+        /// `TPIDR_EL0` bit.
+        const TPIDR_EL0 = 1 << 35;
+        /// `TTBR0_EL1` bit.
+        const TTBR0_EL1 = 1 << 36;
+        /// `TTBR1_EL1` bit.
+        const TTBR1_EL1 = 1 << 37;
+        /// `VBAR_EL1` bit.
+        const VBAR_EL1 = 1 << 38;
+        /// `ICC_IGRPENn_EL1` bit.
+        const ICC_IGRPENN_EL1 = 1 << 39;
+        /// `ERRIDR_EL1` bit.
+        const ERRIDR_EL1 = 1 << 40;
+        /// `ERRSELR_EL1` bit.
+        const ERRSELR_EL1 = 1 << 41;
+        /// `ERXFR_EL1` bit.
+        const ERXFR_EL1 = 1 << 42;
+        /// `ERXCTLR_EL1` bit.
+        const ERXCTLR_EL1 = 1 << 43;
+        /// `ERXSTATUS_EL1` bit.
+        const ERXSTATUS_EL1 = 1 << 44;
+        /// `ERXMISCn_EL1` bit.
+        const ERXMISCN_EL1 = 1 << 45;
+        /// `ERXPFGF_EL1` bit.
+        const ERXPFGF_EL1 = 1 << 46;
+        /// `ERXPFGCTL_EL1` bit.
+        const ERXPFGCTL_EL1 = 1 << 47;
+        /// `ERXPFGCDN_EL1` bit.
+        const ERXPFGCDN_EL1 = 1 << 48;
+        /// `ERXADDR_EL1` bit.
+        const ERXADDR_EL1 = 1 << 49;
+        /// `nACCDATA_EL1` bit.
+        const NACCDATA_EL1 = 1 << 50;
+        /// `nGCS_EL0` bit.
+        const NGCS_EL0 = 1 << 52;
+        /// `nGCS_EL1` bit.
+        const NGCS_EL1 = 1 << 53;
+        /// `nSMPRI_EL1` bit.
+        const NSMPRI_EL1 = 1 << 54;
+        /// `nTPIDR2_EL0` bit.
+        const NTPIDR2_EL0 = 1 << 55;
+        /// `nRCWMASK_EL1` bit.
+        const NRCWMASK_EL1 = 1 << 56;
+        /// `nPIRE0_EL1` bit.
+        const NPIRE0_EL1 = 1 << 57;
+        /// `nPIR_EL1` bit.
+        const NPIR_EL1 = 1 << 58;
+        /// `nPOR_EL0` bit.
+        const NPOR_EL0 = 1 << 59;
+        /// `nPOR_EL1` bit.
+        const NPOR_EL1 = 1 << 60;
+        /// `nS2POR_EL1` bit.
+        const NS2POR_EL1 = 1 << 61;
+        /// `nMAIR2_EL1` bit.
+        const NMAIR2_EL1 = 1 << 62;
+        /// `nAMAIR2_EL1` bit.
+        const NAMAIR2_EL1 = 1 << 63;
+    }
+}
+
+#[cfg(feature = "el2")]
+impl HfgrtrEl2 {
+    /// Descriptor of the `AFSR0_EL1` field, for use with [`FieldAccess`].
+    pub const AFSR0_EL1_FIELD: Field<Self, bool, 0, 1> = Field::new("HFGRTR_EL2", "AFSR0_EL1");
+    /// Descriptor of the `AFSR1_EL1` field, for use with [`FieldAccess`].
+    pub const AFSR1_EL1_FIELD: Field<Self, bool, 1, 1> = Field::new("HFGRTR_EL2", "AFSR1_EL1");
+    /// Descriptor of the `AIDR_EL1` field, for use with [`FieldAccess`].
+    pub const AIDR_EL1_FIELD: Field<Self, bool, 2, 1> = Field::new("HFGRTR_EL2", "AIDR_EL1");
+    /// Descriptor of the `AMAIR_EL1` field, for use with [`FieldAccess`].
+    pub const AMAIR_EL1_FIELD: Field<Self, bool, 3, 1> = Field::new("HFGRTR_EL2", "AMAIR_EL1");
+    /// Descriptor of the `APDAKey` field, for use with [`FieldAccess`].
+    pub const APDAKEY_FIELD: Field<Self, bool, 4, 1> = Field::new("HFGRTR_EL2", "APDAKey");
+    /// Descriptor of the `APDBKey` field, for use with [`FieldAccess`].
+    pub const APDBKEY_FIELD: Field<Self, bool, 5, 1> = Field::new("HFGRTR_EL2", "APDBKey");
+    /// Descriptor of the `APGAKey` field, for use with [`FieldAccess`].
+    pub const APGAKEY_FIELD: Field<Self, bool, 6, 1> = Field::new("HFGRTR_EL2", "APGAKey");
+    /// Descriptor of the `APIAKey` field, for use with [`FieldAccess`].
+    pub const APIAKEY_FIELD: Field<Self, bool, 7, 1> = Field::new("HFGRTR_EL2", "APIAKey");
+    /// Descriptor of the `APIBKey` field, for use with [`FieldAccess`].
+    pub const APIBKEY_FIELD: Field<Self, bool, 8, 1> = Field::new("HFGRTR_EL2", "APIBKey");
+    /// Descriptor of the `CCSIDR_EL1` field, for use with [`FieldAccess`].
+    pub const CCSIDR_EL1_FIELD: Field<Self, bool, 9, 1> = Field::new("HFGRTR_EL2", "CCSIDR_EL1");
+    /// Descriptor of the `CLIDR_EL1` field, for use with [`FieldAccess`].
+    pub const CLIDR_EL1_FIELD: Field<Self, bool, 10, 1> = Field::new("HFGRTR_EL2", "CLIDR_EL1");
+    /// Descriptor of the `CONTEXTIDR_EL1` field, for use with [`FieldAccess`].
+    pub const CONTEXTIDR_EL1_FIELD: Field<Self, bool, 11, 1> =
+        Field::new("HFGRTR_EL2", "CONTEXTIDR_EL1");
+    /// Descriptor of the `CPACR_EL1` field, for use with [`FieldAccess`].
+    pub const CPACR_EL1_FIELD: Field<Self, bool, 12, 1> = Field::new("HFGRTR_EL2", "CPACR_EL1");
+    /// Descriptor of the `CSSELR_EL1` field, for use with [`FieldAccess`].
+    pub const CSSELR_EL1_FIELD: Field<Self, bool, 13, 1> = Field::new("HFGRTR_EL2", "CSSELR_EL1");
+    /// Descriptor of the `CTR_EL0` field, for use with [`FieldAccess`].
+    pub const CTR_EL0_FIELD: Field<Self, bool, 14, 1> = Field::new("HFGRTR_EL2", "CTR_EL0");
+    /// Descriptor of the `DCZID_EL0` field, for use with [`FieldAccess`].
+    pub const DCZID_EL0_FIELD: Field<Self, bool, 15, 1> = Field::new("HFGRTR_EL2", "DCZID_EL0");
+    /// Descriptor of the `ESR_EL1` field, for use with [`FieldAccess`].
+    pub const ESR_EL1_FIELD: Field<Self, bool, 16, 1> = Field::new("HFGRTR_EL2", "ESR_EL1");
+    /// Descriptor of the `FAR_EL1` field, for use with [`FieldAccess`].
+    pub const FAR_EL1_FIELD: Field<Self, bool, 17, 1> = Field::new("HFGRTR_EL2", "FAR_EL1");
+    /// Descriptor of the `ISR_EL1` field, for use with [`FieldAccess`].
+    pub const ISR_EL1_FIELD: Field<Self, bool, 18, 1> = Field::new("HFGRTR_EL2", "ISR_EL1");
+    /// Descriptor of the `LORC_EL1` field, for use with [`FieldAccess`].
+    pub const LORC_EL1_FIELD: Field<Self, bool, 19, 1> = Field::new("HFGRTR_EL2", "LORC_EL1");
+    /// Descriptor of the `LOREA_EL1` field, for use with [`FieldAccess`].
+    pub const LOREA_EL1_FIELD: Field<Self, bool, 20, 1> = Field::new("HFGRTR_EL2", "LOREA_EL1");
+    /// Descriptor of the `LORID_EL1` field, for use with [`FieldAccess`].
+    pub const LORID_EL1_FIELD: Field<Self, bool, 21, 1> = Field::new("HFGRTR_EL2", "LORID_EL1");
+    /// Descriptor of the `LORN_EL1` field, for use with [`FieldAccess`].
+    pub const LORN_EL1_FIELD: Field<Self, bool, 22, 1> = Field::new("HFGRTR_EL2", "LORN_EL1");
+    /// Descriptor of the `LORSA_EL1` field, for use with [`FieldAccess`].
+    pub const LORSA_EL1_FIELD: Field<Self, bool, 23, 1> = Field::new("HFGRTR_EL2", "LORSA_EL1");
+    /// Descriptor of the `MAIR_EL1` field, for use with [`FieldAccess`].
+    pub const MAIR_EL1_FIELD: Field<Self, bool, 24, 1> = Field::new("HFGRTR_EL2", "MAIR_EL1");
+    /// Descriptor of the `MIDR_EL1` field, for use with [`FieldAccess`].
+    pub const MIDR_EL1_FIELD: Field<Self, bool, 25, 1> = Field::new("HFGRTR_EL2", "MIDR_EL1");
+    /// Descriptor of the `MPIDR_EL1` field, for use with [`FieldAccess`].
+    pub const MPIDR_EL1_FIELD: Field<Self, bool, 26, 1> = Field::new("HFGRTR_EL2", "MPIDR_EL1");
+    /// Descriptor of the `PAR_EL1` field, for use with [`FieldAccess`].
+    pub const PAR_EL1_FIELD: Field<Self, bool, 27, 1> = Field::new("HFGRTR_EL2", "PAR_EL1");
+    /// Descriptor of the `REVIDR_EL1` field, for use with [`FieldAccess`].
+    pub const REVIDR_EL1_FIELD: Field<Self, bool, 28, 1> = Field::new("HFGRTR_EL2", "REVIDR_EL1");
+    /// Descriptor of the `SCTLR_EL1` field, for use with [`FieldAccess`].
+    pub const SCTLR_EL1_FIELD: Field<Self, bool, 29, 1> = Field::new("HFGRTR_EL2", "SCTLR_EL1");
+    /// Descriptor of the `SCXTNUM_EL1` field, for use with [`FieldAccess`].
+    pub const SCXTNUM_EL1_FIELD: Field<Self, bool, 30, 1> = Field::new("HFGRTR_EL2", "SCXTNUM_EL1");
+    /// Descriptor of the `SCXTNUM_EL0` field, for use with [`FieldAccess`].
+    pub const SCXTNUM_EL0_FIELD: Field<Self, bool, 31, 1> = Field::new("HFGRTR_EL2", "SCXTNUM_EL0");
+    /// Descriptor of the `TCR_EL1` field, for use with [`FieldAccess`].
+    pub const TCR_EL1_FIELD: Field<Self, bool, 32, 1> = Field::new("HFGRTR_EL2", "TCR_EL1");
+    /// Descriptor of the `TPIDR_EL1` field, for use with [`FieldAccess`].
+    pub const TPIDR_EL1_FIELD: Field<Self, bool, 33, 1> = Field::new("HFGRTR_EL2", "TPIDR_EL1");
+    /// Descriptor of the `TPIDRRO_EL0` field, for use with [`FieldAccess`].
+    pub const TPIDRRO_EL0_FIELD: Field<Self, bool, 34, 1> = Field::new("HFGRTR_EL2", "TPIDRRO_EL0");
+    /// Descriptor of the `TPIDR_EL0` field, for use with [`FieldAccess`].
+    pub const TPIDR_EL0_FIELD: Field<Self, bool, 35, 1> = Field::new("HFGRTR_EL2", "TPIDR_EL0");
+    /// Descriptor of the `TTBR0_EL1` field, for use with [`FieldAccess`].
+    pub const TTBR0_EL1_FIELD: Field<Self, bool, 36, 1> = Field::new("HFGRTR_EL2", "TTBR0_EL1");
+    /// Descriptor of the `TTBR1_EL1` field, for use with [`FieldAccess`].
+    pub const TTBR1_EL1_FIELD: Field<Self, bool, 37, 1> = Field::new("HFGRTR_EL2", "TTBR1_EL1");
+    /// Descriptor of the `VBAR_EL1` field, for use with [`FieldAccess`].
+    pub const VBAR_EL1_FIELD: Field<Self, bool, 38, 1> = Field::new("HFGRTR_EL2", "VBAR_EL1");
+    /// Descriptor of the `ICC_IGRPENn_EL1` field, for use with [`FieldAccess`].
+    pub const ICC_IGRPENN_EL1_FIELD: Field<Self, bool, 39, 1> =
+        Field::new("HFGRTR_EL2", "ICC_IGRPENn_EL1");
+    /// Descriptor of the `ERRIDR_EL1` field, for use with [`FieldAccess`].
+    pub const ERRIDR_EL1_FIELD: Field<Self, bool, 40, 1> = Field::new("HFGRTR_EL2", "ERRIDR_EL1");
+    /// Descriptor of the `ERRSELR_EL1` field, for use with [`FieldAccess`].
+    pub const ERRSELR_EL1_FIELD: Field<Self, bool, 41, 1> = Field::new("HFGRTR_EL2", "ERRSELR_EL1");
+    /// Descriptor of the `ERXFR_EL1` field, for use with [`FieldAccess`].
+    pub const ERXFR_EL1_FIELD: Field<Self, bool, 42, 1> = Field::new("HFGRTR_EL2", "ERXFR_EL1");
+    /// Descriptor of the `ERXCTLR_EL1` field, for use with [`FieldAccess`].
+    pub const ERXCTLR_EL1_FIELD: Field<Self, bool, 43, 1> = Field::new("HFGRTR_EL2", "ERXCTLR_EL1");
+    /// Descriptor of the `ERXSTATUS_EL1` field, for use with [`FieldAccess`].
+    pub const ERXSTATUS_EL1_FIELD: Field<Self, bool, 44, 1> =
+        Field::new("HFGRTR_EL2", "ERXSTATUS_EL1");
+    /// Descriptor of the `ERXMISCn_EL1` field, for use with [`FieldAccess`].
+    pub const ERXMISCN_EL1_FIELD: Field<Self, bool, 45, 1> =
+        Field::new("HFGRTR_EL2", "ERXMISCn_EL1");
+    /// Descriptor of the `ERXPFGF_EL1` field, for use with [`FieldAccess`].
+    pub const ERXPFGF_EL1_FIELD: Field<Self, bool, 46, 1> = Field::new("HFGRTR_EL2", "ERXPFGF_EL1");
+    /// Descriptor of the `ERXPFGCTL_EL1` field, for use with [`FieldAccess`].
+    pub const ERXPFGCTL_EL1_FIELD: Field<Self, bool, 47, 1> =
+        Field::new("HFGRTR_EL2", "ERXPFGCTL_EL1");
+    /// Descriptor of the `ERXPFGCDN_EL1` field, for use with [`FieldAccess`].
+    pub const ERXPFGCDN_EL1_FIELD: Field<Self, bool, 48, 1> =
+        Field::new("HFGRTR_EL2", "ERXPFGCDN_EL1");
+    /// Descriptor of the `ERXADDR_EL1` field, for use with [`FieldAccess`].
+    pub const ERXADDR_EL1_FIELD: Field<Self, bool, 49, 1> = Field::new("HFGRTR_EL2", "ERXADDR_EL1");
+    /// Descriptor of the `nACCDATA_EL1` field, for use with [`FieldAccess`].
+    pub const NACCDATA_EL1_FIELD: Field<Self, bool, 50, 1> =
+        Field::new("HFGRTR_EL2", "nACCDATA_EL1");
+    /// Descriptor of the `nGCS_EL0` field, for use with [`FieldAccess`].
+    pub const NGCS_EL0_FIELD: Field<Self, bool, 52, 1> = Field::new("HFGRTR_EL2", "nGCS_EL0");
+    /// Descriptor of the `nGCS_EL1` field, for use with [`FieldAccess`].
+    pub const NGCS_EL1_FIELD: Field<Self, bool, 53, 1> = Field::new("HFGRTR_EL2", "nGCS_EL1");
+    /// Descriptor of the `nSMPRI_EL1` field, for use with [`FieldAccess`].
+    pub const NSMPRI_EL1_FIELD: Field<Self, bool, 54, 1> = Field::new("HFGRTR_EL2", "nSMPRI_EL1");
+    /// Descriptor of the `nTPIDR2_EL0` field, for use with [`FieldAccess`].
+    pub const NTPIDR2_EL0_FIELD: Field<Self, bool, 55, 1> = Field::new("HFGRTR_EL2", "nTPIDR2_EL0");
+    /// Descriptor of the `nRCWMASK_EL1` field, for use with [`FieldAccess`].
+    pub const NRCWMASK_EL1_FIELD: Field<Self, bool, 56, 1> =
+        Field::new("HFGRTR_EL2", "nRCWMASK_EL1");
+    /// Descriptor of the `nPIRE0_EL1` field, for use with [`FieldAccess`].
+    pub const NPIRE0_EL1_FIELD: Field<Self, bool, 57, 1> = Field::new("HFGRTR_EL2", "nPIRE0_EL1");
+    /// Descriptor of the `nPIR_EL1` field, for use with [`FieldAccess`].
+    pub const NPIR_EL1_FIELD: Field<Self, bool, 58, 1> = Field::new("HFGRTR_EL2", "nPIR_EL1");
+    /// Descriptor of the `nPOR_EL0` field, for use with [`FieldAccess`].
+    pub const NPOR_EL0_FIELD: Field<Self, bool, 59, 1> = Field::new("HFGRTR_EL2", "nPOR_EL0");
+    /// Descriptor of the `nPOR_EL1` field, for use with [`FieldAccess`].
+    pub const NPOR_EL1_FIELD: Field<Self, bool, 60, 1> = Field::new("HFGRTR_EL2", "nPOR_EL1");
+    /// Descriptor of the `nS2POR_EL1` field, for use with [`FieldAccess`].
+    pub const NS2POR_EL1_FIELD: Field<Self, bool, 61, 1> = Field::new("HFGRTR_EL2", "nS2POR_EL1");
+    /// Descriptor of the `nMAIR2_EL1` field, for use with [`FieldAccess`].
+    pub const NMAIR2_EL1_FIELD: Field<Self, bool, 62, 1> = Field::new("HFGRTR_EL2", "nMAIR2_EL1");
+    /// Descriptor of the `nAMAIR2_EL1` field, for use with [`FieldAccess`].
+    pub const NAMAIR2_EL1_FIELD: Field<Self, bool, 63, 1> = Field::new("HFGRTR_EL2", "nAMAIR2_EL1");
+}
+
+#[cfg(feature = "el2")]
+impl Display for HfgrtrEl2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("HfgrtrEl2");
+        let bits = self.bits();
+        s.field("AFSR0_EL1", &Self::AFSR0_EL1_FIELD.extract(bits));
+        s.field("AFSR1_EL1", &Self::AFSR1_EL1_FIELD.extract(bits));
+        s.field("AIDR_EL1", &Self::AIDR_EL1_FIELD.extract(bits));
+        s.field("AMAIR_EL1", &Self::AMAIR_EL1_FIELD.extract(bits));
+        s.field("APDAKey", &Self::APDAKEY_FIELD.extract(bits));
+        s.field("APDBKey", &Self::APDBKEY_FIELD.extract(bits));
+        s.field("APGAKey", &Self::APGAKEY_FIELD.extract(bits));
+        s.field("APIAKey", &Self::APIAKEY_FIELD.extract(bits));
+        s.field("APIBKey", &Self::APIBKEY_FIELD.extract(bits));
+        s.field("CCSIDR_EL1", &Self::CCSIDR_EL1_FIELD.extract(bits));
+        s.field("CLIDR_EL1", &Self::CLIDR_EL1_FIELD.extract(bits));
+        s.field("CONTEXTIDR_EL1", &Self::CONTEXTIDR_EL1_FIELD.extract(bits));
+        s.field("CPACR_EL1", &Self::CPACR_EL1_FIELD.extract(bits));
+        s.field("CSSELR_EL1", &Self::CSSELR_EL1_FIELD.extract(bits));
+        s.field("CTR_EL0", &Self::CTR_EL0_FIELD.extract(bits));
+        s.field("DCZID_EL0", &Self::DCZID_EL0_FIELD.extract(bits));
+        s.field("ESR_EL1", &Self::ESR_EL1_FIELD.extract(bits));
+        s.field("FAR_EL1", &Self::FAR_EL1_FIELD.extract(bits));
+        s.field("ISR_EL1", &Self::ISR_EL1_FIELD.extract(bits));
+        s.field("LORC_EL1", &Self::LORC_EL1_FIELD.extract(bits));
+        s.field("LOREA_EL1", &Self::LOREA_EL1_FIELD.extract(bits));
+        s.field("LORID_EL1", &Self::LORID_EL1_FIELD.extract(bits));
+        s.field("LORN_EL1", &Self::LORN_EL1_FIELD.extract(bits));
+        s.field("LORSA_EL1", &Self::LORSA_EL1_FIELD.extract(bits));
+        s.field("MAIR_EL1", &Self::MAIR_EL1_FIELD.extract(bits));
+        s.field("MIDR_EL1", &Self::MIDR_EL1_FIELD.extract(bits));
+        s.field("MPIDR_EL1", &Self::MPIDR_EL1_FIELD.extract(bits));
+        s.field("PAR_EL1", &Self::PAR_EL1_FIELD.extract(bits));
+        s.field("REVIDR_EL1", &Self::REVIDR_EL1_FIELD.extract(bits));
+        s.field("SCTLR_EL1", &Self::SCTLR_EL1_FIELD.extract(bits));
+        s.field("SCXTNUM_EL1", &Self::SCXTNUM_EL1_FIELD.extract(bits));
+        s.field("SCXTNUM_EL0", &Self::SCXTNUM_EL0_FIELD.extract(bits));
+        s.field("TCR_EL1", &Self::TCR_EL1_FIELD.extract(bits));
+        s.field("TPIDR_EL1", &Self::TPIDR_EL1_FIELD.extract(bits));
+        s.field("TPIDRRO_EL0", &Self::TPIDRRO_EL0_FIELD.extract(bits));
+        s.field("TPIDR_EL0", &Self::TPIDR_EL0_FIELD.extract(bits));
+        s.field("TTBR0_EL1", &Self::TTBR0_EL1_FIELD.extract(bits));
+        s.field("TTBR1_EL1", &Self::TTBR1_EL1_FIELD.extract(bits));
+        s.field("VBAR_EL1", &Self::VBAR_EL1_FIELD.extract(bits));
+        s.field(
+            "ICC_IGRPENn_EL1",
+            &Self::ICC_IGRPENN_EL1_FIELD.extract(bits),
+        );
+        s.field("ERRIDR_EL1", &Self::ERRIDR_EL1_FIELD.extract(bits));
+        s.field("ERRSELR_EL1", &Self::ERRSELR_EL1_FIELD.extract(bits));
+        s.field("ERXFR_EL1", &Self::ERXFR_EL1_FIELD.extract(bits));
+        s.field("ERXCTLR_EL1", &Self::ERXCTLR_EL1_FIELD.extract(bits));
+        s.field("ERXSTATUS_EL1", &Self::ERXSTATUS_EL1_FIELD.extract(bits));
+        s.field("ERXMISCn_EL1", &Self::ERXMISCN_EL1_FIELD.extract(bits));
+        s.field("ERXPFGF_EL1", &Self::ERXPFGF_EL1_FIELD.extract(bits));
+        s.field("ERXPFGCTL_EL1", &Self::ERXPFGCTL_EL1_FIELD.extract(bits));
+        s.field("ERXPFGCDN_EL1", &Self::ERXPFGCDN_EL1_FIELD.extract(bits));
+        s.field("ERXADDR_EL1", &Self::ERXADDR_EL1_FIELD.extract(bits));
+        s.field("nACCDATA_EL1", &Self::NACCDATA_EL1_FIELD.extract(bits));
+        s.field("nGCS_EL0", &Self::NGCS_EL0_FIELD.extract(bits));
+        s.field("nGCS_EL1", &Self::NGCS_EL1_FIELD.extract(bits));
+        s.field("nSMPRI_EL1", &Self::NSMPRI_EL1_FIELD.extract(bits));
+        s.field("nTPIDR2_EL0", &Self::NTPIDR2_EL0_FIELD.extract(bits));
+        s.field("nRCWMASK_EL1", &Self::NRCWMASK_EL1_FIELD.extract(bits));
+        s.field("nPIRE0_EL1", &Self::NPIRE0_EL1_FIELD.extract(bits));
+        s.field("nPIR_EL1", &Self::NPIR_EL1_FIELD.extract(bits));
+        s.field("nPOR_EL0", &Self::NPOR_EL0_FIELD.extract(bits));
+        s.field("nPOR_EL1", &Self::NPOR_EL1_FIELD.extract(bits));
+        s.field("nS2POR_EL1", &Self::NS2POR_EL1_FIELD.extract(bits));
+        s.field("nMAIR2_EL1", &Self::NMAIR2_EL1_FIELD.extract(bits));
+        s.field("nAMAIR2_EL1", &Self::NAMAIR2_EL1_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for HfgrtrEl2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "HfgrtrEl2 {{ AFSR0_EL1: {=u64}, AFSR1_EL1: {=u64}, AIDR_EL1: {=u64}, AMAIR_EL1: {=u64}, APDAKey: {=u64}, APDBKey: {=u64}, APGAKey: {=u64}, APIAKey: {=u64}, APIBKey: {=u64}, CCSIDR_EL1: {=u64}, CLIDR_EL1: {=u64}, CONTEXTIDR_EL1: {=u64}, CPACR_EL1: {=u64}, CSSELR_EL1: {=u64}, CTR_EL0: {=u64}, DCZID_EL0: {=u64}, ESR_EL1: {=u64}, FAR_EL1: {=u64}, ISR_EL1: {=u64}, LORC_EL1: {=u64}, LOREA_EL1: {=u64}, LORID_EL1: {=u64}, LORN_EL1: {=u64}, LORSA_EL1: {=u64}, MAIR_EL1: {=u64}, MIDR_EL1: {=u64}, MPIDR_EL1: {=u64}, PAR_EL1: {=u64}, REVIDR_EL1: {=u64}, SCTLR_EL1: {=u64}, SCXTNUM_EL1: {=u64}, SCXTNUM_EL0: {=u64}, TCR_EL1: {=u64}, TPIDR_EL1: {=u64}, TPIDRRO_EL0: {=u64}, TPIDR_EL0: {=u64}, TTBR0_EL1: {=u64}, TTBR1_EL1: {=u64}, VBAR_EL1: {=u64}, ICC_IGRPENn_EL1: {=u64}, ERRIDR_EL1: {=u64}, ERRSELR_EL1: {=u64}, ERXFR_EL1: {=u64}, ERXCTLR_EL1: {=u64}, ERXSTATUS_EL1: {=u64}, ERXMISCn_EL1: {=u64}, ERXPFGF_EL1: {=u64}, ERXPFGCTL_EL1: {=u64}, ERXPFGCDN_EL1: {=u64}, ERXADDR_EL1: {=u64}, nACCDATA_EL1: {=u64}, nGCS_EL0: {=u64}, nGCS_EL1: {=u64}, nSMPRI_EL1: {=u64}, nTPIDR2_EL0: {=u64}, nRCWMASK_EL1: {=u64}, nPIRE0_EL1: {=u64}, nPIR_EL1: {=u64}, nPOR_EL0: {=u64}, nPOR_EL1: {=u64}, nS2POR_EL1: {=u64}, nMAIR2_EL1: {=u64}, nAMAIR2_EL1: {=u64} }}",
+            Self::AFSR0_EL1_FIELD.extract(self.bits()),
+            Self::AFSR1_EL1_FIELD.extract(self.bits()),
+            Self::AIDR_EL1_FIELD.extract(self.bits()),
+            Self::AMAIR_EL1_FIELD.extract(self.bits()),
+            Self::APDAKEY_FIELD.extract(self.bits()),
+            Self::APDBKEY_FIELD.extract(self.bits()),
+            Self::APGAKEY_FIELD.extract(self.bits()),
+            Self::APIAKEY_FIELD.extract(self.bits()),
+            Self::APIBKEY_FIELD.extract(self.bits()),
+            Self::CCSIDR_EL1_FIELD.extract(self.bits()),
+            Self::CLIDR_EL1_FIELD.extract(self.bits()),
+            Self::CONTEXTIDR_EL1_FIELD.extract(self.bits()),
+            Self::CPACR_EL1_FIELD.extract(self.bits()),
+            Self::CSSELR_EL1_FIELD.extract(self.bits()),
+            Self::CTR_EL0_FIELD.extract(self.bits()),
+            Self::DCZID_EL0_FIELD.extract(self.bits()),
+            Self::ESR_EL1_FIELD.extract(self.bits()),
+            Self::FAR_EL1_FIELD.extract(self.bits()),
+            Self::ISR_EL1_FIELD.extract(self.bits()),
+            Self::LORC_EL1_FIELD.extract(self.bits()),
+            Self::LOREA_EL1_FIELD.extract(self.bits()),
+            Self::LORID_EL1_FIELD.extract(self.bits()),
+            Self::LORN_EL1_FIELD.extract(self.bits()),
+            Self::LORSA_EL1_FIELD.extract(self.bits()),
+            Self::MAIR_EL1_FIELD.extract(self.bits()),
+            Self::MIDR_EL1_FIELD.extract(self.bits()),
+            Self::MPIDR_EL1_FIELD.extract(self.bits()),
+            Self::PAR_EL1_FIELD.extract(self.bits()),
+            Self::REVIDR_EL1_FIELD.extract(self.bits()),
+            Self::SCTLR_EL1_FIELD.extract(self.bits()),
+            Self::SCXTNUM_EL1_FIELD.extract(self.bits()),
+            Self::SCXTNUM_EL0_FIELD.extract(self.bits()),
+            Self::TCR_EL1_FIELD.extract(self.bits()),
+            Self::TPIDR_EL1_FIELD.extract(self.bits()),
+            Self::TPIDRRO_EL0_FIELD.extract(self.bits()),
+            Self::TPIDR_EL0_FIELD.extract(self.bits()),
+            Self::TTBR0_EL1_FIELD.extract(self.bits()),
+            Self::TTBR1_EL1_FIELD.extract(self.bits()),
+            Self::VBAR_EL1_FIELD.extract(self.bits()),
+            Self::ICC_IGRPENN_EL1_FIELD.extract(self.bits()),
+            Self::ERRIDR_EL1_FIELD.extract(self.bits()),
+            Self::ERRSELR_EL1_FIELD.extract(self.bits()),
+            Self::ERXFR_EL1_FIELD.extract(self.bits()),
+            Self::ERXCTLR_EL1_FIELD.extract(self.bits()),
+            Self::ERXSTATUS_EL1_FIELD.extract(self.bits()),
+            Self::ERXMISCN_EL1_FIELD.extract(self.bits()),
+            Self::ERXPFGF_EL1_FIELD.extract(self.bits()),
+            Self::ERXPFGCTL_EL1_FIELD.extract(self.bits()),
+            Self::ERXPFGCDN_EL1_FIELD.extract(self.bits()),
+            Self::ERXADDR_EL1_FIELD.extract(self.bits()),
+            Self::NACCDATA_EL1_FIELD.extract(self.bits()),
+            Self::NGCS_EL0_FIELD.extract(self.bits()),
+            Self::NGCS_EL1_FIELD.extract(self.bits()),
+            Self::NSMPRI_EL1_FIELD.extract(self.bits()),
+            Self::NTPIDR2_EL0_FIELD.extract(self.bits()),
+            Self::NRCWMASK_EL1_FIELD.extract(self.bits()),
+            Self::NPIRE0_EL1_FIELD.extract(self.bits()),
+            Self::NPIR_EL1_FIELD.extract(self.bits()),
+            Self::NPOR_EL0_FIELD.extract(self.bits()),
+            Self::NPOR_EL1_FIELD.extract(self.bits()),
+            Self::NS2POR_EL1_FIELD.extract(self.bits()),
+            Self::NMAIR2_EL1_FIELD.extract(self.bits()),
+            Self::NAMAIR2_EL1_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for HfgrtrEl2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for HfgrtrEl2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl HfgrtrEl2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("AFSR0_EL1"),
+                old: Self::AFSR0_EL1_FIELD.extract(self.bits()),
+                new: Self::AFSR0_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("AFSR1_EL1"),
+                old: Self::AFSR1_EL1_FIELD.extract(self.bits()),
+                new: Self::AFSR1_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("AIDR_EL1"),
+                old: Self::AIDR_EL1_FIELD.extract(self.bits()),
+                new: Self::AIDR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("AMAIR_EL1"),
+                old: Self::AMAIR_EL1_FIELD.extract(self.bits()),
+                new: Self::AMAIR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("APDAKey"),
+                old: Self::APDAKEY_FIELD.extract(self.bits()),
+                new: Self::APDAKEY_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("APDBKey"),
+                old: Self::APDBKEY_FIELD.extract(self.bits()),
+                new: Self::APDBKEY_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("APGAKey"),
+                old: Self::APGAKEY_FIELD.extract(self.bits()),
+                new: Self::APGAKEY_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("APIAKey"),
+                old: Self::APIAKEY_FIELD.extract(self.bits()),
+                new: Self::APIAKEY_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("APIBKey"),
+                old: Self::APIBKEY_FIELD.extract(self.bits()),
+                new: Self::APIBKEY_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CCSIDR_EL1"),
+                old: Self::CCSIDR_EL1_FIELD.extract(self.bits()),
+                new: Self::CCSIDR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CLIDR_EL1"),
+                old: Self::CLIDR_EL1_FIELD.extract(self.bits()),
+                new: Self::CLIDR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CONTEXTIDR_EL1"),
+                old: Self::CONTEXTIDR_EL1_FIELD.extract(self.bits()),
+                new: Self::CONTEXTIDR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CPACR_EL1"),
+                old: Self::CPACR_EL1_FIELD.extract(self.bits()),
+                new: Self::CPACR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CSSELR_EL1"),
+                old: Self::CSSELR_EL1_FIELD.extract(self.bits()),
+                new: Self::CSSELR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CTR_EL0"),
+                old: Self::CTR_EL0_FIELD.extract(self.bits()),
+                new: Self::CTR_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DCZID_EL0"),
+                old: Self::DCZID_EL0_FIELD.extract(self.bits()),
+                new: Self::DCZID_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ESR_EL1"),
+                old: Self::ESR_EL1_FIELD.extract(self.bits()),
+                new: Self::ESR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FAR_EL1"),
+                old: Self::FAR_EL1_FIELD.extract(self.bits()),
+                new: Self::FAR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ISR_EL1"),
+                old: Self::ISR_EL1_FIELD.extract(self.bits()),
+                new: Self::ISR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("LORC_EL1"),
+                old: Self::LORC_EL1_FIELD.extract(self.bits()),
+                new: Self::LORC_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("LOREA_EL1"),
+                old: Self::LOREA_EL1_FIELD.extract(self.bits()),
+                new: Self::LOREA_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("LORID_EL1"),
+                old: Self::LORID_EL1_FIELD.extract(self.bits()),
+                new: Self::LORID_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("LORN_EL1"),
+                old: Self::LORN_EL1_FIELD.extract(self.bits()),
+                new: Self::LORN_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("LORSA_EL1"),
+                old: Self::LORSA_EL1_FIELD.extract(self.bits()),
+                new: Self::LORSA_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("MAIR_EL1"),
+                old: Self::MAIR_EL1_FIELD.extract(self.bits()),
+                new: Self::MAIR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("MIDR_EL1"),
+                old: Self::MIDR_EL1_FIELD.extract(self.bits()),
+                new: Self::MIDR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("MPIDR_EL1"),
+                old: Self::MPIDR_EL1_FIELD.extract(self.bits()),
+                new: Self::MPIDR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PAR_EL1"),
+                old: Self::PAR_EL1_FIELD.extract(self.bits()),
+                new: Self::PAR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("REVIDR_EL1"),
+                old: Self::REVIDR_EL1_FIELD.extract(self.bits()),
+                new: Self::REVIDR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SCTLR_EL1"),
+                old: Self::SCTLR_EL1_FIELD.extract(self.bits()),
+                new: Self::SCTLR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SCXTNUM_EL1"),
+                old: Self::SCXTNUM_EL1_FIELD.extract(self.bits()),
+                new: Self::SCXTNUM_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SCXTNUM_EL0"),
+                old: Self::SCXTNUM_EL0_FIELD.extract(self.bits()),
+                new: Self::SCXTNUM_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TCR_EL1"),
+                old: Self::TCR_EL1_FIELD.extract(self.bits()),
+                new: Self::TCR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TPIDR_EL1"),
+                old: Self::TPIDR_EL1_FIELD.extract(self.bits()),
+                new: Self::TPIDR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TPIDRRO_EL0"),
+                old: Self::TPIDRRO_EL0_FIELD.extract(self.bits()),
+                new: Self::TPIDRRO_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TPIDR_EL0"),
+                old: Self::TPIDR_EL0_FIELD.extract(self.bits()),
+                new: Self::TPIDR_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TTBR0_EL1"),
+                old: Self::TTBR0_EL1_FIELD.extract(self.bits()),
+                new: Self::TTBR0_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TTBR1_EL1"),
+                old: Self::TTBR1_EL1_FIELD.extract(self.bits()),
+                new: Self::TTBR1_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VBAR_EL1"),
+                old: Self::VBAR_EL1_FIELD.extract(self.bits()),
+                new: Self::VBAR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ICC_IGRPENn_EL1"),
+                old: Self::ICC_IGRPENN_EL1_FIELD.extract(self.bits()),
+                new: Self::ICC_IGRPENN_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ERRIDR_EL1"),
+                old: Self::ERRIDR_EL1_FIELD.extract(self.bits()),
+                new: Self::ERRIDR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ERRSELR_EL1"),
+                old: Self::ERRSELR_EL1_FIELD.extract(self.bits()),
+                new: Self::ERRSELR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ERXFR_EL1"),
+                old: Self::ERXFR_EL1_FIELD.extract(self.bits()),
+                new: Self::ERXFR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ERXCTLR_EL1"),
+                old: Self::ERXCTLR_EL1_FIELD.extract(self.bits()),
+                new: Self::ERXCTLR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ERXSTATUS_EL1"),
+                old: Self::ERXSTATUS_EL1_FIELD.extract(self.bits()),
+                new: Self::ERXSTATUS_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ERXMISCn_EL1"),
+                old: Self::ERXMISCN_EL1_FIELD.extract(self.bits()),
+                new: Self::ERXMISCN_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ERXPFGF_EL1"),
+                old: Self::ERXPFGF_EL1_FIELD.extract(self.bits()),
+                new: Self::ERXPFGF_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ERXPFGCTL_EL1"),
+                old: Self::ERXPFGCTL_EL1_FIELD.extract(self.bits()),
+                new: Self::ERXPFGCTL_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ERXPFGCDN_EL1"),
+                old: Self::ERXPFGCDN_EL1_FIELD.extract(self.bits()),
+                new: Self::ERXPFGCDN_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ERXADDR_EL1"),
+                old: Self::ERXADDR_EL1_FIELD.extract(self.bits()),
+                new: Self::ERXADDR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nACCDATA_EL1"),
+                old: Self::NACCDATA_EL1_FIELD.extract(self.bits()),
+                new: Self::NACCDATA_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nGCS_EL0"),
+                old: Self::NGCS_EL0_FIELD.extract(self.bits()),
+                new: Self::NGCS_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nGCS_EL1"),
+                old: Self::NGCS_EL1_FIELD.extract(self.bits()),
+                new: Self::NGCS_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nSMPRI_EL1"),
+                old: Self::NSMPRI_EL1_FIELD.extract(self.bits()),
+                new: Self::NSMPRI_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nTPIDR2_EL0"),
+                old: Self::NTPIDR2_EL0_FIELD.extract(self.bits()),
+                new: Self::NTPIDR2_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nRCWMASK_EL1"),
+                old: Self::NRCWMASK_EL1_FIELD.extract(self.bits()),
+                new: Self::NRCWMASK_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nPIRE0_EL1"),
+                old: Self::NPIRE0_EL1_FIELD.extract(self.bits()),
+                new: Self::NPIRE0_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nPIR_EL1"),
+                old: Self::NPIR_EL1_FIELD.extract(self.bits()),
+                new: Self::NPIR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nPOR_EL0"),
+                old: Self::NPOR_EL0_FIELD.extract(self.bits()),
+                new: Self::NPOR_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nPOR_EL1"),
+                old: Self::NPOR_EL1_FIELD.extract(self.bits()),
+                new: Self::NPOR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nS2POR_EL1"),
+                old: Self::NS2POR_EL1_FIELD.extract(self.bits()),
+                new: Self::NS2POR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nMAIR2_EL1"),
+                old: Self::NMAIR2_EL1_FIELD.extract(self.bits()),
+                new: Self::NMAIR2_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nAMAIR2_EL1"),
+                old: Self::NAMAIR2_EL1_FIELD.extract(self.bits()),
+                new: Self::NAMAIR2_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits()
+                    & 0b1000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000,
+                new: other.bits()
+                    & 0b1000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl HfgrtrEl2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl HfgrtrEl2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `HFGWTR2_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct Hfgwtr2El2: u64 {
+        /// `nPFAR_EL1` bit.
+        const NPFAR_EL1 = 1 << 0;
+        /// `nRCWSMASK_EL1` bit.
+        const NRCWSMASK_EL1 = 1 << 2;
+        /// `nCPACRMASK_EL1` bit.
+        const NCPACRMASK_EL1 = 1 << 3;
+        /// `nSCTLRMASK_EL1` bit.
+        const NSCTLRMASK_EL1 = 1 << 4;
+        /// `nSCTLR2MASK_EL1` bit.
+        const NSCTLR2MASK_EL1 = 1 << 5;
+        /// `nTCRMASK_EL1` bit.
+        const NTCRMASK_EL1 = 1 << 6;
+        /// `nTCR2MASK_EL1` bit.
+        const NTCR2MASK_EL1 = 1 << 7;
+        /// `nCPACRALIAS_EL1` bit.
+        const NCPACRALIAS_EL1 = 1 << 8;
+        /// `nSCTLRALIAS_EL1` bit.
+        const NSCTLRALIAS_EL1 = 1 << 9;
+        /// `nSCTLR2ALIAS_EL1` bit.
+        const NSCTLR2ALIAS_EL1 = 1 << 10;
+        /// `nTCRALIAS_EL1` bit.
+        const NTCRALIAS_EL1 = 1 << 11;
+        /// `nTCR2ALIAS_EL1` bit.
+        const NTCR2ALIAS_EL1 = 1 << 12;
+        /// `nACTLRMASK_EL1` bit.
+        const NACTLRMASK_EL1 = 1 << 13;
+        /// `nACTLRALIAS_EL1` bit.
+        const NACTLRALIAS_EL1 = 1 << 14;
+        /// `nTINDEX_EL0` bit.
+        const NTINDEX_EL0 = 1 << 15;
+        /// `nTINDEX_EL1` bit.
+        const NTINDEX_EL1 = 1 << 16;
+        /// `nSTINDEX_EL1` bit.
+        const NSTINDEX_EL1 = 1 << 17;
+        /// `nTTTBRP_EL1` bit.
+        const NTTTBRP_EL1 = 1 << 20;
+        /// `nTTTBRU_EL1` bit.
+        const NTTTBRU_EL1 = 1 << 21;
+        /// `nIRTBRP_EL1` bit.
+        const NIRTBRP_EL1 = 1 << 22;
+        /// `nIRTBRU_EL1` bit.
+        const NIRTBRU_EL1 = 1 << 23;
+        /// `nDPOTBR1_EL1` bit.
+        const NDPOTBR1_EL1 = 1 << 24;
+        /// `nDPOTBR0_EL1` bit.
+        const NDPOTBR0_EL1 = 1 << 25;
+        /// `nTPMIN1_EL1` bit.
+        const NTPMIN1_EL1 = 1 << 26;
+        /// `nTPMIN0_EL1` bit.
+        const NTPMIN0_EL1 = 1 << 27;
+        /// `nTPMIN1_EL0` bit.
+        const NTPMIN1_EL0 = 1 << 28;
+        /// `nTPMIN0_EL0` bit.
+        const NTPMIN0_EL0 = 1 << 29;
+        /// `TFSR_EL1` bit.
+        const TFSR_EL1 = 1 << 33;
+        /// `RGSR_EL1` bit.
+        const RGSR_EL1 = 1 << 34;
+        /// `GCR_EL1` bit.
+        const GCR_EL1 = 1 << 35;
+        /// `nTPIDR3_EL0` bit.
+        const NTPIDR3_EL0 = 1 << 36;
+        /// `nTPIDR3_EL1` bit.
+        const NTPIDR3_EL1 = 1 << 37;
+        /// `nLDSTT_EL1` bit.
+        const NLDSTT_EL1 = 1 << 38;
+        /// `ACTLR_EL1` bit.
+        const ACTLR_EL1 = 1 << 39;
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Hfgwtr2El2 {
+    /// Descriptor of the `nPFAR_EL1` field, for use with [`FieldAccess`].
+    pub const NPFAR_EL1_FIELD: Field<Self, bool, 0, 1> = Field::new("HFGWTR2_EL2", "nPFAR_EL1");
+    /// Descriptor of the `nRCWSMASK_EL1` field, for use with [`FieldAccess`].
+    pub const NRCWSMASK_EL1_FIELD: Field<Self, bool, 2, 1> =
+        Field::new("HFGWTR2_EL2", "nRCWSMASK_EL1");
+    /// Descriptor of the `nCPACRMASK_EL1` field, for use with [`FieldAccess`].
+    pub const NCPACRMASK_EL1_FIELD: Field<Self, bool, 3, 1> =
+        Field::new("HFGWTR2_EL2", "nCPACRMASK_EL1");
+    /// Descriptor of the `nSCTLRMASK_EL1` field, for use with [`FieldAccess`].
+    pub const NSCTLRMASK_EL1_FIELD: Field<Self, bool, 4, 1> =
+        Field::new("HFGWTR2_EL2", "nSCTLRMASK_EL1");
+    /// Descriptor of the `nSCTLR2MASK_EL1` field, for use with [`FieldAccess`].
+    pub const NSCTLR2MASK_EL1_FIELD: Field<Self, bool, 5, 1> =
+        Field::new("HFGWTR2_EL2", "nSCTLR2MASK_EL1");
+    /// Descriptor of the `nTCRMASK_EL1` field, for use with [`FieldAccess`].
+    pub const NTCRMASK_EL1_FIELD: Field<Self, bool, 6, 1> =
+        Field::new("HFGWTR2_EL2", "nTCRMASK_EL1");
+    /// Descriptor of the `nTCR2MASK_EL1` field, for use with [`FieldAccess`].
+    pub const NTCR2MASK_EL1_FIELD: Field<Self, bool, 7, 1> =
+        Field::new("HFGWTR2_EL2", "nTCR2MASK_EL1");
+    /// Descriptor of the `nCPACRALIAS_EL1` field, for use with [`FieldAccess`].
+    pub const NCPACRALIAS_EL1_FIELD: Field<Self, bool, 8, 1> =
+        Field::new("HFGWTR2_EL2", "nCPACRALIAS_EL1");
+    /// Descriptor of the `nSCTLRALIAS_EL1` field, for use with [`FieldAccess`].
+    pub const NSCTLRALIAS_EL1_FIELD: Field<Self, bool, 9, 1> =
+        Field::new("HFGWTR2_EL2", "nSCTLRALIAS_EL1");
+    /// Descriptor of the `nSCTLR2ALIAS_EL1` field, for use with [`FieldAccess`].
+    pub const NSCTLR2ALIAS_EL1_FIELD: Field<Self, bool, 10, 1> =
+        Field::new("HFGWTR2_EL2", "nSCTLR2ALIAS_EL1");
+    /// Descriptor of the `nTCRALIAS_EL1` field, for use with [`FieldAccess`].
+    pub const NTCRALIAS_EL1_FIELD: Field<Self, bool, 11, 1> =
+        Field::new("HFGWTR2_EL2", "nTCRALIAS_EL1");
+    /// Descriptor of the `nTCR2ALIAS_EL1` field, for use with [`FieldAccess`].
+    pub const NTCR2ALIAS_EL1_FIELD: Field<Self, bool, 12, 1> =
+        Field::new("HFGWTR2_EL2", "nTCR2ALIAS_EL1");
+    /// Descriptor of the `nACTLRMASK_EL1` field, for use with [`FieldAccess`].
+    pub const NACTLRMASK_EL1_FIELD: Field<Self, bool, 13, 1> =
+        Field::new("HFGWTR2_EL2", "nACTLRMASK_EL1");
+    /// Descriptor of the `nACTLRALIAS_EL1` field, for use with [`FieldAccess`].
+    pub const NACTLRALIAS_EL1_FIELD: Field<Self, bool, 14, 1> =
+        Field::new("HFGWTR2_EL2", "nACTLRALIAS_EL1");
+    /// Descriptor of the `nTINDEX_EL0` field, for use with [`FieldAccess`].
+    pub const NTINDEX_EL0_FIELD: Field<Self, bool, 15, 1> =
+        Field::new("HFGWTR2_EL2", "nTINDEX_EL0");
+    /// Descriptor of the `nTINDEX_EL1` field, for use with [`FieldAccess`].
+    pub const NTINDEX_EL1_FIELD: Field<Self, bool, 16, 1> =
+        Field::new("HFGWTR2_EL2", "nTINDEX_EL1");
+    /// Descriptor of the `nSTINDEX_EL1` field, for use with [`FieldAccess`].
+    pub const NSTINDEX_EL1_FIELD: Field<Self, bool, 17, 1> =
+        Field::new("HFGWTR2_EL2", "nSTINDEX_EL1");
+    /// Descriptor of the `nFGDTn_EL1` field, for use with [`FieldAccess`].
+    pub const NFGDTN_EL1_FIELD: Field<Self, u8, 18, 2> = Field::new("HFGWTR2_EL2", "nFGDTn_EL1");
+    /// Descriptor of the `nTTTBRP_EL1` field, for use with [`FieldAccess`].
+    pub const NTTTBRP_EL1_FIELD: Field<Self, bool, 20, 1> =
+        Field::new("HFGWTR2_EL2", "nTTTBRP_EL1");
+    /// Descriptor of the `nTTTBRU_EL1` field, for use with [`FieldAccess`].
+    pub const NTTTBRU_EL1_FIELD: Field<Self, bool, 21, 1> =
+        Field::new("HFGWTR2_EL2", "nTTTBRU_EL1");
+    /// Descriptor of the `nIRTBRP_EL1` field, for use with [`FieldAccess`].
+    pub const NIRTBRP_EL1_FIELD: Field<Self, bool, 22, 1> =
+        Field::new("HFGWTR2_EL2", "nIRTBRP_EL1");
+    /// Descriptor of the `nIRTBRU_EL1` field, for use with [`FieldAccess`].
+    pub const NIRTBRU_EL1_FIELD: Field<Self, bool, 23, 1> =
+        Field::new("HFGWTR2_EL2", "nIRTBRU_EL1");
+    /// Descriptor of the `nDPOTBR1_EL1` field, for use with [`FieldAccess`].
+    pub const NDPOTBR1_EL1_FIELD: Field<Self, bool, 24, 1> =
+        Field::new("HFGWTR2_EL2", "nDPOTBR1_EL1");
+    /// Descriptor of the `nDPOTBR0_EL1` field, for use with [`FieldAccess`].
+    pub const NDPOTBR0_EL1_FIELD: Field<Self, bool, 25, 1> =
+        Field::new("HFGWTR2_EL2", "nDPOTBR0_EL1");
+    /// Descriptor of the `nTPMIN1_EL1` field, for use with [`FieldAccess`].
+    pub const NTPMIN1_EL1_FIELD: Field<Self, bool, 26, 1> =
+        Field::new("HFGWTR2_EL2", "nTPMIN1_EL1");
+    /// Descriptor of the `nTPMIN0_EL1` field, for use with [`FieldAccess`].
+    pub const NTPMIN0_EL1_FIELD: Field<Self, bool, 27, 1> =
+        Field::new("HFGWTR2_EL2", "nTPMIN0_EL1");
+    /// Descriptor of the `nTPMIN1_EL0` field, for use with [`FieldAccess`].
+    pub const NTPMIN1_EL0_FIELD: Field<Self, bool, 28, 1> =
+        Field::new("HFGWTR2_EL2", "nTPMIN1_EL0");
+    /// Descriptor of the `nTPMIN0_EL0` field, for use with [`FieldAccess`].
+    pub const NTPMIN0_EL0_FIELD: Field<Self, bool, 29, 1> =
+        Field::new("HFGWTR2_EL2", "nTPMIN0_EL0");
+    /// Descriptor of the `nAFGDTn_EL1` field, for use with [`FieldAccess`].
+    pub const NAFGDTN_EL1_FIELD: Field<Self, u8, 31, 2> = Field::new("HFGWTR2_EL2", "nAFGDTn_EL1");
+    /// Descriptor of the `TFSR_EL1` field, for use with [`FieldAccess`].
+    pub const TFSR_EL1_FIELD: Field<Self, bool, 33, 1> = Field::new("HFGWTR2_EL2", "TFSR_EL1");
+    /// Descriptor of the `RGSR_EL1` field, for use with [`FieldAccess`].
+    pub const RGSR_EL1_FIELD: Field<Self, bool, 34, 1> = Field::new("HFGWTR2_EL2", "RGSR_EL1");
+    /// Descriptor of the `GCR_EL1` field, for use with [`FieldAccess`].
+    pub const GCR_EL1_FIELD: Field<Self, bool, 35, 1> = Field::new("HFGWTR2_EL2", "GCR_EL1");
+    /// Descriptor of the `nTPIDR3_EL0` field, for use with [`FieldAccess`].
+    pub const NTPIDR3_EL0_FIELD: Field<Self, bool, 36, 1> =
+        Field::new("HFGWTR2_EL2", "nTPIDR3_EL0");
+    /// Descriptor of the `nTPIDR3_EL1` field, for use with [`FieldAccess`].
+    pub const NTPIDR3_EL1_FIELD: Field<Self, bool, 37, 1> =
+        Field::new("HFGWTR2_EL2", "nTPIDR3_EL1");
+    /// Descriptor of the `nLDSTT_EL1` field, for use with [`FieldAccess`].
+    pub const NLDSTT_EL1_FIELD: Field<Self, bool, 38, 1> = Field::new("HFGWTR2_EL2", "nLDSTT_EL1");
+    /// Descriptor of the `ACTLR_EL1` field, for use with [`FieldAccess`].
+    pub const ACTLR_EL1_FIELD: Field<Self, bool, 39, 1> = Field::new("HFGWTR2_EL2", "ACTLR_EL1");
+
+    /// Returns the value of the `nFGDTn_EL1` field.
+    pub const fn nfgdtn_el1(self) -> u8 {
+        Self::NFGDTN_EL1_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `nFGDTn_EL1` field.
+    pub const fn set_nfgdtn_el1(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::NFGDTN_EL1_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `nFGDTn_EL1` field set to the given value.
+    pub const fn with_nfgdtn_el1(mut self, value: u8) -> Self {
+        self.set_nfgdtn_el1(value);
+        self
+    }
+
+    /// Sets the value of the `nFGDTn_EL1` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_nfgdtn_el1(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_nfgdtn_el1(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `nFGDTn_EL1` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_nfgdtn_el1(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::NFGDTN_EL1_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `nAFGDTn_EL1` field.
+    pub const fn nafgdtn_el1(self) -> u8 {
+        Self::NAFGDTN_EL1_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `nAFGDTn_EL1` field.
+    pub const fn set_nafgdtn_el1(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::NAFGDTN_EL1_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `nAFGDTn_EL1` field set to the given value.
+    pub const fn with_nafgdtn_el1(mut self, value: u8) -> Self {
+        self.set_nafgdtn_el1(value);
+        self
+    }
+
+    /// Sets the value of the `nAFGDTn_EL1` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_nafgdtn_el1(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_nafgdtn_el1(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `nAFGDTn_EL1` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_nafgdtn_el1(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::NAFGDTN_EL1_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Display for Hfgwtr2El2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("Hfgwtr2El2");
+        let bits = self.bits();
+        s.field("nPFAR_EL1", &Self::NPFAR_EL1_FIELD.extract(bits));
+        s.field("nRCWSMASK_EL1", &Self::NRCWSMASK_EL1_FIELD.extract(bits));
+        s.field("nCPACRMASK_EL1", &Self::NCPACRMASK_EL1_FIELD.extract(bits));
+        s.field("nSCTLRMASK_EL1", &Self::NSCTLRMASK_EL1_FIELD.extract(bits));
+        s.field(
+            "nSCTLR2MASK_EL1",
+            &Self::NSCTLR2MASK_EL1_FIELD.extract(bits),
+        );
+        s.field("nTCRMASK_EL1", &Self::NTCRMASK_EL1_FIELD.extract(bits));
+        s.field("nTCR2MASK_EL1", &Self::NTCR2MASK_EL1_FIELD.extract(bits));
+        s.field(
+            "nCPACRALIAS_EL1",
+            &Self::NCPACRALIAS_EL1_FIELD.extract(bits),
+        );
+        s.field(
+            "nSCTLRALIAS_EL1",
+            &Self::NSCTLRALIAS_EL1_FIELD.extract(bits),
+        );
+        s.field(
+            "nSCTLR2ALIAS_EL1",
+            &Self::NSCTLR2ALIAS_EL1_FIELD.extract(bits),
+        );
+        s.field("nTCRALIAS_EL1", &Self::NTCRALIAS_EL1_FIELD.extract(bits));
+        s.field("nTCR2ALIAS_EL1", &Self::NTCR2ALIAS_EL1_FIELD.extract(bits));
+        s.field("nACTLRMASK_EL1", &Self::NACTLRMASK_EL1_FIELD.extract(bits));
+        s.field(
+            "nACTLRALIAS_EL1",
+            &Self::NACTLRALIAS_EL1_FIELD.extract(bits),
+        );
+        s.field("nTINDEX_EL0", &Self::NTINDEX_EL0_FIELD.extract(bits));
+        s.field("nTINDEX_EL1", &Self::NTINDEX_EL1_FIELD.extract(bits));
+        s.field("nSTINDEX_EL1", &Self::NSTINDEX_EL1_FIELD.extract(bits));
+        s.field("nFGDTn_EL1", &Self::NFGDTN_EL1_FIELD.extract(bits));
+        s.field("nTTTBRP_EL1", &Self::NTTTBRP_EL1_FIELD.extract(bits));
+        s.field("nTTTBRU_EL1", &Self::NTTTBRU_EL1_FIELD.extract(bits));
+        s.field("nIRTBRP_EL1", &Self::NIRTBRP_EL1_FIELD.extract(bits));
+        s.field("nIRTBRU_EL1", &Self::NIRTBRU_EL1_FIELD.extract(bits));
+        s.field("nDPOTBR1_EL1", &Self::NDPOTBR1_EL1_FIELD.extract(bits));
+        s.field("nDPOTBR0_EL1", &Self::NDPOTBR0_EL1_FIELD.extract(bits));
+        s.field("nTPMIN1_EL1", &Self::NTPMIN1_EL1_FIELD.extract(bits));
+        s.field("nTPMIN0_EL1", &Self::NTPMIN0_EL1_FIELD.extract(bits));
+        s.field("nTPMIN1_EL0", &Self::NTPMIN1_EL0_FIELD.extract(bits));
+        s.field("nTPMIN0_EL0", &Self::NTPMIN0_EL0_FIELD.extract(bits));
+        s.field("nAFGDTn_EL1", &Self::NAFGDTN_EL1_FIELD.extract(bits));
+        s.field("TFSR_EL1", &Self::TFSR_EL1_FIELD.extract(bits));
+        s.field("RGSR_EL1", &Self::RGSR_EL1_FIELD.extract(bits));
+        s.field("GCR_EL1", &Self::GCR_EL1_FIELD.extract(bits));
+        s.field("nTPIDR3_EL0", &Self::NTPIDR3_EL0_FIELD.extract(bits));
+        s.field("nTPIDR3_EL1", &Self::NTPIDR3_EL1_FIELD.extract(bits));
+        s.field("nLDSTT_EL1", &Self::NLDSTT_EL1_FIELD.extract(bits));
+        s.field("ACTLR_EL1", &Self::ACTLR_EL1_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for Hfgwtr2El2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "Hfgwtr2El2 {{ nPFAR_EL1: {=u64}, nRCWSMASK_EL1: {=u64}, nCPACRMASK_EL1: {=u64}, nSCTLRMASK_EL1: {=u64}, nSCTLR2MASK_EL1: {=u64}, nTCRMASK_EL1: {=u64}, nTCR2MASK_EL1: {=u64}, nCPACRALIAS_EL1: {=u64}, nSCTLRALIAS_EL1: {=u64}, nSCTLR2ALIAS_EL1: {=u64}, nTCRALIAS_EL1: {=u64}, nTCR2ALIAS_EL1: {=u64}, nACTLRMASK_EL1: {=u64}, nACTLRALIAS_EL1: {=u64}, nTINDEX_EL0: {=u64}, nTINDEX_EL1: {=u64}, nSTINDEX_EL1: {=u64}, nFGDTn_EL1: {=u64}, nTTTBRP_EL1: {=u64}, nTTTBRU_EL1: {=u64}, nIRTBRP_EL1: {=u64}, nIRTBRU_EL1: {=u64}, nDPOTBR1_EL1: {=u64}, nDPOTBR0_EL1: {=u64}, nTPMIN1_EL1: {=u64}, nTPMIN0_EL1: {=u64}, nTPMIN1_EL0: {=u64}, nTPMIN0_EL0: {=u64}, nAFGDTn_EL1: {=u64}, TFSR_EL1: {=u64}, RGSR_EL1: {=u64}, GCR_EL1: {=u64}, nTPIDR3_EL0: {=u64}, nTPIDR3_EL1: {=u64}, nLDSTT_EL1: {=u64}, ACTLR_EL1: {=u64} }}",
+            Self::NPFAR_EL1_FIELD.extract(self.bits()),
+            Self::NRCWSMASK_EL1_FIELD.extract(self.bits()),
+            Self::NCPACRMASK_EL1_FIELD.extract(self.bits()),
+            Self::NSCTLRMASK_EL1_FIELD.extract(self.bits()),
+            Self::NSCTLR2MASK_EL1_FIELD.extract(self.bits()),
+            Self::NTCRMASK_EL1_FIELD.extract(self.bits()),
+            Self::NTCR2MASK_EL1_FIELD.extract(self.bits()),
+            Self::NCPACRALIAS_EL1_FIELD.extract(self.bits()),
+            Self::NSCTLRALIAS_EL1_FIELD.extract(self.bits()),
+            Self::NSCTLR2ALIAS_EL1_FIELD.extract(self.bits()),
+            Self::NTCRALIAS_EL1_FIELD.extract(self.bits()),
+            Self::NTCR2ALIAS_EL1_FIELD.extract(self.bits()),
+            Self::NACTLRMASK_EL1_FIELD.extract(self.bits()),
+            Self::NACTLRALIAS_EL1_FIELD.extract(self.bits()),
+            Self::NTINDEX_EL0_FIELD.extract(self.bits()),
+            Self::NTINDEX_EL1_FIELD.extract(self.bits()),
+            Self::NSTINDEX_EL1_FIELD.extract(self.bits()),
+            Self::NFGDTN_EL1_FIELD.extract(self.bits()),
+            Self::NTTTBRP_EL1_FIELD.extract(self.bits()),
+            Self::NTTTBRU_EL1_FIELD.extract(self.bits()),
+            Self::NIRTBRP_EL1_FIELD.extract(self.bits()),
+            Self::NIRTBRU_EL1_FIELD.extract(self.bits()),
+            Self::NDPOTBR1_EL1_FIELD.extract(self.bits()),
+            Self::NDPOTBR0_EL1_FIELD.extract(self.bits()),
+            Self::NTPMIN1_EL1_FIELD.extract(self.bits()),
+            Self::NTPMIN0_EL1_FIELD.extract(self.bits()),
+            Self::NTPMIN1_EL0_FIELD.extract(self.bits()),
+            Self::NTPMIN0_EL0_FIELD.extract(self.bits()),
+            Self::NAFGDTN_EL1_FIELD.extract(self.bits()),
+            Self::TFSR_EL1_FIELD.extract(self.bits()),
+            Self::RGSR_EL1_FIELD.extract(self.bits()),
+            Self::GCR_EL1_FIELD.extract(self.bits()),
+            Self::NTPIDR3_EL0_FIELD.extract(self.bits()),
+            Self::NTPIDR3_EL1_FIELD.extract(self.bits()),
+            Self::NLDSTT_EL1_FIELD.extract(self.bits()),
+            Self::ACTLR_EL1_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for Hfgwtr2El2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for Hfgwtr2El2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Hfgwtr2El2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("nPFAR_EL1"),
+                old: Self::NPFAR_EL1_FIELD.extract(self.bits()),
+                new: Self::NPFAR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nRCWSMASK_EL1"),
+                old: Self::NRCWSMASK_EL1_FIELD.extract(self.bits()),
+                new: Self::NRCWSMASK_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nCPACRMASK_EL1"),
+                old: Self::NCPACRMASK_EL1_FIELD.extract(self.bits()),
+                new: Self::NCPACRMASK_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nSCTLRMASK_EL1"),
+                old: Self::NSCTLRMASK_EL1_FIELD.extract(self.bits()),
+                new: Self::NSCTLRMASK_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nSCTLR2MASK_EL1"),
+                old: Self::NSCTLR2MASK_EL1_FIELD.extract(self.bits()),
+                new: Self::NSCTLR2MASK_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nTCRMASK_EL1"),
+                old: Self::NTCRMASK_EL1_FIELD.extract(self.bits()),
+                new: Self::NTCRMASK_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nTCR2MASK_EL1"),
+                old: Self::NTCR2MASK_EL1_FIELD.extract(self.bits()),
+                new: Self::NTCR2MASK_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nCPACRALIAS_EL1"),
+                old: Self::NCPACRALIAS_EL1_FIELD.extract(self.bits()),
+                new: Self::NCPACRALIAS_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nSCTLRALIAS_EL1"),
+                old: Self::NSCTLRALIAS_EL1_FIELD.extract(self.bits()),
+                new: Self::NSCTLRALIAS_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nSCTLR2ALIAS_EL1"),
+                old: Self::NSCTLR2ALIAS_EL1_FIELD.extract(self.bits()),
+                new: Self::NSCTLR2ALIAS_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nTCRALIAS_EL1"),
+                old: Self::NTCRALIAS_EL1_FIELD.extract(self.bits()),
+                new: Self::NTCRALIAS_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nTCR2ALIAS_EL1"),
+                old: Self::NTCR2ALIAS_EL1_FIELD.extract(self.bits()),
+                new: Self::NTCR2ALIAS_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nACTLRMASK_EL1"),
+                old: Self::NACTLRMASK_EL1_FIELD.extract(self.bits()),
+                new: Self::NACTLRMASK_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nACTLRALIAS_EL1"),
+                old: Self::NACTLRALIAS_EL1_FIELD.extract(self.bits()),
+                new: Self::NACTLRALIAS_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nTINDEX_EL0"),
+                old: Self::NTINDEX_EL0_FIELD.extract(self.bits()),
+                new: Self::NTINDEX_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nTINDEX_EL1"),
+                old: Self::NTINDEX_EL1_FIELD.extract(self.bits()),
+                new: Self::NTINDEX_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nSTINDEX_EL1"),
+                old: Self::NSTINDEX_EL1_FIELD.extract(self.bits()),
+                new: Self::NSTINDEX_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nFGDTn_EL1"),
+                old: Self::NFGDTN_EL1_FIELD.extract(self.bits()),
+                new: Self::NFGDTN_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nTTTBRP_EL1"),
+                old: Self::NTTTBRP_EL1_FIELD.extract(self.bits()),
+                new: Self::NTTTBRP_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nTTTBRU_EL1"),
+                old: Self::NTTTBRU_EL1_FIELD.extract(self.bits()),
+                new: Self::NTTTBRU_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nIRTBRP_EL1"),
+                old: Self::NIRTBRP_EL1_FIELD.extract(self.bits()),
+                new: Self::NIRTBRP_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nIRTBRU_EL1"),
+                old: Self::NIRTBRU_EL1_FIELD.extract(self.bits()),
+                new: Self::NIRTBRU_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nDPOTBR1_EL1"),
+                old: Self::NDPOTBR1_EL1_FIELD.extract(self.bits()),
+                new: Self::NDPOTBR1_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nDPOTBR0_EL1"),
+                old: Self::NDPOTBR0_EL1_FIELD.extract(self.bits()),
+                new: Self::NDPOTBR0_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nTPMIN1_EL1"),
+                old: Self::NTPMIN1_EL1_FIELD.extract(self.bits()),
+                new: Self::NTPMIN1_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nTPMIN0_EL1"),
+                old: Self::NTPMIN0_EL1_FIELD.extract(self.bits()),
+                new: Self::NTPMIN0_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nTPMIN1_EL0"),
+                old: Self::NTPMIN1_EL0_FIELD.extract(self.bits()),
+                new: Self::NTPMIN1_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nTPMIN0_EL0"),
+                old: Self::NTPMIN0_EL0_FIELD.extract(self.bits()),
+                new: Self::NTPMIN0_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nAFGDTn_EL1"),
+                old: Self::NAFGDTN_EL1_FIELD.extract(self.bits()),
+                new: Self::NAFGDTN_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TFSR_EL1"),
+                old: Self::TFSR_EL1_FIELD.extract(self.bits()),
+                new: Self::TFSR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("RGSR_EL1"),
+                old: Self::RGSR_EL1_FIELD.extract(self.bits()),
+                new: Self::RGSR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("GCR_EL1"),
+                old: Self::GCR_EL1_FIELD.extract(self.bits()),
+                new: Self::GCR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nTPIDR3_EL0"),
+                old: Self::NTPIDR3_EL0_FIELD.extract(self.bits()),
+                new: Self::NTPIDR3_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nTPIDR3_EL1"),
+                old: Self::NTPIDR3_EL1_FIELD.extract(self.bits()),
+                new: Self::NTPIDR3_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nLDSTT_EL1"),
+                old: Self::NLDSTT_EL1_FIELD.extract(self.bits()),
+                new: Self::NLDSTT_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ACTLR_EL1"),
+                old: Self::ACTLR_EL1_FIELD.extract(self.bits()),
+                new: Self::ACTLR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_0000_0000_0100_0000_0000_0000_0000_0000_0000_0010,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_0000_0000_0100_0000_0000_0000_0000_0000_0000_0010,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Hfgwtr2El2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Hfgwtr2El2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `HFGWTR_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct HfgwtrEl2: u64 {
+        /// `AFSR0_EL1` bit.
+        const AFSR0_EL1 = 1 << 0;
+        /// `AFSR1_EL1` bit.
+        const AFSR1_EL1 = 1 << 1;
+        /// `AMAIR_EL1` bit.
+        const AMAIR_EL1 = 1 << 3;
+        /// `APDAKey` bit.
+        const APDAKEY = 1 << 4;
+        /// `APDBKey` bit.
+        const APDBKEY = 1 << 5;
+        /// `APGAKey` bit.
+        const APGAKEY = 1 << 6;
+        /// `APIAKey` bit.
+        const APIAKEY = 1 << 7;
+        /// `APIBKey` bit.
+        const APIBKEY = 1 << 8;
+        /// `CONTEXTIDR_EL1` bit.
+        const CONTEXTIDR_EL1 = 1 << 11;
+        /// `CPACR_EL1` bit.
+        const CPACR_EL1 = 1 << 12;
+        /// `CSSELR_EL1` bit.
+        const CSSELR_EL1 = 1 << 13;
+        /// `ESR_EL1` bit.
+        const ESR_EL1 = 1 << 16;
+        /// `FAR_EL1` bit.
+        const FAR_EL1 = 1 << 17;
+        /// `LORC_EL1` bit.
+        const LORC_EL1 = 1 << 19;
+        /// `LOREA_EL1` bit.
+        const LOREA_EL1 = 1 << 20;
+        /// `LORN_EL1` bit.
+        const LORN_EL1 = 1 << 22;
+        /// `LORSA_EL1` bit.
+        const LORSA_EL1 = 1 << 23;
+        /// `MAIR_EL1` bit.
+        const MAIR_EL1 = 1 << 24;
+        /// `PAR_EL1` bit.
+        const PAR_EL1 = 1 << 27;
+        /// `SCTLR_EL1` bit.
+        const SCTLR_EL1 = 1 << 29;
+        /// `SCXTNUM_EL1` bit.
+        const SCXTNUM_EL1 = 1 << 30;
+        /// `SCXTNUM_EL0` bit.
+        const SCXTNUM_EL0 = 1 << 31;
+        /// `TCR_EL1` bit.
+        const TCR_EL1 = 1 << 32;
+        /// `TPIDR_EL1` bit.
+        const TPIDR_EL1 = 1 << 33;
+        /// `TPIDRRO_EL0` bit.
+        const TPIDRRO_EL0 = 1 << 34;
+        /// `TPIDR_EL0` bit.
+        const TPIDR_EL0 = 1 << 35;
+        /// `TTBR0_EL1` bit.
+        const TTBR0_EL1 = 1 << 36;
+        /// `TTBR1_EL1` bit.
+        const TTBR1_EL1 = 1 << 37;
+        /// `VBAR_EL1` bit.
+        const VBAR_EL1 = 1 << 38;
+        /// `ICC_IGRPENn_EL1` bit.
+        const ICC_IGRPENN_EL1 = 1 << 39;
+        /// `ERRSELR_EL1` bit.
+        const ERRSELR_EL1 = 1 << 41;
+        /// `ERXCTLR_EL1` bit.
+        const ERXCTLR_EL1 = 1 << 43;
+        /// `ERXSTATUS_EL1` bit.
+        const ERXSTATUS_EL1 = 1 << 44;
+        /// `ERXMISCn_EL1` bit.
+        const ERXMISCN_EL1 = 1 << 45;
+        /// `ERXPFGCTL_EL1` bit.
+        const ERXPFGCTL_EL1 = 1 << 47;
+        /// `ERXPFGCDN_EL1` bit.
+        const ERXPFGCDN_EL1 = 1 << 48;
+        /// `ERXADDR_EL1` bit.
+        const ERXADDR_EL1 = 1 << 49;
+        /// `nACCDATA_EL1` bit.
+        const NACCDATA_EL1 = 1 << 50;
+        /// `nGCS_EL0` bit.
+        const NGCS_EL0 = 1 << 52;
+        /// `nGCS_EL1` bit.
+        const NGCS_EL1 = 1 << 53;
+        /// `nSMPRI_EL1` bit.
+        const NSMPRI_EL1 = 1 << 54;
+        /// `nTPIDR2_EL0` bit.
+        const NTPIDR2_EL0 = 1 << 55;
+        /// `nRCWMASK_EL1` bit.
+        const NRCWMASK_EL1 = 1 << 56;
+        /// `nPIRE0_EL1` bit.
+        const NPIRE0_EL1 = 1 << 57;
+        /// `nPIR_EL1` bit.
+        const NPIR_EL1 = 1 << 58;
+        /// `nPOR_EL0` bit.
+        const NPOR_EL0 = 1 << 59;
+        /// `nPOR_EL1` bit.
+        const NPOR_EL1 = 1 << 60;
+        /// `nS2POR_EL1` bit.
+        const NS2POR_EL1 = 1 << 61;
+        /// `nMAIR2_EL1` bit.
+        const NMAIR2_EL1 = 1 << 62;
+        /// `nAMAIR2_EL1` bit.
+        const NAMAIR2_EL1 = 1 << 63;
+    }
+}
+
+#[cfg(feature = "el2")]
+impl HfgwtrEl2 {
+    /// Descriptor of the `AFSR0_EL1` field, for use with [`FieldAccess`].
+    pub const AFSR0_EL1_FIELD: Field<Self, bool, 0, 1> = Field::new("HFGWTR_EL2", "AFSR0_EL1");
+    /// Descriptor of the `AFSR1_EL1` field, for use with [`FieldAccess`].
+    pub const AFSR1_EL1_FIELD: Field<Self, bool, 1, 1> = Field::new("HFGWTR_EL2", "AFSR1_EL1");
+    /// Descriptor of the `AMAIR_EL1` field, for use with [`FieldAccess`].
+    pub const AMAIR_EL1_FIELD: Field<Self, bool, 3, 1> = Field::new("HFGWTR_EL2", "AMAIR_EL1");
+    /// Descriptor of the `APDAKey` field, for use with [`FieldAccess`].
+    pub const APDAKEY_FIELD: Field<Self, bool, 4, 1> = Field::new("HFGWTR_EL2", "APDAKey");
+    /// Descriptor of the `APDBKey` field, for use with [`FieldAccess`].
+    pub const APDBKEY_FIELD: Field<Self, bool, 5, 1> = Field::new("HFGWTR_EL2", "APDBKey");
+    /// Descriptor of the `APGAKey` field, for use with [`FieldAccess`].
+    pub const APGAKEY_FIELD: Field<Self, bool, 6, 1> = Field::new("HFGWTR_EL2", "APGAKey");
+    /// Descriptor of the `APIAKey` field, for use with [`FieldAccess`].
+    pub const APIAKEY_FIELD: Field<Self, bool, 7, 1> = Field::new("HFGWTR_EL2", "APIAKey");
+    /// Descriptor of the `APIBKey` field, for use with [`FieldAccess`].
+    pub const APIBKEY_FIELD: Field<Self, bool, 8, 1> = Field::new("HFGWTR_EL2", "APIBKey");
+    /// Descriptor of the `CONTEXTIDR_EL1` field, for use with [`FieldAccess`].
+    pub const CONTEXTIDR_EL1_FIELD: Field<Self, bool, 11, 1> =
+        Field::new("HFGWTR_EL2", "CONTEXTIDR_EL1");
+    /// Descriptor of the `CPACR_EL1` field, for use with [`FieldAccess`].
+    pub const CPACR_EL1_FIELD: Field<Self, bool, 12, 1> = Field::new("HFGWTR_EL2", "CPACR_EL1");
+    /// Descriptor of the `CSSELR_EL1` field, for use with [`FieldAccess`].
+    pub const CSSELR_EL1_FIELD: Field<Self, bool, 13, 1> = Field::new("HFGWTR_EL2", "CSSELR_EL1");
+    /// Descriptor of the `ESR_EL1` field, for use with [`FieldAccess`].
+    pub const ESR_EL1_FIELD: Field<Self, bool, 16, 1> = Field::new("HFGWTR_EL2", "ESR_EL1");
+    /// Descriptor of the `FAR_EL1` field, for use with [`FieldAccess`].
+    pub const FAR_EL1_FIELD: Field<Self, bool, 17, 1> = Field::new("HFGWTR_EL2", "FAR_EL1");
+    /// Descriptor of the `LORC_EL1` field, for use with [`FieldAccess`].
+    pub const LORC_EL1_FIELD: Field<Self, bool, 19, 1> = Field::new("HFGWTR_EL2", "LORC_EL1");
+    /// Descriptor of the `LOREA_EL1` field, for use with [`FieldAccess`].
+    pub const LOREA_EL1_FIELD: Field<Self, bool, 20, 1> = Field::new("HFGWTR_EL2", "LOREA_EL1");
+    /// Descriptor of the `LORN_EL1` field, for use with [`FieldAccess`].
+    pub const LORN_EL1_FIELD: Field<Self, bool, 22, 1> = Field::new("HFGWTR_EL2", "LORN_EL1");
+    /// Descriptor of the `LORSA_EL1` field, for use with [`FieldAccess`].
+    pub const LORSA_EL1_FIELD: Field<Self, bool, 23, 1> = Field::new("HFGWTR_EL2", "LORSA_EL1");
+    /// Descriptor of the `MAIR_EL1` field, for use with [`FieldAccess`].
+    pub const MAIR_EL1_FIELD: Field<Self, bool, 24, 1> = Field::new("HFGWTR_EL2", "MAIR_EL1");
+    /// Descriptor of the `PAR_EL1` field, for use with [`FieldAccess`].
+    pub const PAR_EL1_FIELD: Field<Self, bool, 27, 1> = Field::new("HFGWTR_EL2", "PAR_EL1");
+    /// Descriptor of the `SCTLR_EL1` field, for use with [`FieldAccess`].
+    pub const SCTLR_EL1_FIELD: Field<Self, bool, 29, 1> = Field::new("HFGWTR_EL2", "SCTLR_EL1");
+    /// Descriptor of the `SCXTNUM_EL1` field, for use with [`FieldAccess`].
+    pub const SCXTNUM_EL1_FIELD: Field<Self, bool, 30, 1> = Field::new("HFGWTR_EL2", "SCXTNUM_EL1");
+    /// Descriptor of the `SCXTNUM_EL0` field, for use with [`FieldAccess`].
+    pub const SCXTNUM_EL0_FIELD: Field<Self, bool, 31, 1> = Field::new("HFGWTR_EL2", "SCXTNUM_EL0");
+    /// Descriptor of the `TCR_EL1` field, for use with [`FieldAccess`].
+    pub const TCR_EL1_FIELD: Field<Self, bool, 32, 1> = Field::new("HFGWTR_EL2", "TCR_EL1");
+    /// Descriptor of the `TPIDR_EL1` field, for use with [`FieldAccess`].
+    pub const TPIDR_EL1_FIELD: Field<Self, bool, 33, 1> = Field::new("HFGWTR_EL2", "TPIDR_EL1");
+    /// Descriptor of the `TPIDRRO_EL0` field, for use with [`FieldAccess`].
+    pub const TPIDRRO_EL0_FIELD: Field<Self, bool, 34, 1> = Field::new("HFGWTR_EL2", "TPIDRRO_EL0");
+    /// Descriptor of the `TPIDR_EL0` field, for use with [`FieldAccess`].
+    pub const TPIDR_EL0_FIELD: Field<Self, bool, 35, 1> = Field::new("HFGWTR_EL2", "TPIDR_EL0");
+    /// Descriptor of the `TTBR0_EL1` field, for use with [`FieldAccess`].
+    pub const TTBR0_EL1_FIELD: Field<Self, bool, 36, 1> = Field::new("HFGWTR_EL2", "TTBR0_EL1");
+    /// Descriptor of the `TTBR1_EL1` field, for use with [`FieldAccess`].
+    pub const TTBR1_EL1_FIELD: Field<Self, bool, 37, 1> = Field::new("HFGWTR_EL2", "TTBR1_EL1");
+    /// Descriptor of the `VBAR_EL1` field, for use with [`FieldAccess`].
+    pub const VBAR_EL1_FIELD: Field<Self, bool, 38, 1> = Field::new("HFGWTR_EL2", "VBAR_EL1");
+    /// Descriptor of the `ICC_IGRPENn_EL1` field, for use with [`FieldAccess`].
+    pub const ICC_IGRPENN_EL1_FIELD: Field<Self, bool, 39, 1> =
+        Field::new("HFGWTR_EL2", "ICC_IGRPENn_EL1");
+    /// Descriptor of the `ERRSELR_EL1` field, for use with [`FieldAccess`].
+    pub const ERRSELR_EL1_FIELD: Field<Self, bool, 41, 1> = Field::new("HFGWTR_EL2", "ERRSELR_EL1");
+    /// Descriptor of the `ERXCTLR_EL1` field, for use with [`FieldAccess`].
+    pub const ERXCTLR_EL1_FIELD: Field<Self, bool, 43, 1> = Field::new("HFGWTR_EL2", "ERXCTLR_EL1");
+    /// Descriptor of the `ERXSTATUS_EL1` field, for use with [`FieldAccess`].
+    pub const ERXSTATUS_EL1_FIELD: Field<Self, bool, 44, 1> =
+        Field::new("HFGWTR_EL2", "ERXSTATUS_EL1");
+    /// Descriptor of the `ERXMISCn_EL1` field, for use with [`FieldAccess`].
+    pub const ERXMISCN_EL1_FIELD: Field<Self, bool, 45, 1> =
+        Field::new("HFGWTR_EL2", "ERXMISCn_EL1");
+    /// Descriptor of the `ERXPFGCTL_EL1` field, for use with [`FieldAccess`].
+    pub const ERXPFGCTL_EL1_FIELD: Field<Self, bool, 47, 1> =
+        Field::new("HFGWTR_EL2", "ERXPFGCTL_EL1");
+    /// Descriptor of the `ERXPFGCDN_EL1` field, for use with [`FieldAccess`].
+    pub const ERXPFGCDN_EL1_FIELD: Field<Self, bool, 48, 1> =
+        Field::new("HFGWTR_EL2", "ERXPFGCDN_EL1");
+    /// Descriptor of the `ERXADDR_EL1` field, for use with [`FieldAccess`].
+    pub const ERXADDR_EL1_FIELD: Field<Self, bool, 49, 1> = Field::new("HFGWTR_EL2", "ERXADDR_EL1");
+    /// Descriptor of the `nACCDATA_EL1` field, for use with [`FieldAccess`].
+    pub const NACCDATA_EL1_FIELD: Field<Self, bool, 50, 1> =
+        Field::new("HFGWTR_EL2", "nACCDATA_EL1");
+    /// Descriptor of the `nGCS_EL0` field, for use with [`FieldAccess`].
+    pub const NGCS_EL0_FIELD: Field<Self, bool, 52, 1> = Field::new("HFGWTR_EL2", "nGCS_EL0");
+    /// Descriptor of the `nGCS_EL1` field, for use with [`FieldAccess`].
+    pub const NGCS_EL1_FIELD: Field<Self, bool, 53, 1> = Field::new("HFGWTR_EL2", "nGCS_EL1");
+    /// Descriptor of the `nSMPRI_EL1` field, for use with [`FieldAccess`].
+    pub const NSMPRI_EL1_FIELD: Field<Self, bool, 54, 1> = Field::new("HFGWTR_EL2", "nSMPRI_EL1");
+    /// Descriptor of the `nTPIDR2_EL0` field, for use with [`FieldAccess`].
+    pub const NTPIDR2_EL0_FIELD: Field<Self, bool, 55, 1> = Field::new("HFGWTR_EL2", "nTPIDR2_EL0");
+    /// Descriptor of the `nRCWMASK_EL1` field, for use with [`FieldAccess`].
+    pub const NRCWMASK_EL1_FIELD: Field<Self, bool, 56, 1> =
+        Field::new("HFGWTR_EL2", "nRCWMASK_EL1");
+    /// Descriptor of the `nPIRE0_EL1` field, for use with [`FieldAccess`].
+    pub const NPIRE0_EL1_FIELD: Field<Self, bool, 57, 1> = Field::new("HFGWTR_EL2", "nPIRE0_EL1");
+    /// Descriptor of the `nPIR_EL1` field, for use with [`FieldAccess`].
+    pub const NPIR_EL1_FIELD: Field<Self, bool, 58, 1> = Field::new("HFGWTR_EL2", "nPIR_EL1");
+    /// Descriptor of the `nPOR_EL0` field, for use with [`FieldAccess`].
+    pub const NPOR_EL0_FIELD: Field<Self, bool, 59, 1> = Field::new("HFGWTR_EL2", "nPOR_EL0");
+    /// Descriptor of the `nPOR_EL1` field, for use with [`FieldAccess`].
+    pub const NPOR_EL1_FIELD: Field<Self, bool, 60, 1> = Field::new("HFGWTR_EL2", "nPOR_EL1");
+    /// Descriptor of the `nS2POR_EL1` field, for use with [`FieldAccess`].
+    pub const NS2POR_EL1_FIELD: Field<Self, bool, 61, 1> = Field::new("HFGWTR_EL2", "nS2POR_EL1");
+    /// Descriptor of the `nMAIR2_EL1` field, for use with [`FieldAccess`].
+    pub const NMAIR2_EL1_FIELD: Field<Self, bool, 62, 1> = Field::new("HFGWTR_EL2", "nMAIR2_EL1");
+    /// Descriptor of the `nAMAIR2_EL1` field, for use with [`FieldAccess`].
+    pub const NAMAIR2_EL1_FIELD: Field<Self, bool, 63, 1> = Field::new("HFGWTR_EL2", "nAMAIR2_EL1");
+}
+
+#[cfg(feature = "el2")]
+impl Display for HfgwtrEl2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("HfgwtrEl2");
+        let bits = self.bits();
+        s.field("AFSR0_EL1", &Self::AFSR0_EL1_FIELD.extract(bits));
+        s.field("AFSR1_EL1", &Self::AFSR1_EL1_FIELD.extract(bits));
+        s.field("AMAIR_EL1", &Self::AMAIR_EL1_FIELD.extract(bits));
+        s.field("APDAKey", &Self::APDAKEY_FIELD.extract(bits));
+        s.field("APDBKey", &Self::APDBKEY_FIELD.extract(bits));
+        s.field("APGAKey", &Self::APGAKEY_FIELD.extract(bits));
+        s.field("APIAKey", &Self::APIAKEY_FIELD.extract(bits));
+        s.field("APIBKey", &Self::APIBKEY_FIELD.extract(bits));
+        s.field("CONTEXTIDR_EL1", &Self::CONTEXTIDR_EL1_FIELD.extract(bits));
+        s.field("CPACR_EL1", &Self::CPACR_EL1_FIELD.extract(bits));
+        s.field("CSSELR_EL1", &Self::CSSELR_EL1_FIELD.extract(bits));
+        s.field("ESR_EL1", &Self::ESR_EL1_FIELD.extract(bits));
+        s.field("FAR_EL1", &Self::FAR_EL1_FIELD.extract(bits));
+        s.field("LORC_EL1", &Self::LORC_EL1_FIELD.extract(bits));
+        s.field("LOREA_EL1", &Self::LOREA_EL1_FIELD.extract(bits));
+        s.field("LORN_EL1", &Self::LORN_EL1_FIELD.extract(bits));
+        s.field("LORSA_EL1", &Self::LORSA_EL1_FIELD.extract(bits));
+        s.field("MAIR_EL1", &Self::MAIR_EL1_FIELD.extract(bits));
+        s.field("PAR_EL1", &Self::PAR_EL1_FIELD.extract(bits));
+        s.field("SCTLR_EL1", &Self::SCTLR_EL1_FIELD.extract(bits));
+        s.field("SCXTNUM_EL1", &Self::SCXTNUM_EL1_FIELD.extract(bits));
+        s.field("SCXTNUM_EL0", &Self::SCXTNUM_EL0_FIELD.extract(bits));
+        s.field("TCR_EL1", &Self::TCR_EL1_FIELD.extract(bits));
+        s.field("TPIDR_EL1", &Self::TPIDR_EL1_FIELD.extract(bits));
+        s.field("TPIDRRO_EL0", &Self::TPIDRRO_EL0_FIELD.extract(bits));
+        s.field("TPIDR_EL0", &Self::TPIDR_EL0_FIELD.extract(bits));
+        s.field("TTBR0_EL1", &Self::TTBR0_EL1_FIELD.extract(bits));
+        s.field("TTBR1_EL1", &Self::TTBR1_EL1_FIELD.extract(bits));
+        s.field("VBAR_EL1", &Self::VBAR_EL1_FIELD.extract(bits));
+        s.field(
+            "ICC_IGRPENn_EL1",
+            &Self::ICC_IGRPENN_EL1_FIELD.extract(bits),
+        );
+        s.field("ERRSELR_EL1", &Self::ERRSELR_EL1_FIELD.extract(bits));
+        s.field("ERXCTLR_EL1", &Self::ERXCTLR_EL1_FIELD.extract(bits));
+        s.field("ERXSTATUS_EL1", &Self::ERXSTATUS_EL1_FIELD.extract(bits));
+        s.field("ERXMISCn_EL1", &Self::ERXMISCN_EL1_FIELD.extract(bits));
+        s.field("ERXPFGCTL_EL1", &Self::ERXPFGCTL_EL1_FIELD.extract(bits));
+        s.field("ERXPFGCDN_EL1", &Self::ERXPFGCDN_EL1_FIELD.extract(bits));
+        s.field("ERXADDR_EL1", &Self::ERXADDR_EL1_FIELD.extract(bits));
+        s.field("nACCDATA_EL1", &Self::NACCDATA_EL1_FIELD.extract(bits));
+        s.field("nGCS_EL0", &Self::NGCS_EL0_FIELD.extract(bits));
+        s.field("nGCS_EL1", &Self::NGCS_EL1_FIELD.extract(bits));
+        s.field("nSMPRI_EL1", &Self::NSMPRI_EL1_FIELD.extract(bits));
+        s.field("nTPIDR2_EL0", &Self::NTPIDR2_EL0_FIELD.extract(bits));
+        s.field("nRCWMASK_EL1", &Self::NRCWMASK_EL1_FIELD.extract(bits));
+        s.field("nPIRE0_EL1", &Self::NPIRE0_EL1_FIELD.extract(bits));
+        s.field("nPIR_EL1", &Self::NPIR_EL1_FIELD.extract(bits));
+        s.field("nPOR_EL0", &Self::NPOR_EL0_FIELD.extract(bits));
+        s.field("nPOR_EL1", &Self::NPOR_EL1_FIELD.extract(bits));
+        s.field("nS2POR_EL1", &Self::NS2POR_EL1_FIELD.extract(bits));
+        s.field("nMAIR2_EL1", &Self::NMAIR2_EL1_FIELD.extract(bits));
+        s.field("nAMAIR2_EL1", &Self::NAMAIR2_EL1_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for HfgwtrEl2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "HfgwtrEl2 {{ AFSR0_EL1: {=u64}, AFSR1_EL1: {=u64}, AMAIR_EL1: {=u64}, APDAKey: {=u64}, APDBKey: {=u64}, APGAKey: {=u64}, APIAKey: {=u64}, APIBKey: {=u64}, CONTEXTIDR_EL1: {=u64}, CPACR_EL1: {=u64}, CSSELR_EL1: {=u64}, ESR_EL1: {=u64}, FAR_EL1: {=u64}, LORC_EL1: {=u64}, LOREA_EL1: {=u64}, LORN_EL1: {=u64}, LORSA_EL1: {=u64}, MAIR_EL1: {=u64}, PAR_EL1: {=u64}, SCTLR_EL1: {=u64}, SCXTNUM_EL1: {=u64}, SCXTNUM_EL0: {=u64}, TCR_EL1: {=u64}, TPIDR_EL1: {=u64}, TPIDRRO_EL0: {=u64}, TPIDR_EL0: {=u64}, TTBR0_EL1: {=u64}, TTBR1_EL1: {=u64}, VBAR_EL1: {=u64}, ICC_IGRPENn_EL1: {=u64}, ERRSELR_EL1: {=u64}, ERXCTLR_EL1: {=u64}, ERXSTATUS_EL1: {=u64}, ERXMISCn_EL1: {=u64}, ERXPFGCTL_EL1: {=u64}, ERXPFGCDN_EL1: {=u64}, ERXADDR_EL1: {=u64}, nACCDATA_EL1: {=u64}, nGCS_EL0: {=u64}, nGCS_EL1: {=u64}, nSMPRI_EL1: {=u64}, nTPIDR2_EL0: {=u64}, nRCWMASK_EL1: {=u64}, nPIRE0_EL1: {=u64}, nPIR_EL1: {=u64}, nPOR_EL0: {=u64}, nPOR_EL1: {=u64}, nS2POR_EL1: {=u64}, nMAIR2_EL1: {=u64}, nAMAIR2_EL1: {=u64} }}",
+            Self::AFSR0_EL1_FIELD.extract(self.bits()),
+            Self::AFSR1_EL1_FIELD.extract(self.bits()),
+            Self::AMAIR_EL1_FIELD.extract(self.bits()),
+            Self::APDAKEY_FIELD.extract(self.bits()),
+            Self::APDBKEY_FIELD.extract(self.bits()),
+            Self::APGAKEY_FIELD.extract(self.bits()),
+            Self::APIAKEY_FIELD.extract(self.bits()),
+            Self::APIBKEY_FIELD.extract(self.bits()),
+            Self::CONTEXTIDR_EL1_FIELD.extract(self.bits()),
+            Self::CPACR_EL1_FIELD.extract(self.bits()),
+            Self::CSSELR_EL1_FIELD.extract(self.bits()),
+            Self::ESR_EL1_FIELD.extract(self.bits()),
+            Self::FAR_EL1_FIELD.extract(self.bits()),
+            Self::LORC_EL1_FIELD.extract(self.bits()),
+            Self::LOREA_EL1_FIELD.extract(self.bits()),
+            Self::LORN_EL1_FIELD.extract(self.bits()),
+            Self::LORSA_EL1_FIELD.extract(self.bits()),
+            Self::MAIR_EL1_FIELD.extract(self.bits()),
+            Self::PAR_EL1_FIELD.extract(self.bits()),
+            Self::SCTLR_EL1_FIELD.extract(self.bits()),
+            Self::SCXTNUM_EL1_FIELD.extract(self.bits()),
+            Self::SCXTNUM_EL0_FIELD.extract(self.bits()),
+            Self::TCR_EL1_FIELD.extract(self.bits()),
+            Self::TPIDR_EL1_FIELD.extract(self.bits()),
+            Self::TPIDRRO_EL0_FIELD.extract(self.bits()),
+            Self::TPIDR_EL0_FIELD.extract(self.bits()),
+            Self::TTBR0_EL1_FIELD.extract(self.bits()),
+            Self::TTBR1_EL1_FIELD.extract(self.bits()),
+            Self::VBAR_EL1_FIELD.extract(self.bits()),
+            Self::ICC_IGRPENN_EL1_FIELD.extract(self.bits()),
+            Self::ERRSELR_EL1_FIELD.extract(self.bits()),
+            Self::ERXCTLR_EL1_FIELD.extract(self.bits()),
+            Self::ERXSTATUS_EL1_FIELD.extract(self.bits()),
+            Self::ERXMISCN_EL1_FIELD.extract(self.bits()),
+            Self::ERXPFGCTL_EL1_FIELD.extract(self.bits()),
+            Self::ERXPFGCDN_EL1_FIELD.extract(self.bits()),
+            Self::ERXADDR_EL1_FIELD.extract(self.bits()),
+            Self::NACCDATA_EL1_FIELD.extract(self.bits()),
+            Self::NGCS_EL0_FIELD.extract(self.bits()),
+            Self::NGCS_EL1_FIELD.extract(self.bits()),
+            Self::NSMPRI_EL1_FIELD.extract(self.bits()),
+            Self::NTPIDR2_EL0_FIELD.extract(self.bits()),
+            Self::NRCWMASK_EL1_FIELD.extract(self.bits()),
+            Self::NPIRE0_EL1_FIELD.extract(self.bits()),
+            Self::NPIR_EL1_FIELD.extract(self.bits()),
+            Self::NPOR_EL0_FIELD.extract(self.bits()),
+            Self::NPOR_EL1_FIELD.extract(self.bits()),
+            Self::NS2POR_EL1_FIELD.extract(self.bits()),
+            Self::NMAIR2_EL1_FIELD.extract(self.bits()),
+            Self::NAMAIR2_EL1_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for HfgwtrEl2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for HfgwtrEl2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl HfgwtrEl2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("AFSR0_EL1"),
+                old: Self::AFSR0_EL1_FIELD.extract(self.bits()),
+                new: Self::AFSR0_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("AFSR1_EL1"),
+                old: Self::AFSR1_EL1_FIELD.extract(self.bits()),
+                new: Self::AFSR1_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("AMAIR_EL1"),
+                old: Self::AMAIR_EL1_FIELD.extract(self.bits()),
+                new: Self::AMAIR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("APDAKey"),
+                old: Self::APDAKEY_FIELD.extract(self.bits()),
+                new: Self::APDAKEY_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("APDBKey"),
+                old: Self::APDBKEY_FIELD.extract(self.bits()),
+                new: Self::APDBKEY_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("APGAKey"),
+                old: Self::APGAKEY_FIELD.extract(self.bits()),
+                new: Self::APGAKEY_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("APIAKey"),
+                old: Self::APIAKEY_FIELD.extract(self.bits()),
+                new: Self::APIAKEY_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("APIBKey"),
+                old: Self::APIBKEY_FIELD.extract(self.bits()),
+                new: Self::APIBKEY_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CONTEXTIDR_EL1"),
+                old: Self::CONTEXTIDR_EL1_FIELD.extract(self.bits()),
+                new: Self::CONTEXTIDR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CPACR_EL1"),
+                old: Self::CPACR_EL1_FIELD.extract(self.bits()),
+                new: Self::CPACR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CSSELR_EL1"),
+                old: Self::CSSELR_EL1_FIELD.extract(self.bits()),
+                new: Self::CSSELR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ESR_EL1"),
+                old: Self::ESR_EL1_FIELD.extract(self.bits()),
+                new: Self::ESR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FAR_EL1"),
+                old: Self::FAR_EL1_FIELD.extract(self.bits()),
+                new: Self::FAR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("LORC_EL1"),
+                old: Self::LORC_EL1_FIELD.extract(self.bits()),
+                new: Self::LORC_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("LOREA_EL1"),
+                old: Self::LOREA_EL1_FIELD.extract(self.bits()),
+                new: Self::LOREA_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("LORN_EL1"),
+                old: Self::LORN_EL1_FIELD.extract(self.bits()),
+                new: Self::LORN_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("LORSA_EL1"),
+                old: Self::LORSA_EL1_FIELD.extract(self.bits()),
+                new: Self::LORSA_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("MAIR_EL1"),
+                old: Self::MAIR_EL1_FIELD.extract(self.bits()),
+                new: Self::MAIR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PAR_EL1"),
+                old: Self::PAR_EL1_FIELD.extract(self.bits()),
+                new: Self::PAR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SCTLR_EL1"),
+                old: Self::SCTLR_EL1_FIELD.extract(self.bits()),
+                new: Self::SCTLR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SCXTNUM_EL1"),
+                old: Self::SCXTNUM_EL1_FIELD.extract(self.bits()),
+                new: Self::SCXTNUM_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SCXTNUM_EL0"),
+                old: Self::SCXTNUM_EL0_FIELD.extract(self.bits()),
+                new: Self::SCXTNUM_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TCR_EL1"),
+                old: Self::TCR_EL1_FIELD.extract(self.bits()),
+                new: Self::TCR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TPIDR_EL1"),
+                old: Self::TPIDR_EL1_FIELD.extract(self.bits()),
+                new: Self::TPIDR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TPIDRRO_EL0"),
+                old: Self::TPIDRRO_EL0_FIELD.extract(self.bits()),
+                new: Self::TPIDRRO_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TPIDR_EL0"),
+                old: Self::TPIDR_EL0_FIELD.extract(self.bits()),
+                new: Self::TPIDR_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TTBR0_EL1"),
+                old: Self::TTBR0_EL1_FIELD.extract(self.bits()),
+                new: Self::TTBR0_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TTBR1_EL1"),
+                old: Self::TTBR1_EL1_FIELD.extract(self.bits()),
+                new: Self::TTBR1_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VBAR_EL1"),
+                old: Self::VBAR_EL1_FIELD.extract(self.bits()),
+                new: Self::VBAR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ICC_IGRPENn_EL1"),
+                old: Self::ICC_IGRPENN_EL1_FIELD.extract(self.bits()),
+                new: Self::ICC_IGRPENN_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ERRSELR_EL1"),
+                old: Self::ERRSELR_EL1_FIELD.extract(self.bits()),
+                new: Self::ERRSELR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ERXCTLR_EL1"),
+                old: Self::ERXCTLR_EL1_FIELD.extract(self.bits()),
+                new: Self::ERXCTLR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ERXSTATUS_EL1"),
+                old: Self::ERXSTATUS_EL1_FIELD.extract(self.bits()),
+                new: Self::ERXSTATUS_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ERXMISCn_EL1"),
+                old: Self::ERXMISCN_EL1_FIELD.extract(self.bits()),
+                new: Self::ERXMISCN_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ERXPFGCTL_EL1"),
+                old: Self::ERXPFGCTL_EL1_FIELD.extract(self.bits()),
+                new: Self::ERXPFGCTL_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ERXPFGCDN_EL1"),
+                old: Self::ERXPFGCDN_EL1_FIELD.extract(self.bits()),
+                new: Self::ERXPFGCDN_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ERXADDR_EL1"),
+                old: Self::ERXADDR_EL1_FIELD.extract(self.bits()),
+                new: Self::ERXADDR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nACCDATA_EL1"),
+                old: Self::NACCDATA_EL1_FIELD.extract(self.bits()),
+                new: Self::NACCDATA_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nGCS_EL0"),
+                old: Self::NGCS_EL0_FIELD.extract(self.bits()),
+                new: Self::NGCS_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nGCS_EL1"),
+                old: Self::NGCS_EL1_FIELD.extract(self.bits()),
+                new: Self::NGCS_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nSMPRI_EL1"),
+                old: Self::NSMPRI_EL1_FIELD.extract(self.bits()),
+                new: Self::NSMPRI_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nTPIDR2_EL0"),
+                old: Self::NTPIDR2_EL0_FIELD.extract(self.bits()),
+                new: Self::NTPIDR2_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nRCWMASK_EL1"),
+                old: Self::NRCWMASK_EL1_FIELD.extract(self.bits()),
+                new: Self::NRCWMASK_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nPIRE0_EL1"),
+                old: Self::NPIRE0_EL1_FIELD.extract(self.bits()),
+                new: Self::NPIRE0_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nPIR_EL1"),
+                old: Self::NPIR_EL1_FIELD.extract(self.bits()),
+                new: Self::NPIR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nPOR_EL0"),
+                old: Self::NPOR_EL0_FIELD.extract(self.bits()),
+                new: Self::NPOR_EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nPOR_EL1"),
+                old: Self::NPOR_EL1_FIELD.extract(self.bits()),
+                new: Self::NPOR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nS2POR_EL1"),
+                old: Self::NS2POR_EL1_FIELD.extract(self.bits()),
+                new: Self::NS2POR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nMAIR2_EL1"),
+                old: Self::NMAIR2_EL1_FIELD.extract(self.bits()),
+                new: Self::NMAIR2_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nAMAIR2_EL1"),
+                old: Self::NAMAIR2_EL1_FIELD.extract(self.bits()),
+                new: Self::NAMAIR2_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits()
+                    & 0b1000_0100_0101_0000_0000_0001_0110_0010_0100_1100_0110_0000_0100,
+                new: other.bits()
+                    & 0b1000_0100_0101_0000_0000_0001_0110_0010_0100_1100_0110_0000_0100,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl HfgwtrEl2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl HfgwtrEl2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el3")]
+bitflags! {
+    /// `SCR_EL3` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct ScrEl3: u64 {
+        /// RES1 bits in the `SCR_EL3` register.
+        const RES1 = 0b11_0000;
+        /// Non-secure.
+        const NS = 1 << 0;
+        /// Take physical IRQs at EL3.
+        const IRQ = 1 << 1;
+        /// Take physical FIQs at EL3.
+        const FIQ = 1 << 2;
+        /// Take external abort and SError exceptions at EL3.
+        const EA = 1 << 3;
+        /// Disable SMC instructions.
+        const SMD = 1 << 7;
+        /// Enable HVC instructions.
+        const HCE = 1 << 8;
+        /// Disable execution from non-secure memory.
+        const SIF = 1 << 9;
+        /// Enable AArch64 in lower ELs.
+        const RW = 1 << 10;
+        /// Trap physical secure timer to EL3.
+        const ST = 1 << 11;
+        /// Trap WFI to EL3.
+        const TWI = 1 << 12;
+        /// Trap WFE to EL3.
+        const TWE = 1 << 13;
+        /// Trap LOR register access to EL3.
+        const TLOR = 1 << 14;
+        /// Trap error record register access to EL3.
+        const TERR = 1 << 15;
+        /// Don't trap PAC key registers to EL3.
+        const APK = 1 << 16;
+        /// Don't trap PAuth instructions to EL3.
+        const API = 1 << 17;
+        /// Enable Secure EL2.
+        const EEL2 = 1 << 18;
+        /// Synchronous external aborts are taken as SErrors.
+        const EASE = 1 << 19;
+        /// Take SError exceptions at EL3.
+        const NMEA = 1 << 20;
+        /// Enable fault injection at lower ELs.
+        const FIEN = 1 << 21;
+        /// Trap ID group 3 registers to EL3.
+        const TID3 = 1 << 22;
+        /// Trap ID group 5 register to EL3.
+        const TID5 = 1 << 23;
+        /// `POE2En` bit.
+        const POE2EN = 1 << 24;
+        /// Enable SCXT at lower ELs.
+        const ENSCXT = 1 << 25;
+        /// Enable memory tagging at lower ELs.
+        const ATA = 1 << 26;
+        /// Enable fine-grained traps to EL2.
+        const FGTEN = 1 << 27;
+        /// Enable access to CNTPOFF_EL2.
+        const ECVEN = 1 << 28;
+        /// Enable a configurable delay for WFE traps.
+        const TWEDEN = 1 << 29;
+        /// Enable activity monitors virtual offsets.
+        const AMVOFFEN = 1 << 35;
+        /// Enable ST64BV0 at lower ELs.
+        const ENAS0 = 1 << 36;
+        /// Enable ACCDATA_EL1 at lower ELs.
+        const ADEN = 1 << 37;
+        /// Enable HCRX_EL2.
+        const HXEN = 1 << 38;
+        /// Enable guarded control stack.
+        const GCSEN = 1 << 39;
+        /// Trap RNDR and RNDRRS to EL3.
+        const TRNDR = 1 << 40;
+        /// Enable TPIDR2_EL0 at lower ELs.
+        const ENTP2 = 1 << 41;
+        /// Enable RCW and RCWS mask registers at lower ELs.
+        const RCWMASKEN = 1 << 42;
+        /// Enable TCR2_ELx registers at lower ELs.
+        const TCR2EN = 1 << 43;
+        /// Enable SCTLR2_ELx registers at lower ELs.
+        const SCTLR2EN = 1 << 44;
+        /// Enable permission indirection and overlay registers at lower ELs.
+        const PIEN = 1 << 45;
+        /// Enable MAIR2_ELx and AMAIR2_ELx at lower ELs.
+        const AIEN = 1 << 46;
+        /// Enable 128-bit system registers at  lower ELs.
+        const D128EN = 1 << 47;
+        /// Route GPFs to EL3.
+        const GPF = 1 << 48;
+        /// Enable MECID registers at EL2.
+        const MECEN = 1 << 49;
+        /// Enable access to FPMR at lower ELs.
+        const ENFPM = 1 << 50;
+        /// Take synchronous external abort and physical SError exception to EL3.
+        const TMEA = 1 << 51;
+        /// Trap writes to Error Record registers to EL3.
+        const TWERR = 1 << 52;
+        /// Enable access to physical fault address registers at lower ELs.
+        const PFAREN = 1 << 53;
+        /// Enable access to mask registers at lower ELs.
+        const SRMASKEN = 1 << 54;
+        /// Enable implementation-defined 128-bit system registers.
+        const ENIDCP128 = 1 << 55;
+        /// `VTLBIDEn` bit.
+        const VTLBIDEN = 1 << 56;
+        /// A delegated SError exception is pending.
+        const DSE = 1 << 57;
+        /// Enable delegated SError exceptions.
+        const ENDSE = 1 << 58;
+        /// Enable fine-grained traps to EL2.
+        const FGTEN2 = 1 << 59;
+        /// Enable HDBSSBR_EL2 and HDBSSPROD_EL2 registers at EL2.
+        const HDBSSEN = 1 << 60;
+        /// Enable HACDBSBR_EL2 and HACDBSCONS_EL2 registers at EL2.
+        const HACDBSEN = 1 << 61;
+        /// Non-secure realm world bit.
+        const NSE = 1 << 62;
+        /// `TPLIMEn` bit.
+        const TPLIMEN = 1 << 63;
+    }
+}
+
+#[cfg(feature = "el3")]
+impl ScrEl3 {
+    /// Descriptor of the `NS` field, for use with [`FieldAccess`].
+    pub const NS_FIELD: Field<Self, bool, 0, 1> = Field::new("SCR_EL3", "NS");
+    /// Descriptor of the `IRQ` field, for use with [`FieldAccess`].
+    pub const IRQ_FIELD: Field<Self, bool, 1, 1> = Field::new("SCR_EL3", "IRQ");
+    /// Descriptor of the `FIQ` field, for use with [`FieldAccess`].
+    pub const FIQ_FIELD: Field<Self, bool, 2, 1> = Field::new("SCR_EL3", "FIQ");
+    /// Descriptor of the `EA` field, for use with [`FieldAccess`].
+    pub const EA_FIELD: Field<Self, bool, 3, 1> = Field::new("SCR_EL3", "EA");
+    /// Descriptor of the `SMD` field, for use with [`FieldAccess`].
+    pub const SMD_FIELD: Field<Self, bool, 7, 1> = Field::new("SCR_EL3", "SMD");
+    /// Descriptor of the `HCE` field, for use with [`FieldAccess`].
+    pub const HCE_FIELD: Field<Self, bool, 8, 1> = Field::new("SCR_EL3", "HCE");
+    /// Descriptor of the `SIF` field, for use with [`FieldAccess`].
+    pub const SIF_FIELD: Field<Self, bool, 9, 1> = Field::new("SCR_EL3", "SIF");
+    /// Descriptor of the `RW` field, for use with [`FieldAccess`].
+    pub const RW_FIELD: Field<Self, bool, 10, 1> = Field::new("SCR_EL3", "RW");
+    /// Descriptor of the `ST` field, for use with [`FieldAccess`].
+    pub const ST_FIELD: Field<Self, bool, 11, 1> = Field::new("SCR_EL3", "ST");
+    /// Descriptor of the `TWI` field, for use with [`FieldAccess`].
+    pub const TWI_FIELD: Field<Self, bool, 12, 1> = Field::new("SCR_EL3", "TWI");
+    /// Descriptor of the `TWE` field, for use with [`FieldAccess`].
+    pub const TWE_FIELD: Field<Self, bool, 13, 1> = Field::new("SCR_EL3", "TWE");
+    /// Descriptor of the `TLOR` field, for use with [`FieldAccess`].
+    pub const TLOR_FIELD: Field<Self, bool, 14, 1> = Field::new("SCR_EL3", "TLOR");
+    /// Descriptor of the `TERR` field, for use with [`FieldAccess`].
+    pub const TERR_FIELD: Field<Self, bool, 15, 1> = Field::new("SCR_EL3", "TERR");
+    /// Descriptor of the `APK` field, for use with [`FieldAccess`].
+    pub const APK_FIELD: Field<Self, bool, 16, 1> = Field::new("SCR_EL3", "APK");
+    /// Descriptor of the `API` field, for use with [`FieldAccess`].
+    pub const API_FIELD: Field<Self, bool, 17, 1> = Field::new("SCR_EL3", "API");
+    /// Descriptor of the `EEL2` field, for use with [`FieldAccess`].
+    pub const EEL2_FIELD: Field<Self, bool, 18, 1> = Field::new("SCR_EL3", "EEL2");
+    /// Descriptor of the `EASE` field, for use with [`FieldAccess`].
+    pub const EASE_FIELD: Field<Self, bool, 19, 1> = Field::new("SCR_EL3", "EASE");
+    /// Descriptor of the `NMEA` field, for use with [`FieldAccess`].
+    pub const NMEA_FIELD: Field<Self, bool, 20, 1> = Field::new("SCR_EL3", "NMEA");
+    /// Descriptor of the `FIEN` field, for use with [`FieldAccess`].
+    pub const FIEN_FIELD: Field<Self, bool, 21, 1> = Field::new("SCR_EL3", "FIEN");
+    /// Descriptor of the `TID3` field, for use with [`FieldAccess`].
+    pub const TID3_FIELD: Field<Self, bool, 22, 1> = Field::new("SCR_EL3", "TID3");
+    /// Descriptor of the `TID5` field, for use with [`FieldAccess`].
+    pub const TID5_FIELD: Field<Self, bool, 23, 1> = Field::new("SCR_EL3", "TID5");
+    /// Descriptor of the `POE2En` field, for use with [`FieldAccess`].
+    pub const POE2EN_FIELD: Field<Self, bool, 24, 1> = Field::new("SCR_EL3", "POE2En");
+    /// Descriptor of the `EnSCXT` field, for use with [`FieldAccess`].
+    pub const ENSCXT_FIELD: Field<Self, bool, 25, 1> = Field::new("SCR_EL3", "EnSCXT");
+    /// Descriptor of the `ATA` field, for use with [`FieldAccess`].
+    pub const ATA_FIELD: Field<Self, bool, 26, 1> = Field::new("SCR_EL3", "ATA");
+    /// Descriptor of the `FGTEn` field, for use with [`FieldAccess`].
+    pub const FGTEN_FIELD: Field<Self, bool, 27, 1> = Field::new("SCR_EL3", "FGTEn");
+    /// Descriptor of the `ECVEn` field, for use with [`FieldAccess`].
+    pub const ECVEN_FIELD: Field<Self, bool, 28, 1> = Field::new("SCR_EL3", "ECVEn");
+    /// Descriptor of the `TWEDEn` field, for use with [`FieldAccess`].
+    pub const TWEDEN_FIELD: Field<Self, bool, 29, 1> = Field::new("SCR_EL3", "TWEDEn");
+    /// Descriptor of the `TWEDEL` field, for use with [`FieldAccess`].
+    pub const TWEDEL_FIELD: Field<Self, u8, 30, 4> = Field::new("SCR_EL3", "TWEDEL");
+    /// Descriptor of the `AMVOFFEN` field, for use with [`FieldAccess`].
+    pub const AMVOFFEN_FIELD: Field<Self, bool, 35, 1> = Field::new("SCR_EL3", "AMVOFFEN");
+    /// Descriptor of the `EnAS0` field, for use with [`FieldAccess`].
+    pub const ENAS0_FIELD: Field<Self, bool, 36, 1> = Field::new("SCR_EL3", "EnAS0");
+    /// Descriptor of the `ADEn` field, for use with [`FieldAccess`].
+    pub const ADEN_FIELD: Field<Self, bool, 37, 1> = Field::new("SCR_EL3", "ADEn");
+    /// Descriptor of the `HXEn` field, for use with [`FieldAccess`].
+    pub const HXEN_FIELD: Field<Self, bool, 38, 1> = Field::new("SCR_EL3", "HXEn");
+    /// Descriptor of the `GCSEn` field, for use with [`FieldAccess`].
+    pub const GCSEN_FIELD: Field<Self, bool, 39, 1> = Field::new("SCR_EL3", "GCSEn");
+    /// Descriptor of the `TRNDR` field, for use with [`FieldAccess`].
+    pub const TRNDR_FIELD: Field<Self, bool, 40, 1> = Field::new("SCR_EL3", "TRNDR");
+    /// Descriptor of the `EnTP2` field, for use with [`FieldAccess`].
+    pub const ENTP2_FIELD: Field<Self, bool, 41, 1> = Field::new("SCR_EL3", "EnTP2");
+    /// Descriptor of the `RCWMASKEn` field, for use with [`FieldAccess`].
+    pub const RCWMASKEN_FIELD: Field<Self, bool, 42, 1> = Field::new("SCR_EL3", "RCWMASKEn");
+    /// Descriptor of the `TCR2En` field, for use with [`FieldAccess`].
+    pub const TCR2EN_FIELD: Field<Self, bool, 43, 1> = Field::new("SCR_EL3", "TCR2En");
+    /// Descriptor of the `SCTLR2En` field, for use with [`FieldAccess`].
+    pub const SCTLR2EN_FIELD: Field<Self, bool, 44, 1> = Field::new("SCR_EL3", "SCTLR2En");
+    /// Descriptor of the `PIEn` field, for use with [`FieldAccess`].
+    pub const PIEN_FIELD: Field<Self, bool, 45, 1> = Field::new("SCR_EL3", "PIEn");
+    /// Descriptor of the `AIEn` field, for use with [`FieldAccess`].
+    pub const AIEN_FIELD: Field<Self, bool, 46, 1> = Field::new("SCR_EL3", "AIEn");
+    /// Descriptor of the `D128En` field, for use with [`FieldAccess`].
+    pub const D128EN_FIELD: Field<Self, bool, 47, 1> = Field::new("SCR_EL3", "D128En");
+    /// Descriptor of the `GPF` field, for use with [`FieldAccess`].
+    pub const GPF_FIELD: Field<Self, bool, 48, 1> = Field::new("SCR_EL3", "GPF");
+    /// Descriptor of the `MECEn` field, for use with [`FieldAccess`].
+    pub const MECEN_FIELD: Field<Self, bool, 49, 1> = Field::new("SCR_EL3", "MECEn");
+    /// Descriptor of the `EnFPM` field, for use with [`FieldAccess`].
+    pub const ENFPM_FIELD: Field<Self, bool, 50, 1> = Field::new("SCR_EL3", "EnFPM");
+    /// Descriptor of the `TMEA` field, for use with [`FieldAccess`].
+    pub const TMEA_FIELD: Field<Self, bool, 51, 1> = Field::new("SCR_EL3", "TMEA");
+    /// Descriptor of the `TWERR` field, for use with [`FieldAccess`].
+    pub const TWERR_FIELD: Field<Self, bool, 52, 1> = Field::new("SCR_EL3", "TWERR");
+    /// Descriptor of the `PFAREn` field, for use with [`FieldAccess`].
+    pub const PFAREN_FIELD: Field<Self, bool, 53, 1> = Field::new("SCR_EL3", "PFAREn");
+    /// Descriptor of the `SRMASKEn` field, for use with [`FieldAccess`].
+    pub const SRMASKEN_FIELD: Field<Self, bool, 54, 1> = Field::new("SCR_EL3", "SRMASKEn");
+    /// Descriptor of the `EnIDCP128` field, for use with [`FieldAccess`].
+    pub const ENIDCP128_FIELD: Field<Self, bool, 55, 1> = Field::new("SCR_EL3", "EnIDCP128");
+    /// Descriptor of the `VTLBIDEn` field, for use with [`FieldAccess`].
+    pub const VTLBIDEN_FIELD: Field<Self, bool, 56, 1> = Field::new("SCR_EL3", "VTLBIDEn");
+    /// Descriptor of the `DSE` field, for use with [`FieldAccess`].
+    pub const DSE_FIELD: Field<Self, bool, 57, 1> = Field::new("SCR_EL3", "DSE");
+    /// Descriptor of the `EnDSE` field, for use with [`FieldAccess`].
+    pub const ENDSE_FIELD: Field<Self, bool, 58, 1> = Field::new("SCR_EL3", "EnDSE");
+    /// Descriptor of the `FGTEn2` field, for use with [`FieldAccess`].
+    pub const FGTEN2_FIELD: Field<Self, bool, 59, 1> = Field::new("SCR_EL3", "FGTEn2");
+    /// Descriptor of the `HDBSSEn` field, for use with [`FieldAccess`].
+    pub const HDBSSEN_FIELD: Field<Self, bool, 60, 1> = Field::new("SCR_EL3", "HDBSSEn");
+    /// Descriptor of the `HACDBSEn` field, for use with [`FieldAccess`].
+    pub const HACDBSEN_FIELD: Field<Self, bool, 61, 1> = Field::new("SCR_EL3", "HACDBSEn");
+    /// Descriptor of the `NSE` field, for use with [`FieldAccess`].
+    pub const NSE_FIELD: Field<Self, bool, 62, 1> = Field::new("SCR_EL3", "NSE");
+    /// Descriptor of the `TPLIMEn` field, for use with [`FieldAccess`].
+    pub const TPLIMEN_FIELD: Field<Self, bool, 63, 1> = Field::new("SCR_EL3", "TPLIMEn");
+
+    /// Returns the value of the `TWEDEL` field.
+    pub const fn twedel(self) -> u8 {
+        Self::TWEDEL_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `TWEDEL` field.
+    pub const fn set_twedel(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::TWEDEL_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `TWEDEL` field set to the given value.
+    pub const fn with_twedel(mut self, value: u8) -> Self {
+        self.set_twedel(value);
+        self
+    }
+
+    /// Sets the value of the `TWEDEL` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_twedel(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_twedel(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `TWEDEL` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_twedel(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::TWEDEL_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el3")]
+impl Display for ScrEl3 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("ScrEl3");
+        let bits = self.bits();
+        s.field("NS", &Self::NS_FIELD.extract(bits));
+        s.field("IRQ", &Self::IRQ_FIELD.extract(bits));
+        s.field("FIQ", &Self::FIQ_FIELD.extract(bits));
+        s.field("EA", &Self::EA_FIELD.extract(bits));
+        s.field("SMD", &Self::SMD_FIELD.extract(bits));
+        s.field("HCE", &Self::HCE_FIELD.extract(bits));
+        s.field("SIF", &Self::SIF_FIELD.extract(bits));
+        s.field("RW", &Self::RW_FIELD.extract(bits));
+        s.field("ST", &Self::ST_FIELD.extract(bits));
+        s.field("TWI", &Self::TWI_FIELD.extract(bits));
+        s.field("TWE", &Self::TWE_FIELD.extract(bits));
+        s.field("TLOR", &Self::TLOR_FIELD.extract(bits));
+        s.field("TERR", &Self::TERR_FIELD.extract(bits));
+        s.field("APK", &Self::APK_FIELD.extract(bits));
+        s.field("API", &Self::API_FIELD.extract(bits));
+        s.field("EEL2", &Self::EEL2_FIELD.extract(bits));
+        s.field("EASE", &Self::EASE_FIELD.extract(bits));
+        s.field("NMEA", &Self::NMEA_FIELD.extract(bits));
+        s.field("FIEN", &Self::FIEN_FIELD.extract(bits));
+        s.field("TID3", &Self::TID3_FIELD.extract(bits));
+        s.field("TID5", &Self::TID5_FIELD.extract(bits));
+        s.field("POE2En", &Self::POE2EN_FIELD.extract(bits));
+        s.field("EnSCXT", &Self::ENSCXT_FIELD.extract(bits));
+        s.field("ATA", &Self::ATA_FIELD.extract(bits));
+        s.field("FGTEn", &Self::FGTEN_FIELD.extract(bits));
+        s.field("ECVEn", &Self::ECVEN_FIELD.extract(bits));
+        s.field("TWEDEn", &Self::TWEDEN_FIELD.extract(bits));
+        s.field("TWEDEL", &Self::TWEDEL_FIELD.extract(bits));
+        s.field("AMVOFFEN", &Self::AMVOFFEN_FIELD.extract(bits));
+        s.field("EnAS0", &Self::ENAS0_FIELD.extract(bits));
+        s.field("ADEn", &Self::ADEN_FIELD.extract(bits));
+        s.field("HXEn", &Self::HXEN_FIELD.extract(bits));
+        s.field("GCSEn", &Self::GCSEN_FIELD.extract(bits));
+        s.field("TRNDR", &Self::TRNDR_FIELD.extract(bits));
+        s.field("EnTP2", &Self::ENTP2_FIELD.extract(bits));
+        s.field("RCWMASKEn", &Self::RCWMASKEN_FIELD.extract(bits));
+        s.field("TCR2En", &Self::TCR2EN_FIELD.extract(bits));
+        s.field("SCTLR2En", &Self::SCTLR2EN_FIELD.extract(bits));
+        s.field("PIEn", &Self::PIEN_FIELD.extract(bits));
+        s.field("AIEn", &Self::AIEN_FIELD.extract(bits));
+        s.field("D128En", &Self::D128EN_FIELD.extract(bits));
+        s.field("GPF", &Self::GPF_FIELD.extract(bits));
+        s.field("MECEn", &Self::MECEN_FIELD.extract(bits));
+        s.field("EnFPM", &Self::ENFPM_FIELD.extract(bits));
+        s.field("TMEA", &Self::TMEA_FIELD.extract(bits));
+        s.field("TWERR", &Self::TWERR_FIELD.extract(bits));
+        s.field("PFAREn", &Self::PFAREN_FIELD.extract(bits));
+        s.field("SRMASKEn", &Self::SRMASKEN_FIELD.extract(bits));
+        s.field("EnIDCP128", &Self::ENIDCP128_FIELD.extract(bits));
+        s.field("VTLBIDEn", &Self::VTLBIDEN_FIELD.extract(bits));
+        s.field("DSE", &Self::DSE_FIELD.extract(bits));
+        s.field("EnDSE", &Self::ENDSE_FIELD.extract(bits));
+        s.field("FGTEn2", &Self::FGTEN2_FIELD.extract(bits));
+        s.field("HDBSSEn", &Self::HDBSSEN_FIELD.extract(bits));
+        s.field("HACDBSEn", &Self::HACDBSEN_FIELD.extract(bits));
+        s.field("NSE", &Self::NSE_FIELD.extract(bits));
+        s.field("TPLIMEn", &Self::TPLIMEN_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el3")]
+impl defmt::Format for ScrEl3 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "ScrEl3 {{ NS: {=u64}, IRQ: {=u64}, FIQ: {=u64}, EA: {=u64}, SMD: {=u64}, HCE: {=u64}, SIF: {=u64}, RW: {=u64}, ST: {=u64}, TWI: {=u64}, TWE: {=u64}, TLOR: {=u64}, TERR: {=u64}, APK: {=u64}, API: {=u64}, EEL2: {=u64}, EASE: {=u64}, NMEA: {=u64}, FIEN: {=u64}, TID3: {=u64}, TID5: {=u64}, POE2En: {=u64}, EnSCXT: {=u64}, ATA: {=u64}, FGTEn: {=u64}, ECVEn: {=u64}, TWEDEn: {=u64}, TWEDEL: {=u64}, AMVOFFEN: {=u64}, EnAS0: {=u64}, ADEn: {=u64}, HXEn: {=u64}, GCSEn: {=u64}, TRNDR: {=u64}, EnTP2: {=u64}, RCWMASKEn: {=u64}, TCR2En: {=u64}, SCTLR2En: {=u64}, PIEn: {=u64}, AIEn: {=u64}, D128En: {=u64}, GPF: {=u64}, MECEn: {=u64}, EnFPM: {=u64}, TMEA: {=u64}, TWERR: {=u64}, PFAREn: {=u64}, SRMASKEn: {=u64}, EnIDCP128: {=u64}, VTLBIDEn: {=u64}, DSE: {=u64}, EnDSE: {=u64}, FGTEn2: {=u64}, HDBSSEn: {=u64}, HACDBSEn: {=u64}, NSE: {=u64}, TPLIMEn: {=u64} }}",
+            Self::NS_FIELD.extract(self.bits()),
+            Self::IRQ_FIELD.extract(self.bits()),
+            Self::FIQ_FIELD.extract(self.bits()),
+            Self::EA_FIELD.extract(self.bits()),
+            Self::SMD_FIELD.extract(self.bits()),
+            Self::HCE_FIELD.extract(self.bits()),
+            Self::SIF_FIELD.extract(self.bits()),
+            Self::RW_FIELD.extract(self.bits()),
+            Self::ST_FIELD.extract(self.bits()),
+            Self::TWI_FIELD.extract(self.bits()),
+            Self::TWE_FIELD.extract(self.bits()),
+            Self::TLOR_FIELD.extract(self.bits()),
+            Self::TERR_FIELD.extract(self.bits()),
+            Self::APK_FIELD.extract(self.bits()),
+            Self::API_FIELD.extract(self.bits()),
+            Self::EEL2_FIELD.extract(self.bits()),
+            Self::EASE_FIELD.extract(self.bits()),
+            Self::NMEA_FIELD.extract(self.bits()),
+            Self::FIEN_FIELD.extract(self.bits()),
+            Self::TID3_FIELD.extract(self.bits()),
+            Self::TID5_FIELD.extract(self.bits()),
+            Self::POE2EN_FIELD.extract(self.bits()),
+            Self::ENSCXT_FIELD.extract(self.bits()),
+            Self::ATA_FIELD.extract(self.bits()),
+            Self::FGTEN_FIELD.extract(self.bits()),
+            Self::ECVEN_FIELD.extract(self.bits()),
+            Self::TWEDEN_FIELD.extract(self.bits()),
+            Self::TWEDEL_FIELD.extract(self.bits()),
+            Self::AMVOFFEN_FIELD.extract(self.bits()),
+            Self::ENAS0_FIELD.extract(self.bits()),
+            Self::ADEN_FIELD.extract(self.bits()),
+            Self::HXEN_FIELD.extract(self.bits()),
+            Self::GCSEN_FIELD.extract(self.bits()),
+            Self::TRNDR_FIELD.extract(self.bits()),
+            Self::ENTP2_FIELD.extract(self.bits()),
+            Self::RCWMASKEN_FIELD.extract(self.bits()),
+            Self::TCR2EN_FIELD.extract(self.bits()),
+            Self::SCTLR2EN_FIELD.extract(self.bits()),
+            Self::PIEN_FIELD.extract(self.bits()),
+            Self::AIEN_FIELD.extract(self.bits()),
+            Self::D128EN_FIELD.extract(self.bits()),
+            Self::GPF_FIELD.extract(self.bits()),
+            Self::MECEN_FIELD.extract(self.bits()),
+            Self::ENFPM_FIELD.extract(self.bits()),
+            Self::TMEA_FIELD.extract(self.bits()),
+            Self::TWERR_FIELD.extract(self.bits()),
+            Self::PFAREN_FIELD.extract(self.bits()),
+            Self::SRMASKEN_FIELD.extract(self.bits()),
+            Self::ENIDCP128_FIELD.extract(self.bits()),
+            Self::VTLBIDEN_FIELD.extract(self.bits()),
+            Self::DSE_FIELD.extract(self.bits()),
+            Self::ENDSE_FIELD.extract(self.bits()),
+            Self::FGTEN2_FIELD.extract(self.bits()),
+            Self::HDBSSEN_FIELD.extract(self.bits()),
+            Self::HACDBSEN_FIELD.extract(self.bits()),
+            Self::NSE_FIELD.extract(self.bits()),
+            Self::TPLIMEN_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el3")]
+impl serde::Serialize for ScrEl3 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el3")]
+impl<'de> serde::Deserialize<'de> for ScrEl3 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el3")]
+impl ScrEl3 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("NS"),
+                old: Self::NS_FIELD.extract(self.bits()),
+                new: Self::NS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IRQ"),
+                old: Self::IRQ_FIELD.extract(self.bits()),
+                new: Self::IRQ_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FIQ"),
+                old: Self::FIQ_FIELD.extract(self.bits()),
+                new: Self::FIQ_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EA"),
+                old: Self::EA_FIELD.extract(self.bits()),
+                new: Self::EA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SMD"),
+                old: Self::SMD_FIELD.extract(self.bits()),
+                new: Self::SMD_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HCE"),
+                old: Self::HCE_FIELD.extract(self.bits()),
+                new: Self::HCE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SIF"),
+                old: Self::SIF_FIELD.extract(self.bits()),
+                new: Self::SIF_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("RW"),
+                old: Self::RW_FIELD.extract(self.bits()),
+                new: Self::RW_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ST"),
+                old: Self::ST_FIELD.extract(self.bits()),
+                new: Self::ST_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TWI"),
+                old: Self::TWI_FIELD.extract(self.bits()),
+                new: Self::TWI_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TWE"),
+                old: Self::TWE_FIELD.extract(self.bits()),
+                new: Self::TWE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TLOR"),
+                old: Self::TLOR_FIELD.extract(self.bits()),
+                new: Self::TLOR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TERR"),
+                old: Self::TERR_FIELD.extract(self.bits()),
+                new: Self::TERR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("APK"),
+                old: Self::APK_FIELD.extract(self.bits()),
+                new: Self::APK_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("API"),
+                old: Self::API_FIELD.extract(self.bits()),
+                new: Self::API_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EEL2"),
+                old: Self::EEL2_FIELD.extract(self.bits()),
+                new: Self::EEL2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EASE"),
+                old: Self::EASE_FIELD.extract(self.bits()),
+                new: Self::EASE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("NMEA"),
+                old: Self::NMEA_FIELD.extract(self.bits()),
+                new: Self::NMEA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FIEN"),
+                old: Self::FIEN_FIELD.extract(self.bits()),
+                new: Self::FIEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TID3"),
+                old: Self::TID3_FIELD.extract(self.bits()),
+                new: Self::TID3_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TID5"),
+                old: Self::TID5_FIELD.extract(self.bits()),
+                new: Self::TID5_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("POE2En"),
+                old: Self::POE2EN_FIELD.extract(self.bits()),
+                new: Self::POE2EN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnSCXT"),
+                old: Self::ENSCXT_FIELD.extract(self.bits()),
+                new: Self::ENSCXT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ATA"),
+                old: Self::ATA_FIELD.extract(self.bits()),
+                new: Self::ATA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FGTEn"),
+                old: Self::FGTEN_FIELD.extract(self.bits()),
+                new: Self::FGTEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ECVEn"),
+                old: Self::ECVEN_FIELD.extract(self.bits()),
+                new: Self::ECVEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TWEDEn"),
+                old: Self::TWEDEN_FIELD.extract(self.bits()),
+                new: Self::TWEDEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TWEDEL"),
+                old: Self::TWEDEL_FIELD.extract(self.bits()),
+                new: Self::TWEDEL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("AMVOFFEN"),
+                old: Self::AMVOFFEN_FIELD.extract(self.bits()),
+                new: Self::AMVOFFEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnAS0"),
+                old: Self::ENAS0_FIELD.extract(self.bits()),
+                new: Self::ENAS0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ADEn"),
+                old: Self::ADEN_FIELD.extract(self.bits()),
+                new: Self::ADEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HXEn"),
+                old: Self::HXEN_FIELD.extract(self.bits()),
+                new: Self::HXEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("GCSEn"),
+                old: Self::GCSEN_FIELD.extract(self.bits()),
+                new: Self::GCSEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TRNDR"),
+                old: Self::TRNDR_FIELD.extract(self.bits()),
+                new: Self::TRNDR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnTP2"),
+                old: Self::ENTP2_FIELD.extract(self.bits()),
+                new: Self::ENTP2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("RCWMASKEn"),
+                old: Self::RCWMASKEN_FIELD.extract(self.bits()),
+                new: Self::RCWMASKEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TCR2En"),
+                old: Self::TCR2EN_FIELD.extract(self.bits()),
+                new: Self::TCR2EN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SCTLR2En"),
+                old: Self::SCTLR2EN_FIELD.extract(self.bits()),
+                new: Self::SCTLR2EN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PIEn"),
+                old: Self::PIEN_FIELD.extract(self.bits()),
+                new: Self::PIEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("AIEn"),
+                old: Self::AIEN_FIELD.extract(self.bits()),
+                new: Self::AIEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("D128En"),
+                old: Self::D128EN_FIELD.extract(self.bits()),
+                new: Self::D128EN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("GPF"),
+                old: Self::GPF_FIELD.extract(self.bits()),
+                new: Self::GPF_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("MECEn"),
+                old: Self::MECEN_FIELD.extract(self.bits()),
+                new: Self::MECEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnFPM"),
+                old: Self::ENFPM_FIELD.extract(self.bits()),
+                new: Self::ENFPM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TMEA"),
+                old: Self::TMEA_FIELD.extract(self.bits()),
+                new: Self::TMEA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TWERR"),
+                old: Self::TWERR_FIELD.extract(self.bits()),
+                new: Self::TWERR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PFAREn"),
+                old: Self::PFAREN_FIELD.extract(self.bits()),
+                new: Self::PFAREN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SRMASKEn"),
+                old: Self::SRMASKEN_FIELD.extract(self.bits()),
+                new: Self::SRMASKEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnIDCP128"),
+                old: Self::ENIDCP128_FIELD.extract(self.bits()),
+                new: Self::ENIDCP128_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VTLBIDEn"),
+                old: Self::VTLBIDEN_FIELD.extract(self.bits()),
+                new: Self::VTLBIDEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DSE"),
+                old: Self::DSE_FIELD.extract(self.bits()),
+                new: Self::DSE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnDSE"),
+                old: Self::ENDSE_FIELD.extract(self.bits()),
+                new: Self::ENDSE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FGTEn2"),
+                old: Self::FGTEN2_FIELD.extract(self.bits()),
+                new: Self::FGTEN2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HDBSSEn"),
+                old: Self::HDBSSEN_FIELD.extract(self.bits()),
+                new: Self::HDBSSEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HACDBSEn"),
+                old: Self::HACDBSEN_FIELD.extract(self.bits()),
+                new: Self::HACDBSEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("NSE"),
+                old: Self::NSE_FIELD.extract(self.bits()),
+                new: Self::NSE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TPLIMEn"),
+                old: Self::TPLIMEN_FIELD.extract(self.bits()),
+                new: Self::TPLIMEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b100_0000_0000_0000_0000_0000_0000_0111_0000,
+                new: other.bits() & 0b100_0000_0000_0000_0000_0000_0000_0111_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el3")]
+impl ScrEl3 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b11_0000;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el3")]
+impl ScrEl3 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `SCTLR2_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct Sctlr2El1: u64 {
+        /// `NMEA` bit.
+        const NMEA = 1 << 2;
+        /// `EnADERR` bit.
+        const ENADERR = 1 << 3;
+        /// `EnANERR` bit.
+        const ENANERR = 1 << 4;
+        /// `EASE` bit.
+        const EASE = 1 << 5;
+        /// `EnIDCP128` bit.
+        const ENIDCP128 = 1 << 6;
+        /// `EnPACM` bit.
+        const ENPACM = 1 << 7;
+        /// `EnPACM0` bit.
+        const ENPACM0 = 1 << 8;
+        /// `CPTA` bit.
+        const CPTA = 1 << 9;
+        /// `CPTA0` bit.
+        const CPTA0 = 1 << 10;
+        /// `CPTM` bit.
+        const CPTM = 1 << 11;
+        /// `CPTM0` bit.
+        const CPTM0 = 1 << 12;
+        /// `DTZ` bit.
+        const DTZ = 1 << 14;
+        /// `TEIS` bit.
+        const TEIS = 1 << 15;
+        /// `TEOS` bit.
+        const TEOS = 1 << 16;
+        /// `VT` bit.
+        const VT = 1 << 17;
+        /// `EnDB2` bit.
+        const ENDB2 = 1 << 19;
+        /// `EnDA2` bit.
+        const ENDA2 = 1 << 20;
+        /// `EnIB2` bit.
+        const ENIB2 = 1 << 21;
+        /// `EnIA2` bit.
+        const ENIA2 = 1 << 22;
+        /// `BTD0` bit.
+        const BTD0 = 1 << 23;
+        /// `BTD` bit.
+        const BTD = 1 << 24;
+        /// `FDIT` bit.
+        const FDIT = 1 << 25;
+        /// `TLBOSNIS` bit.
+        const TLBOSNIS = 1 << 26;
+        /// `EnTP3` bit.
+        const ENTP3 = 1 << 28;
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Sctlr2El1 {
+    /// Descriptor of the `NMEA` field, for use with [`FieldAccess`].
+    pub const NMEA_FIELD: Field<Self, bool, 2, 1> = Field::new("SCTLR2_EL1", "NMEA");
+    /// Descriptor of the `EnADERR` field, for use with [`FieldAccess`].
+    pub const ENADERR_FIELD: Field<Self, bool, 3, 1> = Field::new("SCTLR2_EL1", "EnADERR");
+    /// Descriptor of the `EnANERR` field, for use with [`FieldAccess`].
+    pub const ENANERR_FIELD: Field<Self, bool, 4, 1> = Field::new("SCTLR2_EL1", "EnANERR");
+    /// Descriptor of the `EASE` field, for use with [`FieldAccess`].
+    pub const EASE_FIELD: Field<Self, bool, 5, 1> = Field::new("SCTLR2_EL1", "EASE");
+    /// Descriptor of the `EnIDCP128` field, for use with [`FieldAccess`].
+    pub const ENIDCP128_FIELD: Field<Self, bool, 6, 1> = Field::new("SCTLR2_EL1", "EnIDCP128");
+    /// Descriptor of the `EnPACM` field, for use with [`FieldAccess`].
+    pub const ENPACM_FIELD: Field<Self, bool, 7, 1> = Field::new("SCTLR2_EL1", "EnPACM");
+    /// Descriptor of the `EnPACM0` field, for use with [`FieldAccess`].
+    pub const ENPACM0_FIELD: Field<Self, bool, 8, 1> = Field::new("SCTLR2_EL1", "EnPACM0");
+    /// Descriptor of the `CPTA` field, for use with [`FieldAccess`].
+    pub const CPTA_FIELD: Field<Self, bool, 9, 1> = Field::new("SCTLR2_EL1", "CPTA");
+    /// Descriptor of the `CPTA0` field, for use with [`FieldAccess`].
+    pub const CPTA0_FIELD: Field<Self, bool, 10, 1> = Field::new("SCTLR2_EL1", "CPTA0");
+    /// Descriptor of the `CPTM` field, for use with [`FieldAccess`].
+    pub const CPTM_FIELD: Field<Self, bool, 11, 1> = Field::new("SCTLR2_EL1", "CPTM");
+    /// Descriptor of the `CPTM0` field, for use with [`FieldAccess`].
+    pub const CPTM0_FIELD: Field<Self, bool, 12, 1> = Field::new("SCTLR2_EL1", "CPTM0");
+    /// Descriptor of the `DTZ` field, for use with [`FieldAccess`].
+    pub const DTZ_FIELD: Field<Self, bool, 14, 1> = Field::new("SCTLR2_EL1", "DTZ");
+    /// Descriptor of the `TEIS` field, for use with [`FieldAccess`].
+    pub const TEIS_FIELD: Field<Self, bool, 15, 1> = Field::new("SCTLR2_EL1", "TEIS");
+    /// Descriptor of the `TEOS` field, for use with [`FieldAccess`].
+    pub const TEOS_FIELD: Field<Self, bool, 16, 1> = Field::new("SCTLR2_EL1", "TEOS");
+    /// Descriptor of the `VT` field, for use with [`FieldAccess`].
+    pub const VT_FIELD: Field<Self, bool, 17, 1> = Field::new("SCTLR2_EL1", "VT");
+    /// Descriptor of the `EnDB2` field, for use with [`FieldAccess`].
+    pub const ENDB2_FIELD: Field<Self, bool, 19, 1> = Field::new("SCTLR2_EL1", "EnDB2");
+    /// Descriptor of the `EnDA2` field, for use with [`FieldAccess`].
+    pub const ENDA2_FIELD: Field<Self, bool, 20, 1> = Field::new("SCTLR2_EL1", "EnDA2");
+    /// Descriptor of the `EnIB2` field, for use with [`FieldAccess`].
+    pub const ENIB2_FIELD: Field<Self, bool, 21, 1> = Field::new("SCTLR2_EL1", "EnIB2");
+    /// Descriptor of the `EnIA2` field, for use with [`FieldAccess`].
+    pub const ENIA2_FIELD: Field<Self, bool, 22, 1> = Field::new("SCTLR2_EL1", "EnIA2");
+    /// Descriptor of the `BTD0` field, for use with [`FieldAccess`].
+    pub const BTD0_FIELD: Field<Self, bool, 23, 1> = Field::new("SCTLR2_EL1", "BTD0");
+    /// Descriptor of the `BTD` field, for use with [`FieldAccess`].
+    pub const BTD_FIELD: Field<Self, bool, 24, 1> = Field::new("SCTLR2_EL1", "BTD");
+    /// Descriptor of the `FDIT` field, for use with [`FieldAccess`].
+    pub const FDIT_FIELD: Field<Self, bool, 25, 1> = Field::new("SCTLR2_EL1", "FDIT");
+    /// Descriptor of the `TLBOSNIS` field, for use with [`FieldAccess`].
+    pub const TLBOSNIS_FIELD: Field<Self, bool, 26, 1> = Field::new("SCTLR2_EL1", "TLBOSNIS");
+    /// Descriptor of the `EnTP3` field, for use with [`FieldAccess`].
+    pub const ENTP3_FIELD: Field<Self, bool, 28, 1> = Field::new("SCTLR2_EL1", "EnTP3");
+}
+
+#[cfg(feature = "el1")]
+impl Display for Sctlr2El1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("Sctlr2El1");
+        let bits = self.bits();
+        s.field("NMEA", &Self::NMEA_FIELD.extract(bits));
+        s.field("EnADERR", &Self::ENADERR_FIELD.extract(bits));
+        s.field("EnANERR", &Self::ENANERR_FIELD.extract(bits));
+        s.field("EASE", &Self::EASE_FIELD.extract(bits));
+        s.field("EnIDCP128", &Self::ENIDCP128_FIELD.extract(bits));
+        s.field("EnPACM", &Self::ENPACM_FIELD.extract(bits));
+        s.field("EnPACM0", &Self::ENPACM0_FIELD.extract(bits));
+        s.field("CPTA", &Self::CPTA_FIELD.extract(bits));
+        s.field("CPTA0", &Self::CPTA0_FIELD.extract(bits));
+        s.field("CPTM", &Self::CPTM_FIELD.extract(bits));
+        s.field("CPTM0", &Self::CPTM0_FIELD.extract(bits));
+        s.field("DTZ", &Self::DTZ_FIELD.extract(bits));
+        s.field("TEIS", &Self::TEIS_FIELD.extract(bits));
+        s.field("TEOS", &Self::TEOS_FIELD.extract(bits));
+        s.field("VT", &Self::VT_FIELD.extract(bits));
+        s.field("EnDB2", &Self::ENDB2_FIELD.extract(bits));
+        s.field("EnDA2", &Self::ENDA2_FIELD.extract(bits));
+        s.field("EnIB2", &Self::ENIB2_FIELD.extract(bits));
+        s.field("EnIA2", &Self::ENIA2_FIELD.extract(bits));
+        s.field("BTD0", &Self::BTD0_FIELD.extract(bits));
+        s.field("BTD", &Self::BTD_FIELD.extract(bits));
+        s.field("FDIT", &Self::FDIT_FIELD.extract(bits));
+        s.field("TLBOSNIS", &Self::TLBOSNIS_FIELD.extract(bits));
+        s.field("EnTP3", &Self::ENTP3_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for Sctlr2El1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "Sctlr2El1 {{ NMEA: {=u64}, EnADERR: {=u64}, EnANERR: {=u64}, EASE: {=u64}, EnIDCP128: {=u64}, EnPACM: {=u64}, EnPACM0: {=u64}, CPTA: {=u64}, CPTA0: {=u64}, CPTM: {=u64}, CPTM0: {=u64}, DTZ: {=u64}, TEIS: {=u64}, TEOS: {=u64}, VT: {=u64}, EnDB2: {=u64}, EnDA2: {=u64}, EnIB2: {=u64}, EnIA2: {=u64}, BTD0: {=u64}, BTD: {=u64}, FDIT: {=u64}, TLBOSNIS: {=u64}, EnTP3: {=u64} }}",
+            Self::NMEA_FIELD.extract(self.bits()),
+            Self::ENADERR_FIELD.extract(self.bits()),
+            Self::ENANERR_FIELD.extract(self.bits()),
+            Self::EASE_FIELD.extract(self.bits()),
+            Self::ENIDCP128_FIELD.extract(self.bits()),
+            Self::ENPACM_FIELD.extract(self.bits()),
+            Self::ENPACM0_FIELD.extract(self.bits()),
+            Self::CPTA_FIELD.extract(self.bits()),
+            Self::CPTA0_FIELD.extract(self.bits()),
+            Self::CPTM_FIELD.extract(self.bits()),
+            Self::CPTM0_FIELD.extract(self.bits()),
+            Self::DTZ_FIELD.extract(self.bits()),
+            Self::TEIS_FIELD.extract(self.bits()),
+            Self::TEOS_FIELD.extract(self.bits()),
+            Self::VT_FIELD.extract(self.bits()),
+            Self::ENDB2_FIELD.extract(self.bits()),
+            Self::ENDA2_FIELD.extract(self.bits()),
+            Self::ENIB2_FIELD.extract(self.bits()),
+            Self::ENIA2_FIELD.extract(self.bits()),
+            Self::BTD0_FIELD.extract(self.bits()),
+            Self::BTD_FIELD.extract(self.bits()),
+            Self::FDIT_FIELD.extract(self.bits()),
+            Self::TLBOSNIS_FIELD.extract(self.bits()),
+            Self::ENTP3_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for Sctlr2El1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for Sctlr2El1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Sctlr2El1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("NMEA"),
+                old: Self::NMEA_FIELD.extract(self.bits()),
+                new: Self::NMEA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnADERR"),
+                old: Self::ENADERR_FIELD.extract(self.bits()),
+                new: Self::ENADERR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnANERR"),
+                old: Self::ENANERR_FIELD.extract(self.bits()),
+                new: Self::ENANERR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EASE"),
+                old: Self::EASE_FIELD.extract(self.bits()),
+                new: Self::EASE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnIDCP128"),
+                old: Self::ENIDCP128_FIELD.extract(self.bits()),
+                new: Self::ENIDCP128_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnPACM"),
+                old: Self::ENPACM_FIELD.extract(self.bits()),
+                new: Self::ENPACM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnPACM0"),
+                old: Self::ENPACM0_FIELD.extract(self.bits()),
+                new: Self::ENPACM0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CPTA"),
+                old: Self::CPTA_FIELD.extract(self.bits()),
+                new: Self::CPTA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CPTA0"),
+                old: Self::CPTA0_FIELD.extract(self.bits()),
+                new: Self::CPTA0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CPTM"),
+                old: Self::CPTM_FIELD.extract(self.bits()),
+                new: Self::CPTM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CPTM0"),
+                old: Self::CPTM0_FIELD.extract(self.bits()),
+                new: Self::CPTM0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DTZ"),
+                old: Self::DTZ_FIELD.extract(self.bits()),
+                new: Self::DTZ_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TEIS"),
+                old: Self::TEIS_FIELD.extract(self.bits()),
+                new: Self::TEIS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TEOS"),
+                old: Self::TEOS_FIELD.extract(self.bits()),
+                new: Self::TEOS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VT"),
+                old: Self::VT_FIELD.extract(self.bits()),
+                new: Self::VT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnDB2"),
+                old: Self::ENDB2_FIELD.extract(self.bits()),
+                new: Self::ENDB2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnDA2"),
+                old: Self::ENDA2_FIELD.extract(self.bits()),
+                new: Self::ENDA2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnIB2"),
+                old: Self::ENIB2_FIELD.extract(self.bits()),
+                new: Self::ENIB2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnIA2"),
+                old: Self::ENIA2_FIELD.extract(self.bits()),
+                new: Self::ENIA2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("BTD0"),
+                old: Self::BTD0_FIELD.extract(self.bits()),
+                new: Self::BTD0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("BTD"),
+                old: Self::BTD_FIELD.extract(self.bits()),
+                new: Self::BTD_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FDIT"),
+                old: Self::FDIT_FIELD.extract(self.bits()),
+                new: Self::FDIT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TLBOSNIS"),
+                old: Self::TLBOSNIS_FIELD.extract(self.bits()),
+                new: Self::TLBOSNIS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnTP3"),
+                old: Self::ENTP3_FIELD.extract(self.bits()),
+                new: Self::ENTP3_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1110_1000_0000_0100_0010_0000_0000_0011,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1110_1000_0000_0100_0010_0000_0000_0011,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Sctlr2El1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Sctlr2El1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                Self::NMEA_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL1",
+                    field: "NMEA",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::ENADERR_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL1",
+                    field: "EnADERR",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::ENANERR_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL1",
+                    field: "EnANERR",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::EASE_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL1",
+                    field: "EASE",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::ENIDCP128_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL1",
+                    field: "EnIDCP128",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::ENPACM_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL1",
+                    field: "EnPACM",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::ENPACM0_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL1",
+                    field: "EnPACM0",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::CPTA_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL1",
+                    field: "CPTA",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::CPTA0_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL1",
+                    field: "CPTA0",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::CPTM_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL1",
+                    field: "CPTM",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::CPTM0_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL1",
+                    field: "CPTM0",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::DTZ_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL1",
+                    field: "DTZ",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::TEIS_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL1",
+                    field: "TEIS",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::TEOS_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL1",
+                    field: "TEOS",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::VT_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL1",
+                    field: "VT",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::ENDB2_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL1",
+                    field: "EnDB2",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::ENDA2_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL1",
+                    field: "EnDA2",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::ENIB2_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL1",
+                    field: "EnIB2",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::ENIA2_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL1",
+                    field: "EnIA2",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::BTD0_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL1",
+                    field: "BTD0",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::BTD_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL1",
+                    field: "BTD",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::FDIT_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL1",
+                    field: "FDIT",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::TLBOSNIS_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL1",
+                    field: "TLBOSNIS",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::ENTP3_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL1",
+                    field: "EnTP3",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `SCTLR2_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct Sctlr2El2: u64 {
+        /// `EMEC` bit.
+        const EMEC = 1 << 1;
+        /// `NMEA` bit.
+        const NMEA = 1 << 2;
+        /// `EnADERR` bit.
+        const ENADERR = 1 << 3;
+        /// `EnANERR` bit.
+        const ENANERR = 1 << 4;
+        /// `EASE` bit.
+        const EASE = 1 << 5;
+        /// `EnIDCP128` bit.
+        const ENIDCP128 = 1 << 6;
+        /// `EnPACM` bit.
+        const ENPACM = 1 << 7;
+        /// `EnPACM0` bit.
+        const ENPACM0 = 1 << 8;
+        /// `CPTA` bit.
+        const CPTA = 1 << 9;
+        /// `CPTA0` bit.
+        const CPTA0 = 1 << 10;
+        /// `CPTM` bit.
+        const CPTM = 1 << 11;
+        /// `CPTM0` bit.
+        const CPTM0 = 1 << 12;
+        /// `DTZ` bit.
+        const DTZ = 1 << 14;
+        /// `TEIS` bit.
+        const TEIS = 1 << 15;
+        /// `TEOS` bit.
+        const TEOS = 1 << 16;
+        /// `VT` bit.
+        const VT = 1 << 17;
+        /// `EnDB2` bit.
+        const ENDB2 = 1 << 19;
+        /// `EnDA2` bit.
+        const ENDA2 = 1 << 20;
+        /// `EnIB2` bit.
+        const ENIB2 = 1 << 21;
+        /// `EnIA2` bit.
+        const ENIA2 = 1 << 22;
+        /// `BTD0` bit.
+        const BTD0 = 1 << 23;
+        /// `BTD` bit.
+        const BTD = 1 << 24;
+        /// `FDIT` bit.
+        const FDIT = 1 << 25;
+        /// `TLBOSNIS` bit.
+        const TLBOSNIS = 1 << 26;
+        /// `EnTP3` bit.
+        const ENTP3 = 1 << 28;
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Sctlr2El2 {
+    /// Descriptor of the `EMEC` field, for use with [`FieldAccess`].
+    pub const EMEC_FIELD: Field<Self, bool, 1, 1> = Field::new("SCTLR2_EL2", "EMEC");
+    /// Descriptor of the `NMEA` field, for use with [`FieldAccess`].
+    pub const NMEA_FIELD: Field<Self, bool, 2, 1> = Field::new("SCTLR2_EL2", "NMEA");
+    /// Descriptor of the `EnADERR` field, for use with [`FieldAccess`].
+    pub const ENADERR_FIELD: Field<Self, bool, 3, 1> = Field::new("SCTLR2_EL2", "EnADERR");
+    /// Descriptor of the `EnANERR` field, for use with [`FieldAccess`].
+    pub const ENANERR_FIELD: Field<Self, bool, 4, 1> = Field::new("SCTLR2_EL2", "EnANERR");
+    /// Descriptor of the `EASE` field, for use with [`FieldAccess`].
+    pub const EASE_FIELD: Field<Self, bool, 5, 1> = Field::new("SCTLR2_EL2", "EASE");
+    /// Descriptor of the `EnIDCP128` field, for use with [`FieldAccess`].
+    pub const ENIDCP128_FIELD: Field<Self, bool, 6, 1> = Field::new("SCTLR2_EL2", "EnIDCP128");
+    /// Descriptor of the `EnPACM` field, for use with [`FieldAccess`].
+    pub const ENPACM_FIELD: Field<Self, bool, 7, 1> = Field::new("SCTLR2_EL2", "EnPACM");
+    /// Descriptor of the `EnPACM0` field, for use with [`FieldAccess`].
+    pub const ENPACM0_FIELD: Field<Self, bool, 8, 1> = Field::new("SCTLR2_EL2", "EnPACM0");
+    /// Descriptor of the `CPTA` field, for use with [`FieldAccess`].
+    pub const CPTA_FIELD: Field<Self, bool, 9, 1> = Field::new("SCTLR2_EL2", "CPTA");
+    /// Descriptor of the `CPTA0` field, for use with [`FieldAccess`].
+    pub const CPTA0_FIELD: Field<Self, bool, 10, 1> = Field::new("SCTLR2_EL2", "CPTA0");
+    /// Descriptor of the `CPTM` field, for use with [`FieldAccess`].
+    pub const CPTM_FIELD: Field<Self, bool, 11, 1> = Field::new("SCTLR2_EL2", "CPTM");
+    /// Descriptor of the `CPTM0` field, for use with [`FieldAccess`].
+    pub const CPTM0_FIELD: Field<Self, bool, 12, 1> = Field::new("SCTLR2_EL2", "CPTM0");
+    /// Descriptor of the `DTZ` field, for use with [`FieldAccess`].
+    pub const DTZ_FIELD: Field<Self, bool, 14, 1> = Field::new("SCTLR2_EL2", "DTZ");
+    /// Descriptor of the `TEIS` field, for use with [`FieldAccess`].
+    pub const TEIS_FIELD: Field<Self, bool, 15, 1> = Field::new("SCTLR2_EL2", "TEIS");
+    /// Descriptor of the `TEOS` field, for use with [`FieldAccess`].
+    pub const TEOS_FIELD: Field<Self, bool, 16, 1> = Field::new("SCTLR2_EL2", "TEOS");
+    /// Descriptor of the `VT` field, for use with [`FieldAccess`].
+    pub const VT_FIELD: Field<Self, bool, 17, 1> = Field::new("SCTLR2_EL2", "VT");
+    /// Descriptor of the `EnDB2` field, for use with [`FieldAccess`].
+    pub const ENDB2_FIELD: Field<Self, bool, 19, 1> = Field::new("SCTLR2_EL2", "EnDB2");
+    /// Descriptor of the `EnDA2` field, for use with [`FieldAccess`].
+    pub const ENDA2_FIELD: Field<Self, bool, 20, 1> = Field::new("SCTLR2_EL2", "EnDA2");
+    /// Descriptor of the `EnIB2` field, for use with [`FieldAccess`].
+    pub const ENIB2_FIELD: Field<Self, bool, 21, 1> = Field::new("SCTLR2_EL2", "EnIB2");
+    /// Descriptor of the `EnIA2` field, for use with [`FieldAccess`].
+    pub const ENIA2_FIELD: Field<Self, bool, 22, 1> = Field::new("SCTLR2_EL2", "EnIA2");
+    /// Descriptor of the `BTD0` field, for use with [`FieldAccess`].
+    pub const BTD0_FIELD: Field<Self, bool, 23, 1> = Field::new("SCTLR2_EL2", "BTD0");
+    /// Descriptor of the `BTD` field, for use with [`FieldAccess`].
+    pub const BTD_FIELD: Field<Self, bool, 24, 1> = Field::new("SCTLR2_EL2", "BTD");
+    /// Descriptor of the `FDIT` field, for use with [`FieldAccess`].
+    pub const FDIT_FIELD: Field<Self, bool, 25, 1> = Field::new("SCTLR2_EL2", "FDIT");
+    /// Descriptor of the `TLBOSNIS` field, for use with [`FieldAccess`].
+    pub const TLBOSNIS_FIELD: Field<Self, bool, 26, 1> = Field::new("SCTLR2_EL2", "TLBOSNIS");
+    /// Descriptor of the `EnTP3` field, for use with [`FieldAccess`].
+    pub const ENTP3_FIELD: Field<Self, bool, 28, 1> = Field::new("SCTLR2_EL2", "EnTP3");
+}
+
+#[cfg(feature = "el2")]
+impl Display for Sctlr2El2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("Sctlr2El2");
+        let bits = self.bits();
+        s.field("EMEC", &Self::EMEC_FIELD.extract(bits));
+        s.field("NMEA", &Self::NMEA_FIELD.extract(bits));
+        s.field("EnADERR", &Self::ENADERR_FIELD.extract(bits));
+        s.field("EnANERR", &Self::ENANERR_FIELD.extract(bits));
+        s.field("EASE", &Self::EASE_FIELD.extract(bits));
+        s.field("EnIDCP128", &Self::ENIDCP128_FIELD.extract(bits));
+        s.field("EnPACM", &Self::ENPACM_FIELD.extract(bits));
+        s.field("EnPACM0", &Self::ENPACM0_FIELD.extract(bits));
+        s.field("CPTA", &Self::CPTA_FIELD.extract(bits));
+        s.field("CPTA0", &Self::CPTA0_FIELD.extract(bits));
+        s.field("CPTM", &Self::CPTM_FIELD.extract(bits));
+        s.field("CPTM0", &Self::CPTM0_FIELD.extract(bits));
+        s.field("DTZ", &Self::DTZ_FIELD.extract(bits));
+        s.field("TEIS", &Self::TEIS_FIELD.extract(bits));
+        s.field("TEOS", &Self::TEOS_FIELD.extract(bits));
+        s.field("VT", &Self::VT_FIELD.extract(bits));
+        s.field("EnDB2", &Self::ENDB2_FIELD.extract(bits));
+        s.field("EnDA2", &Self::ENDA2_FIELD.extract(bits));
+        s.field("EnIB2", &Self::ENIB2_FIELD.extract(bits));
+        s.field("EnIA2", &Self::ENIA2_FIELD.extract(bits));
+        s.field("BTD0", &Self::BTD0_FIELD.extract(bits));
+        s.field("BTD", &Self::BTD_FIELD.extract(bits));
+        s.field("FDIT", &Self::FDIT_FIELD.extract(bits));
+        s.field("TLBOSNIS", &Self::TLBOSNIS_FIELD.extract(bits));
+        s.field("EnTP3", &Self::ENTP3_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for Sctlr2El2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "Sctlr2El2 {{ EMEC: {=u64}, NMEA: {=u64}, EnADERR: {=u64}, EnANERR: {=u64}, EASE: {=u64}, EnIDCP128: {=u64}, EnPACM: {=u64}, EnPACM0: {=u64}, CPTA: {=u64}, CPTA0: {=u64}, CPTM: {=u64}, CPTM0: {=u64}, DTZ: {=u64}, TEIS: {=u64}, TEOS: {=u64}, VT: {=u64}, EnDB2: {=u64}, EnDA2: {=u64}, EnIB2: {=u64}, EnIA2: {=u64}, BTD0: {=u64}, BTD: {=u64}, FDIT: {=u64}, TLBOSNIS: {=u64}, EnTP3: {=u64} }}",
+            Self::EMEC_FIELD.extract(self.bits()),
+            Self::NMEA_FIELD.extract(self.bits()),
+            Self::ENADERR_FIELD.extract(self.bits()),
+            Self::ENANERR_FIELD.extract(self.bits()),
+            Self::EASE_FIELD.extract(self.bits()),
+            Self::ENIDCP128_FIELD.extract(self.bits()),
+            Self::ENPACM_FIELD.extract(self.bits()),
+            Self::ENPACM0_FIELD.extract(self.bits()),
+            Self::CPTA_FIELD.extract(self.bits()),
+            Self::CPTA0_FIELD.extract(self.bits()),
+            Self::CPTM_FIELD.extract(self.bits()),
+            Self::CPTM0_FIELD.extract(self.bits()),
+            Self::DTZ_FIELD.extract(self.bits()),
+            Self::TEIS_FIELD.extract(self.bits()),
+            Self::TEOS_FIELD.extract(self.bits()),
+            Self::VT_FIELD.extract(self.bits()),
+            Self::ENDB2_FIELD.extract(self.bits()),
+            Self::ENDA2_FIELD.extract(self.bits()),
+            Self::ENIB2_FIELD.extract(self.bits()),
+            Self::ENIA2_FIELD.extract(self.bits()),
+            Self::BTD0_FIELD.extract(self.bits()),
+            Self::BTD_FIELD.extract(self.bits()),
+            Self::FDIT_FIELD.extract(self.bits()),
+            Self::TLBOSNIS_FIELD.extract(self.bits()),
+            Self::ENTP3_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for Sctlr2El2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for Sctlr2El2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Sctlr2El2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("EMEC"),
+                old: Self::EMEC_FIELD.extract(self.bits()),
+                new: Self::EMEC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("NMEA"),
+                old: Self::NMEA_FIELD.extract(self.bits()),
+                new: Self::NMEA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnADERR"),
+                old: Self::ENADERR_FIELD.extract(self.bits()),
+                new: Self::ENADERR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnANERR"),
+                old: Self::ENANERR_FIELD.extract(self.bits()),
+                new: Self::ENANERR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EASE"),
+                old: Self::EASE_FIELD.extract(self.bits()),
+                new: Self::EASE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnIDCP128"),
+                old: Self::ENIDCP128_FIELD.extract(self.bits()),
+                new: Self::ENIDCP128_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnPACM"),
+                old: Self::ENPACM_FIELD.extract(self.bits()),
+                new: Self::ENPACM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnPACM0"),
+                old: Self::ENPACM0_FIELD.extract(self.bits()),
+                new: Self::ENPACM0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CPTA"),
+                old: Self::CPTA_FIELD.extract(self.bits()),
+                new: Self::CPTA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CPTA0"),
+                old: Self::CPTA0_FIELD.extract(self.bits()),
+                new: Self::CPTA0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CPTM"),
+                old: Self::CPTM_FIELD.extract(self.bits()),
+                new: Self::CPTM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CPTM0"),
+                old: Self::CPTM0_FIELD.extract(self.bits()),
+                new: Self::CPTM0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DTZ"),
+                old: Self::DTZ_FIELD.extract(self.bits()),
+                new: Self::DTZ_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TEIS"),
+                old: Self::TEIS_FIELD.extract(self.bits()),
+                new: Self::TEIS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TEOS"),
+                old: Self::TEOS_FIELD.extract(self.bits()),
+                new: Self::TEOS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VT"),
+                old: Self::VT_FIELD.extract(self.bits()),
+                new: Self::VT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnDB2"),
+                old: Self::ENDB2_FIELD.extract(self.bits()),
+                new: Self::ENDB2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnDA2"),
+                old: Self::ENDA2_FIELD.extract(self.bits()),
+                new: Self::ENDA2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnIB2"),
+                old: Self::ENIB2_FIELD.extract(self.bits()),
+                new: Self::ENIB2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnIA2"),
+                old: Self::ENIA2_FIELD.extract(self.bits()),
+                new: Self::ENIA2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("BTD0"),
+                old: Self::BTD0_FIELD.extract(self.bits()),
+                new: Self::BTD0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("BTD"),
+                old: Self::BTD_FIELD.extract(self.bits()),
+                new: Self::BTD_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FDIT"),
+                old: Self::FDIT_FIELD.extract(self.bits()),
+                new: Self::FDIT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TLBOSNIS"),
+                old: Self::TLBOSNIS_FIELD.extract(self.bits()),
+                new: Self::TLBOSNIS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnTP3"),
+                old: Self::ENTP3_FIELD.extract(self.bits()),
+                new: Self::ENTP3_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1110_1000_0000_0100_0010_0000_0000_0001,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1110_1000_0000_0100_0010_0000_0000_0001,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Sctlr2El2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Sctlr2El2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                Self::EMEC_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL2",
+                    field: "EMEC",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::NMEA_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL2",
+                    field: "NMEA",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::ENADERR_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL2",
+                    field: "EnADERR",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::ENANERR_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL2",
+                    field: "EnANERR",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::EASE_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL2",
+                    field: "EASE",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::ENIDCP128_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL2",
+                    field: "EnIDCP128",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::ENPACM_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL2",
+                    field: "EnPACM",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::ENPACM0_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL2",
+                    field: "EnPACM0",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::CPTA_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL2",
+                    field: "CPTA",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::CPTA0_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL2",
+                    field: "CPTA0",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::CPTM_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL2",
+                    field: "CPTM",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::CPTM0_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL2",
+                    field: "CPTM0",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::DTZ_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL2",
+                    field: "DTZ",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::TEIS_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL2",
+                    field: "TEIS",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::TEOS_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL2",
+                    field: "TEOS",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::VT_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL2",
+                    field: "VT",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::ENDB2_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL2",
+                    field: "EnDB2",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::ENDA2_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL2",
+                    field: "EnDA2",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::ENIB2_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL2",
+                    field: "EnIB2",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::ENIA2_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL2",
+                    field: "EnIA2",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::BTD0_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL2",
+                    field: "BTD0",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::BTD_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL2",
+                    field: "BTD",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::FDIT_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL2",
+                    field: "FDIT",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::TLBOSNIS_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL2",
+                    field: "TLBOSNIS",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::ENTP3_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL2",
+                    field: "EnTP3",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(feature = "el3")]
+bitflags! {
+    /// `SCTLR2_EL3` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct Sctlr2El3: u64 {
+        /// `EMEC` bit.
+        const EMEC = 1 << 1;
+        /// `EnADERR` bit.
+        const ENADERR = 1 << 3;
+        /// `EnANERR` bit.
+        const ENANERR = 1 << 4;
+        /// `EnPACM` bit.
+        const ENPACM = 1 << 7;
+        /// `CPTA` bit.
+        const CPTA = 1 << 9;
+        /// `CPTM` bit.
+        const CPTM = 1 << 11;
+        /// `DTZ` bit.
+        const DTZ = 1 << 14;
+        /// `TEIS` bit.
+        const TEIS = 1 << 15;
+        /// `TEOS` bit.
+        const TEOS = 1 << 16;
+        /// `VT` bit.
+        const VT = 1 << 17;
+        /// `BTD` bit.
+        const BTD = 1 << 24;
+    }
+}
+
+#[cfg(feature = "el3")]
+impl Sctlr2El3 {
+    /// Descriptor of the `EMEC` field, for use with [`FieldAccess`].
+    pub const EMEC_FIELD: Field<Self, bool, 1, 1> = Field::new("SCTLR2_EL3", "EMEC");
+    /// Descriptor of the `EnADERR` field, for use with [`FieldAccess`].
+    pub const ENADERR_FIELD: Field<Self, bool, 3, 1> = Field::new("SCTLR2_EL3", "EnADERR");
+    /// Descriptor of the `EnANERR` field, for use with [`FieldAccess`].
+    pub const ENANERR_FIELD: Field<Self, bool, 4, 1> = Field::new("SCTLR2_EL3", "EnANERR");
+    /// Descriptor of the `EnPACM` field, for use with [`FieldAccess`].
+    pub const ENPACM_FIELD: Field<Self, bool, 7, 1> = Field::new("SCTLR2_EL3", "EnPACM");
+    /// Descriptor of the `CPTA` field, for use with [`FieldAccess`].
+    pub const CPTA_FIELD: Field<Self, bool, 9, 1> = Field::new("SCTLR2_EL3", "CPTA");
+    /// Descriptor of the `CPTM` field, for use with [`FieldAccess`].
+    pub const CPTM_FIELD: Field<Self, bool, 11, 1> = Field::new("SCTLR2_EL3", "CPTM");
+    /// Descriptor of the `DTZ` field, for use with [`FieldAccess`].
+    pub const DTZ_FIELD: Field<Self, bool, 14, 1> = Field::new("SCTLR2_EL3", "DTZ");
+    /// Descriptor of the `TEIS` field, for use with [`FieldAccess`].
+    pub const TEIS_FIELD: Field<Self, bool, 15, 1> = Field::new("SCTLR2_EL3", "TEIS");
+    /// Descriptor of the `TEOS` field, for use with [`FieldAccess`].
+    pub const TEOS_FIELD: Field<Self, bool, 16, 1> = Field::new("SCTLR2_EL3", "TEOS");
+    /// Descriptor of the `VT` field, for use with [`FieldAccess`].
+    pub const VT_FIELD: Field<Self, bool, 17, 1> = Field::new("SCTLR2_EL3", "VT");
+    /// Descriptor of the `BTD` field, for use with [`FieldAccess`].
+    pub const BTD_FIELD: Field<Self, bool, 24, 1> = Field::new("SCTLR2_EL3", "BTD");
+}
+
+#[cfg(feature = "el3")]
+impl Display for Sctlr2El3 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("Sctlr2El3");
+        let bits = self.bits();
+        s.field("EMEC", &Self::EMEC_FIELD.extract(bits));
+        s.field("EnADERR", &Self::ENADERR_FIELD.extract(bits));
+        s.field("EnANERR", &Self::ENANERR_FIELD.extract(bits));
+        s.field("EnPACM", &Self::ENPACM_FIELD.extract(bits));
+        s.field("CPTA", &Self::CPTA_FIELD.extract(bits));
+        s.field("CPTM", &Self::CPTM_FIELD.extract(bits));
+        s.field("DTZ", &Self::DTZ_FIELD.extract(bits));
+        s.field("TEIS", &Self::TEIS_FIELD.extract(bits));
+        s.field("TEOS", &Self::TEOS_FIELD.extract(bits));
+        s.field("VT", &Self::VT_FIELD.extract(bits));
+        s.field("BTD", &Self::BTD_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el3")]
+impl defmt::Format for Sctlr2El3 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "Sctlr2El3 {{ EMEC: {=u64}, EnADERR: {=u64}, EnANERR: {=u64}, EnPACM: {=u64}, CPTA: {=u64}, CPTM: {=u64}, DTZ: {=u64}, TEIS: {=u64}, TEOS: {=u64}, VT: {=u64}, BTD: {=u64} }}",
+            Self::EMEC_FIELD.extract(self.bits()),
+            Self::ENADERR_FIELD.extract(self.bits()),
+            Self::ENANERR_FIELD.extract(self.bits()),
+            Self::ENPACM_FIELD.extract(self.bits()),
+            Self::CPTA_FIELD.extract(self.bits()),
+            Self::CPTM_FIELD.extract(self.bits()),
+            Self::DTZ_FIELD.extract(self.bits()),
+            Self::TEIS_FIELD.extract(self.bits()),
+            Self::TEOS_FIELD.extract(self.bits()),
+            Self::VT_FIELD.extract(self.bits()),
+            Self::BTD_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el3")]
+impl serde::Serialize for Sctlr2El3 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el3")]
+impl<'de> serde::Deserialize<'de> for Sctlr2El3 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el3")]
+impl Sctlr2El3 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("EMEC"),
+                old: Self::EMEC_FIELD.extract(self.bits()),
+                new: Self::EMEC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnADERR"),
+                old: Self::ENADERR_FIELD.extract(self.bits()),
+                new: Self::ENADERR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnANERR"),
+                old: Self::ENANERR_FIELD.extract(self.bits()),
+                new: Self::ENANERR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnPACM"),
+                old: Self::ENPACM_FIELD.extract(self.bits()),
+                new: Self::ENPACM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CPTA"),
+                old: Self::CPTA_FIELD.extract(self.bits()),
+                new: Self::CPTA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CPTM"),
+                old: Self::CPTM_FIELD.extract(self.bits()),
+                new: Self::CPTM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DTZ"),
+                old: Self::DTZ_FIELD.extract(self.bits()),
+                new: Self::DTZ_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TEIS"),
+                old: Self::TEIS_FIELD.extract(self.bits()),
+                new: Self::TEIS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TEOS"),
+                old: Self::TEOS_FIELD.extract(self.bits()),
+                new: Self::TEOS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VT"),
+                old: Self::VT_FIELD.extract(self.bits()),
+                new: Self::VT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("BTD"),
+                old: Self::BTD_FIELD.extract(self.bits()),
+                new: Self::BTD_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1110_1111_1100_0011_0101_0110_0101,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1110_1111_1100_0011_0101_0110_0101,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el3")]
+impl Sctlr2El3 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el3")]
+impl Sctlr2El3 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                Self::EMEC_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL3",
+                    field: "EMEC",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::ENADERR_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL3",
+                    field: "EnADERR",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::ENANERR_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL3",
+                    field: "EnANERR",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::ENPACM_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL3",
+                    field: "EnPACM",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::CPTA_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL3",
+                    field: "CPTA",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::CPTM_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL3",
+                    field: "CPTM",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::DTZ_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL3",
+                    field: "DTZ",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::TEIS_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL3",
+                    field: "TEIS",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::TEOS_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL3",
+                    field: "TEOS",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::VT_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL3",
+                    field: "VT",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+            (
+                Self::BTD_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sctlr2),
+                UnimplementedField {
+                    register: "SCTLR2_EL3",
+                    field: "BTD",
+                    requires: "FEAT_SCTLR2",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `SCTLR_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct SctlrEl1: u64 {
+        /// `M` bit.
+        const M = 1 << 0;
+        /// `A` bit.
+        const A = 1 << 1;
+        /// `C` bit.
+        const C = 1 << 2;
+        /// `SA` bit.
+        const SA = 1 << 3;
+        /// `SA0` bit.
+        const SA0 = 1 << 4;
+        /// `CP15BEN` bit.
+        const CP15BEN = 1 << 5;
+        /// `nAA` bit.
+        const NAA = 1 << 6;
+        /// `ITD` bit.
+        const ITD = 1 << 7;
+        /// `SED` bit.
+        const SED = 1 << 8;
+        /// `UMA` bit.
+        const UMA = 1 << 9;
+        /// `EnRCTX` bit.
+        const ENRCTX = 1 << 10;
+        /// `EOS` bit.
+        const EOS = 1 << 11;
+        /// `I` bit.
+        const I = 1 << 12;
+        /// `EnDB` bit.
+        const ENDB = 1 << 13;
+        /// `DZE` bit.
+        const DZE = 1 << 14;
+        /// `UCT` bit.
+        const UCT = 1 << 15;
+        /// `nTWI` bit.
+        const NTWI = 1 << 16;
+        /// `nTWE` bit.
+        const NTWE = 1 << 18;
+        /// `WXN` bit.
+        const WXN = 1 << 19;
+        /// `TSCXT` bit.
+        const TSCXT = 1 << 20;
+        /// `IESB` bit.
+        const IESB = 1 << 21;
+        /// `EIS` bit.
+        const EIS = 1 << 22;
+        /// Do not set Privileged Access Never, on taking an exception to EL1.
+        const SPAN = 1 << 23;
+        /// `UCI` bit.
+        const UCI = 1 << 26;
+        /// `EnDA` bit.
+        const ENDA = 1 << 27;
+        /// `nTLSMD` bit.
+        const NTLSMD = 1 << 28;
+        /// `LSMAOE` bit.
+        const LSMAOE = 1 << 29;
+        /// Enable pointer authentication using APIBKey_EL1.
+        const ENIB = 1 << 30;
+        /// Enable pointer authentication using APIAKey_EL1.
+        const ENIA = 1 << 31;
+        /// `CMOW` bit.
+        const CMOW = 1 << 32;
+        /// `MSCEn` bit.
+        const MSCEN = 1 << 33;
+        /// `EnFPM` bit.
+        const ENFPM = 1 << 34;
+        /// `BT0` bit.
+        const BT0 = 1 << 35;
+        /// `BT1` bit.
+        const BT1 = 1 << 36;
+        /// `ITFSB` bit.
+        const ITFSB = 1 << 37;
+        /// `ATA0` bit.
+        const ATA0 = 1 << 42;
+        /// `ATA` bit.
+        const ATA = 1 << 43;
+        /// Default PSTATE.SSBS value on Exception Entry.
+        const DSSBS = 1 << 44;
+        /// `TWEDEn` bit.
+        const TWEDEN = 1 << 45;
+        /// `EnASR` bit.
+        const ENASR = 1 << 54;
+        /// `EnAS0` bit.
+        const ENAS0 = 1 << 55;
+        /// `EnALS` bit.
+        const ENALS = 1 << 56;
+        /// `EPAN` bit.
+        const EPAN = 1 << 57;
+        /// `TCSO0` bit.
+        const TCSO0 = 1 << 58;
+        /// `TCSO` bit.
+        const TCSO = 1 << 59;
+        /// `EnTP2` bit.
+        const ENTP2 = 1 << 60;
+        /// `NMI` bit.
+        const NMI = 1 << 61;
+        /// SP Interrupt Mask enable.
+        const SPINTMASK = 1 << 62;
+        /// `TIDCP` bit.
+        const TIDCP = 1 << 63;
+    }
+}
+
+#[cfg(feature = "el1")]
+impl SctlrEl1 {
+    /// Descriptor of the `M` field, for use with [`FieldAccess`].
+    pub const M_FIELD: Field<Self, bool, 0, 1> = Field::new("SCTLR_EL1", "M");
+    /// Descriptor of the `A` field, for use with [`FieldAccess`].
+    pub const A_FIELD: Field<Self, bool, 1, 1> = Field::new("SCTLR_EL1", "A");
+    /// Descriptor of the `C` field, for use with [`FieldAccess`].
+    pub const C_FIELD: Field<Self, bool, 2, 1> = Field::new("SCTLR_EL1", "C");
+    /// Descriptor of the `SA` field, for use with [`FieldAccess`].
+    pub const SA_FIELD: Field<Self, bool, 3, 1> = Field::new("SCTLR_EL1", "SA");
+    /// Descriptor of the `SA0` field, for use with [`FieldAccess`].
+    pub const SA0_FIELD: Field<Self, bool, 4, 1> = Field::new("SCTLR_EL1", "SA0");
+    /// Descriptor of the `CP15BEN` field, for use with [`FieldAccess`].
+    pub const CP15BEN_FIELD: Field<Self, bool, 5, 1> = Field::new("SCTLR_EL1", "CP15BEN");
+    /// Descriptor of the `nAA` field, for use with [`FieldAccess`].
+    pub const NAA_FIELD: Field<Self, bool, 6, 1> = Field::new("SCTLR_EL1", "nAA");
+    /// Descriptor of the `ITD` field, for use with [`FieldAccess`].
+    pub const ITD_FIELD: Field<Self, bool, 7, 1> = Field::new("SCTLR_EL1", "ITD");
+    /// Descriptor of the `SED` field, for use with [`FieldAccess`].
+    pub const SED_FIELD: Field<Self, bool, 8, 1> = Field::new("SCTLR_EL1", "SED");
+    /// Descriptor of the `UMA` field, for use with [`FieldAccess`].
+    pub const UMA_FIELD: Field<Self, bool, 9, 1> = Field::new("SCTLR_EL1", "UMA");
+    /// Descriptor of the `EnRCTX` field, for use with [`FieldAccess`].
+    pub const ENRCTX_FIELD: Field<Self, bool, 10, 1> = Field::new("SCTLR_EL1", "EnRCTX");
+    /// Descriptor of the `EOS` field, for use with [`FieldAccess`].
+    pub const EOS_FIELD: Field<Self, bool, 11, 1> = Field::new("SCTLR_EL1", "EOS");
+    /// Descriptor of the `I` field, for use with [`FieldAccess`].
+    pub const I_FIELD: Field<Self, bool, 12, 1> = Field::new("SCTLR_EL1", "I");
+    /// Descriptor of the `EnDB` field, for use with [`FieldAccess`].
+    pub const ENDB_FIELD: Field<Self, bool, 13, 1> = Field::new("SCTLR_EL1", "EnDB");
+    /// Descriptor of the `DZE` field, for use with [`FieldAccess`].
+    pub const DZE_FIELD: Field<Self, bool, 14, 1> = Field::new("SCTLR_EL1", "DZE");
+    /// Descriptor of the `UCT` field, for use with [`FieldAccess`].
+    pub const UCT_FIELD: Field<Self, bool, 15, 1> = Field::new("SCTLR_EL1", "UCT");
+    /// Descriptor of the `nTWI` field, for use with [`FieldAccess`].
+    pub const NTWI_FIELD: Field<Self, bool, 16, 1> = Field::new("SCTLR_EL1", "nTWI");
+    /// Descriptor of the `nTWE` field, for use with [`FieldAccess`].
+    pub const NTWE_FIELD: Field<Self, bool, 18, 1> = Field::new("SCTLR_EL1", "nTWE");
+    /// Descriptor of the `WXN` field, for use with [`FieldAccess`].
+    pub const WXN_FIELD: Field<Self, bool, 19, 1> = Field::new("SCTLR_EL1", "WXN");
+    /// Descriptor of the `TSCXT` field, for use with [`FieldAccess`].
+    pub const TSCXT_FIELD: Field<Self, bool, 20, 1> = Field::new("SCTLR_EL1", "TSCXT");
+    /// Descriptor of the `IESB` field, for use with [`FieldAccess`].
+    pub const IESB_FIELD: Field<Self, bool, 21, 1> = Field::new("SCTLR_EL1", "IESB");
+    /// Descriptor of the `EIS` field, for use with [`FieldAccess`].
+    pub const EIS_FIELD: Field<Self, bool, 22, 1> = Field::new("SCTLR_EL1", "EIS");
+    /// Descriptor of the `SPAN` field, for use with [`FieldAccess`].
+    pub const SPAN_FIELD: Field<Self, bool, 23, 1> = Field::new("SCTLR_EL1", "SPAN");
+    /// Descriptor of the `UCI` field, for use with [`FieldAccess`].
+    pub const UCI_FIELD: Field<Self, bool, 26, 1> = Field::new("SCTLR_EL1", "UCI");
+    /// Descriptor of the `EnDA` field, for use with [`FieldAccess`].
+    pub const ENDA_FIELD: Field<Self, bool, 27, 1> = Field::new("SCTLR_EL1", "EnDA");
+    /// Descriptor of the `nTLSMD` field, for use with [`FieldAccess`].
+    pub const NTLSMD_FIELD: Field<Self, bool, 28, 1> = Field::new("SCTLR_EL1", "nTLSMD");
+    /// Descriptor of the `LSMAOE` field, for use with [`FieldAccess`].
+    pub const LSMAOE_FIELD: Field<Self, bool, 29, 1> = Field::new("SCTLR_EL1", "LSMAOE");
+    /// Descriptor of the `EnIB` field, for use with [`FieldAccess`].
+    pub const ENIB_FIELD: Field<Self, bool, 30, 1> = Field::new("SCTLR_EL1", "EnIB");
+    /// Descriptor of the `EnIA` field, for use with [`FieldAccess`].
+    pub const ENIA_FIELD: Field<Self, bool, 31, 1> = Field::new("SCTLR_EL1", "EnIA");
+    /// Descriptor of the `CMOW` field, for use with [`FieldAccess`].
+    pub const CMOW_FIELD: Field<Self, bool, 32, 1> = Field::new("SCTLR_EL1", "CMOW");
+    /// Descriptor of the `MSCEn` field, for use with [`FieldAccess`].
+    pub const MSCEN_FIELD: Field<Self, bool, 33, 1> = Field::new("SCTLR_EL1", "MSCEn");
+    /// Descriptor of the `EnFPM` field, for use with [`FieldAccess`].
+    pub const ENFPM_FIELD: Field<Self, bool, 34, 1> = Field::new("SCTLR_EL1", "EnFPM");
+    /// Descriptor of the `BT0` field, for use with [`FieldAccess`].
+    pub const BT0_FIELD: Field<Self, bool, 35, 1> = Field::new("SCTLR_EL1", "BT0");
+    /// Descriptor of the `BT1` field, for use with [`FieldAccess`].
+    pub const BT1_FIELD: Field<Self, bool, 36, 1> = Field::new("SCTLR_EL1", "BT1");
+    /// Descriptor of the `ITFSB` field, for use with [`FieldAccess`].
+    pub const ITFSB_FIELD: Field<Self, bool, 37, 1> = Field::new("SCTLR_EL1", "ITFSB");
+    /// Descriptor of the `TCF0` field, for use with [`FieldAccess`].
+    pub const TCF0_FIELD: Field<Self, u8, 38, 2> = Field::new("SCTLR_EL1", "TCF0");
+    /// Descriptor of the `TCF` field, for use with [`FieldAccess`].
+    pub const TCF_FIELD: Field<Self, u8, 40, 2> = Field::new("SCTLR_EL1", "TCF");
+    /// Descriptor of the `ATA0` field, for use with [`FieldAccess`].
+    pub const ATA0_FIELD: Field<Self, bool, 42, 1> = Field::new("SCTLR_EL1", "ATA0");
+    /// Descriptor of the `ATA` field, for use with [`FieldAccess`].
+    pub const ATA_FIELD: Field<Self, bool, 43, 1> = Field::new("SCTLR_EL1", "ATA");
+    /// Descriptor of the `DSSBS` field, for use with [`FieldAccess`].
+    pub const DSSBS_FIELD: Field<Self, bool, 44, 1> = Field::new("SCTLR_EL1", "DSSBS");
+    /// Descriptor of the `TWEDEn` field, for use with [`FieldAccess`].
+    pub const TWEDEN_FIELD: Field<Self, bool, 45, 1> = Field::new("SCTLR_EL1", "TWEDEn");
+    /// Descriptor of the `TWEDEL` field, for use with [`FieldAccess`].
+    pub const TWEDEL_FIELD: Field<Self, u8, 46, 4> = Field::new("SCTLR_EL1", "TWEDEL");
+    /// Descriptor of the `EnASR` field, for use with [`FieldAccess`].
+    pub const ENASR_FIELD: Field<Self, bool, 54, 1> = Field::new("SCTLR_EL1", "EnASR");
+    /// Descriptor of the `EnAS0` field, for use with [`FieldAccess`].
+    pub const ENAS0_FIELD: Field<Self, bool, 55, 1> = Field::new("SCTLR_EL1", "EnAS0");
+    /// Descriptor of the `EnALS` field, for use with [`FieldAccess`].
+    pub const ENALS_FIELD: Field<Self, bool, 56, 1> = Field::new("SCTLR_EL1", "EnALS");
+    /// Descriptor of the `EPAN` field, for use with [`FieldAccess`].
+    pub const EPAN_FIELD: Field<Self, bool, 57, 1> = Field::new("SCTLR_EL1", "EPAN");
+    /// Descriptor of the `TCSO0` field, for use with [`FieldAccess`].
+    pub const TCSO0_FIELD: Field<Self, bool, 58, 1> = Field::new("SCTLR_EL1", "TCSO0");
+    /// Descriptor of the `TCSO` field, for use with [`FieldAccess`].
+    pub const TCSO_FIELD: Field<Self, bool, 59, 1> = Field::new("SCTLR_EL1", "TCSO");
+    /// Descriptor of the `EnTP2` field, for use with [`FieldAccess`].
+    pub const ENTP2_FIELD: Field<Self, bool, 60, 1> = Field::new("SCTLR_EL1", "EnTP2");
+    /// Descriptor of the `NMI` field, for use with [`FieldAccess`].
+    pub const NMI_FIELD: Field<Self, bool, 61, 1> = Field::new("SCTLR_EL1", "NMI");
+    /// Descriptor of the `SPINTMASK` field, for use with [`FieldAccess`].
+    pub const SPINTMASK_FIELD: Field<Self, bool, 62, 1> = Field::new("SCTLR_EL1", "SPINTMASK");
+    /// Descriptor of the `TIDCP` field, for use with [`FieldAccess`].
+    pub const TIDCP_FIELD: Field<Self, bool, 63, 1> = Field::new("SCTLR_EL1", "TIDCP");
+
+    /// Returns the value of the `TCF0` field.
+    pub const fn tcf0(self) -> u8 {
+        Self::TCF0_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `TCF0` field.
+    pub const fn set_tcf0(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::TCF0_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `TCF0` field set to the given value.
+    pub const fn with_tcf0(mut self, value: u8) -> Self {
+        self.set_tcf0(value);
+        self
+    }
+
+    /// Sets the value of the `TCF0` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_tcf0(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_tcf0(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `TCF0` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_tcf0(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::TCF0_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `TCF` field.
+    pub const fn tcf(self) -> u8 {
+        Self::TCF_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `TCF` field.
+    pub const fn set_tcf(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::TCF_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `TCF` field set to the given value.
+    pub const fn with_tcf(mut self, value: u8) -> Self {
+        self.set_tcf(value);
+        self
+    }
+
+    /// Sets the value of the `TCF` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_tcf(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_tcf(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `TCF` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_tcf(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::TCF_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `TWEDEL` field.
+    pub const fn twedel(self) -> u8 {
+        Self::TWEDEL_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `TWEDEL` field.
+    pub const fn set_twedel(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::TWEDEL_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `TWEDEL` field set to the given value.
+    pub const fn with_twedel(mut self, value: u8) -> Self {
+        self.set_twedel(value);
+        self
+    }
+
+    /// Sets the value of the `TWEDEL` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_twedel(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_twedel(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `TWEDEL` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_twedel(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::TWEDEL_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for SctlrEl1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("SctlrEl1");
+        let bits = self.bits();
+        s.field("M", &Self::M_FIELD.extract(bits));
+        s.field("A", &Self::A_FIELD.extract(bits));
+        s.field("C", &Self::C_FIELD.extract(bits));
+        s.field("SA", &Self::SA_FIELD.extract(bits));
+        s.field("SA0", &Self::SA0_FIELD.extract(bits));
+        s.field("CP15BEN", &Self::CP15BEN_FIELD.extract(bits));
+        s.field("nAA", &Self::NAA_FIELD.extract(bits));
+        s.field("ITD", &Self::ITD_FIELD.extract(bits));
+        s.field("SED", &Self::SED_FIELD.extract(bits));
+        s.field("UMA", &Self::UMA_FIELD.extract(bits));
+        s.field("EnRCTX", &Self::ENRCTX_FIELD.extract(bits));
+        s.field("EOS", &Self::EOS_FIELD.extract(bits));
+        s.field("I", &Self::I_FIELD.extract(bits));
+        s.field("EnDB", &Self::ENDB_FIELD.extract(bits));
+        s.field("DZE", &Self::DZE_FIELD.extract(bits));
+        s.field("UCT", &Self::UCT_FIELD.extract(bits));
+        s.field("nTWI", &Self::NTWI_FIELD.extract(bits));
+        s.field("nTWE", &Self::NTWE_FIELD.extract(bits));
+        s.field("WXN", &Self::WXN_FIELD.extract(bits));
+        s.field("TSCXT", &Self::TSCXT_FIELD.extract(bits));
+        s.field("IESB", &Self::IESB_FIELD.extract(bits));
+        s.field("EIS", &Self::EIS_FIELD.extract(bits));
+        s.field("SPAN", &Self::SPAN_FIELD.extract(bits));
+        s.field("UCI", &Self::UCI_FIELD.extract(bits));
+        s.field("EnDA", &Self::ENDA_FIELD.extract(bits));
+        s.field("nTLSMD", &Self::NTLSMD_FIELD.extract(bits));
+        s.field("LSMAOE", &Self::LSMAOE_FIELD.extract(bits));
+        s.field("EnIB", &Self::ENIB_FIELD.extract(bits));
+        s.field("EnIA", &Self::ENIA_FIELD.extract(bits));
+        s.field("CMOW", &Self::CMOW_FIELD.extract(bits));
+        s.field("MSCEn", &Self::MSCEN_FIELD.extract(bits));
+        s.field("EnFPM", &Self::ENFPM_FIELD.extract(bits));
+        s.field("BT0", &Self::BT0_FIELD.extract(bits));
+        s.field("BT1", &Self::BT1_FIELD.extract(bits));
+        s.field("ITFSB", &Self::ITFSB_FIELD.extract(bits));
+        s.field("TCF0", &Self::TCF0_FIELD.extract(bits));
+        s.field("TCF", &Self::TCF_FIELD.extract(bits));
+        s.field("ATA0", &Self::ATA0_FIELD.extract(bits));
+        s.field("ATA", &Self::ATA_FIELD.extract(bits));
+        s.field("DSSBS", &Self::DSSBS_FIELD.extract(bits));
+        s.field("TWEDEn", &Self::TWEDEN_FIELD.extract(bits));
+        s.field("TWEDEL", &Self::TWEDEL_FIELD.extract(bits));
+        s.field("EnASR", &Self::ENASR_FIELD.extract(bits));
+        s.field("EnAS0", &Self::ENAS0_FIELD.extract(bits));
+        s.field("EnALS", &Self::ENALS_FIELD.extract(bits));
+        s.field("EPAN", &Self::EPAN_FIELD.extract(bits));
+        s.field("TCSO0", &Self::TCSO0_FIELD.extract(bits));
+        s.field("TCSO", &Self::TCSO_FIELD.extract(bits));
+        s.field("EnTP2", &Self::ENTP2_FIELD.extract(bits));
+        s.field("NMI", &Self::NMI_FIELD.extract(bits));
+        s.field("SPINTMASK", &Self::SPINTMASK_FIELD.extract(bits));
+        s.field("TIDCP", &Self::TIDCP_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for SctlrEl1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "SctlrEl1 {{ M: {=u64}, A: {=u64}, C: {=u64}, SA: {=u64}, SA0: {=u64}, CP15BEN: {=u64}, nAA: {=u64}, ITD: {=u64}, SED: {=u64}, UMA: {=u64}, EnRCTX: {=u64}, EOS: {=u64}, I: {=u64}, EnDB: {=u64}, DZE: {=u64}, UCT: {=u64}, nTWI: {=u64}, nTWE: {=u64}, WXN: {=u64}, TSCXT: {=u64}, IESB: {=u64}, EIS: {=u64}, SPAN: {=u64}, UCI: {=u64}, EnDA: {=u64}, nTLSMD: {=u64}, LSMAOE: {=u64}, EnIB: {=u64}, EnIA: {=u64}, CMOW: {=u64}, MSCEn: {=u64}, EnFPM: {=u64}, BT0: {=u64}, BT1: {=u64}, ITFSB: {=u64}, TCF0: {=u64}, TCF: {=u64}, ATA0: {=u64}, ATA: {=u64}, DSSBS: {=u64}, TWEDEn: {=u64}, TWEDEL: {=u64}, EnASR: {=u64}, EnAS0: {=u64}, EnALS: {=u64}, EPAN: {=u64}, TCSO0: {=u64}, TCSO: {=u64}, EnTP2: {=u64}, NMI: {=u64}, SPINTMASK: {=u64}, TIDCP: {=u64} }}",
+            Self::M_FIELD.extract(self.bits()),
+            Self::A_FIELD.extract(self.bits()),
+            Self::C_FIELD.extract(self.bits()),
+            Self::SA_FIELD.extract(self.bits()),
+            Self::SA0_FIELD.extract(self.bits()),
+            Self::CP15BEN_FIELD.extract(self.bits()),
+            Self::NAA_FIELD.extract(self.bits()),
+            Self::ITD_FIELD.extract(self.bits()),
+            Self::SED_FIELD.extract(self.bits()),
+            Self::UMA_FIELD.extract(self.bits()),
+            Self::ENRCTX_FIELD.extract(self.bits()),
+            Self::EOS_FIELD.extract(self.bits()),
+            Self::I_FIELD.extract(self.bits()),
+            Self::ENDB_FIELD.extract(self.bits()),
+            Self::DZE_FIELD.extract(self.bits()),
+            Self::UCT_FIELD.extract(self.bits()),
+            Self::NTWI_FIELD.extract(self.bits()),
+            Self::NTWE_FIELD.extract(self.bits()),
+            Self::WXN_FIELD.extract(self.bits()),
+            Self::TSCXT_FIELD.extract(self.bits()),
+            Self::IESB_FIELD.extract(self.bits()),
+            Self::EIS_FIELD.extract(self.bits()),
+            Self::SPAN_FIELD.extract(self.bits()),
+            Self::UCI_FIELD.extract(self.bits()),
+            Self::ENDA_FIELD.extract(self.bits()),
+            Self::NTLSMD_FIELD.extract(self.bits()),
+            Self::LSMAOE_FIELD.extract(self.bits()),
+            Self::ENIB_FIELD.extract(self.bits()),
+            Self::ENIA_FIELD.extract(self.bits()),
+            Self::CMOW_FIELD.extract(self.bits()),
+            Self::MSCEN_FIELD.extract(self.bits()),
+            Self::ENFPM_FIELD.extract(self.bits()),
+            Self::BT0_FIELD.extract(self.bits()),
+            Self::BT1_FIELD.extract(self.bits()),
+            Self::ITFSB_FIELD.extract(self.bits()),
+            Self::TCF0_FIELD.extract(self.bits()),
+            Self::TCF_FIELD.extract(self.bits()),
+            Self::ATA0_FIELD.extract(self.bits()),
+            Self::ATA_FIELD.extract(self.bits()),
+            Self::DSSBS_FIELD.extract(self.bits()),
+            Self::TWEDEN_FIELD.extract(self.bits()),
+            Self::TWEDEL_FIELD.extract(self.bits()),
+            Self::ENASR_FIELD.extract(self.bits()),
+            Self::ENAS0_FIELD.extract(self.bits()),
+            Self::ENALS_FIELD.extract(self.bits()),
+            Self::EPAN_FIELD.extract(self.bits()),
+            Self::TCSO0_FIELD.extract(self.bits()),
+            Self::TCSO_FIELD.extract(self.bits()),
+            Self::ENTP2_FIELD.extract(self.bits()),
+            Self::NMI_FIELD.extract(self.bits()),
+            Self::SPINTMASK_FIELD.extract(self.bits()),
+            Self::TIDCP_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for SctlrEl1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for SctlrEl1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl SctlrEl1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("M"),
+                old: Self::M_FIELD.extract(self.bits()),
+                new: Self::M_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("A"),
+                old: Self::A_FIELD.extract(self.bits()),
+                new: Self::A_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("C"),
+                old: Self::C_FIELD.extract(self.bits()),
+                new: Self::C_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SA"),
+                old: Self::SA_FIELD.extract(self.bits()),
+                new: Self::SA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SA0"),
+                old: Self::SA0_FIELD.extract(self.bits()),
+                new: Self::SA0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CP15BEN"),
+                old: Self::CP15BEN_FIELD.extract(self.bits()),
+                new: Self::CP15BEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nAA"),
+                old: Self::NAA_FIELD.extract(self.bits()),
+                new: Self::NAA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ITD"),
+                old: Self::ITD_FIELD.extract(self.bits()),
+                new: Self::ITD_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SED"),
+                old: Self::SED_FIELD.extract(self.bits()),
+                new: Self::SED_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("UMA"),
+                old: Self::UMA_FIELD.extract(self.bits()),
+                new: Self::UMA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnRCTX"),
+                old: Self::ENRCTX_FIELD.extract(self.bits()),
+                new: Self::ENRCTX_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EOS"),
+                old: Self::EOS_FIELD.extract(self.bits()),
+                new: Self::EOS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("I"),
+                old: Self::I_FIELD.extract(self.bits()),
+                new: Self::I_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnDB"),
+                old: Self::ENDB_FIELD.extract(self.bits()),
+                new: Self::ENDB_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DZE"),
+                old: Self::DZE_FIELD.extract(self.bits()),
+                new: Self::DZE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("UCT"),
+                old: Self::UCT_FIELD.extract(self.bits()),
+                new: Self::UCT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nTWI"),
+                old: Self::NTWI_FIELD.extract(self.bits()),
+                new: Self::NTWI_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nTWE"),
+                old: Self::NTWE_FIELD.extract(self.bits()),
+                new: Self::NTWE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("WXN"),
+                old: Self::WXN_FIELD.extract(self.bits()),
+                new: Self::WXN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TSCXT"),
+                old: Self::TSCXT_FIELD.extract(self.bits()),
+                new: Self::TSCXT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IESB"),
+                old: Self::IESB_FIELD.extract(self.bits()),
+                new: Self::IESB_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EIS"),
+                old: Self::EIS_FIELD.extract(self.bits()),
+                new: Self::EIS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SPAN"),
+                old: Self::SPAN_FIELD.extract(self.bits()),
+                new: Self::SPAN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("UCI"),
+                old: Self::UCI_FIELD.extract(self.bits()),
+                new: Self::UCI_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnDA"),
+                old: Self::ENDA_FIELD.extract(self.bits()),
+                new: Self::ENDA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nTLSMD"),
+                old: Self::NTLSMD_FIELD.extract(self.bits()),
+                new: Self::NTLSMD_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("LSMAOE"),
+                old: Self::LSMAOE_FIELD.extract(self.bits()),
+                new: Self::LSMAOE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnIB"),
+                old: Self::ENIB_FIELD.extract(self.bits()),
+                new: Self::ENIB_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnIA"),
+                old: Self::ENIA_FIELD.extract(self.bits()),
+                new: Self::ENIA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CMOW"),
+                old: Self::CMOW_FIELD.extract(self.bits()),
+                new: Self::CMOW_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("MSCEn"),
+                old: Self::MSCEN_FIELD.extract(self.bits()),
+                new: Self::MSCEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnFPM"),
+                old: Self::ENFPM_FIELD.extract(self.bits()),
+                new: Self::ENFPM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("BT0"),
+                old: Self::BT0_FIELD.extract(self.bits()),
+                new: Self::BT0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("BT1"),
+                old: Self::BT1_FIELD.extract(self.bits()),
+                new: Self::BT1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ITFSB"),
+                old: Self::ITFSB_FIELD.extract(self.bits()),
+                new: Self::ITFSB_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TCF0"),
+                old: Self::TCF0_FIELD.extract(self.bits()),
+                new: Self::TCF0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TCF"),
+                old: Self::TCF_FIELD.extract(self.bits()),
+                new: Self::TCF_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ATA0"),
+                old: Self::ATA0_FIELD.extract(self.bits()),
+                new: Self::ATA0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ATA"),
+                old: Self::ATA_FIELD.extract(self.bits()),
+                new: Self::ATA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DSSBS"),
+                old: Self::DSSBS_FIELD.extract(self.bits()),
+                new: Self::DSSBS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TWEDEn"),
+                old: Self::TWEDEN_FIELD.extract(self.bits()),
+                new: Self::TWEDEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TWEDEL"),
+                old: Self::TWEDEL_FIELD.extract(self.bits()),
+                new: Self::TWEDEL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnASR"),
+                old: Self::ENASR_FIELD.extract(self.bits()),
+                new: Self::ENASR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnAS0"),
+                old: Self::ENAS0_FIELD.extract(self.bits()),
+                new: Self::ENAS0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnALS"),
+                old: Self::ENALS_FIELD.extract(self.bits()),
+                new: Self::ENALS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EPAN"),
+                old: Self::EPAN_FIELD.extract(self.bits()),
+                new: Self::EPAN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TCSO0"),
+                old: Self::TCSO0_FIELD.extract(self.bits()),
+                new: Self::TCSO0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TCSO"),
+                old: Self::TCSO_FIELD.extract(self.bits()),
+                new: Self::TCSO_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnTP2"),
+                old: Self::ENTP2_FIELD.extract(self.bits()),
+                new: Self::ENTP2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("NMI"),
+                old: Self::NMI_FIELD.extract(self.bits()),
+                new: Self::NMI_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SPINTMASK"),
+                old: Self::SPINTMASK_FIELD.extract(self.bits()),
+                new: Self::SPINTMASK_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TIDCP"),
+                old: Self::TIDCP_FIELD.extract(self.bits()),
+                new: Self::TIDCP_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits()
+                    & 0b11_1100_0000_0000_0000_0000_0000_0011_0000_0010_0000_0000_0000_0000,
+                new: other.bits()
+                    & 0b11_1100_0000_0000_0000_0000_0000_0011_0000_0010_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl SctlrEl1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, features: Option<&CpuFeatures>) -> Self {
+        let mut bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        if let Some(features) = features
+            && !(features.contains(Feature::Pauth))
+        {
+            bits &= !0b10_0000_0000_0000;
+        }
+        if let Some(features) = features
+            && !(features.contains(Feature::Pauth))
+        {
+            bits &= !0b1000_0000_0000_0000_0000_0000_0000;
+        }
+        if let Some(features) = features
+            && !(features.contains(Feature::Pauth))
+        {
+            bits &= !0b100_0000_0000_0000_0000_0000_0000_0000;
+        }
+        if let Some(features) = features
+            && !(features.contains(Feature::Pauth))
+        {
+            bits &= !0b1000_0000_0000_0000_0000_0000_0000_0000;
+        }
+        if let Some(features) = features
+            && !(features.contains(Feature::Bti))
+        {
+            bits &= !0b1000_0000_0000_0000_0000_0000_0000_0000_0000;
+        }
+        if let Some(features) = features
+            && !(features.contains(Feature::Bti))
+        {
+            bits &= !0b1_0000_0000_0000_0000_0000_0000_0000_0000_0000;
+        }
+        if let Some(features) = features
+            && !(features.contains(Feature::Mte2))
+        {
+            bits &= !0b10_0000_0000_0000_0000_0000_0000_0000_0000_0000;
+        }
+        if let Some(features) = features
+            && !(features.contains(Feature::Mte2))
+        {
+            bits &= !0b1100_0000_0000_0000_0000_0000_0000_0000_0000_0000;
+        }
+        if let Some(features) = features
+            && !(features.contains(Feature::Mte2))
+        {
+            bits &= !0b11_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000;
+        }
+        if let Some(features) = features
+            && !(features.contains(Feature::Mte2))
+        {
+            bits &= !0b100_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000;
+        }
+        if let Some(features) = features
+            && !(features.contains(Feature::Mte2))
+        {
+            bits &= !0b1000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000;
+        }
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl SctlrEl1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                Self::ENDB_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Pauth),
+                UnimplementedField {
+                    register: "SCTLR_EL1",
+                    field: "EnDB",
+                    requires: "FEAT_PAuth",
+                },
+            ),
+            (
+                Self::ENDA_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Pauth),
+                UnimplementedField {
+                    register: "SCTLR_EL1",
+                    field: "EnDA",
+                    requires: "FEAT_PAuth",
+                },
+            ),
+            (
+                Self::ENIB_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Pauth),
+                UnimplementedField {
+                    register: "SCTLR_EL1",
+                    field: "EnIB",
+                    requires: "FEAT_PAuth",
+                },
+            ),
+            (
+                Self::ENIA_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Pauth),
+                UnimplementedField {
+                    register: "SCTLR_EL1",
+                    field: "EnIA",
+                    requires: "FEAT_PAuth",
+                },
+            ),
+            (
+                Self::BT0_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Bti),
+                UnimplementedField {
+                    register: "SCTLR_EL1",
+                    field: "BT0",
+                    requires: "FEAT_BTI",
+                },
+            ),
+            (
+                Self::BT1_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Bti),
+                UnimplementedField {
+                    register: "SCTLR_EL1",
+                    field: "BT1",
+                    requires: "FEAT_BTI",
+                },
+            ),
+            (
+                Self::ITFSB_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Mte2),
+                UnimplementedField {
+                    register: "SCTLR_EL1",
+                    field: "ITFSB",
+                    requires: "FEAT_MTE2",
+                },
+            ),
+            (
+                Self::TCF0_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Mte2),
+                UnimplementedField {
+                    register: "SCTLR_EL1",
+                    field: "TCF0",
+                    requires: "FEAT_MTE2",
+                },
+            ),
+            (
+                Self::TCF_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Mte2),
+                UnimplementedField {
+                    register: "SCTLR_EL1",
+                    field: "TCF",
+                    requires: "FEAT_MTE2",
+                },
+            ),
+            (
+                Self::ATA0_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Mte2),
+                UnimplementedField {
+                    register: "SCTLR_EL1",
+                    field: "ATA0",
+                    requires: "FEAT_MTE2",
+                },
+            ),
+            (
+                Self::ATA_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Mte2),
+                UnimplementedField {
+                    register: "SCTLR_EL1",
+                    field: "ATA",
+                    requires: "FEAT_MTE2",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `SCTLR_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct SctlrEl2: u64 {
+        /// `M` bit.
+        const M = 1 << 0;
+        /// `A` bit.
+        const A = 1 << 1;
+        /// `C` bit.
+        const C = 1 << 2;
+        /// `SA` bit.
+        const SA = 1 << 3;
+        /// `SA0` bit.
+        const SA0 = 1 << 4;
+        /// `CP15BEN` bit.
+        const CP15BEN = 1 << 5;
+        /// `nAA` bit.
+        const NAA = 1 << 6;
+        /// `SED` bit.
+        const SED = 1 << 8;
+        /// `UMA` bit.
+        const UMA = 1 << 9;
+        /// `EnRCTX` bit.
+        const ENRCTX = 1 << 10;
+        /// `EOS` bit.
+        const EOS = 1 << 11;
+        /// `I` bit.
+        const I = 1 << 12;
+        /// `EnDB` bit.
+        const ENDB = 1 << 13;
+        /// `DZE` bit.
+        const DZE = 1 << 14;
+        /// `UCT` bit.
+        const UCT = 1 << 15;
+        /// `nTWI` bit.
+        const NTWI = 1 << 16;
+        /// `nTWE` bit.
+        const NTWE = 1 << 18;
+        /// `WXN` bit.
+        const WXN = 1 << 19;
+        /// `IESB` bit.
+        const IESB = 1 << 21;
+        /// `EIS` bit.
+        const EIS = 1 << 22;
+        /// Do not set Privileged Access Never, on taking an exception to EL2.
+        const SPAN = 1 << 23;
+        /// `UCI` bit.
+        const UCI = 1 << 26;
+        /// `EnDA` bit.
+        const ENDA = 1 << 27;
+        /// `nTLSMD` bit.
+        const NTLSMD = 1 << 28;
+        /// `LSMAOE` bit.
+        const LSMAOE = 1 << 29;
+        /// Enable pointer authentication using APIBKey_EL1.
+        const ENIB = 1 << 30;
+        /// Enable pointer authentication using APIAKey_EL1.
+        const ENIA = 1 << 31;
+        /// `CMOW` bit.
+        const CMOW = 1 << 32;
+        /// `MSCEn` bit.
+        const MSCEN = 1 << 33;
+        /// `EnFPM` bit.
+        const ENFPM = 1 << 34;
+        /// `BT0` bit.
+        const BT0 = 1 << 35;
+        /// `BT` bit.
+        const BT = 1 << 36;
+        /// `ITFSB` bit.
+        const ITFSB = 1 << 37;
+        /// `ATA0` bit.
+        const ATA0 = 1 << 42;
+        /// `ATA` bit.
+        const ATA = 1 << 43;
+        /// Default PSTATE.SSBS value on Exception Entry.
+        const DSSBS = 1 << 44;
+        /// `TWEDEn` bit.
+        const TWEDEN = 1 << 45;
+        /// `EnASR` bit.
+        const ENASR = 1 << 54;
+        /// `EnAS0` bit.
+        const ENAS0 = 1 << 55;
+        /// `EnALS` bit.
+        const ENALS = 1 << 56;
+        /// `EPAN` bit.
+        const EPAN = 1 << 57;
+        /// `TCSO0` bit.
+        const TCSO0 = 1 << 58;
+        /// `TCSO` bit.
+        const TCSO = 1 << 59;
+        /// `EnTP2` bit.
+        const ENTP2 = 1 << 60;
+        /// `NMI` bit.
+        const NMI = 1 << 61;
+        /// SP Interrupt Mask enable.
+        const SPINTMASK = 1 << 62;
+        /// `TIDCP` bit.
+        const TIDCP = 1 << 63;
+    }
+}
+
+#[cfg(feature = "el2")]
+impl SctlrEl2 {
+    /// Descriptor of the `M` field, for use with [`FieldAccess`].
+    pub const M_FIELD: Field<Self, bool, 0, 1> = Field::new("SCTLR_EL2", "M");
+    /// Descriptor of the `A` field, for use with [`FieldAccess`].
+    pub const A_FIELD: Field<Self, bool, 1, 1> = Field::new("SCTLR_EL2", "A");
+    /// Descriptor of the `C` field, for use with [`FieldAccess`].
+    pub const C_FIELD: Field<Self, bool, 2, 1> = Field::new("SCTLR_EL2", "C");
+    /// Descriptor of the `SA` field, for use with [`FieldAccess`].
+    pub const SA_FIELD: Field<Self, bool, 3, 1> = Field::new("SCTLR_EL2", "SA");
+    /// Descriptor of the `SA0` field, for use with [`FieldAccess`].
+    pub const SA0_FIELD: Field<Self, bool, 4, 1> = Field::new("SCTLR_EL2", "SA0");
+    /// Descriptor of the `CP15BEN` field, for use with [`FieldAccess`].
+    pub const CP15BEN_FIELD: Field<Self, bool, 5, 1> = Field::new("SCTLR_EL2", "CP15BEN");
+    /// Descriptor of the `nAA` field, for use with [`FieldAccess`].
+    pub const NAA_FIELD: Field<Self, bool, 6, 1> = Field::new("SCTLR_EL2", "nAA");
+    /// Descriptor of the `SED` field, for use with [`FieldAccess`].
+    pub const SED_FIELD: Field<Self, bool, 8, 1> = Field::new("SCTLR_EL2", "SED");
+    /// Descriptor of the `UMA` field, for use with [`FieldAccess`].
+    pub const UMA_FIELD: Field<Self, bool, 9, 1> = Field::new("SCTLR_EL2", "UMA");
+    /// Descriptor of the `EnRCTX` field, for use with [`FieldAccess`].
+    pub const ENRCTX_FIELD: Field<Self, bool, 10, 1> = Field::new("SCTLR_EL2", "EnRCTX");
+    /// Descriptor of the `EOS` field, for use with [`FieldAccess`].
+    pub const EOS_FIELD: Field<Self, bool, 11, 1> = Field::new("SCTLR_EL2", "EOS");
+    /// Descriptor of the `I` field, for use with [`FieldAccess`].
+    pub const I_FIELD: Field<Self, bool, 12, 1> = Field::new("SCTLR_EL2", "I");
+    /// Descriptor of the `EnDB` field, for use with [`FieldAccess`].
+    pub const ENDB_FIELD: Field<Self, bool, 13, 1> = Field::new("SCTLR_EL2", "EnDB");
+    /// Descriptor of the `DZE` field, for use with [`FieldAccess`].
+    pub const DZE_FIELD: Field<Self, bool, 14, 1> = Field::new("SCTLR_EL2", "DZE");
+    /// Descriptor of the `UCT` field, for use with [`FieldAccess`].
+    pub const UCT_FIELD: Field<Self, bool, 15, 1> = Field::new("SCTLR_EL2", "UCT");
+    /// Descriptor of the `nTWI` field, for use with [`FieldAccess`].
+    pub const NTWI_FIELD: Field<Self, bool, 16, 1> = Field::new("SCTLR_EL2", "nTWI");
+    /// Descriptor of the `nTWE` field, for use with [`FieldAccess`].
+    pub const NTWE_FIELD: Field<Self, bool, 18, 1> = Field::new("SCTLR_EL2", "nTWE");
+    /// Descriptor of the `WXN` field, for use with [`FieldAccess`].
+    pub const WXN_FIELD: Field<Self, bool, 19, 1> = Field::new("SCTLR_EL2", "WXN");
+    /// Descriptor of the `IESB` field, for use with [`FieldAccess`].
+    pub const IESB_FIELD: Field<Self, bool, 21, 1> = Field::new("SCTLR_EL2", "IESB");
+    /// Descriptor of the `EIS` field, for use with [`FieldAccess`].
+    pub const EIS_FIELD: Field<Self, bool, 22, 1> = Field::new("SCTLR_EL2", "EIS");
+    /// Descriptor of the `SPAN` field, for use with [`FieldAccess`].
+    pub const SPAN_FIELD: Field<Self, bool, 23, 1> = Field::new("SCTLR_EL2", "SPAN");
+    /// Descriptor of the `UCI` field, for use with [`FieldAccess`].
+    pub const UCI_FIELD: Field<Self, bool, 26, 1> = Field::new("SCTLR_EL2", "UCI");
+    /// Descriptor of the `EnDA` field, for use with [`FieldAccess`].
+    pub const ENDA_FIELD: Field<Self, bool, 27, 1> = Field::new("SCTLR_EL2", "EnDA");
+    /// Descriptor of the `nTLSMD` field, for use with [`FieldAccess`].
+    pub const NTLSMD_FIELD: Field<Self, bool, 28, 1> = Field::new("SCTLR_EL2", "nTLSMD");
+    /// Descriptor of the `LSMAOE` field, for use with [`FieldAccess`].
+    pub const LSMAOE_FIELD: Field<Self, bool, 29, 1> = Field::new("SCTLR_EL2", "LSMAOE");
+    /// Descriptor of the `EnIB` field, for use with [`FieldAccess`].
+    pub const ENIB_FIELD: Field<Self, bool, 30, 1> = Field::new("SCTLR_EL2", "EnIB");
+    /// Descriptor of the `EnIA` field, for use with [`FieldAccess`].
+    pub const ENIA_FIELD: Field<Self, bool, 31, 1> = Field::new("SCTLR_EL2", "EnIA");
+    /// Descriptor of the `CMOW` field, for use with [`FieldAccess`].
+    pub const CMOW_FIELD: Field<Self, bool, 32, 1> = Field::new("SCTLR_EL2", "CMOW");
+    /// Descriptor of the `MSCEn` field, for use with [`FieldAccess`].
+    pub const MSCEN_FIELD: Field<Self, bool, 33, 1> = Field::new("SCTLR_EL2", "MSCEn");
+    /// Descriptor of the `EnFPM` field, for use with [`FieldAccess`].
+    pub const ENFPM_FIELD: Field<Self, bool, 34, 1> = Field::new("SCTLR_EL2", "EnFPM");
+    /// Descriptor of the `BT0` field, for use with [`FieldAccess`].
+    pub const BT0_FIELD: Field<Self, bool, 35, 1> = Field::new("SCTLR_EL2", "BT0");
+    /// Descriptor of the `BT` field, for use with [`FieldAccess`].
+    pub const BT_FIELD: Field<Self, bool, 36, 1> = Field::new("SCTLR_EL2", "BT");
+    /// Descriptor of the `ITFSB` field, for use with [`FieldAccess`].
+    pub const ITFSB_FIELD: Field<Self, bool, 37, 1> = Field::new("SCTLR_EL2", "ITFSB");
+    /// Descriptor of the `TCF0` field, for use with [`FieldAccess`].
+    pub const TCF0_FIELD: Field<Self, u8, 38, 2> = Field::new("SCTLR_EL2", "TCF0");
+    /// Descriptor of the `TCF` field, for use with [`FieldAccess`].
+    pub const TCF_FIELD: Field<Self, u8, 40, 2> = Field::new("SCTLR_EL2", "TCF");
+    /// Descriptor of the `ATA0` field, for use with [`FieldAccess`].
+    pub const ATA0_FIELD: Field<Self, bool, 42, 1> = Field::new("SCTLR_EL2", "ATA0");
+    /// Descriptor of the `ATA` field, for use with [`FieldAccess`].
+    pub const ATA_FIELD: Field<Self, bool, 43, 1> = Field::new("SCTLR_EL2", "ATA");
+    /// Descriptor of the `DSSBS` field, for use with [`FieldAccess`].
+    pub const DSSBS_FIELD: Field<Self, bool, 44, 1> = Field::new("SCTLR_EL2", "DSSBS");
+    /// Descriptor of the `TWEDEn` field, for use with [`FieldAccess`].
+    pub const TWEDEN_FIELD: Field<Self, bool, 45, 1> = Field::new("SCTLR_EL2", "TWEDEn");
+    /// Descriptor of the `TWEDEL` field, for use with [`FieldAccess`].
+    pub const TWEDEL_FIELD: Field<Self, u8, 46, 4> = Field::new("SCTLR_EL2", "TWEDEL");
+    /// Descriptor of the `EnASR` field, for use with [`FieldAccess`].
+    pub const ENASR_FIELD: Field<Self, bool, 54, 1> = Field::new("SCTLR_EL2", "EnASR");
+    /// Descriptor of the `EnAS0` field, for use with [`FieldAccess`].
+    pub const ENAS0_FIELD: Field<Self, bool, 55, 1> = Field::new("SCTLR_EL2", "EnAS0");
+    /// Descriptor of the `EnALS` field, for use with [`FieldAccess`].
+    pub const ENALS_FIELD: Field<Self, bool, 56, 1> = Field::new("SCTLR_EL2", "EnALS");
+    /// Descriptor of the `EPAN` field, for use with [`FieldAccess`].
+    pub const EPAN_FIELD: Field<Self, bool, 57, 1> = Field::new("SCTLR_EL2", "EPAN");
+    /// Descriptor of the `TCSO0` field, for use with [`FieldAccess`].
+    pub const TCSO0_FIELD: Field<Self, bool, 58, 1> = Field::new("SCTLR_EL2", "TCSO0");
+    /// Descriptor of the `TCSO` field, for use with [`FieldAccess`].
+    pub const TCSO_FIELD: Field<Self, bool, 59, 1> = Field::new("SCTLR_EL2", "TCSO");
+    /// Descriptor of the `EnTP2` field, for use with [`FieldAccess`].
+    pub const ENTP2_FIELD: Field<Self, bool, 60, 1> = Field::new("SCTLR_EL2", "EnTP2");
+    /// Descriptor of the `NMI` field, for use with [`FieldAccess`].
+    pub const NMI_FIELD: Field<Self, bool, 61, 1> = Field::new("SCTLR_EL2", "NMI");
+    /// Descriptor of the `SPINTMASK` field, for use with [`FieldAccess`].
+    pub const SPINTMASK_FIELD: Field<Self, bool, 62, 1> = Field::new("SCTLR_EL2", "SPINTMASK");
+    /// Descriptor of the `TIDCP` field, for use with [`FieldAccess`].
+    pub const TIDCP_FIELD: Field<Self, bool, 63, 1> = Field::new("SCTLR_EL2", "TIDCP");
+
+    /// Returns the value of the `TCF0` field.
+    pub const fn tcf0(self) -> u8 {
+        Self::TCF0_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `TCF0` field.
+    pub const fn set_tcf0(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::TCF0_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `TCF0` field set to the given value.
+    pub const fn with_tcf0(mut self, value: u8) -> Self {
+        self.set_tcf0(value);
+        self
+    }
+
+    /// Sets the value of the `TCF0` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_tcf0(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_tcf0(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `TCF0` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_tcf0(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::TCF0_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `TCF` field.
+    pub const fn tcf(self) -> u8 {
+        Self::TCF_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `TCF` field.
+    pub const fn set_tcf(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::TCF_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `TCF` field set to the given value.
+    pub const fn with_tcf(mut self, value: u8) -> Self {
+        self.set_tcf(value);
+        self
+    }
+
+    /// Sets the value of the `TCF` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_tcf(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_tcf(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `TCF` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_tcf(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::TCF_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `TWEDEL` field.
+    pub const fn twedel(self) -> u8 {
+        Self::TWEDEL_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `TWEDEL` field.
+    pub const fn set_twedel(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::TWEDEL_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `TWEDEL` field set to the given value.
+    pub const fn with_twedel(mut self, value: u8) -> Self {
+        self.set_twedel(value);
+        self
+    }
+
+    /// Sets the value of the `TWEDEL` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_twedel(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_twedel(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `TWEDEL` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_twedel(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::TWEDEL_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Display for SctlrEl2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("SctlrEl2");
+        let bits = self.bits();
+        s.field("M", &Self::M_FIELD.extract(bits));
+        s.field("A", &Self::A_FIELD.extract(bits));
+        s.field("C", &Self::C_FIELD.extract(bits));
+        s.field("SA", &Self::SA_FIELD.extract(bits));
+        s.field("SA0", &Self::SA0_FIELD.extract(bits));
+        s.field("CP15BEN", &Self::CP15BEN_FIELD.extract(bits));
+        s.field("nAA", &Self::NAA_FIELD.extract(bits));
+        s.field("SED", &Self::SED_FIELD.extract(bits));
+        s.field("UMA", &Self::UMA_FIELD.extract(bits));
+        s.field("EnRCTX", &Self::ENRCTX_FIELD.extract(bits));
+        s.field("EOS", &Self::EOS_FIELD.extract(bits));
+        s.field("I", &Self::I_FIELD.extract(bits));
+        s.field("EnDB", &Self::ENDB_FIELD.extract(bits));
+        s.field("DZE", &Self::DZE_FIELD.extract(bits));
+        s.field("UCT", &Self::UCT_FIELD.extract(bits));
+        s.field("nTWI", &Self::NTWI_FIELD.extract(bits));
+        s.field("nTWE", &Self::NTWE_FIELD.extract(bits));
+        s.field("WXN", &Self::WXN_FIELD.extract(bits));
+        s.field("IESB", &Self::IESB_FIELD.extract(bits));
+        s.field("EIS", &Self::EIS_FIELD.extract(bits));
+        s.field("SPAN", &Self::SPAN_FIELD.extract(bits));
+        s.field("UCI", &Self::UCI_FIELD.extract(bits));
+        s.field("EnDA", &Self::ENDA_FIELD.extract(bits));
+        s.field("nTLSMD", &Self::NTLSMD_FIELD.extract(bits));
+        s.field("LSMAOE", &Self::LSMAOE_FIELD.extract(bits));
+        s.field("EnIB", &Self::ENIB_FIELD.extract(bits));
+        s.field("EnIA", &Self::ENIA_FIELD.extract(bits));
+        s.field("CMOW", &Self::CMOW_FIELD.extract(bits));
+        s.field("MSCEn", &Self::MSCEN_FIELD.extract(bits));
+        s.field("EnFPM", &Self::ENFPM_FIELD.extract(bits));
+        s.field("BT0", &Self::BT0_FIELD.extract(bits));
+        s.field("BT", &Self::BT_FIELD.extract(bits));
+        s.field("ITFSB", &Self::ITFSB_FIELD.extract(bits));
+        s.field("TCF0", &Self::TCF0_FIELD.extract(bits));
+        s.field("TCF", &Self::TCF_FIELD.extract(bits));
+        s.field("ATA0", &Self::ATA0_FIELD.extract(bits));
+        s.field("ATA", &Self::ATA_FIELD.extract(bits));
+        s.field("DSSBS", &Self::DSSBS_FIELD.extract(bits));
+        s.field("TWEDEn", &Self::TWEDEN_FIELD.extract(bits));
+        s.field("TWEDEL", &Self::TWEDEL_FIELD.extract(bits));
+        s.field("EnASR", &Self::ENASR_FIELD.extract(bits));
+        s.field("EnAS0", &Self::ENAS0_FIELD.extract(bits));
+        s.field("EnALS", &Self::ENALS_FIELD.extract(bits));
+        s.field("EPAN", &Self::EPAN_FIELD.extract(bits));
+        s.field("TCSO0", &Self::TCSO0_FIELD.extract(bits));
+        s.field("TCSO", &Self::TCSO_FIELD.extract(bits));
+        s.field("EnTP2", &Self::ENTP2_FIELD.extract(bits));
+        s.field("NMI", &Self::NMI_FIELD.extract(bits));
+        s.field("SPINTMASK", &Self::SPINTMASK_FIELD.extract(bits));
+        s.field("TIDCP", &Self::TIDCP_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for SctlrEl2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "SctlrEl2 {{ M: {=u64}, A: {=u64}, C: {=u64}, SA: {=u64}, SA0: {=u64}, CP15BEN: {=u64}, nAA: {=u64}, SED: {=u64}, UMA: {=u64}, EnRCTX: {=u64}, EOS: {=u64}, I: {=u64}, EnDB: {=u64}, DZE: {=u64}, UCT: {=u64}, nTWI: {=u64}, nTWE: {=u64}, WXN: {=u64}, IESB: {=u64}, EIS: {=u64}, SPAN: {=u64}, UCI: {=u64}, EnDA: {=u64}, nTLSMD: {=u64}, LSMAOE: {=u64}, EnIB: {=u64}, EnIA: {=u64}, CMOW: {=u64}, MSCEn: {=u64}, EnFPM: {=u64}, BT0: {=u64}, BT: {=u64}, ITFSB: {=u64}, TCF0: {=u64}, TCF: {=u64}, ATA0: {=u64}, ATA: {=u64}, DSSBS: {=u64}, TWEDEn: {=u64}, TWEDEL: {=u64}, EnASR: {=u64}, EnAS0: {=u64}, EnALS: {=u64}, EPAN: {=u64}, TCSO0: {=u64}, TCSO: {=u64}, EnTP2: {=u64}, NMI: {=u64}, SPINTMASK: {=u64}, TIDCP: {=u64} }}",
+            Self::M_FIELD.extract(self.bits()),
+            Self::A_FIELD.extract(self.bits()),
+            Self::C_FIELD.extract(self.bits()),
+            Self::SA_FIELD.extract(self.bits()),
+            Self::SA0_FIELD.extract(self.bits()),
+            Self::CP15BEN_FIELD.extract(self.bits()),
+            Self::NAA_FIELD.extract(self.bits()),
+            Self::SED_FIELD.extract(self.bits()),
+            Self::UMA_FIELD.extract(self.bits()),
+            Self::ENRCTX_FIELD.extract(self.bits()),
+            Self::EOS_FIELD.extract(self.bits()),
+            Self::I_FIELD.extract(self.bits()),
+            Self::ENDB_FIELD.extract(self.bits()),
+            Self::DZE_FIELD.extract(self.bits()),
+            Self::UCT_FIELD.extract(self.bits()),
+            Self::NTWI_FIELD.extract(self.bits()),
+            Self::NTWE_FIELD.extract(self.bits()),
+            Self::WXN_FIELD.extract(self.bits()),
+            Self::IESB_FIELD.extract(self.bits()),
+            Self::EIS_FIELD.extract(self.bits()),
+            Self::SPAN_FIELD.extract(self.bits()),
+            Self::UCI_FIELD.extract(self.bits()),
+            Self::ENDA_FIELD.extract(self.bits()),
+            Self::NTLSMD_FIELD.extract(self.bits()),
+            Self::LSMAOE_FIELD.extract(self.bits()),
+            Self::ENIB_FIELD.extract(self.bits()),
+            Self::ENIA_FIELD.extract(self.bits()),
+            Self::CMOW_FIELD.extract(self.bits()),
+            Self::MSCEN_FIELD.extract(self.bits()),
+            Self::ENFPM_FIELD.extract(self.bits()),
+            Self::BT0_FIELD.extract(self.bits()),
+            Self::BT_FIELD.extract(self.bits()),
+            Self::ITFSB_FIELD.extract(self.bits()),
+            Self::TCF0_FIELD.extract(self.bits()),
+            Self::TCF_FIELD.extract(self.bits()),
+            Self::ATA0_FIELD.extract(self.bits()),
+            Self::ATA_FIELD.extract(self.bits()),
+            Self::DSSBS_FIELD.extract(self.bits()),
+            Self::TWEDEN_FIELD.extract(self.bits()),
+            Self::TWEDEL_FIELD.extract(self.bits()),
+            Self::ENASR_FIELD.extract(self.bits()),
+            Self::ENAS0_FIELD.extract(self.bits()),
+            Self::ENALS_FIELD.extract(self.bits()),
+            Self::EPAN_FIELD.extract(self.bits()),
+            Self::TCSO0_FIELD.extract(self.bits()),
+            Self::TCSO_FIELD.extract(self.bits()),
+            Self::ENTP2_FIELD.extract(self.bits()),
+            Self::NMI_FIELD.extract(self.bits()),
+            Self::SPINTMASK_FIELD.extract(self.bits()),
+            Self::TIDCP_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for SctlrEl2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for SctlrEl2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl SctlrEl2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("M"),
+                old: Self::M_FIELD.extract(self.bits()),
+                new: Self::M_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("A"),
+                old: Self::A_FIELD.extract(self.bits()),
+                new: Self::A_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("C"),
+                old: Self::C_FIELD.extract(self.bits()),
+                new: Self::C_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SA"),
+                old: Self::SA_FIELD.extract(self.bits()),
+                new: Self::SA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SA0"),
+                old: Self::SA0_FIELD.extract(self.bits()),
+                new: Self::SA0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CP15BEN"),
+                old: Self::CP15BEN_FIELD.extract(self.bits()),
+                new: Self::CP15BEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nAA"),
+                old: Self::NAA_FIELD.extract(self.bits()),
+                new: Self::NAA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SED"),
+                old: Self::SED_FIELD.extract(self.bits()),
+                new: Self::SED_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("UMA"),
+                old: Self::UMA_FIELD.extract(self.bits()),
+                new: Self::UMA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnRCTX"),
+                old: Self::ENRCTX_FIELD.extract(self.bits()),
+                new: Self::ENRCTX_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EOS"),
+                old: Self::EOS_FIELD.extract(self.bits()),
+                new: Self::EOS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("I"),
+                old: Self::I_FIELD.extract(self.bits()),
+                new: Self::I_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnDB"),
+                old: Self::ENDB_FIELD.extract(self.bits()),
+                new: Self::ENDB_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DZE"),
+                old: Self::DZE_FIELD.extract(self.bits()),
+                new: Self::DZE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("UCT"),
+                old: Self::UCT_FIELD.extract(self.bits()),
+                new: Self::UCT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nTWI"),
+                old: Self::NTWI_FIELD.extract(self.bits()),
+                new: Self::NTWI_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nTWE"),
+                old: Self::NTWE_FIELD.extract(self.bits()),
+                new: Self::NTWE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("WXN"),
+                old: Self::WXN_FIELD.extract(self.bits()),
+                new: Self::WXN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IESB"),
+                old: Self::IESB_FIELD.extract(self.bits()),
+                new: Self::IESB_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EIS"),
+                old: Self::EIS_FIELD.extract(self.bits()),
+                new: Self::EIS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SPAN"),
+                old: Self::SPAN_FIELD.extract(self.bits()),
+                new: Self::SPAN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("UCI"),
+                old: Self::UCI_FIELD.extract(self.bits()),
+                new: Self::UCI_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnDA"),
+                old: Self::ENDA_FIELD.extract(self.bits()),
+                new: Self::ENDA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nTLSMD"),
+                old: Self::NTLSMD_FIELD.extract(self.bits()),
+                new: Self::NTLSMD_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("LSMAOE"),
+                old: Self::LSMAOE_FIELD.extract(self.bits()),
+                new: Self::LSMAOE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnIB"),
+                old: Self::ENIB_FIELD.extract(self.bits()),
+                new: Self::ENIB_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnIA"),
+                old: Self::ENIA_FIELD.extract(self.bits()),
+                new: Self::ENIA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CMOW"),
+                old: Self::CMOW_FIELD.extract(self.bits()),
+                new: Self::CMOW_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("MSCEn"),
+                old: Self::MSCEN_FIELD.extract(self.bits()),
+                new: Self::MSCEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnFPM"),
+                old: Self::ENFPM_FIELD.extract(self.bits()),
+                new: Self::ENFPM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("BT0"),
+                old: Self::BT0_FIELD.extract(self.bits()),
+                new: Self::BT0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("BT"),
+                old: Self::BT_FIELD.extract(self.bits()),
+                new: Self::BT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ITFSB"),
+                old: Self::ITFSB_FIELD.extract(self.bits()),
+                new: Self::ITFSB_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TCF0"),
+                old: Self::TCF0_FIELD.extract(self.bits()),
+                new: Self::TCF0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TCF"),
+                old: Self::TCF_FIELD.extract(self.bits()),
+                new: Self::TCF_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ATA0"),
+                old: Self::ATA0_FIELD.extract(self.bits()),
+                new: Self::ATA0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ATA"),
+                old: Self::ATA_FIELD.extract(self.bits()),
+                new: Self::ATA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DSSBS"),
+                old: Self::DSSBS_FIELD.extract(self.bits()),
+                new: Self::DSSBS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TWEDEn"),
+                old: Self::TWEDEN_FIELD.extract(self.bits()),
+                new: Self::TWEDEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TWEDEL"),
+                old: Self::TWEDEL_FIELD.extract(self.bits()),
+                new: Self::TWEDEL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnASR"),
+                old: Self::ENASR_FIELD.extract(self.bits()),
+                new: Self::ENASR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnAS0"),
+                old: Self::ENAS0_FIELD.extract(self.bits()),
+                new: Self::ENAS0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnALS"),
+                old: Self::ENALS_FIELD.extract(self.bits()),
+                new: Self::ENALS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EPAN"),
+                old: Self::EPAN_FIELD.extract(self.bits()),
+                new: Self::EPAN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TCSO0"),
+                old: Self::TCSO0_FIELD.extract(self.bits()),
+                new: Self::TCSO0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TCSO"),
+                old: Self::TCSO_FIELD.extract(self.bits()),
+                new: Self::TCSO_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnTP2"),
+                old: Self::ENTP2_FIELD.extract(self.bits()),
+                new: Self::ENTP2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("NMI"),
+                old: Self::NMI_FIELD.extract(self.bits()),
+                new: Self::NMI_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SPINTMASK"),
+                old: Self::SPINTMASK_FIELD.extract(self.bits()),
+                new: Self::SPINTMASK_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TIDCP"),
+                old: Self::TIDCP_FIELD.extract(self.bits()),
+                new: Self::TIDCP_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits()
+                    & 0b11_1100_0000_0000_0000_0000_0000_0011_0001_0010_0000_0000_1000_0000,
+                new: other.bits()
+                    & 0b11_1100_0000_0000_0000_0000_0000_0011_0001_0010_0000_0000_1000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl SctlrEl2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl SctlrEl2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el3")]
+bitflags! {
+    /// `SCTLR_EL3` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct SctlrEl3: u64 {
+        /// RES1 bits in the `SCTLR_EL3` register.
+        const RES1 = 0b11_0000_1000_0101_0000_0000_0011_0000;
+        /// MMU enable for EL3 stage 1 address translation.
+        const M = 1 << 0;
+        /// Alignment check enable.
+        const A = 1 << 1;
+        /// Cacheability control, for data accesses at EL3.
+        const C = 1 << 2;
+        /// SP alignment check enable.
+        const SA = 1 << 3;
+        /// `nAA` bit.
+        const NAA = 1 << 6;
+        /// `EOS` bit.
+        const EOS = 1 << 11;
+        /// Cacheability control, for instruction accesses at EL3.
+        const I = 1 << 12;
+        /// `EnDB` bit.
+        const ENDB = 1 << 13;
+        /// Write permission implies XN (Execute-never). For the EL3 translation regime, this bit can force all memory regions that are writable to be treated as XN.
+        const WXN = 1 << 19;
+        /// Enable Implicit Error Synchronization events.
+        const IESB = 1 << 21;
+        /// `EIS` bit.
+        const EIS = 1 << 22;
+        /// `EnDA` bit.
+        const ENDA = 1 << 27;
+        /// Enable pointer authentication using APIBKey_EL1.
+        const ENIB = 1 << 30;
+        /// Enable pointer authentication using APIAKey_EL1.
+        const ENIA = 1 << 31;
+        /// `BT` bit.
+        const BT = 1 << 36;
+        /// `ITFSB` bit.
+        const ITFSB = 1 << 37;
+        /// `ATA` bit.
+        const ATA = 1 << 43;
+        /// `DSSBS` bit.
+        const DSSBS = 1 << 44;
+        /// `TCSO` bit.
+        const TCSO = 1 << 59;
+        /// `NMI` bit.
+        const NMI = 1 << 61;
+        /// `SPINTMASK` bit.
+        const SPINTMASK = 1 << 62;
+    }
+}
+
+#[cfg(feature = "el3")]
+impl SctlrEl3 {
+    /// Descriptor of the `M` field, for use with [`FieldAccess`].
+    pub const M_FIELD: Field<Self, bool, 0, 1> = Field::new("SCTLR_EL3", "M");
+    /// Descriptor of the `A` field, for use with [`FieldAccess`].
+    pub const A_FIELD: Field<Self, bool, 1, 1> = Field::new("SCTLR_EL3", "A");
+    /// Descriptor of the `C` field, for use with [`FieldAccess`].
+    pub const C_FIELD: Field<Self, bool, 2, 1> = Field::new("SCTLR_EL3", "C");
+    /// Descriptor of the `SA` field, for use with [`FieldAccess`].
+    pub const SA_FIELD: Field<Self, bool, 3, 1> = Field::new("SCTLR_EL3", "SA");
+    /// Descriptor of the `nAA` field, for use with [`FieldAccess`].
+    pub const NAA_FIELD: Field<Self, bool, 6, 1> = Field::new("SCTLR_EL3", "nAA");
+    /// Descriptor of the `EOS` field, for use with [`FieldAccess`].
+    pub const EOS_FIELD: Field<Self, bool, 11, 1> = Field::new("SCTLR_EL3", "EOS");
+    /// Descriptor of the `I` field, for use with [`FieldAccess`].
+    pub const I_FIELD: Field<Self, bool, 12, 1> = Field::new("SCTLR_EL3", "I");
+    /// Descriptor of the `EnDB` field, for use with [`FieldAccess`].
+    pub const ENDB_FIELD: Field<Self, bool, 13, 1> = Field::new("SCTLR_EL3", "EnDB");
+    /// Descriptor of the `WXN` field, for use with [`FieldAccess`].
+    pub const WXN_FIELD: Field<Self, bool, 19, 1> = Field::new("SCTLR_EL3", "WXN");
+    /// Descriptor of the `IESB` field, for use with [`FieldAccess`].
+    pub const IESB_FIELD: Field<Self, bool, 21, 1> = Field::new("SCTLR_EL3", "IESB");
+    /// Descriptor of the `EIS` field, for use with [`FieldAccess`].
+    pub const EIS_FIELD: Field<Self, bool, 22, 1> = Field::new("SCTLR_EL3", "EIS");
+    /// Descriptor of the `EnDA` field, for use with [`FieldAccess`].
+    pub const ENDA_FIELD: Field<Self, bool, 27, 1> = Field::new("SCTLR_EL3", "EnDA");
+    /// Descriptor of the `EnIB` field, for use with [`FieldAccess`].
+    pub const ENIB_FIELD: Field<Self, bool, 30, 1> = Field::new("SCTLR_EL3", "EnIB");
+    /// Descriptor of the `EnIA` field, for use with [`FieldAccess`].
+    pub const ENIA_FIELD: Field<Self, bool, 31, 1> = Field::new("SCTLR_EL3", "EnIA");
+    /// Descriptor of the `BT` field, for use with [`FieldAccess`].
+    pub const BT_FIELD: Field<Self, bool, 36, 1> = Field::new("SCTLR_EL3", "BT");
+    /// Descriptor of the `ITFSB` field, for use with [`FieldAccess`].
+    pub const ITFSB_FIELD: Field<Self, bool, 37, 1> = Field::new("SCTLR_EL3", "ITFSB");
+    /// Descriptor of the `TCF` field, for use with [`FieldAccess`].
+    pub const TCF_FIELD: Field<Self, u8, 40, 2> = Field::new("SCTLR_EL3", "TCF");
+    /// Descriptor of the `ATA` field, for use with [`FieldAccess`].
+    pub const ATA_FIELD: Field<Self, bool, 43, 1> = Field::new("SCTLR_EL3", "ATA");
+    /// Descriptor of the `DSSBS` field, for use with [`FieldAccess`].
+    pub const DSSBS_FIELD: Field<Self, bool, 44, 1> = Field::new("SCTLR_EL3", "DSSBS");
+    /// Descriptor of the `TCSO` field, for use with [`FieldAccess`].
+    pub const TCSO_FIELD: Field<Self, bool, 59, 1> = Field::new("SCTLR_EL3", "TCSO");
+    /// Descriptor of the `NMI` field, for use with [`FieldAccess`].
+    pub const NMI_FIELD: Field<Self, bool, 61, 1> = Field::new("SCTLR_EL3", "NMI");
+    /// Descriptor of the `SPINTMASK` field, for use with [`FieldAccess`].
+    pub const SPINTMASK_FIELD: Field<Self, bool, 62, 1> = Field::new("SCTLR_EL3", "SPINTMASK");
+
+    /// Returns the value of the `TCF` field.
+    pub const fn tcf(self) -> u8 {
+        Self::TCF_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `TCF` field.
+    pub const fn set_tcf(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::TCF_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `TCF` field set to the given value.
+    pub const fn with_tcf(mut self, value: u8) -> Self {
+        self.set_tcf(value);
+        self
+    }
+
+    /// Sets the value of the `TCF` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_tcf(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_tcf(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `TCF` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_tcf(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::TCF_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el3")]
+impl Display for SctlrEl3 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("SctlrEl3");
+        let bits = self.bits();
+        s.field("M", &Self::M_FIELD.extract(bits));
+        s.field("A", &Self::A_FIELD.extract(bits));
+        s.field("C", &Self::C_FIELD.extract(bits));
+        s.field("SA", &Self::SA_FIELD.extract(bits));
+        s.field("nAA", &Self::NAA_FIELD.extract(bits));
+        s.field("EOS", &Self::EOS_FIELD.extract(bits));
+        s.field("I", &Self::I_FIELD.extract(bits));
+        s.field("EnDB", &Self::ENDB_FIELD.extract(bits));
+        s.field("WXN", &Self::WXN_FIELD.extract(bits));
+        s.field("IESB", &Self::IESB_FIELD.extract(bits));
+        s.field("EIS", &Self::EIS_FIELD.extract(bits));
+        s.field("EnDA", &Self::ENDA_FIELD.extract(bits));
+        s.field("EnIB", &Self::ENIB_FIELD.extract(bits));
+        s.field("EnIA", &Self::ENIA_FIELD.extract(bits));
+        s.field("BT", &Self::BT_FIELD.extract(bits));
+        s.field("ITFSB", &Self::ITFSB_FIELD.extract(bits));
+        s.field("TCF", &Self::TCF_FIELD.extract(bits));
+        s.field("ATA", &Self::ATA_FIELD.extract(bits));
+        s.field("DSSBS", &Self::DSSBS_FIELD.extract(bits));
+        s.field("TCSO", &Self::TCSO_FIELD.extract(bits));
+        s.field("NMI", &Self::NMI_FIELD.extract(bits));
+        s.field("SPINTMASK", &Self::SPINTMASK_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el3")]
+impl defmt::Format for SctlrEl3 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "SctlrEl3 {{ M: {=u64}, A: {=u64}, C: {=u64}, SA: {=u64}, nAA: {=u64}, EOS: {=u64}, I: {=u64}, EnDB: {=u64}, WXN: {=u64}, IESB: {=u64}, EIS: {=u64}, EnDA: {=u64}, EnIB: {=u64}, EnIA: {=u64}, BT: {=u64}, ITFSB: {=u64}, TCF: {=u64}, ATA: {=u64}, DSSBS: {=u64}, TCSO: {=u64}, NMI: {=u64}, SPINTMASK: {=u64} }}",
+            Self::M_FIELD.extract(self.bits()),
+            Self::A_FIELD.extract(self.bits()),
+            Self::C_FIELD.extract(self.bits()),
+            Self::SA_FIELD.extract(self.bits()),
+            Self::NAA_FIELD.extract(self.bits()),
+            Self::EOS_FIELD.extract(self.bits()),
+            Self::I_FIELD.extract(self.bits()),
+            Self::ENDB_FIELD.extract(self.bits()),
+            Self::WXN_FIELD.extract(self.bits()),
+            Self::IESB_FIELD.extract(self.bits()),
+            Self::EIS_FIELD.extract(self.bits()),
+            Self::ENDA_FIELD.extract(self.bits()),
+            Self::ENIB_FIELD.extract(self.bits()),
+            Self::ENIA_FIELD.extract(self.bits()),
+            Self::BT_FIELD.extract(self.bits()),
+            Self::ITFSB_FIELD.extract(self.bits()),
+            Self::TCF_FIELD.extract(self.bits()),
+            Self::ATA_FIELD.extract(self.bits()),
+            Self::DSSBS_FIELD.extract(self.bits()),
+            Self::TCSO_FIELD.extract(self.bits()),
+            Self::NMI_FIELD.extract(self.bits()),
+            Self::SPINTMASK_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el3")]
+impl serde::Serialize for SctlrEl3 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el3")]
+impl<'de> serde::Deserialize<'de> for SctlrEl3 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el3")]
+impl SctlrEl3 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("M"),
+                old: Self::M_FIELD.extract(self.bits()),
+                new: Self::M_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("A"),
+                old: Self::A_FIELD.extract(self.bits()),
+                new: Self::A_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("C"),
+                old: Self::C_FIELD.extract(self.bits()),
+                new: Self::C_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SA"),
+                old: Self::SA_FIELD.extract(self.bits()),
+                new: Self::SA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nAA"),
+                old: Self::NAA_FIELD.extract(self.bits()),
+                new: Self::NAA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EOS"),
+                old: Self::EOS_FIELD.extract(self.bits()),
+                new: Self::EOS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("I"),
+                old: Self::I_FIELD.extract(self.bits()),
+                new: Self::I_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnDB"),
+                old: Self::ENDB_FIELD.extract(self.bits()),
+                new: Self::ENDB_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("WXN"),
+                old: Self::WXN_FIELD.extract(self.bits()),
+                new: Self::WXN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IESB"),
+                old: Self::IESB_FIELD.extract(self.bits()),
+                new: Self::IESB_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EIS"),
+                old: Self::EIS_FIELD.extract(self.bits()),
+                new: Self::EIS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnDA"),
+                old: Self::ENDA_FIELD.extract(self.bits()),
+                new: Self::ENDA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnIB"),
+                old: Self::ENIB_FIELD.extract(self.bits()),
+                new: Self::ENIB_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnIA"),
+                old: Self::ENIA_FIELD.extract(self.bits()),
+                new: Self::ENIA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("BT"),
+                old: Self::BT_FIELD.extract(self.bits()),
+                new: Self::BT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ITFSB"),
+                old: Self::ITFSB_FIELD.extract(self.bits()),
+                new: Self::ITFSB_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TCF"),
+                old: Self::TCF_FIELD.extract(self.bits()),
+                new: Self::TCF_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ATA"),
+                old: Self::ATA_FIELD.extract(self.bits()),
+                new: Self::ATA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DSSBS"),
+                old: Self::DSSBS_FIELD.extract(self.bits()),
+                new: Self::DSSBS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TCSO"),
+                old: Self::TCSO_FIELD.extract(self.bits()),
+                new: Self::TCSO_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("NMI"),
+                old: Self::NMI_FIELD.extract(self.bits()),
+                new: Self::NMI_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SPINTMASK"),
+                old: Self::SPINTMASK_FIELD.extract(self.bits()),
+                new: Self::SPINTMASK_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1001_0111_1111_1111_1110_0100_1100_1111_0011_0111_1001_0111_1100_0111_1011_0000,
+                new: other.bits() & 0b1001_0111_1111_1111_1110_0100_1100_1111_0011_0111_1001_0111_1100_0111_1011_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el3")]
+impl SctlrEl3 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b11_0000_1000_0101_0000_0000_0011_0000;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el3")]
+impl SctlrEl3 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `SP_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct SpEl1: u64 {
+    }
+}
+
+#[cfg(feature = "el1")]
+impl SpEl1 {
+    /// Descriptor of the `StackPointer` field, for use with [`FieldAccess`].
+    pub const STACKPOINTER_FIELD: Field<Self, u64, 0, 64> = Field::new("SP_EL1", "StackPointer");
+
+    /// Returns the value of the `StackPointer` field.
+    pub const fn stackpointer(self) -> u64 {
+        Self::STACKPOINTER_FIELD.extract(self.bits())
+    }
+
+    /// Sets the value of the `StackPointer` field.
+    pub const fn set_stackpointer(&mut self, value: u64) {
+        *self = Self::from_bits_retain(Self::STACKPOINTER_FIELD.insert(self.bits(), value));
+    }
+
+    /// Returns a copy with the `StackPointer` field set to the given value.
+    pub const fn with_stackpointer(mut self, value: u64) -> Self {
+        self.set_stackpointer(value);
+        self
+    }
+
+    /// Sets the value of the `StackPointer` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_stackpointer(&mut self, value: u64) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_stackpointer(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `StackPointer` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_stackpointer(self, value: u64) -> Result<Self, FieldOverflow> {
+        match Self::STACKPOINTER_FIELD.try_insert(self.bits(), value) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for SpEl1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("SpEl1");
+        let bits = self.bits();
+        s.field(
+            "StackPointer",
+            &format_args!("{:#x}", Self::STACKPOINTER_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for SpEl1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "SpEl1 {{ StackPointer: {=u64:#x} }}",
+            Self::STACKPOINTER_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for SpEl1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for SpEl1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl SpEl1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [FieldChange {
+            register,
+            field: Some("StackPointer"),
+            old: Self::STACKPOINTER_FIELD.extract(self.bits()),
+            new: Self::STACKPOINTER_FIELD.extract(other.bits()),
+        }]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl SpEl1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl SpEl1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `SP_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct SpEl2: u64 {
+    }
+}
+
+#[cfg(feature = "el2")]
+impl SpEl2 {
+    /// Descriptor of the `StackPointer` field, for use with [`FieldAccess`].
+    pub const STACKPOINTER_FIELD: Field<Self, u64, 0, 64> = Field::new("SP_EL2", "StackPointer");
+
+    /// Returns the value of the `StackPointer` field.
+    pub const fn stackpointer(self) -> u64 {
+        Self::STACKPOINTER_FIELD.extract(self.bits())
+    }
+
+    /// Sets the value of the `StackPointer` field.
+    pub const fn set_stackpointer(&mut self, value: u64) {
+        *self = Self::from_bits_retain(Self::STACKPOINTER_FIELD.insert(self.bits(), value));
+    }
+
+    /// Returns a copy with the `StackPointer` field set to the given value.
+    pub const fn with_stackpointer(mut self, value: u64) -> Self {
+        self.set_stackpointer(value);
+        self
+    }
+
+    /// Sets the value of the `StackPointer` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_stackpointer(&mut self, value: u64) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_stackpointer(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `StackPointer` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_stackpointer(self, value: u64) -> Result<Self, FieldOverflow> {
+        match Self::STACKPOINTER_FIELD.try_insert(self.bits(), value) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Display for SpEl2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("SpEl2");
+        let bits = self.bits();
+        s.field(
+            "StackPointer",
+            &format_args!("{:#x}", Self::STACKPOINTER_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for SpEl2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "SpEl2 {{ StackPointer: {=u64:#x} }}",
+            Self::STACKPOINTER_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for SpEl2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for SpEl2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl SpEl2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [FieldChange {
+            register,
+            field: Some("StackPointer"),
+            old: Self::STACKPOINTER_FIELD.extract(self.bits()),
+            new: Self::STACKPOINTER_FIELD.extract(other.bits()),
+        }]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl SpEl2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl SpEl2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+bitflags! {
+    /// `TPIDRRO_EL0` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct TpidrroEl0: u64 {
+    }
+}
+
+impl TpidrroEl0 {
+    /// Descriptor of the `ThreadID` field, for use with [`FieldAccess`].
+    pub const THREADID_FIELD: Field<Self, u64, 0, 64> = Field::new("TPIDRRO_EL0", "ThreadID");
+
+    /// Returns the value of the `ThreadID` field.
+    pub const fn threadid(self) -> u64 {
+        Self::THREADID_FIELD.extract(self.bits())
+    }
+
+    /// Sets the value of the `ThreadID` field.
+    pub const fn set_threadid(&mut self, value: u64) {
+        *self = Self::from_bits_retain(Self::THREADID_FIELD.insert(self.bits(), value));
+    }
+
+    /// Returns a copy with the `ThreadID` field set to the given value.
+    pub const fn with_threadid(mut self, value: u64) -> Self {
+        self.set_threadid(value);
+        self
+    }
+
+    /// Sets the value of the `ThreadID` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_threadid(&mut self, value: u64) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_threadid(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ThreadID` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_threadid(self, value: u64) -> Result<Self, FieldOverflow> {
+        match Self::THREADID_FIELD.try_insert(self.bits(), value) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl Display for TpidrroEl0 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("TpidrroEl0");
+        let bits = self.bits();
+        s.field(
+            "ThreadID",
+            &format_args!("{:#x}", Self::THREADID_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for TpidrroEl0 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "TpidrroEl0 {{ ThreadID: {=u64:#x} }}",
+            Self::THREADID_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for TpidrroEl0 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for TpidrroEl0 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+impl TpidrroEl0 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [FieldChange {
+            register,
+            field: Some("ThreadID"),
+            old: Self::THREADID_FIELD.extract(self.bits()),
+            new: Self::THREADID_FIELD.extract(other.bits()),
+        }]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+impl TpidrroEl0 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+impl TpidrroEl0 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+/// `TPIDR_EL0` system register value.
+pub type TpidrEl0 = TpidrroEl0;
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `TPIDR_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct TpidrEl1: u64 {
+    }
+}
+
+#[cfg(feature = "el1")]
+impl TpidrEl1 {
+    /// Descriptor of the `ThreadID` field, for use with [`FieldAccess`].
+    pub const THREADID_FIELD: Field<Self, u64, 0, 64> = Field::new("TPIDR_EL1", "ThreadID");
+
+    /// Returns the value of the `ThreadID` field.
+    pub const fn threadid(self) -> u64 {
+        Self::THREADID_FIELD.extract(self.bits())
+    }
+
+    /// Sets the value of the `ThreadID` field.
+    pub const fn set_threadid(&mut self, value: u64) {
+        *self = Self::from_bits_retain(Self::THREADID_FIELD.insert(self.bits(), value));
+    }
+
+    /// Returns a copy with the `ThreadID` field set to the given value.
+    pub const fn with_threadid(mut self, value: u64) -> Self {
+        self.set_threadid(value);
+        self
+    }
+
+    /// Sets the value of the `ThreadID` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_threadid(&mut self, value: u64) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_threadid(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ThreadID` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_threadid(self, value: u64) -> Result<Self, FieldOverflow> {
+        match Self::THREADID_FIELD.try_insert(self.bits(), value) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for TpidrEl1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("TpidrEl1");
+        let bits = self.bits();
+        s.field(
+            "ThreadID",
+            &format_args!("{:#x}", Self::THREADID_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for TpidrEl1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "TpidrEl1 {{ ThreadID: {=u64:#x} }}",
+            Self::THREADID_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for TpidrEl1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for TpidrEl1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl TpidrEl1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [FieldChange {
+            register,
+            field: Some("ThreadID"),
+            old: Self::THREADID_FIELD.extract(self.bits()),
+            new: Self::THREADID_FIELD.extract(other.bits()),
+        }]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl TpidrEl1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl TpidrEl1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `TPIDR_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct TpidrEl2: u64 {
+    }
+}
+
+#[cfg(feature = "el2")]
+impl TpidrEl2 {
+    /// Descriptor of the `ThreadID` field, for use with [`FieldAccess`].
+    pub const THREADID_FIELD: Field<Self, u64, 0, 64> = Field::new("TPIDR_EL2", "ThreadID");
+
+    /// Returns the value of the `ThreadID` field.
+    pub const fn threadid(self) -> u64 {
+        Self::THREADID_FIELD.extract(self.bits())
+    }
+
+    /// Sets the value of the `ThreadID` field.
+    pub const fn set_threadid(&mut self, value: u64) {
+        *self = Self::from_bits_retain(Self::THREADID_FIELD.insert(self.bits(), value));
+    }
+
+    /// Returns a copy with the `ThreadID` field set to the given value.
+    pub const fn with_threadid(mut self, value: u64) -> Self {
+        self.set_threadid(value);
+        self
+    }
+
+    /// Sets the value of the `ThreadID` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_threadid(&mut self, value: u64) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_threadid(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ThreadID` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_threadid(self, value: u64) -> Result<Self, FieldOverflow> {
+        match Self::THREADID_FIELD.try_insert(self.bits(), value) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Display for TpidrEl2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("TpidrEl2");
+        let bits = self.bits();
+        s.field(
+            "ThreadID",
+            &format_args!("{:#x}", Self::THREADID_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for TpidrEl2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "TpidrEl2 {{ ThreadID: {=u64:#x} }}",
+            Self::THREADID_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for TpidrEl2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for TpidrEl2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl TpidrEl2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [FieldChange {
+            register,
+            field: Some("ThreadID"),
+            old: Self::THREADID_FIELD.extract(self.bits()),
+            new: Self::THREADID_FIELD.extract(other.bits()),
+        }]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl TpidrEl2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl TpidrEl2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el3")]
+bitflags! {
+    /// `TPIDR_EL3` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct TpidrEl3: u64 {
+    }
+}
+
+#[cfg(feature = "el3")]
+impl TpidrEl3 {
+    /// Descriptor of the `ThreadID` field, for use with [`FieldAccess`].
+    pub const THREADID_FIELD: Field<Self, u64, 0, 64> = Field::new("TPIDR_EL3", "ThreadID");
+
+    /// Returns the value of the `ThreadID` field.
+    pub const fn threadid(self) -> u64 {
+        Self::THREADID_FIELD.extract(self.bits())
+    }
+
+    /// Sets the value of the `ThreadID` field.
+    pub const fn set_threadid(&mut self, value: u64) {
+        *self = Self::from_bits_retain(Self::THREADID_FIELD.insert(self.bits(), value));
+    }
+
+    /// Returns a copy with the `ThreadID` field set to the given value.
+    pub const fn with_threadid(mut self, value: u64) -> Self {
+        self.set_threadid(value);
+        self
+    }
+
+    /// Sets the value of the `ThreadID` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_threadid(&mut self, value: u64) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_threadid(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ThreadID` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_threadid(self, value: u64) -> Result<Self, FieldOverflow> {
+        match Self::THREADID_FIELD.try_insert(self.bits(), value) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el3")]
+impl Display for TpidrEl3 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("TpidrEl3");
+        let bits = self.bits();
+        s.field(
+            "ThreadID",
+            &format_args!("{:#x}", Self::THREADID_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el3")]
+impl defmt::Format for TpidrEl3 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "TpidrEl3 {{ ThreadID: {=u64:#x} }}",
+            Self::THREADID_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el3")]
+impl serde::Serialize for TpidrEl3 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el3")]
+impl<'de> serde::Deserialize<'de> for TpidrEl3 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el3")]
+impl TpidrEl3 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [FieldChange {
+            register,
+            field: Some("ThreadID"),
+            old: Self::THREADID_FIELD.extract(self.bits()),
+            new: Self::THREADID_FIELD.extract(other.bits()),
+        }]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el3")]
+impl TpidrEl3 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el3")]
+impl TpidrEl3 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `ACTLR_EL1` system register.
+    actlr_el1, u64, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `ACTLR_EL2` system register.
+    actlr_el2, u64, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_write_sysreg!(contextidr_el1, u64: ContextidrEl1, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg!(contextidr_el2: s3_4_c13_c0_1, u64: ContextidrEl2, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `CPACR_EL1` system register.
+    cpacr_el1, u64: CpacrEl1, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `CPTR_EL2` system register.
+    cptr_el2, u64: CptrEl2, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `CPTR_EL3` system register.
+    cptr_el3, u64: CptrEl3, safe_read, fake::SYSREGS
+}
+#[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+read_sysreg!(currentel, u64: Currentel, safe, fake::SYSREGS);
+#[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `DAIF` system register.
+    daif, u64: Daif, safe_read, fake::SYSREGS
+}
+#[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+read_write_sysreg!(dit: s3_3_c4_c2_5, u64: Dit, safe_read, safe_write, fake::SYSREGS);
+#[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `FPCR` system register.
+    fpcr, u64: Fpcr, safe_read, fake::SYSREGS
+}
+#[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `FPMR` system register.
+    fpmr: s3_3_c4_c4_2, u64: Fpmr, safe_read, fake::SYSREGS
+}
+#[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `FPSR` system register.
+    fpsr, u64: Fpsr, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `GCSCR_EL1` system register.
+    gcscr_el1: s3_0_c2_c5_0, u64: GcscrEl1, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `GCSCR_EL2` system register.
+    gcscr_el2: s3_4_c2_c5_0, u64: GcscrEl2, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `HACR_EL2` system register.
+    hacr_el2, u64, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `HAFGRTR_EL2` system register.
+    hafgrtr_el2: s3_4_c3_c1_6, u64: HafgrtrEl2, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `HCRX_EL2` system register.
+    hcrx_el2: s3_4_c1_c2_2, u64: HcrxEl2, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `HCR_EL2` system register.
+    hcr_el2, u64: HcrEl2, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `HDFGRTR2_EL2` system register.
+    hdfgrtr2_el2: s3_4_c3_c1_0, u64: Hdfgrtr2El2, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `HDFGRTR_EL2` system register.
+    hdfgrtr_el2: s3_4_c3_c1_4, u64: HdfgrtrEl2, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `HDFGWTR2_EL2` system register.
+    hdfgwtr2_el2: s3_4_c3_c1_1, u64: Hdfgwtr2El2, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `HDFGWTR_EL2` system register.
+    hdfgwtr_el2: s3_4_c3_c1_5, u64: HdfgwtrEl2, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `HFGITR2_EL2` system register.
+    hfgitr2_el2: s3_4_c3_c1_7, u64: Hfgitr2El2, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `HFGITR_EL2` system register.
+    hfgitr_el2: s3_4_c1_c1_6, u64: HfgitrEl2, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `HFGRTR2_EL2` system register.
+    hfgrtr2_el2: s3_4_c3_c1_2, u64: Hfgrtr2El2, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `HFGRTR_EL2` system register.
+    hfgrtr_el2: s3_4_c1_c1_4, u64: HfgrtrEl2, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `HFGWTR2_EL2` system register.
+    hfgwtr2_el2: s3_4_c3_c1_3, u64: Hfgwtr2El2, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `HFGWTR_EL2` system register.
+    hfgwtr_el2: s3_4_c1_c1_5, u64: HfgwtrEl2, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg!(hstr_el2, u64, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `SCR_EL3` system register.
+    scr_el3, u64: ScrEl3, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `SCTLR2_EL1` system register.
+    sctlr2_el1: s3_0_c1_c0_3, u64: Sctlr2El1, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `SCTLR2_EL2` system register.
+    sctlr2_el2: s3_4_c1_c0_3, u64: Sctlr2El2, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `SCTLR2_EL3` system register.
+    sctlr2_el3: s3_6_c1_c0_3, u64: Sctlr2El3, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `SCTLR_EL1` system register.
+    sctlr_el1, u64: SctlrEl1, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `SCTLR_EL2` system register.
+    sctlr_el2, u64: SctlrEl2, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe configuration value for the EL3 system control register.
+    sctlr_el3, u64: SctlrEl3, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `SP_EL1` system register.
+    sp_el1, u64: SpEl1, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `SP_EL2` system register.
+    sp_el2, u64: SpEl2, safe_read, fake::SYSREGS
+}
+#[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `TPIDRRO_EL0` system register.
+    tpidrro_el0, u64: TpidrroEl0, safe_read, fake::SYSREGS
+}
+#[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `TPIDR_EL0` system register.
+    tpidr_el0, u64: TpidrEl0, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `TPIDR_EL1` system register.
+    tpidr_el1, u64: TpidrEl1, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `TPIDR_EL2` system register.
+    tpidr_el2, u64: TpidrEl2, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `TPIDR_EL3` system register.
+    tpidr_el3, u64: TpidrEl3, safe_read, fake::SYSREGS
+}
@@ -0,0 +1,2321 @@
+// SPDX-FileCopyrightText: Copyright The arm-sysregs Contributors.
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Self-hosted debug and trace system registers.
+
+// This file is generated, do not edit manually.
+
+// Depending on the enabled features, the module might not use all of these imports.
+#![allow(unused_imports)]
+
+use crate::*;
+use bitflags::bitflags;
+use core::fmt::{self, Display, Formatter};
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `BRBCR_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct BrbcrEl2: u64 {
+        /// `E0HBRE` bit.
+        const E0HBRE = 1 << 0;
+        /// `E2BRE` bit.
+        const E2BRE = 1 << 1;
+        /// `CC` bit.
+        const CC = 1 << 3;
+        /// `MPRED` bit.
+        const MPRED = 1 << 4;
+        /// `FZP` bit.
+        const FZP = 1 << 8;
+        /// `FZPSS` bit.
+        const FZPSS = 1 << 9;
+        /// `ERTN` bit.
+        const ERTN = 1 << 22;
+        /// `EXCEPTION` bit.
+        const EXCEPTION = 1 << 23;
+    }
+}
+
+#[cfg(feature = "el2")]
+impl BrbcrEl2 {
+    /// Descriptor of the `E0HBRE` field, for use with [`FieldAccess`].
+    pub const E0HBRE_FIELD: Field<Self, bool, 0, 1> = Field::new("BRBCR_EL2", "E0HBRE");
+    /// Descriptor of the `E2BRE` field, for use with [`FieldAccess`].
+    pub const E2BRE_FIELD: Field<Self, bool, 1, 1> = Field::new("BRBCR_EL2", "E2BRE");
+    /// Descriptor of the `CC` field, for use with [`FieldAccess`].
+    pub const CC_FIELD: Field<Self, bool, 3, 1> = Field::new("BRBCR_EL2", "CC");
+    /// Descriptor of the `MPRED` field, for use with [`FieldAccess`].
+    pub const MPRED_FIELD: Field<Self, bool, 4, 1> = Field::new("BRBCR_EL2", "MPRED");
+    /// Descriptor of the `TS` field, for use with [`FieldAccess`].
+    pub const TS_FIELD: Field<Self, u8, 5, 2> = Field::new("BRBCR_EL2", "TS");
+    /// Descriptor of the `FZP` field, for use with [`FieldAccess`].
+    pub const FZP_FIELD: Field<Self, bool, 8, 1> = Field::new("BRBCR_EL2", "FZP");
+    /// Descriptor of the `FZPSS` field, for use with [`FieldAccess`].
+    pub const FZPSS_FIELD: Field<Self, bool, 9, 1> = Field::new("BRBCR_EL2", "FZPSS");
+    /// Descriptor of the `ERTN` field, for use with [`FieldAccess`].
+    pub const ERTN_FIELD: Field<Self, bool, 22, 1> = Field::new("BRBCR_EL2", "ERTN");
+    /// Descriptor of the `EXCEPTION` field, for use with [`FieldAccess`].
+    pub const EXCEPTION_FIELD: Field<Self, bool, 23, 1> = Field::new("BRBCR_EL2", "EXCEPTION");
+
+    /// Returns the value of the `TS` field.
+    pub const fn ts(self) -> u8 {
+        Self::TS_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `TS` field.
+    pub const fn set_ts(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::TS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `TS` field set to the given value.
+    pub const fn with_ts(mut self, value: u8) -> Self {
+        self.set_ts(value);
+        self
+    }
+
+    /// Sets the value of the `TS` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ts(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ts(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `TS` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ts(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::TS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Display for BrbcrEl2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("BrbcrEl2");
+        let bits = self.bits();
+        s.field("E0HBRE", &Self::E0HBRE_FIELD.extract(bits));
+        s.field("E2BRE", &Self::E2BRE_FIELD.extract(bits));
+        s.field("CC", &Self::CC_FIELD.extract(bits));
+        s.field("MPRED", &Self::MPRED_FIELD.extract(bits));
+        s.field("TS", &Self::TS_FIELD.extract(bits));
+        s.field("FZP", &Self::FZP_FIELD.extract(bits));
+        s.field("FZPSS", &Self::FZPSS_FIELD.extract(bits));
+        s.field("ERTN", &Self::ERTN_FIELD.extract(bits));
+        s.field("EXCEPTION", &Self::EXCEPTION_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for BrbcrEl2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "BrbcrEl2 {{ E0HBRE: {=u64}, E2BRE: {=u64}, CC: {=u64}, MPRED: {=u64}, TS: {=u64}, FZP: {=u64}, FZPSS: {=u64}, ERTN: {=u64}, EXCEPTION: {=u64} }}",
+            Self::E0HBRE_FIELD.extract(self.bits()),
+            Self::E2BRE_FIELD.extract(self.bits()),
+            Self::CC_FIELD.extract(self.bits()),
+            Self::MPRED_FIELD.extract(self.bits()),
+            Self::TS_FIELD.extract(self.bits()),
+            Self::FZP_FIELD.extract(self.bits()),
+            Self::FZPSS_FIELD.extract(self.bits()),
+            Self::ERTN_FIELD.extract(self.bits()),
+            Self::EXCEPTION_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for BrbcrEl2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for BrbcrEl2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl BrbcrEl2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("E0HBRE"),
+                old: Self::E0HBRE_FIELD.extract(self.bits()),
+                new: Self::E0HBRE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("E2BRE"),
+                old: Self::E2BRE_FIELD.extract(self.bits()),
+                new: Self::E2BRE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CC"),
+                old: Self::CC_FIELD.extract(self.bits()),
+                new: Self::CC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("MPRED"),
+                old: Self::MPRED_FIELD.extract(self.bits()),
+                new: Self::MPRED_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TS"),
+                old: Self::TS_FIELD.extract(self.bits()),
+                new: Self::TS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FZP"),
+                old: Self::FZP_FIELD.extract(self.bits()),
+                new: Self::FZP_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FZPSS"),
+                old: Self::FZPSS_FIELD.extract(self.bits()),
+                new: Self::FZPSS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ERTN"),
+                old: Self::ERTN_FIELD.extract(self.bits()),
+                new: Self::ERTN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EXCEPTION"),
+                old: Self::EXCEPTION_FIELD.extract(self.bits()),
+                new: Self::EXCEPTION_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_0011_1111_1111_1100_1000_0100,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_0011_1111_1111_1100_1000_0100,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl BrbcrEl2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl BrbcrEl2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                Self::E0HBRE_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Brbe),
+                UnimplementedField {
+                    register: "BRBCR_EL2",
+                    field: "E0HBRE",
+                    requires: "FEAT_BRBE",
+                },
+            ),
+            (
+                Self::E2BRE_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Brbe),
+                UnimplementedField {
+                    register: "BRBCR_EL2",
+                    field: "E2BRE",
+                    requires: "FEAT_BRBE",
+                },
+            ),
+            (
+                Self::CC_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Brbe),
+                UnimplementedField {
+                    register: "BRBCR_EL2",
+                    field: "CC",
+                    requires: "FEAT_BRBE",
+                },
+            ),
+            (
+                Self::MPRED_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Brbe),
+                UnimplementedField {
+                    register: "BRBCR_EL2",
+                    field: "MPRED",
+                    requires: "FEAT_BRBE",
+                },
+            ),
+            (
+                Self::TS_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Brbe),
+                UnimplementedField {
+                    register: "BRBCR_EL2",
+                    field: "TS",
+                    requires: "FEAT_BRBE",
+                },
+            ),
+            (
+                Self::FZP_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Brbe),
+                UnimplementedField {
+                    register: "BRBCR_EL2",
+                    field: "FZP",
+                    requires: "FEAT_BRBE",
+                },
+            ),
+            (
+                Self::FZPSS_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Brbe),
+                UnimplementedField {
+                    register: "BRBCR_EL2",
+                    field: "FZPSS",
+                    requires: "FEAT_BRBE",
+                },
+            ),
+            (
+                Self::ERTN_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Brbe),
+                UnimplementedField {
+                    register: "BRBCR_EL2",
+                    field: "ERTN",
+                    requires: "FEAT_BRBE",
+                },
+            ),
+            (
+                Self::EXCEPTION_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Brbe),
+                UnimplementedField {
+                    register: "BRBCR_EL2",
+                    field: "EXCEPTION",
+                    requires: "FEAT_BRBE",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `MDCCINT_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct MdccintEl1: u64 {
+        /// `TX` bit.
+        const TX = 1 << 29;
+        /// `RX` bit.
+        const RX = 1 << 30;
+    }
+}
+
+#[cfg(feature = "el1")]
+impl MdccintEl1 {
+    /// Descriptor of the `TX` field, for use with [`FieldAccess`].
+    pub const TX_FIELD: Field<Self, bool, 29, 1> = Field::new("MDCCINT_EL1", "TX");
+    /// Descriptor of the `RX` field, for use with [`FieldAccess`].
+    pub const RX_FIELD: Field<Self, bool, 30, 1> = Field::new("MDCCINT_EL1", "RX");
+}
+
+#[cfg(feature = "el1")]
+impl Display for MdccintEl1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("MdccintEl1");
+        let bits = self.bits();
+        s.field("TX", &Self::TX_FIELD.extract(bits));
+        s.field("RX", &Self::RX_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for MdccintEl1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "MdccintEl1 {{ TX: {=u64}, RX: {=u64} }}",
+            Self::TX_FIELD.extract(self.bits()),
+            Self::RX_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for MdccintEl1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for MdccintEl1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl MdccintEl1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("TX"),
+                old: Self::TX_FIELD.extract(self.bits()),
+                new: Self::TX_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("RX"),
+                old: Self::RX_FIELD.extract(self.bits()),
+                new: Self::RX_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1001_1111_1111_1111_1111_1111_1111_1111,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1001_1111_1111_1111_1111_1111_1111_1111,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl MdccintEl1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl MdccintEl1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `MDCR_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct MdcrEl2: u64 {
+        /// `TPMCR` bit.
+        const TPMCR = 1 << 5;
+        /// `TPM` bit.
+        const TPM = 1 << 6;
+        /// `HPME` bit.
+        const HPME = 1 << 7;
+        /// `TDE` bit.
+        const TDE = 1 << 8;
+        /// `TDA` bit.
+        const TDA = 1 << 9;
+        /// `TDOSA` bit.
+        const TDOSA = 1 << 10;
+        /// `TDRA` bit.
+        const TDRA = 1 << 11;
+        /// `TPMS` bit.
+        const TPMS = 1 << 14;
+        /// `EnSPM` bit.
+        const ENSPM = 1 << 15;
+        /// `HPMD` bit.
+        const HPMD = 1 << 17;
+        /// `TTRF` bit.
+        const TTRF = 1 << 19;
+        /// `HCCD` bit.
+        const HCCD = 1 << 23;
+        /// `HLP` bit.
+        const HLP = 1 << 26;
+        /// `TDCC` bit.
+        const TDCC = 1 << 27;
+        /// `MTPME` bit.
+        const MTPME = 1 << 28;
+        /// `HPMFZO` bit.
+        const HPMFZO = 1 << 29;
+        /// `HPMFZS` bit.
+        const HPMFZS = 1 << 36;
+        /// `EBWE` bit.
+        const EBWE = 1 << 43;
+        /// `EnSTEPOP` bit.
+        const ENSTEPOP = 1 << 50;
+    }
+}
+
+#[cfg(feature = "el2")]
+impl MdcrEl2 {
+    /// Descriptor of the `HPMN` field, for use with [`FieldAccess`].
+    pub const HPMN_FIELD: Field<Self, u8, 0, 5> = Field::new("MDCR_EL2", "HPMN");
+    /// Descriptor of the `TPMCR` field, for use with [`FieldAccess`].
+    pub const TPMCR_FIELD: Field<Self, bool, 5, 1> = Field::new("MDCR_EL2", "TPMCR");
+    /// Descriptor of the `TPM` field, for use with [`FieldAccess`].
+    pub const TPM_FIELD: Field<Self, bool, 6, 1> = Field::new("MDCR_EL2", "TPM");
+    /// Descriptor of the `HPME` field, for use with [`FieldAccess`].
+    pub const HPME_FIELD: Field<Self, bool, 7, 1> = Field::new("MDCR_EL2", "HPME");
+    /// Descriptor of the `TDE` field, for use with [`FieldAccess`].
+    pub const TDE_FIELD: Field<Self, bool, 8, 1> = Field::new("MDCR_EL2", "TDE");
+    /// Descriptor of the `TDA` field, for use with [`FieldAccess`].
+    pub const TDA_FIELD: Field<Self, bool, 9, 1> = Field::new("MDCR_EL2", "TDA");
+    /// Descriptor of the `TDOSA` field, for use with [`FieldAccess`].
+    pub const TDOSA_FIELD: Field<Self, bool, 10, 1> = Field::new("MDCR_EL2", "TDOSA");
+    /// Descriptor of the `TDRA` field, for use with [`FieldAccess`].
+    pub const TDRA_FIELD: Field<Self, bool, 11, 1> = Field::new("MDCR_EL2", "TDRA");
+    /// Descriptor of the `E2PB` field, for use with [`FieldAccess`].
+    pub const E2PB_FIELD: Field<Self, u8, 12, 2> = Field::new("MDCR_EL2", "E2PB");
+    /// Descriptor of the `TPMS` field, for use with [`FieldAccess`].
+    pub const TPMS_FIELD: Field<Self, bool, 14, 1> = Field::new("MDCR_EL2", "TPMS");
+    /// Descriptor of the `EnSPM` field, for use with [`FieldAccess`].
+    pub const ENSPM_FIELD: Field<Self, bool, 15, 1> = Field::new("MDCR_EL2", "EnSPM");
+    /// Descriptor of the `HPMD` field, for use with [`FieldAccess`].
+    pub const HPMD_FIELD: Field<Self, bool, 17, 1> = Field::new("MDCR_EL2", "HPMD");
+    /// Descriptor of the `TTRF` field, for use with [`FieldAccess`].
+    pub const TTRF_FIELD: Field<Self, bool, 19, 1> = Field::new("MDCR_EL2", "TTRF");
+    /// Descriptor of the `HCCD` field, for use with [`FieldAccess`].
+    pub const HCCD_FIELD: Field<Self, bool, 23, 1> = Field::new("MDCR_EL2", "HCCD");
+    /// Descriptor of the `E2TB` field, for use with [`FieldAccess`].
+    pub const E2TB_FIELD: Field<Self, u8, 24, 2> = Field::new("MDCR_EL2", "E2TB");
+    /// Descriptor of the `HLP` field, for use with [`FieldAccess`].
+    pub const HLP_FIELD: Field<Self, bool, 26, 1> = Field::new("MDCR_EL2", "HLP");
+    /// Descriptor of the `TDCC` field, for use with [`FieldAccess`].
+    pub const TDCC_FIELD: Field<Self, bool, 27, 1> = Field::new("MDCR_EL2", "TDCC");
+    /// Descriptor of the `MTPME` field, for use with [`FieldAccess`].
+    pub const MTPME_FIELD: Field<Self, bool, 28, 1> = Field::new("MDCR_EL2", "MTPME");
+    /// Descriptor of the `HPMFZO` field, for use with [`FieldAccess`].
+    pub const HPMFZO_FIELD: Field<Self, bool, 29, 1> = Field::new("MDCR_EL2", "HPMFZO");
+    /// Descriptor of the `PMSSE` field, for use with [`FieldAccess`].
+    pub const PMSSE_FIELD: Field<Self, u8, 30, 2> = Field::new("MDCR_EL2", "PMSSE");
+    /// Descriptor of the `HPMFZS` field, for use with [`FieldAccess`].
+    pub const HPMFZS_FIELD: Field<Self, bool, 36, 1> = Field::new("MDCR_EL2", "HPMFZS");
+    /// Descriptor of the `PMEE` field, for use with [`FieldAccess`].
+    pub const PMEE_FIELD: Field<Self, u8, 40, 2> = Field::new("MDCR_EL2", "PMEE");
+    /// Descriptor of the `EBWE` field, for use with [`FieldAccess`].
+    pub const EBWE_FIELD: Field<Self, bool, 43, 1> = Field::new("MDCR_EL2", "EBWE");
+    /// Descriptor of the `EnSTEPOP` field, for use with [`FieldAccess`].
+    pub const ENSTEPOP_FIELD: Field<Self, bool, 50, 1> = Field::new("MDCR_EL2", "EnSTEPOP");
+
+    /// Returns the value of the `HPMN` field.
+    pub const fn hpmn(self) -> u8 {
+        Self::HPMN_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `HPMN` field.
+    pub const fn set_hpmn(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::HPMN_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `HPMN` field set to the given value.
+    pub const fn with_hpmn(mut self, value: u8) -> Self {
+        self.set_hpmn(value);
+        self
+    }
+
+    /// Sets the value of the `HPMN` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_hpmn(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_hpmn(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `HPMN` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_hpmn(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::HPMN_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `E2PB` field.
+    pub const fn e2pb(self) -> u8 {
+        Self::E2PB_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `E2PB` field.
+    pub const fn set_e2pb(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::E2PB_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `E2PB` field set to the given value.
+    pub const fn with_e2pb(mut self, value: u8) -> Self {
+        self.set_e2pb(value);
+        self
+    }
+
+    /// Sets the value of the `E2PB` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_e2pb(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_e2pb(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `E2PB` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_e2pb(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::E2PB_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `E2TB` field.
+    pub const fn e2tb(self) -> u8 {
+        Self::E2TB_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `E2TB` field.
+    pub const fn set_e2tb(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::E2TB_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `E2TB` field set to the given value.
+    pub const fn with_e2tb(mut self, value: u8) -> Self {
+        self.set_e2tb(value);
+        self
+    }
+
+    /// Sets the value of the `E2TB` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_e2tb(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_e2tb(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `E2TB` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_e2tb(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::E2TB_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PMSSE` field.
+    pub const fn pmsse(self) -> u8 {
+        Self::PMSSE_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `PMSSE` field.
+    pub const fn set_pmsse(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::PMSSE_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PMSSE` field set to the given value.
+    pub const fn with_pmsse(mut self, value: u8) -> Self {
+        self.set_pmsse(value);
+        self
+    }
+
+    /// Sets the value of the `PMSSE` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_pmsse(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_pmsse(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PMSSE` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_pmsse(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::PMSSE_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PMEE` field.
+    pub const fn pmee(self) -> u8 {
+        Self::PMEE_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `PMEE` field.
+    pub const fn set_pmee(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::PMEE_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PMEE` field set to the given value.
+    pub const fn with_pmee(mut self, value: u8) -> Self {
+        self.set_pmee(value);
+        self
+    }
+
+    /// Sets the value of the `PMEE` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_pmee(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_pmee(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PMEE` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_pmee(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::PMEE_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Display for MdcrEl2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("MdcrEl2");
+        let bits = self.bits();
+        s.field("HPMN", &Self::HPMN_FIELD.extract(bits));
+        s.field("TPMCR", &Self::TPMCR_FIELD.extract(bits));
+        s.field("TPM", &Self::TPM_FIELD.extract(bits));
+        s.field("HPME", &Self::HPME_FIELD.extract(bits));
+        s.field("TDE", &Self::TDE_FIELD.extract(bits));
+        s.field("TDA", &Self::TDA_FIELD.extract(bits));
+        s.field("TDOSA", &Self::TDOSA_FIELD.extract(bits));
+        s.field("TDRA", &Self::TDRA_FIELD.extract(bits));
+        s.field("E2PB", &Self::E2PB_FIELD.extract(bits));
+        s.field("TPMS", &Self::TPMS_FIELD.extract(bits));
+        s.field("EnSPM", &Self::ENSPM_FIELD.extract(bits));
+        s.field("HPMD", &Self::HPMD_FIELD.extract(bits));
+        s.field("TTRF", &Self::TTRF_FIELD.extract(bits));
+        s.field("HCCD", &Self::HCCD_FIELD.extract(bits));
+        s.field("E2TB", &Self::E2TB_FIELD.extract(bits));
+        s.field("HLP", &Self::HLP_FIELD.extract(bits));
+        s.field("TDCC", &Self::TDCC_FIELD.extract(bits));
+        s.field("MTPME", &Self::MTPME_FIELD.extract(bits));
+        s.field("HPMFZO", &Self::HPMFZO_FIELD.extract(bits));
+        s.field("PMSSE", &Self::PMSSE_FIELD.extract(bits));
+        s.field("HPMFZS", &Self::HPMFZS_FIELD.extract(bits));
+        s.field("PMEE", &Self::PMEE_FIELD.extract(bits));
+        s.field("EBWE", &Self::EBWE_FIELD.extract(bits));
+        s.field("EnSTEPOP", &Self::ENSTEPOP_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for MdcrEl2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "MdcrEl2 {{ HPMN: {=u64}, TPMCR: {=u64}, TPM: {=u64}, HPME: {=u64}, TDE: {=u64}, TDA: {=u64}, TDOSA: {=u64}, TDRA: {=u64}, E2PB: {=u64}, TPMS: {=u64}, EnSPM: {=u64}, HPMD: {=u64}, TTRF: {=u64}, HCCD: {=u64}, E2TB: {=u64}, HLP: {=u64}, TDCC: {=u64}, MTPME: {=u64}, HPMFZO: {=u64}, PMSSE: {=u64}, HPMFZS: {=u64}, PMEE: {=u64}, EBWE: {=u64}, EnSTEPOP: {=u64} }}",
+            Self::HPMN_FIELD.extract(self.bits()),
+            Self::TPMCR_FIELD.extract(self.bits()),
+            Self::TPM_FIELD.extract(self.bits()),
+            Self::HPME_FIELD.extract(self.bits()),
+            Self::TDE_FIELD.extract(self.bits()),
+            Self::TDA_FIELD.extract(self.bits()),
+            Self::TDOSA_FIELD.extract(self.bits()),
+            Self::TDRA_FIELD.extract(self.bits()),
+            Self::E2PB_FIELD.extract(self.bits()),
+            Self::TPMS_FIELD.extract(self.bits()),
+            Self::ENSPM_FIELD.extract(self.bits()),
+            Self::HPMD_FIELD.extract(self.bits()),
+            Self::TTRF_FIELD.extract(self.bits()),
+            Self::HCCD_FIELD.extract(self.bits()),
+            Self::E2TB_FIELD.extract(self.bits()),
+            Self::HLP_FIELD.extract(self.bits()),
+            Self::TDCC_FIELD.extract(self.bits()),
+            Self::MTPME_FIELD.extract(self.bits()),
+            Self::HPMFZO_FIELD.extract(self.bits()),
+            Self::PMSSE_FIELD.extract(self.bits()),
+            Self::HPMFZS_FIELD.extract(self.bits()),
+            Self::PMEE_FIELD.extract(self.bits()),
+            Self::EBWE_FIELD.extract(self.bits()),
+            Self::ENSTEPOP_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for MdcrEl2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for MdcrEl2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl MdcrEl2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("HPMN"),
+                old: Self::HPMN_FIELD.extract(self.bits()),
+                new: Self::HPMN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TPMCR"),
+                old: Self::TPMCR_FIELD.extract(self.bits()),
+                new: Self::TPMCR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TPM"),
+                old: Self::TPM_FIELD.extract(self.bits()),
+                new: Self::TPM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HPME"),
+                old: Self::HPME_FIELD.extract(self.bits()),
+                new: Self::HPME_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TDE"),
+                old: Self::TDE_FIELD.extract(self.bits()),
+                new: Self::TDE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TDA"),
+                old: Self::TDA_FIELD.extract(self.bits()),
+                new: Self::TDA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TDOSA"),
+                old: Self::TDOSA_FIELD.extract(self.bits()),
+                new: Self::TDOSA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TDRA"),
+                old: Self::TDRA_FIELD.extract(self.bits()),
+                new: Self::TDRA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("E2PB"),
+                old: Self::E2PB_FIELD.extract(self.bits()),
+                new: Self::E2PB_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TPMS"),
+                old: Self::TPMS_FIELD.extract(self.bits()),
+                new: Self::TPMS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnSPM"),
+                old: Self::ENSPM_FIELD.extract(self.bits()),
+                new: Self::ENSPM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HPMD"),
+                old: Self::HPMD_FIELD.extract(self.bits()),
+                new: Self::HPMD_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TTRF"),
+                old: Self::TTRF_FIELD.extract(self.bits()),
+                new: Self::TTRF_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HCCD"),
+                old: Self::HCCD_FIELD.extract(self.bits()),
+                new: Self::HCCD_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("E2TB"),
+                old: Self::E2TB_FIELD.extract(self.bits()),
+                new: Self::E2TB_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HLP"),
+                old: Self::HLP_FIELD.extract(self.bits()),
+                new: Self::HLP_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TDCC"),
+                old: Self::TDCC_FIELD.extract(self.bits()),
+                new: Self::TDCC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("MTPME"),
+                old: Self::MTPME_FIELD.extract(self.bits()),
+                new: Self::MTPME_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HPMFZO"),
+                old: Self::HPMFZO_FIELD.extract(self.bits()),
+                new: Self::HPMFZO_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMSSE"),
+                old: Self::PMSSE_FIELD.extract(self.bits()),
+                new: Self::PMSSE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HPMFZS"),
+                old: Self::HPMFZS_FIELD.extract(self.bits()),
+                new: Self::HPMFZS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMEE"),
+                old: Self::PMEE_FIELD.extract(self.bits()),
+                new: Self::PMEE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EBWE"),
+                old: Self::EBWE_FIELD.extract(self.bits()),
+                new: Self::EBWE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnSTEPOP"),
+                old: Self::ENSTEPOP_FIELD.extract(self.bits()),
+                new: Self::ENSTEPOP_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1011_1111_0100_1110_1111_0000_0000_0111_0101_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_1111_1011_1111_0100_1110_1111_0000_0000_0111_0101_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl MdcrEl2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl MdcrEl2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el3")]
+bitflags! {
+    /// `MDCR_EL3` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct MdcrEl3: u64 {
+        /// Realm Trace enable. Enables tracing in Realm state.
+        const RLTE = 1 << 0;
+        /// `EPMADE` bit.
+        const EPMADE = 1 << 2;
+        /// `ETADE` bit.
+        const ETADE = 1 << 3;
+        /// `EDADE` bit.
+        const EDADE = 1 << 4;
+        /// Trap Performance Monitor register accesses
+        const TPM = 1 << 6;
+        /// Do not trap various PMUv3p9 related system register accesses to EL3.
+        const ENPM2 = 1 << 7;
+        /// `TDA` bit.
+        const TDA = 1 << 9;
+        /// `TDOSA` bit.
+        const TDOSA = 1 << 10;
+        /// Non-secure Profiling Buffer Extended. Together with MDCR_EL3.NSPB, controls the Profiling Buffer owning Security state and accesses to Statistical Profiling and Profiling Buffer System registers from EL2 and EL1.
+        const NSPBE = 1 << 11;
+        /// Set to one to disable AArch64 Secure self-hosted debug. Debug exceptions, other than Breakpoint Instruction exceptions, are disabled from all ELs in Secure state.
+        const SDD = 1 << 16;
+        /// Secure Performance Monitors Enable. Controls event counting in Secure state and EL3.
+        const SPME = 1 << 17;
+        /// Secure Trace enable. Enables tracing in Secure state.
+        const STE = 1 << 18;
+        /// Trap Trace Filter controls. Traps use of the Trace Filter control registers at EL2 and EL1 to EL3.
+        const TTRF = 1 << 19;
+        /// `EDAD` bit.
+        const EDAD = 1 << 20;
+        /// `EPMAD` bit.
+        const EPMAD = 1 << 21;
+        /// `ETAD` bit.
+        const ETAD = 1 << 22;
+        /// Secure Cycle Counter Disable. Prohibits PMCCNTR_EL0 from counting in Secure state.
+        const SCCD = 1 << 23;
+        /// Non-secure Trace Buffer Extended. Together with MDCR_EL3.NSTB, controls the trace buffer owning Security state and accesses to trace buffer System registers from EL2 and EL1.
+        const NSTBE = 1 << 26;
+        /// `TDCC` bit.
+        const TDCC = 1 << 27;
+        /// Multi-threaded PMU Enable. Enables use of the PMEVTYPER<n>_EL0.MT bits.
+        const MTPME = 1 << 28;
+        /// Monitor Cycle Counter Disable. Prohibits the Cycle Counter, PMCCNTR_EL0, from counting at EL3.
+        const MCCD = 1 << 34;
+        /// Monitor Performance Monitors Extended control. In conjunction with MDCR_EL3.SPME, controls when event counters are enabled at EL3 and in other Secure Exception levels.
+        const MPMX = 1 << 35;
+        /// Trap accesses to PMSNEVFR_EL1. Controls access to Statistical Profiling PMSNEVFR_EL1 System register from EL2 and EL1.
+        const ENPMSN = 1 << 36;
+        /// `E3BREW` bit.
+        const E3BREW = 1 << 37;
+        /// `E3BREC` bit.
+        const E3BREC = 1 << 38;
+        /// `EnTB2` bit.
+        const ENTB2 = 1 << 39;
+        /// Enable access to SPE registers. When disabled, accesses to SPE registers generate a trap to EL3.
+        const ENPMS3 = 1 << 42;
+        /// `EBWE` bit.
+        const EBWE = 1 << 43;
+        /// `EnPMSS` bit.
+        const ENPMSS = 1 << 44;
+        /// `EnITE` bit.
+        const ENITE = 1 << 47;
+        /// `EnSTEPOP` bit.
+        const ENSTEPOP = 1 << 50;
+        /// `EnPMS4` bit.
+        const ENPMS4 = 1 << 55;
+    }
+}
+
+#[cfg(feature = "el3")]
+impl MdcrEl3 {
+    /// Descriptor of the `RLTE` field, for use with [`FieldAccess`].
+    pub const RLTE_FIELD: Field<Self, bool, 0, 1> = Field::new("MDCR_EL3", "RLTE");
+    /// Descriptor of the `EPMADE` field, for use with [`FieldAccess`].
+    pub const EPMADE_FIELD: Field<Self, bool, 2, 1> = Field::new("MDCR_EL3", "EPMADE");
+    /// Descriptor of the `ETADE` field, for use with [`FieldAccess`].
+    pub const ETADE_FIELD: Field<Self, bool, 3, 1> = Field::new("MDCR_EL3", "ETADE");
+    /// Descriptor of the `EDADE` field, for use with [`FieldAccess`].
+    pub const EDADE_FIELD: Field<Self, bool, 4, 1> = Field::new("MDCR_EL3", "EDADE");
+    /// Descriptor of the `TPM` field, for use with [`FieldAccess`].
+    pub const TPM_FIELD: Field<Self, bool, 6, 1> = Field::new("MDCR_EL3", "TPM");
+    /// Descriptor of the `EnPM2` field, for use with [`FieldAccess`].
+    pub const ENPM2_FIELD: Field<Self, bool, 7, 1> = Field::new("MDCR_EL3", "EnPM2");
+    /// Descriptor of the `TDA` field, for use with [`FieldAccess`].
+    pub const TDA_FIELD: Field<Self, bool, 9, 1> = Field::new("MDCR_EL3", "TDA");
+    /// Descriptor of the `TDOSA` field, for use with [`FieldAccess`].
+    pub const TDOSA_FIELD: Field<Self, bool, 10, 1> = Field::new("MDCR_EL3", "TDOSA");
+    /// Descriptor of the `NSPBE` field, for use with [`FieldAccess`].
+    pub const NSPBE_FIELD: Field<Self, bool, 11, 1> = Field::new("MDCR_EL3", "NSPBE");
+    /// Descriptor of the `NSPB` field, for use with [`FieldAccess`].
+    pub const NSPB_FIELD: Field<Self, u8, 12, 2> = Field::new("MDCR_EL3", "NSPB");
+    /// Descriptor of the `SPD32` field, for use with [`FieldAccess`].
+    pub const SPD32_FIELD: Field<Self, u8, 14, 2> = Field::new("MDCR_EL3", "SPD32");
+    /// Descriptor of the `SDD` field, for use with [`FieldAccess`].
+    pub const SDD_FIELD: Field<Self, bool, 16, 1> = Field::new("MDCR_EL3", "SDD");
+    /// Descriptor of the `SPME` field, for use with [`FieldAccess`].
+    pub const SPME_FIELD: Field<Self, bool, 17, 1> = Field::new("MDCR_EL3", "SPME");
+    /// Descriptor of the `STE` field, for use with [`FieldAccess`].
+    pub const STE_FIELD: Field<Self, bool, 18, 1> = Field::new("MDCR_EL3", "STE");
+    /// Descriptor of the `TTRF` field, for use with [`FieldAccess`].
+    pub const TTRF_FIELD: Field<Self, bool, 19, 1> = Field::new("MDCR_EL3", "TTRF");
+    /// Descriptor of the `EDAD` field, for use with [`FieldAccess`].
+    pub const EDAD_FIELD: Field<Self, bool, 20, 1> = Field::new("MDCR_EL3", "EDAD");
+    /// Descriptor of the `EPMAD` field, for use with [`FieldAccess`].
+    pub const EPMAD_FIELD: Field<Self, bool, 21, 1> = Field::new("MDCR_EL3", "EPMAD");
+    /// Descriptor of the `ETAD` field, for use with [`FieldAccess`].
+    pub const ETAD_FIELD: Field<Self, bool, 22, 1> = Field::new("MDCR_EL3", "ETAD");
+    /// Descriptor of the `SCCD` field, for use with [`FieldAccess`].
+    pub const SCCD_FIELD: Field<Self, bool, 23, 1> = Field::new("MDCR_EL3", "SCCD");
+    /// Descriptor of the `NSTB` field, for use with [`FieldAccess`].
+    pub const NSTB_FIELD: Field<Self, u8, 24, 2> = Field::new("MDCR_EL3", "NSTB");
+    /// Descriptor of the `NSTBE` field, for use with [`FieldAccess`].
+    pub const NSTBE_FIELD: Field<Self, bool, 26, 1> = Field::new("MDCR_EL3", "NSTBE");
+    /// Descriptor of the `TDCC` field, for use with [`FieldAccess`].
+    pub const TDCC_FIELD: Field<Self, bool, 27, 1> = Field::new("MDCR_EL3", "TDCC");
+    /// Descriptor of the `MTPME` field, for use with [`FieldAccess`].
+    pub const MTPME_FIELD: Field<Self, bool, 28, 1> = Field::new("MDCR_EL3", "MTPME");
+    /// Descriptor of the `PMSSE` field, for use with [`FieldAccess`].
+    pub const PMSSE_FIELD: Field<Self, u8, 30, 2> = Field::new("MDCR_EL3", "PMSSE");
+    /// Descriptor of the `SBRBE` field, for use with [`FieldAccess`].
+    pub const SBRBE_FIELD: Field<Self, u8, 32, 2> = Field::new("MDCR_EL3", "SBRBE");
+    /// Descriptor of the `MCCD` field, for use with [`FieldAccess`].
+    pub const MCCD_FIELD: Field<Self, bool, 34, 1> = Field::new("MDCR_EL3", "MCCD");
+    /// Descriptor of the `MPMX` field, for use with [`FieldAccess`].
+    pub const MPMX_FIELD: Field<Self, bool, 35, 1> = Field::new("MDCR_EL3", "MPMX");
+    /// Descriptor of the `EnPMSN` field, for use with [`FieldAccess`].
+    pub const ENPMSN_FIELD: Field<Self, bool, 36, 1> = Field::new("MDCR_EL3", "EnPMSN");
+    /// Descriptor of the `E3BREW` field, for use with [`FieldAccess`].
+    pub const E3BREW_FIELD: Field<Self, bool, 37, 1> = Field::new("MDCR_EL3", "E3BREW");
+    /// Descriptor of the `E3BREC` field, for use with [`FieldAccess`].
+    pub const E3BREC_FIELD: Field<Self, bool, 38, 1> = Field::new("MDCR_EL3", "E3BREC");
+    /// Descriptor of the `EnTB2` field, for use with [`FieldAccess`].
+    pub const ENTB2_FIELD: Field<Self, bool, 39, 1> = Field::new("MDCR_EL3", "EnTB2");
+    /// Descriptor of the `PMEE` field, for use with [`FieldAccess`].
+    pub const PMEE_FIELD: Field<Self, u8, 40, 2> = Field::new("MDCR_EL3", "PMEE");
+    /// Descriptor of the `EnPMS3` field, for use with [`FieldAccess`].
+    pub const ENPMS3_FIELD: Field<Self, bool, 42, 1> = Field::new("MDCR_EL3", "EnPMS3");
+    /// Descriptor of the `EBWE` field, for use with [`FieldAccess`].
+    pub const EBWE_FIELD: Field<Self, bool, 43, 1> = Field::new("MDCR_EL3", "EBWE");
+    /// Descriptor of the `EnPMSS` field, for use with [`FieldAccess`].
+    pub const ENPMSS_FIELD: Field<Self, bool, 44, 1> = Field::new("MDCR_EL3", "EnPMSS");
+    /// Descriptor of the `EPMSSAD` field, for use with [`FieldAccess`].
+    pub const EPMSSAD_FIELD: Field<Self, u8, 45, 2> = Field::new("MDCR_EL3", "EPMSSAD");
+    /// Descriptor of the `EnITE` field, for use with [`FieldAccess`].
+    pub const ENITE_FIELD: Field<Self, bool, 47, 1> = Field::new("MDCR_EL3", "EnITE");
+    /// Descriptor of the `ETBAD` field, for use with [`FieldAccess`].
+    pub const ETBAD_FIELD: Field<Self, u8, 48, 2> = Field::new("MDCR_EL3", "ETBAD");
+    /// Descriptor of the `EnSTEPOP` field, for use with [`FieldAccess`].
+    pub const ENSTEPOP_FIELD: Field<Self, bool, 50, 1> = Field::new("MDCR_EL3", "EnSTEPOP");
+    /// Descriptor of the `PMSEE` field, for use with [`FieldAccess`].
+    pub const PMSEE_FIELD: Field<Self, u8, 51, 2> = Field::new("MDCR_EL3", "PMSEE");
+    /// Descriptor of the `TRBEE` field, for use with [`FieldAccess`].
+    pub const TRBEE_FIELD: Field<Self, u8, 53, 2> = Field::new("MDCR_EL3", "TRBEE");
+    /// Descriptor of the `EnPMS4` field, for use with [`FieldAccess`].
+    pub const ENPMS4_FIELD: Field<Self, bool, 55, 1> = Field::new("MDCR_EL3", "EnPMS4");
+
+    /// Returns the value of the `NSPB` field.
+    pub const fn nspb(self) -> u8 {
+        Self::NSPB_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `NSPB` field.
+    pub const fn set_nspb(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::NSPB_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `NSPB` field set to the given value.
+    pub const fn with_nspb(mut self, value: u8) -> Self {
+        self.set_nspb(value);
+        self
+    }
+
+    /// Sets the value of the `NSPB` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_nspb(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_nspb(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `NSPB` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_nspb(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::NSPB_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SPD32` field.
+    pub const fn spd32(self) -> u8 {
+        Self::SPD32_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SPD32` field.
+    pub const fn set_spd32(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SPD32_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SPD32` field set to the given value.
+    pub const fn with_spd32(mut self, value: u8) -> Self {
+        self.set_spd32(value);
+        self
+    }
+
+    /// Sets the value of the `SPD32` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_spd32(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_spd32(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SPD32` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_spd32(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SPD32_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `NSTB` field.
+    pub const fn nstb(self) -> u8 {
+        Self::NSTB_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `NSTB` field.
+    pub const fn set_nstb(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::NSTB_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `NSTB` field set to the given value.
+    pub const fn with_nstb(mut self, value: u8) -> Self {
+        self.set_nstb(value);
+        self
+    }
+
+    /// Sets the value of the `NSTB` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_nstb(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_nstb(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `NSTB` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_nstb(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::NSTB_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PMSSE` field.
+    pub const fn pmsse(self) -> u8 {
+        Self::PMSSE_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `PMSSE` field.
+    pub const fn set_pmsse(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::PMSSE_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PMSSE` field set to the given value.
+    pub const fn with_pmsse(mut self, value: u8) -> Self {
+        self.set_pmsse(value);
+        self
+    }
+
+    /// Sets the value of the `PMSSE` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_pmsse(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_pmsse(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PMSSE` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_pmsse(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::PMSSE_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SBRBE` field.
+    pub const fn sbrbe(self) -> u8 {
+        Self::SBRBE_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SBRBE` field.
+    pub const fn set_sbrbe(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SBRBE_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SBRBE` field set to the given value.
+    pub const fn with_sbrbe(mut self, value: u8) -> Self {
+        self.set_sbrbe(value);
+        self
+    }
+
+    /// Sets the value of the `SBRBE` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_sbrbe(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_sbrbe(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SBRBE` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_sbrbe(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SBRBE_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PMEE` field.
+    pub const fn pmee(self) -> u8 {
+        Self::PMEE_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `PMEE` field.
+    pub const fn set_pmee(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::PMEE_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PMEE` field set to the given value.
+    pub const fn with_pmee(mut self, value: u8) -> Self {
+        self.set_pmee(value);
+        self
+    }
+
+    /// Sets the value of the `PMEE` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_pmee(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_pmee(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PMEE` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_pmee(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::PMEE_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `EPMSSAD` field.
+    pub const fn epmssad(self) -> u8 {
+        Self::EPMSSAD_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `EPMSSAD` field.
+    pub const fn set_epmssad(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::EPMSSAD_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `EPMSSAD` field set to the given value.
+    pub const fn with_epmssad(mut self, value: u8) -> Self {
+        self.set_epmssad(value);
+        self
+    }
+
+    /// Sets the value of the `EPMSSAD` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_epmssad(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_epmssad(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `EPMSSAD` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_epmssad(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::EPMSSAD_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ETBAD` field.
+    pub const fn etbad(self) -> u8 {
+        Self::ETBAD_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `ETBAD` field.
+    pub const fn set_etbad(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::ETBAD_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ETBAD` field set to the given value.
+    pub const fn with_etbad(mut self, value: u8) -> Self {
+        self.set_etbad(value);
+        self
+    }
+
+    /// Sets the value of the `ETBAD` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_etbad(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_etbad(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ETBAD` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_etbad(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::ETBAD_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PMSEE` field.
+    pub const fn pmsee(self) -> u8 {
+        Self::PMSEE_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `PMSEE` field.
+    pub const fn set_pmsee(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::PMSEE_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PMSEE` field set to the given value.
+    pub const fn with_pmsee(mut self, value: u8) -> Self {
+        self.set_pmsee(value);
+        self
+    }
+
+    /// Sets the value of the `PMSEE` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_pmsee(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_pmsee(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PMSEE` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_pmsee(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::PMSEE_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `TRBEE` field.
+    pub const fn trbee(self) -> u8 {
+        Self::TRBEE_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `TRBEE` field.
+    pub const fn set_trbee(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::TRBEE_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `TRBEE` field set to the given value.
+    pub const fn with_trbee(mut self, value: u8) -> Self {
+        self.set_trbee(value);
+        self
+    }
+
+    /// Sets the value of the `TRBEE` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_trbee(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_trbee(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `TRBEE` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_trbee(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::TRBEE_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el3")]
+impl Display for MdcrEl3 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("MdcrEl3");
+        let bits = self.bits();
+        s.field("RLTE", &Self::RLTE_FIELD.extract(bits));
+        s.field("EPMADE", &Self::EPMADE_FIELD.extract(bits));
+        s.field("ETADE", &Self::ETADE_FIELD.extract(bits));
+        s.field("EDADE", &Self::EDADE_FIELD.extract(bits));
+        s.field("TPM", &Self::TPM_FIELD.extract(bits));
+        s.field("EnPM2", &Self::ENPM2_FIELD.extract(bits));
+        s.field("TDA", &Self::TDA_FIELD.extract(bits));
+        s.field("TDOSA", &Self::TDOSA_FIELD.extract(bits));
+        s.field("NSPBE", &Self::NSPBE_FIELD.extract(bits));
+        s.field("NSPB", &Self::NSPB_FIELD.extract(bits));
+        s.field("SPD32", &Self::SPD32_FIELD.extract(bits));
+        s.field("SDD", &Self::SDD_FIELD.extract(bits));
+        s.field("SPME", &Self::SPME_FIELD.extract(bits));
+        s.field("STE", &Self::STE_FIELD.extract(bits));
+        s.field("TTRF", &Self::TTRF_FIELD.extract(bits));
+        s.field("EDAD", &Self::EDAD_FIELD.extract(bits));
+        s.field("EPMAD", &Self::EPMAD_FIELD.extract(bits));
+        s.field("ETAD", &Self::ETAD_FIELD.extract(bits));
+        s.field("SCCD", &Self::SCCD_FIELD.extract(bits));
+        s.field("NSTB", &Self::NSTB_FIELD.extract(bits));
+        s.field("NSTBE", &Self::NSTBE_FIELD.extract(bits));
+        s.field("TDCC", &Self::TDCC_FIELD.extract(bits));
+        s.field("MTPME", &Self::MTPME_FIELD.extract(bits));
+        s.field("PMSSE", &Self::PMSSE_FIELD.extract(bits));
+        s.field("SBRBE", &Self::SBRBE_FIELD.extract(bits));
+        s.field("MCCD", &Self::MCCD_FIELD.extract(bits));
+        s.field("MPMX", &Self::MPMX_FIELD.extract(bits));
+        s.field("EnPMSN", &Self::ENPMSN_FIELD.extract(bits));
+        s.field("E3BREW", &Self::E3BREW_FIELD.extract(bits));
+        s.field("E3BREC", &Self::E3BREC_FIELD.extract(bits));
+        s.field("EnTB2", &Self::ENTB2_FIELD.extract(bits));
+        s.field("PMEE", &Self::PMEE_FIELD.extract(bits));
+        s.field("EnPMS3", &Self::ENPMS3_FIELD.extract(bits));
+        s.field("EBWE", &Self::EBWE_FIELD.extract(bits));
+        s.field("EnPMSS", &Self::ENPMSS_FIELD.extract(bits));
+        s.field("EPMSSAD", &Self::EPMSSAD_FIELD.extract(bits));
+        s.field("EnITE", &Self::ENITE_FIELD.extract(bits));
+        s.field("ETBAD", &Self::ETBAD_FIELD.extract(bits));
+        s.field("EnSTEPOP", &Self::ENSTEPOP_FIELD.extract(bits));
+        s.field("PMSEE", &Self::PMSEE_FIELD.extract(bits));
+        s.field("TRBEE", &Self::TRBEE_FIELD.extract(bits));
+        s.field("EnPMS4", &Self::ENPMS4_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el3")]
+impl defmt::Format for MdcrEl3 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "MdcrEl3 {{ RLTE: {=u64}, EPMADE: {=u64}, ETADE: {=u64}, EDADE: {=u64}, TPM: {=u64}, EnPM2: {=u64}, TDA: {=u64}, TDOSA: {=u64}, NSPBE: {=u64}, NSPB: {=u64}, SPD32: {=u64}, SDD: {=u64}, SPME: {=u64}, STE: {=u64}, TTRF: {=u64}, EDAD: {=u64}, EPMAD: {=u64}, ETAD: {=u64}, SCCD: {=u64}, NSTB: {=u64}, NSTBE: {=u64}, TDCC: {=u64}, MTPME: {=u64}, PMSSE: {=u64}, SBRBE: {=u64}, MCCD: {=u64}, MPMX: {=u64}, EnPMSN: {=u64}, E3BREW: {=u64}, E3BREC: {=u64}, EnTB2: {=u64}, PMEE: {=u64}, EnPMS3: {=u64}, EBWE: {=u64}, EnPMSS: {=u64}, EPMSSAD: {=u64}, EnITE: {=u64}, ETBAD: {=u64}, EnSTEPOP: {=u64}, PMSEE: {=u64}, TRBEE: {=u64}, EnPMS4: {=u64} }}",
+            Self::RLTE_FIELD.extract(self.bits()),
+            Self::EPMADE_FIELD.extract(self.bits()),
+            Self::ETADE_FIELD.extract(self.bits()),
+            Self::EDADE_FIELD.extract(self.bits()),
+            Self::TPM_FIELD.extract(self.bits()),
+            Self::ENPM2_FIELD.extract(self.bits()),
+            Self::TDA_FIELD.extract(self.bits()),
+            Self::TDOSA_FIELD.extract(self.bits()),
+            Self::NSPBE_FIELD.extract(self.bits()),
+            Self::NSPB_FIELD.extract(self.bits()),
+            Self::SPD32_FIELD.extract(self.bits()),
+            Self::SDD_FIELD.extract(self.bits()),
+            Self::SPME_FIELD.extract(self.bits()),
+            Self::STE_FIELD.extract(self.bits()),
+            Self::TTRF_FIELD.extract(self.bits()),
+            Self::EDAD_FIELD.extract(self.bits()),
+            Self::EPMAD_FIELD.extract(self.bits()),
+            Self::ETAD_FIELD.extract(self.bits()),
+            Self::SCCD_FIELD.extract(self.bits()),
+            Self::NSTB_FIELD.extract(self.bits()),
+            Self::NSTBE_FIELD.extract(self.bits()),
+            Self::TDCC_FIELD.extract(self.bits()),
+            Self::MTPME_FIELD.extract(self.bits()),
+            Self::PMSSE_FIELD.extract(self.bits()),
+            Self::SBRBE_FIELD.extract(self.bits()),
+            Self::MCCD_FIELD.extract(self.bits()),
+            Self::MPMX_FIELD.extract(self.bits()),
+            Self::ENPMSN_FIELD.extract(self.bits()),
+            Self::E3BREW_FIELD.extract(self.bits()),
+            Self::E3BREC_FIELD.extract(self.bits()),
+            Self::ENTB2_FIELD.extract(self.bits()),
+            Self::PMEE_FIELD.extract(self.bits()),
+            Self::ENPMS3_FIELD.extract(self.bits()),
+            Self::EBWE_FIELD.extract(self.bits()),
+            Self::ENPMSS_FIELD.extract(self.bits()),
+            Self::EPMSSAD_FIELD.extract(self.bits()),
+            Self::ENITE_FIELD.extract(self.bits()),
+            Self::ETBAD_FIELD.extract(self.bits()),
+            Self::ENSTEPOP_FIELD.extract(self.bits()),
+            Self::PMSEE_FIELD.extract(self.bits()),
+            Self::TRBEE_FIELD.extract(self.bits()),
+            Self::ENPMS4_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el3")]
+impl serde::Serialize for MdcrEl3 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el3")]
+impl<'de> serde::Deserialize<'de> for MdcrEl3 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el3")]
+impl MdcrEl3 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("RLTE"),
+                old: Self::RLTE_FIELD.extract(self.bits()),
+                new: Self::RLTE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EPMADE"),
+                old: Self::EPMADE_FIELD.extract(self.bits()),
+                new: Self::EPMADE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ETADE"),
+                old: Self::ETADE_FIELD.extract(self.bits()),
+                new: Self::ETADE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EDADE"),
+                old: Self::EDADE_FIELD.extract(self.bits()),
+                new: Self::EDADE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TPM"),
+                old: Self::TPM_FIELD.extract(self.bits()),
+                new: Self::TPM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnPM2"),
+                old: Self::ENPM2_FIELD.extract(self.bits()),
+                new: Self::ENPM2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TDA"),
+                old: Self::TDA_FIELD.extract(self.bits()),
+                new: Self::TDA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TDOSA"),
+                old: Self::TDOSA_FIELD.extract(self.bits()),
+                new: Self::TDOSA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("NSPBE"),
+                old: Self::NSPBE_FIELD.extract(self.bits()),
+                new: Self::NSPBE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("NSPB"),
+                old: Self::NSPB_FIELD.extract(self.bits()),
+                new: Self::NSPB_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SPD32"),
+                old: Self::SPD32_FIELD.extract(self.bits()),
+                new: Self::SPD32_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SDD"),
+                old: Self::SDD_FIELD.extract(self.bits()),
+                new: Self::SDD_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SPME"),
+                old: Self::SPME_FIELD.extract(self.bits()),
+                new: Self::SPME_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("STE"),
+                old: Self::STE_FIELD.extract(self.bits()),
+                new: Self::STE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TTRF"),
+                old: Self::TTRF_FIELD.extract(self.bits()),
+                new: Self::TTRF_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EDAD"),
+                old: Self::EDAD_FIELD.extract(self.bits()),
+                new: Self::EDAD_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EPMAD"),
+                old: Self::EPMAD_FIELD.extract(self.bits()),
+                new: Self::EPMAD_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ETAD"),
+                old: Self::ETAD_FIELD.extract(self.bits()),
+                new: Self::ETAD_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SCCD"),
+                old: Self::SCCD_FIELD.extract(self.bits()),
+                new: Self::SCCD_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("NSTB"),
+                old: Self::NSTB_FIELD.extract(self.bits()),
+                new: Self::NSTB_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("NSTBE"),
+                old: Self::NSTBE_FIELD.extract(self.bits()),
+                new: Self::NSTBE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TDCC"),
+                old: Self::TDCC_FIELD.extract(self.bits()),
+                new: Self::TDCC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("MTPME"),
+                old: Self::MTPME_FIELD.extract(self.bits()),
+                new: Self::MTPME_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMSSE"),
+                old: Self::PMSSE_FIELD.extract(self.bits()),
+                new: Self::PMSSE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SBRBE"),
+                old: Self::SBRBE_FIELD.extract(self.bits()),
+                new: Self::SBRBE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("MCCD"),
+                old: Self::MCCD_FIELD.extract(self.bits()),
+                new: Self::MCCD_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("MPMX"),
+                old: Self::MPMX_FIELD.extract(self.bits()),
+                new: Self::MPMX_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnPMSN"),
+                old: Self::ENPMSN_FIELD.extract(self.bits()),
+                new: Self::ENPMSN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("E3BREW"),
+                old: Self::E3BREW_FIELD.extract(self.bits()),
+                new: Self::E3BREW_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("E3BREC"),
+                old: Self::E3BREC_FIELD.extract(self.bits()),
+                new: Self::E3BREC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnTB2"),
+                old: Self::ENTB2_FIELD.extract(self.bits()),
+                new: Self::ENTB2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMEE"),
+                old: Self::PMEE_FIELD.extract(self.bits()),
+                new: Self::PMEE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnPMS3"),
+                old: Self::ENPMS3_FIELD.extract(self.bits()),
+                new: Self::ENPMS3_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EBWE"),
+                old: Self::EBWE_FIELD.extract(self.bits()),
+                new: Self::EBWE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnPMSS"),
+                old: Self::ENPMSS_FIELD.extract(self.bits()),
+                new: Self::ENPMSS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EPMSSAD"),
+                old: Self::EPMSSAD_FIELD.extract(self.bits()),
+                new: Self::EPMSSAD_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnITE"),
+                old: Self::ENITE_FIELD.extract(self.bits()),
+                new: Self::ENITE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ETBAD"),
+                old: Self::ETBAD_FIELD.extract(self.bits()),
+                new: Self::ETBAD_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnSTEPOP"),
+                old: Self::ENSTEPOP_FIELD.extract(self.bits()),
+                new: Self::ENSTEPOP_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMSEE"),
+                old: Self::PMSEE_FIELD.extract(self.bits()),
+                new: Self::PMSEE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TRBEE"),
+                old: Self::TRBEE_FIELD.extract(self.bits()),
+                new: Self::TRBEE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnPMS4"),
+                old: Self::ENPMS4_FIELD.extract(self.bits()),
+                new: Self::ENPMS4_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0010_0000_0000_0000_0000_0001_0010_0010,
+                new: other.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0010_0000_0000_0000_0000_0001_0010_0010,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el3")]
+impl MdcrEl3 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el3")]
+impl MdcrEl3 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `MDSCR_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct MdscrEl1: u64 {
+        /// `SS` bit.
+        const SS = 1 << 0;
+        /// `ERR` bit.
+        const ERR = 1 << 6;
+        /// `TDCC` bit.
+        const TDCC = 1 << 12;
+        /// `KDE` bit.
+        const KDE = 1 << 13;
+        /// `HDE` bit.
+        const HDE = 1 << 14;
+        /// `MDE` bit.
+        const MDE = 1 << 15;
+        /// `SC2` bit.
+        const SC2 = 1 << 19;
+        /// `TDA` bit.
+        const TDA = 1 << 21;
+        /// `TXU` bit.
+        const TXU = 1 << 26;
+        /// `RXO` bit.
+        const RXO = 1 << 27;
+        /// `TXfull` bit.
+        const TXFULL = 1 << 29;
+        /// `RXfull` bit.
+        const RXFULL = 1 << 30;
+        /// `TFO` bit.
+        const TFO = 1 << 31;
+        /// `EMBWE` bit.
+        const EMBWE = 1 << 32;
+        /// `TTA` bit.
+        const TTA = 1 << 33;
+        /// `EnSPM` bit.
+        const ENSPM = 1 << 34;
+        /// `EHBWE` bit.
+        const EHBWE = 1 << 35;
+        /// `EnSTEPOP` bit.
+        const ENSTEPOP = 1 << 50;
+    }
+}
+
+#[cfg(feature = "el1")]
+impl MdscrEl1 {
+    /// Descriptor of the `SS` field, for use with [`FieldAccess`].
+    pub const SS_FIELD: Field<Self, bool, 0, 1> = Field::new("MDSCR_EL1", "SS");
+    /// Descriptor of the `ERR` field, for use with [`FieldAccess`].
+    pub const ERR_FIELD: Field<Self, bool, 6, 1> = Field::new("MDSCR_EL1", "ERR");
+    /// Descriptor of the `TDCC` field, for use with [`FieldAccess`].
+    pub const TDCC_FIELD: Field<Self, bool, 12, 1> = Field::new("MDSCR_EL1", "TDCC");
+    /// Descriptor of the `KDE` field, for use with [`FieldAccess`].
+    pub const KDE_FIELD: Field<Self, bool, 13, 1> = Field::new("MDSCR_EL1", "KDE");
+    /// Descriptor of the `HDE` field, for use with [`FieldAccess`].
+    pub const HDE_FIELD: Field<Self, bool, 14, 1> = Field::new("MDSCR_EL1", "HDE");
+    /// Descriptor of the `MDE` field, for use with [`FieldAccess`].
+    pub const MDE_FIELD: Field<Self, bool, 15, 1> = Field::new("MDSCR_EL1", "MDE");
+    /// Descriptor of the `SC2` field, for use with [`FieldAccess`].
+    pub const SC2_FIELD: Field<Self, bool, 19, 1> = Field::new("MDSCR_EL1", "SC2");
+    /// Descriptor of the `TDA` field, for use with [`FieldAccess`].
+    pub const TDA_FIELD: Field<Self, bool, 21, 1> = Field::new("MDSCR_EL1", "TDA");
+    /// Descriptor of the `INTdis` field, for use with [`FieldAccess`].
+    pub const INTDIS_FIELD: Field<Self, u8, 22, 2> = Field::new("MDSCR_EL1", "INTdis");
+    /// Descriptor of the `TXU` field, for use with [`FieldAccess`].
+    pub const TXU_FIELD: Field<Self, bool, 26, 1> = Field::new("MDSCR_EL1", "TXU");
+    /// Descriptor of the `RXO` field, for use with [`FieldAccess`].
+    pub const RXO_FIELD: Field<Self, bool, 27, 1> = Field::new("MDSCR_EL1", "RXO");
+    /// Descriptor of the `TXfull` field, for use with [`FieldAccess`].
+    pub const TXFULL_FIELD: Field<Self, bool, 29, 1> = Field::new("MDSCR_EL1", "TXfull");
+    /// Descriptor of the `RXfull` field, for use with [`FieldAccess`].
+    pub const RXFULL_FIELD: Field<Self, bool, 30, 1> = Field::new("MDSCR_EL1", "RXfull");
+    /// Descriptor of the `TFO` field, for use with [`FieldAccess`].
+    pub const TFO_FIELD: Field<Self, bool, 31, 1> = Field::new("MDSCR_EL1", "TFO");
+    /// Descriptor of the `EMBWE` field, for use with [`FieldAccess`].
+    pub const EMBWE_FIELD: Field<Self, bool, 32, 1> = Field::new("MDSCR_EL1", "EMBWE");
+    /// Descriptor of the `TTA` field, for use with [`FieldAccess`].
+    pub const TTA_FIELD: Field<Self, bool, 33, 1> = Field::new("MDSCR_EL1", "TTA");
+    /// Descriptor of the `EnSPM` field, for use with [`FieldAccess`].
+    pub const ENSPM_FIELD: Field<Self, bool, 34, 1> = Field::new("MDSCR_EL1", "EnSPM");
+    /// Descriptor of the `EHBWE` field, for use with [`FieldAccess`].
+    pub const EHBWE_FIELD: Field<Self, bool, 35, 1> = Field::new("MDSCR_EL1", "EHBWE");
+    /// Descriptor of the `EnSTEPOP` field, for use with [`FieldAccess`].
+    pub const ENSTEPOP_FIELD: Field<Self, bool, 50, 1> = Field::new("MDSCR_EL1", "EnSTEPOP");
+
+    /// Returns the value of the `INTdis` field.
+    pub const fn intdis(self) -> u8 {
+        Self::INTDIS_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `INTdis` field.
+    pub const fn set_intdis(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::INTDIS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `INTdis` field set to the given value.
+    pub const fn with_intdis(mut self, value: u8) -> Self {
+        self.set_intdis(value);
+        self
+    }
+
+    /// Sets the value of the `INTdis` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_intdis(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_intdis(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `INTdis` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_intdis(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::INTDIS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for MdscrEl1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("MdscrEl1");
+        let bits = self.bits();
+        s.field("SS", &Self::SS_FIELD.extract(bits));
+        s.field("ERR", &Self::ERR_FIELD.extract(bits));
+        s.field("TDCC", &Self::TDCC_FIELD.extract(bits));
+        s.field("KDE", &Self::KDE_FIELD.extract(bits));
+        s.field("HDE", &Self::HDE_FIELD.extract(bits));
+        s.field("MDE", &Self::MDE_FIELD.extract(bits));
+        s.field("SC2", &Self::SC2_FIELD.extract(bits));
+        s.field("TDA", &Self::TDA_FIELD.extract(bits));
+        s.field("INTdis", &Self::INTDIS_FIELD.extract(bits));
+        s.field("TXU", &Self::TXU_FIELD.extract(bits));
+        s.field("RXO", &Self::RXO_FIELD.extract(bits));
+        s.field("TXfull", &Self::TXFULL_FIELD.extract(bits));
+        s.field("RXfull", &Self::RXFULL_FIELD.extract(bits));
+        s.field("TFO", &Self::TFO_FIELD.extract(bits));
+        s.field("EMBWE", &Self::EMBWE_FIELD.extract(bits));
+        s.field("TTA", &Self::TTA_FIELD.extract(bits));
+        s.field("EnSPM", &Self::ENSPM_FIELD.extract(bits));
+        s.field("EHBWE", &Self::EHBWE_FIELD.extract(bits));
+        s.field("EnSTEPOP", &Self::ENSTEPOP_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for MdscrEl1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "MdscrEl1 {{ SS: {=u64}, ERR: {=u64}, TDCC: {=u64}, KDE: {=u64}, HDE: {=u64}, MDE: {=u64}, SC2: {=u64}, TDA: {=u64}, INTdis: {=u64}, TXU: {=u64}, RXO: {=u64}, TXfull: {=u64}, RXfull: {=u64}, TFO: {=u64}, EMBWE: {=u64}, TTA: {=u64}, EnSPM: {=u64}, EHBWE: {=u64}, EnSTEPOP: {=u64} }}",
+            Self::SS_FIELD.extract(self.bits()),
+            Self::ERR_FIELD.extract(self.bits()),
+            Self::TDCC_FIELD.extract(self.bits()),
+            Self::KDE_FIELD.extract(self.bits()),
+            Self::HDE_FIELD.extract(self.bits()),
+            Self::MDE_FIELD.extract(self.bits()),
+            Self::SC2_FIELD.extract(self.bits()),
+            Self::TDA_FIELD.extract(self.bits()),
+            Self::INTDIS_FIELD.extract(self.bits()),
+            Self::TXU_FIELD.extract(self.bits()),
+            Self::RXO_FIELD.extract(self.bits()),
+            Self::TXFULL_FIELD.extract(self.bits()),
+            Self::RXFULL_FIELD.extract(self.bits()),
+            Self::TFO_FIELD.extract(self.bits()),
+            Self::EMBWE_FIELD.extract(self.bits()),
+            Self::TTA_FIELD.extract(self.bits()),
+            Self::ENSPM_FIELD.extract(self.bits()),
+            Self::EHBWE_FIELD.extract(self.bits()),
+            Self::ENSTEPOP_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for MdscrEl1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for MdscrEl1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl MdscrEl1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("SS"),
+                old: Self::SS_FIELD.extract(self.bits()),
+                new: Self::SS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ERR"),
+                old: Self::ERR_FIELD.extract(self.bits()),
+                new: Self::ERR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TDCC"),
+                old: Self::TDCC_FIELD.extract(self.bits()),
+                new: Self::TDCC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("KDE"),
+                old: Self::KDE_FIELD.extract(self.bits()),
+                new: Self::KDE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HDE"),
+                old: Self::HDE_FIELD.extract(self.bits()),
+                new: Self::HDE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("MDE"),
+                old: Self::MDE_FIELD.extract(self.bits()),
+                new: Self::MDE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SC2"),
+                old: Self::SC2_FIELD.extract(self.bits()),
+                new: Self::SC2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TDA"),
+                old: Self::TDA_FIELD.extract(self.bits()),
+                new: Self::TDA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("INTdis"),
+                old: Self::INTDIS_FIELD.extract(self.bits()),
+                new: Self::INTDIS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TXU"),
+                old: Self::TXU_FIELD.extract(self.bits()),
+                new: Self::TXU_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("RXO"),
+                old: Self::RXO_FIELD.extract(self.bits()),
+                new: Self::RXO_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TXfull"),
+                old: Self::TXFULL_FIELD.extract(self.bits()),
+                new: Self::TXFULL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("RXfull"),
+                old: Self::RXFULL_FIELD.extract(self.bits()),
+                new: Self::RXFULL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TFO"),
+                old: Self::TFO_FIELD.extract(self.bits()),
+                new: Self::TFO_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EMBWE"),
+                old: Self::EMBWE_FIELD.extract(self.bits()),
+                new: Self::EMBWE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TTA"),
+                old: Self::TTA_FIELD.extract(self.bits()),
+                new: Self::TTA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnSPM"),
+                old: Self::ENSPM_FIELD.extract(self.bits()),
+                new: Self::ENSPM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EHBWE"),
+                old: Self::EHBWE_FIELD.extract(self.bits()),
+                new: Self::EHBWE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnSTEPOP"),
+                old: Self::ENSTEPOP_FIELD.extract(self.bits()),
+                new: Self::ENSTEPOP_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1011_1111_1111_1111_0000_0001_0011_0001_0111_0000_1111_1011_1110,
+                new: other.bits() & 0b1111_1111_1111_1011_1111_1111_1111_0000_0001_0011_0001_0111_0000_1111_1011_1110,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl MdscrEl1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl MdscrEl1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `BRBCR_EL2` system register.
+    brbcr_el2: s2_1_c9_c0_0, u64: BrbcrEl2, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_write_sysreg!(mdccint_el1, u64: MdccintEl1, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg!(mdcr_el2, u64: MdcrEl2, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
+read_write_sysreg!(mdcr_el3, u64: MdcrEl3, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_write_sysreg!(mdscr_el1, u64: MdscrEl1, safe_read, safe_write, fake::SYSREGS);
@@ -0,0 +1,14567 @@
+// SPDX-FileCopyrightText: Copyright The arm-sysregs Contributors.
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Exception handling system registers.
+
+// This file is generated, do not edit manually.
+
+// Depending on the enabled features, the module might not use all of these imports.
+#![allow(unused_imports)]
+
+use crate::*;
+use bitflags::bitflags;
+use core::fmt::{self, Display, Formatter};
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `ELR_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct ElrEl1: u64 {
+    }
+}
+
+#[cfg(feature = "el1")]
+impl ElrEl1 {
+    /// Descriptor of the `ADDR` field, for use with [`FieldAccess`].
+    pub const ADDR_FIELD: Field<Self, u64, 0, 64> = Field::new("ELR_EL1", "ADDR");
+
+    /// Returns the value of the `ADDR` field.
+    pub const fn addr(self) -> u64 {
+        Self::ADDR_FIELD.extract(self.bits())
+    }
+
+    /// Sets the value of the `ADDR` field.
+    pub const fn set_addr(&mut self, value: u64) {
+        *self = Self::from_bits_retain(Self::ADDR_FIELD.insert(self.bits(), value));
+    }
+
+    /// Returns a copy with the `ADDR` field set to the given value.
+    pub const fn with_addr(mut self, value: u64) -> Self {
+        self.set_addr(value);
+        self
+    }
+
+    /// Sets the value of the `ADDR` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_addr(&mut self, value: u64) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_addr(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ADDR` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_addr(self, value: u64) -> Result<Self, FieldOverflow> {
+        match Self::ADDR_FIELD.try_insert(self.bits(), value) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for ElrEl1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("ElrEl1");
+        let bits = self.bits();
+        s.field(
+            "ADDR",
+            &format_args!("{:#x}", Self::ADDR_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for ElrEl1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "ElrEl1 {{ ADDR: {=u64:#x} }}",
+            Self::ADDR_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for ElrEl1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for ElrEl1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl ElrEl1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [FieldChange {
+            register,
+            field: Some("ADDR"),
+            old: Self::ADDR_FIELD.extract(self.bits()),
+            new: Self::ADDR_FIELD.extract(other.bits()),
+        }]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl ElrEl1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl ElrEl1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `ELR_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct ElrEl2: u64 {
+    }
+}
+
+#[cfg(feature = "el2")]
+impl ElrEl2 {
+    /// Descriptor of the `ADDR` field, for use with [`FieldAccess`].
+    pub const ADDR_FIELD: Field<Self, u64, 0, 64> = Field::new("ELR_EL2", "ADDR");
+
+    /// Returns the value of the `ADDR` field.
+    pub const fn addr(self) -> u64 {
+        Self::ADDR_FIELD.extract(self.bits())
+    }
+
+    /// Sets the value of the `ADDR` field.
+    pub const fn set_addr(&mut self, value: u64) {
+        *self = Self::from_bits_retain(Self::ADDR_FIELD.insert(self.bits(), value));
+    }
+
+    /// Returns a copy with the `ADDR` field set to the given value.
+    pub const fn with_addr(mut self, value: u64) -> Self {
+        self.set_addr(value);
+        self
+    }
+
+    /// Sets the value of the `ADDR` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_addr(&mut self, value: u64) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_addr(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ADDR` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_addr(self, value: u64) -> Result<Self, FieldOverflow> {
+        match Self::ADDR_FIELD.try_insert(self.bits(), value) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Display for ElrEl2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("ElrEl2");
+        let bits = self.bits();
+        s.field(
+            "ADDR",
+            &format_args!("{:#x}", Self::ADDR_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for ElrEl2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "ElrEl2 {{ ADDR: {=u64:#x} }}",
+            Self::ADDR_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for ElrEl2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for ElrEl2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl ElrEl2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [FieldChange {
+            register,
+            field: Some("ADDR"),
+            old: Self::ADDR_FIELD.extract(self.bits()),
+            new: Self::ADDR_FIELD.extract(other.bits()),
+        }]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl ElrEl2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl ElrEl2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `ESR_EL1` system register value.
+    #[derive(Clone, Copy, Eq, Default, PartialEq)]
+    #[repr(transparent)]
+    pub struct EsrEl1: u64 {
+        /// `IL` bit.
+        const IL = 1 << 25;
+    }
+}
+
+#[cfg(feature = "el1")]
+impl EsrEl1 {
+    /// Descriptor of the `ISS` field, for use with [`FieldAccess`].
+    pub const ISS_FIELD: Field<Self, u32, 0, 25> = Field::new("ESR_EL1", "ISS");
+    /// Descriptor of the `IL` field, for use with [`FieldAccess`].
+    pub const IL_FIELD: Field<Self, bool, 25, 1> = Field::new("ESR_EL1", "IL");
+    /// Descriptor of the `EC` field, for use with [`FieldAccess`].
+    pub const EC_FIELD: Field<Self, u8, 26, 6> = Field::new("ESR_EL1", "EC");
+    /// Descriptor of the `ISS2` field, for use with [`FieldAccess`].
+    pub const ISS2_FIELD: Field<Self, u32, 32, 24> = Field::new("ESR_EL1", "ISS2");
+
+    /// Returns the value of the `ISS` field.
+    pub const fn iss(self) -> u32 {
+        Self::ISS_FIELD.extract(self.bits()) as u32
+    }
+
+    /// Sets the value of the `ISS` field.
+    pub const fn set_iss(&mut self, value: u32) {
+        *self = Self::from_bits_retain(Self::ISS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ISS` field set to the given value.
+    pub const fn with_iss(mut self, value: u32) -> Self {
+        self.set_iss(value);
+        self
+    }
+
+    /// Sets the value of the `ISS` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_iss(&mut self, value: u32) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_iss(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ISS` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_iss(self, value: u32) -> Result<Self, FieldOverflow> {
+        match Self::ISS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `EC` field.
+    pub const fn ec(self) -> u8 {
+        Self::EC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `EC` field.
+    pub const fn set_ec(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::EC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `EC` field set to the given value.
+    pub const fn with_ec(mut self, value: u8) -> Self {
+        self.set_ec(value);
+        self
+    }
+
+    /// Sets the value of the `EC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ec(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ec(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `EC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ec(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::EC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ISS2` field.
+    pub const fn iss2(self) -> u32 {
+        Self::ISS2_FIELD.extract(self.bits()) as u32
+    }
+
+    /// Sets the value of the `ISS2` field.
+    pub const fn set_iss2(&mut self, value: u32) {
+        *self = Self::from_bits_retain(Self::ISS2_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value.
+    pub const fn with_iss2(mut self, value: u32) -> Self {
+        self.set_iss2(value);
+        self
+    }
+
+    /// Sets the value of the `ISS2` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_iss2(&mut self, value: u32) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_iss2(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_iss2(self, value: u32) -> Result<Self, FieldOverflow> {
+        match Self::ISS2_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for EsrEl1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("EsrEl1");
+        let bits = self.bits();
+        s.field("ISS", &format_args!("{:#x}", Self::ISS_FIELD.extract(bits)));
+        s.field("IL", &Self::IL_FIELD.extract(bits));
+        s.field("EC", &Self::EC_FIELD.extract(bits));
+        s.field(
+            "ISS2",
+            &format_args!("{:#x}", Self::ISS2_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for EsrEl1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "EsrEl1 {{ ISS: {=u64:#x}, IL: {=u64}, EC: {=u64}, ISS2: {=u64:#x} }}",
+            Self::ISS_FIELD.extract(self.bits()),
+            Self::IL_FIELD.extract(self.bits()),
+            Self::EC_FIELD.extract(self.bits()),
+            Self::ISS2_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for EsrEl1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for EsrEl1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl EsrEl1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("ISS"),
+                old: Self::ISS_FIELD.extract(self.bits()),
+                new: Self::ISS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IL"),
+                old: Self::IL_FIELD.extract(self.bits()),
+                new: Self::IL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EC"),
+                old: Self::EC_FIELD.extract(self.bits()),
+                new: Self::EC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ISS2"),
+                old: Self::ISS2_FIELD.extract(self.bits()),
+                new: Self::ISS2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl EsrEl1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl EsrEl1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `ESR_EL1` system register value, when `EC` is 1.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct EsrEl1Wfx: u64 {
+        /// `RV` bit.
+        const RV = 1 << 2;
+        /// `CV` bit.
+        const CV = 1 << 24;
+        /// `IL` bit.
+        const IL = 1 << 25;
+    }
+}
+
+#[cfg(feature = "el1")]
+impl EsrEl1Wfx {
+    /// Descriptor of the `TI` field, for use with [`FieldAccess`].
+    pub const TI_FIELD: Field<Self, u8, 0, 2> = Field::new("ESR_EL1", "TI");
+    /// Descriptor of the `RV` field, for use with [`FieldAccess`].
+    pub const RV_FIELD: Field<Self, bool, 2, 1> = Field::new("ESR_EL1", "RV");
+    /// Descriptor of the `RN` field, for use with [`FieldAccess`].
+    pub const RN_FIELD: Field<Self, u8, 5, 5> = Field::new("ESR_EL1", "RN");
+    /// Descriptor of the `COND` field, for use with [`FieldAccess`].
+    pub const COND_FIELD: Field<Self, u8, 20, 4> = Field::new("ESR_EL1", "COND");
+    /// Descriptor of the `CV` field, for use with [`FieldAccess`].
+    pub const CV_FIELD: Field<Self, bool, 24, 1> = Field::new("ESR_EL1", "CV");
+    /// Descriptor of the `IL` field, for use with [`FieldAccess`].
+    pub const IL_FIELD: Field<Self, bool, 25, 1> = Field::new("ESR_EL1", "IL");
+    /// Descriptor of the `EC` field, for use with [`FieldAccess`].
+    pub const EC_FIELD: Field<Self, u8, 26, 6> = Field::new("ESR_EL1", "EC");
+    /// Descriptor of the `ISS2` field, for use with [`FieldAccess`].
+    pub const ISS2_FIELD: Field<Self, u32, 32, 24> = Field::new("ESR_EL1", "ISS2");
+
+    /// Returns the value of the `TI` field.
+    pub const fn ti(self) -> u8 {
+        Self::TI_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `TI` field.
+    pub const fn set_ti(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::TI_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `TI` field set to the given value.
+    pub const fn with_ti(mut self, value: u8) -> Self {
+        self.set_ti(value);
+        self
+    }
+
+    /// Sets the value of the `TI` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ti(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ti(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `TI` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ti(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::TI_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `RN` field.
+    pub const fn rn(self) -> u8 {
+        Self::RN_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `RN` field.
+    pub const fn set_rn(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::RN_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `RN` field set to the given value.
+    pub const fn with_rn(mut self, value: u8) -> Self {
+        self.set_rn(value);
+        self
+    }
+
+    /// Sets the value of the `RN` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_rn(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_rn(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `RN` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_rn(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::RN_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `COND` field.
+    pub const fn cond(self) -> u8 {
+        Self::COND_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `COND` field.
+    pub const fn set_cond(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::COND_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `COND` field set to the given value.
+    pub const fn with_cond(mut self, value: u8) -> Self {
+        self.set_cond(value);
+        self
+    }
+
+    /// Sets the value of the `COND` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_cond(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_cond(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `COND` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_cond(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::COND_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `EC` field.
+    pub const fn ec(self) -> u8 {
+        Self::EC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `EC` field.
+    pub const fn set_ec(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::EC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `EC` field set to the given value.
+    pub const fn with_ec(mut self, value: u8) -> Self {
+        self.set_ec(value);
+        self
+    }
+
+    /// Sets the value of the `EC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ec(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ec(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `EC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ec(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::EC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ISS2` field.
+    pub const fn iss2(self) -> u32 {
+        Self::ISS2_FIELD.extract(self.bits()) as u32
+    }
+
+    /// Sets the value of the `ISS2` field.
+    pub const fn set_iss2(&mut self, value: u32) {
+        *self = Self::from_bits_retain(Self::ISS2_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value.
+    pub const fn with_iss2(mut self, value: u32) -> Self {
+        self.set_iss2(value);
+        self
+    }
+
+    /// Sets the value of the `ISS2` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_iss2(&mut self, value: u32) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_iss2(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_iss2(self, value: u32) -> Result<Self, FieldOverflow> {
+        match Self::ISS2_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for EsrEl1Wfx {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("EsrEl1Wfx");
+        let bits = self.bits();
+        s.field("TI", &Self::TI_FIELD.extract(bits));
+        s.field("RV", &Self::RV_FIELD.extract(bits));
+        s.field("RN", &Self::RN_FIELD.extract(bits));
+        s.field("COND", &Self::COND_FIELD.extract(bits));
+        s.field("CV", &Self::CV_FIELD.extract(bits));
+        s.field("IL", &Self::IL_FIELD.extract(bits));
+        s.field("EC", &Self::EC_FIELD.extract(bits));
+        s.field(
+            "ISS2",
+            &format_args!("{:#x}", Self::ISS2_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for EsrEl1Wfx {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "EsrEl1Wfx {{ TI: {=u64}, RV: {=u64}, RN: {=u64}, COND: {=u64}, CV: {=u64}, IL: {=u64}, EC: {=u64}, ISS2: {=u64:#x} }}",
+            Self::TI_FIELD.extract(self.bits()),
+            Self::RV_FIELD.extract(self.bits()),
+            Self::RN_FIELD.extract(self.bits()),
+            Self::COND_FIELD.extract(self.bits()),
+            Self::CV_FIELD.extract(self.bits()),
+            Self::IL_FIELD.extract(self.bits()),
+            Self::EC_FIELD.extract(self.bits()),
+            Self::ISS2_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for EsrEl1Wfx {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for EsrEl1Wfx {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl EsrEl1Wfx {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("TI"),
+                old: Self::TI_FIELD.extract(self.bits()),
+                new: Self::TI_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("RV"),
+                old: Self::RV_FIELD.extract(self.bits()),
+                new: Self::RV_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("RN"),
+                old: Self::RN_FIELD.extract(self.bits()),
+                new: Self::RN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("COND"),
+                old: Self::COND_FIELD.extract(self.bits()),
+                new: Self::COND_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CV"),
+                old: Self::CV_FIELD.extract(self.bits()),
+                new: Self::CV_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IL"),
+                old: Self::IL_FIELD.extract(self.bits()),
+                new: Self::IL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EC"),
+                old: Self::EC_FIELD.extract(self.bits()),
+                new: Self::EC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ISS2"),
+                old: Self::ISS2_FIELD.extract(self.bits()),
+                new: Self::ISS2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0000_0000_1111_1111_1100_0001_1000,
+                new: other.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0000_0000_1111_1111_1100_0001_1000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl EsrEl1Wfx {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl EsrEl1Wfx {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `ESR_EL1` system register value, when `EC` is 21.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct EsrEl1Svc: u64 {
+        /// `IL` bit.
+        const IL = 1 << 25;
+    }
+}
+
+#[cfg(feature = "el1")]
+impl EsrEl1Svc {
+    /// Descriptor of the `imm16` field, for use with [`FieldAccess`].
+    pub const IMM16_FIELD: Field<Self, u16, 0, 16> = Field::new("ESR_EL1", "imm16");
+    /// Descriptor of the `IL` field, for use with [`FieldAccess`].
+    pub const IL_FIELD: Field<Self, bool, 25, 1> = Field::new("ESR_EL1", "IL");
+    /// Descriptor of the `EC` field, for use with [`FieldAccess`].
+    pub const EC_FIELD: Field<Self, u8, 26, 6> = Field::new("ESR_EL1", "EC");
+    /// Descriptor of the `ISS2` field, for use with [`FieldAccess`].
+    pub const ISS2_FIELD: Field<Self, u32, 32, 24> = Field::new("ESR_EL1", "ISS2");
+
+    /// Returns the value of the `imm16` field.
+    pub const fn imm16(self) -> u16 {
+        Self::IMM16_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `imm16` field.
+    pub const fn set_imm16(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::IMM16_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `imm16` field set to the given value.
+    pub const fn with_imm16(mut self, value: u16) -> Self {
+        self.set_imm16(value);
+        self
+    }
+
+    /// Sets the value of the `imm16` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_imm16(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_imm16(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `imm16` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_imm16(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::IMM16_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `EC` field.
+    pub const fn ec(self) -> u8 {
+        Self::EC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `EC` field.
+    pub const fn set_ec(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::EC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `EC` field set to the given value.
+    pub const fn with_ec(mut self, value: u8) -> Self {
+        self.set_ec(value);
+        self
+    }
+
+    /// Sets the value of the `EC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ec(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ec(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `EC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ec(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::EC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ISS2` field.
+    pub const fn iss2(self) -> u32 {
+        Self::ISS2_FIELD.extract(self.bits()) as u32
+    }
+
+    /// Sets the value of the `ISS2` field.
+    pub const fn set_iss2(&mut self, value: u32) {
+        *self = Self::from_bits_retain(Self::ISS2_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value.
+    pub const fn with_iss2(mut self, value: u32) -> Self {
+        self.set_iss2(value);
+        self
+    }
+
+    /// Sets the value of the `ISS2` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_iss2(&mut self, value: u32) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_iss2(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_iss2(self, value: u32) -> Result<Self, FieldOverflow> {
+        match Self::ISS2_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for EsrEl1Svc {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("EsrEl1Svc");
+        let bits = self.bits();
+        s.field(
+            "imm16",
+            &format_args!("{:#x}", Self::IMM16_FIELD.extract(bits)),
+        );
+        s.field("IL", &Self::IL_FIELD.extract(bits));
+        s.field("EC", &Self::EC_FIELD.extract(bits));
+        s.field(
+            "ISS2",
+            &format_args!("{:#x}", Self::ISS2_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for EsrEl1Svc {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "EsrEl1Svc {{ imm16: {=u64:#x}, IL: {=u64}, EC: {=u64}, ISS2: {=u64:#x} }}",
+            Self::IMM16_FIELD.extract(self.bits()),
+            Self::IL_FIELD.extract(self.bits()),
+            Self::EC_FIELD.extract(self.bits()),
+            Self::ISS2_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for EsrEl1Svc {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for EsrEl1Svc {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl EsrEl1Svc {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("imm16"),
+                old: Self::IMM16_FIELD.extract(self.bits()),
+                new: Self::IMM16_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IL"),
+                old: Self::IL_FIELD.extract(self.bits()),
+                new: Self::IL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EC"),
+                old: Self::EC_FIELD.extract(self.bits()),
+                new: Self::EC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ISS2"),
+                old: Self::ISS2_FIELD.extract(self.bits()),
+                new: Self::ISS2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0001_1111_1111_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0001_1111_1111_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl EsrEl1Svc {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl EsrEl1Svc {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `ESR_EL1` system register value, when `EC` is 22.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct EsrEl1Hvc: u64 {
+        /// `IL` bit.
+        const IL = 1 << 25;
+    }
+}
+
+#[cfg(feature = "el1")]
+impl EsrEl1Hvc {
+    /// Descriptor of the `imm16` field, for use with [`FieldAccess`].
+    pub const IMM16_FIELD: Field<Self, u16, 0, 16> = Field::new("ESR_EL1", "imm16");
+    /// Descriptor of the `IL` field, for use with [`FieldAccess`].
+    pub const IL_FIELD: Field<Self, bool, 25, 1> = Field::new("ESR_EL1", "IL");
+    /// Descriptor of the `EC` field, for use with [`FieldAccess`].
+    pub const EC_FIELD: Field<Self, u8, 26, 6> = Field::new("ESR_EL1", "EC");
+    /// Descriptor of the `ISS2` field, for use with [`FieldAccess`].
+    pub const ISS2_FIELD: Field<Self, u32, 32, 24> = Field::new("ESR_EL1", "ISS2");
+
+    /// Returns the value of the `imm16` field.
+    pub const fn imm16(self) -> u16 {
+        Self::IMM16_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `imm16` field.
+    pub const fn set_imm16(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::IMM16_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `imm16` field set to the given value.
+    pub const fn with_imm16(mut self, value: u16) -> Self {
+        self.set_imm16(value);
+        self
+    }
+
+    /// Sets the value of the `imm16` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_imm16(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_imm16(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `imm16` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_imm16(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::IMM16_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `EC` field.
+    pub const fn ec(self) -> u8 {
+        Self::EC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `EC` field.
+    pub const fn set_ec(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::EC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `EC` field set to the given value.
+    pub const fn with_ec(mut self, value: u8) -> Self {
+        self.set_ec(value);
+        self
+    }
+
+    /// Sets the value of the `EC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ec(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ec(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `EC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ec(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::EC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ISS2` field.
+    pub const fn iss2(self) -> u32 {
+        Self::ISS2_FIELD.extract(self.bits()) as u32
+    }
+
+    /// Sets the value of the `ISS2` field.
+    pub const fn set_iss2(&mut self, value: u32) {
+        *self = Self::from_bits_retain(Self::ISS2_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value.
+    pub const fn with_iss2(mut self, value: u32) -> Self {
+        self.set_iss2(value);
+        self
+    }
+
+    /// Sets the value of the `ISS2` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_iss2(&mut self, value: u32) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_iss2(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_iss2(self, value: u32) -> Result<Self, FieldOverflow> {
+        match Self::ISS2_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for EsrEl1Hvc {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("EsrEl1Hvc");
+        let bits = self.bits();
+        s.field(
+            "imm16",
+            &format_args!("{:#x}", Self::IMM16_FIELD.extract(bits)),
+        );
+        s.field("IL", &Self::IL_FIELD.extract(bits));
+        s.field("EC", &Self::EC_FIELD.extract(bits));
+        s.field(
+            "ISS2",
+            &format_args!("{:#x}", Self::ISS2_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for EsrEl1Hvc {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "EsrEl1Hvc {{ imm16: {=u64:#x}, IL: {=u64}, EC: {=u64}, ISS2: {=u64:#x} }}",
+            Self::IMM16_FIELD.extract(self.bits()),
+            Self::IL_FIELD.extract(self.bits()),
+            Self::EC_FIELD.extract(self.bits()),
+            Self::ISS2_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for EsrEl1Hvc {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for EsrEl1Hvc {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl EsrEl1Hvc {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("imm16"),
+                old: Self::IMM16_FIELD.extract(self.bits()),
+                new: Self::IMM16_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IL"),
+                old: Self::IL_FIELD.extract(self.bits()),
+                new: Self::IL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EC"),
+                old: Self::EC_FIELD.extract(self.bits()),
+                new: Self::EC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ISS2"),
+                old: Self::ISS2_FIELD.extract(self.bits()),
+                new: Self::ISS2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0001_1111_1111_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0001_1111_1111_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl EsrEl1Hvc {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl EsrEl1Hvc {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `ESR_EL1` system register value, when `EC` is 23.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct EsrEl1Smc: u64 {
+        /// `IL` bit.
+        const IL = 1 << 25;
+    }
+}
+
+#[cfg(feature = "el1")]
+impl EsrEl1Smc {
+    /// Descriptor of the `imm16` field, for use with [`FieldAccess`].
+    pub const IMM16_FIELD: Field<Self, u16, 0, 16> = Field::new("ESR_EL1", "imm16");
+    /// Descriptor of the `IL` field, for use with [`FieldAccess`].
+    pub const IL_FIELD: Field<Self, bool, 25, 1> = Field::new("ESR_EL1", "IL");
+    /// Descriptor of the `EC` field, for use with [`FieldAccess`].
+    pub const EC_FIELD: Field<Self, u8, 26, 6> = Field::new("ESR_EL1", "EC");
+    /// Descriptor of the `ISS2` field, for use with [`FieldAccess`].
+    pub const ISS2_FIELD: Field<Self, u32, 32, 24> = Field::new("ESR_EL1", "ISS2");
+
+    /// Returns the value of the `imm16` field.
+    pub const fn imm16(self) -> u16 {
+        Self::IMM16_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `imm16` field.
+    pub const fn set_imm16(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::IMM16_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `imm16` field set to the given value.
+    pub const fn with_imm16(mut self, value: u16) -> Self {
+        self.set_imm16(value);
+        self
+    }
+
+    /// Sets the value of the `imm16` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_imm16(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_imm16(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `imm16` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_imm16(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::IMM16_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `EC` field.
+    pub const fn ec(self) -> u8 {
+        Self::EC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `EC` field.
+    pub const fn set_ec(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::EC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `EC` field set to the given value.
+    pub const fn with_ec(mut self, value: u8) -> Self {
+        self.set_ec(value);
+        self
+    }
+
+    /// Sets the value of the `EC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ec(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ec(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `EC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ec(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::EC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ISS2` field.
+    pub const fn iss2(self) -> u32 {
+        Self::ISS2_FIELD.extract(self.bits()) as u32
+    }
+
+    /// Sets the value of the `ISS2` field.
+    pub const fn set_iss2(&mut self, value: u32) {
+        *self = Self::from_bits_retain(Self::ISS2_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value.
+    pub const fn with_iss2(mut self, value: u32) -> Self {
+        self.set_iss2(value);
+        self
+    }
+
+    /// Sets the value of the `ISS2` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_iss2(&mut self, value: u32) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_iss2(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_iss2(self, value: u32) -> Result<Self, FieldOverflow> {
+        match Self::ISS2_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for EsrEl1Smc {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("EsrEl1Smc");
+        let bits = self.bits();
+        s.field(
+            "imm16",
+            &format_args!("{:#x}", Self::IMM16_FIELD.extract(bits)),
+        );
+        s.field("IL", &Self::IL_FIELD.extract(bits));
+        s.field("EC", &Self::EC_FIELD.extract(bits));
+        s.field(
+            "ISS2",
+            &format_args!("{:#x}", Self::ISS2_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for EsrEl1Smc {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "EsrEl1Smc {{ imm16: {=u64:#x}, IL: {=u64}, EC: {=u64}, ISS2: {=u64:#x} }}",
+            Self::IMM16_FIELD.extract(self.bits()),
+            Self::IL_FIELD.extract(self.bits()),
+            Self::EC_FIELD.extract(self.bits()),
+            Self::ISS2_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for EsrEl1Smc {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for EsrEl1Smc {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl EsrEl1Smc {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("imm16"),
+                old: Self::IMM16_FIELD.extract(self.bits()),
+                new: Self::IMM16_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IL"),
+                old: Self::IL_FIELD.extract(self.bits()),
+                new: Self::IL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EC"),
+                old: Self::EC_FIELD.extract(self.bits()),
+                new: Self::EC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ISS2"),
+                old: Self::ISS2_FIELD.extract(self.bits()),
+                new: Self::ISS2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0001_1111_1111_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0001_1111_1111_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl EsrEl1Smc {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl EsrEl1Smc {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `ESR_EL1` system register value, when `EC` is 24.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct EsrEl1Sysreg: u64 {
+        /// `Direction` bit.
+        const DIRECTION = 1 << 0;
+        /// `IL` bit.
+        const IL = 1 << 25;
+    }
+}
+
+#[cfg(feature = "el1")]
+impl EsrEl1Sysreg {
+    /// Descriptor of the `Direction` field, for use with [`FieldAccess`].
+    pub const DIRECTION_FIELD: Field<Self, bool, 0, 1> = Field::new("ESR_EL1", "Direction");
+    /// Descriptor of the `CRm` field, for use with [`FieldAccess`].
+    pub const CRM_FIELD: Field<Self, u8, 1, 4> = Field::new("ESR_EL1", "CRm");
+    /// Descriptor of the `Rt` field, for use with [`FieldAccess`].
+    pub const RT_FIELD: Field<Self, u8, 5, 5> = Field::new("ESR_EL1", "Rt");
+    /// Descriptor of the `CRn` field, for use with [`FieldAccess`].
+    pub const CRN_FIELD: Field<Self, u8, 10, 4> = Field::new("ESR_EL1", "CRn");
+    /// Descriptor of the `Op1` field, for use with [`FieldAccess`].
+    pub const OP1_FIELD: Field<Self, u8, 14, 3> = Field::new("ESR_EL1", "Op1");
+    /// Descriptor of the `Op2` field, for use with [`FieldAccess`].
+    pub const OP2_FIELD: Field<Self, u8, 17, 3> = Field::new("ESR_EL1", "Op2");
+    /// Descriptor of the `Op0` field, for use with [`FieldAccess`].
+    pub const OP0_FIELD: Field<Self, u8, 20, 2> = Field::new("ESR_EL1", "Op0");
+    /// Descriptor of the `IL` field, for use with [`FieldAccess`].
+    pub const IL_FIELD: Field<Self, bool, 25, 1> = Field::new("ESR_EL1", "IL");
+    /// Descriptor of the `EC` field, for use with [`FieldAccess`].
+    pub const EC_FIELD: Field<Self, u8, 26, 6> = Field::new("ESR_EL1", "EC");
+    /// Descriptor of the `ISS2` field, for use with [`FieldAccess`].
+    pub const ISS2_FIELD: Field<Self, u32, 32, 24> = Field::new("ESR_EL1", "ISS2");
+
+    /// Returns the value of the `CRm` field.
+    pub const fn crm(self) -> u8 {
+        Self::CRM_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `CRm` field.
+    pub const fn set_crm(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::CRM_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `CRm` field set to the given value.
+    pub const fn with_crm(mut self, value: u8) -> Self {
+        self.set_crm(value);
+        self
+    }
+
+    /// Sets the value of the `CRm` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_crm(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_crm(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `CRm` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_crm(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::CRM_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `Rt` field.
+    pub const fn rt(self) -> u8 {
+        Self::RT_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `Rt` field.
+    pub const fn set_rt(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::RT_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `Rt` field set to the given value.
+    pub const fn with_rt(mut self, value: u8) -> Self {
+        self.set_rt(value);
+        self
+    }
+
+    /// Sets the value of the `Rt` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_rt(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_rt(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `Rt` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_rt(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::RT_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `CRn` field.
+    pub const fn crn(self) -> u8 {
+        Self::CRN_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `CRn` field.
+    pub const fn set_crn(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::CRN_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `CRn` field set to the given value.
+    pub const fn with_crn(mut self, value: u8) -> Self {
+        self.set_crn(value);
+        self
+    }
+
+    /// Sets the value of the `CRn` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_crn(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_crn(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `CRn` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_crn(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::CRN_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `Op1` field.
+    pub const fn op1(self) -> u8 {
+        Self::OP1_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `Op1` field.
+    pub const fn set_op1(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::OP1_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `Op1` field set to the given value.
+    pub const fn with_op1(mut self, value: u8) -> Self {
+        self.set_op1(value);
+        self
+    }
+
+    /// Sets the value of the `Op1` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_op1(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_op1(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `Op1` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_op1(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::OP1_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `Op2` field.
+    pub const fn op2(self) -> u8 {
+        Self::OP2_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `Op2` field.
+    pub const fn set_op2(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::OP2_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `Op2` field set to the given value.
+    pub const fn with_op2(mut self, value: u8) -> Self {
+        self.set_op2(value);
+        self
+    }
+
+    /// Sets the value of the `Op2` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_op2(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_op2(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `Op2` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_op2(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::OP2_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `Op0` field.
+    pub const fn op0(self) -> u8 {
+        Self::OP0_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `Op0` field.
+    pub const fn set_op0(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::OP0_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `Op0` field set to the given value.
+    pub const fn with_op0(mut self, value: u8) -> Self {
+        self.set_op0(value);
+        self
+    }
+
+    /// Sets the value of the `Op0` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_op0(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_op0(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `Op0` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_op0(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::OP0_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `EC` field.
+    pub const fn ec(self) -> u8 {
+        Self::EC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `EC` field.
+    pub const fn set_ec(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::EC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `EC` field set to the given value.
+    pub const fn with_ec(mut self, value: u8) -> Self {
+        self.set_ec(value);
+        self
+    }
+
+    /// Sets the value of the `EC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ec(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ec(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `EC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ec(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::EC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ISS2` field.
+    pub const fn iss2(self) -> u32 {
+        Self::ISS2_FIELD.extract(self.bits()) as u32
+    }
+
+    /// Sets the value of the `ISS2` field.
+    pub const fn set_iss2(&mut self, value: u32) {
+        *self = Self::from_bits_retain(Self::ISS2_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value.
+    pub const fn with_iss2(mut self, value: u32) -> Self {
+        self.set_iss2(value);
+        self
+    }
+
+    /// Sets the value of the `ISS2` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_iss2(&mut self, value: u32) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_iss2(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_iss2(self, value: u32) -> Result<Self, FieldOverflow> {
+        match Self::ISS2_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for EsrEl1Sysreg {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("EsrEl1Sysreg");
+        let bits = self.bits();
+        s.field("Direction", &Self::DIRECTION_FIELD.extract(bits));
+        s.field("CRm", &Self::CRM_FIELD.extract(bits));
+        s.field("Rt", &Self::RT_FIELD.extract(bits));
+        s.field("CRn", &Self::CRN_FIELD.extract(bits));
+        s.field("Op1", &Self::OP1_FIELD.extract(bits));
+        s.field("Op2", &Self::OP2_FIELD.extract(bits));
+        s.field("Op0", &Self::OP0_FIELD.extract(bits));
+        s.field("IL", &Self::IL_FIELD.extract(bits));
+        s.field("EC", &Self::EC_FIELD.extract(bits));
+        s.field(
+            "ISS2",
+            &format_args!("{:#x}", Self::ISS2_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for EsrEl1Sysreg {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "EsrEl1Sysreg {{ Direction: {=u64}, CRm: {=u64}, Rt: {=u64}, CRn: {=u64}, Op1: {=u64}, Op2: {=u64}, Op0: {=u64}, IL: {=u64}, EC: {=u64}, ISS2: {=u64:#x} }}",
+            Self::DIRECTION_FIELD.extract(self.bits()),
+            Self::CRM_FIELD.extract(self.bits()),
+            Self::RT_FIELD.extract(self.bits()),
+            Self::CRN_FIELD.extract(self.bits()),
+            Self::OP1_FIELD.extract(self.bits()),
+            Self::OP2_FIELD.extract(self.bits()),
+            Self::OP0_FIELD.extract(self.bits()),
+            Self::IL_FIELD.extract(self.bits()),
+            Self::EC_FIELD.extract(self.bits()),
+            Self::ISS2_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for EsrEl1Sysreg {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for EsrEl1Sysreg {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl EsrEl1Sysreg {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("Direction"),
+                old: Self::DIRECTION_FIELD.extract(self.bits()),
+                new: Self::DIRECTION_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CRm"),
+                old: Self::CRM_FIELD.extract(self.bits()),
+                new: Self::CRM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("Rt"),
+                old: Self::RT_FIELD.extract(self.bits()),
+                new: Self::RT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CRn"),
+                old: Self::CRN_FIELD.extract(self.bits()),
+                new: Self::CRN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("Op1"),
+                old: Self::OP1_FIELD.extract(self.bits()),
+                new: Self::OP1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("Op2"),
+                old: Self::OP2_FIELD.extract(self.bits()),
+                new: Self::OP2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("Op0"),
+                old: Self::OP0_FIELD.extract(self.bits()),
+                new: Self::OP0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IL"),
+                old: Self::IL_FIELD.extract(self.bits()),
+                new: Self::IL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EC"),
+                old: Self::EC_FIELD.extract(self.bits()),
+                new: Self::EC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ISS2"),
+                old: Self::ISS2_FIELD.extract(self.bits()),
+                new: Self::ISS2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0001_1100_0000_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0001_1100_0000_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl EsrEl1Sysreg {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl EsrEl1Sysreg {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `ESR_EL1` system register value, when `EC` is 32.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct EsrEl1InstructionAbortLower: u64 {
+        /// `S1PTW` bit.
+        const S1PTW = 1 << 7;
+        /// `EA` bit.
+        const EA = 1 << 9;
+        /// `FnV` bit.
+        const FNV = 1 << 10;
+        /// `IL` bit.
+        const IL = 1 << 25;
+    }
+}
+
+#[cfg(feature = "el1")]
+impl EsrEl1InstructionAbortLower {
+    /// Descriptor of the `IFSC` field, for use with [`FieldAccess`].
+    pub const IFSC_FIELD: Field<Self, u8, 0, 6> = Field::new("ESR_EL1", "IFSC");
+    /// Descriptor of the `S1PTW` field, for use with [`FieldAccess`].
+    pub const S1PTW_FIELD: Field<Self, bool, 7, 1> = Field::new("ESR_EL1", "S1PTW");
+    /// Descriptor of the `EA` field, for use with [`FieldAccess`].
+    pub const EA_FIELD: Field<Self, bool, 9, 1> = Field::new("ESR_EL1", "EA");
+    /// Descriptor of the `FnV` field, for use with [`FieldAccess`].
+    pub const FNV_FIELD: Field<Self, bool, 10, 1> = Field::new("ESR_EL1", "FnV");
+    /// Descriptor of the `SET` field, for use with [`FieldAccess`].
+    pub const SET_FIELD: Field<Self, u8, 11, 2> = Field::new("ESR_EL1", "SET");
+    /// Descriptor of the `IL` field, for use with [`FieldAccess`].
+    pub const IL_FIELD: Field<Self, bool, 25, 1> = Field::new("ESR_EL1", "IL");
+    /// Descriptor of the `EC` field, for use with [`FieldAccess`].
+    pub const EC_FIELD: Field<Self, u8, 26, 6> = Field::new("ESR_EL1", "EC");
+    /// Descriptor of the `ISS2` field, for use with [`FieldAccess`].
+    pub const ISS2_FIELD: Field<Self, u32, 32, 24> = Field::new("ESR_EL1", "ISS2");
+
+    /// Returns the value of the `IFSC` field.
+    pub const fn ifsc(self) -> u8 {
+        Self::IFSC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `IFSC` field.
+    pub const fn set_ifsc(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::IFSC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `IFSC` field set to the given value.
+    pub const fn with_ifsc(mut self, value: u8) -> Self {
+        self.set_ifsc(value);
+        self
+    }
+
+    /// Sets the value of the `IFSC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ifsc(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ifsc(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `IFSC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ifsc(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::IFSC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SET` field.
+    pub const fn set_(self) -> u8 {
+        Self::SET_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SET` field.
+    pub const fn set_set_(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SET_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SET` field set to the given value.
+    pub const fn with_set_(mut self, value: u8) -> Self {
+        self.set_set_(value);
+        self
+    }
+
+    /// Sets the value of the `SET` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_set_(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_set_(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SET` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_set_(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SET_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `EC` field.
+    pub const fn ec(self) -> u8 {
+        Self::EC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `EC` field.
+    pub const fn set_ec(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::EC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `EC` field set to the given value.
+    pub const fn with_ec(mut self, value: u8) -> Self {
+        self.set_ec(value);
+        self
+    }
+
+    /// Sets the value of the `EC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ec(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ec(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `EC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ec(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::EC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ISS2` field.
+    pub const fn iss2(self) -> u32 {
+        Self::ISS2_FIELD.extract(self.bits()) as u32
+    }
+
+    /// Sets the value of the `ISS2` field.
+    pub const fn set_iss2(&mut self, value: u32) {
+        *self = Self::from_bits_retain(Self::ISS2_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value.
+    pub const fn with_iss2(mut self, value: u32) -> Self {
+        self.set_iss2(value);
+        self
+    }
+
+    /// Sets the value of the `ISS2` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_iss2(&mut self, value: u32) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_iss2(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_iss2(self, value: u32) -> Result<Self, FieldOverflow> {
+        match Self::ISS2_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for EsrEl1InstructionAbortLower {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("EsrEl1InstructionAbortLower");
+        let bits = self.bits();
+        s.field("IFSC", &Self::IFSC_FIELD.extract(bits));
+        s.field("S1PTW", &Self::S1PTW_FIELD.extract(bits));
+        s.field("EA", &Self::EA_FIELD.extract(bits));
+        s.field("FnV", &Self::FNV_FIELD.extract(bits));
+        s.field("SET", &Self::SET_FIELD.extract(bits));
+        s.field("IL", &Self::IL_FIELD.extract(bits));
+        s.field("EC", &Self::EC_FIELD.extract(bits));
+        s.field(
+            "ISS2",
+            &format_args!("{:#x}", Self::ISS2_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for EsrEl1InstructionAbortLower {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "EsrEl1InstructionAbortLower {{ IFSC: {=u64}, S1PTW: {=u64}, EA: {=u64}, FnV: {=u64}, SET: {=u64}, IL: {=u64}, EC: {=u64}, ISS2: {=u64:#x} }}",
+            Self::IFSC_FIELD.extract(self.bits()),
+            Self::S1PTW_FIELD.extract(self.bits()),
+            Self::EA_FIELD.extract(self.bits()),
+            Self::FNV_FIELD.extract(self.bits()),
+            Self::SET_FIELD.extract(self.bits()),
+            Self::IL_FIELD.extract(self.bits()),
+            Self::EC_FIELD.extract(self.bits()),
+            Self::ISS2_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for EsrEl1InstructionAbortLower {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for EsrEl1InstructionAbortLower {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl EsrEl1InstructionAbortLower {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("IFSC"),
+                old: Self::IFSC_FIELD.extract(self.bits()),
+                new: Self::IFSC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("S1PTW"),
+                old: Self::S1PTW_FIELD.extract(self.bits()),
+                new: Self::S1PTW_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EA"),
+                old: Self::EA_FIELD.extract(self.bits()),
+                new: Self::EA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FnV"),
+                old: Self::FNV_FIELD.extract(self.bits()),
+                new: Self::FNV_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SET"),
+                old: Self::SET_FIELD.extract(self.bits()),
+                new: Self::SET_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IL"),
+                old: Self::IL_FIELD.extract(self.bits()),
+                new: Self::IL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EC"),
+                old: Self::EC_FIELD.extract(self.bits()),
+                new: Self::EC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ISS2"),
+                old: Self::ISS2_FIELD.extract(self.bits()),
+                new: Self::ISS2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0001_1111_1111_1110_0001_0100_0000,
+                new: other.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0001_1111_1111_1110_0001_0100_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl EsrEl1InstructionAbortLower {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl EsrEl1InstructionAbortLower {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `ESR_EL1` system register value, when `EC` is 33.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct EsrEl1InstructionAbort: u64 {
+        /// `S1PTW` bit.
+        const S1PTW = 1 << 7;
+        /// `EA` bit.
+        const EA = 1 << 9;
+        /// `FnV` bit.
+        const FNV = 1 << 10;
+        /// `IL` bit.
+        const IL = 1 << 25;
+    }
+}
+
+#[cfg(feature = "el1")]
+impl EsrEl1InstructionAbort {
+    /// Descriptor of the `IFSC` field, for use with [`FieldAccess`].
+    pub const IFSC_FIELD: Field<Self, u8, 0, 6> = Field::new("ESR_EL1", "IFSC");
+    /// Descriptor of the `S1PTW` field, for use with [`FieldAccess`].
+    pub const S1PTW_FIELD: Field<Self, bool, 7, 1> = Field::new("ESR_EL1", "S1PTW");
+    /// Descriptor of the `EA` field, for use with [`FieldAccess`].
+    pub const EA_FIELD: Field<Self, bool, 9, 1> = Field::new("ESR_EL1", "EA");
+    /// Descriptor of the `FnV` field, for use with [`FieldAccess`].
+    pub const FNV_FIELD: Field<Self, bool, 10, 1> = Field::new("ESR_EL1", "FnV");
+    /// Descriptor of the `SET` field, for use with [`FieldAccess`].
+    pub const SET_FIELD: Field<Self, u8, 11, 2> = Field::new("ESR_EL1", "SET");
+    /// Descriptor of the `IL` field, for use with [`FieldAccess`].
+    pub const IL_FIELD: Field<Self, bool, 25, 1> = Field::new("ESR_EL1", "IL");
+    /// Descriptor of the `EC` field, for use with [`FieldAccess`].
+    pub const EC_FIELD: Field<Self, u8, 26, 6> = Field::new("ESR_EL1", "EC");
+    /// Descriptor of the `ISS2` field, for use with [`FieldAccess`].
+    pub const ISS2_FIELD: Field<Self, u32, 32, 24> = Field::new("ESR_EL1", "ISS2");
+
+    /// Returns the value of the `IFSC` field.
+    pub const fn ifsc(self) -> u8 {
+        Self::IFSC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `IFSC` field.
+    pub const fn set_ifsc(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::IFSC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `IFSC` field set to the given value.
+    pub const fn with_ifsc(mut self, value: u8) -> Self {
+        self.set_ifsc(value);
+        self
+    }
+
+    /// Sets the value of the `IFSC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ifsc(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ifsc(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `IFSC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ifsc(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::IFSC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SET` field.
+    pub const fn set_(self) -> u8 {
+        Self::SET_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SET` field.
+    pub const fn set_set_(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SET_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SET` field set to the given value.
+    pub const fn with_set_(mut self, value: u8) -> Self {
+        self.set_set_(value);
+        self
+    }
+
+    /// Sets the value of the `SET` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_set_(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_set_(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SET` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_set_(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SET_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `EC` field.
+    pub const fn ec(self) -> u8 {
+        Self::EC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `EC` field.
+    pub const fn set_ec(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::EC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `EC` field set to the given value.
+    pub const fn with_ec(mut self, value: u8) -> Self {
+        self.set_ec(value);
+        self
+    }
+
+    /// Sets the value of the `EC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ec(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ec(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `EC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ec(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::EC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ISS2` field.
+    pub const fn iss2(self) -> u32 {
+        Self::ISS2_FIELD.extract(self.bits()) as u32
+    }
+
+    /// Sets the value of the `ISS2` field.
+    pub const fn set_iss2(&mut self, value: u32) {
+        *self = Self::from_bits_retain(Self::ISS2_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value.
+    pub const fn with_iss2(mut self, value: u32) -> Self {
+        self.set_iss2(value);
+        self
+    }
+
+    /// Sets the value of the `ISS2` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_iss2(&mut self, value: u32) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_iss2(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_iss2(self, value: u32) -> Result<Self, FieldOverflow> {
+        match Self::ISS2_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for EsrEl1InstructionAbort {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("EsrEl1InstructionAbort");
+        let bits = self.bits();
+        s.field("IFSC", &Self::IFSC_FIELD.extract(bits));
+        s.field("S1PTW", &Self::S1PTW_FIELD.extract(bits));
+        s.field("EA", &Self::EA_FIELD.extract(bits));
+        s.field("FnV", &Self::FNV_FIELD.extract(bits));
+        s.field("SET", &Self::SET_FIELD.extract(bits));
+        s.field("IL", &Self::IL_FIELD.extract(bits));
+        s.field("EC", &Self::EC_FIELD.extract(bits));
+        s.field(
+            "ISS2",
+            &format_args!("{:#x}", Self::ISS2_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for EsrEl1InstructionAbort {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "EsrEl1InstructionAbort {{ IFSC: {=u64}, S1PTW: {=u64}, EA: {=u64}, FnV: {=u64}, SET: {=u64}, IL: {=u64}, EC: {=u64}, ISS2: {=u64:#x} }}",
+            Self::IFSC_FIELD.extract(self.bits()),
+            Self::S1PTW_FIELD.extract(self.bits()),
+            Self::EA_FIELD.extract(self.bits()),
+            Self::FNV_FIELD.extract(self.bits()),
+            Self::SET_FIELD.extract(self.bits()),
+            Self::IL_FIELD.extract(self.bits()),
+            Self::EC_FIELD.extract(self.bits()),
+            Self::ISS2_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for EsrEl1InstructionAbort {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for EsrEl1InstructionAbort {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl EsrEl1InstructionAbort {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("IFSC"),
+                old: Self::IFSC_FIELD.extract(self.bits()),
+                new: Self::IFSC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("S1PTW"),
+                old: Self::S1PTW_FIELD.extract(self.bits()),
+                new: Self::S1PTW_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EA"),
+                old: Self::EA_FIELD.extract(self.bits()),
+                new: Self::EA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FnV"),
+                old: Self::FNV_FIELD.extract(self.bits()),
+                new: Self::FNV_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SET"),
+                old: Self::SET_FIELD.extract(self.bits()),
+                new: Self::SET_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IL"),
+                old: Self::IL_FIELD.extract(self.bits()),
+                new: Self::IL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EC"),
+                old: Self::EC_FIELD.extract(self.bits()),
+                new: Self::EC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ISS2"),
+                old: Self::ISS2_FIELD.extract(self.bits()),
+                new: Self::ISS2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0001_1111_1111_1110_0001_0100_0000,
+                new: other.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0001_1111_1111_1110_0001_0100_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl EsrEl1InstructionAbort {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl EsrEl1InstructionAbort {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `ESR_EL1` system register value, when `EC` is 36.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct EsrEl1DataAbortLower: u64 {
+        /// `WnR` bit.
+        const WNR = 1 << 6;
+        /// `S1PTW` bit.
+        const S1PTW = 1 << 7;
+        /// `CM` bit.
+        const CM = 1 << 8;
+        /// `EA` bit.
+        const EA = 1 << 9;
+        /// `FnV` bit.
+        const FNV = 1 << 10;
+        /// `VNCR` bit.
+        const VNCR = 1 << 13;
+        /// `AR` bit.
+        const AR = 1 << 14;
+        /// `SF` bit.
+        const SF = 1 << 15;
+        /// `SSE` bit.
+        const SSE = 1 << 21;
+        /// `ISV` bit.
+        const ISV = 1 << 24;
+        /// `IL` bit.
+        const IL = 1 << 25;
+    }
+}
+
+#[cfg(feature = "el1")]
+impl EsrEl1DataAbortLower {
+    /// Descriptor of the `DFSC` field, for use with [`FieldAccess`].
+    pub const DFSC_FIELD: Field<Self, u8, 0, 6> = Field::new("ESR_EL1", "DFSC");
+    /// Descriptor of the `WnR` field, for use with [`FieldAccess`].
+    pub const WNR_FIELD: Field<Self, bool, 6, 1> = Field::new("ESR_EL1", "WnR");
+    /// Descriptor of the `S1PTW` field, for use with [`FieldAccess`].
+    pub const S1PTW_FIELD: Field<Self, bool, 7, 1> = Field::new("ESR_EL1", "S1PTW");
+    /// Descriptor of the `CM` field, for use with [`FieldAccess`].
+    pub const CM_FIELD: Field<Self, bool, 8, 1> = Field::new("ESR_EL1", "CM");
+    /// Descriptor of the `EA` field, for use with [`FieldAccess`].
+    pub const EA_FIELD: Field<Self, bool, 9, 1> = Field::new("ESR_EL1", "EA");
+    /// Descriptor of the `FnV` field, for use with [`FieldAccess`].
+    pub const FNV_FIELD: Field<Self, bool, 10, 1> = Field::new("ESR_EL1", "FnV");
+    /// Descriptor of the `SET` field, for use with [`FieldAccess`].
+    pub const SET_FIELD: Field<Self, u8, 11, 2> = Field::new("ESR_EL1", "SET");
+    /// Descriptor of the `VNCR` field, for use with [`FieldAccess`].
+    pub const VNCR_FIELD: Field<Self, bool, 13, 1> = Field::new("ESR_EL1", "VNCR");
+    /// Descriptor of the `AR` field, for use with [`FieldAccess`].
+    pub const AR_FIELD: Field<Self, bool, 14, 1> = Field::new("ESR_EL1", "AR");
+    /// Descriptor of the `SF` field, for use with [`FieldAccess`].
+    pub const SF_FIELD: Field<Self, bool, 15, 1> = Field::new("ESR_EL1", "SF");
+    /// Descriptor of the `SRT` field, for use with [`FieldAccess`].
+    pub const SRT_FIELD: Field<Self, u8, 16, 5> = Field::new("ESR_EL1", "SRT");
+    /// Descriptor of the `SSE` field, for use with [`FieldAccess`].
+    pub const SSE_FIELD: Field<Self, bool, 21, 1> = Field::new("ESR_EL1", "SSE");
+    /// Descriptor of the `SAS` field, for use with [`FieldAccess`].
+    pub const SAS_FIELD: Field<Self, u8, 22, 2> = Field::new("ESR_EL1", "SAS");
+    /// Descriptor of the `ISV` field, for use with [`FieldAccess`].
+    pub const ISV_FIELD: Field<Self, bool, 24, 1> = Field::new("ESR_EL1", "ISV");
+    /// Descriptor of the `IL` field, for use with [`FieldAccess`].
+    pub const IL_FIELD: Field<Self, bool, 25, 1> = Field::new("ESR_EL1", "IL");
+    /// Descriptor of the `EC` field, for use with [`FieldAccess`].
+    pub const EC_FIELD: Field<Self, u8, 26, 6> = Field::new("ESR_EL1", "EC");
+    /// Descriptor of the `ISS2` field, for use with [`FieldAccess`].
+    pub const ISS2_FIELD: Field<Self, u32, 32, 24> = Field::new("ESR_EL1", "ISS2");
+
+    /// Returns the value of the `DFSC` field.
+    pub const fn dfsc(self) -> u8 {
+        Self::DFSC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `DFSC` field.
+    pub const fn set_dfsc(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::DFSC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `DFSC` field set to the given value.
+    pub const fn with_dfsc(mut self, value: u8) -> Self {
+        self.set_dfsc(value);
+        self
+    }
+
+    /// Sets the value of the `DFSC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_dfsc(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_dfsc(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `DFSC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_dfsc(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::DFSC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SET` field.
+    pub const fn set_(self) -> u8 {
+        Self::SET_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SET` field.
+    pub const fn set_set_(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SET_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SET` field set to the given value.
+    pub const fn with_set_(mut self, value: u8) -> Self {
+        self.set_set_(value);
+        self
+    }
+
+    /// Sets the value of the `SET` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_set_(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_set_(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SET` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_set_(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SET_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SRT` field.
+    pub const fn srt(self) -> u8 {
+        Self::SRT_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SRT` field.
+    pub const fn set_srt(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SRT_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SRT` field set to the given value.
+    pub const fn with_srt(mut self, value: u8) -> Self {
+        self.set_srt(value);
+        self
+    }
+
+    /// Sets the value of the `SRT` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_srt(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_srt(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SRT` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_srt(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SRT_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SAS` field.
+    pub const fn sas(self) -> u8 {
+        Self::SAS_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SAS` field.
+    pub const fn set_sas(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SAS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SAS` field set to the given value.
+    pub const fn with_sas(mut self, value: u8) -> Self {
+        self.set_sas(value);
+        self
+    }
+
+    /// Sets the value of the `SAS` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_sas(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_sas(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SAS` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_sas(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SAS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `EC` field.
+    pub const fn ec(self) -> u8 {
+        Self::EC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `EC` field.
+    pub const fn set_ec(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::EC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `EC` field set to the given value.
+    pub const fn with_ec(mut self, value: u8) -> Self {
+        self.set_ec(value);
+        self
+    }
+
+    /// Sets the value of the `EC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ec(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ec(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `EC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ec(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::EC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ISS2` field.
+    pub const fn iss2(self) -> u32 {
+        Self::ISS2_FIELD.extract(self.bits()) as u32
+    }
+
+    /// Sets the value of the `ISS2` field.
+    pub const fn set_iss2(&mut self, value: u32) {
+        *self = Self::from_bits_retain(Self::ISS2_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value.
+    pub const fn with_iss2(mut self, value: u32) -> Self {
+        self.set_iss2(value);
+        self
+    }
+
+    /// Sets the value of the `ISS2` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_iss2(&mut self, value: u32) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_iss2(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_iss2(self, value: u32) -> Result<Self, FieldOverflow> {
+        match Self::ISS2_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for EsrEl1DataAbortLower {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("EsrEl1DataAbortLower");
+        let bits = self.bits();
+        s.field("DFSC", &Self::DFSC_FIELD.extract(bits));
+        s.field("WnR", &Self::WNR_FIELD.extract(bits));
+        s.field("S1PTW", &Self::S1PTW_FIELD.extract(bits));
+        s.field("CM", &Self::CM_FIELD.extract(bits));
+        s.field("EA", &Self::EA_FIELD.extract(bits));
+        s.field("FnV", &Self::FNV_FIELD.extract(bits));
+        s.field("SET", &Self::SET_FIELD.extract(bits));
+        s.field("VNCR", &Self::VNCR_FIELD.extract(bits));
+        s.field("AR", &Self::AR_FIELD.extract(bits));
+        s.field("SF", &Self::SF_FIELD.extract(bits));
+        s.field("SRT", &Self::SRT_FIELD.extract(bits));
+        s.field("SSE", &Self::SSE_FIELD.extract(bits));
+        s.field("SAS", &Self::SAS_FIELD.extract(bits));
+        s.field("ISV", &Self::ISV_FIELD.extract(bits));
+        s.field("IL", &Self::IL_FIELD.extract(bits));
+        s.field("EC", &Self::EC_FIELD.extract(bits));
+        s.field(
+            "ISS2",
+            &format_args!("{:#x}", Self::ISS2_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for EsrEl1DataAbortLower {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "EsrEl1DataAbortLower {{ DFSC: {=u64}, WnR: {=u64}, S1PTW: {=u64}, CM: {=u64}, EA: {=u64}, FnV: {=u64}, SET: {=u64}, VNCR: {=u64}, AR: {=u64}, SF: {=u64}, SRT: {=u64}, SSE: {=u64}, SAS: {=u64}, ISV: {=u64}, IL: {=u64}, EC: {=u64}, ISS2: {=u64:#x} }}",
+            Self::DFSC_FIELD.extract(self.bits()),
+            Self::WNR_FIELD.extract(self.bits()),
+            Self::S1PTW_FIELD.extract(self.bits()),
+            Self::CM_FIELD.extract(self.bits()),
+            Self::EA_FIELD.extract(self.bits()),
+            Self::FNV_FIELD.extract(self.bits()),
+            Self::SET_FIELD.extract(self.bits()),
+            Self::VNCR_FIELD.extract(self.bits()),
+            Self::AR_FIELD.extract(self.bits()),
+            Self::SF_FIELD.extract(self.bits()),
+            Self::SRT_FIELD.extract(self.bits()),
+            Self::SSE_FIELD.extract(self.bits()),
+            Self::SAS_FIELD.extract(self.bits()),
+            Self::ISV_FIELD.extract(self.bits()),
+            Self::IL_FIELD.extract(self.bits()),
+            Self::EC_FIELD.extract(self.bits()),
+            Self::ISS2_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for EsrEl1DataAbortLower {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for EsrEl1DataAbortLower {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl EsrEl1DataAbortLower {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("DFSC"),
+                old: Self::DFSC_FIELD.extract(self.bits()),
+                new: Self::DFSC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("WnR"),
+                old: Self::WNR_FIELD.extract(self.bits()),
+                new: Self::WNR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("S1PTW"),
+                old: Self::S1PTW_FIELD.extract(self.bits()),
+                new: Self::S1PTW_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CM"),
+                old: Self::CM_FIELD.extract(self.bits()),
+                new: Self::CM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EA"),
+                old: Self::EA_FIELD.extract(self.bits()),
+                new: Self::EA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FnV"),
+                old: Self::FNV_FIELD.extract(self.bits()),
+                new: Self::FNV_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SET"),
+                old: Self::SET_FIELD.extract(self.bits()),
+                new: Self::SET_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VNCR"),
+                old: Self::VNCR_FIELD.extract(self.bits()),
+                new: Self::VNCR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("AR"),
+                old: Self::AR_FIELD.extract(self.bits()),
+                new: Self::AR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SF"),
+                old: Self::SF_FIELD.extract(self.bits()),
+                new: Self::SF_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SRT"),
+                old: Self::SRT_FIELD.extract(self.bits()),
+                new: Self::SRT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SSE"),
+                old: Self::SSE_FIELD.extract(self.bits()),
+                new: Self::SSE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SAS"),
+                old: Self::SAS_FIELD.extract(self.bits()),
+                new: Self::SAS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ISV"),
+                old: Self::ISV_FIELD.extract(self.bits()),
+                new: Self::ISV_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IL"),
+                old: Self::IL_FIELD.extract(self.bits()),
+                new: Self::IL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EC"),
+                old: Self::EC_FIELD.extract(self.bits()),
+                new: Self::EC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ISS2"),
+                old: Self::ISS2_FIELD.extract(self.bits()),
+                new: Self::ISS2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl EsrEl1DataAbortLower {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl EsrEl1DataAbortLower {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `ESR_EL1` system register value, when `EC` is 37.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct EsrEl1DataAbort: u64 {
+        /// `WnR` bit.
+        const WNR = 1 << 6;
+        /// `S1PTW` bit.
+        const S1PTW = 1 << 7;
+        /// `CM` bit.
+        const CM = 1 << 8;
+        /// `EA` bit.
+        const EA = 1 << 9;
+        /// `FnV` bit.
+        const FNV = 1 << 10;
+        /// `VNCR` bit.
+        const VNCR = 1 << 13;
+        /// `AR` bit.
+        const AR = 1 << 14;
+        /// `SF` bit.
+        const SF = 1 << 15;
+        /// `SSE` bit.
+        const SSE = 1 << 21;
+        /// `ISV` bit.
+        const ISV = 1 << 24;
+        /// `IL` bit.
+        const IL = 1 << 25;
+    }
+}
+
+#[cfg(feature = "el1")]
+impl EsrEl1DataAbort {
+    /// Descriptor of the `DFSC` field, for use with [`FieldAccess`].
+    pub const DFSC_FIELD: Field<Self, u8, 0, 6> = Field::new("ESR_EL1", "DFSC");
+    /// Descriptor of the `WnR` field, for use with [`FieldAccess`].
+    pub const WNR_FIELD: Field<Self, bool, 6, 1> = Field::new("ESR_EL1", "WnR");
+    /// Descriptor of the `S1PTW` field, for use with [`FieldAccess`].
+    pub const S1PTW_FIELD: Field<Self, bool, 7, 1> = Field::new("ESR_EL1", "S1PTW");
+    /// Descriptor of the `CM` field, for use with [`FieldAccess`].
+    pub const CM_FIELD: Field<Self, bool, 8, 1> = Field::new("ESR_EL1", "CM");
+    /// Descriptor of the `EA` field, for use with [`FieldAccess`].
+    pub const EA_FIELD: Field<Self, bool, 9, 1> = Field::new("ESR_EL1", "EA");
+    /// Descriptor of the `FnV` field, for use with [`FieldAccess`].
+    pub const FNV_FIELD: Field<Self, bool, 10, 1> = Field::new("ESR_EL1", "FnV");
+    /// Descriptor of the `SET` field, for use with [`FieldAccess`].
+    pub const SET_FIELD: Field<Self, u8, 11, 2> = Field::new("ESR_EL1", "SET");
+    /// Descriptor of the `VNCR` field, for use with [`FieldAccess`].
+    pub const VNCR_FIELD: Field<Self, bool, 13, 1> = Field::new("ESR_EL1", "VNCR");
+    /// Descriptor of the `AR` field, for use with [`FieldAccess`].
+    pub const AR_FIELD: Field<Self, bool, 14, 1> = Field::new("ESR_EL1", "AR");
+    /// Descriptor of the `SF` field, for use with [`FieldAccess`].
+    pub const SF_FIELD: Field<Self, bool, 15, 1> = Field::new("ESR_EL1", "SF");
+    /// Descriptor of the `SRT` field, for use with [`FieldAccess`].
+    pub const SRT_FIELD: Field<Self, u8, 16, 5> = Field::new("ESR_EL1", "SRT");
+    /// Descriptor of the `SSE` field, for use with [`FieldAccess`].
+    pub const SSE_FIELD: Field<Self, bool, 21, 1> = Field::new("ESR_EL1", "SSE");
+    /// Descriptor of the `SAS` field, for use with [`FieldAccess`].
+    pub const SAS_FIELD: Field<Self, u8, 22, 2> = Field::new("ESR_EL1", "SAS");
+    /// Descriptor of the `ISV` field, for use with [`FieldAccess`].
+    pub const ISV_FIELD: Field<Self, bool, 24, 1> = Field::new("ESR_EL1", "ISV");
+    /// Descriptor of the `IL` field, for use with [`FieldAccess`].
+    pub const IL_FIELD: Field<Self, bool, 25, 1> = Field::new("ESR_EL1", "IL");
+    /// Descriptor of the `EC` field, for use with [`FieldAccess`].
+    pub const EC_FIELD: Field<Self, u8, 26, 6> = Field::new("ESR_EL1", "EC");
+    /// Descriptor of the `ISS2` field, for use with [`FieldAccess`].
+    pub const ISS2_FIELD: Field<Self, u32, 32, 24> = Field::new("ESR_EL1", "ISS2");
+
+    /// Returns the value of the `DFSC` field.
+    pub const fn dfsc(self) -> u8 {
+        Self::DFSC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `DFSC` field.
+    pub const fn set_dfsc(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::DFSC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `DFSC` field set to the given value.
+    pub const fn with_dfsc(mut self, value: u8) -> Self {
+        self.set_dfsc(value);
+        self
+    }
+
+    /// Sets the value of the `DFSC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_dfsc(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_dfsc(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `DFSC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_dfsc(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::DFSC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SET` field.
+    pub const fn set_(self) -> u8 {
+        Self::SET_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SET` field.
+    pub const fn set_set_(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SET_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SET` field set to the given value.
+    pub const fn with_set_(mut self, value: u8) -> Self {
+        self.set_set_(value);
+        self
+    }
+
+    /// Sets the value of the `SET` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_set_(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_set_(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SET` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_set_(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SET_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SRT` field.
+    pub const fn srt(self) -> u8 {
+        Self::SRT_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SRT` field.
+    pub const fn set_srt(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SRT_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SRT` field set to the given value.
+    pub const fn with_srt(mut self, value: u8) -> Self {
+        self.set_srt(value);
+        self
+    }
+
+    /// Sets the value of the `SRT` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_srt(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_srt(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SRT` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_srt(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SRT_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SAS` field.
+    pub const fn sas(self) -> u8 {
+        Self::SAS_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SAS` field.
+    pub const fn set_sas(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SAS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SAS` field set to the given value.
+    pub const fn with_sas(mut self, value: u8) -> Self {
+        self.set_sas(value);
+        self
+    }
+
+    /// Sets the value of the `SAS` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_sas(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_sas(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SAS` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_sas(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SAS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `EC` field.
+    pub const fn ec(self) -> u8 {
+        Self::EC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `EC` field.
+    pub const fn set_ec(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::EC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `EC` field set to the given value.
+    pub const fn with_ec(mut self, value: u8) -> Self {
+        self.set_ec(value);
+        self
+    }
+
+    /// Sets the value of the `EC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ec(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ec(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `EC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ec(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::EC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ISS2` field.
+    pub const fn iss2(self) -> u32 {
+        Self::ISS2_FIELD.extract(self.bits()) as u32
+    }
+
+    /// Sets the value of the `ISS2` field.
+    pub const fn set_iss2(&mut self, value: u32) {
+        *self = Self::from_bits_retain(Self::ISS2_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value.
+    pub const fn with_iss2(mut self, value: u32) -> Self {
+        self.set_iss2(value);
+        self
+    }
+
+    /// Sets the value of the `ISS2` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_iss2(&mut self, value: u32) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_iss2(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_iss2(self, value: u32) -> Result<Self, FieldOverflow> {
+        match Self::ISS2_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for EsrEl1DataAbort {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("EsrEl1DataAbort");
+        let bits = self.bits();
+        s.field("DFSC", &Self::DFSC_FIELD.extract(bits));
+        s.field("WnR", &Self::WNR_FIELD.extract(bits));
+        s.field("S1PTW", &Self::S1PTW_FIELD.extract(bits));
+        s.field("CM", &Self::CM_FIELD.extract(bits));
+        s.field("EA", &Self::EA_FIELD.extract(bits));
+        s.field("FnV", &Self::FNV_FIELD.extract(bits));
+        s.field("SET", &Self::SET_FIELD.extract(bits));
+        s.field("VNCR", &Self::VNCR_FIELD.extract(bits));
+        s.field("AR", &Self::AR_FIELD.extract(bits));
+        s.field("SF", &Self::SF_FIELD.extract(bits));
+        s.field("SRT", &Self::SRT_FIELD.extract(bits));
+        s.field("SSE", &Self::SSE_FIELD.extract(bits));
+        s.field("SAS", &Self::SAS_FIELD.extract(bits));
+        s.field("ISV", &Self::ISV_FIELD.extract(bits));
+        s.field("IL", &Self::IL_FIELD.extract(bits));
+        s.field("EC", &Self::EC_FIELD.extract(bits));
+        s.field(
+            "ISS2",
+            &format_args!("{:#x}", Self::ISS2_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for EsrEl1DataAbort {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "EsrEl1DataAbort {{ DFSC: {=u64}, WnR: {=u64}, S1PTW: {=u64}, CM: {=u64}, EA: {=u64}, FnV: {=u64}, SET: {=u64}, VNCR: {=u64}, AR: {=u64}, SF: {=u64}, SRT: {=u64}, SSE: {=u64}, SAS: {=u64}, ISV: {=u64}, IL: {=u64}, EC: {=u64}, ISS2: {=u64:#x} }}",
+            Self::DFSC_FIELD.extract(self.bits()),
+            Self::WNR_FIELD.extract(self.bits()),
+            Self::S1PTW_FIELD.extract(self.bits()),
+            Self::CM_FIELD.extract(self.bits()),
+            Self::EA_FIELD.extract(self.bits()),
+            Self::FNV_FIELD.extract(self.bits()),
+            Self::SET_FIELD.extract(self.bits()),
+            Self::VNCR_FIELD.extract(self.bits()),
+            Self::AR_FIELD.extract(self.bits()),
+            Self::SF_FIELD.extract(self.bits()),
+            Self::SRT_FIELD.extract(self.bits()),
+            Self::SSE_FIELD.extract(self.bits()),
+            Self::SAS_FIELD.extract(self.bits()),
+            Self::ISV_FIELD.extract(self.bits()),
+            Self::IL_FIELD.extract(self.bits()),
+            Self::EC_FIELD.extract(self.bits()),
+            Self::ISS2_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for EsrEl1DataAbort {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for EsrEl1DataAbort {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl EsrEl1DataAbort {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("DFSC"),
+                old: Self::DFSC_FIELD.extract(self.bits()),
+                new: Self::DFSC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("WnR"),
+                old: Self::WNR_FIELD.extract(self.bits()),
+                new: Self::WNR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("S1PTW"),
+                old: Self::S1PTW_FIELD.extract(self.bits()),
+                new: Self::S1PTW_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CM"),
+                old: Self::CM_FIELD.extract(self.bits()),
+                new: Self::CM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EA"),
+                old: Self::EA_FIELD.extract(self.bits()),
+                new: Self::EA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FnV"),
+                old: Self::FNV_FIELD.extract(self.bits()),
+                new: Self::FNV_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SET"),
+                old: Self::SET_FIELD.extract(self.bits()),
+                new: Self::SET_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VNCR"),
+                old: Self::VNCR_FIELD.extract(self.bits()),
+                new: Self::VNCR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("AR"),
+                old: Self::AR_FIELD.extract(self.bits()),
+                new: Self::AR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SF"),
+                old: Self::SF_FIELD.extract(self.bits()),
+                new: Self::SF_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SRT"),
+                old: Self::SRT_FIELD.extract(self.bits()),
+                new: Self::SRT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SSE"),
+                old: Self::SSE_FIELD.extract(self.bits()),
+                new: Self::SSE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SAS"),
+                old: Self::SAS_FIELD.extract(self.bits()),
+                new: Self::SAS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ISV"),
+                old: Self::ISV_FIELD.extract(self.bits()),
+                new: Self::ISV_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IL"),
+                old: Self::IL_FIELD.extract(self.bits()),
+                new: Self::IL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EC"),
+                old: Self::EC_FIELD.extract(self.bits()),
+                new: Self::EC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ISS2"),
+                old: Self::ISS2_FIELD.extract(self.bits()),
+                new: Self::ISS2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl EsrEl1DataAbort {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl EsrEl1DataAbort {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl EsrEl1 {
+    /// Returns the value as a `EsrEl1Wfx` if `EC` is 1, or `None` if it has another layout.
+    pub const fn as_wfx(self) -> Option<EsrEl1Wfx> {
+        if Self::EC_FIELD.extract(self.bits()) == 1 {
+            Some(EsrEl1Wfx::from_bits_retain(self.bits()))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value as a `EsrEl1Svc` if `EC` is 21, or `None` if it has another layout.
+    pub const fn as_svc(self) -> Option<EsrEl1Svc> {
+        if Self::EC_FIELD.extract(self.bits()) == 21 {
+            Some(EsrEl1Svc::from_bits_retain(self.bits()))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value as a `EsrEl1Hvc` if `EC` is 22, or `None` if it has another layout.
+    pub const fn as_hvc(self) -> Option<EsrEl1Hvc> {
+        if Self::EC_FIELD.extract(self.bits()) == 22 {
+            Some(EsrEl1Hvc::from_bits_retain(self.bits()))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value as a `EsrEl1Smc` if `EC` is 23, or `None` if it has another layout.
+    pub const fn as_smc(self) -> Option<EsrEl1Smc> {
+        if Self::EC_FIELD.extract(self.bits()) == 23 {
+            Some(EsrEl1Smc::from_bits_retain(self.bits()))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value as a `EsrEl1Sysreg` if `EC` is 24, or `None` if it has another layout.
+    pub const fn as_sysreg(self) -> Option<EsrEl1Sysreg> {
+        if Self::EC_FIELD.extract(self.bits()) == 24 {
+            Some(EsrEl1Sysreg::from_bits_retain(self.bits()))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value as a `EsrEl1InstructionAbortLower` if `EC` is 32, or `None` if it has another layout.
+    pub const fn as_instruction_abort_lower(self) -> Option<EsrEl1InstructionAbortLower> {
+        if Self::EC_FIELD.extract(self.bits()) == 32 {
+            Some(EsrEl1InstructionAbortLower::from_bits_retain(self.bits()))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value as a `EsrEl1InstructionAbort` if `EC` is 33, or `None` if it has another layout.
+    pub const fn as_instruction_abort(self) -> Option<EsrEl1InstructionAbort> {
+        if Self::EC_FIELD.extract(self.bits()) == 33 {
+            Some(EsrEl1InstructionAbort::from_bits_retain(self.bits()))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value as a `EsrEl1DataAbortLower` if `EC` is 36, or `None` if it has another layout.
+    pub const fn as_data_abort_lower(self) -> Option<EsrEl1DataAbortLower> {
+        if Self::EC_FIELD.extract(self.bits()) == 36 {
+            Some(EsrEl1DataAbortLower::from_bits_retain(self.bits()))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value as a `EsrEl1DataAbort` if `EC` is 37, or `None` if it has another layout.
+    pub const fn as_data_abort(self) -> Option<EsrEl1DataAbort> {
+        if Self::EC_FIELD.extract(self.bits()) == 37 {
+            Some(EsrEl1DataAbort::from_bits_retain(self.bits()))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl From<EsrEl1Wfx> for EsrEl1 {
+    fn from(value: EsrEl1Wfx) -> Self {
+        Self::from_bits_retain(value.bits())
+    }
+}
+
+#[cfg(feature = "el1")]
+impl From<EsrEl1Svc> for EsrEl1 {
+    fn from(value: EsrEl1Svc) -> Self {
+        Self::from_bits_retain(value.bits())
+    }
+}
+
+#[cfg(feature = "el1")]
+impl From<EsrEl1Hvc> for EsrEl1 {
+    fn from(value: EsrEl1Hvc) -> Self {
+        Self::from_bits_retain(value.bits())
+    }
+}
+
+#[cfg(feature = "el1")]
+impl From<EsrEl1Smc> for EsrEl1 {
+    fn from(value: EsrEl1Smc) -> Self {
+        Self::from_bits_retain(value.bits())
+    }
+}
+
+#[cfg(feature = "el1")]
+impl From<EsrEl1Sysreg> for EsrEl1 {
+    fn from(value: EsrEl1Sysreg) -> Self {
+        Self::from_bits_retain(value.bits())
+    }
+}
+
+#[cfg(feature = "el1")]
+impl From<EsrEl1InstructionAbortLower> for EsrEl1 {
+    fn from(value: EsrEl1InstructionAbortLower) -> Self {
+        Self::from_bits_retain(value.bits())
+    }
+}
+
+#[cfg(feature = "el1")]
+impl From<EsrEl1InstructionAbort> for EsrEl1 {
+    fn from(value: EsrEl1InstructionAbort) -> Self {
+        Self::from_bits_retain(value.bits())
+    }
+}
+
+#[cfg(feature = "el1")]
+impl From<EsrEl1DataAbortLower> for EsrEl1 {
+    fn from(value: EsrEl1DataAbortLower) -> Self {
+        Self::from_bits_retain(value.bits())
+    }
+}
+
+#[cfg(feature = "el1")]
+impl From<EsrEl1DataAbort> for EsrEl1 {
+    fn from(value: EsrEl1DataAbort) -> Self {
+        Self::from_bits_retain(value.bits())
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `ESR_EL2` system register value.
+    #[derive(Clone, Copy, Eq, Default, PartialEq)]
+    #[repr(transparent)]
+    pub struct EsrEl2: u64 {
+        /// 32-bit instruction length.
+        const IL = 1 << 25;
+    }
+}
+
+#[cfg(feature = "el2")]
+impl EsrEl2 {
+    /// Descriptor of the `ISS` field, for use with [`FieldAccess`].
+    pub const ISS_FIELD: Field<Self, u32, 0, 25> = Field::new("ESR_EL2", "ISS");
+    /// Descriptor of the `IL` field, for use with [`FieldAccess`].
+    pub const IL_FIELD: Field<Self, bool, 25, 1> = Field::new("ESR_EL2", "IL");
+    /// Descriptor of the `EC` field, for use with [`FieldAccess`].
+    pub const EC_FIELD: Field<Self, u8, 26, 6> = Field::new("ESR_EL2", "EC");
+    /// Descriptor of the `ISS2` field, for use with [`FieldAccess`].
+    pub const ISS2_FIELD: Field<Self, u32, 32, 24> = Field::new("ESR_EL2", "ISS2");
+
+    /// Returns the value of the `ISS` field.
+    pub const fn iss(self) -> u32 {
+        Self::ISS_FIELD.extract(self.bits()) as u32
+    }
+
+    /// Sets the value of the `ISS` field.
+    pub const fn set_iss(&mut self, value: u32) {
+        *self = Self::from_bits_retain(Self::ISS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ISS` field set to the given value.
+    pub const fn with_iss(mut self, value: u32) -> Self {
+        self.set_iss(value);
+        self
+    }
+
+    /// Sets the value of the `ISS` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_iss(&mut self, value: u32) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_iss(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ISS` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_iss(self, value: u32) -> Result<Self, FieldOverflow> {
+        match Self::ISS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `EC` field.
+    pub const fn ec(self) -> u8 {
+        Self::EC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `EC` field.
+    pub const fn set_ec(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::EC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `EC` field set to the given value.
+    pub const fn with_ec(mut self, value: u8) -> Self {
+        self.set_ec(value);
+        self
+    }
+
+    /// Sets the value of the `EC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ec(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ec(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `EC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ec(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::EC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ISS2` field.
+    pub const fn iss2(self) -> u32 {
+        Self::ISS2_FIELD.extract(self.bits()) as u32
+    }
+
+    /// Sets the value of the `ISS2` field.
+    pub const fn set_iss2(&mut self, value: u32) {
+        *self = Self::from_bits_retain(Self::ISS2_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value.
+    pub const fn with_iss2(mut self, value: u32) -> Self {
+        self.set_iss2(value);
+        self
+    }
+
+    /// Sets the value of the `ISS2` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_iss2(&mut self, value: u32) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_iss2(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_iss2(self, value: u32) -> Result<Self, FieldOverflow> {
+        match Self::ISS2_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Display for EsrEl2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("EsrEl2");
+        let bits = self.bits();
+        s.field("ISS", &format_args!("{:#x}", Self::ISS_FIELD.extract(bits)));
+        s.field("IL", &Self::IL_FIELD.extract(bits));
+        s.field("EC", &Self::EC_FIELD.extract(bits));
+        s.field(
+            "ISS2",
+            &format_args!("{:#x}", Self::ISS2_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for EsrEl2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "EsrEl2 {{ ISS: {=u64:#x}, IL: {=u64}, EC: {=u64}, ISS2: {=u64:#x} }}",
+            Self::ISS_FIELD.extract(self.bits()),
+            Self::IL_FIELD.extract(self.bits()),
+            Self::EC_FIELD.extract(self.bits()),
+            Self::ISS2_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for EsrEl2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for EsrEl2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl EsrEl2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("ISS"),
+                old: Self::ISS_FIELD.extract(self.bits()),
+                new: Self::ISS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IL"),
+                old: Self::IL_FIELD.extract(self.bits()),
+                new: Self::IL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EC"),
+                old: Self::EC_FIELD.extract(self.bits()),
+                new: Self::EC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ISS2"),
+                old: Self::ISS2_FIELD.extract(self.bits()),
+                new: Self::ISS2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl EsrEl2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl EsrEl2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `ESR_EL2` system register value, when `EC` is 1.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct EsrEl2Wfx: u64 {
+        /// `RV` bit.
+        const RV = 1 << 2;
+        /// `CV` bit.
+        const CV = 1 << 24;
+        /// 32-bit instruction length.
+        const IL = 1 << 25;
+    }
+}
+
+#[cfg(feature = "el2")]
+impl EsrEl2Wfx {
+    /// Descriptor of the `TI` field, for use with [`FieldAccess`].
+    pub const TI_FIELD: Field<Self, u8, 0, 2> = Field::new("ESR_EL2", "TI");
+    /// Descriptor of the `RV` field, for use with [`FieldAccess`].
+    pub const RV_FIELD: Field<Self, bool, 2, 1> = Field::new("ESR_EL2", "RV");
+    /// Descriptor of the `RN` field, for use with [`FieldAccess`].
+    pub const RN_FIELD: Field<Self, u8, 5, 5> = Field::new("ESR_EL2", "RN");
+    /// Descriptor of the `COND` field, for use with [`FieldAccess`].
+    pub const COND_FIELD: Field<Self, u8, 20, 4> = Field::new("ESR_EL2", "COND");
+    /// Descriptor of the `CV` field, for use with [`FieldAccess`].
+    pub const CV_FIELD: Field<Self, bool, 24, 1> = Field::new("ESR_EL2", "CV");
+    /// Descriptor of the `IL` field, for use with [`FieldAccess`].
+    pub const IL_FIELD: Field<Self, bool, 25, 1> = Field::new("ESR_EL2", "IL");
+    /// Descriptor of the `EC` field, for use with [`FieldAccess`].
+    pub const EC_FIELD: Field<Self, u8, 26, 6> = Field::new("ESR_EL2", "EC");
+    /// Descriptor of the `ISS2` field, for use with [`FieldAccess`].
+    pub const ISS2_FIELD: Field<Self, u32, 32, 24> = Field::new("ESR_EL2", "ISS2");
+
+    /// Returns the value of the `TI` field.
+    pub const fn ti(self) -> u8 {
+        Self::TI_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `TI` field.
+    pub const fn set_ti(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::TI_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `TI` field set to the given value.
+    pub const fn with_ti(mut self, value: u8) -> Self {
+        self.set_ti(value);
+        self
+    }
+
+    /// Sets the value of the `TI` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ti(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ti(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `TI` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ti(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::TI_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `RN` field.
+    pub const fn rn(self) -> u8 {
+        Self::RN_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `RN` field.
+    pub const fn set_rn(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::RN_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `RN` field set to the given value.
+    pub const fn with_rn(mut self, value: u8) -> Self {
+        self.set_rn(value);
+        self
+    }
+
+    /// Sets the value of the `RN` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_rn(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_rn(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `RN` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_rn(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::RN_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `COND` field.
+    pub const fn cond(self) -> u8 {
+        Self::COND_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `COND` field.
+    pub const fn set_cond(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::COND_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `COND` field set to the given value.
+    pub const fn with_cond(mut self, value: u8) -> Self {
+        self.set_cond(value);
+        self
+    }
+
+    /// Sets the value of the `COND` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_cond(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_cond(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `COND` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_cond(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::COND_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `EC` field.
+    pub const fn ec(self) -> u8 {
+        Self::EC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `EC` field.
+    pub const fn set_ec(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::EC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `EC` field set to the given value.
+    pub const fn with_ec(mut self, value: u8) -> Self {
+        self.set_ec(value);
+        self
+    }
+
+    /// Sets the value of the `EC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ec(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ec(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `EC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ec(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::EC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ISS2` field.
+    pub const fn iss2(self) -> u32 {
+        Self::ISS2_FIELD.extract(self.bits()) as u32
+    }
+
+    /// Sets the value of the `ISS2` field.
+    pub const fn set_iss2(&mut self, value: u32) {
+        *self = Self::from_bits_retain(Self::ISS2_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value.
+    pub const fn with_iss2(mut self, value: u32) -> Self {
+        self.set_iss2(value);
+        self
+    }
+
+    /// Sets the value of the `ISS2` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_iss2(&mut self, value: u32) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_iss2(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_iss2(self, value: u32) -> Result<Self, FieldOverflow> {
+        match Self::ISS2_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Display for EsrEl2Wfx {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("EsrEl2Wfx");
+        let bits = self.bits();
+        s.field("TI", &Self::TI_FIELD.extract(bits));
+        s.field("RV", &Self::RV_FIELD.extract(bits));
+        s.field("RN", &Self::RN_FIELD.extract(bits));
+        s.field("COND", &Self::COND_FIELD.extract(bits));
+        s.field("CV", &Self::CV_FIELD.extract(bits));
+        s.field("IL", &Self::IL_FIELD.extract(bits));
+        s.field("EC", &Self::EC_FIELD.extract(bits));
+        s.field(
+            "ISS2",
+            &format_args!("{:#x}", Self::ISS2_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for EsrEl2Wfx {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "EsrEl2Wfx {{ TI: {=u64}, RV: {=u64}, RN: {=u64}, COND: {=u64}, CV: {=u64}, IL: {=u64}, EC: {=u64}, ISS2: {=u64:#x} }}",
+            Self::TI_FIELD.extract(self.bits()),
+            Self::RV_FIELD.extract(self.bits()),
+            Self::RN_FIELD.extract(self.bits()),
+            Self::COND_FIELD.extract(self.bits()),
+            Self::CV_FIELD.extract(self.bits()),
+            Self::IL_FIELD.extract(self.bits()),
+            Self::EC_FIELD.extract(self.bits()),
+            Self::ISS2_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for EsrEl2Wfx {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for EsrEl2Wfx {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl EsrEl2Wfx {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("TI"),
+                old: Self::TI_FIELD.extract(self.bits()),
+                new: Self::TI_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("RV"),
+                old: Self::RV_FIELD.extract(self.bits()),
+                new: Self::RV_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("RN"),
+                old: Self::RN_FIELD.extract(self.bits()),
+                new: Self::RN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("COND"),
+                old: Self::COND_FIELD.extract(self.bits()),
+                new: Self::COND_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CV"),
+                old: Self::CV_FIELD.extract(self.bits()),
+                new: Self::CV_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IL"),
+                old: Self::IL_FIELD.extract(self.bits()),
+                new: Self::IL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EC"),
+                old: Self::EC_FIELD.extract(self.bits()),
+                new: Self::EC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ISS2"),
+                old: Self::ISS2_FIELD.extract(self.bits()),
+                new: Self::ISS2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0000_0000_1111_1111_1100_0001_1000,
+                new: other.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0000_0000_1111_1111_1100_0001_1000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl EsrEl2Wfx {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl EsrEl2Wfx {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `ESR_EL2` system register value, when `EC` is 21.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct EsrEl2Svc: u64 {
+        /// 32-bit instruction length.
+        const IL = 1 << 25;
+    }
+}
+
+#[cfg(feature = "el2")]
+impl EsrEl2Svc {
+    /// Descriptor of the `imm16` field, for use with [`FieldAccess`].
+    pub const IMM16_FIELD: Field<Self, u16, 0, 16> = Field::new("ESR_EL2", "imm16");
+    /// Descriptor of the `IL` field, for use with [`FieldAccess`].
+    pub const IL_FIELD: Field<Self, bool, 25, 1> = Field::new("ESR_EL2", "IL");
+    /// Descriptor of the `EC` field, for use with [`FieldAccess`].
+    pub const EC_FIELD: Field<Self, u8, 26, 6> = Field::new("ESR_EL2", "EC");
+    /// Descriptor of the `ISS2` field, for use with [`FieldAccess`].
+    pub const ISS2_FIELD: Field<Self, u32, 32, 24> = Field::new("ESR_EL2", "ISS2");
+
+    /// Returns the value of the `imm16` field.
+    pub const fn imm16(self) -> u16 {
+        Self::IMM16_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `imm16` field.
+    pub const fn set_imm16(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::IMM16_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `imm16` field set to the given value.
+    pub const fn with_imm16(mut self, value: u16) -> Self {
+        self.set_imm16(value);
+        self
+    }
+
+    /// Sets the value of the `imm16` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_imm16(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_imm16(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `imm16` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_imm16(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::IMM16_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `EC` field.
+    pub const fn ec(self) -> u8 {
+        Self::EC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `EC` field.
+    pub const fn set_ec(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::EC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `EC` field set to the given value.
+    pub const fn with_ec(mut self, value: u8) -> Self {
+        self.set_ec(value);
+        self
+    }
+
+    /// Sets the value of the `EC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ec(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ec(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `EC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ec(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::EC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ISS2` field.
+    pub const fn iss2(self) -> u32 {
+        Self::ISS2_FIELD.extract(self.bits()) as u32
+    }
+
+    /// Sets the value of the `ISS2` field.
+    pub const fn set_iss2(&mut self, value: u32) {
+        *self = Self::from_bits_retain(Self::ISS2_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value.
+    pub const fn with_iss2(mut self, value: u32) -> Self {
+        self.set_iss2(value);
+        self
+    }
+
+    /// Sets the value of the `ISS2` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_iss2(&mut self, value: u32) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_iss2(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_iss2(self, value: u32) -> Result<Self, FieldOverflow> {
+        match Self::ISS2_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Display for EsrEl2Svc {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("EsrEl2Svc");
+        let bits = self.bits();
+        s.field(
+            "imm16",
+            &format_args!("{:#x}", Self::IMM16_FIELD.extract(bits)),
+        );
+        s.field("IL", &Self::IL_FIELD.extract(bits));
+        s.field("EC", &Self::EC_FIELD.extract(bits));
+        s.field(
+            "ISS2",
+            &format_args!("{:#x}", Self::ISS2_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for EsrEl2Svc {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "EsrEl2Svc {{ imm16: {=u64:#x}, IL: {=u64}, EC: {=u64}, ISS2: {=u64:#x} }}",
+            Self::IMM16_FIELD.extract(self.bits()),
+            Self::IL_FIELD.extract(self.bits()),
+            Self::EC_FIELD.extract(self.bits()),
+            Self::ISS2_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for EsrEl2Svc {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for EsrEl2Svc {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl EsrEl2Svc {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("imm16"),
+                old: Self::IMM16_FIELD.extract(self.bits()),
+                new: Self::IMM16_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IL"),
+                old: Self::IL_FIELD.extract(self.bits()),
+                new: Self::IL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EC"),
+                old: Self::EC_FIELD.extract(self.bits()),
+                new: Self::EC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ISS2"),
+                old: Self::ISS2_FIELD.extract(self.bits()),
+                new: Self::ISS2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0001_1111_1111_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0001_1111_1111_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl EsrEl2Svc {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl EsrEl2Svc {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `ESR_EL2` system register value, when `EC` is 22.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct EsrEl2Hvc: u64 {
+        /// 32-bit instruction length.
+        const IL = 1 << 25;
+    }
+}
+
+#[cfg(feature = "el2")]
+impl EsrEl2Hvc {
+    /// Descriptor of the `imm16` field, for use with [`FieldAccess`].
+    pub const IMM16_FIELD: Field<Self, u16, 0, 16> = Field::new("ESR_EL2", "imm16");
+    /// Descriptor of the `IL` field, for use with [`FieldAccess`].
+    pub const IL_FIELD: Field<Self, bool, 25, 1> = Field::new("ESR_EL2", "IL");
+    /// Descriptor of the `EC` field, for use with [`FieldAccess`].
+    pub const EC_FIELD: Field<Self, u8, 26, 6> = Field::new("ESR_EL2", "EC");
+    /// Descriptor of the `ISS2` field, for use with [`FieldAccess`].
+    pub const ISS2_FIELD: Field<Self, u32, 32, 24> = Field::new("ESR_EL2", "ISS2");
+
+    /// Returns the value of the `imm16` field.
+    pub const fn imm16(self) -> u16 {
+        Self::IMM16_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `imm16` field.
+    pub const fn set_imm16(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::IMM16_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `imm16` field set to the given value.
+    pub const fn with_imm16(mut self, value: u16) -> Self {
+        self.set_imm16(value);
+        self
+    }
+
+    /// Sets the value of the `imm16` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_imm16(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_imm16(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `imm16` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_imm16(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::IMM16_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `EC` field.
+    pub const fn ec(self) -> u8 {
+        Self::EC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `EC` field.
+    pub const fn set_ec(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::EC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `EC` field set to the given value.
+    pub const fn with_ec(mut self, value: u8) -> Self {
+        self.set_ec(value);
+        self
+    }
+
+    /// Sets the value of the `EC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ec(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ec(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `EC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ec(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::EC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ISS2` field.
+    pub const fn iss2(self) -> u32 {
+        Self::ISS2_FIELD.extract(self.bits()) as u32
+    }
+
+    /// Sets the value of the `ISS2` field.
+    pub const fn set_iss2(&mut self, value: u32) {
+        *self = Self::from_bits_retain(Self::ISS2_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value.
+    pub const fn with_iss2(mut self, value: u32) -> Self {
+        self.set_iss2(value);
+        self
+    }
+
+    /// Sets the value of the `ISS2` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_iss2(&mut self, value: u32) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_iss2(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_iss2(self, value: u32) -> Result<Self, FieldOverflow> {
+        match Self::ISS2_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Display for EsrEl2Hvc {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("EsrEl2Hvc");
+        let bits = self.bits();
+        s.field(
+            "imm16",
+            &format_args!("{:#x}", Self::IMM16_FIELD.extract(bits)),
+        );
+        s.field("IL", &Self::IL_FIELD.extract(bits));
+        s.field("EC", &Self::EC_FIELD.extract(bits));
+        s.field(
+            "ISS2",
+            &format_args!("{:#x}", Self::ISS2_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for EsrEl2Hvc {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "EsrEl2Hvc {{ imm16: {=u64:#x}, IL: {=u64}, EC: {=u64}, ISS2: {=u64:#x} }}",
+            Self::IMM16_FIELD.extract(self.bits()),
+            Self::IL_FIELD.extract(self.bits()),
+            Self::EC_FIELD.extract(self.bits()),
+            Self::ISS2_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for EsrEl2Hvc {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for EsrEl2Hvc {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl EsrEl2Hvc {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("imm16"),
+                old: Self::IMM16_FIELD.extract(self.bits()),
+                new: Self::IMM16_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IL"),
+                old: Self::IL_FIELD.extract(self.bits()),
+                new: Self::IL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EC"),
+                old: Self::EC_FIELD.extract(self.bits()),
+                new: Self::EC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ISS2"),
+                old: Self::ISS2_FIELD.extract(self.bits()),
+                new: Self::ISS2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0001_1111_1111_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0001_1111_1111_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl EsrEl2Hvc {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl EsrEl2Hvc {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `ESR_EL2` system register value, when `EC` is 23.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct EsrEl2Smc: u64 {
+        /// 32-bit instruction length.
+        const IL = 1 << 25;
+    }
+}
+
+#[cfg(feature = "el2")]
+impl EsrEl2Smc {
+    /// Descriptor of the `imm16` field, for use with [`FieldAccess`].
+    pub const IMM16_FIELD: Field<Self, u16, 0, 16> = Field::new("ESR_EL2", "imm16");
+    /// Descriptor of the `IL` field, for use with [`FieldAccess`].
+    pub const IL_FIELD: Field<Self, bool, 25, 1> = Field::new("ESR_EL2", "IL");
+    /// Descriptor of the `EC` field, for use with [`FieldAccess`].
+    pub const EC_FIELD: Field<Self, u8, 26, 6> = Field::new("ESR_EL2", "EC");
+    /// Descriptor of the `ISS2` field, for use with [`FieldAccess`].
+    pub const ISS2_FIELD: Field<Self, u32, 32, 24> = Field::new("ESR_EL2", "ISS2");
+
+    /// Returns the value of the `imm16` field.
+    pub const fn imm16(self) -> u16 {
+        Self::IMM16_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `imm16` field.
+    pub const fn set_imm16(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::IMM16_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `imm16` field set to the given value.
+    pub const fn with_imm16(mut self, value: u16) -> Self {
+        self.set_imm16(value);
+        self
+    }
+
+    /// Sets the value of the `imm16` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_imm16(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_imm16(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `imm16` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_imm16(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::IMM16_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `EC` field.
+    pub const fn ec(self) -> u8 {
+        Self::EC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `EC` field.
+    pub const fn set_ec(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::EC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `EC` field set to the given value.
+    pub const fn with_ec(mut self, value: u8) -> Self {
+        self.set_ec(value);
+        self
+    }
+
+    /// Sets the value of the `EC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ec(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ec(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `EC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ec(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::EC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ISS2` field.
+    pub const fn iss2(self) -> u32 {
+        Self::ISS2_FIELD.extract(self.bits()) as u32
+    }
+
+    /// Sets the value of the `ISS2` field.
+    pub const fn set_iss2(&mut self, value: u32) {
+        *self = Self::from_bits_retain(Self::ISS2_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value.
+    pub const fn with_iss2(mut self, value: u32) -> Self {
+        self.set_iss2(value);
+        self
+    }
+
+    /// Sets the value of the `ISS2` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_iss2(&mut self, value: u32) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_iss2(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_iss2(self, value: u32) -> Result<Self, FieldOverflow> {
+        match Self::ISS2_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Display for EsrEl2Smc {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("EsrEl2Smc");
+        let bits = self.bits();
+        s.field(
+            "imm16",
+            &format_args!("{:#x}", Self::IMM16_FIELD.extract(bits)),
+        );
+        s.field("IL", &Self::IL_FIELD.extract(bits));
+        s.field("EC", &Self::EC_FIELD.extract(bits));
+        s.field(
+            "ISS2",
+            &format_args!("{:#x}", Self::ISS2_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for EsrEl2Smc {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "EsrEl2Smc {{ imm16: {=u64:#x}, IL: {=u64}, EC: {=u64}, ISS2: {=u64:#x} }}",
+            Self::IMM16_FIELD.extract(self.bits()),
+            Self::IL_FIELD.extract(self.bits()),
+            Self::EC_FIELD.extract(self.bits()),
+            Self::ISS2_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for EsrEl2Smc {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for EsrEl2Smc {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl EsrEl2Smc {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("imm16"),
+                old: Self::IMM16_FIELD.extract(self.bits()),
+                new: Self::IMM16_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IL"),
+                old: Self::IL_FIELD.extract(self.bits()),
+                new: Self::IL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EC"),
+                old: Self::EC_FIELD.extract(self.bits()),
+                new: Self::EC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ISS2"),
+                old: Self::ISS2_FIELD.extract(self.bits()),
+                new: Self::ISS2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0001_1111_1111_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0001_1111_1111_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl EsrEl2Smc {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl EsrEl2Smc {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `ESR_EL2` system register value, when `EC` is 24.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct EsrEl2Sysreg: u64 {
+        /// `Direction` bit.
+        const DIRECTION = 1 << 0;
+        /// 32-bit instruction length.
+        const IL = 1 << 25;
+    }
+}
+
+#[cfg(feature = "el2")]
+impl EsrEl2Sysreg {
+    /// Descriptor of the `Direction` field, for use with [`FieldAccess`].
+    pub const DIRECTION_FIELD: Field<Self, bool, 0, 1> = Field::new("ESR_EL2", "Direction");
+    /// Descriptor of the `CRm` field, for use with [`FieldAccess`].
+    pub const CRM_FIELD: Field<Self, u8, 1, 4> = Field::new("ESR_EL2", "CRm");
+    /// Descriptor of the `Rt` field, for use with [`FieldAccess`].
+    pub const RT_FIELD: Field<Self, u8, 5, 5> = Field::new("ESR_EL2", "Rt");
+    /// Descriptor of the `CRn` field, for use with [`FieldAccess`].
+    pub const CRN_FIELD: Field<Self, u8, 10, 4> = Field::new("ESR_EL2", "CRn");
+    /// Descriptor of the `Op1` field, for use with [`FieldAccess`].
+    pub const OP1_FIELD: Field<Self, u8, 14, 3> = Field::new("ESR_EL2", "Op1");
+    /// Descriptor of the `Op2` field, for use with [`FieldAccess`].
+    pub const OP2_FIELD: Field<Self, u8, 17, 3> = Field::new("ESR_EL2", "Op2");
+    /// Descriptor of the `Op0` field, for use with [`FieldAccess`].
+    pub const OP0_FIELD: Field<Self, u8, 20, 2> = Field::new("ESR_EL2", "Op0");
+    /// Descriptor of the `IL` field, for use with [`FieldAccess`].
+    pub const IL_FIELD: Field<Self, bool, 25, 1> = Field::new("ESR_EL2", "IL");
+    /// Descriptor of the `EC` field, for use with [`FieldAccess`].
+    pub const EC_FIELD: Field<Self, u8, 26, 6> = Field::new("ESR_EL2", "EC");
+    /// Descriptor of the `ISS2` field, for use with [`FieldAccess`].
+    pub const ISS2_FIELD: Field<Self, u32, 32, 24> = Field::new("ESR_EL2", "ISS2");
+
+    /// Returns the value of the `CRm` field.
+    pub const fn crm(self) -> u8 {
+        Self::CRM_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `CRm` field.
+    pub const fn set_crm(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::CRM_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `CRm` field set to the given value.
+    pub const fn with_crm(mut self, value: u8) -> Self {
+        self.set_crm(value);
+        self
+    }
+
+    /// Sets the value of the `CRm` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_crm(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_crm(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `CRm` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_crm(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::CRM_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `Rt` field.
+    pub const fn rt(self) -> u8 {
+        Self::RT_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `Rt` field.
+    pub const fn set_rt(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::RT_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `Rt` field set to the given value.
+    pub const fn with_rt(mut self, value: u8) -> Self {
+        self.set_rt(value);
+        self
+    }
+
+    /// Sets the value of the `Rt` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_rt(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_rt(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `Rt` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_rt(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::RT_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `CRn` field.
+    pub const fn crn(self) -> u8 {
+        Self::CRN_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `CRn` field.
+    pub const fn set_crn(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::CRN_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `CRn` field set to the given value.
+    pub const fn with_crn(mut self, value: u8) -> Self {
+        self.set_crn(value);
+        self
+    }
+
+    /// Sets the value of the `CRn` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_crn(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_crn(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `CRn` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_crn(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::CRN_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `Op1` field.
+    pub const fn op1(self) -> u8 {
+        Self::OP1_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `Op1` field.
+    pub const fn set_op1(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::OP1_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `Op1` field set to the given value.
+    pub const fn with_op1(mut self, value: u8) -> Self {
+        self.set_op1(value);
+        self
+    }
+
+    /// Sets the value of the `Op1` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_op1(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_op1(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `Op1` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_op1(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::OP1_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `Op2` field.
+    pub const fn op2(self) -> u8 {
+        Self::OP2_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `Op2` field.
+    pub const fn set_op2(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::OP2_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `Op2` field set to the given value.
+    pub const fn with_op2(mut self, value: u8) -> Self {
+        self.set_op2(value);
+        self
+    }
+
+    /// Sets the value of the `Op2` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_op2(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_op2(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `Op2` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_op2(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::OP2_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `Op0` field.
+    pub const fn op0(self) -> u8 {
+        Self::OP0_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `Op0` field.
+    pub const fn set_op0(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::OP0_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `Op0` field set to the given value.
+    pub const fn with_op0(mut self, value: u8) -> Self {
+        self.set_op0(value);
+        self
+    }
+
+    /// Sets the value of the `Op0` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_op0(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_op0(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `Op0` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_op0(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::OP0_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `EC` field.
+    pub const fn ec(self) -> u8 {
+        Self::EC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `EC` field.
+    pub const fn set_ec(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::EC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `EC` field set to the given value.
+    pub const fn with_ec(mut self, value: u8) -> Self {
+        self.set_ec(value);
+        self
+    }
+
+    /// Sets the value of the `EC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ec(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ec(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `EC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ec(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::EC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ISS2` field.
+    pub const fn iss2(self) -> u32 {
+        Self::ISS2_FIELD.extract(self.bits()) as u32
+    }
+
+    /// Sets the value of the `ISS2` field.
+    pub const fn set_iss2(&mut self, value: u32) {
+        *self = Self::from_bits_retain(Self::ISS2_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value.
+    pub const fn with_iss2(mut self, value: u32) -> Self {
+        self.set_iss2(value);
+        self
+    }
+
+    /// Sets the value of the `ISS2` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_iss2(&mut self, value: u32) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_iss2(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_iss2(self, value: u32) -> Result<Self, FieldOverflow> {
+        match Self::ISS2_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Display for EsrEl2Sysreg {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("EsrEl2Sysreg");
+        let bits = self.bits();
+        s.field("Direction", &Self::DIRECTION_FIELD.extract(bits));
+        s.field("CRm", &Self::CRM_FIELD.extract(bits));
+        s.field("Rt", &Self::RT_FIELD.extract(bits));
+        s.field("CRn", &Self::CRN_FIELD.extract(bits));
+        s.field("Op1", &Self::OP1_FIELD.extract(bits));
+        s.field("Op2", &Self::OP2_FIELD.extract(bits));
+        s.field("Op0", &Self::OP0_FIELD.extract(bits));
+        s.field("IL", &Self::IL_FIELD.extract(bits));
+        s.field("EC", &Self::EC_FIELD.extract(bits));
+        s.field(
+            "ISS2",
+            &format_args!("{:#x}", Self::ISS2_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for EsrEl2Sysreg {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "EsrEl2Sysreg {{ Direction: {=u64}, CRm: {=u64}, Rt: {=u64}, CRn: {=u64}, Op1: {=u64}, Op2: {=u64}, Op0: {=u64}, IL: {=u64}, EC: {=u64}, ISS2: {=u64:#x} }}",
+            Self::DIRECTION_FIELD.extract(self.bits()),
+            Self::CRM_FIELD.extract(self.bits()),
+            Self::RT_FIELD.extract(self.bits()),
+            Self::CRN_FIELD.extract(self.bits()),
+            Self::OP1_FIELD.extract(self.bits()),
+            Self::OP2_FIELD.extract(self.bits()),
+            Self::OP0_FIELD.extract(self.bits()),
+            Self::IL_FIELD.extract(self.bits()),
+            Self::EC_FIELD.extract(self.bits()),
+            Self::ISS2_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for EsrEl2Sysreg {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for EsrEl2Sysreg {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl EsrEl2Sysreg {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("Direction"),
+                old: Self::DIRECTION_FIELD.extract(self.bits()),
+                new: Self::DIRECTION_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CRm"),
+                old: Self::CRM_FIELD.extract(self.bits()),
+                new: Self::CRM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("Rt"),
+                old: Self::RT_FIELD.extract(self.bits()),
+                new: Self::RT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CRn"),
+                old: Self::CRN_FIELD.extract(self.bits()),
+                new: Self::CRN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("Op1"),
+                old: Self::OP1_FIELD.extract(self.bits()),
+                new: Self::OP1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("Op2"),
+                old: Self::OP2_FIELD.extract(self.bits()),
+                new: Self::OP2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("Op0"),
+                old: Self::OP0_FIELD.extract(self.bits()),
+                new: Self::OP0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IL"),
+                old: Self::IL_FIELD.extract(self.bits()),
+                new: Self::IL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EC"),
+                old: Self::EC_FIELD.extract(self.bits()),
+                new: Self::EC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ISS2"),
+                old: Self::ISS2_FIELD.extract(self.bits()),
+                new: Self::ISS2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0001_1100_0000_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0001_1100_0000_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl EsrEl2Sysreg {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl EsrEl2Sysreg {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `ESR_EL2` system register value, when `EC` is 32.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct EsrEl2InstructionAbortLower: u64 {
+        /// `S1PTW` bit.
+        const S1PTW = 1 << 7;
+        /// `EA` bit.
+        const EA = 1 << 9;
+        /// `FnV` bit.
+        const FNV = 1 << 10;
+        /// 32-bit instruction length.
+        const IL = 1 << 25;
+    }
+}
+
+#[cfg(feature = "el2")]
+impl EsrEl2InstructionAbortLower {
+    /// Descriptor of the `IFSC` field, for use with [`FieldAccess`].
+    pub const IFSC_FIELD: Field<Self, u8, 0, 6> = Field::new("ESR_EL2", "IFSC");
+    /// Descriptor of the `S1PTW` field, for use with [`FieldAccess`].
+    pub const S1PTW_FIELD: Field<Self, bool, 7, 1> = Field::new("ESR_EL2", "S1PTW");
+    /// Descriptor of the `EA` field, for use with [`FieldAccess`].
+    pub const EA_FIELD: Field<Self, bool, 9, 1> = Field::new("ESR_EL2", "EA");
+    /// Descriptor of the `FnV` field, for use with [`FieldAccess`].
+    pub const FNV_FIELD: Field<Self, bool, 10, 1> = Field::new("ESR_EL2", "FnV");
+    /// Descriptor of the `SET` field, for use with [`FieldAccess`].
+    pub const SET_FIELD: Field<Self, u8, 11, 2> = Field::new("ESR_EL2", "SET");
+    /// Descriptor of the `IL` field, for use with [`FieldAccess`].
+    pub const IL_FIELD: Field<Self, bool, 25, 1> = Field::new("ESR_EL2", "IL");
+    /// Descriptor of the `EC` field, for use with [`FieldAccess`].
+    pub const EC_FIELD: Field<Self, u8, 26, 6> = Field::new("ESR_EL2", "EC");
+    /// Descriptor of the `ISS2` field, for use with [`FieldAccess`].
+    pub const ISS2_FIELD: Field<Self, u32, 32, 24> = Field::new("ESR_EL2", "ISS2");
+
+    /// Returns the value of the `IFSC` field.
+    pub const fn ifsc(self) -> u8 {
+        Self::IFSC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `IFSC` field.
+    pub const fn set_ifsc(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::IFSC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `IFSC` field set to the given value.
+    pub const fn with_ifsc(mut self, value: u8) -> Self {
+        self.set_ifsc(value);
+        self
+    }
+
+    /// Sets the value of the `IFSC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ifsc(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ifsc(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `IFSC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ifsc(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::IFSC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SET` field.
+    pub const fn set_(self) -> u8 {
+        Self::SET_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SET` field.
+    pub const fn set_set_(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SET_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SET` field set to the given value.
+    pub const fn with_set_(mut self, value: u8) -> Self {
+        self.set_set_(value);
+        self
+    }
+
+    /// Sets the value of the `SET` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_set_(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_set_(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SET` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_set_(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SET_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `EC` field.
+    pub const fn ec(self) -> u8 {
+        Self::EC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `EC` field.
+    pub const fn set_ec(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::EC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `EC` field set to the given value.
+    pub const fn with_ec(mut self, value: u8) -> Self {
+        self.set_ec(value);
+        self
+    }
+
+    /// Sets the value of the `EC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ec(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ec(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `EC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ec(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::EC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ISS2` field.
+    pub const fn iss2(self) -> u32 {
+        Self::ISS2_FIELD.extract(self.bits()) as u32
+    }
+
+    /// Sets the value of the `ISS2` field.
+    pub const fn set_iss2(&mut self, value: u32) {
+        *self = Self::from_bits_retain(Self::ISS2_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value.
+    pub const fn with_iss2(mut self, value: u32) -> Self {
+        self.set_iss2(value);
+        self
+    }
+
+    /// Sets the value of the `ISS2` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_iss2(&mut self, value: u32) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_iss2(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_iss2(self, value: u32) -> Result<Self, FieldOverflow> {
+        match Self::ISS2_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Display for EsrEl2InstructionAbortLower {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("EsrEl2InstructionAbortLower");
+        let bits = self.bits();
+        s.field("IFSC", &Self::IFSC_FIELD.extract(bits));
+        s.field("S1PTW", &Self::S1PTW_FIELD.extract(bits));
+        s.field("EA", &Self::EA_FIELD.extract(bits));
+        s.field("FnV", &Self::FNV_FIELD.extract(bits));
+        s.field("SET", &Self::SET_FIELD.extract(bits));
+        s.field("IL", &Self::IL_FIELD.extract(bits));
+        s.field("EC", &Self::EC_FIELD.extract(bits));
+        s.field(
+            "ISS2",
+            &format_args!("{:#x}", Self::ISS2_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for EsrEl2InstructionAbortLower {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "EsrEl2InstructionAbortLower {{ IFSC: {=u64}, S1PTW: {=u64}, EA: {=u64}, FnV: {=u64}, SET: {=u64}, IL: {=u64}, EC: {=u64}, ISS2: {=u64:#x} }}",
+            Self::IFSC_FIELD.extract(self.bits()),
+            Self::S1PTW_FIELD.extract(self.bits()),
+            Self::EA_FIELD.extract(self.bits()),
+            Self::FNV_FIELD.extract(self.bits()),
+            Self::SET_FIELD.extract(self.bits()),
+            Self::IL_FIELD.extract(self.bits()),
+            Self::EC_FIELD.extract(self.bits()),
+            Self::ISS2_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for EsrEl2InstructionAbortLower {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for EsrEl2InstructionAbortLower {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl EsrEl2InstructionAbortLower {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("IFSC"),
+                old: Self::IFSC_FIELD.extract(self.bits()),
+                new: Self::IFSC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("S1PTW"),
+                old: Self::S1PTW_FIELD.extract(self.bits()),
+                new: Self::S1PTW_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EA"),
+                old: Self::EA_FIELD.extract(self.bits()),
+                new: Self::EA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FnV"),
+                old: Self::FNV_FIELD.extract(self.bits()),
+                new: Self::FNV_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SET"),
+                old: Self::SET_FIELD.extract(self.bits()),
+                new: Self::SET_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IL"),
+                old: Self::IL_FIELD.extract(self.bits()),
+                new: Self::IL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EC"),
+                old: Self::EC_FIELD.extract(self.bits()),
+                new: Self::EC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ISS2"),
+                old: Self::ISS2_FIELD.extract(self.bits()),
+                new: Self::ISS2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0001_1111_1111_1110_0001_0100_0000,
+                new: other.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0001_1111_1111_1110_0001_0100_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl EsrEl2InstructionAbortLower {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl EsrEl2InstructionAbortLower {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `ESR_EL2` system register value, when `EC` is 33.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct EsrEl2InstructionAbort: u64 {
+        /// `S1PTW` bit.
+        const S1PTW = 1 << 7;
+        /// `EA` bit.
+        const EA = 1 << 9;
+        /// `FnV` bit.
+        const FNV = 1 << 10;
+        /// 32-bit instruction length.
+        const IL = 1 << 25;
+    }
+}
+
+#[cfg(feature = "el2")]
+impl EsrEl2InstructionAbort {
+    /// Descriptor of the `IFSC` field, for use with [`FieldAccess`].
+    pub const IFSC_FIELD: Field<Self, u8, 0, 6> = Field::new("ESR_EL2", "IFSC");
+    /// Descriptor of the `S1PTW` field, for use with [`FieldAccess`].
+    pub const S1PTW_FIELD: Field<Self, bool, 7, 1> = Field::new("ESR_EL2", "S1PTW");
+    /// Descriptor of the `EA` field, for use with [`FieldAccess`].
+    pub const EA_FIELD: Field<Self, bool, 9, 1> = Field::new("ESR_EL2", "EA");
+    /// Descriptor of the `FnV` field, for use with [`FieldAccess`].
+    pub const FNV_FIELD: Field<Self, bool, 10, 1> = Field::new("ESR_EL2", "FnV");
+    /// Descriptor of the `SET` field, for use with [`FieldAccess`].
+    pub const SET_FIELD: Field<Self, u8, 11, 2> = Field::new("ESR_EL2", "SET");
+    /// Descriptor of the `IL` field, for use with [`FieldAccess`].
+    pub const IL_FIELD: Field<Self, bool, 25, 1> = Field::new("ESR_EL2", "IL");
+    /// Descriptor of the `EC` field, for use with [`FieldAccess`].
+    pub const EC_FIELD: Field<Self, u8, 26, 6> = Field::new("ESR_EL2", "EC");
+    /// Descriptor of the `ISS2` field, for use with [`FieldAccess`].
+    pub const ISS2_FIELD: Field<Self, u32, 32, 24> = Field::new("ESR_EL2", "ISS2");
+
+    /// Returns the value of the `IFSC` field.
+    pub const fn ifsc(self) -> u8 {
+        Self::IFSC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `IFSC` field.
+    pub const fn set_ifsc(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::IFSC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `IFSC` field set to the given value.
+    pub const fn with_ifsc(mut self, value: u8) -> Self {
+        self.set_ifsc(value);
+        self
+    }
+
+    /// Sets the value of the `IFSC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ifsc(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ifsc(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `IFSC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ifsc(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::IFSC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SET` field.
+    pub const fn set_(self) -> u8 {
+        Self::SET_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SET` field.
+    pub const fn set_set_(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SET_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SET` field set to the given value.
+    pub const fn with_set_(mut self, value: u8) -> Self {
+        self.set_set_(value);
+        self
+    }
+
+    /// Sets the value of the `SET` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_set_(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_set_(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SET` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_set_(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SET_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `EC` field.
+    pub const fn ec(self) -> u8 {
+        Self::EC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `EC` field.
+    pub const fn set_ec(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::EC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `EC` field set to the given value.
+    pub const fn with_ec(mut self, value: u8) -> Self {
+        self.set_ec(value);
+        self
+    }
+
+    /// Sets the value of the `EC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ec(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ec(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `EC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ec(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::EC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ISS2` field.
+    pub const fn iss2(self) -> u32 {
+        Self::ISS2_FIELD.extract(self.bits()) as u32
+    }
+
+    /// Sets the value of the `ISS2` field.
+    pub const fn set_iss2(&mut self, value: u32) {
+        *self = Self::from_bits_retain(Self::ISS2_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value.
+    pub const fn with_iss2(mut self, value: u32) -> Self {
+        self.set_iss2(value);
+        self
+    }
+
+    /// Sets the value of the `ISS2` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_iss2(&mut self, value: u32) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_iss2(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_iss2(self, value: u32) -> Result<Self, FieldOverflow> {
+        match Self::ISS2_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Display for EsrEl2InstructionAbort {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("EsrEl2InstructionAbort");
+        let bits = self.bits();
+        s.field("IFSC", &Self::IFSC_FIELD.extract(bits));
+        s.field("S1PTW", &Self::S1PTW_FIELD.extract(bits));
+        s.field("EA", &Self::EA_FIELD.extract(bits));
+        s.field("FnV", &Self::FNV_FIELD.extract(bits));
+        s.field("SET", &Self::SET_FIELD.extract(bits));
+        s.field("IL", &Self::IL_FIELD.extract(bits));
+        s.field("EC", &Self::EC_FIELD.extract(bits));
+        s.field(
+            "ISS2",
+            &format_args!("{:#x}", Self::ISS2_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for EsrEl2InstructionAbort {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "EsrEl2InstructionAbort {{ IFSC: {=u64}, S1PTW: {=u64}, EA: {=u64}, FnV: {=u64}, SET: {=u64}, IL: {=u64}, EC: {=u64}, ISS2: {=u64:#x} }}",
+            Self::IFSC_FIELD.extract(self.bits()),
+            Self::S1PTW_FIELD.extract(self.bits()),
+            Self::EA_FIELD.extract(self.bits()),
+            Self::FNV_FIELD.extract(self.bits()),
+            Self::SET_FIELD.extract(self.bits()),
+            Self::IL_FIELD.extract(self.bits()),
+            Self::EC_FIELD.extract(self.bits()),
+            Self::ISS2_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for EsrEl2InstructionAbort {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for EsrEl2InstructionAbort {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl EsrEl2InstructionAbort {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("IFSC"),
+                old: Self::IFSC_FIELD.extract(self.bits()),
+                new: Self::IFSC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("S1PTW"),
+                old: Self::S1PTW_FIELD.extract(self.bits()),
+                new: Self::S1PTW_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EA"),
+                old: Self::EA_FIELD.extract(self.bits()),
+                new: Self::EA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FnV"),
+                old: Self::FNV_FIELD.extract(self.bits()),
+                new: Self::FNV_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SET"),
+                old: Self::SET_FIELD.extract(self.bits()),
+                new: Self::SET_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IL"),
+                old: Self::IL_FIELD.extract(self.bits()),
+                new: Self::IL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EC"),
+                old: Self::EC_FIELD.extract(self.bits()),
+                new: Self::EC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ISS2"),
+                old: Self::ISS2_FIELD.extract(self.bits()),
+                new: Self::ISS2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0001_1111_1111_1110_0001_0100_0000,
+                new: other.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0001_1111_1111_1110_0001_0100_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl EsrEl2InstructionAbort {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl EsrEl2InstructionAbort {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `ESR_EL2` system register value, when `EC` is 36.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct EsrEl2DataAbortLower: u64 {
+        /// `WnR` bit.
+        const WNR = 1 << 6;
+        /// `S1PTW` bit.
+        const S1PTW = 1 << 7;
+        /// `CM` bit.
+        const CM = 1 << 8;
+        /// `EA` bit.
+        const EA = 1 << 9;
+        /// `FnV` bit.
+        const FNV = 1 << 10;
+        /// `VNCR` bit.
+        const VNCR = 1 << 13;
+        /// `AR` bit.
+        const AR = 1 << 14;
+        /// `SF` bit.
+        const SF = 1 << 15;
+        /// `SSE` bit.
+        const SSE = 1 << 21;
+        /// `ISV` bit.
+        const ISV = 1 << 24;
+        /// 32-bit instruction length.
+        const IL = 1 << 25;
+    }
+}
+
+#[cfg(feature = "el2")]
+impl EsrEl2DataAbortLower {
+    /// Descriptor of the `DFSC` field, for use with [`FieldAccess`].
+    pub const DFSC_FIELD: Field<Self, u8, 0, 6> = Field::new("ESR_EL2", "DFSC");
+    /// Descriptor of the `WnR` field, for use with [`FieldAccess`].
+    pub const WNR_FIELD: Field<Self, bool, 6, 1> = Field::new("ESR_EL2", "WnR");
+    /// Descriptor of the `S1PTW` field, for use with [`FieldAccess`].
+    pub const S1PTW_FIELD: Field<Self, bool, 7, 1> = Field::new("ESR_EL2", "S1PTW");
+    /// Descriptor of the `CM` field, for use with [`FieldAccess`].
+    pub const CM_FIELD: Field<Self, bool, 8, 1> = Field::new("ESR_EL2", "CM");
+    /// Descriptor of the `EA` field, for use with [`FieldAccess`].
+    pub const EA_FIELD: Field<Self, bool, 9, 1> = Field::new("ESR_EL2", "EA");
+    /// Descriptor of the `FnV` field, for use with [`FieldAccess`].
+    pub const FNV_FIELD: Field<Self, bool, 10, 1> = Field::new("ESR_EL2", "FnV");
+    /// Descriptor of the `SET` field, for use with [`FieldAccess`].
+    pub const SET_FIELD: Field<Self, u8, 11, 2> = Field::new("ESR_EL2", "SET");
+    /// Descriptor of the `VNCR` field, for use with [`FieldAccess`].
+    pub const VNCR_FIELD: Field<Self, bool, 13, 1> = Field::new("ESR_EL2", "VNCR");
+    /// Descriptor of the `AR` field, for use with [`FieldAccess`].
+    pub const AR_FIELD: Field<Self, bool, 14, 1> = Field::new("ESR_EL2", "AR");
+    /// Descriptor of the `SF` field, for use with [`FieldAccess`].
+    pub const SF_FIELD: Field<Self, bool, 15, 1> = Field::new("ESR_EL2", "SF");
+    /// Descriptor of the `SRT` field, for use with [`FieldAccess`].
+    pub const SRT_FIELD: Field<Self, u8, 16, 5> = Field::new("ESR_EL2", "SRT");
+    /// Descriptor of the `SSE` field, for use with [`FieldAccess`].
+    pub const SSE_FIELD: Field<Self, bool, 21, 1> = Field::new("ESR_EL2", "SSE");
+    /// Descriptor of the `SAS` field, for use with [`FieldAccess`].
+    pub const SAS_FIELD: Field<Self, u8, 22, 2> = Field::new("ESR_EL2", "SAS");
+    /// Descriptor of the `ISV` field, for use with [`FieldAccess`].
+    pub const ISV_FIELD: Field<Self, bool, 24, 1> = Field::new("ESR_EL2", "ISV");
+    /// Descriptor of the `IL` field, for use with [`FieldAccess`].
+    pub const IL_FIELD: Field<Self, bool, 25, 1> = Field::new("ESR_EL2", "IL");
+    /// Descriptor of the `EC` field, for use with [`FieldAccess`].
+    pub const EC_FIELD: Field<Self, u8, 26, 6> = Field::new("ESR_EL2", "EC");
+    /// Descriptor of the `ISS2` field, for use with [`FieldAccess`].
+    pub const ISS2_FIELD: Field<Self, u32, 32, 24> = Field::new("ESR_EL2", "ISS2");
+
+    /// Returns the value of the `DFSC` field.
+    pub const fn dfsc(self) -> u8 {
+        Self::DFSC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `DFSC` field.
+    pub const fn set_dfsc(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::DFSC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `DFSC` field set to the given value.
+    pub const fn with_dfsc(mut self, value: u8) -> Self {
+        self.set_dfsc(value);
+        self
+    }
+
+    /// Sets the value of the `DFSC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_dfsc(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_dfsc(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `DFSC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_dfsc(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::DFSC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SET` field.
+    pub const fn set_(self) -> u8 {
+        Self::SET_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SET` field.
+    pub const fn set_set_(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SET_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SET` field set to the given value.
+    pub const fn with_set_(mut self, value: u8) -> Self {
+        self.set_set_(value);
+        self
+    }
+
+    /// Sets the value of the `SET` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_set_(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_set_(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SET` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_set_(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SET_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SRT` field.
+    pub const fn srt(self) -> u8 {
+        Self::SRT_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SRT` field.
+    pub const fn set_srt(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SRT_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SRT` field set to the given value.
+    pub const fn with_srt(mut self, value: u8) -> Self {
+        self.set_srt(value);
+        self
+    }
+
+    /// Sets the value of the `SRT` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_srt(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_srt(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SRT` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_srt(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SRT_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SAS` field.
+    pub const fn sas(self) -> u8 {
+        Self::SAS_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SAS` field.
+    pub const fn set_sas(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SAS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SAS` field set to the given value.
+    pub const fn with_sas(mut self, value: u8) -> Self {
+        self.set_sas(value);
+        self
+    }
+
+    /// Sets the value of the `SAS` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_sas(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_sas(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SAS` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_sas(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SAS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `EC` field.
+    pub const fn ec(self) -> u8 {
+        Self::EC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `EC` field.
+    pub const fn set_ec(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::EC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `EC` field set to the given value.
+    pub const fn with_ec(mut self, value: u8) -> Self {
+        self.set_ec(value);
+        self
+    }
+
+    /// Sets the value of the `EC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ec(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ec(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `EC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ec(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::EC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ISS2` field.
+    pub const fn iss2(self) -> u32 {
+        Self::ISS2_FIELD.extract(self.bits()) as u32
+    }
+
+    /// Sets the value of the `ISS2` field.
+    pub const fn set_iss2(&mut self, value: u32) {
+        *self = Self::from_bits_retain(Self::ISS2_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value.
+    pub const fn with_iss2(mut self, value: u32) -> Self {
+        self.set_iss2(value);
+        self
+    }
+
+    /// Sets the value of the `ISS2` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_iss2(&mut self, value: u32) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_iss2(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_iss2(self, value: u32) -> Result<Self, FieldOverflow> {
+        match Self::ISS2_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Display for EsrEl2DataAbortLower {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("EsrEl2DataAbortLower");
+        let bits = self.bits();
+        s.field("DFSC", &Self::DFSC_FIELD.extract(bits));
+        s.field("WnR", &Self::WNR_FIELD.extract(bits));
+        s.field("S1PTW", &Self::S1PTW_FIELD.extract(bits));
+        s.field("CM", &Self::CM_FIELD.extract(bits));
+        s.field("EA", &Self::EA_FIELD.extract(bits));
+        s.field("FnV", &Self::FNV_FIELD.extract(bits));
+        s.field("SET", &Self::SET_FIELD.extract(bits));
+        s.field("VNCR", &Self::VNCR_FIELD.extract(bits));
+        s.field("AR", &Self::AR_FIELD.extract(bits));
+        s.field("SF", &Self::SF_FIELD.extract(bits));
+        s.field("SRT", &Self::SRT_FIELD.extract(bits));
+        s.field("SSE", &Self::SSE_FIELD.extract(bits));
+        s.field("SAS", &Self::SAS_FIELD.extract(bits));
+        s.field("ISV", &Self::ISV_FIELD.extract(bits));
+        s.field("IL", &Self::IL_FIELD.extract(bits));
+        s.field("EC", &Self::EC_FIELD.extract(bits));
+        s.field(
+            "ISS2",
+            &format_args!("{:#x}", Self::ISS2_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for EsrEl2DataAbortLower {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "EsrEl2DataAbortLower {{ DFSC: {=u64}, WnR: {=u64}, S1PTW: {=u64}, CM: {=u64}, EA: {=u64}, FnV: {=u64}, SET: {=u64}, VNCR: {=u64}, AR: {=u64}, SF: {=u64}, SRT: {=u64}, SSE: {=u64}, SAS: {=u64}, ISV: {=u64}, IL: {=u64}, EC: {=u64}, ISS2: {=u64:#x} }}",
+            Self::DFSC_FIELD.extract(self.bits()),
+            Self::WNR_FIELD.extract(self.bits()),
+            Self::S1PTW_FIELD.extract(self.bits()),
+            Self::CM_FIELD.extract(self.bits()),
+            Self::EA_FIELD.extract(self.bits()),
+            Self::FNV_FIELD.extract(self.bits()),
+            Self::SET_FIELD.extract(self.bits()),
+            Self::VNCR_FIELD.extract(self.bits()),
+            Self::AR_FIELD.extract(self.bits()),
+            Self::SF_FIELD.extract(self.bits()),
+            Self::SRT_FIELD.extract(self.bits()),
+            Self::SSE_FIELD.extract(self.bits()),
+            Self::SAS_FIELD.extract(self.bits()),
+            Self::ISV_FIELD.extract(self.bits()),
+            Self::IL_FIELD.extract(self.bits()),
+            Self::EC_FIELD.extract(self.bits()),
+            Self::ISS2_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for EsrEl2DataAbortLower {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for EsrEl2DataAbortLower {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl EsrEl2DataAbortLower {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("DFSC"),
+                old: Self::DFSC_FIELD.extract(self.bits()),
+                new: Self::DFSC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("WnR"),
+                old: Self::WNR_FIELD.extract(self.bits()),
+                new: Self::WNR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("S1PTW"),
+                old: Self::S1PTW_FIELD.extract(self.bits()),
+                new: Self::S1PTW_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CM"),
+                old: Self::CM_FIELD.extract(self.bits()),
+                new: Self::CM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EA"),
+                old: Self::EA_FIELD.extract(self.bits()),
+                new: Self::EA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FnV"),
+                old: Self::FNV_FIELD.extract(self.bits()),
+                new: Self::FNV_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SET"),
+                old: Self::SET_FIELD.extract(self.bits()),
+                new: Self::SET_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VNCR"),
+                old: Self::VNCR_FIELD.extract(self.bits()),
+                new: Self::VNCR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("AR"),
+                old: Self::AR_FIELD.extract(self.bits()),
+                new: Self::AR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SF"),
+                old: Self::SF_FIELD.extract(self.bits()),
+                new: Self::SF_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SRT"),
+                old: Self::SRT_FIELD.extract(self.bits()),
+                new: Self::SRT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SSE"),
+                old: Self::SSE_FIELD.extract(self.bits()),
+                new: Self::SSE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SAS"),
+                old: Self::SAS_FIELD.extract(self.bits()),
+                new: Self::SAS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ISV"),
+                old: Self::ISV_FIELD.extract(self.bits()),
+                new: Self::ISV_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IL"),
+                old: Self::IL_FIELD.extract(self.bits()),
+                new: Self::IL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EC"),
+                old: Self::EC_FIELD.extract(self.bits()),
+                new: Self::EC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ISS2"),
+                old: Self::ISS2_FIELD.extract(self.bits()),
+                new: Self::ISS2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl EsrEl2DataAbortLower {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl EsrEl2DataAbortLower {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `ESR_EL2` system register value, when `EC` is 37.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct EsrEl2DataAbort: u64 {
+        /// `WnR` bit.
+        const WNR = 1 << 6;
+        /// `S1PTW` bit.
+        const S1PTW = 1 << 7;
+        /// `CM` bit.
+        const CM = 1 << 8;
+        /// `EA` bit.
+        const EA = 1 << 9;
+        /// `FnV` bit.
+        const FNV = 1 << 10;
+        /// `VNCR` bit.
+        const VNCR = 1 << 13;
+        /// `AR` bit.
+        const AR = 1 << 14;
+        /// `SF` bit.
+        const SF = 1 << 15;
+        /// `SSE` bit.
+        const SSE = 1 << 21;
+        /// `ISV` bit.
+        const ISV = 1 << 24;
+        /// 32-bit instruction length.
+        const IL = 1 << 25;
+    }
+}
+
+#[cfg(feature = "el2")]
+impl EsrEl2DataAbort {
+    /// Descriptor of the `DFSC` field, for use with [`FieldAccess`].
+    pub const DFSC_FIELD: Field<Self, u8, 0, 6> = Field::new("ESR_EL2", "DFSC");
+    /// Descriptor of the `WnR` field, for use with [`FieldAccess`].
+    pub const WNR_FIELD: Field<Self, bool, 6, 1> = Field::new("ESR_EL2", "WnR");
+    /// Descriptor of the `S1PTW` field, for use with [`FieldAccess`].
+    pub const S1PTW_FIELD: Field<Self, bool, 7, 1> = Field::new("ESR_EL2", "S1PTW");
+    /// Descriptor of the `CM` field, for use with [`FieldAccess`].
+    pub const CM_FIELD: Field<Self, bool, 8, 1> = Field::new("ESR_EL2", "CM");
+    /// Descriptor of the `EA` field, for use with [`FieldAccess`].
+    pub const EA_FIELD: Field<Self, bool, 9, 1> = Field::new("ESR_EL2", "EA");
+    /// Descriptor of the `FnV` field, for use with [`FieldAccess`].
+    pub const FNV_FIELD: Field<Self, bool, 10, 1> = Field::new("ESR_EL2", "FnV");
+    /// Descriptor of the `SET` field, for use with [`FieldAccess`].
+    pub const SET_FIELD: Field<Self, u8, 11, 2> = Field::new("ESR_EL2", "SET");
+    /// Descriptor of the `VNCR` field, for use with [`FieldAccess`].
+    pub const VNCR_FIELD: Field<Self, bool, 13, 1> = Field::new("ESR_EL2", "VNCR");
+    /// Descriptor of the `AR` field, for use with [`FieldAccess`].
+    pub const AR_FIELD: Field<Self, bool, 14, 1> = Field::new("ESR_EL2", "AR");
+    /// Descriptor of the `SF` field, for use with [`FieldAccess`].
+    pub const SF_FIELD: Field<Self, bool, 15, 1> = Field::new("ESR_EL2", "SF");
+    /// Descriptor of the `SRT` field, for use with [`FieldAccess`].
+    pub const SRT_FIELD: Field<Self, u8, 16, 5> = Field::new("ESR_EL2", "SRT");
+    /// Descriptor of the `SSE` field, for use with [`FieldAccess`].
+    pub const SSE_FIELD: Field<Self, bool, 21, 1> = Field::new("ESR_EL2", "SSE");
+    /// Descriptor of the `SAS` field, for use with [`FieldAccess`].
+    pub const SAS_FIELD: Field<Self, u8, 22, 2> = Field::new("ESR_EL2", "SAS");
+    /// Descriptor of the `ISV` field, for use with [`FieldAccess`].
+    pub const ISV_FIELD: Field<Self, bool, 24, 1> = Field::new("ESR_EL2", "ISV");
+    /// Descriptor of the `IL` field, for use with [`FieldAccess`].
+    pub const IL_FIELD: Field<Self, bool, 25, 1> = Field::new("ESR_EL2", "IL");
+    /// Descriptor of the `EC` field, for use with [`FieldAccess`].
+    pub const EC_FIELD: Field<Self, u8, 26, 6> = Field::new("ESR_EL2", "EC");
+    /// Descriptor of the `ISS2` field, for use with [`FieldAccess`].
+    pub const ISS2_FIELD: Field<Self, u32, 32, 24> = Field::new("ESR_EL2", "ISS2");
+
+    /// Returns the value of the `DFSC` field.
+    pub const fn dfsc(self) -> u8 {
+        Self::DFSC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `DFSC` field.
+    pub const fn set_dfsc(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::DFSC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `DFSC` field set to the given value.
+    pub const fn with_dfsc(mut self, value: u8) -> Self {
+        self.set_dfsc(value);
+        self
+    }
+
+    /// Sets the value of the `DFSC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_dfsc(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_dfsc(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `DFSC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_dfsc(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::DFSC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SET` field.
+    pub const fn set_(self) -> u8 {
+        Self::SET_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SET` field.
+    pub const fn set_set_(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SET_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SET` field set to the given value.
+    pub const fn with_set_(mut self, value: u8) -> Self {
+        self.set_set_(value);
+        self
+    }
+
+    /// Sets the value of the `SET` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_set_(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_set_(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SET` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_set_(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SET_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SRT` field.
+    pub const fn srt(self) -> u8 {
+        Self::SRT_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SRT` field.
+    pub const fn set_srt(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SRT_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SRT` field set to the given value.
+    pub const fn with_srt(mut self, value: u8) -> Self {
+        self.set_srt(value);
+        self
+    }
+
+    /// Sets the value of the `SRT` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_srt(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_srt(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SRT` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_srt(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SRT_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SAS` field.
+    pub const fn sas(self) -> u8 {
+        Self::SAS_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SAS` field.
+    pub const fn set_sas(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SAS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SAS` field set to the given value.
+    pub const fn with_sas(mut self, value: u8) -> Self {
+        self.set_sas(value);
+        self
+    }
+
+    /// Sets the value of the `SAS` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_sas(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_sas(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SAS` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_sas(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SAS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `EC` field.
+    pub const fn ec(self) -> u8 {
+        Self::EC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `EC` field.
+    pub const fn set_ec(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::EC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `EC` field set to the given value.
+    pub const fn with_ec(mut self, value: u8) -> Self {
+        self.set_ec(value);
+        self
+    }
+
+    /// Sets the value of the `EC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ec(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ec(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `EC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ec(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::EC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ISS2` field.
+    pub const fn iss2(self) -> u32 {
+        Self::ISS2_FIELD.extract(self.bits()) as u32
+    }
+
+    /// Sets the value of the `ISS2` field.
+    pub const fn set_iss2(&mut self, value: u32) {
+        *self = Self::from_bits_retain(Self::ISS2_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value.
+    pub const fn with_iss2(mut self, value: u32) -> Self {
+        self.set_iss2(value);
+        self
+    }
+
+    /// Sets the value of the `ISS2` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_iss2(&mut self, value: u32) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_iss2(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_iss2(self, value: u32) -> Result<Self, FieldOverflow> {
+        match Self::ISS2_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Display for EsrEl2DataAbort {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("EsrEl2DataAbort");
+        let bits = self.bits();
+        s.field("DFSC", &Self::DFSC_FIELD.extract(bits));
+        s.field("WnR", &Self::WNR_FIELD.extract(bits));
+        s.field("S1PTW", &Self::S1PTW_FIELD.extract(bits));
+        s.field("CM", &Self::CM_FIELD.extract(bits));
+        s.field("EA", &Self::EA_FIELD.extract(bits));
+        s.field("FnV", &Self::FNV_FIELD.extract(bits));
+        s.field("SET", &Self::SET_FIELD.extract(bits));
+        s.field("VNCR", &Self::VNCR_FIELD.extract(bits));
+        s.field("AR", &Self::AR_FIELD.extract(bits));
+        s.field("SF", &Self::SF_FIELD.extract(bits));
+        s.field("SRT", &Self::SRT_FIELD.extract(bits));
+        s.field("SSE", &Self::SSE_FIELD.extract(bits));
+        s.field("SAS", &Self::SAS_FIELD.extract(bits));
+        s.field("ISV", &Self::ISV_FIELD.extract(bits));
+        s.field("IL", &Self::IL_FIELD.extract(bits));
+        s.field("EC", &Self::EC_FIELD.extract(bits));
+        s.field(
+            "ISS2",
+            &format_args!("{:#x}", Self::ISS2_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for EsrEl2DataAbort {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "EsrEl2DataAbort {{ DFSC: {=u64}, WnR: {=u64}, S1PTW: {=u64}, CM: {=u64}, EA: {=u64}, FnV: {=u64}, SET: {=u64}, VNCR: {=u64}, AR: {=u64}, SF: {=u64}, SRT: {=u64}, SSE: {=u64}, SAS: {=u64}, ISV: {=u64}, IL: {=u64}, EC: {=u64}, ISS2: {=u64:#x} }}",
+            Self::DFSC_FIELD.extract(self.bits()),
+            Self::WNR_FIELD.extract(self.bits()),
+            Self::S1PTW_FIELD.extract(self.bits()),
+            Self::CM_FIELD.extract(self.bits()),
+            Self::EA_FIELD.extract(self.bits()),
+            Self::FNV_FIELD.extract(self.bits()),
+            Self::SET_FIELD.extract(self.bits()),
+            Self::VNCR_FIELD.extract(self.bits()),
+            Self::AR_FIELD.extract(self.bits()),
+            Self::SF_FIELD.extract(self.bits()),
+            Self::SRT_FIELD.extract(self.bits()),
+            Self::SSE_FIELD.extract(self.bits()),
+            Self::SAS_FIELD.extract(self.bits()),
+            Self::ISV_FIELD.extract(self.bits()),
+            Self::IL_FIELD.extract(self.bits()),
+            Self::EC_FIELD.extract(self.bits()),
+            Self::ISS2_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for EsrEl2DataAbort {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for EsrEl2DataAbort {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl EsrEl2DataAbort {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("DFSC"),
+                old: Self::DFSC_FIELD.extract(self.bits()),
+                new: Self::DFSC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("WnR"),
+                old: Self::WNR_FIELD.extract(self.bits()),
+                new: Self::WNR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("S1PTW"),
+                old: Self::S1PTW_FIELD.extract(self.bits()),
+                new: Self::S1PTW_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CM"),
+                old: Self::CM_FIELD.extract(self.bits()),
+                new: Self::CM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EA"),
+                old: Self::EA_FIELD.extract(self.bits()),
+                new: Self::EA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FnV"),
+                old: Self::FNV_FIELD.extract(self.bits()),
+                new: Self::FNV_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SET"),
+                old: Self::SET_FIELD.extract(self.bits()),
+                new: Self::SET_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VNCR"),
+                old: Self::VNCR_FIELD.extract(self.bits()),
+                new: Self::VNCR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("AR"),
+                old: Self::AR_FIELD.extract(self.bits()),
+                new: Self::AR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SF"),
+                old: Self::SF_FIELD.extract(self.bits()),
+                new: Self::SF_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SRT"),
+                old: Self::SRT_FIELD.extract(self.bits()),
+                new: Self::SRT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SSE"),
+                old: Self::SSE_FIELD.extract(self.bits()),
+                new: Self::SSE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SAS"),
+                old: Self::SAS_FIELD.extract(self.bits()),
+                new: Self::SAS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ISV"),
+                old: Self::ISV_FIELD.extract(self.bits()),
+                new: Self::ISV_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IL"),
+                old: Self::IL_FIELD.extract(self.bits()),
+                new: Self::IL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EC"),
+                old: Self::EC_FIELD.extract(self.bits()),
+                new: Self::EC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ISS2"),
+                old: Self::ISS2_FIELD.extract(self.bits()),
+                new: Self::ISS2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl EsrEl2DataAbort {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl EsrEl2DataAbort {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl EsrEl2 {
+    /// Returns the value as a `EsrEl2Wfx` if `EC` is 1, or `None` if it has another layout.
+    pub const fn as_wfx(self) -> Option<EsrEl2Wfx> {
+        if Self::EC_FIELD.extract(self.bits()) == 1 {
+            Some(EsrEl2Wfx::from_bits_retain(self.bits()))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value as a `EsrEl2Svc` if `EC` is 21, or `None` if it has another layout.
+    pub const fn as_svc(self) -> Option<EsrEl2Svc> {
+        if Self::EC_FIELD.extract(self.bits()) == 21 {
+            Some(EsrEl2Svc::from_bits_retain(self.bits()))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value as a `EsrEl2Hvc` if `EC` is 22, or `None` if it has another layout.
+    pub const fn as_hvc(self) -> Option<EsrEl2Hvc> {
+        if Self::EC_FIELD.extract(self.bits()) == 22 {
+            Some(EsrEl2Hvc::from_bits_retain(self.bits()))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value as a `EsrEl2Smc` if `EC` is 23, or `None` if it has another layout.
+    pub const fn as_smc(self) -> Option<EsrEl2Smc> {
+        if Self::EC_FIELD.extract(self.bits()) == 23 {
+            Some(EsrEl2Smc::from_bits_retain(self.bits()))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value as a `EsrEl2Sysreg` if `EC` is 24, or `None` if it has another layout.
+    pub const fn as_sysreg(self) -> Option<EsrEl2Sysreg> {
+        if Self::EC_FIELD.extract(self.bits()) == 24 {
+            Some(EsrEl2Sysreg::from_bits_retain(self.bits()))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value as a `EsrEl2InstructionAbortLower` if `EC` is 32, or `None` if it has another layout.
+    pub const fn as_instruction_abort_lower(self) -> Option<EsrEl2InstructionAbortLower> {
+        if Self::EC_FIELD.extract(self.bits()) == 32 {
+            Some(EsrEl2InstructionAbortLower::from_bits_retain(self.bits()))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value as a `EsrEl2InstructionAbort` if `EC` is 33, or `None` if it has another layout.
+    pub const fn as_instruction_abort(self) -> Option<EsrEl2InstructionAbort> {
+        if Self::EC_FIELD.extract(self.bits()) == 33 {
+            Some(EsrEl2InstructionAbort::from_bits_retain(self.bits()))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value as a `EsrEl2DataAbortLower` if `EC` is 36, or `None` if it has another layout.
+    pub const fn as_data_abort_lower(self) -> Option<EsrEl2DataAbortLower> {
+        if Self::EC_FIELD.extract(self.bits()) == 36 {
+            Some(EsrEl2DataAbortLower::from_bits_retain(self.bits()))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value as a `EsrEl2DataAbort` if `EC` is 37, or `None` if it has another layout.
+    pub const fn as_data_abort(self) -> Option<EsrEl2DataAbort> {
+        if Self::EC_FIELD.extract(self.bits()) == 37 {
+            Some(EsrEl2DataAbort::from_bits_retain(self.bits()))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(feature = "el2")]
+impl From<EsrEl2Wfx> for EsrEl2 {
+    fn from(value: EsrEl2Wfx) -> Self {
+        Self::from_bits_retain(value.bits())
+    }
+}
+
+#[cfg(feature = "el2")]
+impl From<EsrEl2Svc> for EsrEl2 {
+    fn from(value: EsrEl2Svc) -> Self {
+        Self::from_bits_retain(value.bits())
+    }
+}
+
+#[cfg(feature = "el2")]
+impl From<EsrEl2Hvc> for EsrEl2 {
+    fn from(value: EsrEl2Hvc) -> Self {
+        Self::from_bits_retain(value.bits())
+    }
+}
+
+#[cfg(feature = "el2")]
+impl From<EsrEl2Smc> for EsrEl2 {
+    fn from(value: EsrEl2Smc) -> Self {
+        Self::from_bits_retain(value.bits())
+    }
+}
+
+#[cfg(feature = "el2")]
+impl From<EsrEl2Sysreg> for EsrEl2 {
+    fn from(value: EsrEl2Sysreg) -> Self {
+        Self::from_bits_retain(value.bits())
+    }
+}
+
+#[cfg(feature = "el2")]
+impl From<EsrEl2InstructionAbortLower> for EsrEl2 {
+    fn from(value: EsrEl2InstructionAbortLower) -> Self {
+        Self::from_bits_retain(value.bits())
+    }
+}
+
+#[cfg(feature = "el2")]
+impl From<EsrEl2InstructionAbort> for EsrEl2 {
+    fn from(value: EsrEl2InstructionAbort) -> Self {
+        Self::from_bits_retain(value.bits())
+    }
+}
+
+#[cfg(feature = "el2")]
+impl From<EsrEl2DataAbortLower> for EsrEl2 {
+    fn from(value: EsrEl2DataAbortLower) -> Self {
+        Self::from_bits_retain(value.bits())
+    }
+}
+
+#[cfg(feature = "el2")]
+impl From<EsrEl2DataAbort> for EsrEl2 {
+    fn from(value: EsrEl2DataAbort) -> Self {
+        Self::from_bits_retain(value.bits())
+    }
+}
+
+#[cfg(feature = "el3")]
+bitflags! {
+    /// `ESR_EL3` system register value.
+    #[derive(Clone, Copy, Eq, Default, PartialEq)]
+    #[repr(transparent)]
+    pub struct EsrEl3: u64 {
+        /// 32-bit instruction length.
+        const IL = 1 << 25;
+    }
+}
+
+#[cfg(feature = "el3")]
+impl EsrEl3 {
+    /// Descriptor of the `ISS` field, for use with [`FieldAccess`].
+    pub const ISS_FIELD: Field<Self, u32, 0, 25> = Field::new("ESR_EL3", "ISS");
+    /// Descriptor of the `IL` field, for use with [`FieldAccess`].
+    pub const IL_FIELD: Field<Self, bool, 25, 1> = Field::new("ESR_EL3", "IL");
+    /// Descriptor of the `EC` field, for use with [`FieldAccess`].
+    pub const EC_FIELD: Field<Self, u8, 26, 6> = Field::new("ESR_EL3", "EC");
+    /// Descriptor of the `ISS2` field, for use with [`FieldAccess`].
+    pub const ISS2_FIELD: Field<Self, u32, 32, 24> = Field::new("ESR_EL3", "ISS2");
+
+    /// Returns the value of the `ISS` field.
+    pub const fn iss(self) -> u32 {
+        Self::ISS_FIELD.extract(self.bits()) as u32
+    }
+
+    /// Sets the value of the `ISS` field.
+    pub const fn set_iss(&mut self, value: u32) {
+        *self = Self::from_bits_retain(Self::ISS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ISS` field set to the given value.
+    pub const fn with_iss(mut self, value: u32) -> Self {
+        self.set_iss(value);
+        self
+    }
+
+    /// Sets the value of the `ISS` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_iss(&mut self, value: u32) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_iss(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ISS` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_iss(self, value: u32) -> Result<Self, FieldOverflow> {
+        match Self::ISS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `EC` field.
+    pub const fn ec(self) -> u8 {
+        Self::EC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `EC` field.
+    pub const fn set_ec(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::EC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `EC` field set to the given value.
+    pub const fn with_ec(mut self, value: u8) -> Self {
+        self.set_ec(value);
+        self
+    }
+
+    /// Sets the value of the `EC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ec(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ec(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `EC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ec(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::EC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ISS2` field.
+    pub const fn iss2(self) -> u32 {
+        Self::ISS2_FIELD.extract(self.bits()) as u32
+    }
+
+    /// Sets the value of the `ISS2` field.
+    pub const fn set_iss2(&mut self, value: u32) {
+        *self = Self::from_bits_retain(Self::ISS2_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value.
+    pub const fn with_iss2(mut self, value: u32) -> Self {
+        self.set_iss2(value);
+        self
+    }
+
+    /// Sets the value of the `ISS2` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_iss2(&mut self, value: u32) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_iss2(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_iss2(self, value: u32) -> Result<Self, FieldOverflow> {
+        match Self::ISS2_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el3")]
+impl Display for EsrEl3 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("EsrEl3");
+        let bits = self.bits();
+        s.field("ISS", &format_args!("{:#x}", Self::ISS_FIELD.extract(bits)));
+        s.field("IL", &Self::IL_FIELD.extract(bits));
+        s.field("EC", &Self::EC_FIELD.extract(bits));
+        s.field(
+            "ISS2",
+            &format_args!("{:#x}", Self::ISS2_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el3")]
+impl defmt::Format for EsrEl3 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "EsrEl3 {{ ISS: {=u64:#x}, IL: {=u64}, EC: {=u64}, ISS2: {=u64:#x} }}",
+            Self::ISS_FIELD.extract(self.bits()),
+            Self::IL_FIELD.extract(self.bits()),
+            Self::EC_FIELD.extract(self.bits()),
+            Self::ISS2_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el3")]
+impl serde::Serialize for EsrEl3 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el3")]
+impl<'de> serde::Deserialize<'de> for EsrEl3 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el3")]
+impl EsrEl3 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("ISS"),
+                old: Self::ISS_FIELD.extract(self.bits()),
+                new: Self::ISS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IL"),
+                old: Self::IL_FIELD.extract(self.bits()),
+                new: Self::IL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EC"),
+                old: Self::EC_FIELD.extract(self.bits()),
+                new: Self::EC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ISS2"),
+                old: Self::ISS2_FIELD.extract(self.bits()),
+                new: Self::ISS2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el3")]
+impl EsrEl3 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el3")]
+impl EsrEl3 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el3")]
+bitflags! {
+    /// `ESR_EL3` system register value, when `EC` is 1.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct EsrEl3Wfx: u64 {
+        /// `RV` bit.
+        const RV = 1 << 2;
+        /// `CV` bit.
+        const CV = 1 << 24;
+        /// 32-bit instruction length.
+        const IL = 1 << 25;
+    }
+}
+
+#[cfg(feature = "el3")]
+impl EsrEl3Wfx {
+    /// Descriptor of the `TI` field, for use with [`FieldAccess`].
+    pub const TI_FIELD: Field<Self, u8, 0, 2> = Field::new("ESR_EL3", "TI");
+    /// Descriptor of the `RV` field, for use with [`FieldAccess`].
+    pub const RV_FIELD: Field<Self, bool, 2, 1> = Field::new("ESR_EL3", "RV");
+    /// Descriptor of the `RN` field, for use with [`FieldAccess`].
+    pub const RN_FIELD: Field<Self, u8, 5, 5> = Field::new("ESR_EL3", "RN");
+    /// Descriptor of the `COND` field, for use with [`FieldAccess`].
+    pub const COND_FIELD: Field<Self, u8, 20, 4> = Field::new("ESR_EL3", "COND");
+    /// Descriptor of the `CV` field, for use with [`FieldAccess`].
+    pub const CV_FIELD: Field<Self, bool, 24, 1> = Field::new("ESR_EL3", "CV");
+    /// Descriptor of the `IL` field, for use with [`FieldAccess`].
+    pub const IL_FIELD: Field<Self, bool, 25, 1> = Field::new("ESR_EL3", "IL");
+    /// Descriptor of the `EC` field, for use with [`FieldAccess`].
+    pub const EC_FIELD: Field<Self, u8, 26, 6> = Field::new("ESR_EL3", "EC");
+    /// Descriptor of the `ISS2` field, for use with [`FieldAccess`].
+    pub const ISS2_FIELD: Field<Self, u32, 32, 24> = Field::new("ESR_EL3", "ISS2");
+
+    /// Returns the value of the `TI` field.
+    pub const fn ti(self) -> u8 {
+        Self::TI_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `TI` field.
+    pub const fn set_ti(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::TI_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `TI` field set to the given value.
+    pub const fn with_ti(mut self, value: u8) -> Self {
+        self.set_ti(value);
+        self
+    }
+
+    /// Sets the value of the `TI` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ti(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ti(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `TI` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ti(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::TI_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `RN` field.
+    pub const fn rn(self) -> u8 {
+        Self::RN_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `RN` field.
+    pub const fn set_rn(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::RN_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `RN` field set to the given value.
+    pub const fn with_rn(mut self, value: u8) -> Self {
+        self.set_rn(value);
+        self
+    }
+
+    /// Sets the value of the `RN` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_rn(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_rn(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `RN` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_rn(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::RN_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `COND` field.
+    pub const fn cond(self) -> u8 {
+        Self::COND_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `COND` field.
+    pub const fn set_cond(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::COND_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `COND` field set to the given value.
+    pub const fn with_cond(mut self, value: u8) -> Self {
+        self.set_cond(value);
+        self
+    }
+
+    /// Sets the value of the `COND` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_cond(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_cond(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `COND` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_cond(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::COND_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `EC` field.
+    pub const fn ec(self) -> u8 {
+        Self::EC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `EC` field.
+    pub const fn set_ec(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::EC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `EC` field set to the given value.
+    pub const fn with_ec(mut self, value: u8) -> Self {
+        self.set_ec(value);
+        self
+    }
+
+    /// Sets the value of the `EC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ec(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ec(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `EC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ec(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::EC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ISS2` field.
+    pub const fn iss2(self) -> u32 {
+        Self::ISS2_FIELD.extract(self.bits()) as u32
+    }
+
+    /// Sets the value of the `ISS2` field.
+    pub const fn set_iss2(&mut self, value: u32) {
+        *self = Self::from_bits_retain(Self::ISS2_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value.
+    pub const fn with_iss2(mut self, value: u32) -> Self {
+        self.set_iss2(value);
+        self
+    }
+
+    /// Sets the value of the `ISS2` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_iss2(&mut self, value: u32) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_iss2(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_iss2(self, value: u32) -> Result<Self, FieldOverflow> {
+        match Self::ISS2_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el3")]
+impl Display for EsrEl3Wfx {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("EsrEl3Wfx");
+        let bits = self.bits();
+        s.field("TI", &Self::TI_FIELD.extract(bits));
+        s.field("RV", &Self::RV_FIELD.extract(bits));
+        s.field("RN", &Self::RN_FIELD.extract(bits));
+        s.field("COND", &Self::COND_FIELD.extract(bits));
+        s.field("CV", &Self::CV_FIELD.extract(bits));
+        s.field("IL", &Self::IL_FIELD.extract(bits));
+        s.field("EC", &Self::EC_FIELD.extract(bits));
+        s.field(
+            "ISS2",
+            &format_args!("{:#x}", Self::ISS2_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el3")]
+impl defmt::Format for EsrEl3Wfx {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "EsrEl3Wfx {{ TI: {=u64}, RV: {=u64}, RN: {=u64}, COND: {=u64}, CV: {=u64}, IL: {=u64}, EC: {=u64}, ISS2: {=u64:#x} }}",
+            Self::TI_FIELD.extract(self.bits()),
+            Self::RV_FIELD.extract(self.bits()),
+            Self::RN_FIELD.extract(self.bits()),
+            Self::COND_FIELD.extract(self.bits()),
+            Self::CV_FIELD.extract(self.bits()),
+            Self::IL_FIELD.extract(self.bits()),
+            Self::EC_FIELD.extract(self.bits()),
+            Self::ISS2_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el3")]
+impl serde::Serialize for EsrEl3Wfx {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el3")]
+impl<'de> serde::Deserialize<'de> for EsrEl3Wfx {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el3")]
+impl EsrEl3Wfx {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("TI"),
+                old: Self::TI_FIELD.extract(self.bits()),
+                new: Self::TI_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("RV"),
+                old: Self::RV_FIELD.extract(self.bits()),
+                new: Self::RV_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("RN"),
+                old: Self::RN_FIELD.extract(self.bits()),
+                new: Self::RN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("COND"),
+                old: Self::COND_FIELD.extract(self.bits()),
+                new: Self::COND_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CV"),
+                old: Self::CV_FIELD.extract(self.bits()),
+                new: Self::CV_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IL"),
+                old: Self::IL_FIELD.extract(self.bits()),
+                new: Self::IL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EC"),
+                old: Self::EC_FIELD.extract(self.bits()),
+                new: Self::EC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ISS2"),
+                old: Self::ISS2_FIELD.extract(self.bits()),
+                new: Self::ISS2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0000_0000_1111_1111_1100_0001_1000,
+                new: other.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0000_0000_1111_1111_1100_0001_1000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el3")]
+impl EsrEl3Wfx {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el3")]
+impl EsrEl3Wfx {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el3")]
+bitflags! {
+    /// `ESR_EL3` system register value, when `EC` is 21.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct EsrEl3Svc: u64 {
+        /// 32-bit instruction length.
+        const IL = 1 << 25;
+    }
+}
+
+#[cfg(feature = "el3")]
+impl EsrEl3Svc {
+    /// Descriptor of the `imm16` field, for use with [`FieldAccess`].
+    pub const IMM16_FIELD: Field<Self, u16, 0, 16> = Field::new("ESR_EL3", "imm16");
+    /// Descriptor of the `IL` field, for use with [`FieldAccess`].
+    pub const IL_FIELD: Field<Self, bool, 25, 1> = Field::new("ESR_EL3", "IL");
+    /// Descriptor of the `EC` field, for use with [`FieldAccess`].
+    pub const EC_FIELD: Field<Self, u8, 26, 6> = Field::new("ESR_EL3", "EC");
+    /// Descriptor of the `ISS2` field, for use with [`FieldAccess`].
+    pub const ISS2_FIELD: Field<Self, u32, 32, 24> = Field::new("ESR_EL3", "ISS2");
+
+    /// Returns the value of the `imm16` field.
+    pub const fn imm16(self) -> u16 {
+        Self::IMM16_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `imm16` field.
+    pub const fn set_imm16(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::IMM16_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `imm16` field set to the given value.
+    pub const fn with_imm16(mut self, value: u16) -> Self {
+        self.set_imm16(value);
+        self
+    }
+
+    /// Sets the value of the `imm16` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_imm16(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_imm16(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `imm16` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_imm16(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::IMM16_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `EC` field.
+    pub const fn ec(self) -> u8 {
+        Self::EC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `EC` field.
+    pub const fn set_ec(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::EC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `EC` field set to the given value.
+    pub const fn with_ec(mut self, value: u8) -> Self {
+        self.set_ec(value);
+        self
+    }
+
+    /// Sets the value of the `EC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ec(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ec(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `EC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ec(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::EC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ISS2` field.
+    pub const fn iss2(self) -> u32 {
+        Self::ISS2_FIELD.extract(self.bits()) as u32
+    }
+
+    /// Sets the value of the `ISS2` field.
+    pub const fn set_iss2(&mut self, value: u32) {
+        *self = Self::from_bits_retain(Self::ISS2_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value.
+    pub const fn with_iss2(mut self, value: u32) -> Self {
+        self.set_iss2(value);
+        self
+    }
+
+    /// Sets the value of the `ISS2` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_iss2(&mut self, value: u32) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_iss2(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_iss2(self, value: u32) -> Result<Self, FieldOverflow> {
+        match Self::ISS2_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el3")]
+impl Display for EsrEl3Svc {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("EsrEl3Svc");
+        let bits = self.bits();
+        s.field(
+            "imm16",
+            &format_args!("{:#x}", Self::IMM16_FIELD.extract(bits)),
+        );
+        s.field("IL", &Self::IL_FIELD.extract(bits));
+        s.field("EC", &Self::EC_FIELD.extract(bits));
+        s.field(
+            "ISS2",
+            &format_args!("{:#x}", Self::ISS2_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el3")]
+impl defmt::Format for EsrEl3Svc {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "EsrEl3Svc {{ imm16: {=u64:#x}, IL: {=u64}, EC: {=u64}, ISS2: {=u64:#x} }}",
+            Self::IMM16_FIELD.extract(self.bits()),
+            Self::IL_FIELD.extract(self.bits()),
+            Self::EC_FIELD.extract(self.bits()),
+            Self::ISS2_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el3")]
+impl serde::Serialize for EsrEl3Svc {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el3")]
+impl<'de> serde::Deserialize<'de> for EsrEl3Svc {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el3")]
+impl EsrEl3Svc {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("imm16"),
+                old: Self::IMM16_FIELD.extract(self.bits()),
+                new: Self::IMM16_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IL"),
+                old: Self::IL_FIELD.extract(self.bits()),
+                new: Self::IL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EC"),
+                old: Self::EC_FIELD.extract(self.bits()),
+                new: Self::EC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ISS2"),
+                old: Self::ISS2_FIELD.extract(self.bits()),
+                new: Self::ISS2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0001_1111_1111_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0001_1111_1111_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el3")]
+impl EsrEl3Svc {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el3")]
+impl EsrEl3Svc {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el3")]
+bitflags! {
+    /// `ESR_EL3` system register value, when `EC` is 22.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct EsrEl3Hvc: u64 {
+        /// 32-bit instruction length.
+        const IL = 1 << 25;
+    }
+}
+
+#[cfg(feature = "el3")]
+impl EsrEl3Hvc {
+    /// Descriptor of the `imm16` field, for use with [`FieldAccess`].
+    pub const IMM16_FIELD: Field<Self, u16, 0, 16> = Field::new("ESR_EL3", "imm16");
+    /// Descriptor of the `IL` field, for use with [`FieldAccess`].
+    pub const IL_FIELD: Field<Self, bool, 25, 1> = Field::new("ESR_EL3", "IL");
+    /// Descriptor of the `EC` field, for use with [`FieldAccess`].
+    pub const EC_FIELD: Field<Self, u8, 26, 6> = Field::new("ESR_EL3", "EC");
+    /// Descriptor of the `ISS2` field, for use with [`FieldAccess`].
+    pub const ISS2_FIELD: Field<Self, u32, 32, 24> = Field::new("ESR_EL3", "ISS2");
+
+    /// Returns the value of the `imm16` field.
+    pub const fn imm16(self) -> u16 {
+        Self::IMM16_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `imm16` field.
+    pub const fn set_imm16(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::IMM16_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `imm16` field set to the given value.
+    pub const fn with_imm16(mut self, value: u16) -> Self {
+        self.set_imm16(value);
+        self
+    }
+
+    /// Sets the value of the `imm16` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_imm16(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_imm16(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `imm16` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_imm16(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::IMM16_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `EC` field.
+    pub const fn ec(self) -> u8 {
+        Self::EC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `EC` field.
+    pub const fn set_ec(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::EC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `EC` field set to the given value.
+    pub const fn with_ec(mut self, value: u8) -> Self {
+        self.set_ec(value);
+        self
+    }
+
+    /// Sets the value of the `EC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ec(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ec(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `EC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ec(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::EC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ISS2` field.
+    pub const fn iss2(self) -> u32 {
+        Self::ISS2_FIELD.extract(self.bits()) as u32
+    }
+
+    /// Sets the value of the `ISS2` field.
+    pub const fn set_iss2(&mut self, value: u32) {
+        *self = Self::from_bits_retain(Self::ISS2_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value.
+    pub const fn with_iss2(mut self, value: u32) -> Self {
+        self.set_iss2(value);
+        self
+    }
+
+    /// Sets the value of the `ISS2` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_iss2(&mut self, value: u32) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_iss2(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_iss2(self, value: u32) -> Result<Self, FieldOverflow> {
+        match Self::ISS2_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el3")]
+impl Display for EsrEl3Hvc {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("EsrEl3Hvc");
+        let bits = self.bits();
+        s.field(
+            "imm16",
+            &format_args!("{:#x}", Self::IMM16_FIELD.extract(bits)),
+        );
+        s.field("IL", &Self::IL_FIELD.extract(bits));
+        s.field("EC", &Self::EC_FIELD.extract(bits));
+        s.field(
+            "ISS2",
+            &format_args!("{:#x}", Self::ISS2_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el3")]
+impl defmt::Format for EsrEl3Hvc {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "EsrEl3Hvc {{ imm16: {=u64:#x}, IL: {=u64}, EC: {=u64}, ISS2: {=u64:#x} }}",
+            Self::IMM16_FIELD.extract(self.bits()),
+            Self::IL_FIELD.extract(self.bits()),
+            Self::EC_FIELD.extract(self.bits()),
+            Self::ISS2_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el3")]
+impl serde::Serialize for EsrEl3Hvc {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el3")]
+impl<'de> serde::Deserialize<'de> for EsrEl3Hvc {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el3")]
+impl EsrEl3Hvc {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("imm16"),
+                old: Self::IMM16_FIELD.extract(self.bits()),
+                new: Self::IMM16_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IL"),
+                old: Self::IL_FIELD.extract(self.bits()),
+                new: Self::IL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EC"),
+                old: Self::EC_FIELD.extract(self.bits()),
+                new: Self::EC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ISS2"),
+                old: Self::ISS2_FIELD.extract(self.bits()),
+                new: Self::ISS2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0001_1111_1111_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0001_1111_1111_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el3")]
+impl EsrEl3Hvc {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el3")]
+impl EsrEl3Hvc {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el3")]
+bitflags! {
+    /// `ESR_EL3` system register value, when `EC` is 23.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct EsrEl3Smc: u64 {
+        /// 32-bit instruction length.
+        const IL = 1 << 25;
+    }
+}
+
+#[cfg(feature = "el3")]
+impl EsrEl3Smc {
+    /// Descriptor of the `imm16` field, for use with [`FieldAccess`].
+    pub const IMM16_FIELD: Field<Self, u16, 0, 16> = Field::new("ESR_EL3", "imm16");
+    /// Descriptor of the `IL` field, for use with [`FieldAccess`].
+    pub const IL_FIELD: Field<Self, bool, 25, 1> = Field::new("ESR_EL3", "IL");
+    /// Descriptor of the `EC` field, for use with [`FieldAccess`].
+    pub const EC_FIELD: Field<Self, u8, 26, 6> = Field::new("ESR_EL3", "EC");
+    /// Descriptor of the `ISS2` field, for use with [`FieldAccess`].
+    pub const ISS2_FIELD: Field<Self, u32, 32, 24> = Field::new("ESR_EL3", "ISS2");
+
+    /// Returns the value of the `imm16` field.
+    pub const fn imm16(self) -> u16 {
+        Self::IMM16_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `imm16` field.
+    pub const fn set_imm16(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::IMM16_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `imm16` field set to the given value.
+    pub const fn with_imm16(mut self, value: u16) -> Self {
+        self.set_imm16(value);
+        self
+    }
+
+    /// Sets the value of the `imm16` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_imm16(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_imm16(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `imm16` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_imm16(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::IMM16_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `EC` field.
+    pub const fn ec(self) -> u8 {
+        Self::EC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `EC` field.
+    pub const fn set_ec(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::EC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `EC` field set to the given value.
+    pub const fn with_ec(mut self, value: u8) -> Self {
+        self.set_ec(value);
+        self
+    }
+
+    /// Sets the value of the `EC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ec(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ec(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `EC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ec(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::EC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ISS2` field.
+    pub const fn iss2(self) -> u32 {
+        Self::ISS2_FIELD.extract(self.bits()) as u32
+    }
+
+    /// Sets the value of the `ISS2` field.
+    pub const fn set_iss2(&mut self, value: u32) {
+        *self = Self::from_bits_retain(Self::ISS2_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value.
+    pub const fn with_iss2(mut self, value: u32) -> Self {
+        self.set_iss2(value);
+        self
+    }
+
+    /// Sets the value of the `ISS2` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_iss2(&mut self, value: u32) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_iss2(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_iss2(self, value: u32) -> Result<Self, FieldOverflow> {
+        match Self::ISS2_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el3")]
+impl Display for EsrEl3Smc {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("EsrEl3Smc");
+        let bits = self.bits();
+        s.field(
+            "imm16",
+            &format_args!("{:#x}", Self::IMM16_FIELD.extract(bits)),
+        );
+        s.field("IL", &Self::IL_FIELD.extract(bits));
+        s.field("EC", &Self::EC_FIELD.extract(bits));
+        s.field(
+            "ISS2",
+            &format_args!("{:#x}", Self::ISS2_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el3")]
+impl defmt::Format for EsrEl3Smc {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "EsrEl3Smc {{ imm16: {=u64:#x}, IL: {=u64}, EC: {=u64}, ISS2: {=u64:#x} }}",
+            Self::IMM16_FIELD.extract(self.bits()),
+            Self::IL_FIELD.extract(self.bits()),
+            Self::EC_FIELD.extract(self.bits()),
+            Self::ISS2_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el3")]
+impl serde::Serialize for EsrEl3Smc {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el3")]
+impl<'de> serde::Deserialize<'de> for EsrEl3Smc {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el3")]
+impl EsrEl3Smc {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("imm16"),
+                old: Self::IMM16_FIELD.extract(self.bits()),
+                new: Self::IMM16_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IL"),
+                old: Self::IL_FIELD.extract(self.bits()),
+                new: Self::IL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EC"),
+                old: Self::EC_FIELD.extract(self.bits()),
+                new: Self::EC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ISS2"),
+                old: Self::ISS2_FIELD.extract(self.bits()),
+                new: Self::ISS2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0001_1111_1111_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0001_1111_1111_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el3")]
+impl EsrEl3Smc {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el3")]
+impl EsrEl3Smc {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el3")]
+bitflags! {
+    /// `ESR_EL3` system register value, when `EC` is 24.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct EsrEl3Sysreg: u64 {
+        /// `Direction` bit.
+        const DIRECTION = 1 << 0;
+        /// 32-bit instruction length.
+        const IL = 1 << 25;
+    }
+}
+
+#[cfg(feature = "el3")]
+impl EsrEl3Sysreg {
+    /// Descriptor of the `Direction` field, for use with [`FieldAccess`].
+    pub const DIRECTION_FIELD: Field<Self, bool, 0, 1> = Field::new("ESR_EL3", "Direction");
+    /// Descriptor of the `CRm` field, for use with [`FieldAccess`].
+    pub const CRM_FIELD: Field<Self, u8, 1, 4> = Field::new("ESR_EL3", "CRm");
+    /// Descriptor of the `Rt` field, for use with [`FieldAccess`].
+    pub const RT_FIELD: Field<Self, u8, 5, 5> = Field::new("ESR_EL3", "Rt");
+    /// Descriptor of the `CRn` field, for use with [`FieldAccess`].
+    pub const CRN_FIELD: Field<Self, u8, 10, 4> = Field::new("ESR_EL3", "CRn");
+    /// Descriptor of the `Op1` field, for use with [`FieldAccess`].
+    pub const OP1_FIELD: Field<Self, u8, 14, 3> = Field::new("ESR_EL3", "Op1");
+    /// Descriptor of the `Op2` field, for use with [`FieldAccess`].
+    pub const OP2_FIELD: Field<Self, u8, 17, 3> = Field::new("ESR_EL3", "Op2");
+    /// Descriptor of the `Op0` field, for use with [`FieldAccess`].
+    pub const OP0_FIELD: Field<Self, u8, 20, 2> = Field::new("ESR_EL3", "Op0");
+    /// Descriptor of the `IL` field, for use with [`FieldAccess`].
+    pub const IL_FIELD: Field<Self, bool, 25, 1> = Field::new("ESR_EL3", "IL");
+    /// Descriptor of the `EC` field, for use with [`FieldAccess`].
+    pub const EC_FIELD: Field<Self, u8, 26, 6> = Field::new("ESR_EL3", "EC");
+    /// Descriptor of the `ISS2` field, for use with [`FieldAccess`].
+    pub const ISS2_FIELD: Field<Self, u32, 32, 24> = Field::new("ESR_EL3", "ISS2");
+
+    /// Returns the value of the `CRm` field.
+    pub const fn crm(self) -> u8 {
+        Self::CRM_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `CRm` field.
+    pub const fn set_crm(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::CRM_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `CRm` field set to the given value.
+    pub const fn with_crm(mut self, value: u8) -> Self {
+        self.set_crm(value);
+        self
+    }
+
+    /// Sets the value of the `CRm` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_crm(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_crm(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `CRm` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_crm(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::CRM_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `Rt` field.
+    pub const fn rt(self) -> u8 {
+        Self::RT_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `Rt` field.
+    pub const fn set_rt(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::RT_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `Rt` field set to the given value.
+    pub const fn with_rt(mut self, value: u8) -> Self {
+        self.set_rt(value);
+        self
+    }
+
+    /// Sets the value of the `Rt` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_rt(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_rt(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `Rt` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_rt(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::RT_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `CRn` field.
+    pub const fn crn(self) -> u8 {
+        Self::CRN_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `CRn` field.
+    pub const fn set_crn(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::CRN_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `CRn` field set to the given value.
+    pub const fn with_crn(mut self, value: u8) -> Self {
+        self.set_crn(value);
+        self
+    }
+
+    /// Sets the value of the `CRn` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_crn(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_crn(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `CRn` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_crn(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::CRN_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `Op1` field.
+    pub const fn op1(self) -> u8 {
+        Self::OP1_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `Op1` field.
+    pub const fn set_op1(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::OP1_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `Op1` field set to the given value.
+    pub const fn with_op1(mut self, value: u8) -> Self {
+        self.set_op1(value);
+        self
+    }
+
+    /// Sets the value of the `Op1` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_op1(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_op1(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `Op1` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_op1(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::OP1_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `Op2` field.
+    pub const fn op2(self) -> u8 {
+        Self::OP2_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `Op2` field.
+    pub const fn set_op2(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::OP2_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `Op2` field set to the given value.
+    pub const fn with_op2(mut self, value: u8) -> Self {
+        self.set_op2(value);
+        self
+    }
+
+    /// Sets the value of the `Op2` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_op2(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_op2(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `Op2` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_op2(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::OP2_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `Op0` field.
+    pub const fn op0(self) -> u8 {
+        Self::OP0_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `Op0` field.
+    pub const fn set_op0(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::OP0_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `Op0` field set to the given value.
+    pub const fn with_op0(mut self, value: u8) -> Self {
+        self.set_op0(value);
+        self
+    }
+
+    /// Sets the value of the `Op0` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_op0(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_op0(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `Op0` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_op0(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::OP0_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `EC` field.
+    pub const fn ec(self) -> u8 {
+        Self::EC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `EC` field.
+    pub const fn set_ec(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::EC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `EC` field set to the given value.
+    pub const fn with_ec(mut self, value: u8) -> Self {
+        self.set_ec(value);
+        self
+    }
+
+    /// Sets the value of the `EC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ec(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ec(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `EC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ec(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::EC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ISS2` field.
+    pub const fn iss2(self) -> u32 {
+        Self::ISS2_FIELD.extract(self.bits()) as u32
+    }
+
+    /// Sets the value of the `ISS2` field.
+    pub const fn set_iss2(&mut self, value: u32) {
+        *self = Self::from_bits_retain(Self::ISS2_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value.
+    pub const fn with_iss2(mut self, value: u32) -> Self {
+        self.set_iss2(value);
+        self
+    }
+
+    /// Sets the value of the `ISS2` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_iss2(&mut self, value: u32) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_iss2(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_iss2(self, value: u32) -> Result<Self, FieldOverflow> {
+        match Self::ISS2_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el3")]
+impl Display for EsrEl3Sysreg {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("EsrEl3Sysreg");
+        let bits = self.bits();
+        s.field("Direction", &Self::DIRECTION_FIELD.extract(bits));
+        s.field("CRm", &Self::CRM_FIELD.extract(bits));
+        s.field("Rt", &Self::RT_FIELD.extract(bits));
+        s.field("CRn", &Self::CRN_FIELD.extract(bits));
+        s.field("Op1", &Self::OP1_FIELD.extract(bits));
+        s.field("Op2", &Self::OP2_FIELD.extract(bits));
+        s.field("Op0", &Self::OP0_FIELD.extract(bits));
+        s.field("IL", &Self::IL_FIELD.extract(bits));
+        s.field("EC", &Self::EC_FIELD.extract(bits));
+        s.field(
+            "ISS2",
+            &format_args!("{:#x}", Self::ISS2_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el3")]
+impl defmt::Format for EsrEl3Sysreg {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "EsrEl3Sysreg {{ Direction: {=u64}, CRm: {=u64}, Rt: {=u64}, CRn: {=u64}, Op1: {=u64}, Op2: {=u64}, Op0: {=u64}, IL: {=u64}, EC: {=u64}, ISS2: {=u64:#x} }}",
+            Self::DIRECTION_FIELD.extract(self.bits()),
+            Self::CRM_FIELD.extract(self.bits()),
+            Self::RT_FIELD.extract(self.bits()),
+            Self::CRN_FIELD.extract(self.bits()),
+            Self::OP1_FIELD.extract(self.bits()),
+            Self::OP2_FIELD.extract(self.bits()),
+            Self::OP0_FIELD.extract(self.bits()),
+            Self::IL_FIELD.extract(self.bits()),
+            Self::EC_FIELD.extract(self.bits()),
+            Self::ISS2_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el3")]
+impl serde::Serialize for EsrEl3Sysreg {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el3")]
+impl<'de> serde::Deserialize<'de> for EsrEl3Sysreg {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el3")]
+impl EsrEl3Sysreg {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("Direction"),
+                old: Self::DIRECTION_FIELD.extract(self.bits()),
+                new: Self::DIRECTION_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CRm"),
+                old: Self::CRM_FIELD.extract(self.bits()),
+                new: Self::CRM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("Rt"),
+                old: Self::RT_FIELD.extract(self.bits()),
+                new: Self::RT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CRn"),
+                old: Self::CRN_FIELD.extract(self.bits()),
+                new: Self::CRN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("Op1"),
+                old: Self::OP1_FIELD.extract(self.bits()),
+                new: Self::OP1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("Op2"),
+                old: Self::OP2_FIELD.extract(self.bits()),
+                new: Self::OP2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("Op0"),
+                old: Self::OP0_FIELD.extract(self.bits()),
+                new: Self::OP0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IL"),
+                old: Self::IL_FIELD.extract(self.bits()),
+                new: Self::IL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EC"),
+                old: Self::EC_FIELD.extract(self.bits()),
+                new: Self::EC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ISS2"),
+                old: Self::ISS2_FIELD.extract(self.bits()),
+                new: Self::ISS2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0001_1100_0000_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0001_1100_0000_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el3")]
+impl EsrEl3Sysreg {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el3")]
+impl EsrEl3Sysreg {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el3")]
+bitflags! {
+    /// `ESR_EL3` system register value, when `EC` is 32.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct EsrEl3InstructionAbortLower: u64 {
+        /// `S1PTW` bit.
+        const S1PTW = 1 << 7;
+        /// `EA` bit.
+        const EA = 1 << 9;
+        /// `FnV` bit.
+        const FNV = 1 << 10;
+        /// 32-bit instruction length.
+        const IL = 1 << 25;
+    }
+}
+
+#[cfg(feature = "el3")]
+impl EsrEl3InstructionAbortLower {
+    /// Descriptor of the `IFSC` field, for use with [`FieldAccess`].
+    pub const IFSC_FIELD: Field<Self, u8, 0, 6> = Field::new("ESR_EL3", "IFSC");
+    /// Descriptor of the `S1PTW` field, for use with [`FieldAccess`].
+    pub const S1PTW_FIELD: Field<Self, bool, 7, 1> = Field::new("ESR_EL3", "S1PTW");
+    /// Descriptor of the `EA` field, for use with [`FieldAccess`].
+    pub const EA_FIELD: Field<Self, bool, 9, 1> = Field::new("ESR_EL3", "EA");
+    /// Descriptor of the `FnV` field, for use with [`FieldAccess`].
+    pub const FNV_FIELD: Field<Self, bool, 10, 1> = Field::new("ESR_EL3", "FnV");
+    /// Descriptor of the `SET` field, for use with [`FieldAccess`].
+    pub const SET_FIELD: Field<Self, u8, 11, 2> = Field::new("ESR_EL3", "SET");
+    /// Descriptor of the `IL` field, for use with [`FieldAccess`].
+    pub const IL_FIELD: Field<Self, bool, 25, 1> = Field::new("ESR_EL3", "IL");
+    /// Descriptor of the `EC` field, for use with [`FieldAccess`].
+    pub const EC_FIELD: Field<Self, u8, 26, 6> = Field::new("ESR_EL3", "EC");
+    /// Descriptor of the `ISS2` field, for use with [`FieldAccess`].
+    pub const ISS2_FIELD: Field<Self, u32, 32, 24> = Field::new("ESR_EL3", "ISS2");
+
+    /// Returns the value of the `IFSC` field.
+    pub const fn ifsc(self) -> u8 {
+        Self::IFSC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `IFSC` field.
+    pub const fn set_ifsc(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::IFSC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `IFSC` field set to the given value.
+    pub const fn with_ifsc(mut self, value: u8) -> Self {
+        self.set_ifsc(value);
+        self
+    }
+
+    /// Sets the value of the `IFSC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ifsc(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ifsc(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `IFSC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ifsc(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::IFSC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SET` field.
+    pub const fn set_(self) -> u8 {
+        Self::SET_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SET` field.
+    pub const fn set_set_(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SET_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SET` field set to the given value.
+    pub const fn with_set_(mut self, value: u8) -> Self {
+        self.set_set_(value);
+        self
+    }
+
+    /// Sets the value of the `SET` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_set_(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_set_(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SET` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_set_(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SET_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `EC` field.
+    pub const fn ec(self) -> u8 {
+        Self::EC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `EC` field.
+    pub const fn set_ec(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::EC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `EC` field set to the given value.
+    pub const fn with_ec(mut self, value: u8) -> Self {
+        self.set_ec(value);
+        self
+    }
+
+    /// Sets the value of the `EC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ec(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ec(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `EC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ec(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::EC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ISS2` field.
+    pub const fn iss2(self) -> u32 {
+        Self::ISS2_FIELD.extract(self.bits()) as u32
+    }
+
+    /// Sets the value of the `ISS2` field.
+    pub const fn set_iss2(&mut self, value: u32) {
+        *self = Self::from_bits_retain(Self::ISS2_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value.
+    pub const fn with_iss2(mut self, value: u32) -> Self {
+        self.set_iss2(value);
+        self
+    }
+
+    /// Sets the value of the `ISS2` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_iss2(&mut self, value: u32) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_iss2(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_iss2(self, value: u32) -> Result<Self, FieldOverflow> {
+        match Self::ISS2_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el3")]
+impl Display for EsrEl3InstructionAbortLower {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("EsrEl3InstructionAbortLower");
+        let bits = self.bits();
+        s.field("IFSC", &Self::IFSC_FIELD.extract(bits));
+        s.field("S1PTW", &Self::S1PTW_FIELD.extract(bits));
+        s.field("EA", &Self::EA_FIELD.extract(bits));
+        s.field("FnV", &Self::FNV_FIELD.extract(bits));
+        s.field("SET", &Self::SET_FIELD.extract(bits));
+        s.field("IL", &Self::IL_FIELD.extract(bits));
+        s.field("EC", &Self::EC_FIELD.extract(bits));
+        s.field(
+            "ISS2",
+            &format_args!("{:#x}", Self::ISS2_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el3")]
+impl defmt::Format for EsrEl3InstructionAbortLower {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "EsrEl3InstructionAbortLower {{ IFSC: {=u64}, S1PTW: {=u64}, EA: {=u64}, FnV: {=u64}, SET: {=u64}, IL: {=u64}, EC: {=u64}, ISS2: {=u64:#x} }}",
+            Self::IFSC_FIELD.extract(self.bits()),
+            Self::S1PTW_FIELD.extract(self.bits()),
+            Self::EA_FIELD.extract(self.bits()),
+            Self::FNV_FIELD.extract(self.bits()),
+            Self::SET_FIELD.extract(self.bits()),
+            Self::IL_FIELD.extract(self.bits()),
+            Self::EC_FIELD.extract(self.bits()),
+            Self::ISS2_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el3")]
+impl serde::Serialize for EsrEl3InstructionAbortLower {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el3")]
+impl<'de> serde::Deserialize<'de> for EsrEl3InstructionAbortLower {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el3")]
+impl EsrEl3InstructionAbortLower {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("IFSC"),
+                old: Self::IFSC_FIELD.extract(self.bits()),
+                new: Self::IFSC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("S1PTW"),
+                old: Self::S1PTW_FIELD.extract(self.bits()),
+                new: Self::S1PTW_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EA"),
+                old: Self::EA_FIELD.extract(self.bits()),
+                new: Self::EA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FnV"),
+                old: Self::FNV_FIELD.extract(self.bits()),
+                new: Self::FNV_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SET"),
+                old: Self::SET_FIELD.extract(self.bits()),
+                new: Self::SET_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IL"),
+                old: Self::IL_FIELD.extract(self.bits()),
+                new: Self::IL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EC"),
+                old: Self::EC_FIELD.extract(self.bits()),
+                new: Self::EC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ISS2"),
+                old: Self::ISS2_FIELD.extract(self.bits()),
+                new: Self::ISS2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0001_1111_1111_1110_0001_0100_0000,
+                new: other.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0001_1111_1111_1110_0001_0100_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el3")]
+impl EsrEl3InstructionAbortLower {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el3")]
+impl EsrEl3InstructionAbortLower {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el3")]
+bitflags! {
+    /// `ESR_EL3` system register value, when `EC` is 33.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct EsrEl3InstructionAbort: u64 {
+        /// `S1PTW` bit.
+        const S1PTW = 1 << 7;
+        /// `EA` bit.
+        const EA = 1 << 9;
+        /// `FnV` bit.
+        const FNV = 1 << 10;
+        /// 32-bit instruction length.
+        const IL = 1 << 25;
+    }
+}
+
+#[cfg(feature = "el3")]
+impl EsrEl3InstructionAbort {
+    /// Descriptor of the `IFSC` field, for use with [`FieldAccess`].
+    pub const IFSC_FIELD: Field<Self, u8, 0, 6> = Field::new("ESR_EL3", "IFSC");
+    /// Descriptor of the `S1PTW` field, for use with [`FieldAccess`].
+    pub const S1PTW_FIELD: Field<Self, bool, 7, 1> = Field::new("ESR_EL3", "S1PTW");
+    /// Descriptor of the `EA` field, for use with [`FieldAccess`].
+    pub const EA_FIELD: Field<Self, bool, 9, 1> = Field::new("ESR_EL3", "EA");
+    /// Descriptor of the `FnV` field, for use with [`FieldAccess`].
+    pub const FNV_FIELD: Field<Self, bool, 10, 1> = Field::new("ESR_EL3", "FnV");
+    /// Descriptor of the `SET` field, for use with [`FieldAccess`].
+    pub const SET_FIELD: Field<Self, u8, 11, 2> = Field::new("ESR_EL3", "SET");
+    /// Descriptor of the `IL` field, for use with [`FieldAccess`].
+    pub const IL_FIELD: Field<Self, bool, 25, 1> = Field::new("ESR_EL3", "IL");
+    /// Descriptor of the `EC` field, for use with [`FieldAccess`].
+    pub const EC_FIELD: Field<Self, u8, 26, 6> = Field::new("ESR_EL3", "EC");
+    /// Descriptor of the `ISS2` field, for use with [`FieldAccess`].
+    pub const ISS2_FIELD: Field<Self, u32, 32, 24> = Field::new("ESR_EL3", "ISS2");
+
+    /// Returns the value of the `IFSC` field.
+    pub const fn ifsc(self) -> u8 {
+        Self::IFSC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `IFSC` field.
+    pub const fn set_ifsc(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::IFSC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `IFSC` field set to the given value.
+    pub const fn with_ifsc(mut self, value: u8) -> Self {
+        self.set_ifsc(value);
+        self
+    }
+
+    /// Sets the value of the `IFSC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ifsc(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ifsc(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `IFSC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ifsc(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::IFSC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SET` field.
+    pub const fn set_(self) -> u8 {
+        Self::SET_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SET` field.
+    pub const fn set_set_(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SET_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SET` field set to the given value.
+    pub const fn with_set_(mut self, value: u8) -> Self {
+        self.set_set_(value);
+        self
+    }
+
+    /// Sets the value of the `SET` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_set_(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_set_(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SET` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_set_(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SET_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `EC` field.
+    pub const fn ec(self) -> u8 {
+        Self::EC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `EC` field.
+    pub const fn set_ec(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::EC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `EC` field set to the given value.
+    pub const fn with_ec(mut self, value: u8) -> Self {
+        self.set_ec(value);
+        self
+    }
+
+    /// Sets the value of the `EC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ec(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ec(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `EC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ec(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::EC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ISS2` field.
+    pub const fn iss2(self) -> u32 {
+        Self::ISS2_FIELD.extract(self.bits()) as u32
+    }
+
+    /// Sets the value of the `ISS2` field.
+    pub const fn set_iss2(&mut self, value: u32) {
+        *self = Self::from_bits_retain(Self::ISS2_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value.
+    pub const fn with_iss2(mut self, value: u32) -> Self {
+        self.set_iss2(value);
+        self
+    }
+
+    /// Sets the value of the `ISS2` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_iss2(&mut self, value: u32) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_iss2(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_iss2(self, value: u32) -> Result<Self, FieldOverflow> {
+        match Self::ISS2_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el3")]
+impl Display for EsrEl3InstructionAbort {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("EsrEl3InstructionAbort");
+        let bits = self.bits();
+        s.field("IFSC", &Self::IFSC_FIELD.extract(bits));
+        s.field("S1PTW", &Self::S1PTW_FIELD.extract(bits));
+        s.field("EA", &Self::EA_FIELD.extract(bits));
+        s.field("FnV", &Self::FNV_FIELD.extract(bits));
+        s.field("SET", &Self::SET_FIELD.extract(bits));
+        s.field("IL", &Self::IL_FIELD.extract(bits));
+        s.field("EC", &Self::EC_FIELD.extract(bits));
+        s.field(
+            "ISS2",
+            &format_args!("{:#x}", Self::ISS2_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el3")]
+impl defmt::Format for EsrEl3InstructionAbort {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "EsrEl3InstructionAbort {{ IFSC: {=u64}, S1PTW: {=u64}, EA: {=u64}, FnV: {=u64}, SET: {=u64}, IL: {=u64}, EC: {=u64}, ISS2: {=u64:#x} }}",
+            Self::IFSC_FIELD.extract(self.bits()),
+            Self::S1PTW_FIELD.extract(self.bits()),
+            Self::EA_FIELD.extract(self.bits()),
+            Self::FNV_FIELD.extract(self.bits()),
+            Self::SET_FIELD.extract(self.bits()),
+            Self::IL_FIELD.extract(self.bits()),
+            Self::EC_FIELD.extract(self.bits()),
+            Self::ISS2_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el3")]
+impl serde::Serialize for EsrEl3InstructionAbort {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el3")]
+impl<'de> serde::Deserialize<'de> for EsrEl3InstructionAbort {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el3")]
+impl EsrEl3InstructionAbort {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("IFSC"),
+                old: Self::IFSC_FIELD.extract(self.bits()),
+                new: Self::IFSC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("S1PTW"),
+                old: Self::S1PTW_FIELD.extract(self.bits()),
+                new: Self::S1PTW_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EA"),
+                old: Self::EA_FIELD.extract(self.bits()),
+                new: Self::EA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FnV"),
+                old: Self::FNV_FIELD.extract(self.bits()),
+                new: Self::FNV_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SET"),
+                old: Self::SET_FIELD.extract(self.bits()),
+                new: Self::SET_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IL"),
+                old: Self::IL_FIELD.extract(self.bits()),
+                new: Self::IL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EC"),
+                old: Self::EC_FIELD.extract(self.bits()),
+                new: Self::EC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ISS2"),
+                old: Self::ISS2_FIELD.extract(self.bits()),
+                new: Self::ISS2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0001_1111_1111_1110_0001_0100_0000,
+                new: other.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0001_1111_1111_1110_0001_0100_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el3")]
+impl EsrEl3InstructionAbort {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el3")]
+impl EsrEl3InstructionAbort {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el3")]
+bitflags! {
+    /// `ESR_EL3` system register value, when `EC` is 36.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct EsrEl3DataAbortLower: u64 {
+        /// `WnR` bit.
+        const WNR = 1 << 6;
+        /// `S1PTW` bit.
+        const S1PTW = 1 << 7;
+        /// `CM` bit.
+        const CM = 1 << 8;
+        /// `EA` bit.
+        const EA = 1 << 9;
+        /// `FnV` bit.
+        const FNV = 1 << 10;
+        /// `VNCR` bit.
+        const VNCR = 1 << 13;
+        /// `AR` bit.
+        const AR = 1 << 14;
+        /// `SF` bit.
+        const SF = 1 << 15;
+        /// `SSE` bit.
+        const SSE = 1 << 21;
+        /// `ISV` bit.
+        const ISV = 1 << 24;
+        /// 32-bit instruction length.
+        const IL = 1 << 25;
+    }
+}
+
+#[cfg(feature = "el3")]
+impl EsrEl3DataAbortLower {
+    /// Descriptor of the `DFSC` field, for use with [`FieldAccess`].
+    pub const DFSC_FIELD: Field<Self, u8, 0, 6> = Field::new("ESR_EL3", "DFSC");
+    /// Descriptor of the `WnR` field, for use with [`FieldAccess`].
+    pub const WNR_FIELD: Field<Self, bool, 6, 1> = Field::new("ESR_EL3", "WnR");
+    /// Descriptor of the `S1PTW` field, for use with [`FieldAccess`].
+    pub const S1PTW_FIELD: Field<Self, bool, 7, 1> = Field::new("ESR_EL3", "S1PTW");
+    /// Descriptor of the `CM` field, for use with [`FieldAccess`].
+    pub const CM_FIELD: Field<Self, bool, 8, 1> = Field::new("ESR_EL3", "CM");
+    /// Descriptor of the `EA` field, for use with [`FieldAccess`].
+    pub const EA_FIELD: Field<Self, bool, 9, 1> = Field::new("ESR_EL3", "EA");
+    /// Descriptor of the `FnV` field, for use with [`FieldAccess`].
+    pub const FNV_FIELD: Field<Self, bool, 10, 1> = Field::new("ESR_EL3", "FnV");
+    /// Descriptor of the `SET` field, for use with [`FieldAccess`].
+    pub const SET_FIELD: Field<Self, u8, 11, 2> = Field::new("ESR_EL3", "SET");
+    /// Descriptor of the `VNCR` field, for use with [`FieldAccess`].
+    pub const VNCR_FIELD: Field<Self, bool, 13, 1> = Field::new("ESR_EL3", "VNCR");
+    /// Descriptor of the `AR` field, for use with [`FieldAccess`].
+    pub const AR_FIELD: Field<Self, bool, 14, 1> = Field::new("ESR_EL3", "AR");
+    /// Descriptor of the `SF` field, for use with [`FieldAccess`].
+    pub const SF_FIELD: Field<Self, bool, 15, 1> = Field::new("ESR_EL3", "SF");
+    /// Descriptor of the `SRT` field, for use with [`FieldAccess`].
+    pub const SRT_FIELD: Field<Self, u8, 16, 5> = Field::new("ESR_EL3", "SRT");
+    /// Descriptor of the `SSE` field, for use with [`FieldAccess`].
+    pub const SSE_FIELD: Field<Self, bool, 21, 1> = Field::new("ESR_EL3", "SSE");
+    /// Descriptor of the `SAS` field, for use with [`FieldAccess`].
+    pub const SAS_FIELD: Field<Self, u8, 22, 2> = Field::new("ESR_EL3", "SAS");
+    /// Descriptor of the `ISV` field, for use with [`FieldAccess`].
+    pub const ISV_FIELD: Field<Self, bool, 24, 1> = Field::new("ESR_EL3", "ISV");
+    /// Descriptor of the `IL` field, for use with [`FieldAccess`].
+    pub const IL_FIELD: Field<Self, bool, 25, 1> = Field::new("ESR_EL3", "IL");
+    /// Descriptor of the `EC` field, for use with [`FieldAccess`].
+    pub const EC_FIELD: Field<Self, u8, 26, 6> = Field::new("ESR_EL3", "EC");
+    /// Descriptor of the `ISS2` field, for use with [`FieldAccess`].
+    pub const ISS2_FIELD: Field<Self, u32, 32, 24> = Field::new("ESR_EL3", "ISS2");
+
+    /// Returns the value of the `DFSC` field.
+    pub const fn dfsc(self) -> u8 {
+        Self::DFSC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `DFSC` field.
+    pub const fn set_dfsc(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::DFSC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `DFSC` field set to the given value.
+    pub const fn with_dfsc(mut self, value: u8) -> Self {
+        self.set_dfsc(value);
+        self
+    }
+
+    /// Sets the value of the `DFSC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_dfsc(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_dfsc(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `DFSC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_dfsc(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::DFSC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SET` field.
+    pub const fn set_(self) -> u8 {
+        Self::SET_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SET` field.
+    pub const fn set_set_(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SET_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SET` field set to the given value.
+    pub const fn with_set_(mut self, value: u8) -> Self {
+        self.set_set_(value);
+        self
+    }
+
+    /// Sets the value of the `SET` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_set_(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_set_(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SET` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_set_(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SET_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SRT` field.
+    pub const fn srt(self) -> u8 {
+        Self::SRT_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SRT` field.
+    pub const fn set_srt(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SRT_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SRT` field set to the given value.
+    pub const fn with_srt(mut self, value: u8) -> Self {
+        self.set_srt(value);
+        self
+    }
+
+    /// Sets the value of the `SRT` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_srt(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_srt(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SRT` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_srt(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SRT_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SAS` field.
+    pub const fn sas(self) -> u8 {
+        Self::SAS_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SAS` field.
+    pub const fn set_sas(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SAS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SAS` field set to the given value.
+    pub const fn with_sas(mut self, value: u8) -> Self {
+        self.set_sas(value);
+        self
+    }
+
+    /// Sets the value of the `SAS` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_sas(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_sas(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SAS` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_sas(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SAS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `EC` field.
+    pub const fn ec(self) -> u8 {
+        Self::EC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `EC` field.
+    pub const fn set_ec(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::EC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `EC` field set to the given value.
+    pub const fn with_ec(mut self, value: u8) -> Self {
+        self.set_ec(value);
+        self
+    }
+
+    /// Sets the value of the `EC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ec(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ec(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `EC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ec(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::EC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ISS2` field.
+    pub const fn iss2(self) -> u32 {
+        Self::ISS2_FIELD.extract(self.bits()) as u32
+    }
+
+    /// Sets the value of the `ISS2` field.
+    pub const fn set_iss2(&mut self, value: u32) {
+        *self = Self::from_bits_retain(Self::ISS2_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value.
+    pub const fn with_iss2(mut self, value: u32) -> Self {
+        self.set_iss2(value);
+        self
+    }
+
+    /// Sets the value of the `ISS2` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_iss2(&mut self, value: u32) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_iss2(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_iss2(self, value: u32) -> Result<Self, FieldOverflow> {
+        match Self::ISS2_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el3")]
+impl Display for EsrEl3DataAbortLower {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("EsrEl3DataAbortLower");
+        let bits = self.bits();
+        s.field("DFSC", &Self::DFSC_FIELD.extract(bits));
+        s.field("WnR", &Self::WNR_FIELD.extract(bits));
+        s.field("S1PTW", &Self::S1PTW_FIELD.extract(bits));
+        s.field("CM", &Self::CM_FIELD.extract(bits));
+        s.field("EA", &Self::EA_FIELD.extract(bits));
+        s.field("FnV", &Self::FNV_FIELD.extract(bits));
+        s.field("SET", &Self::SET_FIELD.extract(bits));
+        s.field("VNCR", &Self::VNCR_FIELD.extract(bits));
+        s.field("AR", &Self::AR_FIELD.extract(bits));
+        s.field("SF", &Self::SF_FIELD.extract(bits));
+        s.field("SRT", &Self::SRT_FIELD.extract(bits));
+        s.field("SSE", &Self::SSE_FIELD.extract(bits));
+        s.field("SAS", &Self::SAS_FIELD.extract(bits));
+        s.field("ISV", &Self::ISV_FIELD.extract(bits));
+        s.field("IL", &Self::IL_FIELD.extract(bits));
+        s.field("EC", &Self::EC_FIELD.extract(bits));
+        s.field(
+            "ISS2",
+            &format_args!("{:#x}", Self::ISS2_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el3")]
+impl defmt::Format for EsrEl3DataAbortLower {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "EsrEl3DataAbortLower {{ DFSC: {=u64}, WnR: {=u64}, S1PTW: {=u64}, CM: {=u64}, EA: {=u64}, FnV: {=u64}, SET: {=u64}, VNCR: {=u64}, AR: {=u64}, SF: {=u64}, SRT: {=u64}, SSE: {=u64}, SAS: {=u64}, ISV: {=u64}, IL: {=u64}, EC: {=u64}, ISS2: {=u64:#x} }}",
+            Self::DFSC_FIELD.extract(self.bits()),
+            Self::WNR_FIELD.extract(self.bits()),
+            Self::S1PTW_FIELD.extract(self.bits()),
+            Self::CM_FIELD.extract(self.bits()),
+            Self::EA_FIELD.extract(self.bits()),
+            Self::FNV_FIELD.extract(self.bits()),
+            Self::SET_FIELD.extract(self.bits()),
+            Self::VNCR_FIELD.extract(self.bits()),
+            Self::AR_FIELD.extract(self.bits()),
+            Self::SF_FIELD.extract(self.bits()),
+            Self::SRT_FIELD.extract(self.bits()),
+            Self::SSE_FIELD.extract(self.bits()),
+            Self::SAS_FIELD.extract(self.bits()),
+            Self::ISV_FIELD.extract(self.bits()),
+            Self::IL_FIELD.extract(self.bits()),
+            Self::EC_FIELD.extract(self.bits()),
+            Self::ISS2_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el3")]
+impl serde::Serialize for EsrEl3DataAbortLower {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el3")]
+impl<'de> serde::Deserialize<'de> for EsrEl3DataAbortLower {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el3")]
+impl EsrEl3DataAbortLower {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("DFSC"),
+                old: Self::DFSC_FIELD.extract(self.bits()),
+                new: Self::DFSC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("WnR"),
+                old: Self::WNR_FIELD.extract(self.bits()),
+                new: Self::WNR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("S1PTW"),
+                old: Self::S1PTW_FIELD.extract(self.bits()),
+                new: Self::S1PTW_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CM"),
+                old: Self::CM_FIELD.extract(self.bits()),
+                new: Self::CM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EA"),
+                old: Self::EA_FIELD.extract(self.bits()),
+                new: Self::EA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FnV"),
+                old: Self::FNV_FIELD.extract(self.bits()),
+                new: Self::FNV_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SET"),
+                old: Self::SET_FIELD.extract(self.bits()),
+                new: Self::SET_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VNCR"),
+                old: Self::VNCR_FIELD.extract(self.bits()),
+                new: Self::VNCR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("AR"),
+                old: Self::AR_FIELD.extract(self.bits()),
+                new: Self::AR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SF"),
+                old: Self::SF_FIELD.extract(self.bits()),
+                new: Self::SF_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SRT"),
+                old: Self::SRT_FIELD.extract(self.bits()),
+                new: Self::SRT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SSE"),
+                old: Self::SSE_FIELD.extract(self.bits()),
+                new: Self::SSE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SAS"),
+                old: Self::SAS_FIELD.extract(self.bits()),
+                new: Self::SAS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ISV"),
+                old: Self::ISV_FIELD.extract(self.bits()),
+                new: Self::ISV_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IL"),
+                old: Self::IL_FIELD.extract(self.bits()),
+                new: Self::IL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EC"),
+                old: Self::EC_FIELD.extract(self.bits()),
+                new: Self::EC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ISS2"),
+                old: Self::ISS2_FIELD.extract(self.bits()),
+                new: Self::ISS2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el3")]
+impl EsrEl3DataAbortLower {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el3")]
+impl EsrEl3DataAbortLower {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el3")]
+bitflags! {
+    /// `ESR_EL3` system register value, when `EC` is 37.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct EsrEl3DataAbort: u64 {
+        /// `WnR` bit.
+        const WNR = 1 << 6;
+        /// `S1PTW` bit.
+        const S1PTW = 1 << 7;
+        /// `CM` bit.
+        const CM = 1 << 8;
+        /// `EA` bit.
+        const EA = 1 << 9;
+        /// `FnV` bit.
+        const FNV = 1 << 10;
+        /// `VNCR` bit.
+        const VNCR = 1 << 13;
+        /// `AR` bit.
+        const AR = 1 << 14;
+        /// `SF` bit.
+        const SF = 1 << 15;
+        /// `SSE` bit.
+        const SSE = 1 << 21;
+        /// `ISV` bit.
+        const ISV = 1 << 24;
+        /// 32-bit instruction length.
+        const IL = 1 << 25;
+    }
+}
+
+#[cfg(feature = "el3")]
+impl EsrEl3DataAbort {
+    /// Descriptor of the `DFSC` field, for use with [`FieldAccess`].
+    pub const DFSC_FIELD: Field<Self, u8, 0, 6> = Field::new("ESR_EL3", "DFSC");
+    /// Descriptor of the `WnR` field, for use with [`FieldAccess`].
+    pub const WNR_FIELD: Field<Self, bool, 6, 1> = Field::new("ESR_EL3", "WnR");
+    /// Descriptor of the `S1PTW` field, for use with [`FieldAccess`].
+    pub const S1PTW_FIELD: Field<Self, bool, 7, 1> = Field::new("ESR_EL3", "S1PTW");
+    /// Descriptor of the `CM` field, for use with [`FieldAccess`].
+    pub const CM_FIELD: Field<Self, bool, 8, 1> = Field::new("ESR_EL3", "CM");
+    /// Descriptor of the `EA` field, for use with [`FieldAccess`].
+    pub const EA_FIELD: Field<Self, bool, 9, 1> = Field::new("ESR_EL3", "EA");
+    /// Descriptor of the `FnV` field, for use with [`FieldAccess`].
+    pub const FNV_FIELD: Field<Self, bool, 10, 1> = Field::new("ESR_EL3", "FnV");
+    /// Descriptor of the `SET` field, for use with [`FieldAccess`].
+    pub const SET_FIELD: Field<Self, u8, 11, 2> = Field::new("ESR_EL3", "SET");
+    /// Descriptor of the `VNCR` field, for use with [`FieldAccess`].
+    pub const VNCR_FIELD: Field<Self, bool, 13, 1> = Field::new("ESR_EL3", "VNCR");
+    /// Descriptor of the `AR` field, for use with [`FieldAccess`].
+    pub const AR_FIELD: Field<Self, bool, 14, 1> = Field::new("ESR_EL3", "AR");
+    /// Descriptor of the `SF` field, for use with [`FieldAccess`].
+    pub const SF_FIELD: Field<Self, bool, 15, 1> = Field::new("ESR_EL3", "SF");
+    /// Descriptor of the `SRT` field, for use with [`FieldAccess`].
+    pub const SRT_FIELD: Field<Self, u8, 16, 5> = Field::new("ESR_EL3", "SRT");
+    /// Descriptor of the `SSE` field, for use with [`FieldAccess`].
+    pub const SSE_FIELD: Field<Self, bool, 21, 1> = Field::new("ESR_EL3", "SSE");
+    /// Descriptor of the `SAS` field, for use with [`FieldAccess`].
+    pub const SAS_FIELD: Field<Self, u8, 22, 2> = Field::new("ESR_EL3", "SAS");
+    /// Descriptor of the `ISV` field, for use with [`FieldAccess`].
+    pub const ISV_FIELD: Field<Self, bool, 24, 1> = Field::new("ESR_EL3", "ISV");
+    /// Descriptor of the `IL` field, for use with [`FieldAccess`].
+    pub const IL_FIELD: Field<Self, bool, 25, 1> = Field::new("ESR_EL3", "IL");
+    /// Descriptor of the `EC` field, for use with [`FieldAccess`].
+    pub const EC_FIELD: Field<Self, u8, 26, 6> = Field::new("ESR_EL3", "EC");
+    /// Descriptor of the `ISS2` field, for use with [`FieldAccess`].
+    pub const ISS2_FIELD: Field<Self, u32, 32, 24> = Field::new("ESR_EL3", "ISS2");
+
+    /// Returns the value of the `DFSC` field.
+    pub const fn dfsc(self) -> u8 {
+        Self::DFSC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `DFSC` field.
+    pub const fn set_dfsc(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::DFSC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `DFSC` field set to the given value.
+    pub const fn with_dfsc(mut self, value: u8) -> Self {
+        self.set_dfsc(value);
+        self
+    }
+
+    /// Sets the value of the `DFSC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_dfsc(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_dfsc(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `DFSC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_dfsc(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::DFSC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SET` field.
+    pub const fn set_(self) -> u8 {
+        Self::SET_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SET` field.
+    pub const fn set_set_(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SET_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SET` field set to the given value.
+    pub const fn with_set_(mut self, value: u8) -> Self {
+        self.set_set_(value);
+        self
+    }
+
+    /// Sets the value of the `SET` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_set_(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_set_(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SET` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_set_(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SET_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SRT` field.
+    pub const fn srt(self) -> u8 {
+        Self::SRT_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SRT` field.
+    pub const fn set_srt(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SRT_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SRT` field set to the given value.
+    pub const fn with_srt(mut self, value: u8) -> Self {
+        self.set_srt(value);
+        self
+    }
+
+    /// Sets the value of the `SRT` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_srt(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_srt(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SRT` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_srt(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SRT_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SAS` field.
+    pub const fn sas(self) -> u8 {
+        Self::SAS_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SAS` field.
+    pub const fn set_sas(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SAS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SAS` field set to the given value.
+    pub const fn with_sas(mut self, value: u8) -> Self {
+        self.set_sas(value);
+        self
+    }
+
+    /// Sets the value of the `SAS` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_sas(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_sas(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SAS` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_sas(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SAS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `EC` field.
+    pub const fn ec(self) -> u8 {
+        Self::EC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `EC` field.
+    pub const fn set_ec(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::EC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `EC` field set to the given value.
+    pub const fn with_ec(mut self, value: u8) -> Self {
+        self.set_ec(value);
+        self
+    }
+
+    /// Sets the value of the `EC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ec(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ec(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `EC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ec(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::EC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ISS2` field.
+    pub const fn iss2(self) -> u32 {
+        Self::ISS2_FIELD.extract(self.bits()) as u32
+    }
+
+    /// Sets the value of the `ISS2` field.
+    pub const fn set_iss2(&mut self, value: u32) {
+        *self = Self::from_bits_retain(Self::ISS2_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value.
+    pub const fn with_iss2(mut self, value: u32) -> Self {
+        self.set_iss2(value);
+        self
+    }
+
+    /// Sets the value of the `ISS2` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_iss2(&mut self, value: u32) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_iss2(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ISS2` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_iss2(self, value: u32) -> Result<Self, FieldOverflow> {
+        match Self::ISS2_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el3")]
+impl Display for EsrEl3DataAbort {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("EsrEl3DataAbort");
+        let bits = self.bits();
+        s.field("DFSC", &Self::DFSC_FIELD.extract(bits));
+        s.field("WnR", &Self::WNR_FIELD.extract(bits));
+        s.field("S1PTW", &Self::S1PTW_FIELD.extract(bits));
+        s.field("CM", &Self::CM_FIELD.extract(bits));
+        s.field("EA", &Self::EA_FIELD.extract(bits));
+        s.field("FnV", &Self::FNV_FIELD.extract(bits));
+        s.field("SET", &Self::SET_FIELD.extract(bits));
+        s.field("VNCR", &Self::VNCR_FIELD.extract(bits));
+        s.field("AR", &Self::AR_FIELD.extract(bits));
+        s.field("SF", &Self::SF_FIELD.extract(bits));
+        s.field("SRT", &Self::SRT_FIELD.extract(bits));
+        s.field("SSE", &Self::SSE_FIELD.extract(bits));
+        s.field("SAS", &Self::SAS_FIELD.extract(bits));
+        s.field("ISV", &Self::ISV_FIELD.extract(bits));
+        s.field("IL", &Self::IL_FIELD.extract(bits));
+        s.field("EC", &Self::EC_FIELD.extract(bits));
+        s.field(
+            "ISS2",
+            &format_args!("{:#x}", Self::ISS2_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el3")]
+impl defmt::Format for EsrEl3DataAbort {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "EsrEl3DataAbort {{ DFSC: {=u64}, WnR: {=u64}, S1PTW: {=u64}, CM: {=u64}, EA: {=u64}, FnV: {=u64}, SET: {=u64}, VNCR: {=u64}, AR: {=u64}, SF: {=u64}, SRT: {=u64}, SSE: {=u64}, SAS: {=u64}, ISV: {=u64}, IL: {=u64}, EC: {=u64}, ISS2: {=u64:#x} }}",
+            Self::DFSC_FIELD.extract(self.bits()),
+            Self::WNR_FIELD.extract(self.bits()),
+            Self::S1PTW_FIELD.extract(self.bits()),
+            Self::CM_FIELD.extract(self.bits()),
+            Self::EA_FIELD.extract(self.bits()),
+            Self::FNV_FIELD.extract(self.bits()),
+            Self::SET_FIELD.extract(self.bits()),
+            Self::VNCR_FIELD.extract(self.bits()),
+            Self::AR_FIELD.extract(self.bits()),
+            Self::SF_FIELD.extract(self.bits()),
+            Self::SRT_FIELD.extract(self.bits()),
+            Self::SSE_FIELD.extract(self.bits()),
+            Self::SAS_FIELD.extract(self.bits()),
+            Self::ISV_FIELD.extract(self.bits()),
+            Self::IL_FIELD.extract(self.bits()),
+            Self::EC_FIELD.extract(self.bits()),
+            Self::ISS2_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el3")]
+impl serde::Serialize for EsrEl3DataAbort {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el3")]
+impl<'de> serde::Deserialize<'de> for EsrEl3DataAbort {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el3")]
+impl EsrEl3DataAbort {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("DFSC"),
+                old: Self::DFSC_FIELD.extract(self.bits()),
+                new: Self::DFSC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("WnR"),
+                old: Self::WNR_FIELD.extract(self.bits()),
+                new: Self::WNR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("S1PTW"),
+                old: Self::S1PTW_FIELD.extract(self.bits()),
+                new: Self::S1PTW_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CM"),
+                old: Self::CM_FIELD.extract(self.bits()),
+                new: Self::CM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EA"),
+                old: Self::EA_FIELD.extract(self.bits()),
+                new: Self::EA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FnV"),
+                old: Self::FNV_FIELD.extract(self.bits()),
+                new: Self::FNV_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SET"),
+                old: Self::SET_FIELD.extract(self.bits()),
+                new: Self::SET_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VNCR"),
+                old: Self::VNCR_FIELD.extract(self.bits()),
+                new: Self::VNCR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("AR"),
+                old: Self::AR_FIELD.extract(self.bits()),
+                new: Self::AR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SF"),
+                old: Self::SF_FIELD.extract(self.bits()),
+                new: Self::SF_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SRT"),
+                old: Self::SRT_FIELD.extract(self.bits()),
+                new: Self::SRT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SSE"),
+                old: Self::SSE_FIELD.extract(self.bits()),
+                new: Self::SSE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SAS"),
+                old: Self::SAS_FIELD.extract(self.bits()),
+                new: Self::SAS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ISV"),
+                old: Self::ISV_FIELD.extract(self.bits()),
+                new: Self::ISV_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IL"),
+                old: Self::IL_FIELD.extract(self.bits()),
+                new: Self::IL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EC"),
+                old: Self::EC_FIELD.extract(self.bits()),
+                new: Self::EC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ISS2"),
+                old: Self::ISS2_FIELD.extract(self.bits()),
+                new: Self::ISS2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el3")]
+impl EsrEl3DataAbort {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el3")]
+impl EsrEl3DataAbort {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el3")]
+impl EsrEl3 {
+    /// Returns the value as a `EsrEl3Wfx` if `EC` is 1, or `None` if it has another layout.
+    pub const fn as_wfx(self) -> Option<EsrEl3Wfx> {
+        if Self::EC_FIELD.extract(self.bits()) == 1 {
+            Some(EsrEl3Wfx::from_bits_retain(self.bits()))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value as a `EsrEl3Svc` if `EC` is 21, or `None` if it has another layout.
+    pub const fn as_svc(self) -> Option<EsrEl3Svc> {
+        if Self::EC_FIELD.extract(self.bits()) == 21 {
+            Some(EsrEl3Svc::from_bits_retain(self.bits()))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value as a `EsrEl3Hvc` if `EC` is 22, or `None` if it has another layout.
+    pub const fn as_hvc(self) -> Option<EsrEl3Hvc> {
+        if Self::EC_FIELD.extract(self.bits()) == 22 {
+            Some(EsrEl3Hvc::from_bits_retain(self.bits()))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value as a `EsrEl3Smc` if `EC` is 23, or `None` if it has another layout.
+    pub const fn as_smc(self) -> Option<EsrEl3Smc> {
+        if Self::EC_FIELD.extract(self.bits()) == 23 {
+            Some(EsrEl3Smc::from_bits_retain(self.bits()))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value as a `EsrEl3Sysreg` if `EC` is 24, or `None` if it has another layout.
+    pub const fn as_sysreg(self) -> Option<EsrEl3Sysreg> {
+        if Self::EC_FIELD.extract(self.bits()) == 24 {
+            Some(EsrEl3Sysreg::from_bits_retain(self.bits()))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value as a `EsrEl3InstructionAbortLower` if `EC` is 32, or `None` if it has another layout.
+    pub const fn as_instruction_abort_lower(self) -> Option<EsrEl3InstructionAbortLower> {
+        if Self::EC_FIELD.extract(self.bits()) == 32 {
+            Some(EsrEl3InstructionAbortLower::from_bits_retain(self.bits()))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value as a `EsrEl3InstructionAbort` if `EC` is 33, or `None` if it has another layout.
+    pub const fn as_instruction_abort(self) -> Option<EsrEl3InstructionAbort> {
+        if Self::EC_FIELD.extract(self.bits()) == 33 {
+            Some(EsrEl3InstructionAbort::from_bits_retain(self.bits()))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value as a `EsrEl3DataAbortLower` if `EC` is 36, or `None` if it has another layout.
+    pub const fn as_data_abort_lower(self) -> Option<EsrEl3DataAbortLower> {
+        if Self::EC_FIELD.extract(self.bits()) == 36 {
+            Some(EsrEl3DataAbortLower::from_bits_retain(self.bits()))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value as a `EsrEl3DataAbort` if `EC` is 37, or `None` if it has another layout.
+    pub const fn as_data_abort(self) -> Option<EsrEl3DataAbort> {
+        if Self::EC_FIELD.extract(self.bits()) == 37 {
+            Some(EsrEl3DataAbort::from_bits_retain(self.bits()))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(feature = "el3")]
+impl From<EsrEl3Wfx> for EsrEl3 {
+    fn from(value: EsrEl3Wfx) -> Self {
+        Self::from_bits_retain(value.bits())
+    }
+}
+
+#[cfg(feature = "el3")]
+impl From<EsrEl3Svc> for EsrEl3 {
+    fn from(value: EsrEl3Svc) -> Self {
+        Self::from_bits_retain(value.bits())
+    }
+}
+
+#[cfg(feature = "el3")]
+impl From<EsrEl3Hvc> for EsrEl3 {
+    fn from(value: EsrEl3Hvc) -> Self {
+        Self::from_bits_retain(value.bits())
+    }
+}
+
+#[cfg(feature = "el3")]
+impl From<EsrEl3Smc> for EsrEl3 {
+    fn from(value: EsrEl3Smc) -> Self {
+        Self::from_bits_retain(value.bits())
+    }
+}
+
+#[cfg(feature = "el3")]
+impl From<EsrEl3Sysreg> for EsrEl3 {
+    fn from(value: EsrEl3Sysreg) -> Self {
+        Self::from_bits_retain(value.bits())
+    }
+}
+
+#[cfg(feature = "el3")]
+impl From<EsrEl3InstructionAbortLower> for EsrEl3 {
+    fn from(value: EsrEl3InstructionAbortLower) -> Self {
+        Self::from_bits_retain(value.bits())
+    }
+}
+
+#[cfg(feature = "el3")]
+impl From<EsrEl3InstructionAbort> for EsrEl3 {
+    fn from(value: EsrEl3InstructionAbort) -> Self {
+        Self::from_bits_retain(value.bits())
+    }
+}
+
+#[cfg(feature = "el3")]
+impl From<EsrEl3DataAbortLower> for EsrEl3 {
+    fn from(value: EsrEl3DataAbortLower) -> Self {
+        Self::from_bits_retain(value.bits())
+    }
+}
+
+#[cfg(feature = "el3")]
+impl From<EsrEl3DataAbort> for EsrEl3 {
+    fn from(value: EsrEl3DataAbort) -> Self {
+        Self::from_bits_retain(value.bits())
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `FAR_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct FarEl1: u64 {
+    }
+}
+
+#[cfg(feature = "el1")]
+impl FarEl1 {
+    /// Descriptor of the `VA` field, for use with [`FieldAccess`].
+    pub const VA_FIELD: Field<Self, u64, 0, 64> = Field::new("FAR_EL1", "VA");
+
+    /// Returns the value of the `VA` field.
+    pub const fn va(self) -> u64 {
+        Self::VA_FIELD.extract(self.bits())
+    }
+
+    /// Sets the value of the `VA` field.
+    pub const fn set_va(&mut self, value: u64) {
+        *self = Self::from_bits_retain(Self::VA_FIELD.insert(self.bits(), value));
+    }
+
+    /// Returns a copy with the `VA` field set to the given value.
+    pub const fn with_va(mut self, value: u64) -> Self {
+        self.set_va(value);
+        self
+    }
+
+    /// Sets the value of the `VA` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_va(&mut self, value: u64) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_va(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `VA` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_va(self, value: u64) -> Result<Self, FieldOverflow> {
+        match Self::VA_FIELD.try_insert(self.bits(), value) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for FarEl1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("FarEl1");
+        let bits = self.bits();
+        s.field("VA", &format_args!("{:#x}", Self::VA_FIELD.extract(bits)));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for FarEl1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "FarEl1 {{ VA: {=u64:#x} }}",
+            Self::VA_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for FarEl1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for FarEl1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl FarEl1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [FieldChange {
+            register,
+            field: Some("VA"),
+            old: Self::VA_FIELD.extract(self.bits()),
+            new: Self::VA_FIELD.extract(other.bits()),
+        }]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl FarEl1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl FarEl1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `FAR_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct FarEl2: u64 {
+    }
+}
+
+#[cfg(feature = "el2")]
+impl FarEl2 {
+    /// Descriptor of the `VA` field, for use with [`FieldAccess`].
+    pub const VA_FIELD: Field<Self, u64, 0, 64> = Field::new("FAR_EL2", "VA");
+
+    /// Returns the value of the `VA` field.
+    pub const fn va(self) -> u64 {
+        Self::VA_FIELD.extract(self.bits())
+    }
+
+    /// Sets the value of the `VA` field.
+    pub const fn set_va(&mut self, value: u64) {
+        *self = Self::from_bits_retain(Self::VA_FIELD.insert(self.bits(), value));
+    }
+
+    /// Returns a copy with the `VA` field set to the given value.
+    pub const fn with_va(mut self, value: u64) -> Self {
+        self.set_va(value);
+        self
+    }
+
+    /// Sets the value of the `VA` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_va(&mut self, value: u64) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_va(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `VA` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_va(self, value: u64) -> Result<Self, FieldOverflow> {
+        match Self::VA_FIELD.try_insert(self.bits(), value) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Display for FarEl2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("FarEl2");
+        let bits = self.bits();
+        s.field("VA", &format_args!("{:#x}", Self::VA_FIELD.extract(bits)));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for FarEl2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "FarEl2 {{ VA: {=u64:#x} }}",
+            Self::VA_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for FarEl2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for FarEl2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl FarEl2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [FieldChange {
+            register,
+            field: Some("VA"),
+            old: Self::VA_FIELD.extract(self.bits()),
+            new: Self::VA_FIELD.extract(other.bits()),
+        }]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl FarEl2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl FarEl2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `HPFAR_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct HpfarEl2: u64 {
+        /// `NS` bit.
+        const NS = 1 << 63;
+    }
+}
+
+#[cfg(feature = "el2")]
+impl HpfarEl2 {
+    /// Descriptor of the `FIPA` field, for use with [`FieldAccess`].
+    pub const FIPA_FIELD: Field<Self, u64, 4, 44> = Field::new("HPFAR_EL2", "FIPA");
+    /// Descriptor of the `NS` field, for use with [`FieldAccess`].
+    pub const NS_FIELD: Field<Self, bool, 63, 1> = Field::new("HPFAR_EL2", "NS");
+
+    /// Returns the value of the `FIPA` field.
+    pub const fn fipa(self) -> u64 {
+        Self::FIPA_FIELD.extract(self.bits())
+    }
+
+    /// Sets the value of the `FIPA` field.
+    pub const fn set_fipa(&mut self, value: u64) {
+        *self = Self::from_bits_retain(Self::FIPA_FIELD.insert(self.bits(), value));
+    }
+
+    /// Returns a copy with the `FIPA` field set to the given value.
+    pub const fn with_fipa(mut self, value: u64) -> Self {
+        self.set_fipa(value);
+        self
+    }
+
+    /// Sets the value of the `FIPA` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_fipa(&mut self, value: u64) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_fipa(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `FIPA` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_fipa(self, value: u64) -> Result<Self, FieldOverflow> {
+        match Self::FIPA_FIELD.try_insert(self.bits(), value) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Display for HpfarEl2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("HpfarEl2");
+        let bits = self.bits();
+        s.field(
+            "FIPA",
+            &format_args!("{:#x}", Self::FIPA_FIELD.extract(bits)),
+        );
+        s.field("NS", &Self::NS_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for HpfarEl2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "HpfarEl2 {{ FIPA: {=u64:#x}, NS: {=u64} }}",
+            Self::FIPA_FIELD.extract(self.bits()),
+            Self::NS_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for HpfarEl2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for HpfarEl2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl HpfarEl2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("FIPA"),
+                old: Self::FIPA_FIELD.extract(self.bits()),
+                new: Self::FIPA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("NS"),
+                old: Self::NS_FIELD.extract(self.bits()),
+                new: Self::NS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b111_1111_1111_1111_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_1111,
+                new: other.bits() & 0b111_1111_1111_1111_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_1111,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl HpfarEl2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl HpfarEl2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `ISR_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct IsrEl1: u64 {
+        /// `F` bit.
+        const F = 1 << 6;
+        /// `I` bit.
+        const I = 1 << 7;
+        /// `A` bit.
+        const A = 1 << 8;
+        /// `FS` bit.
+        const FS = 1 << 9;
+        /// `IS` bit.
+        const IS = 1 << 10;
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IsrEl1 {
+    /// Descriptor of the `F` field, for use with [`FieldAccess`].
+    pub const F_FIELD: Field<Self, bool, 6, 1> = Field::new("ISR_EL1", "F");
+    /// Descriptor of the `I` field, for use with [`FieldAccess`].
+    pub const I_FIELD: Field<Self, bool, 7, 1> = Field::new("ISR_EL1", "I");
+    /// Descriptor of the `A` field, for use with [`FieldAccess`].
+    pub const A_FIELD: Field<Self, bool, 8, 1> = Field::new("ISR_EL1", "A");
+    /// Descriptor of the `FS` field, for use with [`FieldAccess`].
+    pub const FS_FIELD: Field<Self, bool, 9, 1> = Field::new("ISR_EL1", "FS");
+    /// Descriptor of the `IS` field, for use with [`FieldAccess`].
+    pub const IS_FIELD: Field<Self, bool, 10, 1> = Field::new("ISR_EL1", "IS");
+}
+
+#[cfg(feature = "el1")]
+impl Display for IsrEl1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("IsrEl1");
+        let bits = self.bits();
+        s.field("F", &Self::F_FIELD.extract(bits));
+        s.field("I", &Self::I_FIELD.extract(bits));
+        s.field("A", &Self::A_FIELD.extract(bits));
+        s.field("FS", &Self::FS_FIELD.extract(bits));
+        s.field("IS", &Self::IS_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for IsrEl1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "IsrEl1 {{ F: {=u64}, I: {=u64}, A: {=u64}, FS: {=u64}, IS: {=u64} }}",
+            Self::F_FIELD.extract(self.bits()),
+            Self::I_FIELD.extract(self.bits()),
+            Self::A_FIELD.extract(self.bits()),
+            Self::FS_FIELD.extract(self.bits()),
+            Self::IS_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for IsrEl1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for IsrEl1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IsrEl1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("F"),
+                old: Self::F_FIELD.extract(self.bits()),
+                new: Self::F_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("I"),
+                old: Self::I_FIELD.extract(self.bits()),
+                new: Self::I_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("A"),
+                old: Self::A_FIELD.extract(self.bits()),
+                new: Self::A_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FS"),
+                old: Self::FS_FIELD.extract(self.bits()),
+                new: Self::FS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IS"),
+                old: Self::IS_FIELD.extract(self.bits()),
+                new: Self::IS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1000_0011_1111,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1000_0011_1111,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IsrEl1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IsrEl1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `PFAR_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct PfarEl1: u64 {
+        /// `NSE` bit.
+        const NSE = 1 << 62;
+        /// `NS` bit.
+        const NS = 1 << 63;
+    }
+}
+
+#[cfg(feature = "el1")]
+impl PfarEl1 {
+    /// Descriptor of the `PA` field, for use with [`FieldAccess`].
+    pub const PA_FIELD: Field<Self, u64, 0, 48> = Field::new("PFAR_EL1", "PA");
+    /// Descriptor of the `PA[51:48]` field, for use with [`FieldAccess`].
+    pub const PA_51_48_FIELD: Field<Self, u8, 48, 4> = Field::new("PFAR_EL1", "PA[51:48]");
+    /// Descriptor of the `PA[55:52]` field, for use with [`FieldAccess`].
+    pub const PA_55_52_FIELD: Field<Self, u8, 52, 4> = Field::new("PFAR_EL1", "PA[55:52]");
+    /// Descriptor of the `NSE` field, for use with [`FieldAccess`].
+    pub const NSE_FIELD: Field<Self, bool, 62, 1> = Field::new("PFAR_EL1", "NSE");
+    /// Descriptor of the `NS` field, for use with [`FieldAccess`].
+    pub const NS_FIELD: Field<Self, bool, 63, 1> = Field::new("PFAR_EL1", "NS");
+
+    /// Returns the value of the `PA` field.
+    pub const fn pa(self) -> u64 {
+        Self::PA_FIELD.extract(self.bits())
+    }
+
+    /// Sets the value of the `PA` field.
+    pub const fn set_pa(&mut self, value: u64) {
+        *self = Self::from_bits_retain(Self::PA_FIELD.insert(self.bits(), value));
+    }
+
+    /// Returns a copy with the `PA` field set to the given value.
+    pub const fn with_pa(mut self, value: u64) -> Self {
+        self.set_pa(value);
+        self
+    }
+
+    /// Sets the value of the `PA` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_pa(&mut self, value: u64) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_pa(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PA` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_pa(self, value: u64) -> Result<Self, FieldOverflow> {
+        match Self::PA_FIELD.try_insert(self.bits(), value) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PA[51:48]` field.
+    pub const fn pa_51_48(self) -> u8 {
+        Self::PA_51_48_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `PA[51:48]` field.
+    pub const fn set_pa_51_48(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::PA_51_48_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PA[51:48]` field set to the given value.
+    pub const fn with_pa_51_48(mut self, value: u8) -> Self {
+        self.set_pa_51_48(value);
+        self
+    }
+
+    /// Sets the value of the `PA[51:48]` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_pa_51_48(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_pa_51_48(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PA[51:48]` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_pa_51_48(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::PA_51_48_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PA[55:52]` field.
+    pub const fn pa_55_52(self) -> u8 {
+        Self::PA_55_52_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `PA[55:52]` field.
+    pub const fn set_pa_55_52(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::PA_55_52_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PA[55:52]` field set to the given value.
+    pub const fn with_pa_55_52(mut self, value: u8) -> Self {
+        self.set_pa_55_52(value);
+        self
+    }
+
+    /// Sets the value of the `PA[55:52]` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_pa_55_52(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_pa_55_52(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PA[55:52]` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_pa_55_52(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::PA_55_52_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for PfarEl1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("PfarEl1");
+        let bits = self.bits();
+        s.field("PA", &format_args!("{:#x}", Self::PA_FIELD.extract(bits)));
+        s.field("PA[51:48]", &Self::PA_51_48_FIELD.extract(bits));
+        s.field("PA[55:52]", &Self::PA_55_52_FIELD.extract(bits));
+        s.field("NSE", &Self::NSE_FIELD.extract(bits));
+        s.field("NS", &Self::NS_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for PfarEl1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "PfarEl1 {{ PA: {=u64:#x}, PA[51:48]: {=u64}, PA[55:52]: {=u64}, NSE: {=u64}, NS: {=u64} }}",
+            Self::PA_FIELD.extract(self.bits()),
+            Self::PA_51_48_FIELD.extract(self.bits()),
+            Self::PA_55_52_FIELD.extract(self.bits()),
+            Self::NSE_FIELD.extract(self.bits()),
+            Self::NS_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for PfarEl1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for PfarEl1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl PfarEl1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("PA"),
+                old: Self::PA_FIELD.extract(self.bits()),
+                new: Self::PA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PA[51:48]"),
+                old: Self::PA_51_48_FIELD.extract(self.bits()),
+                new: Self::PA_51_48_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PA[55:52]"),
+                old: Self::PA_55_52_FIELD.extract(self.bits()),
+                new: Self::PA_55_52_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("NSE"),
+                old: Self::NSE_FIELD.extract(self.bits()),
+                new: Self::NSE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("NS"),
+                old: Self::NS_FIELD.extract(self.bits()),
+                new: Self::NS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b11_1111_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000,
+                new: other.bits() & 0b11_1111_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl PfarEl1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl PfarEl1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                Self::PA_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Pfar),
+                UnimplementedField {
+                    register: "PFAR_EL1",
+                    field: "PA",
+                    requires: "FEAT_PFAR",
+                },
+            ),
+            (
+                Self::PA_51_48_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Pfar),
+                UnimplementedField {
+                    register: "PFAR_EL1",
+                    field: "PA[51:48]",
+                    requires: "FEAT_PFAR",
+                },
+            ),
+            (
+                Self::PA_55_52_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Pfar),
+                UnimplementedField {
+                    register: "PFAR_EL1",
+                    field: "PA[55:52]",
+                    requires: "FEAT_PFAR",
+                },
+            ),
+            (
+                Self::NSE_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Pfar),
+                UnimplementedField {
+                    register: "PFAR_EL1",
+                    field: "NSE",
+                    requires: "FEAT_PFAR",
+                },
+            ),
+            (
+                Self::NS_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Pfar),
+                UnimplementedField {
+                    register: "PFAR_EL1",
+                    field: "NS",
+                    requires: "FEAT_PFAR",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `PFAR_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct PfarEl2: u64 {
+        /// `NSE2` bit.
+        const NSE2 = 1 << 61;
+        /// `NSE` bit.
+        const NSE = 1 << 62;
+        /// `NS` bit.
+        const NS = 1 << 63;
+    }
+}
+
+#[cfg(feature = "el2")]
+impl PfarEl2 {
+    /// Descriptor of the `PA` field, for use with [`FieldAccess`].
+    pub const PA_FIELD: Field<Self, u64, 0, 48> = Field::new("PFAR_EL2", "PA");
+    /// Descriptor of the `PA[51:48]` field, for use with [`FieldAccess`].
+    pub const PA_51_48_FIELD: Field<Self, u8, 48, 4> = Field::new("PFAR_EL2", "PA[51:48]");
+    /// Descriptor of the `PA[55:52]` field, for use with [`FieldAccess`].
+    pub const PA_55_52_FIELD: Field<Self, u8, 52, 4> = Field::new("PFAR_EL2", "PA[55:52]");
+    /// Descriptor of the `NSE2` field, for use with [`FieldAccess`].
+    pub const NSE2_FIELD: Field<Self, bool, 61, 1> = Field::new("PFAR_EL2", "NSE2");
+    /// Descriptor of the `NSE` field, for use with [`FieldAccess`].
+    pub const NSE_FIELD: Field<Self, bool, 62, 1> = Field::new("PFAR_EL2", "NSE");
+    /// Descriptor of the `NS` field, for use with [`FieldAccess`].
+    pub const NS_FIELD: Field<Self, bool, 63, 1> = Field::new("PFAR_EL2", "NS");
+
+    /// Returns the value of the `PA` field.
+    pub const fn pa(self) -> u64 {
+        Self::PA_FIELD.extract(self.bits())
+    }
+
+    /// Sets the value of the `PA` field.
+    pub const fn set_pa(&mut self, value: u64) {
+        *self = Self::from_bits_retain(Self::PA_FIELD.insert(self.bits(), value));
+    }
+
+    /// Returns a copy with the `PA` field set to the given value.
+    pub const fn with_pa(mut self, value: u64) -> Self {
+        self.set_pa(value);
+        self
+    }
+
+    /// Sets the value of the `PA` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_pa(&mut self, value: u64) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_pa(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PA` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_pa(self, value: u64) -> Result<Self, FieldOverflow> {
+        match Self::PA_FIELD.try_insert(self.bits(), value) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PA[51:48]` field.
+    pub const fn pa_51_48(self) -> u8 {
+        Self::PA_51_48_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `PA[51:48]` field.
+    pub const fn set_pa_51_48(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::PA_51_48_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PA[51:48]` field set to the given value.
+    pub const fn with_pa_51_48(mut self, value: u8) -> Self {
+        self.set_pa_51_48(value);
+        self
+    }
+
+    /// Sets the value of the `PA[51:48]` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_pa_51_48(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_pa_51_48(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PA[51:48]` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_pa_51_48(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::PA_51_48_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PA[55:52]` field.
+    pub const fn pa_55_52(self) -> u8 {
+        Self::PA_55_52_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `PA[55:52]` field.
+    pub const fn set_pa_55_52(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::PA_55_52_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PA[55:52]` field set to the given value.
+    pub const fn with_pa_55_52(mut self, value: u8) -> Self {
+        self.set_pa_55_52(value);
+        self
+    }
+
+    /// Sets the value of the `PA[55:52]` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_pa_55_52(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_pa_55_52(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PA[55:52]` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_pa_55_52(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::PA_55_52_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Display for PfarEl2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("PfarEl2");
+        let bits = self.bits();
+        s.field("PA", &format_args!("{:#x}", Self::PA_FIELD.extract(bits)));
+        s.field("PA[51:48]", &Self::PA_51_48_FIELD.extract(bits));
+        s.field("PA[55:52]", &Self::PA_55_52_FIELD.extract(bits));
+        s.field("NSE2", &Self::NSE2_FIELD.extract(bits));
+        s.field("NSE", &Self::NSE_FIELD.extract(bits));
+        s.field("NS", &Self::NS_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for PfarEl2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "PfarEl2 {{ PA: {=u64:#x}, PA[51:48]: {=u64}, PA[55:52]: {=u64}, NSE2: {=u64}, NSE: {=u64}, NS: {=u64} }}",
+            Self::PA_FIELD.extract(self.bits()),
+            Self::PA_51_48_FIELD.extract(self.bits()),
+            Self::PA_55_52_FIELD.extract(self.bits()),
+            Self::NSE2_FIELD.extract(self.bits()),
+            Self::NSE_FIELD.extract(self.bits()),
+            Self::NS_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for PfarEl2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for PfarEl2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl PfarEl2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("PA"),
+                old: Self::PA_FIELD.extract(self.bits()),
+                new: Self::PA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PA[51:48]"),
+                old: Self::PA_51_48_FIELD.extract(self.bits()),
+                new: Self::PA_51_48_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PA[55:52]"),
+                old: Self::PA_55_52_FIELD.extract(self.bits()),
+                new: Self::PA_55_52_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("NSE2"),
+                old: Self::NSE2_FIELD.extract(self.bits()),
+                new: Self::NSE2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("NSE"),
+                old: Self::NSE_FIELD.extract(self.bits()),
+                new: Self::NSE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("NS"),
+                old: Self::NS_FIELD.extract(self.bits()),
+                new: Self::NS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1_1111_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000,
+                new: other.bits() & 0b1_1111_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl PfarEl2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl PfarEl2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                Self::PA_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Pfar),
+                UnimplementedField {
+                    register: "PFAR_EL2",
+                    field: "PA",
+                    requires: "FEAT_PFAR",
+                },
+            ),
+            (
+                Self::PA_51_48_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Pfar),
+                UnimplementedField {
+                    register: "PFAR_EL2",
+                    field: "PA[51:48]",
+                    requires: "FEAT_PFAR",
+                },
+            ),
+            (
+                Self::PA_55_52_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Pfar),
+                UnimplementedField {
+                    register: "PFAR_EL2",
+                    field: "PA[55:52]",
+                    requires: "FEAT_PFAR",
+                },
+            ),
+            (
+                Self::NSE2_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Pfar),
+                UnimplementedField {
+                    register: "PFAR_EL2",
+                    field: "NSE2",
+                    requires: "FEAT_PFAR",
+                },
+            ),
+            (
+                Self::NSE_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Pfar),
+                UnimplementedField {
+                    register: "PFAR_EL2",
+                    field: "NSE",
+                    requires: "FEAT_PFAR",
+                },
+            ),
+            (
+                Self::NS_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Pfar),
+                UnimplementedField {
+                    register: "PFAR_EL2",
+                    field: "NS",
+                    requires: "FEAT_PFAR",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `SPSR_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct SpsrEl1: u64 {
+        /// `M[4]` bit.
+        const M_4 = 1 << 4;
+        /// `T` bit.
+        const T = 1 << 5;
+        /// `F` bit.
+        const F = 1 << 6;
+        /// `I` bit.
+        const I = 1 << 7;
+        /// `A` bit.
+        const A = 1 << 8;
+        /// `D` bit.
+        const D = 1 << 9;
+        /// `E` bit.
+        const E = 1 << 9;
+        /// `ALLINT` bit.
+        const ALLINT = 1 << 13;
+        /// `BTYPE2` bit.
+        const BTYPE2 = 1 << 14;
+        /// `IL` bit.
+        const IL = 1 << 20;
+        /// `SS` bit.
+        const SS = 1 << 21;
+        /// `PAN` bit.
+        const PAN = 1 << 22;
+        /// `UAO` bit.
+        const UAO = 1 << 23;
+        /// `DIT` bit.
+        const DIT = 1 << 24;
+        /// `TCO` bit.
+        const TCO = 1 << 25;
+        /// `Q` bit.
+        const Q = 1 << 27;
+        /// `V` bit.
+        const V = 1 << 28;
+        /// `C` bit.
+        const C = 1 << 29;
+        /// `Z` bit.
+        const Z = 1 << 30;
+        /// `N` bit.
+        const N = 1 << 31;
+        /// `PM` bit.
+        const PM = 1 << 32;
+        /// `EXLOCK` bit.
+        const EXLOCK = 1 << 34;
+        /// `PACM` bit.
+        const PACM = 1 << 35;
+        /// `UINJ` bit.
+        const UINJ = 1 << 36;
+    }
+}
+
+#[cfg(feature = "el1")]
+impl SpsrEl1 {
+    /// Descriptor of the `M[3:0]` field, for use with [`FieldAccess`].
+    pub const M_3_0_FIELD: Field<Self, u8, 0, 4> = Field::new("SPSR_EL1", "M[3:0]");
+    /// Descriptor of the `M[4]` field, for use with [`FieldAccess`].
+    pub const M_4_FIELD: Field<Self, bool, 4, 1> = Field::new("SPSR_EL1", "M[4]");
+    /// Descriptor of the `T` field, for use with [`FieldAccess`].
+    pub const T_FIELD: Field<Self, bool, 5, 1> = Field::new("SPSR_EL1", "T");
+    /// Descriptor of the `F` field, for use with [`FieldAccess`].
+    pub const F_FIELD: Field<Self, bool, 6, 1> = Field::new("SPSR_EL1", "F");
+    /// Descriptor of the `I` field, for use with [`FieldAccess`].
+    pub const I_FIELD: Field<Self, bool, 7, 1> = Field::new("SPSR_EL1", "I");
+    /// Descriptor of the `A` field, for use with [`FieldAccess`].
+    pub const A_FIELD: Field<Self, bool, 8, 1> = Field::new("SPSR_EL1", "A");
+    /// Descriptor of the `D` field, for use with [`FieldAccess`].
+    pub const D_FIELD: Field<Self, bool, 9, 1> = Field::new("SPSR_EL1", "D");
+    /// Descriptor of the `E` field, for use with [`FieldAccess`].
+    pub const E_FIELD: Field<Self, bool, 9, 1> = Field::new("SPSR_EL1", "E");
+    /// Descriptor of the `BTYPE` field, for use with [`FieldAccess`].
+    pub const BTYPE_FIELD: Field<Self, u8, 10, 2> = Field::new("SPSR_EL1", "BTYPE");
+    /// Descriptor of the `ALLINT` field, for use with [`FieldAccess`].
+    pub const ALLINT_FIELD: Field<Self, bool, 13, 1> = Field::new("SPSR_EL1", "ALLINT");
+    /// Descriptor of the `BTYPE2` field, for use with [`FieldAccess`].
+    pub const BTYPE2_FIELD: Field<Self, bool, 14, 1> = Field::new("SPSR_EL1", "BTYPE2");
+    /// Descriptor of the `GE` field, for use with [`FieldAccess`].
+    pub const GE_FIELD: Field<Self, u8, 16, 4> = Field::new("SPSR_EL1", "GE");
+    /// Descriptor of the `IL` field, for use with [`FieldAccess`].
+    pub const IL_FIELD: Field<Self, bool, 20, 1> = Field::new("SPSR_EL1", "IL");
+    /// Descriptor of the `SS` field, for use with [`FieldAccess`].
+    pub const SS_FIELD: Field<Self, bool, 21, 1> = Field::new("SPSR_EL1", "SS");
+    /// Descriptor of the `PAN` field, for use with [`FieldAccess`].
+    pub const PAN_FIELD: Field<Self, bool, 22, 1> = Field::new("SPSR_EL1", "PAN");
+    /// Descriptor of the `UAO` field, for use with [`FieldAccess`].
+    pub const UAO_FIELD: Field<Self, bool, 23, 1> = Field::new("SPSR_EL1", "UAO");
+    /// Descriptor of the `DIT` field, for use with [`FieldAccess`].
+    pub const DIT_FIELD: Field<Self, bool, 24, 1> = Field::new("SPSR_EL1", "DIT");
+    /// Descriptor of the `TCO` field, for use with [`FieldAccess`].
+    pub const TCO_FIELD: Field<Self, bool, 25, 1> = Field::new("SPSR_EL1", "TCO");
+    /// Descriptor of the `Q` field, for use with [`FieldAccess`].
+    pub const Q_FIELD: Field<Self, bool, 27, 1> = Field::new("SPSR_EL1", "Q");
+    /// Descriptor of the `V` field, for use with [`FieldAccess`].
+    pub const V_FIELD: Field<Self, bool, 28, 1> = Field::new("SPSR_EL1", "V");
+    /// Descriptor of the `C` field, for use with [`FieldAccess`].
+    pub const C_FIELD: Field<Self, bool, 29, 1> = Field::new("SPSR_EL1", "C");
+    /// Descriptor of the `Z` field, for use with [`FieldAccess`].
+    pub const Z_FIELD: Field<Self, bool, 30, 1> = Field::new("SPSR_EL1", "Z");
+    /// Descriptor of the `N` field, for use with [`FieldAccess`].
+    pub const N_FIELD: Field<Self, bool, 31, 1> = Field::new("SPSR_EL1", "N");
+    /// Descriptor of the `PM` field, for use with [`FieldAccess`].
+    pub const PM_FIELD: Field<Self, bool, 32, 1> = Field::new("SPSR_EL1", "PM");
+    /// Descriptor of the `EXLOCK` field, for use with [`FieldAccess`].
+    pub const EXLOCK_FIELD: Field<Self, bool, 34, 1> = Field::new("SPSR_EL1", "EXLOCK");
+    /// Descriptor of the `PACM` field, for use with [`FieldAccess`].
+    pub const PACM_FIELD: Field<Self, bool, 35, 1> = Field::new("SPSR_EL1", "PACM");
+    /// Descriptor of the `UINJ` field, for use with [`FieldAccess`].
+    pub const UINJ_FIELD: Field<Self, bool, 36, 1> = Field::new("SPSR_EL1", "UINJ");
+
+    /// Returns the value of the `M[3:0]` field.
+    pub const fn m_3_0(self) -> u8 {
+        Self::M_3_0_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `M[3:0]` field.
+    pub const fn set_m_3_0(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::M_3_0_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `M[3:0]` field set to the given value.
+    pub const fn with_m_3_0(mut self, value: u8) -> Self {
+        self.set_m_3_0(value);
+        self
+    }
+
+    /// Sets the value of the `M[3:0]` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_m_3_0(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_m_3_0(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `M[3:0]` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_m_3_0(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::M_3_0_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `BTYPE` field.
+    pub const fn btype(self) -> u8 {
+        Self::BTYPE_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `BTYPE` field.
+    pub const fn set_btype(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::BTYPE_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `BTYPE` field set to the given value.
+    pub const fn with_btype(mut self, value: u8) -> Self {
+        self.set_btype(value);
+        self
+    }
+
+    /// Sets the value of the `BTYPE` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_btype(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_btype(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `BTYPE` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_btype(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::BTYPE_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `GE` field.
+    pub const fn ge(self) -> u8 {
+        Self::GE_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `GE` field.
+    pub const fn set_ge(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::GE_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `GE` field set to the given value.
+    pub const fn with_ge(mut self, value: u8) -> Self {
+        self.set_ge(value);
+        self
+    }
+
+    /// Sets the value of the `GE` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ge(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ge(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `GE` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ge(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::GE_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for SpsrEl1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("SpsrEl1");
+        let bits = self.bits();
+        s.field("M[3:0]", &Self::M_3_0_FIELD.extract(bits));
+        s.field("M[4]", &Self::M_4_FIELD.extract(bits));
+        s.field("T", &Self::T_FIELD.extract(bits));
+        s.field("F", &Self::F_FIELD.extract(bits));
+        s.field("I", &Self::I_FIELD.extract(bits));
+        s.field("A", &Self::A_FIELD.extract(bits));
+        s.field("D", &Self::D_FIELD.extract(bits));
+        s.field("E", &Self::E_FIELD.extract(bits));
+        s.field("BTYPE", &Self::BTYPE_FIELD.extract(bits));
+        s.field("ALLINT", &Self::ALLINT_FIELD.extract(bits));
+        s.field("BTYPE2", &Self::BTYPE2_FIELD.extract(bits));
+        s.field("GE", &Self::GE_FIELD.extract(bits));
+        s.field("IL", &Self::IL_FIELD.extract(bits));
+        s.field("SS", &Self::SS_FIELD.extract(bits));
+        s.field("PAN", &Self::PAN_FIELD.extract(bits));
+        s.field("UAO", &Self::UAO_FIELD.extract(bits));
+        s.field("DIT", &Self::DIT_FIELD.extract(bits));
+        s.field("TCO", &Self::TCO_FIELD.extract(bits));
+        s.field("Q", &Self::Q_FIELD.extract(bits));
+        s.field("V", &Self::V_FIELD.extract(bits));
+        s.field("C", &Self::C_FIELD.extract(bits));
+        s.field("Z", &Self::Z_FIELD.extract(bits));
+        s.field("N", &Self::N_FIELD.extract(bits));
+        s.field("PM", &Self::PM_FIELD.extract(bits));
+        s.field("EXLOCK", &Self::EXLOCK_FIELD.extract(bits));
+        s.field("PACM", &Self::PACM_FIELD.extract(bits));
+        s.field("UINJ", &Self::UINJ_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for SpsrEl1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "SpsrEl1 {{ M[3:0]: {=u64}, M[4]: {=u64}, T: {=u64}, F: {=u64}, I: {=u64}, A: {=u64}, D: {=u64}, E: {=u64}, BTYPE: {=u64}, ALLINT: {=u64}, BTYPE2: {=u64}, GE: {=u64}, IL: {=u64}, SS: {=u64}, PAN: {=u64}, UAO: {=u64}, DIT: {=u64}, TCO: {=u64}, Q: {=u64}, V: {=u64}, C: {=u64}, Z: {=u64}, N: {=u64}, PM: {=u64}, EXLOCK: {=u64}, PACM: {=u64}, UINJ: {=u64} }}",
+            Self::M_3_0_FIELD.extract(self.bits()),
+            Self::M_4_FIELD.extract(self.bits()),
+            Self::T_FIELD.extract(self.bits()),
+            Self::F_FIELD.extract(self.bits()),
+            Self::I_FIELD.extract(self.bits()),
+            Self::A_FIELD.extract(self.bits()),
+            Self::D_FIELD.extract(self.bits()),
+            Self::E_FIELD.extract(self.bits()),
+            Self::BTYPE_FIELD.extract(self.bits()),
+            Self::ALLINT_FIELD.extract(self.bits()),
+            Self::BTYPE2_FIELD.extract(self.bits()),
+            Self::GE_FIELD.extract(self.bits()),
+            Self::IL_FIELD.extract(self.bits()),
+            Self::SS_FIELD.extract(self.bits()),
+            Self::PAN_FIELD.extract(self.bits()),
+            Self::UAO_FIELD.extract(self.bits()),
+            Self::DIT_FIELD.extract(self.bits()),
+            Self::TCO_FIELD.extract(self.bits()),
+            Self::Q_FIELD.extract(self.bits()),
+            Self::V_FIELD.extract(self.bits()),
+            Self::C_FIELD.extract(self.bits()),
+            Self::Z_FIELD.extract(self.bits()),
+            Self::N_FIELD.extract(self.bits()),
+            Self::PM_FIELD.extract(self.bits()),
+            Self::EXLOCK_FIELD.extract(self.bits()),
+            Self::PACM_FIELD.extract(self.bits()),
+            Self::UINJ_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for SpsrEl1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for SpsrEl1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl SpsrEl1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("M[3:0]"),
+                old: Self::M_3_0_FIELD.extract(self.bits()),
+                new: Self::M_3_0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("M[4]"),
+                old: Self::M_4_FIELD.extract(self.bits()),
+                new: Self::M_4_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("T"),
+                old: Self::T_FIELD.extract(self.bits()),
+                new: Self::T_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("F"),
+                old: Self::F_FIELD.extract(self.bits()),
+                new: Self::F_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("I"),
+                old: Self::I_FIELD.extract(self.bits()),
+                new: Self::I_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("A"),
+                old: Self::A_FIELD.extract(self.bits()),
+                new: Self::A_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("D"),
+                old: Self::D_FIELD.extract(self.bits()),
+                new: Self::D_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("E"),
+                old: Self::E_FIELD.extract(self.bits()),
+                new: Self::E_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("BTYPE"),
+                old: Self::BTYPE_FIELD.extract(self.bits()),
+                new: Self::BTYPE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ALLINT"),
+                old: Self::ALLINT_FIELD.extract(self.bits()),
+                new: Self::ALLINT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("BTYPE2"),
+                old: Self::BTYPE2_FIELD.extract(self.bits()),
+                new: Self::BTYPE2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("GE"),
+                old: Self::GE_FIELD.extract(self.bits()),
+                new: Self::GE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IL"),
+                old: Self::IL_FIELD.extract(self.bits()),
+                new: Self::IL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SS"),
+                old: Self::SS_FIELD.extract(self.bits()),
+                new: Self::SS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PAN"),
+                old: Self::PAN_FIELD.extract(self.bits()),
+                new: Self::PAN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("UAO"),
+                old: Self::UAO_FIELD.extract(self.bits()),
+                new: Self::UAO_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DIT"),
+                old: Self::DIT_FIELD.extract(self.bits()),
+                new: Self::DIT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TCO"),
+                old: Self::TCO_FIELD.extract(self.bits()),
+                new: Self::TCO_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("Q"),
+                old: Self::Q_FIELD.extract(self.bits()),
+                new: Self::Q_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("V"),
+                old: Self::V_FIELD.extract(self.bits()),
+                new: Self::V_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("C"),
+                old: Self::C_FIELD.extract(self.bits()),
+                new: Self::C_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("Z"),
+                old: Self::Z_FIELD.extract(self.bits()),
+                new: Self::Z_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("N"),
+                old: Self::N_FIELD.extract(self.bits()),
+                new: Self::N_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PM"),
+                old: Self::PM_FIELD.extract(self.bits()),
+                new: Self::PM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EXLOCK"),
+                old: Self::EXLOCK_FIELD.extract(self.bits()),
+                new: Self::EXLOCK_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PACM"),
+                old: Self::PACM_FIELD.extract(self.bits()),
+                new: Self::PACM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("UINJ"),
+                old: Self::UINJ_FIELD.extract(self.bits()),
+                new: Self::UINJ_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1110_0010_0000_0100_0000_0000_1001_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1110_0010_0000_0100_0000_0000_1001_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl SpsrEl1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl SpsrEl1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `SPSR_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct SpsrEl2: u64 {
+        /// `M[4]` bit.
+        const M_4 = 1 << 4;
+        /// `T` bit.
+        const T = 1 << 5;
+        /// `F` bit.
+        const F = 1 << 6;
+        /// `I` bit.
+        const I = 1 << 7;
+        /// `A` bit.
+        const A = 1 << 8;
+        /// `D` bit.
+        const D = 1 << 9;
+        /// `E` bit.
+        const E = 1 << 9;
+        /// `ALLINT` bit.
+        const ALLINT = 1 << 13;
+        /// `BTYPE2` bit.
+        const BTYPE2 = 1 << 14;
+        /// `IL` bit.
+        const IL = 1 << 20;
+        /// `SS` bit.
+        const SS = 1 << 21;
+        /// `PAN` bit.
+        const PAN = 1 << 22;
+        /// `UAO` bit.
+        const UAO = 1 << 23;
+        /// `DIT` bit.
+        const DIT = 1 << 24;
+        /// `TCO` bit.
+        const TCO = 1 << 25;
+        /// `Q` bit.
+        const Q = 1 << 27;
+        /// `V` bit.
+        const V = 1 << 28;
+        /// `C` bit.
+        const C = 1 << 29;
+        /// `Z` bit.
+        const Z = 1 << 30;
+        /// `N` bit.
+        const N = 1 << 31;
+        /// `PM` bit.
+        const PM = 1 << 32;
+        /// `EXLOCK` bit.
+        const EXLOCK = 1 << 34;
+        /// `PACM` bit.
+        const PACM = 1 << 35;
+        /// `UINJ` bit.
+        const UINJ = 1 << 36;
+    }
+}
+
+#[cfg(feature = "el2")]
+impl SpsrEl2 {
+    /// Descriptor of the `M[3:0]` field, for use with [`FieldAccess`].
+    pub const M_3_0_FIELD: Field<Self, u8, 0, 4> = Field::new("SPSR_EL2", "M[3:0]");
+    /// Descriptor of the `M[4]` field, for use with [`FieldAccess`].
+    pub const M_4_FIELD: Field<Self, bool, 4, 1> = Field::new("SPSR_EL2", "M[4]");
+    /// Descriptor of the `T` field, for use with [`FieldAccess`].
+    pub const T_FIELD: Field<Self, bool, 5, 1> = Field::new("SPSR_EL2", "T");
+    /// Descriptor of the `F` field, for use with [`FieldAccess`].
+    pub const F_FIELD: Field<Self, bool, 6, 1> = Field::new("SPSR_EL2", "F");
+    /// Descriptor of the `I` field, for use with [`FieldAccess`].
+    pub const I_FIELD: Field<Self, bool, 7, 1> = Field::new("SPSR_EL2", "I");
+    /// Descriptor of the `A` field, for use with [`FieldAccess`].
+    pub const A_FIELD: Field<Self, bool, 8, 1> = Field::new("SPSR_EL2", "A");
+    /// Descriptor of the `D` field, for use with [`FieldAccess`].
+    pub const D_FIELD: Field<Self, bool, 9, 1> = Field::new("SPSR_EL2", "D");
+    /// Descriptor of the `E` field, for use with [`FieldAccess`].
+    pub const E_FIELD: Field<Self, bool, 9, 1> = Field::new("SPSR_EL2", "E");
+    /// Descriptor of the `BTYPE` field, for use with [`FieldAccess`].
+    pub const BTYPE_FIELD: Field<Self, u8, 10, 2> = Field::new("SPSR_EL2", "BTYPE");
+    /// Descriptor of the `ALLINT` field, for use with [`FieldAccess`].
+    pub const ALLINT_FIELD: Field<Self, bool, 13, 1> = Field::new("SPSR_EL2", "ALLINT");
+    /// Descriptor of the `BTYPE2` field, for use with [`FieldAccess`].
+    pub const BTYPE2_FIELD: Field<Self, bool, 14, 1> = Field::new("SPSR_EL2", "BTYPE2");
+    /// Descriptor of the `GE` field, for use with [`FieldAccess`].
+    pub const GE_FIELD: Field<Self, u8, 16, 4> = Field::new("SPSR_EL2", "GE");
+    /// Descriptor of the `IL` field, for use with [`FieldAccess`].
+    pub const IL_FIELD: Field<Self, bool, 20, 1> = Field::new("SPSR_EL2", "IL");
+    /// Descriptor of the `SS` field, for use with [`FieldAccess`].
+    pub const SS_FIELD: Field<Self, bool, 21, 1> = Field::new("SPSR_EL2", "SS");
+    /// Descriptor of the `PAN` field, for use with [`FieldAccess`].
+    pub const PAN_FIELD: Field<Self, bool, 22, 1> = Field::new("SPSR_EL2", "PAN");
+    /// Descriptor of the `UAO` field, for use with [`FieldAccess`].
+    pub const UAO_FIELD: Field<Self, bool, 23, 1> = Field::new("SPSR_EL2", "UAO");
+    /// Descriptor of the `DIT` field, for use with [`FieldAccess`].
+    pub const DIT_FIELD: Field<Self, bool, 24, 1> = Field::new("SPSR_EL2", "DIT");
+    /// Descriptor of the `TCO` field, for use with [`FieldAccess`].
+    pub const TCO_FIELD: Field<Self, bool, 25, 1> = Field::new("SPSR_EL2", "TCO");
+    /// Descriptor of the `Q` field, for use with [`FieldAccess`].
+    pub const Q_FIELD: Field<Self, bool, 27, 1> = Field::new("SPSR_EL2", "Q");
+    /// Descriptor of the `V` field, for use with [`FieldAccess`].
+    pub const V_FIELD: Field<Self, bool, 28, 1> = Field::new("SPSR_EL2", "V");
+    /// Descriptor of the `C` field, for use with [`FieldAccess`].
+    pub const C_FIELD: Field<Self, bool, 29, 1> = Field::new("SPSR_EL2", "C");
+    /// Descriptor of the `Z` field, for use with [`FieldAccess`].
+    pub const Z_FIELD: Field<Self, bool, 30, 1> = Field::new("SPSR_EL2", "Z");
+    /// Descriptor of the `N` field, for use with [`FieldAccess`].
+    pub const N_FIELD: Field<Self, bool, 31, 1> = Field::new("SPSR_EL2", "N");
+    /// Descriptor of the `PM` field, for use with [`FieldAccess`].
+    pub const PM_FIELD: Field<Self, bool, 32, 1> = Field::new("SPSR_EL2", "PM");
+    /// Descriptor of the `EXLOCK` field, for use with [`FieldAccess`].
+    pub const EXLOCK_FIELD: Field<Self, bool, 34, 1> = Field::new("SPSR_EL2", "EXLOCK");
+    /// Descriptor of the `PACM` field, for use with [`FieldAccess`].
+    pub const PACM_FIELD: Field<Self, bool, 35, 1> = Field::new("SPSR_EL2", "PACM");
+    /// Descriptor of the `UINJ` field, for use with [`FieldAccess`].
+    pub const UINJ_FIELD: Field<Self, bool, 36, 1> = Field::new("SPSR_EL2", "UINJ");
+
+    /// Returns the value of the `M[3:0]` field.
+    pub const fn m_3_0(self) -> u8 {
+        Self::M_3_0_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `M[3:0]` field.
+    pub const fn set_m_3_0(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::M_3_0_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `M[3:0]` field set to the given value.
+    pub const fn with_m_3_0(mut self, value: u8) -> Self {
+        self.set_m_3_0(value);
+        self
+    }
+
+    /// Sets the value of the `M[3:0]` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_m_3_0(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_m_3_0(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `M[3:0]` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_m_3_0(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::M_3_0_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `BTYPE` field.
+    pub const fn btype(self) -> u8 {
+        Self::BTYPE_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `BTYPE` field.
+    pub const fn set_btype(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::BTYPE_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `BTYPE` field set to the given value.
+    pub const fn with_btype(mut self, value: u8) -> Self {
+        self.set_btype(value);
+        self
+    }
+
+    /// Sets the value of the `BTYPE` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_btype(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_btype(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `BTYPE` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_btype(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::BTYPE_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `GE` field.
+    pub const fn ge(self) -> u8 {
+        Self::GE_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `GE` field.
+    pub const fn set_ge(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::GE_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `GE` field set to the given value.
+    pub const fn with_ge(mut self, value: u8) -> Self {
+        self.set_ge(value);
+        self
+    }
+
+    /// Sets the value of the `GE` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ge(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ge(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `GE` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ge(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::GE_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Display for SpsrEl2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("SpsrEl2");
+        let bits = self.bits();
+        s.field("M[3:0]", &Self::M_3_0_FIELD.extract(bits));
+        s.field("M[4]", &Self::M_4_FIELD.extract(bits));
+        s.field("T", &Self::T_FIELD.extract(bits));
+        s.field("F", &Self::F_FIELD.extract(bits));
+        s.field("I", &Self::I_FIELD.extract(bits));
+        s.field("A", &Self::A_FIELD.extract(bits));
+        s.field("D", &Self::D_FIELD.extract(bits));
+        s.field("E", &Self::E_FIELD.extract(bits));
+        s.field("BTYPE", &Self::BTYPE_FIELD.extract(bits));
+        s.field("ALLINT", &Self::ALLINT_FIELD.extract(bits));
+        s.field("BTYPE2", &Self::BTYPE2_FIELD.extract(bits));
+        s.field("GE", &Self::GE_FIELD.extract(bits));
+        s.field("IL", &Self::IL_FIELD.extract(bits));
+        s.field("SS", &Self::SS_FIELD.extract(bits));
+        s.field("PAN", &Self::PAN_FIELD.extract(bits));
+        s.field("UAO", &Self::UAO_FIELD.extract(bits));
+        s.field("DIT", &Self::DIT_FIELD.extract(bits));
+        s.field("TCO", &Self::TCO_FIELD.extract(bits));
+        s.field("Q", &Self::Q_FIELD.extract(bits));
+        s.field("V", &Self::V_FIELD.extract(bits));
+        s.field("C", &Self::C_FIELD.extract(bits));
+        s.field("Z", &Self::Z_FIELD.extract(bits));
+        s.field("N", &Self::N_FIELD.extract(bits));
+        s.field("PM", &Self::PM_FIELD.extract(bits));
+        s.field("EXLOCK", &Self::EXLOCK_FIELD.extract(bits));
+        s.field("PACM", &Self::PACM_FIELD.extract(bits));
+        s.field("UINJ", &Self::UINJ_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for SpsrEl2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "SpsrEl2 {{ M[3:0]: {=u64}, M[4]: {=u64}, T: {=u64}, F: {=u64}, I: {=u64}, A: {=u64}, D: {=u64}, E: {=u64}, BTYPE: {=u64}, ALLINT: {=u64}, BTYPE2: {=u64}, GE: {=u64}, IL: {=u64}, SS: {=u64}, PAN: {=u64}, UAO: {=u64}, DIT: {=u64}, TCO: {=u64}, Q: {=u64}, V: {=u64}, C: {=u64}, Z: {=u64}, N: {=u64}, PM: {=u64}, EXLOCK: {=u64}, PACM: {=u64}, UINJ: {=u64} }}",
+            Self::M_3_0_FIELD.extract(self.bits()),
+            Self::M_4_FIELD.extract(self.bits()),
+            Self::T_FIELD.extract(self.bits()),
+            Self::F_FIELD.extract(self.bits()),
+            Self::I_FIELD.extract(self.bits()),
+            Self::A_FIELD.extract(self.bits()),
+            Self::D_FIELD.extract(self.bits()),
+            Self::E_FIELD.extract(self.bits()),
+            Self::BTYPE_FIELD.extract(self.bits()),
+            Self::ALLINT_FIELD.extract(self.bits()),
+            Self::BTYPE2_FIELD.extract(self.bits()),
+            Self::GE_FIELD.extract(self.bits()),
+            Self::IL_FIELD.extract(self.bits()),
+            Self::SS_FIELD.extract(self.bits()),
+            Self::PAN_FIELD.extract(self.bits()),
+            Self::UAO_FIELD.extract(self.bits()),
+            Self::DIT_FIELD.extract(self.bits()),
+            Self::TCO_FIELD.extract(self.bits()),
+            Self::Q_FIELD.extract(self.bits()),
+            Self::V_FIELD.extract(self.bits()),
+            Self::C_FIELD.extract(self.bits()),
+            Self::Z_FIELD.extract(self.bits()),
+            Self::N_FIELD.extract(self.bits()),
+            Self::PM_FIELD.extract(self.bits()),
+            Self::EXLOCK_FIELD.extract(self.bits()),
+            Self::PACM_FIELD.extract(self.bits()),
+            Self::UINJ_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for SpsrEl2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for SpsrEl2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl SpsrEl2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("M[3:0]"),
+                old: Self::M_3_0_FIELD.extract(self.bits()),
+                new: Self::M_3_0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("M[4]"),
+                old: Self::M_4_FIELD.extract(self.bits()),
+                new: Self::M_4_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("T"),
+                old: Self::T_FIELD.extract(self.bits()),
+                new: Self::T_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("F"),
+                old: Self::F_FIELD.extract(self.bits()),
+                new: Self::F_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("I"),
+                old: Self::I_FIELD.extract(self.bits()),
+                new: Self::I_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("A"),
+                old: Self::A_FIELD.extract(self.bits()),
+                new: Self::A_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("D"),
+                old: Self::D_FIELD.extract(self.bits()),
+                new: Self::D_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("E"),
+                old: Self::E_FIELD.extract(self.bits()),
+                new: Self::E_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("BTYPE"),
+                old: Self::BTYPE_FIELD.extract(self.bits()),
+                new: Self::BTYPE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ALLINT"),
+                old: Self::ALLINT_FIELD.extract(self.bits()),
+                new: Self::ALLINT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("BTYPE2"),
+                old: Self::BTYPE2_FIELD.extract(self.bits()),
+                new: Self::BTYPE2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("GE"),
+                old: Self::GE_FIELD.extract(self.bits()),
+                new: Self::GE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IL"),
+                old: Self::IL_FIELD.extract(self.bits()),
+                new: Self::IL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SS"),
+                old: Self::SS_FIELD.extract(self.bits()),
+                new: Self::SS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PAN"),
+                old: Self::PAN_FIELD.extract(self.bits()),
+                new: Self::PAN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("UAO"),
+                old: Self::UAO_FIELD.extract(self.bits()),
+                new: Self::UAO_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DIT"),
+                old: Self::DIT_FIELD.extract(self.bits()),
+                new: Self::DIT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TCO"),
+                old: Self::TCO_FIELD.extract(self.bits()),
+                new: Self::TCO_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("Q"),
+                old: Self::Q_FIELD.extract(self.bits()),
+                new: Self::Q_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("V"),
+                old: Self::V_FIELD.extract(self.bits()),
+                new: Self::V_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("C"),
+                old: Self::C_FIELD.extract(self.bits()),
+                new: Self::C_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("Z"),
+                old: Self::Z_FIELD.extract(self.bits()),
+                new: Self::Z_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("N"),
+                old: Self::N_FIELD.extract(self.bits()),
+                new: Self::N_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PM"),
+                old: Self::PM_FIELD.extract(self.bits()),
+                new: Self::PM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EXLOCK"),
+                old: Self::EXLOCK_FIELD.extract(self.bits()),
+                new: Self::EXLOCK_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PACM"),
+                old: Self::PACM_FIELD.extract(self.bits()),
+                new: Self::PACM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("UINJ"),
+                old: Self::UINJ_FIELD.extract(self.bits()),
+                new: Self::UINJ_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1110_0010_0000_0100_0000_0000_1001_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1110_0010_0000_0100_0000_0000_1001_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl SpsrEl2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl SpsrEl2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el3")]
+bitflags! {
+    /// `SPSR_EL3` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct SpsrEl3: u64 {
+        /// `M[4]` bit.
+        const M_4 = 1 << 4;
+        /// `T` bit.
+        const T = 1 << 5;
+        /// `F` bit.
+        const F = 1 << 6;
+        /// `I` bit.
+        const I = 1 << 7;
+        /// `A` bit.
+        const A = 1 << 8;
+        /// `D` bit.
+        const D = 1 << 9;
+        /// `E` bit.
+        const E = 1 << 9;
+        /// `ALLINT` bit.
+        const ALLINT = 1 << 13;
+        /// `BTYPE2` bit.
+        const BTYPE2 = 1 << 14;
+        /// `IL` bit.
+        const IL = 1 << 20;
+        /// `SS` bit.
+        const SS = 1 << 21;
+        /// `PAN` bit.
+        const PAN = 1 << 22;
+        /// `UAO` bit.
+        const UAO = 1 << 23;
+        /// `DIT` bit.
+        const DIT = 1 << 24;
+        /// `TCO` bit.
+        const TCO = 1 << 25;
+        /// `Q` bit.
+        const Q = 1 << 27;
+        /// `V` bit.
+        const V = 1 << 28;
+        /// `C` bit.
+        const C = 1 << 29;
+        /// `Z` bit.
+        const Z = 1 << 30;
+        /// `N` bit.
+        const N = 1 << 31;
+        /// `PM` bit.
+        const PM = 1 << 32;
+        /// `EXLOCK` bit.
+        const EXLOCK = 1 << 34;
+        /// `PACM` bit.
+        const PACM = 1 << 35;
+        /// `UINJ` bit.
+        const UINJ = 1 << 36;
+    }
+}
+
+#[cfg(feature = "el3")]
+impl SpsrEl3 {
+    /// Descriptor of the `M[3:0]` field, for use with [`FieldAccess`].
+    pub const M_3_0_FIELD: Field<Self, u8, 0, 4> = Field::new("SPSR_EL3", "M[3:0]");
+    /// Descriptor of the `M[4]` field, for use with [`FieldAccess`].
+    pub const M_4_FIELD: Field<Self, bool, 4, 1> = Field::new("SPSR_EL3", "M[4]");
+    /// Descriptor of the `T` field, for use with [`FieldAccess`].
+    pub const T_FIELD: Field<Self, bool, 5, 1> = Field::new("SPSR_EL3", "T");
+    /// Descriptor of the `F` field, for use with [`FieldAccess`].
+    pub const F_FIELD: Field<Self, bool, 6, 1> = Field::new("SPSR_EL3", "F");
+    /// Descriptor of the `I` field, for use with [`FieldAccess`].
+    pub const I_FIELD: Field<Self, bool, 7, 1> = Field::new("SPSR_EL3", "I");
+    /// Descriptor of the `A` field, for use with [`FieldAccess`].
+    pub const A_FIELD: Field<Self, bool, 8, 1> = Field::new("SPSR_EL3", "A");
+    /// Descriptor of the `D` field, for use with [`FieldAccess`].
+    pub const D_FIELD: Field<Self, bool, 9, 1> = Field::new("SPSR_EL3", "D");
+    /// Descriptor of the `E` field, for use with [`FieldAccess`].
+    pub const E_FIELD: Field<Self, bool, 9, 1> = Field::new("SPSR_EL3", "E");
+    /// Descriptor of the `BTYPE` field, for use with [`FieldAccess`].
+    pub const BTYPE_FIELD: Field<Self, u8, 10, 2> = Field::new("SPSR_EL3", "BTYPE");
+    /// Descriptor of the `ALLINT` field, for use with [`FieldAccess`].
+    pub const ALLINT_FIELD: Field<Self, bool, 13, 1> = Field::new("SPSR_EL3", "ALLINT");
+    /// Descriptor of the `BTYPE2` field, for use with [`FieldAccess`].
+    pub const BTYPE2_FIELD: Field<Self, bool, 14, 1> = Field::new("SPSR_EL3", "BTYPE2");
+    /// Descriptor of the `GE` field, for use with [`FieldAccess`].
+    pub const GE_FIELD: Field<Self, u8, 16, 4> = Field::new("SPSR_EL3", "GE");
+    /// Descriptor of the `IL` field, for use with [`FieldAccess`].
+    pub const IL_FIELD: Field<Self, bool, 20, 1> = Field::new("SPSR_EL3", "IL");
+    /// Descriptor of the `SS` field, for use with [`FieldAccess`].
+    pub const SS_FIELD: Field<Self, bool, 21, 1> = Field::new("SPSR_EL3", "SS");
+    /// Descriptor of the `PAN` field, for use with [`FieldAccess`].
+    pub const PAN_FIELD: Field<Self, bool, 22, 1> = Field::new("SPSR_EL3", "PAN");
+    /// Descriptor of the `UAO` field, for use with [`FieldAccess`].
+    pub const UAO_FIELD: Field<Self, bool, 23, 1> = Field::new("SPSR_EL3", "UAO");
+    /// Descriptor of the `DIT` field, for use with [`FieldAccess`].
+    pub const DIT_FIELD: Field<Self, bool, 24, 1> = Field::new("SPSR_EL3", "DIT");
+    /// Descriptor of the `TCO` field, for use with [`FieldAccess`].
+    pub const TCO_FIELD: Field<Self, bool, 25, 1> = Field::new("SPSR_EL3", "TCO");
+    /// Descriptor of the `Q` field, for use with [`FieldAccess`].
+    pub const Q_FIELD: Field<Self, bool, 27, 1> = Field::new("SPSR_EL3", "Q");
+    /// Descriptor of the `V` field, for use with [`FieldAccess`].
+    pub const V_FIELD: Field<Self, bool, 28, 1> = Field::new("SPSR_EL3", "V");
+    /// Descriptor of the `C` field, for use with [`FieldAccess`].
+    pub const C_FIELD: Field<Self, bool, 29, 1> = Field::new("SPSR_EL3", "C");
+    /// Descriptor of the `Z` field, for use with [`FieldAccess`].
+    pub const Z_FIELD: Field<Self, bool, 30, 1> = Field::new("SPSR_EL3", "Z");
+    /// Descriptor of the `N` field, for use with [`FieldAccess`].
+    pub const N_FIELD: Field<Self, bool, 31, 1> = Field::new("SPSR_EL3", "N");
+    /// Descriptor of the `PM` field, for use with [`FieldAccess`].
+    pub const PM_FIELD: Field<Self, bool, 32, 1> = Field::new("SPSR_EL3", "PM");
+    /// Descriptor of the `EXLOCK` field, for use with [`FieldAccess`].
+    pub const EXLOCK_FIELD: Field<Self, bool, 34, 1> = Field::new("SPSR_EL3", "EXLOCK");
+    /// Descriptor of the `PACM` field, for use with [`FieldAccess`].
+    pub const PACM_FIELD: Field<Self, bool, 35, 1> = Field::new("SPSR_EL3", "PACM");
+    /// Descriptor of the `UINJ` field, for use with [`FieldAccess`].
+    pub const UINJ_FIELD: Field<Self, bool, 36, 1> = Field::new("SPSR_EL3", "UINJ");
+
+    /// Returns the value of the `M[3:0]` field.
+    pub const fn m_3_0(self) -> u8 {
+        Self::M_3_0_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `M[3:0]` field.
+    pub const fn set_m_3_0(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::M_3_0_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `M[3:0]` field set to the given value.
+    pub const fn with_m_3_0(mut self, value: u8) -> Self {
+        self.set_m_3_0(value);
+        self
+    }
+
+    /// Sets the value of the `M[3:0]` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_m_3_0(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_m_3_0(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `M[3:0]` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_m_3_0(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::M_3_0_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `BTYPE` field.
+    pub const fn btype(self) -> u8 {
+        Self::BTYPE_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `BTYPE` field.
+    pub const fn set_btype(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::BTYPE_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `BTYPE` field set to the given value.
+    pub const fn with_btype(mut self, value: u8) -> Self {
+        self.set_btype(value);
+        self
+    }
+
+    /// Sets the value of the `BTYPE` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_btype(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_btype(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `BTYPE` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_btype(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::BTYPE_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `GE` field.
+    pub const fn ge(self) -> u8 {
+        Self::GE_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `GE` field.
+    pub const fn set_ge(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::GE_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `GE` field set to the given value.
+    pub const fn with_ge(mut self, value: u8) -> Self {
+        self.set_ge(value);
+        self
+    }
+
+    /// Sets the value of the `GE` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ge(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ge(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `GE` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ge(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::GE_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el3")]
+impl Display for SpsrEl3 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("SpsrEl3");
+        let bits = self.bits();
+        s.field("M[3:0]", &Self::M_3_0_FIELD.extract(bits));
+        s.field("M[4]", &Self::M_4_FIELD.extract(bits));
+        s.field("T", &Self::T_FIELD.extract(bits));
+        s.field("F", &Self::F_FIELD.extract(bits));
+        s.field("I", &Self::I_FIELD.extract(bits));
+        s.field("A", &Self::A_FIELD.extract(bits));
+        s.field("D", &Self::D_FIELD.extract(bits));
+        s.field("E", &Self::E_FIELD.extract(bits));
+        s.field("BTYPE", &Self::BTYPE_FIELD.extract(bits));
+        s.field("ALLINT", &Self::ALLINT_FIELD.extract(bits));
+        s.field("BTYPE2", &Self::BTYPE2_FIELD.extract(bits));
+        s.field("GE", &Self::GE_FIELD.extract(bits));
+        s.field("IL", &Self::IL_FIELD.extract(bits));
+        s.field("SS", &Self::SS_FIELD.extract(bits));
+        s.field("PAN", &Self::PAN_FIELD.extract(bits));
+        s.field("UAO", &Self::UAO_FIELD.extract(bits));
+        s.field("DIT", &Self::DIT_FIELD.extract(bits));
+        s.field("TCO", &Self::TCO_FIELD.extract(bits));
+        s.field("Q", &Self::Q_FIELD.extract(bits));
+        s.field("V", &Self::V_FIELD.extract(bits));
+        s.field("C", &Self::C_FIELD.extract(bits));
+        s.field("Z", &Self::Z_FIELD.extract(bits));
+        s.field("N", &Self::N_FIELD.extract(bits));
+        s.field("PM", &Self::PM_FIELD.extract(bits));
+        s.field("EXLOCK", &Self::EXLOCK_FIELD.extract(bits));
+        s.field("PACM", &Self::PACM_FIELD.extract(bits));
+        s.field("UINJ", &Self::UINJ_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el3")]
+impl defmt::Format for SpsrEl3 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "SpsrEl3 {{ M[3:0]: {=u64}, M[4]: {=u64}, T: {=u64}, F: {=u64}, I: {=u64}, A: {=u64}, D: {=u64}, E: {=u64}, BTYPE: {=u64}, ALLINT: {=u64}, BTYPE2: {=u64}, GE: {=u64}, IL: {=u64}, SS: {=u64}, PAN: {=u64}, UAO: {=u64}, DIT: {=u64}, TCO: {=u64}, Q: {=u64}, V: {=u64}, C: {=u64}, Z: {=u64}, N: {=u64}, PM: {=u64}, EXLOCK: {=u64}, PACM: {=u64}, UINJ: {=u64} }}",
+            Self::M_3_0_FIELD.extract(self.bits()),
+            Self::M_4_FIELD.extract(self.bits()),
+            Self::T_FIELD.extract(self.bits()),
+            Self::F_FIELD.extract(self.bits()),
+            Self::I_FIELD.extract(self.bits()),
+            Self::A_FIELD.extract(self.bits()),
+            Self::D_FIELD.extract(self.bits()),
+            Self::E_FIELD.extract(self.bits()),
+            Self::BTYPE_FIELD.extract(self.bits()),
+            Self::ALLINT_FIELD.extract(self.bits()),
+            Self::BTYPE2_FIELD.extract(self.bits()),
+            Self::GE_FIELD.extract(self.bits()),
+            Self::IL_FIELD.extract(self.bits()),
+            Self::SS_FIELD.extract(self.bits()),
+            Self::PAN_FIELD.extract(self.bits()),
+            Self::UAO_FIELD.extract(self.bits()),
+            Self::DIT_FIELD.extract(self.bits()),
+            Self::TCO_FIELD.extract(self.bits()),
+            Self::Q_FIELD.extract(self.bits()),
+            Self::V_FIELD.extract(self.bits()),
+            Self::C_FIELD.extract(self.bits()),
+            Self::Z_FIELD.extract(self.bits()),
+            Self::N_FIELD.extract(self.bits()),
+            Self::PM_FIELD.extract(self.bits()),
+            Self::EXLOCK_FIELD.extract(self.bits()),
+            Self::PACM_FIELD.extract(self.bits()),
+            Self::UINJ_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el3")]
+impl serde::Serialize for SpsrEl3 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el3")]
+impl<'de> serde::Deserialize<'de> for SpsrEl3 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el3")]
+impl SpsrEl3 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("M[3:0]"),
+                old: Self::M_3_0_FIELD.extract(self.bits()),
+                new: Self::M_3_0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("M[4]"),
+                old: Self::M_4_FIELD.extract(self.bits()),
+                new: Self::M_4_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("T"),
+                old: Self::T_FIELD.extract(self.bits()),
+                new: Self::T_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("F"),
+                old: Self::F_FIELD.extract(self.bits()),
+                new: Self::F_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("I"),
+                old: Self::I_FIELD.extract(self.bits()),
+                new: Self::I_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("A"),
+                old: Self::A_FIELD.extract(self.bits()),
+                new: Self::A_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("D"),
+                old: Self::D_FIELD.extract(self.bits()),
+                new: Self::D_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("E"),
+                old: Self::E_FIELD.extract(self.bits()),
+                new: Self::E_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("BTYPE"),
+                old: Self::BTYPE_FIELD.extract(self.bits()),
+                new: Self::BTYPE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ALLINT"),
+                old: Self::ALLINT_FIELD.extract(self.bits()),
+                new: Self::ALLINT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("BTYPE2"),
+                old: Self::BTYPE2_FIELD.extract(self.bits()),
+                new: Self::BTYPE2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("GE"),
+                old: Self::GE_FIELD.extract(self.bits()),
+                new: Self::GE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IL"),
+                old: Self::IL_FIELD.extract(self.bits()),
+                new: Self::IL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SS"),
+                old: Self::SS_FIELD.extract(self.bits()),
+                new: Self::SS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PAN"),
+                old: Self::PAN_FIELD.extract(self.bits()),
+                new: Self::PAN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("UAO"),
+                old: Self::UAO_FIELD.extract(self.bits()),
+                new: Self::UAO_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DIT"),
+                old: Self::DIT_FIELD.extract(self.bits()),
+                new: Self::DIT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TCO"),
+                old: Self::TCO_FIELD.extract(self.bits()),
+                new: Self::TCO_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("Q"),
+                old: Self::Q_FIELD.extract(self.bits()),
+                new: Self::Q_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("V"),
+                old: Self::V_FIELD.extract(self.bits()),
+                new: Self::V_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("C"),
+                old: Self::C_FIELD.extract(self.bits()),
+                new: Self::C_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("Z"),
+                old: Self::Z_FIELD.extract(self.bits()),
+                new: Self::Z_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("N"),
+                old: Self::N_FIELD.extract(self.bits()),
+                new: Self::N_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PM"),
+                old: Self::PM_FIELD.extract(self.bits()),
+                new: Self::PM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EXLOCK"),
+                old: Self::EXLOCK_FIELD.extract(self.bits()),
+                new: Self::EXLOCK_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PACM"),
+                old: Self::PACM_FIELD.extract(self.bits()),
+                new: Self::PACM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("UINJ"),
+                old: Self::UINJ_FIELD.extract(self.bits()),
+                new: Self::UINJ_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1110_0010_0000_0100_0000_0000_1001_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1110_0010_0000_0100_0000_0000_1001_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el3")]
+impl SpsrEl3 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el3")]
+impl SpsrEl3 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `VBAR_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct VbarEl1: u64 {
+        /// `UT` bit.
+        const UT = 1 << 0;
+    }
+}
+
+#[cfg(feature = "el1")]
+impl VbarEl1 {
+    /// Descriptor of the `UT` field, for use with [`FieldAccess`].
+    pub const UT_FIELD: Field<Self, bool, 0, 1> = Field::new("VBAR_EL1", "UT");
+    /// Descriptor of the `VBA` field, for use with [`FieldAccess`].
+    pub const VBA_FIELD: Field<Self, u64, 11, 53> = Field::new("VBAR_EL1", "VBA");
+
+    /// Returns the value of the `VBA` field.
+    pub const fn vba(self) -> u64 {
+        Self::VBA_FIELD.extract(self.bits())
+    }
+
+    /// Sets the value of the `VBA` field.
+    pub const fn set_vba(&mut self, value: u64) {
+        *self = Self::from_bits_retain(Self::VBA_FIELD.insert(self.bits(), value));
+    }
+
+    /// Returns a copy with the `VBA` field set to the given value.
+    pub const fn with_vba(mut self, value: u64) -> Self {
+        self.set_vba(value);
+        self
+    }
+
+    /// Sets the value of the `VBA` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_vba(&mut self, value: u64) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_vba(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `VBA` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_vba(self, value: u64) -> Result<Self, FieldOverflow> {
+        match Self::VBA_FIELD.try_insert(self.bits(), value) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for VbarEl1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("VbarEl1");
+        let bits = self.bits();
+        s.field("UT", &Self::UT_FIELD.extract(bits));
+        s.field("VBA", &format_args!("{:#x}", Self::VBA_FIELD.extract(bits)));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for VbarEl1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "VbarEl1 {{ UT: {=u64}, VBA: {=u64:#x} }}",
+            Self::UT_FIELD.extract(self.bits()),
+            Self::VBA_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for VbarEl1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for VbarEl1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl VbarEl1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("UT"),
+                old: Self::UT_FIELD.extract(self.bits()),
+                new: Self::UT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VBA"),
+                old: Self::VBA_FIELD.extract(self.bits()),
+                new: Self::VBA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b111_1111_1110,
+                new: other.bits() & 0b111_1111_1110,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl VbarEl1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl VbarEl1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `VBAR_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct VbarEl2: u64 {
+        /// `UT` bit.
+        const UT = 1 << 0;
+    }
+}
+
+#[cfg(feature = "el2")]
+impl VbarEl2 {
+    /// Descriptor of the `UT` field, for use with [`FieldAccess`].
+    pub const UT_FIELD: Field<Self, bool, 0, 1> = Field::new("VBAR_EL2", "UT");
+    /// Descriptor of the `VBA` field, for use with [`FieldAccess`].
+    pub const VBA_FIELD: Field<Self, u64, 11, 53> = Field::new("VBAR_EL2", "VBA");
+
+    /// Returns the value of the `VBA` field.
+    pub const fn vba(self) -> u64 {
+        Self::VBA_FIELD.extract(self.bits())
+    }
+
+    /// Sets the value of the `VBA` field.
+    pub const fn set_vba(&mut self, value: u64) {
+        *self = Self::from_bits_retain(Self::VBA_FIELD.insert(self.bits(), value));
+    }
+
+    /// Returns a copy with the `VBA` field set to the given value.
+    pub const fn with_vba(mut self, value: u64) -> Self {
+        self.set_vba(value);
+        self
+    }
+
+    /// Sets the value of the `VBA` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_vba(&mut self, value: u64) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_vba(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `VBA` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_vba(self, value: u64) -> Result<Self, FieldOverflow> {
+        match Self::VBA_FIELD.try_insert(self.bits(), value) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Display for VbarEl2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("VbarEl2");
+        let bits = self.bits();
+        s.field("UT", &Self::UT_FIELD.extract(bits));
+        s.field("VBA", &format_args!("{:#x}", Self::VBA_FIELD.extract(bits)));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for VbarEl2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "VbarEl2 {{ UT: {=u64}, VBA: {=u64:#x} }}",
+            Self::UT_FIELD.extract(self.bits()),
+            Self::VBA_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for VbarEl2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for VbarEl2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl VbarEl2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("UT"),
+                old: Self::UT_FIELD.extract(self.bits()),
+                new: Self::UT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VBA"),
+                old: Self::VBA_FIELD.extract(self.bits()),
+                new: Self::VBA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b111_1111_1110,
+                new: other.bits() & 0b111_1111_1110,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl VbarEl2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl VbarEl2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `AFSR0_EL1` system register.
+    afsr0_el1, u64, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `AFSR0_EL2` system register.
+    afsr0_el2, u64, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `AFSR1_EL1` system register.
+    afsr1_el1, u64, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `AFSR1_EL2` system register.
+    afsr1_el2, u64, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `ELR_EL1` system register.
+    elr_el1, u64: ElrEl1, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `ELR_EL2` system register.
+    elr_el2, u64: ElrEl2, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_write_sysreg!(esr_el1, u64: EsrEl1, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg!(esr_el2, u64: EsrEl2, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
+read_write_sysreg!(esr_el3, u64: EsrEl3, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `FAR_EL1` system register.
+    far_el1, u64: FarEl1, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `FAR_EL2` system register.
+    far_el2, u64: FarEl2, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `HPFAR_EL2` system register.
+    hpfar_el2, u64: HpfarEl2, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_sysreg!(isr_el1, u64: IsrEl1, safe, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `PFAR_EL1` system register.
+    pfar_el1: s3_0_c6_c0_5, u64: PfarEl1, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `PFAR_EL2` system register.
+    pfar_el2: s3_4_c6_c0_5, u64: PfarEl2, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `SPSR_EL1` system register.
+    spsr_el1, u64: SpsrEl1, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `SPSR_EL2` system register.
+    spsr_el2, u64: SpsrEl2, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `SPSR_EL3` system register.
+    spsr_el3, u64: SpsrEl3, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The base address must point to a valid exception vector.
+    vbar_el1, u64: VbarEl1, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The base address must point to a valid exception vector.
+    vbar_el2, u64: VbarEl2, safe_read, fake::SYSREGS
+}
@@ -0,0 +1,4249 @@
+// SPDX-FileCopyrightText: Copyright The arm-sysregs Contributors.
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! GIC CPU interface system registers.
+
+// This file is generated, do not edit manually.
+
+// Depending on the enabled features, the module might not use all of these imports.
+#![allow(unused_imports)]
+
+use crate::*;
+use bitflags::bitflags;
+use core::fmt::{self, Display, Formatter};
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+bitflags! {
+    /// `ICC_AP1R0_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct IccAp1r0El1: u64 {
+        /// `NMI` bit.
+        const NMI = 1 << 63;
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl IccAp1r0El1 {
+    /// Descriptor of the `NMI` field, for use with [`FieldAccess`].
+    pub const NMI_FIELD: Field<Self, bool, 63, 1> = Field::new("ICC_AP1R0_EL1", "NMI");
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl Display for IccAp1r0El1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("IccAp1r0El1");
+        let bits = self.bits();
+        s.field("NMI", &Self::NMI_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl defmt::Format for IccAp1r0El1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "IccAp1r0El1 {{ NMI: {=u64} }}",
+            Self::NMI_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl serde::Serialize for IccAp1r0El1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl<'de> serde::Deserialize<'de> for IccAp1r0El1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl IccAp1r0El1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("NMI"),
+                old: Self::NMI_FIELD.extract(self.bits()),
+                new: Self::NMI_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111,
+                new: other.bits() & 0b111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl IccAp1r0El1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl IccAp1r0El1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [(
+            Self::NMI_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+            UnimplementedField {
+                register: "ICC_AP1R0_EL1",
+                field: "NMI",
+                requires: "FEAT_GICv3",
+            },
+        )]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+bitflags! {
+    /// `ICC_ASGI1R_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct IccAsgi1rEl1: u64 {
+        /// `IRM` bit.
+        const IRM = 1 << 40;
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl IccAsgi1rEl1 {
+    /// Descriptor of the `TargetList` field, for use with [`FieldAccess`].
+    pub const TARGETLIST_FIELD: Field<Self, u16, 0, 16> =
+        Field::new("ICC_ASGI1R_EL1", "TargetList");
+    /// Descriptor of the `Aff1` field, for use with [`FieldAccess`].
+    pub const AFF1_FIELD: Field<Self, u8, 16, 8> = Field::new("ICC_ASGI1R_EL1", "Aff1");
+    /// Descriptor of the `INTID` field, for use with [`FieldAccess`].
+    pub const INTID_FIELD: Field<Self, u8, 24, 4> = Field::new("ICC_ASGI1R_EL1", "INTID");
+    /// Descriptor of the `Aff2` field, for use with [`FieldAccess`].
+    pub const AFF2_FIELD: Field<Self, u8, 32, 8> = Field::new("ICC_ASGI1R_EL1", "Aff2");
+    /// Descriptor of the `IRM` field, for use with [`FieldAccess`].
+    pub const IRM_FIELD: Field<Self, bool, 40, 1> = Field::new("ICC_ASGI1R_EL1", "IRM");
+    /// Descriptor of the `RS` field, for use with [`FieldAccess`].
+    pub const RS_FIELD: Field<Self, u8, 44, 4> = Field::new("ICC_ASGI1R_EL1", "RS");
+    /// Descriptor of the `Aff3` field, for use with [`FieldAccess`].
+    pub const AFF3_FIELD: Field<Self, u8, 48, 8> = Field::new("ICC_ASGI1R_EL1", "Aff3");
+
+    /// Returns the value of the `TargetList` field.
+    pub const fn targetlist(self) -> u16 {
+        Self::TARGETLIST_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `TargetList` field.
+    pub const fn set_targetlist(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::TARGETLIST_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `TargetList` field set to the given value.
+    pub const fn with_targetlist(mut self, value: u16) -> Self {
+        self.set_targetlist(value);
+        self
+    }
+
+    /// Sets the value of the `TargetList` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_targetlist(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_targetlist(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `TargetList` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_targetlist(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::TARGETLIST_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `Aff1` field.
+    pub const fn aff1(self) -> u8 {
+        Self::AFF1_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `Aff1` field.
+    pub const fn set_aff1(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::AFF1_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `Aff1` field set to the given value.
+    pub const fn with_aff1(mut self, value: u8) -> Self {
+        self.set_aff1(value);
+        self
+    }
+
+    /// Sets the value of the `Aff1` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_aff1(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_aff1(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `Aff1` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_aff1(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::AFF1_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `INTID` field.
+    pub const fn intid(self) -> u8 {
+        Self::INTID_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `INTID` field.
+    pub const fn set_intid(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::INTID_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `INTID` field set to the given value.
+    pub const fn with_intid(mut self, value: u8) -> Self {
+        self.set_intid(value);
+        self
+    }
+
+    /// Sets the value of the `INTID` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_intid(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_intid(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `INTID` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_intid(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::INTID_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `Aff2` field.
+    pub const fn aff2(self) -> u8 {
+        Self::AFF2_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `Aff2` field.
+    pub const fn set_aff2(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::AFF2_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `Aff2` field set to the given value.
+    pub const fn with_aff2(mut self, value: u8) -> Self {
+        self.set_aff2(value);
+        self
+    }
+
+    /// Sets the value of the `Aff2` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_aff2(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_aff2(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `Aff2` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_aff2(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::AFF2_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `RS` field.
+    pub const fn rs(self) -> u8 {
+        Self::RS_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `RS` field.
+    pub const fn set_rs(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::RS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `RS` field set to the given value.
+    pub const fn with_rs(mut self, value: u8) -> Self {
+        self.set_rs(value);
+        self
+    }
+
+    /// Sets the value of the `RS` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_rs(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_rs(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `RS` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_rs(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::RS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `Aff3` field.
+    pub const fn aff3(self) -> u8 {
+        Self::AFF3_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `Aff3` field.
+    pub const fn set_aff3(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::AFF3_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `Aff3` field set to the given value.
+    pub const fn with_aff3(mut self, value: u8) -> Self {
+        self.set_aff3(value);
+        self
+    }
+
+    /// Sets the value of the `Aff3` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_aff3(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_aff3(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `Aff3` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_aff3(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::AFF3_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl Display for IccAsgi1rEl1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("IccAsgi1rEl1");
+        let bits = self.bits();
+        s.field(
+            "TargetList",
+            &format_args!("{:#x}", Self::TARGETLIST_FIELD.extract(bits)),
+        );
+        s.field("Aff1", &Self::AFF1_FIELD.extract(bits));
+        s.field("INTID", &Self::INTID_FIELD.extract(bits));
+        s.field("Aff2", &Self::AFF2_FIELD.extract(bits));
+        s.field("IRM", &Self::IRM_FIELD.extract(bits));
+        s.field("RS", &Self::RS_FIELD.extract(bits));
+        s.field("Aff3", &Self::AFF3_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl defmt::Format for IccAsgi1rEl1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "IccAsgi1rEl1 {{ TargetList: {=u64:#x}, Aff1: {=u64}, INTID: {=u64}, Aff2: {=u64}, IRM: {=u64}, RS: {=u64}, Aff3: {=u64} }}",
+            Self::TARGETLIST_FIELD.extract(self.bits()),
+            Self::AFF1_FIELD.extract(self.bits()),
+            Self::INTID_FIELD.extract(self.bits()),
+            Self::AFF2_FIELD.extract(self.bits()),
+            Self::IRM_FIELD.extract(self.bits()),
+            Self::RS_FIELD.extract(self.bits()),
+            Self::AFF3_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl serde::Serialize for IccAsgi1rEl1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl<'de> serde::Deserialize<'de> for IccAsgi1rEl1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl IccAsgi1rEl1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("TargetList"),
+                old: Self::TARGETLIST_FIELD.extract(self.bits()),
+                new: Self::TARGETLIST_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("Aff1"),
+                old: Self::AFF1_FIELD.extract(self.bits()),
+                new: Self::AFF1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("INTID"),
+                old: Self::INTID_FIELD.extract(self.bits()),
+                new: Self::INTID_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("Aff2"),
+                old: Self::AFF2_FIELD.extract(self.bits()),
+                new: Self::AFF2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IRM"),
+                old: Self::IRM_FIELD.extract(self.bits()),
+                new: Self::IRM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("RS"),
+                old: Self::RS_FIELD.extract(self.bits()),
+                new: Self::RS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("Aff3"),
+                old: Self::AFF3_FIELD.extract(self.bits()),
+                new: Self::AFF3_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_0000_0000_0000_1110_0000_0000_1111_0000_0000_0000_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_0000_0000_0000_1110_0000_0000_1111_0000_0000_0000_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl IccAsgi1rEl1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl IccAsgi1rEl1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                Self::TARGETLIST_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICC_ASGI1R_EL1",
+                    field: "TargetList",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::AFF1_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICC_ASGI1R_EL1",
+                    field: "Aff1",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::INTID_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICC_ASGI1R_EL1",
+                    field: "INTID",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::AFF2_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICC_ASGI1R_EL1",
+                    field: "Aff2",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::IRM_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICC_ASGI1R_EL1",
+                    field: "IRM",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::RS_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICC_ASGI1R_EL1",
+                    field: "RS",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::AFF3_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICC_ASGI1R_EL1",
+                    field: "Aff3",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+bitflags! {
+    /// `ICC_BPR0_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct IccBpr0El1: u64 {
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl IccBpr0El1 {
+    /// Descriptor of the `BinaryPoint` field, for use with [`FieldAccess`].
+    pub const BINARYPOINT_FIELD: Field<Self, u8, 0, 3> = Field::new("ICC_BPR0_EL1", "BinaryPoint");
+
+    /// Returns the value of the `BinaryPoint` field.
+    pub const fn binarypoint(self) -> u8 {
+        Self::BINARYPOINT_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `BinaryPoint` field.
+    pub const fn set_binarypoint(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::BINARYPOINT_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `BinaryPoint` field set to the given value.
+    pub const fn with_binarypoint(mut self, value: u8) -> Self {
+        self.set_binarypoint(value);
+        self
+    }
+
+    /// Sets the value of the `BinaryPoint` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_binarypoint(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_binarypoint(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `BinaryPoint` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_binarypoint(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::BINARYPOINT_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl Display for IccBpr0El1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("IccBpr0El1");
+        let bits = self.bits();
+        s.field("BinaryPoint", &Self::BINARYPOINT_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl defmt::Format for IccBpr0El1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "IccBpr0El1 {{ BinaryPoint: {=u64} }}",
+            Self::BINARYPOINT_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl serde::Serialize for IccBpr0El1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl<'de> serde::Deserialize<'de> for IccBpr0El1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl IccBpr0El1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("BinaryPoint"),
+                old: Self::BINARYPOINT_FIELD.extract(self.bits()),
+                new: Self::BINARYPOINT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl IccBpr0El1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl IccBpr0El1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [(
+            Self::BINARYPOINT_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+            UnimplementedField {
+                register: "ICC_BPR0_EL1",
+                field: "BinaryPoint",
+                requires: "FEAT_GICv3",
+            },
+        )]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+/// `ICC_BPR1_EL1` system register value.
+pub type IccBpr1El1 = IccBpr0El1;
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+bitflags! {
+    /// `ICC_CTLR_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct IccCtlrEl1: u64 {
+        /// `CBPR` bit.
+        const CBPR = 1 << 0;
+        /// `EOImode` bit.
+        const EOIMODE = 1 << 1;
+        /// `PMHE` bit.
+        const PMHE = 1 << 6;
+        /// `SEIS` bit.
+        const SEIS = 1 << 14;
+        /// `A3V` bit.
+        const A3V = 1 << 15;
+        /// `RSS` bit.
+        const RSS = 1 << 18;
+        /// `ExtRange` bit.
+        const EXTRANGE = 1 << 19;
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl IccCtlrEl1 {
+    /// Descriptor of the `CBPR` field, for use with [`FieldAccess`].
+    pub const CBPR_FIELD: Field<Self, bool, 0, 1> = Field::new("ICC_CTLR_EL1", "CBPR");
+    /// Descriptor of the `EOImode` field, for use with [`FieldAccess`].
+    pub const EOIMODE_FIELD: Field<Self, bool, 1, 1> = Field::new("ICC_CTLR_EL1", "EOImode");
+    /// Descriptor of the `PMHE` field, for use with [`FieldAccess`].
+    pub const PMHE_FIELD: Field<Self, bool, 6, 1> = Field::new("ICC_CTLR_EL1", "PMHE");
+    /// Descriptor of the `PRIbits` field, for use with [`FieldAccess`].
+    pub const PRIBITS_FIELD: Field<Self, u8, 8, 3> = Field::new("ICC_CTLR_EL1", "PRIbits");
+    /// Descriptor of the `IDbits` field, for use with [`FieldAccess`].
+    pub const IDBITS_FIELD: Field<Self, u8, 11, 3> = Field::new("ICC_CTLR_EL1", "IDbits");
+    /// Descriptor of the `SEIS` field, for use with [`FieldAccess`].
+    pub const SEIS_FIELD: Field<Self, bool, 14, 1> = Field::new("ICC_CTLR_EL1", "SEIS");
+    /// Descriptor of the `A3V` field, for use with [`FieldAccess`].
+    pub const A3V_FIELD: Field<Self, bool, 15, 1> = Field::new("ICC_CTLR_EL1", "A3V");
+    /// Descriptor of the `RSS` field, for use with [`FieldAccess`].
+    pub const RSS_FIELD: Field<Self, bool, 18, 1> = Field::new("ICC_CTLR_EL1", "RSS");
+    /// Descriptor of the `ExtRange` field, for use with [`FieldAccess`].
+    pub const EXTRANGE_FIELD: Field<Self, bool, 19, 1> = Field::new("ICC_CTLR_EL1", "ExtRange");
+
+    /// Returns the value of the `PRIbits` field.
+    pub const fn pribits(self) -> u8 {
+        Self::PRIBITS_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `PRIbits` field.
+    pub const fn set_pribits(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::PRIBITS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PRIbits` field set to the given value.
+    pub const fn with_pribits(mut self, value: u8) -> Self {
+        self.set_pribits(value);
+        self
+    }
+
+    /// Sets the value of the `PRIbits` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_pribits(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_pribits(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PRIbits` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_pribits(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::PRIBITS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `IDbits` field.
+    pub const fn idbits(self) -> u8 {
+        Self::IDBITS_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `IDbits` field.
+    pub const fn set_idbits(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::IDBITS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `IDbits` field set to the given value.
+    pub const fn with_idbits(mut self, value: u8) -> Self {
+        self.set_idbits(value);
+        self
+    }
+
+    /// Sets the value of the `IDbits` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_idbits(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_idbits(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `IDbits` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_idbits(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::IDBITS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl Display for IccCtlrEl1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("IccCtlrEl1");
+        let bits = self.bits();
+        s.field("CBPR", &Self::CBPR_FIELD.extract(bits));
+        s.field("EOImode", &Self::EOIMODE_FIELD.extract(bits));
+        s.field("PMHE", &Self::PMHE_FIELD.extract(bits));
+        s.field("PRIbits", &Self::PRIBITS_FIELD.extract(bits));
+        s.field("IDbits", &Self::IDBITS_FIELD.extract(bits));
+        s.field("SEIS", &Self::SEIS_FIELD.extract(bits));
+        s.field("A3V", &Self::A3V_FIELD.extract(bits));
+        s.field("RSS", &Self::RSS_FIELD.extract(bits));
+        s.field("ExtRange", &Self::EXTRANGE_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl defmt::Format for IccCtlrEl1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "IccCtlrEl1 {{ CBPR: {=u64}, EOImode: {=u64}, PMHE: {=u64}, PRIbits: {=u64}, IDbits: {=u64}, SEIS: {=u64}, A3V: {=u64}, RSS: {=u64}, ExtRange: {=u64} }}",
+            Self::CBPR_FIELD.extract(self.bits()),
+            Self::EOIMODE_FIELD.extract(self.bits()),
+            Self::PMHE_FIELD.extract(self.bits()),
+            Self::PRIBITS_FIELD.extract(self.bits()),
+            Self::IDBITS_FIELD.extract(self.bits()),
+            Self::SEIS_FIELD.extract(self.bits()),
+            Self::A3V_FIELD.extract(self.bits()),
+            Self::RSS_FIELD.extract(self.bits()),
+            Self::EXTRANGE_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl serde::Serialize for IccCtlrEl1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl<'de> serde::Deserialize<'de> for IccCtlrEl1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl IccCtlrEl1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("CBPR"),
+                old: Self::CBPR_FIELD.extract(self.bits()),
+                new: Self::CBPR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EOImode"),
+                old: Self::EOIMODE_FIELD.extract(self.bits()),
+                new: Self::EOIMODE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMHE"),
+                old: Self::PMHE_FIELD.extract(self.bits()),
+                new: Self::PMHE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PRIbits"),
+                old: Self::PRIBITS_FIELD.extract(self.bits()),
+                new: Self::PRIBITS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IDbits"),
+                old: Self::IDBITS_FIELD.extract(self.bits()),
+                new: Self::IDBITS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SEIS"),
+                old: Self::SEIS_FIELD.extract(self.bits()),
+                new: Self::SEIS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("A3V"),
+                old: Self::A3V_FIELD.extract(self.bits()),
+                new: Self::A3V_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("RSS"),
+                old: Self::RSS_FIELD.extract(self.bits()),
+                new: Self::RSS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ExtRange"),
+                old: Self::EXTRANGE_FIELD.extract(self.bits()),
+                new: Self::EXTRANGE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_0011_0000_0000_1011_1100,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_0011_0000_0000_1011_1100,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl IccCtlrEl1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl IccCtlrEl1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                Self::CBPR_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICC_CTLR_EL1",
+                    field: "CBPR",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::EOIMODE_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICC_CTLR_EL1",
+                    field: "EOImode",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::PMHE_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICC_CTLR_EL1",
+                    field: "PMHE",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::PRIBITS_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICC_CTLR_EL1",
+                    field: "PRIbits",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::IDBITS_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICC_CTLR_EL1",
+                    field: "IDbits",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::SEIS_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICC_CTLR_EL1",
+                    field: "SEIS",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::A3V_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICC_CTLR_EL1",
+                    field: "A3V",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::RSS_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICC_CTLR_EL1",
+                    field: "RSS",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::EXTRANGE_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICC_CTLR_EL1",
+                    field: "ExtRange",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(all(feature = "el3", feature = "feat-gic"))]
+bitflags! {
+    /// `ICC_CTLR_EL3` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct IccCtlrEl3: u64 {
+        /// `CBPR_EL1S` bit.
+        const CBPR_EL1S = 1 << 0;
+        /// `CBPR_EL1NS` bit.
+        const CBPR_EL1NS = 1 << 1;
+        /// `EOImode_EL3` bit.
+        const EOIMODE_EL3 = 1 << 2;
+        /// `EOImode_EL1S` bit.
+        const EOIMODE_EL1S = 1 << 3;
+        /// `EOImode_EL1NS` bit.
+        const EOIMODE_EL1NS = 1 << 4;
+        /// `RM` bit.
+        const RM = 1 << 5;
+        /// `PMHE` bit.
+        const PMHE = 1 << 6;
+        /// `SEIS` bit.
+        const SEIS = 1 << 14;
+        /// `A3V` bit.
+        const A3V = 1 << 15;
+        /// `nDS` bit.
+        const NDS = 1 << 17;
+        /// `RSS` bit.
+        const RSS = 1 << 18;
+        /// `ExtRange` bit.
+        const EXTRANGE = 1 << 19;
+    }
+}
+
+#[cfg(all(feature = "el3", feature = "feat-gic"))]
+impl IccCtlrEl3 {
+    /// Descriptor of the `CBPR_EL1S` field, for use with [`FieldAccess`].
+    pub const CBPR_EL1S_FIELD: Field<Self, bool, 0, 1> = Field::new("ICC_CTLR_EL3", "CBPR_EL1S");
+    /// Descriptor of the `CBPR_EL1NS` field, for use with [`FieldAccess`].
+    pub const CBPR_EL1NS_FIELD: Field<Self, bool, 1, 1> = Field::new("ICC_CTLR_EL3", "CBPR_EL1NS");
+    /// Descriptor of the `EOImode_EL3` field, for use with [`FieldAccess`].
+    pub const EOIMODE_EL3_FIELD: Field<Self, bool, 2, 1> =
+        Field::new("ICC_CTLR_EL3", "EOImode_EL3");
+    /// Descriptor of the `EOImode_EL1S` field, for use with [`FieldAccess`].
+    pub const EOIMODE_EL1S_FIELD: Field<Self, bool, 3, 1> =
+        Field::new("ICC_CTLR_EL3", "EOImode_EL1S");
+    /// Descriptor of the `EOImode_EL1NS` field, for use with [`FieldAccess`].
+    pub const EOIMODE_EL1NS_FIELD: Field<Self, bool, 4, 1> =
+        Field::new("ICC_CTLR_EL3", "EOImode_EL1NS");
+    /// Descriptor of the `RM` field, for use with [`FieldAccess`].
+    pub const RM_FIELD: Field<Self, bool, 5, 1> = Field::new("ICC_CTLR_EL3", "RM");
+    /// Descriptor of the `PMHE` field, for use with [`FieldAccess`].
+    pub const PMHE_FIELD: Field<Self, bool, 6, 1> = Field::new("ICC_CTLR_EL3", "PMHE");
+    /// Descriptor of the `PRIbits` field, for use with [`FieldAccess`].
+    pub const PRIBITS_FIELD: Field<Self, u8, 8, 3> = Field::new("ICC_CTLR_EL3", "PRIbits");
+    /// Descriptor of the `IDbits` field, for use with [`FieldAccess`].
+    pub const IDBITS_FIELD: Field<Self, u8, 11, 3> = Field::new("ICC_CTLR_EL3", "IDbits");
+    /// Descriptor of the `SEIS` field, for use with [`FieldAccess`].
+    pub const SEIS_FIELD: Field<Self, bool, 14, 1> = Field::new("ICC_CTLR_EL3", "SEIS");
+    /// Descriptor of the `A3V` field, for use with [`FieldAccess`].
+    pub const A3V_FIELD: Field<Self, bool, 15, 1> = Field::new("ICC_CTLR_EL3", "A3V");
+    /// Descriptor of the `nDS` field, for use with [`FieldAccess`].
+    pub const NDS_FIELD: Field<Self, bool, 17, 1> = Field::new("ICC_CTLR_EL3", "nDS");
+    /// Descriptor of the `RSS` field, for use with [`FieldAccess`].
+    pub const RSS_FIELD: Field<Self, bool, 18, 1> = Field::new("ICC_CTLR_EL3", "RSS");
+    /// Descriptor of the `ExtRange` field, for use with [`FieldAccess`].
+    pub const EXTRANGE_FIELD: Field<Self, bool, 19, 1> = Field::new("ICC_CTLR_EL3", "ExtRange");
+
+    /// Returns the value of the `PRIbits` field.
+    pub const fn pribits(self) -> u8 {
+        Self::PRIBITS_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `PRIbits` field.
+    pub const fn set_pribits(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::PRIBITS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PRIbits` field set to the given value.
+    pub const fn with_pribits(mut self, value: u8) -> Self {
+        self.set_pribits(value);
+        self
+    }
+
+    /// Sets the value of the `PRIbits` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_pribits(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_pribits(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PRIbits` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_pribits(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::PRIBITS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `IDbits` field.
+    pub const fn idbits(self) -> u8 {
+        Self::IDBITS_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `IDbits` field.
+    pub const fn set_idbits(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::IDBITS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `IDbits` field set to the given value.
+    pub const fn with_idbits(mut self, value: u8) -> Self {
+        self.set_idbits(value);
+        self
+    }
+
+    /// Sets the value of the `IDbits` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_idbits(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_idbits(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `IDbits` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_idbits(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::IDBITS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(all(feature = "el3", feature = "feat-gic"))]
+impl Display for IccCtlrEl3 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("IccCtlrEl3");
+        let bits = self.bits();
+        s.field("CBPR_EL1S", &Self::CBPR_EL1S_FIELD.extract(bits));
+        s.field("CBPR_EL1NS", &Self::CBPR_EL1NS_FIELD.extract(bits));
+        s.field("EOImode_EL3", &Self::EOIMODE_EL3_FIELD.extract(bits));
+        s.field("EOImode_EL1S", &Self::EOIMODE_EL1S_FIELD.extract(bits));
+        s.field("EOImode_EL1NS", &Self::EOIMODE_EL1NS_FIELD.extract(bits));
+        s.field("RM", &Self::RM_FIELD.extract(bits));
+        s.field("PMHE", &Self::PMHE_FIELD.extract(bits));
+        s.field("PRIbits", &Self::PRIBITS_FIELD.extract(bits));
+        s.field("IDbits", &Self::IDBITS_FIELD.extract(bits));
+        s.field("SEIS", &Self::SEIS_FIELD.extract(bits));
+        s.field("A3V", &Self::A3V_FIELD.extract(bits));
+        s.field("nDS", &Self::NDS_FIELD.extract(bits));
+        s.field("RSS", &Self::RSS_FIELD.extract(bits));
+        s.field("ExtRange", &Self::EXTRANGE_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(all(feature = "el3", feature = "feat-gic"))]
+impl defmt::Format for IccCtlrEl3 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "IccCtlrEl3 {{ CBPR_EL1S: {=u64}, CBPR_EL1NS: {=u64}, EOImode_EL3: {=u64}, EOImode_EL1S: {=u64}, EOImode_EL1NS: {=u64}, RM: {=u64}, PMHE: {=u64}, PRIbits: {=u64}, IDbits: {=u64}, SEIS: {=u64}, A3V: {=u64}, nDS: {=u64}, RSS: {=u64}, ExtRange: {=u64} }}",
+            Self::CBPR_EL1S_FIELD.extract(self.bits()),
+            Self::CBPR_EL1NS_FIELD.extract(self.bits()),
+            Self::EOIMODE_EL3_FIELD.extract(self.bits()),
+            Self::EOIMODE_EL1S_FIELD.extract(self.bits()),
+            Self::EOIMODE_EL1NS_FIELD.extract(self.bits()),
+            Self::RM_FIELD.extract(self.bits()),
+            Self::PMHE_FIELD.extract(self.bits()),
+            Self::PRIBITS_FIELD.extract(self.bits()),
+            Self::IDBITS_FIELD.extract(self.bits()),
+            Self::SEIS_FIELD.extract(self.bits()),
+            Self::A3V_FIELD.extract(self.bits()),
+            Self::NDS_FIELD.extract(self.bits()),
+            Self::RSS_FIELD.extract(self.bits()),
+            Self::EXTRANGE_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el3", feature = "feat-gic"))]
+impl serde::Serialize for IccCtlrEl3 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el3", feature = "feat-gic"))]
+impl<'de> serde::Deserialize<'de> for IccCtlrEl3 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(all(feature = "el3", feature = "feat-gic"))]
+impl IccCtlrEl3 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("CBPR_EL1S"),
+                old: Self::CBPR_EL1S_FIELD.extract(self.bits()),
+                new: Self::CBPR_EL1S_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CBPR_EL1NS"),
+                old: Self::CBPR_EL1NS_FIELD.extract(self.bits()),
+                new: Self::CBPR_EL1NS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EOImode_EL3"),
+                old: Self::EOIMODE_EL3_FIELD.extract(self.bits()),
+                new: Self::EOIMODE_EL3_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EOImode_EL1S"),
+                old: Self::EOIMODE_EL1S_FIELD.extract(self.bits()),
+                new: Self::EOIMODE_EL1S_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EOImode_EL1NS"),
+                old: Self::EOIMODE_EL1NS_FIELD.extract(self.bits()),
+                new: Self::EOIMODE_EL1NS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("RM"),
+                old: Self::RM_FIELD.extract(self.bits()),
+                new: Self::RM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMHE"),
+                old: Self::PMHE_FIELD.extract(self.bits()),
+                new: Self::PMHE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PRIbits"),
+                old: Self::PRIBITS_FIELD.extract(self.bits()),
+                new: Self::PRIBITS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IDbits"),
+                old: Self::IDBITS_FIELD.extract(self.bits()),
+                new: Self::IDBITS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SEIS"),
+                old: Self::SEIS_FIELD.extract(self.bits()),
+                new: Self::SEIS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("A3V"),
+                old: Self::A3V_FIELD.extract(self.bits()),
+                new: Self::A3V_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nDS"),
+                old: Self::NDS_FIELD.extract(self.bits()),
+                new: Self::NDS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("RSS"),
+                old: Self::RSS_FIELD.extract(self.bits()),
+                new: Self::RSS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ExtRange"),
+                old: Self::EXTRANGE_FIELD.extract(self.bits()),
+                new: Self::EXTRANGE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_0001_0000_0000_1000_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_0001_0000_0000_1000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(all(feature = "el3", feature = "feat-gic"))]
+impl IccCtlrEl3 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(all(feature = "el3", feature = "feat-gic"))]
+impl IccCtlrEl3 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                Self::CBPR_EL1S_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICC_CTLR_EL3",
+                    field: "CBPR_EL1S",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::CBPR_EL1NS_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICC_CTLR_EL3",
+                    field: "CBPR_EL1NS",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::EOIMODE_EL3_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICC_CTLR_EL3",
+                    field: "EOImode_EL3",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::EOIMODE_EL1S_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICC_CTLR_EL3",
+                    field: "EOImode_EL1S",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::EOIMODE_EL1NS_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICC_CTLR_EL3",
+                    field: "EOImode_EL1NS",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::RM_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICC_CTLR_EL3",
+                    field: "RM",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::PMHE_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICC_CTLR_EL3",
+                    field: "PMHE",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::PRIBITS_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICC_CTLR_EL3",
+                    field: "PRIbits",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::IDBITS_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICC_CTLR_EL3",
+                    field: "IDbits",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::SEIS_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICC_CTLR_EL3",
+                    field: "SEIS",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::A3V_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICC_CTLR_EL3",
+                    field: "A3V",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::NDS_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICC_CTLR_EL3",
+                    field: "nDS",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::RSS_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICC_CTLR_EL3",
+                    field: "RSS",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::EXTRANGE_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICC_CTLR_EL3",
+                    field: "ExtRange",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+bitflags! {
+    /// `ICC_DIR_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct IccDirEl1: u64 {
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl IccDirEl1 {
+    /// Descriptor of the `INTID` field, for use with [`FieldAccess`].
+    pub const INTID_FIELD: Field<Self, u32, 0, 24> = Field::new("ICC_DIR_EL1", "INTID");
+
+    /// Returns the value of the `INTID` field.
+    pub const fn intid(self) -> u32 {
+        Self::INTID_FIELD.extract(self.bits()) as u32
+    }
+
+    /// Sets the value of the `INTID` field.
+    pub const fn set_intid(&mut self, value: u32) {
+        *self = Self::from_bits_retain(Self::INTID_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `INTID` field set to the given value.
+    pub const fn with_intid(mut self, value: u32) -> Self {
+        self.set_intid(value);
+        self
+    }
+
+    /// Sets the value of the `INTID` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_intid(&mut self, value: u32) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_intid(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `INTID` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_intid(self, value: u32) -> Result<Self, FieldOverflow> {
+        match Self::INTID_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl Display for IccDirEl1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("IccDirEl1");
+        let bits = self.bits();
+        s.field(
+            "INTID",
+            &format_args!("{:#x}", Self::INTID_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl defmt::Format for IccDirEl1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "IccDirEl1 {{ INTID: {=u64:#x} }}",
+            Self::INTID_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl serde::Serialize for IccDirEl1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl<'de> serde::Deserialize<'de> for IccDirEl1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl IccDirEl1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("INTID"),
+                old: Self::INTID_FIELD.extract(self.bits()),
+                new: Self::INTID_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_0000_0000_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_0000_0000_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl IccDirEl1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl IccDirEl1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [(
+            Self::INTID_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+            UnimplementedField {
+                register: "ICC_DIR_EL1",
+                field: "INTID",
+                requires: "FEAT_GICv3",
+            },
+        )]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+/// `ICC_EOIR0_EL1` system register value.
+pub type IccEoir0El1 = IccDirEl1;
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+/// `ICC_EOIR1_EL1` system register value.
+pub type IccEoir1El1 = IccDirEl1;
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+bitflags! {
+    /// `ICC_HPPIR0_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct IccHppir0El1: u64 {
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl IccHppir0El1 {
+    /// Descriptor of the `INTID` field, for use with [`FieldAccess`].
+    pub const INTID_FIELD: Field<Self, u32, 0, 24> = Field::new("ICC_HPPIR0_EL1", "INTID");
+
+    /// Returns the value of the `INTID` field.
+    pub const fn intid(self) -> u32 {
+        Self::INTID_FIELD.extract(self.bits()) as u32
+    }
+
+    /// Sets the value of the `INTID` field.
+    pub const fn set_intid(&mut self, value: u32) {
+        *self = Self::from_bits_retain(Self::INTID_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `INTID` field set to the given value.
+    pub const fn with_intid(mut self, value: u32) -> Self {
+        self.set_intid(value);
+        self
+    }
+
+    /// Sets the value of the `INTID` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_intid(&mut self, value: u32) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_intid(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `INTID` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_intid(self, value: u32) -> Result<Self, FieldOverflow> {
+        match Self::INTID_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl Display for IccHppir0El1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("IccHppir0El1");
+        let bits = self.bits();
+        s.field(
+            "INTID",
+            &format_args!("{:#x}", Self::INTID_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl defmt::Format for IccHppir0El1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "IccHppir0El1 {{ INTID: {=u64:#x} }}",
+            Self::INTID_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl serde::Serialize for IccHppir0El1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl<'de> serde::Deserialize<'de> for IccHppir0El1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl IccHppir0El1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("INTID"),
+                old: Self::INTID_FIELD.extract(self.bits()),
+                new: Self::INTID_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_0000_0000_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_0000_0000_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl IccHppir0El1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl IccHppir0El1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [(
+            Self::INTID_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+            UnimplementedField {
+                register: "ICC_HPPIR0_EL1",
+                field: "INTID",
+                requires: "FEAT_GICv3",
+            },
+        )]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+/// `ICC_HPPIR1_EL1` system register value.
+pub type IccHppir1El1 = IccHppir0El1;
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+/// `ICC_IAR0_EL1` system register value.
+pub type IccIar0El1 = IccHppir0El1;
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+/// `ICC_IAR1_EL1` system register value.
+pub type IccIar1El1 = IccHppir0El1;
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+bitflags! {
+    /// `ICC_IGRPEN0_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct IccIgrpen0El1: u64 {
+        /// `Enable` bit.
+        const ENABLE = 1 << 0;
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl IccIgrpen0El1 {
+    /// Descriptor of the `Enable` field, for use with [`FieldAccess`].
+    pub const ENABLE_FIELD: Field<Self, bool, 0, 1> = Field::new("ICC_IGRPEN0_EL1", "Enable");
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl Display for IccIgrpen0El1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("IccIgrpen0El1");
+        let bits = self.bits();
+        s.field("Enable", &Self::ENABLE_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl defmt::Format for IccIgrpen0El1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "IccIgrpen0El1 {{ Enable: {=u64} }}",
+            Self::ENABLE_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl serde::Serialize for IccIgrpen0El1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl<'de> serde::Deserialize<'de> for IccIgrpen0El1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl IccIgrpen0El1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("Enable"),
+                old: Self::ENABLE_FIELD.extract(self.bits()),
+                new: Self::ENABLE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1110,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1110,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl IccIgrpen0El1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl IccIgrpen0El1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [(
+            Self::ENABLE_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+            UnimplementedField {
+                register: "ICC_IGRPEN0_EL1",
+                field: "Enable",
+                requires: "FEAT_GICv3",
+            },
+        )]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+/// `ICC_IGRPEN1_EL1` system register value.
+pub type IccIgrpen1El1 = IccIgrpen0El1;
+
+#[cfg(all(feature = "el3", feature = "feat-gic"))]
+bitflags! {
+    /// `ICC_IGRPEN1_EL3` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct IccIgrpen1El3: u64 {
+        /// `EnableGrp1NS` bit.
+        const ENABLEGRP1NS = 1 << 0;
+        /// `EnableGrp1S` bit.
+        const ENABLEGRP1S = 1 << 1;
+    }
+}
+
+#[cfg(all(feature = "el3", feature = "feat-gic"))]
+impl IccIgrpen1El3 {
+    /// Descriptor of the `EnableGrp1NS` field, for use with [`FieldAccess`].
+    pub const ENABLEGRP1NS_FIELD: Field<Self, bool, 0, 1> =
+        Field::new("ICC_IGRPEN1_EL3", "EnableGrp1NS");
+    /// Descriptor of the `EnableGrp1S` field, for use with [`FieldAccess`].
+    pub const ENABLEGRP1S_FIELD: Field<Self, bool, 1, 1> =
+        Field::new("ICC_IGRPEN1_EL3", "EnableGrp1S");
+}
+
+#[cfg(all(feature = "el3", feature = "feat-gic"))]
+impl Display for IccIgrpen1El3 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("IccIgrpen1El3");
+        let bits = self.bits();
+        s.field("EnableGrp1NS", &Self::ENABLEGRP1NS_FIELD.extract(bits));
+        s.field("EnableGrp1S", &Self::ENABLEGRP1S_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(all(feature = "el3", feature = "feat-gic"))]
+impl defmt::Format for IccIgrpen1El3 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "IccIgrpen1El3 {{ EnableGrp1NS: {=u64}, EnableGrp1S: {=u64} }}",
+            Self::ENABLEGRP1NS_FIELD.extract(self.bits()),
+            Self::ENABLEGRP1S_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el3", feature = "feat-gic"))]
+impl serde::Serialize for IccIgrpen1El3 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el3", feature = "feat-gic"))]
+impl<'de> serde::Deserialize<'de> for IccIgrpen1El3 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(all(feature = "el3", feature = "feat-gic"))]
+impl IccIgrpen1El3 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("EnableGrp1NS"),
+                old: Self::ENABLEGRP1NS_FIELD.extract(self.bits()),
+                new: Self::ENABLEGRP1NS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnableGrp1S"),
+                old: Self::ENABLEGRP1S_FIELD.extract(self.bits()),
+                new: Self::ENABLEGRP1S_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1100,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1100,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(all(feature = "el3", feature = "feat-gic"))]
+impl IccIgrpen1El3 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(all(feature = "el3", feature = "feat-gic"))]
+impl IccIgrpen1El3 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                Self::ENABLEGRP1NS_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICC_IGRPEN1_EL3",
+                    field: "EnableGrp1NS",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::ENABLEGRP1S_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICC_IGRPEN1_EL3",
+                    field: "EnableGrp1S",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+/// `ICC_NMIAR1_EL1` system register value.
+pub type IccNmiar1El1 = IccHppir0El1;
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+bitflags! {
+    /// `ICC_PMR_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct IccPmrEl1: u64 {
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl IccPmrEl1 {
+    /// Descriptor of the `Priority` field, for use with [`FieldAccess`].
+    pub const PRIORITY_FIELD: Field<Self, u8, 0, 8> = Field::new("ICC_PMR_EL1", "Priority");
+
+    /// Returns the value of the `Priority` field.
+    pub const fn priority(self) -> u8 {
+        Self::PRIORITY_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `Priority` field.
+    pub const fn set_priority(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::PRIORITY_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `Priority` field set to the given value.
+    pub const fn with_priority(mut self, value: u8) -> Self {
+        self.set_priority(value);
+        self
+    }
+
+    /// Sets the value of the `Priority` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_priority(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_priority(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `Priority` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_priority(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::PRIORITY_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl Display for IccPmrEl1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("IccPmrEl1");
+        let bits = self.bits();
+        s.field("Priority", &Self::PRIORITY_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl defmt::Format for IccPmrEl1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "IccPmrEl1 {{ Priority: {=u64} }}",
+            Self::PRIORITY_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl serde::Serialize for IccPmrEl1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl<'de> serde::Deserialize<'de> for IccPmrEl1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl IccPmrEl1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("Priority"),
+                old: Self::PRIORITY_FIELD.extract(self.bits()),
+                new: Self::PRIORITY_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_0000_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl IccPmrEl1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl IccPmrEl1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [(
+            Self::PRIORITY_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+            UnimplementedField {
+                register: "ICC_PMR_EL1",
+                field: "Priority",
+                requires: "FEAT_GICv3",
+            },
+        )]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+bitflags! {
+    /// `ICC_RPR_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct IccRprEl1: u64 {
+        /// `NMI_NS` bit.
+        const NMI_NS = 1 << 62;
+        /// `NMI` bit.
+        const NMI = 1 << 63;
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl IccRprEl1 {
+    /// Descriptor of the `Priority` field, for use with [`FieldAccess`].
+    pub const PRIORITY_FIELD: Field<Self, u8, 0, 8> = Field::new("ICC_RPR_EL1", "Priority");
+    /// Descriptor of the `NMI_NS` field, for use with [`FieldAccess`].
+    pub const NMI_NS_FIELD: Field<Self, bool, 62, 1> = Field::new("ICC_RPR_EL1", "NMI_NS");
+    /// Descriptor of the `NMI` field, for use with [`FieldAccess`].
+    pub const NMI_FIELD: Field<Self, bool, 63, 1> = Field::new("ICC_RPR_EL1", "NMI");
+
+    /// Returns the value of the `Priority` field.
+    pub const fn priority(self) -> u8 {
+        Self::PRIORITY_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `Priority` field.
+    pub const fn set_priority(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::PRIORITY_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `Priority` field set to the given value.
+    pub const fn with_priority(mut self, value: u8) -> Self {
+        self.set_priority(value);
+        self
+    }
+
+    /// Sets the value of the `Priority` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_priority(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_priority(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `Priority` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_priority(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::PRIORITY_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl Display for IccRprEl1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("IccRprEl1");
+        let bits = self.bits();
+        s.field("Priority", &Self::PRIORITY_FIELD.extract(bits));
+        s.field("NMI_NS", &Self::NMI_NS_FIELD.extract(bits));
+        s.field("NMI", &Self::NMI_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl defmt::Format for IccRprEl1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "IccRprEl1 {{ Priority: {=u64}, NMI_NS: {=u64}, NMI: {=u64} }}",
+            Self::PRIORITY_FIELD.extract(self.bits()),
+            Self::NMI_NS_FIELD.extract(self.bits()),
+            Self::NMI_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl serde::Serialize for IccRprEl1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl<'de> serde::Deserialize<'de> for IccRprEl1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl IccRprEl1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("Priority"),
+                old: Self::PRIORITY_FIELD.extract(self.bits()),
+                new: Self::PRIORITY_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("NMI_NS"),
+                old: Self::NMI_NS_FIELD.extract(self.bits()),
+                new: Self::NMI_NS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("NMI"),
+                old: Self::NMI_FIELD.extract(self.bits()),
+                new: Self::NMI_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b11_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_0000_0000,
+                new: other.bits() & 0b11_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl IccRprEl1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl IccRprEl1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                Self::PRIORITY_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICC_RPR_EL1",
+                    field: "Priority",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::NMI_NS_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICC_RPR_EL1",
+                    field: "NMI_NS",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::NMI_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICC_RPR_EL1",
+                    field: "NMI",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+/// `ICC_SGI0R_EL1` system register value.
+pub type IccSgi0rEl1 = IccAsgi1rEl1;
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+/// `ICC_SGI1R_EL1` system register value.
+pub type IccSgi1rEl1 = IccAsgi1rEl1;
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+bitflags! {
+    /// `ICC_SRE_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct IccSreEl1: u64 {
+        /// Enable the system register interface.
+        const SRE = 1 << 0;
+        /// Disable FIQ bypass.
+        const DFB = 1 << 1;
+        /// Disable IRQ bypass.
+        const DIB = 1 << 2;
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl IccSreEl1 {
+    /// Descriptor of the `SRE` field, for use with [`FieldAccess`].
+    pub const SRE_FIELD: Field<Self, bool, 0, 1> = Field::new("ICC_SRE_EL1", "SRE");
+    /// Descriptor of the `DFB` field, for use with [`FieldAccess`].
+    pub const DFB_FIELD: Field<Self, bool, 1, 1> = Field::new("ICC_SRE_EL1", "DFB");
+    /// Descriptor of the `DIB` field, for use with [`FieldAccess`].
+    pub const DIB_FIELD: Field<Self, bool, 2, 1> = Field::new("ICC_SRE_EL1", "DIB");
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl Display for IccSreEl1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("IccSreEl1");
+        let bits = self.bits();
+        s.field("SRE", &Self::SRE_FIELD.extract(bits));
+        s.field("DFB", &Self::DFB_FIELD.extract(bits));
+        s.field("DIB", &Self::DIB_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl defmt::Format for IccSreEl1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "IccSreEl1 {{ SRE: {=u64}, DFB: {=u64}, DIB: {=u64} }}",
+            Self::SRE_FIELD.extract(self.bits()),
+            Self::DFB_FIELD.extract(self.bits()),
+            Self::DIB_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl serde::Serialize for IccSreEl1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl<'de> serde::Deserialize<'de> for IccSreEl1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl IccSreEl1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("SRE"),
+                old: Self::SRE_FIELD.extract(self.bits()),
+                new: Self::SRE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DFB"),
+                old: Self::DFB_FIELD.extract(self.bits()),
+                new: Self::DFB_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DIB"),
+                old: Self::DIB_FIELD.extract(self.bits()),
+                new: Self::DIB_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl IccSreEl1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-gic"))]
+impl IccSreEl1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                Self::SRE_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICC_SRE_EL1",
+                    field: "SRE",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::DFB_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICC_SRE_EL1",
+                    field: "DFB",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::DIB_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICC_SRE_EL1",
+                    field: "DIB",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-gic"))]
+bitflags! {
+    /// `ICC_SRE_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct IccSreEl2: u64 {
+        /// Enable the system register interface.
+        const SRE = 1 << 0;
+        /// Disable FIQ bypass.
+        const DFB = 1 << 1;
+        /// Disable IRQ bypass.
+        const DIB = 1 << 2;
+        /// Enable lower exception level access.
+        const ENABLE = 1 << 3;
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-gic"))]
+impl IccSreEl2 {
+    /// Descriptor of the `SRE` field, for use with [`FieldAccess`].
+    pub const SRE_FIELD: Field<Self, bool, 0, 1> = Field::new("ICC_SRE_EL2", "SRE");
+    /// Descriptor of the `DFB` field, for use with [`FieldAccess`].
+    pub const DFB_FIELD: Field<Self, bool, 1, 1> = Field::new("ICC_SRE_EL2", "DFB");
+    /// Descriptor of the `DIB` field, for use with [`FieldAccess`].
+    pub const DIB_FIELD: Field<Self, bool, 2, 1> = Field::new("ICC_SRE_EL2", "DIB");
+    /// Descriptor of the `Enable` field, for use with [`FieldAccess`].
+    pub const ENABLE_FIELD: Field<Self, bool, 3, 1> = Field::new("ICC_SRE_EL2", "Enable");
+}
+
+#[cfg(all(feature = "el2", feature = "feat-gic"))]
+impl Display for IccSreEl2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("IccSreEl2");
+        let bits = self.bits();
+        s.field("SRE", &Self::SRE_FIELD.extract(bits));
+        s.field("DFB", &Self::DFB_FIELD.extract(bits));
+        s.field("DIB", &Self::DIB_FIELD.extract(bits));
+        s.field("Enable", &Self::ENABLE_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(all(feature = "el2", feature = "feat-gic"))]
+impl defmt::Format for IccSreEl2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "IccSreEl2 {{ SRE: {=u64}, DFB: {=u64}, DIB: {=u64}, Enable: {=u64} }}",
+            Self::SRE_FIELD.extract(self.bits()),
+            Self::DFB_FIELD.extract(self.bits()),
+            Self::DIB_FIELD.extract(self.bits()),
+            Self::ENABLE_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el2", feature = "feat-gic"))]
+impl serde::Serialize for IccSreEl2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el2", feature = "feat-gic"))]
+impl<'de> serde::Deserialize<'de> for IccSreEl2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-gic"))]
+impl IccSreEl2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("SRE"),
+                old: Self::SRE_FIELD.extract(self.bits()),
+                new: Self::SRE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DFB"),
+                old: Self::DFB_FIELD.extract(self.bits()),
+                new: Self::DFB_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DIB"),
+                old: Self::DIB_FIELD.extract(self.bits()),
+                new: Self::DIB_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("Enable"),
+                old: Self::ENABLE_FIELD.extract(self.bits()),
+                new: Self::ENABLE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-gic"))]
+impl IccSreEl2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-gic"))]
+impl IccSreEl2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                Self::SRE_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICC_SRE_EL2",
+                    field: "SRE",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::DFB_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICC_SRE_EL2",
+                    field: "DFB",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::DIB_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICC_SRE_EL2",
+                    field: "DIB",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::ENABLE_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICC_SRE_EL2",
+                    field: "Enable",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(all(feature = "el3", feature = "feat-gic"))]
+bitflags! {
+    /// `ICC_SRE_EL3` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct IccSreEl3: u64 {
+        /// Enable the system register interface.
+        const SRE = 1 << 0;
+        /// Disable FIQ bypass.
+        const DFB = 1 << 1;
+        /// Disable IRQ bypass.
+        const DIB = 1 << 2;
+        /// Enable lower exception level access.
+        const ENABLE = 1 << 3;
+    }
+}
+
+#[cfg(all(feature = "el3", feature = "feat-gic"))]
+impl IccSreEl3 {
+    /// Descriptor of the `SRE` field, for use with [`FieldAccess`].
+    pub const SRE_FIELD: Field<Self, bool, 0, 1> = Field::new("ICC_SRE_EL3", "SRE");
+    /// Descriptor of the `DFB` field, for use with [`FieldAccess`].
+    pub const DFB_FIELD: Field<Self, bool, 1, 1> = Field::new("ICC_SRE_EL3", "DFB");
+    /// Descriptor of the `DIB` field, for use with [`FieldAccess`].
+    pub const DIB_FIELD: Field<Self, bool, 2, 1> = Field::new("ICC_SRE_EL3", "DIB");
+    /// Descriptor of the `Enable` field, for use with [`FieldAccess`].
+    pub const ENABLE_FIELD: Field<Self, bool, 3, 1> = Field::new("ICC_SRE_EL3", "Enable");
+}
+
+#[cfg(all(feature = "el3", feature = "feat-gic"))]
+impl Display for IccSreEl3 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("IccSreEl3");
+        let bits = self.bits();
+        s.field("SRE", &Self::SRE_FIELD.extract(bits));
+        s.field("DFB", &Self::DFB_FIELD.extract(bits));
+        s.field("DIB", &Self::DIB_FIELD.extract(bits));
+        s.field("Enable", &Self::ENABLE_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(all(feature = "el3", feature = "feat-gic"))]
+impl defmt::Format for IccSreEl3 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "IccSreEl3 {{ SRE: {=u64}, DFB: {=u64}, DIB: {=u64}, Enable: {=u64} }}",
+            Self::SRE_FIELD.extract(self.bits()),
+            Self::DFB_FIELD.extract(self.bits()),
+            Self::DIB_FIELD.extract(self.bits()),
+            Self::ENABLE_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el3", feature = "feat-gic"))]
+impl serde::Serialize for IccSreEl3 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el3", feature = "feat-gic"))]
+impl<'de> serde::Deserialize<'de> for IccSreEl3 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(all(feature = "el3", feature = "feat-gic"))]
+impl IccSreEl3 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("SRE"),
+                old: Self::SRE_FIELD.extract(self.bits()),
+                new: Self::SRE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DFB"),
+                old: Self::DFB_FIELD.extract(self.bits()),
+                new: Self::DFB_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DIB"),
+                old: Self::DIB_FIELD.extract(self.bits()),
+                new: Self::DIB_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("Enable"),
+                old: Self::ENABLE_FIELD.extract(self.bits()),
+                new: Self::ENABLE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(all(feature = "el3", feature = "feat-gic"))]
+impl IccSreEl3 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(all(feature = "el3", feature = "feat-gic"))]
+impl IccSreEl3 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                Self::SRE_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICC_SRE_EL3",
+                    field: "SRE",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::DFB_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICC_SRE_EL3",
+                    field: "DFB",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::DIB_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICC_SRE_EL3",
+                    field: "DIB",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::ENABLE_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICC_SRE_EL3",
+                    field: "Enable",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-gic"))]
+bitflags! {
+    /// `ICH_HCR_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct IchHcrEl2: u64 {
+        /// `En` bit.
+        const EN = 1 << 0;
+        /// `UIE` bit.
+        const UIE = 1 << 1;
+        /// `LRENPIE` bit.
+        const LRENPIE = 1 << 2;
+        /// `NPIE` bit.
+        const NPIE = 1 << 3;
+        /// `VGrp0EIE` bit.
+        const VGRP0EIE = 1 << 4;
+        /// `VGrp0DIE` bit.
+        const VGRP0DIE = 1 << 5;
+        /// `VGrp1EIE` bit.
+        const VGRP1EIE = 1 << 6;
+        /// `VGrp1DIE` bit.
+        const VGRP1DIE = 1 << 7;
+        /// `vSGIEOICount` bit.
+        const VSGIEOICOUNT = 1 << 8;
+        /// `TC` bit.
+        const TC = 1 << 10;
+        /// `TALL0` bit.
+        const TALL0 = 1 << 11;
+        /// `TALL1` bit.
+        const TALL1 = 1 << 12;
+        /// `TSEI` bit.
+        const TSEI = 1 << 13;
+        /// `TDIR` bit.
+        const TDIR = 1 << 14;
+        /// `DVIM` bit.
+        const DVIM = 1 << 15;
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-gic"))]
+impl IchHcrEl2 {
+    /// Descriptor of the `En` field, for use with [`FieldAccess`].
+    pub const EN_FIELD: Field<Self, bool, 0, 1> = Field::new("ICH_HCR_EL2", "En");
+    /// Descriptor of the `UIE` field, for use with [`FieldAccess`].
+    pub const UIE_FIELD: Field<Self, bool, 1, 1> = Field::new("ICH_HCR_EL2", "UIE");
+    /// Descriptor of the `LRENPIE` field, for use with [`FieldAccess`].
+    pub const LRENPIE_FIELD: Field<Self, bool, 2, 1> = Field::new("ICH_HCR_EL2", "LRENPIE");
+    /// Descriptor of the `NPIE` field, for use with [`FieldAccess`].
+    pub const NPIE_FIELD: Field<Self, bool, 3, 1> = Field::new("ICH_HCR_EL2", "NPIE");
+    /// Descriptor of the `VGrp0EIE` field, for use with [`FieldAccess`].
+    pub const VGRP0EIE_FIELD: Field<Self, bool, 4, 1> = Field::new("ICH_HCR_EL2", "VGrp0EIE");
+    /// Descriptor of the `VGrp0DIE` field, for use with [`FieldAccess`].
+    pub const VGRP0DIE_FIELD: Field<Self, bool, 5, 1> = Field::new("ICH_HCR_EL2", "VGrp0DIE");
+    /// Descriptor of the `VGrp1EIE` field, for use with [`FieldAccess`].
+    pub const VGRP1EIE_FIELD: Field<Self, bool, 6, 1> = Field::new("ICH_HCR_EL2", "VGrp1EIE");
+    /// Descriptor of the `VGrp1DIE` field, for use with [`FieldAccess`].
+    pub const VGRP1DIE_FIELD: Field<Self, bool, 7, 1> = Field::new("ICH_HCR_EL2", "VGrp1DIE");
+    /// Descriptor of the `vSGIEOICount` field, for use with [`FieldAccess`].
+    pub const VSGIEOICOUNT_FIELD: Field<Self, bool, 8, 1> =
+        Field::new("ICH_HCR_EL2", "vSGIEOICount");
+    /// Descriptor of the `TC` field, for use with [`FieldAccess`].
+    pub const TC_FIELD: Field<Self, bool, 10, 1> = Field::new("ICH_HCR_EL2", "TC");
+    /// Descriptor of the `TALL0` field, for use with [`FieldAccess`].
+    pub const TALL0_FIELD: Field<Self, bool, 11, 1> = Field::new("ICH_HCR_EL2", "TALL0");
+    /// Descriptor of the `TALL1` field, for use with [`FieldAccess`].
+    pub const TALL1_FIELD: Field<Self, bool, 12, 1> = Field::new("ICH_HCR_EL2", "TALL1");
+    /// Descriptor of the `TSEI` field, for use with [`FieldAccess`].
+    pub const TSEI_FIELD: Field<Self, bool, 13, 1> = Field::new("ICH_HCR_EL2", "TSEI");
+    /// Descriptor of the `TDIR` field, for use with [`FieldAccess`].
+    pub const TDIR_FIELD: Field<Self, bool, 14, 1> = Field::new("ICH_HCR_EL2", "TDIR");
+    /// Descriptor of the `DVIM` field, for use with [`FieldAccess`].
+    pub const DVIM_FIELD: Field<Self, bool, 15, 1> = Field::new("ICH_HCR_EL2", "DVIM");
+    /// Descriptor of the `EOIcount` field, for use with [`FieldAccess`].
+    pub const EOICOUNT_FIELD: Field<Self, u8, 27, 5> = Field::new("ICH_HCR_EL2", "EOIcount");
+
+    /// Returns the value of the `EOIcount` field.
+    pub const fn eoicount(self) -> u8 {
+        Self::EOICOUNT_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `EOIcount` field.
+    pub const fn set_eoicount(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::EOICOUNT_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `EOIcount` field set to the given value.
+    pub const fn with_eoicount(mut self, value: u8) -> Self {
+        self.set_eoicount(value);
+        self
+    }
+
+    /// Sets the value of the `EOIcount` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_eoicount(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_eoicount(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `EOIcount` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_eoicount(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::EOICOUNT_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-gic"))]
+impl Display for IchHcrEl2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("IchHcrEl2");
+        let bits = self.bits();
+        s.field("En", &Self::EN_FIELD.extract(bits));
+        s.field("UIE", &Self::UIE_FIELD.extract(bits));
+        s.field("LRENPIE", &Self::LRENPIE_FIELD.extract(bits));
+        s.field("NPIE", &Self::NPIE_FIELD.extract(bits));
+        s.field("VGrp0EIE", &Self::VGRP0EIE_FIELD.extract(bits));
+        s.field("VGrp0DIE", &Self::VGRP0DIE_FIELD.extract(bits));
+        s.field("VGrp1EIE", &Self::VGRP1EIE_FIELD.extract(bits));
+        s.field("VGrp1DIE", &Self::VGRP1DIE_FIELD.extract(bits));
+        s.field("vSGIEOICount", &Self::VSGIEOICOUNT_FIELD.extract(bits));
+        s.field("TC", &Self::TC_FIELD.extract(bits));
+        s.field("TALL0", &Self::TALL0_FIELD.extract(bits));
+        s.field("TALL1", &Self::TALL1_FIELD.extract(bits));
+        s.field("TSEI", &Self::TSEI_FIELD.extract(bits));
+        s.field("TDIR", &Self::TDIR_FIELD.extract(bits));
+        s.field("DVIM", &Self::DVIM_FIELD.extract(bits));
+        s.field("EOIcount", &Self::EOICOUNT_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(all(feature = "el2", feature = "feat-gic"))]
+impl defmt::Format for IchHcrEl2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "IchHcrEl2 {{ En: {=u64}, UIE: {=u64}, LRENPIE: {=u64}, NPIE: {=u64}, VGrp0EIE: {=u64}, VGrp0DIE: {=u64}, VGrp1EIE: {=u64}, VGrp1DIE: {=u64}, vSGIEOICount: {=u64}, TC: {=u64}, TALL0: {=u64}, TALL1: {=u64}, TSEI: {=u64}, TDIR: {=u64}, DVIM: {=u64}, EOIcount: {=u64} }}",
+            Self::EN_FIELD.extract(self.bits()),
+            Self::UIE_FIELD.extract(self.bits()),
+            Self::LRENPIE_FIELD.extract(self.bits()),
+            Self::NPIE_FIELD.extract(self.bits()),
+            Self::VGRP0EIE_FIELD.extract(self.bits()),
+            Self::VGRP0DIE_FIELD.extract(self.bits()),
+            Self::VGRP1EIE_FIELD.extract(self.bits()),
+            Self::VGRP1DIE_FIELD.extract(self.bits()),
+            Self::VSGIEOICOUNT_FIELD.extract(self.bits()),
+            Self::TC_FIELD.extract(self.bits()),
+            Self::TALL0_FIELD.extract(self.bits()),
+            Self::TALL1_FIELD.extract(self.bits()),
+            Self::TSEI_FIELD.extract(self.bits()),
+            Self::TDIR_FIELD.extract(self.bits()),
+            Self::DVIM_FIELD.extract(self.bits()),
+            Self::EOICOUNT_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el2", feature = "feat-gic"))]
+impl serde::Serialize for IchHcrEl2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el2", feature = "feat-gic"))]
+impl<'de> serde::Deserialize<'de> for IchHcrEl2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-gic"))]
+impl IchHcrEl2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("En"),
+                old: Self::EN_FIELD.extract(self.bits()),
+                new: Self::EN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("UIE"),
+                old: Self::UIE_FIELD.extract(self.bits()),
+                new: Self::UIE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("LRENPIE"),
+                old: Self::LRENPIE_FIELD.extract(self.bits()),
+                new: Self::LRENPIE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("NPIE"),
+                old: Self::NPIE_FIELD.extract(self.bits()),
+                new: Self::NPIE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VGrp0EIE"),
+                old: Self::VGRP0EIE_FIELD.extract(self.bits()),
+                new: Self::VGRP0EIE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VGrp0DIE"),
+                old: Self::VGRP0DIE_FIELD.extract(self.bits()),
+                new: Self::VGRP0DIE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VGrp1EIE"),
+                old: Self::VGRP1EIE_FIELD.extract(self.bits()),
+                new: Self::VGRP1EIE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VGrp1DIE"),
+                old: Self::VGRP1DIE_FIELD.extract(self.bits()),
+                new: Self::VGRP1DIE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("vSGIEOICount"),
+                old: Self::VSGIEOICOUNT_FIELD.extract(self.bits()),
+                new: Self::VSGIEOICOUNT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TC"),
+                old: Self::TC_FIELD.extract(self.bits()),
+                new: Self::TC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TALL0"),
+                old: Self::TALL0_FIELD.extract(self.bits()),
+                new: Self::TALL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TALL1"),
+                old: Self::TALL1_FIELD.extract(self.bits()),
+                new: Self::TALL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TSEI"),
+                old: Self::TSEI_FIELD.extract(self.bits()),
+                new: Self::TSEI_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TDIR"),
+                old: Self::TDIR_FIELD.extract(self.bits()),
+                new: Self::TDIR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DVIM"),
+                old: Self::DVIM_FIELD.extract(self.bits()),
+                new: Self::DVIM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EOIcount"),
+                old: Self::EOICOUNT_FIELD.extract(self.bits()),
+                new: Self::EOICOUNT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_0000_0111_1111_1111_0000_0010_0000_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_0000_0111_1111_1111_0000_0010_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-gic"))]
+impl IchHcrEl2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-gic"))]
+impl IchHcrEl2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                Self::EN_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICH_HCR_EL2",
+                    field: "En",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::UIE_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICH_HCR_EL2",
+                    field: "UIE",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::LRENPIE_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICH_HCR_EL2",
+                    field: "LRENPIE",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::NPIE_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICH_HCR_EL2",
+                    field: "NPIE",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::VGRP0EIE_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICH_HCR_EL2",
+                    field: "VGrp0EIE",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::VGRP0DIE_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICH_HCR_EL2",
+                    field: "VGrp0DIE",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::VGRP1EIE_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICH_HCR_EL2",
+                    field: "VGrp1EIE",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::VGRP1DIE_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICH_HCR_EL2",
+                    field: "VGrp1DIE",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::VSGIEOICOUNT_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICH_HCR_EL2",
+                    field: "vSGIEOICount",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::TC_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICH_HCR_EL2",
+                    field: "TC",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::TALL0_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICH_HCR_EL2",
+                    field: "TALL0",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::TALL1_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICH_HCR_EL2",
+                    field: "TALL1",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::TSEI_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICH_HCR_EL2",
+                    field: "TSEI",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::TDIR_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICH_HCR_EL2",
+                    field: "TDIR",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::DVIM_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICH_HCR_EL2",
+                    field: "DVIM",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::EOICOUNT_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICH_HCR_EL2",
+                    field: "EOIcount",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-gic"))]
+bitflags! {
+    /// `ICH_VMCR_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct IchVmcrEl2: u64 {
+        /// `EN` bit.
+        const EN = 1 << 0;
+        /// `VENG0` bit.
+        const VENG0 = 1 << 0;
+        /// `VENG1` bit.
+        const VENG1 = 1 << 1;
+        /// `VAckCtl` bit.
+        const VACKCTL = 1 << 2;
+        /// `VFIQEn` bit.
+        const VFIQEN = 1 << 3;
+        /// `VCBPR` bit.
+        const VCBPR = 1 << 4;
+        /// `VEOIM` bit.
+        const VEOIM = 1 << 9;
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-gic"))]
+impl IchVmcrEl2 {
+    /// Descriptor of the `EN` field, for use with [`FieldAccess`].
+    pub const EN_FIELD: Field<Self, bool, 0, 1> = Field::new("ICH_VMCR_EL2", "EN");
+    /// Descriptor of the `VENG0` field, for use with [`FieldAccess`].
+    pub const VENG0_FIELD: Field<Self, bool, 0, 1> = Field::new("ICH_VMCR_EL2", "VENG0");
+    /// Descriptor of the `VENG1` field, for use with [`FieldAccess`].
+    pub const VENG1_FIELD: Field<Self, bool, 1, 1> = Field::new("ICH_VMCR_EL2", "VENG1");
+    /// Descriptor of the `VAckCtl` field, for use with [`FieldAccess`].
+    pub const VACKCTL_FIELD: Field<Self, bool, 2, 1> = Field::new("ICH_VMCR_EL2", "VAckCtl");
+    /// Descriptor of the `VFIQEn` field, for use with [`FieldAccess`].
+    pub const VFIQEN_FIELD: Field<Self, bool, 3, 1> = Field::new("ICH_VMCR_EL2", "VFIQEn");
+    /// Descriptor of the `VCBPR` field, for use with [`FieldAccess`].
+    pub const VCBPR_FIELD: Field<Self, bool, 4, 1> = Field::new("ICH_VMCR_EL2", "VCBPR");
+    /// Descriptor of the `VEOIM` field, for use with [`FieldAccess`].
+    pub const VEOIM_FIELD: Field<Self, bool, 9, 1> = Field::new("ICH_VMCR_EL2", "VEOIM");
+    /// Descriptor of the `VBPR1` field, for use with [`FieldAccess`].
+    pub const VBPR1_FIELD: Field<Self, u8, 18, 3> = Field::new("ICH_VMCR_EL2", "VBPR1");
+    /// Descriptor of the `VBPR0` field, for use with [`FieldAccess`].
+    pub const VBPR0_FIELD: Field<Self, u8, 21, 3> = Field::new("ICH_VMCR_EL2", "VBPR0");
+
+    /// Returns the value of the `VBPR1` field.
+    pub const fn vbpr1(self) -> u8 {
+        Self::VBPR1_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `VBPR1` field.
+    pub const fn set_vbpr1(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::VBPR1_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `VBPR1` field set to the given value.
+    pub const fn with_vbpr1(mut self, value: u8) -> Self {
+        self.set_vbpr1(value);
+        self
+    }
+
+    /// Sets the value of the `VBPR1` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_vbpr1(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_vbpr1(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `VBPR1` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_vbpr1(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::VBPR1_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `VBPR0` field.
+    pub const fn vbpr0(self) -> u8 {
+        Self::VBPR0_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `VBPR0` field.
+    pub const fn set_vbpr0(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::VBPR0_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `VBPR0` field set to the given value.
+    pub const fn with_vbpr0(mut self, value: u8) -> Self {
+        self.set_vbpr0(value);
+        self
+    }
+
+    /// Sets the value of the `VBPR0` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_vbpr0(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_vbpr0(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `VBPR0` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_vbpr0(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::VBPR0_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-gic"))]
+impl Display for IchVmcrEl2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("IchVmcrEl2");
+        let bits = self.bits();
+        s.field("EN", &Self::EN_FIELD.extract(bits));
+        s.field("VENG0", &Self::VENG0_FIELD.extract(bits));
+        s.field("VENG1", &Self::VENG1_FIELD.extract(bits));
+        s.field("VAckCtl", &Self::VACKCTL_FIELD.extract(bits));
+        s.field("VFIQEn", &Self::VFIQEN_FIELD.extract(bits));
+        s.field("VCBPR", &Self::VCBPR_FIELD.extract(bits));
+        s.field("VEOIM", &Self::VEOIM_FIELD.extract(bits));
+        s.field("VBPR1", &Self::VBPR1_FIELD.extract(bits));
+        s.field("VBPR0", &Self::VBPR0_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(all(feature = "el2", feature = "feat-gic"))]
+impl defmt::Format for IchVmcrEl2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "IchVmcrEl2 {{ EN: {=u64}, VENG0: {=u64}, VENG1: {=u64}, VAckCtl: {=u64}, VFIQEn: {=u64}, VCBPR: {=u64}, VEOIM: {=u64}, VBPR1: {=u64}, VBPR0: {=u64} }}",
+            Self::EN_FIELD.extract(self.bits()),
+            Self::VENG0_FIELD.extract(self.bits()),
+            Self::VENG1_FIELD.extract(self.bits()),
+            Self::VACKCTL_FIELD.extract(self.bits()),
+            Self::VFIQEN_FIELD.extract(self.bits()),
+            Self::VCBPR_FIELD.extract(self.bits()),
+            Self::VEOIM_FIELD.extract(self.bits()),
+            Self::VBPR1_FIELD.extract(self.bits()),
+            Self::VBPR0_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el2", feature = "feat-gic"))]
+impl serde::Serialize for IchVmcrEl2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el2", feature = "feat-gic"))]
+impl<'de> serde::Deserialize<'de> for IchVmcrEl2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-gic"))]
+impl IchVmcrEl2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("EN"),
+                old: Self::EN_FIELD.extract(self.bits()),
+                new: Self::EN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VENG0"),
+                old: Self::VENG0_FIELD.extract(self.bits()),
+                new: Self::VENG0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VENG1"),
+                old: Self::VENG1_FIELD.extract(self.bits()),
+                new: Self::VENG1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VAckCtl"),
+                old: Self::VACKCTL_FIELD.extract(self.bits()),
+                new: Self::VACKCTL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VFIQEn"),
+                old: Self::VFIQEN_FIELD.extract(self.bits()),
+                new: Self::VFIQEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VCBPR"),
+                old: Self::VCBPR_FIELD.extract(self.bits()),
+                new: Self::VCBPR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VEOIM"),
+                old: Self::VEOIM_FIELD.extract(self.bits()),
+                new: Self::VEOIM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VBPR1"),
+                old: Self::VBPR1_FIELD.extract(self.bits()),
+                new: Self::VBPR1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VBPR0"),
+                old: Self::VBPR0_FIELD.extract(self.bits()),
+                new: Self::VBPR0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_0000_0011_1111_1101_1110_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_0000_0011_1111_1101_1110_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-gic"))]
+impl IchVmcrEl2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-gic"))]
+impl IchVmcrEl2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                Self::EN_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICH_VMCR_EL2",
+                    field: "EN",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::VENG0_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICH_VMCR_EL2",
+                    field: "VENG0",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::VENG1_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICH_VMCR_EL2",
+                    field: "VENG1",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::VACKCTL_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICH_VMCR_EL2",
+                    field: "VAckCtl",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::VFIQEN_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICH_VMCR_EL2",
+                    field: "VFIQEn",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::VCBPR_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICH_VMCR_EL2",
+                    field: "VCBPR",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::VEOIM_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICH_VMCR_EL2",
+                    field: "VEOIM",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::VBPR1_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICH_VMCR_EL2",
+                    field: "VBPR1",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+            (
+                Self::VBPR0_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Gicv3),
+                UnimplementedField {
+                    register: "ICH_VMCR_EL2",
+                    field: "VBPR0",
+                    requires: "FEAT_GICv3",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el1",
+    feature = "feat-gic"
+))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `ICC_AP0R0_EL1` system register.
+    icc_ap0r0_el1: s3_0_c12_c8_4, u64, safe_read, fake::SYSREGS
+}
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el1",
+    feature = "feat-gic"
+))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `ICC_AP0R1_EL1` system register.
+    icc_ap0r1_el1: s3_0_c12_c8_5, u64, safe_read, fake::SYSREGS
+}
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el1",
+    feature = "feat-gic"
+))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `ICC_AP0R2_EL1` system register.
+    icc_ap0r2_el1: s3_0_c12_c8_6, u64, safe_read, fake::SYSREGS
+}
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el1",
+    feature = "feat-gic"
+))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `ICC_AP0R3_EL1` system register.
+    icc_ap0r3_el1: s3_0_c12_c8_7, u64, safe_read, fake::SYSREGS
+}
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el1",
+    feature = "feat-gic"
+))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `ICC_AP1R0_EL1` system register.
+    icc_ap1r0_el1: s3_0_c12_c9_0, u64: IccAp1r0El1, safe_read, fake::SYSREGS
+}
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el1",
+    feature = "feat-gic"
+))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `ICC_AP1R1_EL1` system register.
+    icc_ap1r1_el1: s3_0_c12_c9_1, u64, safe_read, fake::SYSREGS
+}
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el1",
+    feature = "feat-gic"
+))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `ICC_AP1R2_EL1` system register.
+    icc_ap1r2_el1: s3_0_c12_c9_2, u64, safe_read, fake::SYSREGS
+}
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el1",
+    feature = "feat-gic"
+))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `ICC_AP1R3_EL1` system register.
+    icc_ap1r3_el1: s3_0_c12_c9_3, u64, safe_read, fake::SYSREGS
+}
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el1",
+    feature = "feat-gic"
+))]
+write_sysreg!(icc_asgi1r_el1: s3_0_c12_c11_6, u64: IccAsgi1rEl1, safe, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el1",
+    feature = "feat-gic"
+))]
+read_write_sysreg!(icc_bpr0_el1: s3_0_c12_c8_3, u64: IccBpr0El1, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el1",
+    feature = "feat-gic"
+))]
+read_write_sysreg!(icc_bpr1_el1: s3_0_c12_c12_3, u64: IccBpr1El1, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el1",
+    feature = "feat-gic"
+))]
+read_write_sysreg!(icc_ctlr_el1: s3_0_c12_c12_4, u64: IccCtlrEl1, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el3",
+    feature = "feat-gic"
+))]
+read_write_sysreg!(icc_ctlr_el3: s3_6_c12_c12_4, u64: IccCtlrEl3, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el1",
+    feature = "feat-gic"
+))]
+write_sysreg!(icc_dir_el1: s3_0_c12_c11_1, u64: IccDirEl1, safe, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el1",
+    feature = "feat-gic"
+))]
+write_sysreg!(icc_eoir0_el1: s3_0_c12_c8_1, u64: IccEoir0El1, safe, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el1",
+    feature = "feat-gic"
+))]
+write_sysreg!(icc_eoir1_el1: s3_0_c12_c12_1, u64: IccEoir1El1, safe, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el1",
+    feature = "feat-gic"
+))]
+read_sysreg!(icc_hppir0_el1: s3_0_c12_c8_2, u64: IccHppir0El1, safe, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el1",
+    feature = "feat-gic"
+))]
+read_sysreg!(icc_hppir1_el1: s3_0_c12_c12_2, u64: IccHppir1El1, safe, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el1",
+    feature = "feat-gic"
+))]
+read_sysreg!(icc_iar0_el1: s3_0_c12_c8_0, u64: IccIar0El1, safe, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el1",
+    feature = "feat-gic"
+))]
+read_sysreg!(icc_iar1_el1: s3_0_c12_c12_0, u64: IccIar1El1, safe, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el1",
+    feature = "feat-gic"
+))]
+read_write_sysreg!(icc_igrpen0_el1: s3_0_c12_c12_6, u64: IccIgrpen0El1, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el1",
+    feature = "feat-gic"
+))]
+read_write_sysreg!(icc_igrpen1_el1: s3_0_c12_c12_7, u64: IccIgrpen1El1, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el3",
+    feature = "feat-gic"
+))]
+read_write_sysreg!(icc_igrpen1_el3: s3_6_c12_c12_7, u64: IccIgrpen1El3, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el1",
+    feature = "feat-gic"
+))]
+read_sysreg!(icc_nmiar1_el1: s3_0_c12_c9_5, u64: IccNmiar1El1, safe, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el1",
+    feature = "feat-gic"
+))]
+read_write_sysreg!(icc_pmr_el1: s3_0_c4_c6_0, u64: IccPmrEl1, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el1",
+    feature = "feat-gic"
+))]
+read_sysreg!(icc_rpr_el1: s3_0_c12_c11_3, u64: IccRprEl1, safe, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el1",
+    feature = "feat-gic"
+))]
+write_sysreg!(icc_sgi0r_el1: s3_0_c12_c11_7, u64: IccSgi0rEl1, safe, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el1",
+    feature = "feat-gic"
+))]
+write_sysreg!(icc_sgi1r_el1: s3_0_c12_c11_5, u64: IccSgi1rEl1, safe, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el1",
+    feature = "feat-gic"
+))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `ICC_SRE_EL1` system register.
+    icc_sre_el1: s3_0_c12_c12_5, u64: IccSreEl1, safe_read, fake::SYSREGS
+}
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el2",
+    feature = "feat-gic"
+))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `ICC_SRE_EL2` system register.
+    icc_sre_el2: s3_4_c12_c9_5, u64: IccSreEl2, safe_read, fake::SYSREGS
+}
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el3",
+    feature = "feat-gic"
+))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The SRE bit of `icc_sre_el3` must not be changed from 1 to 0, as this can result in unpredictable behaviour.
+    icc_sre_el3: s3_6_c12_c12_5, u64: IccSreEl3, safe_read, fake::SYSREGS
+}
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el2",
+    feature = "feat-gic"
+))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `ICH_HCR_EL2` system register.
+    ich_hcr_el2: s3_4_c12_c11_0, u64: IchHcrEl2, safe_read, fake::SYSREGS
+}
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el2",
+    feature = "feat-gic"
+))]
+read_write_sysreg!(ich_vmcr_el2: s3_4_c12_c11_7, u64: IchVmcrEl2, safe_read, safe_write, fake::SYSREGS);
+
+/// Returns the value of the `ICC_AP0R<n>_EL1` system register with the given index, or `None` if
+/// there is no such register.
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el1",
+    feature = "feat-gic"
+))]
+pub fn read_icc_ap0r_el1(n: usize) -> Option<u64> {
+    match n {
+        0 => Some(read_icc_ap0r0_el1()),
+        1 => Some(read_icc_ap0r1_el1()),
+        2 => Some(read_icc_ap0r2_el1()),
+        3 => Some(read_icc_ap0r3_el1()),
+        _ => None,
+    }
+}
+
+/// Writes `value` to the `ICC_AP0R<n>_EL1` system register with the given index.
+///
+/// # Panics
+///
+/// Panics if there is no register with the given index.
+///
+/// # Safety
+///
+/// The caller must ensure that `value` is a correct and safe value to write to the `ICC_AP0R<n>_EL1` system register.
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el1",
+    feature = "feat-gic"
+))]
+pub unsafe fn write_icc_ap0r_el1(n: usize, value: u64) {
+    // SAFETY: The caller promises that `value` is safe to write to the register.
+    unsafe {
+        match n {
+            0 => write_icc_ap0r0_el1(value),
+            1 => write_icc_ap0r1_el1(value),
+            2 => write_icc_ap0r2_el1(value),
+            3 => write_icc_ap0r3_el1(value),
+            _ => panic!("Invalid index {n} for ICC_AP0R<n>_EL1"),
+        }
+    }
+}
+
+/// Returns the value of the `ICC_AP1R<n>_EL1` system register with the given index, or `None` if
+/// there is no such register.
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el1",
+    feature = "feat-gic"
+))]
+pub fn read_icc_ap1r_el1(n: usize) -> Option<u64> {
+    match n {
+        0 => Some(read_icc_ap1r0_el1().bits()),
+        1 => Some(read_icc_ap1r1_el1()),
+        2 => Some(read_icc_ap1r2_el1()),
+        3 => Some(read_icc_ap1r3_el1()),
+        _ => None,
+    }
+}
+
+/// Writes `value` to the `ICC_AP1R<n>_EL1` system register with the given index.
+///
+/// # Panics
+///
+/// Panics if there is no register with the given index.
+///
+/// # Safety
+///
+/// The caller must ensure that `value` is a correct and safe value to write to the `ICC_AP1R<n>_EL1` system register.
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el1",
+    feature = "feat-gic"
+))]
+pub unsafe fn write_icc_ap1r_el1(n: usize, value: u64) {
+    // SAFETY: The caller promises that `value` is safe to write to the register.
+    unsafe {
+        match n {
+            0 => write_icc_ap1r0_el1(IccAp1r0El1::from_bits_retain(value)),
+            1 => write_icc_ap1r1_el1(value),
+            2 => write_icc_ap1r2_el1(value),
+            3 => write_icc_ap1r3_el1(value),
+            _ => panic!("Invalid index {n} for ICC_AP1R<n>_EL1"),
+        }
+    }
+}
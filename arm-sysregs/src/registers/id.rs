@@ -0,0 +1,12216 @@
+// SPDX-FileCopyrightText: Copyright The arm-sysregs Contributors.
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Identification system registers.
+
+// This file is generated, do not edit manually.
+
+// Depending on the enabled features, the module might not use all of these imports.
+#![allow(unused_imports)]
+
+use crate::*;
+use bitflags::bitflags;
+use core::fmt::{self, Display, Formatter};
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `CCSIDR_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct CcsidrEl1: u64 {
+    }
+}
+
+#[cfg(feature = "el1")]
+impl CcsidrEl1 {
+    /// Descriptor of the `LineSize` field, for use with [`FieldAccess`].
+    pub const LINESIZE_FIELD: Field<Self, u8, 0, 3> = Field::new("CCSIDR_EL1", "LineSize");
+
+    /// Returns the value of the `LineSize` field.
+    pub const fn linesize(self) -> u8 {
+        Self::LINESIZE_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `LineSize` field.
+    pub const fn set_linesize(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::LINESIZE_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `LineSize` field set to the given value.
+    pub const fn with_linesize(mut self, value: u8) -> Self {
+        self.set_linesize(value);
+        self
+    }
+
+    /// Sets the value of the `LineSize` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_linesize(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_linesize(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `LineSize` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_linesize(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::LINESIZE_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for CcsidrEl1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("CcsidrEl1");
+        let bits = self.bits();
+        s.field("LineSize", &Self::LINESIZE_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for CcsidrEl1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "CcsidrEl1 {{ LineSize: {=u64} }}",
+            Self::LINESIZE_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for CcsidrEl1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for CcsidrEl1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl CcsidrEl1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("LineSize"),
+                old: Self::LINESIZE_FIELD.extract(self.bits()),
+                new: Self::LINESIZE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl CcsidrEl1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl CcsidrEl1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `CLIDR_EL1` system register value.
+    ///
+    /// Cache Level ID.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct ClidrEl1: u64 {
+    }
+}
+
+#[cfg(feature = "el1")]
+impl ClidrEl1 {
+    /// Offset of the `Ctype<n>` field.
+    pub const CTYPE_SHIFT: u32 = 0;
+    /// Mask for the `Ctype<n>` field.
+    pub const CTYPE_MASK: u64 = 0b111;
+    /// Offset of the `Ttype<n>` field.
+    pub const TTYPE_SHIFT: u32 = 33;
+    /// Mask for the `Ttype<n>` field.
+    pub const TTYPE_MASK: u64 = 0b11;
+    /// Descriptor of the `LoUIS` field, for use with [`FieldAccess`].
+    pub const LOUIS_FIELD: Field<Self, u8, 21, 3> = Field::new("CLIDR_EL1", "LoUIS");
+    /// Descriptor of the `LoC` field, for use with [`FieldAccess`].
+    pub const LOC_FIELD: Field<Self, u8, 24, 3> = Field::new("CLIDR_EL1", "LoC");
+    /// Descriptor of the `LoUU` field, for use with [`FieldAccess`].
+    pub const LOUU_FIELD: Field<Self, u8, 27, 3> = Field::new("CLIDR_EL1", "LoUU");
+    /// Descriptor of the `ICB` field, for use with [`FieldAccess`].
+    pub const ICB_FIELD: Field<Self, u8, 30, 3> = Field::new("CLIDR_EL1", "ICB");
+
+    /// Returns the value of the given `Ctype<n>` field.
+    #[allow(clippy::manual_range_contains)]
+    pub const fn ctype(self, n: u32) -> crate::manual::CacheType {
+        assert!(n >= 1 && n < 8);
+        crate::manual::CacheType::from_raw(
+            ((self.bits() >> (Self::CTYPE_SHIFT + (n - 1) * 3)) & Self::CTYPE_MASK) as u8,
+        )
+        .unwrap()
+    }
+
+    /// Returns the value of the `Ctype<n>` field, or an error if it isn't valid for the field type or the
+    /// index is out of range.
+    #[allow(clippy::manual_range_contains)]
+    pub const fn try_ctype(self, n: u32) -> Result<crate::manual::CacheType, InvalidFieldValue> {
+        if n < 1 || n >= 8 {
+            return Err(InvalidFieldValue {
+                register: "CLIDR_EL1",
+                field: "Ctype",
+                index: Some(n),
+                value: 0,
+            });
+        }
+        let value = ((self.bits() >> (Self::CTYPE_SHIFT + (n - 1) * 3)) & Self::CTYPE_MASK) as u8;
+        match crate::manual::CacheType::from_raw(value) {
+            Some(value) => Ok(value),
+            None => Err(InvalidFieldValue {
+                register: "CLIDR_EL1",
+                field: "Ctype",
+                index: Some(n),
+                value: value as u64,
+            }),
+        }
+    }
+
+    /// Sets the value of the `Ctype<n>` field.
+    #[allow(clippy::manual_range_contains)]
+    pub const fn set_ctype(&mut self, n: u32, value: crate::manual::CacheType) {
+        assert!(n >= 1 && n < 8);
+        let offset = Self::CTYPE_SHIFT + (n - 1) * 3;
+        let value = value.to_raw();
+        assert!(value & (Self::CTYPE_MASK as u8) == value);
+        *self = Self::from_bits_retain(
+            (self.bits() & !(Self::CTYPE_MASK << offset)) | ((value as u64) << offset),
+        );
+    }
+
+    /// Returns a copy with the `Ctype<n>` field set to the given value.
+    pub const fn with_ctype(mut self, n: u32, value: crate::manual::CacheType) -> Self {
+        self.set_ctype(n, value);
+        self
+    }
+
+    /// Sets the value of the `Ctype<n>` field, or returns an error if the value doesn't fit or the
+    /// index is out of range.
+    #[allow(clippy::manual_range_contains)]
+    pub const fn try_set_ctype(
+        &mut self,
+        n: u32,
+        value: crate::manual::CacheType,
+    ) -> Result<(), FieldOverflow> {
+        let raw = value.to_raw();
+        if n < 1 || n >= 8 || raw & (Self::CTYPE_MASK as u8) != raw {
+            return Err(FieldOverflow {
+                register: "CLIDR_EL1",
+                field: "Ctype",
+                index: Some(n),
+                value: raw as u64,
+            });
+        }
+        self.set_ctype(n, value);
+        Ok(())
+    }
+
+    /// Returns a copy with the `Ctype<n>` field set to the given value, or an error if the value
+    /// doesn't fit or the index is out of range.
+    pub const fn try_with_ctype(
+        mut self,
+        n: u32,
+        value: crate::manual::CacheType,
+    ) -> Result<Self, FieldOverflow> {
+        match self.try_set_ctype(n, value) {
+            Ok(()) => Ok(self),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `LoUIS` field.
+    ///
+    /// Level of Unification Inner Shareable for the cache hierarchy.
+    pub const fn louis(self) -> u8 {
+        Self::LOUIS_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `LoUIS` field.
+    ///
+    /// Level of Unification Inner Shareable for the cache hierarchy.
+    pub const fn set_louis(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::LOUIS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `LoUIS` field set to the given value.
+    ///
+    /// Level of Unification Inner Shareable for the cache hierarchy.
+    pub const fn with_louis(mut self, value: u8) -> Self {
+        self.set_louis(value);
+        self
+    }
+
+    /// Sets the value of the `LoUIS` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_louis(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_louis(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `LoUIS` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_louis(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::LOUIS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `LoC` field.
+    ///
+    /// Level of Coherence for the cache hierarchy.
+    pub const fn loc(self) -> u8 {
+        Self::LOC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `LoC` field.
+    ///
+    /// Level of Coherence for the cache hierarchy.
+    pub const fn set_loc(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::LOC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `LoC` field set to the given value.
+    ///
+    /// Level of Coherence for the cache hierarchy.
+    pub const fn with_loc(mut self, value: u8) -> Self {
+        self.set_loc(value);
+        self
+    }
+
+    /// Sets the value of the `LoC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_loc(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_loc(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `LoC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_loc(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::LOC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `LoUU` field.
+    ///
+    /// Level of Unification Uniprocessor for the cache hierarchy.
+    pub const fn louu(self) -> u8 {
+        Self::LOUU_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `LoUU` field.
+    ///
+    /// Level of Unification Uniprocessor for the cache hierarchy.
+    pub const fn set_louu(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::LOUU_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `LoUU` field set to the given value.
+    ///
+    /// Level of Unification Uniprocessor for the cache hierarchy.
+    pub const fn with_louu(mut self, value: u8) -> Self {
+        self.set_louu(value);
+        self
+    }
+
+    /// Sets the value of the `LoUU` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_louu(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_louu(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `LoUU` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_louu(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::LOUU_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ICB` field.
+    ///
+    /// Inner cache boundary level.
+    pub const fn icb(self) -> u8 {
+        Self::ICB_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `ICB` field.
+    ///
+    /// Inner cache boundary level.
+    pub const fn set_icb(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::ICB_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ICB` field set to the given value.
+    ///
+    /// Inner cache boundary level.
+    pub const fn with_icb(mut self, value: u8) -> Self {
+        self.set_icb(value);
+        self
+    }
+
+    /// Sets the value of the `ICB` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_icb(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_icb(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ICB` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_icb(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::ICB_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the given `Ttype<n>` field.
+    #[allow(clippy::manual_range_contains)]
+    pub const fn ttype(self, n: u32) -> u8 {
+        assert!(n >= 1 && n < 8);
+        ((self.bits() >> (Self::TTYPE_SHIFT + (n - 1) * 2)) & Self::TTYPE_MASK) as u8
+    }
+
+    /// Sets the value of the `Ttype<n>` field.
+    #[allow(clippy::manual_range_contains)]
+    pub const fn set_ttype(&mut self, n: u32, value: u8) {
+        assert!(n >= 1 && n < 8);
+        let offset = Self::TTYPE_SHIFT + (n - 1) * 2;
+        assert!(value & (Self::TTYPE_MASK as u8) == value);
+        *self = Self::from_bits_retain(
+            (self.bits() & !(Self::TTYPE_MASK << offset)) | ((value as u64) << offset),
+        );
+    }
+
+    /// Returns a copy with the `Ttype<n>` field set to the given value.
+    pub const fn with_ttype(mut self, n: u32, value: u8) -> Self {
+        self.set_ttype(n, value);
+        self
+    }
+
+    /// Sets the value of the `Ttype<n>` field, or returns an error if the value doesn't fit or the
+    /// index is out of range.
+    #[allow(clippy::manual_range_contains)]
+    pub const fn try_set_ttype(&mut self, n: u32, value: u8) -> Result<(), FieldOverflow> {
+        if n < 1 || n >= 8 || value & (Self::TTYPE_MASK as u8) != value {
+            return Err(FieldOverflow {
+                register: "CLIDR_EL1",
+                field: "Ttype",
+                index: Some(n),
+                value: value as u64,
+            });
+        }
+        self.set_ttype(n, value);
+        Ok(())
+    }
+
+    /// Returns a copy with the `Ttype<n>` field set to the given value, or an error if the value
+    /// doesn't fit or the index is out of range.
+    pub const fn try_with_ttype(mut self, n: u32, value: u8) -> Result<Self, FieldOverflow> {
+        match self.try_set_ttype(n, value) {
+            Ok(()) => Ok(self),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for ClidrEl1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("ClidrEl1");
+        let bits = self.bits();
+        let raw = (bits >> Self::CTYPE_SHIFT) & Self::CTYPE_MASK;
+        if let Some(value) = crate::manual::CacheType::from_raw(raw as u8) {
+            s.field("Ctype1", &value);
+        } else {
+            s.field("Ctype1", &format_args!("{}", raw));
+        }
+        let raw = (bits >> (Self::CTYPE_SHIFT + 3)) & Self::CTYPE_MASK;
+        if let Some(value) = crate::manual::CacheType::from_raw(raw as u8) {
+            s.field("Ctype2", &value);
+        } else {
+            s.field("Ctype2", &format_args!("{}", raw));
+        }
+        let raw = (bits >> (Self::CTYPE_SHIFT + 6)) & Self::CTYPE_MASK;
+        if let Some(value) = crate::manual::CacheType::from_raw(raw as u8) {
+            s.field("Ctype3", &value);
+        } else {
+            s.field("Ctype3", &format_args!("{}", raw));
+        }
+        let raw = (bits >> (Self::CTYPE_SHIFT + 9)) & Self::CTYPE_MASK;
+        if let Some(value) = crate::manual::CacheType::from_raw(raw as u8) {
+            s.field("Ctype4", &value);
+        } else {
+            s.field("Ctype4", &format_args!("{}", raw));
+        }
+        let raw = (bits >> (Self::CTYPE_SHIFT + 12)) & Self::CTYPE_MASK;
+        if let Some(value) = crate::manual::CacheType::from_raw(raw as u8) {
+            s.field("Ctype5", &value);
+        } else {
+            s.field("Ctype5", &format_args!("{}", raw));
+        }
+        let raw = (bits >> (Self::CTYPE_SHIFT + 15)) & Self::CTYPE_MASK;
+        if let Some(value) = crate::manual::CacheType::from_raw(raw as u8) {
+            s.field("Ctype6", &value);
+        } else {
+            s.field("Ctype6", &format_args!("{}", raw));
+        }
+        let raw = (bits >> (Self::CTYPE_SHIFT + 18)) & Self::CTYPE_MASK;
+        if let Some(value) = crate::manual::CacheType::from_raw(raw as u8) {
+            s.field("Ctype7", &value);
+        } else {
+            s.field("Ctype7", &format_args!("{}", raw));
+        }
+        s.field("LoUIS", &Self::LOUIS_FIELD.extract(bits));
+        s.field("LoC", &Self::LOC_FIELD.extract(bits));
+        s.field("LoUU", &Self::LOUU_FIELD.extract(bits));
+        s.field("ICB", &Self::ICB_FIELD.extract(bits));
+        s.field("Ttype1", &((bits >> Self::TTYPE_SHIFT) & Self::TTYPE_MASK));
+        s.field(
+            "Ttype2",
+            &((bits >> (Self::TTYPE_SHIFT + 2)) & Self::TTYPE_MASK),
+        );
+        s.field(
+            "Ttype3",
+            &((bits >> (Self::TTYPE_SHIFT + 4)) & Self::TTYPE_MASK),
+        );
+        s.field(
+            "Ttype4",
+            &((bits >> (Self::TTYPE_SHIFT + 6)) & Self::TTYPE_MASK),
+        );
+        s.field(
+            "Ttype5",
+            &((bits >> (Self::TTYPE_SHIFT + 8)) & Self::TTYPE_MASK),
+        );
+        s.field(
+            "Ttype6",
+            &((bits >> (Self::TTYPE_SHIFT + 10)) & Self::TTYPE_MASK),
+        );
+        s.field(
+            "Ttype7",
+            &((bits >> (Self::TTYPE_SHIFT + 12)) & Self::TTYPE_MASK),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for ClidrEl1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "ClidrEl1 {{ Ctype1: {}, Ctype2: {}, Ctype3: {}, Ctype4: {}, Ctype5: {}, Ctype6: {}, Ctype7: {}, LoUIS: {=u64}, LoC: {=u64}, LoUU: {=u64}, ICB: {=u64}, Ttype1: {=u64}, Ttype2: {=u64}, Ttype3: {=u64}, Ttype4: {=u64}, Ttype5: {=u64}, Ttype6: {=u64}, Ttype7: {=u64} }}",
+            crate::manual::DefmtField::new(
+                (self.bits() >> Self::CTYPE_SHIFT) & Self::CTYPE_MASK,
+                crate::manual::CacheType::from_raw(
+                    ((self.bits() >> Self::CTYPE_SHIFT) & Self::CTYPE_MASK) as u8
+                )
+            ),
+            crate::manual::DefmtField::new(
+                (self.bits() >> (Self::CTYPE_SHIFT + 3)) & Self::CTYPE_MASK,
+                crate::manual::CacheType::from_raw(
+                    ((self.bits() >> (Self::CTYPE_SHIFT + 3)) & Self::CTYPE_MASK) as u8
+                )
+            ),
+            crate::manual::DefmtField::new(
+                (self.bits() >> (Self::CTYPE_SHIFT + 6)) & Self::CTYPE_MASK,
+                crate::manual::CacheType::from_raw(
+                    ((self.bits() >> (Self::CTYPE_SHIFT + 6)) & Self::CTYPE_MASK) as u8
+                )
+            ),
+            crate::manual::DefmtField::new(
+                (self.bits() >> (Self::CTYPE_SHIFT + 9)) & Self::CTYPE_MASK,
+                crate::manual::CacheType::from_raw(
+                    ((self.bits() >> (Self::CTYPE_SHIFT + 9)) & Self::CTYPE_MASK) as u8
+                )
+            ),
+            crate::manual::DefmtField::new(
+                (self.bits() >> (Self::CTYPE_SHIFT + 12)) & Self::CTYPE_MASK,
+                crate::manual::CacheType::from_raw(
+                    ((self.bits() >> (Self::CTYPE_SHIFT + 12)) & Self::CTYPE_MASK) as u8
+                )
+            ),
+            crate::manual::DefmtField::new(
+                (self.bits() >> (Self::CTYPE_SHIFT + 15)) & Self::CTYPE_MASK,
+                crate::manual::CacheType::from_raw(
+                    ((self.bits() >> (Self::CTYPE_SHIFT + 15)) & Self::CTYPE_MASK) as u8
+                )
+            ),
+            crate::manual::DefmtField::new(
+                (self.bits() >> (Self::CTYPE_SHIFT + 18)) & Self::CTYPE_MASK,
+                crate::manual::CacheType::from_raw(
+                    ((self.bits() >> (Self::CTYPE_SHIFT + 18)) & Self::CTYPE_MASK) as u8
+                )
+            ),
+            Self::LOUIS_FIELD.extract(self.bits()),
+            Self::LOC_FIELD.extract(self.bits()),
+            Self::LOUU_FIELD.extract(self.bits()),
+            Self::ICB_FIELD.extract(self.bits()),
+            (self.bits() >> Self::TTYPE_SHIFT) & Self::TTYPE_MASK,
+            (self.bits() >> (Self::TTYPE_SHIFT + 2)) & Self::TTYPE_MASK,
+            (self.bits() >> (Self::TTYPE_SHIFT + 4)) & Self::TTYPE_MASK,
+            (self.bits() >> (Self::TTYPE_SHIFT + 6)) & Self::TTYPE_MASK,
+            (self.bits() >> (Self::TTYPE_SHIFT + 8)) & Self::TTYPE_MASK,
+            (self.bits() >> (Self::TTYPE_SHIFT + 10)) & Self::TTYPE_MASK,
+            (self.bits() >> (Self::TTYPE_SHIFT + 12)) & Self::TTYPE_MASK
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for ClidrEl1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for ClidrEl1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl ClidrEl1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("Ctype1"),
+                old: (self.bits() >> Self::CTYPE_SHIFT) & Self::CTYPE_MASK,
+                new: (other.bits() >> Self::CTYPE_SHIFT) & Self::CTYPE_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Ctype2"),
+                old: (self.bits() >> (Self::CTYPE_SHIFT + 3)) & Self::CTYPE_MASK,
+                new: (other.bits() >> (Self::CTYPE_SHIFT + 3)) & Self::CTYPE_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Ctype3"),
+                old: (self.bits() >> (Self::CTYPE_SHIFT + 6)) & Self::CTYPE_MASK,
+                new: (other.bits() >> (Self::CTYPE_SHIFT + 6)) & Self::CTYPE_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Ctype4"),
+                old: (self.bits() >> (Self::CTYPE_SHIFT + 9)) & Self::CTYPE_MASK,
+                new: (other.bits() >> (Self::CTYPE_SHIFT + 9)) & Self::CTYPE_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Ctype5"),
+                old: (self.bits() >> (Self::CTYPE_SHIFT + 12)) & Self::CTYPE_MASK,
+                new: (other.bits() >> (Self::CTYPE_SHIFT + 12)) & Self::CTYPE_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Ctype6"),
+                old: (self.bits() >> (Self::CTYPE_SHIFT + 15)) & Self::CTYPE_MASK,
+                new: (other.bits() >> (Self::CTYPE_SHIFT + 15)) & Self::CTYPE_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Ctype7"),
+                old: (self.bits() >> (Self::CTYPE_SHIFT + 18)) & Self::CTYPE_MASK,
+                new: (other.bits() >> (Self::CTYPE_SHIFT + 18)) & Self::CTYPE_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("LoUIS"),
+                old: Self::LOUIS_FIELD.extract(self.bits()),
+                new: Self::LOUIS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("LoC"),
+                old: Self::LOC_FIELD.extract(self.bits()),
+                new: Self::LOC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("LoUU"),
+                old: Self::LOUU_FIELD.extract(self.bits()),
+                new: Self::LOUU_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ICB"),
+                old: Self::ICB_FIELD.extract(self.bits()),
+                new: Self::ICB_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("Ttype1"),
+                old: (self.bits() >> Self::TTYPE_SHIFT) & Self::TTYPE_MASK,
+                new: (other.bits() >> Self::TTYPE_SHIFT) & Self::TTYPE_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Ttype2"),
+                old: (self.bits() >> (Self::TTYPE_SHIFT + 2)) & Self::TTYPE_MASK,
+                new: (other.bits() >> (Self::TTYPE_SHIFT + 2)) & Self::TTYPE_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Ttype3"),
+                old: (self.bits() >> (Self::TTYPE_SHIFT + 4)) & Self::TTYPE_MASK,
+                new: (other.bits() >> (Self::TTYPE_SHIFT + 4)) & Self::TTYPE_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Ttype4"),
+                old: (self.bits() >> (Self::TTYPE_SHIFT + 6)) & Self::TTYPE_MASK,
+                new: (other.bits() >> (Self::TTYPE_SHIFT + 6)) & Self::TTYPE_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Ttype5"),
+                old: (self.bits() >> (Self::TTYPE_SHIFT + 8)) & Self::TTYPE_MASK,
+                new: (other.bits() >> (Self::TTYPE_SHIFT + 8)) & Self::TTYPE_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Ttype6"),
+                old: (self.bits() >> (Self::TTYPE_SHIFT + 10)) & Self::TTYPE_MASK,
+                new: (other.bits() >> (Self::TTYPE_SHIFT + 10)) & Self::TTYPE_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Ttype7"),
+                old: (self.bits() >> (Self::TTYPE_SHIFT + 12)) & Self::TTYPE_MASK,
+                new: (other.bits() >> (Self::TTYPE_SHIFT + 12)) & Self::TTYPE_MASK,
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl ClidrEl1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl ClidrEl1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `CSSELR_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct CsselrEl1: u64 {
+        /// Instruction not Data bit.
+        const IND = 1 << 0;
+        /// Allocation Tag not Data bit, only valid if FEAT_MTE2 is implemented.
+        const TND = 1 << 4;
+    }
+}
+
+#[cfg(feature = "el1")]
+impl CsselrEl1 {
+    /// Descriptor of the `InD` field, for use with [`FieldAccess`].
+    pub const IND_FIELD: Field<Self, bool, 0, 1> = Field::new("CSSELR_EL1", "InD");
+    /// Descriptor of the `Level` field, for use with [`FieldAccess`].
+    pub const LEVEL_FIELD: Field<Self, u8, 1, 3> = Field::new("CSSELR_EL1", "Level");
+    /// Descriptor of the `TnD` field, for use with [`FieldAccess`].
+    pub const TND_FIELD: Field<Self, bool, 4, 1> = Field::new("CSSELR_EL1", "TnD");
+
+    /// Returns the value of the `Level` field.
+    pub const fn level(self) -> u8 {
+        Self::LEVEL_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `Level` field.
+    pub const fn set_level(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::LEVEL_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `Level` field set to the given value.
+    pub const fn with_level(mut self, value: u8) -> Self {
+        self.set_level(value);
+        self
+    }
+
+    /// Sets the value of the `Level` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_level(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_level(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `Level` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_level(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::LEVEL_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for CsselrEl1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("CsselrEl1");
+        let bits = self.bits();
+        s.field("InD", &Self::IND_FIELD.extract(bits));
+        s.field("Level", &Self::LEVEL_FIELD.extract(bits));
+        s.field("TnD", &Self::TND_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for CsselrEl1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "CsselrEl1 {{ InD: {=u64}, Level: {=u64}, TnD: {=u64} }}",
+            Self::IND_FIELD.extract(self.bits()),
+            Self::LEVEL_FIELD.extract(self.bits()),
+            Self::TND_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for CsselrEl1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for CsselrEl1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl CsselrEl1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("InD"),
+                old: Self::IND_FIELD.extract(self.bits()),
+                new: Self::IND_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("Level"),
+                old: Self::LEVEL_FIELD.extract(self.bits()),
+                new: Self::LEVEL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TnD"),
+                old: Self::TND_FIELD.extract(self.bits()),
+                new: Self::TND_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1110_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1110_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl CsselrEl1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl CsselrEl1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+bitflags! {
+    /// `CTR_EL0` system register value.
+    ///
+    /// Cache Type Register.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct CtrEl0: u64 {
+        /// RES1 bits in the `CTR_EL0` register.
+        const RES1 = 0b1000_0000_0000_0000_0000_0000_0000_0000;
+        /// `IDC` bit.
+        const IDC = 1 << 28;
+        /// `DIC` bit.
+        const DIC = 1 << 29;
+    }
+}
+
+impl CtrEl0 {
+    /// Descriptor of the `IminLine` field, for use with [`FieldAccess`].
+    pub const IMINLINE_FIELD: Field<Self, u8, 0, 4> = Field::new("CTR_EL0", "IminLine");
+    /// Descriptor of the `L1Ip` field, for use with [`FieldAccess`].
+    pub const L1IP_FIELD: Field<Self, u8, 14, 2> = Field::new("CTR_EL0", "L1Ip");
+    /// Descriptor of the `DminLine` field, for use with [`FieldAccess`].
+    pub const DMINLINE_FIELD: Field<Self, u8, 16, 4> = Field::new("CTR_EL0", "DminLine");
+    /// Descriptor of the `ERG` field, for use with [`FieldAccess`].
+    pub const ERG_FIELD: Field<Self, u8, 20, 4> = Field::new("CTR_EL0", "ERG");
+    /// Descriptor of the `CWG` field, for use with [`FieldAccess`].
+    pub const CWG_FIELD: Field<Self, u8, 24, 4> = Field::new("CTR_EL0", "CWG");
+    /// Descriptor of the `IDC` field, for use with [`FieldAccess`].
+    pub const IDC_FIELD: Field<Self, bool, 28, 1> = Field::new("CTR_EL0", "IDC");
+    /// Descriptor of the `DIC` field, for use with [`FieldAccess`].
+    pub const DIC_FIELD: Field<Self, bool, 29, 1> = Field::new("CTR_EL0", "DIC");
+    /// Descriptor of the `TminLine` field, for use with [`FieldAccess`].
+    pub const TMINLINE_FIELD: Field<Self, u8, 32, 6> = Field::new("CTR_EL0", "TminLine");
+
+    /// Returns the value of the `IminLine` field.
+    pub const fn iminline(self) -> u8 {
+        Self::IMINLINE_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `IminLine` field.
+    pub const fn set_iminline(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::IMINLINE_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `IminLine` field set to the given value.
+    pub const fn with_iminline(mut self, value: u8) -> Self {
+        self.set_iminline(value);
+        self
+    }
+
+    /// Sets the value of the `IminLine` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_iminline(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_iminline(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `IminLine` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_iminline(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::IMINLINE_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `L1Ip` field.
+    pub const fn l1ip(self) -> u8 {
+        Self::L1IP_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `L1Ip` field.
+    pub const fn set_l1ip(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::L1IP_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `L1Ip` field set to the given value.
+    pub const fn with_l1ip(mut self, value: u8) -> Self {
+        self.set_l1ip(value);
+        self
+    }
+
+    /// Sets the value of the `L1Ip` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_l1ip(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_l1ip(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `L1Ip` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_l1ip(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::L1IP_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `DminLine` field.
+    ///
+    /// Log2 of the number of words in the smallest cache line of all the data caches and unified caches that are controlled by the PE.
+    pub const fn dminline(self) -> u8 {
+        Self::DMINLINE_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `DminLine` field.
+    ///
+    /// Log2 of the number of words in the smallest cache line of all the data caches and unified caches that are controlled by the PE.
+    pub const fn set_dminline(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::DMINLINE_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `DminLine` field set to the given value.
+    ///
+    /// Log2 of the number of words in the smallest cache line of all the data caches and unified caches that are controlled by the PE.
+    pub const fn with_dminline(mut self, value: u8) -> Self {
+        self.set_dminline(value);
+        self
+    }
+
+    /// Sets the value of the `DminLine` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_dminline(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_dminline(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `DminLine` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_dminline(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::DMINLINE_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ERG` field.
+    pub const fn erg(self) -> u8 {
+        Self::ERG_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `ERG` field.
+    pub const fn set_erg(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::ERG_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ERG` field set to the given value.
+    pub const fn with_erg(mut self, value: u8) -> Self {
+        self.set_erg(value);
+        self
+    }
+
+    /// Sets the value of the `ERG` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_erg(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_erg(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ERG` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_erg(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::ERG_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `CWG` field.
+    pub const fn cwg(self) -> u8 {
+        Self::CWG_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `CWG` field.
+    pub const fn set_cwg(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::CWG_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `CWG` field set to the given value.
+    pub const fn with_cwg(mut self, value: u8) -> Self {
+        self.set_cwg(value);
+        self
+    }
+
+    /// Sets the value of the `CWG` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_cwg(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_cwg(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `CWG` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_cwg(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::CWG_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `TminLine` field.
+    pub const fn tminline(self) -> u8 {
+        Self::TMINLINE_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `TminLine` field.
+    pub const fn set_tminline(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::TMINLINE_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `TminLine` field set to the given value.
+    pub const fn with_tminline(mut self, value: u8) -> Self {
+        self.set_tminline(value);
+        self
+    }
+
+    /// Sets the value of the `TminLine` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_tminline(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_tminline(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `TminLine` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_tminline(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::TMINLINE_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl Display for CtrEl0 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("CtrEl0");
+        let bits = self.bits();
+        s.field("IminLine", &Self::IMINLINE_FIELD.extract(bits));
+        s.field("L1Ip", &Self::L1IP_FIELD.extract(bits));
+        s.field("DminLine", &Self::DMINLINE_FIELD.extract(bits));
+        s.field("ERG", &Self::ERG_FIELD.extract(bits));
+        s.field("CWG", &Self::CWG_FIELD.extract(bits));
+        s.field("IDC", &Self::IDC_FIELD.extract(bits));
+        s.field("DIC", &Self::DIC_FIELD.extract(bits));
+        s.field("TminLine", &Self::TMINLINE_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for CtrEl0 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "CtrEl0 {{ IminLine: {=u64}, L1Ip: {=u64}, DminLine: {=u64}, ERG: {=u64}, CWG: {=u64}, IDC: {=u64}, DIC: {=u64}, TminLine: {=u64} }}",
+            Self::IMINLINE_FIELD.extract(self.bits()),
+            Self::L1IP_FIELD.extract(self.bits()),
+            Self::DMINLINE_FIELD.extract(self.bits()),
+            Self::ERG_FIELD.extract(self.bits()),
+            Self::CWG_FIELD.extract(self.bits()),
+            Self::IDC_FIELD.extract(self.bits()),
+            Self::DIC_FIELD.extract(self.bits()),
+            Self::TMINLINE_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for CtrEl0 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CtrEl0 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+impl CtrEl0 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("IminLine"),
+                old: Self::IMINLINE_FIELD.extract(self.bits()),
+                new: Self::IMINLINE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("L1Ip"),
+                old: Self::L1IP_FIELD.extract(self.bits()),
+                new: Self::L1IP_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DminLine"),
+                old: Self::DMINLINE_FIELD.extract(self.bits()),
+                new: Self::DMINLINE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ERG"),
+                old: Self::ERG_FIELD.extract(self.bits()),
+                new: Self::ERG_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CWG"),
+                old: Self::CWG_FIELD.extract(self.bits()),
+                new: Self::CWG_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IDC"),
+                old: Self::IDC_FIELD.extract(self.bits()),
+                new: Self::IDC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DIC"),
+                old: Self::DIC_FIELD.extract(self.bits()),
+                new: Self::DIC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TminLine"),
+                old: Self::TMINLINE_FIELD.extract(self.bits()),
+                new: Self::TMINLINE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1100_0000_1100_0000_0000_0000_0011_1111_1111_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1100_0000_1100_0000_0000_0000_0011_1111_1111_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+impl CtrEl0 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b1000_0000_0000_0000_0000_0000_0000_0000;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+impl CtrEl0 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `ID_AA64DFR0_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct IdAa64dfr0El1: u64 {
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64dfr0El1 {
+    /// Descriptor of the `DebugVer` field, for use with [`FieldAccess`].
+    pub const DEBUGVER_FIELD: Field<Self, u8, 0, 4> = Field::new("ID_AA64DFR0_EL1", "DebugVer");
+    /// Descriptor of the `TraceVer` field, for use with [`FieldAccess`].
+    pub const TRACEVER_FIELD: Field<Self, u8, 4, 4> = Field::new("ID_AA64DFR0_EL1", "TraceVer");
+    /// Descriptor of the `PMUVer` field, for use with [`FieldAccess`].
+    pub const PMUVER_FIELD: Field<Self, u8, 8, 4> = Field::new("ID_AA64DFR0_EL1", "PMUVer");
+    /// Descriptor of the `BRPs` field, for use with [`FieldAccess`].
+    pub const BRPS_FIELD: Field<Self, u8, 12, 4> = Field::new("ID_AA64DFR0_EL1", "BRPs");
+    /// Descriptor of the `PMSS` field, for use with [`FieldAccess`].
+    pub const PMSS_FIELD: Field<Self, u8, 16, 4> = Field::new("ID_AA64DFR0_EL1", "PMSS");
+    /// Descriptor of the `WRPs` field, for use with [`FieldAccess`].
+    pub const WRPS_FIELD: Field<Self, u8, 20, 4> = Field::new("ID_AA64DFR0_EL1", "WRPs");
+    /// Descriptor of the `CTX_CMPs` field, for use with [`FieldAccess`].
+    pub const CTX_CMPS_FIELD: Field<Self, u8, 28, 4> = Field::new("ID_AA64DFR0_EL1", "CTX_CMPs");
+    /// Descriptor of the `PMSVer` field, for use with [`FieldAccess`].
+    pub const PMSVER_FIELD: Field<Self, u8, 32, 4> = Field::new("ID_AA64DFR0_EL1", "PMSVer");
+    /// Descriptor of the `DoubleLock` field, for use with [`FieldAccess`].
+    pub const DOUBLELOCK_FIELD: Field<Self, u8, 36, 4> =
+        Field::new("ID_AA64DFR0_EL1", "DoubleLock");
+    /// Descriptor of the `TraceFilt` field, for use with [`FieldAccess`].
+    pub const TRACEFILT_FIELD: Field<Self, u8, 40, 4> = Field::new("ID_AA64DFR0_EL1", "TraceFilt");
+    /// Descriptor of the `TraceBuffer` field, for use with [`FieldAccess`].
+    pub const TRACEBUFFER_FIELD: Field<Self, u8, 44, 4> =
+        Field::new("ID_AA64DFR0_EL1", "TraceBuffer");
+    /// Descriptor of the `MTPMU` field, for use with [`FieldAccess`].
+    pub const MTPMU_FIELD: Field<Self, u8, 48, 4> = Field::new("ID_AA64DFR0_EL1", "MTPMU");
+    /// Descriptor of the `BRBE` field, for use with [`FieldAccess`].
+    pub const BRBE_FIELD: Field<Self, u8, 52, 4> = Field::new("ID_AA64DFR0_EL1", "BRBE");
+    /// Descriptor of the `ExtTrcBuff` field, for use with [`FieldAccess`].
+    pub const EXTTRCBUFF_FIELD: Field<Self, u8, 56, 4> =
+        Field::new("ID_AA64DFR0_EL1", "ExtTrcBuff");
+    /// Descriptor of the `HPMN0` field, for use with [`FieldAccess`].
+    pub const HPMN0_FIELD: Field<Self, u8, 60, 4> = Field::new("ID_AA64DFR0_EL1", "HPMN0");
+
+    /// Returns the value of the `DebugVer` field.
+    pub const fn debugver(self) -> u8 {
+        Self::DEBUGVER_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `DebugVer` field.
+    pub const fn set_debugver(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::DEBUGVER_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `DebugVer` field set to the given value.
+    pub const fn with_debugver(mut self, value: u8) -> Self {
+        self.set_debugver(value);
+        self
+    }
+
+    /// Sets the value of the `DebugVer` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_debugver(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_debugver(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `DebugVer` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_debugver(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::DEBUGVER_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `TraceVer` field.
+    pub const fn tracever(self) -> u8 {
+        Self::TRACEVER_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `TraceVer` field.
+    pub const fn set_tracever(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::TRACEVER_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `TraceVer` field set to the given value.
+    pub const fn with_tracever(mut self, value: u8) -> Self {
+        self.set_tracever(value);
+        self
+    }
+
+    /// Sets the value of the `TraceVer` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_tracever(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_tracever(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `TraceVer` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_tracever(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::TRACEVER_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PMUVer` field.
+    pub const fn pmuver(self) -> u8 {
+        Self::PMUVER_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `PMUVer` field.
+    pub const fn set_pmuver(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::PMUVER_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PMUVer` field set to the given value.
+    pub const fn with_pmuver(mut self, value: u8) -> Self {
+        self.set_pmuver(value);
+        self
+    }
+
+    /// Sets the value of the `PMUVer` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_pmuver(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_pmuver(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PMUVer` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_pmuver(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::PMUVER_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `BRPs` field.
+    pub const fn brps(self) -> u8 {
+        Self::BRPS_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `BRPs` field.
+    pub const fn set_brps(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::BRPS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `BRPs` field set to the given value.
+    pub const fn with_brps(mut self, value: u8) -> Self {
+        self.set_brps(value);
+        self
+    }
+
+    /// Sets the value of the `BRPs` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_brps(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_brps(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `BRPs` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_brps(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::BRPS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PMSS` field.
+    pub const fn pmss(self) -> u8 {
+        Self::PMSS_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `PMSS` field.
+    pub const fn set_pmss(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::PMSS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PMSS` field set to the given value.
+    pub const fn with_pmss(mut self, value: u8) -> Self {
+        self.set_pmss(value);
+        self
+    }
+
+    /// Sets the value of the `PMSS` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_pmss(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_pmss(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PMSS` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_pmss(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::PMSS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `WRPs` field.
+    pub const fn wrps(self) -> u8 {
+        Self::WRPS_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `WRPs` field.
+    pub const fn set_wrps(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::WRPS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `WRPs` field set to the given value.
+    pub const fn with_wrps(mut self, value: u8) -> Self {
+        self.set_wrps(value);
+        self
+    }
+
+    /// Sets the value of the `WRPs` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_wrps(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_wrps(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `WRPs` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_wrps(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::WRPS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `CTX_CMPs` field.
+    pub const fn ctx_cmps(self) -> u8 {
+        Self::CTX_CMPS_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `CTX_CMPs` field.
+    pub const fn set_ctx_cmps(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::CTX_CMPS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `CTX_CMPs` field set to the given value.
+    pub const fn with_ctx_cmps(mut self, value: u8) -> Self {
+        self.set_ctx_cmps(value);
+        self
+    }
+
+    /// Sets the value of the `CTX_CMPs` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ctx_cmps(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ctx_cmps(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `CTX_CMPs` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ctx_cmps(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::CTX_CMPS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PMSVer` field.
+    pub const fn pmsver(self) -> u8 {
+        Self::PMSVER_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `PMSVer` field.
+    pub const fn set_pmsver(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::PMSVER_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PMSVer` field set to the given value.
+    pub const fn with_pmsver(mut self, value: u8) -> Self {
+        self.set_pmsver(value);
+        self
+    }
+
+    /// Sets the value of the `PMSVer` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_pmsver(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_pmsver(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PMSVer` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_pmsver(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::PMSVER_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `DoubleLock` field.
+    pub const fn doublelock(self) -> u8 {
+        Self::DOUBLELOCK_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `DoubleLock` field.
+    pub const fn set_doublelock(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::DOUBLELOCK_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `DoubleLock` field set to the given value.
+    pub const fn with_doublelock(mut self, value: u8) -> Self {
+        self.set_doublelock(value);
+        self
+    }
+
+    /// Sets the value of the `DoubleLock` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_doublelock(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_doublelock(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `DoubleLock` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_doublelock(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::DOUBLELOCK_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `TraceFilt` field.
+    pub const fn tracefilt(self) -> u8 {
+        Self::TRACEFILT_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `TraceFilt` field.
+    pub const fn set_tracefilt(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::TRACEFILT_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `TraceFilt` field set to the given value.
+    pub const fn with_tracefilt(mut self, value: u8) -> Self {
+        self.set_tracefilt(value);
+        self
+    }
+
+    /// Sets the value of the `TraceFilt` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_tracefilt(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_tracefilt(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `TraceFilt` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_tracefilt(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::TRACEFILT_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `TraceBuffer` field.
+    pub const fn tracebuffer(self) -> u8 {
+        Self::TRACEBUFFER_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `TraceBuffer` field.
+    pub const fn set_tracebuffer(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::TRACEBUFFER_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `TraceBuffer` field set to the given value.
+    pub const fn with_tracebuffer(mut self, value: u8) -> Self {
+        self.set_tracebuffer(value);
+        self
+    }
+
+    /// Sets the value of the `TraceBuffer` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_tracebuffer(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_tracebuffer(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `TraceBuffer` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_tracebuffer(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::TRACEBUFFER_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `MTPMU` field.
+    pub const fn mtpmu(self) -> u8 {
+        Self::MTPMU_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `MTPMU` field.
+    pub const fn set_mtpmu(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::MTPMU_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `MTPMU` field set to the given value.
+    pub const fn with_mtpmu(mut self, value: u8) -> Self {
+        self.set_mtpmu(value);
+        self
+    }
+
+    /// Sets the value of the `MTPMU` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_mtpmu(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_mtpmu(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `MTPMU` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_mtpmu(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::MTPMU_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `BRBE` field.
+    pub const fn brbe(self) -> u8 {
+        Self::BRBE_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `BRBE` field.
+    pub const fn set_brbe(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::BRBE_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `BRBE` field set to the given value.
+    pub const fn with_brbe(mut self, value: u8) -> Self {
+        self.set_brbe(value);
+        self
+    }
+
+    /// Sets the value of the `BRBE` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_brbe(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_brbe(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `BRBE` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_brbe(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::BRBE_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ExtTrcBuff` field.
+    pub const fn exttrcbuff(self) -> u8 {
+        Self::EXTTRCBUFF_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `ExtTrcBuff` field.
+    pub const fn set_exttrcbuff(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::EXTTRCBUFF_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ExtTrcBuff` field set to the given value.
+    pub const fn with_exttrcbuff(mut self, value: u8) -> Self {
+        self.set_exttrcbuff(value);
+        self
+    }
+
+    /// Sets the value of the `ExtTrcBuff` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_exttrcbuff(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_exttrcbuff(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ExtTrcBuff` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_exttrcbuff(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::EXTTRCBUFF_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `HPMN0` field.
+    pub const fn hpmn0(self) -> u8 {
+        Self::HPMN0_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `HPMN0` field.
+    pub const fn set_hpmn0(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::HPMN0_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `HPMN0` field set to the given value.
+    pub const fn with_hpmn0(mut self, value: u8) -> Self {
+        self.set_hpmn0(value);
+        self
+    }
+
+    /// Sets the value of the `HPMN0` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_hpmn0(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_hpmn0(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `HPMN0` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_hpmn0(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::HPMN0_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for IdAa64dfr0El1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("IdAa64dfr0El1");
+        let bits = self.bits();
+        s.field("DebugVer", &Self::DEBUGVER_FIELD.extract(bits));
+        s.field("TraceVer", &Self::TRACEVER_FIELD.extract(bits));
+        s.field("PMUVer", &Self::PMUVER_FIELD.extract(bits));
+        s.field("BRPs", &Self::BRPS_FIELD.extract(bits));
+        s.field("PMSS", &Self::PMSS_FIELD.extract(bits));
+        s.field("WRPs", &Self::WRPS_FIELD.extract(bits));
+        s.field("CTX_CMPs", &Self::CTX_CMPS_FIELD.extract(bits));
+        s.field("PMSVer", &Self::PMSVER_FIELD.extract(bits));
+        s.field("DoubleLock", &Self::DOUBLELOCK_FIELD.extract(bits));
+        s.field("TraceFilt", &Self::TRACEFILT_FIELD.extract(bits));
+        s.field("TraceBuffer", &Self::TRACEBUFFER_FIELD.extract(bits));
+        s.field("MTPMU", &Self::MTPMU_FIELD.extract(bits));
+        s.field("BRBE", &Self::BRBE_FIELD.extract(bits));
+        s.field("ExtTrcBuff", &Self::EXTTRCBUFF_FIELD.extract(bits));
+        s.field("HPMN0", &Self::HPMN0_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for IdAa64dfr0El1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "IdAa64dfr0El1 {{ DebugVer: {=u64}, TraceVer: {=u64}, PMUVer: {=u64}, BRPs: {=u64}, PMSS: {=u64}, WRPs: {=u64}, CTX_CMPs: {=u64}, PMSVer: {=u64}, DoubleLock: {=u64}, TraceFilt: {=u64}, TraceBuffer: {=u64}, MTPMU: {=u64}, BRBE: {=u64}, ExtTrcBuff: {=u64}, HPMN0: {=u64} }}",
+            Self::DEBUGVER_FIELD.extract(self.bits()),
+            Self::TRACEVER_FIELD.extract(self.bits()),
+            Self::PMUVER_FIELD.extract(self.bits()),
+            Self::BRPS_FIELD.extract(self.bits()),
+            Self::PMSS_FIELD.extract(self.bits()),
+            Self::WRPS_FIELD.extract(self.bits()),
+            Self::CTX_CMPS_FIELD.extract(self.bits()),
+            Self::PMSVER_FIELD.extract(self.bits()),
+            Self::DOUBLELOCK_FIELD.extract(self.bits()),
+            Self::TRACEFILT_FIELD.extract(self.bits()),
+            Self::TRACEBUFFER_FIELD.extract(self.bits()),
+            Self::MTPMU_FIELD.extract(self.bits()),
+            Self::BRBE_FIELD.extract(self.bits()),
+            Self::EXTTRCBUFF_FIELD.extract(self.bits()),
+            Self::HPMN0_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for IdAa64dfr0El1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for IdAa64dfr0El1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64dfr0El1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("DebugVer"),
+                old: Self::DEBUGVER_FIELD.extract(self.bits()),
+                new: Self::DEBUGVER_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TraceVer"),
+                old: Self::TRACEVER_FIELD.extract(self.bits()),
+                new: Self::TRACEVER_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMUVer"),
+                old: Self::PMUVER_FIELD.extract(self.bits()),
+                new: Self::PMUVER_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("BRPs"),
+                old: Self::BRPS_FIELD.extract(self.bits()),
+                new: Self::BRPS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMSS"),
+                old: Self::PMSS_FIELD.extract(self.bits()),
+                new: Self::PMSS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("WRPs"),
+                old: Self::WRPS_FIELD.extract(self.bits()),
+                new: Self::WRPS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CTX_CMPs"),
+                old: Self::CTX_CMPS_FIELD.extract(self.bits()),
+                new: Self::CTX_CMPS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMSVer"),
+                old: Self::PMSVER_FIELD.extract(self.bits()),
+                new: Self::PMSVER_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DoubleLock"),
+                old: Self::DOUBLELOCK_FIELD.extract(self.bits()),
+                new: Self::DOUBLELOCK_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TraceFilt"),
+                old: Self::TRACEFILT_FIELD.extract(self.bits()),
+                new: Self::TRACEFILT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TraceBuffer"),
+                old: Self::TRACEBUFFER_FIELD.extract(self.bits()),
+                new: Self::TRACEBUFFER_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("MTPMU"),
+                old: Self::MTPMU_FIELD.extract(self.bits()),
+                new: Self::MTPMU_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("BRBE"),
+                old: Self::BRBE_FIELD.extract(self.bits()),
+                new: Self::BRBE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ExtTrcBuff"),
+                old: Self::EXTTRCBUFF_FIELD.extract(self.bits()),
+                new: Self::EXTTRCBUFF_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HPMN0"),
+                old: Self::HPMN0_FIELD.extract(self.bits()),
+                new: Self::HPMN0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_0000_0000_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_0000_0000_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64dfr0El1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64dfr0El1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `ID_AA64DFR1_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct IdAa64dfr1El1: u64 {
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64dfr1El1 {
+    /// Descriptor of the `SYSPMUID` field, for use with [`FieldAccess`].
+    pub const SYSPMUID_FIELD: Field<Self, u8, 0, 8> = Field::new("ID_AA64DFR1_EL1", "SYSPMUID");
+    /// Descriptor of the `BRPs` field, for use with [`FieldAccess`].
+    pub const BRPS_FIELD: Field<Self, u8, 8, 8> = Field::new("ID_AA64DFR1_EL1", "BRPs");
+    /// Descriptor of the `WRPs` field, for use with [`FieldAccess`].
+    pub const WRPS_FIELD: Field<Self, u8, 16, 8> = Field::new("ID_AA64DFR1_EL1", "WRPs");
+    /// Descriptor of the `CTX_CMPs` field, for use with [`FieldAccess`].
+    pub const CTX_CMPS_FIELD: Field<Self, u8, 24, 8> = Field::new("ID_AA64DFR1_EL1", "CTX_CMPs");
+    /// Descriptor of the `SPMU` field, for use with [`FieldAccess`].
+    pub const SPMU_FIELD: Field<Self, u8, 32, 4> = Field::new("ID_AA64DFR1_EL1", "SPMU");
+    /// Descriptor of the `PMICNTR` field, for use with [`FieldAccess`].
+    pub const PMICNTR_FIELD: Field<Self, u8, 36, 4> = Field::new("ID_AA64DFR1_EL1", "PMICNTR");
+    /// Descriptor of the `ABLE` field, for use with [`FieldAccess`].
+    pub const ABLE_FIELD: Field<Self, u8, 40, 4> = Field::new("ID_AA64DFR1_EL1", "ABLE");
+    /// Descriptor of the `ITE` field, for use with [`FieldAccess`].
+    pub const ITE_FIELD: Field<Self, u8, 44, 4> = Field::new("ID_AA64DFR1_EL1", "ITE");
+    /// Descriptor of the `EBEP` field, for use with [`FieldAccess`].
+    pub const EBEP_FIELD: Field<Self, u8, 48, 4> = Field::new("ID_AA64DFR1_EL1", "EBEP");
+    /// Descriptor of the `DPFZS` field, for use with [`FieldAccess`].
+    pub const DPFZS_FIELD: Field<Self, u8, 52, 4> = Field::new("ID_AA64DFR1_EL1", "DPFZS");
+    /// Descriptor of the `ABL_CMPs` field, for use with [`FieldAccess`].
+    pub const ABL_CMPS_FIELD: Field<Self, u8, 56, 8> = Field::new("ID_AA64DFR1_EL1", "ABL_CMPs");
+
+    /// Returns the value of the `SYSPMUID` field.
+    pub const fn syspmuid(self) -> u8 {
+        Self::SYSPMUID_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SYSPMUID` field.
+    pub const fn set_syspmuid(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SYSPMUID_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SYSPMUID` field set to the given value.
+    pub const fn with_syspmuid(mut self, value: u8) -> Self {
+        self.set_syspmuid(value);
+        self
+    }
+
+    /// Sets the value of the `SYSPMUID` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_syspmuid(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_syspmuid(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SYSPMUID` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_syspmuid(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SYSPMUID_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `BRPs` field.
+    pub const fn brps(self) -> u8 {
+        Self::BRPS_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `BRPs` field.
+    pub const fn set_brps(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::BRPS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `BRPs` field set to the given value.
+    pub const fn with_brps(mut self, value: u8) -> Self {
+        self.set_brps(value);
+        self
+    }
+
+    /// Sets the value of the `BRPs` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_brps(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_brps(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `BRPs` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_brps(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::BRPS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `WRPs` field.
+    pub const fn wrps(self) -> u8 {
+        Self::WRPS_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `WRPs` field.
+    pub const fn set_wrps(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::WRPS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `WRPs` field set to the given value.
+    pub const fn with_wrps(mut self, value: u8) -> Self {
+        self.set_wrps(value);
+        self
+    }
+
+    /// Sets the value of the `WRPs` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_wrps(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_wrps(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `WRPs` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_wrps(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::WRPS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `CTX_CMPs` field.
+    pub const fn ctx_cmps(self) -> u8 {
+        Self::CTX_CMPS_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `CTX_CMPs` field.
+    pub const fn set_ctx_cmps(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::CTX_CMPS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `CTX_CMPs` field set to the given value.
+    pub const fn with_ctx_cmps(mut self, value: u8) -> Self {
+        self.set_ctx_cmps(value);
+        self
+    }
+
+    /// Sets the value of the `CTX_CMPs` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ctx_cmps(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ctx_cmps(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `CTX_CMPs` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ctx_cmps(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::CTX_CMPS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SPMU` field.
+    pub const fn spmu(self) -> u8 {
+        Self::SPMU_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SPMU` field.
+    pub const fn set_spmu(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SPMU_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SPMU` field set to the given value.
+    pub const fn with_spmu(mut self, value: u8) -> Self {
+        self.set_spmu(value);
+        self
+    }
+
+    /// Sets the value of the `SPMU` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_spmu(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_spmu(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SPMU` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_spmu(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SPMU_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PMICNTR` field.
+    pub const fn pmicntr(self) -> u8 {
+        Self::PMICNTR_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `PMICNTR` field.
+    pub const fn set_pmicntr(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::PMICNTR_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PMICNTR` field set to the given value.
+    pub const fn with_pmicntr(mut self, value: u8) -> Self {
+        self.set_pmicntr(value);
+        self
+    }
+
+    /// Sets the value of the `PMICNTR` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_pmicntr(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_pmicntr(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PMICNTR` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_pmicntr(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::PMICNTR_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ABLE` field.
+    pub const fn able(self) -> u8 {
+        Self::ABLE_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `ABLE` field.
+    pub const fn set_able(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::ABLE_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ABLE` field set to the given value.
+    pub const fn with_able(mut self, value: u8) -> Self {
+        self.set_able(value);
+        self
+    }
+
+    /// Sets the value of the `ABLE` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_able(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_able(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ABLE` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_able(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::ABLE_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ITE` field.
+    pub const fn ite(self) -> u8 {
+        Self::ITE_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `ITE` field.
+    pub const fn set_ite(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::ITE_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ITE` field set to the given value.
+    pub const fn with_ite(mut self, value: u8) -> Self {
+        self.set_ite(value);
+        self
+    }
+
+    /// Sets the value of the `ITE` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ite(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ite(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ITE` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ite(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::ITE_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `EBEP` field.
+    pub const fn ebep(self) -> u8 {
+        Self::EBEP_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `EBEP` field.
+    pub const fn set_ebep(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::EBEP_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `EBEP` field set to the given value.
+    pub const fn with_ebep(mut self, value: u8) -> Self {
+        self.set_ebep(value);
+        self
+    }
+
+    /// Sets the value of the `EBEP` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ebep(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ebep(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `EBEP` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ebep(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::EBEP_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `DPFZS` field.
+    pub const fn dpfzs(self) -> u8 {
+        Self::DPFZS_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `DPFZS` field.
+    pub const fn set_dpfzs(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::DPFZS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `DPFZS` field set to the given value.
+    pub const fn with_dpfzs(mut self, value: u8) -> Self {
+        self.set_dpfzs(value);
+        self
+    }
+
+    /// Sets the value of the `DPFZS` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_dpfzs(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_dpfzs(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `DPFZS` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_dpfzs(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::DPFZS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ABL_CMPs` field.
+    pub const fn abl_cmps(self) -> u8 {
+        Self::ABL_CMPS_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `ABL_CMPs` field.
+    pub const fn set_abl_cmps(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::ABL_CMPS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ABL_CMPs` field set to the given value.
+    pub const fn with_abl_cmps(mut self, value: u8) -> Self {
+        self.set_abl_cmps(value);
+        self
+    }
+
+    /// Sets the value of the `ABL_CMPs` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_abl_cmps(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_abl_cmps(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ABL_CMPs` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_abl_cmps(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::ABL_CMPS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for IdAa64dfr1El1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("IdAa64dfr1El1");
+        let bits = self.bits();
+        s.field("SYSPMUID", &Self::SYSPMUID_FIELD.extract(bits));
+        s.field("BRPs", &Self::BRPS_FIELD.extract(bits));
+        s.field("WRPs", &Self::WRPS_FIELD.extract(bits));
+        s.field("CTX_CMPs", &Self::CTX_CMPS_FIELD.extract(bits));
+        s.field("SPMU", &Self::SPMU_FIELD.extract(bits));
+        s.field("PMICNTR", &Self::PMICNTR_FIELD.extract(bits));
+        s.field("ABLE", &Self::ABLE_FIELD.extract(bits));
+        s.field("ITE", &Self::ITE_FIELD.extract(bits));
+        s.field("EBEP", &Self::EBEP_FIELD.extract(bits));
+        s.field("DPFZS", &Self::DPFZS_FIELD.extract(bits));
+        s.field("ABL_CMPs", &Self::ABL_CMPS_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for IdAa64dfr1El1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "IdAa64dfr1El1 {{ SYSPMUID: {=u64}, BRPs: {=u64}, WRPs: {=u64}, CTX_CMPs: {=u64}, SPMU: {=u64}, PMICNTR: {=u64}, ABLE: {=u64}, ITE: {=u64}, EBEP: {=u64}, DPFZS: {=u64}, ABL_CMPs: {=u64} }}",
+            Self::SYSPMUID_FIELD.extract(self.bits()),
+            Self::BRPS_FIELD.extract(self.bits()),
+            Self::WRPS_FIELD.extract(self.bits()),
+            Self::CTX_CMPS_FIELD.extract(self.bits()),
+            Self::SPMU_FIELD.extract(self.bits()),
+            Self::PMICNTR_FIELD.extract(self.bits()),
+            Self::ABLE_FIELD.extract(self.bits()),
+            Self::ITE_FIELD.extract(self.bits()),
+            Self::EBEP_FIELD.extract(self.bits()),
+            Self::DPFZS_FIELD.extract(self.bits()),
+            Self::ABL_CMPS_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for IdAa64dfr1El1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for IdAa64dfr1El1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64dfr1El1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("SYSPMUID"),
+                old: Self::SYSPMUID_FIELD.extract(self.bits()),
+                new: Self::SYSPMUID_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("BRPs"),
+                old: Self::BRPS_FIELD.extract(self.bits()),
+                new: Self::BRPS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("WRPs"),
+                old: Self::WRPS_FIELD.extract(self.bits()),
+                new: Self::WRPS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CTX_CMPs"),
+                old: Self::CTX_CMPS_FIELD.extract(self.bits()),
+                new: Self::CTX_CMPS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SPMU"),
+                old: Self::SPMU_FIELD.extract(self.bits()),
+                new: Self::SPMU_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMICNTR"),
+                old: Self::PMICNTR_FIELD.extract(self.bits()),
+                new: Self::PMICNTR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ABLE"),
+                old: Self::ABLE_FIELD.extract(self.bits()),
+                new: Self::ABLE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ITE"),
+                old: Self::ITE_FIELD.extract(self.bits()),
+                new: Self::ITE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EBEP"),
+                old: Self::EBEP_FIELD.extract(self.bits()),
+                new: Self::EBEP_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DPFZS"),
+                old: Self::DPFZS_FIELD.extract(self.bits()),
+                new: Self::DPFZS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ABL_CMPs"),
+                old: Self::ABL_CMPS_FIELD.extract(self.bits()),
+                new: Self::ABL_CMPS_FIELD.extract(other.bits()),
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64dfr1El1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64dfr1El1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `ID_AA64ISAR1_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct IdAa64isar1El1: u64 {
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64isar1El1 {
+    /// Descriptor of the `DPB` field, for use with [`FieldAccess`].
+    pub const DPB_FIELD: Field<Self, u8, 0, 4> = Field::new("ID_AA64ISAR1_EL1", "DPB");
+    /// Descriptor of the `APA` field, for use with [`FieldAccess`].
+    pub const APA_FIELD: Field<Self, u8, 4, 4> = Field::new("ID_AA64ISAR1_EL1", "APA");
+    /// Descriptor of the `API` field, for use with [`FieldAccess`].
+    pub const API_FIELD: Field<Self, u8, 8, 4> = Field::new("ID_AA64ISAR1_EL1", "API");
+    /// Descriptor of the `JSCVT` field, for use with [`FieldAccess`].
+    pub const JSCVT_FIELD: Field<Self, u8, 12, 4> = Field::new("ID_AA64ISAR1_EL1", "JSCVT");
+    /// Descriptor of the `FCMA` field, for use with [`FieldAccess`].
+    pub const FCMA_FIELD: Field<Self, u8, 16, 4> = Field::new("ID_AA64ISAR1_EL1", "FCMA");
+    /// Descriptor of the `LRCPC` field, for use with [`FieldAccess`].
+    pub const LRCPC_FIELD: Field<Self, u8, 20, 4> = Field::new("ID_AA64ISAR1_EL1", "LRCPC");
+    /// Descriptor of the `GPA` field, for use with [`FieldAccess`].
+    pub const GPA_FIELD: Field<Self, u8, 24, 4> = Field::new("ID_AA64ISAR1_EL1", "GPA");
+    /// Descriptor of the `GPI` field, for use with [`FieldAccess`].
+    pub const GPI_FIELD: Field<Self, u8, 28, 4> = Field::new("ID_AA64ISAR1_EL1", "GPI");
+    /// Descriptor of the `FRINTTS` field, for use with [`FieldAccess`].
+    pub const FRINTTS_FIELD: Field<Self, u8, 32, 4> = Field::new("ID_AA64ISAR1_EL1", "FRINTTS");
+    /// Descriptor of the `SB` field, for use with [`FieldAccess`].
+    pub const SB_FIELD: Field<Self, u8, 36, 4> = Field::new("ID_AA64ISAR1_EL1", "SB");
+    /// Descriptor of the `SPECRES` field, for use with [`FieldAccess`].
+    pub const SPECRES_FIELD: Field<Self, u8, 40, 4> = Field::new("ID_AA64ISAR1_EL1", "SPECRES");
+    /// Descriptor of the `BF16` field, for use with [`FieldAccess`].
+    pub const BF16_FIELD: Field<Self, u8, 44, 4> = Field::new("ID_AA64ISAR1_EL1", "BF16");
+    /// Descriptor of the `DGH` field, for use with [`FieldAccess`].
+    pub const DGH_FIELD: Field<Self, u8, 48, 4> = Field::new("ID_AA64ISAR1_EL1", "DGH");
+    /// Descriptor of the `I8MM` field, for use with [`FieldAccess`].
+    pub const I8MM_FIELD: Field<Self, u8, 52, 4> = Field::new("ID_AA64ISAR1_EL1", "I8MM");
+    /// Descriptor of the `XS` field, for use with [`FieldAccess`].
+    pub const XS_FIELD: Field<Self, u8, 56, 4> = Field::new("ID_AA64ISAR1_EL1", "XS");
+    /// Descriptor of the `LS64` field, for use with [`FieldAccess`].
+    pub const LS64_FIELD: Field<Self, u8, 60, 4> = Field::new("ID_AA64ISAR1_EL1", "LS64");
+
+    /// Returns the value of the `DPB` field.
+    pub const fn dpb(self) -> u8 {
+        Self::DPB_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `DPB` field.
+    pub const fn set_dpb(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::DPB_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `DPB` field set to the given value.
+    pub const fn with_dpb(mut self, value: u8) -> Self {
+        self.set_dpb(value);
+        self
+    }
+
+    /// Sets the value of the `DPB` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_dpb(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_dpb(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `DPB` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_dpb(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::DPB_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `APA` field.
+    pub const fn apa(self) -> u8 {
+        Self::APA_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `APA` field.
+    pub const fn set_apa(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::APA_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `APA` field set to the given value.
+    pub const fn with_apa(mut self, value: u8) -> Self {
+        self.set_apa(value);
+        self
+    }
+
+    /// Sets the value of the `APA` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_apa(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_apa(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `APA` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_apa(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::APA_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `API` field.
+    pub const fn api(self) -> u8 {
+        Self::API_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `API` field.
+    pub const fn set_api(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::API_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `API` field set to the given value.
+    pub const fn with_api(mut self, value: u8) -> Self {
+        self.set_api(value);
+        self
+    }
+
+    /// Sets the value of the `API` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_api(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_api(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `API` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_api(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::API_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `JSCVT` field.
+    pub const fn jscvt(self) -> u8 {
+        Self::JSCVT_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `JSCVT` field.
+    pub const fn set_jscvt(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::JSCVT_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `JSCVT` field set to the given value.
+    pub const fn with_jscvt(mut self, value: u8) -> Self {
+        self.set_jscvt(value);
+        self
+    }
+
+    /// Sets the value of the `JSCVT` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_jscvt(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_jscvt(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `JSCVT` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_jscvt(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::JSCVT_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `FCMA` field.
+    pub const fn fcma(self) -> u8 {
+        Self::FCMA_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `FCMA` field.
+    pub const fn set_fcma(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::FCMA_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `FCMA` field set to the given value.
+    pub const fn with_fcma(mut self, value: u8) -> Self {
+        self.set_fcma(value);
+        self
+    }
+
+    /// Sets the value of the `FCMA` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_fcma(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_fcma(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `FCMA` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_fcma(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::FCMA_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `LRCPC` field.
+    pub const fn lrcpc(self) -> u8 {
+        Self::LRCPC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `LRCPC` field.
+    pub const fn set_lrcpc(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::LRCPC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `LRCPC` field set to the given value.
+    pub const fn with_lrcpc(mut self, value: u8) -> Self {
+        self.set_lrcpc(value);
+        self
+    }
+
+    /// Sets the value of the `LRCPC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_lrcpc(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_lrcpc(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `LRCPC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_lrcpc(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::LRCPC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `GPA` field.
+    pub const fn gpa(self) -> u8 {
+        Self::GPA_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `GPA` field.
+    pub const fn set_gpa(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::GPA_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `GPA` field set to the given value.
+    pub const fn with_gpa(mut self, value: u8) -> Self {
+        self.set_gpa(value);
+        self
+    }
+
+    /// Sets the value of the `GPA` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_gpa(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_gpa(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `GPA` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_gpa(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::GPA_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `GPI` field.
+    pub const fn gpi(self) -> u8 {
+        Self::GPI_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `GPI` field.
+    pub const fn set_gpi(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::GPI_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `GPI` field set to the given value.
+    pub const fn with_gpi(mut self, value: u8) -> Self {
+        self.set_gpi(value);
+        self
+    }
+
+    /// Sets the value of the `GPI` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_gpi(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_gpi(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `GPI` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_gpi(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::GPI_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `FRINTTS` field.
+    pub const fn frintts(self) -> u8 {
+        Self::FRINTTS_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `FRINTTS` field.
+    pub const fn set_frintts(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::FRINTTS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `FRINTTS` field set to the given value.
+    pub const fn with_frintts(mut self, value: u8) -> Self {
+        self.set_frintts(value);
+        self
+    }
+
+    /// Sets the value of the `FRINTTS` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_frintts(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_frintts(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `FRINTTS` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_frintts(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::FRINTTS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SB` field.
+    pub const fn sb(self) -> u8 {
+        Self::SB_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SB` field.
+    pub const fn set_sb(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SB_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SB` field set to the given value.
+    pub const fn with_sb(mut self, value: u8) -> Self {
+        self.set_sb(value);
+        self
+    }
+
+    /// Sets the value of the `SB` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_sb(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_sb(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SB` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_sb(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SB_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SPECRES` field.
+    pub const fn specres(self) -> u8 {
+        Self::SPECRES_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SPECRES` field.
+    pub const fn set_specres(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SPECRES_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SPECRES` field set to the given value.
+    pub const fn with_specres(mut self, value: u8) -> Self {
+        self.set_specres(value);
+        self
+    }
+
+    /// Sets the value of the `SPECRES` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_specres(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_specres(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SPECRES` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_specres(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SPECRES_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `BF16` field.
+    pub const fn bf16(self) -> u8 {
+        Self::BF16_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `BF16` field.
+    pub const fn set_bf16(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::BF16_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `BF16` field set to the given value.
+    pub const fn with_bf16(mut self, value: u8) -> Self {
+        self.set_bf16(value);
+        self
+    }
+
+    /// Sets the value of the `BF16` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_bf16(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_bf16(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `BF16` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_bf16(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::BF16_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `DGH` field.
+    pub const fn dgh(self) -> u8 {
+        Self::DGH_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `DGH` field.
+    pub const fn set_dgh(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::DGH_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `DGH` field set to the given value.
+    pub const fn with_dgh(mut self, value: u8) -> Self {
+        self.set_dgh(value);
+        self
+    }
+
+    /// Sets the value of the `DGH` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_dgh(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_dgh(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `DGH` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_dgh(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::DGH_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `I8MM` field.
+    pub const fn i8mm(self) -> u8 {
+        Self::I8MM_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `I8MM` field.
+    pub const fn set_i8mm(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::I8MM_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `I8MM` field set to the given value.
+    pub const fn with_i8mm(mut self, value: u8) -> Self {
+        self.set_i8mm(value);
+        self
+    }
+
+    /// Sets the value of the `I8MM` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_i8mm(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_i8mm(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `I8MM` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_i8mm(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::I8MM_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `XS` field.
+    pub const fn xs(self) -> u8 {
+        Self::XS_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `XS` field.
+    pub const fn set_xs(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::XS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `XS` field set to the given value.
+    pub const fn with_xs(mut self, value: u8) -> Self {
+        self.set_xs(value);
+        self
+    }
+
+    /// Sets the value of the `XS` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_xs(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_xs(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `XS` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_xs(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::XS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `LS64` field.
+    pub const fn ls64(self) -> u8 {
+        Self::LS64_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `LS64` field.
+    pub const fn set_ls64(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::LS64_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `LS64` field set to the given value.
+    pub const fn with_ls64(mut self, value: u8) -> Self {
+        self.set_ls64(value);
+        self
+    }
+
+    /// Sets the value of the `LS64` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ls64(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ls64(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `LS64` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ls64(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::LS64_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for IdAa64isar1El1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("IdAa64isar1El1");
+        let bits = self.bits();
+        s.field("DPB", &Self::DPB_FIELD.extract(bits));
+        s.field("APA", &Self::APA_FIELD.extract(bits));
+        s.field("API", &Self::API_FIELD.extract(bits));
+        s.field("JSCVT", &Self::JSCVT_FIELD.extract(bits));
+        s.field("FCMA", &Self::FCMA_FIELD.extract(bits));
+        s.field("LRCPC", &Self::LRCPC_FIELD.extract(bits));
+        s.field("GPA", &Self::GPA_FIELD.extract(bits));
+        s.field("GPI", &Self::GPI_FIELD.extract(bits));
+        s.field("FRINTTS", &Self::FRINTTS_FIELD.extract(bits));
+        s.field("SB", &Self::SB_FIELD.extract(bits));
+        s.field("SPECRES", &Self::SPECRES_FIELD.extract(bits));
+        s.field("BF16", &Self::BF16_FIELD.extract(bits));
+        s.field("DGH", &Self::DGH_FIELD.extract(bits));
+        s.field("I8MM", &Self::I8MM_FIELD.extract(bits));
+        s.field("XS", &Self::XS_FIELD.extract(bits));
+        s.field("LS64", &Self::LS64_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for IdAa64isar1El1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "IdAa64isar1El1 {{ DPB: {=u64}, APA: {=u64}, API: {=u64}, JSCVT: {=u64}, FCMA: {=u64}, LRCPC: {=u64}, GPA: {=u64}, GPI: {=u64}, FRINTTS: {=u64}, SB: {=u64}, SPECRES: {=u64}, BF16: {=u64}, DGH: {=u64}, I8MM: {=u64}, XS: {=u64}, LS64: {=u64} }}",
+            Self::DPB_FIELD.extract(self.bits()),
+            Self::APA_FIELD.extract(self.bits()),
+            Self::API_FIELD.extract(self.bits()),
+            Self::JSCVT_FIELD.extract(self.bits()),
+            Self::FCMA_FIELD.extract(self.bits()),
+            Self::LRCPC_FIELD.extract(self.bits()),
+            Self::GPA_FIELD.extract(self.bits()),
+            Self::GPI_FIELD.extract(self.bits()),
+            Self::FRINTTS_FIELD.extract(self.bits()),
+            Self::SB_FIELD.extract(self.bits()),
+            Self::SPECRES_FIELD.extract(self.bits()),
+            Self::BF16_FIELD.extract(self.bits()),
+            Self::DGH_FIELD.extract(self.bits()),
+            Self::I8MM_FIELD.extract(self.bits()),
+            Self::XS_FIELD.extract(self.bits()),
+            Self::LS64_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for IdAa64isar1El1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for IdAa64isar1El1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64isar1El1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("DPB"),
+                old: Self::DPB_FIELD.extract(self.bits()),
+                new: Self::DPB_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("APA"),
+                old: Self::APA_FIELD.extract(self.bits()),
+                new: Self::APA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("API"),
+                old: Self::API_FIELD.extract(self.bits()),
+                new: Self::API_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("JSCVT"),
+                old: Self::JSCVT_FIELD.extract(self.bits()),
+                new: Self::JSCVT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FCMA"),
+                old: Self::FCMA_FIELD.extract(self.bits()),
+                new: Self::FCMA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("LRCPC"),
+                old: Self::LRCPC_FIELD.extract(self.bits()),
+                new: Self::LRCPC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("GPA"),
+                old: Self::GPA_FIELD.extract(self.bits()),
+                new: Self::GPA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("GPI"),
+                old: Self::GPI_FIELD.extract(self.bits()),
+                new: Self::GPI_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FRINTTS"),
+                old: Self::FRINTTS_FIELD.extract(self.bits()),
+                new: Self::FRINTTS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SB"),
+                old: Self::SB_FIELD.extract(self.bits()),
+                new: Self::SB_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SPECRES"),
+                old: Self::SPECRES_FIELD.extract(self.bits()),
+                new: Self::SPECRES_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("BF16"),
+                old: Self::BF16_FIELD.extract(self.bits()),
+                new: Self::BF16_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DGH"),
+                old: Self::DGH_FIELD.extract(self.bits()),
+                new: Self::DGH_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("I8MM"),
+                old: Self::I8MM_FIELD.extract(self.bits()),
+                new: Self::I8MM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("XS"),
+                old: Self::XS_FIELD.extract(self.bits()),
+                new: Self::XS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("LS64"),
+                old: Self::LS64_FIELD.extract(self.bits()),
+                new: Self::LS64_FIELD.extract(other.bits()),
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64isar1El1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64isar1El1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `ID_AA64ISAR2_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct IdAa64isar2El1: u64 {
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64isar2El1 {
+    /// Descriptor of the `WFxT` field, for use with [`FieldAccess`].
+    pub const WFXT_FIELD: Field<Self, u8, 0, 4> = Field::new("ID_AA64ISAR2_EL1", "WFxT");
+    /// Descriptor of the `RPRES` field, for use with [`FieldAccess`].
+    pub const RPRES_FIELD: Field<Self, u8, 4, 4> = Field::new("ID_AA64ISAR2_EL1", "RPRES");
+    /// Descriptor of the `GPA3` field, for use with [`FieldAccess`].
+    pub const GPA3_FIELD: Field<Self, u8, 8, 4> = Field::new("ID_AA64ISAR2_EL1", "GPA3");
+    /// Descriptor of the `APA3` field, for use with [`FieldAccess`].
+    pub const APA3_FIELD: Field<Self, u8, 12, 4> = Field::new("ID_AA64ISAR2_EL1", "APA3");
+    /// Descriptor of the `MOPS` field, for use with [`FieldAccess`].
+    pub const MOPS_FIELD: Field<Self, u8, 16, 4> = Field::new("ID_AA64ISAR2_EL1", "MOPS");
+    /// Descriptor of the `BC` field, for use with [`FieldAccess`].
+    pub const BC_FIELD: Field<Self, u8, 20, 4> = Field::new("ID_AA64ISAR2_EL1", "BC");
+    /// Descriptor of the `PAC_frac` field, for use with [`FieldAccess`].
+    pub const PAC_FRAC_FIELD: Field<Self, u8, 24, 4> = Field::new("ID_AA64ISAR2_EL1", "PAC_frac");
+    /// Descriptor of the `CLRBHB` field, for use with [`FieldAccess`].
+    pub const CLRBHB_FIELD: Field<Self, u8, 28, 4> = Field::new("ID_AA64ISAR2_EL1", "CLRBHB");
+    /// Descriptor of the `SYSREG_128` field, for use with [`FieldAccess`].
+    pub const SYSREG_128_FIELD: Field<Self, u8, 32, 4> =
+        Field::new("ID_AA64ISAR2_EL1", "SYSREG_128");
+    /// Descriptor of the `SYSINSTR_128` field, for use with [`FieldAccess`].
+    pub const SYSINSTR_128_FIELD: Field<Self, u8, 36, 4> =
+        Field::new("ID_AA64ISAR2_EL1", "SYSINSTR_128");
+    /// Descriptor of the `PRFMSLC` field, for use with [`FieldAccess`].
+    pub const PRFMSLC_FIELD: Field<Self, u8, 40, 4> = Field::new("ID_AA64ISAR2_EL1", "PRFMSLC");
+    /// Descriptor of the `PCDPHINT` field, for use with [`FieldAccess`].
+    pub const PCDPHINT_FIELD: Field<Self, u8, 44, 4> = Field::new("ID_AA64ISAR2_EL1", "PCDPHINT");
+    /// Descriptor of the `RPRFM` field, for use with [`FieldAccess`].
+    pub const RPRFM_FIELD: Field<Self, u8, 48, 4> = Field::new("ID_AA64ISAR2_EL1", "RPRFM");
+    /// Descriptor of the `CSSC` field, for use with [`FieldAccess`].
+    pub const CSSC_FIELD: Field<Self, u8, 52, 4> = Field::new("ID_AA64ISAR2_EL1", "CSSC");
+    /// Descriptor of the `LUT` field, for use with [`FieldAccess`].
+    pub const LUT_FIELD: Field<Self, u8, 56, 4> = Field::new("ID_AA64ISAR2_EL1", "LUT");
+    /// Descriptor of the `ATS1A` field, for use with [`FieldAccess`].
+    pub const ATS1A_FIELD: Field<Self, u8, 60, 4> = Field::new("ID_AA64ISAR2_EL1", "ATS1A");
+
+    /// Returns the value of the `WFxT` field.
+    pub const fn wfxt(self) -> u8 {
+        Self::WFXT_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `WFxT` field.
+    pub const fn set_wfxt(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::WFXT_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `WFxT` field set to the given value.
+    pub const fn with_wfxt(mut self, value: u8) -> Self {
+        self.set_wfxt(value);
+        self
+    }
+
+    /// Sets the value of the `WFxT` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_wfxt(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_wfxt(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `WFxT` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_wfxt(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::WFXT_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `RPRES` field.
+    pub const fn rpres(self) -> u8 {
+        Self::RPRES_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `RPRES` field.
+    pub const fn set_rpres(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::RPRES_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `RPRES` field set to the given value.
+    pub const fn with_rpres(mut self, value: u8) -> Self {
+        self.set_rpres(value);
+        self
+    }
+
+    /// Sets the value of the `RPRES` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_rpres(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_rpres(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `RPRES` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_rpres(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::RPRES_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `GPA3` field.
+    pub const fn gpa3(self) -> u8 {
+        Self::GPA3_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `GPA3` field.
+    pub const fn set_gpa3(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::GPA3_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `GPA3` field set to the given value.
+    pub const fn with_gpa3(mut self, value: u8) -> Self {
+        self.set_gpa3(value);
+        self
+    }
+
+    /// Sets the value of the `GPA3` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_gpa3(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_gpa3(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `GPA3` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_gpa3(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::GPA3_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `APA3` field.
+    pub const fn apa3(self) -> u8 {
+        Self::APA3_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `APA3` field.
+    pub const fn set_apa3(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::APA3_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `APA3` field set to the given value.
+    pub const fn with_apa3(mut self, value: u8) -> Self {
+        self.set_apa3(value);
+        self
+    }
+
+    /// Sets the value of the `APA3` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_apa3(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_apa3(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `APA3` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_apa3(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::APA3_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `MOPS` field.
+    pub const fn mops(self) -> u8 {
+        Self::MOPS_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `MOPS` field.
+    pub const fn set_mops(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::MOPS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `MOPS` field set to the given value.
+    pub const fn with_mops(mut self, value: u8) -> Self {
+        self.set_mops(value);
+        self
+    }
+
+    /// Sets the value of the `MOPS` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_mops(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_mops(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `MOPS` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_mops(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::MOPS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `BC` field.
+    pub const fn bc(self) -> u8 {
+        Self::BC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `BC` field.
+    pub const fn set_bc(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::BC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `BC` field set to the given value.
+    pub const fn with_bc(mut self, value: u8) -> Self {
+        self.set_bc(value);
+        self
+    }
+
+    /// Sets the value of the `BC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_bc(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_bc(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `BC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_bc(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::BC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PAC_frac` field.
+    pub const fn pac_frac(self) -> u8 {
+        Self::PAC_FRAC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `PAC_frac` field.
+    pub const fn set_pac_frac(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::PAC_FRAC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PAC_frac` field set to the given value.
+    pub const fn with_pac_frac(mut self, value: u8) -> Self {
+        self.set_pac_frac(value);
+        self
+    }
+
+    /// Sets the value of the `PAC_frac` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_pac_frac(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_pac_frac(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PAC_frac` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_pac_frac(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::PAC_FRAC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `CLRBHB` field.
+    pub const fn clrbhb(self) -> u8 {
+        Self::CLRBHB_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `CLRBHB` field.
+    pub const fn set_clrbhb(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::CLRBHB_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `CLRBHB` field set to the given value.
+    pub const fn with_clrbhb(mut self, value: u8) -> Self {
+        self.set_clrbhb(value);
+        self
+    }
+
+    /// Sets the value of the `CLRBHB` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_clrbhb(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_clrbhb(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `CLRBHB` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_clrbhb(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::CLRBHB_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SYSREG_128` field.
+    pub const fn sysreg_128(self) -> u8 {
+        Self::SYSREG_128_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SYSREG_128` field.
+    pub const fn set_sysreg_128(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SYSREG_128_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SYSREG_128` field set to the given value.
+    pub const fn with_sysreg_128(mut self, value: u8) -> Self {
+        self.set_sysreg_128(value);
+        self
+    }
+
+    /// Sets the value of the `SYSREG_128` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_sysreg_128(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_sysreg_128(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SYSREG_128` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_sysreg_128(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SYSREG_128_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SYSINSTR_128` field.
+    pub const fn sysinstr_128(self) -> u8 {
+        Self::SYSINSTR_128_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SYSINSTR_128` field.
+    pub const fn set_sysinstr_128(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SYSINSTR_128_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SYSINSTR_128` field set to the given value.
+    pub const fn with_sysinstr_128(mut self, value: u8) -> Self {
+        self.set_sysinstr_128(value);
+        self
+    }
+
+    /// Sets the value of the `SYSINSTR_128` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_sysinstr_128(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_sysinstr_128(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SYSINSTR_128` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_sysinstr_128(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SYSINSTR_128_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PRFMSLC` field.
+    pub const fn prfmslc(self) -> u8 {
+        Self::PRFMSLC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `PRFMSLC` field.
+    pub const fn set_prfmslc(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::PRFMSLC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PRFMSLC` field set to the given value.
+    pub const fn with_prfmslc(mut self, value: u8) -> Self {
+        self.set_prfmslc(value);
+        self
+    }
+
+    /// Sets the value of the `PRFMSLC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_prfmslc(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_prfmslc(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PRFMSLC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_prfmslc(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::PRFMSLC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PCDPHINT` field.
+    pub const fn pcdphint(self) -> u8 {
+        Self::PCDPHINT_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `PCDPHINT` field.
+    pub const fn set_pcdphint(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::PCDPHINT_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PCDPHINT` field set to the given value.
+    pub const fn with_pcdphint(mut self, value: u8) -> Self {
+        self.set_pcdphint(value);
+        self
+    }
+
+    /// Sets the value of the `PCDPHINT` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_pcdphint(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_pcdphint(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PCDPHINT` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_pcdphint(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::PCDPHINT_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `RPRFM` field.
+    pub const fn rprfm(self) -> u8 {
+        Self::RPRFM_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `RPRFM` field.
+    pub const fn set_rprfm(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::RPRFM_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `RPRFM` field set to the given value.
+    pub const fn with_rprfm(mut self, value: u8) -> Self {
+        self.set_rprfm(value);
+        self
+    }
+
+    /// Sets the value of the `RPRFM` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_rprfm(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_rprfm(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `RPRFM` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_rprfm(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::RPRFM_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `CSSC` field.
+    pub const fn cssc(self) -> u8 {
+        Self::CSSC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `CSSC` field.
+    pub const fn set_cssc(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::CSSC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `CSSC` field set to the given value.
+    pub const fn with_cssc(mut self, value: u8) -> Self {
+        self.set_cssc(value);
+        self
+    }
+
+    /// Sets the value of the `CSSC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_cssc(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_cssc(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `CSSC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_cssc(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::CSSC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `LUT` field.
+    pub const fn lut(self) -> u8 {
+        Self::LUT_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `LUT` field.
+    pub const fn set_lut(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::LUT_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `LUT` field set to the given value.
+    pub const fn with_lut(mut self, value: u8) -> Self {
+        self.set_lut(value);
+        self
+    }
+
+    /// Sets the value of the `LUT` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_lut(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_lut(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `LUT` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_lut(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::LUT_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ATS1A` field.
+    pub const fn ats1a(self) -> u8 {
+        Self::ATS1A_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `ATS1A` field.
+    pub const fn set_ats1a(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::ATS1A_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ATS1A` field set to the given value.
+    pub const fn with_ats1a(mut self, value: u8) -> Self {
+        self.set_ats1a(value);
+        self
+    }
+
+    /// Sets the value of the `ATS1A` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ats1a(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ats1a(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ATS1A` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ats1a(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::ATS1A_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for IdAa64isar2El1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("IdAa64isar2El1");
+        let bits = self.bits();
+        s.field("WFxT", &Self::WFXT_FIELD.extract(bits));
+        s.field("RPRES", &Self::RPRES_FIELD.extract(bits));
+        s.field("GPA3", &Self::GPA3_FIELD.extract(bits));
+        s.field("APA3", &Self::APA3_FIELD.extract(bits));
+        s.field("MOPS", &Self::MOPS_FIELD.extract(bits));
+        s.field("BC", &Self::BC_FIELD.extract(bits));
+        s.field("PAC_frac", &Self::PAC_FRAC_FIELD.extract(bits));
+        s.field("CLRBHB", &Self::CLRBHB_FIELD.extract(bits));
+        s.field("SYSREG_128", &Self::SYSREG_128_FIELD.extract(bits));
+        s.field("SYSINSTR_128", &Self::SYSINSTR_128_FIELD.extract(bits));
+        s.field("PRFMSLC", &Self::PRFMSLC_FIELD.extract(bits));
+        s.field("PCDPHINT", &Self::PCDPHINT_FIELD.extract(bits));
+        s.field("RPRFM", &Self::RPRFM_FIELD.extract(bits));
+        s.field("CSSC", &Self::CSSC_FIELD.extract(bits));
+        s.field("LUT", &Self::LUT_FIELD.extract(bits));
+        s.field("ATS1A", &Self::ATS1A_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for IdAa64isar2El1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "IdAa64isar2El1 {{ WFxT: {=u64}, RPRES: {=u64}, GPA3: {=u64}, APA3: {=u64}, MOPS: {=u64}, BC: {=u64}, PAC_frac: {=u64}, CLRBHB: {=u64}, SYSREG_128: {=u64}, SYSINSTR_128: {=u64}, PRFMSLC: {=u64}, PCDPHINT: {=u64}, RPRFM: {=u64}, CSSC: {=u64}, LUT: {=u64}, ATS1A: {=u64} }}",
+            Self::WFXT_FIELD.extract(self.bits()),
+            Self::RPRES_FIELD.extract(self.bits()),
+            Self::GPA3_FIELD.extract(self.bits()),
+            Self::APA3_FIELD.extract(self.bits()),
+            Self::MOPS_FIELD.extract(self.bits()),
+            Self::BC_FIELD.extract(self.bits()),
+            Self::PAC_FRAC_FIELD.extract(self.bits()),
+            Self::CLRBHB_FIELD.extract(self.bits()),
+            Self::SYSREG_128_FIELD.extract(self.bits()),
+            Self::SYSINSTR_128_FIELD.extract(self.bits()),
+            Self::PRFMSLC_FIELD.extract(self.bits()),
+            Self::PCDPHINT_FIELD.extract(self.bits()),
+            Self::RPRFM_FIELD.extract(self.bits()),
+            Self::CSSC_FIELD.extract(self.bits()),
+            Self::LUT_FIELD.extract(self.bits()),
+            Self::ATS1A_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for IdAa64isar2El1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for IdAa64isar2El1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64isar2El1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("WFxT"),
+                old: Self::WFXT_FIELD.extract(self.bits()),
+                new: Self::WFXT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("RPRES"),
+                old: Self::RPRES_FIELD.extract(self.bits()),
+                new: Self::RPRES_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("GPA3"),
+                old: Self::GPA3_FIELD.extract(self.bits()),
+                new: Self::GPA3_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("APA3"),
+                old: Self::APA3_FIELD.extract(self.bits()),
+                new: Self::APA3_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("MOPS"),
+                old: Self::MOPS_FIELD.extract(self.bits()),
+                new: Self::MOPS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("BC"),
+                old: Self::BC_FIELD.extract(self.bits()),
+                new: Self::BC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PAC_frac"),
+                old: Self::PAC_FRAC_FIELD.extract(self.bits()),
+                new: Self::PAC_FRAC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CLRBHB"),
+                old: Self::CLRBHB_FIELD.extract(self.bits()),
+                new: Self::CLRBHB_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SYSREG_128"),
+                old: Self::SYSREG_128_FIELD.extract(self.bits()),
+                new: Self::SYSREG_128_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SYSINSTR_128"),
+                old: Self::SYSINSTR_128_FIELD.extract(self.bits()),
+                new: Self::SYSINSTR_128_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PRFMSLC"),
+                old: Self::PRFMSLC_FIELD.extract(self.bits()),
+                new: Self::PRFMSLC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PCDPHINT"),
+                old: Self::PCDPHINT_FIELD.extract(self.bits()),
+                new: Self::PCDPHINT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("RPRFM"),
+                old: Self::RPRFM_FIELD.extract(self.bits()),
+                new: Self::RPRFM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CSSC"),
+                old: Self::CSSC_FIELD.extract(self.bits()),
+                new: Self::CSSC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("LUT"),
+                old: Self::LUT_FIELD.extract(self.bits()),
+                new: Self::LUT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ATS1A"),
+                old: Self::ATS1A_FIELD.extract(self.bits()),
+                new: Self::ATS1A_FIELD.extract(other.bits()),
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64isar2El1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64isar2El1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `ID_AA64MMFR0_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct IdAa64mmfr0El1: u64 {
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64mmfr0El1 {
+    /// Descriptor of the `PARange` field, for use with [`FieldAccess`].
+    pub const PARANGE_FIELD: Field<Self, u8, 0, 4> = Field::new("ID_AA64MMFR0_EL1", "PARange");
+    /// Descriptor of the `ASIDBits` field, for use with [`FieldAccess`].
+    pub const ASIDBITS_FIELD: Field<Self, u8, 4, 4> = Field::new("ID_AA64MMFR0_EL1", "ASIDBits");
+    /// Descriptor of the `BigEnd` field, for use with [`FieldAccess`].
+    pub const BIGEND_FIELD: Field<Self, u8, 8, 4> = Field::new("ID_AA64MMFR0_EL1", "BigEnd");
+    /// Descriptor of the `SNSMem` field, for use with [`FieldAccess`].
+    pub const SNSMEM_FIELD: Field<Self, u8, 12, 4> = Field::new("ID_AA64MMFR0_EL1", "SNSMem");
+    /// Descriptor of the `BigEndEL0` field, for use with [`FieldAccess`].
+    pub const BIGENDEL0_FIELD: Field<Self, u8, 16, 4> = Field::new("ID_AA64MMFR0_EL1", "BigEndEL0");
+    /// Descriptor of the `TGran16` field, for use with [`FieldAccess`].
+    pub const TGRAN16_FIELD: Field<Self, u8, 20, 4> = Field::new("ID_AA64MMFR0_EL1", "TGran16");
+    /// Descriptor of the `TGran64` field, for use with [`FieldAccess`].
+    pub const TGRAN64_FIELD: Field<Self, u8, 24, 4> = Field::new("ID_AA64MMFR0_EL1", "TGran64");
+    /// Descriptor of the `TGran4` field, for use with [`FieldAccess`].
+    pub const TGRAN4_FIELD: Field<Self, u8, 28, 4> = Field::new("ID_AA64MMFR0_EL1", "TGran4");
+    /// Descriptor of the `TGran16_2` field, for use with [`FieldAccess`].
+    pub const TGRAN16_2_FIELD: Field<Self, u8, 32, 4> = Field::new("ID_AA64MMFR0_EL1", "TGran16_2");
+    /// Descriptor of the `TGran64_2` field, for use with [`FieldAccess`].
+    pub const TGRAN64_2_FIELD: Field<Self, u8, 36, 4> = Field::new("ID_AA64MMFR0_EL1", "TGran64_2");
+    /// Descriptor of the `TGran4_2` field, for use with [`FieldAccess`].
+    pub const TGRAN4_2_FIELD: Field<Self, u8, 40, 4> = Field::new("ID_AA64MMFR0_EL1", "TGran4_2");
+    /// Descriptor of the `ExS` field, for use with [`FieldAccess`].
+    pub const EXS_FIELD: Field<Self, u8, 44, 4> = Field::new("ID_AA64MMFR0_EL1", "ExS");
+    /// Descriptor of the `FGT` field, for use with [`FieldAccess`].
+    pub const FGT_FIELD: Field<Self, u8, 56, 4> = Field::new("ID_AA64MMFR0_EL1", "FGT");
+    /// Descriptor of the `ECV` field, for use with [`FieldAccess`].
+    pub const ECV_FIELD: Field<Self, u8, 60, 4> = Field::new("ID_AA64MMFR0_EL1", "ECV");
+
+    /// Returns the value of the `PARange` field.
+    pub const fn parange(self) -> u8 {
+        Self::PARANGE_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `PARange` field.
+    pub const fn set_parange(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::PARANGE_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PARange` field set to the given value.
+    pub const fn with_parange(mut self, value: u8) -> Self {
+        self.set_parange(value);
+        self
+    }
+
+    /// Sets the value of the `PARange` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_parange(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_parange(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PARange` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_parange(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::PARANGE_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ASIDBits` field.
+    pub const fn asidbits(self) -> u8 {
+        Self::ASIDBITS_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `ASIDBits` field.
+    pub const fn set_asidbits(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::ASIDBITS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ASIDBits` field set to the given value.
+    pub const fn with_asidbits(mut self, value: u8) -> Self {
+        self.set_asidbits(value);
+        self
+    }
+
+    /// Sets the value of the `ASIDBits` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_asidbits(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_asidbits(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ASIDBits` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_asidbits(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::ASIDBITS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `BigEnd` field.
+    pub const fn bigend(self) -> u8 {
+        Self::BIGEND_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `BigEnd` field.
+    pub const fn set_bigend(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::BIGEND_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `BigEnd` field set to the given value.
+    pub const fn with_bigend(mut self, value: u8) -> Self {
+        self.set_bigend(value);
+        self
+    }
+
+    /// Sets the value of the `BigEnd` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_bigend(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_bigend(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `BigEnd` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_bigend(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::BIGEND_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SNSMem` field.
+    pub const fn snsmem(self) -> u8 {
+        Self::SNSMEM_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SNSMem` field.
+    pub const fn set_snsmem(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SNSMEM_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SNSMem` field set to the given value.
+    pub const fn with_snsmem(mut self, value: u8) -> Self {
+        self.set_snsmem(value);
+        self
+    }
+
+    /// Sets the value of the `SNSMem` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_snsmem(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_snsmem(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SNSMem` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_snsmem(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SNSMEM_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `BigEndEL0` field.
+    pub const fn bigendel0(self) -> u8 {
+        Self::BIGENDEL0_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `BigEndEL0` field.
+    pub const fn set_bigendel0(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::BIGENDEL0_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `BigEndEL0` field set to the given value.
+    pub const fn with_bigendel0(mut self, value: u8) -> Self {
+        self.set_bigendel0(value);
+        self
+    }
+
+    /// Sets the value of the `BigEndEL0` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_bigendel0(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_bigendel0(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `BigEndEL0` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_bigendel0(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::BIGENDEL0_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `TGran16` field.
+    pub const fn tgran16(self) -> u8 {
+        Self::TGRAN16_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `TGran16` field.
+    pub const fn set_tgran16(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::TGRAN16_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `TGran16` field set to the given value.
+    pub const fn with_tgran16(mut self, value: u8) -> Self {
+        self.set_tgran16(value);
+        self
+    }
+
+    /// Sets the value of the `TGran16` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_tgran16(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_tgran16(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `TGran16` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_tgran16(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::TGRAN16_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `TGran64` field.
+    pub const fn tgran64(self) -> u8 {
+        Self::TGRAN64_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `TGran64` field.
+    pub const fn set_tgran64(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::TGRAN64_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `TGran64` field set to the given value.
+    pub const fn with_tgran64(mut self, value: u8) -> Self {
+        self.set_tgran64(value);
+        self
+    }
+
+    /// Sets the value of the `TGran64` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_tgran64(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_tgran64(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `TGran64` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_tgran64(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::TGRAN64_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `TGran4` field.
+    pub const fn tgran4(self) -> u8 {
+        Self::TGRAN4_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `TGran4` field.
+    pub const fn set_tgran4(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::TGRAN4_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `TGran4` field set to the given value.
+    pub const fn with_tgran4(mut self, value: u8) -> Self {
+        self.set_tgran4(value);
+        self
+    }
+
+    /// Sets the value of the `TGran4` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_tgran4(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_tgran4(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `TGran4` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_tgran4(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::TGRAN4_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `TGran16_2` field.
+    pub const fn tgran16_2(self) -> u8 {
+        Self::TGRAN16_2_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `TGran16_2` field.
+    pub const fn set_tgran16_2(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::TGRAN16_2_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `TGran16_2` field set to the given value.
+    pub const fn with_tgran16_2(mut self, value: u8) -> Self {
+        self.set_tgran16_2(value);
+        self
+    }
+
+    /// Sets the value of the `TGran16_2` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_tgran16_2(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_tgran16_2(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `TGran16_2` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_tgran16_2(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::TGRAN16_2_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `TGran64_2` field.
+    pub const fn tgran64_2(self) -> u8 {
+        Self::TGRAN64_2_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `TGran64_2` field.
+    pub const fn set_tgran64_2(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::TGRAN64_2_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `TGran64_2` field set to the given value.
+    pub const fn with_tgran64_2(mut self, value: u8) -> Self {
+        self.set_tgran64_2(value);
+        self
+    }
+
+    /// Sets the value of the `TGran64_2` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_tgran64_2(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_tgran64_2(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `TGran64_2` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_tgran64_2(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::TGRAN64_2_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `TGran4_2` field.
+    pub const fn tgran4_2(self) -> u8 {
+        Self::TGRAN4_2_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `TGran4_2` field.
+    pub const fn set_tgran4_2(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::TGRAN4_2_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `TGran4_2` field set to the given value.
+    pub const fn with_tgran4_2(mut self, value: u8) -> Self {
+        self.set_tgran4_2(value);
+        self
+    }
+
+    /// Sets the value of the `TGran4_2` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_tgran4_2(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_tgran4_2(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `TGran4_2` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_tgran4_2(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::TGRAN4_2_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ExS` field.
+    pub const fn exs(self) -> u8 {
+        Self::EXS_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `ExS` field.
+    pub const fn set_exs(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::EXS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ExS` field set to the given value.
+    pub const fn with_exs(mut self, value: u8) -> Self {
+        self.set_exs(value);
+        self
+    }
+
+    /// Sets the value of the `ExS` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_exs(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_exs(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ExS` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_exs(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::EXS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `FGT` field.
+    pub const fn fgt(self) -> u8 {
+        Self::FGT_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `FGT` field.
+    pub const fn set_fgt(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::FGT_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `FGT` field set to the given value.
+    pub const fn with_fgt(mut self, value: u8) -> Self {
+        self.set_fgt(value);
+        self
+    }
+
+    /// Sets the value of the `FGT` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_fgt(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_fgt(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `FGT` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_fgt(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::FGT_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ECV` field.
+    pub const fn ecv(self) -> u8 {
+        Self::ECV_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `ECV` field.
+    pub const fn set_ecv(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::ECV_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ECV` field set to the given value.
+    pub const fn with_ecv(mut self, value: u8) -> Self {
+        self.set_ecv(value);
+        self
+    }
+
+    /// Sets the value of the `ECV` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ecv(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ecv(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ECV` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ecv(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::ECV_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for IdAa64mmfr0El1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("IdAa64mmfr0El1");
+        let bits = self.bits();
+        s.field("PARange", &Self::PARANGE_FIELD.extract(bits));
+        s.field("ASIDBits", &Self::ASIDBITS_FIELD.extract(bits));
+        s.field("BigEnd", &Self::BIGEND_FIELD.extract(bits));
+        s.field("SNSMem", &Self::SNSMEM_FIELD.extract(bits));
+        s.field("BigEndEL0", &Self::BIGENDEL0_FIELD.extract(bits));
+        s.field("TGran16", &Self::TGRAN16_FIELD.extract(bits));
+        s.field("TGran64", &Self::TGRAN64_FIELD.extract(bits));
+        s.field("TGran4", &Self::TGRAN4_FIELD.extract(bits));
+        s.field("TGran16_2", &Self::TGRAN16_2_FIELD.extract(bits));
+        s.field("TGran64_2", &Self::TGRAN64_2_FIELD.extract(bits));
+        s.field("TGran4_2", &Self::TGRAN4_2_FIELD.extract(bits));
+        s.field("ExS", &Self::EXS_FIELD.extract(bits));
+        s.field("FGT", &Self::FGT_FIELD.extract(bits));
+        s.field("ECV", &Self::ECV_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for IdAa64mmfr0El1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "IdAa64mmfr0El1 {{ PARange: {=u64}, ASIDBits: {=u64}, BigEnd: {=u64}, SNSMem: {=u64}, BigEndEL0: {=u64}, TGran16: {=u64}, TGran64: {=u64}, TGran4: {=u64}, TGran16_2: {=u64}, TGran64_2: {=u64}, TGran4_2: {=u64}, ExS: {=u64}, FGT: {=u64}, ECV: {=u64} }}",
+            Self::PARANGE_FIELD.extract(self.bits()),
+            Self::ASIDBITS_FIELD.extract(self.bits()),
+            Self::BIGEND_FIELD.extract(self.bits()),
+            Self::SNSMEM_FIELD.extract(self.bits()),
+            Self::BIGENDEL0_FIELD.extract(self.bits()),
+            Self::TGRAN16_FIELD.extract(self.bits()),
+            Self::TGRAN64_FIELD.extract(self.bits()),
+            Self::TGRAN4_FIELD.extract(self.bits()),
+            Self::TGRAN16_2_FIELD.extract(self.bits()),
+            Self::TGRAN64_2_FIELD.extract(self.bits()),
+            Self::TGRAN4_2_FIELD.extract(self.bits()),
+            Self::EXS_FIELD.extract(self.bits()),
+            Self::FGT_FIELD.extract(self.bits()),
+            Self::ECV_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for IdAa64mmfr0El1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for IdAa64mmfr0El1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64mmfr0El1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("PARange"),
+                old: Self::PARANGE_FIELD.extract(self.bits()),
+                new: Self::PARANGE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ASIDBits"),
+                old: Self::ASIDBITS_FIELD.extract(self.bits()),
+                new: Self::ASIDBITS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("BigEnd"),
+                old: Self::BIGEND_FIELD.extract(self.bits()),
+                new: Self::BIGEND_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SNSMem"),
+                old: Self::SNSMEM_FIELD.extract(self.bits()),
+                new: Self::SNSMEM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("BigEndEL0"),
+                old: Self::BIGENDEL0_FIELD.extract(self.bits()),
+                new: Self::BIGENDEL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TGran16"),
+                old: Self::TGRAN16_FIELD.extract(self.bits()),
+                new: Self::TGRAN16_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TGran64"),
+                old: Self::TGRAN64_FIELD.extract(self.bits()),
+                new: Self::TGRAN64_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TGran4"),
+                old: Self::TGRAN4_FIELD.extract(self.bits()),
+                new: Self::TGRAN4_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TGran16_2"),
+                old: Self::TGRAN16_2_FIELD.extract(self.bits()),
+                new: Self::TGRAN16_2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TGran64_2"),
+                old: Self::TGRAN64_2_FIELD.extract(self.bits()),
+                new: Self::TGRAN64_2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TGran4_2"),
+                old: Self::TGRAN4_2_FIELD.extract(self.bits()),
+                new: Self::TGRAN4_2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ExS"),
+                old: Self::EXS_FIELD.extract(self.bits()),
+                new: Self::EXS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FGT"),
+                old: Self::FGT_FIELD.extract(self.bits()),
+                new: Self::FGT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ECV"),
+                old: Self::ECV_FIELD.extract(self.bits()),
+                new: Self::ECV_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits()
+                    & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000,
+                new: other.bits()
+                    & 0b1111_1111_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64mmfr0El1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64mmfr0El1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `ID_AA64MMFR1_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct IdAa64mmfr1El1: u64 {
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64mmfr1El1 {
+    /// Descriptor of the `HAFDBS` field, for use with [`FieldAccess`].
+    pub const HAFDBS_FIELD: Field<Self, u8, 0, 4> = Field::new("ID_AA64MMFR1_EL1", "HAFDBS");
+    /// Descriptor of the `VMIDBits` field, for use with [`FieldAccess`].
+    pub const VMIDBITS_FIELD: Field<Self, u8, 4, 4> = Field::new("ID_AA64MMFR1_EL1", "VMIDBits");
+    /// Descriptor of the `VH` field, for use with [`FieldAccess`].
+    pub const VH_FIELD: Field<Self, u8, 8, 4> = Field::new("ID_AA64MMFR1_EL1", "VH");
+    /// Descriptor of the `HPDS` field, for use with [`FieldAccess`].
+    pub const HPDS_FIELD: Field<Self, u8, 12, 4> = Field::new("ID_AA64MMFR1_EL1", "HPDS");
+    /// Descriptor of the `LO` field, for use with [`FieldAccess`].
+    pub const LO_FIELD: Field<Self, u8, 16, 4> = Field::new("ID_AA64MMFR1_EL1", "LO");
+    /// Descriptor of the `PAN` field, for use with [`FieldAccess`].
+    pub const PAN_FIELD: Field<Self, u8, 20, 4> = Field::new("ID_AA64MMFR1_EL1", "PAN");
+    /// Descriptor of the `SpecSEI` field, for use with [`FieldAccess`].
+    pub const SPECSEI_FIELD: Field<Self, u8, 24, 4> = Field::new("ID_AA64MMFR1_EL1", "SpecSEI");
+    /// Descriptor of the `XNX` field, for use with [`FieldAccess`].
+    pub const XNX_FIELD: Field<Self, u8, 28, 4> = Field::new("ID_AA64MMFR1_EL1", "XNX");
+    /// Descriptor of the `TWED` field, for use with [`FieldAccess`].
+    pub const TWED_FIELD: Field<Self, u8, 32, 4> = Field::new("ID_AA64MMFR1_EL1", "TWED");
+    /// Descriptor of the `ETS` field, for use with [`FieldAccess`].
+    pub const ETS_FIELD: Field<Self, u8, 36, 4> = Field::new("ID_AA64MMFR1_EL1", "ETS");
+    /// Descriptor of the `HCX` field, for use with [`FieldAccess`].
+    pub const HCX_FIELD: Field<Self, u8, 40, 4> = Field::new("ID_AA64MMFR1_EL1", "HCX");
+    /// Descriptor of the `AFP` field, for use with [`FieldAccess`].
+    pub const AFP_FIELD: Field<Self, u8, 44, 4> = Field::new("ID_AA64MMFR1_EL1", "AFP");
+    /// Descriptor of the `nTLBPA` field, for use with [`FieldAccess`].
+    pub const NTLBPA_FIELD: Field<Self, u8, 48, 4> = Field::new("ID_AA64MMFR1_EL1", "nTLBPA");
+    /// Descriptor of the `TIDCP1` field, for use with [`FieldAccess`].
+    pub const TIDCP1_FIELD: Field<Self, u8, 52, 4> = Field::new("ID_AA64MMFR1_EL1", "TIDCP1");
+    /// Descriptor of the `CMOW` field, for use with [`FieldAccess`].
+    pub const CMOW_FIELD: Field<Self, u8, 56, 4> = Field::new("ID_AA64MMFR1_EL1", "CMOW");
+    /// Descriptor of the `ECBHB` field, for use with [`FieldAccess`].
+    pub const ECBHB_FIELD: Field<Self, u8, 60, 4> = Field::new("ID_AA64MMFR1_EL1", "ECBHB");
+
+    /// Returns the value of the `HAFDBS` field.
+    pub const fn hafdbs(self) -> u8 {
+        Self::HAFDBS_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `HAFDBS` field.
+    pub const fn set_hafdbs(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::HAFDBS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `HAFDBS` field set to the given value.
+    pub const fn with_hafdbs(mut self, value: u8) -> Self {
+        self.set_hafdbs(value);
+        self
+    }
+
+    /// Sets the value of the `HAFDBS` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_hafdbs(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_hafdbs(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `HAFDBS` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_hafdbs(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::HAFDBS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `VMIDBits` field.
+    pub const fn vmidbits(self) -> u8 {
+        Self::VMIDBITS_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `VMIDBits` field.
+    pub const fn set_vmidbits(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::VMIDBITS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `VMIDBits` field set to the given value.
+    pub const fn with_vmidbits(mut self, value: u8) -> Self {
+        self.set_vmidbits(value);
+        self
+    }
+
+    /// Sets the value of the `VMIDBits` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_vmidbits(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_vmidbits(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `VMIDBits` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_vmidbits(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::VMIDBITS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `VH` field.
+    pub const fn vh(self) -> u8 {
+        Self::VH_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `VH` field.
+    pub const fn set_vh(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::VH_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `VH` field set to the given value.
+    pub const fn with_vh(mut self, value: u8) -> Self {
+        self.set_vh(value);
+        self
+    }
+
+    /// Sets the value of the `VH` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_vh(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_vh(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `VH` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_vh(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::VH_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `HPDS` field.
+    pub const fn hpds(self) -> u8 {
+        Self::HPDS_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `HPDS` field.
+    pub const fn set_hpds(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::HPDS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `HPDS` field set to the given value.
+    pub const fn with_hpds(mut self, value: u8) -> Self {
+        self.set_hpds(value);
+        self
+    }
+
+    /// Sets the value of the `HPDS` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_hpds(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_hpds(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `HPDS` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_hpds(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::HPDS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `LO` field.
+    pub const fn lo(self) -> u8 {
+        Self::LO_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `LO` field.
+    pub const fn set_lo(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::LO_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `LO` field set to the given value.
+    pub const fn with_lo(mut self, value: u8) -> Self {
+        self.set_lo(value);
+        self
+    }
+
+    /// Sets the value of the `LO` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_lo(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_lo(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `LO` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_lo(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::LO_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PAN` field.
+    pub const fn pan(self) -> u8 {
+        Self::PAN_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `PAN` field.
+    pub const fn set_pan(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::PAN_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PAN` field set to the given value.
+    pub const fn with_pan(mut self, value: u8) -> Self {
+        self.set_pan(value);
+        self
+    }
+
+    /// Sets the value of the `PAN` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_pan(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_pan(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PAN` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_pan(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::PAN_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SpecSEI` field.
+    pub const fn specsei(self) -> u8 {
+        Self::SPECSEI_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SpecSEI` field.
+    pub const fn set_specsei(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SPECSEI_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SpecSEI` field set to the given value.
+    pub const fn with_specsei(mut self, value: u8) -> Self {
+        self.set_specsei(value);
+        self
+    }
+
+    /// Sets the value of the `SpecSEI` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_specsei(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_specsei(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SpecSEI` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_specsei(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SPECSEI_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `XNX` field.
+    pub const fn xnx(self) -> u8 {
+        Self::XNX_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `XNX` field.
+    pub const fn set_xnx(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::XNX_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `XNX` field set to the given value.
+    pub const fn with_xnx(mut self, value: u8) -> Self {
+        self.set_xnx(value);
+        self
+    }
+
+    /// Sets the value of the `XNX` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_xnx(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_xnx(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `XNX` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_xnx(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::XNX_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `TWED` field.
+    pub const fn twed(self) -> u8 {
+        Self::TWED_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `TWED` field.
+    pub const fn set_twed(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::TWED_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `TWED` field set to the given value.
+    pub const fn with_twed(mut self, value: u8) -> Self {
+        self.set_twed(value);
+        self
+    }
+
+    /// Sets the value of the `TWED` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_twed(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_twed(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `TWED` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_twed(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::TWED_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ETS` field.
+    pub const fn ets(self) -> u8 {
+        Self::ETS_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `ETS` field.
+    pub const fn set_ets(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::ETS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ETS` field set to the given value.
+    pub const fn with_ets(mut self, value: u8) -> Self {
+        self.set_ets(value);
+        self
+    }
+
+    /// Sets the value of the `ETS` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ets(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ets(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ETS` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ets(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::ETS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `HCX` field.
+    pub const fn hcx(self) -> u8 {
+        Self::HCX_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `HCX` field.
+    pub const fn set_hcx(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::HCX_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `HCX` field set to the given value.
+    pub const fn with_hcx(mut self, value: u8) -> Self {
+        self.set_hcx(value);
+        self
+    }
+
+    /// Sets the value of the `HCX` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_hcx(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_hcx(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `HCX` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_hcx(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::HCX_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `AFP` field.
+    pub const fn afp(self) -> u8 {
+        Self::AFP_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `AFP` field.
+    pub const fn set_afp(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::AFP_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `AFP` field set to the given value.
+    pub const fn with_afp(mut self, value: u8) -> Self {
+        self.set_afp(value);
+        self
+    }
+
+    /// Sets the value of the `AFP` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_afp(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_afp(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `AFP` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_afp(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::AFP_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `nTLBPA` field.
+    pub const fn ntlbpa(self) -> u8 {
+        Self::NTLBPA_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `nTLBPA` field.
+    pub const fn set_ntlbpa(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::NTLBPA_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `nTLBPA` field set to the given value.
+    pub const fn with_ntlbpa(mut self, value: u8) -> Self {
+        self.set_ntlbpa(value);
+        self
+    }
+
+    /// Sets the value of the `nTLBPA` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ntlbpa(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ntlbpa(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `nTLBPA` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ntlbpa(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::NTLBPA_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `TIDCP1` field.
+    pub const fn tidcp1(self) -> u8 {
+        Self::TIDCP1_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `TIDCP1` field.
+    pub const fn set_tidcp1(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::TIDCP1_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `TIDCP1` field set to the given value.
+    pub const fn with_tidcp1(mut self, value: u8) -> Self {
+        self.set_tidcp1(value);
+        self
+    }
+
+    /// Sets the value of the `TIDCP1` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_tidcp1(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_tidcp1(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `TIDCP1` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_tidcp1(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::TIDCP1_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `CMOW` field.
+    pub const fn cmow(self) -> u8 {
+        Self::CMOW_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `CMOW` field.
+    pub const fn set_cmow(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::CMOW_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `CMOW` field set to the given value.
+    pub const fn with_cmow(mut self, value: u8) -> Self {
+        self.set_cmow(value);
+        self
+    }
+
+    /// Sets the value of the `CMOW` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_cmow(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_cmow(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `CMOW` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_cmow(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::CMOW_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ECBHB` field.
+    pub const fn ecbhb(self) -> u8 {
+        Self::ECBHB_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `ECBHB` field.
+    pub const fn set_ecbhb(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::ECBHB_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ECBHB` field set to the given value.
+    pub const fn with_ecbhb(mut self, value: u8) -> Self {
+        self.set_ecbhb(value);
+        self
+    }
+
+    /// Sets the value of the `ECBHB` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ecbhb(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ecbhb(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ECBHB` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ecbhb(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::ECBHB_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for IdAa64mmfr1El1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("IdAa64mmfr1El1");
+        let bits = self.bits();
+        s.field("HAFDBS", &Self::HAFDBS_FIELD.extract(bits));
+        s.field("VMIDBits", &Self::VMIDBITS_FIELD.extract(bits));
+        s.field("VH", &Self::VH_FIELD.extract(bits));
+        s.field("HPDS", &Self::HPDS_FIELD.extract(bits));
+        s.field("LO", &Self::LO_FIELD.extract(bits));
+        s.field("PAN", &Self::PAN_FIELD.extract(bits));
+        s.field("SpecSEI", &Self::SPECSEI_FIELD.extract(bits));
+        s.field("XNX", &Self::XNX_FIELD.extract(bits));
+        s.field("TWED", &Self::TWED_FIELD.extract(bits));
+        s.field("ETS", &Self::ETS_FIELD.extract(bits));
+        s.field("HCX", &Self::HCX_FIELD.extract(bits));
+        s.field("AFP", &Self::AFP_FIELD.extract(bits));
+        s.field("nTLBPA", &Self::NTLBPA_FIELD.extract(bits));
+        s.field("TIDCP1", &Self::TIDCP1_FIELD.extract(bits));
+        s.field("CMOW", &Self::CMOW_FIELD.extract(bits));
+        s.field("ECBHB", &Self::ECBHB_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for IdAa64mmfr1El1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "IdAa64mmfr1El1 {{ HAFDBS: {=u64}, VMIDBits: {=u64}, VH: {=u64}, HPDS: {=u64}, LO: {=u64}, PAN: {=u64}, SpecSEI: {=u64}, XNX: {=u64}, TWED: {=u64}, ETS: {=u64}, HCX: {=u64}, AFP: {=u64}, nTLBPA: {=u64}, TIDCP1: {=u64}, CMOW: {=u64}, ECBHB: {=u64} }}",
+            Self::HAFDBS_FIELD.extract(self.bits()),
+            Self::VMIDBITS_FIELD.extract(self.bits()),
+            Self::VH_FIELD.extract(self.bits()),
+            Self::HPDS_FIELD.extract(self.bits()),
+            Self::LO_FIELD.extract(self.bits()),
+            Self::PAN_FIELD.extract(self.bits()),
+            Self::SPECSEI_FIELD.extract(self.bits()),
+            Self::XNX_FIELD.extract(self.bits()),
+            Self::TWED_FIELD.extract(self.bits()),
+            Self::ETS_FIELD.extract(self.bits()),
+            Self::HCX_FIELD.extract(self.bits()),
+            Self::AFP_FIELD.extract(self.bits()),
+            Self::NTLBPA_FIELD.extract(self.bits()),
+            Self::TIDCP1_FIELD.extract(self.bits()),
+            Self::CMOW_FIELD.extract(self.bits()),
+            Self::ECBHB_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for IdAa64mmfr1El1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for IdAa64mmfr1El1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64mmfr1El1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("HAFDBS"),
+                old: Self::HAFDBS_FIELD.extract(self.bits()),
+                new: Self::HAFDBS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VMIDBits"),
+                old: Self::VMIDBITS_FIELD.extract(self.bits()),
+                new: Self::VMIDBITS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VH"),
+                old: Self::VH_FIELD.extract(self.bits()),
+                new: Self::VH_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HPDS"),
+                old: Self::HPDS_FIELD.extract(self.bits()),
+                new: Self::HPDS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("LO"),
+                old: Self::LO_FIELD.extract(self.bits()),
+                new: Self::LO_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PAN"),
+                old: Self::PAN_FIELD.extract(self.bits()),
+                new: Self::PAN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SpecSEI"),
+                old: Self::SPECSEI_FIELD.extract(self.bits()),
+                new: Self::SPECSEI_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("XNX"),
+                old: Self::XNX_FIELD.extract(self.bits()),
+                new: Self::XNX_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TWED"),
+                old: Self::TWED_FIELD.extract(self.bits()),
+                new: Self::TWED_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ETS"),
+                old: Self::ETS_FIELD.extract(self.bits()),
+                new: Self::ETS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HCX"),
+                old: Self::HCX_FIELD.extract(self.bits()),
+                new: Self::HCX_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("AFP"),
+                old: Self::AFP_FIELD.extract(self.bits()),
+                new: Self::AFP_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nTLBPA"),
+                old: Self::NTLBPA_FIELD.extract(self.bits()),
+                new: Self::NTLBPA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TIDCP1"),
+                old: Self::TIDCP1_FIELD.extract(self.bits()),
+                new: Self::TIDCP1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CMOW"),
+                old: Self::CMOW_FIELD.extract(self.bits()),
+                new: Self::CMOW_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ECBHB"),
+                old: Self::ECBHB_FIELD.extract(self.bits()),
+                new: Self::ECBHB_FIELD.extract(other.bits()),
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64mmfr1El1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64mmfr1El1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `ID_AA64MMFR2_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct IdAa64mmfr2El1: u64 {
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64mmfr2El1 {
+    /// Descriptor of the `CnP` field, for use with [`FieldAccess`].
+    pub const CNP_FIELD: Field<Self, u8, 0, 4> = Field::new("ID_AA64MMFR2_EL1", "CnP");
+    /// Descriptor of the `UAO` field, for use with [`FieldAccess`].
+    pub const UAO_FIELD: Field<Self, u8, 4, 4> = Field::new("ID_AA64MMFR2_EL1", "UAO");
+    /// Descriptor of the `LSM` field, for use with [`FieldAccess`].
+    pub const LSM_FIELD: Field<Self, u8, 8, 4> = Field::new("ID_AA64MMFR2_EL1", "LSM");
+    /// Descriptor of the `IESB` field, for use with [`FieldAccess`].
+    pub const IESB_FIELD: Field<Self, u8, 12, 4> = Field::new("ID_AA64MMFR2_EL1", "IESB");
+    /// Descriptor of the `VARange` field, for use with [`FieldAccess`].
+    pub const VARANGE_FIELD: Field<Self, u8, 16, 4> = Field::new("ID_AA64MMFR2_EL1", "VARange");
+    /// Descriptor of the `CCIDX` field, for use with [`FieldAccess`].
+    pub const CCIDX_FIELD: Field<Self, u8, 20, 4> = Field::new("ID_AA64MMFR2_EL1", "CCIDX");
+    /// Descriptor of the `NV` field, for use with [`FieldAccess`].
+    pub const NV_FIELD: Field<Self, u8, 24, 4> = Field::new("ID_AA64MMFR2_EL1", "NV");
+    /// Descriptor of the `ST` field, for use with [`FieldAccess`].
+    pub const ST_FIELD: Field<Self, u8, 28, 4> = Field::new("ID_AA64MMFR2_EL1", "ST");
+    /// Descriptor of the `AT` field, for use with [`FieldAccess`].
+    pub const AT_FIELD: Field<Self, u8, 32, 4> = Field::new("ID_AA64MMFR2_EL1", "AT");
+    /// Descriptor of the `IDS` field, for use with [`FieldAccess`].
+    pub const IDS_FIELD: Field<Self, u8, 36, 4> = Field::new("ID_AA64MMFR2_EL1", "IDS");
+    /// Descriptor of the `FWB` field, for use with [`FieldAccess`].
+    pub const FWB_FIELD: Field<Self, u8, 40, 4> = Field::new("ID_AA64MMFR2_EL1", "FWB");
+    /// Descriptor of the `TTL` field, for use with [`FieldAccess`].
+    pub const TTL_FIELD: Field<Self, u8, 48, 4> = Field::new("ID_AA64MMFR2_EL1", "TTL");
+    /// Descriptor of the `BBM` field, for use with [`FieldAccess`].
+    pub const BBM_FIELD: Field<Self, u8, 52, 4> = Field::new("ID_AA64MMFR2_EL1", "BBM");
+    /// Descriptor of the `EVT` field, for use with [`FieldAccess`].
+    pub const EVT_FIELD: Field<Self, u8, 56, 4> = Field::new("ID_AA64MMFR2_EL1", "EVT");
+    /// Descriptor of the `E0PD` field, for use with [`FieldAccess`].
+    pub const E0PD_FIELD: Field<Self, u8, 60, 4> = Field::new("ID_AA64MMFR2_EL1", "E0PD");
+
+    /// Returns the value of the `CnP` field.
+    pub const fn cnp(self) -> u8 {
+        Self::CNP_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `CnP` field.
+    pub const fn set_cnp(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::CNP_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `CnP` field set to the given value.
+    pub const fn with_cnp(mut self, value: u8) -> Self {
+        self.set_cnp(value);
+        self
+    }
+
+    /// Sets the value of the `CnP` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_cnp(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_cnp(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `CnP` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_cnp(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::CNP_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `UAO` field.
+    pub const fn uao(self) -> u8 {
+        Self::UAO_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `UAO` field.
+    pub const fn set_uao(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::UAO_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `UAO` field set to the given value.
+    pub const fn with_uao(mut self, value: u8) -> Self {
+        self.set_uao(value);
+        self
+    }
+
+    /// Sets the value of the `UAO` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_uao(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_uao(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `UAO` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_uao(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::UAO_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `LSM` field.
+    pub const fn lsm(self) -> u8 {
+        Self::LSM_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `LSM` field.
+    pub const fn set_lsm(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::LSM_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `LSM` field set to the given value.
+    pub const fn with_lsm(mut self, value: u8) -> Self {
+        self.set_lsm(value);
+        self
+    }
+
+    /// Sets the value of the `LSM` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_lsm(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_lsm(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `LSM` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_lsm(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::LSM_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `IESB` field.
+    pub const fn iesb(self) -> u8 {
+        Self::IESB_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `IESB` field.
+    pub const fn set_iesb(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::IESB_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `IESB` field set to the given value.
+    pub const fn with_iesb(mut self, value: u8) -> Self {
+        self.set_iesb(value);
+        self
+    }
+
+    /// Sets the value of the `IESB` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_iesb(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_iesb(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `IESB` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_iesb(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::IESB_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `VARange` field.
+    pub const fn varange(self) -> u8 {
+        Self::VARANGE_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `VARange` field.
+    pub const fn set_varange(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::VARANGE_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `VARange` field set to the given value.
+    pub const fn with_varange(mut self, value: u8) -> Self {
+        self.set_varange(value);
+        self
+    }
+
+    /// Sets the value of the `VARange` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_varange(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_varange(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `VARange` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_varange(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::VARANGE_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `CCIDX` field.
+    pub const fn ccidx(self) -> u8 {
+        Self::CCIDX_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `CCIDX` field.
+    pub const fn set_ccidx(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::CCIDX_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `CCIDX` field set to the given value.
+    pub const fn with_ccidx(mut self, value: u8) -> Self {
+        self.set_ccidx(value);
+        self
+    }
+
+    /// Sets the value of the `CCIDX` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ccidx(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ccidx(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `CCIDX` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ccidx(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::CCIDX_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `NV` field.
+    pub const fn nv(self) -> u8 {
+        Self::NV_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `NV` field.
+    pub const fn set_nv(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::NV_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `NV` field set to the given value.
+    pub const fn with_nv(mut self, value: u8) -> Self {
+        self.set_nv(value);
+        self
+    }
+
+    /// Sets the value of the `NV` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_nv(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_nv(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `NV` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_nv(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::NV_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ST` field.
+    pub const fn st(self) -> u8 {
+        Self::ST_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `ST` field.
+    pub const fn set_st(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::ST_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ST` field set to the given value.
+    pub const fn with_st(mut self, value: u8) -> Self {
+        self.set_st(value);
+        self
+    }
+
+    /// Sets the value of the `ST` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_st(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_st(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ST` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_st(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::ST_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `AT` field.
+    pub const fn at(self) -> u8 {
+        Self::AT_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `AT` field.
+    pub const fn set_at(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::AT_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `AT` field set to the given value.
+    pub const fn with_at(mut self, value: u8) -> Self {
+        self.set_at(value);
+        self
+    }
+
+    /// Sets the value of the `AT` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_at(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_at(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `AT` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_at(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::AT_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `IDS` field.
+    pub const fn ids(self) -> u8 {
+        Self::IDS_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `IDS` field.
+    pub const fn set_ids(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::IDS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `IDS` field set to the given value.
+    pub const fn with_ids(mut self, value: u8) -> Self {
+        self.set_ids(value);
+        self
+    }
+
+    /// Sets the value of the `IDS` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ids(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ids(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `IDS` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ids(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::IDS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `FWB` field.
+    pub const fn fwb(self) -> u8 {
+        Self::FWB_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `FWB` field.
+    pub const fn set_fwb(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::FWB_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `FWB` field set to the given value.
+    pub const fn with_fwb(mut self, value: u8) -> Self {
+        self.set_fwb(value);
+        self
+    }
+
+    /// Sets the value of the `FWB` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_fwb(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_fwb(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `FWB` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_fwb(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::FWB_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `TTL` field.
+    pub const fn ttl(self) -> u8 {
+        Self::TTL_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `TTL` field.
+    pub const fn set_ttl(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::TTL_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `TTL` field set to the given value.
+    pub const fn with_ttl(mut self, value: u8) -> Self {
+        self.set_ttl(value);
+        self
+    }
+
+    /// Sets the value of the `TTL` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ttl(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ttl(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `TTL` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ttl(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::TTL_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `BBM` field.
+    pub const fn bbm(self) -> u8 {
+        Self::BBM_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `BBM` field.
+    pub const fn set_bbm(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::BBM_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `BBM` field set to the given value.
+    pub const fn with_bbm(mut self, value: u8) -> Self {
+        self.set_bbm(value);
+        self
+    }
+
+    /// Sets the value of the `BBM` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_bbm(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_bbm(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `BBM` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_bbm(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::BBM_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `EVT` field.
+    pub const fn evt(self) -> u8 {
+        Self::EVT_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `EVT` field.
+    pub const fn set_evt(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::EVT_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `EVT` field set to the given value.
+    pub const fn with_evt(mut self, value: u8) -> Self {
+        self.set_evt(value);
+        self
+    }
+
+    /// Sets the value of the `EVT` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_evt(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_evt(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `EVT` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_evt(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::EVT_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `E0PD` field.
+    pub const fn e0pd(self) -> u8 {
+        Self::E0PD_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `E0PD` field.
+    pub const fn set_e0pd(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::E0PD_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `E0PD` field set to the given value.
+    pub const fn with_e0pd(mut self, value: u8) -> Self {
+        self.set_e0pd(value);
+        self
+    }
+
+    /// Sets the value of the `E0PD` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_e0pd(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_e0pd(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `E0PD` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_e0pd(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::E0PD_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for IdAa64mmfr2El1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("IdAa64mmfr2El1");
+        let bits = self.bits();
+        s.field("CnP", &Self::CNP_FIELD.extract(bits));
+        s.field("UAO", &Self::UAO_FIELD.extract(bits));
+        s.field("LSM", &Self::LSM_FIELD.extract(bits));
+        s.field("IESB", &Self::IESB_FIELD.extract(bits));
+        s.field("VARange", &Self::VARANGE_FIELD.extract(bits));
+        s.field("CCIDX", &Self::CCIDX_FIELD.extract(bits));
+        s.field("NV", &Self::NV_FIELD.extract(bits));
+        s.field("ST", &Self::ST_FIELD.extract(bits));
+        s.field("AT", &Self::AT_FIELD.extract(bits));
+        s.field("IDS", &Self::IDS_FIELD.extract(bits));
+        s.field("FWB", &Self::FWB_FIELD.extract(bits));
+        s.field("TTL", &Self::TTL_FIELD.extract(bits));
+        s.field("BBM", &Self::BBM_FIELD.extract(bits));
+        s.field("EVT", &Self::EVT_FIELD.extract(bits));
+        s.field("E0PD", &Self::E0PD_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for IdAa64mmfr2El1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "IdAa64mmfr2El1 {{ CnP: {=u64}, UAO: {=u64}, LSM: {=u64}, IESB: {=u64}, VARange: {=u64}, CCIDX: {=u64}, NV: {=u64}, ST: {=u64}, AT: {=u64}, IDS: {=u64}, FWB: {=u64}, TTL: {=u64}, BBM: {=u64}, EVT: {=u64}, E0PD: {=u64} }}",
+            Self::CNP_FIELD.extract(self.bits()),
+            Self::UAO_FIELD.extract(self.bits()),
+            Self::LSM_FIELD.extract(self.bits()),
+            Self::IESB_FIELD.extract(self.bits()),
+            Self::VARANGE_FIELD.extract(self.bits()),
+            Self::CCIDX_FIELD.extract(self.bits()),
+            Self::NV_FIELD.extract(self.bits()),
+            Self::ST_FIELD.extract(self.bits()),
+            Self::AT_FIELD.extract(self.bits()),
+            Self::IDS_FIELD.extract(self.bits()),
+            Self::FWB_FIELD.extract(self.bits()),
+            Self::TTL_FIELD.extract(self.bits()),
+            Self::BBM_FIELD.extract(self.bits()),
+            Self::EVT_FIELD.extract(self.bits()),
+            Self::E0PD_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for IdAa64mmfr2El1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for IdAa64mmfr2El1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64mmfr2El1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("CnP"),
+                old: Self::CNP_FIELD.extract(self.bits()),
+                new: Self::CNP_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("UAO"),
+                old: Self::UAO_FIELD.extract(self.bits()),
+                new: Self::UAO_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("LSM"),
+                old: Self::LSM_FIELD.extract(self.bits()),
+                new: Self::LSM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IESB"),
+                old: Self::IESB_FIELD.extract(self.bits()),
+                new: Self::IESB_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VARange"),
+                old: Self::VARANGE_FIELD.extract(self.bits()),
+                new: Self::VARANGE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CCIDX"),
+                old: Self::CCIDX_FIELD.extract(self.bits()),
+                new: Self::CCIDX_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("NV"),
+                old: Self::NV_FIELD.extract(self.bits()),
+                new: Self::NV_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ST"),
+                old: Self::ST_FIELD.extract(self.bits()),
+                new: Self::ST_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("AT"),
+                old: Self::AT_FIELD.extract(self.bits()),
+                new: Self::AT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IDS"),
+                old: Self::IDS_FIELD.extract(self.bits()),
+                new: Self::IDS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FWB"),
+                old: Self::FWB_FIELD.extract(self.bits()),
+                new: Self::FWB_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TTL"),
+                old: Self::TTL_FIELD.extract(self.bits()),
+                new: Self::TTL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("BBM"),
+                old: Self::BBM_FIELD.extract(self.bits()),
+                new: Self::BBM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EVT"),
+                old: Self::EVT_FIELD.extract(self.bits()),
+                new: Self::EVT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("E0PD"),
+                old: Self::E0PD_FIELD.extract(self.bits()),
+                new: Self::E0PD_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64mmfr2El1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64mmfr2El1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `ID_AA64MMFR3_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct IdAa64mmfr3El1: u64 {
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64mmfr3El1 {
+    /// Descriptor of the `TCRX` field, for use with [`FieldAccess`].
+    pub const TCRX_FIELD: Field<Self, u8, 0, 4> = Field::new("ID_AA64MMFR3_EL1", "TCRX");
+    /// Descriptor of the `SCTLRX` field, for use with [`FieldAccess`].
+    pub const SCTLRX_FIELD: Field<Self, u8, 4, 4> = Field::new("ID_AA64MMFR3_EL1", "SCTLRX");
+    /// Descriptor of the `S1PIE` field, for use with [`FieldAccess`].
+    pub const S1PIE_FIELD: Field<Self, u8, 8, 4> = Field::new("ID_AA64MMFR3_EL1", "S1PIE");
+    /// Descriptor of the `S2PIE` field, for use with [`FieldAccess`].
+    pub const S2PIE_FIELD: Field<Self, u8, 12, 4> = Field::new("ID_AA64MMFR3_EL1", "S2PIE");
+    /// Descriptor of the `S1POE` field, for use with [`FieldAccess`].
+    pub const S1POE_FIELD: Field<Self, u8, 16, 4> = Field::new("ID_AA64MMFR3_EL1", "S1POE");
+    /// Descriptor of the `S2POE` field, for use with [`FieldAccess`].
+    pub const S2POE_FIELD: Field<Self, u8, 20, 4> = Field::new("ID_AA64MMFR3_EL1", "S2POE");
+    /// Descriptor of the `AIE` field, for use with [`FieldAccess`].
+    pub const AIE_FIELD: Field<Self, u8, 24, 4> = Field::new("ID_AA64MMFR3_EL1", "AIE");
+    /// Descriptor of the `MEC` field, for use with [`FieldAccess`].
+    pub const MEC_FIELD: Field<Self, u8, 28, 4> = Field::new("ID_AA64MMFR3_EL1", "MEC");
+    /// Descriptor of the `D128` field, for use with [`FieldAccess`].
+    pub const D128_FIELD: Field<Self, u8, 32, 4> = Field::new("ID_AA64MMFR3_EL1", "D128");
+    /// Descriptor of the `D128_2` field, for use with [`FieldAccess`].
+    pub const D128_2_FIELD: Field<Self, u8, 36, 4> = Field::new("ID_AA64MMFR3_EL1", "D128_2");
+    /// Descriptor of the `SNERR` field, for use with [`FieldAccess`].
+    pub const SNERR_FIELD: Field<Self, u8, 40, 4> = Field::new("ID_AA64MMFR3_EL1", "SNERR");
+    /// Descriptor of the `ANERR` field, for use with [`FieldAccess`].
+    pub const ANERR_FIELD: Field<Self, u8, 44, 4> = Field::new("ID_AA64MMFR3_EL1", "ANERR");
+    /// Descriptor of the `SDERR` field, for use with [`FieldAccess`].
+    pub const SDERR_FIELD: Field<Self, u8, 52, 4> = Field::new("ID_AA64MMFR3_EL1", "SDERR");
+    /// Descriptor of the `ADERR` field, for use with [`FieldAccess`].
+    pub const ADERR_FIELD: Field<Self, u8, 56, 4> = Field::new("ID_AA64MMFR3_EL1", "ADERR");
+    /// Descriptor of the `Spec_FPACC` field, for use with [`FieldAccess`].
+    pub const SPEC_FPACC_FIELD: Field<Self, u8, 60, 4> =
+        Field::new("ID_AA64MMFR3_EL1", "Spec_FPACC");
+
+    /// Returns the value of the `TCRX` field.
+    pub const fn tcrx(self) -> u8 {
+        Self::TCRX_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `TCRX` field.
+    pub const fn set_tcrx(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::TCRX_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `TCRX` field set to the given value.
+    pub const fn with_tcrx(mut self, value: u8) -> Self {
+        self.set_tcrx(value);
+        self
+    }
+
+    /// Sets the value of the `TCRX` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_tcrx(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_tcrx(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `TCRX` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_tcrx(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::TCRX_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SCTLRX` field.
+    pub const fn sctlrx(self) -> u8 {
+        Self::SCTLRX_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SCTLRX` field.
+    pub const fn set_sctlrx(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SCTLRX_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SCTLRX` field set to the given value.
+    pub const fn with_sctlrx(mut self, value: u8) -> Self {
+        self.set_sctlrx(value);
+        self
+    }
+
+    /// Sets the value of the `SCTLRX` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_sctlrx(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_sctlrx(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SCTLRX` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_sctlrx(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SCTLRX_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `S1PIE` field.
+    pub const fn s1pie(self) -> u8 {
+        Self::S1PIE_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `S1PIE` field.
+    pub const fn set_s1pie(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::S1PIE_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `S1PIE` field set to the given value.
+    pub const fn with_s1pie(mut self, value: u8) -> Self {
+        self.set_s1pie(value);
+        self
+    }
+
+    /// Sets the value of the `S1PIE` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_s1pie(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_s1pie(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `S1PIE` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_s1pie(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::S1PIE_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `S2PIE` field.
+    pub const fn s2pie(self) -> u8 {
+        Self::S2PIE_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `S2PIE` field.
+    pub const fn set_s2pie(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::S2PIE_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `S2PIE` field set to the given value.
+    pub const fn with_s2pie(mut self, value: u8) -> Self {
+        self.set_s2pie(value);
+        self
+    }
+
+    /// Sets the value of the `S2PIE` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_s2pie(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_s2pie(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `S2PIE` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_s2pie(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::S2PIE_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `S1POE` field.
+    pub const fn s1poe(self) -> u8 {
+        Self::S1POE_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `S1POE` field.
+    pub const fn set_s1poe(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::S1POE_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `S1POE` field set to the given value.
+    pub const fn with_s1poe(mut self, value: u8) -> Self {
+        self.set_s1poe(value);
+        self
+    }
+
+    /// Sets the value of the `S1POE` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_s1poe(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_s1poe(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `S1POE` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_s1poe(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::S1POE_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `S2POE` field.
+    pub const fn s2poe(self) -> u8 {
+        Self::S2POE_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `S2POE` field.
+    pub const fn set_s2poe(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::S2POE_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `S2POE` field set to the given value.
+    pub const fn with_s2poe(mut self, value: u8) -> Self {
+        self.set_s2poe(value);
+        self
+    }
+
+    /// Sets the value of the `S2POE` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_s2poe(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_s2poe(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `S2POE` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_s2poe(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::S2POE_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `AIE` field.
+    pub const fn aie(self) -> u8 {
+        Self::AIE_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `AIE` field.
+    pub const fn set_aie(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::AIE_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `AIE` field set to the given value.
+    pub const fn with_aie(mut self, value: u8) -> Self {
+        self.set_aie(value);
+        self
+    }
+
+    /// Sets the value of the `AIE` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_aie(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_aie(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `AIE` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_aie(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::AIE_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `MEC` field.
+    pub const fn mec(self) -> u8 {
+        Self::MEC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `MEC` field.
+    pub const fn set_mec(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::MEC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `MEC` field set to the given value.
+    pub const fn with_mec(mut self, value: u8) -> Self {
+        self.set_mec(value);
+        self
+    }
+
+    /// Sets the value of the `MEC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_mec(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_mec(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `MEC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_mec(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::MEC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `D128` field.
+    pub const fn d128(self) -> u8 {
+        Self::D128_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `D128` field.
+    pub const fn set_d128(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::D128_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `D128` field set to the given value.
+    pub const fn with_d128(mut self, value: u8) -> Self {
+        self.set_d128(value);
+        self
+    }
+
+    /// Sets the value of the `D128` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_d128(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_d128(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `D128` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_d128(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::D128_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `D128_2` field.
+    pub const fn d128_2(self) -> u8 {
+        Self::D128_2_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `D128_2` field.
+    pub const fn set_d128_2(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::D128_2_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `D128_2` field set to the given value.
+    pub const fn with_d128_2(mut self, value: u8) -> Self {
+        self.set_d128_2(value);
+        self
+    }
+
+    /// Sets the value of the `D128_2` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_d128_2(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_d128_2(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `D128_2` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_d128_2(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::D128_2_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SNERR` field.
+    pub const fn snerr(self) -> u8 {
+        Self::SNERR_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SNERR` field.
+    pub const fn set_snerr(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SNERR_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SNERR` field set to the given value.
+    pub const fn with_snerr(mut self, value: u8) -> Self {
+        self.set_snerr(value);
+        self
+    }
+
+    /// Sets the value of the `SNERR` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_snerr(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_snerr(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SNERR` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_snerr(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SNERR_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ANERR` field.
+    pub const fn anerr(self) -> u8 {
+        Self::ANERR_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `ANERR` field.
+    pub const fn set_anerr(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::ANERR_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ANERR` field set to the given value.
+    pub const fn with_anerr(mut self, value: u8) -> Self {
+        self.set_anerr(value);
+        self
+    }
+
+    /// Sets the value of the `ANERR` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_anerr(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_anerr(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ANERR` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_anerr(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::ANERR_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SDERR` field.
+    pub const fn sderr(self) -> u8 {
+        Self::SDERR_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SDERR` field.
+    pub const fn set_sderr(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SDERR_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SDERR` field set to the given value.
+    pub const fn with_sderr(mut self, value: u8) -> Self {
+        self.set_sderr(value);
+        self
+    }
+
+    /// Sets the value of the `SDERR` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_sderr(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_sderr(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SDERR` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_sderr(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SDERR_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ADERR` field.
+    pub const fn aderr(self) -> u8 {
+        Self::ADERR_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `ADERR` field.
+    pub const fn set_aderr(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::ADERR_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ADERR` field set to the given value.
+    pub const fn with_aderr(mut self, value: u8) -> Self {
+        self.set_aderr(value);
+        self
+    }
+
+    /// Sets the value of the `ADERR` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_aderr(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_aderr(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ADERR` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_aderr(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::ADERR_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `Spec_FPACC` field.
+    pub const fn spec_fpacc(self) -> u8 {
+        Self::SPEC_FPACC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `Spec_FPACC` field.
+    pub const fn set_spec_fpacc(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SPEC_FPACC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `Spec_FPACC` field set to the given value.
+    pub const fn with_spec_fpacc(mut self, value: u8) -> Self {
+        self.set_spec_fpacc(value);
+        self
+    }
+
+    /// Sets the value of the `Spec_FPACC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_spec_fpacc(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_spec_fpacc(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `Spec_FPACC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_spec_fpacc(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SPEC_FPACC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for IdAa64mmfr3El1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("IdAa64mmfr3El1");
+        let bits = self.bits();
+        s.field("TCRX", &Self::TCRX_FIELD.extract(bits));
+        s.field("SCTLRX", &Self::SCTLRX_FIELD.extract(bits));
+        s.field("S1PIE", &Self::S1PIE_FIELD.extract(bits));
+        s.field("S2PIE", &Self::S2PIE_FIELD.extract(bits));
+        s.field("S1POE", &Self::S1POE_FIELD.extract(bits));
+        s.field("S2POE", &Self::S2POE_FIELD.extract(bits));
+        s.field("AIE", &Self::AIE_FIELD.extract(bits));
+        s.field("MEC", &Self::MEC_FIELD.extract(bits));
+        s.field("D128", &Self::D128_FIELD.extract(bits));
+        s.field("D128_2", &Self::D128_2_FIELD.extract(bits));
+        s.field("SNERR", &Self::SNERR_FIELD.extract(bits));
+        s.field("ANERR", &Self::ANERR_FIELD.extract(bits));
+        s.field("SDERR", &Self::SDERR_FIELD.extract(bits));
+        s.field("ADERR", &Self::ADERR_FIELD.extract(bits));
+        s.field("Spec_FPACC", &Self::SPEC_FPACC_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for IdAa64mmfr3El1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "IdAa64mmfr3El1 {{ TCRX: {=u64}, SCTLRX: {=u64}, S1PIE: {=u64}, S2PIE: {=u64}, S1POE: {=u64}, S2POE: {=u64}, AIE: {=u64}, MEC: {=u64}, D128: {=u64}, D128_2: {=u64}, SNERR: {=u64}, ANERR: {=u64}, SDERR: {=u64}, ADERR: {=u64}, Spec_FPACC: {=u64} }}",
+            Self::TCRX_FIELD.extract(self.bits()),
+            Self::SCTLRX_FIELD.extract(self.bits()),
+            Self::S1PIE_FIELD.extract(self.bits()),
+            Self::S2PIE_FIELD.extract(self.bits()),
+            Self::S1POE_FIELD.extract(self.bits()),
+            Self::S2POE_FIELD.extract(self.bits()),
+            Self::AIE_FIELD.extract(self.bits()),
+            Self::MEC_FIELD.extract(self.bits()),
+            Self::D128_FIELD.extract(self.bits()),
+            Self::D128_2_FIELD.extract(self.bits()),
+            Self::SNERR_FIELD.extract(self.bits()),
+            Self::ANERR_FIELD.extract(self.bits()),
+            Self::SDERR_FIELD.extract(self.bits()),
+            Self::ADERR_FIELD.extract(self.bits()),
+            Self::SPEC_FPACC_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for IdAa64mmfr3El1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for IdAa64mmfr3El1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64mmfr3El1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("TCRX"),
+                old: Self::TCRX_FIELD.extract(self.bits()),
+                new: Self::TCRX_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SCTLRX"),
+                old: Self::SCTLRX_FIELD.extract(self.bits()),
+                new: Self::SCTLRX_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("S1PIE"),
+                old: Self::S1PIE_FIELD.extract(self.bits()),
+                new: Self::S1PIE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("S2PIE"),
+                old: Self::S2PIE_FIELD.extract(self.bits()),
+                new: Self::S2PIE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("S1POE"),
+                old: Self::S1POE_FIELD.extract(self.bits()),
+                new: Self::S1POE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("S2POE"),
+                old: Self::S2POE_FIELD.extract(self.bits()),
+                new: Self::S2POE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("AIE"),
+                old: Self::AIE_FIELD.extract(self.bits()),
+                new: Self::AIE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("MEC"),
+                old: Self::MEC_FIELD.extract(self.bits()),
+                new: Self::MEC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("D128"),
+                old: Self::D128_FIELD.extract(self.bits()),
+                new: Self::D128_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("D128_2"),
+                old: Self::D128_2_FIELD.extract(self.bits()),
+                new: Self::D128_2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SNERR"),
+                old: Self::SNERR_FIELD.extract(self.bits()),
+                new: Self::SNERR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ANERR"),
+                old: Self::ANERR_FIELD.extract(self.bits()),
+                new: Self::ANERR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SDERR"),
+                old: Self::SDERR_FIELD.extract(self.bits()),
+                new: Self::SDERR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ADERR"),
+                old: Self::ADERR_FIELD.extract(self.bits()),
+                new: Self::ADERR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("Spec_FPACC"),
+                old: Self::SPEC_FPACC_FIELD.extract(self.bits()),
+                new: Self::SPEC_FPACC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits()
+                    & 0b1111_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000,
+                new: other.bits()
+                    & 0b1111_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64mmfr3El1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64mmfr3El1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `ID_AA64MMFR4_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct IdAa64mmfr4El1: u64 {
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64mmfr4El1 {
+    /// Descriptor of the `PoPS` field, for use with [`FieldAccess`].
+    pub const POPS_FIELD: Field<Self, u8, 0, 4> = Field::new("ID_AA64MMFR4_EL1", "PoPS");
+    /// Descriptor of the `EIESB` field, for use with [`FieldAccess`].
+    pub const EIESB_FIELD: Field<Self, u8, 4, 4> = Field::new("ID_AA64MMFR4_EL1", "EIESB");
+    /// Descriptor of the `ASID2` field, for use with [`FieldAccess`].
+    pub const ASID2_FIELD: Field<Self, u8, 8, 4> = Field::new("ID_AA64MMFR4_EL1", "ASID2");
+    /// Descriptor of the `HACDBS` field, for use with [`FieldAccess`].
+    pub const HACDBS_FIELD: Field<Self, u8, 12, 4> = Field::new("ID_AA64MMFR4_EL1", "HACDBS");
+    /// Descriptor of the `FGWTE3` field, for use with [`FieldAccess`].
+    pub const FGWTE3_FIELD: Field<Self, u8, 16, 4> = Field::new("ID_AA64MMFR4_EL1", "FGWTE3");
+    /// Descriptor of the `NV_frac` field, for use with [`FieldAccess`].
+    pub const NV_FRAC_FIELD: Field<Self, u8, 20, 4> = Field::new("ID_AA64MMFR4_EL1", "NV_frac");
+    /// Descriptor of the `E2H0` field, for use with [`FieldAccess`].
+    pub const E2H0_FIELD: Field<Self, u8, 24, 4> = Field::new("ID_AA64MMFR4_EL1", "E2H0");
+    /// Descriptor of the `RMEGDI` field, for use with [`FieldAccess`].
+    pub const RMEGDI_FIELD: Field<Self, u8, 28, 4> = Field::new("ID_AA64MMFR4_EL1", "RMEGDI");
+    /// Descriptor of the `EAESR` field, for use with [`FieldAccess`].
+    pub const EAESR_FIELD: Field<Self, u8, 32, 4> = Field::new("ID_AA64MMFR4_EL1", "EAESR");
+    /// Descriptor of the `E3DSE` field, for use with [`FieldAccess`].
+    pub const E3DSE_FIELD: Field<Self, u8, 36, 4> = Field::new("ID_AA64MMFR4_EL1", "E3DSE");
+    /// Descriptor of the `TLBID` field, for use with [`FieldAccess`].
+    pub const TLBID_FIELD: Field<Self, u8, 40, 4> = Field::new("ID_AA64MMFR4_EL1", "TLBID");
+    /// Descriptor of the `SRMASK` field, for use with [`FieldAccess`].
+    pub const SRMASK_FIELD: Field<Self, u8, 44, 4> = Field::new("ID_AA64MMFR4_EL1", "SRMASK");
+    /// Descriptor of the `TPS` field, for use with [`FieldAccess`].
+    pub const TPS_FIELD: Field<Self, u8, 48, 4> = Field::new("ID_AA64MMFR4_EL1", "TPS");
+    /// Descriptor of the `TEV` field, for use with [`FieldAccess`].
+    pub const TEV_FIELD: Field<Self, u8, 52, 4> = Field::new("ID_AA64MMFR4_EL1", "TEV");
+    /// Descriptor of the `SCRX` field, for use with [`FieldAccess`].
+    pub const SCRX_FIELD: Field<Self, u8, 56, 4> = Field::new("ID_AA64MMFR4_EL1", "SCRX");
+    /// Descriptor of the `MTEFGT` field, for use with [`FieldAccess`].
+    pub const MTEFGT_FIELD: Field<Self, u8, 60, 4> = Field::new("ID_AA64MMFR4_EL1", "MTEFGT");
+
+    /// Returns the value of the `PoPS` field.
+    pub const fn pops(self) -> u8 {
+        Self::POPS_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `PoPS` field.
+    pub const fn set_pops(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::POPS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PoPS` field set to the given value.
+    pub const fn with_pops(mut self, value: u8) -> Self {
+        self.set_pops(value);
+        self
+    }
+
+    /// Sets the value of the `PoPS` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_pops(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_pops(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PoPS` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_pops(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::POPS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `EIESB` field.
+    pub const fn eiesb(self) -> u8 {
+        Self::EIESB_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `EIESB` field.
+    pub const fn set_eiesb(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::EIESB_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `EIESB` field set to the given value.
+    pub const fn with_eiesb(mut self, value: u8) -> Self {
+        self.set_eiesb(value);
+        self
+    }
+
+    /// Sets the value of the `EIESB` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_eiesb(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_eiesb(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `EIESB` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_eiesb(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::EIESB_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ASID2` field.
+    pub const fn asid2(self) -> u8 {
+        Self::ASID2_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `ASID2` field.
+    pub const fn set_asid2(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::ASID2_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ASID2` field set to the given value.
+    pub const fn with_asid2(mut self, value: u8) -> Self {
+        self.set_asid2(value);
+        self
+    }
+
+    /// Sets the value of the `ASID2` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_asid2(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_asid2(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ASID2` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_asid2(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::ASID2_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `HACDBS` field.
+    pub const fn hacdbs(self) -> u8 {
+        Self::HACDBS_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `HACDBS` field.
+    pub const fn set_hacdbs(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::HACDBS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `HACDBS` field set to the given value.
+    pub const fn with_hacdbs(mut self, value: u8) -> Self {
+        self.set_hacdbs(value);
+        self
+    }
+
+    /// Sets the value of the `HACDBS` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_hacdbs(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_hacdbs(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `HACDBS` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_hacdbs(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::HACDBS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `FGWTE3` field.
+    pub const fn fgwte3(self) -> u8 {
+        Self::FGWTE3_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `FGWTE3` field.
+    pub const fn set_fgwte3(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::FGWTE3_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `FGWTE3` field set to the given value.
+    pub const fn with_fgwte3(mut self, value: u8) -> Self {
+        self.set_fgwte3(value);
+        self
+    }
+
+    /// Sets the value of the `FGWTE3` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_fgwte3(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_fgwte3(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `FGWTE3` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_fgwte3(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::FGWTE3_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `NV_frac` field.
+    pub const fn nv_frac(self) -> u8 {
+        Self::NV_FRAC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `NV_frac` field.
+    pub const fn set_nv_frac(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::NV_FRAC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `NV_frac` field set to the given value.
+    pub const fn with_nv_frac(mut self, value: u8) -> Self {
+        self.set_nv_frac(value);
+        self
+    }
+
+    /// Sets the value of the `NV_frac` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_nv_frac(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_nv_frac(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `NV_frac` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_nv_frac(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::NV_FRAC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `E2H0` field.
+    pub const fn e2h0(self) -> u8 {
+        Self::E2H0_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `E2H0` field.
+    pub const fn set_e2h0(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::E2H0_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `E2H0` field set to the given value.
+    pub const fn with_e2h0(mut self, value: u8) -> Self {
+        self.set_e2h0(value);
+        self
+    }
+
+    /// Sets the value of the `E2H0` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_e2h0(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_e2h0(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `E2H0` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_e2h0(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::E2H0_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `RMEGDI` field.
+    pub const fn rmegdi(self) -> u8 {
+        Self::RMEGDI_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `RMEGDI` field.
+    pub const fn set_rmegdi(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::RMEGDI_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `RMEGDI` field set to the given value.
+    pub const fn with_rmegdi(mut self, value: u8) -> Self {
+        self.set_rmegdi(value);
+        self
+    }
+
+    /// Sets the value of the `RMEGDI` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_rmegdi(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_rmegdi(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `RMEGDI` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_rmegdi(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::RMEGDI_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `EAESR` field.
+    pub const fn eaesr(self) -> u8 {
+        Self::EAESR_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `EAESR` field.
+    pub const fn set_eaesr(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::EAESR_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `EAESR` field set to the given value.
+    pub const fn with_eaesr(mut self, value: u8) -> Self {
+        self.set_eaesr(value);
+        self
+    }
+
+    /// Sets the value of the `EAESR` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_eaesr(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_eaesr(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `EAESR` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_eaesr(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::EAESR_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `E3DSE` field.
+    pub const fn e3dse(self) -> u8 {
+        Self::E3DSE_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `E3DSE` field.
+    pub const fn set_e3dse(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::E3DSE_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `E3DSE` field set to the given value.
+    pub const fn with_e3dse(mut self, value: u8) -> Self {
+        self.set_e3dse(value);
+        self
+    }
+
+    /// Sets the value of the `E3DSE` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_e3dse(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_e3dse(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `E3DSE` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_e3dse(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::E3DSE_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `TLBID` field.
+    pub const fn tlbid(self) -> u8 {
+        Self::TLBID_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `TLBID` field.
+    pub const fn set_tlbid(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::TLBID_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `TLBID` field set to the given value.
+    pub const fn with_tlbid(mut self, value: u8) -> Self {
+        self.set_tlbid(value);
+        self
+    }
+
+    /// Sets the value of the `TLBID` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_tlbid(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_tlbid(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `TLBID` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_tlbid(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::TLBID_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SRMASK` field.
+    pub const fn srmask(self) -> u8 {
+        Self::SRMASK_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SRMASK` field.
+    pub const fn set_srmask(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SRMASK_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SRMASK` field set to the given value.
+    pub const fn with_srmask(mut self, value: u8) -> Self {
+        self.set_srmask(value);
+        self
+    }
+
+    /// Sets the value of the `SRMASK` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_srmask(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_srmask(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SRMASK` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_srmask(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SRMASK_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `TPS` field.
+    pub const fn tps(self) -> u8 {
+        Self::TPS_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `TPS` field.
+    pub const fn set_tps(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::TPS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `TPS` field set to the given value.
+    pub const fn with_tps(mut self, value: u8) -> Self {
+        self.set_tps(value);
+        self
+    }
+
+    /// Sets the value of the `TPS` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_tps(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_tps(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `TPS` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_tps(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::TPS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `TEV` field.
+    pub const fn tev(self) -> u8 {
+        Self::TEV_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `TEV` field.
+    pub const fn set_tev(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::TEV_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `TEV` field set to the given value.
+    pub const fn with_tev(mut self, value: u8) -> Self {
+        self.set_tev(value);
+        self
+    }
+
+    /// Sets the value of the `TEV` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_tev(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_tev(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `TEV` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_tev(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::TEV_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SCRX` field.
+    pub const fn scrx(self) -> u8 {
+        Self::SCRX_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SCRX` field.
+    pub const fn set_scrx(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SCRX_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SCRX` field set to the given value.
+    pub const fn with_scrx(mut self, value: u8) -> Self {
+        self.set_scrx(value);
+        self
+    }
+
+    /// Sets the value of the `SCRX` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_scrx(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_scrx(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SCRX` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_scrx(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SCRX_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `MTEFGT` field.
+    pub const fn mtefgt(self) -> u8 {
+        Self::MTEFGT_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `MTEFGT` field.
+    pub const fn set_mtefgt(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::MTEFGT_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `MTEFGT` field set to the given value.
+    pub const fn with_mtefgt(mut self, value: u8) -> Self {
+        self.set_mtefgt(value);
+        self
+    }
+
+    /// Sets the value of the `MTEFGT` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_mtefgt(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_mtefgt(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `MTEFGT` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_mtefgt(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::MTEFGT_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for IdAa64mmfr4El1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("IdAa64mmfr4El1");
+        let bits = self.bits();
+        s.field("PoPS", &Self::POPS_FIELD.extract(bits));
+        s.field("EIESB", &Self::EIESB_FIELD.extract(bits));
+        s.field("ASID2", &Self::ASID2_FIELD.extract(bits));
+        s.field("HACDBS", &Self::HACDBS_FIELD.extract(bits));
+        s.field("FGWTE3", &Self::FGWTE3_FIELD.extract(bits));
+        s.field("NV_frac", &Self::NV_FRAC_FIELD.extract(bits));
+        s.field("E2H0", &Self::E2H0_FIELD.extract(bits));
+        s.field("RMEGDI", &Self::RMEGDI_FIELD.extract(bits));
+        s.field("EAESR", &Self::EAESR_FIELD.extract(bits));
+        s.field("E3DSE", &Self::E3DSE_FIELD.extract(bits));
+        s.field("TLBID", &Self::TLBID_FIELD.extract(bits));
+        s.field("SRMASK", &Self::SRMASK_FIELD.extract(bits));
+        s.field("TPS", &Self::TPS_FIELD.extract(bits));
+        s.field("TEV", &Self::TEV_FIELD.extract(bits));
+        s.field("SCRX", &Self::SCRX_FIELD.extract(bits));
+        s.field("MTEFGT", &Self::MTEFGT_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for IdAa64mmfr4El1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "IdAa64mmfr4El1 {{ PoPS: {=u64}, EIESB: {=u64}, ASID2: {=u64}, HACDBS: {=u64}, FGWTE3: {=u64}, NV_frac: {=u64}, E2H0: {=u64}, RMEGDI: {=u64}, EAESR: {=u64}, E3DSE: {=u64}, TLBID: {=u64}, SRMASK: {=u64}, TPS: {=u64}, TEV: {=u64}, SCRX: {=u64}, MTEFGT: {=u64} }}",
+            Self::POPS_FIELD.extract(self.bits()),
+            Self::EIESB_FIELD.extract(self.bits()),
+            Self::ASID2_FIELD.extract(self.bits()),
+            Self::HACDBS_FIELD.extract(self.bits()),
+            Self::FGWTE3_FIELD.extract(self.bits()),
+            Self::NV_FRAC_FIELD.extract(self.bits()),
+            Self::E2H0_FIELD.extract(self.bits()),
+            Self::RMEGDI_FIELD.extract(self.bits()),
+            Self::EAESR_FIELD.extract(self.bits()),
+            Self::E3DSE_FIELD.extract(self.bits()),
+            Self::TLBID_FIELD.extract(self.bits()),
+            Self::SRMASK_FIELD.extract(self.bits()),
+            Self::TPS_FIELD.extract(self.bits()),
+            Self::TEV_FIELD.extract(self.bits()),
+            Self::SCRX_FIELD.extract(self.bits()),
+            Self::MTEFGT_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for IdAa64mmfr4El1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for IdAa64mmfr4El1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64mmfr4El1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("PoPS"),
+                old: Self::POPS_FIELD.extract(self.bits()),
+                new: Self::POPS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EIESB"),
+                old: Self::EIESB_FIELD.extract(self.bits()),
+                new: Self::EIESB_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ASID2"),
+                old: Self::ASID2_FIELD.extract(self.bits()),
+                new: Self::ASID2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HACDBS"),
+                old: Self::HACDBS_FIELD.extract(self.bits()),
+                new: Self::HACDBS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FGWTE3"),
+                old: Self::FGWTE3_FIELD.extract(self.bits()),
+                new: Self::FGWTE3_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("NV_frac"),
+                old: Self::NV_FRAC_FIELD.extract(self.bits()),
+                new: Self::NV_FRAC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("E2H0"),
+                old: Self::E2H0_FIELD.extract(self.bits()),
+                new: Self::E2H0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("RMEGDI"),
+                old: Self::RMEGDI_FIELD.extract(self.bits()),
+                new: Self::RMEGDI_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EAESR"),
+                old: Self::EAESR_FIELD.extract(self.bits()),
+                new: Self::EAESR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("E3DSE"),
+                old: Self::E3DSE_FIELD.extract(self.bits()),
+                new: Self::E3DSE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TLBID"),
+                old: Self::TLBID_FIELD.extract(self.bits()),
+                new: Self::TLBID_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SRMASK"),
+                old: Self::SRMASK_FIELD.extract(self.bits()),
+                new: Self::SRMASK_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TPS"),
+                old: Self::TPS_FIELD.extract(self.bits()),
+                new: Self::TPS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TEV"),
+                old: Self::TEV_FIELD.extract(self.bits()),
+                new: Self::TEV_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SCRX"),
+                old: Self::SCRX_FIELD.extract(self.bits()),
+                new: Self::SCRX_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("MTEFGT"),
+                old: Self::MTEFGT_FIELD.extract(self.bits()),
+                new: Self::MTEFGT_FIELD.extract(other.bits()),
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64mmfr4El1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64mmfr4El1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `ID_AA64PFR0_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct IdAa64pfr0El1: u64 {
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64pfr0El1 {
+    /// Descriptor of the `EL0` field, for use with [`FieldAccess`].
+    pub const EL0_FIELD: Field<Self, u8, 0, 4> = Field::new("ID_AA64PFR0_EL1", "EL0");
+    /// Descriptor of the `EL1` field, for use with [`FieldAccess`].
+    pub const EL1_FIELD: Field<Self, u8, 4, 4> = Field::new("ID_AA64PFR0_EL1", "EL1");
+    /// Descriptor of the `EL2` field, for use with [`FieldAccess`].
+    pub const EL2_FIELD: Field<Self, u8, 8, 4> = Field::new("ID_AA64PFR0_EL1", "EL2");
+    /// Descriptor of the `EL3` field, for use with [`FieldAccess`].
+    pub const EL3_FIELD: Field<Self, u8, 12, 4> = Field::new("ID_AA64PFR0_EL1", "EL3");
+    /// Descriptor of the `FP` field, for use with [`FieldAccess`].
+    pub const FP_FIELD: Field<Self, u8, 16, 4> = Field::new("ID_AA64PFR0_EL1", "FP");
+    /// Descriptor of the `AdvSIMD` field, for use with [`FieldAccess`].
+    pub const ADVSIMD_FIELD: Field<Self, u8, 20, 4> = Field::new("ID_AA64PFR0_EL1", "AdvSIMD");
+    /// Descriptor of the `GIC` field, for use with [`FieldAccess`].
+    pub const GIC_FIELD: Field<Self, u8, 24, 4> = Field::new("ID_AA64PFR0_EL1", "GIC");
+    /// Descriptor of the `RAS` field, for use with [`FieldAccess`].
+    pub const RAS_FIELD: Field<Self, u8, 28, 4> = Field::new("ID_AA64PFR0_EL1", "RAS");
+    /// Descriptor of the `SVE` field, for use with [`FieldAccess`].
+    pub const SVE_FIELD: Field<Self, u8, 32, 4> = Field::new("ID_AA64PFR0_EL1", "SVE");
+    /// Descriptor of the `SEL2` field, for use with [`FieldAccess`].
+    pub const SEL2_FIELD: Field<Self, u8, 36, 4> = Field::new("ID_AA64PFR0_EL1", "SEL2");
+    /// Descriptor of the `MPAM` field, for use with [`FieldAccess`].
+    pub const MPAM_FIELD: Field<Self, u8, 40, 4> = Field::new("ID_AA64PFR0_EL1", "MPAM");
+    /// Descriptor of the `AMU` field, for use with [`FieldAccess`].
+    pub const AMU_FIELD: Field<Self, u8, 44, 4> = Field::new("ID_AA64PFR0_EL1", "AMU");
+    /// Descriptor of the `DIT` field, for use with [`FieldAccess`].
+    pub const DIT_FIELD: Field<Self, u8, 48, 4> = Field::new("ID_AA64PFR0_EL1", "DIT");
+    /// Descriptor of the `RME` field, for use with [`FieldAccess`].
+    pub const RME_FIELD: Field<Self, u8, 52, 4> = Field::new("ID_AA64PFR0_EL1", "RME");
+    /// Descriptor of the `CSV2` field, for use with [`FieldAccess`].
+    pub const CSV2_FIELD: Field<Self, u8, 56, 4> = Field::new("ID_AA64PFR0_EL1", "CSV2");
+    /// Descriptor of the `CSV3` field, for use with [`FieldAccess`].
+    pub const CSV3_FIELD: Field<Self, u8, 60, 4> = Field::new("ID_AA64PFR0_EL1", "CSV3");
+
+    /// Returns the value of the `EL0` field.
+    pub const fn el0(self) -> u8 {
+        Self::EL0_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `EL0` field.
+    pub const fn set_el0(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::EL0_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `EL0` field set to the given value.
+    pub const fn with_el0(mut self, value: u8) -> Self {
+        self.set_el0(value);
+        self
+    }
+
+    /// Sets the value of the `EL0` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_el0(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_el0(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `EL0` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_el0(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::EL0_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `EL1` field.
+    pub const fn el1(self) -> u8 {
+        Self::EL1_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `EL1` field.
+    pub const fn set_el1(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::EL1_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `EL1` field set to the given value.
+    pub const fn with_el1(mut self, value: u8) -> Self {
+        self.set_el1(value);
+        self
+    }
+
+    /// Sets the value of the `EL1` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_el1(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_el1(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `EL1` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_el1(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::EL1_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `EL2` field.
+    pub const fn el2(self) -> u8 {
+        Self::EL2_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `EL2` field.
+    pub const fn set_el2(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::EL2_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `EL2` field set to the given value.
+    pub const fn with_el2(mut self, value: u8) -> Self {
+        self.set_el2(value);
+        self
+    }
+
+    /// Sets the value of the `EL2` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_el2(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_el2(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `EL2` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_el2(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::EL2_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `EL3` field.
+    pub const fn el3(self) -> u8 {
+        Self::EL3_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `EL3` field.
+    pub const fn set_el3(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::EL3_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `EL3` field set to the given value.
+    pub const fn with_el3(mut self, value: u8) -> Self {
+        self.set_el3(value);
+        self
+    }
+
+    /// Sets the value of the `EL3` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_el3(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_el3(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `EL3` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_el3(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::EL3_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `FP` field.
+    pub const fn fp(self) -> u8 {
+        Self::FP_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `FP` field.
+    pub const fn set_fp(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::FP_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `FP` field set to the given value.
+    pub const fn with_fp(mut self, value: u8) -> Self {
+        self.set_fp(value);
+        self
+    }
+
+    /// Sets the value of the `FP` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_fp(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_fp(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `FP` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_fp(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::FP_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `AdvSIMD` field.
+    pub const fn advsimd(self) -> u8 {
+        Self::ADVSIMD_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `AdvSIMD` field.
+    pub const fn set_advsimd(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::ADVSIMD_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `AdvSIMD` field set to the given value.
+    pub const fn with_advsimd(mut self, value: u8) -> Self {
+        self.set_advsimd(value);
+        self
+    }
+
+    /// Sets the value of the `AdvSIMD` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_advsimd(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_advsimd(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `AdvSIMD` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_advsimd(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::ADVSIMD_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `GIC` field.
+    pub const fn gic(self) -> u8 {
+        Self::GIC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `GIC` field.
+    pub const fn set_gic(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::GIC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `GIC` field set to the given value.
+    pub const fn with_gic(mut self, value: u8) -> Self {
+        self.set_gic(value);
+        self
+    }
+
+    /// Sets the value of the `GIC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_gic(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_gic(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `GIC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_gic(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::GIC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `RAS` field.
+    pub const fn ras(self) -> u8 {
+        Self::RAS_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `RAS` field.
+    pub const fn set_ras(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::RAS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `RAS` field set to the given value.
+    pub const fn with_ras(mut self, value: u8) -> Self {
+        self.set_ras(value);
+        self
+    }
+
+    /// Sets the value of the `RAS` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ras(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ras(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `RAS` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ras(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::RAS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SVE` field.
+    pub const fn sve(self) -> u8 {
+        Self::SVE_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SVE` field.
+    pub const fn set_sve(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SVE_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SVE` field set to the given value.
+    pub const fn with_sve(mut self, value: u8) -> Self {
+        self.set_sve(value);
+        self
+    }
+
+    /// Sets the value of the `SVE` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_sve(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_sve(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SVE` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_sve(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SVE_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SEL2` field.
+    pub const fn sel2(self) -> u8 {
+        Self::SEL2_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SEL2` field.
+    pub const fn set_sel2(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SEL2_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SEL2` field set to the given value.
+    pub const fn with_sel2(mut self, value: u8) -> Self {
+        self.set_sel2(value);
+        self
+    }
+
+    /// Sets the value of the `SEL2` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_sel2(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_sel2(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SEL2` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_sel2(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SEL2_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `MPAM` field.
+    pub const fn mpam(self) -> u8 {
+        Self::MPAM_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `MPAM` field.
+    pub const fn set_mpam(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::MPAM_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `MPAM` field set to the given value.
+    pub const fn with_mpam(mut self, value: u8) -> Self {
+        self.set_mpam(value);
+        self
+    }
+
+    /// Sets the value of the `MPAM` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_mpam(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_mpam(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `MPAM` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_mpam(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::MPAM_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `AMU` field.
+    pub const fn amu(self) -> u8 {
+        Self::AMU_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `AMU` field.
+    pub const fn set_amu(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::AMU_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `AMU` field set to the given value.
+    pub const fn with_amu(mut self, value: u8) -> Self {
+        self.set_amu(value);
+        self
+    }
+
+    /// Sets the value of the `AMU` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_amu(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_amu(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `AMU` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_amu(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::AMU_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `DIT` field.
+    pub const fn dit(self) -> u8 {
+        Self::DIT_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `DIT` field.
+    pub const fn set_dit(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::DIT_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `DIT` field set to the given value.
+    pub const fn with_dit(mut self, value: u8) -> Self {
+        self.set_dit(value);
+        self
+    }
+
+    /// Sets the value of the `DIT` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_dit(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_dit(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `DIT` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_dit(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::DIT_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `RME` field.
+    pub const fn rme(self) -> u8 {
+        Self::RME_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `RME` field.
+    pub const fn set_rme(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::RME_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `RME` field set to the given value.
+    pub const fn with_rme(mut self, value: u8) -> Self {
+        self.set_rme(value);
+        self
+    }
+
+    /// Sets the value of the `RME` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_rme(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_rme(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `RME` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_rme(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::RME_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `CSV2` field.
+    pub const fn csv2(self) -> u8 {
+        Self::CSV2_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `CSV2` field.
+    pub const fn set_csv2(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::CSV2_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `CSV2` field set to the given value.
+    pub const fn with_csv2(mut self, value: u8) -> Self {
+        self.set_csv2(value);
+        self
+    }
+
+    /// Sets the value of the `CSV2` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_csv2(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_csv2(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `CSV2` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_csv2(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::CSV2_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `CSV3` field.
+    pub const fn csv3(self) -> u8 {
+        Self::CSV3_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `CSV3` field.
+    pub const fn set_csv3(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::CSV3_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `CSV3` field set to the given value.
+    pub const fn with_csv3(mut self, value: u8) -> Self {
+        self.set_csv3(value);
+        self
+    }
+
+    /// Sets the value of the `CSV3` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_csv3(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_csv3(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `CSV3` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_csv3(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::CSV3_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for IdAa64pfr0El1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("IdAa64pfr0El1");
+        let bits = self.bits();
+        s.field("EL0", &Self::EL0_FIELD.extract(bits));
+        s.field("EL1", &Self::EL1_FIELD.extract(bits));
+        s.field("EL2", &Self::EL2_FIELD.extract(bits));
+        s.field("EL3", &Self::EL3_FIELD.extract(bits));
+        s.field("FP", &Self::FP_FIELD.extract(bits));
+        s.field("AdvSIMD", &Self::ADVSIMD_FIELD.extract(bits));
+        s.field("GIC", &Self::GIC_FIELD.extract(bits));
+        s.field("RAS", &Self::RAS_FIELD.extract(bits));
+        s.field("SVE", &Self::SVE_FIELD.extract(bits));
+        s.field("SEL2", &Self::SEL2_FIELD.extract(bits));
+        s.field("MPAM", &Self::MPAM_FIELD.extract(bits));
+        s.field("AMU", &Self::AMU_FIELD.extract(bits));
+        s.field("DIT", &Self::DIT_FIELD.extract(bits));
+        s.field("RME", &Self::RME_FIELD.extract(bits));
+        s.field("CSV2", &Self::CSV2_FIELD.extract(bits));
+        s.field("CSV3", &Self::CSV3_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for IdAa64pfr0El1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "IdAa64pfr0El1 {{ EL0: {=u64}, EL1: {=u64}, EL2: {=u64}, EL3: {=u64}, FP: {=u64}, AdvSIMD: {=u64}, GIC: {=u64}, RAS: {=u64}, SVE: {=u64}, SEL2: {=u64}, MPAM: {=u64}, AMU: {=u64}, DIT: {=u64}, RME: {=u64}, CSV2: {=u64}, CSV3: {=u64} }}",
+            Self::EL0_FIELD.extract(self.bits()),
+            Self::EL1_FIELD.extract(self.bits()),
+            Self::EL2_FIELD.extract(self.bits()),
+            Self::EL3_FIELD.extract(self.bits()),
+            Self::FP_FIELD.extract(self.bits()),
+            Self::ADVSIMD_FIELD.extract(self.bits()),
+            Self::GIC_FIELD.extract(self.bits()),
+            Self::RAS_FIELD.extract(self.bits()),
+            Self::SVE_FIELD.extract(self.bits()),
+            Self::SEL2_FIELD.extract(self.bits()),
+            Self::MPAM_FIELD.extract(self.bits()),
+            Self::AMU_FIELD.extract(self.bits()),
+            Self::DIT_FIELD.extract(self.bits()),
+            Self::RME_FIELD.extract(self.bits()),
+            Self::CSV2_FIELD.extract(self.bits()),
+            Self::CSV3_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for IdAa64pfr0El1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for IdAa64pfr0El1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64pfr0El1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("EL0"),
+                old: Self::EL0_FIELD.extract(self.bits()),
+                new: Self::EL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EL1"),
+                old: Self::EL1_FIELD.extract(self.bits()),
+                new: Self::EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EL2"),
+                old: Self::EL2_FIELD.extract(self.bits()),
+                new: Self::EL2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EL3"),
+                old: Self::EL3_FIELD.extract(self.bits()),
+                new: Self::EL3_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FP"),
+                old: Self::FP_FIELD.extract(self.bits()),
+                new: Self::FP_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("AdvSIMD"),
+                old: Self::ADVSIMD_FIELD.extract(self.bits()),
+                new: Self::ADVSIMD_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("GIC"),
+                old: Self::GIC_FIELD.extract(self.bits()),
+                new: Self::GIC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("RAS"),
+                old: Self::RAS_FIELD.extract(self.bits()),
+                new: Self::RAS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SVE"),
+                old: Self::SVE_FIELD.extract(self.bits()),
+                new: Self::SVE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SEL2"),
+                old: Self::SEL2_FIELD.extract(self.bits()),
+                new: Self::SEL2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("MPAM"),
+                old: Self::MPAM_FIELD.extract(self.bits()),
+                new: Self::MPAM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("AMU"),
+                old: Self::AMU_FIELD.extract(self.bits()),
+                new: Self::AMU_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DIT"),
+                old: Self::DIT_FIELD.extract(self.bits()),
+                new: Self::DIT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("RME"),
+                old: Self::RME_FIELD.extract(self.bits()),
+                new: Self::RME_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CSV2"),
+                old: Self::CSV2_FIELD.extract(self.bits()),
+                new: Self::CSV2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CSV3"),
+                old: Self::CSV3_FIELD.extract(self.bits()),
+                new: Self::CSV3_FIELD.extract(other.bits()),
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64pfr0El1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64pfr0El1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `ID_AA64PFR1_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct IdAa64pfr1El1: u64 {
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64pfr1El1 {
+    /// Descriptor of the `BT` field, for use with [`FieldAccess`].
+    pub const BT_FIELD: Field<Self, u8, 0, 4> = Field::new("ID_AA64PFR1_EL1", "BT");
+    /// Descriptor of the `SSBS` field, for use with [`FieldAccess`].
+    pub const SSBS_FIELD: Field<Self, u8, 4, 4> = Field::new("ID_AA64PFR1_EL1", "SSBS");
+    /// Descriptor of the `MTE` field, for use with [`FieldAccess`].
+    pub const MTE_FIELD: Field<Self, u8, 8, 4> = Field::new("ID_AA64PFR1_EL1", "MTE");
+    /// Descriptor of the `RAS_frac` field, for use with [`FieldAccess`].
+    pub const RAS_FRAC_FIELD: Field<Self, u8, 12, 4> = Field::new("ID_AA64PFR1_EL1", "RAS_frac");
+    /// Descriptor of the `MPAM_frac` field, for use with [`FieldAccess`].
+    pub const MPAM_FRAC_FIELD: Field<Self, u8, 16, 4> = Field::new("ID_AA64PFR1_EL1", "MPAM_frac");
+    /// Descriptor of the `SME` field, for use with [`FieldAccess`].
+    pub const SME_FIELD: Field<Self, u8, 24, 4> = Field::new("ID_AA64PFR1_EL1", "SME");
+    /// Descriptor of the `RNDR_trap` field, for use with [`FieldAccess`].
+    pub const RNDR_TRAP_FIELD: Field<Self, u8, 28, 4> = Field::new("ID_AA64PFR1_EL1", "RNDR_trap");
+    /// Descriptor of the `CSV2_frac` field, for use with [`FieldAccess`].
+    pub const CSV2_FRAC_FIELD: Field<Self, u8, 32, 4> = Field::new("ID_AA64PFR1_EL1", "CSV2_frac");
+    /// Descriptor of the `NMI` field, for use with [`FieldAccess`].
+    pub const NMI_FIELD: Field<Self, u8, 36, 4> = Field::new("ID_AA64PFR1_EL1", "NMI");
+    /// Descriptor of the `MTE_frac` field, for use with [`FieldAccess`].
+    pub const MTE_FRAC_FIELD: Field<Self, u8, 40, 4> = Field::new("ID_AA64PFR1_EL1", "MTE_frac");
+    /// Descriptor of the `GCS` field, for use with [`FieldAccess`].
+    pub const GCS_FIELD: Field<Self, u8, 44, 4> = Field::new("ID_AA64PFR1_EL1", "GCS");
+    /// Descriptor of the `THE` field, for use with [`FieldAccess`].
+    pub const THE_FIELD: Field<Self, u8, 48, 4> = Field::new("ID_AA64PFR1_EL1", "THE");
+    /// Descriptor of the `MTEX` field, for use with [`FieldAccess`].
+    pub const MTEX_FIELD: Field<Self, u8, 52, 4> = Field::new("ID_AA64PFR1_EL1", "MTEX");
+    /// Descriptor of the `DF2` field, for use with [`FieldAccess`].
+    pub const DF2_FIELD: Field<Self, u8, 56, 4> = Field::new("ID_AA64PFR1_EL1", "DF2");
+    /// Descriptor of the `PFAR` field, for use with [`FieldAccess`].
+    pub const PFAR_FIELD: Field<Self, u8, 60, 4> = Field::new("ID_AA64PFR1_EL1", "PFAR");
+
+    /// Returns the value of the `BT` field.
+    pub const fn bt(self) -> u8 {
+        Self::BT_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `BT` field.
+    pub const fn set_bt(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::BT_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `BT` field set to the given value.
+    pub const fn with_bt(mut self, value: u8) -> Self {
+        self.set_bt(value);
+        self
+    }
+
+    /// Sets the value of the `BT` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_bt(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_bt(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `BT` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_bt(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::BT_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SSBS` field.
+    pub const fn ssbs(self) -> u8 {
+        Self::SSBS_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SSBS` field.
+    pub const fn set_ssbs(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SSBS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SSBS` field set to the given value.
+    pub const fn with_ssbs(mut self, value: u8) -> Self {
+        self.set_ssbs(value);
+        self
+    }
+
+    /// Sets the value of the `SSBS` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ssbs(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ssbs(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SSBS` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ssbs(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SSBS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `MTE` field.
+    pub const fn mte(self) -> u8 {
+        Self::MTE_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `MTE` field.
+    pub const fn set_mte(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::MTE_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `MTE` field set to the given value.
+    pub const fn with_mte(mut self, value: u8) -> Self {
+        self.set_mte(value);
+        self
+    }
+
+    /// Sets the value of the `MTE` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_mte(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_mte(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `MTE` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_mte(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::MTE_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `RAS_frac` field.
+    pub const fn ras_frac(self) -> u8 {
+        Self::RAS_FRAC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `RAS_frac` field.
+    pub const fn set_ras_frac(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::RAS_FRAC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `RAS_frac` field set to the given value.
+    pub const fn with_ras_frac(mut self, value: u8) -> Self {
+        self.set_ras_frac(value);
+        self
+    }
+
+    /// Sets the value of the `RAS_frac` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ras_frac(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ras_frac(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `RAS_frac` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ras_frac(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::RAS_FRAC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `MPAM_frac` field.
+    pub const fn mpam_frac(self) -> u8 {
+        Self::MPAM_FRAC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `MPAM_frac` field.
+    pub const fn set_mpam_frac(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::MPAM_FRAC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `MPAM_frac` field set to the given value.
+    pub const fn with_mpam_frac(mut self, value: u8) -> Self {
+        self.set_mpam_frac(value);
+        self
+    }
+
+    /// Sets the value of the `MPAM_frac` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_mpam_frac(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_mpam_frac(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `MPAM_frac` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_mpam_frac(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::MPAM_FRAC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SME` field.
+    pub const fn sme(self) -> u8 {
+        Self::SME_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SME` field.
+    pub const fn set_sme(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SME_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SME` field set to the given value.
+    pub const fn with_sme(mut self, value: u8) -> Self {
+        self.set_sme(value);
+        self
+    }
+
+    /// Sets the value of the `SME` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_sme(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_sme(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SME` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_sme(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SME_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `RNDR_trap` field.
+    pub const fn rndr_trap(self) -> u8 {
+        Self::RNDR_TRAP_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `RNDR_trap` field.
+    pub const fn set_rndr_trap(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::RNDR_TRAP_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `RNDR_trap` field set to the given value.
+    pub const fn with_rndr_trap(mut self, value: u8) -> Self {
+        self.set_rndr_trap(value);
+        self
+    }
+
+    /// Sets the value of the `RNDR_trap` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_rndr_trap(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_rndr_trap(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `RNDR_trap` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_rndr_trap(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::RNDR_TRAP_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `CSV2_frac` field.
+    pub const fn csv2_frac(self) -> u8 {
+        Self::CSV2_FRAC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `CSV2_frac` field.
+    pub const fn set_csv2_frac(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::CSV2_FRAC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `CSV2_frac` field set to the given value.
+    pub const fn with_csv2_frac(mut self, value: u8) -> Self {
+        self.set_csv2_frac(value);
+        self
+    }
+
+    /// Sets the value of the `CSV2_frac` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_csv2_frac(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_csv2_frac(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `CSV2_frac` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_csv2_frac(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::CSV2_FRAC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `NMI` field.
+    pub const fn nmi(self) -> u8 {
+        Self::NMI_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `NMI` field.
+    pub const fn set_nmi(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::NMI_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `NMI` field set to the given value.
+    pub const fn with_nmi(mut self, value: u8) -> Self {
+        self.set_nmi(value);
+        self
+    }
+
+    /// Sets the value of the `NMI` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_nmi(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_nmi(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `NMI` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_nmi(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::NMI_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `MTE_frac` field.
+    pub const fn mte_frac(self) -> u8 {
+        Self::MTE_FRAC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `MTE_frac` field.
+    pub const fn set_mte_frac(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::MTE_FRAC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `MTE_frac` field set to the given value.
+    pub const fn with_mte_frac(mut self, value: u8) -> Self {
+        self.set_mte_frac(value);
+        self
+    }
+
+    /// Sets the value of the `MTE_frac` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_mte_frac(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_mte_frac(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `MTE_frac` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_mte_frac(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::MTE_FRAC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `GCS` field.
+    pub const fn gcs(self) -> u8 {
+        Self::GCS_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `GCS` field.
+    pub const fn set_gcs(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::GCS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `GCS` field set to the given value.
+    pub const fn with_gcs(mut self, value: u8) -> Self {
+        self.set_gcs(value);
+        self
+    }
+
+    /// Sets the value of the `GCS` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_gcs(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_gcs(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `GCS` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_gcs(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::GCS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `THE` field.
+    pub const fn the(self) -> u8 {
+        Self::THE_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `THE` field.
+    pub const fn set_the(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::THE_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `THE` field set to the given value.
+    pub const fn with_the(mut self, value: u8) -> Self {
+        self.set_the(value);
+        self
+    }
+
+    /// Sets the value of the `THE` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_the(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_the(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `THE` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_the(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::THE_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `MTEX` field.
+    pub const fn mtex(self) -> u8 {
+        Self::MTEX_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `MTEX` field.
+    pub const fn set_mtex(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::MTEX_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `MTEX` field set to the given value.
+    pub const fn with_mtex(mut self, value: u8) -> Self {
+        self.set_mtex(value);
+        self
+    }
+
+    /// Sets the value of the `MTEX` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_mtex(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_mtex(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `MTEX` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_mtex(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::MTEX_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `DF2` field.
+    pub const fn df2(self) -> u8 {
+        Self::DF2_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `DF2` field.
+    pub const fn set_df2(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::DF2_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `DF2` field set to the given value.
+    pub const fn with_df2(mut self, value: u8) -> Self {
+        self.set_df2(value);
+        self
+    }
+
+    /// Sets the value of the `DF2` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_df2(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_df2(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `DF2` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_df2(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::DF2_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PFAR` field.
+    pub const fn pfar(self) -> u8 {
+        Self::PFAR_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `PFAR` field.
+    pub const fn set_pfar(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::PFAR_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PFAR` field set to the given value.
+    pub const fn with_pfar(mut self, value: u8) -> Self {
+        self.set_pfar(value);
+        self
+    }
+
+    /// Sets the value of the `PFAR` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_pfar(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_pfar(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PFAR` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_pfar(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::PFAR_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for IdAa64pfr1El1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("IdAa64pfr1El1");
+        let bits = self.bits();
+        s.field("BT", &Self::BT_FIELD.extract(bits));
+        s.field("SSBS", &Self::SSBS_FIELD.extract(bits));
+        s.field("MTE", &Self::MTE_FIELD.extract(bits));
+        s.field("RAS_frac", &Self::RAS_FRAC_FIELD.extract(bits));
+        s.field("MPAM_frac", &Self::MPAM_FRAC_FIELD.extract(bits));
+        s.field("SME", &Self::SME_FIELD.extract(bits));
+        s.field("RNDR_trap", &Self::RNDR_TRAP_FIELD.extract(bits));
+        s.field("CSV2_frac", &Self::CSV2_FRAC_FIELD.extract(bits));
+        s.field("NMI", &Self::NMI_FIELD.extract(bits));
+        s.field("MTE_frac", &Self::MTE_FRAC_FIELD.extract(bits));
+        s.field("GCS", &Self::GCS_FIELD.extract(bits));
+        s.field("THE", &Self::THE_FIELD.extract(bits));
+        s.field("MTEX", &Self::MTEX_FIELD.extract(bits));
+        s.field("DF2", &Self::DF2_FIELD.extract(bits));
+        s.field("PFAR", &Self::PFAR_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for IdAa64pfr1El1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "IdAa64pfr1El1 {{ BT: {=u64}, SSBS: {=u64}, MTE: {=u64}, RAS_frac: {=u64}, MPAM_frac: {=u64}, SME: {=u64}, RNDR_trap: {=u64}, CSV2_frac: {=u64}, NMI: {=u64}, MTE_frac: {=u64}, GCS: {=u64}, THE: {=u64}, MTEX: {=u64}, DF2: {=u64}, PFAR: {=u64} }}",
+            Self::BT_FIELD.extract(self.bits()),
+            Self::SSBS_FIELD.extract(self.bits()),
+            Self::MTE_FIELD.extract(self.bits()),
+            Self::RAS_FRAC_FIELD.extract(self.bits()),
+            Self::MPAM_FRAC_FIELD.extract(self.bits()),
+            Self::SME_FIELD.extract(self.bits()),
+            Self::RNDR_TRAP_FIELD.extract(self.bits()),
+            Self::CSV2_FRAC_FIELD.extract(self.bits()),
+            Self::NMI_FIELD.extract(self.bits()),
+            Self::MTE_FRAC_FIELD.extract(self.bits()),
+            Self::GCS_FIELD.extract(self.bits()),
+            Self::THE_FIELD.extract(self.bits()),
+            Self::MTEX_FIELD.extract(self.bits()),
+            Self::DF2_FIELD.extract(self.bits()),
+            Self::PFAR_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for IdAa64pfr1El1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for IdAa64pfr1El1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64pfr1El1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("BT"),
+                old: Self::BT_FIELD.extract(self.bits()),
+                new: Self::BT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SSBS"),
+                old: Self::SSBS_FIELD.extract(self.bits()),
+                new: Self::SSBS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("MTE"),
+                old: Self::MTE_FIELD.extract(self.bits()),
+                new: Self::MTE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("RAS_frac"),
+                old: Self::RAS_FRAC_FIELD.extract(self.bits()),
+                new: Self::RAS_FRAC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("MPAM_frac"),
+                old: Self::MPAM_FRAC_FIELD.extract(self.bits()),
+                new: Self::MPAM_FRAC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SME"),
+                old: Self::SME_FIELD.extract(self.bits()),
+                new: Self::SME_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("RNDR_trap"),
+                old: Self::RNDR_TRAP_FIELD.extract(self.bits()),
+                new: Self::RNDR_TRAP_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CSV2_frac"),
+                old: Self::CSV2_FRAC_FIELD.extract(self.bits()),
+                new: Self::CSV2_FRAC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("NMI"),
+                old: Self::NMI_FIELD.extract(self.bits()),
+                new: Self::NMI_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("MTE_frac"),
+                old: Self::MTE_FRAC_FIELD.extract(self.bits()),
+                new: Self::MTE_FRAC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("GCS"),
+                old: Self::GCS_FIELD.extract(self.bits()),
+                new: Self::GCS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("THE"),
+                old: Self::THE_FIELD.extract(self.bits()),
+                new: Self::THE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("MTEX"),
+                old: Self::MTEX_FIELD.extract(self.bits()),
+                new: Self::MTEX_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DF2"),
+                old: Self::DF2_FIELD.extract(self.bits()),
+                new: Self::DF2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PFAR"),
+                old: Self::PFAR_FIELD.extract(self.bits()),
+                new: Self::PFAR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_0000_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_0000_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64pfr1El1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64pfr1El1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `ID_AA64PFR2_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct IdAa64pfr2El1: u64 {
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64pfr2El1 {
+    /// Descriptor of the `MTEPERM` field, for use with [`FieldAccess`].
+    pub const MTEPERM_FIELD: Field<Self, u8, 0, 4> = Field::new("ID_AA64PFR2_EL1", "MTEPERM");
+    /// Descriptor of the `MTESTOREONLY` field, for use with [`FieldAccess`].
+    pub const MTESTOREONLY_FIELD: Field<Self, u8, 4, 4> =
+        Field::new("ID_AA64PFR2_EL1", "MTESTOREONLY");
+    /// Descriptor of the `MTEFAR` field, for use with [`FieldAccess`].
+    pub const MTEFAR_FIELD: Field<Self, u8, 8, 4> = Field::new("ID_AA64PFR2_EL1", "MTEFAR");
+    /// Descriptor of the `GCIE` field, for use with [`FieldAccess`].
+    pub const GCIE_FIELD: Field<Self, u8, 12, 4> = Field::new("ID_AA64PFR2_EL1", "GCIE");
+    /// Descriptor of the `UINJ` field, for use with [`FieldAccess`].
+    pub const UINJ_FIELD: Field<Self, u8, 16, 4> = Field::new("ID_AA64PFR2_EL1", "UINJ");
+    /// Descriptor of the `MTEEIRG` field, for use with [`FieldAccess`].
+    pub const MTEEIRG_FIELD: Field<Self, u8, 20, 4> = Field::new("ID_AA64PFR2_EL1", "MTEEIRG");
+    /// Descriptor of the `FGDT` field, for use with [`FieldAccess`].
+    pub const FGDT_FIELD: Field<Self, u8, 24, 4> = Field::new("ID_AA64PFR2_EL1", "FGDT");
+    /// Descriptor of the `MPAM2` field, for use with [`FieldAccess`].
+    pub const MPAM2_FIELD: Field<Self, u8, 28, 4> = Field::new("ID_AA64PFR2_EL1", "MPAM2");
+    /// Descriptor of the `FPMR` field, for use with [`FieldAccess`].
+    pub const FPMR_FIELD: Field<Self, u8, 32, 4> = Field::new("ID_AA64PFR2_EL1", "FPMR");
+    /// Descriptor of the `VMTE` field, for use with [`FieldAccess`].
+    pub const VMTE_FIELD: Field<Self, u8, 36, 4> = Field::new("ID_AA64PFR2_EL1", "VMTE");
+    /// Descriptor of the `VMTETC` field, for use with [`FieldAccess`].
+    pub const VMTETC_FIELD: Field<Self, u8, 40, 4> = Field::new("ID_AA64PFR2_EL1", "VMTETC");
+    /// Descriptor of the `VMTETCL` field, for use with [`FieldAccess`].
+    pub const VMTETCL_FIELD: Field<Self, u8, 44, 4> = Field::new("ID_AA64PFR2_EL1", "VMTETCL");
+
+    /// Returns the value of the `MTEPERM` field.
+    pub const fn mteperm(self) -> u8 {
+        Self::MTEPERM_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `MTEPERM` field.
+    pub const fn set_mteperm(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::MTEPERM_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `MTEPERM` field set to the given value.
+    pub const fn with_mteperm(mut self, value: u8) -> Self {
+        self.set_mteperm(value);
+        self
+    }
+
+    /// Sets the value of the `MTEPERM` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_mteperm(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_mteperm(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `MTEPERM` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_mteperm(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::MTEPERM_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `MTESTOREONLY` field.
+    pub const fn mtestoreonly(self) -> u8 {
+        Self::MTESTOREONLY_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `MTESTOREONLY` field.
+    pub const fn set_mtestoreonly(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::MTESTOREONLY_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `MTESTOREONLY` field set to the given value.
+    pub const fn with_mtestoreonly(mut self, value: u8) -> Self {
+        self.set_mtestoreonly(value);
+        self
+    }
+
+    /// Sets the value of the `MTESTOREONLY` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_mtestoreonly(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_mtestoreonly(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `MTESTOREONLY` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_mtestoreonly(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::MTESTOREONLY_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `MTEFAR` field.
+    pub const fn mtefar(self) -> u8 {
+        Self::MTEFAR_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `MTEFAR` field.
+    pub const fn set_mtefar(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::MTEFAR_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `MTEFAR` field set to the given value.
+    pub const fn with_mtefar(mut self, value: u8) -> Self {
+        self.set_mtefar(value);
+        self
+    }
+
+    /// Sets the value of the `MTEFAR` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_mtefar(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_mtefar(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `MTEFAR` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_mtefar(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::MTEFAR_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `GCIE` field.
+    pub const fn gcie(self) -> u8 {
+        Self::GCIE_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `GCIE` field.
+    pub const fn set_gcie(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::GCIE_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `GCIE` field set to the given value.
+    pub const fn with_gcie(mut self, value: u8) -> Self {
+        self.set_gcie(value);
+        self
+    }
+
+    /// Sets the value of the `GCIE` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_gcie(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_gcie(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `GCIE` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_gcie(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::GCIE_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `UINJ` field.
+    pub const fn uinj(self) -> u8 {
+        Self::UINJ_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `UINJ` field.
+    pub const fn set_uinj(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::UINJ_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `UINJ` field set to the given value.
+    pub const fn with_uinj(mut self, value: u8) -> Self {
+        self.set_uinj(value);
+        self
+    }
+
+    /// Sets the value of the `UINJ` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_uinj(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_uinj(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `UINJ` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_uinj(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::UINJ_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `MTEEIRG` field.
+    pub const fn mteeirg(self) -> u8 {
+        Self::MTEEIRG_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `MTEEIRG` field.
+    pub const fn set_mteeirg(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::MTEEIRG_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `MTEEIRG` field set to the given value.
+    pub const fn with_mteeirg(mut self, value: u8) -> Self {
+        self.set_mteeirg(value);
+        self
+    }
+
+    /// Sets the value of the `MTEEIRG` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_mteeirg(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_mteeirg(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `MTEEIRG` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_mteeirg(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::MTEEIRG_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `FGDT` field.
+    pub const fn fgdt(self) -> u8 {
+        Self::FGDT_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `FGDT` field.
+    pub const fn set_fgdt(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::FGDT_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `FGDT` field set to the given value.
+    pub const fn with_fgdt(mut self, value: u8) -> Self {
+        self.set_fgdt(value);
+        self
+    }
+
+    /// Sets the value of the `FGDT` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_fgdt(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_fgdt(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `FGDT` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_fgdt(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::FGDT_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `MPAM2` field.
+    pub const fn mpam2(self) -> u8 {
+        Self::MPAM2_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `MPAM2` field.
+    pub const fn set_mpam2(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::MPAM2_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `MPAM2` field set to the given value.
+    pub const fn with_mpam2(mut self, value: u8) -> Self {
+        self.set_mpam2(value);
+        self
+    }
+
+    /// Sets the value of the `MPAM2` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_mpam2(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_mpam2(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `MPAM2` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_mpam2(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::MPAM2_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `FPMR` field.
+    pub const fn fpmr(self) -> u8 {
+        Self::FPMR_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `FPMR` field.
+    pub const fn set_fpmr(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::FPMR_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `FPMR` field set to the given value.
+    pub const fn with_fpmr(mut self, value: u8) -> Self {
+        self.set_fpmr(value);
+        self
+    }
+
+    /// Sets the value of the `FPMR` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_fpmr(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_fpmr(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `FPMR` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_fpmr(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::FPMR_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `VMTE` field.
+    pub const fn vmte(self) -> u8 {
+        Self::VMTE_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `VMTE` field.
+    pub const fn set_vmte(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::VMTE_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `VMTE` field set to the given value.
+    pub const fn with_vmte(mut self, value: u8) -> Self {
+        self.set_vmte(value);
+        self
+    }
+
+    /// Sets the value of the `VMTE` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_vmte(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_vmte(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `VMTE` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_vmte(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::VMTE_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `VMTETC` field.
+    pub const fn vmtetc(self) -> u8 {
+        Self::VMTETC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `VMTETC` field.
+    pub const fn set_vmtetc(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::VMTETC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `VMTETC` field set to the given value.
+    pub const fn with_vmtetc(mut self, value: u8) -> Self {
+        self.set_vmtetc(value);
+        self
+    }
+
+    /// Sets the value of the `VMTETC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_vmtetc(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_vmtetc(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `VMTETC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_vmtetc(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::VMTETC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `VMTETCL` field.
+    pub const fn vmtetcl(self) -> u8 {
+        Self::VMTETCL_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `VMTETCL` field.
+    pub const fn set_vmtetcl(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::VMTETCL_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `VMTETCL` field set to the given value.
+    pub const fn with_vmtetcl(mut self, value: u8) -> Self {
+        self.set_vmtetcl(value);
+        self
+    }
+
+    /// Sets the value of the `VMTETCL` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_vmtetcl(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_vmtetcl(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `VMTETCL` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_vmtetcl(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::VMTETCL_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for IdAa64pfr2El1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("IdAa64pfr2El1");
+        let bits = self.bits();
+        s.field("MTEPERM", &Self::MTEPERM_FIELD.extract(bits));
+        s.field("MTESTOREONLY", &Self::MTESTOREONLY_FIELD.extract(bits));
+        s.field("MTEFAR", &Self::MTEFAR_FIELD.extract(bits));
+        s.field("GCIE", &Self::GCIE_FIELD.extract(bits));
+        s.field("UINJ", &Self::UINJ_FIELD.extract(bits));
+        s.field("MTEEIRG", &Self::MTEEIRG_FIELD.extract(bits));
+        s.field("FGDT", &Self::FGDT_FIELD.extract(bits));
+        s.field("MPAM2", &Self::MPAM2_FIELD.extract(bits));
+        s.field("FPMR", &Self::FPMR_FIELD.extract(bits));
+        s.field("VMTE", &Self::VMTE_FIELD.extract(bits));
+        s.field("VMTETC", &Self::VMTETC_FIELD.extract(bits));
+        s.field("VMTETCL", &Self::VMTETCL_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for IdAa64pfr2El1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "IdAa64pfr2El1 {{ MTEPERM: {=u64}, MTESTOREONLY: {=u64}, MTEFAR: {=u64}, GCIE: {=u64}, UINJ: {=u64}, MTEEIRG: {=u64}, FGDT: {=u64}, MPAM2: {=u64}, FPMR: {=u64}, VMTE: {=u64}, VMTETC: {=u64}, VMTETCL: {=u64} }}",
+            Self::MTEPERM_FIELD.extract(self.bits()),
+            Self::MTESTOREONLY_FIELD.extract(self.bits()),
+            Self::MTEFAR_FIELD.extract(self.bits()),
+            Self::GCIE_FIELD.extract(self.bits()),
+            Self::UINJ_FIELD.extract(self.bits()),
+            Self::MTEEIRG_FIELD.extract(self.bits()),
+            Self::FGDT_FIELD.extract(self.bits()),
+            Self::MPAM2_FIELD.extract(self.bits()),
+            Self::FPMR_FIELD.extract(self.bits()),
+            Self::VMTE_FIELD.extract(self.bits()),
+            Self::VMTETC_FIELD.extract(self.bits()),
+            Self::VMTETCL_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for IdAa64pfr2El1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for IdAa64pfr2El1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64pfr2El1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("MTEPERM"),
+                old: Self::MTEPERM_FIELD.extract(self.bits()),
+                new: Self::MTEPERM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("MTESTOREONLY"),
+                old: Self::MTESTOREONLY_FIELD.extract(self.bits()),
+                new: Self::MTESTOREONLY_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("MTEFAR"),
+                old: Self::MTEFAR_FIELD.extract(self.bits()),
+                new: Self::MTEFAR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("GCIE"),
+                old: Self::GCIE_FIELD.extract(self.bits()),
+                new: Self::GCIE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("UINJ"),
+                old: Self::UINJ_FIELD.extract(self.bits()),
+                new: Self::UINJ_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("MTEEIRG"),
+                old: Self::MTEEIRG_FIELD.extract(self.bits()),
+                new: Self::MTEEIRG_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FGDT"),
+                old: Self::FGDT_FIELD.extract(self.bits()),
+                new: Self::FGDT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("MPAM2"),
+                old: Self::MPAM2_FIELD.extract(self.bits()),
+                new: Self::MPAM2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FPMR"),
+                old: Self::FPMR_FIELD.extract(self.bits()),
+                new: Self::FPMR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VMTE"),
+                old: Self::VMTE_FIELD.extract(self.bits()),
+                new: Self::VMTE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VMTETC"),
+                old: Self::VMTETC_FIELD.extract(self.bits()),
+                new: Self::VMTETC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VMTETCL"),
+                old: Self::VMTETCL_FIELD.extract(self.bits()),
+                new: Self::VMTETCL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64pfr2El1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64pfr2El1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `ID_AA64SMFR0_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct IdAa64smfr0El1: u64 {
+        /// `SMOP4` bit.
+        const SMOP4 = 1 << 0;
+        /// `STMOP` bit.
+        const STMOP = 1 << 16;
+        /// `SFEXPA` bit.
+        const SFEXPA = 1 << 23;
+        /// `AES` bit.
+        const AES = 1 << 24;
+        /// `SBitPerm` bit.
+        const SBITPERM = 1 << 25;
+        /// `SF8DP2` bit.
+        const SF8DP2 = 1 << 28;
+        /// `SF8DP4` bit.
+        const SF8DP4 = 1 << 29;
+        /// `SF8FMA` bit.
+        const SF8FMA = 1 << 30;
+        /// `F32F32` bit.
+        const F32F32 = 1 << 32;
+        /// `BI32I32` bit.
+        const BI32I32 = 1 << 33;
+        /// `B16F32` bit.
+        const B16F32 = 1 << 34;
+        /// `F16F32` bit.
+        const F16F32 = 1 << 35;
+        /// `F8F32` bit.
+        const F8F32 = 1 << 40;
+        /// `F8F16` bit.
+        const F8F16 = 1 << 41;
+        /// `F16F16` bit.
+        const F16F16 = 1 << 42;
+        /// `B16B16` bit.
+        const B16B16 = 1 << 43;
+        /// `F64F64` bit.
+        const F64F64 = 1 << 48;
+        /// `LUTv2` bit.
+        const LUTV2 = 1 << 60;
+        /// `LUT6` bit.
+        const LUT6 = 1 << 61;
+        /// `FA64` bit.
+        const FA64 = 1 << 63;
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64smfr0El1 {
+    /// Descriptor of the `SMOP4` field, for use with [`FieldAccess`].
+    pub const SMOP4_FIELD: Field<Self, bool, 0, 1> = Field::new("ID_AA64SMFR0_EL1", "SMOP4");
+    /// Descriptor of the `STMOP` field, for use with [`FieldAccess`].
+    pub const STMOP_FIELD: Field<Self, bool, 16, 1> = Field::new("ID_AA64SMFR0_EL1", "STMOP");
+    /// Descriptor of the `SFEXPA` field, for use with [`FieldAccess`].
+    pub const SFEXPA_FIELD: Field<Self, bool, 23, 1> = Field::new("ID_AA64SMFR0_EL1", "SFEXPA");
+    /// Descriptor of the `AES` field, for use with [`FieldAccess`].
+    pub const AES_FIELD: Field<Self, bool, 24, 1> = Field::new("ID_AA64SMFR0_EL1", "AES");
+    /// Descriptor of the `SBitPerm` field, for use with [`FieldAccess`].
+    pub const SBITPERM_FIELD: Field<Self, bool, 25, 1> = Field::new("ID_AA64SMFR0_EL1", "SBitPerm");
+    /// Descriptor of the `SF8DP2` field, for use with [`FieldAccess`].
+    pub const SF8DP2_FIELD: Field<Self, bool, 28, 1> = Field::new("ID_AA64SMFR0_EL1", "SF8DP2");
+    /// Descriptor of the `SF8DP4` field, for use with [`FieldAccess`].
+    pub const SF8DP4_FIELD: Field<Self, bool, 29, 1> = Field::new("ID_AA64SMFR0_EL1", "SF8DP4");
+    /// Descriptor of the `SF8FMA` field, for use with [`FieldAccess`].
+    pub const SF8FMA_FIELD: Field<Self, bool, 30, 1> = Field::new("ID_AA64SMFR0_EL1", "SF8FMA");
+    /// Descriptor of the `F32F32` field, for use with [`FieldAccess`].
+    pub const F32F32_FIELD: Field<Self, bool, 32, 1> = Field::new("ID_AA64SMFR0_EL1", "F32F32");
+    /// Descriptor of the `BI32I32` field, for use with [`FieldAccess`].
+    pub const BI32I32_FIELD: Field<Self, bool, 33, 1> = Field::new("ID_AA64SMFR0_EL1", "BI32I32");
+    /// Descriptor of the `B16F32` field, for use with [`FieldAccess`].
+    pub const B16F32_FIELD: Field<Self, bool, 34, 1> = Field::new("ID_AA64SMFR0_EL1", "B16F32");
+    /// Descriptor of the `F16F32` field, for use with [`FieldAccess`].
+    pub const F16F32_FIELD: Field<Self, bool, 35, 1> = Field::new("ID_AA64SMFR0_EL1", "F16F32");
+    /// Descriptor of the `I8I32` field, for use with [`FieldAccess`].
+    pub const I8I32_FIELD: Field<Self, u8, 36, 4> = Field::new("ID_AA64SMFR0_EL1", "I8I32");
+    /// Descriptor of the `F8F32` field, for use with [`FieldAccess`].
+    pub const F8F32_FIELD: Field<Self, bool, 40, 1> = Field::new("ID_AA64SMFR0_EL1", "F8F32");
+    /// Descriptor of the `F8F16` field, for use with [`FieldAccess`].
+    pub const F8F16_FIELD: Field<Self, bool, 41, 1> = Field::new("ID_AA64SMFR0_EL1", "F8F16");
+    /// Descriptor of the `F16F16` field, for use with [`FieldAccess`].
+    pub const F16F16_FIELD: Field<Self, bool, 42, 1> = Field::new("ID_AA64SMFR0_EL1", "F16F16");
+    /// Descriptor of the `B16B16` field, for use with [`FieldAccess`].
+    pub const B16B16_FIELD: Field<Self, bool, 43, 1> = Field::new("ID_AA64SMFR0_EL1", "B16B16");
+    /// Descriptor of the `I16I32` field, for use with [`FieldAccess`].
+    pub const I16I32_FIELD: Field<Self, u8, 44, 4> = Field::new("ID_AA64SMFR0_EL1", "I16I32");
+    /// Descriptor of the `F64F64` field, for use with [`FieldAccess`].
+    pub const F64F64_FIELD: Field<Self, bool, 48, 1> = Field::new("ID_AA64SMFR0_EL1", "F64F64");
+    /// Descriptor of the `I16I64` field, for use with [`FieldAccess`].
+    pub const I16I64_FIELD: Field<Self, u8, 52, 4> = Field::new("ID_AA64SMFR0_EL1", "I16I64");
+    /// Descriptor of the `SMEver` field, for use with [`FieldAccess`].
+    pub const SMEVER_FIELD: Field<Self, u8, 56, 4> = Field::new("ID_AA64SMFR0_EL1", "SMEver");
+    /// Descriptor of the `LUTv2` field, for use with [`FieldAccess`].
+    pub const LUTV2_FIELD: Field<Self, bool, 60, 1> = Field::new("ID_AA64SMFR0_EL1", "LUTv2");
+    /// Descriptor of the `LUT6` field, for use with [`FieldAccess`].
+    pub const LUT6_FIELD: Field<Self, bool, 61, 1> = Field::new("ID_AA64SMFR0_EL1", "LUT6");
+    /// Descriptor of the `FA64` field, for use with [`FieldAccess`].
+    pub const FA64_FIELD: Field<Self, bool, 63, 1> = Field::new("ID_AA64SMFR0_EL1", "FA64");
+
+    /// Returns the value of the `I8I32` field.
+    pub const fn i8i32(self) -> u8 {
+        Self::I8I32_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `I8I32` field.
+    pub const fn set_i8i32(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::I8I32_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `I8I32` field set to the given value.
+    pub const fn with_i8i32(mut self, value: u8) -> Self {
+        self.set_i8i32(value);
+        self
+    }
+
+    /// Sets the value of the `I8I32` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_i8i32(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_i8i32(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `I8I32` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_i8i32(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::I8I32_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `I16I32` field.
+    pub const fn i16i32(self) -> u8 {
+        Self::I16I32_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `I16I32` field.
+    pub const fn set_i16i32(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::I16I32_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `I16I32` field set to the given value.
+    pub const fn with_i16i32(mut self, value: u8) -> Self {
+        self.set_i16i32(value);
+        self
+    }
+
+    /// Sets the value of the `I16I32` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_i16i32(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_i16i32(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `I16I32` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_i16i32(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::I16I32_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `I16I64` field.
+    pub const fn i16i64(self) -> u8 {
+        Self::I16I64_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `I16I64` field.
+    pub const fn set_i16i64(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::I16I64_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `I16I64` field set to the given value.
+    pub const fn with_i16i64(mut self, value: u8) -> Self {
+        self.set_i16i64(value);
+        self
+    }
+
+    /// Sets the value of the `I16I64` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_i16i64(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_i16i64(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `I16I64` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_i16i64(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::I16I64_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SMEver` field.
+    pub const fn smever(self) -> u8 {
+        Self::SMEVER_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SMEver` field.
+    pub const fn set_smever(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SMEVER_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SMEver` field set to the given value.
+    pub const fn with_smever(mut self, value: u8) -> Self {
+        self.set_smever(value);
+        self
+    }
+
+    /// Sets the value of the `SMEver` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_smever(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_smever(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SMEver` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_smever(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SMEVER_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for IdAa64smfr0El1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("IdAa64smfr0El1");
+        let bits = self.bits();
+        s.field("SMOP4", &Self::SMOP4_FIELD.extract(bits));
+        s.field("STMOP", &Self::STMOP_FIELD.extract(bits));
+        s.field("SFEXPA", &Self::SFEXPA_FIELD.extract(bits));
+        s.field("AES", &Self::AES_FIELD.extract(bits));
+        s.field("SBitPerm", &Self::SBITPERM_FIELD.extract(bits));
+        s.field("SF8DP2", &Self::SF8DP2_FIELD.extract(bits));
+        s.field("SF8DP4", &Self::SF8DP4_FIELD.extract(bits));
+        s.field("SF8FMA", &Self::SF8FMA_FIELD.extract(bits));
+        s.field("F32F32", &Self::F32F32_FIELD.extract(bits));
+        s.field("BI32I32", &Self::BI32I32_FIELD.extract(bits));
+        s.field("B16F32", &Self::B16F32_FIELD.extract(bits));
+        s.field("F16F32", &Self::F16F32_FIELD.extract(bits));
+        s.field("I8I32", &Self::I8I32_FIELD.extract(bits));
+        s.field("F8F32", &Self::F8F32_FIELD.extract(bits));
+        s.field("F8F16", &Self::F8F16_FIELD.extract(bits));
+        s.field("F16F16", &Self::F16F16_FIELD.extract(bits));
+        s.field("B16B16", &Self::B16B16_FIELD.extract(bits));
+        s.field("I16I32", &Self::I16I32_FIELD.extract(bits));
+        s.field("F64F64", &Self::F64F64_FIELD.extract(bits));
+        s.field("I16I64", &Self::I16I64_FIELD.extract(bits));
+        s.field("SMEver", &Self::SMEVER_FIELD.extract(bits));
+        s.field("LUTv2", &Self::LUTV2_FIELD.extract(bits));
+        s.field("LUT6", &Self::LUT6_FIELD.extract(bits));
+        s.field("FA64", &Self::FA64_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for IdAa64smfr0El1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "IdAa64smfr0El1 {{ SMOP4: {=u64}, STMOP: {=u64}, SFEXPA: {=u64}, AES: {=u64}, SBitPerm: {=u64}, SF8DP2: {=u64}, SF8DP4: {=u64}, SF8FMA: {=u64}, F32F32: {=u64}, BI32I32: {=u64}, B16F32: {=u64}, F16F32: {=u64}, I8I32: {=u64}, F8F32: {=u64}, F8F16: {=u64}, F16F16: {=u64}, B16B16: {=u64}, I16I32: {=u64}, F64F64: {=u64}, I16I64: {=u64}, SMEver: {=u64}, LUTv2: {=u64}, LUT6: {=u64}, FA64: {=u64} }}",
+            Self::SMOP4_FIELD.extract(self.bits()),
+            Self::STMOP_FIELD.extract(self.bits()),
+            Self::SFEXPA_FIELD.extract(self.bits()),
+            Self::AES_FIELD.extract(self.bits()),
+            Self::SBITPERM_FIELD.extract(self.bits()),
+            Self::SF8DP2_FIELD.extract(self.bits()),
+            Self::SF8DP4_FIELD.extract(self.bits()),
+            Self::SF8FMA_FIELD.extract(self.bits()),
+            Self::F32F32_FIELD.extract(self.bits()),
+            Self::BI32I32_FIELD.extract(self.bits()),
+            Self::B16F32_FIELD.extract(self.bits()),
+            Self::F16F32_FIELD.extract(self.bits()),
+            Self::I8I32_FIELD.extract(self.bits()),
+            Self::F8F32_FIELD.extract(self.bits()),
+            Self::F8F16_FIELD.extract(self.bits()),
+            Self::F16F16_FIELD.extract(self.bits()),
+            Self::B16B16_FIELD.extract(self.bits()),
+            Self::I16I32_FIELD.extract(self.bits()),
+            Self::F64F64_FIELD.extract(self.bits()),
+            Self::I16I64_FIELD.extract(self.bits()),
+            Self::SMEVER_FIELD.extract(self.bits()),
+            Self::LUTV2_FIELD.extract(self.bits()),
+            Self::LUT6_FIELD.extract(self.bits()),
+            Self::FA64_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for IdAa64smfr0El1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for IdAa64smfr0El1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64smfr0El1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("SMOP4"),
+                old: Self::SMOP4_FIELD.extract(self.bits()),
+                new: Self::SMOP4_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("STMOP"),
+                old: Self::STMOP_FIELD.extract(self.bits()),
+                new: Self::STMOP_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SFEXPA"),
+                old: Self::SFEXPA_FIELD.extract(self.bits()),
+                new: Self::SFEXPA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("AES"),
+                old: Self::AES_FIELD.extract(self.bits()),
+                new: Self::AES_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SBitPerm"),
+                old: Self::SBITPERM_FIELD.extract(self.bits()),
+                new: Self::SBITPERM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SF8DP2"),
+                old: Self::SF8DP2_FIELD.extract(self.bits()),
+                new: Self::SF8DP2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SF8DP4"),
+                old: Self::SF8DP4_FIELD.extract(self.bits()),
+                new: Self::SF8DP4_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SF8FMA"),
+                old: Self::SF8FMA_FIELD.extract(self.bits()),
+                new: Self::SF8FMA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("F32F32"),
+                old: Self::F32F32_FIELD.extract(self.bits()),
+                new: Self::F32F32_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("BI32I32"),
+                old: Self::BI32I32_FIELD.extract(self.bits()),
+                new: Self::BI32I32_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("B16F32"),
+                old: Self::B16F32_FIELD.extract(self.bits()),
+                new: Self::B16F32_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("F16F32"),
+                old: Self::F16F32_FIELD.extract(self.bits()),
+                new: Self::F16F32_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("I8I32"),
+                old: Self::I8I32_FIELD.extract(self.bits()),
+                new: Self::I8I32_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("F8F32"),
+                old: Self::F8F32_FIELD.extract(self.bits()),
+                new: Self::F8F32_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("F8F16"),
+                old: Self::F8F16_FIELD.extract(self.bits()),
+                new: Self::F8F16_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("F16F16"),
+                old: Self::F16F16_FIELD.extract(self.bits()),
+                new: Self::F16F16_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("B16B16"),
+                old: Self::B16B16_FIELD.extract(self.bits()),
+                new: Self::B16B16_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("I16I32"),
+                old: Self::I16I32_FIELD.extract(self.bits()),
+                new: Self::I16I32_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("F64F64"),
+                old: Self::F64F64_FIELD.extract(self.bits()),
+                new: Self::F64F64_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("I16I64"),
+                old: Self::I16I64_FIELD.extract(self.bits()),
+                new: Self::I16I64_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SMEver"),
+                old: Self::SMEVER_FIELD.extract(self.bits()),
+                new: Self::SMEVER_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("LUTv2"),
+                old: Self::LUTV2_FIELD.extract(self.bits()),
+                new: Self::LUTV2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("LUT6"),
+                old: Self::LUT6_FIELD.extract(self.bits()),
+                new: Self::LUT6_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FA64"),
+                old: Self::FA64_FIELD.extract(self.bits()),
+                new: Self::FA64_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b100_0000_0000_1110_0000_0000_0000_0000_1000_1100_0111_1110_1111_1111_1111_1110,
+                new: other.bits() & 0b100_0000_0000_1110_0000_0000_0000_0000_1000_1100_0111_1110_1111_1111_1111_1110,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64smfr0El1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl IdAa64smfr0El1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `MIDR_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct MidrEl1: u64 {
+    }
+}
+
+#[cfg(feature = "el1")]
+impl MidrEl1 {
+    /// Descriptor of the `Revision` field, for use with [`FieldAccess`].
+    pub const REVISION_FIELD: Field<Self, u8, 0, 4> = Field::new("MIDR_EL1", "Revision");
+    /// Descriptor of the `PartNum` field, for use with [`FieldAccess`].
+    pub const PARTNUM_FIELD: Field<Self, u16, 4, 12> = Field::new("MIDR_EL1", "PartNum");
+    /// Descriptor of the `Architecture` field, for use with [`FieldAccess`].
+    pub const ARCHITECTURE_FIELD: Field<Self, u8, 16, 4> = Field::new("MIDR_EL1", "Architecture");
+    /// Descriptor of the `Variant` field, for use with [`FieldAccess`].
+    pub const VARIANT_FIELD: Field<Self, u8, 20, 4> = Field::new("MIDR_EL1", "Variant");
+    /// Descriptor of the `Implementer` field, for use with [`FieldAccess`].
+    pub const IMPLEMENTER_FIELD: Field<Self, u8, 24, 8> = Field::new("MIDR_EL1", "Implementer");
+
+    /// Returns the value of the `Revision` field.
+    pub const fn revision(self) -> u8 {
+        Self::REVISION_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `Revision` field.
+    pub const fn set_revision(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::REVISION_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `Revision` field set to the given value.
+    pub const fn with_revision(mut self, value: u8) -> Self {
+        self.set_revision(value);
+        self
+    }
+
+    /// Sets the value of the `Revision` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_revision(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_revision(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `Revision` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_revision(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::REVISION_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PartNum` field.
+    pub const fn partnum(self) -> u16 {
+        Self::PARTNUM_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `PartNum` field.
+    pub const fn set_partnum(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::PARTNUM_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PartNum` field set to the given value.
+    pub const fn with_partnum(mut self, value: u16) -> Self {
+        self.set_partnum(value);
+        self
+    }
+
+    /// Sets the value of the `PartNum` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_partnum(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_partnum(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PartNum` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_partnum(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::PARTNUM_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `Architecture` field.
+    pub const fn architecture(self) -> u8 {
+        Self::ARCHITECTURE_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `Architecture` field.
+    pub const fn set_architecture(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::ARCHITECTURE_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `Architecture` field set to the given value.
+    pub const fn with_architecture(mut self, value: u8) -> Self {
+        self.set_architecture(value);
+        self
+    }
+
+    /// Sets the value of the `Architecture` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_architecture(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_architecture(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `Architecture` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_architecture(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::ARCHITECTURE_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `Variant` field.
+    pub const fn variant(self) -> u8 {
+        Self::VARIANT_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `Variant` field.
+    pub const fn set_variant(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::VARIANT_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `Variant` field set to the given value.
+    pub const fn with_variant(mut self, value: u8) -> Self {
+        self.set_variant(value);
+        self
+    }
+
+    /// Sets the value of the `Variant` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_variant(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_variant(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `Variant` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_variant(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::VARIANT_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `Implementer` field.
+    pub const fn implementer(self) -> u8 {
+        Self::IMPLEMENTER_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `Implementer` field.
+    pub const fn set_implementer(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::IMPLEMENTER_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `Implementer` field set to the given value.
+    pub const fn with_implementer(mut self, value: u8) -> Self {
+        self.set_implementer(value);
+        self
+    }
+
+    /// Sets the value of the `Implementer` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_implementer(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_implementer(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `Implementer` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_implementer(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::IMPLEMENTER_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for MidrEl1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("MidrEl1");
+        let bits = self.bits();
+        s.field("Revision", &Self::REVISION_FIELD.extract(bits));
+        s.field(
+            "PartNum",
+            &format_args!("{:#x}", Self::PARTNUM_FIELD.extract(bits)),
+        );
+        s.field("Architecture", &Self::ARCHITECTURE_FIELD.extract(bits));
+        s.field("Variant", &Self::VARIANT_FIELD.extract(bits));
+        s.field("Implementer", &Self::IMPLEMENTER_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for MidrEl1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "MidrEl1 {{ Revision: {=u64}, PartNum: {=u64:#x}, Architecture: {=u64}, Variant: {=u64}, Implementer: {=u64} }}",
+            Self::REVISION_FIELD.extract(self.bits()),
+            Self::PARTNUM_FIELD.extract(self.bits()),
+            Self::ARCHITECTURE_FIELD.extract(self.bits()),
+            Self::VARIANT_FIELD.extract(self.bits()),
+            Self::IMPLEMENTER_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for MidrEl1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for MidrEl1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl MidrEl1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("Revision"),
+                old: Self::REVISION_FIELD.extract(self.bits()),
+                new: Self::REVISION_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PartNum"),
+                old: Self::PARTNUM_FIELD.extract(self.bits()),
+                new: Self::PARTNUM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("Architecture"),
+                old: Self::ARCHITECTURE_FIELD.extract(self.bits()),
+                new: Self::ARCHITECTURE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("Variant"),
+                old: Self::VARIANT_FIELD.extract(self.bits()),
+                new: Self::VARIANT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("Implementer"),
+                old: Self::IMPLEMENTER_FIELD.extract(self.bits()),
+                new: Self::IMPLEMENTER_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_0000_0000_0000_0000_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_0000_0000_0000_0000_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl MidrEl1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl MidrEl1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `MPIDR_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct MpidrEl1: u64 {
+        /// RES1 bits in the `MPIDR_EL1` register.
+        const RES1 = 0b1000_0000_0000_0000_0000_0000_0000_0000;
+        /// `MT` bit.
+        const MT = 1 << 24;
+        /// `U` bit.
+        const U = 1 << 30;
+    }
+}
+
+#[cfg(feature = "el1")]
+impl MpidrEl1 {
+    /// Descriptor of the `Aff0` field, for use with [`FieldAccess`].
+    pub const AFF0_FIELD: Field<Self, u8, 0, 8> = Field::new("MPIDR_EL1", "Aff0");
+    /// Descriptor of the `Aff1` field, for use with [`FieldAccess`].
+    pub const AFF1_FIELD: Field<Self, u8, 8, 8> = Field::new("MPIDR_EL1", "Aff1");
+    /// Descriptor of the `Aff2` field, for use with [`FieldAccess`].
+    pub const AFF2_FIELD: Field<Self, u8, 16, 8> = Field::new("MPIDR_EL1", "Aff2");
+    /// Descriptor of the `MT` field, for use with [`FieldAccess`].
+    pub const MT_FIELD: Field<Self, bool, 24, 1> = Field::new("MPIDR_EL1", "MT");
+    /// Descriptor of the `U` field, for use with [`FieldAccess`].
+    pub const U_FIELD: Field<Self, bool, 30, 1> = Field::new("MPIDR_EL1", "U");
+    /// Descriptor of the `Aff3` field, for use with [`FieldAccess`].
+    pub const AFF3_FIELD: Field<Self, u8, 32, 8> = Field::new("MPIDR_EL1", "Aff3");
+
+    /// Returns the value of the `Aff0` field.
+    pub const fn aff0(self) -> u8 {
+        Self::AFF0_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `Aff0` field.
+    pub const fn set_aff0(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::AFF0_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `Aff0` field set to the given value.
+    pub const fn with_aff0(mut self, value: u8) -> Self {
+        self.set_aff0(value);
+        self
+    }
+
+    /// Sets the value of the `Aff0` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_aff0(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_aff0(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `Aff0` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_aff0(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::AFF0_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `Aff1` field.
+    pub const fn aff1(self) -> u8 {
+        Self::AFF1_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `Aff1` field.
+    pub const fn set_aff1(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::AFF1_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `Aff1` field set to the given value.
+    pub const fn with_aff1(mut self, value: u8) -> Self {
+        self.set_aff1(value);
+        self
+    }
+
+    /// Sets the value of the `Aff1` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_aff1(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_aff1(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `Aff1` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_aff1(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::AFF1_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `Aff2` field.
+    pub const fn aff2(self) -> u8 {
+        Self::AFF2_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `Aff2` field.
+    pub const fn set_aff2(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::AFF2_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `Aff2` field set to the given value.
+    pub const fn with_aff2(mut self, value: u8) -> Self {
+        self.set_aff2(value);
+        self
+    }
+
+    /// Sets the value of the `Aff2` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_aff2(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_aff2(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `Aff2` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_aff2(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::AFF2_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `Aff3` field.
+    pub const fn aff3(self) -> u8 {
+        Self::AFF3_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `Aff3` field.
+    pub const fn set_aff3(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::AFF3_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `Aff3` field set to the given value.
+    pub const fn with_aff3(mut self, value: u8) -> Self {
+        self.set_aff3(value);
+        self
+    }
+
+    /// Sets the value of the `Aff3` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_aff3(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_aff3(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `Aff3` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_aff3(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::AFF3_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for MpidrEl1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("MpidrEl1");
+        let bits = self.bits();
+        s.field("Aff0", &Self::AFF0_FIELD.extract(bits));
+        s.field("Aff1", &Self::AFF1_FIELD.extract(bits));
+        s.field("Aff2", &Self::AFF2_FIELD.extract(bits));
+        s.field("MT", &Self::MT_FIELD.extract(bits));
+        s.field("U", &Self::U_FIELD.extract(bits));
+        s.field("Aff3", &Self::AFF3_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for MpidrEl1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "MpidrEl1 {{ Aff0: {=u64}, Aff1: {=u64}, Aff2: {=u64}, MT: {=u64}, U: {=u64}, Aff3: {=u64} }}",
+            Self::AFF0_FIELD.extract(self.bits()),
+            Self::AFF1_FIELD.extract(self.bits()),
+            Self::AFF2_FIELD.extract(self.bits()),
+            Self::MT_FIELD.extract(self.bits()),
+            Self::U_FIELD.extract(self.bits()),
+            Self::AFF3_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for MpidrEl1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for MpidrEl1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl MpidrEl1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("Aff0"),
+                old: Self::AFF0_FIELD.extract(self.bits()),
+                new: Self::AFF0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("Aff1"),
+                old: Self::AFF1_FIELD.extract(self.bits()),
+                new: Self::AFF1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("Aff2"),
+                old: Self::AFF2_FIELD.extract(self.bits()),
+                new: Self::AFF2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("MT"),
+                old: Self::MT_FIELD.extract(self.bits()),
+                new: Self::MT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("U"),
+                old: Self::U_FIELD.extract(self.bits()),
+                new: Self::U_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("Aff3"),
+                old: Self::AFF3_FIELD.extract(self.bits()),
+                new: Self::AFF3_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_0000_0000_1011_1110_0000_0000_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_0000_0000_1011_1110_0000_0000_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl MpidrEl1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 =
+        0b1111_1111_1111_1111_1111_1111_0000_0000_0011_1110_0000_0000_0000_0000_0000_0000;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b1000_0000_0000_0000_0000_0000_0000_0000;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl MpidrEl1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `VMPIDR_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct VmpidrEl2: u64 {
+        /// RES1 bits in the `VMPIDR_EL2` register.
+        const RES1 = 0b1000_0000_0000_0000_0000_0000_0000_0000;
+        /// `MT` bit.
+        const MT = 1 << 24;
+        /// `U` bit.
+        const U = 1 << 30;
+    }
+}
+
+#[cfg(feature = "el2")]
+impl VmpidrEl2 {
+    /// Descriptor of the `Aff0` field, for use with [`FieldAccess`].
+    pub const AFF0_FIELD: Field<Self, u8, 0, 8> = Field::new("VMPIDR_EL2", "Aff0");
+    /// Descriptor of the `Aff1` field, for use with [`FieldAccess`].
+    pub const AFF1_FIELD: Field<Self, u8, 8, 8> = Field::new("VMPIDR_EL2", "Aff1");
+    /// Descriptor of the `Aff2` field, for use with [`FieldAccess`].
+    pub const AFF2_FIELD: Field<Self, u8, 16, 8> = Field::new("VMPIDR_EL2", "Aff2");
+    /// Descriptor of the `MT` field, for use with [`FieldAccess`].
+    pub const MT_FIELD: Field<Self, bool, 24, 1> = Field::new("VMPIDR_EL2", "MT");
+    /// Descriptor of the `U` field, for use with [`FieldAccess`].
+    pub const U_FIELD: Field<Self, bool, 30, 1> = Field::new("VMPIDR_EL2", "U");
+    /// Descriptor of the `Aff3` field, for use with [`FieldAccess`].
+    pub const AFF3_FIELD: Field<Self, u8, 32, 8> = Field::new("VMPIDR_EL2", "Aff3");
+
+    /// Returns the value of the `Aff0` field.
+    pub const fn aff0(self) -> u8 {
+        Self::AFF0_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `Aff0` field.
+    pub const fn set_aff0(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::AFF0_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `Aff0` field set to the given value.
+    pub const fn with_aff0(mut self, value: u8) -> Self {
+        self.set_aff0(value);
+        self
+    }
+
+    /// Sets the value of the `Aff0` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_aff0(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_aff0(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `Aff0` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_aff0(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::AFF0_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `Aff1` field.
+    pub const fn aff1(self) -> u8 {
+        Self::AFF1_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `Aff1` field.
+    pub const fn set_aff1(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::AFF1_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `Aff1` field set to the given value.
+    pub const fn with_aff1(mut self, value: u8) -> Self {
+        self.set_aff1(value);
+        self
+    }
+
+    /// Sets the value of the `Aff1` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_aff1(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_aff1(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `Aff1` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_aff1(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::AFF1_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `Aff2` field.
+    pub const fn aff2(self) -> u8 {
+        Self::AFF2_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `Aff2` field.
+    pub const fn set_aff2(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::AFF2_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `Aff2` field set to the given value.
+    pub const fn with_aff2(mut self, value: u8) -> Self {
+        self.set_aff2(value);
+        self
+    }
+
+    /// Sets the value of the `Aff2` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_aff2(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_aff2(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `Aff2` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_aff2(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::AFF2_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `Aff3` field.
+    pub const fn aff3(self) -> u8 {
+        Self::AFF3_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `Aff3` field.
+    pub const fn set_aff3(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::AFF3_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `Aff3` field set to the given value.
+    pub const fn with_aff3(mut self, value: u8) -> Self {
+        self.set_aff3(value);
+        self
+    }
+
+    /// Sets the value of the `Aff3` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_aff3(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_aff3(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `Aff3` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_aff3(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::AFF3_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Display for VmpidrEl2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("VmpidrEl2");
+        let bits = self.bits();
+        s.field("Aff0", &Self::AFF0_FIELD.extract(bits));
+        s.field("Aff1", &Self::AFF1_FIELD.extract(bits));
+        s.field("Aff2", &Self::AFF2_FIELD.extract(bits));
+        s.field("MT", &Self::MT_FIELD.extract(bits));
+        s.field("U", &Self::U_FIELD.extract(bits));
+        s.field("Aff3", &Self::AFF3_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for VmpidrEl2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "VmpidrEl2 {{ Aff0: {=u64}, Aff1: {=u64}, Aff2: {=u64}, MT: {=u64}, U: {=u64}, Aff3: {=u64} }}",
+            Self::AFF0_FIELD.extract(self.bits()),
+            Self::AFF1_FIELD.extract(self.bits()),
+            Self::AFF2_FIELD.extract(self.bits()),
+            Self::MT_FIELD.extract(self.bits()),
+            Self::U_FIELD.extract(self.bits()),
+            Self::AFF3_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for VmpidrEl2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for VmpidrEl2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl VmpidrEl2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("Aff0"),
+                old: Self::AFF0_FIELD.extract(self.bits()),
+                new: Self::AFF0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("Aff1"),
+                old: Self::AFF1_FIELD.extract(self.bits()),
+                new: Self::AFF1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("Aff2"),
+                old: Self::AFF2_FIELD.extract(self.bits()),
+                new: Self::AFF2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("MT"),
+                old: Self::MT_FIELD.extract(self.bits()),
+                new: Self::MT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("U"),
+                old: Self::U_FIELD.extract(self.bits()),
+                new: Self::U_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("Aff3"),
+                old: Self::AFF3_FIELD.extract(self.bits()),
+                new: Self::AFF3_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_0000_0000_1011_1110_0000_0000_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_0000_0000_1011_1110_0000_0000_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl VmpidrEl2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b1000_0000_0000_0000_0000_0000_0000_0000;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl VmpidrEl2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `VPIDR_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct VpidrEl2: u64 {
+    }
+}
+
+#[cfg(feature = "el2")]
+impl VpidrEl2 {
+    /// Descriptor of the `Revision` field, for use with [`FieldAccess`].
+    pub const REVISION_FIELD: Field<Self, u8, 0, 4> = Field::new("VPIDR_EL2", "Revision");
+    /// Descriptor of the `PartNum` field, for use with [`FieldAccess`].
+    pub const PARTNUM_FIELD: Field<Self, u16, 4, 12> = Field::new("VPIDR_EL2", "PartNum");
+    /// Descriptor of the `Architecture` field, for use with [`FieldAccess`].
+    pub const ARCHITECTURE_FIELD: Field<Self, u8, 16, 4> = Field::new("VPIDR_EL2", "Architecture");
+    /// Descriptor of the `Variant` field, for use with [`FieldAccess`].
+    pub const VARIANT_FIELD: Field<Self, u8, 20, 4> = Field::new("VPIDR_EL2", "Variant");
+    /// Descriptor of the `Implementer` field, for use with [`FieldAccess`].
+    pub const IMPLEMENTER_FIELD: Field<Self, u8, 24, 8> = Field::new("VPIDR_EL2", "Implementer");
+
+    /// Returns the value of the `Revision` field.
+    pub const fn revision(self) -> u8 {
+        Self::REVISION_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `Revision` field.
+    pub const fn set_revision(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::REVISION_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `Revision` field set to the given value.
+    pub const fn with_revision(mut self, value: u8) -> Self {
+        self.set_revision(value);
+        self
+    }
+
+    /// Sets the value of the `Revision` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_revision(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_revision(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `Revision` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_revision(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::REVISION_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PartNum` field.
+    pub const fn partnum(self) -> u16 {
+        Self::PARTNUM_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `PartNum` field.
+    pub const fn set_partnum(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::PARTNUM_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PartNum` field set to the given value.
+    pub const fn with_partnum(mut self, value: u16) -> Self {
+        self.set_partnum(value);
+        self
+    }
+
+    /// Sets the value of the `PartNum` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_partnum(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_partnum(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PartNum` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_partnum(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::PARTNUM_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `Architecture` field.
+    pub const fn architecture(self) -> u8 {
+        Self::ARCHITECTURE_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `Architecture` field.
+    pub const fn set_architecture(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::ARCHITECTURE_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `Architecture` field set to the given value.
+    pub const fn with_architecture(mut self, value: u8) -> Self {
+        self.set_architecture(value);
+        self
+    }
+
+    /// Sets the value of the `Architecture` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_architecture(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_architecture(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `Architecture` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_architecture(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::ARCHITECTURE_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `Variant` field.
+    pub const fn variant(self) -> u8 {
+        Self::VARIANT_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `Variant` field.
+    pub const fn set_variant(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::VARIANT_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `Variant` field set to the given value.
+    pub const fn with_variant(mut self, value: u8) -> Self {
+        self.set_variant(value);
+        self
+    }
+
+    /// Sets the value of the `Variant` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_variant(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_variant(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `Variant` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_variant(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::VARIANT_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `Implementer` field.
+    pub const fn implementer(self) -> u8 {
+        Self::IMPLEMENTER_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `Implementer` field.
+    pub const fn set_implementer(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::IMPLEMENTER_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `Implementer` field set to the given value.
+    pub const fn with_implementer(mut self, value: u8) -> Self {
+        self.set_implementer(value);
+        self
+    }
+
+    /// Sets the value of the `Implementer` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_implementer(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_implementer(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `Implementer` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_implementer(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::IMPLEMENTER_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Display for VpidrEl2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("VpidrEl2");
+        let bits = self.bits();
+        s.field("Revision", &Self::REVISION_FIELD.extract(bits));
+        s.field(
+            "PartNum",
+            &format_args!("{:#x}", Self::PARTNUM_FIELD.extract(bits)),
+        );
+        s.field("Architecture", &Self::ARCHITECTURE_FIELD.extract(bits));
+        s.field("Variant", &Self::VARIANT_FIELD.extract(bits));
+        s.field("Implementer", &Self::IMPLEMENTER_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for VpidrEl2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "VpidrEl2 {{ Revision: {=u64}, PartNum: {=u64:#x}, Architecture: {=u64}, Variant: {=u64}, Implementer: {=u64} }}",
+            Self::REVISION_FIELD.extract(self.bits()),
+            Self::PARTNUM_FIELD.extract(self.bits()),
+            Self::ARCHITECTURE_FIELD.extract(self.bits()),
+            Self::VARIANT_FIELD.extract(self.bits()),
+            Self::IMPLEMENTER_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for VpidrEl2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for VpidrEl2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl VpidrEl2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("Revision"),
+                old: Self::REVISION_FIELD.extract(self.bits()),
+                new: Self::REVISION_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PartNum"),
+                old: Self::PARTNUM_FIELD.extract(self.bits()),
+                new: Self::PARTNUM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("Architecture"),
+                old: Self::ARCHITECTURE_FIELD.extract(self.bits()),
+                new: Self::ARCHITECTURE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("Variant"),
+                old: Self::VARIANT_FIELD.extract(self.bits()),
+                new: Self::VARIANT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("Implementer"),
+                old: Self::IMPLEMENTER_FIELD.extract(self.bits()),
+                new: Self::IMPLEMENTER_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_0000_0000_0000_0000_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_0000_0000_0000_0000_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl VpidrEl2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl VpidrEl2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_sysreg!(ccsidr_el1, u64: CcsidrEl1, safe, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_sysreg!(clidr_el1, u64: ClidrEl1, safe, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_write_sysreg!(csselr_el1, u64: CsselrEl1, safe_read, safe_write, fake::SYSREGS);
+#[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+read_sysreg!(ctr_el0, u64: CtrEl0, safe, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_sysreg!(id_aa64dfr0_el1, u64: IdAa64dfr0El1, safe, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_sysreg!(id_aa64dfr1_el1, u64: IdAa64dfr1El1, safe, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_sysreg!(id_aa64isar1_el1, u64: IdAa64isar1El1, safe, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_sysreg!(id_aa64isar2_el1, u64: IdAa64isar2El1, safe, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_sysreg!(id_aa64mmfr0_el1, u64: IdAa64mmfr0El1, safe, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_sysreg!(id_aa64mmfr1_el1, u64: IdAa64mmfr1El1, safe, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_sysreg!(id_aa64mmfr2_el1, u64: IdAa64mmfr2El1, safe, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_sysreg!(id_aa64mmfr3_el1, u64: IdAa64mmfr3El1, safe, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_sysreg!(id_aa64mmfr4_el1, u64: IdAa64mmfr4El1, safe, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_sysreg!(id_aa64pfr0_el1, u64: IdAa64pfr0El1, safe, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_sysreg!(id_aa64pfr1_el1, u64: IdAa64pfr1El1, safe, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_sysreg!(id_aa64pfr2_el1, u64: IdAa64pfr2El1, safe, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_sysreg!(id_aa64smfr0_el1: s3_0_c0_c4_5, u64: IdAa64smfr0El1, safe, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_sysreg!(midr_el1, u64: MidrEl1, safe, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_sysreg!(mpidr_el1, u64: MpidrEl1, safe, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg!(vmpidr_el2, u64: VmpidrEl2, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg!(vpidr_el2, u64: VpidrEl2, safe_read, safe_write, fake::SYSREGS);
@@ -0,0 +1,2209 @@
+// SPDX-FileCopyrightText: Copyright The arm-sysregs Contributors.
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Details of AArch64 system registers for accessing several at once.
+
+// This file is generated, do not edit manually.
+
+/// Expands to the assembly name of the given system register, or converts a value of it to or from
+/// a `u64`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _sysreg_info {
+    (asm_name actlr_el1) => {
+        "actlr_el1"
+    };
+    (from_u64 actlr_el1, $value:expr) => {
+        $value
+    };
+    (to_u64 actlr_el1, $value:expr) => {
+        $value
+    };
+    (asm_name actlr_el2) => {
+        "actlr_el2"
+    };
+    (from_u64 actlr_el2, $value:expr) => {
+        $value
+    };
+    (to_u64 actlr_el2, $value:expr) => {
+        $value
+    };
+    (asm_name afsr0_el1) => {
+        "afsr0_el1"
+    };
+    (from_u64 afsr0_el1, $value:expr) => {
+        $value
+    };
+    (to_u64 afsr0_el1, $value:expr) => {
+        $value
+    };
+    (asm_name afsr0_el2) => {
+        "afsr0_el2"
+    };
+    (from_u64 afsr0_el2, $value:expr) => {
+        $value
+    };
+    (to_u64 afsr0_el2, $value:expr) => {
+        $value
+    };
+    (asm_name afsr1_el1) => {
+        "afsr1_el1"
+    };
+    (from_u64 afsr1_el1, $value:expr) => {
+        $value
+    };
+    (to_u64 afsr1_el1, $value:expr) => {
+        $value
+    };
+    (asm_name afsr1_el2) => {
+        "afsr1_el2"
+    };
+    (from_u64 afsr1_el2, $value:expr) => {
+        $value
+    };
+    (to_u64 afsr1_el2, $value:expr) => {
+        $value
+    };
+    (asm_name amair_el1) => {
+        "amair_el1"
+    };
+    (from_u64 amair_el1, $value:expr) => {
+        $value
+    };
+    (to_u64 amair_el1, $value:expr) => {
+        $value
+    };
+    (asm_name amair_el2) => {
+        "amair_el2"
+    };
+    (from_u64 amair_el2, $value:expr) => {
+        $value
+    };
+    (to_u64 amair_el2, $value:expr) => {
+        $value
+    };
+    (asm_name amcfgr_el0) => {
+        "s3_3_c13_c2_1"
+    };
+    (from_u64 amcfgr_el0, $value:expr) => {
+        $crate::AmcfgrEl0::from_bits_retain($value)
+    };
+    (to_u64 amcfgr_el0, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name amcgcr_el0) => {
+        "s3_3_c13_c2_2"
+    };
+    (from_u64 amcgcr_el0, $value:expr) => {
+        $crate::AmcgcrEl0::from_bits_retain($value)
+    };
+    (to_u64 amcgcr_el0, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name amcntenclr0_el0) => {
+        "s3_3_c13_c2_4"
+    };
+    (from_u64 amcntenclr0_el0, $value:expr) => {
+        $crate::Amcntenclr0El0::from_bits_retain($value)
+    };
+    (to_u64 amcntenclr0_el0, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name amcntenclr1_el0) => {
+        "s3_3_c13_c3_0"
+    };
+    (from_u64 amcntenclr1_el0, $value:expr) => {
+        $crate::Amcntenclr1El0::from_bits_retain($value)
+    };
+    (to_u64 amcntenclr1_el0, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name amcntenset0_el0) => {
+        "s3_3_c13_c2_5"
+    };
+    (from_u64 amcntenset0_el0, $value:expr) => {
+        $crate::Amcntenset0El0::from_bits_retain($value)
+    };
+    (to_u64 amcntenset0_el0, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name amcntenset1_el0) => {
+        "s3_3_c13_c3_1"
+    };
+    (from_u64 amcntenset1_el0, $value:expr) => {
+        $crate::Amcntenset1El0::from_bits_retain($value)
+    };
+    (to_u64 amcntenset1_el0, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name amcr_el0) => {
+        "s3_3_c13_c2_0"
+    };
+    (from_u64 amcr_el0, $value:expr) => {
+        $crate::AmcrEl0::from_bits_retain($value)
+    };
+    (to_u64 amcr_el0, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name amevcntr00_el0) => {
+        "s3_3_c13_c4_0"
+    };
+    (from_u64 amevcntr00_el0, $value:expr) => {
+        $crate::Amevcntr00El0::from_bits_retain($value)
+    };
+    (to_u64 amevcntr00_el0, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name amevcntr01_el0) => {
+        "s3_3_c13_c4_1"
+    };
+    (from_u64 amevcntr01_el0, $value:expr) => {
+        $crate::Amevcntr01El0::from_bits_retain($value)
+    };
+    (to_u64 amevcntr01_el0, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name amevcntr02_el0) => {
+        "s3_3_c13_c4_2"
+    };
+    (from_u64 amevcntr02_el0, $value:expr) => {
+        $crate::Amevcntr02El0::from_bits_retain($value)
+    };
+    (to_u64 amevcntr02_el0, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name amevcntr03_el0) => {
+        "s3_3_c13_c4_3"
+    };
+    (from_u64 amevcntr03_el0, $value:expr) => {
+        $crate::Amevcntr03El0::from_bits_retain($value)
+    };
+    (to_u64 amevcntr03_el0, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name amevcntr10_el0) => {
+        "s3_3_c13_c12_0"
+    };
+    (from_u64 amevcntr10_el0, $value:expr) => {
+        $crate::Amevcntr10El0::from_bits_retain($value)
+    };
+    (to_u64 amevcntr10_el0, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name amevcntr110_el0) => {
+        "s3_3_c13_c13_2"
+    };
+    (from_u64 amevcntr110_el0, $value:expr) => {
+        $crate::Amevcntr110El0::from_bits_retain($value)
+    };
+    (to_u64 amevcntr110_el0, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name amevcntr111_el0) => {
+        "s3_3_c13_c13_3"
+    };
+    (from_u64 amevcntr111_el0, $value:expr) => {
+        $crate::Amevcntr111El0::from_bits_retain($value)
+    };
+    (to_u64 amevcntr111_el0, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name amevcntr112_el0) => {
+        "s3_3_c13_c13_4"
+    };
+    (from_u64 amevcntr112_el0, $value:expr) => {
+        $crate::Amevcntr112El0::from_bits_retain($value)
+    };
+    (to_u64 amevcntr112_el0, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name amevcntr113_el0) => {
+        "s3_3_c13_c13_5"
+    };
+    (from_u64 amevcntr113_el0, $value:expr) => {
+        $crate::Amevcntr113El0::from_bits_retain($value)
+    };
+    (to_u64 amevcntr113_el0, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name amevcntr114_el0) => {
+        "s3_3_c13_c13_6"
+    };
+    (from_u64 amevcntr114_el0, $value:expr) => {
+        $crate::Amevcntr114El0::from_bits_retain($value)
+    };
+    (to_u64 amevcntr114_el0, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name amevcntr115_el0) => {
+        "s3_3_c13_c13_7"
+    };
+    (from_u64 amevcntr115_el0, $value:expr) => {
+        $crate::Amevcntr115El0::from_bits_retain($value)
+    };
+    (to_u64 amevcntr115_el0, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name amevcntr11_el0) => {
+        "s3_3_c13_c12_1"
+    };
+    (from_u64 amevcntr11_el0, $value:expr) => {
+        $crate::Amevcntr11El0::from_bits_retain($value)
+    };
+    (to_u64 amevcntr11_el0, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name amevcntr12_el0) => {
+        "s3_3_c13_c12_2"
+    };
+    (from_u64 amevcntr12_el0, $value:expr) => {
+        $crate::Amevcntr12El0::from_bits_retain($value)
+    };
+    (to_u64 amevcntr12_el0, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name amevcntr13_el0) => {
+        "s3_3_c13_c12_3"
+    };
+    (from_u64 amevcntr13_el0, $value:expr) => {
+        $crate::Amevcntr13El0::from_bits_retain($value)
+    };
+    (to_u64 amevcntr13_el0, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name amevcntr14_el0) => {
+        "s3_3_c13_c12_4"
+    };
+    (from_u64 amevcntr14_el0, $value:expr) => {
+        $crate::Amevcntr14El0::from_bits_retain($value)
+    };
+    (to_u64 amevcntr14_el0, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name amevcntr15_el0) => {
+        "s3_3_c13_c12_5"
+    };
+    (from_u64 amevcntr15_el0, $value:expr) => {
+        $crate::Amevcntr15El0::from_bits_retain($value)
+    };
+    (to_u64 amevcntr15_el0, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name amevcntr16_el0) => {
+        "s3_3_c13_c12_6"
+    };
+    (from_u64 amevcntr16_el0, $value:expr) => {
+        $crate::Amevcntr16El0::from_bits_retain($value)
+    };
+    (to_u64 amevcntr16_el0, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name amevcntr17_el0) => {
+        "s3_3_c13_c12_7"
+    };
+    (from_u64 amevcntr17_el0, $value:expr) => {
+        $crate::Amevcntr17El0::from_bits_retain($value)
+    };
+    (to_u64 amevcntr17_el0, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name amevcntr18_el0) => {
+        "s3_3_c13_c13_0"
+    };
+    (from_u64 amevcntr18_el0, $value:expr) => {
+        $crate::Amevcntr18El0::from_bits_retain($value)
+    };
+    (to_u64 amevcntr18_el0, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name amevcntr19_el0) => {
+        "s3_3_c13_c13_1"
+    };
+    (from_u64 amevcntr19_el0, $value:expr) => {
+        $crate::Amevcntr19El0::from_bits_retain($value)
+    };
+    (to_u64 amevcntr19_el0, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name amevtyper00_el0) => {
+        "s3_3_c13_c6_0"
+    };
+    (from_u64 amevtyper00_el0, $value:expr) => {
+        $crate::Amevtyper00El0::from_bits_retain($value)
+    };
+    (to_u64 amevtyper00_el0, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name amevtyper01_el0) => {
+        "s3_3_c13_c6_1"
+    };
+    (from_u64 amevtyper01_el0, $value:expr) => {
+        $crate::Amevtyper01El0::from_bits_retain($value)
+    };
+    (to_u64 amevtyper01_el0, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name amevtyper02_el0) => {
+        "s3_3_c13_c6_2"
+    };
+    (from_u64 amevtyper02_el0, $value:expr) => {
+        $crate::Amevtyper02El0::from_bits_retain($value)
+    };
+    (to_u64 amevtyper02_el0, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name amevtyper03_el0) => {
+        "s3_3_c13_c6_3"
+    };
+    (from_u64 amevtyper03_el0, $value:expr) => {
+        $crate::Amevtyper03El0::from_bits_retain($value)
+    };
+    (to_u64 amevtyper03_el0, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name amuserenr_el0) => {
+        "s3_3_c13_c2_3"
+    };
+    (from_u64 amuserenr_el0, $value:expr) => {
+        $crate::AmuserenrEl0::from_bits_retain($value)
+    };
+    (to_u64 amuserenr_el0, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name apiakeyhi_el1) => {
+        "s3_0_c2_c1_1"
+    };
+    (from_u64 apiakeyhi_el1, $value:expr) => {
+        $crate::ApiakeyhiEl1::from_bits_retain($value)
+    };
+    (to_u64 apiakeyhi_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name apiakeylo_el1) => {
+        "s3_0_c2_c1_0"
+    };
+    (from_u64 apiakeylo_el1, $value:expr) => {
+        $crate::ApiakeyloEl1::from_bits_retain($value)
+    };
+    (to_u64 apiakeylo_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name brbcr_el2) => {
+        "s2_1_c9_c0_0"
+    };
+    (from_u64 brbcr_el2, $value:expr) => {
+        $crate::BrbcrEl2::from_bits_retain($value)
+    };
+    (to_u64 brbcr_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name ccsidr_el1) => {
+        "ccsidr_el1"
+    };
+    (from_u64 ccsidr_el1, $value:expr) => {
+        $crate::CcsidrEl1::from_bits_retain($value)
+    };
+    (to_u64 ccsidr_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name clidr_el1) => {
+        "clidr_el1"
+    };
+    (from_u64 clidr_el1, $value:expr) => {
+        $crate::ClidrEl1::from_bits_retain($value)
+    };
+    (to_u64 clidr_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name cntfrq_el0) => {
+        "cntfrq_el0"
+    };
+    (from_u64 cntfrq_el0, $value:expr) => {
+        $crate::CntfrqEl0::from_bits_retain($value)
+    };
+    (to_u64 cntfrq_el0, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name cnthctl_el2) => {
+        "cnthctl_el2"
+    };
+    (from_u64 cnthctl_el2, $value:expr) => {
+        $crate::CnthctlEl2::from_bits_retain($value)
+    };
+    (to_u64 cnthctl_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name cnthps_ctl_el2) => {
+        "s3_4_c14_c5_1"
+    };
+    (from_u64 cnthps_ctl_el2, $value:expr) => {
+        $crate::CnthpsCtlEl2::from_bits_retain($value)
+    };
+    (to_u64 cnthps_ctl_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name cnthps_cval_el2) => {
+        "s3_4_c14_c5_2"
+    };
+    (from_u64 cnthps_cval_el2, $value:expr) => {
+        $crate::CnthpsCvalEl2::from_bits_retain($value)
+    };
+    (to_u64 cnthps_cval_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name cnthps_tval_el2) => {
+        "s3_4_c14_c5_0"
+    };
+    (from_u64 cnthps_tval_el2, $value:expr) => {
+        $crate::CnthpsTvalEl2::from_bits_retain($value)
+    };
+    (to_u64 cnthps_tval_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name cnthp_ctl_el2) => {
+        "s3_4_c14_c2_1"
+    };
+    (from_u64 cnthp_ctl_el2, $value:expr) => {
+        $crate::CnthpCtlEl2::from_bits_retain($value)
+    };
+    (to_u64 cnthp_ctl_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name cnthp_cval_el2) => {
+        "s3_4_c14_c2_2"
+    };
+    (from_u64 cnthp_cval_el2, $value:expr) => {
+        $crate::CnthpCvalEl2::from_bits_retain($value)
+    };
+    (to_u64 cnthp_cval_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name cnthp_tval_el2) => {
+        "s3_4_c14_c2_0"
+    };
+    (from_u64 cnthp_tval_el2, $value:expr) => {
+        $crate::CnthpTvalEl2::from_bits_retain($value)
+    };
+    (to_u64 cnthp_tval_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name cnthvs_ctl_el2) => {
+        "s3_4_c14_c4_1"
+    };
+    (from_u64 cnthvs_ctl_el2, $value:expr) => {
+        $crate::CnthvsCtlEl2::from_bits_retain($value)
+    };
+    (to_u64 cnthvs_ctl_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name cnthvs_cval_el2) => {
+        "s3_4_c14_c4_2"
+    };
+    (from_u64 cnthvs_cval_el2, $value:expr) => {
+        $crate::CnthvsCvalEl2::from_bits_retain($value)
+    };
+    (to_u64 cnthvs_cval_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name cnthvs_tval_el2) => {
+        "s3_4_c14_c4_0"
+    };
+    (from_u64 cnthvs_tval_el2, $value:expr) => {
+        $crate::CnthvsTvalEl2::from_bits_retain($value)
+    };
+    (to_u64 cnthvs_tval_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name cnthv_ctl_el2) => {
+        "s3_4_c14_c3_1"
+    };
+    (from_u64 cnthv_ctl_el2, $value:expr) => {
+        $crate::CnthvCtlEl2::from_bits_retain($value)
+    };
+    (to_u64 cnthv_ctl_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name cnthv_cval_el2) => {
+        "s3_4_c14_c3_2"
+    };
+    (from_u64 cnthv_cval_el2, $value:expr) => {
+        $crate::CnthvCvalEl2::from_bits_retain($value)
+    };
+    (to_u64 cnthv_cval_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name cnthv_tval_el2) => {
+        "s3_4_c14_c3_0"
+    };
+    (from_u64 cnthv_tval_el2, $value:expr) => {
+        $crate::CnthvTvalEl2::from_bits_retain($value)
+    };
+    (to_u64 cnthv_tval_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name cntkctl_el1) => {
+        "cntkctl_el1"
+    };
+    (from_u64 cntkctl_el1, $value:expr) => {
+        $crate::CntkctlEl1::from_bits_retain($value)
+    };
+    (to_u64 cntkctl_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name cntpctss_el0) => {
+        "s3_3_c14_c0_5"
+    };
+    (from_u64 cntpctss_el0, $value:expr) => {
+        $crate::CntpctssEl0::from_bits_retain($value)
+    };
+    (to_u64 cntpctss_el0, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name cntpct_el0) => {
+        "cntpct_el0"
+    };
+    (from_u64 cntpct_el0, $value:expr) => {
+        $crate::CntpctEl0::from_bits_retain($value)
+    };
+    (to_u64 cntpct_el0, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name cntpoff_el2) => {
+        "s3_4_c14_c0_6"
+    };
+    (from_u64 cntpoff_el2, $value:expr) => {
+        $crate::CntpoffEl2::from_bits_retain($value)
+    };
+    (to_u64 cntpoff_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name cntps_ctl_el1) => {
+        "cntps_ctl_el1"
+    };
+    (from_u64 cntps_ctl_el1, $value:expr) => {
+        $crate::CntpsCtlEl1::from_bits_retain($value)
+    };
+    (to_u64 cntps_ctl_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name cntps_cval_el1) => {
+        "cntps_cval_el1"
+    };
+    (from_u64 cntps_cval_el1, $value:expr) => {
+        $crate::CntpsCvalEl1::from_bits_retain($value)
+    };
+    (to_u64 cntps_cval_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name cntps_tval_el1) => {
+        "cntps_tval_el1"
+    };
+    (from_u64 cntps_tval_el1, $value:expr) => {
+        $crate::CntpsTvalEl1::from_bits_retain($value)
+    };
+    (to_u64 cntps_tval_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name cntp_ctl_el0) => {
+        "cntp_ctl_el0"
+    };
+    (from_u64 cntp_ctl_el0, $value:expr) => {
+        $crate::CntpCtlEl0::from_bits_retain($value)
+    };
+    (to_u64 cntp_ctl_el0, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name cntp_cval_el0) => {
+        "cntp_cval_el0"
+    };
+    (from_u64 cntp_cval_el0, $value:expr) => {
+        $crate::CntpCvalEl0::from_bits_retain($value)
+    };
+    (to_u64 cntp_cval_el0, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name cntp_tval_el0) => {
+        "cntp_tval_el0"
+    };
+    (from_u64 cntp_tval_el0, $value:expr) => {
+        $crate::CntpTvalEl0::from_bits_retain($value)
+    };
+    (to_u64 cntp_tval_el0, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name cntvctss_el0) => {
+        "s3_3_c14_c0_6"
+    };
+    (from_u64 cntvctss_el0, $value:expr) => {
+        $crate::CntvctssEl0::from_bits_retain($value)
+    };
+    (to_u64 cntvctss_el0, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name cntvct_el0) => {
+        "cntvct_el0"
+    };
+    (from_u64 cntvct_el0, $value:expr) => {
+        $crate::CntvctEl0::from_bits_retain($value)
+    };
+    (to_u64 cntvct_el0, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name cntvoff_el2) => {
+        "cntvoff_el2"
+    };
+    (from_u64 cntvoff_el2, $value:expr) => {
+        $crate::CntvoffEl2::from_bits_retain($value)
+    };
+    (to_u64 cntvoff_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name cntv_ctl_el0) => {
+        "cntv_ctl_el0"
+    };
+    (from_u64 cntv_ctl_el0, $value:expr) => {
+        $crate::CntvCtlEl0::from_bits_retain($value)
+    };
+    (to_u64 cntv_ctl_el0, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name cntv_cval_el0) => {
+        "cntv_cval_el0"
+    };
+    (from_u64 cntv_cval_el0, $value:expr) => {
+        $crate::CntvCvalEl0::from_bits_retain($value)
+    };
+    (to_u64 cntv_cval_el0, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name cntv_tval_el0) => {
+        "cntv_tval_el0"
+    };
+    (from_u64 cntv_tval_el0, $value:expr) => {
+        $crate::CntvTvalEl0::from_bits_retain($value)
+    };
+    (to_u64 cntv_tval_el0, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name contextidr_el1) => {
+        "contextidr_el1"
+    };
+    (from_u64 contextidr_el1, $value:expr) => {
+        $crate::ContextidrEl1::from_bits_retain($value)
+    };
+    (to_u64 contextidr_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name contextidr_el2) => {
+        "s3_4_c13_c0_1"
+    };
+    (from_u64 contextidr_el2, $value:expr) => {
+        $crate::ContextidrEl2::from_bits_retain($value)
+    };
+    (to_u64 contextidr_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name cpacr_el1) => {
+        "cpacr_el1"
+    };
+    (from_u64 cpacr_el1, $value:expr) => {
+        $crate::CpacrEl1::from_bits_retain($value)
+    };
+    (to_u64 cpacr_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name cptr_el2) => {
+        "cptr_el2"
+    };
+    (from_u64 cptr_el2, $value:expr) => {
+        $crate::CptrEl2::from_bits_retain($value)
+    };
+    (to_u64 cptr_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name cptr_el3) => {
+        "cptr_el3"
+    };
+    (from_u64 cptr_el3, $value:expr) => {
+        $crate::CptrEl3::from_bits_retain($value)
+    };
+    (to_u64 cptr_el3, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name csselr_el1) => {
+        "csselr_el1"
+    };
+    (from_u64 csselr_el1, $value:expr) => {
+        $crate::CsselrEl1::from_bits_retain($value)
+    };
+    (to_u64 csselr_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name ctr_el0) => {
+        "ctr_el0"
+    };
+    (from_u64 ctr_el0, $value:expr) => {
+        $crate::CtrEl0::from_bits_retain($value)
+    };
+    (to_u64 ctr_el0, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name currentel) => {
+        "currentel"
+    };
+    (from_u64 currentel, $value:expr) => {
+        $crate::Currentel::from_bits_retain($value)
+    };
+    (to_u64 currentel, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name daif) => {
+        "daif"
+    };
+    (from_u64 daif, $value:expr) => {
+        $crate::Daif::from_bits_retain($value)
+    };
+    (to_u64 daif, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name disr_el1) => {
+        "s3_0_c12_c1_1"
+    };
+    (from_u64 disr_el1, $value:expr) => {
+        $crate::DisrEl1::from_bits_retain($value)
+    };
+    (to_u64 disr_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name dit) => {
+        "s3_3_c4_c2_5"
+    };
+    (from_u64 dit, $value:expr) => {
+        $crate::Dit::from_bits_retain($value)
+    };
+    (to_u64 dit, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name elr_el1) => {
+        "elr_el1"
+    };
+    (from_u64 elr_el1, $value:expr) => {
+        $crate::ElrEl1::from_bits_retain($value)
+    };
+    (to_u64 elr_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name elr_el2) => {
+        "elr_el2"
+    };
+    (from_u64 elr_el2, $value:expr) => {
+        $crate::ElrEl2::from_bits_retain($value)
+    };
+    (to_u64 elr_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name elr_hyp) => {
+        "elr_hyp"
+    };
+    (from_u64 elr_hyp, $value:expr) => {
+        $crate::ElrHyp::from_bits_retain($value as u32)
+    };
+    (to_u64 elr_hyp, $value:expr) => {
+        u64::from($value.bits())
+    };
+    (asm_name esr_el1) => {
+        "esr_el1"
+    };
+    (from_u64 esr_el1, $value:expr) => {
+        $crate::EsrEl1::from_bits_retain($value)
+    };
+    (to_u64 esr_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name esr_el2) => {
+        "esr_el2"
+    };
+    (from_u64 esr_el2, $value:expr) => {
+        $crate::EsrEl2::from_bits_retain($value)
+    };
+    (to_u64 esr_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name esr_el3) => {
+        "esr_el3"
+    };
+    (from_u64 esr_el3, $value:expr) => {
+        $crate::EsrEl3::from_bits_retain($value)
+    };
+    (to_u64 esr_el3, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name far_el1) => {
+        "far_el1"
+    };
+    (from_u64 far_el1, $value:expr) => {
+        $crate::FarEl1::from_bits_retain($value)
+    };
+    (to_u64 far_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name far_el2) => {
+        "far_el2"
+    };
+    (from_u64 far_el2, $value:expr) => {
+        $crate::FarEl2::from_bits_retain($value)
+    };
+    (to_u64 far_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name fpcr) => {
+        "fpcr"
+    };
+    (from_u64 fpcr, $value:expr) => {
+        $crate::Fpcr::from_bits_retain($value)
+    };
+    (to_u64 fpcr, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name fpmr) => {
+        "s3_3_c4_c4_2"
+    };
+    (from_u64 fpmr, $value:expr) => {
+        $crate::Fpmr::from_bits_retain($value)
+    };
+    (to_u64 fpmr, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name fpsr) => {
+        "fpsr"
+    };
+    (from_u64 fpsr, $value:expr) => {
+        $crate::Fpsr::from_bits_retain($value)
+    };
+    (to_u64 fpsr, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name gcr_el1) => {
+        "s3_0_c1_c0_6"
+    };
+    (from_u64 gcr_el1, $value:expr) => {
+        $crate::GcrEl1::from_bits_retain($value)
+    };
+    (to_u64 gcr_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name gcscr_el1) => {
+        "s3_0_c2_c5_0"
+    };
+    (from_u64 gcscr_el1, $value:expr) => {
+        $crate::GcscrEl1::from_bits_retain($value)
+    };
+    (to_u64 gcscr_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name gcscr_el2) => {
+        "s3_4_c2_c5_0"
+    };
+    (from_u64 gcscr_el2, $value:expr) => {
+        $crate::GcscrEl2::from_bits_retain($value)
+    };
+    (to_u64 gcscr_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name gpccr_el3) => {
+        "s3_6_c2_c1_6"
+    };
+    (from_u64 gpccr_el3, $value:expr) => {
+        $crate::GpccrEl3::from_bits_retain($value)
+    };
+    (to_u64 gpccr_el3, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name gptbr_el3) => {
+        "s3_6_c2_c1_4"
+    };
+    (from_u64 gptbr_el3, $value:expr) => {
+        $crate::GptbrEl3::from_bits_retain($value)
+    };
+    (to_u64 gptbr_el3, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name hacr_el2) => {
+        "hacr_el2"
+    };
+    (from_u64 hacr_el2, $value:expr) => {
+        $value
+    };
+    (to_u64 hacr_el2, $value:expr) => {
+        $value
+    };
+    (asm_name hafgrtr_el2) => {
+        "s3_4_c3_c1_6"
+    };
+    (from_u64 hafgrtr_el2, $value:expr) => {
+        $crate::HafgrtrEl2::from_bits_retain($value)
+    };
+    (to_u64 hafgrtr_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name hcrx_el2) => {
+        "s3_4_c1_c2_2"
+    };
+    (from_u64 hcrx_el2, $value:expr) => {
+        $crate::HcrxEl2::from_bits_retain($value)
+    };
+    (to_u64 hcrx_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name hcr_el2) => {
+        "hcr_el2"
+    };
+    (from_u64 hcr_el2, $value:expr) => {
+        $crate::HcrEl2::from_bits_retain($value)
+    };
+    (to_u64 hcr_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name hdfgrtr2_el2) => {
+        "s3_4_c3_c1_0"
+    };
+    (from_u64 hdfgrtr2_el2, $value:expr) => {
+        $crate::Hdfgrtr2El2::from_bits_retain($value)
+    };
+    (to_u64 hdfgrtr2_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name hdfgrtr_el2) => {
+        "s3_4_c3_c1_4"
+    };
+    (from_u64 hdfgrtr_el2, $value:expr) => {
+        $crate::HdfgrtrEl2::from_bits_retain($value)
+    };
+    (to_u64 hdfgrtr_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name hdfgwtr2_el2) => {
+        "s3_4_c3_c1_1"
+    };
+    (from_u64 hdfgwtr2_el2, $value:expr) => {
+        $crate::Hdfgwtr2El2::from_bits_retain($value)
+    };
+    (to_u64 hdfgwtr2_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name hdfgwtr_el2) => {
+        "s3_4_c3_c1_5"
+    };
+    (from_u64 hdfgwtr_el2, $value:expr) => {
+        $crate::HdfgwtrEl2::from_bits_retain($value)
+    };
+    (to_u64 hdfgwtr_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name hfgitr2_el2) => {
+        "s3_4_c3_c1_7"
+    };
+    (from_u64 hfgitr2_el2, $value:expr) => {
+        $crate::Hfgitr2El2::from_bits_retain($value)
+    };
+    (to_u64 hfgitr2_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name hfgitr_el2) => {
+        "s3_4_c1_c1_6"
+    };
+    (from_u64 hfgitr_el2, $value:expr) => {
+        $crate::HfgitrEl2::from_bits_retain($value)
+    };
+    (to_u64 hfgitr_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name hfgrtr2_el2) => {
+        "s3_4_c3_c1_2"
+    };
+    (from_u64 hfgrtr2_el2, $value:expr) => {
+        $crate::Hfgrtr2El2::from_bits_retain($value)
+    };
+    (to_u64 hfgrtr2_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name hfgrtr_el2) => {
+        "s3_4_c1_c1_4"
+    };
+    (from_u64 hfgrtr_el2, $value:expr) => {
+        $crate::HfgrtrEl2::from_bits_retain($value)
+    };
+    (to_u64 hfgrtr_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name hfgwtr2_el2) => {
+        "s3_4_c3_c1_3"
+    };
+    (from_u64 hfgwtr2_el2, $value:expr) => {
+        $crate::Hfgwtr2El2::from_bits_retain($value)
+    };
+    (to_u64 hfgwtr2_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name hfgwtr_el2) => {
+        "s3_4_c1_c1_5"
+    };
+    (from_u64 hfgwtr_el2, $value:expr) => {
+        $crate::HfgwtrEl2::from_bits_retain($value)
+    };
+    (to_u64 hfgwtr_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name hpfar_el2) => {
+        "hpfar_el2"
+    };
+    (from_u64 hpfar_el2, $value:expr) => {
+        $crate::HpfarEl2::from_bits_retain($value)
+    };
+    (to_u64 hpfar_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name hstr_el2) => {
+        "hstr_el2"
+    };
+    (from_u64 hstr_el2, $value:expr) => {
+        $value
+    };
+    (to_u64 hstr_el2, $value:expr) => {
+        $value
+    };
+    (asm_name icc_ap0r0_el1) => {
+        "s3_0_c12_c8_4"
+    };
+    (from_u64 icc_ap0r0_el1, $value:expr) => {
+        $value
+    };
+    (to_u64 icc_ap0r0_el1, $value:expr) => {
+        $value
+    };
+    (asm_name icc_ap0r1_el1) => {
+        "s3_0_c12_c8_5"
+    };
+    (from_u64 icc_ap0r1_el1, $value:expr) => {
+        $value
+    };
+    (to_u64 icc_ap0r1_el1, $value:expr) => {
+        $value
+    };
+    (asm_name icc_ap0r2_el1) => {
+        "s3_0_c12_c8_6"
+    };
+    (from_u64 icc_ap0r2_el1, $value:expr) => {
+        $value
+    };
+    (to_u64 icc_ap0r2_el1, $value:expr) => {
+        $value
+    };
+    (asm_name icc_ap0r3_el1) => {
+        "s3_0_c12_c8_7"
+    };
+    (from_u64 icc_ap0r3_el1, $value:expr) => {
+        $value
+    };
+    (to_u64 icc_ap0r3_el1, $value:expr) => {
+        $value
+    };
+    (asm_name icc_ap1r0_el1) => {
+        "s3_0_c12_c9_0"
+    };
+    (from_u64 icc_ap1r0_el1, $value:expr) => {
+        $crate::IccAp1r0El1::from_bits_retain($value)
+    };
+    (to_u64 icc_ap1r0_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name icc_ap1r1_el1) => {
+        "s3_0_c12_c9_1"
+    };
+    (from_u64 icc_ap1r1_el1, $value:expr) => {
+        $value
+    };
+    (to_u64 icc_ap1r1_el1, $value:expr) => {
+        $value
+    };
+    (asm_name icc_ap1r2_el1) => {
+        "s3_0_c12_c9_2"
+    };
+    (from_u64 icc_ap1r2_el1, $value:expr) => {
+        $value
+    };
+    (to_u64 icc_ap1r2_el1, $value:expr) => {
+        $value
+    };
+    (asm_name icc_ap1r3_el1) => {
+        "s3_0_c12_c9_3"
+    };
+    (from_u64 icc_ap1r3_el1, $value:expr) => {
+        $value
+    };
+    (to_u64 icc_ap1r3_el1, $value:expr) => {
+        $value
+    };
+    (asm_name icc_asgi1r_el1) => {
+        "s3_0_c12_c11_6"
+    };
+    (from_u64 icc_asgi1r_el1, $value:expr) => {
+        $crate::IccAsgi1rEl1::from_bits_retain($value)
+    };
+    (to_u64 icc_asgi1r_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name icc_bpr0_el1) => {
+        "s3_0_c12_c8_3"
+    };
+    (from_u64 icc_bpr0_el1, $value:expr) => {
+        $crate::IccBpr0El1::from_bits_retain($value)
+    };
+    (to_u64 icc_bpr0_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name icc_bpr1_el1) => {
+        "s3_0_c12_c12_3"
+    };
+    (from_u64 icc_bpr1_el1, $value:expr) => {
+        $crate::IccBpr1El1::from_bits_retain($value)
+    };
+    (to_u64 icc_bpr1_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name icc_ctlr_el1) => {
+        "s3_0_c12_c12_4"
+    };
+    (from_u64 icc_ctlr_el1, $value:expr) => {
+        $crate::IccCtlrEl1::from_bits_retain($value)
+    };
+    (to_u64 icc_ctlr_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name icc_ctlr_el3) => {
+        "s3_6_c12_c12_4"
+    };
+    (from_u64 icc_ctlr_el3, $value:expr) => {
+        $crate::IccCtlrEl3::from_bits_retain($value)
+    };
+    (to_u64 icc_ctlr_el3, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name icc_dir_el1) => {
+        "s3_0_c12_c11_1"
+    };
+    (from_u64 icc_dir_el1, $value:expr) => {
+        $crate::IccDirEl1::from_bits_retain($value)
+    };
+    (to_u64 icc_dir_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name icc_eoir0_el1) => {
+        "s3_0_c12_c8_1"
+    };
+    (from_u64 icc_eoir0_el1, $value:expr) => {
+        $crate::IccEoir0El1::from_bits_retain($value)
+    };
+    (to_u64 icc_eoir0_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name icc_eoir1_el1) => {
+        "s3_0_c12_c12_1"
+    };
+    (from_u64 icc_eoir1_el1, $value:expr) => {
+        $crate::IccEoir1El1::from_bits_retain($value)
+    };
+    (to_u64 icc_eoir1_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name icc_hppir0_el1) => {
+        "s3_0_c12_c8_2"
+    };
+    (from_u64 icc_hppir0_el1, $value:expr) => {
+        $crate::IccHppir0El1::from_bits_retain($value)
+    };
+    (to_u64 icc_hppir0_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name icc_hppir1_el1) => {
+        "s3_0_c12_c12_2"
+    };
+    (from_u64 icc_hppir1_el1, $value:expr) => {
+        $crate::IccHppir1El1::from_bits_retain($value)
+    };
+    (to_u64 icc_hppir1_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name icc_iar0_el1) => {
+        "s3_0_c12_c8_0"
+    };
+    (from_u64 icc_iar0_el1, $value:expr) => {
+        $crate::IccIar0El1::from_bits_retain($value)
+    };
+    (to_u64 icc_iar0_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name icc_iar1_el1) => {
+        "s3_0_c12_c12_0"
+    };
+    (from_u64 icc_iar1_el1, $value:expr) => {
+        $crate::IccIar1El1::from_bits_retain($value)
+    };
+    (to_u64 icc_iar1_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name icc_igrpen0_el1) => {
+        "s3_0_c12_c12_6"
+    };
+    (from_u64 icc_igrpen0_el1, $value:expr) => {
+        $crate::IccIgrpen0El1::from_bits_retain($value)
+    };
+    (to_u64 icc_igrpen0_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name icc_igrpen1_el1) => {
+        "s3_0_c12_c12_7"
+    };
+    (from_u64 icc_igrpen1_el1, $value:expr) => {
+        $crate::IccIgrpen1El1::from_bits_retain($value)
+    };
+    (to_u64 icc_igrpen1_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name icc_igrpen1_el3) => {
+        "s3_6_c12_c12_7"
+    };
+    (from_u64 icc_igrpen1_el3, $value:expr) => {
+        $crate::IccIgrpen1El3::from_bits_retain($value)
+    };
+    (to_u64 icc_igrpen1_el3, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name icc_nmiar1_el1) => {
+        "s3_0_c12_c9_5"
+    };
+    (from_u64 icc_nmiar1_el1, $value:expr) => {
+        $crate::IccNmiar1El1::from_bits_retain($value)
+    };
+    (to_u64 icc_nmiar1_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name icc_pmr_el1) => {
+        "s3_0_c4_c6_0"
+    };
+    (from_u64 icc_pmr_el1, $value:expr) => {
+        $crate::IccPmrEl1::from_bits_retain($value)
+    };
+    (to_u64 icc_pmr_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name icc_rpr_el1) => {
+        "s3_0_c12_c11_3"
+    };
+    (from_u64 icc_rpr_el1, $value:expr) => {
+        $crate::IccRprEl1::from_bits_retain($value)
+    };
+    (to_u64 icc_rpr_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name icc_sgi0r_el1) => {
+        "s3_0_c12_c11_7"
+    };
+    (from_u64 icc_sgi0r_el1, $value:expr) => {
+        $crate::IccSgi0rEl1::from_bits_retain($value)
+    };
+    (to_u64 icc_sgi0r_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name icc_sgi1r_el1) => {
+        "s3_0_c12_c11_5"
+    };
+    (from_u64 icc_sgi1r_el1, $value:expr) => {
+        $crate::IccSgi1rEl1::from_bits_retain($value)
+    };
+    (to_u64 icc_sgi1r_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name icc_sre_el1) => {
+        "s3_0_c12_c12_5"
+    };
+    (from_u64 icc_sre_el1, $value:expr) => {
+        $crate::IccSreEl1::from_bits_retain($value)
+    };
+    (to_u64 icc_sre_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name icc_sre_el2) => {
+        "s3_4_c12_c9_5"
+    };
+    (from_u64 icc_sre_el2, $value:expr) => {
+        $crate::IccSreEl2::from_bits_retain($value)
+    };
+    (to_u64 icc_sre_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name icc_sre_el3) => {
+        "s3_6_c12_c12_5"
+    };
+    (from_u64 icc_sre_el3, $value:expr) => {
+        $crate::IccSreEl3::from_bits_retain($value)
+    };
+    (to_u64 icc_sre_el3, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name ich_hcr_el2) => {
+        "s3_4_c12_c11_0"
+    };
+    (from_u64 ich_hcr_el2, $value:expr) => {
+        $crate::IchHcrEl2::from_bits_retain($value)
+    };
+    (to_u64 ich_hcr_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name ich_vmcr_el2) => {
+        "s3_4_c12_c11_7"
+    };
+    (from_u64 ich_vmcr_el2, $value:expr) => {
+        $crate::IchVmcrEl2::from_bits_retain($value)
+    };
+    (to_u64 ich_vmcr_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name id_aa64dfr0_el1) => {
+        "id_aa64dfr0_el1"
+    };
+    (from_u64 id_aa64dfr0_el1, $value:expr) => {
+        $crate::IdAa64dfr0El1::from_bits_retain($value)
+    };
+    (to_u64 id_aa64dfr0_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name id_aa64dfr1_el1) => {
+        "id_aa64dfr1_el1"
+    };
+    (from_u64 id_aa64dfr1_el1, $value:expr) => {
+        $crate::IdAa64dfr1El1::from_bits_retain($value)
+    };
+    (to_u64 id_aa64dfr1_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name id_aa64isar1_el1) => {
+        "id_aa64isar1_el1"
+    };
+    (from_u64 id_aa64isar1_el1, $value:expr) => {
+        $crate::IdAa64isar1El1::from_bits_retain($value)
+    };
+    (to_u64 id_aa64isar1_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name id_aa64isar2_el1) => {
+        "id_aa64isar2_el1"
+    };
+    (from_u64 id_aa64isar2_el1, $value:expr) => {
+        $crate::IdAa64isar2El1::from_bits_retain($value)
+    };
+    (to_u64 id_aa64isar2_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name id_aa64mmfr0_el1) => {
+        "id_aa64mmfr0_el1"
+    };
+    (from_u64 id_aa64mmfr0_el1, $value:expr) => {
+        $crate::IdAa64mmfr0El1::from_bits_retain($value)
+    };
+    (to_u64 id_aa64mmfr0_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name id_aa64mmfr1_el1) => {
+        "id_aa64mmfr1_el1"
+    };
+    (from_u64 id_aa64mmfr1_el1, $value:expr) => {
+        $crate::IdAa64mmfr1El1::from_bits_retain($value)
+    };
+    (to_u64 id_aa64mmfr1_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name id_aa64mmfr2_el1) => {
+        "id_aa64mmfr2_el1"
+    };
+    (from_u64 id_aa64mmfr2_el1, $value:expr) => {
+        $crate::IdAa64mmfr2El1::from_bits_retain($value)
+    };
+    (to_u64 id_aa64mmfr2_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name id_aa64mmfr3_el1) => {
+        "id_aa64mmfr3_el1"
+    };
+    (from_u64 id_aa64mmfr3_el1, $value:expr) => {
+        $crate::IdAa64mmfr3El1::from_bits_retain($value)
+    };
+    (to_u64 id_aa64mmfr3_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name id_aa64mmfr4_el1) => {
+        "id_aa64mmfr4_el1"
+    };
+    (from_u64 id_aa64mmfr4_el1, $value:expr) => {
+        $crate::IdAa64mmfr4El1::from_bits_retain($value)
+    };
+    (to_u64 id_aa64mmfr4_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name id_aa64pfr0_el1) => {
+        "id_aa64pfr0_el1"
+    };
+    (from_u64 id_aa64pfr0_el1, $value:expr) => {
+        $crate::IdAa64pfr0El1::from_bits_retain($value)
+    };
+    (to_u64 id_aa64pfr0_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name id_aa64pfr1_el1) => {
+        "id_aa64pfr1_el1"
+    };
+    (from_u64 id_aa64pfr1_el1, $value:expr) => {
+        $crate::IdAa64pfr1El1::from_bits_retain($value)
+    };
+    (to_u64 id_aa64pfr1_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name id_aa64pfr2_el1) => {
+        "id_aa64pfr2_el1"
+    };
+    (from_u64 id_aa64pfr2_el1, $value:expr) => {
+        $crate::IdAa64pfr2El1::from_bits_retain($value)
+    };
+    (to_u64 id_aa64pfr2_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name id_aa64smfr0_el1) => {
+        "s3_0_c0_c4_5"
+    };
+    (from_u64 id_aa64smfr0_el1, $value:expr) => {
+        $crate::IdAa64smfr0El1::from_bits_retain($value)
+    };
+    (to_u64 id_aa64smfr0_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name isr_el1) => {
+        "isr_el1"
+    };
+    (from_u64 isr_el1, $value:expr) => {
+        $crate::IsrEl1::from_bits_retain($value)
+    };
+    (to_u64 isr_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name mair_el1) => {
+        "mair_el1"
+    };
+    (from_u64 mair_el1, $value:expr) => {
+        $crate::MairEl1::from_bits_retain($value)
+    };
+    (to_u64 mair_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name mair_el2) => {
+        "mair_el2"
+    };
+    (from_u64 mair_el2, $value:expr) => {
+        $crate::MairEl2::from_bits_retain($value)
+    };
+    (to_u64 mair_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name mair_el3) => {
+        "mair_el3"
+    };
+    (from_u64 mair_el3, $value:expr) => {
+        $crate::MairEl3::from_bits_retain($value)
+    };
+    (to_u64 mair_el3, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name mdccint_el1) => {
+        "mdccint_el1"
+    };
+    (from_u64 mdccint_el1, $value:expr) => {
+        $crate::MdccintEl1::from_bits_retain($value)
+    };
+    (to_u64 mdccint_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name mdcr_el2) => {
+        "mdcr_el2"
+    };
+    (from_u64 mdcr_el2, $value:expr) => {
+        $crate::MdcrEl2::from_bits_retain($value)
+    };
+    (to_u64 mdcr_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name mdcr_el3) => {
+        "mdcr_el3"
+    };
+    (from_u64 mdcr_el3, $value:expr) => {
+        $crate::MdcrEl3::from_bits_retain($value)
+    };
+    (to_u64 mdcr_el3, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name mdscr_el1) => {
+        "mdscr_el1"
+    };
+    (from_u64 mdscr_el1, $value:expr) => {
+        $crate::MdscrEl1::from_bits_retain($value)
+    };
+    (to_u64 mdscr_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name midr_el1) => {
+        "midr_el1"
+    };
+    (from_u64 midr_el1, $value:expr) => {
+        $crate::MidrEl1::from_bits_retain($value)
+    };
+    (to_u64 midr_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name mpam2_el2) => {
+        "s3_4_c10_c5_0"
+    };
+    (from_u64 mpam2_el2, $value:expr) => {
+        $crate::Mpam2El2::from_bits_retain($value)
+    };
+    (to_u64 mpam2_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name mpam3_el3) => {
+        "s3_6_c10_c5_0"
+    };
+    (from_u64 mpam3_el3, $value:expr) => {
+        $crate::Mpam3El3::from_bits_retain($value)
+    };
+    (to_u64 mpam3_el3, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name mpamhcr_el2) => {
+        "s3_4_c10_c4_0"
+    };
+    (from_u64 mpamhcr_el2, $value:expr) => {
+        $crate::MpamhcrEl2::from_bits_retain($value)
+    };
+    (to_u64 mpamhcr_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name mpamidr_el1) => {
+        "s3_0_c10_c4_4"
+    };
+    (from_u64 mpamidr_el1, $value:expr) => {
+        $crate::MpamidrEl1::from_bits_retain($value)
+    };
+    (to_u64 mpamidr_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name mpamvpm0_el2) => {
+        "s3_4_c10_c6_0"
+    };
+    (from_u64 mpamvpm0_el2, $value:expr) => {
+        $crate::Mpamvpm0El2::from_bits_retain($value)
+    };
+    (to_u64 mpamvpm0_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name mpamvpm1_el2) => {
+        "s3_4_c10_c6_1"
+    };
+    (from_u64 mpamvpm1_el2, $value:expr) => {
+        $crate::Mpamvpm1El2::from_bits_retain($value)
+    };
+    (to_u64 mpamvpm1_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name mpamvpm2_el2) => {
+        "s3_4_c10_c6_2"
+    };
+    (from_u64 mpamvpm2_el2, $value:expr) => {
+        $crate::Mpamvpm2El2::from_bits_retain($value)
+    };
+    (to_u64 mpamvpm2_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name mpamvpm3_el2) => {
+        "s3_4_c10_c6_3"
+    };
+    (from_u64 mpamvpm3_el2, $value:expr) => {
+        $crate::Mpamvpm3El2::from_bits_retain($value)
+    };
+    (to_u64 mpamvpm3_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name mpamvpm4_el2) => {
+        "s3_4_c10_c6_4"
+    };
+    (from_u64 mpamvpm4_el2, $value:expr) => {
+        $crate::Mpamvpm4El2::from_bits_retain($value)
+    };
+    (to_u64 mpamvpm4_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name mpamvpm5_el2) => {
+        "s3_4_c10_c6_5"
+    };
+    (from_u64 mpamvpm5_el2, $value:expr) => {
+        $crate::Mpamvpm5El2::from_bits_retain($value)
+    };
+    (to_u64 mpamvpm5_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name mpamvpm6_el2) => {
+        "s3_4_c10_c6_6"
+    };
+    (from_u64 mpamvpm6_el2, $value:expr) => {
+        $crate::Mpamvpm6El2::from_bits_retain($value)
+    };
+    (to_u64 mpamvpm6_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name mpamvpm7_el2) => {
+        "s3_4_c10_c6_7"
+    };
+    (from_u64 mpamvpm7_el2, $value:expr) => {
+        $crate::Mpamvpm7El2::from_bits_retain($value)
+    };
+    (to_u64 mpamvpm7_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name mpamvpmv_el2) => {
+        "s3_4_c10_c4_1"
+    };
+    (from_u64 mpamvpmv_el2, $value:expr) => {
+        $crate::MpamvpmvEl2::from_bits_retain($value)
+    };
+    (to_u64 mpamvpmv_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name mpidr_el1) => {
+        "mpidr_el1"
+    };
+    (from_u64 mpidr_el1, $value:expr) => {
+        $crate::MpidrEl1::from_bits_retain($value)
+    };
+    (to_u64 mpidr_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name par_el1) => {
+        "par_el1"
+    };
+    (from_u64 par_el1, $value:expr) => {
+        $crate::ParEl1::from_bits_retain($value)
+    };
+    (to_u64 par_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name pfar_el1) => {
+        "s3_0_c6_c0_5"
+    };
+    (from_u64 pfar_el1, $value:expr) => {
+        $crate::PfarEl1::from_bits_retain($value)
+    };
+    (to_u64 pfar_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name pfar_el2) => {
+        "s3_4_c6_c0_5"
+    };
+    (from_u64 pfar_el2, $value:expr) => {
+        $crate::PfarEl2::from_bits_retain($value)
+    };
+    (to_u64 pfar_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name pire0_el1) => {
+        "s3_0_c10_c2_2"
+    };
+    (from_u64 pire0_el1, $value:expr) => {
+        $crate::Pire0El1::from_bits_retain($value)
+    };
+    (to_u64 pire0_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name pire0_el2) => {
+        "s3_4_c10_c2_2"
+    };
+    (from_u64 pire0_el2, $value:expr) => {
+        $crate::Pire0El2::from_bits_retain($value)
+    };
+    (to_u64 pire0_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name pir_el1) => {
+        "s3_0_c10_c2_3"
+    };
+    (from_u64 pir_el1, $value:expr) => {
+        $crate::PirEl1::from_bits_retain($value)
+    };
+    (to_u64 pir_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name pir_el2) => {
+        "s3_4_c10_c2_3"
+    };
+    (from_u64 pir_el2, $value:expr) => {
+        $crate::PirEl2::from_bits_retain($value)
+    };
+    (to_u64 pir_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name pir_el3) => {
+        "s3_6_c10_c2_3"
+    };
+    (from_u64 pir_el3, $value:expr) => {
+        $crate::PirEl3::from_bits_retain($value)
+    };
+    (to_u64 pir_el3, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name pmcr_el0) => {
+        "s3_3_c9_c12_0"
+    };
+    (from_u64 pmcr_el0, $value:expr) => {
+        $crate::PmcrEl0::from_bits_retain($value)
+    };
+    (to_u64 pmcr_el0, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name por_el0) => {
+        "s3_3_c10_c2_4"
+    };
+    (from_u64 por_el0, $value:expr) => {
+        $crate::PorEl0::from_bits_retain($value)
+    };
+    (to_u64 por_el0, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name por_el1) => {
+        "s3_0_c10_c2_4"
+    };
+    (from_u64 por_el1, $value:expr) => {
+        $crate::PorEl1::from_bits_retain($value)
+    };
+    (to_u64 por_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name por_el2) => {
+        "s3_4_c10_c2_4"
+    };
+    (from_u64 por_el2, $value:expr) => {
+        $crate::PorEl2::from_bits_retain($value)
+    };
+    (to_u64 por_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name por_el3) => {
+        "s3_6_c10_c2_4"
+    };
+    (from_u64 por_el3, $value:expr) => {
+        $crate::PorEl3::from_bits_retain($value)
+    };
+    (to_u64 por_el3, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name rgsr_el1) => {
+        "s3_0_c1_c0_5"
+    };
+    (from_u64 rgsr_el1, $value:expr) => {
+        $crate::RgsrEl1::from_bits_retain($value)
+    };
+    (to_u64 rgsr_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name s2pir_el2) => {
+        "s3_4_c10_c2_5"
+    };
+    (from_u64 s2pir_el2, $value:expr) => {
+        $crate::S2pirEl2::from_bits_retain($value)
+    };
+    (to_u64 s2pir_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name s2por_el1) => {
+        "s3_0_c10_c2_5"
+    };
+    (from_u64 s2por_el1, $value:expr) => {
+        $crate::S2porEl1::from_bits_retain($value)
+    };
+    (to_u64 s2por_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name scr_el3) => {
+        "scr_el3"
+    };
+    (from_u64 scr_el3, $value:expr) => {
+        $crate::ScrEl3::from_bits_retain($value)
+    };
+    (to_u64 scr_el3, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name sctlr2_el1) => {
+        "s3_0_c1_c0_3"
+    };
+    (from_u64 sctlr2_el1, $value:expr) => {
+        $crate::Sctlr2El1::from_bits_retain($value)
+    };
+    (to_u64 sctlr2_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name sctlr2_el2) => {
+        "s3_4_c1_c0_3"
+    };
+    (from_u64 sctlr2_el2, $value:expr) => {
+        $crate::Sctlr2El2::from_bits_retain($value)
+    };
+    (to_u64 sctlr2_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name sctlr2_el3) => {
+        "s3_6_c1_c0_3"
+    };
+    (from_u64 sctlr2_el3, $value:expr) => {
+        $crate::Sctlr2El3::from_bits_retain($value)
+    };
+    (to_u64 sctlr2_el3, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name sctlr_el1) => {
+        "sctlr_el1"
+    };
+    (from_u64 sctlr_el1, $value:expr) => {
+        $crate::SctlrEl1::from_bits_retain($value)
+    };
+    (to_u64 sctlr_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name sctlr_el2) => {
+        "sctlr_el2"
+    };
+    (from_u64 sctlr_el2, $value:expr) => {
+        $crate::SctlrEl2::from_bits_retain($value)
+    };
+    (to_u64 sctlr_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name sctlr_el3) => {
+        "sctlr_el3"
+    };
+    (from_u64 sctlr_el3, $value:expr) => {
+        $crate::SctlrEl3::from_bits_retain($value)
+    };
+    (to_u64 sctlr_el3, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name smcr_el3) => {
+        "s3_6_c1_c2_6"
+    };
+    (from_u64 smcr_el3, $value:expr) => {
+        $crate::SmcrEl3::from_bits_retain($value)
+    };
+    (to_u64 smcr_el3, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name spsr_el1) => {
+        "spsr_el1"
+    };
+    (from_u64 spsr_el1, $value:expr) => {
+        $crate::SpsrEl1::from_bits_retain($value)
+    };
+    (to_u64 spsr_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name spsr_el2) => {
+        "spsr_el2"
+    };
+    (from_u64 spsr_el2, $value:expr) => {
+        $crate::SpsrEl2::from_bits_retain($value)
+    };
+    (to_u64 spsr_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name spsr_el3) => {
+        "spsr_el3"
+    };
+    (from_u64 spsr_el3, $value:expr) => {
+        $crate::SpsrEl3::from_bits_retain($value)
+    };
+    (to_u64 spsr_el3, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name sp_el1) => {
+        "sp_el1"
+    };
+    (from_u64 sp_el1, $value:expr) => {
+        $crate::SpEl1::from_bits_retain($value)
+    };
+    (to_u64 sp_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name sp_el2) => {
+        "sp_el2"
+    };
+    (from_u64 sp_el2, $value:expr) => {
+        $crate::SpEl2::from_bits_retain($value)
+    };
+    (to_u64 sp_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name svcr) => {
+        "s3_3_c4_c2_2"
+    };
+    (from_u64 svcr, $value:expr) => {
+        $crate::Svcr::from_bits_retain($value)
+    };
+    (to_u64 svcr, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name tcr2_el1) => {
+        "s3_0_c2_c0_3"
+    };
+    (from_u64 tcr2_el1, $value:expr) => {
+        $crate::Tcr2El1::from_bits_retain($value)
+    };
+    (to_u64 tcr2_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name tcr2_el2) => {
+        "s3_4_c2_c0_3"
+    };
+    (from_u64 tcr2_el2, $value:expr) => {
+        $crate::Tcr2El2::from_bits_retain($value)
+    };
+    (to_u64 tcr2_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name tcr_el1) => {
+        "tcr_el1"
+    };
+    (from_u64 tcr_el1, $value:expr) => {
+        $crate::TcrEl1::from_bits_retain($value)
+    };
+    (to_u64 tcr_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name tcr_el2) => {
+        "tcr_el2"
+    };
+    (from_u64 tcr_el2, $value:expr) => {
+        $crate::TcrEl2::from_bits_retain($value)
+    };
+    (to_u64 tcr_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name tcr_el3) => {
+        "tcr_el3"
+    };
+    (from_u64 tcr_el3, $value:expr) => {
+        $crate::TcrEl3::from_bits_retain($value)
+    };
+    (to_u64 tcr_el3, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name tfsre0_el1) => {
+        "s3_0_c5_c6_1"
+    };
+    (from_u64 tfsre0_el1, $value:expr) => {
+        $crate::Tfsre0El1::from_bits_retain($value)
+    };
+    (to_u64 tfsre0_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name tfsr_el1) => {
+        "s3_0_c5_c6_0"
+    };
+    (from_u64 tfsr_el1, $value:expr) => {
+        $crate::TfsrEl1::from_bits_retain($value)
+    };
+    (to_u64 tfsr_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name tfsr_el2) => {
+        "s3_4_c5_c6_0"
+    };
+    (from_u64 tfsr_el2, $value:expr) => {
+        $crate::TfsrEl2::from_bits_retain($value)
+    };
+    (to_u64 tfsr_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name tpidrro_el0) => {
+        "tpidrro_el0"
+    };
+    (from_u64 tpidrro_el0, $value:expr) => {
+        $crate::TpidrroEl0::from_bits_retain($value)
+    };
+    (to_u64 tpidrro_el0, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name tpidr_el0) => {
+        "tpidr_el0"
+    };
+    (from_u64 tpidr_el0, $value:expr) => {
+        $crate::TpidrEl0::from_bits_retain($value)
+    };
+    (to_u64 tpidr_el0, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name tpidr_el1) => {
+        "tpidr_el1"
+    };
+    (from_u64 tpidr_el1, $value:expr) => {
+        $crate::TpidrEl1::from_bits_retain($value)
+    };
+    (to_u64 tpidr_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name tpidr_el2) => {
+        "tpidr_el2"
+    };
+    (from_u64 tpidr_el2, $value:expr) => {
+        $crate::TpidrEl2::from_bits_retain($value)
+    };
+    (to_u64 tpidr_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name tpidr_el3) => {
+        "tpidr_el3"
+    };
+    (from_u64 tpidr_el3, $value:expr) => {
+        $crate::TpidrEl3::from_bits_retain($value)
+    };
+    (to_u64 tpidr_el3, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name ttbr0_el1) => {
+        "ttbr0_el1"
+    };
+    (from_u64 ttbr0_el1, $value:expr) => {
+        $crate::Ttbr0El1::from_bits_retain($value)
+    };
+    (to_u64 ttbr0_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name ttbr0_el2) => {
+        "ttbr0_el2"
+    };
+    (from_u64 ttbr0_el2, $value:expr) => {
+        $crate::Ttbr0El2::from_bits_retain($value)
+    };
+    (to_u64 ttbr0_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name ttbr0_el3) => {
+        "ttbr0_el3"
+    };
+    (from_u64 ttbr0_el3, $value:expr) => {
+        $crate::Ttbr0El3::from_bits_retain($value)
+    };
+    (to_u64 ttbr0_el3, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name ttbr1_el1) => {
+        "ttbr1_el1"
+    };
+    (from_u64 ttbr1_el1, $value:expr) => {
+        $crate::Ttbr1El1::from_bits_retain($value)
+    };
+    (to_u64 ttbr1_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name ttbr1_el2) => {
+        "s3_4_c2_c0_1"
+    };
+    (from_u64 ttbr1_el2, $value:expr) => {
+        $crate::Ttbr1El2::from_bits_retain($value)
+    };
+    (to_u64 ttbr1_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name vbar_el1) => {
+        "vbar_el1"
+    };
+    (from_u64 vbar_el1, $value:expr) => {
+        $crate::VbarEl1::from_bits_retain($value)
+    };
+    (to_u64 vbar_el1, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name vbar_el2) => {
+        "vbar_el2"
+    };
+    (from_u64 vbar_el2, $value:expr) => {
+        $crate::VbarEl2::from_bits_retain($value)
+    };
+    (to_u64 vbar_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name vdisr_el2) => {
+        "s3_4_c12_c1_1"
+    };
+    (from_u64 vdisr_el2, $value:expr) => {
+        $crate::VdisrEl2::from_bits_retain($value)
+    };
+    (to_u64 vdisr_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name vmpidr_el2) => {
+        "vmpidr_el2"
+    };
+    (from_u64 vmpidr_el2, $value:expr) => {
+        $crate::VmpidrEl2::from_bits_retain($value)
+    };
+    (to_u64 vmpidr_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name vpidr_el2) => {
+        "vpidr_el2"
+    };
+    (from_u64 vpidr_el2, $value:expr) => {
+        $crate::VpidrEl2::from_bits_retain($value)
+    };
+    (to_u64 vpidr_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name vsesr_el2) => {
+        "s3_4_c5_c2_3"
+    };
+    (from_u64 vsesr_el2, $value:expr) => {
+        $crate::VsesrEl2::from_bits_retain($value)
+    };
+    (to_u64 vsesr_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name vtcr_el2) => {
+        "vtcr_el2"
+    };
+    (from_u64 vtcr_el2, $value:expr) => {
+        $crate::VtcrEl2::from_bits_retain($value)
+    };
+    (to_u64 vtcr_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name vttbr_el2) => {
+        "vttbr_el2"
+    };
+    (from_u64 vttbr_el2, $value:expr) => {
+        $crate::VttbrEl2::from_bits_retain($value)
+    };
+    (to_u64 vttbr_el2, $value:expr) => {
+        $value.bits()
+    };
+    (asm_name zcr_el3) => {
+        "s3_6_c1_c2_0"
+    };
+    (from_u64 zcr_el3, $value:expr) => {
+        $crate::ZcrEl3::from_bits_retain($value)
+    };
+    (to_u64 zcr_el3, $value:expr) => {
+        $value.bits()
+    };
+}
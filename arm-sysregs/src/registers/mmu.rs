@@ -0,0 +1,12538 @@
+// SPDX-FileCopyrightText: Copyright The arm-sysregs Contributors.
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Memory management and address translation system registers.
+
+// This file is generated, do not edit manually.
+
+// Depending on the enabled features, the module might not use all of these imports.
+#![allow(unused_imports)]
+
+use crate::*;
+use bitflags::bitflags;
+use core::fmt::{self, Display, Formatter};
+
+#[cfg(all(feature = "el3", feature = "feat-rme"))]
+bitflags! {
+    /// `GPCCR_EL3` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct GpccrEl3: u64 {
+        /// `PPS3` bit.
+        const PPS3 = 1 << 3;
+        /// `RLPAD` bit.
+        const RLPAD = 1 << 5;
+        /// `NSPAD` bit.
+        const NSPAD = 1 << 6;
+        /// `SPAD` bit.
+        const SPAD = 1 << 7;
+        /// `GPC` bit.
+        const GPC = 1 << 16;
+        /// `GPCP` bit.
+        const GPCP = 1 << 17;
+        /// `TBGPCD` bit.
+        const TBGPCD = 1 << 18;
+        /// `NSO` bit.
+        const NSO = 1 << 19;
+        /// `APPSAA` bit.
+        const APPSAA = 1 << 24;
+        /// `SA` bit.
+        const SA = 1 << 25;
+        /// `NSP` bit.
+        const NSP = 1 << 26;
+        /// `NA6` bit.
+        const NA6 = 1 << 27;
+        /// `NA7` bit.
+        const NA7 = 1 << 28;
+        /// `GPCBW` bit.
+        const GPCBW = 1 << 29;
+    }
+}
+
+#[cfg(all(feature = "el3", feature = "feat-rme"))]
+impl GpccrEl3 {
+    /// Descriptor of the `PPS` field, for use with [`FieldAccess`].
+    pub const PPS_FIELD: Field<Self, u8, 0, 3> = Field::new("GPCCR_EL3", "PPS");
+    /// Descriptor of the `PPS3` field, for use with [`FieldAccess`].
+    pub const PPS3_FIELD: Field<Self, bool, 3, 1> = Field::new("GPCCR_EL3", "PPS3");
+    /// Descriptor of the `RLPAD` field, for use with [`FieldAccess`].
+    pub const RLPAD_FIELD: Field<Self, bool, 5, 1> = Field::new("GPCCR_EL3", "RLPAD");
+    /// Descriptor of the `NSPAD` field, for use with [`FieldAccess`].
+    pub const NSPAD_FIELD: Field<Self, bool, 6, 1> = Field::new("GPCCR_EL3", "NSPAD");
+    /// Descriptor of the `SPAD` field, for use with [`FieldAccess`].
+    pub const SPAD_FIELD: Field<Self, bool, 7, 1> = Field::new("GPCCR_EL3", "SPAD");
+    /// Descriptor of the `IRGN` field, for use with [`FieldAccess`].
+    pub const IRGN_FIELD: Field<Self, crate::manual::Cacheability, 8, 2> =
+        Field::new("GPCCR_EL3", "IRGN");
+    /// Descriptor of the `ORGN` field, for use with [`FieldAccess`].
+    pub const ORGN_FIELD: Field<Self, crate::manual::Cacheability, 10, 2> =
+        Field::new("GPCCR_EL3", "ORGN");
+    /// Descriptor of the `SH` field, for use with [`FieldAccess`].
+    pub const SH_FIELD: Field<Self, crate::manual::Shareability, 12, 2> =
+        Field::new("GPCCR_EL3", "SH");
+    /// Descriptor of the `PGS` field, for use with [`FieldAccess`].
+    pub const PGS_FIELD: Field<Self, u8, 14, 2> = Field::new("GPCCR_EL3", "PGS");
+    /// Descriptor of the `GPC` field, for use with [`FieldAccess`].
+    pub const GPC_FIELD: Field<Self, bool, 16, 1> = Field::new("GPCCR_EL3", "GPC");
+    /// Descriptor of the `GPCP` field, for use with [`FieldAccess`].
+    pub const GPCP_FIELD: Field<Self, bool, 17, 1> = Field::new("GPCCR_EL3", "GPCP");
+    /// Descriptor of the `TBGPCD` field, for use with [`FieldAccess`].
+    pub const TBGPCD_FIELD: Field<Self, bool, 18, 1> = Field::new("GPCCR_EL3", "TBGPCD");
+    /// Descriptor of the `NSO` field, for use with [`FieldAccess`].
+    pub const NSO_FIELD: Field<Self, bool, 19, 1> = Field::new("GPCCR_EL3", "NSO");
+    /// Descriptor of the `L0GPTSZ` field, for use with [`FieldAccess`].
+    pub const L0GPTSZ_FIELD: Field<Self, u8, 20, 4> = Field::new("GPCCR_EL3", "L0GPTSZ");
+    /// Descriptor of the `APPSAA` field, for use with [`FieldAccess`].
+    pub const APPSAA_FIELD: Field<Self, bool, 24, 1> = Field::new("GPCCR_EL3", "APPSAA");
+    /// Descriptor of the `SA` field, for use with [`FieldAccess`].
+    pub const SA_FIELD: Field<Self, bool, 25, 1> = Field::new("GPCCR_EL3", "SA");
+    /// Descriptor of the `NSP` field, for use with [`FieldAccess`].
+    pub const NSP_FIELD: Field<Self, bool, 26, 1> = Field::new("GPCCR_EL3", "NSP");
+    /// Descriptor of the `NA6` field, for use with [`FieldAccess`].
+    pub const NA6_FIELD: Field<Self, bool, 27, 1> = Field::new("GPCCR_EL3", "NA6");
+    /// Descriptor of the `NA7` field, for use with [`FieldAccess`].
+    pub const NA7_FIELD: Field<Self, bool, 28, 1> = Field::new("GPCCR_EL3", "NA7");
+    /// Descriptor of the `GPCBW` field, for use with [`FieldAccess`].
+    pub const GPCBW_FIELD: Field<Self, bool, 29, 1> = Field::new("GPCCR_EL3", "GPCBW");
+
+    /// Returns the value of the `PPS` field.
+    pub const fn pps(self) -> u8 {
+        Self::PPS_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `PPS` field.
+    pub const fn set_pps(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::PPS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PPS` field set to the given value.
+    pub const fn with_pps(mut self, value: u8) -> Self {
+        self.set_pps(value);
+        self
+    }
+
+    /// Sets the value of the `PPS` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_pps(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_pps(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PPS` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_pps(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::PPS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `IRGN` field.
+    pub const fn irgn(self) -> crate::manual::Cacheability {
+        crate::manual::Cacheability::from_raw(Self::IRGN_FIELD.extract(self.bits()) as u8).unwrap()
+    }
+
+    /// Returns the value of the `IRGN` field, or an error if it isn't valid for the field type.
+    pub const fn try_irgn(self) -> Result<crate::manual::Cacheability, InvalidFieldValue> {
+        let raw = Self::IRGN_FIELD.extract(self.bits());
+        match crate::manual::Cacheability::from_raw(raw as u8) {
+            Some(value) => Ok(value),
+            None => Err(Self::IRGN_FIELD.invalid_value(raw)),
+        }
+    }
+
+    /// Sets the value of the `IRGN` field.
+    pub const fn set_irgn(&mut self, value: crate::manual::Cacheability) {
+        *self = Self::from_bits_retain(Self::IRGN_FIELD.insert(self.bits(), value.to_raw() as u64));
+    }
+
+    /// Returns a copy with the `IRGN` field set to the given value.
+    pub const fn with_irgn(mut self, value: crate::manual::Cacheability) -> Self {
+        self.set_irgn(value);
+        self
+    }
+
+    /// Sets the value of the `IRGN` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_irgn(
+        &mut self,
+        value: crate::manual::Cacheability,
+    ) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_irgn(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `IRGN` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_irgn(
+        self,
+        value: crate::manual::Cacheability,
+    ) -> Result<Self, FieldOverflow> {
+        match Self::IRGN_FIELD.try_insert(self.bits(), value.to_raw() as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ORGN` field.
+    pub const fn orgn(self) -> crate::manual::Cacheability {
+        crate::manual::Cacheability::from_raw(Self::ORGN_FIELD.extract(self.bits()) as u8).unwrap()
+    }
+
+    /// Returns the value of the `ORGN` field, or an error if it isn't valid for the field type.
+    pub const fn try_orgn(self) -> Result<crate::manual::Cacheability, InvalidFieldValue> {
+        let raw = Self::ORGN_FIELD.extract(self.bits());
+        match crate::manual::Cacheability::from_raw(raw as u8) {
+            Some(value) => Ok(value),
+            None => Err(Self::ORGN_FIELD.invalid_value(raw)),
+        }
+    }
+
+    /// Sets the value of the `ORGN` field.
+    pub const fn set_orgn(&mut self, value: crate::manual::Cacheability) {
+        *self = Self::from_bits_retain(Self::ORGN_FIELD.insert(self.bits(), value.to_raw() as u64));
+    }
+
+    /// Returns a copy with the `ORGN` field set to the given value.
+    pub const fn with_orgn(mut self, value: crate::manual::Cacheability) -> Self {
+        self.set_orgn(value);
+        self
+    }
+
+    /// Sets the value of the `ORGN` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_orgn(
+        &mut self,
+        value: crate::manual::Cacheability,
+    ) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_orgn(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ORGN` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_orgn(
+        self,
+        value: crate::manual::Cacheability,
+    ) -> Result<Self, FieldOverflow> {
+        match Self::ORGN_FIELD.try_insert(self.bits(), value.to_raw() as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SH` field.
+    pub const fn sh(self) -> crate::manual::Shareability {
+        crate::manual::Shareability::from_raw(Self::SH_FIELD.extract(self.bits()) as u8).unwrap()
+    }
+
+    /// Returns the value of the `SH` field, or an error if it isn't valid for the field type.
+    pub const fn try_sh(self) -> Result<crate::manual::Shareability, InvalidFieldValue> {
+        let raw = Self::SH_FIELD.extract(self.bits());
+        match crate::manual::Shareability::from_raw(raw as u8) {
+            Some(value) => Ok(value),
+            None => Err(Self::SH_FIELD.invalid_value(raw)),
+        }
+    }
+
+    /// Sets the value of the `SH` field.
+    pub const fn set_sh(&mut self, value: crate::manual::Shareability) {
+        *self = Self::from_bits_retain(Self::SH_FIELD.insert(self.bits(), value.to_raw() as u64));
+    }
+
+    /// Returns a copy with the `SH` field set to the given value.
+    pub const fn with_sh(mut self, value: crate::manual::Shareability) -> Self {
+        self.set_sh(value);
+        self
+    }
+
+    /// Sets the value of the `SH` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_sh(
+        &mut self,
+        value: crate::manual::Shareability,
+    ) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_sh(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SH` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_sh(
+        self,
+        value: crate::manual::Shareability,
+    ) -> Result<Self, FieldOverflow> {
+        match Self::SH_FIELD.try_insert(self.bits(), value.to_raw() as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PGS` field.
+    pub const fn pgs(self) -> u8 {
+        Self::PGS_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `PGS` field.
+    pub const fn set_pgs(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::PGS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PGS` field set to the given value.
+    pub const fn with_pgs(mut self, value: u8) -> Self {
+        self.set_pgs(value);
+        self
+    }
+
+    /// Sets the value of the `PGS` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_pgs(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_pgs(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PGS` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_pgs(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::PGS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `L0GPTSZ` field.
+    pub const fn l0gptsz(self) -> u8 {
+        Self::L0GPTSZ_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `L0GPTSZ` field.
+    pub const fn set_l0gptsz(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::L0GPTSZ_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `L0GPTSZ` field set to the given value.
+    pub const fn with_l0gptsz(mut self, value: u8) -> Self {
+        self.set_l0gptsz(value);
+        self
+    }
+
+    /// Sets the value of the `L0GPTSZ` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_l0gptsz(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_l0gptsz(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `L0GPTSZ` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_l0gptsz(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::L0GPTSZ_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(all(feature = "el3", feature = "feat-rme"))]
+impl Display for GpccrEl3 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("GpccrEl3");
+        let bits = self.bits();
+        s.field("PPS", &Self::PPS_FIELD.extract(bits));
+        s.field("PPS3", &Self::PPS3_FIELD.extract(bits));
+        s.field("RLPAD", &Self::RLPAD_FIELD.extract(bits));
+        s.field("NSPAD", &Self::NSPAD_FIELD.extract(bits));
+        s.field("SPAD", &Self::SPAD_FIELD.extract(bits));
+        let raw = Self::IRGN_FIELD.extract(bits);
+        if let Some(value) = crate::manual::Cacheability::from_raw(raw as u8) {
+            s.field("IRGN", &value);
+        } else {
+            s.field("IRGN", &format_args!("{}", raw));
+        }
+        let raw = Self::ORGN_FIELD.extract(bits);
+        if let Some(value) = crate::manual::Cacheability::from_raw(raw as u8) {
+            s.field("ORGN", &value);
+        } else {
+            s.field("ORGN", &format_args!("{}", raw));
+        }
+        let raw = Self::SH_FIELD.extract(bits);
+        if let Some(value) = crate::manual::Shareability::from_raw(raw as u8) {
+            s.field("SH", &value);
+        } else {
+            s.field("SH", &format_args!("{}", raw));
+        }
+        s.field("PGS", &Self::PGS_FIELD.extract(bits));
+        s.field("GPC", &Self::GPC_FIELD.extract(bits));
+        s.field("GPCP", &Self::GPCP_FIELD.extract(bits));
+        s.field("TBGPCD", &Self::TBGPCD_FIELD.extract(bits));
+        s.field("NSO", &Self::NSO_FIELD.extract(bits));
+        s.field("L0GPTSZ", &Self::L0GPTSZ_FIELD.extract(bits));
+        s.field("APPSAA", &Self::APPSAA_FIELD.extract(bits));
+        s.field("SA", &Self::SA_FIELD.extract(bits));
+        s.field("NSP", &Self::NSP_FIELD.extract(bits));
+        s.field("NA6", &Self::NA6_FIELD.extract(bits));
+        s.field("NA7", &Self::NA7_FIELD.extract(bits));
+        s.field("GPCBW", &Self::GPCBW_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(all(feature = "el3", feature = "feat-rme"))]
+impl defmt::Format for GpccrEl3 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "GpccrEl3 {{ PPS: {=u64}, PPS3: {=u64}, RLPAD: {=u64}, NSPAD: {=u64}, SPAD: {=u64}, IRGN: {}, ORGN: {}, SH: {}, PGS: {=u64}, GPC: {=u64}, GPCP: {=u64}, TBGPCD: {=u64}, NSO: {=u64}, L0GPTSZ: {=u64}, APPSAA: {=u64}, SA: {=u64}, NSP: {=u64}, NA6: {=u64}, NA7: {=u64}, GPCBW: {=u64} }}",
+            Self::PPS_FIELD.extract(self.bits()),
+            Self::PPS3_FIELD.extract(self.bits()),
+            Self::RLPAD_FIELD.extract(self.bits()),
+            Self::NSPAD_FIELD.extract(self.bits()),
+            Self::SPAD_FIELD.extract(self.bits()),
+            crate::manual::DefmtField::new(
+                Self::IRGN_FIELD.extract(self.bits()),
+                crate::manual::Cacheability::from_raw(
+                    (Self::IRGN_FIELD.extract(self.bits())) as u8
+                )
+            ),
+            crate::manual::DefmtField::new(
+                Self::ORGN_FIELD.extract(self.bits()),
+                crate::manual::Cacheability::from_raw(
+                    (Self::ORGN_FIELD.extract(self.bits())) as u8
+                )
+            ),
+            crate::manual::DefmtField::new(
+                Self::SH_FIELD.extract(self.bits()),
+                crate::manual::Shareability::from_raw((Self::SH_FIELD.extract(self.bits())) as u8)
+            ),
+            Self::PGS_FIELD.extract(self.bits()),
+            Self::GPC_FIELD.extract(self.bits()),
+            Self::GPCP_FIELD.extract(self.bits()),
+            Self::TBGPCD_FIELD.extract(self.bits()),
+            Self::NSO_FIELD.extract(self.bits()),
+            Self::L0GPTSZ_FIELD.extract(self.bits()),
+            Self::APPSAA_FIELD.extract(self.bits()),
+            Self::SA_FIELD.extract(self.bits()),
+            Self::NSP_FIELD.extract(self.bits()),
+            Self::NA6_FIELD.extract(self.bits()),
+            Self::NA7_FIELD.extract(self.bits()),
+            Self::GPCBW_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el3", feature = "feat-rme"))]
+impl serde::Serialize for GpccrEl3 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el3", feature = "feat-rme"))]
+impl<'de> serde::Deserialize<'de> for GpccrEl3 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(all(feature = "el3", feature = "feat-rme"))]
+impl GpccrEl3 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("PPS"),
+                old: Self::PPS_FIELD.extract(self.bits()),
+                new: Self::PPS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PPS3"),
+                old: Self::PPS3_FIELD.extract(self.bits()),
+                new: Self::PPS3_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("RLPAD"),
+                old: Self::RLPAD_FIELD.extract(self.bits()),
+                new: Self::RLPAD_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("NSPAD"),
+                old: Self::NSPAD_FIELD.extract(self.bits()),
+                new: Self::NSPAD_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SPAD"),
+                old: Self::SPAD_FIELD.extract(self.bits()),
+                new: Self::SPAD_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IRGN"),
+                old: Self::IRGN_FIELD.extract(self.bits()),
+                new: Self::IRGN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ORGN"),
+                old: Self::ORGN_FIELD.extract(self.bits()),
+                new: Self::ORGN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SH"),
+                old: Self::SH_FIELD.extract(self.bits()),
+                new: Self::SH_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PGS"),
+                old: Self::PGS_FIELD.extract(self.bits()),
+                new: Self::PGS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("GPC"),
+                old: Self::GPC_FIELD.extract(self.bits()),
+                new: Self::GPC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("GPCP"),
+                old: Self::GPCP_FIELD.extract(self.bits()),
+                new: Self::GPCP_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TBGPCD"),
+                old: Self::TBGPCD_FIELD.extract(self.bits()),
+                new: Self::TBGPCD_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("NSO"),
+                old: Self::NSO_FIELD.extract(self.bits()),
+                new: Self::NSO_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("L0GPTSZ"),
+                old: Self::L0GPTSZ_FIELD.extract(self.bits()),
+                new: Self::L0GPTSZ_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("APPSAA"),
+                old: Self::APPSAA_FIELD.extract(self.bits()),
+                new: Self::APPSAA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SA"),
+                old: Self::SA_FIELD.extract(self.bits()),
+                new: Self::SA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("NSP"),
+                old: Self::NSP_FIELD.extract(self.bits()),
+                new: Self::NSP_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("NA6"),
+                old: Self::NA6_FIELD.extract(self.bits()),
+                new: Self::NA6_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("NA7"),
+                old: Self::NA7_FIELD.extract(self.bits()),
+                new: Self::NA7_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("GPCBW"),
+                old: Self::GPCBW_FIELD.extract(self.bits()),
+                new: Self::GPCBW_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1100_0000_0000_0000_0000_0000_0001_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1100_0000_0000_0000_0000_0000_0001_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(all(feature = "el3", feature = "feat-rme"))]
+impl GpccrEl3 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(all(feature = "el3", feature = "feat-rme"))]
+impl GpccrEl3 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                Self::PPS_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Rme),
+                UnimplementedField {
+                    register: "GPCCR_EL3",
+                    field: "PPS",
+                    requires: "FEAT_RME",
+                },
+            ),
+            (
+                Self::PPS3_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Rme),
+                UnimplementedField {
+                    register: "GPCCR_EL3",
+                    field: "PPS3",
+                    requires: "FEAT_RME",
+                },
+            ),
+            (
+                Self::RLPAD_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Rme),
+                UnimplementedField {
+                    register: "GPCCR_EL3",
+                    field: "RLPAD",
+                    requires: "FEAT_RME",
+                },
+            ),
+            (
+                Self::NSPAD_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Rme),
+                UnimplementedField {
+                    register: "GPCCR_EL3",
+                    field: "NSPAD",
+                    requires: "FEAT_RME",
+                },
+            ),
+            (
+                Self::SPAD_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Rme),
+                UnimplementedField {
+                    register: "GPCCR_EL3",
+                    field: "SPAD",
+                    requires: "FEAT_RME",
+                },
+            ),
+            (
+                Self::IRGN_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Rme),
+                UnimplementedField {
+                    register: "GPCCR_EL3",
+                    field: "IRGN",
+                    requires: "FEAT_RME",
+                },
+            ),
+            (
+                Self::ORGN_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Rme),
+                UnimplementedField {
+                    register: "GPCCR_EL3",
+                    field: "ORGN",
+                    requires: "FEAT_RME",
+                },
+            ),
+            (
+                Self::SH_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Rme),
+                UnimplementedField {
+                    register: "GPCCR_EL3",
+                    field: "SH",
+                    requires: "FEAT_RME",
+                },
+            ),
+            (
+                Self::PGS_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Rme),
+                UnimplementedField {
+                    register: "GPCCR_EL3",
+                    field: "PGS",
+                    requires: "FEAT_RME",
+                },
+            ),
+            (
+                Self::GPC_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Rme),
+                UnimplementedField {
+                    register: "GPCCR_EL3",
+                    field: "GPC",
+                    requires: "FEAT_RME",
+                },
+            ),
+            (
+                Self::GPCP_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Rme),
+                UnimplementedField {
+                    register: "GPCCR_EL3",
+                    field: "GPCP",
+                    requires: "FEAT_RME",
+                },
+            ),
+            (
+                Self::TBGPCD_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Rme),
+                UnimplementedField {
+                    register: "GPCCR_EL3",
+                    field: "TBGPCD",
+                    requires: "FEAT_RME",
+                },
+            ),
+            (
+                Self::NSO_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Rme),
+                UnimplementedField {
+                    register: "GPCCR_EL3",
+                    field: "NSO",
+                    requires: "FEAT_RME",
+                },
+            ),
+            (
+                Self::L0GPTSZ_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Rme),
+                UnimplementedField {
+                    register: "GPCCR_EL3",
+                    field: "L0GPTSZ",
+                    requires: "FEAT_RME",
+                },
+            ),
+            (
+                Self::APPSAA_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Rme),
+                UnimplementedField {
+                    register: "GPCCR_EL3",
+                    field: "APPSAA",
+                    requires: "FEAT_RME",
+                },
+            ),
+            (
+                Self::SA_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Rme),
+                UnimplementedField {
+                    register: "GPCCR_EL3",
+                    field: "SA",
+                    requires: "FEAT_RME",
+                },
+            ),
+            (
+                Self::NSP_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Rme),
+                UnimplementedField {
+                    register: "GPCCR_EL3",
+                    field: "NSP",
+                    requires: "FEAT_RME",
+                },
+            ),
+            (
+                Self::NA6_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Rme),
+                UnimplementedField {
+                    register: "GPCCR_EL3",
+                    field: "NA6",
+                    requires: "FEAT_RME",
+                },
+            ),
+            (
+                Self::NA7_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Rme),
+                UnimplementedField {
+                    register: "GPCCR_EL3",
+                    field: "NA7",
+                    requires: "FEAT_RME",
+                },
+            ),
+            (
+                Self::GPCBW_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Rme),
+                UnimplementedField {
+                    register: "GPCCR_EL3",
+                    field: "GPCBW",
+                    requires: "FEAT_RME",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(all(feature = "el3", feature = "feat-rme"))]
+bitflags! {
+    /// `GPTBR_EL3` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct GptbrEl3: u64 {
+    }
+}
+
+#[cfg(all(feature = "el3", feature = "feat-rme"))]
+impl GptbrEl3 {
+    /// Descriptor of the `BADDR` field, for use with [`FieldAccess`].
+    pub const BADDR_FIELD: Field<Self, u64, 0, 40> = Field::new("GPTBR_EL3", "BADDR");
+    /// Descriptor of the `BADDR[43:40]` field, for use with [`FieldAccess`].
+    pub const BADDR_43_40_FIELD: Field<Self, u8, 40, 4> = Field::new("GPTBR_EL3", "BADDR[43:40]");
+
+    /// Returns the value of the `BADDR` field.
+    pub const fn baddr(self) -> u64 {
+        Self::BADDR_FIELD.extract(self.bits())
+    }
+
+    /// Sets the value of the `BADDR` field.
+    pub const fn set_baddr(&mut self, value: u64) {
+        *self = Self::from_bits_retain(Self::BADDR_FIELD.insert(self.bits(), value));
+    }
+
+    /// Returns a copy with the `BADDR` field set to the given value.
+    pub const fn with_baddr(mut self, value: u64) -> Self {
+        self.set_baddr(value);
+        self
+    }
+
+    /// Sets the value of the `BADDR` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_baddr(&mut self, value: u64) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_baddr(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `BADDR` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_baddr(self, value: u64) -> Result<Self, FieldOverflow> {
+        match Self::BADDR_FIELD.try_insert(self.bits(), value) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `BADDR[43:40]` field.
+    pub const fn baddr_43_40(self) -> u8 {
+        Self::BADDR_43_40_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `BADDR[43:40]` field.
+    pub const fn set_baddr_43_40(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::BADDR_43_40_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `BADDR[43:40]` field set to the given value.
+    pub const fn with_baddr_43_40(mut self, value: u8) -> Self {
+        self.set_baddr_43_40(value);
+        self
+    }
+
+    /// Sets the value of the `BADDR[43:40]` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_baddr_43_40(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_baddr_43_40(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `BADDR[43:40]` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_baddr_43_40(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::BADDR_43_40_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(all(feature = "el3", feature = "feat-rme"))]
+impl Display for GptbrEl3 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("GptbrEl3");
+        let bits = self.bits();
+        s.field(
+            "BADDR",
+            &format_args!("{:#x}", Self::BADDR_FIELD.extract(bits)),
+        );
+        s.field("BADDR[43:40]", &Self::BADDR_43_40_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(all(feature = "el3", feature = "feat-rme"))]
+impl defmt::Format for GptbrEl3 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "GptbrEl3 {{ BADDR: {=u64:#x}, BADDR[43:40]: {=u64} }}",
+            Self::BADDR_FIELD.extract(self.bits()),
+            Self::BADDR_43_40_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el3", feature = "feat-rme"))]
+impl serde::Serialize for GptbrEl3 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el3", feature = "feat-rme"))]
+impl<'de> serde::Deserialize<'de> for GptbrEl3 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(all(feature = "el3", feature = "feat-rme"))]
+impl GptbrEl3 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("BADDR"),
+                old: Self::BADDR_FIELD.extract(self.bits()),
+                new: Self::BADDR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("BADDR[43:40]"),
+                old: Self::BADDR_43_40_FIELD.extract(self.bits()),
+                new: Self::BADDR_43_40_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(all(feature = "el3", feature = "feat-rme"))]
+impl GptbrEl3 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(all(feature = "el3", feature = "feat-rme"))]
+impl GptbrEl3 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                Self::BADDR_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Rme),
+                UnimplementedField {
+                    register: "GPTBR_EL3",
+                    field: "BADDR",
+                    requires: "FEAT_RME",
+                },
+            ),
+            (
+                Self::BADDR_43_40_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Rme),
+                UnimplementedField {
+                    register: "GPTBR_EL3",
+                    field: "BADDR[43:40]",
+                    requires: "FEAT_RME",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `MAIR_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct MairEl1: u64 {
+    }
+}
+
+#[cfg(feature = "el1")]
+impl MairEl1 {
+    /// Offset of the `Attr<n>` field.
+    pub const ATTR_SHIFT: u32 = 0;
+    /// Mask for the `Attr<n>` field.
+    pub const ATTR_MASK: u64 = 0b1111_1111;
+
+    /// Returns the value of the given `Attr<n>` field.
+    pub const fn attr(self, n: u32) -> u8 {
+        assert!(n < 8);
+        ((self.bits() >> (Self::ATTR_SHIFT + n * 8)) & Self::ATTR_MASK) as u8
+    }
+
+    /// Sets the value of the `Attr<n>` field.
+    pub const fn set_attr(&mut self, n: u32, value: u8) {
+        assert!(n < 8);
+        let offset = Self::ATTR_SHIFT + n * 8;
+        assert!(value & (Self::ATTR_MASK as u8) == value);
+        *self = Self::from_bits_retain(
+            (self.bits() & !(Self::ATTR_MASK << offset)) | ((value as u64) << offset),
+        );
+    }
+
+    /// Returns a copy with the `Attr<n>` field set to the given value.
+    pub const fn with_attr(mut self, n: u32, value: u8) -> Self {
+        self.set_attr(n, value);
+        self
+    }
+
+    /// Sets the value of the `Attr<n>` field, or returns an error if the value doesn't fit or the
+    /// index is out of range.
+    pub const fn try_set_attr(&mut self, n: u32, value: u8) -> Result<(), FieldOverflow> {
+        if n >= 8 || value & (Self::ATTR_MASK as u8) != value {
+            return Err(FieldOverflow {
+                register: "MAIR_EL1",
+                field: "Attr",
+                index: Some(n),
+                value: value as u64,
+            });
+        }
+        self.set_attr(n, value);
+        Ok(())
+    }
+
+    /// Returns a copy with the `Attr<n>` field set to the given value, or an error if the value
+    /// doesn't fit or the index is out of range.
+    pub const fn try_with_attr(mut self, n: u32, value: u8) -> Result<Self, FieldOverflow> {
+        match self.try_set_attr(n, value) {
+            Ok(()) => Ok(self),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for MairEl1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("MairEl1");
+        let bits = self.bits();
+        s.field("Attr0", &((bits >> Self::ATTR_SHIFT) & Self::ATTR_MASK));
+        s.field(
+            "Attr1",
+            &((bits >> (Self::ATTR_SHIFT + 8)) & Self::ATTR_MASK),
+        );
+        s.field(
+            "Attr2",
+            &((bits >> (Self::ATTR_SHIFT + 16)) & Self::ATTR_MASK),
+        );
+        s.field(
+            "Attr3",
+            &((bits >> (Self::ATTR_SHIFT + 24)) & Self::ATTR_MASK),
+        );
+        s.field(
+            "Attr4",
+            &((bits >> (Self::ATTR_SHIFT + 32)) & Self::ATTR_MASK),
+        );
+        s.field(
+            "Attr5",
+            &((bits >> (Self::ATTR_SHIFT + 40)) & Self::ATTR_MASK),
+        );
+        s.field(
+            "Attr6",
+            &((bits >> (Self::ATTR_SHIFT + 48)) & Self::ATTR_MASK),
+        );
+        s.field(
+            "Attr7",
+            &((bits >> (Self::ATTR_SHIFT + 56)) & Self::ATTR_MASK),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for MairEl1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "MairEl1 {{ Attr0: {=u64}, Attr1: {=u64}, Attr2: {=u64}, Attr3: {=u64}, Attr4: {=u64}, Attr5: {=u64}, Attr6: {=u64}, Attr7: {=u64} }}",
+            (self.bits() >> Self::ATTR_SHIFT) & Self::ATTR_MASK,
+            (self.bits() >> (Self::ATTR_SHIFT + 8)) & Self::ATTR_MASK,
+            (self.bits() >> (Self::ATTR_SHIFT + 16)) & Self::ATTR_MASK,
+            (self.bits() >> (Self::ATTR_SHIFT + 24)) & Self::ATTR_MASK,
+            (self.bits() >> (Self::ATTR_SHIFT + 32)) & Self::ATTR_MASK,
+            (self.bits() >> (Self::ATTR_SHIFT + 40)) & Self::ATTR_MASK,
+            (self.bits() >> (Self::ATTR_SHIFT + 48)) & Self::ATTR_MASK,
+            (self.bits() >> (Self::ATTR_SHIFT + 56)) & Self::ATTR_MASK
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for MairEl1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for MairEl1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl MairEl1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("Attr0"),
+                old: (self.bits() >> Self::ATTR_SHIFT) & Self::ATTR_MASK,
+                new: (other.bits() >> Self::ATTR_SHIFT) & Self::ATTR_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Attr1"),
+                old: (self.bits() >> (Self::ATTR_SHIFT + 8)) & Self::ATTR_MASK,
+                new: (other.bits() >> (Self::ATTR_SHIFT + 8)) & Self::ATTR_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Attr2"),
+                old: (self.bits() >> (Self::ATTR_SHIFT + 16)) & Self::ATTR_MASK,
+                new: (other.bits() >> (Self::ATTR_SHIFT + 16)) & Self::ATTR_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Attr3"),
+                old: (self.bits() >> (Self::ATTR_SHIFT + 24)) & Self::ATTR_MASK,
+                new: (other.bits() >> (Self::ATTR_SHIFT + 24)) & Self::ATTR_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Attr4"),
+                old: (self.bits() >> (Self::ATTR_SHIFT + 32)) & Self::ATTR_MASK,
+                new: (other.bits() >> (Self::ATTR_SHIFT + 32)) & Self::ATTR_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Attr5"),
+                old: (self.bits() >> (Self::ATTR_SHIFT + 40)) & Self::ATTR_MASK,
+                new: (other.bits() >> (Self::ATTR_SHIFT + 40)) & Self::ATTR_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Attr6"),
+                old: (self.bits() >> (Self::ATTR_SHIFT + 48)) & Self::ATTR_MASK,
+                new: (other.bits() >> (Self::ATTR_SHIFT + 48)) & Self::ATTR_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Attr7"),
+                old: (self.bits() >> (Self::ATTR_SHIFT + 56)) & Self::ATTR_MASK,
+                new: (other.bits() >> (Self::ATTR_SHIFT + 56)) & Self::ATTR_MASK,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl MairEl1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl MairEl1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `MAIR_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct MairEl2: u64 {
+    }
+}
+
+#[cfg(feature = "el2")]
+impl MairEl2 {
+    /// Offset of the `Attr<n>` field.
+    pub const ATTR_SHIFT: u32 = 0;
+    /// Mask for the `Attr<n>` field.
+    pub const ATTR_MASK: u64 = 0b1111_1111;
+
+    /// Returns the value of the given `Attr<n>` field.
+    pub const fn attr(self, n: u32) -> u8 {
+        assert!(n < 8);
+        ((self.bits() >> (Self::ATTR_SHIFT + n * 8)) & Self::ATTR_MASK) as u8
+    }
+
+    /// Sets the value of the `Attr<n>` field.
+    pub const fn set_attr(&mut self, n: u32, value: u8) {
+        assert!(n < 8);
+        let offset = Self::ATTR_SHIFT + n * 8;
+        assert!(value & (Self::ATTR_MASK as u8) == value);
+        *self = Self::from_bits_retain(
+            (self.bits() & !(Self::ATTR_MASK << offset)) | ((value as u64) << offset),
+        );
+    }
+
+    /// Returns a copy with the `Attr<n>` field set to the given value.
+    pub const fn with_attr(mut self, n: u32, value: u8) -> Self {
+        self.set_attr(n, value);
+        self
+    }
+
+    /// Sets the value of the `Attr<n>` field, or returns an error if the value doesn't fit or the
+    /// index is out of range.
+    pub const fn try_set_attr(&mut self, n: u32, value: u8) -> Result<(), FieldOverflow> {
+        if n >= 8 || value & (Self::ATTR_MASK as u8) != value {
+            return Err(FieldOverflow {
+                register: "MAIR_EL2",
+                field: "Attr",
+                index: Some(n),
+                value: value as u64,
+            });
+        }
+        self.set_attr(n, value);
+        Ok(())
+    }
+
+    /// Returns a copy with the `Attr<n>` field set to the given value, or an error if the value
+    /// doesn't fit or the index is out of range.
+    pub const fn try_with_attr(mut self, n: u32, value: u8) -> Result<Self, FieldOverflow> {
+        match self.try_set_attr(n, value) {
+            Ok(()) => Ok(self),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Display for MairEl2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("MairEl2");
+        let bits = self.bits();
+        s.field("Attr0", &((bits >> Self::ATTR_SHIFT) & Self::ATTR_MASK));
+        s.field(
+            "Attr1",
+            &((bits >> (Self::ATTR_SHIFT + 8)) & Self::ATTR_MASK),
+        );
+        s.field(
+            "Attr2",
+            &((bits >> (Self::ATTR_SHIFT + 16)) & Self::ATTR_MASK),
+        );
+        s.field(
+            "Attr3",
+            &((bits >> (Self::ATTR_SHIFT + 24)) & Self::ATTR_MASK),
+        );
+        s.field(
+            "Attr4",
+            &((bits >> (Self::ATTR_SHIFT + 32)) & Self::ATTR_MASK),
+        );
+        s.field(
+            "Attr5",
+            &((bits >> (Self::ATTR_SHIFT + 40)) & Self::ATTR_MASK),
+        );
+        s.field(
+            "Attr6",
+            &((bits >> (Self::ATTR_SHIFT + 48)) & Self::ATTR_MASK),
+        );
+        s.field(
+            "Attr7",
+            &((bits >> (Self::ATTR_SHIFT + 56)) & Self::ATTR_MASK),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for MairEl2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "MairEl2 {{ Attr0: {=u64}, Attr1: {=u64}, Attr2: {=u64}, Attr3: {=u64}, Attr4: {=u64}, Attr5: {=u64}, Attr6: {=u64}, Attr7: {=u64} }}",
+            (self.bits() >> Self::ATTR_SHIFT) & Self::ATTR_MASK,
+            (self.bits() >> (Self::ATTR_SHIFT + 8)) & Self::ATTR_MASK,
+            (self.bits() >> (Self::ATTR_SHIFT + 16)) & Self::ATTR_MASK,
+            (self.bits() >> (Self::ATTR_SHIFT + 24)) & Self::ATTR_MASK,
+            (self.bits() >> (Self::ATTR_SHIFT + 32)) & Self::ATTR_MASK,
+            (self.bits() >> (Self::ATTR_SHIFT + 40)) & Self::ATTR_MASK,
+            (self.bits() >> (Self::ATTR_SHIFT + 48)) & Self::ATTR_MASK,
+            (self.bits() >> (Self::ATTR_SHIFT + 56)) & Self::ATTR_MASK
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for MairEl2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for MairEl2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl MairEl2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("Attr0"),
+                old: (self.bits() >> Self::ATTR_SHIFT) & Self::ATTR_MASK,
+                new: (other.bits() >> Self::ATTR_SHIFT) & Self::ATTR_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Attr1"),
+                old: (self.bits() >> (Self::ATTR_SHIFT + 8)) & Self::ATTR_MASK,
+                new: (other.bits() >> (Self::ATTR_SHIFT + 8)) & Self::ATTR_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Attr2"),
+                old: (self.bits() >> (Self::ATTR_SHIFT + 16)) & Self::ATTR_MASK,
+                new: (other.bits() >> (Self::ATTR_SHIFT + 16)) & Self::ATTR_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Attr3"),
+                old: (self.bits() >> (Self::ATTR_SHIFT + 24)) & Self::ATTR_MASK,
+                new: (other.bits() >> (Self::ATTR_SHIFT + 24)) & Self::ATTR_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Attr4"),
+                old: (self.bits() >> (Self::ATTR_SHIFT + 32)) & Self::ATTR_MASK,
+                new: (other.bits() >> (Self::ATTR_SHIFT + 32)) & Self::ATTR_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Attr5"),
+                old: (self.bits() >> (Self::ATTR_SHIFT + 40)) & Self::ATTR_MASK,
+                new: (other.bits() >> (Self::ATTR_SHIFT + 40)) & Self::ATTR_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Attr6"),
+                old: (self.bits() >> (Self::ATTR_SHIFT + 48)) & Self::ATTR_MASK,
+                new: (other.bits() >> (Self::ATTR_SHIFT + 48)) & Self::ATTR_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Attr7"),
+                old: (self.bits() >> (Self::ATTR_SHIFT + 56)) & Self::ATTR_MASK,
+                new: (other.bits() >> (Self::ATTR_SHIFT + 56)) & Self::ATTR_MASK,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl MairEl2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl MairEl2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el3")]
+bitflags! {
+    /// `MAIR_EL3` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct MairEl3: u64 {
+    }
+}
+
+#[cfg(feature = "el3")]
+impl MairEl3 {
+    /// Offset of the `Attr<n>` field.
+    pub const ATTR_SHIFT: u32 = 0;
+    /// Mask for the `Attr<n>` field.
+    pub const ATTR_MASK: u64 = 0b1111_1111;
+
+    /// Returns the value of the given `Attr<n>` field.
+    pub const fn attr(self, n: u32) -> u8 {
+        assert!(n < 8);
+        ((self.bits() >> (Self::ATTR_SHIFT + n * 8)) & Self::ATTR_MASK) as u8
+    }
+
+    /// Sets the value of the `Attr<n>` field.
+    pub const fn set_attr(&mut self, n: u32, value: u8) {
+        assert!(n < 8);
+        let offset = Self::ATTR_SHIFT + n * 8;
+        assert!(value & (Self::ATTR_MASK as u8) == value);
+        *self = Self::from_bits_retain(
+            (self.bits() & !(Self::ATTR_MASK << offset)) | ((value as u64) << offset),
+        );
+    }
+
+    /// Returns a copy with the `Attr<n>` field set to the given value.
+    pub const fn with_attr(mut self, n: u32, value: u8) -> Self {
+        self.set_attr(n, value);
+        self
+    }
+
+    /// Sets the value of the `Attr<n>` field, or returns an error if the value doesn't fit or the
+    /// index is out of range.
+    pub const fn try_set_attr(&mut self, n: u32, value: u8) -> Result<(), FieldOverflow> {
+        if n >= 8 || value & (Self::ATTR_MASK as u8) != value {
+            return Err(FieldOverflow {
+                register: "MAIR_EL3",
+                field: "Attr",
+                index: Some(n),
+                value: value as u64,
+            });
+        }
+        self.set_attr(n, value);
+        Ok(())
+    }
+
+    /// Returns a copy with the `Attr<n>` field set to the given value, or an error if the value
+    /// doesn't fit or the index is out of range.
+    pub const fn try_with_attr(mut self, n: u32, value: u8) -> Result<Self, FieldOverflow> {
+        match self.try_set_attr(n, value) {
+            Ok(()) => Ok(self),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el3")]
+impl Display for MairEl3 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("MairEl3");
+        let bits = self.bits();
+        s.field("Attr0", &((bits >> Self::ATTR_SHIFT) & Self::ATTR_MASK));
+        s.field(
+            "Attr1",
+            &((bits >> (Self::ATTR_SHIFT + 8)) & Self::ATTR_MASK),
+        );
+        s.field(
+            "Attr2",
+            &((bits >> (Self::ATTR_SHIFT + 16)) & Self::ATTR_MASK),
+        );
+        s.field(
+            "Attr3",
+            &((bits >> (Self::ATTR_SHIFT + 24)) & Self::ATTR_MASK),
+        );
+        s.field(
+            "Attr4",
+            &((bits >> (Self::ATTR_SHIFT + 32)) & Self::ATTR_MASK),
+        );
+        s.field(
+            "Attr5",
+            &((bits >> (Self::ATTR_SHIFT + 40)) & Self::ATTR_MASK),
+        );
+        s.field(
+            "Attr6",
+            &((bits >> (Self::ATTR_SHIFT + 48)) & Self::ATTR_MASK),
+        );
+        s.field(
+            "Attr7",
+            &((bits >> (Self::ATTR_SHIFT + 56)) & Self::ATTR_MASK),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el3")]
+impl defmt::Format for MairEl3 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "MairEl3 {{ Attr0: {=u64}, Attr1: {=u64}, Attr2: {=u64}, Attr3: {=u64}, Attr4: {=u64}, Attr5: {=u64}, Attr6: {=u64}, Attr7: {=u64} }}",
+            (self.bits() >> Self::ATTR_SHIFT) & Self::ATTR_MASK,
+            (self.bits() >> (Self::ATTR_SHIFT + 8)) & Self::ATTR_MASK,
+            (self.bits() >> (Self::ATTR_SHIFT + 16)) & Self::ATTR_MASK,
+            (self.bits() >> (Self::ATTR_SHIFT + 24)) & Self::ATTR_MASK,
+            (self.bits() >> (Self::ATTR_SHIFT + 32)) & Self::ATTR_MASK,
+            (self.bits() >> (Self::ATTR_SHIFT + 40)) & Self::ATTR_MASK,
+            (self.bits() >> (Self::ATTR_SHIFT + 48)) & Self::ATTR_MASK,
+            (self.bits() >> (Self::ATTR_SHIFT + 56)) & Self::ATTR_MASK
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el3")]
+impl serde::Serialize for MairEl3 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el3")]
+impl<'de> serde::Deserialize<'de> for MairEl3 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el3")]
+impl MairEl3 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("Attr0"),
+                old: (self.bits() >> Self::ATTR_SHIFT) & Self::ATTR_MASK,
+                new: (other.bits() >> Self::ATTR_SHIFT) & Self::ATTR_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Attr1"),
+                old: (self.bits() >> (Self::ATTR_SHIFT + 8)) & Self::ATTR_MASK,
+                new: (other.bits() >> (Self::ATTR_SHIFT + 8)) & Self::ATTR_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Attr2"),
+                old: (self.bits() >> (Self::ATTR_SHIFT + 16)) & Self::ATTR_MASK,
+                new: (other.bits() >> (Self::ATTR_SHIFT + 16)) & Self::ATTR_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Attr3"),
+                old: (self.bits() >> (Self::ATTR_SHIFT + 24)) & Self::ATTR_MASK,
+                new: (other.bits() >> (Self::ATTR_SHIFT + 24)) & Self::ATTR_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Attr4"),
+                old: (self.bits() >> (Self::ATTR_SHIFT + 32)) & Self::ATTR_MASK,
+                new: (other.bits() >> (Self::ATTR_SHIFT + 32)) & Self::ATTR_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Attr5"),
+                old: (self.bits() >> (Self::ATTR_SHIFT + 40)) & Self::ATTR_MASK,
+                new: (other.bits() >> (Self::ATTR_SHIFT + 40)) & Self::ATTR_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Attr6"),
+                old: (self.bits() >> (Self::ATTR_SHIFT + 48)) & Self::ATTR_MASK,
+                new: (other.bits() >> (Self::ATTR_SHIFT + 48)) & Self::ATTR_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Attr7"),
+                old: (self.bits() >> (Self::ATTR_SHIFT + 56)) & Self::ATTR_MASK,
+                new: (other.bits() >> (Self::ATTR_SHIFT + 56)) & Self::ATTR_MASK,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el3")]
+impl MairEl3 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el3")]
+impl MairEl3 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `PAR_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct ParEl1: u64 {
+        /// `F` bit.
+        const F = 1 << 0;
+        /// `PTW` bit.
+        const PTW = 1 << 8;
+        /// `NS` bit.
+        const NS = 1 << 9;
+        /// `S` bit.
+        const S = 1 << 9;
+        /// `NSE` bit.
+        const NSE = 1 << 11;
+        /// `AssuredOnly` bit.
+        const ASSUREDONLY = 1 << 12;
+        /// `TopLevel` bit.
+        const TOPLEVEL = 1 << 13;
+        /// `Overlay` bit.
+        const OVERLAY = 1 << 14;
+        /// `DirtyBit` bit.
+        const DIRTYBIT = 1 << 15;
+    }
+}
+
+#[cfg(feature = "el1")]
+impl ParEl1 {
+    /// Descriptor of the `F` field, for use with [`FieldAccess`].
+    pub const F_FIELD: Field<Self, bool, 0, 1> = Field::new("PAR_EL1", "F");
+    /// Descriptor of the `FST` field, for use with [`FieldAccess`].
+    pub const FST_FIELD: Field<Self, u8, 1, 6> = Field::new("PAR_EL1", "FST");
+    /// Descriptor of the `SH` field, for use with [`FieldAccess`].
+    pub const SH_FIELD: Field<Self, u8, 7, 2> = Field::new("PAR_EL1", "SH");
+    /// Descriptor of the `PTW` field, for use with [`FieldAccess`].
+    pub const PTW_FIELD: Field<Self, bool, 8, 1> = Field::new("PAR_EL1", "PTW");
+    /// Descriptor of the `NS` field, for use with [`FieldAccess`].
+    pub const NS_FIELD: Field<Self, bool, 9, 1> = Field::new("PAR_EL1", "NS");
+    /// Descriptor of the `S` field, for use with [`FieldAccess`].
+    pub const S_FIELD: Field<Self, bool, 9, 1> = Field::new("PAR_EL1", "S");
+    /// Descriptor of the `NSE` field, for use with [`FieldAccess`].
+    pub const NSE_FIELD: Field<Self, bool, 11, 1> = Field::new("PAR_EL1", "NSE");
+    /// Descriptor of the `AssuredOnly` field, for use with [`FieldAccess`].
+    pub const ASSUREDONLY_FIELD: Field<Self, bool, 12, 1> = Field::new("PAR_EL1", "AssuredOnly");
+    /// Descriptor of the `PA[47:12]` field, for use with [`FieldAccess`].
+    pub const PA_47_12_FIELD: Field<Self, u64, 12, 36> = Field::new("PAR_EL1", "PA[47:12]");
+    /// Descriptor of the `TopLevel` field, for use with [`FieldAccess`].
+    pub const TOPLEVEL_FIELD: Field<Self, bool, 13, 1> = Field::new("PAR_EL1", "TopLevel");
+    /// Descriptor of the `Overlay` field, for use with [`FieldAccess`].
+    pub const OVERLAY_FIELD: Field<Self, bool, 14, 1> = Field::new("PAR_EL1", "Overlay");
+    /// Descriptor of the `DirtyBit` field, for use with [`FieldAccess`].
+    pub const DIRTYBIT_FIELD: Field<Self, bool, 15, 1> = Field::new("PAR_EL1", "DirtyBit");
+    /// Descriptor of the `PA[51:48]` field, for use with [`FieldAccess`].
+    pub const PA_51_48_FIELD: Field<Self, u8, 48, 4> = Field::new("PAR_EL1", "PA[51:48]");
+    /// Descriptor of the `ATTR` field, for use with [`FieldAccess`].
+    pub const ATTR_FIELD: Field<Self, u8, 56, 8> = Field::new("PAR_EL1", "ATTR");
+
+    /// Returns the value of the `FST` field.
+    pub const fn fst(self) -> u8 {
+        Self::FST_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `FST` field.
+    pub const fn set_fst(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::FST_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `FST` field set to the given value.
+    pub const fn with_fst(mut self, value: u8) -> Self {
+        self.set_fst(value);
+        self
+    }
+
+    /// Sets the value of the `FST` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_fst(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_fst(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `FST` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_fst(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::FST_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SH` field.
+    pub const fn sh(self) -> u8 {
+        Self::SH_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SH` field.
+    pub const fn set_sh(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SH_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SH` field set to the given value.
+    pub const fn with_sh(mut self, value: u8) -> Self {
+        self.set_sh(value);
+        self
+    }
+
+    /// Sets the value of the `SH` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_sh(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_sh(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SH` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_sh(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SH_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PA[47:12]` field.
+    pub const fn pa_47_12(self) -> u64 {
+        Self::PA_47_12_FIELD.extract(self.bits())
+    }
+
+    /// Sets the value of the `PA[47:12]` field.
+    pub const fn set_pa_47_12(&mut self, value: u64) {
+        *self = Self::from_bits_retain(Self::PA_47_12_FIELD.insert(self.bits(), value));
+    }
+
+    /// Returns a copy with the `PA[47:12]` field set to the given value.
+    pub const fn with_pa_47_12(mut self, value: u64) -> Self {
+        self.set_pa_47_12(value);
+        self
+    }
+
+    /// Sets the value of the `PA[47:12]` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_pa_47_12(&mut self, value: u64) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_pa_47_12(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PA[47:12]` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_pa_47_12(self, value: u64) -> Result<Self, FieldOverflow> {
+        match Self::PA_47_12_FIELD.try_insert(self.bits(), value) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PA[51:48]` field.
+    pub const fn pa_51_48(self) -> u8 {
+        Self::PA_51_48_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `PA[51:48]` field.
+    pub const fn set_pa_51_48(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::PA_51_48_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PA[51:48]` field set to the given value.
+    pub const fn with_pa_51_48(mut self, value: u8) -> Self {
+        self.set_pa_51_48(value);
+        self
+    }
+
+    /// Sets the value of the `PA[51:48]` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_pa_51_48(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_pa_51_48(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PA[51:48]` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_pa_51_48(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::PA_51_48_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ATTR` field.
+    pub const fn attr(self) -> u8 {
+        Self::ATTR_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `ATTR` field.
+    pub const fn set_attr(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::ATTR_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ATTR` field set to the given value.
+    pub const fn with_attr(mut self, value: u8) -> Self {
+        self.set_attr(value);
+        self
+    }
+
+    /// Sets the value of the `ATTR` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_attr(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_attr(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ATTR` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_attr(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::ATTR_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for ParEl1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("ParEl1");
+        let bits = self.bits();
+        s.field("F", &Self::F_FIELD.extract(bits));
+        s.field("FST", &Self::FST_FIELD.extract(bits));
+        s.field("SH", &Self::SH_FIELD.extract(bits));
+        s.field("PTW", &Self::PTW_FIELD.extract(bits));
+        s.field("NS", &Self::NS_FIELD.extract(bits));
+        s.field("S", &Self::S_FIELD.extract(bits));
+        s.field("NSE", &Self::NSE_FIELD.extract(bits));
+        s.field("AssuredOnly", &Self::ASSUREDONLY_FIELD.extract(bits));
+        s.field(
+            "PA[47:12]",
+            &format_args!("{:#x}", Self::PA_47_12_FIELD.extract(bits)),
+        );
+        s.field("TopLevel", &Self::TOPLEVEL_FIELD.extract(bits));
+        s.field("Overlay", &Self::OVERLAY_FIELD.extract(bits));
+        s.field("DirtyBit", &Self::DIRTYBIT_FIELD.extract(bits));
+        s.field("PA[51:48]", &Self::PA_51_48_FIELD.extract(bits));
+        s.field("ATTR", &Self::ATTR_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for ParEl1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "ParEl1 {{ F: {=u64}, FST: {=u64}, SH: {=u64}, PTW: {=u64}, NS: {=u64}, S: {=u64}, NSE: {=u64}, AssuredOnly: {=u64}, PA[47:12]: {=u64:#x}, TopLevel: {=u64}, Overlay: {=u64}, DirtyBit: {=u64}, PA[51:48]: {=u64}, ATTR: {=u64} }}",
+            Self::F_FIELD.extract(self.bits()),
+            Self::FST_FIELD.extract(self.bits()),
+            Self::SH_FIELD.extract(self.bits()),
+            Self::PTW_FIELD.extract(self.bits()),
+            Self::NS_FIELD.extract(self.bits()),
+            Self::S_FIELD.extract(self.bits()),
+            Self::NSE_FIELD.extract(self.bits()),
+            Self::ASSUREDONLY_FIELD.extract(self.bits()),
+            Self::PA_47_12_FIELD.extract(self.bits()),
+            Self::TOPLEVEL_FIELD.extract(self.bits()),
+            Self::OVERLAY_FIELD.extract(self.bits()),
+            Self::DIRTYBIT_FIELD.extract(self.bits()),
+            Self::PA_51_48_FIELD.extract(self.bits()),
+            Self::ATTR_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for ParEl1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for ParEl1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl ParEl1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("F"),
+                old: Self::F_FIELD.extract(self.bits()),
+                new: Self::F_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FST"),
+                old: Self::FST_FIELD.extract(self.bits()),
+                new: Self::FST_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SH"),
+                old: Self::SH_FIELD.extract(self.bits()),
+                new: Self::SH_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PTW"),
+                old: Self::PTW_FIELD.extract(self.bits()),
+                new: Self::PTW_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("NS"),
+                old: Self::NS_FIELD.extract(self.bits()),
+                new: Self::NS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("S"),
+                old: Self::S_FIELD.extract(self.bits()),
+                new: Self::S_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("NSE"),
+                old: Self::NSE_FIELD.extract(self.bits()),
+                new: Self::NSE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("AssuredOnly"),
+                old: Self::ASSUREDONLY_FIELD.extract(self.bits()),
+                new: Self::ASSUREDONLY_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PA[47:12]"),
+                old: Self::PA_47_12_FIELD.extract(self.bits()),
+                new: Self::PA_47_12_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TopLevel"),
+                old: Self::TOPLEVEL_FIELD.extract(self.bits()),
+                new: Self::TOPLEVEL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("Overlay"),
+                old: Self::OVERLAY_FIELD.extract(self.bits()),
+                new: Self::OVERLAY_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DirtyBit"),
+                old: Self::DIRTYBIT_FIELD.extract(self.bits()),
+                new: Self::DIRTYBIT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PA[51:48]"),
+                old: Self::PA_51_48_FIELD.extract(self.bits()),
+                new: Self::PA_51_48_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ATTR"),
+                old: Self::ATTR_FIELD.extract(self.bits()),
+                new: Self::ATTR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits()
+                    & 0b1111_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0100_0000_0000,
+                new: other.bits()
+                    & 0b1111_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0100_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl ParEl1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl ParEl1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `PAR_EL1` system register value, when `F` is 0.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct ParEl1Success: u64 {
+        /// `F` bit.
+        const F = 1 << 0;
+        /// `NS` bit.
+        const NS = 1 << 9;
+        /// `NSE` bit.
+        const NSE = 1 << 11;
+    }
+}
+
+#[cfg(feature = "el1")]
+impl ParEl1Success {
+    /// Descriptor of the `F` field, for use with [`FieldAccess`].
+    pub const F_FIELD: Field<Self, bool, 0, 1> = Field::new("PAR_EL1", "F");
+    /// Descriptor of the `SH` field, for use with [`FieldAccess`].
+    pub const SH_FIELD: Field<Self, u8, 7, 2> = Field::new("PAR_EL1", "SH");
+    /// Descriptor of the `NS` field, for use with [`FieldAccess`].
+    pub const NS_FIELD: Field<Self, bool, 9, 1> = Field::new("PAR_EL1", "NS");
+    /// Descriptor of the `NSE` field, for use with [`FieldAccess`].
+    pub const NSE_FIELD: Field<Self, bool, 11, 1> = Field::new("PAR_EL1", "NSE");
+    /// Descriptor of the `PA[47:12]` field, for use with [`FieldAccess`].
+    pub const PA_47_12_FIELD: Field<Self, u64, 12, 36> = Field::new("PAR_EL1", "PA[47:12]");
+    /// Descriptor of the `PA[51:48]` field, for use with [`FieldAccess`].
+    pub const PA_51_48_FIELD: Field<Self, u8, 48, 4> = Field::new("PAR_EL1", "PA[51:48]");
+    /// Descriptor of the `ATTR` field, for use with [`FieldAccess`].
+    pub const ATTR_FIELD: Field<Self, u8, 56, 8> = Field::new("PAR_EL1", "ATTR");
+
+    /// Returns the value of the `SH` field.
+    pub const fn sh(self) -> u8 {
+        Self::SH_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SH` field.
+    pub const fn set_sh(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SH_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SH` field set to the given value.
+    pub const fn with_sh(mut self, value: u8) -> Self {
+        self.set_sh(value);
+        self
+    }
+
+    /// Sets the value of the `SH` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_sh(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_sh(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SH` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_sh(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SH_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PA[47:12]` field.
+    pub const fn pa_47_12(self) -> u64 {
+        Self::PA_47_12_FIELD.extract(self.bits())
+    }
+
+    /// Sets the value of the `PA[47:12]` field.
+    pub const fn set_pa_47_12(&mut self, value: u64) {
+        *self = Self::from_bits_retain(Self::PA_47_12_FIELD.insert(self.bits(), value));
+    }
+
+    /// Returns a copy with the `PA[47:12]` field set to the given value.
+    pub const fn with_pa_47_12(mut self, value: u64) -> Self {
+        self.set_pa_47_12(value);
+        self
+    }
+
+    /// Sets the value of the `PA[47:12]` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_pa_47_12(&mut self, value: u64) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_pa_47_12(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PA[47:12]` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_pa_47_12(self, value: u64) -> Result<Self, FieldOverflow> {
+        match Self::PA_47_12_FIELD.try_insert(self.bits(), value) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PA[51:48]` field.
+    pub const fn pa_51_48(self) -> u8 {
+        Self::PA_51_48_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `PA[51:48]` field.
+    pub const fn set_pa_51_48(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::PA_51_48_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PA[51:48]` field set to the given value.
+    pub const fn with_pa_51_48(mut self, value: u8) -> Self {
+        self.set_pa_51_48(value);
+        self
+    }
+
+    /// Sets the value of the `PA[51:48]` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_pa_51_48(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_pa_51_48(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PA[51:48]` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_pa_51_48(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::PA_51_48_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ATTR` field.
+    pub const fn attr(self) -> u8 {
+        Self::ATTR_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `ATTR` field.
+    pub const fn set_attr(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::ATTR_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ATTR` field set to the given value.
+    pub const fn with_attr(mut self, value: u8) -> Self {
+        self.set_attr(value);
+        self
+    }
+
+    /// Sets the value of the `ATTR` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_attr(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_attr(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ATTR` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_attr(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::ATTR_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for ParEl1Success {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("ParEl1Success");
+        let bits = self.bits();
+        s.field("F", &Self::F_FIELD.extract(bits));
+        s.field("SH", &Self::SH_FIELD.extract(bits));
+        s.field("NS", &Self::NS_FIELD.extract(bits));
+        s.field("NSE", &Self::NSE_FIELD.extract(bits));
+        s.field(
+            "PA[47:12]",
+            &format_args!("{:#x}", Self::PA_47_12_FIELD.extract(bits)),
+        );
+        s.field("PA[51:48]", &Self::PA_51_48_FIELD.extract(bits));
+        s.field("ATTR", &Self::ATTR_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for ParEl1Success {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "ParEl1Success {{ F: {=u64}, SH: {=u64}, NS: {=u64}, NSE: {=u64}, PA[47:12]: {=u64:#x}, PA[51:48]: {=u64}, ATTR: {=u64} }}",
+            Self::F_FIELD.extract(self.bits()),
+            Self::SH_FIELD.extract(self.bits()),
+            Self::NS_FIELD.extract(self.bits()),
+            Self::NSE_FIELD.extract(self.bits()),
+            Self::PA_47_12_FIELD.extract(self.bits()),
+            Self::PA_51_48_FIELD.extract(self.bits()),
+            Self::ATTR_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for ParEl1Success {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for ParEl1Success {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl ParEl1Success {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("F"),
+                old: Self::F_FIELD.extract(self.bits()),
+                new: Self::F_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SH"),
+                old: Self::SH_FIELD.extract(self.bits()),
+                new: Self::SH_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("NS"),
+                old: Self::NS_FIELD.extract(self.bits()),
+                new: Self::NS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("NSE"),
+                old: Self::NSE_FIELD.extract(self.bits()),
+                new: Self::NSE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PA[47:12]"),
+                old: Self::PA_47_12_FIELD.extract(self.bits()),
+                new: Self::PA_47_12_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PA[51:48]"),
+                old: Self::PA_51_48_FIELD.extract(self.bits()),
+                new: Self::PA_51_48_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ATTR"),
+                old: Self::ATTR_FIELD.extract(self.bits()),
+                new: Self::ATTR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits()
+                    & 0b1111_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0100_0111_1110,
+                new: other.bits()
+                    & 0b1111_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0100_0111_1110,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl ParEl1Success {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 =
+        0b1111_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0111_1110;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl ParEl1Success {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `PAR_EL1` system register value, when `F` is 1.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct ParEl1Fault: u64 {
+        /// RES1 bits in the `PAR_EL1` register.
+        const RES1 = 0b1000_0000_0000;
+        /// `F` bit.
+        const F = 1 << 0;
+        /// `PTW` bit.
+        const PTW = 1 << 8;
+        /// `S` bit.
+        const S = 1 << 9;
+        /// `AssuredOnly` bit.
+        const ASSUREDONLY = 1 << 12;
+        /// `TopLevel` bit.
+        const TOPLEVEL = 1 << 13;
+        /// `Overlay` bit.
+        const OVERLAY = 1 << 14;
+        /// `DirtyBit` bit.
+        const DIRTYBIT = 1 << 15;
+    }
+}
+
+#[cfg(feature = "el1")]
+impl ParEl1Fault {
+    /// Descriptor of the `F` field, for use with [`FieldAccess`].
+    pub const F_FIELD: Field<Self, bool, 0, 1> = Field::new("PAR_EL1", "F");
+    /// Descriptor of the `FST` field, for use with [`FieldAccess`].
+    pub const FST_FIELD: Field<Self, u8, 1, 6> = Field::new("PAR_EL1", "FST");
+    /// Descriptor of the `PTW` field, for use with [`FieldAccess`].
+    pub const PTW_FIELD: Field<Self, bool, 8, 1> = Field::new("PAR_EL1", "PTW");
+    /// Descriptor of the `S` field, for use with [`FieldAccess`].
+    pub const S_FIELD: Field<Self, bool, 9, 1> = Field::new("PAR_EL1", "S");
+    /// Descriptor of the `AssuredOnly` field, for use with [`FieldAccess`].
+    pub const ASSUREDONLY_FIELD: Field<Self, bool, 12, 1> = Field::new("PAR_EL1", "AssuredOnly");
+    /// Descriptor of the `TopLevel` field, for use with [`FieldAccess`].
+    pub const TOPLEVEL_FIELD: Field<Self, bool, 13, 1> = Field::new("PAR_EL1", "TopLevel");
+    /// Descriptor of the `Overlay` field, for use with [`FieldAccess`].
+    pub const OVERLAY_FIELD: Field<Self, bool, 14, 1> = Field::new("PAR_EL1", "Overlay");
+    /// Descriptor of the `DirtyBit` field, for use with [`FieldAccess`].
+    pub const DIRTYBIT_FIELD: Field<Self, bool, 15, 1> = Field::new("PAR_EL1", "DirtyBit");
+
+    /// Returns the value of the `FST` field.
+    pub const fn fst(self) -> u8 {
+        Self::FST_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `FST` field.
+    pub const fn set_fst(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::FST_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `FST` field set to the given value.
+    pub const fn with_fst(mut self, value: u8) -> Self {
+        self.set_fst(value);
+        self
+    }
+
+    /// Sets the value of the `FST` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_fst(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_fst(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `FST` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_fst(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::FST_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for ParEl1Fault {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("ParEl1Fault");
+        let bits = self.bits();
+        s.field("F", &Self::F_FIELD.extract(bits));
+        s.field("FST", &Self::FST_FIELD.extract(bits));
+        s.field("PTW", &Self::PTW_FIELD.extract(bits));
+        s.field("S", &Self::S_FIELD.extract(bits));
+        s.field("AssuredOnly", &Self::ASSUREDONLY_FIELD.extract(bits));
+        s.field("TopLevel", &Self::TOPLEVEL_FIELD.extract(bits));
+        s.field("Overlay", &Self::OVERLAY_FIELD.extract(bits));
+        s.field("DirtyBit", &Self::DIRTYBIT_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for ParEl1Fault {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "ParEl1Fault {{ F: {=u64}, FST: {=u64}, PTW: {=u64}, S: {=u64}, AssuredOnly: {=u64}, TopLevel: {=u64}, Overlay: {=u64}, DirtyBit: {=u64} }}",
+            Self::F_FIELD.extract(self.bits()),
+            Self::FST_FIELD.extract(self.bits()),
+            Self::PTW_FIELD.extract(self.bits()),
+            Self::S_FIELD.extract(self.bits()),
+            Self::ASSUREDONLY_FIELD.extract(self.bits()),
+            Self::TOPLEVEL_FIELD.extract(self.bits()),
+            Self::OVERLAY_FIELD.extract(self.bits()),
+            Self::DIRTYBIT_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for ParEl1Fault {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for ParEl1Fault {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl ParEl1Fault {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("F"),
+                old: Self::F_FIELD.extract(self.bits()),
+                new: Self::F_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FST"),
+                old: Self::FST_FIELD.extract(self.bits()),
+                new: Self::FST_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PTW"),
+                old: Self::PTW_FIELD.extract(self.bits()),
+                new: Self::PTW_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("S"),
+                old: Self::S_FIELD.extract(self.bits()),
+                new: Self::S_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("AssuredOnly"),
+                old: Self::ASSUREDONLY_FIELD.extract(self.bits()),
+                new: Self::ASSUREDONLY_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TopLevel"),
+                old: Self::TOPLEVEL_FIELD.extract(self.bits()),
+                new: Self::TOPLEVEL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("Overlay"),
+                old: Self::OVERLAY_FIELD.extract(self.bits()),
+                new: Self::OVERLAY_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DirtyBit"),
+                old: Self::DIRTYBIT_FIELD.extract(self.bits()),
+                new: Self::DIRTYBIT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_0000_1100_1000_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_0000_1100_1000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl ParEl1Fault {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_0000_0100_0000_0000;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b1000_0000_0000;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl ParEl1Fault {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl ParEl1 {
+    /// Returns the value as a `ParEl1Success` if `F` is 0, or `None` if it has another layout.
+    pub const fn as_success(self) -> Option<ParEl1Success> {
+        if Self::F_FIELD.extract(self.bits()) == 0 {
+            Some(ParEl1Success::from_bits_retain(self.bits()))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value as a `ParEl1Fault` if `F` is 1, or `None` if it has another layout.
+    pub const fn as_fault(self) -> Option<ParEl1Fault> {
+        if Self::F_FIELD.extract(self.bits()) == 1 {
+            Some(ParEl1Fault::from_bits_retain(self.bits()))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl From<ParEl1Success> for ParEl1 {
+    fn from(value: ParEl1Success) -> Self {
+        Self::from_bits_retain(value.bits())
+    }
+}
+
+#[cfg(feature = "el1")]
+impl From<ParEl1Fault> for ParEl1 {
+    fn from(value: ParEl1Fault) -> Self {
+        Self::from_bits_retain(value.bits())
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `PIRE0_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct Pire0El1: u64 {
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Pire0El1 {
+    /// Offset of the `Perm<m>` field.
+    pub const PERM_SHIFT: u32 = 0;
+    /// Mask for the `Perm<m>` field.
+    pub const PERM_MASK: u64 = 0b1111;
+
+    /// Returns the value of the given `Perm<m>` field.
+    pub const fn perm(self, m: u32) -> u8 {
+        assert!(m < 16);
+        ((self.bits() >> (Self::PERM_SHIFT + m * 4)) & Self::PERM_MASK) as u8
+    }
+
+    /// Sets the value of the `Perm<m>` field.
+    pub const fn set_perm(&mut self, m: u32, value: u8) {
+        assert!(m < 16);
+        let offset = Self::PERM_SHIFT + m * 4;
+        assert!(value & (Self::PERM_MASK as u8) == value);
+        *self = Self::from_bits_retain(
+            (self.bits() & !(Self::PERM_MASK << offset)) | ((value as u64) << offset),
+        );
+    }
+
+    /// Returns a copy with the `Perm<m>` field set to the given value.
+    pub const fn with_perm(mut self, m: u32, value: u8) -> Self {
+        self.set_perm(m, value);
+        self
+    }
+
+    /// Sets the value of the `Perm<m>` field, or returns an error if the value doesn't fit or the
+    /// index is out of range.
+    pub const fn try_set_perm(&mut self, m: u32, value: u8) -> Result<(), FieldOverflow> {
+        if m >= 16 || value & (Self::PERM_MASK as u8) != value {
+            return Err(FieldOverflow {
+                register: "PIRE0_EL1",
+                field: "Perm",
+                index: Some(m),
+                value: value as u64,
+            });
+        }
+        self.set_perm(m, value);
+        Ok(())
+    }
+
+    /// Returns a copy with the `Perm<m>` field set to the given value, or an error if the value
+    /// doesn't fit or the index is out of range.
+    pub const fn try_with_perm(mut self, m: u32, value: u8) -> Result<Self, FieldOverflow> {
+        match self.try_set_perm(m, value) {
+            Ok(()) => Ok(self),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for Pire0El1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("Pire0El1");
+        let bits = self.bits();
+        s.field("Perm0", &((bits >> Self::PERM_SHIFT) & Self::PERM_MASK));
+        s.field(
+            "Perm1",
+            &((bits >> (Self::PERM_SHIFT + 4)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm2",
+            &((bits >> (Self::PERM_SHIFT + 8)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm3",
+            &((bits >> (Self::PERM_SHIFT + 12)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm4",
+            &((bits >> (Self::PERM_SHIFT + 16)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm5",
+            &((bits >> (Self::PERM_SHIFT + 20)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm6",
+            &((bits >> (Self::PERM_SHIFT + 24)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm7",
+            &((bits >> (Self::PERM_SHIFT + 28)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm8",
+            &((bits >> (Self::PERM_SHIFT + 32)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm9",
+            &((bits >> (Self::PERM_SHIFT + 36)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm10",
+            &((bits >> (Self::PERM_SHIFT + 40)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm11",
+            &((bits >> (Self::PERM_SHIFT + 44)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm12",
+            &((bits >> (Self::PERM_SHIFT + 48)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm13",
+            &((bits >> (Self::PERM_SHIFT + 52)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm14",
+            &((bits >> (Self::PERM_SHIFT + 56)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm15",
+            &((bits >> (Self::PERM_SHIFT + 60)) & Self::PERM_MASK),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for Pire0El1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "Pire0El1 {{ Perm0: {=u64}, Perm1: {=u64}, Perm2: {=u64}, Perm3: {=u64}, Perm4: {=u64}, Perm5: {=u64}, Perm6: {=u64}, Perm7: {=u64}, Perm8: {=u64}, Perm9: {=u64}, Perm10: {=u64}, Perm11: {=u64}, Perm12: {=u64}, Perm13: {=u64}, Perm14: {=u64}, Perm15: {=u64} }}",
+            (self.bits() >> Self::PERM_SHIFT) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 4)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 8)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 12)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 16)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 20)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 24)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 28)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 32)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 36)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 40)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 44)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 48)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 52)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 56)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 60)) & Self::PERM_MASK
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for Pire0El1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for Pire0El1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Pire0El1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("Perm0"),
+                old: (self.bits() >> Self::PERM_SHIFT) & Self::PERM_MASK,
+                new: (other.bits() >> Self::PERM_SHIFT) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm1"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 4)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 4)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm2"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 8)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 8)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm3"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 12)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 12)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm4"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 16)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 16)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm5"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 20)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 20)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm6"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 24)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 24)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm7"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 28)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 28)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm8"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 32)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 32)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm9"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 36)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 36)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm10"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 40)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 40)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm11"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 44)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 44)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm12"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 48)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 48)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm13"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 52)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 52)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm14"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 56)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 56)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm15"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 60)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 60)) & Self::PERM_MASK,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Pire0El1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Pire0El1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                (self.bits() >> Self::PERM_SHIFT) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1pie),
+                UnimplementedField {
+                    register: "PIRE0_EL1",
+                    field: "Perm0",
+                    requires: "FEAT_S1PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 4)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1pie),
+                UnimplementedField {
+                    register: "PIRE0_EL1",
+                    field: "Perm1",
+                    requires: "FEAT_S1PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 8)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1pie),
+                UnimplementedField {
+                    register: "PIRE0_EL1",
+                    field: "Perm2",
+                    requires: "FEAT_S1PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 12)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1pie),
+                UnimplementedField {
+                    register: "PIRE0_EL1",
+                    field: "Perm3",
+                    requires: "FEAT_S1PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 16)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1pie),
+                UnimplementedField {
+                    register: "PIRE0_EL1",
+                    field: "Perm4",
+                    requires: "FEAT_S1PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 20)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1pie),
+                UnimplementedField {
+                    register: "PIRE0_EL1",
+                    field: "Perm5",
+                    requires: "FEAT_S1PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 24)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1pie),
+                UnimplementedField {
+                    register: "PIRE0_EL1",
+                    field: "Perm6",
+                    requires: "FEAT_S1PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 28)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1pie),
+                UnimplementedField {
+                    register: "PIRE0_EL1",
+                    field: "Perm7",
+                    requires: "FEAT_S1PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 32)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1pie),
+                UnimplementedField {
+                    register: "PIRE0_EL1",
+                    field: "Perm8",
+                    requires: "FEAT_S1PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 36)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1pie),
+                UnimplementedField {
+                    register: "PIRE0_EL1",
+                    field: "Perm9",
+                    requires: "FEAT_S1PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 40)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1pie),
+                UnimplementedField {
+                    register: "PIRE0_EL1",
+                    field: "Perm10",
+                    requires: "FEAT_S1PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 44)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1pie),
+                UnimplementedField {
+                    register: "PIRE0_EL1",
+                    field: "Perm11",
+                    requires: "FEAT_S1PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 48)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1pie),
+                UnimplementedField {
+                    register: "PIRE0_EL1",
+                    field: "Perm12",
+                    requires: "FEAT_S1PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 52)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1pie),
+                UnimplementedField {
+                    register: "PIRE0_EL1",
+                    field: "Perm13",
+                    requires: "FEAT_S1PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 56)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1pie),
+                UnimplementedField {
+                    register: "PIRE0_EL1",
+                    field: "Perm14",
+                    requires: "FEAT_S1PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 60)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1pie),
+                UnimplementedField {
+                    register: "PIRE0_EL1",
+                    field: "Perm15",
+                    requires: "FEAT_S1PIE",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `PIRE0_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct Pire0El2: u64 {
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Pire0El2 {
+    /// Offset of the `Perm<m>` field.
+    pub const PERM_SHIFT: u32 = 0;
+    /// Mask for the `Perm<m>` field.
+    pub const PERM_MASK: u64 = 0b1111;
+
+    /// Returns the value of the given `Perm<m>` field.
+    pub const fn perm(self, m: u32) -> u8 {
+        assert!(m < 16);
+        ((self.bits() >> (Self::PERM_SHIFT + m * 4)) & Self::PERM_MASK) as u8
+    }
+
+    /// Sets the value of the `Perm<m>` field.
+    pub const fn set_perm(&mut self, m: u32, value: u8) {
+        assert!(m < 16);
+        let offset = Self::PERM_SHIFT + m * 4;
+        assert!(value & (Self::PERM_MASK as u8) == value);
+        *self = Self::from_bits_retain(
+            (self.bits() & !(Self::PERM_MASK << offset)) | ((value as u64) << offset),
+        );
+    }
+
+    /// Returns a copy with the `Perm<m>` field set to the given value.
+    pub const fn with_perm(mut self, m: u32, value: u8) -> Self {
+        self.set_perm(m, value);
+        self
+    }
+
+    /// Sets the value of the `Perm<m>` field, or returns an error if the value doesn't fit or the
+    /// index is out of range.
+    pub const fn try_set_perm(&mut self, m: u32, value: u8) -> Result<(), FieldOverflow> {
+        if m >= 16 || value & (Self::PERM_MASK as u8) != value {
+            return Err(FieldOverflow {
+                register: "PIRE0_EL2",
+                field: "Perm",
+                index: Some(m),
+                value: value as u64,
+            });
+        }
+        self.set_perm(m, value);
+        Ok(())
+    }
+
+    /// Returns a copy with the `Perm<m>` field set to the given value, or an error if the value
+    /// doesn't fit or the index is out of range.
+    pub const fn try_with_perm(mut self, m: u32, value: u8) -> Result<Self, FieldOverflow> {
+        match self.try_set_perm(m, value) {
+            Ok(()) => Ok(self),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Display for Pire0El2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("Pire0El2");
+        let bits = self.bits();
+        s.field("Perm0", &((bits >> Self::PERM_SHIFT) & Self::PERM_MASK));
+        s.field(
+            "Perm1",
+            &((bits >> (Self::PERM_SHIFT + 4)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm2",
+            &((bits >> (Self::PERM_SHIFT + 8)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm3",
+            &((bits >> (Self::PERM_SHIFT + 12)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm4",
+            &((bits >> (Self::PERM_SHIFT + 16)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm5",
+            &((bits >> (Self::PERM_SHIFT + 20)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm6",
+            &((bits >> (Self::PERM_SHIFT + 24)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm7",
+            &((bits >> (Self::PERM_SHIFT + 28)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm8",
+            &((bits >> (Self::PERM_SHIFT + 32)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm9",
+            &((bits >> (Self::PERM_SHIFT + 36)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm10",
+            &((bits >> (Self::PERM_SHIFT + 40)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm11",
+            &((bits >> (Self::PERM_SHIFT + 44)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm12",
+            &((bits >> (Self::PERM_SHIFT + 48)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm13",
+            &((bits >> (Self::PERM_SHIFT + 52)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm14",
+            &((bits >> (Self::PERM_SHIFT + 56)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm15",
+            &((bits >> (Self::PERM_SHIFT + 60)) & Self::PERM_MASK),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for Pire0El2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "Pire0El2 {{ Perm0: {=u64}, Perm1: {=u64}, Perm2: {=u64}, Perm3: {=u64}, Perm4: {=u64}, Perm5: {=u64}, Perm6: {=u64}, Perm7: {=u64}, Perm8: {=u64}, Perm9: {=u64}, Perm10: {=u64}, Perm11: {=u64}, Perm12: {=u64}, Perm13: {=u64}, Perm14: {=u64}, Perm15: {=u64} }}",
+            (self.bits() >> Self::PERM_SHIFT) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 4)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 8)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 12)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 16)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 20)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 24)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 28)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 32)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 36)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 40)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 44)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 48)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 52)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 56)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 60)) & Self::PERM_MASK
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for Pire0El2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for Pire0El2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Pire0El2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("Perm0"),
+                old: (self.bits() >> Self::PERM_SHIFT) & Self::PERM_MASK,
+                new: (other.bits() >> Self::PERM_SHIFT) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm1"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 4)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 4)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm2"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 8)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 8)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm3"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 12)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 12)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm4"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 16)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 16)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm5"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 20)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 20)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm6"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 24)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 24)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm7"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 28)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 28)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm8"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 32)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 32)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm9"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 36)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 36)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm10"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 40)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 40)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm11"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 44)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 44)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm12"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 48)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 48)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm13"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 52)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 52)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm14"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 56)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 56)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm15"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 60)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 60)) & Self::PERM_MASK,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Pire0El2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Pire0El2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                (self.bits() >> Self::PERM_SHIFT) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1pie),
+                UnimplementedField {
+                    register: "PIRE0_EL2",
+                    field: "Perm0",
+                    requires: "FEAT_S1PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 4)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1pie),
+                UnimplementedField {
+                    register: "PIRE0_EL2",
+                    field: "Perm1",
+                    requires: "FEAT_S1PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 8)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1pie),
+                UnimplementedField {
+                    register: "PIRE0_EL2",
+                    field: "Perm2",
+                    requires: "FEAT_S1PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 12)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1pie),
+                UnimplementedField {
+                    register: "PIRE0_EL2",
+                    field: "Perm3",
+                    requires: "FEAT_S1PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 16)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1pie),
+                UnimplementedField {
+                    register: "PIRE0_EL2",
+                    field: "Perm4",
+                    requires: "FEAT_S1PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 20)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1pie),
+                UnimplementedField {
+                    register: "PIRE0_EL2",
+                    field: "Perm5",
+                    requires: "FEAT_S1PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 24)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1pie),
+                UnimplementedField {
+                    register: "PIRE0_EL2",
+                    field: "Perm6",
+                    requires: "FEAT_S1PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 28)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1pie),
+                UnimplementedField {
+                    register: "PIRE0_EL2",
+                    field: "Perm7",
+                    requires: "FEAT_S1PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 32)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1pie),
+                UnimplementedField {
+                    register: "PIRE0_EL2",
+                    field: "Perm8",
+                    requires: "FEAT_S1PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 36)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1pie),
+                UnimplementedField {
+                    register: "PIRE0_EL2",
+                    field: "Perm9",
+                    requires: "FEAT_S1PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 40)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1pie),
+                UnimplementedField {
+                    register: "PIRE0_EL2",
+                    field: "Perm10",
+                    requires: "FEAT_S1PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 44)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1pie),
+                UnimplementedField {
+                    register: "PIRE0_EL2",
+                    field: "Perm11",
+                    requires: "FEAT_S1PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 48)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1pie),
+                UnimplementedField {
+                    register: "PIRE0_EL2",
+                    field: "Perm12",
+                    requires: "FEAT_S1PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 52)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1pie),
+                UnimplementedField {
+                    register: "PIRE0_EL2",
+                    field: "Perm13",
+                    requires: "FEAT_S1PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 56)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1pie),
+                UnimplementedField {
+                    register: "PIRE0_EL2",
+                    field: "Perm14",
+                    requires: "FEAT_S1PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 60)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1pie),
+                UnimplementedField {
+                    register: "PIRE0_EL2",
+                    field: "Perm15",
+                    requires: "FEAT_S1PIE",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(feature = "el1")]
+/// `PIR_EL1` system register value.
+pub type PirEl1 = Pire0El1;
+
+#[cfg(feature = "el2")]
+/// `PIR_EL2` system register value.
+pub type PirEl2 = Pire0El2;
+
+#[cfg(feature = "el3")]
+bitflags! {
+    /// `PIR_EL3` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct PirEl3: u64 {
+    }
+}
+
+#[cfg(feature = "el3")]
+impl PirEl3 {
+    /// Offset of the `Perm<m>` field.
+    pub const PERM_SHIFT: u32 = 0;
+    /// Mask for the `Perm<m>` field.
+    pub const PERM_MASK: u64 = 0b1111;
+
+    /// Returns the value of the given `Perm<m>` field.
+    pub const fn perm(self, m: u32) -> u8 {
+        assert!(m < 16);
+        ((self.bits() >> (Self::PERM_SHIFT + m * 4)) & Self::PERM_MASK) as u8
+    }
+
+    /// Sets the value of the `Perm<m>` field.
+    pub const fn set_perm(&mut self, m: u32, value: u8) {
+        assert!(m < 16);
+        let offset = Self::PERM_SHIFT + m * 4;
+        assert!(value & (Self::PERM_MASK as u8) == value);
+        *self = Self::from_bits_retain(
+            (self.bits() & !(Self::PERM_MASK << offset)) | ((value as u64) << offset),
+        );
+    }
+
+    /// Returns a copy with the `Perm<m>` field set to the given value.
+    pub const fn with_perm(mut self, m: u32, value: u8) -> Self {
+        self.set_perm(m, value);
+        self
+    }
+
+    /// Sets the value of the `Perm<m>` field, or returns an error if the value doesn't fit or the
+    /// index is out of range.
+    pub const fn try_set_perm(&mut self, m: u32, value: u8) -> Result<(), FieldOverflow> {
+        if m >= 16 || value & (Self::PERM_MASK as u8) != value {
+            return Err(FieldOverflow {
+                register: "PIR_EL3",
+                field: "Perm",
+                index: Some(m),
+                value: value as u64,
+            });
+        }
+        self.set_perm(m, value);
+        Ok(())
+    }
+
+    /// Returns a copy with the `Perm<m>` field set to the given value, or an error if the value
+    /// doesn't fit or the index is out of range.
+    pub const fn try_with_perm(mut self, m: u32, value: u8) -> Result<Self, FieldOverflow> {
+        match self.try_set_perm(m, value) {
+            Ok(()) => Ok(self),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el3")]
+impl Display for PirEl3 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("PirEl3");
+        let bits = self.bits();
+        s.field("Perm0", &((bits >> Self::PERM_SHIFT) & Self::PERM_MASK));
+        s.field(
+            "Perm1",
+            &((bits >> (Self::PERM_SHIFT + 4)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm2",
+            &((bits >> (Self::PERM_SHIFT + 8)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm3",
+            &((bits >> (Self::PERM_SHIFT + 12)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm4",
+            &((bits >> (Self::PERM_SHIFT + 16)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm5",
+            &((bits >> (Self::PERM_SHIFT + 20)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm6",
+            &((bits >> (Self::PERM_SHIFT + 24)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm7",
+            &((bits >> (Self::PERM_SHIFT + 28)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm8",
+            &((bits >> (Self::PERM_SHIFT + 32)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm9",
+            &((bits >> (Self::PERM_SHIFT + 36)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm10",
+            &((bits >> (Self::PERM_SHIFT + 40)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm11",
+            &((bits >> (Self::PERM_SHIFT + 44)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm12",
+            &((bits >> (Self::PERM_SHIFT + 48)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm13",
+            &((bits >> (Self::PERM_SHIFT + 52)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm14",
+            &((bits >> (Self::PERM_SHIFT + 56)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm15",
+            &((bits >> (Self::PERM_SHIFT + 60)) & Self::PERM_MASK),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el3")]
+impl defmt::Format for PirEl3 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "PirEl3 {{ Perm0: {=u64}, Perm1: {=u64}, Perm2: {=u64}, Perm3: {=u64}, Perm4: {=u64}, Perm5: {=u64}, Perm6: {=u64}, Perm7: {=u64}, Perm8: {=u64}, Perm9: {=u64}, Perm10: {=u64}, Perm11: {=u64}, Perm12: {=u64}, Perm13: {=u64}, Perm14: {=u64}, Perm15: {=u64} }}",
+            (self.bits() >> Self::PERM_SHIFT) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 4)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 8)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 12)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 16)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 20)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 24)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 28)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 32)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 36)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 40)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 44)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 48)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 52)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 56)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 60)) & Self::PERM_MASK
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el3")]
+impl serde::Serialize for PirEl3 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el3")]
+impl<'de> serde::Deserialize<'de> for PirEl3 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el3")]
+impl PirEl3 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("Perm0"),
+                old: (self.bits() >> Self::PERM_SHIFT) & Self::PERM_MASK,
+                new: (other.bits() >> Self::PERM_SHIFT) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm1"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 4)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 4)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm2"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 8)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 8)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm3"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 12)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 12)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm4"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 16)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 16)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm5"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 20)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 20)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm6"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 24)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 24)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm7"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 28)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 28)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm8"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 32)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 32)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm9"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 36)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 36)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm10"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 40)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 40)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm11"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 44)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 44)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm12"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 48)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 48)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm13"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 52)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 52)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm14"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 56)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 56)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm15"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 60)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 60)) & Self::PERM_MASK,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el3")]
+impl PirEl3 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el3")]
+impl PirEl3 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                (self.bits() >> Self::PERM_SHIFT) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1pie),
+                UnimplementedField {
+                    register: "PIR_EL3",
+                    field: "Perm0",
+                    requires: "FEAT_S1PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 4)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1pie),
+                UnimplementedField {
+                    register: "PIR_EL3",
+                    field: "Perm1",
+                    requires: "FEAT_S1PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 8)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1pie),
+                UnimplementedField {
+                    register: "PIR_EL3",
+                    field: "Perm2",
+                    requires: "FEAT_S1PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 12)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1pie),
+                UnimplementedField {
+                    register: "PIR_EL3",
+                    field: "Perm3",
+                    requires: "FEAT_S1PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 16)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1pie),
+                UnimplementedField {
+                    register: "PIR_EL3",
+                    field: "Perm4",
+                    requires: "FEAT_S1PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 20)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1pie),
+                UnimplementedField {
+                    register: "PIR_EL3",
+                    field: "Perm5",
+                    requires: "FEAT_S1PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 24)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1pie),
+                UnimplementedField {
+                    register: "PIR_EL3",
+                    field: "Perm6",
+                    requires: "FEAT_S1PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 28)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1pie),
+                UnimplementedField {
+                    register: "PIR_EL3",
+                    field: "Perm7",
+                    requires: "FEAT_S1PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 32)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1pie),
+                UnimplementedField {
+                    register: "PIR_EL3",
+                    field: "Perm8",
+                    requires: "FEAT_S1PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 36)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1pie),
+                UnimplementedField {
+                    register: "PIR_EL3",
+                    field: "Perm9",
+                    requires: "FEAT_S1PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 40)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1pie),
+                UnimplementedField {
+                    register: "PIR_EL3",
+                    field: "Perm10",
+                    requires: "FEAT_S1PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 44)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1pie),
+                UnimplementedField {
+                    register: "PIR_EL3",
+                    field: "Perm11",
+                    requires: "FEAT_S1PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 48)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1pie),
+                UnimplementedField {
+                    register: "PIR_EL3",
+                    field: "Perm12",
+                    requires: "FEAT_S1PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 52)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1pie),
+                UnimplementedField {
+                    register: "PIR_EL3",
+                    field: "Perm13",
+                    requires: "FEAT_S1PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 56)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1pie),
+                UnimplementedField {
+                    register: "PIR_EL3",
+                    field: "Perm14",
+                    requires: "FEAT_S1PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 60)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1pie),
+                UnimplementedField {
+                    register: "PIR_EL3",
+                    field: "Perm15",
+                    requires: "FEAT_S1PIE",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+bitflags! {
+    /// `POR_EL0` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct PorEl0: u64 {
+    }
+}
+
+impl PorEl0 {
+    /// Offset of the `Perm<m>` field.
+    pub const PERM_SHIFT: u32 = 0;
+    /// Mask for the `Perm<m>` field.
+    pub const PERM_MASK: u64 = 0b1111;
+
+    /// Returns the value of the given `Perm<m>` field.
+    pub const fn perm(self, m: u32) -> u8 {
+        assert!(m < 16);
+        ((self.bits() >> (Self::PERM_SHIFT + m * 4)) & Self::PERM_MASK) as u8
+    }
+
+    /// Sets the value of the `Perm<m>` field.
+    pub const fn set_perm(&mut self, m: u32, value: u8) {
+        assert!(m < 16);
+        let offset = Self::PERM_SHIFT + m * 4;
+        assert!(value & (Self::PERM_MASK as u8) == value);
+        *self = Self::from_bits_retain(
+            (self.bits() & !(Self::PERM_MASK << offset)) | ((value as u64) << offset),
+        );
+    }
+
+    /// Returns a copy with the `Perm<m>` field set to the given value.
+    pub const fn with_perm(mut self, m: u32, value: u8) -> Self {
+        self.set_perm(m, value);
+        self
+    }
+
+    /// Sets the value of the `Perm<m>` field, or returns an error if the value doesn't fit or the
+    /// index is out of range.
+    pub const fn try_set_perm(&mut self, m: u32, value: u8) -> Result<(), FieldOverflow> {
+        if m >= 16 || value & (Self::PERM_MASK as u8) != value {
+            return Err(FieldOverflow {
+                register: "POR_EL0",
+                field: "Perm",
+                index: Some(m),
+                value: value as u64,
+            });
+        }
+        self.set_perm(m, value);
+        Ok(())
+    }
+
+    /// Returns a copy with the `Perm<m>` field set to the given value, or an error if the value
+    /// doesn't fit or the index is out of range.
+    pub const fn try_with_perm(mut self, m: u32, value: u8) -> Result<Self, FieldOverflow> {
+        match self.try_set_perm(m, value) {
+            Ok(()) => Ok(self),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl Display for PorEl0 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("PorEl0");
+        let bits = self.bits();
+        s.field("Perm0", &((bits >> Self::PERM_SHIFT) & Self::PERM_MASK));
+        s.field(
+            "Perm1",
+            &((bits >> (Self::PERM_SHIFT + 4)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm2",
+            &((bits >> (Self::PERM_SHIFT + 8)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm3",
+            &((bits >> (Self::PERM_SHIFT + 12)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm4",
+            &((bits >> (Self::PERM_SHIFT + 16)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm5",
+            &((bits >> (Self::PERM_SHIFT + 20)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm6",
+            &((bits >> (Self::PERM_SHIFT + 24)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm7",
+            &((bits >> (Self::PERM_SHIFT + 28)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm8",
+            &((bits >> (Self::PERM_SHIFT + 32)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm9",
+            &((bits >> (Self::PERM_SHIFT + 36)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm10",
+            &((bits >> (Self::PERM_SHIFT + 40)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm11",
+            &((bits >> (Self::PERM_SHIFT + 44)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm12",
+            &((bits >> (Self::PERM_SHIFT + 48)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm13",
+            &((bits >> (Self::PERM_SHIFT + 52)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm14",
+            &((bits >> (Self::PERM_SHIFT + 56)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm15",
+            &((bits >> (Self::PERM_SHIFT + 60)) & Self::PERM_MASK),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for PorEl0 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "PorEl0 {{ Perm0: {=u64}, Perm1: {=u64}, Perm2: {=u64}, Perm3: {=u64}, Perm4: {=u64}, Perm5: {=u64}, Perm6: {=u64}, Perm7: {=u64}, Perm8: {=u64}, Perm9: {=u64}, Perm10: {=u64}, Perm11: {=u64}, Perm12: {=u64}, Perm13: {=u64}, Perm14: {=u64}, Perm15: {=u64} }}",
+            (self.bits() >> Self::PERM_SHIFT) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 4)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 8)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 12)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 16)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 20)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 24)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 28)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 32)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 36)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 40)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 44)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 48)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 52)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 56)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 60)) & Self::PERM_MASK
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for PorEl0 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for PorEl0 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+impl PorEl0 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("Perm0"),
+                old: (self.bits() >> Self::PERM_SHIFT) & Self::PERM_MASK,
+                new: (other.bits() >> Self::PERM_SHIFT) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm1"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 4)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 4)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm2"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 8)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 8)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm3"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 12)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 12)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm4"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 16)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 16)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm5"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 20)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 20)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm6"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 24)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 24)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm7"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 28)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 28)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm8"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 32)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 32)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm9"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 36)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 36)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm10"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 40)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 40)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm11"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 44)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 44)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm12"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 48)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 48)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm13"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 52)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 52)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm14"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 56)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 56)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm15"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 60)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 60)) & Self::PERM_MASK,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+impl PorEl0 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+impl PorEl0 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                (self.bits() >> Self::PERM_SHIFT) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL0",
+                    field: "Perm0",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 4)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL0",
+                    field: "Perm1",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 8)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL0",
+                    field: "Perm2",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 12)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL0",
+                    field: "Perm3",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 16)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL0",
+                    field: "Perm4",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 20)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL0",
+                    field: "Perm5",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 24)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL0",
+                    field: "Perm6",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 28)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL0",
+                    field: "Perm7",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 32)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL0",
+                    field: "Perm8",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 36)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL0",
+                    field: "Perm9",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 40)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL0",
+                    field: "Perm10",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 44)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL0",
+                    field: "Perm11",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 48)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL0",
+                    field: "Perm12",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 52)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL0",
+                    field: "Perm13",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 56)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL0",
+                    field: "Perm14",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 60)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL0",
+                    field: "Perm15",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `POR_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct PorEl1: u64 {
+    }
+}
+
+#[cfg(feature = "el1")]
+impl PorEl1 {
+    /// Offset of the `Perm<m>` field.
+    pub const PERM_SHIFT: u32 = 0;
+    /// Mask for the `Perm<m>` field.
+    pub const PERM_MASK: u64 = 0b1111;
+
+    /// Returns the value of the given `Perm<m>` field.
+    pub const fn perm(self, m: u32) -> u8 {
+        assert!(m < 16);
+        ((self.bits() >> (Self::PERM_SHIFT + m * 4)) & Self::PERM_MASK) as u8
+    }
+
+    /// Sets the value of the `Perm<m>` field.
+    pub const fn set_perm(&mut self, m: u32, value: u8) {
+        assert!(m < 16);
+        let offset = Self::PERM_SHIFT + m * 4;
+        assert!(value & (Self::PERM_MASK as u8) == value);
+        *self = Self::from_bits_retain(
+            (self.bits() & !(Self::PERM_MASK << offset)) | ((value as u64) << offset),
+        );
+    }
+
+    /// Returns a copy with the `Perm<m>` field set to the given value.
+    pub const fn with_perm(mut self, m: u32, value: u8) -> Self {
+        self.set_perm(m, value);
+        self
+    }
+
+    /// Sets the value of the `Perm<m>` field, or returns an error if the value doesn't fit or the
+    /// index is out of range.
+    pub const fn try_set_perm(&mut self, m: u32, value: u8) -> Result<(), FieldOverflow> {
+        if m >= 16 || value & (Self::PERM_MASK as u8) != value {
+            return Err(FieldOverflow {
+                register: "POR_EL1",
+                field: "Perm",
+                index: Some(m),
+                value: value as u64,
+            });
+        }
+        self.set_perm(m, value);
+        Ok(())
+    }
+
+    /// Returns a copy with the `Perm<m>` field set to the given value, or an error if the value
+    /// doesn't fit or the index is out of range.
+    pub const fn try_with_perm(mut self, m: u32, value: u8) -> Result<Self, FieldOverflow> {
+        match self.try_set_perm(m, value) {
+            Ok(()) => Ok(self),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for PorEl1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("PorEl1");
+        let bits = self.bits();
+        s.field("Perm0", &((bits >> Self::PERM_SHIFT) & Self::PERM_MASK));
+        s.field(
+            "Perm1",
+            &((bits >> (Self::PERM_SHIFT + 4)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm2",
+            &((bits >> (Self::PERM_SHIFT + 8)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm3",
+            &((bits >> (Self::PERM_SHIFT + 12)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm4",
+            &((bits >> (Self::PERM_SHIFT + 16)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm5",
+            &((bits >> (Self::PERM_SHIFT + 20)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm6",
+            &((bits >> (Self::PERM_SHIFT + 24)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm7",
+            &((bits >> (Self::PERM_SHIFT + 28)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm8",
+            &((bits >> (Self::PERM_SHIFT + 32)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm9",
+            &((bits >> (Self::PERM_SHIFT + 36)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm10",
+            &((bits >> (Self::PERM_SHIFT + 40)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm11",
+            &((bits >> (Self::PERM_SHIFT + 44)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm12",
+            &((bits >> (Self::PERM_SHIFT + 48)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm13",
+            &((bits >> (Self::PERM_SHIFT + 52)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm14",
+            &((bits >> (Self::PERM_SHIFT + 56)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm15",
+            &((bits >> (Self::PERM_SHIFT + 60)) & Self::PERM_MASK),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for PorEl1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "PorEl1 {{ Perm0: {=u64}, Perm1: {=u64}, Perm2: {=u64}, Perm3: {=u64}, Perm4: {=u64}, Perm5: {=u64}, Perm6: {=u64}, Perm7: {=u64}, Perm8: {=u64}, Perm9: {=u64}, Perm10: {=u64}, Perm11: {=u64}, Perm12: {=u64}, Perm13: {=u64}, Perm14: {=u64}, Perm15: {=u64} }}",
+            (self.bits() >> Self::PERM_SHIFT) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 4)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 8)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 12)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 16)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 20)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 24)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 28)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 32)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 36)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 40)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 44)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 48)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 52)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 56)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 60)) & Self::PERM_MASK
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for PorEl1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for PorEl1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl PorEl1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("Perm0"),
+                old: (self.bits() >> Self::PERM_SHIFT) & Self::PERM_MASK,
+                new: (other.bits() >> Self::PERM_SHIFT) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm1"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 4)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 4)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm2"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 8)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 8)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm3"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 12)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 12)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm4"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 16)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 16)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm5"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 20)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 20)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm6"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 24)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 24)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm7"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 28)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 28)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm8"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 32)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 32)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm9"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 36)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 36)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm10"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 40)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 40)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm11"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 44)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 44)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm12"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 48)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 48)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm13"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 52)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 52)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm14"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 56)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 56)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm15"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 60)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 60)) & Self::PERM_MASK,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl PorEl1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl PorEl1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                (self.bits() >> Self::PERM_SHIFT) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL1",
+                    field: "Perm0",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 4)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL1",
+                    field: "Perm1",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 8)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL1",
+                    field: "Perm2",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 12)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL1",
+                    field: "Perm3",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 16)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL1",
+                    field: "Perm4",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 20)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL1",
+                    field: "Perm5",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 24)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL1",
+                    field: "Perm6",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 28)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL1",
+                    field: "Perm7",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 32)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL1",
+                    field: "Perm8",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 36)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL1",
+                    field: "Perm9",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 40)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL1",
+                    field: "Perm10",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 44)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL1",
+                    field: "Perm11",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 48)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL1",
+                    field: "Perm12",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 52)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL1",
+                    field: "Perm13",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 56)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL1",
+                    field: "Perm14",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 60)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL1",
+                    field: "Perm15",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `POR_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct PorEl2: u64 {
+    }
+}
+
+#[cfg(feature = "el2")]
+impl PorEl2 {
+    /// Offset of the `Perm<m>` field.
+    pub const PERM_SHIFT: u32 = 0;
+    /// Mask for the `Perm<m>` field.
+    pub const PERM_MASK: u64 = 0b1111;
+
+    /// Returns the value of the given `Perm<m>` field.
+    pub const fn perm(self, m: u32) -> u8 {
+        assert!(m < 16);
+        ((self.bits() >> (Self::PERM_SHIFT + m * 4)) & Self::PERM_MASK) as u8
+    }
+
+    /// Sets the value of the `Perm<m>` field.
+    pub const fn set_perm(&mut self, m: u32, value: u8) {
+        assert!(m < 16);
+        let offset = Self::PERM_SHIFT + m * 4;
+        assert!(value & (Self::PERM_MASK as u8) == value);
+        *self = Self::from_bits_retain(
+            (self.bits() & !(Self::PERM_MASK << offset)) | ((value as u64) << offset),
+        );
+    }
+
+    /// Returns a copy with the `Perm<m>` field set to the given value.
+    pub const fn with_perm(mut self, m: u32, value: u8) -> Self {
+        self.set_perm(m, value);
+        self
+    }
+
+    /// Sets the value of the `Perm<m>` field, or returns an error if the value doesn't fit or the
+    /// index is out of range.
+    pub const fn try_set_perm(&mut self, m: u32, value: u8) -> Result<(), FieldOverflow> {
+        if m >= 16 || value & (Self::PERM_MASK as u8) != value {
+            return Err(FieldOverflow {
+                register: "POR_EL2",
+                field: "Perm",
+                index: Some(m),
+                value: value as u64,
+            });
+        }
+        self.set_perm(m, value);
+        Ok(())
+    }
+
+    /// Returns a copy with the `Perm<m>` field set to the given value, or an error if the value
+    /// doesn't fit or the index is out of range.
+    pub const fn try_with_perm(mut self, m: u32, value: u8) -> Result<Self, FieldOverflow> {
+        match self.try_set_perm(m, value) {
+            Ok(()) => Ok(self),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Display for PorEl2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("PorEl2");
+        let bits = self.bits();
+        s.field("Perm0", &((bits >> Self::PERM_SHIFT) & Self::PERM_MASK));
+        s.field(
+            "Perm1",
+            &((bits >> (Self::PERM_SHIFT + 4)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm2",
+            &((bits >> (Self::PERM_SHIFT + 8)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm3",
+            &((bits >> (Self::PERM_SHIFT + 12)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm4",
+            &((bits >> (Self::PERM_SHIFT + 16)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm5",
+            &((bits >> (Self::PERM_SHIFT + 20)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm6",
+            &((bits >> (Self::PERM_SHIFT + 24)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm7",
+            &((bits >> (Self::PERM_SHIFT + 28)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm8",
+            &((bits >> (Self::PERM_SHIFT + 32)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm9",
+            &((bits >> (Self::PERM_SHIFT + 36)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm10",
+            &((bits >> (Self::PERM_SHIFT + 40)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm11",
+            &((bits >> (Self::PERM_SHIFT + 44)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm12",
+            &((bits >> (Self::PERM_SHIFT + 48)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm13",
+            &((bits >> (Self::PERM_SHIFT + 52)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm14",
+            &((bits >> (Self::PERM_SHIFT + 56)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm15",
+            &((bits >> (Self::PERM_SHIFT + 60)) & Self::PERM_MASK),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for PorEl2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "PorEl2 {{ Perm0: {=u64}, Perm1: {=u64}, Perm2: {=u64}, Perm3: {=u64}, Perm4: {=u64}, Perm5: {=u64}, Perm6: {=u64}, Perm7: {=u64}, Perm8: {=u64}, Perm9: {=u64}, Perm10: {=u64}, Perm11: {=u64}, Perm12: {=u64}, Perm13: {=u64}, Perm14: {=u64}, Perm15: {=u64} }}",
+            (self.bits() >> Self::PERM_SHIFT) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 4)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 8)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 12)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 16)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 20)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 24)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 28)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 32)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 36)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 40)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 44)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 48)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 52)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 56)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 60)) & Self::PERM_MASK
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for PorEl2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for PorEl2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl PorEl2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("Perm0"),
+                old: (self.bits() >> Self::PERM_SHIFT) & Self::PERM_MASK,
+                new: (other.bits() >> Self::PERM_SHIFT) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm1"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 4)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 4)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm2"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 8)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 8)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm3"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 12)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 12)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm4"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 16)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 16)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm5"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 20)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 20)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm6"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 24)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 24)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm7"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 28)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 28)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm8"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 32)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 32)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm9"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 36)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 36)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm10"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 40)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 40)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm11"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 44)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 44)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm12"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 48)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 48)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm13"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 52)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 52)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm14"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 56)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 56)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm15"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 60)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 60)) & Self::PERM_MASK,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl PorEl2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl PorEl2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                (self.bits() >> Self::PERM_SHIFT) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL2",
+                    field: "Perm0",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 4)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL2",
+                    field: "Perm1",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 8)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL2",
+                    field: "Perm2",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 12)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL2",
+                    field: "Perm3",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 16)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL2",
+                    field: "Perm4",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 20)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL2",
+                    field: "Perm5",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 24)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL2",
+                    field: "Perm6",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 28)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL2",
+                    field: "Perm7",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 32)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL2",
+                    field: "Perm8",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 36)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL2",
+                    field: "Perm9",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 40)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL2",
+                    field: "Perm10",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 44)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL2",
+                    field: "Perm11",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 48)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL2",
+                    field: "Perm12",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 52)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL2",
+                    field: "Perm13",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 56)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL2",
+                    field: "Perm14",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 60)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL2",
+                    field: "Perm15",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(feature = "el3")]
+bitflags! {
+    /// `POR_EL3` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct PorEl3: u64 {
+    }
+}
+
+#[cfg(feature = "el3")]
+impl PorEl3 {
+    /// Offset of the `Perm<m>` field.
+    pub const PERM_SHIFT: u32 = 0;
+    /// Mask for the `Perm<m>` field.
+    pub const PERM_MASK: u64 = 0b1111;
+
+    /// Returns the value of the given `Perm<m>` field.
+    pub const fn perm(self, m: u32) -> u8 {
+        assert!(m < 16);
+        ((self.bits() >> (Self::PERM_SHIFT + m * 4)) & Self::PERM_MASK) as u8
+    }
+
+    /// Sets the value of the `Perm<m>` field.
+    pub const fn set_perm(&mut self, m: u32, value: u8) {
+        assert!(m < 16);
+        let offset = Self::PERM_SHIFT + m * 4;
+        assert!(value & (Self::PERM_MASK as u8) == value);
+        *self = Self::from_bits_retain(
+            (self.bits() & !(Self::PERM_MASK << offset)) | ((value as u64) << offset),
+        );
+    }
+
+    /// Returns a copy with the `Perm<m>` field set to the given value.
+    pub const fn with_perm(mut self, m: u32, value: u8) -> Self {
+        self.set_perm(m, value);
+        self
+    }
+
+    /// Sets the value of the `Perm<m>` field, or returns an error if the value doesn't fit or the
+    /// index is out of range.
+    pub const fn try_set_perm(&mut self, m: u32, value: u8) -> Result<(), FieldOverflow> {
+        if m >= 16 || value & (Self::PERM_MASK as u8) != value {
+            return Err(FieldOverflow {
+                register: "POR_EL3",
+                field: "Perm",
+                index: Some(m),
+                value: value as u64,
+            });
+        }
+        self.set_perm(m, value);
+        Ok(())
+    }
+
+    /// Returns a copy with the `Perm<m>` field set to the given value, or an error if the value
+    /// doesn't fit or the index is out of range.
+    pub const fn try_with_perm(mut self, m: u32, value: u8) -> Result<Self, FieldOverflow> {
+        match self.try_set_perm(m, value) {
+            Ok(()) => Ok(self),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el3")]
+impl Display for PorEl3 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("PorEl3");
+        let bits = self.bits();
+        s.field("Perm0", &((bits >> Self::PERM_SHIFT) & Self::PERM_MASK));
+        s.field(
+            "Perm1",
+            &((bits >> (Self::PERM_SHIFT + 4)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm2",
+            &((bits >> (Self::PERM_SHIFT + 8)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm3",
+            &((bits >> (Self::PERM_SHIFT + 12)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm4",
+            &((bits >> (Self::PERM_SHIFT + 16)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm5",
+            &((bits >> (Self::PERM_SHIFT + 20)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm6",
+            &((bits >> (Self::PERM_SHIFT + 24)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm7",
+            &((bits >> (Self::PERM_SHIFT + 28)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm8",
+            &((bits >> (Self::PERM_SHIFT + 32)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm9",
+            &((bits >> (Self::PERM_SHIFT + 36)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm10",
+            &((bits >> (Self::PERM_SHIFT + 40)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm11",
+            &((bits >> (Self::PERM_SHIFT + 44)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm12",
+            &((bits >> (Self::PERM_SHIFT + 48)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm13",
+            &((bits >> (Self::PERM_SHIFT + 52)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm14",
+            &((bits >> (Self::PERM_SHIFT + 56)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm15",
+            &((bits >> (Self::PERM_SHIFT + 60)) & Self::PERM_MASK),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el3")]
+impl defmt::Format for PorEl3 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "PorEl3 {{ Perm0: {=u64}, Perm1: {=u64}, Perm2: {=u64}, Perm3: {=u64}, Perm4: {=u64}, Perm5: {=u64}, Perm6: {=u64}, Perm7: {=u64}, Perm8: {=u64}, Perm9: {=u64}, Perm10: {=u64}, Perm11: {=u64}, Perm12: {=u64}, Perm13: {=u64}, Perm14: {=u64}, Perm15: {=u64} }}",
+            (self.bits() >> Self::PERM_SHIFT) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 4)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 8)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 12)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 16)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 20)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 24)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 28)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 32)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 36)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 40)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 44)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 48)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 52)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 56)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 60)) & Self::PERM_MASK
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el3")]
+impl serde::Serialize for PorEl3 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el3")]
+impl<'de> serde::Deserialize<'de> for PorEl3 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el3")]
+impl PorEl3 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("Perm0"),
+                old: (self.bits() >> Self::PERM_SHIFT) & Self::PERM_MASK,
+                new: (other.bits() >> Self::PERM_SHIFT) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm1"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 4)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 4)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm2"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 8)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 8)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm3"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 12)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 12)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm4"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 16)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 16)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm5"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 20)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 20)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm6"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 24)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 24)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm7"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 28)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 28)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm8"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 32)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 32)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm9"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 36)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 36)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm10"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 40)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 40)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm11"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 44)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 44)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm12"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 48)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 48)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm13"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 52)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 52)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm14"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 56)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 56)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm15"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 60)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 60)) & Self::PERM_MASK,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el3")]
+impl PorEl3 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el3")]
+impl PorEl3 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                (self.bits() >> Self::PERM_SHIFT) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL3",
+                    field: "Perm0",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 4)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL3",
+                    field: "Perm1",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 8)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL3",
+                    field: "Perm2",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 12)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL3",
+                    field: "Perm3",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 16)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL3",
+                    field: "Perm4",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 20)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL3",
+                    field: "Perm5",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 24)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL3",
+                    field: "Perm6",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 28)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL3",
+                    field: "Perm7",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 32)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL3",
+                    field: "Perm8",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 36)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL3",
+                    field: "Perm9",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 40)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL3",
+                    field: "Perm10",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 44)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL3",
+                    field: "Perm11",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 48)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL3",
+                    field: "Perm12",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 52)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL3",
+                    field: "Perm13",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 56)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL3",
+                    field: "Perm14",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 60)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S1poe),
+                UnimplementedField {
+                    register: "POR_EL3",
+                    field: "Perm15",
+                    requires: "FEAT_S1POE",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `S2PIR_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct S2pirEl2: u64 {
+    }
+}
+
+#[cfg(feature = "el2")]
+impl S2pirEl2 {
+    /// Offset of the `Perm<m>` field.
+    pub const PERM_SHIFT: u32 = 0;
+    /// Mask for the `Perm<m>` field.
+    pub const PERM_MASK: u64 = 0b1111;
+
+    /// Returns the value of the given `Perm<m>` field.
+    pub const fn perm(self, m: u32) -> u8 {
+        assert!(m < 16);
+        ((self.bits() >> (Self::PERM_SHIFT + m * 4)) & Self::PERM_MASK) as u8
+    }
+
+    /// Sets the value of the `Perm<m>` field.
+    pub const fn set_perm(&mut self, m: u32, value: u8) {
+        assert!(m < 16);
+        let offset = Self::PERM_SHIFT + m * 4;
+        assert!(value & (Self::PERM_MASK as u8) == value);
+        *self = Self::from_bits_retain(
+            (self.bits() & !(Self::PERM_MASK << offset)) | ((value as u64) << offset),
+        );
+    }
+
+    /// Returns a copy with the `Perm<m>` field set to the given value.
+    pub const fn with_perm(mut self, m: u32, value: u8) -> Self {
+        self.set_perm(m, value);
+        self
+    }
+
+    /// Sets the value of the `Perm<m>` field, or returns an error if the value doesn't fit or the
+    /// index is out of range.
+    pub const fn try_set_perm(&mut self, m: u32, value: u8) -> Result<(), FieldOverflow> {
+        if m >= 16 || value & (Self::PERM_MASK as u8) != value {
+            return Err(FieldOverflow {
+                register: "S2PIR_EL2",
+                field: "Perm",
+                index: Some(m),
+                value: value as u64,
+            });
+        }
+        self.set_perm(m, value);
+        Ok(())
+    }
+
+    /// Returns a copy with the `Perm<m>` field set to the given value, or an error if the value
+    /// doesn't fit or the index is out of range.
+    pub const fn try_with_perm(mut self, m: u32, value: u8) -> Result<Self, FieldOverflow> {
+        match self.try_set_perm(m, value) {
+            Ok(()) => Ok(self),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Display for S2pirEl2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("S2pirEl2");
+        let bits = self.bits();
+        s.field("Perm0", &((bits >> Self::PERM_SHIFT) & Self::PERM_MASK));
+        s.field(
+            "Perm1",
+            &((bits >> (Self::PERM_SHIFT + 4)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm2",
+            &((bits >> (Self::PERM_SHIFT + 8)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm3",
+            &((bits >> (Self::PERM_SHIFT + 12)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm4",
+            &((bits >> (Self::PERM_SHIFT + 16)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm5",
+            &((bits >> (Self::PERM_SHIFT + 20)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm6",
+            &((bits >> (Self::PERM_SHIFT + 24)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm7",
+            &((bits >> (Self::PERM_SHIFT + 28)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm8",
+            &((bits >> (Self::PERM_SHIFT + 32)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm9",
+            &((bits >> (Self::PERM_SHIFT + 36)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm10",
+            &((bits >> (Self::PERM_SHIFT + 40)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm11",
+            &((bits >> (Self::PERM_SHIFT + 44)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm12",
+            &((bits >> (Self::PERM_SHIFT + 48)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm13",
+            &((bits >> (Self::PERM_SHIFT + 52)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm14",
+            &((bits >> (Self::PERM_SHIFT + 56)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm15",
+            &((bits >> (Self::PERM_SHIFT + 60)) & Self::PERM_MASK),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for S2pirEl2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "S2pirEl2 {{ Perm0: {=u64}, Perm1: {=u64}, Perm2: {=u64}, Perm3: {=u64}, Perm4: {=u64}, Perm5: {=u64}, Perm6: {=u64}, Perm7: {=u64}, Perm8: {=u64}, Perm9: {=u64}, Perm10: {=u64}, Perm11: {=u64}, Perm12: {=u64}, Perm13: {=u64}, Perm14: {=u64}, Perm15: {=u64} }}",
+            (self.bits() >> Self::PERM_SHIFT) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 4)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 8)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 12)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 16)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 20)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 24)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 28)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 32)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 36)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 40)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 44)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 48)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 52)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 56)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 60)) & Self::PERM_MASK
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for S2pirEl2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for S2pirEl2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl S2pirEl2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("Perm0"),
+                old: (self.bits() >> Self::PERM_SHIFT) & Self::PERM_MASK,
+                new: (other.bits() >> Self::PERM_SHIFT) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm1"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 4)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 4)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm2"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 8)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 8)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm3"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 12)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 12)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm4"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 16)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 16)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm5"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 20)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 20)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm6"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 24)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 24)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm7"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 28)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 28)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm8"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 32)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 32)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm9"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 36)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 36)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm10"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 40)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 40)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm11"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 44)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 44)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm12"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 48)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 48)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm13"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 52)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 52)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm14"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 56)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 56)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm15"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 60)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 60)) & Self::PERM_MASK,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl S2pirEl2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl S2pirEl2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                (self.bits() >> Self::PERM_SHIFT) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S2pie),
+                UnimplementedField {
+                    register: "S2PIR_EL2",
+                    field: "Perm0",
+                    requires: "FEAT_S2PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 4)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S2pie),
+                UnimplementedField {
+                    register: "S2PIR_EL2",
+                    field: "Perm1",
+                    requires: "FEAT_S2PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 8)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S2pie),
+                UnimplementedField {
+                    register: "S2PIR_EL2",
+                    field: "Perm2",
+                    requires: "FEAT_S2PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 12)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S2pie),
+                UnimplementedField {
+                    register: "S2PIR_EL2",
+                    field: "Perm3",
+                    requires: "FEAT_S2PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 16)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S2pie),
+                UnimplementedField {
+                    register: "S2PIR_EL2",
+                    field: "Perm4",
+                    requires: "FEAT_S2PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 20)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S2pie),
+                UnimplementedField {
+                    register: "S2PIR_EL2",
+                    field: "Perm5",
+                    requires: "FEAT_S2PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 24)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S2pie),
+                UnimplementedField {
+                    register: "S2PIR_EL2",
+                    field: "Perm6",
+                    requires: "FEAT_S2PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 28)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S2pie),
+                UnimplementedField {
+                    register: "S2PIR_EL2",
+                    field: "Perm7",
+                    requires: "FEAT_S2PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 32)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S2pie),
+                UnimplementedField {
+                    register: "S2PIR_EL2",
+                    field: "Perm8",
+                    requires: "FEAT_S2PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 36)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S2pie),
+                UnimplementedField {
+                    register: "S2PIR_EL2",
+                    field: "Perm9",
+                    requires: "FEAT_S2PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 40)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S2pie),
+                UnimplementedField {
+                    register: "S2PIR_EL2",
+                    field: "Perm10",
+                    requires: "FEAT_S2PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 44)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S2pie),
+                UnimplementedField {
+                    register: "S2PIR_EL2",
+                    field: "Perm11",
+                    requires: "FEAT_S2PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 48)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S2pie),
+                UnimplementedField {
+                    register: "S2PIR_EL2",
+                    field: "Perm12",
+                    requires: "FEAT_S2PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 52)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S2pie),
+                UnimplementedField {
+                    register: "S2PIR_EL2",
+                    field: "Perm13",
+                    requires: "FEAT_S2PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 56)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S2pie),
+                UnimplementedField {
+                    register: "S2PIR_EL2",
+                    field: "Perm14",
+                    requires: "FEAT_S2PIE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 60)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S2pie),
+                UnimplementedField {
+                    register: "S2PIR_EL2",
+                    field: "Perm15",
+                    requires: "FEAT_S2PIE",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `S2POR_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct S2porEl1: u64 {
+    }
+}
+
+#[cfg(feature = "el1")]
+impl S2porEl1 {
+    /// Offset of the `Perm<m>` field.
+    pub const PERM_SHIFT: u32 = 0;
+    /// Mask for the `Perm<m>` field.
+    pub const PERM_MASK: u64 = 0b1111;
+
+    /// Returns the value of the given `Perm<m>` field.
+    pub const fn perm(self, m: u32) -> u8 {
+        assert!(m < 16);
+        ((self.bits() >> (Self::PERM_SHIFT + m * 4)) & Self::PERM_MASK) as u8
+    }
+
+    /// Sets the value of the `Perm<m>` field.
+    pub const fn set_perm(&mut self, m: u32, value: u8) {
+        assert!(m < 16);
+        let offset = Self::PERM_SHIFT + m * 4;
+        assert!(value & (Self::PERM_MASK as u8) == value);
+        *self = Self::from_bits_retain(
+            (self.bits() & !(Self::PERM_MASK << offset)) | ((value as u64) << offset),
+        );
+    }
+
+    /// Returns a copy with the `Perm<m>` field set to the given value.
+    pub const fn with_perm(mut self, m: u32, value: u8) -> Self {
+        self.set_perm(m, value);
+        self
+    }
+
+    /// Sets the value of the `Perm<m>` field, or returns an error if the value doesn't fit or the
+    /// index is out of range.
+    pub const fn try_set_perm(&mut self, m: u32, value: u8) -> Result<(), FieldOverflow> {
+        if m >= 16 || value & (Self::PERM_MASK as u8) != value {
+            return Err(FieldOverflow {
+                register: "S2POR_EL1",
+                field: "Perm",
+                index: Some(m),
+                value: value as u64,
+            });
+        }
+        self.set_perm(m, value);
+        Ok(())
+    }
+
+    /// Returns a copy with the `Perm<m>` field set to the given value, or an error if the value
+    /// doesn't fit or the index is out of range.
+    pub const fn try_with_perm(mut self, m: u32, value: u8) -> Result<Self, FieldOverflow> {
+        match self.try_set_perm(m, value) {
+            Ok(()) => Ok(self),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for S2porEl1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("S2porEl1");
+        let bits = self.bits();
+        s.field("Perm0", &((bits >> Self::PERM_SHIFT) & Self::PERM_MASK));
+        s.field(
+            "Perm1",
+            &((bits >> (Self::PERM_SHIFT + 4)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm2",
+            &((bits >> (Self::PERM_SHIFT + 8)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm3",
+            &((bits >> (Self::PERM_SHIFT + 12)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm4",
+            &((bits >> (Self::PERM_SHIFT + 16)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm5",
+            &((bits >> (Self::PERM_SHIFT + 20)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm6",
+            &((bits >> (Self::PERM_SHIFT + 24)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm7",
+            &((bits >> (Self::PERM_SHIFT + 28)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm8",
+            &((bits >> (Self::PERM_SHIFT + 32)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm9",
+            &((bits >> (Self::PERM_SHIFT + 36)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm10",
+            &((bits >> (Self::PERM_SHIFT + 40)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm11",
+            &((bits >> (Self::PERM_SHIFT + 44)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm12",
+            &((bits >> (Self::PERM_SHIFT + 48)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm13",
+            &((bits >> (Self::PERM_SHIFT + 52)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm14",
+            &((bits >> (Self::PERM_SHIFT + 56)) & Self::PERM_MASK),
+        );
+        s.field(
+            "Perm15",
+            &((bits >> (Self::PERM_SHIFT + 60)) & Self::PERM_MASK),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for S2porEl1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "S2porEl1 {{ Perm0: {=u64}, Perm1: {=u64}, Perm2: {=u64}, Perm3: {=u64}, Perm4: {=u64}, Perm5: {=u64}, Perm6: {=u64}, Perm7: {=u64}, Perm8: {=u64}, Perm9: {=u64}, Perm10: {=u64}, Perm11: {=u64}, Perm12: {=u64}, Perm13: {=u64}, Perm14: {=u64}, Perm15: {=u64} }}",
+            (self.bits() >> Self::PERM_SHIFT) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 4)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 8)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 12)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 16)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 20)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 24)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 28)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 32)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 36)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 40)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 44)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 48)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 52)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 56)) & Self::PERM_MASK,
+            (self.bits() >> (Self::PERM_SHIFT + 60)) & Self::PERM_MASK
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for S2porEl1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for S2porEl1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl S2porEl1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("Perm0"),
+                old: (self.bits() >> Self::PERM_SHIFT) & Self::PERM_MASK,
+                new: (other.bits() >> Self::PERM_SHIFT) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm1"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 4)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 4)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm2"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 8)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 8)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm3"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 12)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 12)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm4"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 16)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 16)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm5"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 20)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 20)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm6"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 24)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 24)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm7"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 28)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 28)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm8"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 32)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 32)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm9"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 36)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 36)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm10"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 40)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 40)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm11"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 44)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 44)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm12"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 48)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 48)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm13"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 52)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 52)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm14"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 56)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 56)) & Self::PERM_MASK,
+            },
+            FieldChange {
+                register,
+                field: Some("Perm15"),
+                old: (self.bits() >> (Self::PERM_SHIFT + 60)) & Self::PERM_MASK,
+                new: (other.bits() >> (Self::PERM_SHIFT + 60)) & Self::PERM_MASK,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl S2porEl1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl S2porEl1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                (self.bits() >> Self::PERM_SHIFT) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S2poe),
+                UnimplementedField {
+                    register: "S2POR_EL1",
+                    field: "Perm0",
+                    requires: "FEAT_S2POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 4)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S2poe),
+                UnimplementedField {
+                    register: "S2POR_EL1",
+                    field: "Perm1",
+                    requires: "FEAT_S2POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 8)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S2poe),
+                UnimplementedField {
+                    register: "S2POR_EL1",
+                    field: "Perm2",
+                    requires: "FEAT_S2POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 12)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S2poe),
+                UnimplementedField {
+                    register: "S2POR_EL1",
+                    field: "Perm3",
+                    requires: "FEAT_S2POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 16)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S2poe),
+                UnimplementedField {
+                    register: "S2POR_EL1",
+                    field: "Perm4",
+                    requires: "FEAT_S2POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 20)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S2poe),
+                UnimplementedField {
+                    register: "S2POR_EL1",
+                    field: "Perm5",
+                    requires: "FEAT_S2POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 24)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S2poe),
+                UnimplementedField {
+                    register: "S2POR_EL1",
+                    field: "Perm6",
+                    requires: "FEAT_S2POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 28)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S2poe),
+                UnimplementedField {
+                    register: "S2POR_EL1",
+                    field: "Perm7",
+                    requires: "FEAT_S2POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 32)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S2poe),
+                UnimplementedField {
+                    register: "S2POR_EL1",
+                    field: "Perm8",
+                    requires: "FEAT_S2POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 36)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S2poe),
+                UnimplementedField {
+                    register: "S2POR_EL1",
+                    field: "Perm9",
+                    requires: "FEAT_S2POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 40)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S2poe),
+                UnimplementedField {
+                    register: "S2POR_EL1",
+                    field: "Perm10",
+                    requires: "FEAT_S2POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 44)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S2poe),
+                UnimplementedField {
+                    register: "S2POR_EL1",
+                    field: "Perm11",
+                    requires: "FEAT_S2POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 48)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S2poe),
+                UnimplementedField {
+                    register: "S2POR_EL1",
+                    field: "Perm12",
+                    requires: "FEAT_S2POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 52)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S2poe),
+                UnimplementedField {
+                    register: "S2POR_EL1",
+                    field: "Perm13",
+                    requires: "FEAT_S2POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 56)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S2poe),
+                UnimplementedField {
+                    register: "S2POR_EL1",
+                    field: "Perm14",
+                    requires: "FEAT_S2POE",
+                },
+            ),
+            (
+                (self.bits() >> (Self::PERM_SHIFT + 60)) & Self::PERM_MASK != 0
+                    && !features.contains(Feature::S2poe),
+                UnimplementedField {
+                    register: "S2POR_EL1",
+                    field: "Perm15",
+                    requires: "FEAT_S2POE",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `TCR2_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct Tcr2El1: u64 {
+        /// `PnCH` bit.
+        const PNCH = 1 << 0;
+        /// `PIE` bit.
+        const PIE = 1 << 1;
+        /// `E0POE` bit.
+        const E0POE = 1 << 2;
+        /// `POE` bit.
+        const POE = 1 << 3;
+        /// `AIE` bit.
+        const AIE = 1 << 4;
+        /// `D128` bit.
+        const D128 = 1 << 5;
+        /// `PTTWI` bit.
+        const PTTWI = 1 << 10;
+        /// `HAFT` bit.
+        const HAFT = 1 << 11;
+        /// `DisCH0` bit.
+        const DISCH0 = 1 << 14;
+        /// `DisCH1` bit.
+        const DISCH1 = 1 << 15;
+        /// `A2` bit.
+        const A2 = 1 << 16;
+        /// `FNG0` bit.
+        const FNG0 = 1 << 17;
+        /// `FNG1` bit.
+        const FNG1 = 1 << 18;
+        /// `POE2F` bit.
+        const POE2F = 1 << 19;
+        /// `FNGNA0` bit.
+        const FNGNA0 = 1 << 20;
+        /// `FNGNA1` bit.
+        const FNGNA1 = 1 << 21;
+        /// `TVAD0` bit.
+        const TVAD0 = 1 << 35;
+        /// `TVAD1` bit.
+        const TVAD1 = 1 << 36;
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Tcr2El1 {
+    /// Descriptor of the `PnCH` field, for use with [`FieldAccess`].
+    pub const PNCH_FIELD: Field<Self, bool, 0, 1> = Field::new("TCR2_EL1", "PnCH");
+    /// Descriptor of the `PIE` field, for use with [`FieldAccess`].
+    pub const PIE_FIELD: Field<Self, bool, 1, 1> = Field::new("TCR2_EL1", "PIE");
+    /// Descriptor of the `E0POE` field, for use with [`FieldAccess`].
+    pub const E0POE_FIELD: Field<Self, bool, 2, 1> = Field::new("TCR2_EL1", "E0POE");
+    /// Descriptor of the `POE` field, for use with [`FieldAccess`].
+    pub const POE_FIELD: Field<Self, bool, 3, 1> = Field::new("TCR2_EL1", "POE");
+    /// Descriptor of the `AIE` field, for use with [`FieldAccess`].
+    pub const AIE_FIELD: Field<Self, bool, 4, 1> = Field::new("TCR2_EL1", "AIE");
+    /// Descriptor of the `D128` field, for use with [`FieldAccess`].
+    pub const D128_FIELD: Field<Self, bool, 5, 1> = Field::new("TCR2_EL1", "D128");
+    /// Descriptor of the `PTTWI` field, for use with [`FieldAccess`].
+    pub const PTTWI_FIELD: Field<Self, bool, 10, 1> = Field::new("TCR2_EL1", "PTTWI");
+    /// Descriptor of the `HAFT` field, for use with [`FieldAccess`].
+    pub const HAFT_FIELD: Field<Self, bool, 11, 1> = Field::new("TCR2_EL1", "HAFT");
+    /// Descriptor of the `DisCH0` field, for use with [`FieldAccess`].
+    pub const DISCH0_FIELD: Field<Self, bool, 14, 1> = Field::new("TCR2_EL1", "DisCH0");
+    /// Descriptor of the `DisCH1` field, for use with [`FieldAccess`].
+    pub const DISCH1_FIELD: Field<Self, bool, 15, 1> = Field::new("TCR2_EL1", "DisCH1");
+    /// Descriptor of the `A2` field, for use with [`FieldAccess`].
+    pub const A2_FIELD: Field<Self, bool, 16, 1> = Field::new("TCR2_EL1", "A2");
+    /// Descriptor of the `FNG0` field, for use with [`FieldAccess`].
+    pub const FNG0_FIELD: Field<Self, bool, 17, 1> = Field::new("TCR2_EL1", "FNG0");
+    /// Descriptor of the `FNG1` field, for use with [`FieldAccess`].
+    pub const FNG1_FIELD: Field<Self, bool, 18, 1> = Field::new("TCR2_EL1", "FNG1");
+    /// Descriptor of the `POE2F` field, for use with [`FieldAccess`].
+    pub const POE2F_FIELD: Field<Self, bool, 19, 1> = Field::new("TCR2_EL1", "POE2F");
+    /// Descriptor of the `FNGNA0` field, for use with [`FieldAccess`].
+    pub const FNGNA0_FIELD: Field<Self, bool, 20, 1> = Field::new("TCR2_EL1", "FNGNA0");
+    /// Descriptor of the `FNGNA1` field, for use with [`FieldAccess`].
+    pub const FNGNA1_FIELD: Field<Self, bool, 21, 1> = Field::new("TCR2_EL1", "FNGNA1");
+    /// Descriptor of the `POIW` field, for use with [`FieldAccess`].
+    pub const POIW_FIELD: Field<Self, u8, 22, 3> = Field::new("TCR2_EL1", "POIW");
+    /// Descriptor of the `VTB0` field, for use with [`FieldAccess`].
+    pub const VTB0_FIELD: Field<Self, u8, 25, 5> = Field::new("TCR2_EL1", "VTB0");
+    /// Descriptor of the `VTB1` field, for use with [`FieldAccess`].
+    pub const VTB1_FIELD: Field<Self, u8, 30, 5> = Field::new("TCR2_EL1", "VTB1");
+    /// Descriptor of the `TVAD0` field, for use with [`FieldAccess`].
+    pub const TVAD0_FIELD: Field<Self, bool, 35, 1> = Field::new("TCR2_EL1", "TVAD0");
+    /// Descriptor of the `TVAD1` field, for use with [`FieldAccess`].
+    pub const TVAD1_FIELD: Field<Self, bool, 36, 1> = Field::new("TCR2_EL1", "TVAD1");
+
+    /// Returns the value of the `POIW` field.
+    pub const fn poiw(self) -> u8 {
+        Self::POIW_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `POIW` field.
+    pub const fn set_poiw(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::POIW_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `POIW` field set to the given value.
+    pub const fn with_poiw(mut self, value: u8) -> Self {
+        self.set_poiw(value);
+        self
+    }
+
+    /// Sets the value of the `POIW` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_poiw(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_poiw(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `POIW` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_poiw(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::POIW_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `VTB0` field.
+    pub const fn vtb0(self) -> u8 {
+        Self::VTB0_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `VTB0` field.
+    pub const fn set_vtb0(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::VTB0_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `VTB0` field set to the given value.
+    pub const fn with_vtb0(mut self, value: u8) -> Self {
+        self.set_vtb0(value);
+        self
+    }
+
+    /// Sets the value of the `VTB0` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_vtb0(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_vtb0(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `VTB0` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_vtb0(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::VTB0_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `VTB1` field.
+    pub const fn vtb1(self) -> u8 {
+        Self::VTB1_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `VTB1` field.
+    pub const fn set_vtb1(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::VTB1_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `VTB1` field set to the given value.
+    pub const fn with_vtb1(mut self, value: u8) -> Self {
+        self.set_vtb1(value);
+        self
+    }
+
+    /// Sets the value of the `VTB1` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_vtb1(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_vtb1(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `VTB1` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_vtb1(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::VTB1_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for Tcr2El1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("Tcr2El1");
+        let bits = self.bits();
+        s.field("PnCH", &Self::PNCH_FIELD.extract(bits));
+        s.field("PIE", &Self::PIE_FIELD.extract(bits));
+        s.field("E0POE", &Self::E0POE_FIELD.extract(bits));
+        s.field("POE", &Self::POE_FIELD.extract(bits));
+        s.field("AIE", &Self::AIE_FIELD.extract(bits));
+        s.field("D128", &Self::D128_FIELD.extract(bits));
+        s.field("PTTWI", &Self::PTTWI_FIELD.extract(bits));
+        s.field("HAFT", &Self::HAFT_FIELD.extract(bits));
+        s.field("DisCH0", &Self::DISCH0_FIELD.extract(bits));
+        s.field("DisCH1", &Self::DISCH1_FIELD.extract(bits));
+        s.field("A2", &Self::A2_FIELD.extract(bits));
+        s.field("FNG0", &Self::FNG0_FIELD.extract(bits));
+        s.field("FNG1", &Self::FNG1_FIELD.extract(bits));
+        s.field("POE2F", &Self::POE2F_FIELD.extract(bits));
+        s.field("FNGNA0", &Self::FNGNA0_FIELD.extract(bits));
+        s.field("FNGNA1", &Self::FNGNA1_FIELD.extract(bits));
+        s.field("POIW", &Self::POIW_FIELD.extract(bits));
+        s.field("VTB0", &Self::VTB0_FIELD.extract(bits));
+        s.field("VTB1", &Self::VTB1_FIELD.extract(bits));
+        s.field("TVAD0", &Self::TVAD0_FIELD.extract(bits));
+        s.field("TVAD1", &Self::TVAD1_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for Tcr2El1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "Tcr2El1 {{ PnCH: {=u64}, PIE: {=u64}, E0POE: {=u64}, POE: {=u64}, AIE: {=u64}, D128: {=u64}, PTTWI: {=u64}, HAFT: {=u64}, DisCH0: {=u64}, DisCH1: {=u64}, A2: {=u64}, FNG0: {=u64}, FNG1: {=u64}, POE2F: {=u64}, FNGNA0: {=u64}, FNGNA1: {=u64}, POIW: {=u64}, VTB0: {=u64}, VTB1: {=u64}, TVAD0: {=u64}, TVAD1: {=u64} }}",
+            Self::PNCH_FIELD.extract(self.bits()),
+            Self::PIE_FIELD.extract(self.bits()),
+            Self::E0POE_FIELD.extract(self.bits()),
+            Self::POE_FIELD.extract(self.bits()),
+            Self::AIE_FIELD.extract(self.bits()),
+            Self::D128_FIELD.extract(self.bits()),
+            Self::PTTWI_FIELD.extract(self.bits()),
+            Self::HAFT_FIELD.extract(self.bits()),
+            Self::DISCH0_FIELD.extract(self.bits()),
+            Self::DISCH1_FIELD.extract(self.bits()),
+            Self::A2_FIELD.extract(self.bits()),
+            Self::FNG0_FIELD.extract(self.bits()),
+            Self::FNG1_FIELD.extract(self.bits()),
+            Self::POE2F_FIELD.extract(self.bits()),
+            Self::FNGNA0_FIELD.extract(self.bits()),
+            Self::FNGNA1_FIELD.extract(self.bits()),
+            Self::POIW_FIELD.extract(self.bits()),
+            Self::VTB0_FIELD.extract(self.bits()),
+            Self::VTB1_FIELD.extract(self.bits()),
+            Self::TVAD0_FIELD.extract(self.bits()),
+            Self::TVAD1_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for Tcr2El1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for Tcr2El1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Tcr2El1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("PnCH"),
+                old: Self::PNCH_FIELD.extract(self.bits()),
+                new: Self::PNCH_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PIE"),
+                old: Self::PIE_FIELD.extract(self.bits()),
+                new: Self::PIE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("E0POE"),
+                old: Self::E0POE_FIELD.extract(self.bits()),
+                new: Self::E0POE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("POE"),
+                old: Self::POE_FIELD.extract(self.bits()),
+                new: Self::POE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("AIE"),
+                old: Self::AIE_FIELD.extract(self.bits()),
+                new: Self::AIE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("D128"),
+                old: Self::D128_FIELD.extract(self.bits()),
+                new: Self::D128_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PTTWI"),
+                old: Self::PTTWI_FIELD.extract(self.bits()),
+                new: Self::PTTWI_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HAFT"),
+                old: Self::HAFT_FIELD.extract(self.bits()),
+                new: Self::HAFT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DisCH0"),
+                old: Self::DISCH0_FIELD.extract(self.bits()),
+                new: Self::DISCH0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DisCH1"),
+                old: Self::DISCH1_FIELD.extract(self.bits()),
+                new: Self::DISCH1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("A2"),
+                old: Self::A2_FIELD.extract(self.bits()),
+                new: Self::A2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FNG0"),
+                old: Self::FNG0_FIELD.extract(self.bits()),
+                new: Self::FNG0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FNG1"),
+                old: Self::FNG1_FIELD.extract(self.bits()),
+                new: Self::FNG1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("POE2F"),
+                old: Self::POE2F_FIELD.extract(self.bits()),
+                new: Self::POE2F_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FNGNA0"),
+                old: Self::FNGNA0_FIELD.extract(self.bits()),
+                new: Self::FNGNA0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FNGNA1"),
+                old: Self::FNGNA1_FIELD.extract(self.bits()),
+                new: Self::FNGNA1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("POIW"),
+                old: Self::POIW_FIELD.extract(self.bits()),
+                new: Self::POIW_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VTB0"),
+                old: Self::VTB0_FIELD.extract(self.bits()),
+                new: Self::VTB0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VTB1"),
+                old: Self::VTB1_FIELD.extract(self.bits()),
+                new: Self::VTB1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TVAD0"),
+                old: Self::TVAD0_FIELD.extract(self.bits()),
+                new: Self::TVAD0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TVAD1"),
+                old: Self::TVAD1_FIELD.extract(self.bits()),
+                new: Self::TVAD1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1110_0000_0000_0000_0000_0000_0011_0011_1100_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1110_0000_0000_0000_0000_0000_0011_0011_1100_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Tcr2El1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, features: Option<&CpuFeatures>) -> Self {
+        let mut bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        if let Some(features) = features
+            && !(features.contains(Feature::S1pie))
+        {
+            bits &= !0b10;
+        }
+        if let Some(features) = features
+            && !(features.contains(Feature::S1pie))
+        {
+            bits &= !0b100;
+        }
+        if let Some(features) = features
+            && !(features.contains(Feature::S1pie))
+        {
+            bits &= !0b1000;
+        }
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Tcr2El1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                Self::PNCH_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Tcr2),
+                UnimplementedField {
+                    register: "TCR2_EL1",
+                    field: "PnCH",
+                    requires: "FEAT_TCR2",
+                },
+            ),
+            (
+                Self::PIE_FIELD.extract(self.bits()) != 0
+                    && !(features.contains(Feature::Tcr2) && features.contains(Feature::S1pie)),
+                UnimplementedField {
+                    register: "TCR2_EL1",
+                    field: "PIE",
+                    requires: "FEAT_TCR2 && FEAT_S1PIE",
+                },
+            ),
+            (
+                Self::E0POE_FIELD.extract(self.bits()) != 0
+                    && !(features.contains(Feature::Tcr2) && features.contains(Feature::S1pie)),
+                UnimplementedField {
+                    register: "TCR2_EL1",
+                    field: "E0POE",
+                    requires: "FEAT_TCR2 && FEAT_S1PIE",
+                },
+            ),
+            (
+                Self::POE_FIELD.extract(self.bits()) != 0
+                    && !(features.contains(Feature::Tcr2) && features.contains(Feature::S1pie)),
+                UnimplementedField {
+                    register: "TCR2_EL1",
+                    field: "POE",
+                    requires: "FEAT_TCR2 && FEAT_S1PIE",
+                },
+            ),
+            (
+                Self::AIE_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Tcr2),
+                UnimplementedField {
+                    register: "TCR2_EL1",
+                    field: "AIE",
+                    requires: "FEAT_TCR2",
+                },
+            ),
+            (
+                Self::D128_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Tcr2),
+                UnimplementedField {
+                    register: "TCR2_EL1",
+                    field: "D128",
+                    requires: "FEAT_TCR2",
+                },
+            ),
+            (
+                Self::PTTWI_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Tcr2),
+                UnimplementedField {
+                    register: "TCR2_EL1",
+                    field: "PTTWI",
+                    requires: "FEAT_TCR2",
+                },
+            ),
+            (
+                Self::HAFT_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Tcr2),
+                UnimplementedField {
+                    register: "TCR2_EL1",
+                    field: "HAFT",
+                    requires: "FEAT_TCR2",
+                },
+            ),
+            (
+                Self::DISCH0_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Tcr2),
+                UnimplementedField {
+                    register: "TCR2_EL1",
+                    field: "DisCH0",
+                    requires: "FEAT_TCR2",
+                },
+            ),
+            (
+                Self::DISCH1_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Tcr2),
+                UnimplementedField {
+                    register: "TCR2_EL1",
+                    field: "DisCH1",
+                    requires: "FEAT_TCR2",
+                },
+            ),
+            (
+                Self::A2_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Tcr2),
+                UnimplementedField {
+                    register: "TCR2_EL1",
+                    field: "A2",
+                    requires: "FEAT_TCR2",
+                },
+            ),
+            (
+                Self::FNG0_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Tcr2),
+                UnimplementedField {
+                    register: "TCR2_EL1",
+                    field: "FNG0",
+                    requires: "FEAT_TCR2",
+                },
+            ),
+            (
+                Self::FNG1_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Tcr2),
+                UnimplementedField {
+                    register: "TCR2_EL1",
+                    field: "FNG1",
+                    requires: "FEAT_TCR2",
+                },
+            ),
+            (
+                Self::POE2F_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Tcr2),
+                UnimplementedField {
+                    register: "TCR2_EL1",
+                    field: "POE2F",
+                    requires: "FEAT_TCR2",
+                },
+            ),
+            (
+                Self::FNGNA0_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Tcr2),
+                UnimplementedField {
+                    register: "TCR2_EL1",
+                    field: "FNGNA0",
+                    requires: "FEAT_TCR2",
+                },
+            ),
+            (
+                Self::FNGNA1_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Tcr2),
+                UnimplementedField {
+                    register: "TCR2_EL1",
+                    field: "FNGNA1",
+                    requires: "FEAT_TCR2",
+                },
+            ),
+            (
+                Self::POIW_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Tcr2),
+                UnimplementedField {
+                    register: "TCR2_EL1",
+                    field: "POIW",
+                    requires: "FEAT_TCR2",
+                },
+            ),
+            (
+                Self::VTB0_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Tcr2),
+                UnimplementedField {
+                    register: "TCR2_EL1",
+                    field: "VTB0",
+                    requires: "FEAT_TCR2",
+                },
+            ),
+            (
+                Self::VTB1_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Tcr2),
+                UnimplementedField {
+                    register: "TCR2_EL1",
+                    field: "VTB1",
+                    requires: "FEAT_TCR2",
+                },
+            ),
+            (
+                Self::TVAD0_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Tcr2),
+                UnimplementedField {
+                    register: "TCR2_EL1",
+                    field: "TVAD0",
+                    requires: "FEAT_TCR2",
+                },
+            ),
+            (
+                Self::TVAD1_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Tcr2),
+                UnimplementedField {
+                    register: "TCR2_EL1",
+                    field: "TVAD1",
+                    requires: "FEAT_TCR2",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `TCR2_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct Tcr2El2: u64 {
+        /// `PnCH` bit.
+        const PNCH = 1 << 0;
+        /// `PIE` bit.
+        const PIE = 1 << 1;
+        /// `E0POE` bit.
+        const E0POE = 1 << 2;
+        /// `POE` bit.
+        const POE = 1 << 3;
+        /// `AIE` bit.
+        const AIE = 1 << 4;
+        /// `D128` bit.
+        const D128 = 1 << 5;
+        /// `PTTWI` bit.
+        const PTTWI = 1 << 10;
+        /// `HAFT` bit.
+        const HAFT = 1 << 11;
+        /// `AMEC0` bit.
+        const AMEC0 = 1 << 12;
+        /// `AMEC1` bit.
+        const AMEC1 = 1 << 13;
+        /// `DisCH0` bit.
+        const DISCH0 = 1 << 14;
+        /// `DisCH1` bit.
+        const DISCH1 = 1 << 15;
+        /// `A2` bit.
+        const A2 = 1 << 16;
+        /// `FNG0` bit.
+        const FNG0 = 1 << 17;
+        /// `FNG1` bit.
+        const FNG1 = 1 << 18;
+        /// `POE2F` bit.
+        const POE2F = 1 << 19;
+        /// `TVAD0` bit.
+        const TVAD0 = 1 << 35;
+        /// `TVAD1` bit.
+        const TVAD1 = 1 << 36;
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Tcr2El2 {
+    /// Descriptor of the `PnCH` field, for use with [`FieldAccess`].
+    pub const PNCH_FIELD: Field<Self, bool, 0, 1> = Field::new("TCR2_EL2", "PnCH");
+    /// Descriptor of the `PIE` field, for use with [`FieldAccess`].
+    pub const PIE_FIELD: Field<Self, bool, 1, 1> = Field::new("TCR2_EL2", "PIE");
+    /// Descriptor of the `E0POE` field, for use with [`FieldAccess`].
+    pub const E0POE_FIELD: Field<Self, bool, 2, 1> = Field::new("TCR2_EL2", "E0POE");
+    /// Descriptor of the `POE` field, for use with [`FieldAccess`].
+    pub const POE_FIELD: Field<Self, bool, 3, 1> = Field::new("TCR2_EL2", "POE");
+    /// Descriptor of the `AIE` field, for use with [`FieldAccess`].
+    pub const AIE_FIELD: Field<Self, bool, 4, 1> = Field::new("TCR2_EL2", "AIE");
+    /// Descriptor of the `D128` field, for use with [`FieldAccess`].
+    pub const D128_FIELD: Field<Self, bool, 5, 1> = Field::new("TCR2_EL2", "D128");
+    /// Descriptor of the `PTTWI` field, for use with [`FieldAccess`].
+    pub const PTTWI_FIELD: Field<Self, bool, 10, 1> = Field::new("TCR2_EL2", "PTTWI");
+    /// Descriptor of the `HAFT` field, for use with [`FieldAccess`].
+    pub const HAFT_FIELD: Field<Self, bool, 11, 1> = Field::new("TCR2_EL2", "HAFT");
+    /// Descriptor of the `AMEC0` field, for use with [`FieldAccess`].
+    pub const AMEC0_FIELD: Field<Self, bool, 12, 1> = Field::new("TCR2_EL2", "AMEC0");
+    /// Descriptor of the `AMEC1` field, for use with [`FieldAccess`].
+    pub const AMEC1_FIELD: Field<Self, bool, 13, 1> = Field::new("TCR2_EL2", "AMEC1");
+    /// Descriptor of the `DisCH0` field, for use with [`FieldAccess`].
+    pub const DISCH0_FIELD: Field<Self, bool, 14, 1> = Field::new("TCR2_EL2", "DisCH0");
+    /// Descriptor of the `DisCH1` field, for use with [`FieldAccess`].
+    pub const DISCH1_FIELD: Field<Self, bool, 15, 1> = Field::new("TCR2_EL2", "DisCH1");
+    /// Descriptor of the `A2` field, for use with [`FieldAccess`].
+    pub const A2_FIELD: Field<Self, bool, 16, 1> = Field::new("TCR2_EL2", "A2");
+    /// Descriptor of the `FNG0` field, for use with [`FieldAccess`].
+    pub const FNG0_FIELD: Field<Self, bool, 17, 1> = Field::new("TCR2_EL2", "FNG0");
+    /// Descriptor of the `FNG1` field, for use with [`FieldAccess`].
+    pub const FNG1_FIELD: Field<Self, bool, 18, 1> = Field::new("TCR2_EL2", "FNG1");
+    /// Descriptor of the `POE2F` field, for use with [`FieldAccess`].
+    pub const POE2F_FIELD: Field<Self, bool, 19, 1> = Field::new("TCR2_EL2", "POE2F");
+    /// Descriptor of the `POIW` field, for use with [`FieldAccess`].
+    pub const POIW_FIELD: Field<Self, u8, 22, 3> = Field::new("TCR2_EL2", "POIW");
+    /// Descriptor of the `VTB0` field, for use with [`FieldAccess`].
+    pub const VTB0_FIELD: Field<Self, u8, 25, 5> = Field::new("TCR2_EL2", "VTB0");
+    /// Descriptor of the `VTB1` field, for use with [`FieldAccess`].
+    pub const VTB1_FIELD: Field<Self, u8, 30, 5> = Field::new("TCR2_EL2", "VTB1");
+    /// Descriptor of the `TVAD0` field, for use with [`FieldAccess`].
+    pub const TVAD0_FIELD: Field<Self, bool, 35, 1> = Field::new("TCR2_EL2", "TVAD0");
+    /// Descriptor of the `TVAD1` field, for use with [`FieldAccess`].
+    pub const TVAD1_FIELD: Field<Self, bool, 36, 1> = Field::new("TCR2_EL2", "TVAD1");
+
+    /// Returns the value of the `POIW` field.
+    pub const fn poiw(self) -> u8 {
+        Self::POIW_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `POIW` field.
+    pub const fn set_poiw(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::POIW_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `POIW` field set to the given value.
+    pub const fn with_poiw(mut self, value: u8) -> Self {
+        self.set_poiw(value);
+        self
+    }
+
+    /// Sets the value of the `POIW` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_poiw(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_poiw(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `POIW` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_poiw(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::POIW_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `VTB0` field.
+    pub const fn vtb0(self) -> u8 {
+        Self::VTB0_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `VTB0` field.
+    pub const fn set_vtb0(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::VTB0_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `VTB0` field set to the given value.
+    pub const fn with_vtb0(mut self, value: u8) -> Self {
+        self.set_vtb0(value);
+        self
+    }
+
+    /// Sets the value of the `VTB0` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_vtb0(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_vtb0(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `VTB0` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_vtb0(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::VTB0_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `VTB1` field.
+    pub const fn vtb1(self) -> u8 {
+        Self::VTB1_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `VTB1` field.
+    pub const fn set_vtb1(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::VTB1_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `VTB1` field set to the given value.
+    pub const fn with_vtb1(mut self, value: u8) -> Self {
+        self.set_vtb1(value);
+        self
+    }
+
+    /// Sets the value of the `VTB1` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_vtb1(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_vtb1(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `VTB1` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_vtb1(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::VTB1_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Display for Tcr2El2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("Tcr2El2");
+        let bits = self.bits();
+        s.field("PnCH", &Self::PNCH_FIELD.extract(bits));
+        s.field("PIE", &Self::PIE_FIELD.extract(bits));
+        s.field("E0POE", &Self::E0POE_FIELD.extract(bits));
+        s.field("POE", &Self::POE_FIELD.extract(bits));
+        s.field("AIE", &Self::AIE_FIELD.extract(bits));
+        s.field("D128", &Self::D128_FIELD.extract(bits));
+        s.field("PTTWI", &Self::PTTWI_FIELD.extract(bits));
+        s.field("HAFT", &Self::HAFT_FIELD.extract(bits));
+        s.field("AMEC0", &Self::AMEC0_FIELD.extract(bits));
+        s.field("AMEC1", &Self::AMEC1_FIELD.extract(bits));
+        s.field("DisCH0", &Self::DISCH0_FIELD.extract(bits));
+        s.field("DisCH1", &Self::DISCH1_FIELD.extract(bits));
+        s.field("A2", &Self::A2_FIELD.extract(bits));
+        s.field("FNG0", &Self::FNG0_FIELD.extract(bits));
+        s.field("FNG1", &Self::FNG1_FIELD.extract(bits));
+        s.field("POE2F", &Self::POE2F_FIELD.extract(bits));
+        s.field("POIW", &Self::POIW_FIELD.extract(bits));
+        s.field("VTB0", &Self::VTB0_FIELD.extract(bits));
+        s.field("VTB1", &Self::VTB1_FIELD.extract(bits));
+        s.field("TVAD0", &Self::TVAD0_FIELD.extract(bits));
+        s.field("TVAD1", &Self::TVAD1_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for Tcr2El2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "Tcr2El2 {{ PnCH: {=u64}, PIE: {=u64}, E0POE: {=u64}, POE: {=u64}, AIE: {=u64}, D128: {=u64}, PTTWI: {=u64}, HAFT: {=u64}, AMEC0: {=u64}, AMEC1: {=u64}, DisCH0: {=u64}, DisCH1: {=u64}, A2: {=u64}, FNG0: {=u64}, FNG1: {=u64}, POE2F: {=u64}, POIW: {=u64}, VTB0: {=u64}, VTB1: {=u64}, TVAD0: {=u64}, TVAD1: {=u64} }}",
+            Self::PNCH_FIELD.extract(self.bits()),
+            Self::PIE_FIELD.extract(self.bits()),
+            Self::E0POE_FIELD.extract(self.bits()),
+            Self::POE_FIELD.extract(self.bits()),
+            Self::AIE_FIELD.extract(self.bits()),
+            Self::D128_FIELD.extract(self.bits()),
+            Self::PTTWI_FIELD.extract(self.bits()),
+            Self::HAFT_FIELD.extract(self.bits()),
+            Self::AMEC0_FIELD.extract(self.bits()),
+            Self::AMEC1_FIELD.extract(self.bits()),
+            Self::DISCH0_FIELD.extract(self.bits()),
+            Self::DISCH1_FIELD.extract(self.bits()),
+            Self::A2_FIELD.extract(self.bits()),
+            Self::FNG0_FIELD.extract(self.bits()),
+            Self::FNG1_FIELD.extract(self.bits()),
+            Self::POE2F_FIELD.extract(self.bits()),
+            Self::POIW_FIELD.extract(self.bits()),
+            Self::VTB0_FIELD.extract(self.bits()),
+            Self::VTB1_FIELD.extract(self.bits()),
+            Self::TVAD0_FIELD.extract(self.bits()),
+            Self::TVAD1_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for Tcr2El2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for Tcr2El2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Tcr2El2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("PnCH"),
+                old: Self::PNCH_FIELD.extract(self.bits()),
+                new: Self::PNCH_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PIE"),
+                old: Self::PIE_FIELD.extract(self.bits()),
+                new: Self::PIE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("E0POE"),
+                old: Self::E0POE_FIELD.extract(self.bits()),
+                new: Self::E0POE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("POE"),
+                old: Self::POE_FIELD.extract(self.bits()),
+                new: Self::POE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("AIE"),
+                old: Self::AIE_FIELD.extract(self.bits()),
+                new: Self::AIE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("D128"),
+                old: Self::D128_FIELD.extract(self.bits()),
+                new: Self::D128_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PTTWI"),
+                old: Self::PTTWI_FIELD.extract(self.bits()),
+                new: Self::PTTWI_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HAFT"),
+                old: Self::HAFT_FIELD.extract(self.bits()),
+                new: Self::HAFT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("AMEC0"),
+                old: Self::AMEC0_FIELD.extract(self.bits()),
+                new: Self::AMEC0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("AMEC1"),
+                old: Self::AMEC1_FIELD.extract(self.bits()),
+                new: Self::AMEC1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DisCH0"),
+                old: Self::DISCH0_FIELD.extract(self.bits()),
+                new: Self::DISCH0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DisCH1"),
+                old: Self::DISCH1_FIELD.extract(self.bits()),
+                new: Self::DISCH1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("A2"),
+                old: Self::A2_FIELD.extract(self.bits()),
+                new: Self::A2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FNG0"),
+                old: Self::FNG0_FIELD.extract(self.bits()),
+                new: Self::FNG0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FNG1"),
+                old: Self::FNG1_FIELD.extract(self.bits()),
+                new: Self::FNG1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("POE2F"),
+                old: Self::POE2F_FIELD.extract(self.bits()),
+                new: Self::POE2F_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("POIW"),
+                old: Self::POIW_FIELD.extract(self.bits()),
+                new: Self::POIW_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VTB0"),
+                old: Self::VTB0_FIELD.extract(self.bits()),
+                new: Self::VTB0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VTB1"),
+                old: Self::VTB1_FIELD.extract(self.bits()),
+                new: Self::VTB1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TVAD0"),
+                old: Self::TVAD0_FIELD.extract(self.bits()),
+                new: Self::TVAD0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TVAD1"),
+                old: Self::TVAD1_FIELD.extract(self.bits()),
+                new: Self::TVAD1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1110_0000_0000_0000_0011_0000_0000_0011_1100_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1110_0000_0000_0000_0011_0000_0000_0011_1100_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Tcr2El2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Tcr2El2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `TCR_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct TcrEl1: u64 {
+        /// `EPD0` bit.
+        const EPD0 = 1 << 7;
+        /// `A1` bit.
+        const A1 = 1 << 22;
+        /// `EPD1` bit.
+        const EPD1 = 1 << 23;
+        /// `AS` bit.
+        const AS = 1 << 36;
+        /// `TBI0` bit.
+        const TBI0 = 1 << 37;
+        /// `TBI1` bit.
+        const TBI1 = 1 << 38;
+        /// `HA` bit.
+        const HA = 1 << 39;
+        /// `HD` bit.
+        const HD = 1 << 40;
+        /// `HPD0` bit.
+        const HPD0 = 1 << 41;
+        /// `HPD1` bit.
+        const HPD1 = 1 << 42;
+        /// `HWU059` bit.
+        const HWU059 = 1 << 43;
+        /// `HWU060` bit.
+        const HWU060 = 1 << 44;
+        /// `HWU061` bit.
+        const HWU061 = 1 << 45;
+        /// `HWU062` bit.
+        const HWU062 = 1 << 46;
+        /// `HWU159` bit.
+        const HWU159 = 1 << 47;
+        /// `HWU160` bit.
+        const HWU160 = 1 << 48;
+        /// `HWU161` bit.
+        const HWU161 = 1 << 49;
+        /// `HWU162` bit.
+        const HWU162 = 1 << 50;
+        /// `TBID0` bit.
+        const TBID0 = 1 << 51;
+        /// `TBID1` bit.
+        const TBID1 = 1 << 52;
+        /// `NFD0` bit.
+        const NFD0 = 1 << 53;
+        /// `NFD1` bit.
+        const NFD1 = 1 << 54;
+        /// `E0PD0` bit.
+        const E0PD0 = 1 << 55;
+        /// `E0PD1` bit.
+        const E0PD1 = 1 << 56;
+        /// `TCMA0` bit.
+        const TCMA0 = 1 << 57;
+        /// `TCMA1` bit.
+        const TCMA1 = 1 << 58;
+        /// `DS` bit.
+        const DS = 1 << 59;
+        /// `MTX0` bit.
+        const MTX0 = 1 << 60;
+        /// `MTX1` bit.
+        const MTX1 = 1 << 61;
+    }
+}
+
+#[cfg(feature = "el1")]
+impl TcrEl1 {
+    /// Descriptor of the `T0SZ` field, for use with [`FieldAccess`].
+    pub const T0SZ_FIELD: Field<Self, u8, 0, 6> = Field::new("TCR_EL1", "T0SZ");
+    /// Descriptor of the `EPD0` field, for use with [`FieldAccess`].
+    pub const EPD0_FIELD: Field<Self, bool, 7, 1> = Field::new("TCR_EL1", "EPD0");
+    /// Descriptor of the `IRGN0` field, for use with [`FieldAccess`].
+    pub const IRGN0_FIELD: Field<Self, u8, 8, 2> = Field::new("TCR_EL1", "IRGN0");
+    /// Descriptor of the `ORGN0` field, for use with [`FieldAccess`].
+    pub const ORGN0_FIELD: Field<Self, u8, 10, 2> = Field::new("TCR_EL1", "ORGN0");
+    /// Descriptor of the `SH0` field, for use with [`FieldAccess`].
+    pub const SH0_FIELD: Field<Self, u8, 12, 2> = Field::new("TCR_EL1", "SH0");
+    /// Descriptor of the `TG0` field, for use with [`FieldAccess`].
+    pub const TG0_FIELD: Field<Self, u8, 14, 2> = Field::new("TCR_EL1", "TG0");
+    /// Descriptor of the `T1SZ` field, for use with [`FieldAccess`].
+    pub const T1SZ_FIELD: Field<Self, u8, 16, 6> = Field::new("TCR_EL1", "T1SZ");
+    /// Descriptor of the `A1` field, for use with [`FieldAccess`].
+    pub const A1_FIELD: Field<Self, bool, 22, 1> = Field::new("TCR_EL1", "A1");
+    /// Descriptor of the `EPD1` field, for use with [`FieldAccess`].
+    pub const EPD1_FIELD: Field<Self, bool, 23, 1> = Field::new("TCR_EL1", "EPD1");
+    /// Descriptor of the `IRGN1` field, for use with [`FieldAccess`].
+    pub const IRGN1_FIELD: Field<Self, u8, 24, 2> = Field::new("TCR_EL1", "IRGN1");
+    /// Descriptor of the `ORGN1` field, for use with [`FieldAccess`].
+    pub const ORGN1_FIELD: Field<Self, u8, 26, 2> = Field::new("TCR_EL1", "ORGN1");
+    /// Descriptor of the `SH1` field, for use with [`FieldAccess`].
+    pub const SH1_FIELD: Field<Self, u8, 28, 2> = Field::new("TCR_EL1", "SH1");
+    /// Descriptor of the `TG1` field, for use with [`FieldAccess`].
+    pub const TG1_FIELD: Field<Self, u8, 30, 2> = Field::new("TCR_EL1", "TG1");
+    /// Descriptor of the `IPS` field, for use with [`FieldAccess`].
+    pub const IPS_FIELD: Field<Self, u8, 32, 3> = Field::new("TCR_EL1", "IPS");
+    /// Descriptor of the `AS` field, for use with [`FieldAccess`].
+    pub const AS_FIELD: Field<Self, bool, 36, 1> = Field::new("TCR_EL1", "AS");
+    /// Descriptor of the `TBI0` field, for use with [`FieldAccess`].
+    pub const TBI0_FIELD: Field<Self, bool, 37, 1> = Field::new("TCR_EL1", "TBI0");
+    /// Descriptor of the `TBI1` field, for use with [`FieldAccess`].
+    pub const TBI1_FIELD: Field<Self, bool, 38, 1> = Field::new("TCR_EL1", "TBI1");
+    /// Descriptor of the `HA` field, for use with [`FieldAccess`].
+    pub const HA_FIELD: Field<Self, bool, 39, 1> = Field::new("TCR_EL1", "HA");
+    /// Descriptor of the `HD` field, for use with [`FieldAccess`].
+    pub const HD_FIELD: Field<Self, bool, 40, 1> = Field::new("TCR_EL1", "HD");
+    /// Descriptor of the `HPD0` field, for use with [`FieldAccess`].
+    pub const HPD0_FIELD: Field<Self, bool, 41, 1> = Field::new("TCR_EL1", "HPD0");
+    /// Descriptor of the `HPD1` field, for use with [`FieldAccess`].
+    pub const HPD1_FIELD: Field<Self, bool, 42, 1> = Field::new("TCR_EL1", "HPD1");
+    /// Descriptor of the `HWU059` field, for use with [`FieldAccess`].
+    pub const HWU059_FIELD: Field<Self, bool, 43, 1> = Field::new("TCR_EL1", "HWU059");
+    /// Descriptor of the `HWU060` field, for use with [`FieldAccess`].
+    pub const HWU060_FIELD: Field<Self, bool, 44, 1> = Field::new("TCR_EL1", "HWU060");
+    /// Descriptor of the `HWU061` field, for use with [`FieldAccess`].
+    pub const HWU061_FIELD: Field<Self, bool, 45, 1> = Field::new("TCR_EL1", "HWU061");
+    /// Descriptor of the `HWU062` field, for use with [`FieldAccess`].
+    pub const HWU062_FIELD: Field<Self, bool, 46, 1> = Field::new("TCR_EL1", "HWU062");
+    /// Descriptor of the `HWU159` field, for use with [`FieldAccess`].
+    pub const HWU159_FIELD: Field<Self, bool, 47, 1> = Field::new("TCR_EL1", "HWU159");
+    /// Descriptor of the `HWU160` field, for use with [`FieldAccess`].
+    pub const HWU160_FIELD: Field<Self, bool, 48, 1> = Field::new("TCR_EL1", "HWU160");
+    /// Descriptor of the `HWU161` field, for use with [`FieldAccess`].
+    pub const HWU161_FIELD: Field<Self, bool, 49, 1> = Field::new("TCR_EL1", "HWU161");
+    /// Descriptor of the `HWU162` field, for use with [`FieldAccess`].
+    pub const HWU162_FIELD: Field<Self, bool, 50, 1> = Field::new("TCR_EL1", "HWU162");
+    /// Descriptor of the `TBID0` field, for use with [`FieldAccess`].
+    pub const TBID0_FIELD: Field<Self, bool, 51, 1> = Field::new("TCR_EL1", "TBID0");
+    /// Descriptor of the `TBID1` field, for use with [`FieldAccess`].
+    pub const TBID1_FIELD: Field<Self, bool, 52, 1> = Field::new("TCR_EL1", "TBID1");
+    /// Descriptor of the `NFD0` field, for use with [`FieldAccess`].
+    pub const NFD0_FIELD: Field<Self, bool, 53, 1> = Field::new("TCR_EL1", "NFD0");
+    /// Descriptor of the `NFD1` field, for use with [`FieldAccess`].
+    pub const NFD1_FIELD: Field<Self, bool, 54, 1> = Field::new("TCR_EL1", "NFD1");
+    /// Descriptor of the `E0PD0` field, for use with [`FieldAccess`].
+    pub const E0PD0_FIELD: Field<Self, bool, 55, 1> = Field::new("TCR_EL1", "E0PD0");
+    /// Descriptor of the `E0PD1` field, for use with [`FieldAccess`].
+    pub const E0PD1_FIELD: Field<Self, bool, 56, 1> = Field::new("TCR_EL1", "E0PD1");
+    /// Descriptor of the `TCMA0` field, for use with [`FieldAccess`].
+    pub const TCMA0_FIELD: Field<Self, bool, 57, 1> = Field::new("TCR_EL1", "TCMA0");
+    /// Descriptor of the `TCMA1` field, for use with [`FieldAccess`].
+    pub const TCMA1_FIELD: Field<Self, bool, 58, 1> = Field::new("TCR_EL1", "TCMA1");
+    /// Descriptor of the `DS` field, for use with [`FieldAccess`].
+    pub const DS_FIELD: Field<Self, bool, 59, 1> = Field::new("TCR_EL1", "DS");
+    /// Descriptor of the `MTX0` field, for use with [`FieldAccess`].
+    pub const MTX0_FIELD: Field<Self, bool, 60, 1> = Field::new("TCR_EL1", "MTX0");
+    /// Descriptor of the `MTX1` field, for use with [`FieldAccess`].
+    pub const MTX1_FIELD: Field<Self, bool, 61, 1> = Field::new("TCR_EL1", "MTX1");
+
+    /// Returns the value of the `T0SZ` field.
+    pub const fn t0sz(self) -> u8 {
+        Self::T0SZ_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `T0SZ` field.
+    pub const fn set_t0sz(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::T0SZ_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `T0SZ` field set to the given value.
+    pub const fn with_t0sz(mut self, value: u8) -> Self {
+        self.set_t0sz(value);
+        self
+    }
+
+    /// Sets the value of the `T0SZ` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_t0sz(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_t0sz(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `T0SZ` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_t0sz(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::T0SZ_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `IRGN0` field.
+    pub const fn irgn0(self) -> u8 {
+        Self::IRGN0_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `IRGN0` field.
+    pub const fn set_irgn0(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::IRGN0_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `IRGN0` field set to the given value.
+    pub const fn with_irgn0(mut self, value: u8) -> Self {
+        self.set_irgn0(value);
+        self
+    }
+
+    /// Sets the value of the `IRGN0` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_irgn0(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_irgn0(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `IRGN0` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_irgn0(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::IRGN0_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ORGN0` field.
+    pub const fn orgn0(self) -> u8 {
+        Self::ORGN0_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `ORGN0` field.
+    pub const fn set_orgn0(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::ORGN0_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ORGN0` field set to the given value.
+    pub const fn with_orgn0(mut self, value: u8) -> Self {
+        self.set_orgn0(value);
+        self
+    }
+
+    /// Sets the value of the `ORGN0` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_orgn0(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_orgn0(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ORGN0` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_orgn0(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::ORGN0_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SH0` field.
+    pub const fn sh0(self) -> u8 {
+        Self::SH0_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SH0` field.
+    pub const fn set_sh0(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SH0_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SH0` field set to the given value.
+    pub const fn with_sh0(mut self, value: u8) -> Self {
+        self.set_sh0(value);
+        self
+    }
+
+    /// Sets the value of the `SH0` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_sh0(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_sh0(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SH0` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_sh0(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SH0_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `TG0` field.
+    pub const fn tg0(self) -> u8 {
+        Self::TG0_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `TG0` field.
+    pub const fn set_tg0(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::TG0_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `TG0` field set to the given value.
+    pub const fn with_tg0(mut self, value: u8) -> Self {
+        self.set_tg0(value);
+        self
+    }
+
+    /// Sets the value of the `TG0` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_tg0(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_tg0(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `TG0` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_tg0(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::TG0_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `T1SZ` field.
+    pub const fn t1sz(self) -> u8 {
+        Self::T1SZ_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `T1SZ` field.
+    pub const fn set_t1sz(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::T1SZ_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `T1SZ` field set to the given value.
+    pub const fn with_t1sz(mut self, value: u8) -> Self {
+        self.set_t1sz(value);
+        self
+    }
+
+    /// Sets the value of the `T1SZ` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_t1sz(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_t1sz(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `T1SZ` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_t1sz(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::T1SZ_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `IRGN1` field.
+    pub const fn irgn1(self) -> u8 {
+        Self::IRGN1_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `IRGN1` field.
+    pub const fn set_irgn1(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::IRGN1_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `IRGN1` field set to the given value.
+    pub const fn with_irgn1(mut self, value: u8) -> Self {
+        self.set_irgn1(value);
+        self
+    }
+
+    /// Sets the value of the `IRGN1` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_irgn1(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_irgn1(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `IRGN1` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_irgn1(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::IRGN1_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ORGN1` field.
+    pub const fn orgn1(self) -> u8 {
+        Self::ORGN1_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `ORGN1` field.
+    pub const fn set_orgn1(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::ORGN1_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ORGN1` field set to the given value.
+    pub const fn with_orgn1(mut self, value: u8) -> Self {
+        self.set_orgn1(value);
+        self
+    }
+
+    /// Sets the value of the `ORGN1` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_orgn1(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_orgn1(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ORGN1` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_orgn1(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::ORGN1_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SH1` field.
+    pub const fn sh1(self) -> u8 {
+        Self::SH1_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SH1` field.
+    pub const fn set_sh1(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SH1_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SH1` field set to the given value.
+    pub const fn with_sh1(mut self, value: u8) -> Self {
+        self.set_sh1(value);
+        self
+    }
+
+    /// Sets the value of the `SH1` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_sh1(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_sh1(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SH1` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_sh1(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SH1_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `TG1` field.
+    pub const fn tg1(self) -> u8 {
+        Self::TG1_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `TG1` field.
+    pub const fn set_tg1(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::TG1_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `TG1` field set to the given value.
+    pub const fn with_tg1(mut self, value: u8) -> Self {
+        self.set_tg1(value);
+        self
+    }
+
+    /// Sets the value of the `TG1` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_tg1(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_tg1(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `TG1` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_tg1(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::TG1_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `IPS` field.
+    pub const fn ips(self) -> u8 {
+        Self::IPS_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `IPS` field.
+    pub const fn set_ips(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::IPS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `IPS` field set to the given value.
+    pub const fn with_ips(mut self, value: u8) -> Self {
+        self.set_ips(value);
+        self
+    }
+
+    /// Sets the value of the `IPS` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ips(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ips(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `IPS` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ips(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::IPS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for TcrEl1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("TcrEl1");
+        let bits = self.bits();
+        s.field("T0SZ", &Self::T0SZ_FIELD.extract(bits));
+        s.field("EPD0", &Self::EPD0_FIELD.extract(bits));
+        s.field("IRGN0", &Self::IRGN0_FIELD.extract(bits));
+        s.field("ORGN0", &Self::ORGN0_FIELD.extract(bits));
+        s.field("SH0", &Self::SH0_FIELD.extract(bits));
+        s.field("TG0", &Self::TG0_FIELD.extract(bits));
+        s.field("T1SZ", &Self::T1SZ_FIELD.extract(bits));
+        s.field("A1", &Self::A1_FIELD.extract(bits));
+        s.field("EPD1", &Self::EPD1_FIELD.extract(bits));
+        s.field("IRGN1", &Self::IRGN1_FIELD.extract(bits));
+        s.field("ORGN1", &Self::ORGN1_FIELD.extract(bits));
+        s.field("SH1", &Self::SH1_FIELD.extract(bits));
+        s.field("TG1", &Self::TG1_FIELD.extract(bits));
+        s.field("IPS", &Self::IPS_FIELD.extract(bits));
+        s.field("AS", &Self::AS_FIELD.extract(bits));
+        s.field("TBI0", &Self::TBI0_FIELD.extract(bits));
+        s.field("TBI1", &Self::TBI1_FIELD.extract(bits));
+        s.field("HA", &Self::HA_FIELD.extract(bits));
+        s.field("HD", &Self::HD_FIELD.extract(bits));
+        s.field("HPD0", &Self::HPD0_FIELD.extract(bits));
+        s.field("HPD1", &Self::HPD1_FIELD.extract(bits));
+        s.field("HWU059", &Self::HWU059_FIELD.extract(bits));
+        s.field("HWU060", &Self::HWU060_FIELD.extract(bits));
+        s.field("HWU061", &Self::HWU061_FIELD.extract(bits));
+        s.field("HWU062", &Self::HWU062_FIELD.extract(bits));
+        s.field("HWU159", &Self::HWU159_FIELD.extract(bits));
+        s.field("HWU160", &Self::HWU160_FIELD.extract(bits));
+        s.field("HWU161", &Self::HWU161_FIELD.extract(bits));
+        s.field("HWU162", &Self::HWU162_FIELD.extract(bits));
+        s.field("TBID0", &Self::TBID0_FIELD.extract(bits));
+        s.field("TBID1", &Self::TBID1_FIELD.extract(bits));
+        s.field("NFD0", &Self::NFD0_FIELD.extract(bits));
+        s.field("NFD1", &Self::NFD1_FIELD.extract(bits));
+        s.field("E0PD0", &Self::E0PD0_FIELD.extract(bits));
+        s.field("E0PD1", &Self::E0PD1_FIELD.extract(bits));
+        s.field("TCMA0", &Self::TCMA0_FIELD.extract(bits));
+        s.field("TCMA1", &Self::TCMA1_FIELD.extract(bits));
+        s.field("DS", &Self::DS_FIELD.extract(bits));
+        s.field("MTX0", &Self::MTX0_FIELD.extract(bits));
+        s.field("MTX1", &Self::MTX1_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for TcrEl1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "TcrEl1 {{ T0SZ: {=u64}, EPD0: {=u64}, IRGN0: {=u64}, ORGN0: {=u64}, SH0: {=u64}, TG0: {=u64}, T1SZ: {=u64}, A1: {=u64}, EPD1: {=u64}, IRGN1: {=u64}, ORGN1: {=u64}, SH1: {=u64}, TG1: {=u64}, IPS: {=u64}, AS: {=u64}, TBI0: {=u64}, TBI1: {=u64}, HA: {=u64}, HD: {=u64}, HPD0: {=u64}, HPD1: {=u64}, HWU059: {=u64}, HWU060: {=u64}, HWU061: {=u64}, HWU062: {=u64}, HWU159: {=u64}, HWU160: {=u64}, HWU161: {=u64}, HWU162: {=u64}, TBID0: {=u64}, TBID1: {=u64}, NFD0: {=u64}, NFD1: {=u64}, E0PD0: {=u64}, E0PD1: {=u64}, TCMA0: {=u64}, TCMA1: {=u64}, DS: {=u64}, MTX0: {=u64}, MTX1: {=u64} }}",
+            Self::T0SZ_FIELD.extract(self.bits()),
+            Self::EPD0_FIELD.extract(self.bits()),
+            Self::IRGN0_FIELD.extract(self.bits()),
+            Self::ORGN0_FIELD.extract(self.bits()),
+            Self::SH0_FIELD.extract(self.bits()),
+            Self::TG0_FIELD.extract(self.bits()),
+            Self::T1SZ_FIELD.extract(self.bits()),
+            Self::A1_FIELD.extract(self.bits()),
+            Self::EPD1_FIELD.extract(self.bits()),
+            Self::IRGN1_FIELD.extract(self.bits()),
+            Self::ORGN1_FIELD.extract(self.bits()),
+            Self::SH1_FIELD.extract(self.bits()),
+            Self::TG1_FIELD.extract(self.bits()),
+            Self::IPS_FIELD.extract(self.bits()),
+            Self::AS_FIELD.extract(self.bits()),
+            Self::TBI0_FIELD.extract(self.bits()),
+            Self::TBI1_FIELD.extract(self.bits()),
+            Self::HA_FIELD.extract(self.bits()),
+            Self::HD_FIELD.extract(self.bits()),
+            Self::HPD0_FIELD.extract(self.bits()),
+            Self::HPD1_FIELD.extract(self.bits()),
+            Self::HWU059_FIELD.extract(self.bits()),
+            Self::HWU060_FIELD.extract(self.bits()),
+            Self::HWU061_FIELD.extract(self.bits()),
+            Self::HWU062_FIELD.extract(self.bits()),
+            Self::HWU159_FIELD.extract(self.bits()),
+            Self::HWU160_FIELD.extract(self.bits()),
+            Self::HWU161_FIELD.extract(self.bits()),
+            Self::HWU162_FIELD.extract(self.bits()),
+            Self::TBID0_FIELD.extract(self.bits()),
+            Self::TBID1_FIELD.extract(self.bits()),
+            Self::NFD0_FIELD.extract(self.bits()),
+            Self::NFD1_FIELD.extract(self.bits()),
+            Self::E0PD0_FIELD.extract(self.bits()),
+            Self::E0PD1_FIELD.extract(self.bits()),
+            Self::TCMA0_FIELD.extract(self.bits()),
+            Self::TCMA1_FIELD.extract(self.bits()),
+            Self::DS_FIELD.extract(self.bits()),
+            Self::MTX0_FIELD.extract(self.bits()),
+            Self::MTX1_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for TcrEl1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for TcrEl1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl TcrEl1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("T0SZ"),
+                old: Self::T0SZ_FIELD.extract(self.bits()),
+                new: Self::T0SZ_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EPD0"),
+                old: Self::EPD0_FIELD.extract(self.bits()),
+                new: Self::EPD0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IRGN0"),
+                old: Self::IRGN0_FIELD.extract(self.bits()),
+                new: Self::IRGN0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ORGN0"),
+                old: Self::ORGN0_FIELD.extract(self.bits()),
+                new: Self::ORGN0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SH0"),
+                old: Self::SH0_FIELD.extract(self.bits()),
+                new: Self::SH0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TG0"),
+                old: Self::TG0_FIELD.extract(self.bits()),
+                new: Self::TG0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("T1SZ"),
+                old: Self::T1SZ_FIELD.extract(self.bits()),
+                new: Self::T1SZ_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("A1"),
+                old: Self::A1_FIELD.extract(self.bits()),
+                new: Self::A1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EPD1"),
+                old: Self::EPD1_FIELD.extract(self.bits()),
+                new: Self::EPD1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IRGN1"),
+                old: Self::IRGN1_FIELD.extract(self.bits()),
+                new: Self::IRGN1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ORGN1"),
+                old: Self::ORGN1_FIELD.extract(self.bits()),
+                new: Self::ORGN1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SH1"),
+                old: Self::SH1_FIELD.extract(self.bits()),
+                new: Self::SH1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TG1"),
+                old: Self::TG1_FIELD.extract(self.bits()),
+                new: Self::TG1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IPS"),
+                old: Self::IPS_FIELD.extract(self.bits()),
+                new: Self::IPS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("AS"),
+                old: Self::AS_FIELD.extract(self.bits()),
+                new: Self::AS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TBI0"),
+                old: Self::TBI0_FIELD.extract(self.bits()),
+                new: Self::TBI0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TBI1"),
+                old: Self::TBI1_FIELD.extract(self.bits()),
+                new: Self::TBI1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HA"),
+                old: Self::HA_FIELD.extract(self.bits()),
+                new: Self::HA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HD"),
+                old: Self::HD_FIELD.extract(self.bits()),
+                new: Self::HD_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HPD0"),
+                old: Self::HPD0_FIELD.extract(self.bits()),
+                new: Self::HPD0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HPD1"),
+                old: Self::HPD1_FIELD.extract(self.bits()),
+                new: Self::HPD1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HWU059"),
+                old: Self::HWU059_FIELD.extract(self.bits()),
+                new: Self::HWU059_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HWU060"),
+                old: Self::HWU060_FIELD.extract(self.bits()),
+                new: Self::HWU060_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HWU061"),
+                old: Self::HWU061_FIELD.extract(self.bits()),
+                new: Self::HWU061_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HWU062"),
+                old: Self::HWU062_FIELD.extract(self.bits()),
+                new: Self::HWU062_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HWU159"),
+                old: Self::HWU159_FIELD.extract(self.bits()),
+                new: Self::HWU159_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HWU160"),
+                old: Self::HWU160_FIELD.extract(self.bits()),
+                new: Self::HWU160_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HWU161"),
+                old: Self::HWU161_FIELD.extract(self.bits()),
+                new: Self::HWU161_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HWU162"),
+                old: Self::HWU162_FIELD.extract(self.bits()),
+                new: Self::HWU162_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TBID0"),
+                old: Self::TBID0_FIELD.extract(self.bits()),
+                new: Self::TBID0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TBID1"),
+                old: Self::TBID1_FIELD.extract(self.bits()),
+                new: Self::TBID1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("NFD0"),
+                old: Self::NFD0_FIELD.extract(self.bits()),
+                new: Self::NFD0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("NFD1"),
+                old: Self::NFD1_FIELD.extract(self.bits()),
+                new: Self::NFD1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("E0PD0"),
+                old: Self::E0PD0_FIELD.extract(self.bits()),
+                new: Self::E0PD0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("E0PD1"),
+                old: Self::E0PD1_FIELD.extract(self.bits()),
+                new: Self::E0PD1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TCMA0"),
+                old: Self::TCMA0_FIELD.extract(self.bits()),
+                new: Self::TCMA0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TCMA1"),
+                old: Self::TCMA1_FIELD.extract(self.bits()),
+                new: Self::TCMA1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DS"),
+                old: Self::DS_FIELD.extract(self.bits()),
+                new: Self::DS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("MTX0"),
+                old: Self::MTX0_FIELD.extract(self.bits()),
+                new: Self::MTX0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("MTX1"),
+                old: Self::MTX1_FIELD.extract(self.bits()),
+                new: Self::MTX1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1100_0000_0000_0000_0000_0000_0000_1000_0000_0000_0000_0000_0000_0000_0100_0000,
+                new: other.bits() & 0b1100_0000_0000_0000_0000_0000_0000_1000_0000_0000_0000_0000_0000_0000_0100_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl TcrEl1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl TcrEl1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `TCR_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct TcrEl2: u64 {
+        /// RES1 bits in the `TCR_EL2` register.
+        const RES1 = 0b1000_0000_1000_0000_0000_0000_0000_0000;
+        /// `EPD0` bit.
+        const EPD0 = 1 << 7;
+        /// `TBI` bit.
+        const TBI = 1 << 20;
+        /// `A1` bit.
+        const A1 = 1 << 22;
+        /// `EPD1` bit.
+        const EPD1 = 1 << 23;
+        /// `HPD` bit.
+        const HPD = 1 << 24;
+        /// `HWU59` bit.
+        const HWU59 = 1 << 25;
+        /// `HWU60` bit.
+        const HWU60 = 1 << 26;
+        /// `HWU61` bit.
+        const HWU61 = 1 << 27;
+        /// `HWU62` bit.
+        const HWU62 = 1 << 28;
+        /// `TBID` bit.
+        const TBID = 1 << 29;
+        /// `TCMA` bit.
+        const TCMA = 1 << 30;
+        /// `MTX` bit.
+        const MTX = 1 << 33;
+        /// `AS` bit.
+        const AS = 1 << 36;
+        /// `TBI0` bit.
+        const TBI0 = 1 << 37;
+        /// `TBI1` bit.
+        const TBI1 = 1 << 38;
+        /// `HPD0` bit.
+        const HPD0 = 1 << 41;
+        /// `HPD1` bit.
+        const HPD1 = 1 << 42;
+        /// `HWU059` bit.
+        const HWU059 = 1 << 43;
+        /// `HWU060` bit.
+        const HWU060 = 1 << 44;
+        /// `HWU061` bit.
+        const HWU061 = 1 << 45;
+        /// `HWU062` bit.
+        const HWU062 = 1 << 46;
+        /// `HWU159` bit.
+        const HWU159 = 1 << 47;
+        /// `HWU160` bit.
+        const HWU160 = 1 << 48;
+        /// `HWU161` bit.
+        const HWU161 = 1 << 49;
+        /// `HWU162` bit.
+        const HWU162 = 1 << 50;
+        /// `TBID0` bit.
+        const TBID0 = 1 << 51;
+        /// `TBID1` bit.
+        const TBID1 = 1 << 52;
+        /// `NFD0` bit.
+        const NFD0 = 1 << 53;
+        /// `NFD1` bit.
+        const NFD1 = 1 << 54;
+        /// `E0PD0` bit.
+        const E0PD0 = 1 << 55;
+        /// `E0PD1` bit.
+        const E0PD1 = 1 << 56;
+        /// `TCMA0` bit.
+        const TCMA0 = 1 << 57;
+        /// `TCMA1` bit.
+        const TCMA1 = 1 << 58;
+        /// `MTX0` bit.
+        const MTX0 = 1 << 60;
+        /// `MTX1` bit.
+        const MTX1 = 1 << 61;
+    }
+}
+
+#[cfg(feature = "el2")]
+impl TcrEl2 {
+    /// Descriptor of the `T0SZ` field, for use with [`FieldAccess`].
+    pub const T0SZ_FIELD: Field<Self, u8, 0, 6> = Field::new("TCR_EL2", "T0SZ");
+    /// Descriptor of the `EPD0` field, for use with [`FieldAccess`].
+    pub const EPD0_FIELD: Field<Self, bool, 7, 1> = Field::new("TCR_EL2", "EPD0");
+    /// Descriptor of the `IRGN0` field, for use with [`FieldAccess`].
+    pub const IRGN0_FIELD: Field<Self, u8, 8, 2> = Field::new("TCR_EL2", "IRGN0");
+    /// Descriptor of the `ORGN0` field, for use with [`FieldAccess`].
+    pub const ORGN0_FIELD: Field<Self, u8, 10, 2> = Field::new("TCR_EL2", "ORGN0");
+    /// Descriptor of the `SH0` field, for use with [`FieldAccess`].
+    pub const SH0_FIELD: Field<Self, u8, 12, 2> = Field::new("TCR_EL2", "SH0");
+    /// Descriptor of the `TG0` field, for use with [`FieldAccess`].
+    pub const TG0_FIELD: Field<Self, u8, 14, 2> = Field::new("TCR_EL2", "TG0");
+    /// Descriptor of the `PS` field, for use with [`FieldAccess`].
+    pub const PS_FIELD: Field<Self, u8, 16, 3> = Field::new("TCR_EL2", "PS");
+    /// Descriptor of the `T1SZ` field, for use with [`FieldAccess`].
+    pub const T1SZ_FIELD: Field<Self, u8, 16, 6> = Field::new("TCR_EL2", "T1SZ");
+    /// Descriptor of the `TBI` field, for use with [`FieldAccess`].
+    pub const TBI_FIELD: Field<Self, bool, 20, 1> = Field::new("TCR_EL2", "TBI");
+    /// Descriptor of the `A1` field, for use with [`FieldAccess`].
+    pub const A1_FIELD: Field<Self, bool, 22, 1> = Field::new("TCR_EL2", "A1");
+    /// Descriptor of the `EPD1` field, for use with [`FieldAccess`].
+    pub const EPD1_FIELD: Field<Self, bool, 23, 1> = Field::new("TCR_EL2", "EPD1");
+    /// Descriptor of the `HPD` field, for use with [`FieldAccess`].
+    pub const HPD_FIELD: Field<Self, bool, 24, 1> = Field::new("TCR_EL2", "HPD");
+    /// Descriptor of the `IRGN1` field, for use with [`FieldAccess`].
+    pub const IRGN1_FIELD: Field<Self, u8, 24, 2> = Field::new("TCR_EL2", "IRGN1");
+    /// Descriptor of the `HWU59` field, for use with [`FieldAccess`].
+    pub const HWU59_FIELD: Field<Self, bool, 25, 1> = Field::new("TCR_EL2", "HWU59");
+    /// Descriptor of the `HWU60` field, for use with [`FieldAccess`].
+    pub const HWU60_FIELD: Field<Self, bool, 26, 1> = Field::new("TCR_EL2", "HWU60");
+    /// Descriptor of the `ORGN1` field, for use with [`FieldAccess`].
+    pub const ORGN1_FIELD: Field<Self, u8, 26, 2> = Field::new("TCR_EL2", "ORGN1");
+    /// Descriptor of the `HWU61` field, for use with [`FieldAccess`].
+    pub const HWU61_FIELD: Field<Self, bool, 27, 1> = Field::new("TCR_EL2", "HWU61");
+    /// Descriptor of the `HWU62` field, for use with [`FieldAccess`].
+    pub const HWU62_FIELD: Field<Self, bool, 28, 1> = Field::new("TCR_EL2", "HWU62");
+    /// Descriptor of the `SH1` field, for use with [`FieldAccess`].
+    pub const SH1_FIELD: Field<Self, u8, 28, 2> = Field::new("TCR_EL2", "SH1");
+    /// Descriptor of the `TBID` field, for use with [`FieldAccess`].
+    pub const TBID_FIELD: Field<Self, bool, 29, 1> = Field::new("TCR_EL2", "TBID");
+    /// Descriptor of the `TCMA` field, for use with [`FieldAccess`].
+    pub const TCMA_FIELD: Field<Self, bool, 30, 1> = Field::new("TCR_EL2", "TCMA");
+    /// Descriptor of the `TG1` field, for use with [`FieldAccess`].
+    pub const TG1_FIELD: Field<Self, u8, 30, 2> = Field::new("TCR_EL2", "TG1");
+    /// Descriptor of the `IPS` field, for use with [`FieldAccess`].
+    pub const IPS_FIELD: Field<Self, u8, 32, 3> = Field::new("TCR_EL2", "IPS");
+    /// Descriptor of the `MTX` field, for use with [`FieldAccess`].
+    pub const MTX_FIELD: Field<Self, bool, 33, 1> = Field::new("TCR_EL2", "MTX");
+    /// Descriptor of the `AS` field, for use with [`FieldAccess`].
+    pub const AS_FIELD: Field<Self, bool, 36, 1> = Field::new("TCR_EL2", "AS");
+    /// Descriptor of the `TBI0` field, for use with [`FieldAccess`].
+    pub const TBI0_FIELD: Field<Self, bool, 37, 1> = Field::new("TCR_EL2", "TBI0");
+    /// Descriptor of the `TBI1` field, for use with [`FieldAccess`].
+    pub const TBI1_FIELD: Field<Self, bool, 38, 1> = Field::new("TCR_EL2", "TBI1");
+    /// Descriptor of the `HPD0` field, for use with [`FieldAccess`].
+    pub const HPD0_FIELD: Field<Self, bool, 41, 1> = Field::new("TCR_EL2", "HPD0");
+    /// Descriptor of the `HPD1` field, for use with [`FieldAccess`].
+    pub const HPD1_FIELD: Field<Self, bool, 42, 1> = Field::new("TCR_EL2", "HPD1");
+    /// Descriptor of the `HWU059` field, for use with [`FieldAccess`].
+    pub const HWU059_FIELD: Field<Self, bool, 43, 1> = Field::new("TCR_EL2", "HWU059");
+    /// Descriptor of the `HWU060` field, for use with [`FieldAccess`].
+    pub const HWU060_FIELD: Field<Self, bool, 44, 1> = Field::new("TCR_EL2", "HWU060");
+    /// Descriptor of the `HWU061` field, for use with [`FieldAccess`].
+    pub const HWU061_FIELD: Field<Self, bool, 45, 1> = Field::new("TCR_EL2", "HWU061");
+    /// Descriptor of the `HWU062` field, for use with [`FieldAccess`].
+    pub const HWU062_FIELD: Field<Self, bool, 46, 1> = Field::new("TCR_EL2", "HWU062");
+    /// Descriptor of the `HWU159` field, for use with [`FieldAccess`].
+    pub const HWU159_FIELD: Field<Self, bool, 47, 1> = Field::new("TCR_EL2", "HWU159");
+    /// Descriptor of the `HWU160` field, for use with [`FieldAccess`].
+    pub const HWU160_FIELD: Field<Self, bool, 48, 1> = Field::new("TCR_EL2", "HWU160");
+    /// Descriptor of the `HWU161` field, for use with [`FieldAccess`].
+    pub const HWU161_FIELD: Field<Self, bool, 49, 1> = Field::new("TCR_EL2", "HWU161");
+    /// Descriptor of the `HWU162` field, for use with [`FieldAccess`].
+    pub const HWU162_FIELD: Field<Self, bool, 50, 1> = Field::new("TCR_EL2", "HWU162");
+    /// Descriptor of the `TBID0` field, for use with [`FieldAccess`].
+    pub const TBID0_FIELD: Field<Self, bool, 51, 1> = Field::new("TCR_EL2", "TBID0");
+    /// Descriptor of the `TBID1` field, for use with [`FieldAccess`].
+    pub const TBID1_FIELD: Field<Self, bool, 52, 1> = Field::new("TCR_EL2", "TBID1");
+    /// Descriptor of the `NFD0` field, for use with [`FieldAccess`].
+    pub const NFD0_FIELD: Field<Self, bool, 53, 1> = Field::new("TCR_EL2", "NFD0");
+    /// Descriptor of the `NFD1` field, for use with [`FieldAccess`].
+    pub const NFD1_FIELD: Field<Self, bool, 54, 1> = Field::new("TCR_EL2", "NFD1");
+    /// Descriptor of the `E0PD0` field, for use with [`FieldAccess`].
+    pub const E0PD0_FIELD: Field<Self, bool, 55, 1> = Field::new("TCR_EL2", "E0PD0");
+    /// Descriptor of the `E0PD1` field, for use with [`FieldAccess`].
+    pub const E0PD1_FIELD: Field<Self, bool, 56, 1> = Field::new("TCR_EL2", "E0PD1");
+    /// Descriptor of the `TCMA0` field, for use with [`FieldAccess`].
+    pub const TCMA0_FIELD: Field<Self, bool, 57, 1> = Field::new("TCR_EL2", "TCMA0");
+    /// Descriptor of the `TCMA1` field, for use with [`FieldAccess`].
+    pub const TCMA1_FIELD: Field<Self, bool, 58, 1> = Field::new("TCR_EL2", "TCMA1");
+    /// Descriptor of the `MTX0` field, for use with [`FieldAccess`].
+    pub const MTX0_FIELD: Field<Self, bool, 60, 1> = Field::new("TCR_EL2", "MTX0");
+    /// Descriptor of the `MTX1` field, for use with [`FieldAccess`].
+    pub const MTX1_FIELD: Field<Self, bool, 61, 1> = Field::new("TCR_EL2", "MTX1");
+
+    /// Returns the value of the `T0SZ` field.
+    pub const fn t0sz(self) -> u8 {
+        Self::T0SZ_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `T0SZ` field.
+    pub const fn set_t0sz(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::T0SZ_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `T0SZ` field set to the given value.
+    pub const fn with_t0sz(mut self, value: u8) -> Self {
+        self.set_t0sz(value);
+        self
+    }
+
+    /// Sets the value of the `T0SZ` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_t0sz(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_t0sz(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `T0SZ` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_t0sz(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::T0SZ_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `IRGN0` field.
+    pub const fn irgn0(self) -> u8 {
+        Self::IRGN0_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `IRGN0` field.
+    pub const fn set_irgn0(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::IRGN0_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `IRGN0` field set to the given value.
+    pub const fn with_irgn0(mut self, value: u8) -> Self {
+        self.set_irgn0(value);
+        self
+    }
+
+    /// Sets the value of the `IRGN0` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_irgn0(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_irgn0(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `IRGN0` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_irgn0(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::IRGN0_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ORGN0` field.
+    pub const fn orgn0(self) -> u8 {
+        Self::ORGN0_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `ORGN0` field.
+    pub const fn set_orgn0(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::ORGN0_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ORGN0` field set to the given value.
+    pub const fn with_orgn0(mut self, value: u8) -> Self {
+        self.set_orgn0(value);
+        self
+    }
+
+    /// Sets the value of the `ORGN0` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_orgn0(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_orgn0(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ORGN0` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_orgn0(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::ORGN0_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SH0` field.
+    pub const fn sh0(self) -> u8 {
+        Self::SH0_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SH0` field.
+    pub const fn set_sh0(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SH0_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SH0` field set to the given value.
+    pub const fn with_sh0(mut self, value: u8) -> Self {
+        self.set_sh0(value);
+        self
+    }
+
+    /// Sets the value of the `SH0` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_sh0(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_sh0(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SH0` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_sh0(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SH0_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `TG0` field.
+    pub const fn tg0(self) -> u8 {
+        Self::TG0_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `TG0` field.
+    pub const fn set_tg0(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::TG0_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `TG0` field set to the given value.
+    pub const fn with_tg0(mut self, value: u8) -> Self {
+        self.set_tg0(value);
+        self
+    }
+
+    /// Sets the value of the `TG0` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_tg0(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_tg0(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `TG0` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_tg0(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::TG0_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PS` field.
+    pub const fn ps(self) -> u8 {
+        Self::PS_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `PS` field.
+    pub const fn set_ps(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::PS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PS` field set to the given value.
+    pub const fn with_ps(mut self, value: u8) -> Self {
+        self.set_ps(value);
+        self
+    }
+
+    /// Sets the value of the `PS` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ps(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ps(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PS` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ps(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::PS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `T1SZ` field.
+    pub const fn t1sz(self) -> u8 {
+        Self::T1SZ_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `T1SZ` field.
+    pub const fn set_t1sz(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::T1SZ_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `T1SZ` field set to the given value.
+    pub const fn with_t1sz(mut self, value: u8) -> Self {
+        self.set_t1sz(value);
+        self
+    }
+
+    /// Sets the value of the `T1SZ` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_t1sz(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_t1sz(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `T1SZ` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_t1sz(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::T1SZ_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `IRGN1` field.
+    pub const fn irgn1(self) -> u8 {
+        Self::IRGN1_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `IRGN1` field.
+    pub const fn set_irgn1(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::IRGN1_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `IRGN1` field set to the given value.
+    pub const fn with_irgn1(mut self, value: u8) -> Self {
+        self.set_irgn1(value);
+        self
+    }
+
+    /// Sets the value of the `IRGN1` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_irgn1(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_irgn1(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `IRGN1` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_irgn1(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::IRGN1_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ORGN1` field.
+    pub const fn orgn1(self) -> u8 {
+        Self::ORGN1_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `ORGN1` field.
+    pub const fn set_orgn1(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::ORGN1_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ORGN1` field set to the given value.
+    pub const fn with_orgn1(mut self, value: u8) -> Self {
+        self.set_orgn1(value);
+        self
+    }
+
+    /// Sets the value of the `ORGN1` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_orgn1(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_orgn1(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ORGN1` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_orgn1(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::ORGN1_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SH1` field.
+    pub const fn sh1(self) -> u8 {
+        Self::SH1_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SH1` field.
+    pub const fn set_sh1(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SH1_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SH1` field set to the given value.
+    pub const fn with_sh1(mut self, value: u8) -> Self {
+        self.set_sh1(value);
+        self
+    }
+
+    /// Sets the value of the `SH1` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_sh1(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_sh1(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SH1` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_sh1(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SH1_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `TG1` field.
+    pub const fn tg1(self) -> u8 {
+        Self::TG1_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `TG1` field.
+    pub const fn set_tg1(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::TG1_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `TG1` field set to the given value.
+    pub const fn with_tg1(mut self, value: u8) -> Self {
+        self.set_tg1(value);
+        self
+    }
+
+    /// Sets the value of the `TG1` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_tg1(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_tg1(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `TG1` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_tg1(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::TG1_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `IPS` field.
+    pub const fn ips(self) -> u8 {
+        Self::IPS_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `IPS` field.
+    pub const fn set_ips(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::IPS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `IPS` field set to the given value.
+    pub const fn with_ips(mut self, value: u8) -> Self {
+        self.set_ips(value);
+        self
+    }
+
+    /// Sets the value of the `IPS` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ips(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ips(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `IPS` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ips(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::IPS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Display for TcrEl2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("TcrEl2");
+        let bits = self.bits();
+        s.field("T0SZ", &Self::T0SZ_FIELD.extract(bits));
+        s.field("EPD0", &Self::EPD0_FIELD.extract(bits));
+        s.field("IRGN0", &Self::IRGN0_FIELD.extract(bits));
+        s.field("ORGN0", &Self::ORGN0_FIELD.extract(bits));
+        s.field("SH0", &Self::SH0_FIELD.extract(bits));
+        s.field("TG0", &Self::TG0_FIELD.extract(bits));
+        s.field("PS", &Self::PS_FIELD.extract(bits));
+        s.field("T1SZ", &Self::T1SZ_FIELD.extract(bits));
+        s.field("TBI", &Self::TBI_FIELD.extract(bits));
+        s.field("A1", &Self::A1_FIELD.extract(bits));
+        s.field("EPD1", &Self::EPD1_FIELD.extract(bits));
+        s.field("HPD", &Self::HPD_FIELD.extract(bits));
+        s.field("IRGN1", &Self::IRGN1_FIELD.extract(bits));
+        s.field("HWU59", &Self::HWU59_FIELD.extract(bits));
+        s.field("HWU60", &Self::HWU60_FIELD.extract(bits));
+        s.field("ORGN1", &Self::ORGN1_FIELD.extract(bits));
+        s.field("HWU61", &Self::HWU61_FIELD.extract(bits));
+        s.field("HWU62", &Self::HWU62_FIELD.extract(bits));
+        s.field("SH1", &Self::SH1_FIELD.extract(bits));
+        s.field("TBID", &Self::TBID_FIELD.extract(bits));
+        s.field("TCMA", &Self::TCMA_FIELD.extract(bits));
+        s.field("TG1", &Self::TG1_FIELD.extract(bits));
+        s.field("IPS", &Self::IPS_FIELD.extract(bits));
+        s.field("MTX", &Self::MTX_FIELD.extract(bits));
+        s.field("AS", &Self::AS_FIELD.extract(bits));
+        s.field("TBI0", &Self::TBI0_FIELD.extract(bits));
+        s.field("TBI1", &Self::TBI1_FIELD.extract(bits));
+        s.field("HPD0", &Self::HPD0_FIELD.extract(bits));
+        s.field("HPD1", &Self::HPD1_FIELD.extract(bits));
+        s.field("HWU059", &Self::HWU059_FIELD.extract(bits));
+        s.field("HWU060", &Self::HWU060_FIELD.extract(bits));
+        s.field("HWU061", &Self::HWU061_FIELD.extract(bits));
+        s.field("HWU062", &Self::HWU062_FIELD.extract(bits));
+        s.field("HWU159", &Self::HWU159_FIELD.extract(bits));
+        s.field("HWU160", &Self::HWU160_FIELD.extract(bits));
+        s.field("HWU161", &Self::HWU161_FIELD.extract(bits));
+        s.field("HWU162", &Self::HWU162_FIELD.extract(bits));
+        s.field("TBID0", &Self::TBID0_FIELD.extract(bits));
+        s.field("TBID1", &Self::TBID1_FIELD.extract(bits));
+        s.field("NFD0", &Self::NFD0_FIELD.extract(bits));
+        s.field("NFD1", &Self::NFD1_FIELD.extract(bits));
+        s.field("E0PD0", &Self::E0PD0_FIELD.extract(bits));
+        s.field("E0PD1", &Self::E0PD1_FIELD.extract(bits));
+        s.field("TCMA0", &Self::TCMA0_FIELD.extract(bits));
+        s.field("TCMA1", &Self::TCMA1_FIELD.extract(bits));
+        s.field("MTX0", &Self::MTX0_FIELD.extract(bits));
+        s.field("MTX1", &Self::MTX1_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for TcrEl2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "TcrEl2 {{ T0SZ: {=u64}, EPD0: {=u64}, IRGN0: {=u64}, ORGN0: {=u64}, SH0: {=u64}, TG0: {=u64}, PS: {=u64}, T1SZ: {=u64}, TBI: {=u64}, A1: {=u64}, EPD1: {=u64}, HPD: {=u64}, IRGN1: {=u64}, HWU59: {=u64}, HWU60: {=u64}, ORGN1: {=u64}, HWU61: {=u64}, HWU62: {=u64}, SH1: {=u64}, TBID: {=u64}, TCMA: {=u64}, TG1: {=u64}, IPS: {=u64}, MTX: {=u64}, AS: {=u64}, TBI0: {=u64}, TBI1: {=u64}, HPD0: {=u64}, HPD1: {=u64}, HWU059: {=u64}, HWU060: {=u64}, HWU061: {=u64}, HWU062: {=u64}, HWU159: {=u64}, HWU160: {=u64}, HWU161: {=u64}, HWU162: {=u64}, TBID0: {=u64}, TBID1: {=u64}, NFD0: {=u64}, NFD1: {=u64}, E0PD0: {=u64}, E0PD1: {=u64}, TCMA0: {=u64}, TCMA1: {=u64}, MTX0: {=u64}, MTX1: {=u64} }}",
+            Self::T0SZ_FIELD.extract(self.bits()),
+            Self::EPD0_FIELD.extract(self.bits()),
+            Self::IRGN0_FIELD.extract(self.bits()),
+            Self::ORGN0_FIELD.extract(self.bits()),
+            Self::SH0_FIELD.extract(self.bits()),
+            Self::TG0_FIELD.extract(self.bits()),
+            Self::PS_FIELD.extract(self.bits()),
+            Self::T1SZ_FIELD.extract(self.bits()),
+            Self::TBI_FIELD.extract(self.bits()),
+            Self::A1_FIELD.extract(self.bits()),
+            Self::EPD1_FIELD.extract(self.bits()),
+            Self::HPD_FIELD.extract(self.bits()),
+            Self::IRGN1_FIELD.extract(self.bits()),
+            Self::HWU59_FIELD.extract(self.bits()),
+            Self::HWU60_FIELD.extract(self.bits()),
+            Self::ORGN1_FIELD.extract(self.bits()),
+            Self::HWU61_FIELD.extract(self.bits()),
+            Self::HWU62_FIELD.extract(self.bits()),
+            Self::SH1_FIELD.extract(self.bits()),
+            Self::TBID_FIELD.extract(self.bits()),
+            Self::TCMA_FIELD.extract(self.bits()),
+            Self::TG1_FIELD.extract(self.bits()),
+            Self::IPS_FIELD.extract(self.bits()),
+            Self::MTX_FIELD.extract(self.bits()),
+            Self::AS_FIELD.extract(self.bits()),
+            Self::TBI0_FIELD.extract(self.bits()),
+            Self::TBI1_FIELD.extract(self.bits()),
+            Self::HPD0_FIELD.extract(self.bits()),
+            Self::HPD1_FIELD.extract(self.bits()),
+            Self::HWU059_FIELD.extract(self.bits()),
+            Self::HWU060_FIELD.extract(self.bits()),
+            Self::HWU061_FIELD.extract(self.bits()),
+            Self::HWU062_FIELD.extract(self.bits()),
+            Self::HWU159_FIELD.extract(self.bits()),
+            Self::HWU160_FIELD.extract(self.bits()),
+            Self::HWU161_FIELD.extract(self.bits()),
+            Self::HWU162_FIELD.extract(self.bits()),
+            Self::TBID0_FIELD.extract(self.bits()),
+            Self::TBID1_FIELD.extract(self.bits()),
+            Self::NFD0_FIELD.extract(self.bits()),
+            Self::NFD1_FIELD.extract(self.bits()),
+            Self::E0PD0_FIELD.extract(self.bits()),
+            Self::E0PD1_FIELD.extract(self.bits()),
+            Self::TCMA0_FIELD.extract(self.bits()),
+            Self::TCMA1_FIELD.extract(self.bits()),
+            Self::MTX0_FIELD.extract(self.bits()),
+            Self::MTX1_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for TcrEl2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for TcrEl2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl TcrEl2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("T0SZ"),
+                old: Self::T0SZ_FIELD.extract(self.bits()),
+                new: Self::T0SZ_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EPD0"),
+                old: Self::EPD0_FIELD.extract(self.bits()),
+                new: Self::EPD0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IRGN0"),
+                old: Self::IRGN0_FIELD.extract(self.bits()),
+                new: Self::IRGN0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ORGN0"),
+                old: Self::ORGN0_FIELD.extract(self.bits()),
+                new: Self::ORGN0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SH0"),
+                old: Self::SH0_FIELD.extract(self.bits()),
+                new: Self::SH0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TG0"),
+                old: Self::TG0_FIELD.extract(self.bits()),
+                new: Self::TG0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PS"),
+                old: Self::PS_FIELD.extract(self.bits()),
+                new: Self::PS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("T1SZ"),
+                old: Self::T1SZ_FIELD.extract(self.bits()),
+                new: Self::T1SZ_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TBI"),
+                old: Self::TBI_FIELD.extract(self.bits()),
+                new: Self::TBI_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("A1"),
+                old: Self::A1_FIELD.extract(self.bits()),
+                new: Self::A1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EPD1"),
+                old: Self::EPD1_FIELD.extract(self.bits()),
+                new: Self::EPD1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HPD"),
+                old: Self::HPD_FIELD.extract(self.bits()),
+                new: Self::HPD_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IRGN1"),
+                old: Self::IRGN1_FIELD.extract(self.bits()),
+                new: Self::IRGN1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HWU59"),
+                old: Self::HWU59_FIELD.extract(self.bits()),
+                new: Self::HWU59_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HWU60"),
+                old: Self::HWU60_FIELD.extract(self.bits()),
+                new: Self::HWU60_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ORGN1"),
+                old: Self::ORGN1_FIELD.extract(self.bits()),
+                new: Self::ORGN1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HWU61"),
+                old: Self::HWU61_FIELD.extract(self.bits()),
+                new: Self::HWU61_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HWU62"),
+                old: Self::HWU62_FIELD.extract(self.bits()),
+                new: Self::HWU62_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SH1"),
+                old: Self::SH1_FIELD.extract(self.bits()),
+                new: Self::SH1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TBID"),
+                old: Self::TBID_FIELD.extract(self.bits()),
+                new: Self::TBID_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TCMA"),
+                old: Self::TCMA_FIELD.extract(self.bits()),
+                new: Self::TCMA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TG1"),
+                old: Self::TG1_FIELD.extract(self.bits()),
+                new: Self::TG1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IPS"),
+                old: Self::IPS_FIELD.extract(self.bits()),
+                new: Self::IPS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("MTX"),
+                old: Self::MTX_FIELD.extract(self.bits()),
+                new: Self::MTX_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("AS"),
+                old: Self::AS_FIELD.extract(self.bits()),
+                new: Self::AS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TBI0"),
+                old: Self::TBI0_FIELD.extract(self.bits()),
+                new: Self::TBI0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TBI1"),
+                old: Self::TBI1_FIELD.extract(self.bits()),
+                new: Self::TBI1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HPD0"),
+                old: Self::HPD0_FIELD.extract(self.bits()),
+                new: Self::HPD0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HPD1"),
+                old: Self::HPD1_FIELD.extract(self.bits()),
+                new: Self::HPD1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HWU059"),
+                old: Self::HWU059_FIELD.extract(self.bits()),
+                new: Self::HWU059_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HWU060"),
+                old: Self::HWU060_FIELD.extract(self.bits()),
+                new: Self::HWU060_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HWU061"),
+                old: Self::HWU061_FIELD.extract(self.bits()),
+                new: Self::HWU061_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HWU062"),
+                old: Self::HWU062_FIELD.extract(self.bits()),
+                new: Self::HWU062_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HWU159"),
+                old: Self::HWU159_FIELD.extract(self.bits()),
+                new: Self::HWU159_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HWU160"),
+                old: Self::HWU160_FIELD.extract(self.bits()),
+                new: Self::HWU160_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HWU161"),
+                old: Self::HWU161_FIELD.extract(self.bits()),
+                new: Self::HWU161_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HWU162"),
+                old: Self::HWU162_FIELD.extract(self.bits()),
+                new: Self::HWU162_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TBID0"),
+                old: Self::TBID0_FIELD.extract(self.bits()),
+                new: Self::TBID0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TBID1"),
+                old: Self::TBID1_FIELD.extract(self.bits()),
+                new: Self::TBID1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("NFD0"),
+                old: Self::NFD0_FIELD.extract(self.bits()),
+                new: Self::NFD0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("NFD1"),
+                old: Self::NFD1_FIELD.extract(self.bits()),
+                new: Self::NFD1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("E0PD0"),
+                old: Self::E0PD0_FIELD.extract(self.bits()),
+                new: Self::E0PD0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("E0PD1"),
+                old: Self::E0PD1_FIELD.extract(self.bits()),
+                new: Self::E0PD1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TCMA0"),
+                old: Self::TCMA0_FIELD.extract(self.bits()),
+                new: Self::TCMA0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TCMA1"),
+                old: Self::TCMA1_FIELD.extract(self.bits()),
+                new: Self::TCMA1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("MTX0"),
+                old: Self::MTX0_FIELD.extract(self.bits()),
+                new: Self::MTX0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("MTX1"),
+                old: Self::MTX1_FIELD.extract(self.bits()),
+                new: Self::MTX1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1100_1000_0000_0000_0000_0001_1000_1000_0000_0000_0000_0000_0000_0000_0100_0000,
+                new: other.bits() & 0b1100_1000_0000_0000_0000_0001_1000_1000_0000_0000_0000_0000_0000_0000_0100_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl TcrEl2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b1000_0000_1000_0000_0000_0000_0000_0000;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl TcrEl2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el3")]
+bitflags! {
+    /// `TCR_EL3` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct TcrEl3: u64 {
+        /// RES1 bits in the `TCR_EL3` register.
+        const RES1 = 0b1000_0000_1000_0000_0000_0000_0000_0000;
+        /// `TBI` bit.
+        const TBI = 1 << 20;
+        /// `HA` bit.
+        const HA = 1 << 21;
+        /// `HD` bit.
+        const HD = 1 << 22;
+        /// `HPD` bit.
+        const HPD = 1 << 24;
+        /// `HWU59` bit.
+        const HWU59 = 1 << 25;
+        /// `HWU60` bit.
+        const HWU60 = 1 << 26;
+        /// `HWU61` bit.
+        const HWU61 = 1 << 27;
+        /// `HWU62` bit.
+        const HWU62 = 1 << 28;
+        /// `TBID` bit.
+        const TBID = 1 << 29;
+        /// `TCMA` bit.
+        const TCMA = 1 << 30;
+        /// `DS` bit.
+        const DS = 1 << 32;
+        /// `MTX` bit.
+        const MTX = 1 << 33;
+        /// `PnCH` bit.
+        const PNCH = 1 << 34;
+        /// `PIE` bit.
+        const PIE = 1 << 35;
+        /// `POE` bit.
+        const POE = 1 << 36;
+        /// `AIE` bit.
+        const AIE = 1 << 37;
+        /// `D128` bit.
+        const D128 = 1 << 38;
+        /// `PTTWI` bit.
+        const PTTWI = 1 << 41;
+        /// `HAFT` bit.
+        const HAFT = 1 << 42;
+        /// `DisCH0` bit.
+        const DISCH0 = 1 << 43;
+        /// `POE2F` bit.
+        const POE2F = 1 << 44;
+        /// `TVAD` bit.
+        const TVAD = 1 << 53;
+    }
+}
+
+#[cfg(feature = "el3")]
+impl TcrEl3 {
+    /// Descriptor of the `T0SZ` field, for use with [`FieldAccess`].
+    pub const T0SZ_FIELD: Field<Self, u8, 0, 6> = Field::new("TCR_EL3", "T0SZ");
+    /// Descriptor of the `IRGN0` field, for use with [`FieldAccess`].
+    pub const IRGN0_FIELD: Field<Self, u8, 8, 2> = Field::new("TCR_EL3", "IRGN0");
+    /// Descriptor of the `ORGN0` field, for use with [`FieldAccess`].
+    pub const ORGN0_FIELD: Field<Self, u8, 10, 2> = Field::new("TCR_EL3", "ORGN0");
+    /// Descriptor of the `SH0` field, for use with [`FieldAccess`].
+    pub const SH0_FIELD: Field<Self, u8, 12, 2> = Field::new("TCR_EL3", "SH0");
+    /// Descriptor of the `TG0` field, for use with [`FieldAccess`].
+    pub const TG0_FIELD: Field<Self, u8, 14, 2> = Field::new("TCR_EL3", "TG0");
+    /// Descriptor of the `PS` field, for use with [`FieldAccess`].
+    pub const PS_FIELD: Field<Self, u8, 16, 3> = Field::new("TCR_EL3", "PS");
+    /// Descriptor of the `TBI` field, for use with [`FieldAccess`].
+    pub const TBI_FIELD: Field<Self, bool, 20, 1> = Field::new("TCR_EL3", "TBI");
+    /// Descriptor of the `HA` field, for use with [`FieldAccess`].
+    pub const HA_FIELD: Field<Self, bool, 21, 1> = Field::new("TCR_EL3", "HA");
+    /// Descriptor of the `HD` field, for use with [`FieldAccess`].
+    pub const HD_FIELD: Field<Self, bool, 22, 1> = Field::new("TCR_EL3", "HD");
+    /// Descriptor of the `HPD` field, for use with [`FieldAccess`].
+    pub const HPD_FIELD: Field<Self, bool, 24, 1> = Field::new("TCR_EL3", "HPD");
+    /// Descriptor of the `HWU59` field, for use with [`FieldAccess`].
+    pub const HWU59_FIELD: Field<Self, bool, 25, 1> = Field::new("TCR_EL3", "HWU59");
+    /// Descriptor of the `HWU60` field, for use with [`FieldAccess`].
+    pub const HWU60_FIELD: Field<Self, bool, 26, 1> = Field::new("TCR_EL3", "HWU60");
+    /// Descriptor of the `HWU61` field, for use with [`FieldAccess`].
+    pub const HWU61_FIELD: Field<Self, bool, 27, 1> = Field::new("TCR_EL3", "HWU61");
+    /// Descriptor of the `HWU62` field, for use with [`FieldAccess`].
+    pub const HWU62_FIELD: Field<Self, bool, 28, 1> = Field::new("TCR_EL3", "HWU62");
+    /// Descriptor of the `TBID` field, for use with [`FieldAccess`].
+    pub const TBID_FIELD: Field<Self, bool, 29, 1> = Field::new("TCR_EL3", "TBID");
+    /// Descriptor of the `TCMA` field, for use with [`FieldAccess`].
+    pub const TCMA_FIELD: Field<Self, bool, 30, 1> = Field::new("TCR_EL3", "TCMA");
+    /// Descriptor of the `DS` field, for use with [`FieldAccess`].
+    pub const DS_FIELD: Field<Self, bool, 32, 1> = Field::new("TCR_EL3", "DS");
+    /// Descriptor of the `MTX` field, for use with [`FieldAccess`].
+    pub const MTX_FIELD: Field<Self, bool, 33, 1> = Field::new("TCR_EL3", "MTX");
+    /// Descriptor of the `PnCH` field, for use with [`FieldAccess`].
+    pub const PNCH_FIELD: Field<Self, bool, 34, 1> = Field::new("TCR_EL3", "PnCH");
+    /// Descriptor of the `PIE` field, for use with [`FieldAccess`].
+    pub const PIE_FIELD: Field<Self, bool, 35, 1> = Field::new("TCR_EL3", "PIE");
+    /// Descriptor of the `POE` field, for use with [`FieldAccess`].
+    pub const POE_FIELD: Field<Self, bool, 36, 1> = Field::new("TCR_EL3", "POE");
+    /// Descriptor of the `AIE` field, for use with [`FieldAccess`].
+    pub const AIE_FIELD: Field<Self, bool, 37, 1> = Field::new("TCR_EL3", "AIE");
+    /// Descriptor of the `D128` field, for use with [`FieldAccess`].
+    pub const D128_FIELD: Field<Self, bool, 38, 1> = Field::new("TCR_EL3", "D128");
+    /// Descriptor of the `PTTWI` field, for use with [`FieldAccess`].
+    pub const PTTWI_FIELD: Field<Self, bool, 41, 1> = Field::new("TCR_EL3", "PTTWI");
+    /// Descriptor of the `HAFT` field, for use with [`FieldAccess`].
+    pub const HAFT_FIELD: Field<Self, bool, 42, 1> = Field::new("TCR_EL3", "HAFT");
+    /// Descriptor of the `DisCH0` field, for use with [`FieldAccess`].
+    pub const DISCH0_FIELD: Field<Self, bool, 43, 1> = Field::new("TCR_EL3", "DisCH0");
+    /// Descriptor of the `POE2F` field, for use with [`FieldAccess`].
+    pub const POE2F_FIELD: Field<Self, bool, 44, 1> = Field::new("TCR_EL3", "POE2F");
+    /// Descriptor of the `POIW` field, for use with [`FieldAccess`].
+    pub const POIW_FIELD: Field<Self, u8, 45, 3> = Field::new("TCR_EL3", "POIW");
+    /// Descriptor of the `VTB` field, for use with [`FieldAccess`].
+    pub const VTB_FIELD: Field<Self, u8, 48, 5> = Field::new("TCR_EL3", "VTB");
+    /// Descriptor of the `TVAD` field, for use with [`FieldAccess`].
+    pub const TVAD_FIELD: Field<Self, bool, 53, 1> = Field::new("TCR_EL3", "TVAD");
+
+    /// Returns the value of the `T0SZ` field.
+    pub const fn t0sz(self) -> u8 {
+        Self::T0SZ_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `T0SZ` field.
+    pub const fn set_t0sz(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::T0SZ_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `T0SZ` field set to the given value.
+    pub const fn with_t0sz(mut self, value: u8) -> Self {
+        self.set_t0sz(value);
+        self
+    }
+
+    /// Sets the value of the `T0SZ` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_t0sz(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_t0sz(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `T0SZ` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_t0sz(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::T0SZ_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `IRGN0` field.
+    pub const fn irgn0(self) -> u8 {
+        Self::IRGN0_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `IRGN0` field.
+    pub const fn set_irgn0(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::IRGN0_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `IRGN0` field set to the given value.
+    pub const fn with_irgn0(mut self, value: u8) -> Self {
+        self.set_irgn0(value);
+        self
+    }
+
+    /// Sets the value of the `IRGN0` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_irgn0(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_irgn0(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `IRGN0` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_irgn0(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::IRGN0_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ORGN0` field.
+    pub const fn orgn0(self) -> u8 {
+        Self::ORGN0_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `ORGN0` field.
+    pub const fn set_orgn0(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::ORGN0_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ORGN0` field set to the given value.
+    pub const fn with_orgn0(mut self, value: u8) -> Self {
+        self.set_orgn0(value);
+        self
+    }
+
+    /// Sets the value of the `ORGN0` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_orgn0(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_orgn0(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ORGN0` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_orgn0(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::ORGN0_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SH0` field.
+    pub const fn sh0(self) -> u8 {
+        Self::SH0_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SH0` field.
+    pub const fn set_sh0(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SH0_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SH0` field set to the given value.
+    pub const fn with_sh0(mut self, value: u8) -> Self {
+        self.set_sh0(value);
+        self
+    }
+
+    /// Sets the value of the `SH0` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_sh0(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_sh0(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SH0` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_sh0(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SH0_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `TG0` field.
+    pub const fn tg0(self) -> u8 {
+        Self::TG0_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `TG0` field.
+    pub const fn set_tg0(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::TG0_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `TG0` field set to the given value.
+    pub const fn with_tg0(mut self, value: u8) -> Self {
+        self.set_tg0(value);
+        self
+    }
+
+    /// Sets the value of the `TG0` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_tg0(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_tg0(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `TG0` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_tg0(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::TG0_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PS` field.
+    pub const fn ps(self) -> u8 {
+        Self::PS_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `PS` field.
+    pub const fn set_ps(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::PS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PS` field set to the given value.
+    pub const fn with_ps(mut self, value: u8) -> Self {
+        self.set_ps(value);
+        self
+    }
+
+    /// Sets the value of the `PS` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ps(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ps(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PS` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ps(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::PS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `POIW` field.
+    pub const fn poiw(self) -> u8 {
+        Self::POIW_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `POIW` field.
+    pub const fn set_poiw(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::POIW_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `POIW` field set to the given value.
+    pub const fn with_poiw(mut self, value: u8) -> Self {
+        self.set_poiw(value);
+        self
+    }
+
+    /// Sets the value of the `POIW` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_poiw(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_poiw(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `POIW` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_poiw(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::POIW_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `VTB` field.
+    pub const fn vtb(self) -> u8 {
+        Self::VTB_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `VTB` field.
+    pub const fn set_vtb(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::VTB_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `VTB` field set to the given value.
+    pub const fn with_vtb(mut self, value: u8) -> Self {
+        self.set_vtb(value);
+        self
+    }
+
+    /// Sets the value of the `VTB` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_vtb(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_vtb(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `VTB` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_vtb(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::VTB_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el3")]
+impl Display for TcrEl3 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("TcrEl3");
+        let bits = self.bits();
+        s.field("T0SZ", &Self::T0SZ_FIELD.extract(bits));
+        s.field("IRGN0", &Self::IRGN0_FIELD.extract(bits));
+        s.field("ORGN0", &Self::ORGN0_FIELD.extract(bits));
+        s.field("SH0", &Self::SH0_FIELD.extract(bits));
+        s.field("TG0", &Self::TG0_FIELD.extract(bits));
+        s.field("PS", &Self::PS_FIELD.extract(bits));
+        s.field("TBI", &Self::TBI_FIELD.extract(bits));
+        s.field("HA", &Self::HA_FIELD.extract(bits));
+        s.field("HD", &Self::HD_FIELD.extract(bits));
+        s.field("HPD", &Self::HPD_FIELD.extract(bits));
+        s.field("HWU59", &Self::HWU59_FIELD.extract(bits));
+        s.field("HWU60", &Self::HWU60_FIELD.extract(bits));
+        s.field("HWU61", &Self::HWU61_FIELD.extract(bits));
+        s.field("HWU62", &Self::HWU62_FIELD.extract(bits));
+        s.field("TBID", &Self::TBID_FIELD.extract(bits));
+        s.field("TCMA", &Self::TCMA_FIELD.extract(bits));
+        s.field("DS", &Self::DS_FIELD.extract(bits));
+        s.field("MTX", &Self::MTX_FIELD.extract(bits));
+        s.field("PnCH", &Self::PNCH_FIELD.extract(bits));
+        s.field("PIE", &Self::PIE_FIELD.extract(bits));
+        s.field("POE", &Self::POE_FIELD.extract(bits));
+        s.field("AIE", &Self::AIE_FIELD.extract(bits));
+        s.field("D128", &Self::D128_FIELD.extract(bits));
+        s.field("PTTWI", &Self::PTTWI_FIELD.extract(bits));
+        s.field("HAFT", &Self::HAFT_FIELD.extract(bits));
+        s.field("DisCH0", &Self::DISCH0_FIELD.extract(bits));
+        s.field("POE2F", &Self::POE2F_FIELD.extract(bits));
+        s.field("POIW", &Self::POIW_FIELD.extract(bits));
+        s.field("VTB", &Self::VTB_FIELD.extract(bits));
+        s.field("TVAD", &Self::TVAD_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el3")]
+impl defmt::Format for TcrEl3 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "TcrEl3 {{ T0SZ: {=u64}, IRGN0: {=u64}, ORGN0: {=u64}, SH0: {=u64}, TG0: {=u64}, PS: {=u64}, TBI: {=u64}, HA: {=u64}, HD: {=u64}, HPD: {=u64}, HWU59: {=u64}, HWU60: {=u64}, HWU61: {=u64}, HWU62: {=u64}, TBID: {=u64}, TCMA: {=u64}, DS: {=u64}, MTX: {=u64}, PnCH: {=u64}, PIE: {=u64}, POE: {=u64}, AIE: {=u64}, D128: {=u64}, PTTWI: {=u64}, HAFT: {=u64}, DisCH0: {=u64}, POE2F: {=u64}, POIW: {=u64}, VTB: {=u64}, TVAD: {=u64} }}",
+            Self::T0SZ_FIELD.extract(self.bits()),
+            Self::IRGN0_FIELD.extract(self.bits()),
+            Self::ORGN0_FIELD.extract(self.bits()),
+            Self::SH0_FIELD.extract(self.bits()),
+            Self::TG0_FIELD.extract(self.bits()),
+            Self::PS_FIELD.extract(self.bits()),
+            Self::TBI_FIELD.extract(self.bits()),
+            Self::HA_FIELD.extract(self.bits()),
+            Self::HD_FIELD.extract(self.bits()),
+            Self::HPD_FIELD.extract(self.bits()),
+            Self::HWU59_FIELD.extract(self.bits()),
+            Self::HWU60_FIELD.extract(self.bits()),
+            Self::HWU61_FIELD.extract(self.bits()),
+            Self::HWU62_FIELD.extract(self.bits()),
+            Self::TBID_FIELD.extract(self.bits()),
+            Self::TCMA_FIELD.extract(self.bits()),
+            Self::DS_FIELD.extract(self.bits()),
+            Self::MTX_FIELD.extract(self.bits()),
+            Self::PNCH_FIELD.extract(self.bits()),
+            Self::PIE_FIELD.extract(self.bits()),
+            Self::POE_FIELD.extract(self.bits()),
+            Self::AIE_FIELD.extract(self.bits()),
+            Self::D128_FIELD.extract(self.bits()),
+            Self::PTTWI_FIELD.extract(self.bits()),
+            Self::HAFT_FIELD.extract(self.bits()),
+            Self::DISCH0_FIELD.extract(self.bits()),
+            Self::POE2F_FIELD.extract(self.bits()),
+            Self::POIW_FIELD.extract(self.bits()),
+            Self::VTB_FIELD.extract(self.bits()),
+            Self::TVAD_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el3")]
+impl serde::Serialize for TcrEl3 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el3")]
+impl<'de> serde::Deserialize<'de> for TcrEl3 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el3")]
+impl TcrEl3 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("T0SZ"),
+                old: Self::T0SZ_FIELD.extract(self.bits()),
+                new: Self::T0SZ_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IRGN0"),
+                old: Self::IRGN0_FIELD.extract(self.bits()),
+                new: Self::IRGN0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ORGN0"),
+                old: Self::ORGN0_FIELD.extract(self.bits()),
+                new: Self::ORGN0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SH0"),
+                old: Self::SH0_FIELD.extract(self.bits()),
+                new: Self::SH0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TG0"),
+                old: Self::TG0_FIELD.extract(self.bits()),
+                new: Self::TG0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PS"),
+                old: Self::PS_FIELD.extract(self.bits()),
+                new: Self::PS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TBI"),
+                old: Self::TBI_FIELD.extract(self.bits()),
+                new: Self::TBI_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HA"),
+                old: Self::HA_FIELD.extract(self.bits()),
+                new: Self::HA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HD"),
+                old: Self::HD_FIELD.extract(self.bits()),
+                new: Self::HD_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HPD"),
+                old: Self::HPD_FIELD.extract(self.bits()),
+                new: Self::HPD_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HWU59"),
+                old: Self::HWU59_FIELD.extract(self.bits()),
+                new: Self::HWU59_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HWU60"),
+                old: Self::HWU60_FIELD.extract(self.bits()),
+                new: Self::HWU60_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HWU61"),
+                old: Self::HWU61_FIELD.extract(self.bits()),
+                new: Self::HWU61_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HWU62"),
+                old: Self::HWU62_FIELD.extract(self.bits()),
+                new: Self::HWU62_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TBID"),
+                old: Self::TBID_FIELD.extract(self.bits()),
+                new: Self::TBID_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TCMA"),
+                old: Self::TCMA_FIELD.extract(self.bits()),
+                new: Self::TCMA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DS"),
+                old: Self::DS_FIELD.extract(self.bits()),
+                new: Self::DS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("MTX"),
+                old: Self::MTX_FIELD.extract(self.bits()),
+                new: Self::MTX_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PnCH"),
+                old: Self::PNCH_FIELD.extract(self.bits()),
+                new: Self::PNCH_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PIE"),
+                old: Self::PIE_FIELD.extract(self.bits()),
+                new: Self::PIE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("POE"),
+                old: Self::POE_FIELD.extract(self.bits()),
+                new: Self::POE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("AIE"),
+                old: Self::AIE_FIELD.extract(self.bits()),
+                new: Self::AIE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("D128"),
+                old: Self::D128_FIELD.extract(self.bits()),
+                new: Self::D128_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PTTWI"),
+                old: Self::PTTWI_FIELD.extract(self.bits()),
+                new: Self::PTTWI_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HAFT"),
+                old: Self::HAFT_FIELD.extract(self.bits()),
+                new: Self::HAFT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DisCH0"),
+                old: Self::DISCH0_FIELD.extract(self.bits()),
+                new: Self::DISCH0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("POE2F"),
+                old: Self::POE2F_FIELD.extract(self.bits()),
+                new: Self::POE2F_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("POIW"),
+                old: Self::POIW_FIELD.extract(self.bits()),
+                new: Self::POIW_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VTB"),
+                old: Self::VTB_FIELD.extract(self.bits()),
+                new: Self::VTB_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TVAD"),
+                old: Self::TVAD_FIELD.extract(self.bits()),
+                new: Self::TVAD_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1100_0000_0000_0001_1000_0000_1000_0000_1000_1000_0000_0000_1100_0000,
+                new: other.bits() & 0b1111_1111_1100_0000_0000_0001_1000_0000_1000_0000_1000_1000_0000_0000_1100_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el3")]
+impl TcrEl3 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b1000_0000_1000_0000_0000_0000_0000_0000;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el3")]
+impl TcrEl3 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `TTBR0_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct Ttbr0El1: u64 {
+        /// `CnP` bit.
+        const CNP = 1 << 0;
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Ttbr0El1 {
+    /// Descriptor of the `CnP` field, for use with [`FieldAccess`].
+    pub const CNP_FIELD: Field<Self, bool, 0, 1> = Field::new("TTBR0_EL1", "CnP");
+    /// Descriptor of the `BADDR[47:1]` field, for use with [`FieldAccess`].
+    pub const BADDR_47_1_FIELD: Field<Self, u64, 1, 47> = Field::new("TTBR0_EL1", "BADDR[47:1]");
+    /// Descriptor of the `SKL` field, for use with [`FieldAccess`].
+    pub const SKL_FIELD: Field<Self, u8, 1, 2> = Field::new("TTBR0_EL1", "SKL");
+    /// Descriptor of the `ASID` field, for use with [`FieldAccess`].
+    pub const ASID_FIELD: Field<Self, u16, 48, 16> = Field::new("TTBR0_EL1", "ASID");
+
+    /// Returns the value of the `BADDR[47:1]` field.
+    pub const fn baddr_47_1(self) -> u64 {
+        Self::BADDR_47_1_FIELD.extract(self.bits())
+    }
+
+    /// Sets the value of the `BADDR[47:1]` field.
+    pub const fn set_baddr_47_1(&mut self, value: u64) {
+        *self = Self::from_bits_retain(Self::BADDR_47_1_FIELD.insert(self.bits(), value));
+    }
+
+    /// Returns a copy with the `BADDR[47:1]` field set to the given value.
+    pub const fn with_baddr_47_1(mut self, value: u64) -> Self {
+        self.set_baddr_47_1(value);
+        self
+    }
+
+    /// Sets the value of the `BADDR[47:1]` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_baddr_47_1(&mut self, value: u64) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_baddr_47_1(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `BADDR[47:1]` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_baddr_47_1(self, value: u64) -> Result<Self, FieldOverflow> {
+        match Self::BADDR_47_1_FIELD.try_insert(self.bits(), value) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SKL` field.
+    pub const fn skl(self) -> u8 {
+        Self::SKL_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SKL` field.
+    pub const fn set_skl(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SKL_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SKL` field set to the given value.
+    pub const fn with_skl(mut self, value: u8) -> Self {
+        self.set_skl(value);
+        self
+    }
+
+    /// Sets the value of the `SKL` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_skl(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_skl(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SKL` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_skl(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SKL_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ASID` field.
+    pub const fn asid(self) -> u16 {
+        Self::ASID_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `ASID` field.
+    pub const fn set_asid(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::ASID_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ASID` field set to the given value.
+    pub const fn with_asid(mut self, value: u16) -> Self {
+        self.set_asid(value);
+        self
+    }
+
+    /// Sets the value of the `ASID` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_asid(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_asid(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ASID` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_asid(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::ASID_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for Ttbr0El1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("Ttbr0El1");
+        let bits = self.bits();
+        s.field("CnP", &Self::CNP_FIELD.extract(bits));
+        s.field(
+            "BADDR[47:1]",
+            &format_args!("{:#x}", Self::BADDR_47_1_FIELD.extract(bits)),
+        );
+        s.field("SKL", &Self::SKL_FIELD.extract(bits));
+        s.field(
+            "ASID",
+            &format_args!("{:#x}", Self::ASID_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for Ttbr0El1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "Ttbr0El1 {{ CnP: {=u64}, BADDR[47:1]: {=u64:#x}, SKL: {=u64}, ASID: {=u64:#x} }}",
+            Self::CNP_FIELD.extract(self.bits()),
+            Self::BADDR_47_1_FIELD.extract(self.bits()),
+            Self::SKL_FIELD.extract(self.bits()),
+            Self::ASID_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for Ttbr0El1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for Ttbr0El1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Ttbr0El1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("CnP"),
+                old: Self::CNP_FIELD.extract(self.bits()),
+                new: Self::CNP_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("BADDR[47:1]"),
+                old: Self::BADDR_47_1_FIELD.extract(self.bits()),
+                new: Self::BADDR_47_1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SKL"),
+                old: Self::SKL_FIELD.extract(self.bits()),
+                new: Self::SKL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ASID"),
+                old: Self::ASID_FIELD.extract(self.bits()),
+                new: Self::ASID_FIELD.extract(other.bits()),
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Ttbr0El1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Ttbr0El1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `TTBR0_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct Ttbr0El2: u64 {
+        /// `CnP` bit.
+        const CNP = 1 << 0;
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Ttbr0El2 {
+    /// Descriptor of the `CnP` field, for use with [`FieldAccess`].
+    pub const CNP_FIELD: Field<Self, bool, 0, 1> = Field::new("TTBR0_EL2", "CnP");
+    /// Descriptor of the `BADDR[47:1]` field, for use with [`FieldAccess`].
+    pub const BADDR_47_1_FIELD: Field<Self, u64, 1, 47> = Field::new("TTBR0_EL2", "BADDR[47:1]");
+    /// Descriptor of the `SKL` field, for use with [`FieldAccess`].
+    pub const SKL_FIELD: Field<Self, u8, 1, 2> = Field::new("TTBR0_EL2", "SKL");
+    /// Descriptor of the `ASID` field, for use with [`FieldAccess`].
+    pub const ASID_FIELD: Field<Self, u16, 48, 16> = Field::new("TTBR0_EL2", "ASID");
+
+    /// Returns the value of the `BADDR[47:1]` field.
+    pub const fn baddr_47_1(self) -> u64 {
+        Self::BADDR_47_1_FIELD.extract(self.bits())
+    }
+
+    /// Sets the value of the `BADDR[47:1]` field.
+    pub const fn set_baddr_47_1(&mut self, value: u64) {
+        *self = Self::from_bits_retain(Self::BADDR_47_1_FIELD.insert(self.bits(), value));
+    }
+
+    /// Returns a copy with the `BADDR[47:1]` field set to the given value.
+    pub const fn with_baddr_47_1(mut self, value: u64) -> Self {
+        self.set_baddr_47_1(value);
+        self
+    }
+
+    /// Sets the value of the `BADDR[47:1]` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_baddr_47_1(&mut self, value: u64) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_baddr_47_1(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `BADDR[47:1]` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_baddr_47_1(self, value: u64) -> Result<Self, FieldOverflow> {
+        match Self::BADDR_47_1_FIELD.try_insert(self.bits(), value) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SKL` field.
+    pub const fn skl(self) -> u8 {
+        Self::SKL_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SKL` field.
+    pub const fn set_skl(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SKL_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SKL` field set to the given value.
+    pub const fn with_skl(mut self, value: u8) -> Self {
+        self.set_skl(value);
+        self
+    }
+
+    /// Sets the value of the `SKL` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_skl(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_skl(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SKL` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_skl(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SKL_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ASID` field.
+    pub const fn asid(self) -> u16 {
+        Self::ASID_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `ASID` field.
+    pub const fn set_asid(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::ASID_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ASID` field set to the given value.
+    pub const fn with_asid(mut self, value: u16) -> Self {
+        self.set_asid(value);
+        self
+    }
+
+    /// Sets the value of the `ASID` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_asid(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_asid(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ASID` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_asid(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::ASID_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Display for Ttbr0El2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("Ttbr0El2");
+        let bits = self.bits();
+        s.field("CnP", &Self::CNP_FIELD.extract(bits));
+        s.field(
+            "BADDR[47:1]",
+            &format_args!("{:#x}", Self::BADDR_47_1_FIELD.extract(bits)),
+        );
+        s.field("SKL", &Self::SKL_FIELD.extract(bits));
+        s.field(
+            "ASID",
+            &format_args!("{:#x}", Self::ASID_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for Ttbr0El2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "Ttbr0El2 {{ CnP: {=u64}, BADDR[47:1]: {=u64:#x}, SKL: {=u64}, ASID: {=u64:#x} }}",
+            Self::CNP_FIELD.extract(self.bits()),
+            Self::BADDR_47_1_FIELD.extract(self.bits()),
+            Self::SKL_FIELD.extract(self.bits()),
+            Self::ASID_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for Ttbr0El2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for Ttbr0El2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Ttbr0El2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("CnP"),
+                old: Self::CNP_FIELD.extract(self.bits()),
+                new: Self::CNP_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("BADDR[47:1]"),
+                old: Self::BADDR_47_1_FIELD.extract(self.bits()),
+                new: Self::BADDR_47_1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SKL"),
+                old: Self::SKL_FIELD.extract(self.bits()),
+                new: Self::SKL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ASID"),
+                old: Self::ASID_FIELD.extract(self.bits()),
+                new: Self::ASID_FIELD.extract(other.bits()),
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Ttbr0El2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Ttbr0El2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el3")]
+bitflags! {
+    /// `TTBR0_EL3` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct Ttbr0El3: u64 {
+        /// `CnP` bit.
+        const CNP = 1 << 0;
+    }
+}
+
+#[cfg(feature = "el3")]
+impl Ttbr0El3 {
+    /// Descriptor of the `CnP` field, for use with [`FieldAccess`].
+    pub const CNP_FIELD: Field<Self, bool, 0, 1> = Field::new("TTBR0_EL3", "CnP");
+    /// Descriptor of the `SKL` field, for use with [`FieldAccess`].
+    pub const SKL_FIELD: Field<Self, u8, 1, 2> = Field::new("TTBR0_EL3", "SKL");
+
+    /// Returns the value of the `SKL` field.
+    pub const fn skl(self) -> u8 {
+        Self::SKL_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SKL` field.
+    pub const fn set_skl(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SKL_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SKL` field set to the given value.
+    pub const fn with_skl(mut self, value: u8) -> Self {
+        self.set_skl(value);
+        self
+    }
+
+    /// Sets the value of the `SKL` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_skl(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_skl(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SKL` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_skl(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SKL_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el3")]
+impl Display for Ttbr0El3 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("Ttbr0El3");
+        let bits = self.bits();
+        s.field("CnP", &Self::CNP_FIELD.extract(bits));
+        s.field("SKL", &Self::SKL_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el3")]
+impl defmt::Format for Ttbr0El3 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "Ttbr0El3 {{ CnP: {=u64}, SKL: {=u64} }}",
+            Self::CNP_FIELD.extract(self.bits()),
+            Self::SKL_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el3")]
+impl serde::Serialize for Ttbr0El3 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el3")]
+impl<'de> serde::Deserialize<'de> for Ttbr0El3 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el3")]
+impl Ttbr0El3 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("CnP"),
+                old: Self::CNP_FIELD.extract(self.bits()),
+                new: Self::CNP_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SKL"),
+                old: Self::SKL_FIELD.extract(self.bits()),
+                new: Self::SKL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el3")]
+impl Ttbr0El3 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el3")]
+impl Ttbr0El3 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el1")]
+/// `TTBR1_EL1` system register value.
+pub type Ttbr1El1 = Ttbr0El1;
+
+#[cfg(feature = "el2")]
+/// `TTBR1_EL2` system register value.
+pub type Ttbr1El2 = Ttbr0El2;
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `VTCR_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct VtcrEl2: u64 {
+        /// RES1 bits in the `VTCR_EL2` register.
+        const RES1 = 0b1000_0000_0000_0000_0000_0000_0000_0000;
+        /// `VS` bit.
+        const VS = 1 << 19;
+        /// `HA` bit.
+        const HA = 1 << 21;
+        /// `HD` bit.
+        const HD = 1 << 22;
+        /// `HWU59` bit.
+        const HWU59 = 1 << 25;
+        /// `HWU60` bit.
+        const HWU60 = 1 << 26;
+        /// `HWU61` bit.
+        const HWU61 = 1 << 27;
+        /// `HWU62` bit.
+        const HWU62 = 1 << 28;
+        /// `NSW` bit.
+        const NSW = 1 << 29;
+        /// `NSA` bit.
+        const NSA = 1 << 30;
+        /// `DS` bit.
+        const DS = 1 << 32;
+        /// `SL2` bit.
+        const SL2 = 1 << 33;
+        /// `AssuredOnly` bit.
+        const ASSUREDONLY = 1 << 34;
+        /// `TL1` bit.
+        const TL1 = 1 << 35;
+        /// `S2PIE` bit.
+        const S2PIE = 1 << 36;
+        /// `S2POE` bit.
+        const S2POE = 1 << 37;
+        /// `D128` bit.
+        const D128 = 1 << 38;
+        /// `GCSH` bit.
+        const GCSH = 1 << 40;
+        /// `TL0` bit.
+        const TL0 = 1 << 41;
+        /// `HAFT` bit.
+        const HAFT = 1 << 44;
+        /// `HDBSS` bit.
+        const HDBSS = 1 << 45;
+    }
+}
+
+#[cfg(feature = "el2")]
+impl VtcrEl2 {
+    /// Descriptor of the `T0SZ` field, for use with [`FieldAccess`].
+    pub const T0SZ_FIELD: Field<Self, u8, 0, 6> = Field::new("VTCR_EL2", "T0SZ");
+    /// Descriptor of the `SL0` field, for use with [`FieldAccess`].
+    pub const SL0_FIELD: Field<Self, u8, 6, 2> = Field::new("VTCR_EL2", "SL0");
+    /// Descriptor of the `IRGN0` field, for use with [`FieldAccess`].
+    pub const IRGN0_FIELD: Field<Self, u8, 8, 2> = Field::new("VTCR_EL2", "IRGN0");
+    /// Descriptor of the `ORGN0` field, for use with [`FieldAccess`].
+    pub const ORGN0_FIELD: Field<Self, u8, 10, 2> = Field::new("VTCR_EL2", "ORGN0");
+    /// Descriptor of the `SH0` field, for use with [`FieldAccess`].
+    pub const SH0_FIELD: Field<Self, u8, 12, 2> = Field::new("VTCR_EL2", "SH0");
+    /// Descriptor of the `TG0` field, for use with [`FieldAccess`].
+    pub const TG0_FIELD: Field<Self, u8, 14, 2> = Field::new("VTCR_EL2", "TG0");
+    /// Descriptor of the `PS` field, for use with [`FieldAccess`].
+    pub const PS_FIELD: Field<Self, u8, 16, 3> = Field::new("VTCR_EL2", "PS");
+    /// Descriptor of the `VS` field, for use with [`FieldAccess`].
+    pub const VS_FIELD: Field<Self, bool, 19, 1> = Field::new("VTCR_EL2", "VS");
+    /// Descriptor of the `HA` field, for use with [`FieldAccess`].
+    pub const HA_FIELD: Field<Self, bool, 21, 1> = Field::new("VTCR_EL2", "HA");
+    /// Descriptor of the `HD` field, for use with [`FieldAccess`].
+    pub const HD_FIELD: Field<Self, bool, 22, 1> = Field::new("VTCR_EL2", "HD");
+    /// Descriptor of the `HWU59` field, for use with [`FieldAccess`].
+    pub const HWU59_FIELD: Field<Self, bool, 25, 1> = Field::new("VTCR_EL2", "HWU59");
+    /// Descriptor of the `HWU60` field, for use with [`FieldAccess`].
+    pub const HWU60_FIELD: Field<Self, bool, 26, 1> = Field::new("VTCR_EL2", "HWU60");
+    /// Descriptor of the `HWU61` field, for use with [`FieldAccess`].
+    pub const HWU61_FIELD: Field<Self, bool, 27, 1> = Field::new("VTCR_EL2", "HWU61");
+    /// Descriptor of the `HWU62` field, for use with [`FieldAccess`].
+    pub const HWU62_FIELD: Field<Self, bool, 28, 1> = Field::new("VTCR_EL2", "HWU62");
+    /// Descriptor of the `NSW` field, for use with [`FieldAccess`].
+    pub const NSW_FIELD: Field<Self, bool, 29, 1> = Field::new("VTCR_EL2", "NSW");
+    /// Descriptor of the `NSA` field, for use with [`FieldAccess`].
+    pub const NSA_FIELD: Field<Self, bool, 30, 1> = Field::new("VTCR_EL2", "NSA");
+    /// Descriptor of the `DS` field, for use with [`FieldAccess`].
+    pub const DS_FIELD: Field<Self, bool, 32, 1> = Field::new("VTCR_EL2", "DS");
+    /// Descriptor of the `SL2` field, for use with [`FieldAccess`].
+    pub const SL2_FIELD: Field<Self, bool, 33, 1> = Field::new("VTCR_EL2", "SL2");
+    /// Descriptor of the `AssuredOnly` field, for use with [`FieldAccess`].
+    pub const ASSUREDONLY_FIELD: Field<Self, bool, 34, 1> = Field::new("VTCR_EL2", "AssuredOnly");
+    /// Descriptor of the `TL1` field, for use with [`FieldAccess`].
+    pub const TL1_FIELD: Field<Self, bool, 35, 1> = Field::new("VTCR_EL2", "TL1");
+    /// Descriptor of the `S2PIE` field, for use with [`FieldAccess`].
+    pub const S2PIE_FIELD: Field<Self, bool, 36, 1> = Field::new("VTCR_EL2", "S2PIE");
+    /// Descriptor of the `S2POE` field, for use with [`FieldAccess`].
+    pub const S2POE_FIELD: Field<Self, bool, 37, 1> = Field::new("VTCR_EL2", "S2POE");
+    /// Descriptor of the `D128` field, for use with [`FieldAccess`].
+    pub const D128_FIELD: Field<Self, bool, 38, 1> = Field::new("VTCR_EL2", "D128");
+    /// Descriptor of the `GCSH` field, for use with [`FieldAccess`].
+    pub const GCSH_FIELD: Field<Self, bool, 40, 1> = Field::new("VTCR_EL2", "GCSH");
+    /// Descriptor of the `TL0` field, for use with [`FieldAccess`].
+    pub const TL0_FIELD: Field<Self, bool, 41, 1> = Field::new("VTCR_EL2", "TL0");
+    /// Descriptor of the `HAFT` field, for use with [`FieldAccess`].
+    pub const HAFT_FIELD: Field<Self, bool, 44, 1> = Field::new("VTCR_EL2", "HAFT");
+    /// Descriptor of the `HDBSS` field, for use with [`FieldAccess`].
+    pub const HDBSS_FIELD: Field<Self, bool, 45, 1> = Field::new("VTCR_EL2", "HDBSS");
+
+    /// Returns the value of the `T0SZ` field.
+    pub const fn t0sz(self) -> u8 {
+        Self::T0SZ_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `T0SZ` field.
+    pub const fn set_t0sz(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::T0SZ_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `T0SZ` field set to the given value.
+    pub const fn with_t0sz(mut self, value: u8) -> Self {
+        self.set_t0sz(value);
+        self
+    }
+
+    /// Sets the value of the `T0SZ` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_t0sz(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_t0sz(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `T0SZ` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_t0sz(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::T0SZ_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SL0` field.
+    pub const fn sl0(self) -> u8 {
+        Self::SL0_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SL0` field.
+    pub const fn set_sl0(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SL0_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SL0` field set to the given value.
+    pub const fn with_sl0(mut self, value: u8) -> Self {
+        self.set_sl0(value);
+        self
+    }
+
+    /// Sets the value of the `SL0` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_sl0(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_sl0(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SL0` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_sl0(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SL0_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `IRGN0` field.
+    pub const fn irgn0(self) -> u8 {
+        Self::IRGN0_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `IRGN0` field.
+    pub const fn set_irgn0(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::IRGN0_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `IRGN0` field set to the given value.
+    pub const fn with_irgn0(mut self, value: u8) -> Self {
+        self.set_irgn0(value);
+        self
+    }
+
+    /// Sets the value of the `IRGN0` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_irgn0(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_irgn0(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `IRGN0` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_irgn0(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::IRGN0_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `ORGN0` field.
+    pub const fn orgn0(self) -> u8 {
+        Self::ORGN0_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `ORGN0` field.
+    pub const fn set_orgn0(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::ORGN0_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ORGN0` field set to the given value.
+    pub const fn with_orgn0(mut self, value: u8) -> Self {
+        self.set_orgn0(value);
+        self
+    }
+
+    /// Sets the value of the `ORGN0` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_orgn0(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_orgn0(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ORGN0` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_orgn0(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::ORGN0_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SH0` field.
+    pub const fn sh0(self) -> u8 {
+        Self::SH0_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SH0` field.
+    pub const fn set_sh0(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SH0_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SH0` field set to the given value.
+    pub const fn with_sh0(mut self, value: u8) -> Self {
+        self.set_sh0(value);
+        self
+    }
+
+    /// Sets the value of the `SH0` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_sh0(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_sh0(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SH0` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_sh0(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SH0_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `TG0` field.
+    pub const fn tg0(self) -> u8 {
+        Self::TG0_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `TG0` field.
+    pub const fn set_tg0(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::TG0_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `TG0` field set to the given value.
+    pub const fn with_tg0(mut self, value: u8) -> Self {
+        self.set_tg0(value);
+        self
+    }
+
+    /// Sets the value of the `TG0` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_tg0(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_tg0(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `TG0` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_tg0(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::TG0_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PS` field.
+    pub const fn ps(self) -> u8 {
+        Self::PS_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `PS` field.
+    pub const fn set_ps(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::PS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PS` field set to the given value.
+    pub const fn with_ps(mut self, value: u8) -> Self {
+        self.set_ps(value);
+        self
+    }
+
+    /// Sets the value of the `PS` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ps(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ps(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PS` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ps(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::PS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Display for VtcrEl2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("VtcrEl2");
+        let bits = self.bits();
+        s.field("T0SZ", &Self::T0SZ_FIELD.extract(bits));
+        s.field("SL0", &Self::SL0_FIELD.extract(bits));
+        s.field("IRGN0", &Self::IRGN0_FIELD.extract(bits));
+        s.field("ORGN0", &Self::ORGN0_FIELD.extract(bits));
+        s.field("SH0", &Self::SH0_FIELD.extract(bits));
+        s.field("TG0", &Self::TG0_FIELD.extract(bits));
+        s.field("PS", &Self::PS_FIELD.extract(bits));
+        s.field("VS", &Self::VS_FIELD.extract(bits));
+        s.field("HA", &Self::HA_FIELD.extract(bits));
+        s.field("HD", &Self::HD_FIELD.extract(bits));
+        s.field("HWU59", &Self::HWU59_FIELD.extract(bits));
+        s.field("HWU60", &Self::HWU60_FIELD.extract(bits));
+        s.field("HWU61", &Self::HWU61_FIELD.extract(bits));
+        s.field("HWU62", &Self::HWU62_FIELD.extract(bits));
+        s.field("NSW", &Self::NSW_FIELD.extract(bits));
+        s.field("NSA", &Self::NSA_FIELD.extract(bits));
+        s.field("DS", &Self::DS_FIELD.extract(bits));
+        s.field("SL2", &Self::SL2_FIELD.extract(bits));
+        s.field("AssuredOnly", &Self::ASSUREDONLY_FIELD.extract(bits));
+        s.field("TL1", &Self::TL1_FIELD.extract(bits));
+        s.field("S2PIE", &Self::S2PIE_FIELD.extract(bits));
+        s.field("S2POE", &Self::S2POE_FIELD.extract(bits));
+        s.field("D128", &Self::D128_FIELD.extract(bits));
+        s.field("GCSH", &Self::GCSH_FIELD.extract(bits));
+        s.field("TL0", &Self::TL0_FIELD.extract(bits));
+        s.field("HAFT", &Self::HAFT_FIELD.extract(bits));
+        s.field("HDBSS", &Self::HDBSS_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for VtcrEl2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "VtcrEl2 {{ T0SZ: {=u64}, SL0: {=u64}, IRGN0: {=u64}, ORGN0: {=u64}, SH0: {=u64}, TG0: {=u64}, PS: {=u64}, VS: {=u64}, HA: {=u64}, HD: {=u64}, HWU59: {=u64}, HWU60: {=u64}, HWU61: {=u64}, HWU62: {=u64}, NSW: {=u64}, NSA: {=u64}, DS: {=u64}, SL2: {=u64}, AssuredOnly: {=u64}, TL1: {=u64}, S2PIE: {=u64}, S2POE: {=u64}, D128: {=u64}, GCSH: {=u64}, TL0: {=u64}, HAFT: {=u64}, HDBSS: {=u64} }}",
+            Self::T0SZ_FIELD.extract(self.bits()),
+            Self::SL0_FIELD.extract(self.bits()),
+            Self::IRGN0_FIELD.extract(self.bits()),
+            Self::ORGN0_FIELD.extract(self.bits()),
+            Self::SH0_FIELD.extract(self.bits()),
+            Self::TG0_FIELD.extract(self.bits()),
+            Self::PS_FIELD.extract(self.bits()),
+            Self::VS_FIELD.extract(self.bits()),
+            Self::HA_FIELD.extract(self.bits()),
+            Self::HD_FIELD.extract(self.bits()),
+            Self::HWU59_FIELD.extract(self.bits()),
+            Self::HWU60_FIELD.extract(self.bits()),
+            Self::HWU61_FIELD.extract(self.bits()),
+            Self::HWU62_FIELD.extract(self.bits()),
+            Self::NSW_FIELD.extract(self.bits()),
+            Self::NSA_FIELD.extract(self.bits()),
+            Self::DS_FIELD.extract(self.bits()),
+            Self::SL2_FIELD.extract(self.bits()),
+            Self::ASSUREDONLY_FIELD.extract(self.bits()),
+            Self::TL1_FIELD.extract(self.bits()),
+            Self::S2PIE_FIELD.extract(self.bits()),
+            Self::S2POE_FIELD.extract(self.bits()),
+            Self::D128_FIELD.extract(self.bits()),
+            Self::GCSH_FIELD.extract(self.bits()),
+            Self::TL0_FIELD.extract(self.bits()),
+            Self::HAFT_FIELD.extract(self.bits()),
+            Self::HDBSS_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for VtcrEl2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for VtcrEl2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl VtcrEl2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("T0SZ"),
+                old: Self::T0SZ_FIELD.extract(self.bits()),
+                new: Self::T0SZ_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SL0"),
+                old: Self::SL0_FIELD.extract(self.bits()),
+                new: Self::SL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IRGN0"),
+                old: Self::IRGN0_FIELD.extract(self.bits()),
+                new: Self::IRGN0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ORGN0"),
+                old: Self::ORGN0_FIELD.extract(self.bits()),
+                new: Self::ORGN0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SH0"),
+                old: Self::SH0_FIELD.extract(self.bits()),
+                new: Self::SH0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TG0"),
+                old: Self::TG0_FIELD.extract(self.bits()),
+                new: Self::TG0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PS"),
+                old: Self::PS_FIELD.extract(self.bits()),
+                new: Self::PS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VS"),
+                old: Self::VS_FIELD.extract(self.bits()),
+                new: Self::VS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HA"),
+                old: Self::HA_FIELD.extract(self.bits()),
+                new: Self::HA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HD"),
+                old: Self::HD_FIELD.extract(self.bits()),
+                new: Self::HD_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HWU59"),
+                old: Self::HWU59_FIELD.extract(self.bits()),
+                new: Self::HWU59_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HWU60"),
+                old: Self::HWU60_FIELD.extract(self.bits()),
+                new: Self::HWU60_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HWU61"),
+                old: Self::HWU61_FIELD.extract(self.bits()),
+                new: Self::HWU61_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HWU62"),
+                old: Self::HWU62_FIELD.extract(self.bits()),
+                new: Self::HWU62_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("NSW"),
+                old: Self::NSW_FIELD.extract(self.bits()),
+                new: Self::NSW_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("NSA"),
+                old: Self::NSA_FIELD.extract(self.bits()),
+                new: Self::NSA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DS"),
+                old: Self::DS_FIELD.extract(self.bits()),
+                new: Self::DS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SL2"),
+                old: Self::SL2_FIELD.extract(self.bits()),
+                new: Self::SL2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("AssuredOnly"),
+                old: Self::ASSUREDONLY_FIELD.extract(self.bits()),
+                new: Self::ASSUREDONLY_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TL1"),
+                old: Self::TL1_FIELD.extract(self.bits()),
+                new: Self::TL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("S2PIE"),
+                old: Self::S2PIE_FIELD.extract(self.bits()),
+                new: Self::S2PIE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("S2POE"),
+                old: Self::S2POE_FIELD.extract(self.bits()),
+                new: Self::S2POE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("D128"),
+                old: Self::D128_FIELD.extract(self.bits()),
+                new: Self::D128_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("GCSH"),
+                old: Self::GCSH_FIELD.extract(self.bits()),
+                new: Self::GCSH_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TL0"),
+                old: Self::TL0_FIELD.extract(self.bits()),
+                new: Self::TL0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HAFT"),
+                old: Self::HAFT_FIELD.extract(self.bits()),
+                new: Self::HAFT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HDBSS"),
+                old: Self::HDBSS_FIELD.extract(self.bits()),
+                new: Self::HDBSS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1100_1100_1000_0000_1000_0001_1001_0000_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1100_1100_1000_0000_1000_0001_1001_0000_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl VtcrEl2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b1000_0000_0000_0000_0000_0000_0000_0000;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl VtcrEl2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `VTTBR_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct VttbrEl2: u64 {
+        /// `CnP` bit.
+        const CNP = 1 << 0;
+    }
+}
+
+#[cfg(feature = "el2")]
+impl VttbrEl2 {
+    /// Descriptor of the `CnP` field, for use with [`FieldAccess`].
+    pub const CNP_FIELD: Field<Self, bool, 0, 1> = Field::new("VTTBR_EL2", "CnP");
+    /// Descriptor of the `BADDR` field, for use with [`FieldAccess`].
+    pub const BADDR_FIELD: Field<Self, u64, 1, 47> = Field::new("VTTBR_EL2", "BADDR");
+    /// Descriptor of the `SKL` field, for use with [`FieldAccess`].
+    pub const SKL_FIELD: Field<Self, u8, 1, 2> = Field::new("VTTBR_EL2", "SKL");
+    /// Descriptor of the `VMID` field, for use with [`FieldAccess`].
+    pub const VMID_FIELD: Field<Self, u16, 48, 16> = Field::new("VTTBR_EL2", "VMID");
+
+    /// Returns the value of the `BADDR` field.
+    pub const fn baddr(self) -> u64 {
+        Self::BADDR_FIELD.extract(self.bits())
+    }
+
+    /// Sets the value of the `BADDR` field.
+    pub const fn set_baddr(&mut self, value: u64) {
+        *self = Self::from_bits_retain(Self::BADDR_FIELD.insert(self.bits(), value));
+    }
+
+    /// Returns a copy with the `BADDR` field set to the given value.
+    pub const fn with_baddr(mut self, value: u64) -> Self {
+        self.set_baddr(value);
+        self
+    }
+
+    /// Sets the value of the `BADDR` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_baddr(&mut self, value: u64) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_baddr(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `BADDR` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_baddr(self, value: u64) -> Result<Self, FieldOverflow> {
+        match Self::BADDR_FIELD.try_insert(self.bits(), value) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SKL` field.
+    pub const fn skl(self) -> u8 {
+        Self::SKL_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `SKL` field.
+    pub const fn set_skl(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::SKL_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SKL` field set to the given value.
+    pub const fn with_skl(mut self, value: u8) -> Self {
+        self.set_skl(value);
+        self
+    }
+
+    /// Sets the value of the `SKL` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_skl(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_skl(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SKL` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_skl(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::SKL_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `VMID` field.
+    pub const fn vmid(self) -> u16 {
+        Self::VMID_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `VMID` field.
+    pub const fn set_vmid(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::VMID_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `VMID` field set to the given value.
+    pub const fn with_vmid(mut self, value: u16) -> Self {
+        self.set_vmid(value);
+        self
+    }
+
+    /// Sets the value of the `VMID` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_vmid(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_vmid(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `VMID` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_vmid(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::VMID_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Display for VttbrEl2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("VttbrEl2");
+        let bits = self.bits();
+        s.field("CnP", &Self::CNP_FIELD.extract(bits));
+        s.field(
+            "BADDR",
+            &format_args!("{:#x}", Self::BADDR_FIELD.extract(bits)),
+        );
+        s.field("SKL", &Self::SKL_FIELD.extract(bits));
+        s.field(
+            "VMID",
+            &format_args!("{:#x}", Self::VMID_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for VttbrEl2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "VttbrEl2 {{ CnP: {=u64}, BADDR: {=u64:#x}, SKL: {=u64}, VMID: {=u64:#x} }}",
+            Self::CNP_FIELD.extract(self.bits()),
+            Self::BADDR_FIELD.extract(self.bits()),
+            Self::SKL_FIELD.extract(self.bits()),
+            Self::VMID_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for VttbrEl2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for VttbrEl2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl VttbrEl2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("CnP"),
+                old: Self::CNP_FIELD.extract(self.bits()),
+                new: Self::CNP_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("BADDR"),
+                old: Self::BADDR_FIELD.extract(self.bits()),
+                new: Self::BADDR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SKL"),
+                old: Self::SKL_FIELD.extract(self.bits()),
+                new: Self::SKL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VMID"),
+                old: Self::VMID_FIELD.extract(self.bits()),
+                new: Self::VMID_FIELD.extract(other.bits()),
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl VttbrEl2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl VttbrEl2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `AMAIR_EL1` system register.
+    amair_el1, u64, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `AMAIR_EL2` system register.
+    amair_el2, u64, safe_read, fake::SYSREGS
+}
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el3",
+    feature = "feat-rme"
+))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `GPCCR_EL3` system register.
+    gpccr_el3: s3_6_c2_c1_6, u64: GpccrEl3, safe_read, fake::SYSREGS
+}
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el3",
+    feature = "feat-rme"
+))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `GPTBR_EL3` system register.
+    gptbr_el3: s3_6_c2_c1_4, u64: GptbrEl3, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `MAIR_EL1` system register.
+    mair_el1, u64: MairEl1, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `MAIR_EL2` system register.
+    mair_el2, u64: MairEl2, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe configuration value for the EL3 memory attribute indirection register.
+    mair_el3, u64: MairEl3, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `PAR_EL1` system register.
+    par_el1, u64: ParEl1, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `PIRE0_EL1` system register.
+    pire0_el1: s3_0_c10_c2_2, u64: Pire0El1, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `PIRE0_EL2` system register.
+    pire0_el2: s3_4_c10_c2_2, u64: Pire0El2, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `PIR_EL1` system register.
+    pir_el1: s3_0_c10_c2_3, u64: PirEl1, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `PIR_EL2` system register.
+    pir_el2: s3_4_c10_c2_3, u64: PirEl2, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `PIR_EL3` system register.
+    pir_el3: s3_6_c10_c2_3, u64: PirEl3, safe_read, fake::SYSREGS
+}
+#[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `POR_EL0` system register.
+    por_el0: s3_3_c10_c2_4, u64: PorEl0, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `POR_EL1` system register.
+    por_el1: s3_0_c10_c2_4, u64: PorEl1, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `POR_EL2` system register.
+    por_el2: s3_4_c10_c2_4, u64: PorEl2, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `POR_EL3` system register.
+    por_el3: s3_6_c10_c2_4, u64: PorEl3, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `S2PIR_EL2` system register.
+    s2pir_el2: s3_4_c10_c2_5, u64: S2pirEl2, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `S2POR_EL1` system register.
+    s2por_el1: s3_0_c10_c2_5, u64: S2porEl1, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `TCR2_EL1` system register.
+    tcr2_el1: s3_0_c2_c0_3, u64: Tcr2El1, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `TCR2_EL2` system register.
+    tcr2_el2: s3_4_c2_c0_3, u64: Tcr2El2, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `TCR_EL1` system register.
+    tcr_el1, u64: TcrEl1, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `TCR_EL2` system register.
+    tcr_el2, u64: TcrEl2, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe configuration value for the EL3 translation control register.
+    tcr_el3, u64: TcrEl3, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The base address must point to a valid and properly aligned translation table.
+    ttbr0_el1, u64: Ttbr0El1, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The base address must point to a valid and properly aligned translation table.
+    ttbr0_el2, u64: Ttbr0El2, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The base address must point to a valid and properly aligned translation table.
+    ttbr0_el3, u64: Ttbr0El3, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The base address must point to a valid and properly aligned translation table.
+    ttbr1_el1, u64: Ttbr1El1, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The base address must point to a valid and properly aligned translation table.
+    ttbr1_el2: s3_4_c2_c0_1, u64: Ttbr1El2, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `VTCR_EL2` system register.
+    vtcr_el2, u64: VtcrEl2, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The base address must point to a valid and properly aligned stage 2 translation table.
+    vttbr_el2, u64: VttbrEl2, safe_read, fake::SYSREGS
+}
@@ -0,0 +1,5638 @@
+// SPDX-FileCopyrightText: Copyright The arm-sysregs Contributors.
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Memory partitioning and monitoring system registers.
+
+// This file is generated, do not edit manually.
+
+// Depending on the enabled features, the module might not use all of these imports.
+#![allow(unused_imports)]
+
+use crate::*;
+use bitflags::bitflags;
+use core::fmt::{self, Display, Formatter};
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+bitflags! {
+    /// `MPAM2_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct Mpam2El2: u64 {
+        /// `TRAPMPAM1EL1` bit.
+        const TRAPMPAM1EL1 = 1 << 48;
+        /// `TRAPMPAM0EL1` bit.
+        const TRAPMPAM0EL1 = 1 << 49;
+        /// `EnMPAMSM` bit.
+        const ENMPAMSM = 1 << 50;
+        /// `ALTSP_FRCD` bit.
+        const ALTSP_FRCD = 1 << 54;
+        /// `ALTSP_EL2` bit.
+        const ALTSP_EL2 = 1 << 55;
+        /// `ALTSP_HFC` bit.
+        const ALTSP_HFC = 1 << 56;
+        /// `TIDR` bit.
+        const TIDR = 1 << 58;
+        /// `MPAMEN` bit.
+        const MPAMEN = 1 << 63;
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl Mpam2El2 {
+    /// Descriptor of the `PARTID` field, for use with [`FieldAccess`].
+    pub const PARTID_FIELD: Field<Self, u16, 0, 16> = Field::new("MPAM2_EL2", "PARTID");
+    /// Descriptor of the `PARTID_I` field, for use with [`FieldAccess`].
+    pub const PARTID_I_FIELD: Field<Self, u16, 0, 16> = Field::new("MPAM2_EL2", "PARTID_I");
+    /// Descriptor of the `PARTID_D` field, for use with [`FieldAccess`].
+    pub const PARTID_D_FIELD: Field<Self, u16, 16, 16> = Field::new("MPAM2_EL2", "PARTID_D");
+    /// Descriptor of the `altPARTID` field, for use with [`FieldAccess`].
+    pub const ALTPARTID_FIELD: Field<Self, u16, 16, 16> = Field::new("MPAM2_EL2", "altPARTID");
+    /// Descriptor of the `PMG` field, for use with [`FieldAccess`].
+    pub const PMG_FIELD: Field<Self, u16, 32, 16> = Field::new("MPAM2_EL2", "PMG");
+    /// Descriptor of the `PMG_I` field, for use with [`FieldAccess`].
+    pub const PMG_I_FIELD: Field<Self, u8, 32, 8> = Field::new("MPAM2_EL2", "PMG_I");
+    /// Descriptor of the `PMG_D` field, for use with [`FieldAccess`].
+    pub const PMG_D_FIELD: Field<Self, u8, 40, 8> = Field::new("MPAM2_EL2", "PMG_D");
+    /// Descriptor of the `TRAPMPAM1EL1` field, for use with [`FieldAccess`].
+    pub const TRAPMPAM1EL1_FIELD: Field<Self, bool, 48, 1> =
+        Field::new("MPAM2_EL2", "TRAPMPAM1EL1");
+    /// Descriptor of the `altPMG` field, for use with [`FieldAccess`].
+    pub const ALTPMG_FIELD: Field<Self, u16, 48, 16> = Field::new("MPAM2_EL2", "altPMG");
+    /// Descriptor of the `TRAPMPAM0EL1` field, for use with [`FieldAccess`].
+    pub const TRAPMPAM0EL1_FIELD: Field<Self, bool, 49, 1> =
+        Field::new("MPAM2_EL2", "TRAPMPAM0EL1");
+    /// Descriptor of the `EnMPAMSM` field, for use with [`FieldAccess`].
+    pub const ENMPAMSM_FIELD: Field<Self, bool, 50, 1> = Field::new("MPAM2_EL2", "EnMPAMSM");
+    /// Descriptor of the `ALTSP_FRCD` field, for use with [`FieldAccess`].
+    pub const ALTSP_FRCD_FIELD: Field<Self, bool, 54, 1> = Field::new("MPAM2_EL2", "ALTSP_FRCD");
+    /// Descriptor of the `ALTSP_EL2` field, for use with [`FieldAccess`].
+    pub const ALTSP_EL2_FIELD: Field<Self, bool, 55, 1> = Field::new("MPAM2_EL2", "ALTSP_EL2");
+    /// Descriptor of the `ALTSP_HFC` field, for use with [`FieldAccess`].
+    pub const ALTSP_HFC_FIELD: Field<Self, bool, 56, 1> = Field::new("MPAM2_EL2", "ALTSP_HFC");
+    /// Descriptor of the `TIDR` field, for use with [`FieldAccess`].
+    pub const TIDR_FIELD: Field<Self, bool, 58, 1> = Field::new("MPAM2_EL2", "TIDR");
+    /// Descriptor of the `MPAMEN` field, for use with [`FieldAccess`].
+    pub const MPAMEN_FIELD: Field<Self, bool, 63, 1> = Field::new("MPAM2_EL2", "MPAMEN");
+
+    /// Returns the value of the `PARTID` field.
+    pub const fn partid(self) -> u16 {
+        Self::PARTID_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `PARTID` field.
+    pub const fn set_partid(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::PARTID_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PARTID` field set to the given value.
+    pub const fn with_partid(mut self, value: u16) -> Self {
+        self.set_partid(value);
+        self
+    }
+
+    /// Sets the value of the `PARTID` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_partid(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_partid(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PARTID` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_partid(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::PARTID_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PARTID_I` field.
+    pub const fn partid_i(self) -> u16 {
+        Self::PARTID_I_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `PARTID_I` field.
+    pub const fn set_partid_i(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::PARTID_I_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PARTID_I` field set to the given value.
+    pub const fn with_partid_i(mut self, value: u16) -> Self {
+        self.set_partid_i(value);
+        self
+    }
+
+    /// Sets the value of the `PARTID_I` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_partid_i(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_partid_i(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PARTID_I` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_partid_i(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::PARTID_I_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PARTID_D` field.
+    pub const fn partid_d(self) -> u16 {
+        Self::PARTID_D_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `PARTID_D` field.
+    pub const fn set_partid_d(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::PARTID_D_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PARTID_D` field set to the given value.
+    pub const fn with_partid_d(mut self, value: u16) -> Self {
+        self.set_partid_d(value);
+        self
+    }
+
+    /// Sets the value of the `PARTID_D` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_partid_d(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_partid_d(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PARTID_D` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_partid_d(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::PARTID_D_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `altPARTID` field.
+    pub const fn altpartid(self) -> u16 {
+        Self::ALTPARTID_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `altPARTID` field.
+    pub const fn set_altpartid(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::ALTPARTID_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `altPARTID` field set to the given value.
+    pub const fn with_altpartid(mut self, value: u16) -> Self {
+        self.set_altpartid(value);
+        self
+    }
+
+    /// Sets the value of the `altPARTID` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_altpartid(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_altpartid(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `altPARTID` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_altpartid(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::ALTPARTID_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PMG` field.
+    pub const fn pmg(self) -> u16 {
+        Self::PMG_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `PMG` field.
+    pub const fn set_pmg(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::PMG_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PMG` field set to the given value.
+    pub const fn with_pmg(mut self, value: u16) -> Self {
+        self.set_pmg(value);
+        self
+    }
+
+    /// Sets the value of the `PMG` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_pmg(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_pmg(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PMG` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_pmg(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::PMG_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PMG_I` field.
+    pub const fn pmg_i(self) -> u8 {
+        Self::PMG_I_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `PMG_I` field.
+    pub const fn set_pmg_i(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::PMG_I_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PMG_I` field set to the given value.
+    pub const fn with_pmg_i(mut self, value: u8) -> Self {
+        self.set_pmg_i(value);
+        self
+    }
+
+    /// Sets the value of the `PMG_I` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_pmg_i(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_pmg_i(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PMG_I` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_pmg_i(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::PMG_I_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PMG_D` field.
+    pub const fn pmg_d(self) -> u8 {
+        Self::PMG_D_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `PMG_D` field.
+    pub const fn set_pmg_d(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::PMG_D_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PMG_D` field set to the given value.
+    pub const fn with_pmg_d(mut self, value: u8) -> Self {
+        self.set_pmg_d(value);
+        self
+    }
+
+    /// Sets the value of the `PMG_D` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_pmg_d(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_pmg_d(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PMG_D` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_pmg_d(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::PMG_D_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `altPMG` field.
+    pub const fn altpmg(self) -> u16 {
+        Self::ALTPMG_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `altPMG` field.
+    pub const fn set_altpmg(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::ALTPMG_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `altPMG` field set to the given value.
+    pub const fn with_altpmg(mut self, value: u16) -> Self {
+        self.set_altpmg(value);
+        self
+    }
+
+    /// Sets the value of the `altPMG` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_altpmg(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_altpmg(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `altPMG` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_altpmg(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::ALTPMG_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl Display for Mpam2El2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("Mpam2El2");
+        let bits = self.bits();
+        s.field(
+            "PARTID",
+            &format_args!("{:#x}", Self::PARTID_FIELD.extract(bits)),
+        );
+        s.field(
+            "PARTID_I",
+            &format_args!("{:#x}", Self::PARTID_I_FIELD.extract(bits)),
+        );
+        s.field(
+            "PARTID_D",
+            &format_args!("{:#x}", Self::PARTID_D_FIELD.extract(bits)),
+        );
+        s.field(
+            "altPARTID",
+            &format_args!("{:#x}", Self::ALTPARTID_FIELD.extract(bits)),
+        );
+        s.field("PMG", &format_args!("{:#x}", Self::PMG_FIELD.extract(bits)));
+        s.field("PMG_I", &Self::PMG_I_FIELD.extract(bits));
+        s.field("PMG_D", &Self::PMG_D_FIELD.extract(bits));
+        s.field("TRAPMPAM1EL1", &Self::TRAPMPAM1EL1_FIELD.extract(bits));
+        s.field(
+            "altPMG",
+            &format_args!("{:#x}", Self::ALTPMG_FIELD.extract(bits)),
+        );
+        s.field("TRAPMPAM0EL1", &Self::TRAPMPAM0EL1_FIELD.extract(bits));
+        s.field("EnMPAMSM", &Self::ENMPAMSM_FIELD.extract(bits));
+        s.field("ALTSP_FRCD", &Self::ALTSP_FRCD_FIELD.extract(bits));
+        s.field("ALTSP_EL2", &Self::ALTSP_EL2_FIELD.extract(bits));
+        s.field("ALTSP_HFC", &Self::ALTSP_HFC_FIELD.extract(bits));
+        s.field("TIDR", &Self::TIDR_FIELD.extract(bits));
+        s.field("MPAMEN", &Self::MPAMEN_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl defmt::Format for Mpam2El2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "Mpam2El2 {{ PARTID: {=u64:#x}, PARTID_I: {=u64:#x}, PARTID_D: {=u64:#x}, altPARTID: {=u64:#x}, PMG: {=u64:#x}, PMG_I: {=u64}, PMG_D: {=u64}, TRAPMPAM1EL1: {=u64}, altPMG: {=u64:#x}, TRAPMPAM0EL1: {=u64}, EnMPAMSM: {=u64}, ALTSP_FRCD: {=u64}, ALTSP_EL2: {=u64}, ALTSP_HFC: {=u64}, TIDR: {=u64}, MPAMEN: {=u64} }}",
+            Self::PARTID_FIELD.extract(self.bits()),
+            Self::PARTID_I_FIELD.extract(self.bits()),
+            Self::PARTID_D_FIELD.extract(self.bits()),
+            Self::ALTPARTID_FIELD.extract(self.bits()),
+            Self::PMG_FIELD.extract(self.bits()),
+            Self::PMG_I_FIELD.extract(self.bits()),
+            Self::PMG_D_FIELD.extract(self.bits()),
+            Self::TRAPMPAM1EL1_FIELD.extract(self.bits()),
+            Self::ALTPMG_FIELD.extract(self.bits()),
+            Self::TRAPMPAM0EL1_FIELD.extract(self.bits()),
+            Self::ENMPAMSM_FIELD.extract(self.bits()),
+            Self::ALTSP_FRCD_FIELD.extract(self.bits()),
+            Self::ALTSP_EL2_FIELD.extract(self.bits()),
+            Self::ALTSP_HFC_FIELD.extract(self.bits()),
+            Self::TIDR_FIELD.extract(self.bits()),
+            Self::MPAMEN_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl serde::Serialize for Mpam2El2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl<'de> serde::Deserialize<'de> for Mpam2El2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl Mpam2El2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("PARTID"),
+                old: Self::PARTID_FIELD.extract(self.bits()),
+                new: Self::PARTID_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PARTID_I"),
+                old: Self::PARTID_I_FIELD.extract(self.bits()),
+                new: Self::PARTID_I_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PARTID_D"),
+                old: Self::PARTID_D_FIELD.extract(self.bits()),
+                new: Self::PARTID_D_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("altPARTID"),
+                old: Self::ALTPARTID_FIELD.extract(self.bits()),
+                new: Self::ALTPARTID_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMG"),
+                old: Self::PMG_FIELD.extract(self.bits()),
+                new: Self::PMG_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMG_I"),
+                old: Self::PMG_I_FIELD.extract(self.bits()),
+                new: Self::PMG_I_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMG_D"),
+                old: Self::PMG_D_FIELD.extract(self.bits()),
+                new: Self::PMG_D_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TRAPMPAM1EL1"),
+                old: Self::TRAPMPAM1EL1_FIELD.extract(self.bits()),
+                new: Self::TRAPMPAM1EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("altPMG"),
+                old: Self::ALTPMG_FIELD.extract(self.bits()),
+                new: Self::ALTPMG_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TRAPMPAM0EL1"),
+                old: Self::TRAPMPAM0EL1_FIELD.extract(self.bits()),
+                new: Self::TRAPMPAM0EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EnMPAMSM"),
+                old: Self::ENMPAMSM_FIELD.extract(self.bits()),
+                new: Self::ENMPAMSM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ALTSP_FRCD"),
+                old: Self::ALTSP_FRCD_FIELD.extract(self.bits()),
+                new: Self::ALTSP_FRCD_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ALTSP_EL2"),
+                old: Self::ALTSP_EL2_FIELD.extract(self.bits()),
+                new: Self::ALTSP_EL2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ALTSP_HFC"),
+                old: Self::ALTSP_HFC_FIELD.extract(self.bits()),
+                new: Self::ALTSP_HFC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TIDR"),
+                old: Self::TIDR_FIELD.extract(self.bits()),
+                new: Self::TIDR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("MPAMEN"),
+                old: Self::MPAMEN_FIELD.extract(self.bits()),
+                new: Self::MPAMEN_FIELD.extract(other.bits()),
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl Mpam2El2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl Mpam2El2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                Self::PARTID_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAM2_EL2",
+                    field: "PARTID",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::PARTID_I_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAM2_EL2",
+                    field: "PARTID_I",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::PARTID_D_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAM2_EL2",
+                    field: "PARTID_D",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::ALTPARTID_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAM2_EL2",
+                    field: "altPARTID",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::PMG_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAM2_EL2",
+                    field: "PMG",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::PMG_I_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAM2_EL2",
+                    field: "PMG_I",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::PMG_D_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAM2_EL2",
+                    field: "PMG_D",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::TRAPMPAM1EL1_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAM2_EL2",
+                    field: "TRAPMPAM1EL1",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::ALTPMG_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAM2_EL2",
+                    field: "altPMG",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::TRAPMPAM0EL1_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAM2_EL2",
+                    field: "TRAPMPAM0EL1",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::ENMPAMSM_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAM2_EL2",
+                    field: "EnMPAMSM",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::ALTSP_FRCD_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAM2_EL2",
+                    field: "ALTSP_FRCD",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::ALTSP_EL2_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAM2_EL2",
+                    field: "ALTSP_EL2",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::ALTSP_HFC_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAM2_EL2",
+                    field: "ALTSP_HFC",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::TIDR_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAM2_EL2",
+                    field: "TIDR",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::MPAMEN_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAM2_EL2",
+                    field: "MPAMEN",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(all(feature = "el3", feature = "feat-mpam"))]
+bitflags! {
+    /// `MPAM3_EL3` system register value.
+    ///
+    /// Holds information to generate MPAM labels for memory requests when executing at EL3.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct Mpam3El3: u64 {
+        /// `RT_ALTSP_NS` bit.
+        const RT_ALTSP_NS = 1 << 52;
+        /// `ALTSP_EL3` bit.
+        const ALTSP_EL3 = 1 << 55;
+        /// `ALTSP_HFC` bit.
+        const ALTSP_HFC = 1 << 56;
+        /// `ALTSP_HEN` bit.
+        const ALTSP_HEN = 1 << 57;
+        /// `FORCE_NS` bit.
+        const FORCE_NS = 1 << 60;
+        /// `SDEFLT` bit.
+        const SDEFLT = 1 << 61;
+        /// Trap direct accesses to MPAM System registers that are not UNDEFINED from all ELn lower than EL3.
+        const TRAPLOWER = 1 << 62;
+        /// MPAM Enable. If set, MPAM information is output based on the MPAMn_ELx register for ELn according the MPAM configuration. If not set, the default PARTID and default PMG are output in MPAM information when executing at any ELn.
+        const MPAMEN = 1 << 63;
+    }
+}
+
+#[cfg(all(feature = "el3", feature = "feat-mpam"))]
+impl Mpam3El3 {
+    /// Descriptor of the `PARTID` field, for use with [`FieldAccess`].
+    pub const PARTID_FIELD: Field<Self, u16, 0, 16> = Field::new("MPAM3_EL3", "PARTID");
+    /// Descriptor of the `PARTID_I` field, for use with [`FieldAccess`].
+    pub const PARTID_I_FIELD: Field<Self, u16, 0, 16> = Field::new("MPAM3_EL3", "PARTID_I");
+    /// Descriptor of the `PARTID_D` field, for use with [`FieldAccess`].
+    pub const PARTID_D_FIELD: Field<Self, u16, 16, 16> = Field::new("MPAM3_EL3", "PARTID_D");
+    /// Descriptor of the `altPARTID` field, for use with [`FieldAccess`].
+    pub const ALTPARTID_FIELD: Field<Self, u16, 16, 16> = Field::new("MPAM3_EL3", "altPARTID");
+    /// Descriptor of the `PMG` field, for use with [`FieldAccess`].
+    pub const PMG_FIELD: Field<Self, u16, 32, 16> = Field::new("MPAM3_EL3", "PMG");
+    /// Descriptor of the `PMG_I` field, for use with [`FieldAccess`].
+    pub const PMG_I_FIELD: Field<Self, u8, 32, 8> = Field::new("MPAM3_EL3", "PMG_I");
+    /// Descriptor of the `PMG_D` field, for use with [`FieldAccess`].
+    pub const PMG_D_FIELD: Field<Self, u8, 40, 8> = Field::new("MPAM3_EL3", "PMG_D");
+    /// Descriptor of the `altPMG` field, for use with [`FieldAccess`].
+    pub const ALTPMG_FIELD: Field<Self, u16, 48, 16> = Field::new("MPAM3_EL3", "altPMG");
+    /// Descriptor of the `RT_ALTSP_NS` field, for use with [`FieldAccess`].
+    pub const RT_ALTSP_NS_FIELD: Field<Self, bool, 52, 1> = Field::new("MPAM3_EL3", "RT_ALTSP_NS");
+    /// Descriptor of the `ALTSP_EL3` field, for use with [`FieldAccess`].
+    pub const ALTSP_EL3_FIELD: Field<Self, bool, 55, 1> = Field::new("MPAM3_EL3", "ALTSP_EL3");
+    /// Descriptor of the `ALTSP_HFC` field, for use with [`FieldAccess`].
+    pub const ALTSP_HFC_FIELD: Field<Self, bool, 56, 1> = Field::new("MPAM3_EL3", "ALTSP_HFC");
+    /// Descriptor of the `ALTSP_HEN` field, for use with [`FieldAccess`].
+    pub const ALTSP_HEN_FIELD: Field<Self, bool, 57, 1> = Field::new("MPAM3_EL3", "ALTSP_HEN");
+    /// Descriptor of the `FORCE_NS` field, for use with [`FieldAccess`].
+    pub const FORCE_NS_FIELD: Field<Self, bool, 60, 1> = Field::new("MPAM3_EL3", "FORCE_NS");
+    /// Descriptor of the `SDEFLT` field, for use with [`FieldAccess`].
+    pub const SDEFLT_FIELD: Field<Self, bool, 61, 1> = Field::new("MPAM3_EL3", "SDEFLT");
+    /// Descriptor of the `TRAPLOWER` field, for use with [`FieldAccess`].
+    pub const TRAPLOWER_FIELD: Field<Self, bool, 62, 1> = Field::new("MPAM3_EL3", "TRAPLOWER");
+    /// Descriptor of the `MPAMEN` field, for use with [`FieldAccess`].
+    pub const MPAMEN_FIELD: Field<Self, bool, 63, 1> = Field::new("MPAM3_EL3", "MPAMEN");
+
+    /// Returns the value of the `PARTID` field.
+    pub const fn partid(self) -> u16 {
+        Self::PARTID_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `PARTID` field.
+    pub const fn set_partid(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::PARTID_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PARTID` field set to the given value.
+    pub const fn with_partid(mut self, value: u16) -> Self {
+        self.set_partid(value);
+        self
+    }
+
+    /// Sets the value of the `PARTID` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_partid(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_partid(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PARTID` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_partid(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::PARTID_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PARTID_I` field.
+    pub const fn partid_i(self) -> u16 {
+        Self::PARTID_I_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `PARTID_I` field.
+    pub const fn set_partid_i(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::PARTID_I_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PARTID_I` field set to the given value.
+    pub const fn with_partid_i(mut self, value: u16) -> Self {
+        self.set_partid_i(value);
+        self
+    }
+
+    /// Sets the value of the `PARTID_I` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_partid_i(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_partid_i(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PARTID_I` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_partid_i(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::PARTID_I_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PARTID_D` field.
+    pub const fn partid_d(self) -> u16 {
+        Self::PARTID_D_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `PARTID_D` field.
+    pub const fn set_partid_d(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::PARTID_D_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PARTID_D` field set to the given value.
+    pub const fn with_partid_d(mut self, value: u16) -> Self {
+        self.set_partid_d(value);
+        self
+    }
+
+    /// Sets the value of the `PARTID_D` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_partid_d(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_partid_d(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PARTID_D` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_partid_d(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::PARTID_D_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `altPARTID` field.
+    pub const fn altpartid(self) -> u16 {
+        Self::ALTPARTID_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `altPARTID` field.
+    pub const fn set_altpartid(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::ALTPARTID_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `altPARTID` field set to the given value.
+    pub const fn with_altpartid(mut self, value: u16) -> Self {
+        self.set_altpartid(value);
+        self
+    }
+
+    /// Sets the value of the `altPARTID` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_altpartid(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_altpartid(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `altPARTID` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_altpartid(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::ALTPARTID_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PMG` field.
+    pub const fn pmg(self) -> u16 {
+        Self::PMG_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `PMG` field.
+    pub const fn set_pmg(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::PMG_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PMG` field set to the given value.
+    pub const fn with_pmg(mut self, value: u16) -> Self {
+        self.set_pmg(value);
+        self
+    }
+
+    /// Sets the value of the `PMG` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_pmg(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_pmg(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PMG` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_pmg(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::PMG_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PMG_I` field.
+    pub const fn pmg_i(self) -> u8 {
+        Self::PMG_I_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `PMG_I` field.
+    pub const fn set_pmg_i(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::PMG_I_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PMG_I` field set to the given value.
+    pub const fn with_pmg_i(mut self, value: u8) -> Self {
+        self.set_pmg_i(value);
+        self
+    }
+
+    /// Sets the value of the `PMG_I` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_pmg_i(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_pmg_i(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PMG_I` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_pmg_i(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::PMG_I_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PMG_D` field.
+    pub const fn pmg_d(self) -> u8 {
+        Self::PMG_D_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `PMG_D` field.
+    pub const fn set_pmg_d(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::PMG_D_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PMG_D` field set to the given value.
+    pub const fn with_pmg_d(mut self, value: u8) -> Self {
+        self.set_pmg_d(value);
+        self
+    }
+
+    /// Sets the value of the `PMG_D` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_pmg_d(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_pmg_d(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PMG_D` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_pmg_d(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::PMG_D_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `altPMG` field.
+    pub const fn altpmg(self) -> u16 {
+        Self::ALTPMG_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `altPMG` field.
+    pub const fn set_altpmg(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::ALTPMG_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `altPMG` field set to the given value.
+    pub const fn with_altpmg(mut self, value: u16) -> Self {
+        self.set_altpmg(value);
+        self
+    }
+
+    /// Sets the value of the `altPMG` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_altpmg(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_altpmg(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `altPMG` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_altpmg(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::ALTPMG_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(all(feature = "el3", feature = "feat-mpam"))]
+impl Display for Mpam3El3 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("Mpam3El3");
+        let bits = self.bits();
+        s.field(
+            "PARTID",
+            &format_args!("{:#x}", Self::PARTID_FIELD.extract(bits)),
+        );
+        s.field(
+            "PARTID_I",
+            &format_args!("{:#x}", Self::PARTID_I_FIELD.extract(bits)),
+        );
+        s.field(
+            "PARTID_D",
+            &format_args!("{:#x}", Self::PARTID_D_FIELD.extract(bits)),
+        );
+        s.field(
+            "altPARTID",
+            &format_args!("{:#x}", Self::ALTPARTID_FIELD.extract(bits)),
+        );
+        s.field("PMG", &format_args!("{:#x}", Self::PMG_FIELD.extract(bits)));
+        s.field("PMG_I", &Self::PMG_I_FIELD.extract(bits));
+        s.field("PMG_D", &Self::PMG_D_FIELD.extract(bits));
+        s.field(
+            "altPMG",
+            &format_args!("{:#x}", Self::ALTPMG_FIELD.extract(bits)),
+        );
+        s.field("RT_ALTSP_NS", &Self::RT_ALTSP_NS_FIELD.extract(bits));
+        s.field("ALTSP_EL3", &Self::ALTSP_EL3_FIELD.extract(bits));
+        s.field("ALTSP_HFC", &Self::ALTSP_HFC_FIELD.extract(bits));
+        s.field("ALTSP_HEN", &Self::ALTSP_HEN_FIELD.extract(bits));
+        s.field("FORCE_NS", &Self::FORCE_NS_FIELD.extract(bits));
+        s.field("SDEFLT", &Self::SDEFLT_FIELD.extract(bits));
+        s.field("TRAPLOWER", &Self::TRAPLOWER_FIELD.extract(bits));
+        s.field("MPAMEN", &Self::MPAMEN_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(all(feature = "el3", feature = "feat-mpam"))]
+impl defmt::Format for Mpam3El3 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "Mpam3El3 {{ PARTID: {=u64:#x}, PARTID_I: {=u64:#x}, PARTID_D: {=u64:#x}, altPARTID: {=u64:#x}, PMG: {=u64:#x}, PMG_I: {=u64}, PMG_D: {=u64}, altPMG: {=u64:#x}, RT_ALTSP_NS: {=u64}, ALTSP_EL3: {=u64}, ALTSP_HFC: {=u64}, ALTSP_HEN: {=u64}, FORCE_NS: {=u64}, SDEFLT: {=u64}, TRAPLOWER: {=u64}, MPAMEN: {=u64} }}",
+            Self::PARTID_FIELD.extract(self.bits()),
+            Self::PARTID_I_FIELD.extract(self.bits()),
+            Self::PARTID_D_FIELD.extract(self.bits()),
+            Self::ALTPARTID_FIELD.extract(self.bits()),
+            Self::PMG_FIELD.extract(self.bits()),
+            Self::PMG_I_FIELD.extract(self.bits()),
+            Self::PMG_D_FIELD.extract(self.bits()),
+            Self::ALTPMG_FIELD.extract(self.bits()),
+            Self::RT_ALTSP_NS_FIELD.extract(self.bits()),
+            Self::ALTSP_EL3_FIELD.extract(self.bits()),
+            Self::ALTSP_HFC_FIELD.extract(self.bits()),
+            Self::ALTSP_HEN_FIELD.extract(self.bits()),
+            Self::FORCE_NS_FIELD.extract(self.bits()),
+            Self::SDEFLT_FIELD.extract(self.bits()),
+            Self::TRAPLOWER_FIELD.extract(self.bits()),
+            Self::MPAMEN_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el3", feature = "feat-mpam"))]
+impl serde::Serialize for Mpam3El3 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el3", feature = "feat-mpam"))]
+impl<'de> serde::Deserialize<'de> for Mpam3El3 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(all(feature = "el3", feature = "feat-mpam"))]
+impl Mpam3El3 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("PARTID"),
+                old: Self::PARTID_FIELD.extract(self.bits()),
+                new: Self::PARTID_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PARTID_I"),
+                old: Self::PARTID_I_FIELD.extract(self.bits()),
+                new: Self::PARTID_I_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PARTID_D"),
+                old: Self::PARTID_D_FIELD.extract(self.bits()),
+                new: Self::PARTID_D_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("altPARTID"),
+                old: Self::ALTPARTID_FIELD.extract(self.bits()),
+                new: Self::ALTPARTID_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMG"),
+                old: Self::PMG_FIELD.extract(self.bits()),
+                new: Self::PMG_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMG_I"),
+                old: Self::PMG_I_FIELD.extract(self.bits()),
+                new: Self::PMG_I_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PMG_D"),
+                old: Self::PMG_D_FIELD.extract(self.bits()),
+                new: Self::PMG_D_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("altPMG"),
+                old: Self::ALTPMG_FIELD.extract(self.bits()),
+                new: Self::ALTPMG_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("RT_ALTSP_NS"),
+                old: Self::RT_ALTSP_NS_FIELD.extract(self.bits()),
+                new: Self::RT_ALTSP_NS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ALTSP_EL3"),
+                old: Self::ALTSP_EL3_FIELD.extract(self.bits()),
+                new: Self::ALTSP_EL3_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ALTSP_HFC"),
+                old: Self::ALTSP_HFC_FIELD.extract(self.bits()),
+                new: Self::ALTSP_HFC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ALTSP_HEN"),
+                old: Self::ALTSP_HEN_FIELD.extract(self.bits()),
+                new: Self::ALTSP_HEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FORCE_NS"),
+                old: Self::FORCE_NS_FIELD.extract(self.bits()),
+                new: Self::FORCE_NS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SDEFLT"),
+                old: Self::SDEFLT_FIELD.extract(self.bits()),
+                new: Self::SDEFLT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TRAPLOWER"),
+                old: Self::TRAPLOWER_FIELD.extract(self.bits()),
+                new: Self::TRAPLOWER_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("MPAMEN"),
+                old: Self::MPAMEN_FIELD.extract(self.bits()),
+                new: Self::MPAMEN_FIELD.extract(other.bits()),
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(all(feature = "el3", feature = "feat-mpam"))]
+impl Mpam3El3 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(all(feature = "el3", feature = "feat-mpam"))]
+impl Mpam3El3 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                Self::PARTID_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAM3_EL3",
+                    field: "PARTID",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::PARTID_I_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAM3_EL3",
+                    field: "PARTID_I",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::PARTID_D_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAM3_EL3",
+                    field: "PARTID_D",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::ALTPARTID_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAM3_EL3",
+                    field: "altPARTID",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::PMG_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAM3_EL3",
+                    field: "PMG",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::PMG_I_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAM3_EL3",
+                    field: "PMG_I",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::PMG_D_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAM3_EL3",
+                    field: "PMG_D",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::ALTPMG_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAM3_EL3",
+                    field: "altPMG",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::RT_ALTSP_NS_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAM3_EL3",
+                    field: "RT_ALTSP_NS",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::ALTSP_EL3_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAM3_EL3",
+                    field: "ALTSP_EL3",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::ALTSP_HFC_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAM3_EL3",
+                    field: "ALTSP_HFC",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::ALTSP_HEN_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAM3_EL3",
+                    field: "ALTSP_HEN",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::FORCE_NS_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAM3_EL3",
+                    field: "FORCE_NS",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::SDEFLT_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAM3_EL3",
+                    field: "SDEFLT",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::TRAPLOWER_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAM3_EL3",
+                    field: "TRAPLOWER",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::MPAMEN_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAM3_EL3",
+                    field: "MPAMEN",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+bitflags! {
+    /// `MPAMHCR_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct MpamhcrEl2: u64 {
+        /// `EL0_VPMEN` bit.
+        const EL0_VPMEN = 1 << 0;
+        /// `EL1_VPMEN` bit.
+        const EL1_VPMEN = 1 << 1;
+        /// `GSTAPP_PLK` bit.
+        const GSTAPP_PLK = 1 << 8;
+        /// `TRAP_MPAMIDR_EL1` bit.
+        const TRAP_MPAMIDR_EL1 = 1 << 31;
+        /// `nTRAPMPAM1EL1` bit.
+        const NTRAPMPAM1EL1 = 1 << 48;
+        /// `nTRAPMPAM0EL1` bit.
+        const NTRAPMPAM0EL1 = 1 << 49;
+        /// `nTRAPMPAMSM` bit.
+        const NTRAPMPAMSM = 1 << 50;
+        /// `nTIDR` bit.
+        const NTIDR = 1 << 58;
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl MpamhcrEl2 {
+    /// Descriptor of the `EL0_VPMEN` field, for use with [`FieldAccess`].
+    pub const EL0_VPMEN_FIELD: Field<Self, bool, 0, 1> = Field::new("MPAMHCR_EL2", "EL0_VPMEN");
+    /// Descriptor of the `EL1_VPMEN` field, for use with [`FieldAccess`].
+    pub const EL1_VPMEN_FIELD: Field<Self, bool, 1, 1> = Field::new("MPAMHCR_EL2", "EL1_VPMEN");
+    /// Descriptor of the `GSTAPP_PLK` field, for use with [`FieldAccess`].
+    pub const GSTAPP_PLK_FIELD: Field<Self, bool, 8, 1> = Field::new("MPAMHCR_EL2", "GSTAPP_PLK");
+    /// Descriptor of the `TRAP_MPAMIDR_EL1` field, for use with [`FieldAccess`].
+    pub const TRAP_MPAMIDR_EL1_FIELD: Field<Self, bool, 31, 1> =
+        Field::new("MPAMHCR_EL2", "TRAP_MPAMIDR_EL1");
+    /// Descriptor of the `nTRAPMPAM1EL1` field, for use with [`FieldAccess`].
+    pub const NTRAPMPAM1EL1_FIELD: Field<Self, bool, 48, 1> =
+        Field::new("MPAMHCR_EL2", "nTRAPMPAM1EL1");
+    /// Descriptor of the `nTRAPMPAM0EL1` field, for use with [`FieldAccess`].
+    pub const NTRAPMPAM0EL1_FIELD: Field<Self, bool, 49, 1> =
+        Field::new("MPAMHCR_EL2", "nTRAPMPAM0EL1");
+    /// Descriptor of the `nTRAPMPAMSM` field, for use with [`FieldAccess`].
+    pub const NTRAPMPAMSM_FIELD: Field<Self, bool, 50, 1> =
+        Field::new("MPAMHCR_EL2", "nTRAPMPAMSM");
+    /// Descriptor of the `nTIDR` field, for use with [`FieldAccess`].
+    pub const NTIDR_FIELD: Field<Self, bool, 58, 1> = Field::new("MPAMHCR_EL2", "nTIDR");
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl Display for MpamhcrEl2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("MpamhcrEl2");
+        let bits = self.bits();
+        s.field("EL0_VPMEN", &Self::EL0_VPMEN_FIELD.extract(bits));
+        s.field("EL1_VPMEN", &Self::EL1_VPMEN_FIELD.extract(bits));
+        s.field("GSTAPP_PLK", &Self::GSTAPP_PLK_FIELD.extract(bits));
+        s.field(
+            "TRAP_MPAMIDR_EL1",
+            &Self::TRAP_MPAMIDR_EL1_FIELD.extract(bits),
+        );
+        s.field("nTRAPMPAM1EL1", &Self::NTRAPMPAM1EL1_FIELD.extract(bits));
+        s.field("nTRAPMPAM0EL1", &Self::NTRAPMPAM0EL1_FIELD.extract(bits));
+        s.field("nTRAPMPAMSM", &Self::NTRAPMPAMSM_FIELD.extract(bits));
+        s.field("nTIDR", &Self::NTIDR_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl defmt::Format for MpamhcrEl2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "MpamhcrEl2 {{ EL0_VPMEN: {=u64}, EL1_VPMEN: {=u64}, GSTAPP_PLK: {=u64}, TRAP_MPAMIDR_EL1: {=u64}, nTRAPMPAM1EL1: {=u64}, nTRAPMPAM0EL1: {=u64}, nTRAPMPAMSM: {=u64}, nTIDR: {=u64} }}",
+            Self::EL0_VPMEN_FIELD.extract(self.bits()),
+            Self::EL1_VPMEN_FIELD.extract(self.bits()),
+            Self::GSTAPP_PLK_FIELD.extract(self.bits()),
+            Self::TRAP_MPAMIDR_EL1_FIELD.extract(self.bits()),
+            Self::NTRAPMPAM1EL1_FIELD.extract(self.bits()),
+            Self::NTRAPMPAM0EL1_FIELD.extract(self.bits()),
+            Self::NTRAPMPAMSM_FIELD.extract(self.bits()),
+            Self::NTIDR_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl serde::Serialize for MpamhcrEl2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl<'de> serde::Deserialize<'de> for MpamhcrEl2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl MpamhcrEl2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("EL0_VPMEN"),
+                old: Self::EL0_VPMEN_FIELD.extract(self.bits()),
+                new: Self::EL0_VPMEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EL1_VPMEN"),
+                old: Self::EL1_VPMEN_FIELD.extract(self.bits()),
+                new: Self::EL1_VPMEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("GSTAPP_PLK"),
+                old: Self::GSTAPP_PLK_FIELD.extract(self.bits()),
+                new: Self::GSTAPP_PLK_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TRAP_MPAMIDR_EL1"),
+                old: Self::TRAP_MPAMIDR_EL1_FIELD.extract(self.bits()),
+                new: Self::TRAP_MPAMIDR_EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nTRAPMPAM1EL1"),
+                old: Self::NTRAPMPAM1EL1_FIELD.extract(self.bits()),
+                new: Self::NTRAPMPAM1EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nTRAPMPAM0EL1"),
+                old: Self::NTRAPMPAM0EL1_FIELD.extract(self.bits()),
+                new: Self::NTRAPMPAM0EL1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nTRAPMPAMSM"),
+                old: Self::NTRAPMPAMSM_FIELD.extract(self.bits()),
+                new: Self::NTRAPMPAMSM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("nTIDR"),
+                old: Self::NTIDR_FIELD.extract(self.bits()),
+                new: Self::NTIDR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1011_1111_1000_1111_1111_1111_1111_0111_1111_1111_1111_1111_1110_1111_1100,
+                new: other.bits() & 0b1111_1011_1111_1000_1111_1111_1111_1111_0111_1111_1111_1111_1111_1110_1111_1100,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl MpamhcrEl2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl MpamhcrEl2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                Self::EL0_VPMEN_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMHCR_EL2",
+                    field: "EL0_VPMEN",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::EL1_VPMEN_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMHCR_EL2",
+                    field: "EL1_VPMEN",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::GSTAPP_PLK_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMHCR_EL2",
+                    field: "GSTAPP_PLK",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::TRAP_MPAMIDR_EL1_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMHCR_EL2",
+                    field: "TRAP_MPAMIDR_EL1",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::NTRAPMPAM1EL1_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMHCR_EL2",
+                    field: "nTRAPMPAM1EL1",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::NTRAPMPAM0EL1_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMHCR_EL2",
+                    field: "nTRAPMPAM0EL1",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::NTRAPMPAMSM_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMHCR_EL2",
+                    field: "nTRAPMPAMSM",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::NTIDR_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMHCR_EL2",
+                    field: "nTIDR",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-mpam"))]
+bitflags! {
+    /// `MPAMIDR_EL1` system register value.
+    ///
+    /// Indicates the maximum PARTID and PMG values supported in the implementation and the support for other optional features.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct MpamidrEl1: u64 {
+        /// Indicates support for MPAM virtualization.
+        const HAS_HCR = 1 << 17;
+        /// `HAS_ALT_ID` bit.
+        const HAS_ALT_ID = 1 << 21;
+        /// `HAS_INSTR_ALT_ID` bit.
+        const HAS_INSTR_ALT_ID = 1 << 22;
+        /// `HAS_BW_CTRL` bit.
+        const HAS_BW_CTRL = 1 << 56;
+        /// `HAS_ALTSP` bit.
+        const HAS_ALTSP = 1 << 57;
+        /// `HAS_TIDR` bit.
+        const HAS_TIDR = 1 << 58;
+        /// `SP4` bit.
+        const SP4 = 1 << 59;
+        /// `HAS_FORCE_NS` bit.
+        const HAS_FORCE_NS = 1 << 60;
+        /// `HAS_SDEFLT` bit.
+        const HAS_SDEFLT = 1 << 61;
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-mpam"))]
+impl MpamidrEl1 {
+    /// Descriptor of the `PARTID_MAX` field, for use with [`FieldAccess`].
+    pub const PARTID_MAX_FIELD: Field<Self, u16, 0, 16> = Field::new("MPAMIDR_EL1", "PARTID_MAX");
+    /// Descriptor of the `HAS_HCR` field, for use with [`FieldAccess`].
+    pub const HAS_HCR_FIELD: Field<Self, bool, 17, 1> = Field::new("MPAMIDR_EL1", "HAS_HCR");
+    /// Descriptor of the `VPMR_MAX` field, for use with [`FieldAccess`].
+    pub const VPMR_MAX_FIELD: Field<Self, u8, 18, 3> = Field::new("MPAMIDR_EL1", "VPMR_MAX");
+    /// Descriptor of the `HAS_ALT_ID` field, for use with [`FieldAccess`].
+    pub const HAS_ALT_ID_FIELD: Field<Self, bool, 21, 1> = Field::new("MPAMIDR_EL1", "HAS_ALT_ID");
+    /// Descriptor of the `HAS_INSTR_ALT_ID` field, for use with [`FieldAccess`].
+    pub const HAS_INSTR_ALT_ID_FIELD: Field<Self, bool, 22, 1> =
+        Field::new("MPAMIDR_EL1", "HAS_INSTR_ALT_ID");
+    /// Descriptor of the `HAS_BW_CTRL` field, for use with [`FieldAccess`].
+    pub const HAS_BW_CTRL_FIELD: Field<Self, bool, 56, 1> =
+        Field::new("MPAMIDR_EL1", "HAS_BW_CTRL");
+    /// Descriptor of the `HAS_ALTSP` field, for use with [`FieldAccess`].
+    pub const HAS_ALTSP_FIELD: Field<Self, bool, 57, 1> = Field::new("MPAMIDR_EL1", "HAS_ALTSP");
+    /// Descriptor of the `HAS_TIDR` field, for use with [`FieldAccess`].
+    pub const HAS_TIDR_FIELD: Field<Self, bool, 58, 1> = Field::new("MPAMIDR_EL1", "HAS_TIDR");
+    /// Descriptor of the `SP4` field, for use with [`FieldAccess`].
+    pub const SP4_FIELD: Field<Self, bool, 59, 1> = Field::new("MPAMIDR_EL1", "SP4");
+    /// Descriptor of the `HAS_FORCE_NS` field, for use with [`FieldAccess`].
+    pub const HAS_FORCE_NS_FIELD: Field<Self, bool, 60, 1> =
+        Field::new("MPAMIDR_EL1", "HAS_FORCE_NS");
+    /// Descriptor of the `HAS_SDEFLT` field, for use with [`FieldAccess`].
+    pub const HAS_SDEFLT_FIELD: Field<Self, bool, 61, 1> = Field::new("MPAMIDR_EL1", "HAS_SDEFLT");
+
+    /// Returns the value of the `PARTID_MAX` field.
+    pub const fn partid_max(self) -> u16 {
+        Self::PARTID_MAX_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `PARTID_MAX` field.
+    pub const fn set_partid_max(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::PARTID_MAX_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PARTID_MAX` field set to the given value.
+    pub const fn with_partid_max(mut self, value: u16) -> Self {
+        self.set_partid_max(value);
+        self
+    }
+
+    /// Sets the value of the `PARTID_MAX` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_partid_max(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_partid_max(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PARTID_MAX` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_partid_max(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::PARTID_MAX_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `VPMR_MAX` field.
+    ///
+    /// Indicates the maximum register index n for the `MPAMVPM<n>_EL2` registers.
+    pub const fn vpmr_max(self) -> u8 {
+        Self::VPMR_MAX_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `VPMR_MAX` field.
+    ///
+    /// Indicates the maximum register index n for the `MPAMVPM<n>_EL2` registers.
+    pub const fn set_vpmr_max(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::VPMR_MAX_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `VPMR_MAX` field set to the given value.
+    ///
+    /// Indicates the maximum register index n for the `MPAMVPM<n>_EL2` registers.
+    pub const fn with_vpmr_max(mut self, value: u8) -> Self {
+        self.set_vpmr_max(value);
+        self
+    }
+
+    /// Sets the value of the `VPMR_MAX` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_vpmr_max(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_vpmr_max(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `VPMR_MAX` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_vpmr_max(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::VPMR_MAX_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-mpam"))]
+impl Display for MpamidrEl1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("MpamidrEl1");
+        let bits = self.bits();
+        s.field(
+            "PARTID_MAX",
+            &format_args!("{:#x}", Self::PARTID_MAX_FIELD.extract(bits)),
+        );
+        s.field("HAS_HCR", &Self::HAS_HCR_FIELD.extract(bits));
+        s.field("VPMR_MAX", &Self::VPMR_MAX_FIELD.extract(bits));
+        s.field("HAS_ALT_ID", &Self::HAS_ALT_ID_FIELD.extract(bits));
+        s.field(
+            "HAS_INSTR_ALT_ID",
+            &Self::HAS_INSTR_ALT_ID_FIELD.extract(bits),
+        );
+        s.field("HAS_BW_CTRL", &Self::HAS_BW_CTRL_FIELD.extract(bits));
+        s.field("HAS_ALTSP", &Self::HAS_ALTSP_FIELD.extract(bits));
+        s.field("HAS_TIDR", &Self::HAS_TIDR_FIELD.extract(bits));
+        s.field("SP4", &Self::SP4_FIELD.extract(bits));
+        s.field("HAS_FORCE_NS", &Self::HAS_FORCE_NS_FIELD.extract(bits));
+        s.field("HAS_SDEFLT", &Self::HAS_SDEFLT_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(all(feature = "el1", feature = "feat-mpam"))]
+impl defmt::Format for MpamidrEl1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "MpamidrEl1 {{ PARTID_MAX: {=u64:#x}, HAS_HCR: {=u64}, VPMR_MAX: {=u64}, HAS_ALT_ID: {=u64}, HAS_INSTR_ALT_ID: {=u64}, HAS_BW_CTRL: {=u64}, HAS_ALTSP: {=u64}, HAS_TIDR: {=u64}, SP4: {=u64}, HAS_FORCE_NS: {=u64}, HAS_SDEFLT: {=u64} }}",
+            Self::PARTID_MAX_FIELD.extract(self.bits()),
+            Self::HAS_HCR_FIELD.extract(self.bits()),
+            Self::VPMR_MAX_FIELD.extract(self.bits()),
+            Self::HAS_ALT_ID_FIELD.extract(self.bits()),
+            Self::HAS_INSTR_ALT_ID_FIELD.extract(self.bits()),
+            Self::HAS_BW_CTRL_FIELD.extract(self.bits()),
+            Self::HAS_ALTSP_FIELD.extract(self.bits()),
+            Self::HAS_TIDR_FIELD.extract(self.bits()),
+            Self::SP4_FIELD.extract(self.bits()),
+            Self::HAS_FORCE_NS_FIELD.extract(self.bits()),
+            Self::HAS_SDEFLT_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el1", feature = "feat-mpam"))]
+impl serde::Serialize for MpamidrEl1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el1", feature = "feat-mpam"))]
+impl<'de> serde::Deserialize<'de> for MpamidrEl1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-mpam"))]
+impl MpamidrEl1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("PARTID_MAX"),
+                old: Self::PARTID_MAX_FIELD.extract(self.bits()),
+                new: Self::PARTID_MAX_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HAS_HCR"),
+                old: Self::HAS_HCR_FIELD.extract(self.bits()),
+                new: Self::HAS_HCR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("VPMR_MAX"),
+                old: Self::VPMR_MAX_FIELD.extract(self.bits()),
+                new: Self::VPMR_MAX_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HAS_ALT_ID"),
+                old: Self::HAS_ALT_ID_FIELD.extract(self.bits()),
+                new: Self::HAS_ALT_ID_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HAS_INSTR_ALT_ID"),
+                old: Self::HAS_INSTR_ALT_ID_FIELD.extract(self.bits()),
+                new: Self::HAS_INSTR_ALT_ID_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HAS_BW_CTRL"),
+                old: Self::HAS_BW_CTRL_FIELD.extract(self.bits()),
+                new: Self::HAS_BW_CTRL_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HAS_ALTSP"),
+                old: Self::HAS_ALTSP_FIELD.extract(self.bits()),
+                new: Self::HAS_ALTSP_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HAS_TIDR"),
+                old: Self::HAS_TIDR_FIELD.extract(self.bits()),
+                new: Self::HAS_TIDR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SP4"),
+                old: Self::SP4_FIELD.extract(self.bits()),
+                new: Self::SP4_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HAS_FORCE_NS"),
+                old: Self::HAS_FORCE_NS_FIELD.extract(self.bits()),
+                new: Self::HAS_FORCE_NS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("HAS_SDEFLT"),
+                old: Self::HAS_SDEFLT_FIELD.extract(self.bits()),
+                new: Self::HAS_SDEFLT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1100_0000_1111_1111_1111_1111_1111_1111_1111_1111_1000_0001_0000_0000_0000_0000,
+                new: other.bits() & 0b1100_0000_1111_1111_1111_1111_1111_1111_1111_1111_1000_0001_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-mpam"))]
+impl MpamidrEl1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-mpam"))]
+impl MpamidrEl1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                Self::PARTID_MAX_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMIDR_EL1",
+                    field: "PARTID_MAX",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::HAS_HCR_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMIDR_EL1",
+                    field: "HAS_HCR",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::VPMR_MAX_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMIDR_EL1",
+                    field: "VPMR_MAX",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::HAS_ALT_ID_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMIDR_EL1",
+                    field: "HAS_ALT_ID",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::HAS_INSTR_ALT_ID_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMIDR_EL1",
+                    field: "HAS_INSTR_ALT_ID",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::HAS_BW_CTRL_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMIDR_EL1",
+                    field: "HAS_BW_CTRL",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::HAS_ALTSP_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMIDR_EL1",
+                    field: "HAS_ALTSP",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::HAS_TIDR_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMIDR_EL1",
+                    field: "HAS_TIDR",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::SP4_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMIDR_EL1",
+                    field: "SP4",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::HAS_FORCE_NS_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMIDR_EL1",
+                    field: "HAS_FORCE_NS",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::HAS_SDEFLT_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMIDR_EL1",
+                    field: "HAS_SDEFLT",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+bitflags! {
+    /// `MPAMVPM0_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct Mpamvpm0El2: u64 {
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl Mpamvpm0El2 {
+    /// Descriptor of the `PhyPARTID0` field, for use with [`FieldAccess`].
+    pub const PHYPARTID0_FIELD: Field<Self, u16, 0, 16> = Field::new("MPAMVPM0_EL2", "PhyPARTID0");
+    /// Descriptor of the `PhyPARTID1` field, for use with [`FieldAccess`].
+    pub const PHYPARTID1_FIELD: Field<Self, u16, 16, 16> = Field::new("MPAMVPM0_EL2", "PhyPARTID1");
+    /// Descriptor of the `PhyPARTID2` field, for use with [`FieldAccess`].
+    pub const PHYPARTID2_FIELD: Field<Self, u16, 32, 16> = Field::new("MPAMVPM0_EL2", "PhyPARTID2");
+    /// Descriptor of the `PhyPARTID3` field, for use with [`FieldAccess`].
+    pub const PHYPARTID3_FIELD: Field<Self, u16, 48, 16> = Field::new("MPAMVPM0_EL2", "PhyPARTID3");
+
+    /// Returns the value of the `PhyPARTID0` field.
+    pub const fn phypartid0(self) -> u16 {
+        Self::PHYPARTID0_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `PhyPARTID0` field.
+    pub const fn set_phypartid0(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::PHYPARTID0_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PhyPARTID0` field set to the given value.
+    pub const fn with_phypartid0(mut self, value: u16) -> Self {
+        self.set_phypartid0(value);
+        self
+    }
+
+    /// Sets the value of the `PhyPARTID0` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_phypartid0(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_phypartid0(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PhyPARTID0` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_phypartid0(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::PHYPARTID0_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PhyPARTID1` field.
+    pub const fn phypartid1(self) -> u16 {
+        Self::PHYPARTID1_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `PhyPARTID1` field.
+    pub const fn set_phypartid1(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::PHYPARTID1_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PhyPARTID1` field set to the given value.
+    pub const fn with_phypartid1(mut self, value: u16) -> Self {
+        self.set_phypartid1(value);
+        self
+    }
+
+    /// Sets the value of the `PhyPARTID1` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_phypartid1(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_phypartid1(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PhyPARTID1` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_phypartid1(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::PHYPARTID1_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PhyPARTID2` field.
+    pub const fn phypartid2(self) -> u16 {
+        Self::PHYPARTID2_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `PhyPARTID2` field.
+    pub const fn set_phypartid2(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::PHYPARTID2_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PhyPARTID2` field set to the given value.
+    pub const fn with_phypartid2(mut self, value: u16) -> Self {
+        self.set_phypartid2(value);
+        self
+    }
+
+    /// Sets the value of the `PhyPARTID2` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_phypartid2(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_phypartid2(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PhyPARTID2` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_phypartid2(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::PHYPARTID2_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PhyPARTID3` field.
+    pub const fn phypartid3(self) -> u16 {
+        Self::PHYPARTID3_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `PhyPARTID3` field.
+    pub const fn set_phypartid3(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::PHYPARTID3_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PhyPARTID3` field set to the given value.
+    pub const fn with_phypartid3(mut self, value: u16) -> Self {
+        self.set_phypartid3(value);
+        self
+    }
+
+    /// Sets the value of the `PhyPARTID3` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_phypartid3(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_phypartid3(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PhyPARTID3` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_phypartid3(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::PHYPARTID3_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl Display for Mpamvpm0El2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("Mpamvpm0El2");
+        let bits = self.bits();
+        s.field(
+            "PhyPARTID0",
+            &format_args!("{:#x}", Self::PHYPARTID0_FIELD.extract(bits)),
+        );
+        s.field(
+            "PhyPARTID1",
+            &format_args!("{:#x}", Self::PHYPARTID1_FIELD.extract(bits)),
+        );
+        s.field(
+            "PhyPARTID2",
+            &format_args!("{:#x}", Self::PHYPARTID2_FIELD.extract(bits)),
+        );
+        s.field(
+            "PhyPARTID3",
+            &format_args!("{:#x}", Self::PHYPARTID3_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl defmt::Format for Mpamvpm0El2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "Mpamvpm0El2 {{ PhyPARTID0: {=u64:#x}, PhyPARTID1: {=u64:#x}, PhyPARTID2: {=u64:#x}, PhyPARTID3: {=u64:#x} }}",
+            Self::PHYPARTID0_FIELD.extract(self.bits()),
+            Self::PHYPARTID1_FIELD.extract(self.bits()),
+            Self::PHYPARTID2_FIELD.extract(self.bits()),
+            Self::PHYPARTID3_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl serde::Serialize for Mpamvpm0El2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl<'de> serde::Deserialize<'de> for Mpamvpm0El2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl Mpamvpm0El2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("PhyPARTID0"),
+                old: Self::PHYPARTID0_FIELD.extract(self.bits()),
+                new: Self::PHYPARTID0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PhyPARTID1"),
+                old: Self::PHYPARTID1_FIELD.extract(self.bits()),
+                new: Self::PHYPARTID1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PhyPARTID2"),
+                old: Self::PHYPARTID2_FIELD.extract(self.bits()),
+                new: Self::PHYPARTID2_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PhyPARTID3"),
+                old: Self::PHYPARTID3_FIELD.extract(self.bits()),
+                new: Self::PHYPARTID3_FIELD.extract(other.bits()),
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl Mpamvpm0El2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl Mpamvpm0El2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                Self::PHYPARTID0_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPM0_EL2",
+                    field: "PhyPARTID0",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::PHYPARTID1_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPM0_EL2",
+                    field: "PhyPARTID1",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::PHYPARTID2_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPM0_EL2",
+                    field: "PhyPARTID2",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::PHYPARTID3_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPM0_EL2",
+                    field: "PhyPARTID3",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+bitflags! {
+    /// `MPAMVPM1_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct Mpamvpm1El2: u64 {
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl Mpamvpm1El2 {
+    /// Descriptor of the `PhyPARTID4` field, for use with [`FieldAccess`].
+    pub const PHYPARTID4_FIELD: Field<Self, u16, 0, 16> = Field::new("MPAMVPM1_EL2", "PhyPARTID4");
+    /// Descriptor of the `PhyPARTID5` field, for use with [`FieldAccess`].
+    pub const PHYPARTID5_FIELD: Field<Self, u16, 16, 16> = Field::new("MPAMVPM1_EL2", "PhyPARTID5");
+    /// Descriptor of the `PhyPARTID6` field, for use with [`FieldAccess`].
+    pub const PHYPARTID6_FIELD: Field<Self, u16, 32, 16> = Field::new("MPAMVPM1_EL2", "PhyPARTID6");
+    /// Descriptor of the `PhyPARTID7` field, for use with [`FieldAccess`].
+    pub const PHYPARTID7_FIELD: Field<Self, u16, 48, 16> = Field::new("MPAMVPM1_EL2", "PhyPARTID7");
+
+    /// Returns the value of the `PhyPARTID4` field.
+    pub const fn phypartid4(self) -> u16 {
+        Self::PHYPARTID4_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `PhyPARTID4` field.
+    pub const fn set_phypartid4(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::PHYPARTID4_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PhyPARTID4` field set to the given value.
+    pub const fn with_phypartid4(mut self, value: u16) -> Self {
+        self.set_phypartid4(value);
+        self
+    }
+
+    /// Sets the value of the `PhyPARTID4` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_phypartid4(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_phypartid4(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PhyPARTID4` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_phypartid4(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::PHYPARTID4_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PhyPARTID5` field.
+    pub const fn phypartid5(self) -> u16 {
+        Self::PHYPARTID5_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `PhyPARTID5` field.
+    pub const fn set_phypartid5(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::PHYPARTID5_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PhyPARTID5` field set to the given value.
+    pub const fn with_phypartid5(mut self, value: u16) -> Self {
+        self.set_phypartid5(value);
+        self
+    }
+
+    /// Sets the value of the `PhyPARTID5` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_phypartid5(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_phypartid5(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PhyPARTID5` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_phypartid5(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::PHYPARTID5_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PhyPARTID6` field.
+    pub const fn phypartid6(self) -> u16 {
+        Self::PHYPARTID6_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `PhyPARTID6` field.
+    pub const fn set_phypartid6(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::PHYPARTID6_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PhyPARTID6` field set to the given value.
+    pub const fn with_phypartid6(mut self, value: u16) -> Self {
+        self.set_phypartid6(value);
+        self
+    }
+
+    /// Sets the value of the `PhyPARTID6` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_phypartid6(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_phypartid6(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PhyPARTID6` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_phypartid6(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::PHYPARTID6_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PhyPARTID7` field.
+    pub const fn phypartid7(self) -> u16 {
+        Self::PHYPARTID7_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `PhyPARTID7` field.
+    pub const fn set_phypartid7(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::PHYPARTID7_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PhyPARTID7` field set to the given value.
+    pub const fn with_phypartid7(mut self, value: u16) -> Self {
+        self.set_phypartid7(value);
+        self
+    }
+
+    /// Sets the value of the `PhyPARTID7` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_phypartid7(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_phypartid7(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PhyPARTID7` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_phypartid7(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::PHYPARTID7_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl Display for Mpamvpm1El2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("Mpamvpm1El2");
+        let bits = self.bits();
+        s.field(
+            "PhyPARTID4",
+            &format_args!("{:#x}", Self::PHYPARTID4_FIELD.extract(bits)),
+        );
+        s.field(
+            "PhyPARTID5",
+            &format_args!("{:#x}", Self::PHYPARTID5_FIELD.extract(bits)),
+        );
+        s.field(
+            "PhyPARTID6",
+            &format_args!("{:#x}", Self::PHYPARTID6_FIELD.extract(bits)),
+        );
+        s.field(
+            "PhyPARTID7",
+            &format_args!("{:#x}", Self::PHYPARTID7_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl defmt::Format for Mpamvpm1El2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "Mpamvpm1El2 {{ PhyPARTID4: {=u64:#x}, PhyPARTID5: {=u64:#x}, PhyPARTID6: {=u64:#x}, PhyPARTID7: {=u64:#x} }}",
+            Self::PHYPARTID4_FIELD.extract(self.bits()),
+            Self::PHYPARTID5_FIELD.extract(self.bits()),
+            Self::PHYPARTID6_FIELD.extract(self.bits()),
+            Self::PHYPARTID7_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl serde::Serialize for Mpamvpm1El2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl<'de> serde::Deserialize<'de> for Mpamvpm1El2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl Mpamvpm1El2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("PhyPARTID4"),
+                old: Self::PHYPARTID4_FIELD.extract(self.bits()),
+                new: Self::PHYPARTID4_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PhyPARTID5"),
+                old: Self::PHYPARTID5_FIELD.extract(self.bits()),
+                new: Self::PHYPARTID5_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PhyPARTID6"),
+                old: Self::PHYPARTID6_FIELD.extract(self.bits()),
+                new: Self::PHYPARTID6_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PhyPARTID7"),
+                old: Self::PHYPARTID7_FIELD.extract(self.bits()),
+                new: Self::PHYPARTID7_FIELD.extract(other.bits()),
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl Mpamvpm1El2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl Mpamvpm1El2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                Self::PHYPARTID4_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPM1_EL2",
+                    field: "PhyPARTID4",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::PHYPARTID5_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPM1_EL2",
+                    field: "PhyPARTID5",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::PHYPARTID6_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPM1_EL2",
+                    field: "PhyPARTID6",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::PHYPARTID7_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPM1_EL2",
+                    field: "PhyPARTID7",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+bitflags! {
+    /// `MPAMVPM2_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct Mpamvpm2El2: u64 {
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl Mpamvpm2El2 {
+    /// Descriptor of the `PhyPARTID8` field, for use with [`FieldAccess`].
+    pub const PHYPARTID8_FIELD: Field<Self, u16, 0, 16> = Field::new("MPAMVPM2_EL2", "PhyPARTID8");
+    /// Descriptor of the `PhyPARTID9` field, for use with [`FieldAccess`].
+    pub const PHYPARTID9_FIELD: Field<Self, u16, 16, 16> = Field::new("MPAMVPM2_EL2", "PhyPARTID9");
+    /// Descriptor of the `PhyPARTID10` field, for use with [`FieldAccess`].
+    pub const PHYPARTID10_FIELD: Field<Self, u16, 32, 16> =
+        Field::new("MPAMVPM2_EL2", "PhyPARTID10");
+    /// Descriptor of the `PhyPARTID11` field, for use with [`FieldAccess`].
+    pub const PHYPARTID11_FIELD: Field<Self, u16, 48, 16> =
+        Field::new("MPAMVPM2_EL2", "PhyPARTID11");
+
+    /// Returns the value of the `PhyPARTID8` field.
+    pub const fn phypartid8(self) -> u16 {
+        Self::PHYPARTID8_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `PhyPARTID8` field.
+    pub const fn set_phypartid8(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::PHYPARTID8_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PhyPARTID8` field set to the given value.
+    pub const fn with_phypartid8(mut self, value: u16) -> Self {
+        self.set_phypartid8(value);
+        self
+    }
+
+    /// Sets the value of the `PhyPARTID8` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_phypartid8(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_phypartid8(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PhyPARTID8` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_phypartid8(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::PHYPARTID8_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PhyPARTID9` field.
+    pub const fn phypartid9(self) -> u16 {
+        Self::PHYPARTID9_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `PhyPARTID9` field.
+    pub const fn set_phypartid9(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::PHYPARTID9_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PhyPARTID9` field set to the given value.
+    pub const fn with_phypartid9(mut self, value: u16) -> Self {
+        self.set_phypartid9(value);
+        self
+    }
+
+    /// Sets the value of the `PhyPARTID9` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_phypartid9(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_phypartid9(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PhyPARTID9` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_phypartid9(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::PHYPARTID9_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PhyPARTID10` field.
+    pub const fn phypartid10(self) -> u16 {
+        Self::PHYPARTID10_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `PhyPARTID10` field.
+    pub const fn set_phypartid10(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::PHYPARTID10_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PhyPARTID10` field set to the given value.
+    pub const fn with_phypartid10(mut self, value: u16) -> Self {
+        self.set_phypartid10(value);
+        self
+    }
+
+    /// Sets the value of the `PhyPARTID10` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_phypartid10(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_phypartid10(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PhyPARTID10` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_phypartid10(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::PHYPARTID10_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PhyPARTID11` field.
+    pub const fn phypartid11(self) -> u16 {
+        Self::PHYPARTID11_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `PhyPARTID11` field.
+    pub const fn set_phypartid11(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::PHYPARTID11_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PhyPARTID11` field set to the given value.
+    pub const fn with_phypartid11(mut self, value: u16) -> Self {
+        self.set_phypartid11(value);
+        self
+    }
+
+    /// Sets the value of the `PhyPARTID11` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_phypartid11(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_phypartid11(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PhyPARTID11` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_phypartid11(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::PHYPARTID11_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl Display for Mpamvpm2El2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("Mpamvpm2El2");
+        let bits = self.bits();
+        s.field(
+            "PhyPARTID8",
+            &format_args!("{:#x}", Self::PHYPARTID8_FIELD.extract(bits)),
+        );
+        s.field(
+            "PhyPARTID9",
+            &format_args!("{:#x}", Self::PHYPARTID9_FIELD.extract(bits)),
+        );
+        s.field(
+            "PhyPARTID10",
+            &format_args!("{:#x}", Self::PHYPARTID10_FIELD.extract(bits)),
+        );
+        s.field(
+            "PhyPARTID11",
+            &format_args!("{:#x}", Self::PHYPARTID11_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl defmt::Format for Mpamvpm2El2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "Mpamvpm2El2 {{ PhyPARTID8: {=u64:#x}, PhyPARTID9: {=u64:#x}, PhyPARTID10: {=u64:#x}, PhyPARTID11: {=u64:#x} }}",
+            Self::PHYPARTID8_FIELD.extract(self.bits()),
+            Self::PHYPARTID9_FIELD.extract(self.bits()),
+            Self::PHYPARTID10_FIELD.extract(self.bits()),
+            Self::PHYPARTID11_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl serde::Serialize for Mpamvpm2El2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl<'de> serde::Deserialize<'de> for Mpamvpm2El2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl Mpamvpm2El2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("PhyPARTID8"),
+                old: Self::PHYPARTID8_FIELD.extract(self.bits()),
+                new: Self::PHYPARTID8_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PhyPARTID9"),
+                old: Self::PHYPARTID9_FIELD.extract(self.bits()),
+                new: Self::PHYPARTID9_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PhyPARTID10"),
+                old: Self::PHYPARTID10_FIELD.extract(self.bits()),
+                new: Self::PHYPARTID10_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PhyPARTID11"),
+                old: Self::PHYPARTID11_FIELD.extract(self.bits()),
+                new: Self::PHYPARTID11_FIELD.extract(other.bits()),
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl Mpamvpm2El2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl Mpamvpm2El2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                Self::PHYPARTID8_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPM2_EL2",
+                    field: "PhyPARTID8",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::PHYPARTID9_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPM2_EL2",
+                    field: "PhyPARTID9",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::PHYPARTID10_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPM2_EL2",
+                    field: "PhyPARTID10",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::PHYPARTID11_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPM2_EL2",
+                    field: "PhyPARTID11",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+bitflags! {
+    /// `MPAMVPM3_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct Mpamvpm3El2: u64 {
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl Mpamvpm3El2 {
+    /// Descriptor of the `PhyPARTID12` field, for use with [`FieldAccess`].
+    pub const PHYPARTID12_FIELD: Field<Self, u16, 0, 16> =
+        Field::new("MPAMVPM3_EL2", "PhyPARTID12");
+    /// Descriptor of the `PhyPARTID13` field, for use with [`FieldAccess`].
+    pub const PHYPARTID13_FIELD: Field<Self, u16, 16, 16> =
+        Field::new("MPAMVPM3_EL2", "PhyPARTID13");
+    /// Descriptor of the `PhyPARTID14` field, for use with [`FieldAccess`].
+    pub const PHYPARTID14_FIELD: Field<Self, u16, 32, 16> =
+        Field::new("MPAMVPM3_EL2", "PhyPARTID14");
+    /// Descriptor of the `PhyPARTID15` field, for use with [`FieldAccess`].
+    pub const PHYPARTID15_FIELD: Field<Self, u16, 48, 16> =
+        Field::new("MPAMVPM3_EL2", "PhyPARTID15");
+
+    /// Returns the value of the `PhyPARTID12` field.
+    pub const fn phypartid12(self) -> u16 {
+        Self::PHYPARTID12_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `PhyPARTID12` field.
+    pub const fn set_phypartid12(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::PHYPARTID12_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PhyPARTID12` field set to the given value.
+    pub const fn with_phypartid12(mut self, value: u16) -> Self {
+        self.set_phypartid12(value);
+        self
+    }
+
+    /// Sets the value of the `PhyPARTID12` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_phypartid12(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_phypartid12(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PhyPARTID12` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_phypartid12(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::PHYPARTID12_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PhyPARTID13` field.
+    pub const fn phypartid13(self) -> u16 {
+        Self::PHYPARTID13_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `PhyPARTID13` field.
+    pub const fn set_phypartid13(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::PHYPARTID13_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PhyPARTID13` field set to the given value.
+    pub const fn with_phypartid13(mut self, value: u16) -> Self {
+        self.set_phypartid13(value);
+        self
+    }
+
+    /// Sets the value of the `PhyPARTID13` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_phypartid13(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_phypartid13(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PhyPARTID13` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_phypartid13(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::PHYPARTID13_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PhyPARTID14` field.
+    pub const fn phypartid14(self) -> u16 {
+        Self::PHYPARTID14_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `PhyPARTID14` field.
+    pub const fn set_phypartid14(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::PHYPARTID14_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PhyPARTID14` field set to the given value.
+    pub const fn with_phypartid14(mut self, value: u16) -> Self {
+        self.set_phypartid14(value);
+        self
+    }
+
+    /// Sets the value of the `PhyPARTID14` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_phypartid14(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_phypartid14(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PhyPARTID14` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_phypartid14(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::PHYPARTID14_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PhyPARTID15` field.
+    pub const fn phypartid15(self) -> u16 {
+        Self::PHYPARTID15_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `PhyPARTID15` field.
+    pub const fn set_phypartid15(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::PHYPARTID15_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PhyPARTID15` field set to the given value.
+    pub const fn with_phypartid15(mut self, value: u16) -> Self {
+        self.set_phypartid15(value);
+        self
+    }
+
+    /// Sets the value of the `PhyPARTID15` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_phypartid15(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_phypartid15(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PhyPARTID15` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_phypartid15(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::PHYPARTID15_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl Display for Mpamvpm3El2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("Mpamvpm3El2");
+        let bits = self.bits();
+        s.field(
+            "PhyPARTID12",
+            &format_args!("{:#x}", Self::PHYPARTID12_FIELD.extract(bits)),
+        );
+        s.field(
+            "PhyPARTID13",
+            &format_args!("{:#x}", Self::PHYPARTID13_FIELD.extract(bits)),
+        );
+        s.field(
+            "PhyPARTID14",
+            &format_args!("{:#x}", Self::PHYPARTID14_FIELD.extract(bits)),
+        );
+        s.field(
+            "PhyPARTID15",
+            &format_args!("{:#x}", Self::PHYPARTID15_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl defmt::Format for Mpamvpm3El2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "Mpamvpm3El2 {{ PhyPARTID12: {=u64:#x}, PhyPARTID13: {=u64:#x}, PhyPARTID14: {=u64:#x}, PhyPARTID15: {=u64:#x} }}",
+            Self::PHYPARTID12_FIELD.extract(self.bits()),
+            Self::PHYPARTID13_FIELD.extract(self.bits()),
+            Self::PHYPARTID14_FIELD.extract(self.bits()),
+            Self::PHYPARTID15_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl serde::Serialize for Mpamvpm3El2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl<'de> serde::Deserialize<'de> for Mpamvpm3El2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl Mpamvpm3El2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("PhyPARTID12"),
+                old: Self::PHYPARTID12_FIELD.extract(self.bits()),
+                new: Self::PHYPARTID12_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PhyPARTID13"),
+                old: Self::PHYPARTID13_FIELD.extract(self.bits()),
+                new: Self::PHYPARTID13_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PhyPARTID14"),
+                old: Self::PHYPARTID14_FIELD.extract(self.bits()),
+                new: Self::PHYPARTID14_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PhyPARTID15"),
+                old: Self::PHYPARTID15_FIELD.extract(self.bits()),
+                new: Self::PHYPARTID15_FIELD.extract(other.bits()),
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl Mpamvpm3El2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl Mpamvpm3El2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                Self::PHYPARTID12_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPM3_EL2",
+                    field: "PhyPARTID12",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::PHYPARTID13_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPM3_EL2",
+                    field: "PhyPARTID13",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::PHYPARTID14_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPM3_EL2",
+                    field: "PhyPARTID14",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::PHYPARTID15_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPM3_EL2",
+                    field: "PhyPARTID15",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+bitflags! {
+    /// `MPAMVPM4_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct Mpamvpm4El2: u64 {
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl Mpamvpm4El2 {
+    /// Descriptor of the `PhyPARTID16` field, for use with [`FieldAccess`].
+    pub const PHYPARTID16_FIELD: Field<Self, u16, 0, 16> =
+        Field::new("MPAMVPM4_EL2", "PhyPARTID16");
+    /// Descriptor of the `PhyPARTID17` field, for use with [`FieldAccess`].
+    pub const PHYPARTID17_FIELD: Field<Self, u16, 16, 16> =
+        Field::new("MPAMVPM4_EL2", "PhyPARTID17");
+    /// Descriptor of the `PhyPARTID18` field, for use with [`FieldAccess`].
+    pub const PHYPARTID18_FIELD: Field<Self, u16, 32, 16> =
+        Field::new("MPAMVPM4_EL2", "PhyPARTID18");
+    /// Descriptor of the `PhyPARTID19` field, for use with [`FieldAccess`].
+    pub const PHYPARTID19_FIELD: Field<Self, u16, 48, 16> =
+        Field::new("MPAMVPM4_EL2", "PhyPARTID19");
+
+    /// Returns the value of the `PhyPARTID16` field.
+    pub const fn phypartid16(self) -> u16 {
+        Self::PHYPARTID16_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `PhyPARTID16` field.
+    pub const fn set_phypartid16(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::PHYPARTID16_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PhyPARTID16` field set to the given value.
+    pub const fn with_phypartid16(mut self, value: u16) -> Self {
+        self.set_phypartid16(value);
+        self
+    }
+
+    /// Sets the value of the `PhyPARTID16` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_phypartid16(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_phypartid16(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PhyPARTID16` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_phypartid16(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::PHYPARTID16_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PhyPARTID17` field.
+    pub const fn phypartid17(self) -> u16 {
+        Self::PHYPARTID17_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `PhyPARTID17` field.
+    pub const fn set_phypartid17(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::PHYPARTID17_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PhyPARTID17` field set to the given value.
+    pub const fn with_phypartid17(mut self, value: u16) -> Self {
+        self.set_phypartid17(value);
+        self
+    }
+
+    /// Sets the value of the `PhyPARTID17` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_phypartid17(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_phypartid17(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PhyPARTID17` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_phypartid17(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::PHYPARTID17_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PhyPARTID18` field.
+    pub const fn phypartid18(self) -> u16 {
+        Self::PHYPARTID18_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `PhyPARTID18` field.
+    pub const fn set_phypartid18(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::PHYPARTID18_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PhyPARTID18` field set to the given value.
+    pub const fn with_phypartid18(mut self, value: u16) -> Self {
+        self.set_phypartid18(value);
+        self
+    }
+
+    /// Sets the value of the `PhyPARTID18` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_phypartid18(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_phypartid18(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PhyPARTID18` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_phypartid18(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::PHYPARTID18_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PhyPARTID19` field.
+    pub const fn phypartid19(self) -> u16 {
+        Self::PHYPARTID19_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `PhyPARTID19` field.
+    pub const fn set_phypartid19(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::PHYPARTID19_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PhyPARTID19` field set to the given value.
+    pub const fn with_phypartid19(mut self, value: u16) -> Self {
+        self.set_phypartid19(value);
+        self
+    }
+
+    /// Sets the value of the `PhyPARTID19` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_phypartid19(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_phypartid19(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PhyPARTID19` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_phypartid19(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::PHYPARTID19_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl Display for Mpamvpm4El2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("Mpamvpm4El2");
+        let bits = self.bits();
+        s.field(
+            "PhyPARTID16",
+            &format_args!("{:#x}", Self::PHYPARTID16_FIELD.extract(bits)),
+        );
+        s.field(
+            "PhyPARTID17",
+            &format_args!("{:#x}", Self::PHYPARTID17_FIELD.extract(bits)),
+        );
+        s.field(
+            "PhyPARTID18",
+            &format_args!("{:#x}", Self::PHYPARTID18_FIELD.extract(bits)),
+        );
+        s.field(
+            "PhyPARTID19",
+            &format_args!("{:#x}", Self::PHYPARTID19_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl defmt::Format for Mpamvpm4El2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "Mpamvpm4El2 {{ PhyPARTID16: {=u64:#x}, PhyPARTID17: {=u64:#x}, PhyPARTID18: {=u64:#x}, PhyPARTID19: {=u64:#x} }}",
+            Self::PHYPARTID16_FIELD.extract(self.bits()),
+            Self::PHYPARTID17_FIELD.extract(self.bits()),
+            Self::PHYPARTID18_FIELD.extract(self.bits()),
+            Self::PHYPARTID19_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl serde::Serialize for Mpamvpm4El2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl<'de> serde::Deserialize<'de> for Mpamvpm4El2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl Mpamvpm4El2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("PhyPARTID16"),
+                old: Self::PHYPARTID16_FIELD.extract(self.bits()),
+                new: Self::PHYPARTID16_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PhyPARTID17"),
+                old: Self::PHYPARTID17_FIELD.extract(self.bits()),
+                new: Self::PHYPARTID17_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PhyPARTID18"),
+                old: Self::PHYPARTID18_FIELD.extract(self.bits()),
+                new: Self::PHYPARTID18_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PhyPARTID19"),
+                old: Self::PHYPARTID19_FIELD.extract(self.bits()),
+                new: Self::PHYPARTID19_FIELD.extract(other.bits()),
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl Mpamvpm4El2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl Mpamvpm4El2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                Self::PHYPARTID16_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPM4_EL2",
+                    field: "PhyPARTID16",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::PHYPARTID17_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPM4_EL2",
+                    field: "PhyPARTID17",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::PHYPARTID18_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPM4_EL2",
+                    field: "PhyPARTID18",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::PHYPARTID19_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPM4_EL2",
+                    field: "PhyPARTID19",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+bitflags! {
+    /// `MPAMVPM5_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct Mpamvpm5El2: u64 {
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl Mpamvpm5El2 {
+    /// Descriptor of the `PhyPARTID20` field, for use with [`FieldAccess`].
+    pub const PHYPARTID20_FIELD: Field<Self, u16, 0, 16> =
+        Field::new("MPAMVPM5_EL2", "PhyPARTID20");
+    /// Descriptor of the `PhyPARTID21` field, for use with [`FieldAccess`].
+    pub const PHYPARTID21_FIELD: Field<Self, u16, 16, 16> =
+        Field::new("MPAMVPM5_EL2", "PhyPARTID21");
+    /// Descriptor of the `PhyPARTID22` field, for use with [`FieldAccess`].
+    pub const PHYPARTID22_FIELD: Field<Self, u16, 32, 16> =
+        Field::new("MPAMVPM5_EL2", "PhyPARTID22");
+    /// Descriptor of the `PhyPARTID23` field, for use with [`FieldAccess`].
+    pub const PHYPARTID23_FIELD: Field<Self, u16, 48, 16> =
+        Field::new("MPAMVPM5_EL2", "PhyPARTID23");
+
+    /// Returns the value of the `PhyPARTID20` field.
+    pub const fn phypartid20(self) -> u16 {
+        Self::PHYPARTID20_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `PhyPARTID20` field.
+    pub const fn set_phypartid20(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::PHYPARTID20_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PhyPARTID20` field set to the given value.
+    pub const fn with_phypartid20(mut self, value: u16) -> Self {
+        self.set_phypartid20(value);
+        self
+    }
+
+    /// Sets the value of the `PhyPARTID20` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_phypartid20(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_phypartid20(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PhyPARTID20` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_phypartid20(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::PHYPARTID20_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PhyPARTID21` field.
+    pub const fn phypartid21(self) -> u16 {
+        Self::PHYPARTID21_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `PhyPARTID21` field.
+    pub const fn set_phypartid21(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::PHYPARTID21_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PhyPARTID21` field set to the given value.
+    pub const fn with_phypartid21(mut self, value: u16) -> Self {
+        self.set_phypartid21(value);
+        self
+    }
+
+    /// Sets the value of the `PhyPARTID21` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_phypartid21(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_phypartid21(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PhyPARTID21` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_phypartid21(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::PHYPARTID21_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PhyPARTID22` field.
+    pub const fn phypartid22(self) -> u16 {
+        Self::PHYPARTID22_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `PhyPARTID22` field.
+    pub const fn set_phypartid22(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::PHYPARTID22_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PhyPARTID22` field set to the given value.
+    pub const fn with_phypartid22(mut self, value: u16) -> Self {
+        self.set_phypartid22(value);
+        self
+    }
+
+    /// Sets the value of the `PhyPARTID22` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_phypartid22(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_phypartid22(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PhyPARTID22` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_phypartid22(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::PHYPARTID22_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PhyPARTID23` field.
+    pub const fn phypartid23(self) -> u16 {
+        Self::PHYPARTID23_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `PhyPARTID23` field.
+    pub const fn set_phypartid23(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::PHYPARTID23_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PhyPARTID23` field set to the given value.
+    pub const fn with_phypartid23(mut self, value: u16) -> Self {
+        self.set_phypartid23(value);
+        self
+    }
+
+    /// Sets the value of the `PhyPARTID23` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_phypartid23(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_phypartid23(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PhyPARTID23` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_phypartid23(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::PHYPARTID23_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl Display for Mpamvpm5El2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("Mpamvpm5El2");
+        let bits = self.bits();
+        s.field(
+            "PhyPARTID20",
+            &format_args!("{:#x}", Self::PHYPARTID20_FIELD.extract(bits)),
+        );
+        s.field(
+            "PhyPARTID21",
+            &format_args!("{:#x}", Self::PHYPARTID21_FIELD.extract(bits)),
+        );
+        s.field(
+            "PhyPARTID22",
+            &format_args!("{:#x}", Self::PHYPARTID22_FIELD.extract(bits)),
+        );
+        s.field(
+            "PhyPARTID23",
+            &format_args!("{:#x}", Self::PHYPARTID23_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl defmt::Format for Mpamvpm5El2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "Mpamvpm5El2 {{ PhyPARTID20: {=u64:#x}, PhyPARTID21: {=u64:#x}, PhyPARTID22: {=u64:#x}, PhyPARTID23: {=u64:#x} }}",
+            Self::PHYPARTID20_FIELD.extract(self.bits()),
+            Self::PHYPARTID21_FIELD.extract(self.bits()),
+            Self::PHYPARTID22_FIELD.extract(self.bits()),
+            Self::PHYPARTID23_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl serde::Serialize for Mpamvpm5El2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl<'de> serde::Deserialize<'de> for Mpamvpm5El2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl Mpamvpm5El2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("PhyPARTID20"),
+                old: Self::PHYPARTID20_FIELD.extract(self.bits()),
+                new: Self::PHYPARTID20_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PhyPARTID21"),
+                old: Self::PHYPARTID21_FIELD.extract(self.bits()),
+                new: Self::PHYPARTID21_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PhyPARTID22"),
+                old: Self::PHYPARTID22_FIELD.extract(self.bits()),
+                new: Self::PHYPARTID22_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PhyPARTID23"),
+                old: Self::PHYPARTID23_FIELD.extract(self.bits()),
+                new: Self::PHYPARTID23_FIELD.extract(other.bits()),
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl Mpamvpm5El2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl Mpamvpm5El2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                Self::PHYPARTID20_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPM5_EL2",
+                    field: "PhyPARTID20",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::PHYPARTID21_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPM5_EL2",
+                    field: "PhyPARTID21",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::PHYPARTID22_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPM5_EL2",
+                    field: "PhyPARTID22",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::PHYPARTID23_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPM5_EL2",
+                    field: "PhyPARTID23",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+bitflags! {
+    /// `MPAMVPM6_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct Mpamvpm6El2: u64 {
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl Mpamvpm6El2 {
+    /// Descriptor of the `PhyPARTID24` field, for use with [`FieldAccess`].
+    pub const PHYPARTID24_FIELD: Field<Self, u16, 0, 16> =
+        Field::new("MPAMVPM6_EL2", "PhyPARTID24");
+    /// Descriptor of the `PhyPARTID25` field, for use with [`FieldAccess`].
+    pub const PHYPARTID25_FIELD: Field<Self, u16, 16, 16> =
+        Field::new("MPAMVPM6_EL2", "PhyPARTID25");
+    /// Descriptor of the `PhyPARTID26` field, for use with [`FieldAccess`].
+    pub const PHYPARTID26_FIELD: Field<Self, u16, 32, 16> =
+        Field::new("MPAMVPM6_EL2", "PhyPARTID26");
+    /// Descriptor of the `PhyPARTID27` field, for use with [`FieldAccess`].
+    pub const PHYPARTID27_FIELD: Field<Self, u16, 48, 16> =
+        Field::new("MPAMVPM6_EL2", "PhyPARTID27");
+
+    /// Returns the value of the `PhyPARTID24` field.
+    pub const fn phypartid24(self) -> u16 {
+        Self::PHYPARTID24_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `PhyPARTID24` field.
+    pub const fn set_phypartid24(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::PHYPARTID24_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PhyPARTID24` field set to the given value.
+    pub const fn with_phypartid24(mut self, value: u16) -> Self {
+        self.set_phypartid24(value);
+        self
+    }
+
+    /// Sets the value of the `PhyPARTID24` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_phypartid24(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_phypartid24(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PhyPARTID24` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_phypartid24(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::PHYPARTID24_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PhyPARTID25` field.
+    pub const fn phypartid25(self) -> u16 {
+        Self::PHYPARTID25_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `PhyPARTID25` field.
+    pub const fn set_phypartid25(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::PHYPARTID25_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PhyPARTID25` field set to the given value.
+    pub const fn with_phypartid25(mut self, value: u16) -> Self {
+        self.set_phypartid25(value);
+        self
+    }
+
+    /// Sets the value of the `PhyPARTID25` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_phypartid25(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_phypartid25(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PhyPARTID25` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_phypartid25(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::PHYPARTID25_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PhyPARTID26` field.
+    pub const fn phypartid26(self) -> u16 {
+        Self::PHYPARTID26_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `PhyPARTID26` field.
+    pub const fn set_phypartid26(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::PHYPARTID26_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PhyPARTID26` field set to the given value.
+    pub const fn with_phypartid26(mut self, value: u16) -> Self {
+        self.set_phypartid26(value);
+        self
+    }
+
+    /// Sets the value of the `PhyPARTID26` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_phypartid26(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_phypartid26(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PhyPARTID26` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_phypartid26(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::PHYPARTID26_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PhyPARTID27` field.
+    pub const fn phypartid27(self) -> u16 {
+        Self::PHYPARTID27_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `PhyPARTID27` field.
+    pub const fn set_phypartid27(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::PHYPARTID27_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PhyPARTID27` field set to the given value.
+    pub const fn with_phypartid27(mut self, value: u16) -> Self {
+        self.set_phypartid27(value);
+        self
+    }
+
+    /// Sets the value of the `PhyPARTID27` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_phypartid27(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_phypartid27(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PhyPARTID27` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_phypartid27(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::PHYPARTID27_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl Display for Mpamvpm6El2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("Mpamvpm6El2");
+        let bits = self.bits();
+        s.field(
+            "PhyPARTID24",
+            &format_args!("{:#x}", Self::PHYPARTID24_FIELD.extract(bits)),
+        );
+        s.field(
+            "PhyPARTID25",
+            &format_args!("{:#x}", Self::PHYPARTID25_FIELD.extract(bits)),
+        );
+        s.field(
+            "PhyPARTID26",
+            &format_args!("{:#x}", Self::PHYPARTID26_FIELD.extract(bits)),
+        );
+        s.field(
+            "PhyPARTID27",
+            &format_args!("{:#x}", Self::PHYPARTID27_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl defmt::Format for Mpamvpm6El2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "Mpamvpm6El2 {{ PhyPARTID24: {=u64:#x}, PhyPARTID25: {=u64:#x}, PhyPARTID26: {=u64:#x}, PhyPARTID27: {=u64:#x} }}",
+            Self::PHYPARTID24_FIELD.extract(self.bits()),
+            Self::PHYPARTID25_FIELD.extract(self.bits()),
+            Self::PHYPARTID26_FIELD.extract(self.bits()),
+            Self::PHYPARTID27_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl serde::Serialize for Mpamvpm6El2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl<'de> serde::Deserialize<'de> for Mpamvpm6El2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl Mpamvpm6El2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("PhyPARTID24"),
+                old: Self::PHYPARTID24_FIELD.extract(self.bits()),
+                new: Self::PHYPARTID24_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PhyPARTID25"),
+                old: Self::PHYPARTID25_FIELD.extract(self.bits()),
+                new: Self::PHYPARTID25_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PhyPARTID26"),
+                old: Self::PHYPARTID26_FIELD.extract(self.bits()),
+                new: Self::PHYPARTID26_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PhyPARTID27"),
+                old: Self::PHYPARTID27_FIELD.extract(self.bits()),
+                new: Self::PHYPARTID27_FIELD.extract(other.bits()),
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl Mpamvpm6El2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl Mpamvpm6El2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                Self::PHYPARTID24_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPM6_EL2",
+                    field: "PhyPARTID24",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::PHYPARTID25_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPM6_EL2",
+                    field: "PhyPARTID25",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::PHYPARTID26_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPM6_EL2",
+                    field: "PhyPARTID26",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::PHYPARTID27_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPM6_EL2",
+                    field: "PhyPARTID27",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+bitflags! {
+    /// `MPAMVPM7_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct Mpamvpm7El2: u64 {
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl Mpamvpm7El2 {
+    /// Descriptor of the `PhyPARTID28` field, for use with [`FieldAccess`].
+    pub const PHYPARTID28_FIELD: Field<Self, u16, 0, 16> =
+        Field::new("MPAMVPM7_EL2", "PhyPARTID28");
+    /// Descriptor of the `PhyPARTID29` field, for use with [`FieldAccess`].
+    pub const PHYPARTID29_FIELD: Field<Self, u16, 16, 16> =
+        Field::new("MPAMVPM7_EL2", "PhyPARTID29");
+    /// Descriptor of the `PhyPARTID30` field, for use with [`FieldAccess`].
+    pub const PHYPARTID30_FIELD: Field<Self, u16, 32, 16> =
+        Field::new("MPAMVPM7_EL2", "PhyPARTID30");
+    /// Descriptor of the `PhyPARTID31` field, for use with [`FieldAccess`].
+    pub const PHYPARTID31_FIELD: Field<Self, u16, 48, 16> =
+        Field::new("MPAMVPM7_EL2", "PhyPARTID31");
+
+    /// Returns the value of the `PhyPARTID28` field.
+    pub const fn phypartid28(self) -> u16 {
+        Self::PHYPARTID28_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `PhyPARTID28` field.
+    pub const fn set_phypartid28(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::PHYPARTID28_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PhyPARTID28` field set to the given value.
+    pub const fn with_phypartid28(mut self, value: u16) -> Self {
+        self.set_phypartid28(value);
+        self
+    }
+
+    /// Sets the value of the `PhyPARTID28` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_phypartid28(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_phypartid28(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PhyPARTID28` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_phypartid28(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::PHYPARTID28_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PhyPARTID29` field.
+    pub const fn phypartid29(self) -> u16 {
+        Self::PHYPARTID29_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `PhyPARTID29` field.
+    pub const fn set_phypartid29(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::PHYPARTID29_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PhyPARTID29` field set to the given value.
+    pub const fn with_phypartid29(mut self, value: u16) -> Self {
+        self.set_phypartid29(value);
+        self
+    }
+
+    /// Sets the value of the `PhyPARTID29` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_phypartid29(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_phypartid29(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PhyPARTID29` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_phypartid29(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::PHYPARTID29_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PhyPARTID30` field.
+    pub const fn phypartid30(self) -> u16 {
+        Self::PHYPARTID30_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `PhyPARTID30` field.
+    pub const fn set_phypartid30(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::PHYPARTID30_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PhyPARTID30` field set to the given value.
+    pub const fn with_phypartid30(mut self, value: u16) -> Self {
+        self.set_phypartid30(value);
+        self
+    }
+
+    /// Sets the value of the `PhyPARTID30` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_phypartid30(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_phypartid30(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PhyPARTID30` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_phypartid30(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::PHYPARTID30_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `PhyPARTID31` field.
+    pub const fn phypartid31(self) -> u16 {
+        Self::PHYPARTID31_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `PhyPARTID31` field.
+    pub const fn set_phypartid31(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::PHYPARTID31_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `PhyPARTID31` field set to the given value.
+    pub const fn with_phypartid31(mut self, value: u16) -> Self {
+        self.set_phypartid31(value);
+        self
+    }
+
+    /// Sets the value of the `PhyPARTID31` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_phypartid31(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_phypartid31(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PhyPARTID31` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_phypartid31(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::PHYPARTID31_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl Display for Mpamvpm7El2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("Mpamvpm7El2");
+        let bits = self.bits();
+        s.field(
+            "PhyPARTID28",
+            &format_args!("{:#x}", Self::PHYPARTID28_FIELD.extract(bits)),
+        );
+        s.field(
+            "PhyPARTID29",
+            &format_args!("{:#x}", Self::PHYPARTID29_FIELD.extract(bits)),
+        );
+        s.field(
+            "PhyPARTID30",
+            &format_args!("{:#x}", Self::PHYPARTID30_FIELD.extract(bits)),
+        );
+        s.field(
+            "PhyPARTID31",
+            &format_args!("{:#x}", Self::PHYPARTID31_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl defmt::Format for Mpamvpm7El2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "Mpamvpm7El2 {{ PhyPARTID28: {=u64:#x}, PhyPARTID29: {=u64:#x}, PhyPARTID30: {=u64:#x}, PhyPARTID31: {=u64:#x} }}",
+            Self::PHYPARTID28_FIELD.extract(self.bits()),
+            Self::PHYPARTID29_FIELD.extract(self.bits()),
+            Self::PHYPARTID30_FIELD.extract(self.bits()),
+            Self::PHYPARTID31_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl serde::Serialize for Mpamvpm7El2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl<'de> serde::Deserialize<'de> for Mpamvpm7El2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl Mpamvpm7El2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("PhyPARTID28"),
+                old: Self::PHYPARTID28_FIELD.extract(self.bits()),
+                new: Self::PHYPARTID28_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PhyPARTID29"),
+                old: Self::PHYPARTID29_FIELD.extract(self.bits()),
+                new: Self::PHYPARTID29_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PhyPARTID30"),
+                old: Self::PHYPARTID30_FIELD.extract(self.bits()),
+                new: Self::PHYPARTID30_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("PhyPARTID31"),
+                old: Self::PHYPARTID31_FIELD.extract(self.bits()),
+                new: Self::PHYPARTID31_FIELD.extract(other.bits()),
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl Mpamvpm7El2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl Mpamvpm7El2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                Self::PHYPARTID28_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPM7_EL2",
+                    field: "PhyPARTID28",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::PHYPARTID29_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPM7_EL2",
+                    field: "PhyPARTID29",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::PHYPARTID30_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPM7_EL2",
+                    field: "PhyPARTID30",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                Self::PHYPARTID31_FIELD.extract(self.bits()) != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPM7_EL2",
+                    field: "PhyPARTID31",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+bitflags! {
+    /// `MPAMVPMV_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct MpamvpmvEl2: u64 {
+        /// `VPM_V<m>` bit 0.
+        const VPM_V0 = 1 << 0;
+        /// `VPM_V<m>` bit 1.
+        const VPM_V1 = 1 << 1;
+        /// `VPM_V<m>` bit 2.
+        const VPM_V2 = 1 << 2;
+        /// `VPM_V<m>` bit 3.
+        const VPM_V3 = 1 << 3;
+        /// `VPM_V<m>` bit 4.
+        const VPM_V4 = 1 << 4;
+        /// `VPM_V<m>` bit 5.
+        const VPM_V5 = 1 << 5;
+        /// `VPM_V<m>` bit 6.
+        const VPM_V6 = 1 << 6;
+        /// `VPM_V<m>` bit 7.
+        const VPM_V7 = 1 << 7;
+        /// `VPM_V<m>` bit 8.
+        const VPM_V8 = 1 << 8;
+        /// `VPM_V<m>` bit 9.
+        const VPM_V9 = 1 << 9;
+        /// `VPM_V<m>` bit 10.
+        const VPM_V10 = 1 << 10;
+        /// `VPM_V<m>` bit 11.
+        const VPM_V11 = 1 << 11;
+        /// `VPM_V<m>` bit 12.
+        const VPM_V12 = 1 << 12;
+        /// `VPM_V<m>` bit 13.
+        const VPM_V13 = 1 << 13;
+        /// `VPM_V<m>` bit 14.
+        const VPM_V14 = 1 << 14;
+        /// `VPM_V<m>` bit 15.
+        const VPM_V15 = 1 << 15;
+        /// `VPM_V<m>` bit 16.
+        const VPM_V16 = 1 << 16;
+        /// `VPM_V<m>` bit 17.
+        const VPM_V17 = 1 << 17;
+        /// `VPM_V<m>` bit 18.
+        const VPM_V18 = 1 << 18;
+        /// `VPM_V<m>` bit 19.
+        const VPM_V19 = 1 << 19;
+        /// `VPM_V<m>` bit 20.
+        const VPM_V20 = 1 << 20;
+        /// `VPM_V<m>` bit 21.
+        const VPM_V21 = 1 << 21;
+        /// `VPM_V<m>` bit 22.
+        const VPM_V22 = 1 << 22;
+        /// `VPM_V<m>` bit 23.
+        const VPM_V23 = 1 << 23;
+        /// `VPM_V<m>` bit 24.
+        const VPM_V24 = 1 << 24;
+        /// `VPM_V<m>` bit 25.
+        const VPM_V25 = 1 << 25;
+        /// `VPM_V<m>` bit 26.
+        const VPM_V26 = 1 << 26;
+        /// `VPM_V<m>` bit 27.
+        const VPM_V27 = 1 << 27;
+        /// `VPM_V<m>` bit 28.
+        const VPM_V28 = 1 << 28;
+        /// `VPM_V<m>` bit 29.
+        const VPM_V29 = 1 << 29;
+        /// `VPM_V<m>` bit 30.
+        const VPM_V30 = 1 << 30;
+        /// `VPM_V<m>` bit 31.
+        const VPM_V31 = 1 << 31;
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl MpamvpmvEl2 {
+    /// Offset of the `VPM_V<m>` field.
+    pub const VPM_V_SHIFT: u32 = 0;
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl Display for MpamvpmvEl2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("MpamvpmvEl2");
+        let bits = self.bits();
+        s.field("VPM_V0", &((bits >> Self::VPM_V_SHIFT) & 1));
+        s.field("VPM_V1", &((bits >> (Self::VPM_V_SHIFT + 1)) & 1));
+        s.field("VPM_V2", &((bits >> (Self::VPM_V_SHIFT + 2)) & 1));
+        s.field("VPM_V3", &((bits >> (Self::VPM_V_SHIFT + 3)) & 1));
+        s.field("VPM_V4", &((bits >> (Self::VPM_V_SHIFT + 4)) & 1));
+        s.field("VPM_V5", &((bits >> (Self::VPM_V_SHIFT + 5)) & 1));
+        s.field("VPM_V6", &((bits >> (Self::VPM_V_SHIFT + 6)) & 1));
+        s.field("VPM_V7", &((bits >> (Self::VPM_V_SHIFT + 7)) & 1));
+        s.field("VPM_V8", &((bits >> (Self::VPM_V_SHIFT + 8)) & 1));
+        s.field("VPM_V9", &((bits >> (Self::VPM_V_SHIFT + 9)) & 1));
+        s.field("VPM_V10", &((bits >> (Self::VPM_V_SHIFT + 10)) & 1));
+        s.field("VPM_V11", &((bits >> (Self::VPM_V_SHIFT + 11)) & 1));
+        s.field("VPM_V12", &((bits >> (Self::VPM_V_SHIFT + 12)) & 1));
+        s.field("VPM_V13", &((bits >> (Self::VPM_V_SHIFT + 13)) & 1));
+        s.field("VPM_V14", &((bits >> (Self::VPM_V_SHIFT + 14)) & 1));
+        s.field("VPM_V15", &((bits >> (Self::VPM_V_SHIFT + 15)) & 1));
+        s.field("VPM_V16", &((bits >> (Self::VPM_V_SHIFT + 16)) & 1));
+        s.field("VPM_V17", &((bits >> (Self::VPM_V_SHIFT + 17)) & 1));
+        s.field("VPM_V18", &((bits >> (Self::VPM_V_SHIFT + 18)) & 1));
+        s.field("VPM_V19", &((bits >> (Self::VPM_V_SHIFT + 19)) & 1));
+        s.field("VPM_V20", &((bits >> (Self::VPM_V_SHIFT + 20)) & 1));
+        s.field("VPM_V21", &((bits >> (Self::VPM_V_SHIFT + 21)) & 1));
+        s.field("VPM_V22", &((bits >> (Self::VPM_V_SHIFT + 22)) & 1));
+        s.field("VPM_V23", &((bits >> (Self::VPM_V_SHIFT + 23)) & 1));
+        s.field("VPM_V24", &((bits >> (Self::VPM_V_SHIFT + 24)) & 1));
+        s.field("VPM_V25", &((bits >> (Self::VPM_V_SHIFT + 25)) & 1));
+        s.field("VPM_V26", &((bits >> (Self::VPM_V_SHIFT + 26)) & 1));
+        s.field("VPM_V27", &((bits >> (Self::VPM_V_SHIFT + 27)) & 1));
+        s.field("VPM_V28", &((bits >> (Self::VPM_V_SHIFT + 28)) & 1));
+        s.field("VPM_V29", &((bits >> (Self::VPM_V_SHIFT + 29)) & 1));
+        s.field("VPM_V30", &((bits >> (Self::VPM_V_SHIFT + 30)) & 1));
+        s.field("VPM_V31", &((bits >> (Self::VPM_V_SHIFT + 31)) & 1));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl defmt::Format for MpamvpmvEl2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "MpamvpmvEl2 {{ VPM_V0: {=u64}, VPM_V1: {=u64}, VPM_V2: {=u64}, VPM_V3: {=u64}, VPM_V4: {=u64}, VPM_V5: {=u64}, VPM_V6: {=u64}, VPM_V7: {=u64}, VPM_V8: {=u64}, VPM_V9: {=u64}, VPM_V10: {=u64}, VPM_V11: {=u64}, VPM_V12: {=u64}, VPM_V13: {=u64}, VPM_V14: {=u64}, VPM_V15: {=u64}, VPM_V16: {=u64}, VPM_V17: {=u64}, VPM_V18: {=u64}, VPM_V19: {=u64}, VPM_V20: {=u64}, VPM_V21: {=u64}, VPM_V22: {=u64}, VPM_V23: {=u64}, VPM_V24: {=u64}, VPM_V25: {=u64}, VPM_V26: {=u64}, VPM_V27: {=u64}, VPM_V28: {=u64}, VPM_V29: {=u64}, VPM_V30: {=u64}, VPM_V31: {=u64} }}",
+            (self.bits() >> Self::VPM_V_SHIFT) & 1,
+            (self.bits() >> (Self::VPM_V_SHIFT + 1)) & 1,
+            (self.bits() >> (Self::VPM_V_SHIFT + 2)) & 1,
+            (self.bits() >> (Self::VPM_V_SHIFT + 3)) & 1,
+            (self.bits() >> (Self::VPM_V_SHIFT + 4)) & 1,
+            (self.bits() >> (Self::VPM_V_SHIFT + 5)) & 1,
+            (self.bits() >> (Self::VPM_V_SHIFT + 6)) & 1,
+            (self.bits() >> (Self::VPM_V_SHIFT + 7)) & 1,
+            (self.bits() >> (Self::VPM_V_SHIFT + 8)) & 1,
+            (self.bits() >> (Self::VPM_V_SHIFT + 9)) & 1,
+            (self.bits() >> (Self::VPM_V_SHIFT + 10)) & 1,
+            (self.bits() >> (Self::VPM_V_SHIFT + 11)) & 1,
+            (self.bits() >> (Self::VPM_V_SHIFT + 12)) & 1,
+            (self.bits() >> (Self::VPM_V_SHIFT + 13)) & 1,
+            (self.bits() >> (Self::VPM_V_SHIFT + 14)) & 1,
+            (self.bits() >> (Self::VPM_V_SHIFT + 15)) & 1,
+            (self.bits() >> (Self::VPM_V_SHIFT + 16)) & 1,
+            (self.bits() >> (Self::VPM_V_SHIFT + 17)) & 1,
+            (self.bits() >> (Self::VPM_V_SHIFT + 18)) & 1,
+            (self.bits() >> (Self::VPM_V_SHIFT + 19)) & 1,
+            (self.bits() >> (Self::VPM_V_SHIFT + 20)) & 1,
+            (self.bits() >> (Self::VPM_V_SHIFT + 21)) & 1,
+            (self.bits() >> (Self::VPM_V_SHIFT + 22)) & 1,
+            (self.bits() >> (Self::VPM_V_SHIFT + 23)) & 1,
+            (self.bits() >> (Self::VPM_V_SHIFT + 24)) & 1,
+            (self.bits() >> (Self::VPM_V_SHIFT + 25)) & 1,
+            (self.bits() >> (Self::VPM_V_SHIFT + 26)) & 1,
+            (self.bits() >> (Self::VPM_V_SHIFT + 27)) & 1,
+            (self.bits() >> (Self::VPM_V_SHIFT + 28)) & 1,
+            (self.bits() >> (Self::VPM_V_SHIFT + 29)) & 1,
+            (self.bits() >> (Self::VPM_V_SHIFT + 30)) & 1,
+            (self.bits() >> (Self::VPM_V_SHIFT + 31)) & 1
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl serde::Serialize for MpamvpmvEl2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl<'de> serde::Deserialize<'de> for MpamvpmvEl2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl MpamvpmvEl2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("VPM_V0"),
+                old: (self.bits() >> Self::VPM_V_SHIFT) & 1,
+                new: (other.bits() >> Self::VPM_V_SHIFT) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("VPM_V1"),
+                old: (self.bits() >> (Self::VPM_V_SHIFT + 1)) & 1,
+                new: (other.bits() >> (Self::VPM_V_SHIFT + 1)) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("VPM_V2"),
+                old: (self.bits() >> (Self::VPM_V_SHIFT + 2)) & 1,
+                new: (other.bits() >> (Self::VPM_V_SHIFT + 2)) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("VPM_V3"),
+                old: (self.bits() >> (Self::VPM_V_SHIFT + 3)) & 1,
+                new: (other.bits() >> (Self::VPM_V_SHIFT + 3)) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("VPM_V4"),
+                old: (self.bits() >> (Self::VPM_V_SHIFT + 4)) & 1,
+                new: (other.bits() >> (Self::VPM_V_SHIFT + 4)) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("VPM_V5"),
+                old: (self.bits() >> (Self::VPM_V_SHIFT + 5)) & 1,
+                new: (other.bits() >> (Self::VPM_V_SHIFT + 5)) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("VPM_V6"),
+                old: (self.bits() >> (Self::VPM_V_SHIFT + 6)) & 1,
+                new: (other.bits() >> (Self::VPM_V_SHIFT + 6)) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("VPM_V7"),
+                old: (self.bits() >> (Self::VPM_V_SHIFT + 7)) & 1,
+                new: (other.bits() >> (Self::VPM_V_SHIFT + 7)) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("VPM_V8"),
+                old: (self.bits() >> (Self::VPM_V_SHIFT + 8)) & 1,
+                new: (other.bits() >> (Self::VPM_V_SHIFT + 8)) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("VPM_V9"),
+                old: (self.bits() >> (Self::VPM_V_SHIFT + 9)) & 1,
+                new: (other.bits() >> (Self::VPM_V_SHIFT + 9)) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("VPM_V10"),
+                old: (self.bits() >> (Self::VPM_V_SHIFT + 10)) & 1,
+                new: (other.bits() >> (Self::VPM_V_SHIFT + 10)) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("VPM_V11"),
+                old: (self.bits() >> (Self::VPM_V_SHIFT + 11)) & 1,
+                new: (other.bits() >> (Self::VPM_V_SHIFT + 11)) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("VPM_V12"),
+                old: (self.bits() >> (Self::VPM_V_SHIFT + 12)) & 1,
+                new: (other.bits() >> (Self::VPM_V_SHIFT + 12)) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("VPM_V13"),
+                old: (self.bits() >> (Self::VPM_V_SHIFT + 13)) & 1,
+                new: (other.bits() >> (Self::VPM_V_SHIFT + 13)) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("VPM_V14"),
+                old: (self.bits() >> (Self::VPM_V_SHIFT + 14)) & 1,
+                new: (other.bits() >> (Self::VPM_V_SHIFT + 14)) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("VPM_V15"),
+                old: (self.bits() >> (Self::VPM_V_SHIFT + 15)) & 1,
+                new: (other.bits() >> (Self::VPM_V_SHIFT + 15)) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("VPM_V16"),
+                old: (self.bits() >> (Self::VPM_V_SHIFT + 16)) & 1,
+                new: (other.bits() >> (Self::VPM_V_SHIFT + 16)) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("VPM_V17"),
+                old: (self.bits() >> (Self::VPM_V_SHIFT + 17)) & 1,
+                new: (other.bits() >> (Self::VPM_V_SHIFT + 17)) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("VPM_V18"),
+                old: (self.bits() >> (Self::VPM_V_SHIFT + 18)) & 1,
+                new: (other.bits() >> (Self::VPM_V_SHIFT + 18)) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("VPM_V19"),
+                old: (self.bits() >> (Self::VPM_V_SHIFT + 19)) & 1,
+                new: (other.bits() >> (Self::VPM_V_SHIFT + 19)) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("VPM_V20"),
+                old: (self.bits() >> (Self::VPM_V_SHIFT + 20)) & 1,
+                new: (other.bits() >> (Self::VPM_V_SHIFT + 20)) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("VPM_V21"),
+                old: (self.bits() >> (Self::VPM_V_SHIFT + 21)) & 1,
+                new: (other.bits() >> (Self::VPM_V_SHIFT + 21)) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("VPM_V22"),
+                old: (self.bits() >> (Self::VPM_V_SHIFT + 22)) & 1,
+                new: (other.bits() >> (Self::VPM_V_SHIFT + 22)) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("VPM_V23"),
+                old: (self.bits() >> (Self::VPM_V_SHIFT + 23)) & 1,
+                new: (other.bits() >> (Self::VPM_V_SHIFT + 23)) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("VPM_V24"),
+                old: (self.bits() >> (Self::VPM_V_SHIFT + 24)) & 1,
+                new: (other.bits() >> (Self::VPM_V_SHIFT + 24)) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("VPM_V25"),
+                old: (self.bits() >> (Self::VPM_V_SHIFT + 25)) & 1,
+                new: (other.bits() >> (Self::VPM_V_SHIFT + 25)) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("VPM_V26"),
+                old: (self.bits() >> (Self::VPM_V_SHIFT + 26)) & 1,
+                new: (other.bits() >> (Self::VPM_V_SHIFT + 26)) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("VPM_V27"),
+                old: (self.bits() >> (Self::VPM_V_SHIFT + 27)) & 1,
+                new: (other.bits() >> (Self::VPM_V_SHIFT + 27)) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("VPM_V28"),
+                old: (self.bits() >> (Self::VPM_V_SHIFT + 28)) & 1,
+                new: (other.bits() >> (Self::VPM_V_SHIFT + 28)) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("VPM_V29"),
+                old: (self.bits() >> (Self::VPM_V_SHIFT + 29)) & 1,
+                new: (other.bits() >> (Self::VPM_V_SHIFT + 29)) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("VPM_V30"),
+                old: (self.bits() >> (Self::VPM_V_SHIFT + 30)) & 1,
+                new: (other.bits() >> (Self::VPM_V_SHIFT + 30)) & 1,
+            },
+            FieldChange {
+                register,
+                field: Some("VPM_V31"),
+                old: (self.bits() >> (Self::VPM_V_SHIFT + 31)) & 1,
+                new: (other.bits() >> (Self::VPM_V_SHIFT + 31)) & 1,
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_0000_0000_0000_0000_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_0000_0000_0000_0000_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl MpamvpmvEl2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-mpam"))]
+impl MpamvpmvEl2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                (self.bits() >> Self::VPM_V_SHIFT) & 1 != 0 && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPMV_EL2",
+                    field: "VPM_V0",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                (self.bits() >> (Self::VPM_V_SHIFT + 1)) & 1 != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPMV_EL2",
+                    field: "VPM_V1",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                (self.bits() >> (Self::VPM_V_SHIFT + 2)) & 1 != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPMV_EL2",
+                    field: "VPM_V2",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                (self.bits() >> (Self::VPM_V_SHIFT + 3)) & 1 != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPMV_EL2",
+                    field: "VPM_V3",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                (self.bits() >> (Self::VPM_V_SHIFT + 4)) & 1 != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPMV_EL2",
+                    field: "VPM_V4",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                (self.bits() >> (Self::VPM_V_SHIFT + 5)) & 1 != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPMV_EL2",
+                    field: "VPM_V5",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                (self.bits() >> (Self::VPM_V_SHIFT + 6)) & 1 != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPMV_EL2",
+                    field: "VPM_V6",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                (self.bits() >> (Self::VPM_V_SHIFT + 7)) & 1 != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPMV_EL2",
+                    field: "VPM_V7",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                (self.bits() >> (Self::VPM_V_SHIFT + 8)) & 1 != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPMV_EL2",
+                    field: "VPM_V8",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                (self.bits() >> (Self::VPM_V_SHIFT + 9)) & 1 != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPMV_EL2",
+                    field: "VPM_V9",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                (self.bits() >> (Self::VPM_V_SHIFT + 10)) & 1 != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPMV_EL2",
+                    field: "VPM_V10",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                (self.bits() >> (Self::VPM_V_SHIFT + 11)) & 1 != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPMV_EL2",
+                    field: "VPM_V11",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                (self.bits() >> (Self::VPM_V_SHIFT + 12)) & 1 != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPMV_EL2",
+                    field: "VPM_V12",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                (self.bits() >> (Self::VPM_V_SHIFT + 13)) & 1 != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPMV_EL2",
+                    field: "VPM_V13",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                (self.bits() >> (Self::VPM_V_SHIFT + 14)) & 1 != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPMV_EL2",
+                    field: "VPM_V14",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                (self.bits() >> (Self::VPM_V_SHIFT + 15)) & 1 != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPMV_EL2",
+                    field: "VPM_V15",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                (self.bits() >> (Self::VPM_V_SHIFT + 16)) & 1 != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPMV_EL2",
+                    field: "VPM_V16",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                (self.bits() >> (Self::VPM_V_SHIFT + 17)) & 1 != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPMV_EL2",
+                    field: "VPM_V17",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                (self.bits() >> (Self::VPM_V_SHIFT + 18)) & 1 != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPMV_EL2",
+                    field: "VPM_V18",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                (self.bits() >> (Self::VPM_V_SHIFT + 19)) & 1 != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPMV_EL2",
+                    field: "VPM_V19",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                (self.bits() >> (Self::VPM_V_SHIFT + 20)) & 1 != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPMV_EL2",
+                    field: "VPM_V20",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                (self.bits() >> (Self::VPM_V_SHIFT + 21)) & 1 != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPMV_EL2",
+                    field: "VPM_V21",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                (self.bits() >> (Self::VPM_V_SHIFT + 22)) & 1 != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPMV_EL2",
+                    field: "VPM_V22",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                (self.bits() >> (Self::VPM_V_SHIFT + 23)) & 1 != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPMV_EL2",
+                    field: "VPM_V23",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                (self.bits() >> (Self::VPM_V_SHIFT + 24)) & 1 != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPMV_EL2",
+                    field: "VPM_V24",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                (self.bits() >> (Self::VPM_V_SHIFT + 25)) & 1 != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPMV_EL2",
+                    field: "VPM_V25",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                (self.bits() >> (Self::VPM_V_SHIFT + 26)) & 1 != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPMV_EL2",
+                    field: "VPM_V26",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                (self.bits() >> (Self::VPM_V_SHIFT + 27)) & 1 != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPMV_EL2",
+                    field: "VPM_V27",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                (self.bits() >> (Self::VPM_V_SHIFT + 28)) & 1 != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPMV_EL2",
+                    field: "VPM_V28",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                (self.bits() >> (Self::VPM_V_SHIFT + 29)) & 1 != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPMV_EL2",
+                    field: "VPM_V29",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                (self.bits() >> (Self::VPM_V_SHIFT + 30)) & 1 != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPMV_EL2",
+                    field: "VPM_V30",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+            (
+                (self.bits() >> (Self::VPM_V_SHIFT + 31)) & 1 != 0
+                    && !features.contains(Feature::Mpam),
+                UnimplementedField {
+                    register: "MPAMVPMV_EL2",
+                    field: "VPM_V31",
+                    requires: "FEAT_MPAM",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el2",
+    feature = "feat-mpam"
+))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `MPAM2_EL2` system register.
+    mpam2_el2: s3_4_c10_c5_0, u64: Mpam2El2, safe_read, fake::SYSREGS
+}
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el3",
+    feature = "feat-mpam"
+))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `MPAM3_EL3` system register.
+    mpam3_el3: s3_6_c10_c5_0, u64: Mpam3El3, safe_read, fake::SYSREGS
+}
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el2",
+    feature = "feat-mpam"
+))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `MPAMHCR_EL2` system register.
+    mpamhcr_el2: s3_4_c10_c4_0, u64: MpamhcrEl2, safe_read, fake::SYSREGS
+}
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el1",
+    feature = "feat-mpam"
+))]
+read_sysreg!(mpamidr_el1: s3_0_c10_c4_4, u64: MpamidrEl1, safe, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el2",
+    feature = "feat-mpam"
+))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `MPAMVPM0_EL2` system register.
+    mpamvpm0_el2: s3_4_c10_c6_0, u64: Mpamvpm0El2, safe_read, fake::SYSREGS
+}
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el2",
+    feature = "feat-mpam"
+))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `MPAMVPM1_EL2` system register.
+    mpamvpm1_el2: s3_4_c10_c6_1, u64: Mpamvpm1El2, safe_read, fake::SYSREGS
+}
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el2",
+    feature = "feat-mpam"
+))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `MPAMVPM2_EL2` system register.
+    mpamvpm2_el2: s3_4_c10_c6_2, u64: Mpamvpm2El2, safe_read, fake::SYSREGS
+}
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el2",
+    feature = "feat-mpam"
+))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `MPAMVPM3_EL2` system register.
+    mpamvpm3_el2: s3_4_c10_c6_3, u64: Mpamvpm3El2, safe_read, fake::SYSREGS
+}
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el2",
+    feature = "feat-mpam"
+))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `MPAMVPM4_EL2` system register.
+    mpamvpm4_el2: s3_4_c10_c6_4, u64: Mpamvpm4El2, safe_read, fake::SYSREGS
+}
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el2",
+    feature = "feat-mpam"
+))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `MPAMVPM5_EL2` system register.
+    mpamvpm5_el2: s3_4_c10_c6_5, u64: Mpamvpm5El2, safe_read, fake::SYSREGS
+}
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el2",
+    feature = "feat-mpam"
+))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `MPAMVPM6_EL2` system register.
+    mpamvpm6_el2: s3_4_c10_c6_6, u64: Mpamvpm6El2, safe_read, fake::SYSREGS
+}
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el2",
+    feature = "feat-mpam"
+))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `MPAMVPM7_EL2` system register.
+    mpamvpm7_el2: s3_4_c10_c6_7, u64: Mpamvpm7El2, safe_read, fake::SYSREGS
+}
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el2",
+    feature = "feat-mpam"
+))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `MPAMVPMV_EL2` system register.
+    mpamvpmv_el2: s3_4_c10_c4_1, u64: MpamvpmvEl2, safe_read, fake::SYSREGS
+}
+
+/// Returns the value of the `MPAMVPM<n>_EL2` system register with the given index, or `None` if
+/// there is no such register.
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el2",
+    feature = "feat-mpam"
+))]
+pub fn read_mpamvpm_el2(n: usize) -> Option<u64> {
+    match n {
+        0 => Some(read_mpamvpm0_el2().bits()),
+        1 => Some(read_mpamvpm1_el2().bits()),
+        2 => Some(read_mpamvpm2_el2().bits()),
+        3 => Some(read_mpamvpm3_el2().bits()),
+        4 => Some(read_mpamvpm4_el2().bits()),
+        5 => Some(read_mpamvpm5_el2().bits()),
+        6 => Some(read_mpamvpm6_el2().bits()),
+        7 => Some(read_mpamvpm7_el2().bits()),
+        _ => None,
+    }
+}
+
+/// Writes `value` to the `MPAMVPM<n>_EL2` system register with the given index.
+///
+/// # Panics
+///
+/// Panics if there is no register with the given index.
+///
+/// # Safety
+///
+/// The caller must ensure that `value` is a correct and safe value to write to the `MPAMVPM<n>_EL2` system register.
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el2",
+    feature = "feat-mpam"
+))]
+pub unsafe fn write_mpamvpm_el2(n: usize, value: u64) {
+    // SAFETY: The caller promises that `value` is safe to write to the register.
+    unsafe {
+        match n {
+            0 => write_mpamvpm0_el2(Mpamvpm0El2::from_bits_retain(value)),
+            1 => write_mpamvpm1_el2(Mpamvpm1El2::from_bits_retain(value)),
+            2 => write_mpamvpm2_el2(Mpamvpm2El2::from_bits_retain(value)),
+            3 => write_mpamvpm3_el2(Mpamvpm3El2::from_bits_retain(value)),
+            4 => write_mpamvpm4_el2(Mpamvpm4El2::from_bits_retain(value)),
+            5 => write_mpamvpm5_el2(Mpamvpm5El2::from_bits_retain(value)),
+            6 => write_mpamvpm6_el2(Mpamvpm6El2::from_bits_retain(value)),
+            7 => write_mpamvpm7_el2(Mpamvpm7El2::from_bits_retain(value)),
+            _ => panic!("Invalid index {n} for MPAMVPM<n>_EL2"),
+        }
+    }
+}
@@ -0,0 +1,723 @@
+// SPDX-FileCopyrightText: Copyright The arm-sysregs Contributors.
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Memory tagging system registers.
+
+// This file is generated, do not edit manually.
+
+// Depending on the enabled features, the module might not use all of these imports.
+#![allow(unused_imports)]
+
+use crate::*;
+use bitflags::bitflags;
+use core::fmt::{self, Display, Formatter};
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `GCR_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct GcrEl1: u64 {
+        /// `RRND` bit.
+        const RRND = 1 << 16;
+    }
+}
+
+#[cfg(feature = "el1")]
+impl GcrEl1 {
+    /// Descriptor of the `Exclude` field, for use with [`FieldAccess`].
+    pub const EXCLUDE_FIELD: Field<Self, u16, 0, 16> = Field::new("GCR_EL1", "Exclude");
+    /// Descriptor of the `RRND` field, for use with [`FieldAccess`].
+    pub const RRND_FIELD: Field<Self, bool, 16, 1> = Field::new("GCR_EL1", "RRND");
+
+    /// Returns the value of the `Exclude` field.
+    pub const fn exclude(self) -> u16 {
+        Self::EXCLUDE_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `Exclude` field.
+    pub const fn set_exclude(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::EXCLUDE_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `Exclude` field set to the given value.
+    pub const fn with_exclude(mut self, value: u16) -> Self {
+        self.set_exclude(value);
+        self
+    }
+
+    /// Sets the value of the `Exclude` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_exclude(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_exclude(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `Exclude` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_exclude(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::EXCLUDE_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for GcrEl1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("GcrEl1");
+        let bits = self.bits();
+        s.field(
+            "Exclude",
+            &format_args!("{:#x}", Self::EXCLUDE_FIELD.extract(bits)),
+        );
+        s.field("RRND", &Self::RRND_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for GcrEl1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "GcrEl1 {{ Exclude: {=u64:#x}, RRND: {=u64} }}",
+            Self::EXCLUDE_FIELD.extract(self.bits()),
+            Self::RRND_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for GcrEl1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for GcrEl1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl GcrEl1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("Exclude"),
+                old: Self::EXCLUDE_FIELD.extract(self.bits()),
+                new: Self::EXCLUDE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("RRND"),
+                old: Self::RRND_FIELD.extract(self.bits()),
+                new: Self::RRND_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1110_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1110_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl GcrEl1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl GcrEl1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                Self::EXCLUDE_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Mte2),
+                UnimplementedField {
+                    register: "GCR_EL1",
+                    field: "Exclude",
+                    requires: "FEAT_MTE2",
+                },
+            ),
+            (
+                Self::RRND_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Mte2),
+                UnimplementedField {
+                    register: "GCR_EL1",
+                    field: "RRND",
+                    requires: "FEAT_MTE2",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `RGSR_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct RgsrEl1: u64 {
+    }
+}
+
+#[cfg(feature = "el1")]
+impl RgsrEl1 {
+    /// Descriptor of the `TAG` field, for use with [`FieldAccess`].
+    pub const TAG_FIELD: Field<Self, u8, 0, 4> = Field::new("RGSR_EL1", "TAG");
+    /// Descriptor of the `SEED` field, for use with [`FieldAccess`].
+    pub const SEED_FIELD: Field<Self, u16, 8, 16> = Field::new("RGSR_EL1", "SEED");
+
+    /// Returns the value of the `TAG` field.
+    pub const fn tag(self) -> u8 {
+        Self::TAG_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `TAG` field.
+    pub const fn set_tag(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::TAG_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `TAG` field set to the given value.
+    pub const fn with_tag(mut self, value: u8) -> Self {
+        self.set_tag(value);
+        self
+    }
+
+    /// Sets the value of the `TAG` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_tag(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_tag(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `TAG` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_tag(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::TAG_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `SEED` field.
+    pub const fn seed(self) -> u16 {
+        Self::SEED_FIELD.extract(self.bits()) as u16
+    }
+
+    /// Sets the value of the `SEED` field.
+    pub const fn set_seed(&mut self, value: u16) {
+        *self = Self::from_bits_retain(Self::SEED_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `SEED` field set to the given value.
+    pub const fn with_seed(mut self, value: u16) -> Self {
+        self.set_seed(value);
+        self
+    }
+
+    /// Sets the value of the `SEED` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_seed(&mut self, value: u16) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_seed(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SEED` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_seed(self, value: u16) -> Result<Self, FieldOverflow> {
+        match Self::SEED_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for RgsrEl1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("RgsrEl1");
+        let bits = self.bits();
+        s.field("TAG", &Self::TAG_FIELD.extract(bits));
+        s.field(
+            "SEED",
+            &format_args!("{:#x}", Self::SEED_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for RgsrEl1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "RgsrEl1 {{ TAG: {=u64}, SEED: {=u64:#x} }}",
+            Self::TAG_FIELD.extract(self.bits()),
+            Self::SEED_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for RgsrEl1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for RgsrEl1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl RgsrEl1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("TAG"),
+                old: Self::TAG_FIELD.extract(self.bits()),
+                new: Self::TAG_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("SEED"),
+                old: Self::SEED_FIELD.extract(self.bits()),
+                new: Self::SEED_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_0000_0000_0000_0000_1111_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_0000_0000_0000_0000_1111_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl RgsrEl1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl RgsrEl1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                Self::TAG_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Mte2),
+                UnimplementedField {
+                    register: "RGSR_EL1",
+                    field: "TAG",
+                    requires: "FEAT_MTE2",
+                },
+            ),
+            (
+                Self::SEED_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Mte2),
+                UnimplementedField {
+                    register: "RGSR_EL1",
+                    field: "SEED",
+                    requires: "FEAT_MTE2",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `TFSRE0_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct Tfsre0El1: u64 {
+        /// `TF0` bit.
+        const TF0 = 1 << 0;
+        /// `TF1` bit.
+        const TF1 = 1 << 1;
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Tfsre0El1 {
+    /// Descriptor of the `TF0` field, for use with [`FieldAccess`].
+    pub const TF0_FIELD: Field<Self, bool, 0, 1> = Field::new("TFSRE0_EL1", "TF0");
+    /// Descriptor of the `TF1` field, for use with [`FieldAccess`].
+    pub const TF1_FIELD: Field<Self, bool, 1, 1> = Field::new("TFSRE0_EL1", "TF1");
+}
+
+#[cfg(feature = "el1")]
+impl Display for Tfsre0El1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("Tfsre0El1");
+        let bits = self.bits();
+        s.field("TF0", &Self::TF0_FIELD.extract(bits));
+        s.field("TF1", &Self::TF1_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for Tfsre0El1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "Tfsre0El1 {{ TF0: {=u64}, TF1: {=u64} }}",
+            Self::TF0_FIELD.extract(self.bits()),
+            Self::TF1_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for Tfsre0El1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for Tfsre0El1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Tfsre0El1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("TF0"),
+                old: Self::TF0_FIELD.extract(self.bits()),
+                new: Self::TF0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TF1"),
+                old: Self::TF1_FIELD.extract(self.bits()),
+                new: Self::TF1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1100,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1100,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Tfsre0El1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Tfsre0El1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                Self::TF0_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Mte2),
+                UnimplementedField {
+                    register: "TFSRE0_EL1",
+                    field: "TF0",
+                    requires: "FEAT_MTE2",
+                },
+            ),
+            (
+                Self::TF1_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Mte2),
+                UnimplementedField {
+                    register: "TFSRE0_EL1",
+                    field: "TF1",
+                    requires: "FEAT_MTE2",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(feature = "el1")]
+/// `TFSR_EL1` system register value.
+pub type TfsrEl1 = Tfsre0El1;
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `TFSR_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct TfsrEl2: u64 {
+        /// `TF0` bit.
+        const TF0 = 1 << 0;
+        /// `TF1` bit.
+        const TF1 = 1 << 1;
+    }
+}
+
+#[cfg(feature = "el2")]
+impl TfsrEl2 {
+    /// Descriptor of the `TF0` field, for use with [`FieldAccess`].
+    pub const TF0_FIELD: Field<Self, bool, 0, 1> = Field::new("TFSR_EL2", "TF0");
+    /// Descriptor of the `TF1` field, for use with [`FieldAccess`].
+    pub const TF1_FIELD: Field<Self, bool, 1, 1> = Field::new("TFSR_EL2", "TF1");
+}
+
+#[cfg(feature = "el2")]
+impl Display for TfsrEl2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("TfsrEl2");
+        let bits = self.bits();
+        s.field("TF0", &Self::TF0_FIELD.extract(bits));
+        s.field("TF1", &Self::TF1_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for TfsrEl2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "TfsrEl2 {{ TF0: {=u64}, TF1: {=u64} }}",
+            Self::TF0_FIELD.extract(self.bits()),
+            Self::TF1_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for TfsrEl2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for TfsrEl2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl TfsrEl2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("TF0"),
+                old: Self::TF0_FIELD.extract(self.bits()),
+                new: Self::TF0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("TF1"),
+                old: Self::TF1_FIELD.extract(self.bits()),
+                new: Self::TF1_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1100,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1100,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl TfsrEl2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl TfsrEl2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                Self::TF0_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Mte2),
+                UnimplementedField {
+                    register: "TFSR_EL2",
+                    field: "TF0",
+                    requires: "FEAT_MTE2",
+                },
+            ),
+            (
+                Self::TF1_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Mte2),
+                UnimplementedField {
+                    register: "TFSR_EL2",
+                    field: "TF1",
+                    requires: "FEAT_MTE2",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `GCR_EL1` system register.
+    gcr_el1: s3_0_c1_c0_6, u64: GcrEl1, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_write_sysreg!(rgsr_el1: s3_0_c1_c0_5, u64: RgsrEl1, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_write_sysreg!(tfsre0_el1: s3_0_c5_c6_1, u64: Tfsre0El1, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_write_sysreg!(tfsr_el1: s3_0_c5_c6_0, u64: TfsrEl1, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg!(tfsr_el2: s3_4_c5_c6_0, u64: TfsrEl2, safe_read, safe_write, fake::SYSREGS);
@@ -0,0 +1,332 @@
+// SPDX-FileCopyrightText: Copyright The arm-sysregs Contributors.
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Pointer authentication system registers.
+
+// This file is generated, do not edit manually.
+
+// Depending on the enabled features, the module might not use all of these imports.
+#![allow(unused_imports)]
+
+use crate::*;
+use bitflags::bitflags;
+use core::fmt::{self, Display, Formatter};
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `APIAKeyHi_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct ApiakeyhiEl1: u64 {
+    }
+}
+
+#[cfg(feature = "el1")]
+impl ApiakeyhiEl1 {
+    /// Descriptor of the `APIAKeyHi` field, for use with [`FieldAccess`].
+    pub const APIAKEYHI_FIELD: Field<Self, u64, 0, 64> = Field::new("APIAKeyHi_EL1", "APIAKeyHi");
+
+    /// Returns the value of the `APIAKeyHi` field.
+    pub const fn apiakeyhi(self) -> u64 {
+        Self::APIAKEYHI_FIELD.extract(self.bits())
+    }
+
+    /// Sets the value of the `APIAKeyHi` field.
+    pub const fn set_apiakeyhi(&mut self, value: u64) {
+        *self = Self::from_bits_retain(Self::APIAKEYHI_FIELD.insert(self.bits(), value));
+    }
+
+    /// Returns a copy with the `APIAKeyHi` field set to the given value.
+    pub const fn with_apiakeyhi(mut self, value: u64) -> Self {
+        self.set_apiakeyhi(value);
+        self
+    }
+
+    /// Sets the value of the `APIAKeyHi` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_apiakeyhi(&mut self, value: u64) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_apiakeyhi(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `APIAKeyHi` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_apiakeyhi(self, value: u64) -> Result<Self, FieldOverflow> {
+        match Self::APIAKEYHI_FIELD.try_insert(self.bits(), value) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for ApiakeyhiEl1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("ApiakeyhiEl1");
+        let bits = self.bits();
+        s.field(
+            "APIAKeyHi",
+            &format_args!("{:#x}", Self::APIAKEYHI_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for ApiakeyhiEl1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "ApiakeyhiEl1 {{ APIAKeyHi: {=u64:#x} }}",
+            Self::APIAKEYHI_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for ApiakeyhiEl1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for ApiakeyhiEl1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl ApiakeyhiEl1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [FieldChange {
+            register,
+            field: Some("APIAKeyHi"),
+            old: Self::APIAKEYHI_FIELD.extract(self.bits()),
+            new: Self::APIAKEYHI_FIELD.extract(other.bits()),
+        }]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl ApiakeyhiEl1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl ApiakeyhiEl1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [(
+            Self::APIAKEYHI_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Pauth),
+            UnimplementedField {
+                register: "APIAKeyHi_EL1",
+                field: "APIAKeyHi",
+                requires: "FEAT_PAuth",
+            },
+        )]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `APIAKeyLo_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct ApiakeyloEl1: u64 {
+    }
+}
+
+#[cfg(feature = "el1")]
+impl ApiakeyloEl1 {
+    /// Descriptor of the `APIAKeyLo` field, for use with [`FieldAccess`].
+    pub const APIAKEYLO_FIELD: Field<Self, u64, 0, 64> = Field::new("APIAKeyLo_EL1", "APIAKeyLo");
+
+    /// Returns the value of the `APIAKeyLo` field.
+    pub const fn apiakeylo(self) -> u64 {
+        Self::APIAKEYLO_FIELD.extract(self.bits())
+    }
+
+    /// Sets the value of the `APIAKeyLo` field.
+    pub const fn set_apiakeylo(&mut self, value: u64) {
+        *self = Self::from_bits_retain(Self::APIAKEYLO_FIELD.insert(self.bits(), value));
+    }
+
+    /// Returns a copy with the `APIAKeyLo` field set to the given value.
+    pub const fn with_apiakeylo(mut self, value: u64) -> Self {
+        self.set_apiakeylo(value);
+        self
+    }
+
+    /// Sets the value of the `APIAKeyLo` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_apiakeylo(&mut self, value: u64) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_apiakeylo(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `APIAKeyLo` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_apiakeylo(self, value: u64) -> Result<Self, FieldOverflow> {
+        match Self::APIAKEYLO_FIELD.try_insert(self.bits(), value) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for ApiakeyloEl1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("ApiakeyloEl1");
+        let bits = self.bits();
+        s.field(
+            "APIAKeyLo",
+            &format_args!("{:#x}", Self::APIAKEYLO_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for ApiakeyloEl1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "ApiakeyloEl1 {{ APIAKeyLo: {=u64:#x} }}",
+            Self::APIAKEYLO_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for ApiakeyloEl1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for ApiakeyloEl1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl ApiakeyloEl1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [FieldChange {
+            register,
+            field: Some("APIAKeyLo"),
+            old: Self::APIAKEYLO_FIELD.extract(self.bits()),
+            new: Self::APIAKEYLO_FIELD.extract(other.bits()),
+        }]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl ApiakeyloEl1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl ApiakeyloEl1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [(
+            Self::APIAKEYLO_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Pauth),
+            UnimplementedField {
+                register: "APIAKeyLo_EL1",
+                field: "APIAKeyLo",
+                requires: "FEAT_PAuth",
+            },
+        )]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `APIAKeyHi_EL1` system register.
+    apiakeyhi_el1: s3_0_c2_c1_1, u64: ApiakeyhiEl1, safe_read, fake::SYSREGS
+}
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `APIAKeyLo_EL1` system register.
+    apiakeylo_el1: s3_0_c2_c1_0, u64: ApiakeyloEl1, safe_read, fake::SYSREGS
+}
@@ -0,0 +1,482 @@
+// SPDX-FileCopyrightText: Copyright The arm-sysregs Contributors.
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Performance monitors system registers.
+
+// This file is generated, do not edit manually.
+
+// Depending on the enabled features, the module might not use all of these imports.
+#![allow(unused_imports)]
+
+use crate::*;
+use bitflags::bitflags;
+use core::fmt::{self, Display, Formatter};
+
+#[cfg(feature = "feat-pmu")]
+bitflags! {
+    /// `PMCR_EL0` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct PmcrEl0: u64 {
+        /// Enable. Affected counters are enabled by PMCNTENSET_EL0.
+        const E = 1 << 0;
+        /// Event counter reset. Reset all affected event counters PMEVCNTR<n>_EL0 to zero.
+        const P = 1 << 1;
+        /// Cycle counter reset. Reset PMCCNTR_EL0 to zero.
+        const C = 1 << 2;
+        /// Clock divider. If set PMCCNTR_EL0 counts once every 64 clock cycles.
+        const D = 1 << 3;
+        /// Enable export of events in an IMPLEMENTATION DEFINED PMU event export bus. If set, export events where not prohibited.
+        const X = 1 << 4;
+        /// If set, cycle counting by PMCCNTR_EL0 is disabled in prohibited regions.
+        const DP = 1 << 5;
+        /// `LC` bit.
+        const LC = 1 << 6;
+        /// `LP` bit.
+        const LP = 1 << 7;
+        /// `FZO` bit.
+        const FZO = 1 << 9;
+        /// `FZS` bit.
+        const FZS = 1 << 32;
+    }
+}
+
+#[cfg(feature = "feat-pmu")]
+impl PmcrEl0 {
+    /// Descriptor of the `E` field, for use with [`FieldAccess`].
+    pub const E_FIELD: Field<Self, bool, 0, 1> = Field::new("PMCR_EL0", "E");
+    /// Descriptor of the `P` field, for use with [`FieldAccess`].
+    pub const P_FIELD: Field<Self, bool, 1, 1> = Field::new("PMCR_EL0", "P");
+    /// Descriptor of the `C` field, for use with [`FieldAccess`].
+    pub const C_FIELD: Field<Self, bool, 2, 1> = Field::new("PMCR_EL0", "C");
+    /// Descriptor of the `D` field, for use with [`FieldAccess`].
+    pub const D_FIELD: Field<Self, bool, 3, 1> = Field::new("PMCR_EL0", "D");
+    /// Descriptor of the `X` field, for use with [`FieldAccess`].
+    pub const X_FIELD: Field<Self, bool, 4, 1> = Field::new("PMCR_EL0", "X");
+    /// Descriptor of the `DP` field, for use with [`FieldAccess`].
+    pub const DP_FIELD: Field<Self, bool, 5, 1> = Field::new("PMCR_EL0", "DP");
+    /// Descriptor of the `LC` field, for use with [`FieldAccess`].
+    pub const LC_FIELD: Field<Self, bool, 6, 1> = Field::new("PMCR_EL0", "LC");
+    /// Descriptor of the `LP` field, for use with [`FieldAccess`].
+    pub const LP_FIELD: Field<Self, bool, 7, 1> = Field::new("PMCR_EL0", "LP");
+    /// Descriptor of the `FZO` field, for use with [`FieldAccess`].
+    pub const FZO_FIELD: Field<Self, bool, 9, 1> = Field::new("PMCR_EL0", "FZO");
+    /// Descriptor of the `N` field, for use with [`FieldAccess`].
+    pub const N_FIELD: Field<Self, u8, 11, 5> = Field::new("PMCR_EL0", "N");
+    /// Descriptor of the `IDCODE` field, for use with [`FieldAccess`].
+    pub const IDCODE_FIELD: Field<Self, u8, 16, 8> = Field::new("PMCR_EL0", "IDCODE");
+    /// Descriptor of the `IMP` field, for use with [`FieldAccess`].
+    pub const IMP_FIELD: Field<Self, u8, 24, 8> = Field::new("PMCR_EL0", "IMP");
+    /// Descriptor of the `FZS` field, for use with [`FieldAccess`].
+    pub const FZS_FIELD: Field<Self, bool, 32, 1> = Field::new("PMCR_EL0", "FZS");
+
+    /// Returns the value of the `N` field.
+    pub const fn n(self) -> u8 {
+        Self::N_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `N` field.
+    pub const fn set_n(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::N_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `N` field set to the given value.
+    pub const fn with_n(mut self, value: u8) -> Self {
+        self.set_n(value);
+        self
+    }
+
+    /// Sets the value of the `N` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_n(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_n(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `N` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_n(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::N_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `IDCODE` field.
+    pub const fn idcode(self) -> u8 {
+        Self::IDCODE_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `IDCODE` field.
+    pub const fn set_idcode(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::IDCODE_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `IDCODE` field set to the given value.
+    pub const fn with_idcode(mut self, value: u8) -> Self {
+        self.set_idcode(value);
+        self
+    }
+
+    /// Sets the value of the `IDCODE` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_idcode(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_idcode(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `IDCODE` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_idcode(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::IDCODE_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `IMP` field.
+    pub const fn imp(self) -> u8 {
+        Self::IMP_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `IMP` field.
+    pub const fn set_imp(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::IMP_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `IMP` field set to the given value.
+    pub const fn with_imp(mut self, value: u8) -> Self {
+        self.set_imp(value);
+        self
+    }
+
+    /// Sets the value of the `IMP` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_imp(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_imp(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `IMP` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_imp(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::IMP_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "feat-pmu")]
+impl Display for PmcrEl0 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("PmcrEl0");
+        let bits = self.bits();
+        s.field("E", &Self::E_FIELD.extract(bits));
+        s.field("P", &Self::P_FIELD.extract(bits));
+        s.field("C", &Self::C_FIELD.extract(bits));
+        s.field("D", &Self::D_FIELD.extract(bits));
+        s.field("X", &Self::X_FIELD.extract(bits));
+        s.field("DP", &Self::DP_FIELD.extract(bits));
+        s.field("LC", &Self::LC_FIELD.extract(bits));
+        s.field("LP", &Self::LP_FIELD.extract(bits));
+        s.field("FZO", &Self::FZO_FIELD.extract(bits));
+        s.field("N", &Self::N_FIELD.extract(bits));
+        s.field("IDCODE", &Self::IDCODE_FIELD.extract(bits));
+        s.field("IMP", &Self::IMP_FIELD.extract(bits));
+        s.field("FZS", &Self::FZS_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "feat-pmu")]
+impl defmt::Format for PmcrEl0 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "PmcrEl0 {{ E: {=u64}, P: {=u64}, C: {=u64}, D: {=u64}, X: {=u64}, DP: {=u64}, LC: {=u64}, LP: {=u64}, FZO: {=u64}, N: {=u64}, IDCODE: {=u64}, IMP: {=u64}, FZS: {=u64} }}",
+            Self::E_FIELD.extract(self.bits()),
+            Self::P_FIELD.extract(self.bits()),
+            Self::C_FIELD.extract(self.bits()),
+            Self::D_FIELD.extract(self.bits()),
+            Self::X_FIELD.extract(self.bits()),
+            Self::DP_FIELD.extract(self.bits()),
+            Self::LC_FIELD.extract(self.bits()),
+            Self::LP_FIELD.extract(self.bits()),
+            Self::FZO_FIELD.extract(self.bits()),
+            Self::N_FIELD.extract(self.bits()),
+            Self::IDCODE_FIELD.extract(self.bits()),
+            Self::IMP_FIELD.extract(self.bits()),
+            Self::FZS_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "feat-pmu")]
+impl serde::Serialize for PmcrEl0 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "feat-pmu")]
+impl<'de> serde::Deserialize<'de> for PmcrEl0 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "feat-pmu")]
+impl PmcrEl0 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("E"),
+                old: Self::E_FIELD.extract(self.bits()),
+                new: Self::E_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("P"),
+                old: Self::P_FIELD.extract(self.bits()),
+                new: Self::P_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("C"),
+                old: Self::C_FIELD.extract(self.bits()),
+                new: Self::C_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("D"),
+                old: Self::D_FIELD.extract(self.bits()),
+                new: Self::D_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("X"),
+                old: Self::X_FIELD.extract(self.bits()),
+                new: Self::X_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("DP"),
+                old: Self::DP_FIELD.extract(self.bits()),
+                new: Self::DP_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("LC"),
+                old: Self::LC_FIELD.extract(self.bits()),
+                new: Self::LC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("LP"),
+                old: Self::LP_FIELD.extract(self.bits()),
+                new: Self::LP_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FZO"),
+                old: Self::FZO_FIELD.extract(self.bits()),
+                new: Self::FZO_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("N"),
+                old: Self::N_FIELD.extract(self.bits()),
+                new: Self::N_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IDCODE"),
+                old: Self::IDCODE_FIELD.extract(self.bits()),
+                new: Self::IDCODE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IMP"),
+                old: Self::IMP_FIELD.extract(self.bits()),
+                new: Self::IMP_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FZS"),
+                old: Self::FZS_FIELD.extract(self.bits()),
+                new: Self::FZS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1110_0000_0000_0000_0000_0000_0101_0000_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1110_0000_0000_0000_0000_0000_0101_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "feat-pmu")]
+impl PmcrEl0 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "feat-pmu")]
+impl PmcrEl0 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                Self::E_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Pmuv3),
+                UnimplementedField {
+                    register: "PMCR_EL0",
+                    field: "E",
+                    requires: "FEAT_PMUv3",
+                },
+            ),
+            (
+                Self::P_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Pmuv3),
+                UnimplementedField {
+                    register: "PMCR_EL0",
+                    field: "P",
+                    requires: "FEAT_PMUv3",
+                },
+            ),
+            (
+                Self::C_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Pmuv3),
+                UnimplementedField {
+                    register: "PMCR_EL0",
+                    field: "C",
+                    requires: "FEAT_PMUv3",
+                },
+            ),
+            (
+                Self::D_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Pmuv3),
+                UnimplementedField {
+                    register: "PMCR_EL0",
+                    field: "D",
+                    requires: "FEAT_PMUv3",
+                },
+            ),
+            (
+                Self::X_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Pmuv3),
+                UnimplementedField {
+                    register: "PMCR_EL0",
+                    field: "X",
+                    requires: "FEAT_PMUv3",
+                },
+            ),
+            (
+                Self::DP_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Pmuv3),
+                UnimplementedField {
+                    register: "PMCR_EL0",
+                    field: "DP",
+                    requires: "FEAT_PMUv3",
+                },
+            ),
+            (
+                Self::LC_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Pmuv3),
+                UnimplementedField {
+                    register: "PMCR_EL0",
+                    field: "LC",
+                    requires: "FEAT_PMUv3",
+                },
+            ),
+            (
+                Self::LP_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Pmuv3),
+                UnimplementedField {
+                    register: "PMCR_EL0",
+                    field: "LP",
+                    requires: "FEAT_PMUv3",
+                },
+            ),
+            (
+                Self::FZO_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Pmuv3),
+                UnimplementedField {
+                    register: "PMCR_EL0",
+                    field: "FZO",
+                    requires: "FEAT_PMUv3",
+                },
+            ),
+            (
+                Self::N_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Pmuv3),
+                UnimplementedField {
+                    register: "PMCR_EL0",
+                    field: "N",
+                    requires: "FEAT_PMUv3",
+                },
+            ),
+            (
+                Self::IDCODE_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Pmuv3),
+                UnimplementedField {
+                    register: "PMCR_EL0",
+                    field: "IDCODE",
+                    requires: "FEAT_PMUv3",
+                },
+            ),
+            (
+                Self::IMP_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Pmuv3),
+                UnimplementedField {
+                    register: "PMCR_EL0",
+                    field: "IMP",
+                    requires: "FEAT_PMUv3",
+                },
+            ),
+            (
+                Self::FZS_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Pmuv3),
+                UnimplementedField {
+                    register: "PMCR_EL0",
+                    field: "FZS",
+                    requires: "FEAT_PMUv3",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "feat-pmu"
+))]
+read_write_sysreg!(pmcr_el0: s3_3_c9_c12_0, u64: PmcrEl0, safe_read, safe_write, fake::SYSREGS);
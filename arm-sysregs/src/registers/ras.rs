@@ -0,0 +1,989 @@
+// SPDX-FileCopyrightText: Copyright The arm-sysregs Contributors.
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Reliability, availability, and serviceability system registers.
+
+// This file is generated, do not edit manually.
+
+// Depending on the enabled features, the module might not use all of these imports.
+#![allow(unused_imports)]
+
+use crate::*;
+use bitflags::bitflags;
+use core::fmt::{self, Display, Formatter};
+
+#[cfg(all(feature = "el1", feature = "feat-ras"))]
+bitflags! {
+    /// `DISR_EL1` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct DisrEl1: u64 {
+        /// `WnR` bit.
+        const WNR = 1 << 6;
+        /// `WnRV` bit.
+        const WNRV = 1 << 7;
+        /// `EA` bit.
+        const EA = 1 << 9;
+        /// `IDS` bit.
+        const IDS = 1 << 24;
+        /// `A` bit.
+        const A = 1 << 31;
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-ras"))]
+impl DisrEl1 {
+    /// Descriptor of the `DFSC` field, for use with [`FieldAccess`].
+    pub const DFSC_FIELD: Field<Self, u8, 0, 6> = Field::new("DISR_EL1", "DFSC");
+    /// Descriptor of the `WnR` field, for use with [`FieldAccess`].
+    pub const WNR_FIELD: Field<Self, bool, 6, 1> = Field::new("DISR_EL1", "WnR");
+    /// Descriptor of the `WnRV` field, for use with [`FieldAccess`].
+    pub const WNRV_FIELD: Field<Self, bool, 7, 1> = Field::new("DISR_EL1", "WnRV");
+    /// Descriptor of the `EA` field, for use with [`FieldAccess`].
+    pub const EA_FIELD: Field<Self, bool, 9, 1> = Field::new("DISR_EL1", "EA");
+    /// Descriptor of the `AET` field, for use with [`FieldAccess`].
+    pub const AET_FIELD: Field<Self, u8, 10, 3> = Field::new("DISR_EL1", "AET");
+    /// Descriptor of the `WU` field, for use with [`FieldAccess`].
+    pub const WU_FIELD: Field<Self, u8, 16, 2> = Field::new("DISR_EL1", "WU");
+    /// Descriptor of the `IDS` field, for use with [`FieldAccess`].
+    pub const IDS_FIELD: Field<Self, bool, 24, 1> = Field::new("DISR_EL1", "IDS");
+    /// Descriptor of the `A` field, for use with [`FieldAccess`].
+    pub const A_FIELD: Field<Self, bool, 31, 1> = Field::new("DISR_EL1", "A");
+
+    /// Returns the value of the `DFSC` field.
+    pub const fn dfsc(self) -> u8 {
+        Self::DFSC_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `DFSC` field.
+    pub const fn set_dfsc(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::DFSC_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `DFSC` field set to the given value.
+    pub const fn with_dfsc(mut self, value: u8) -> Self {
+        self.set_dfsc(value);
+        self
+    }
+
+    /// Sets the value of the `DFSC` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_dfsc(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_dfsc(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `DFSC` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_dfsc(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::DFSC_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `AET` field.
+    pub const fn aet(self) -> u8 {
+        Self::AET_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `AET` field.
+    pub const fn set_aet(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::AET_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `AET` field set to the given value.
+    pub const fn with_aet(mut self, value: u8) -> Self {
+        self.set_aet(value);
+        self
+    }
+
+    /// Sets the value of the `AET` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_aet(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_aet(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `AET` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_aet(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::AET_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `WU` field.
+    pub const fn wu(self) -> u8 {
+        Self::WU_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `WU` field.
+    pub const fn set_wu(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::WU_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `WU` field set to the given value.
+    pub const fn with_wu(mut self, value: u8) -> Self {
+        self.set_wu(value);
+        self
+    }
+
+    /// Sets the value of the `WU` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_wu(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_wu(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `WU` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_wu(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::WU_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-ras"))]
+impl Display for DisrEl1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("DisrEl1");
+        let bits = self.bits();
+        s.field("DFSC", &Self::DFSC_FIELD.extract(bits));
+        s.field("WnR", &Self::WNR_FIELD.extract(bits));
+        s.field("WnRV", &Self::WNRV_FIELD.extract(bits));
+        s.field("EA", &Self::EA_FIELD.extract(bits));
+        s.field("AET", &Self::AET_FIELD.extract(bits));
+        s.field("WU", &Self::WU_FIELD.extract(bits));
+        s.field("IDS", &Self::IDS_FIELD.extract(bits));
+        s.field("A", &Self::A_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(all(feature = "el1", feature = "feat-ras"))]
+impl defmt::Format for DisrEl1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "DisrEl1 {{ DFSC: {=u64}, WnR: {=u64}, WnRV: {=u64}, EA: {=u64}, AET: {=u64}, WU: {=u64}, IDS: {=u64}, A: {=u64} }}",
+            Self::DFSC_FIELD.extract(self.bits()),
+            Self::WNR_FIELD.extract(self.bits()),
+            Self::WNRV_FIELD.extract(self.bits()),
+            Self::EA_FIELD.extract(self.bits()),
+            Self::AET_FIELD.extract(self.bits()),
+            Self::WU_FIELD.extract(self.bits()),
+            Self::IDS_FIELD.extract(self.bits()),
+            Self::A_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el1", feature = "feat-ras"))]
+impl serde::Serialize for DisrEl1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el1", feature = "feat-ras"))]
+impl<'de> serde::Deserialize<'de> for DisrEl1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-ras"))]
+impl DisrEl1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("DFSC"),
+                old: Self::DFSC_FIELD.extract(self.bits()),
+                new: Self::DFSC_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("WnR"),
+                old: Self::WNR_FIELD.extract(self.bits()),
+                new: Self::WNR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("WnRV"),
+                old: Self::WNRV_FIELD.extract(self.bits()),
+                new: Self::WNRV_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EA"),
+                old: Self::EA_FIELD.extract(self.bits()),
+                new: Self::EA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("AET"),
+                old: Self::AET_FIELD.extract(self.bits()),
+                new: Self::AET_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("WU"),
+                old: Self::WU_FIELD.extract(self.bits()),
+                new: Self::WU_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IDS"),
+                old: Self::IDS_FIELD.extract(self.bits()),
+                new: Self::IDS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("A"),
+                old: Self::A_FIELD.extract(self.bits()),
+                new: Self::A_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_0111_1110_1111_1100_1110_0001_0000_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_0111_1110_1111_1100_1110_0001_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-ras"))]
+impl DisrEl1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(all(feature = "el1", feature = "feat-ras"))]
+impl DisrEl1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                Self::DFSC_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Ras),
+                UnimplementedField {
+                    register: "DISR_EL1",
+                    field: "DFSC",
+                    requires: "FEAT_RAS",
+                },
+            ),
+            (
+                Self::WNR_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Ras),
+                UnimplementedField {
+                    register: "DISR_EL1",
+                    field: "WnR",
+                    requires: "FEAT_RAS",
+                },
+            ),
+            (
+                Self::WNRV_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Ras),
+                UnimplementedField {
+                    register: "DISR_EL1",
+                    field: "WnRV",
+                    requires: "FEAT_RAS",
+                },
+            ),
+            (
+                Self::EA_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Ras),
+                UnimplementedField {
+                    register: "DISR_EL1",
+                    field: "EA",
+                    requires: "FEAT_RAS",
+                },
+            ),
+            (
+                Self::AET_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Ras),
+                UnimplementedField {
+                    register: "DISR_EL1",
+                    field: "AET",
+                    requires: "FEAT_RAS",
+                },
+            ),
+            (
+                Self::WU_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Ras),
+                UnimplementedField {
+                    register: "DISR_EL1",
+                    field: "WU",
+                    requires: "FEAT_RAS",
+                },
+            ),
+            (
+                Self::IDS_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Ras),
+                UnimplementedField {
+                    register: "DISR_EL1",
+                    field: "IDS",
+                    requires: "FEAT_RAS",
+                },
+            ),
+            (
+                Self::A_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Ras),
+                UnimplementedField {
+                    register: "DISR_EL1",
+                    field: "A",
+                    requires: "FEAT_RAS",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-ras"))]
+bitflags! {
+    /// `VDISR_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct VdisrEl2: u64 {
+        /// `LPAE` bit.
+        const LPAE = 1 << 9;
+        /// `ExT` bit.
+        const EXT = 1 << 12;
+        /// `IDS` bit.
+        const IDS = 1 << 24;
+        /// `A` bit.
+        const A = 1 << 31;
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-ras"))]
+impl VdisrEl2 {
+    /// Descriptor of the `ISS` field, for use with [`FieldAccess`].
+    pub const ISS_FIELD: Field<Self, u32, 0, 24> = Field::new("VDISR_EL2", "ISS");
+    /// Descriptor of the `STATUS` field, for use with [`FieldAccess`].
+    pub const STATUS_FIELD: Field<Self, u8, 0, 6> = Field::new("VDISR_EL2", "STATUS");
+    /// Descriptor of the `LPAE` field, for use with [`FieldAccess`].
+    pub const LPAE_FIELD: Field<Self, bool, 9, 1> = Field::new("VDISR_EL2", "LPAE");
+    /// Descriptor of the `ExT` field, for use with [`FieldAccess`].
+    pub const EXT_FIELD: Field<Self, bool, 12, 1> = Field::new("VDISR_EL2", "ExT");
+    /// Descriptor of the `AET` field, for use with [`FieldAccess`].
+    pub const AET_FIELD: Field<Self, u8, 14, 2> = Field::new("VDISR_EL2", "AET");
+    /// Descriptor of the `IDS` field, for use with [`FieldAccess`].
+    pub const IDS_FIELD: Field<Self, bool, 24, 1> = Field::new("VDISR_EL2", "IDS");
+    /// Descriptor of the `A` field, for use with [`FieldAccess`].
+    pub const A_FIELD: Field<Self, bool, 31, 1> = Field::new("VDISR_EL2", "A");
+
+    /// Returns the value of the `ISS` field.
+    pub const fn iss(self) -> u32 {
+        Self::ISS_FIELD.extract(self.bits()) as u32
+    }
+
+    /// Sets the value of the `ISS` field.
+    pub const fn set_iss(&mut self, value: u32) {
+        *self = Self::from_bits_retain(Self::ISS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ISS` field set to the given value.
+    pub const fn with_iss(mut self, value: u32) -> Self {
+        self.set_iss(value);
+        self
+    }
+
+    /// Sets the value of the `ISS` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_iss(&mut self, value: u32) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_iss(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ISS` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_iss(self, value: u32) -> Result<Self, FieldOverflow> {
+        match Self::ISS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `STATUS` field.
+    pub const fn status(self) -> u8 {
+        Self::STATUS_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `STATUS` field.
+    pub const fn set_status(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::STATUS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `STATUS` field set to the given value.
+    pub const fn with_status(mut self, value: u8) -> Self {
+        self.set_status(value);
+        self
+    }
+
+    /// Sets the value of the `STATUS` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_status(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_status(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `STATUS` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_status(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::STATUS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `AET` field.
+    pub const fn aet(self) -> u8 {
+        Self::AET_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `AET` field.
+    pub const fn set_aet(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::AET_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `AET` field set to the given value.
+    pub const fn with_aet(mut self, value: u8) -> Self {
+        self.set_aet(value);
+        self
+    }
+
+    /// Sets the value of the `AET` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_aet(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_aet(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `AET` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_aet(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::AET_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-ras"))]
+impl Display for VdisrEl2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("VdisrEl2");
+        let bits = self.bits();
+        s.field("ISS", &format_args!("{:#x}", Self::ISS_FIELD.extract(bits)));
+        s.field("STATUS", &Self::STATUS_FIELD.extract(bits));
+        s.field("LPAE", &Self::LPAE_FIELD.extract(bits));
+        s.field("ExT", &Self::EXT_FIELD.extract(bits));
+        s.field("AET", &Self::AET_FIELD.extract(bits));
+        s.field("IDS", &Self::IDS_FIELD.extract(bits));
+        s.field("A", &Self::A_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(all(feature = "el2", feature = "feat-ras"))]
+impl defmt::Format for VdisrEl2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "VdisrEl2 {{ ISS: {=u64:#x}, STATUS: {=u64}, LPAE: {=u64}, ExT: {=u64}, AET: {=u64}, IDS: {=u64}, A: {=u64} }}",
+            Self::ISS_FIELD.extract(self.bits()),
+            Self::STATUS_FIELD.extract(self.bits()),
+            Self::LPAE_FIELD.extract(self.bits()),
+            Self::EXT_FIELD.extract(self.bits()),
+            Self::AET_FIELD.extract(self.bits()),
+            Self::IDS_FIELD.extract(self.bits()),
+            Self::A_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el2", feature = "feat-ras"))]
+impl serde::Serialize for VdisrEl2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el2", feature = "feat-ras"))]
+impl<'de> serde::Deserialize<'de> for VdisrEl2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-ras"))]
+impl VdisrEl2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("ISS"),
+                old: Self::ISS_FIELD.extract(self.bits()),
+                new: Self::ISS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("STATUS"),
+                old: Self::STATUS_FIELD.extract(self.bits()),
+                new: Self::STATUS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("LPAE"),
+                old: Self::LPAE_FIELD.extract(self.bits()),
+                new: Self::LPAE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ExT"),
+                old: Self::EXT_FIELD.extract(self.bits()),
+                new: Self::EXT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("AET"),
+                old: Self::AET_FIELD.extract(self.bits()),
+                new: Self::AET_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IDS"),
+                old: Self::IDS_FIELD.extract(self.bits()),
+                new: Self::IDS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("A"),
+                old: Self::A_FIELD.extract(self.bits()),
+                new: Self::A_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_0111_1110_0000_0000_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_0111_1110_0000_0000_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-ras"))]
+impl VdisrEl2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-ras"))]
+impl VdisrEl2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                Self::ISS_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Ras),
+                UnimplementedField {
+                    register: "VDISR_EL2",
+                    field: "ISS",
+                    requires: "FEAT_RAS",
+                },
+            ),
+            (
+                Self::STATUS_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Ras),
+                UnimplementedField {
+                    register: "VDISR_EL2",
+                    field: "STATUS",
+                    requires: "FEAT_RAS",
+                },
+            ),
+            (
+                Self::LPAE_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Ras),
+                UnimplementedField {
+                    register: "VDISR_EL2",
+                    field: "LPAE",
+                    requires: "FEAT_RAS",
+                },
+            ),
+            (
+                Self::EXT_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Ras),
+                UnimplementedField {
+                    register: "VDISR_EL2",
+                    field: "ExT",
+                    requires: "FEAT_RAS",
+                },
+            ),
+            (
+                Self::AET_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Ras),
+                UnimplementedField {
+                    register: "VDISR_EL2",
+                    field: "AET",
+                    requires: "FEAT_RAS",
+                },
+            ),
+            (
+                Self::IDS_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Ras),
+                UnimplementedField {
+                    register: "VDISR_EL2",
+                    field: "IDS",
+                    requires: "FEAT_RAS",
+                },
+            ),
+            (
+                Self::A_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Ras),
+                UnimplementedField {
+                    register: "VDISR_EL2",
+                    field: "A",
+                    requires: "FEAT_RAS",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-ras"))]
+bitflags! {
+    /// `VSESR_EL2` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct VsesrEl2: u64 {
+        /// `ExT` bit.
+        const EXT = 1 << 12;
+        /// `IDS` bit.
+        const IDS = 1 << 24;
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-ras"))]
+impl VsesrEl2 {
+    /// Descriptor of the `ISS` field, for use with [`FieldAccess`].
+    pub const ISS_FIELD: Field<Self, u32, 0, 24> = Field::new("VSESR_EL2", "ISS");
+    /// Descriptor of the `ExT` field, for use with [`FieldAccess`].
+    pub const EXT_FIELD: Field<Self, bool, 12, 1> = Field::new("VSESR_EL2", "ExT");
+    /// Descriptor of the `AET` field, for use with [`FieldAccess`].
+    pub const AET_FIELD: Field<Self, u8, 14, 2> = Field::new("VSESR_EL2", "AET");
+    /// Descriptor of the `IDS` field, for use with [`FieldAccess`].
+    pub const IDS_FIELD: Field<Self, bool, 24, 1> = Field::new("VSESR_EL2", "IDS");
+
+    /// Returns the value of the `ISS` field.
+    pub const fn iss(self) -> u32 {
+        Self::ISS_FIELD.extract(self.bits()) as u32
+    }
+
+    /// Sets the value of the `ISS` field.
+    pub const fn set_iss(&mut self, value: u32) {
+        *self = Self::from_bits_retain(Self::ISS_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ISS` field set to the given value.
+    pub const fn with_iss(mut self, value: u32) -> Self {
+        self.set_iss(value);
+        self
+    }
+
+    /// Sets the value of the `ISS` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_iss(&mut self, value: u32) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_iss(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ISS` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_iss(self, value: u32) -> Result<Self, FieldOverflow> {
+        match Self::ISS_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns the value of the `AET` field.
+    pub const fn aet(self) -> u8 {
+        Self::AET_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `AET` field.
+    pub const fn set_aet(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::AET_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `AET` field set to the given value.
+    pub const fn with_aet(mut self, value: u8) -> Self {
+        self.set_aet(value);
+        self
+    }
+
+    /// Sets the value of the `AET` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_aet(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_aet(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `AET` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_aet(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::AET_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-ras"))]
+impl Display for VsesrEl2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("VsesrEl2");
+        let bits = self.bits();
+        s.field("ISS", &format_args!("{:#x}", Self::ISS_FIELD.extract(bits)));
+        s.field("ExT", &Self::EXT_FIELD.extract(bits));
+        s.field("AET", &Self::AET_FIELD.extract(bits));
+        s.field("IDS", &Self::IDS_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(all(feature = "el2", feature = "feat-ras"))]
+impl defmt::Format for VsesrEl2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "VsesrEl2 {{ ISS: {=u64:#x}, ExT: {=u64}, AET: {=u64}, IDS: {=u64} }}",
+            Self::ISS_FIELD.extract(self.bits()),
+            Self::EXT_FIELD.extract(self.bits()),
+            Self::AET_FIELD.extract(self.bits()),
+            Self::IDS_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el2", feature = "feat-ras"))]
+impl serde::Serialize for VsesrEl2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el2", feature = "feat-ras"))]
+impl<'de> serde::Deserialize<'de> for VsesrEl2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-ras"))]
+impl VsesrEl2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("ISS"),
+                old: Self::ISS_FIELD.extract(self.bits()),
+                new: Self::ISS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ExT"),
+                old: Self::EXT_FIELD.extract(self.bits()),
+                new: Self::EXT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("AET"),
+                old: Self::AET_FIELD.extract(self.bits()),
+                new: Self::AET_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IDS"),
+                old: Self::IDS_FIELD.extract(self.bits()),
+                new: Self::IDS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1110_0000_0000_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1110_0000_0000_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-ras"))]
+impl VsesrEl2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(all(feature = "el2", feature = "feat-ras"))]
+impl VsesrEl2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                Self::ISS_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Ras),
+                UnimplementedField {
+                    register: "VSESR_EL2",
+                    field: "ISS",
+                    requires: "FEAT_RAS",
+                },
+            ),
+            (
+                Self::EXT_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Ras),
+                UnimplementedField {
+                    register: "VSESR_EL2",
+                    field: "ExT",
+                    requires: "FEAT_RAS",
+                },
+            ),
+            (
+                Self::AET_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Ras),
+                UnimplementedField {
+                    register: "VSESR_EL2",
+                    field: "AET",
+                    requires: "FEAT_RAS",
+                },
+            ),
+            (
+                Self::IDS_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Ras),
+                UnimplementedField {
+                    register: "VSESR_EL2",
+                    field: "IDS",
+                    requires: "FEAT_RAS",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el1",
+    feature = "feat-ras"
+))]
+read_write_sysreg!(disr_el1: s3_0_c12_c1_1, u64: DisrEl1, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el2",
+    feature = "feat-ras"
+))]
+read_write_sysreg!(vdisr_el2: s3_4_c12_c1_1, u64: VdisrEl2, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el2",
+    feature = "feat-ras"
+))]
+read_write_sysreg!(vsesr_el2: s3_4_c5_c2_3, u64: VsesrEl2, safe_read, safe_write, fake::SYSREGS);
@@ -0,0 +1,3244 @@
+// SPDX-FileCopyrightText: Copyright The arm-sysregs Contributors.
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Snapshots of the values of all safely readable system registers.
+
+// This file is generated, do not edit manually.
+
+// Depending on the enabled features, some registers might not be included, so the features passed
+// to `capture` might not be used.
+#![allow(unused_imports, unused_variables)]
+
+use crate::*;
+
+/// A snapshot of the values of all system registers which can safely be read, for the enabled
+/// exception levels and current architecture.
+///
+/// Registers which can't be read without side effects, such as `ICC_IAR1_EL1`, aren't included.
+///
+/// With the `serde` feature this can be serialised, with each register stored as its raw value
+/// under its lowercase name. Registers missing when deserialising are set to 0, so snapshots can be
+/// loaded by later versions of the crate which add registers. Snapshots with a version newer than
+/// [`RegisterSnapshot::VERSION`] are rejected.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+#[non_exhaustive]
+pub struct RegisterSnapshot {
+    /// The version of the snapshot format, which is [`RegisterSnapshot::VERSION`] for snapshots
+    /// captured by this version of the crate.
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "deserialize_version"))]
+    pub version: u32,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ACTLR` system register.
+    pub actlr: u32,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ACTLR2` system register.
+    pub actlr2: u32,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `ACTLR_EL1` system register.
+    pub actlr_el1: u64,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+    /// Value of the `ACTLR_EL2` system register.
+    pub actlr_el2: u64,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ADFSR` system register.
+    pub adfsr: u32,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `AFSR0_EL1` system register.
+    pub afsr0_el1: u64,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+    /// Value of the `AFSR0_EL2` system register.
+    pub afsr0_el2: u64,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `AFSR1_EL1` system register.
+    pub afsr1_el1: u64,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+    /// Value of the `AFSR1_EL2` system register.
+    pub afsr1_el2: u64,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `AIDR` system register.
+    pub aidr: u32,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `AIFSR` system register.
+    pub aifsr: u32,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `AMAIR0` system register.
+    pub amair0: u32,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `AMAIR1` system register.
+    pub amair1: u32,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `AMAIR_EL1` system register.
+    pub amair_el1: u64,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+    /// Value of the `AMAIR_EL2` system register.
+    pub amair_el2: u64,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `AMCFGR` system register.
+    pub amcfgr: Amcfgr,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    /// Value of the `AMCFGR_EL0` system register.
+    pub amcfgr_el0: AmcfgrEl0,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `AMCGCR` system register.
+    pub amcgcr: Amcgcr,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    /// Value of the `AMCGCR_EL0` system register.
+    pub amcgcr_el0: AmcgcrEl0,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `AMCNTENCLR0` system register.
+    pub amcntenclr0: Amcntenclr0,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    /// Value of the `AMCNTENCLR0_EL0` system register.
+    pub amcntenclr0_el0: Amcntenclr0El0,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `AMCNTENCLR1` system register.
+    pub amcntenclr1: Amcntenclr1,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    /// Value of the `AMCNTENCLR1_EL0` system register.
+    pub amcntenclr1_el0: Amcntenclr1El0,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `AMCNTENSET0` system register.
+    pub amcntenset0: Amcntenset0,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    /// Value of the `AMCNTENSET0_EL0` system register.
+    pub amcntenset0_el0: Amcntenset0El0,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `AMCNTENSET1` system register.
+    pub amcntenset1: Amcntenset1,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    /// Value of the `AMCNTENSET1_EL0` system register.
+    pub amcntenset1_el0: Amcntenset1El0,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `AMCR` system register.
+    pub amcr: Amcr,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    /// Value of the `AMCR_EL0` system register.
+    pub amcr_el0: AmcrEl0,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `AMEVCNTR00` system register.
+    pub amevcntr00: Amevcntr00,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    /// Value of the `AMEVCNTR00_EL0` system register.
+    pub amevcntr00_el0: Amevcntr00El0,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `AMEVCNTR01` system register.
+    pub amevcntr01: Amevcntr01,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    /// Value of the `AMEVCNTR01_EL0` system register.
+    pub amevcntr01_el0: Amevcntr01El0,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `AMEVCNTR02` system register.
+    pub amevcntr02: Amevcntr02,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    /// Value of the `AMEVCNTR02_EL0` system register.
+    pub amevcntr02_el0: Amevcntr02El0,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `AMEVCNTR03` system register.
+    pub amevcntr03: Amevcntr03,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    /// Value of the `AMEVCNTR03_EL0` system register.
+    pub amevcntr03_el0: Amevcntr03El0,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    /// Value of the `AMEVCNTR10_EL0` system register.
+    pub amevcntr10_el0: Amevcntr10El0,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    /// Value of the `AMEVCNTR110_EL0` system register.
+    pub amevcntr110_el0: Amevcntr110El0,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    /// Value of the `AMEVCNTR111_EL0` system register.
+    pub amevcntr111_el0: Amevcntr111El0,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    /// Value of the `AMEVCNTR112_EL0` system register.
+    pub amevcntr112_el0: Amevcntr112El0,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    /// Value of the `AMEVCNTR113_EL0` system register.
+    pub amevcntr113_el0: Amevcntr113El0,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    /// Value of the `AMEVCNTR114_EL0` system register.
+    pub amevcntr114_el0: Amevcntr114El0,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    /// Value of the `AMEVCNTR115_EL0` system register.
+    pub amevcntr115_el0: Amevcntr115El0,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    /// Value of the `AMEVCNTR11_EL0` system register.
+    pub amevcntr11_el0: Amevcntr11El0,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    /// Value of the `AMEVCNTR12_EL0` system register.
+    pub amevcntr12_el0: Amevcntr12El0,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    /// Value of the `AMEVCNTR13_EL0` system register.
+    pub amevcntr13_el0: Amevcntr13El0,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    /// Value of the `AMEVCNTR14_EL0` system register.
+    pub amevcntr14_el0: Amevcntr14El0,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    /// Value of the `AMEVCNTR15_EL0` system register.
+    pub amevcntr15_el0: Amevcntr15El0,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    /// Value of the `AMEVCNTR16_EL0` system register.
+    pub amevcntr16_el0: Amevcntr16El0,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    /// Value of the `AMEVCNTR17_EL0` system register.
+    pub amevcntr17_el0: Amevcntr17El0,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    /// Value of the `AMEVCNTR18_EL0` system register.
+    pub amevcntr18_el0: Amevcntr18El0,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    /// Value of the `AMEVCNTR19_EL0` system register.
+    pub amevcntr19_el0: Amevcntr19El0,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `AMEVTYPER00` system register.
+    pub amevtyper00: Amevtyper00,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    /// Value of the `AMEVTYPER00_EL0` system register.
+    pub amevtyper00_el0: Amevtyper00El0,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `AMEVTYPER01` system register.
+    pub amevtyper01: Amevtyper01,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    /// Value of the `AMEVTYPER01_EL0` system register.
+    pub amevtyper01_el0: Amevtyper01El0,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `AMEVTYPER02` system register.
+    pub amevtyper02: Amevtyper02,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    /// Value of the `AMEVTYPER02_EL0` system register.
+    pub amevtyper02_el0: Amevtyper02El0,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `AMEVTYPER03` system register.
+    pub amevtyper03: Amevtyper03,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    /// Value of the `AMEVTYPER03_EL0` system register.
+    pub amevtyper03_el0: Amevtyper03El0,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `AMEVTYPER10` system register.
+    pub amevtyper10: Amevtyper10,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `AMEVTYPER11` system register.
+    pub amevtyper11: Amevtyper11,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `AMEVTYPER110` system register.
+    pub amevtyper110: Amevtyper110,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `AMEVTYPER111` system register.
+    pub amevtyper111: Amevtyper111,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `AMEVTYPER112` system register.
+    pub amevtyper112: Amevtyper112,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `AMEVTYPER113` system register.
+    pub amevtyper113: Amevtyper113,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `AMEVTYPER114` system register.
+    pub amevtyper114: Amevtyper114,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `AMEVTYPER115` system register.
+    pub amevtyper115: Amevtyper115,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `AMEVTYPER12` system register.
+    pub amevtyper12: Amevtyper12,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `AMEVTYPER13` system register.
+    pub amevtyper13: Amevtyper13,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `AMEVTYPER14` system register.
+    pub amevtyper14: Amevtyper14,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `AMEVTYPER15` system register.
+    pub amevtyper15: Amevtyper15,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `AMEVTYPER16` system register.
+    pub amevtyper16: Amevtyper16,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `AMEVTYPER17` system register.
+    pub amevtyper17: Amevtyper17,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `AMEVTYPER18` system register.
+    pub amevtyper18: Amevtyper18,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `AMEVTYPER19` system register.
+    pub amevtyper19: Amevtyper19,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `AMUSERENR` system register.
+    pub amuserenr: Amuserenr,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-amu"
+    ))]
+    /// Value of the `AMUSERENR_EL0` system register.
+    pub amuserenr_el0: AmuserenrEl0,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `APIAKeyHi_EL1` system register.
+    pub apiakeyhi_el1: ApiakeyhiEl1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `APIAKeyLo_EL1` system register.
+    pub apiakeylo_el1: ApiakeyloEl1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+    /// Value of the `BRBCR_EL2` system register.
+    pub brbcr_el2: BrbcrEl2,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `CCSIDR` system register.
+    pub ccsidr: Ccsidr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `CCSIDR2` system register.
+    pub ccsidr2: Ccsidr2,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `CCSIDR_EL1` system register.
+    pub ccsidr_el1: CcsidrEl1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `CLIDR` system register.
+    pub clidr: Clidr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `CLIDR_EL1` system register.
+    pub clidr_el1: ClidrEl1,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `CNTFRQ` system register.
+    pub cntfrq: Cntfrq,
+    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+    /// Value of the `CNTFRQ_EL0` system register.
+    pub cntfrq_el0: CntfrqEl0,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    /// Value of the `CNTHCTL` system register.
+    pub cnthctl: Cnthctl,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+    /// Value of the `CNTHCTL_EL2` system register.
+    pub cnthctl_el2: CnthctlEl2,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `CNTHPS_CTL` system register.
+    pub cnthps_ctl: CnthpsCtl,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `CNTHPS_CVAL` system register.
+    pub cnthps_cval: CnthpsCval,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `CNTHPS_TVAL` system register.
+    pub cnthps_tval: CnthpsTval,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `CNTHP_CTL` system register.
+    pub cnthp_ctl: CnthpCtl,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `CNTHP_CVAL` system register.
+    pub cnthp_cval: CnthpCval,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `CNTHP_TVAL` system register.
+    pub cnthp_tval: CnthpTval,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `CNTHVS_CTL` system register.
+    pub cnthvs_ctl: CnthvsCtl,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `CNTHVS_CVAL` system register.
+    pub cnthvs_cval: CnthvsCval,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `CNTHVS_TVAL` system register.
+    pub cnthvs_tval: CnthvsTval,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `CNTHV_CTL` system register.
+    pub cnthv_ctl: CnthvCtl,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `CNTHV_CVAL` system register.
+    pub cnthv_cval: CnthvCval,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `CNTHV_TVAL` system register.
+    pub cnthv_tval: CnthvTval,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `CNTKCTL` system register.
+    pub cntkctl: Cntkctl,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `CNTKCTL_EL1` system register.
+    pub cntkctl_el1: CntkctlEl1,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `CNTPCT` system register.
+    pub cntpct: Cntpct,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `CNTPCTSS` system register.
+    pub cntpctss: Cntpctss,
+    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+    /// Value of the `CNTPCT_EL0` system register.
+    pub cntpct_el0: CntpctEl0,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `CNTPS_CTL_EL1` system register.
+    pub cntps_ctl_el1: CntpsCtlEl1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `CNTPS_CVAL_EL1` system register.
+    pub cntps_cval_el1: CntpsCvalEl1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `CNTPS_TVAL_EL1` system register.
+    pub cntps_tval_el1: CntpsTvalEl1,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `CNTP_CTL` system register.
+    pub cntp_ctl: CntpCtl,
+    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+    /// Value of the `CNTP_CTL_EL0` system register.
+    pub cntp_ctl_el0: CntpCtlEl0,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `CNTP_CVAL` system register.
+    pub cntp_cval: CntpCval,
+    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+    /// Value of the `CNTP_CVAL_EL0` system register.
+    pub cntp_cval_el0: CntpCvalEl0,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `CNTP_TVAL` system register.
+    pub cntp_tval: CntpTval,
+    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+    /// Value of the `CNTP_TVAL_EL0` system register.
+    pub cntp_tval_el0: CntpTvalEl0,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `CNTVCT` system register.
+    pub cntvct: Cntvct,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `CNTVCTSS` system register.
+    pub cntvctss: Cntvctss,
+    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+    /// Value of the `CNTVCT_EL0` system register.
+    pub cntvct_el0: CntvctEl0,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    /// Value of the `CNTVOFF` system register.
+    pub cntvoff: Cntvoff,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+    /// Value of the `CNTVOFF_EL2` system register.
+    pub cntvoff_el2: CntvoffEl2,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `CNTV_CTL` system register.
+    pub cntv_ctl: CntvCtl,
+    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+    /// Value of the `CNTV_CTL_EL0` system register.
+    pub cntv_ctl_el0: CntvCtlEl0,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `CNTV_CVAL` system register.
+    pub cntv_cval: CntvCval,
+    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+    /// Value of the `CNTV_CVAL_EL0` system register.
+    pub cntv_cval_el0: CntvCvalEl0,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `CNTV_TVAL` system register.
+    pub cntv_tval: CntvTval,
+    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+    /// Value of the `CNTV_TVAL_EL0` system register.
+    pub cntv_tval_el0: CntvTvalEl0,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `CONTEXTIDR` system register.
+    pub contextidr: Contextidr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `CONTEXTIDR_EL1` system register.
+    pub contextidr_el1: ContextidrEl1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `CPACR` system register.
+    pub cpacr: Cpacr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `CPACR_EL1` system register.
+    pub cpacr_el1: CpacrEl1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+    /// Value of the `CPTR_EL2` system register.
+    pub cptr_el2: CptrEl2,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
+    /// Value of the `CPTR_EL3` system register.
+    pub cptr_el3: CptrEl3,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `CSSELR` system register.
+    pub csselr: Csselr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `CSSELR_EL1` system register.
+    pub csselr_el1: CsselrEl1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `CTR` system register.
+    pub ctr: Ctr,
+    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+    /// Value of the `CTR_EL0` system register.
+    pub ctr_el0: CtrEl0,
+    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+    /// Value of the `CurrentEL` system register.
+    pub currentel: Currentel,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `DACR` system register.
+    pub dacr: Dacr,
+    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+    /// Value of the `DAIF` system register.
+    pub daif: Daif,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `DBGAUTHSTATUS` system register.
+    pub dbgauthstatus: Dbgauthstatus,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `DBGCLAIMCLR` system register.
+    pub dbgclaimclr: Dbgclaimclr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `DBGCLAIMSET` system register.
+    pub dbgclaimset: Dbgclaimset,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `DBGDCCINT` system register.
+    pub dbgdccint: Dbgdccint,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `DBGDEVID` system register.
+    pub dbgdevid: Dbgdevid,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `DBGDEVID1` system register.
+    pub dbgdevid1: Dbgdevid1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `DBGDEVID2` system register.
+    pub dbgdevid2: u32,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `DBGDIDR` system register.
+    pub dbgdidr: Dbgdidr,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `DBGDRAR` system register.
+    pub dbgdrar: Dbgdrar,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `DBGDSAR` system register.
+    pub dbgdsar: u64,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `DBGDSCRext` system register.
+    pub dbgdscrext: Dbgdscrext,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `DBGDSCRint` system register.
+    pub dbgdscrint: Dbgdscrint,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `DBGDTRRXext` system register.
+    pub dbgdtrrxext: Dbgdtrrxext,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `DBGDTRRXint` system register.
+    pub dbgdtrrxint: Dbgdtrrxint,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `DBGDTRTXext` system register.
+    pub dbgdtrtxext: Dbgdtrtxext,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `DBGOSDLR` system register.
+    pub dbgosdlr: Dbgosdlr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `DBGOSECCR` system register.
+    pub dbgoseccr: Dbgoseccr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `DBGOSLSR` system register.
+    pub dbgoslsr: Dbgoslsr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `DBGPRCR` system register.
+    pub dbgprcr: Dbgprcr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `DBGVCR` system register.
+    pub dbgvcr: Dbgvcr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `DBGWFAR` system register.
+    pub dbgwfar: u32,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `DFAR` system register.
+    pub dfar: Dfar,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `DFSR` system register.
+    pub dfsr: Dfsr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `DISR` system register.
+    pub disr: Disr,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el1",
+        feature = "feat-ras"
+    ))]
+    /// Value of the `DISR_EL1` system register.
+    pub disr_el1: DisrEl1,
+    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+    /// Value of the `DIT` system register.
+    pub dit: Dit,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `DLR` system register.
+    pub dlr: Dlr,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `DSPSR` system register.
+    pub dspsr: Dspsr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `DSPSR2` system register.
+    pub dspsr2: Dspsr2,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `ELR_EL1` system register.
+    pub elr_el1: ElrEl1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+    /// Value of the `ELR_EL2` system register.
+    pub elr_el2: ElrEl2,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    /// Value of the `ELR_hyp` system register.
+    pub elr_hyp: ElrHyp,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ERRIDR` system register.
+    pub erridr: Erridr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ERRSELR` system register.
+    pub errselr: Errselr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ERXADDR` system register.
+    pub erxaddr: Erxaddr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ERXADDR2` system register.
+    pub erxaddr2: Erxaddr2,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ERXCTLR` system register.
+    pub erxctlr: Erxctlr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ERXCTLR2` system register.
+    pub erxctlr2: Erxctlr2,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ERXFR` system register.
+    pub erxfr: Erxfr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ERXFR2` system register.
+    pub erxfr2: Erxfr2,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ERXMISC0` system register.
+    pub erxmisc0: Erxmisc0,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ERXMISC1` system register.
+    pub erxmisc1: Erxmisc1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ERXMISC2` system register.
+    pub erxmisc2: Erxmisc2,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ERXMISC3` system register.
+    pub erxmisc3: Erxmisc3,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ERXMISC4` system register.
+    pub erxmisc4: Erxmisc4,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ERXMISC5` system register.
+    pub erxmisc5: Erxmisc5,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ERXMISC6` system register.
+    pub erxmisc6: Erxmisc6,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ERXMISC7` system register.
+    pub erxmisc7: Erxmisc7,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ERXSTATUS` system register.
+    pub erxstatus: Erxstatus,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `ESR_EL1` system register.
+    pub esr_el1: EsrEl1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+    /// Value of the `ESR_EL2` system register.
+    pub esr_el2: EsrEl2,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
+    /// Value of the `ESR_EL3` system register.
+    pub esr_el3: EsrEl3,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `FAR_EL1` system register.
+    pub far_el1: FarEl1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+    /// Value of the `FAR_EL2` system register.
+    pub far_el2: FarEl2,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `FCSEIDR` system register.
+    pub fcseidr: u32,
+    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+    /// Value of the `FPCR` system register.
+    pub fpcr: Fpcr,
+    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+    /// Value of the `FPMR` system register.
+    pub fpmr: Fpmr,
+    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+    /// Value of the `FPSR` system register.
+    pub fpsr: Fpsr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `GCR_EL1` system register.
+    pub gcr_el1: GcrEl1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `GCSCR_EL1` system register.
+    pub gcscr_el1: GcscrEl1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+    /// Value of the `GCSCR_EL2` system register.
+    pub gcscr_el2: GcscrEl2,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el3",
+        feature = "feat-rme"
+    ))]
+    /// Value of the `GPCCR_EL3` system register.
+    pub gpccr_el3: GpccrEl3,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el3",
+        feature = "feat-rme"
+    ))]
+    /// Value of the `GPTBR_EL3` system register.
+    pub gptbr_el3: GptbrEl3,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    /// Value of the `HACR` system register.
+    pub hacr: u32,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+    /// Value of the `HACR_EL2` system register.
+    pub hacr_el2: u64,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    /// Value of the `HACTLR` system register.
+    pub hactlr: u32,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    /// Value of the `HACTLR2` system register.
+    pub hactlr2: u32,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    /// Value of the `HADFSR` system register.
+    pub hadfsr: u32,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    /// Value of the `HAIFSR` system register.
+    pub haifsr: u32,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    /// Value of the `HAMAIR0` system register.
+    pub hamair0: u32,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    /// Value of the `HAMAIR1` system register.
+    pub hamair1: u32,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    /// Value of the `HCPTR` system register.
+    pub hcptr: Hcptr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    /// Value of the `HCR` system register.
+    pub hcr: Hcr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    /// Value of the `HCR2` system register.
+    pub hcr2: Hcr2,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+    /// Value of the `HCRX_EL2` system register.
+    pub hcrx_el2: HcrxEl2,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+    /// Value of the `HCR_EL2` system register.
+    pub hcr_el2: HcrEl2,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    /// Value of the `HDCR` system register.
+    pub hdcr: Hdcr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    /// Value of the `HDFAR` system register.
+    pub hdfar: Hdfar,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    /// Value of the `HIFAR` system register.
+    pub hifar: Hifar,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    /// Value of the `HMAIR0` system register.
+    pub hmair0: Hmair0,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    /// Value of the `HMAIR1` system register.
+    pub hmair1: Hmair1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    /// Value of the `HPFAR` system register.
+    pub hpfar: Hpfar,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+    /// Value of the `HPFAR_EL2` system register.
+    pub hpfar_el2: HpfarEl2,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    /// Value of the `HRMR` system register.
+    pub hrmr: Hrmr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    /// Value of the `HSCTLR` system register.
+    pub hsctlr: Hsctlr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    /// Value of the `HSR` system register.
+    pub hsr: Hsr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    /// Value of the `HSTR` system register.
+    pub hstr: u32,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+    /// Value of the `HSTR_EL2` system register.
+    pub hstr_el2: u64,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    /// Value of the `HTCR` system register.
+    pub htcr: Htcr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    /// Value of the `HTPIDR` system register.
+    pub htpidr: Htpidr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    /// Value of the `HTRFCR` system register.
+    pub htrfcr: Htrfcr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    /// Value of the `HTTBR` system register.
+    pub httbr: Httbr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    /// Value of the `HVBAR` system register.
+    pub hvbar: Hvbar,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el1",
+        feature = "feat-gic"
+    ))]
+    /// Value of the `ICC_AP0R0_EL1` system register.
+    pub icc_ap0r0_el1: u64,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el1",
+        feature = "feat-gic"
+    ))]
+    /// Value of the `ICC_AP0R1_EL1` system register.
+    pub icc_ap0r1_el1: u64,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el1",
+        feature = "feat-gic"
+    ))]
+    /// Value of the `ICC_AP0R2_EL1` system register.
+    pub icc_ap0r2_el1: u64,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el1",
+        feature = "feat-gic"
+    ))]
+    /// Value of the `ICC_AP0R3_EL1` system register.
+    pub icc_ap0r3_el1: u64,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el1",
+        feature = "feat-gic"
+    ))]
+    /// Value of the `ICC_AP1R0_EL1` system register.
+    pub icc_ap1r0_el1: IccAp1r0El1,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el1",
+        feature = "feat-gic"
+    ))]
+    /// Value of the `ICC_AP1R1_EL1` system register.
+    pub icc_ap1r1_el1: u64,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el1",
+        feature = "feat-gic"
+    ))]
+    /// Value of the `ICC_AP1R2_EL1` system register.
+    pub icc_ap1r2_el1: u64,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el1",
+        feature = "feat-gic"
+    ))]
+    /// Value of the `ICC_AP1R3_EL1` system register.
+    pub icc_ap1r3_el1: u64,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ICC_BPR0` system register.
+    pub icc_bpr0: IccBpr0,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el1",
+        feature = "feat-gic"
+    ))]
+    /// Value of the `ICC_BPR0_EL1` system register.
+    pub icc_bpr0_el1: IccBpr0El1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ICC_BPR1` system register.
+    pub icc_bpr1: IccBpr1,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el1",
+        feature = "feat-gic"
+    ))]
+    /// Value of the `ICC_BPR1_EL1` system register.
+    pub icc_bpr1_el1: IccBpr1El1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ICC_CTLR` system register.
+    pub icc_ctlr: IccCtlr,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el1",
+        feature = "feat-gic"
+    ))]
+    /// Value of the `ICC_CTLR_EL1` system register.
+    pub icc_ctlr_el1: IccCtlrEl1,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el3",
+        feature = "feat-gic"
+    ))]
+    /// Value of the `ICC_CTLR_EL3` system register.
+    pub icc_ctlr_el3: IccCtlrEl3,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ICC_HPPIR0` system register.
+    pub icc_hppir0: IccHppir0,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el1",
+        feature = "feat-gic"
+    ))]
+    /// Value of the `ICC_HPPIR0_EL1` system register.
+    pub icc_hppir0_el1: IccHppir0El1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ICC_HPPIR1` system register.
+    pub icc_hppir1: IccHppir1,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el1",
+        feature = "feat-gic"
+    ))]
+    /// Value of the `ICC_HPPIR1_EL1` system register.
+    pub icc_hppir1_el1: IccHppir1El1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    /// Value of the `ICC_HSRE` system register.
+    pub icc_hsre: IccHsre,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ICC_IGRPEN0` system register.
+    pub icc_igrpen0: IccIgrpen0,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el1",
+        feature = "feat-gic"
+    ))]
+    /// Value of the `ICC_IGRPEN0_EL1` system register.
+    pub icc_igrpen0_el1: IccIgrpen0El1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ICC_IGRPEN1` system register.
+    pub icc_igrpen1: IccIgrpen1,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el1",
+        feature = "feat-gic"
+    ))]
+    /// Value of the `ICC_IGRPEN1_EL1` system register.
+    pub icc_igrpen1_el1: IccIgrpen1El1,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el3",
+        feature = "feat-gic"
+    ))]
+    /// Value of the `ICC_IGRPEN1_EL3` system register.
+    pub icc_igrpen1_el3: IccIgrpen1El3,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el3"))]
+    /// Value of the `ICC_MCTLR` system register.
+    pub icc_mctlr: IccMctlr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el3"))]
+    /// Value of the `ICC_MGRPEN1` system register.
+    pub icc_mgrpen1: IccMgrpen1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el3"))]
+    /// Value of the `ICC_MSRE` system register.
+    pub icc_msre: IccMsre,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ICC_PMR` system register.
+    pub icc_pmr: IccPmr,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el1",
+        feature = "feat-gic"
+    ))]
+    /// Value of the `ICC_PMR_EL1` system register.
+    pub icc_pmr_el1: IccPmrEl1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ICC_RPR` system register.
+    pub icc_rpr: IccRpr,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el1",
+        feature = "feat-gic"
+    ))]
+    /// Value of the `ICC_RPR_EL1` system register.
+    pub icc_rpr_el1: IccRprEl1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ICC_SRE` system register.
+    pub icc_sre: IccSre,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el1",
+        feature = "feat-gic"
+    ))]
+    /// Value of the `ICC_SRE_EL1` system register.
+    pub icc_sre_el1: IccSreEl1,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el2",
+        feature = "feat-gic"
+    ))]
+    /// Value of the `ICC_SRE_EL2` system register.
+    pub icc_sre_el2: IccSreEl2,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el3",
+        feature = "feat-gic"
+    ))]
+    /// Value of the `ICC_SRE_EL3` system register.
+    pub icc_sre_el3: IccSreEl3,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el2",
+        feature = "feat-gic"
+    ))]
+    /// Value of the `ICH_HCR_EL2` system register.
+    pub ich_hcr_el2: IchHcrEl2,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el2",
+        feature = "feat-gic"
+    ))]
+    /// Value of the `ICH_VMCR_EL2` system register.
+    pub ich_vmcr_el2: IchVmcrEl2,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `ID_AA64DFR0_EL1` system register.
+    pub id_aa64dfr0_el1: IdAa64dfr0El1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `ID_AA64DFR1_EL1` system register.
+    pub id_aa64dfr1_el1: IdAa64dfr1El1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `ID_AA64ISAR1_EL1` system register.
+    pub id_aa64isar1_el1: IdAa64isar1El1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `ID_AA64ISAR2_EL1` system register.
+    pub id_aa64isar2_el1: IdAa64isar2El1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `ID_AA64MMFR0_EL1` system register.
+    pub id_aa64mmfr0_el1: IdAa64mmfr0El1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `ID_AA64MMFR1_EL1` system register.
+    pub id_aa64mmfr1_el1: IdAa64mmfr1El1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `ID_AA64MMFR2_EL1` system register.
+    pub id_aa64mmfr2_el1: IdAa64mmfr2El1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `ID_AA64MMFR3_EL1` system register.
+    pub id_aa64mmfr3_el1: IdAa64mmfr3El1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `ID_AA64MMFR4_EL1` system register.
+    pub id_aa64mmfr4_el1: IdAa64mmfr4El1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `ID_AA64PFR0_EL1` system register.
+    pub id_aa64pfr0_el1: IdAa64pfr0El1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `ID_AA64PFR1_EL1` system register.
+    pub id_aa64pfr1_el1: IdAa64pfr1El1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `ID_AA64PFR2_EL1` system register.
+    pub id_aa64pfr2_el1: IdAa64pfr2El1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `ID_AA64SMFR0_EL1` system register.
+    pub id_aa64smfr0_el1: IdAa64smfr0El1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ID_AFR0` system register.
+    pub id_afr0: u32,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ID_DFR0` system register.
+    pub id_dfr0: IdDfr0,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ID_DFR1` system register.
+    pub id_dfr1: IdDfr1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ID_ISAR0` system register.
+    pub id_isar0: IdIsar0,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ID_ISAR1` system register.
+    pub id_isar1: IdIsar1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ID_ISAR2` system register.
+    pub id_isar2: IdIsar2,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ID_ISAR3` system register.
+    pub id_isar3: IdIsar3,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ID_ISAR4` system register.
+    pub id_isar4: IdIsar4,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ID_ISAR5` system register.
+    pub id_isar5: IdIsar5,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ID_ISAR6` system register.
+    pub id_isar6: IdIsar6,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ID_MMFR0` system register.
+    pub id_mmfr0: IdMmfr0,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ID_MMFR1` system register.
+    pub id_mmfr1: IdMmfr1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ID_MMFR2` system register.
+    pub id_mmfr2: IdMmfr2,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ID_MMFR3` system register.
+    pub id_mmfr3: IdMmfr3,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ID_MMFR4` system register.
+    pub id_mmfr4: IdMmfr4,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ID_MMFR5` system register.
+    pub id_mmfr5: IdMmfr5,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ID_PFR0` system register.
+    pub id_pfr0: IdPfr0,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ID_PFR1` system register.
+    pub id_pfr1: IdPfr1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ID_PFR2` system register.
+    pub id_pfr2: IdPfr2,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `IFAR` system register.
+    pub ifar: Ifar,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `IFSR` system register.
+    pub ifsr: Ifsr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `ISR` system register.
+    pub isr: Isr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `ISR_EL1` system register.
+    pub isr_el1: IsrEl1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `JIDR` system register.
+    pub jidr: u32,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `JMCR` system register.
+    pub jmcr: u32,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `JOSCR` system register.
+    pub joscr: u32,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `MAIR0` system register.
+    pub mair0: Mair0,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `MAIR1` system register.
+    pub mair1: Mair1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `MAIR_EL1` system register.
+    pub mair_el1: MairEl1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+    /// Value of the `MAIR_EL2` system register.
+    pub mair_el2: MairEl2,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
+    /// Value of the `MAIR_EL3` system register.
+    pub mair_el3: MairEl3,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `MDCCINT_EL1` system register.
+    pub mdccint_el1: MdccintEl1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+    /// Value of the `MDCR_EL2` system register.
+    pub mdcr_el2: MdcrEl2,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
+    /// Value of the `MDCR_EL3` system register.
+    pub mdcr_el3: MdcrEl3,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `MDSCR_EL1` system register.
+    pub mdscr_el1: MdscrEl1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `MIDR` system register.
+    pub midr: Midr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `MIDR_EL1` system register.
+    pub midr_el1: MidrEl1,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el2",
+        feature = "feat-mpam"
+    ))]
+    /// Value of the `MPAM2_EL2` system register.
+    pub mpam2_el2: Mpam2El2,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el3",
+        feature = "feat-mpam"
+    ))]
+    /// Value of the `MPAM3_EL3` system register.
+    pub mpam3_el3: Mpam3El3,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el2",
+        feature = "feat-mpam"
+    ))]
+    /// Value of the `MPAMHCR_EL2` system register.
+    pub mpamhcr_el2: MpamhcrEl2,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el1",
+        feature = "feat-mpam"
+    ))]
+    /// Value of the `MPAMIDR_EL1` system register.
+    pub mpamidr_el1: MpamidrEl1,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el2",
+        feature = "feat-mpam"
+    ))]
+    /// Value of the `MPAMVPM0_EL2` system register.
+    pub mpamvpm0_el2: Mpamvpm0El2,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el2",
+        feature = "feat-mpam"
+    ))]
+    /// Value of the `MPAMVPM1_EL2` system register.
+    pub mpamvpm1_el2: Mpamvpm1El2,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el2",
+        feature = "feat-mpam"
+    ))]
+    /// Value of the `MPAMVPM2_EL2` system register.
+    pub mpamvpm2_el2: Mpamvpm2El2,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el2",
+        feature = "feat-mpam"
+    ))]
+    /// Value of the `MPAMVPM3_EL2` system register.
+    pub mpamvpm3_el2: Mpamvpm3El2,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el2",
+        feature = "feat-mpam"
+    ))]
+    /// Value of the `MPAMVPM4_EL2` system register.
+    pub mpamvpm4_el2: Mpamvpm4El2,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el2",
+        feature = "feat-mpam"
+    ))]
+    /// Value of the `MPAMVPM5_EL2` system register.
+    pub mpamvpm5_el2: Mpamvpm5El2,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el2",
+        feature = "feat-mpam"
+    ))]
+    /// Value of the `MPAMVPM6_EL2` system register.
+    pub mpamvpm6_el2: Mpamvpm6El2,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el2",
+        feature = "feat-mpam"
+    ))]
+    /// Value of the `MPAMVPM7_EL2` system register.
+    pub mpamvpm7_el2: Mpamvpm7El2,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el2",
+        feature = "feat-mpam"
+    ))]
+    /// Value of the `MPAMVPMV_EL2` system register.
+    pub mpamvpmv_el2: MpamvpmvEl2,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `MPIDR` system register.
+    pub mpidr: Mpidr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `MPIDR_EL1` system register.
+    pub mpidr_el1: MpidrEl1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `MVBAR` system register.
+    pub mvbar: Mvbar,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `NMRR` system register.
+    pub nmrr: Nmrr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `NSACR` system register.
+    pub nsacr: Nsacr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `PAR` system register.
+    pub par: Par,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `PAR_EL1` system register.
+    pub par_el1: ParEl1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `PFAR_EL1` system register.
+    pub pfar_el1: PfarEl1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+    /// Value of the `PFAR_EL2` system register.
+    pub pfar_el2: PfarEl2,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `PIRE0_EL1` system register.
+    pub pire0_el1: Pire0El1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+    /// Value of the `PIRE0_EL2` system register.
+    pub pire0_el2: Pire0El2,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `PIR_EL1` system register.
+    pub pir_el1: PirEl1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+    /// Value of the `PIR_EL2` system register.
+    pub pir_el2: PirEl2,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
+    /// Value of the `PIR_EL3` system register.
+    pub pir_el3: PirEl3,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `PMCCFILTR` system register.
+    pub pmccfiltr: Pmccfiltr,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `PMCCNTR` system register.
+    pub pmccntr: Pmccntr,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `PMCEID0` system register.
+    pub pmceid0: Pmceid0,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `PMCEID1` system register.
+    pub pmceid1: Pmceid1,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `PMCEID2` system register.
+    pub pmceid2: Pmceid2,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `PMCEID3` system register.
+    pub pmceid3: Pmceid3,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `PMCNTENCLR` system register.
+    pub pmcntenclr: Pmcntenclr,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `PMCNTENSET` system register.
+    pub pmcntenset: Pmcntenset,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `PMCR` system register.
+    pub pmcr: Pmcr,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-pmu"
+    ))]
+    /// Value of the `PMCR_EL0` system register.
+    pub pmcr_el0: PmcrEl0,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `PMINTENCLR` system register.
+    pub pmintenclr: Pmintenclr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `PMINTENSET` system register.
+    pub pmintenset: Pmintenset,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `PMMIR` system register.
+    pub pmmir: Pmmir,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `PMOVSR` system register.
+    pub pmovsr: Pmovsr,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `PMOVSSET` system register.
+    pub pmovsset: Pmovsset,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `PMSELR` system register.
+    pub pmselr: Pmselr,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `PMUSERENR` system register.
+    pub pmuserenr: Pmuserenr,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `PMXEVTYPER` system register.
+    pub pmxevtyper: Pmxevtyper,
+    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+    /// Value of the `POR_EL0` system register.
+    pub por_el0: PorEl0,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `POR_EL1` system register.
+    pub por_el1: PorEl1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+    /// Value of the `POR_EL2` system register.
+    pub por_el2: PorEl2,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
+    /// Value of the `POR_EL3` system register.
+    pub por_el3: PorEl3,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `PRRR` system register.
+    pub prrr: Prrr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `REVIDR` system register.
+    pub revidr: u32,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `RGSR_EL1` system register.
+    pub rgsr_el1: RgsrEl1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `RMR` system register.
+    pub rmr: Rmr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `RVBAR` system register.
+    pub rvbar: Rvbar,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+    /// Value of the `S2PIR_EL2` system register.
+    pub s2pir_el2: S2pirEl2,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `S2POR_EL1` system register.
+    pub s2por_el1: S2porEl1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `SCR` system register.
+    pub scr: Scr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
+    /// Value of the `SCR_EL3` system register.
+    pub scr_el3: ScrEl3,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `SCTLR` system register.
+    pub sctlr: Sctlr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `SCTLR2_EL1` system register.
+    pub sctlr2_el1: Sctlr2El1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+    /// Value of the `SCTLR2_EL2` system register.
+    pub sctlr2_el2: Sctlr2El2,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
+    /// Value of the `SCTLR2_EL3` system register.
+    pub sctlr2_el3: Sctlr2El3,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `SCTLR_EL1` system register.
+    pub sctlr_el1: SctlrEl1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+    /// Value of the `SCTLR_EL2` system register.
+    pub sctlr_el2: SctlrEl2,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
+    /// Value of the `SCTLR_EL3` system register.
+    pub sctlr_el3: SctlrEl3,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `SDCR` system register.
+    pub sdcr: Sdcr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `SDER` system register.
+    pub sder: Sder,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el3",
+        feature = "feat-sve"
+    ))]
+    /// Value of the `SMCR_EL3` system register.
+    pub smcr_el3: SmcrEl3,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `SPSR_EL1` system register.
+    pub spsr_el1: SpsrEl1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+    /// Value of the `SPSR_EL2` system register.
+    pub spsr_el2: SpsrEl2,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
+    /// Value of the `SPSR_EL3` system register.
+    pub spsr_el3: SpsrEl3,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `SP_EL1` system register.
+    pub sp_el1: SpEl1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+    /// Value of the `SP_EL2` system register.
+    pub sp_el2: SpEl2,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "feat-sve"
+    ))]
+    /// Value of the `SVCR` system register.
+    pub svcr: Svcr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `TCMTR` system register.
+    pub tcmtr: u32,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `TCR2_EL1` system register.
+    pub tcr2_el1: Tcr2El1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `TCR_EL1` system register.
+    pub tcr_el1: TcrEl1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+    /// Value of the `TCR_EL2` system register.
+    pub tcr_el2: TcrEl2,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
+    /// Value of the `TCR_EL3` system register.
+    pub tcr_el3: TcrEl3,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `TFSRE0_EL1` system register.
+    pub tfsre0_el1: Tfsre0El1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `TFSR_EL1` system register.
+    pub tfsr_el1: TfsrEl1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+    /// Value of the `TFSR_EL2` system register.
+    pub tfsr_el2: TfsrEl2,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `TLBTR` system register.
+    pub tlbtr: Tlbtr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `TPIDRPRW` system register.
+    pub tpidrprw: Tpidrprw,
+    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+    /// Value of the `TPIDRRO_EL0` system register.
+    pub tpidrro_el0: TpidrroEl0,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `TPIDRURO` system register.
+    pub tpidruro: Tpidruro,
+    #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+    /// Value of the `TPIDRURW` system register.
+    pub tpidrurw: Tpidrurw,
+    #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+    /// Value of the `TPIDR_EL0` system register.
+    pub tpidr_el0: TpidrEl0,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `TPIDR_EL1` system register.
+    pub tpidr_el1: TpidrEl1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+    /// Value of the `TPIDR_EL2` system register.
+    pub tpidr_el2: TpidrEl2,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
+    /// Value of the `TPIDR_EL3` system register.
+    pub tpidr_el3: TpidrEl3,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `TRFCR` system register.
+    pub trfcr: Trfcr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `TTBCR` system register.
+    pub ttbcr: Ttbcr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `TTBCR2` system register.
+    pub ttbcr2: Ttbcr2,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `TTBR0` system register.
+    pub ttbr0: Ttbr0,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `TTBR0_EL1` system register.
+    pub ttbr0_el1: Ttbr0El1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+    /// Value of the `TTBR0_EL2` system register.
+    pub ttbr0_el2: Ttbr0El2,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
+    /// Value of the `TTBR0_EL3` system register.
+    pub ttbr0_el3: Ttbr0El3,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `TTBR1` system register.
+    pub ttbr1: Ttbr1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `TTBR1_EL1` system register.
+    pub ttbr1_el1: Ttbr1El1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+    /// Value of the `TTBR1_EL2` system register.
+    pub ttbr1_el2: Ttbr1El2,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `VBAR` system register.
+    pub vbar: Vbar,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+    /// Value of the `VBAR_EL1` system register.
+    pub vbar_el1: VbarEl1,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+    /// Value of the `VBAR_EL2` system register.
+    pub vbar_el2: VbarEl2,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    /// Value of the `VDFSR` system register.
+    pub vdfsr: Vdfsr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `VDISR` system register.
+    pub vdisr: Vdisr,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el2",
+        feature = "feat-ras"
+    ))]
+    /// Value of the `VDISR_EL2` system register.
+    pub vdisr_el2: VdisrEl2,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `VMPIDR` system register.
+    pub vmpidr: Vmpidr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+    /// Value of the `VMPIDR_EL2` system register.
+    pub vmpidr_el2: VmpidrEl2,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+    /// Value of the `VPIDR` system register.
+    pub vpidr: Vpidr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+    /// Value of the `VPIDR_EL2` system register.
+    pub vpidr_el2: VpidrEl2,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el2",
+        feature = "feat-ras"
+    ))]
+    /// Value of the `VSESR_EL2` system register.
+    pub vsesr_el2: VsesrEl2,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    /// Value of the `VTCR` system register.
+    pub vtcr: Vtcr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+    /// Value of the `VTCR_EL2` system register.
+    pub vtcr_el2: VtcrEl2,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+    /// Value of the `VTTBR` system register.
+    pub vttbr: Vttbr,
+    #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+    /// Value of the `VTTBR_EL2` system register.
+    pub vttbr_el2: VttbrEl2,
+    #[cfg(all(
+        any(test, feature = "fakes", target_arch = "aarch64"),
+        feature = "el3",
+        feature = "feat-sve"
+    ))]
+    /// Value of the `ZCR_EL3` system register.
+    pub zcr_el3: ZcrEl3,
+}
+
+impl RegisterSnapshot {
+    /// The current version of the snapshot format.
+    pub const VERSION: u32 = 1;
+
+    /// Reads the current values of all registers in the snapshot.
+    ///
+    /// Registers which only exist if some feature is implemented are only read if `features`
+    /// contains it, and are otherwise left as 0.
+    pub fn capture(features: &CpuFeatures) -> Self {
+        Self {
+            version: Self::VERSION,
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            actlr: read_actlr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            actlr2: read_actlr2(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            actlr_el1: read_actlr_el1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+            actlr_el2: read_actlr_el2(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            adfsr: read_adfsr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            afsr0_el1: read_afsr0_el1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+            afsr0_el2: read_afsr0_el2(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            afsr1_el1: read_afsr1_el1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+            afsr1_el2: read_afsr1_el2(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            aidr: read_aidr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            aifsr: read_aifsr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            amair0: read_amair0(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            amair1: read_amair1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            amair_el1: read_amair_el1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+            amair_el2: read_amair_el2(),
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            amcfgr: read_amcfgr(),
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "feat-amu"
+            ))]
+            amcfgr_el0: if features.contains(Feature::Amuv1) {
+                read_amcfgr_el0()
+            } else {
+                Default::default()
+            },
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            amcgcr: read_amcgcr(),
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "feat-amu"
+            ))]
+            amcgcr_el0: if features.contains(Feature::Amuv1) {
+                read_amcgcr_el0()
+            } else {
+                Default::default()
+            },
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            amcntenclr0: read_amcntenclr0(),
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "feat-amu"
+            ))]
+            amcntenclr0_el0: if features.contains(Feature::Amuv1) {
+                read_amcntenclr0_el0()
+            } else {
+                Default::default()
+            },
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            amcntenclr1: read_amcntenclr1(),
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "feat-amu"
+            ))]
+            amcntenclr1_el0: if features.contains(Feature::Amuv1) {
+                read_amcntenclr1_el0()
+            } else {
+                Default::default()
+            },
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            amcntenset0: read_amcntenset0(),
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "feat-amu"
+            ))]
+            amcntenset0_el0: if features.contains(Feature::Amuv1) {
+                read_amcntenset0_el0()
+            } else {
+                Default::default()
+            },
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            amcntenset1: read_amcntenset1(),
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "feat-amu"
+            ))]
+            amcntenset1_el0: if features.contains(Feature::Amuv1) {
+                read_amcntenset1_el0()
+            } else {
+                Default::default()
+            },
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            amcr: read_amcr(),
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "feat-amu"
+            ))]
+            amcr_el0: if features.contains(Feature::Amuv1) {
+                read_amcr_el0()
+            } else {
+                Default::default()
+            },
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            amevcntr00: read_amevcntr00(),
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "feat-amu"
+            ))]
+            amevcntr00_el0: if features.contains(Feature::Amuv1) {
+                read_amevcntr00_el0()
+            } else {
+                Default::default()
+            },
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            amevcntr01: read_amevcntr01(),
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "feat-amu"
+            ))]
+            amevcntr01_el0: if features.contains(Feature::Amuv1) {
+                read_amevcntr01_el0()
+            } else {
+                Default::default()
+            },
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            amevcntr02: read_amevcntr02(),
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "feat-amu"
+            ))]
+            amevcntr02_el0: if features.contains(Feature::Amuv1) {
+                read_amevcntr02_el0()
+            } else {
+                Default::default()
+            },
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            amevcntr03: read_amevcntr03(),
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "feat-amu"
+            ))]
+            amevcntr03_el0: if features.contains(Feature::Amuv1) {
+                read_amevcntr03_el0()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "feat-amu"
+            ))]
+            amevcntr10_el0: if features.contains(Feature::Amuv1) {
+                read_amevcntr10_el0()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "feat-amu"
+            ))]
+            amevcntr110_el0: if features.contains(Feature::Amuv1) {
+                read_amevcntr110_el0()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "feat-amu"
+            ))]
+            amevcntr111_el0: if features.contains(Feature::Amuv1) {
+                read_amevcntr111_el0()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "feat-amu"
+            ))]
+            amevcntr112_el0: if features.contains(Feature::Amuv1) {
+                read_amevcntr112_el0()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "feat-amu"
+            ))]
+            amevcntr113_el0: if features.contains(Feature::Amuv1) {
+                read_amevcntr113_el0()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "feat-amu"
+            ))]
+            amevcntr114_el0: if features.contains(Feature::Amuv1) {
+                read_amevcntr114_el0()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "feat-amu"
+            ))]
+            amevcntr115_el0: if features.contains(Feature::Amuv1) {
+                read_amevcntr115_el0()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "feat-amu"
+            ))]
+            amevcntr11_el0: if features.contains(Feature::Amuv1) {
+                read_amevcntr11_el0()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "feat-amu"
+            ))]
+            amevcntr12_el0: if features.contains(Feature::Amuv1) {
+                read_amevcntr12_el0()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "feat-amu"
+            ))]
+            amevcntr13_el0: if features.contains(Feature::Amuv1) {
+                read_amevcntr13_el0()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "feat-amu"
+            ))]
+            amevcntr14_el0: if features.contains(Feature::Amuv1) {
+                read_amevcntr14_el0()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "feat-amu"
+            ))]
+            amevcntr15_el0: if features.contains(Feature::Amuv1) {
+                read_amevcntr15_el0()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "feat-amu"
+            ))]
+            amevcntr16_el0: if features.contains(Feature::Amuv1) {
+                read_amevcntr16_el0()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "feat-amu"
+            ))]
+            amevcntr17_el0: if features.contains(Feature::Amuv1) {
+                read_amevcntr17_el0()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "feat-amu"
+            ))]
+            amevcntr18_el0: if features.contains(Feature::Amuv1) {
+                read_amevcntr18_el0()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "feat-amu"
+            ))]
+            amevcntr19_el0: if features.contains(Feature::Amuv1) {
+                read_amevcntr19_el0()
+            } else {
+                Default::default()
+            },
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            amevtyper00: read_amevtyper00(),
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "feat-amu"
+            ))]
+            amevtyper00_el0: if features.contains(Feature::Amuv1) {
+                read_amevtyper00_el0()
+            } else {
+                Default::default()
+            },
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            amevtyper01: read_amevtyper01(),
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "feat-amu"
+            ))]
+            amevtyper01_el0: if features.contains(Feature::Amuv1) {
+                read_amevtyper01_el0()
+            } else {
+                Default::default()
+            },
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            amevtyper02: read_amevtyper02(),
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "feat-amu"
+            ))]
+            amevtyper02_el0: if features.contains(Feature::Amuv1) {
+                read_amevtyper02_el0()
+            } else {
+                Default::default()
+            },
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            amevtyper03: read_amevtyper03(),
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "feat-amu"
+            ))]
+            amevtyper03_el0: if features.contains(Feature::Amuv1) {
+                read_amevtyper03_el0()
+            } else {
+                Default::default()
+            },
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            amevtyper10: read_amevtyper10(),
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            amevtyper11: read_amevtyper11(),
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            amevtyper110: read_amevtyper110(),
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            amevtyper111: read_amevtyper111(),
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            amevtyper112: read_amevtyper112(),
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            amevtyper113: read_amevtyper113(),
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            amevtyper114: read_amevtyper114(),
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            amevtyper115: read_amevtyper115(),
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            amevtyper12: read_amevtyper12(),
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            amevtyper13: read_amevtyper13(),
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            amevtyper14: read_amevtyper14(),
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            amevtyper15: read_amevtyper15(),
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            amevtyper16: read_amevtyper16(),
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            amevtyper17: read_amevtyper17(),
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            amevtyper18: read_amevtyper18(),
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            amevtyper19: read_amevtyper19(),
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            amuserenr: read_amuserenr(),
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "feat-amu"
+            ))]
+            amuserenr_el0: if features.contains(Feature::Amuv1) {
+                read_amuserenr_el0()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            apiakeyhi_el1: if features.contains(Feature::Pauth) {
+                read_apiakeyhi_el1()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            apiakeylo_el1: if features.contains(Feature::Pauth) {
+                read_apiakeylo_el1()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+            brbcr_el2: if features.contains(Feature::Brbe) {
+                read_brbcr_el2()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            ccsidr: read_ccsidr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            ccsidr2: read_ccsidr2(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            ccsidr_el1: read_ccsidr_el1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            clidr: read_clidr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            clidr_el1: read_clidr_el1(),
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            cntfrq: read_cntfrq(),
+            #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+            cntfrq_el0: read_cntfrq_el0(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+            cnthctl: read_cnthctl(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+            cnthctl_el2: read_cnthctl_el2(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            cnthps_ctl: read_cnthps_ctl(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            cnthps_cval: read_cnthps_cval(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            cnthps_tval: read_cnthps_tval(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            cnthp_ctl: read_cnthp_ctl(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            cnthp_cval: read_cnthp_cval(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            cnthp_tval: read_cnthp_tval(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            cnthvs_ctl: read_cnthvs_ctl(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            cnthvs_cval: read_cnthvs_cval(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            cnthvs_tval: read_cnthvs_tval(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            cnthv_ctl: read_cnthv_ctl(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            cnthv_cval: read_cnthv_cval(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            cnthv_tval: read_cnthv_tval(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            cntkctl: read_cntkctl(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            cntkctl_el1: read_cntkctl_el1(),
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            cntpct: read_cntpct(),
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            cntpctss: read_cntpctss(),
+            #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+            cntpct_el0: read_cntpct_el0(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            cntps_ctl_el1: read_cntps_ctl_el1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            cntps_cval_el1: read_cntps_cval_el1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            cntps_tval_el1: read_cntps_tval_el1(),
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            cntp_ctl: read_cntp_ctl(),
+            #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+            cntp_ctl_el0: read_cntp_ctl_el0(),
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            cntp_cval: read_cntp_cval(),
+            #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+            cntp_cval_el0: read_cntp_cval_el0(),
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            cntp_tval: read_cntp_tval(),
+            #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+            cntp_tval_el0: read_cntp_tval_el0(),
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            cntvct: read_cntvct(),
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            cntvctss: read_cntvctss(),
+            #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+            cntvct_el0: read_cntvct_el0(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+            cntvoff: read_cntvoff(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+            cntvoff_el2: read_cntvoff_el2(),
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            cntv_ctl: read_cntv_ctl(),
+            #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+            cntv_ctl_el0: read_cntv_ctl_el0(),
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            cntv_cval: read_cntv_cval(),
+            #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+            cntv_cval_el0: read_cntv_cval_el0(),
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            cntv_tval: read_cntv_tval(),
+            #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+            cntv_tval_el0: read_cntv_tval_el0(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            contextidr: read_contextidr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            contextidr_el1: read_contextidr_el1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            cpacr: read_cpacr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            cpacr_el1: read_cpacr_el1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+            cptr_el2: read_cptr_el2(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
+            cptr_el3: read_cptr_el3(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            csselr: read_csselr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            csselr_el1: read_csselr_el1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            ctr: read_ctr(),
+            #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+            ctr_el0: read_ctr_el0(),
+            #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+            currentel: read_currentel(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            dacr: read_dacr(),
+            #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+            daif: read_daif(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            dbgauthstatus: read_dbgauthstatus(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            dbgclaimclr: read_dbgclaimclr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            dbgclaimset: read_dbgclaimset(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            dbgdccint: read_dbgdccint(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            dbgdevid: read_dbgdevid(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            dbgdevid1: read_dbgdevid1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            dbgdevid2: read_dbgdevid2(),
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            dbgdidr: read_dbgdidr(),
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            dbgdrar: read_dbgdrar(),
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            dbgdsar: read_dbgdsar(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            dbgdscrext: read_dbgdscrext(),
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            dbgdscrint: read_dbgdscrint(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            dbgdtrrxext: read_dbgdtrrxext(),
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            dbgdtrrxint: read_dbgdtrrxint(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            dbgdtrtxext: read_dbgdtrtxext(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            dbgosdlr: read_dbgosdlr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            dbgoseccr: read_dbgoseccr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            dbgoslsr: read_dbgoslsr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            dbgprcr: read_dbgprcr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            dbgvcr: read_dbgvcr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            dbgwfar: read_dbgwfar(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            dfar: read_dfar(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            dfsr: read_dfsr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            disr: read_disr(),
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "el1",
+                feature = "feat-ras"
+            ))]
+            disr_el1: if features.contains(Feature::Ras) {
+                read_disr_el1()
+            } else {
+                Default::default()
+            },
+            #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+            dit: if features.contains(Feature::Dit) {
+                read_dit()
+            } else {
+                Default::default()
+            },
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            dlr: read_dlr(),
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            dspsr: read_dspsr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            dspsr2: read_dspsr2(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            elr_el1: read_elr_el1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+            elr_el2: read_elr_el2(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+            elr_hyp: read_elr_hyp(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            erridr: read_erridr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            errselr: read_errselr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            erxaddr: read_erxaddr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            erxaddr2: read_erxaddr2(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            erxctlr: read_erxctlr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            erxctlr2: read_erxctlr2(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            erxfr: read_erxfr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            erxfr2: read_erxfr2(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            erxmisc0: read_erxmisc0(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            erxmisc1: read_erxmisc1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            erxmisc2: read_erxmisc2(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            erxmisc3: read_erxmisc3(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            erxmisc4: read_erxmisc4(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            erxmisc5: read_erxmisc5(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            erxmisc6: read_erxmisc6(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            erxmisc7: read_erxmisc7(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            erxstatus: read_erxstatus(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            esr_el1: read_esr_el1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+            esr_el2: read_esr_el2(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
+            esr_el3: read_esr_el3(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            far_el1: read_far_el1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+            far_el2: read_far_el2(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            fcseidr: read_fcseidr(),
+            #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+            fpcr: read_fpcr(),
+            #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+            fpmr: if features.contains(Feature::Fpmr) {
+                read_fpmr()
+            } else {
+                Default::default()
+            },
+            #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+            fpsr: read_fpsr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            gcr_el1: if features.contains(Feature::Mte2) {
+                read_gcr_el1()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            gcscr_el1: if features.contains(Feature::Gcs) {
+                read_gcscr_el1()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+            gcscr_el2: if features.contains(Feature::Gcs) {
+                read_gcscr_el2()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "el3",
+                feature = "feat-rme"
+            ))]
+            gpccr_el3: if features.contains(Feature::Rme) {
+                read_gpccr_el3()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "el3",
+                feature = "feat-rme"
+            ))]
+            gptbr_el3: if features.contains(Feature::Rme) {
+                read_gptbr_el3()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+            hacr: read_hacr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+            hacr_el2: read_hacr_el2(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+            hactlr: read_hactlr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+            hactlr2: read_hactlr2(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+            hadfsr: read_hadfsr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+            haifsr: read_haifsr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+            hamair0: read_hamair0(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+            hamair1: read_hamair1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+            hcptr: read_hcptr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+            hcr: read_hcr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+            hcr2: read_hcr2(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+            hcrx_el2: if features.contains(Feature::Hcx) {
+                read_hcrx_el2()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+            hcr_el2: read_hcr_el2(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+            hdcr: read_hdcr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+            hdfar: read_hdfar(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+            hifar: read_hifar(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+            hmair0: read_hmair0(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+            hmair1: read_hmair1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+            hpfar: read_hpfar(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+            hpfar_el2: read_hpfar_el2(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+            hrmr: read_hrmr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+            hsctlr: read_hsctlr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+            hsr: read_hsr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+            hstr: read_hstr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+            hstr_el2: read_hstr_el2(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+            htcr: read_htcr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+            htpidr: read_htpidr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+            htrfcr: read_htrfcr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+            httbr: read_httbr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+            hvbar: read_hvbar(),
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "el1",
+                feature = "feat-gic"
+            ))]
+            icc_ap0r0_el1: if features.contains(Feature::Gicv3) {
+                read_icc_ap0r0_el1()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "el1",
+                feature = "feat-gic"
+            ))]
+            icc_ap0r1_el1: if features.contains(Feature::Gicv3) {
+                read_icc_ap0r1_el1()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "el1",
+                feature = "feat-gic"
+            ))]
+            icc_ap0r2_el1: if features.contains(Feature::Gicv3) {
+                read_icc_ap0r2_el1()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "el1",
+                feature = "feat-gic"
+            ))]
+            icc_ap0r3_el1: if features.contains(Feature::Gicv3) {
+                read_icc_ap0r3_el1()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "el1",
+                feature = "feat-gic"
+            ))]
+            icc_ap1r0_el1: if features.contains(Feature::Gicv3) {
+                read_icc_ap1r0_el1()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "el1",
+                feature = "feat-gic"
+            ))]
+            icc_ap1r1_el1: if features.contains(Feature::Gicv3) {
+                read_icc_ap1r1_el1()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "el1",
+                feature = "feat-gic"
+            ))]
+            icc_ap1r2_el1: if features.contains(Feature::Gicv3) {
+                read_icc_ap1r2_el1()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "el1",
+                feature = "feat-gic"
+            ))]
+            icc_ap1r3_el1: if features.contains(Feature::Gicv3) {
+                read_icc_ap1r3_el1()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            icc_bpr0: read_icc_bpr0(),
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "el1",
+                feature = "feat-gic"
+            ))]
+            icc_bpr0_el1: if features.contains(Feature::Gicv3) {
+                read_icc_bpr0_el1()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            icc_bpr1: read_icc_bpr1(),
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "el1",
+                feature = "feat-gic"
+            ))]
+            icc_bpr1_el1: if features.contains(Feature::Gicv3) {
+                read_icc_bpr1_el1()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            icc_ctlr: read_icc_ctlr(),
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "el1",
+                feature = "feat-gic"
+            ))]
+            icc_ctlr_el1: if features.contains(Feature::Gicv3) {
+                read_icc_ctlr_el1()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "el3",
+                feature = "feat-gic"
+            ))]
+            icc_ctlr_el3: if features.contains(Feature::Gicv3) {
+                read_icc_ctlr_el3()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            icc_hppir0: read_icc_hppir0(),
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "el1",
+                feature = "feat-gic"
+            ))]
+            icc_hppir0_el1: if features.contains(Feature::Gicv3) {
+                read_icc_hppir0_el1()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            icc_hppir1: read_icc_hppir1(),
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "el1",
+                feature = "feat-gic"
+            ))]
+            icc_hppir1_el1: if features.contains(Feature::Gicv3) {
+                read_icc_hppir1_el1()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+            icc_hsre: read_icc_hsre(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            icc_igrpen0: read_icc_igrpen0(),
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "el1",
+                feature = "feat-gic"
+            ))]
+            icc_igrpen0_el1: if features.contains(Feature::Gicv3) {
+                read_icc_igrpen0_el1()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            icc_igrpen1: read_icc_igrpen1(),
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "el1",
+                feature = "feat-gic"
+            ))]
+            icc_igrpen1_el1: if features.contains(Feature::Gicv3) {
+                read_icc_igrpen1_el1()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "el3",
+                feature = "feat-gic"
+            ))]
+            icc_igrpen1_el3: if features.contains(Feature::Gicv3) {
+                read_icc_igrpen1_el3()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el3"))]
+            icc_mctlr: read_icc_mctlr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el3"))]
+            icc_mgrpen1: read_icc_mgrpen1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el3"))]
+            icc_msre: read_icc_msre(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            icc_pmr: read_icc_pmr(),
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "el1",
+                feature = "feat-gic"
+            ))]
+            icc_pmr_el1: if features.contains(Feature::Gicv3) {
+                read_icc_pmr_el1()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            icc_rpr: read_icc_rpr(),
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "el1",
+                feature = "feat-gic"
+            ))]
+            icc_rpr_el1: if features.contains(Feature::Gicv3) {
+                read_icc_rpr_el1()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            icc_sre: read_icc_sre(),
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "el1",
+                feature = "feat-gic"
+            ))]
+            icc_sre_el1: if features.contains(Feature::Gicv3) {
+                read_icc_sre_el1()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "el2",
+                feature = "feat-gic"
+            ))]
+            icc_sre_el2: if features.contains(Feature::Gicv3) {
+                read_icc_sre_el2()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "el3",
+                feature = "feat-gic"
+            ))]
+            icc_sre_el3: if features.contains(Feature::Gicv3) {
+                read_icc_sre_el3()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "el2",
+                feature = "feat-gic"
+            ))]
+            ich_hcr_el2: if features.contains(Feature::Gicv3) {
+                read_ich_hcr_el2()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "el2",
+                feature = "feat-gic"
+            ))]
+            ich_vmcr_el2: if features.contains(Feature::Gicv3) {
+                read_ich_vmcr_el2()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            id_aa64dfr0_el1: read_id_aa64dfr0_el1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            id_aa64dfr1_el1: read_id_aa64dfr1_el1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            id_aa64isar1_el1: read_id_aa64isar1_el1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            id_aa64isar2_el1: read_id_aa64isar2_el1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            id_aa64mmfr0_el1: read_id_aa64mmfr0_el1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            id_aa64mmfr1_el1: read_id_aa64mmfr1_el1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            id_aa64mmfr2_el1: read_id_aa64mmfr2_el1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            id_aa64mmfr3_el1: read_id_aa64mmfr3_el1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            id_aa64mmfr4_el1: read_id_aa64mmfr4_el1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            id_aa64pfr0_el1: read_id_aa64pfr0_el1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            id_aa64pfr1_el1: read_id_aa64pfr1_el1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            id_aa64pfr2_el1: read_id_aa64pfr2_el1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            id_aa64smfr0_el1: read_id_aa64smfr0_el1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            id_afr0: read_id_afr0(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            id_dfr0: read_id_dfr0(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            id_dfr1: read_id_dfr1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            id_isar0: read_id_isar0(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            id_isar1: read_id_isar1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            id_isar2: read_id_isar2(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            id_isar3: read_id_isar3(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            id_isar4: read_id_isar4(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            id_isar5: read_id_isar5(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            id_isar6: read_id_isar6(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            id_mmfr0: read_id_mmfr0(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            id_mmfr1: read_id_mmfr1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            id_mmfr2: read_id_mmfr2(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            id_mmfr3: read_id_mmfr3(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            id_mmfr4: read_id_mmfr4(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            id_mmfr5: read_id_mmfr5(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            id_pfr0: read_id_pfr0(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            id_pfr1: read_id_pfr1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            id_pfr2: read_id_pfr2(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            ifar: read_ifar(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            ifsr: read_ifsr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            isr: read_isr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            isr_el1: read_isr_el1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            jidr: read_jidr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            jmcr: read_jmcr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            joscr: read_joscr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            mair0: read_mair0(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            mair1: read_mair1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            mair_el1: read_mair_el1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+            mair_el2: read_mair_el2(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
+            mair_el3: read_mair_el3(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            mdccint_el1: read_mdccint_el1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+            mdcr_el2: read_mdcr_el2(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
+            mdcr_el3: read_mdcr_el3(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            mdscr_el1: read_mdscr_el1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            midr: read_midr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            midr_el1: read_midr_el1(),
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "el2",
+                feature = "feat-mpam"
+            ))]
+            mpam2_el2: if features.contains(Feature::Mpam) {
+                read_mpam2_el2()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "el3",
+                feature = "feat-mpam"
+            ))]
+            mpam3_el3: if features.contains(Feature::Mpam) {
+                read_mpam3_el3()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "el2",
+                feature = "feat-mpam"
+            ))]
+            mpamhcr_el2: if features.contains(Feature::Mpam) {
+                read_mpamhcr_el2()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "el1",
+                feature = "feat-mpam"
+            ))]
+            mpamidr_el1: if features.contains(Feature::Mpam) {
+                read_mpamidr_el1()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "el2",
+                feature = "feat-mpam"
+            ))]
+            mpamvpm0_el2: if features.contains(Feature::Mpam) {
+                read_mpamvpm0_el2()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "el2",
+                feature = "feat-mpam"
+            ))]
+            mpamvpm1_el2: if features.contains(Feature::Mpam) {
+                read_mpamvpm1_el2()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "el2",
+                feature = "feat-mpam"
+            ))]
+            mpamvpm2_el2: if features.contains(Feature::Mpam) {
+                read_mpamvpm2_el2()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "el2",
+                feature = "feat-mpam"
+            ))]
+            mpamvpm3_el2: if features.contains(Feature::Mpam) {
+                read_mpamvpm3_el2()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "el2",
+                feature = "feat-mpam"
+            ))]
+            mpamvpm4_el2: if features.contains(Feature::Mpam) {
+                read_mpamvpm4_el2()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "el2",
+                feature = "feat-mpam"
+            ))]
+            mpamvpm5_el2: if features.contains(Feature::Mpam) {
+                read_mpamvpm5_el2()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "el2",
+                feature = "feat-mpam"
+            ))]
+            mpamvpm6_el2: if features.contains(Feature::Mpam) {
+                read_mpamvpm6_el2()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "el2",
+                feature = "feat-mpam"
+            ))]
+            mpamvpm7_el2: if features.contains(Feature::Mpam) {
+                read_mpamvpm7_el2()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "el2",
+                feature = "feat-mpam"
+            ))]
+            mpamvpmv_el2: if features.contains(Feature::Mpam) {
+                read_mpamvpmv_el2()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            mpidr: read_mpidr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            mpidr_el1: read_mpidr_el1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            mvbar: read_mvbar(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            nmrr: read_nmrr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            nsacr: read_nsacr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            par: read_par(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            par_el1: read_par_el1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            pfar_el1: if features.contains(Feature::Pfar) {
+                read_pfar_el1()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+            pfar_el2: if features.contains(Feature::Pfar) {
+                read_pfar_el2()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            pire0_el1: if features.contains(Feature::S1pie) {
+                read_pire0_el1()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+            pire0_el2: if features.contains(Feature::S1pie) {
+                read_pire0_el2()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            pir_el1: if features.contains(Feature::S1pie) {
+                read_pir_el1()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+            pir_el2: if features.contains(Feature::S1pie) {
+                read_pir_el2()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
+            pir_el3: if features.contains(Feature::S1pie) {
+                read_pir_el3()
+            } else {
+                Default::default()
+            },
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            pmccfiltr: read_pmccfiltr(),
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            pmccntr: read_pmccntr(),
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            pmceid0: read_pmceid0(),
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            pmceid1: read_pmceid1(),
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            pmceid2: read_pmceid2(),
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            pmceid3: read_pmceid3(),
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            pmcntenclr: read_pmcntenclr(),
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            pmcntenset: read_pmcntenset(),
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            pmcr: read_pmcr(),
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "feat-pmu"
+            ))]
+            pmcr_el0: if features.contains(Feature::Pmuv3) {
+                read_pmcr_el0()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            pmintenclr: read_pmintenclr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            pmintenset: read_pmintenset(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            pmmir: read_pmmir(),
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            pmovsr: read_pmovsr(),
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            pmovsset: read_pmovsset(),
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            pmselr: read_pmselr(),
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            pmuserenr: read_pmuserenr(),
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            pmxevtyper: read_pmxevtyper(),
+            #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+            por_el0: if features.contains(Feature::S1poe) {
+                read_por_el0()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            por_el1: if features.contains(Feature::S1poe) {
+                read_por_el1()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+            por_el2: if features.contains(Feature::S1poe) {
+                read_por_el2()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
+            por_el3: if features.contains(Feature::S1poe) {
+                read_por_el3()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            prrr: read_prrr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            revidr: read_revidr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            rgsr_el1: if features.contains(Feature::Mte2) {
+                read_rgsr_el1()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            rmr: read_rmr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            rvbar: read_rvbar(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+            s2pir_el2: if features.contains(Feature::S2pie) {
+                read_s2pir_el2()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            s2por_el1: if features.contains(Feature::S2poe) {
+                read_s2por_el1()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            scr: read_scr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
+            scr_el3: read_scr_el3(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            sctlr: read_sctlr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            sctlr2_el1: if features.contains(Feature::Sctlr2) {
+                read_sctlr2_el1()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+            sctlr2_el2: if features.contains(Feature::Sctlr2) {
+                read_sctlr2_el2()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
+            sctlr2_el3: if features.contains(Feature::Sctlr2) {
+                read_sctlr2_el3()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            sctlr_el1: read_sctlr_el1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+            sctlr_el2: read_sctlr_el2(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
+            sctlr_el3: read_sctlr_el3(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            sdcr: read_sdcr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            sder: read_sder(),
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "el3",
+                feature = "feat-sve"
+            ))]
+            smcr_el3: if features.contains(Feature::Sme) {
+                read_smcr_el3()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            spsr_el1: read_spsr_el1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+            spsr_el2: read_spsr_el2(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
+            spsr_el3: read_spsr_el3(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            sp_el1: read_sp_el1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+            sp_el2: read_sp_el2(),
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "feat-sve"
+            ))]
+            svcr: if features.contains(Feature::Sme) {
+                read_svcr()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            tcmtr: read_tcmtr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            tcr2_el1: if features.contains(Feature::Tcr2) {
+                read_tcr2_el1()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            tcr_el1: read_tcr_el1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+            tcr_el2: read_tcr_el2(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
+            tcr_el3: read_tcr_el3(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            tfsre0_el1: if features.contains(Feature::Mte2) {
+                read_tfsre0_el1()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            tfsr_el1: if features.contains(Feature::Mte2) {
+                read_tfsr_el1()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+            tfsr_el2: if features.contains(Feature::Mte2) {
+                read_tfsr_el2()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            tlbtr: read_tlbtr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            tpidrprw: read_tpidrprw(),
+            #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+            tpidrro_el0: read_tpidrro_el0(),
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            tpidruro: read_tpidruro(),
+            #[cfg(any(test, feature = "fakes", target_arch = "arm"))]
+            tpidrurw: read_tpidrurw(),
+            #[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+            tpidr_el0: read_tpidr_el0(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            tpidr_el1: read_tpidr_el1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+            tpidr_el2: read_tpidr_el2(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
+            tpidr_el3: read_tpidr_el3(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            trfcr: read_trfcr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            ttbcr: read_ttbcr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            ttbcr2: read_ttbcr2(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            ttbr0: read_ttbr0(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            ttbr0_el1: read_ttbr0_el1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+            ttbr0_el2: read_ttbr0_el2(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el3"))]
+            ttbr0_el3: read_ttbr0_el3(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            ttbr1: read_ttbr1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            ttbr1_el1: read_ttbr1_el1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+            ttbr1_el2: read_ttbr1_el2(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            vbar: read_vbar(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+            vbar_el1: read_vbar_el1(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+            vbar_el2: read_vbar_el2(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+            vdfsr: read_vdfsr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            vdisr: read_vdisr(),
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "el2",
+                feature = "feat-ras"
+            ))]
+            vdisr_el2: if features.contains(Feature::Ras) {
+                read_vdisr_el2()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            vmpidr: read_vmpidr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+            vmpidr_el2: read_vmpidr_el2(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el1"))]
+            vpidr: read_vpidr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+            vpidr_el2: read_vpidr_el2(),
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "el2",
+                feature = "feat-ras"
+            ))]
+            vsesr_el2: if features.contains(Feature::Ras) {
+                read_vsesr_el2()
+            } else {
+                Default::default()
+            },
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+            vtcr: read_vtcr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+            vtcr_el2: read_vtcr_el2(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "arm"), feature = "el2"))]
+            vttbr: read_vttbr(),
+            #[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+            vttbr_el2: read_vttbr_el2(),
+            #[cfg(all(
+                any(test, feature = "fakes", target_arch = "aarch64"),
+                feature = "el3",
+                feature = "feat-sve"
+            ))]
+            zcr_el3: if features.contains(Feature::Sve) {
+                read_zcr_el3()
+            } else {
+                Default::default()
+            },
+        }
+    }
+}
+
+/// Deserialises the version of a snapshot, rejecting versions newer than
+/// [`RegisterSnapshot::VERSION`] as they might store registers differently.
+#[cfg(feature = "serde")]
+fn deserialize_version<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<u32, D::Error> {
+    let version = <u32 as serde::Deserialize>::deserialize(deserializer)?;
+    if version > RegisterSnapshot::VERSION {
+        return Err(serde::de::Error::custom(format_args!(
+            "unsupported snapshot version {version}, expected at most {}",
+            RegisterSnapshot::VERSION
+        )));
+    }
+    Ok(version)
+}
@@ -0,0 +1,551 @@
+// SPDX-FileCopyrightText: Copyright The arm-sysregs Contributors.
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Scalable vector and matrix extensions system registers.
+
+// This file is generated, do not edit manually.
+
+// Depending on the enabled features, the module might not use all of these imports.
+#![allow(unused_imports)]
+
+use crate::*;
+use bitflags::bitflags;
+use core::fmt::{self, Display, Formatter};
+
+#[cfg(all(feature = "el3", feature = "feat-sve"))]
+bitflags! {
+    /// `SMCR_EL3` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct SmcrEl3: u64 {
+        /// `EZT0` bit.
+        const EZT0 = 1 << 30;
+        /// `FA64` bit.
+        const FA64 = 1 << 31;
+    }
+}
+
+#[cfg(all(feature = "el3", feature = "feat-sve"))]
+impl SmcrEl3 {
+    /// Descriptor of the `LEN` field, for use with [`FieldAccess`].
+    pub const LEN_FIELD: Field<Self, u8, 0, 4> = Field::new("SMCR_EL3", "LEN");
+    /// Descriptor of the `EZT0` field, for use with [`FieldAccess`].
+    pub const EZT0_FIELD: Field<Self, bool, 30, 1> = Field::new("SMCR_EL3", "EZT0");
+    /// Descriptor of the `FA64` field, for use with [`FieldAccess`].
+    pub const FA64_FIELD: Field<Self, bool, 31, 1> = Field::new("SMCR_EL3", "FA64");
+
+    /// Returns the value of the `LEN` field.
+    #[allow(clippy::len_without_is_empty)]
+    pub const fn len(self) -> u8 {
+        Self::LEN_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `LEN` field.
+    pub const fn set_len(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::LEN_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `LEN` field set to the given value.
+    pub const fn with_len(mut self, value: u8) -> Self {
+        self.set_len(value);
+        self
+    }
+
+    /// Sets the value of the `LEN` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_len(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_len(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `LEN` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_len(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::LEN_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(all(feature = "el3", feature = "feat-sve"))]
+impl Display for SmcrEl3 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("SmcrEl3");
+        let bits = self.bits();
+        s.field("LEN", &Self::LEN_FIELD.extract(bits));
+        s.field("EZT0", &Self::EZT0_FIELD.extract(bits));
+        s.field("FA64", &Self::FA64_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(all(feature = "el3", feature = "feat-sve"))]
+impl defmt::Format for SmcrEl3 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "SmcrEl3 {{ LEN: {=u64}, EZT0: {=u64}, FA64: {=u64} }}",
+            Self::LEN_FIELD.extract(self.bits()),
+            Self::EZT0_FIELD.extract(self.bits()),
+            Self::FA64_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el3", feature = "feat-sve"))]
+impl serde::Serialize for SmcrEl3 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el3", feature = "feat-sve"))]
+impl<'de> serde::Deserialize<'de> for SmcrEl3 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(all(feature = "el3", feature = "feat-sve"))]
+impl SmcrEl3 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("LEN"),
+                old: Self::LEN_FIELD.extract(self.bits()),
+                new: Self::LEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EZT0"),
+                old: Self::EZT0_FIELD.extract(self.bits()),
+                new: Self::EZT0_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("FA64"),
+                old: Self::FA64_FIELD.extract(self.bits()),
+                new: Self::FA64_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_0011_1111_1111_1111_1111_1111_1111_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_0011_1111_1111_1111_1111_1111_1111_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(all(feature = "el3", feature = "feat-sve"))]
+impl SmcrEl3 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(all(feature = "el3", feature = "feat-sve"))]
+impl SmcrEl3 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                Self::LEN_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sme),
+                UnimplementedField {
+                    register: "SMCR_EL3",
+                    field: "LEN",
+                    requires: "FEAT_SME",
+                },
+            ),
+            (
+                Self::EZT0_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sme),
+                UnimplementedField {
+                    register: "SMCR_EL3",
+                    field: "EZT0",
+                    requires: "FEAT_SME",
+                },
+            ),
+            (
+                Self::FA64_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sme),
+                UnimplementedField {
+                    register: "SMCR_EL3",
+                    field: "FA64",
+                    requires: "FEAT_SME",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(feature = "feat-sve")]
+bitflags! {
+    /// `SVCR` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct Svcr: u64 {
+        /// `SM` bit.
+        const SM = 1 << 0;
+        /// `ZA` bit.
+        const ZA = 1 << 1;
+    }
+}
+
+#[cfg(feature = "feat-sve")]
+impl Svcr {
+    /// Descriptor of the `SM` field, for use with [`FieldAccess`].
+    pub const SM_FIELD: Field<Self, bool, 0, 1> = Field::new("SVCR", "SM");
+    /// Descriptor of the `ZA` field, for use with [`FieldAccess`].
+    pub const ZA_FIELD: Field<Self, bool, 1, 1> = Field::new("SVCR", "ZA");
+}
+
+#[cfg(feature = "feat-sve")]
+impl Display for Svcr {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("Svcr");
+        let bits = self.bits();
+        s.field("SM", &Self::SM_FIELD.extract(bits));
+        s.field("ZA", &Self::ZA_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "feat-sve")]
+impl defmt::Format for Svcr {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "Svcr {{ SM: {=u64}, ZA: {=u64} }}",
+            Self::SM_FIELD.extract(self.bits()),
+            Self::ZA_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "feat-sve")]
+impl serde::Serialize for Svcr {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "feat-sve")]
+impl<'de> serde::Deserialize<'de> for Svcr {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "feat-sve")]
+impl Svcr {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("SM"),
+                old: Self::SM_FIELD.extract(self.bits()),
+                new: Self::SM_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ZA"),
+                old: Self::ZA_FIELD.extract(self.bits()),
+                new: Self::ZA_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1100,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1100,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "feat-sve")]
+impl Svcr {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "feat-sve")]
+impl Svcr {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [
+            (
+                Self::SM_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sme),
+                UnimplementedField {
+                    register: "SVCR",
+                    field: "SM",
+                    requires: "FEAT_SME",
+                },
+            ),
+            (
+                Self::ZA_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sme),
+                UnimplementedField {
+                    register: "SVCR",
+                    field: "ZA",
+                    requires: "FEAT_SME",
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(all(feature = "el3", feature = "feat-sve"))]
+bitflags! {
+    /// `ZCR_EL3` system register value.
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct ZcrEl3: u64 {
+    }
+}
+
+#[cfg(all(feature = "el3", feature = "feat-sve"))]
+impl ZcrEl3 {
+    /// Descriptor of the `LEN` field, for use with [`FieldAccess`].
+    pub const LEN_FIELD: Field<Self, u8, 0, 4> = Field::new("ZCR_EL3", "LEN");
+
+    /// Returns the value of the `LEN` field.
+    #[allow(clippy::len_without_is_empty)]
+    pub const fn len(self) -> u8 {
+        Self::LEN_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `LEN` field.
+    pub const fn set_len(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::LEN_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `LEN` field set to the given value.
+    pub const fn with_len(mut self, value: u8) -> Self {
+        self.set_len(value);
+        self
+    }
+
+    /// Sets the value of the `LEN` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_len(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_len(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `LEN` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_len(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::LEN_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(all(feature = "el3", feature = "feat-sve"))]
+impl Display for ZcrEl3 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("ZcrEl3");
+        let bits = self.bits();
+        s.field("LEN", &Self::LEN_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(all(feature = "el3", feature = "feat-sve"))]
+impl defmt::Format for ZcrEl3 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "ZcrEl3 {{ LEN: {=u64} }}",
+            Self::LEN_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el3", feature = "feat-sve"))]
+impl serde::Serialize for ZcrEl3 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(all(feature = "el3", feature = "feat-sve"))]
+impl<'de> serde::Deserialize<'de> for ZcrEl3 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(all(feature = "el3", feature = "feat-sve"))]
+impl ZcrEl3 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("LEN"),
+                old: Self::LEN_FIELD.extract(self.bits()),
+                new: Self::LEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(all(feature = "el3", feature = "feat-sve"))]
+impl ZcrEl3 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(all(feature = "el3", feature = "feat-sve"))]
+impl ZcrEl3 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        [(
+            Self::LEN_FIELD.extract(self.bits()) != 0 && !features.contains(Feature::Sve),
+            UnimplementedField {
+                register: "ZCR_EL3",
+                field: "LEN",
+                requires: "FEAT_SVE",
+            },
+        )]
+        .into_iter()
+        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))
+    }
+}
+
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el3",
+    feature = "feat-sve"
+))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `SMCR_EL3` system register.
+    smcr_el3: s3_6_c1_c2_6, u64: SmcrEl3, safe_read, fake::SYSREGS
+}
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "feat-sve"
+))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `SVCR` system register.
+    svcr: s3_3_c4_c2_2, u64: Svcr, safe_read, fake::SYSREGS
+}
+#[cfg(all(
+    any(test, feature = "fakes", target_arch = "aarch64"),
+    feature = "el3",
+    feature = "feat-sve"
+))]
+read_write_sysreg! {
+    /// # Safety
+    ///
+    /// The caller must ensure that `value` is a correct and safe value to write to the `ZCR_EL3` system register.
+    zcr_el3: s3_6_c1_c2_0, u64: ZcrEl3, safe_read, fake::SYSREGS
+}
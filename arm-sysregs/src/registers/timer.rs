@@ -0,0 +1,3767 @@
+// SPDX-FileCopyrightText: Copyright The arm-sysregs Contributors.
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Generic timer system registers.
+
+// This file is generated, do not edit manually.
+
+// Depending on the enabled features, the module might not use all of these imports.
+#![allow(unused_imports)]
+
+use crate::*;
+use bitflags::bitflags;
+use core::fmt::{self, Display, Formatter};
+
+bitflags! {
+    /// `CNTFRQ_EL0` system register value.
+    ///
+    /// Counter-timer Frequency Register
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct CntfrqEl0: u64 {
+    }
+}
+
+impl CntfrqEl0 {
+    /// Descriptor of the `ClockFreq` field, for use with [`FieldAccess`].
+    pub const CLOCKFREQ_FIELD: Field<Self, u32, 0, 32> = Field::new("CNTFRQ_EL0", "ClockFreq");
+
+    /// Returns the value of the `ClockFreq` field.
+    pub const fn clockfreq(self) -> u32 {
+        Self::CLOCKFREQ_FIELD.extract(self.bits()) as u32
+    }
+
+    /// Sets the value of the `ClockFreq` field.
+    pub const fn set_clockfreq(&mut self, value: u32) {
+        *self = Self::from_bits_retain(Self::CLOCKFREQ_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `ClockFreq` field set to the given value.
+    pub const fn with_clockfreq(mut self, value: u32) -> Self {
+        self.set_clockfreq(value);
+        self
+    }
+
+    /// Sets the value of the `ClockFreq` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_clockfreq(&mut self, value: u32) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_clockfreq(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `ClockFreq` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_clockfreq(self, value: u32) -> Result<Self, FieldOverflow> {
+        match Self::CLOCKFREQ_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl Display for CntfrqEl0 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("CntfrqEl0");
+        let bits = self.bits();
+        s.field(
+            "ClockFreq",
+            &format_args!("{:#x}", Self::CLOCKFREQ_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for CntfrqEl0 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "CntfrqEl0 {{ ClockFreq: {=u64:#x} }}",
+            Self::CLOCKFREQ_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for CntfrqEl0 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CntfrqEl0 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+impl CntfrqEl0 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("ClockFreq"),
+                old: Self::CLOCKFREQ_FIELD.extract(self.bits()),
+                new: Self::CLOCKFREQ_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_0000_0000_0000_0000_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_0000_0000_0000_0000_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+impl CntfrqEl0 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+impl CntfrqEl0 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `CNTHCTL_EL2` system register value.
+    ///
+    /// Counter-timer Hypervisor Control Register
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct CnthctlEl2: u64 {
+        /// `EL0PCTEN` bit.
+        const EL0PCTEN = 1 << 0;
+        /// `EL0VCTEN` bit.
+        const EL0VCTEN = 1 << 1;
+        /// `EL1PCEN` bit.
+        const EL1PCEN = 1 << 1;
+        /// `EVNTEN` bit.
+        const EVNTEN = 1 << 2;
+        /// `EVNTDIR` bit.
+        const EVNTDIR = 1 << 3;
+        /// `EL0VTEN` bit.
+        const EL0VTEN = 1 << 8;
+        /// `EL0PTEN` bit.
+        const EL0PTEN = 1 << 9;
+        /// `EL1PTEN` bit.
+        const EL1PTEN = 1 << 11;
+        /// `ECV` bit.
+        const ECV = 1 << 12;
+        /// `EL1TVT` bit.
+        const EL1TVT = 1 << 13;
+        /// `EL1TVCT` bit.
+        const EL1TVCT = 1 << 14;
+        /// `EL1NVPCT` bit.
+        const EL1NVPCT = 1 << 15;
+        /// `EL1NVVCT` bit.
+        const EL1NVVCT = 1 << 16;
+        /// `EVNTIS` bit.
+        const EVNTIS = 1 << 17;
+        /// `CNTVMASK` bit.
+        const CNTVMASK = 1 << 18;
+        /// `CNTPMASK` bit.
+        const CNTPMASK = 1 << 19;
+    }
+}
+
+#[cfg(feature = "el2")]
+impl CnthctlEl2 {
+    /// Descriptor of the `EL0PCTEN` field, for use with [`FieldAccess`].
+    pub const EL0PCTEN_FIELD: Field<Self, bool, 0, 1> = Field::new("CNTHCTL_EL2", "EL0PCTEN");
+    /// Descriptor of the `EL0VCTEN` field, for use with [`FieldAccess`].
+    pub const EL0VCTEN_FIELD: Field<Self, bool, 1, 1> = Field::new("CNTHCTL_EL2", "EL0VCTEN");
+    /// Descriptor of the `EL1PCEN` field, for use with [`FieldAccess`].
+    pub const EL1PCEN_FIELD: Field<Self, bool, 1, 1> = Field::new("CNTHCTL_EL2", "EL1PCEN");
+    /// Descriptor of the `EVNTEN` field, for use with [`FieldAccess`].
+    pub const EVNTEN_FIELD: Field<Self, bool, 2, 1> = Field::new("CNTHCTL_EL2", "EVNTEN");
+    /// Descriptor of the `EVNTDIR` field, for use with [`FieldAccess`].
+    pub const EVNTDIR_FIELD: Field<Self, bool, 3, 1> = Field::new("CNTHCTL_EL2", "EVNTDIR");
+    /// Descriptor of the `EVNTI` field, for use with [`FieldAccess`].
+    pub const EVNTI_FIELD: Field<Self, u8, 4, 4> = Field::new("CNTHCTL_EL2", "EVNTI");
+    /// Descriptor of the `EL0VTEN` field, for use with [`FieldAccess`].
+    pub const EL0VTEN_FIELD: Field<Self, bool, 8, 1> = Field::new("CNTHCTL_EL2", "EL0VTEN");
+    /// Descriptor of the `EL0PTEN` field, for use with [`FieldAccess`].
+    pub const EL0PTEN_FIELD: Field<Self, bool, 9, 1> = Field::new("CNTHCTL_EL2", "EL0PTEN");
+    /// Descriptor of the `EL1PTEN` field, for use with [`FieldAccess`].
+    pub const EL1PTEN_FIELD: Field<Self, bool, 11, 1> = Field::new("CNTHCTL_EL2", "EL1PTEN");
+    /// Descriptor of the `ECV` field, for use with [`FieldAccess`].
+    pub const ECV_FIELD: Field<Self, bool, 12, 1> = Field::new("CNTHCTL_EL2", "ECV");
+    /// Descriptor of the `EL1TVT` field, for use with [`FieldAccess`].
+    pub const EL1TVT_FIELD: Field<Self, bool, 13, 1> = Field::new("CNTHCTL_EL2", "EL1TVT");
+    /// Descriptor of the `EL1TVCT` field, for use with [`FieldAccess`].
+    pub const EL1TVCT_FIELD: Field<Self, bool, 14, 1> = Field::new("CNTHCTL_EL2", "EL1TVCT");
+    /// Descriptor of the `EL1NVPCT` field, for use with [`FieldAccess`].
+    pub const EL1NVPCT_FIELD: Field<Self, bool, 15, 1> = Field::new("CNTHCTL_EL2", "EL1NVPCT");
+    /// Descriptor of the `EL1NVVCT` field, for use with [`FieldAccess`].
+    pub const EL1NVVCT_FIELD: Field<Self, bool, 16, 1> = Field::new("CNTHCTL_EL2", "EL1NVVCT");
+    /// Descriptor of the `EVNTIS` field, for use with [`FieldAccess`].
+    pub const EVNTIS_FIELD: Field<Self, bool, 17, 1> = Field::new("CNTHCTL_EL2", "EVNTIS");
+    /// Descriptor of the `CNTVMASK` field, for use with [`FieldAccess`].
+    pub const CNTVMASK_FIELD: Field<Self, bool, 18, 1> = Field::new("CNTHCTL_EL2", "CNTVMASK");
+    /// Descriptor of the `CNTPMASK` field, for use with [`FieldAccess`].
+    pub const CNTPMASK_FIELD: Field<Self, bool, 19, 1> = Field::new("CNTHCTL_EL2", "CNTPMASK");
+
+    /// Returns the value of the `EVNTI` field.
+    pub const fn evnti(self) -> u8 {
+        Self::EVNTI_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `EVNTI` field.
+    pub const fn set_evnti(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::EVNTI_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `EVNTI` field set to the given value.
+    pub const fn with_evnti(mut self, value: u8) -> Self {
+        self.set_evnti(value);
+        self
+    }
+
+    /// Sets the value of the `EVNTI` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_evnti(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_evnti(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `EVNTI` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_evnti(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::EVNTI_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Display for CnthctlEl2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("CnthctlEl2");
+        let bits = self.bits();
+        s.field("EL0PCTEN", &Self::EL0PCTEN_FIELD.extract(bits));
+        s.field("EL0VCTEN", &Self::EL0VCTEN_FIELD.extract(bits));
+        s.field("EL1PCEN", &Self::EL1PCEN_FIELD.extract(bits));
+        s.field("EVNTEN", &Self::EVNTEN_FIELD.extract(bits));
+        s.field("EVNTDIR", &Self::EVNTDIR_FIELD.extract(bits));
+        s.field("EVNTI", &Self::EVNTI_FIELD.extract(bits));
+        s.field("EL0VTEN", &Self::EL0VTEN_FIELD.extract(bits));
+        s.field("EL0PTEN", &Self::EL0PTEN_FIELD.extract(bits));
+        s.field("EL1PTEN", &Self::EL1PTEN_FIELD.extract(bits));
+        s.field("ECV", &Self::ECV_FIELD.extract(bits));
+        s.field("EL1TVT", &Self::EL1TVT_FIELD.extract(bits));
+        s.field("EL1TVCT", &Self::EL1TVCT_FIELD.extract(bits));
+        s.field("EL1NVPCT", &Self::EL1NVPCT_FIELD.extract(bits));
+        s.field("EL1NVVCT", &Self::EL1NVVCT_FIELD.extract(bits));
+        s.field("EVNTIS", &Self::EVNTIS_FIELD.extract(bits));
+        s.field("CNTVMASK", &Self::CNTVMASK_FIELD.extract(bits));
+        s.field("CNTPMASK", &Self::CNTPMASK_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for CnthctlEl2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "CnthctlEl2 {{ EL0PCTEN: {=u64}, EL0VCTEN: {=u64}, EL1PCEN: {=u64}, EVNTEN: {=u64}, EVNTDIR: {=u64}, EVNTI: {=u64}, EL0VTEN: {=u64}, EL0PTEN: {=u64}, EL1PTEN: {=u64}, ECV: {=u64}, EL1TVT: {=u64}, EL1TVCT: {=u64}, EL1NVPCT: {=u64}, EL1NVVCT: {=u64}, EVNTIS: {=u64}, CNTVMASK: {=u64}, CNTPMASK: {=u64} }}",
+            Self::EL0PCTEN_FIELD.extract(self.bits()),
+            Self::EL0VCTEN_FIELD.extract(self.bits()),
+            Self::EL1PCEN_FIELD.extract(self.bits()),
+            Self::EVNTEN_FIELD.extract(self.bits()),
+            Self::EVNTDIR_FIELD.extract(self.bits()),
+            Self::EVNTI_FIELD.extract(self.bits()),
+            Self::EL0VTEN_FIELD.extract(self.bits()),
+            Self::EL0PTEN_FIELD.extract(self.bits()),
+            Self::EL1PTEN_FIELD.extract(self.bits()),
+            Self::ECV_FIELD.extract(self.bits()),
+            Self::EL1TVT_FIELD.extract(self.bits()),
+            Self::EL1TVCT_FIELD.extract(self.bits()),
+            Self::EL1NVPCT_FIELD.extract(self.bits()),
+            Self::EL1NVVCT_FIELD.extract(self.bits()),
+            Self::EVNTIS_FIELD.extract(self.bits()),
+            Self::CNTVMASK_FIELD.extract(self.bits()),
+            Self::CNTPMASK_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for CnthctlEl2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for CnthctlEl2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl CnthctlEl2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("EL0PCTEN"),
+                old: Self::EL0PCTEN_FIELD.extract(self.bits()),
+                new: Self::EL0PCTEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EL0VCTEN"),
+                old: Self::EL0VCTEN_FIELD.extract(self.bits()),
+                new: Self::EL0VCTEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EL1PCEN"),
+                old: Self::EL1PCEN_FIELD.extract(self.bits()),
+                new: Self::EL1PCEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EVNTEN"),
+                old: Self::EVNTEN_FIELD.extract(self.bits()),
+                new: Self::EVNTEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EVNTDIR"),
+                old: Self::EVNTDIR_FIELD.extract(self.bits()),
+                new: Self::EVNTDIR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EVNTI"),
+                old: Self::EVNTI_FIELD.extract(self.bits()),
+                new: Self::EVNTI_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EL0VTEN"),
+                old: Self::EL0VTEN_FIELD.extract(self.bits()),
+                new: Self::EL0VTEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EL0PTEN"),
+                old: Self::EL0PTEN_FIELD.extract(self.bits()),
+                new: Self::EL0PTEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EL1PTEN"),
+                old: Self::EL1PTEN_FIELD.extract(self.bits()),
+                new: Self::EL1PTEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ECV"),
+                old: Self::ECV_FIELD.extract(self.bits()),
+                new: Self::ECV_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EL1TVT"),
+                old: Self::EL1TVT_FIELD.extract(self.bits()),
+                new: Self::EL1TVT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EL1TVCT"),
+                old: Self::EL1TVCT_FIELD.extract(self.bits()),
+                new: Self::EL1TVCT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EL1NVPCT"),
+                old: Self::EL1NVPCT_FIELD.extract(self.bits()),
+                new: Self::EL1NVPCT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EL1NVVCT"),
+                old: Self::EL1NVVCT_FIELD.extract(self.bits()),
+                new: Self::EL1NVVCT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EVNTIS"),
+                old: Self::EVNTIS_FIELD.extract(self.bits()),
+                new: Self::EVNTIS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CNTVMASK"),
+                old: Self::CNTVMASK_FIELD.extract(self.bits()),
+                new: Self::CNTVMASK_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CNTPMASK"),
+                old: Self::CNTPMASK_FIELD.extract(self.bits()),
+                new: Self::CNTPMASK_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_0000_0000_0100_0000_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_0000_0000_0100_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl CnthctlEl2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl CnthctlEl2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `CNTHCTL_EL2` system register value, when `HCR_EL2.E2H` is 0.
+    ///
+    /// Counter-timer Hypervisor Control Register
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct CnthctlEl2E2h0: u64 {
+        /// `EL1PCTEN` bit.
+        const EL1PCTEN = 1 << 0;
+        /// `EL1PCEN` bit.
+        const EL1PCEN = 1 << 1;
+        /// `EVNTEN` bit.
+        const EVNTEN = 1 << 2;
+        /// `EVNTDIR` bit.
+        const EVNTDIR = 1 << 3;
+        /// `ECV` bit.
+        const ECV = 1 << 12;
+        /// `EL1TVT` bit.
+        const EL1TVT = 1 << 13;
+        /// `EL1TVCT` bit.
+        const EL1TVCT = 1 << 14;
+        /// `EL1NVPCT` bit.
+        const EL1NVPCT = 1 << 15;
+        /// `EL1NVVCT` bit.
+        const EL1NVVCT = 1 << 16;
+        /// `EVNTIS` bit.
+        const EVNTIS = 1 << 17;
+        /// `CNTVMASK` bit.
+        const CNTVMASK = 1 << 18;
+        /// `CNTPMASK` bit.
+        const CNTPMASK = 1 << 19;
+    }
+}
+
+#[cfg(feature = "el2")]
+impl CnthctlEl2E2h0 {
+    /// Descriptor of the `EL1PCTEN` field, for use with [`FieldAccess`].
+    pub const EL1PCTEN_FIELD: Field<Self, bool, 0, 1> = Field::new("CNTHCTL_EL2", "EL1PCTEN");
+    /// Descriptor of the `EL1PCEN` field, for use with [`FieldAccess`].
+    pub const EL1PCEN_FIELD: Field<Self, bool, 1, 1> = Field::new("CNTHCTL_EL2", "EL1PCEN");
+    /// Descriptor of the `EVNTEN` field, for use with [`FieldAccess`].
+    pub const EVNTEN_FIELD: Field<Self, bool, 2, 1> = Field::new("CNTHCTL_EL2", "EVNTEN");
+    /// Descriptor of the `EVNTDIR` field, for use with [`FieldAccess`].
+    pub const EVNTDIR_FIELD: Field<Self, bool, 3, 1> = Field::new("CNTHCTL_EL2", "EVNTDIR");
+    /// Descriptor of the `EVNTI` field, for use with [`FieldAccess`].
+    pub const EVNTI_FIELD: Field<Self, u8, 4, 4> = Field::new("CNTHCTL_EL2", "EVNTI");
+    /// Descriptor of the `ECV` field, for use with [`FieldAccess`].
+    pub const ECV_FIELD: Field<Self, bool, 12, 1> = Field::new("CNTHCTL_EL2", "ECV");
+    /// Descriptor of the `EL1TVT` field, for use with [`FieldAccess`].
+    pub const EL1TVT_FIELD: Field<Self, bool, 13, 1> = Field::new("CNTHCTL_EL2", "EL1TVT");
+    /// Descriptor of the `EL1TVCT` field, for use with [`FieldAccess`].
+    pub const EL1TVCT_FIELD: Field<Self, bool, 14, 1> = Field::new("CNTHCTL_EL2", "EL1TVCT");
+    /// Descriptor of the `EL1NVPCT` field, for use with [`FieldAccess`].
+    pub const EL1NVPCT_FIELD: Field<Self, bool, 15, 1> = Field::new("CNTHCTL_EL2", "EL1NVPCT");
+    /// Descriptor of the `EL1NVVCT` field, for use with [`FieldAccess`].
+    pub const EL1NVVCT_FIELD: Field<Self, bool, 16, 1> = Field::new("CNTHCTL_EL2", "EL1NVVCT");
+    /// Descriptor of the `EVNTIS` field, for use with [`FieldAccess`].
+    pub const EVNTIS_FIELD: Field<Self, bool, 17, 1> = Field::new("CNTHCTL_EL2", "EVNTIS");
+    /// Descriptor of the `CNTVMASK` field, for use with [`FieldAccess`].
+    pub const CNTVMASK_FIELD: Field<Self, bool, 18, 1> = Field::new("CNTHCTL_EL2", "CNTVMASK");
+    /// Descriptor of the `CNTPMASK` field, for use with [`FieldAccess`].
+    pub const CNTPMASK_FIELD: Field<Self, bool, 19, 1> = Field::new("CNTHCTL_EL2", "CNTPMASK");
+
+    /// Returns the value of the `EVNTI` field.
+    pub const fn evnti(self) -> u8 {
+        Self::EVNTI_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `EVNTI` field.
+    pub const fn set_evnti(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::EVNTI_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `EVNTI` field set to the given value.
+    pub const fn with_evnti(mut self, value: u8) -> Self {
+        self.set_evnti(value);
+        self
+    }
+
+    /// Sets the value of the `EVNTI` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_evnti(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_evnti(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `EVNTI` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_evnti(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::EVNTI_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Display for CnthctlEl2E2h0 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("CnthctlEl2E2h0");
+        let bits = self.bits();
+        s.field("EL1PCTEN", &Self::EL1PCTEN_FIELD.extract(bits));
+        s.field("EL1PCEN", &Self::EL1PCEN_FIELD.extract(bits));
+        s.field("EVNTEN", &Self::EVNTEN_FIELD.extract(bits));
+        s.field("EVNTDIR", &Self::EVNTDIR_FIELD.extract(bits));
+        s.field("EVNTI", &Self::EVNTI_FIELD.extract(bits));
+        s.field("ECV", &Self::ECV_FIELD.extract(bits));
+        s.field("EL1TVT", &Self::EL1TVT_FIELD.extract(bits));
+        s.field("EL1TVCT", &Self::EL1TVCT_FIELD.extract(bits));
+        s.field("EL1NVPCT", &Self::EL1NVPCT_FIELD.extract(bits));
+        s.field("EL1NVVCT", &Self::EL1NVVCT_FIELD.extract(bits));
+        s.field("EVNTIS", &Self::EVNTIS_FIELD.extract(bits));
+        s.field("CNTVMASK", &Self::CNTVMASK_FIELD.extract(bits));
+        s.field("CNTPMASK", &Self::CNTPMASK_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for CnthctlEl2E2h0 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "CnthctlEl2E2h0 {{ EL1PCTEN: {=u64}, EL1PCEN: {=u64}, EVNTEN: {=u64}, EVNTDIR: {=u64}, EVNTI: {=u64}, ECV: {=u64}, EL1TVT: {=u64}, EL1TVCT: {=u64}, EL1NVPCT: {=u64}, EL1NVVCT: {=u64}, EVNTIS: {=u64}, CNTVMASK: {=u64}, CNTPMASK: {=u64} }}",
+            Self::EL1PCTEN_FIELD.extract(self.bits()),
+            Self::EL1PCEN_FIELD.extract(self.bits()),
+            Self::EVNTEN_FIELD.extract(self.bits()),
+            Self::EVNTDIR_FIELD.extract(self.bits()),
+            Self::EVNTI_FIELD.extract(self.bits()),
+            Self::ECV_FIELD.extract(self.bits()),
+            Self::EL1TVT_FIELD.extract(self.bits()),
+            Self::EL1TVCT_FIELD.extract(self.bits()),
+            Self::EL1NVPCT_FIELD.extract(self.bits()),
+            Self::EL1NVVCT_FIELD.extract(self.bits()),
+            Self::EVNTIS_FIELD.extract(self.bits()),
+            Self::CNTVMASK_FIELD.extract(self.bits()),
+            Self::CNTPMASK_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for CnthctlEl2E2h0 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for CnthctlEl2E2h0 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl CnthctlEl2E2h0 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("EL1PCTEN"),
+                old: Self::EL1PCTEN_FIELD.extract(self.bits()),
+                new: Self::EL1PCTEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EL1PCEN"),
+                old: Self::EL1PCEN_FIELD.extract(self.bits()),
+                new: Self::EL1PCEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EVNTEN"),
+                old: Self::EVNTEN_FIELD.extract(self.bits()),
+                new: Self::EVNTEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EVNTDIR"),
+                old: Self::EVNTDIR_FIELD.extract(self.bits()),
+                new: Self::EVNTDIR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EVNTI"),
+                old: Self::EVNTI_FIELD.extract(self.bits()),
+                new: Self::EVNTI_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ECV"),
+                old: Self::ECV_FIELD.extract(self.bits()),
+                new: Self::ECV_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EL1TVT"),
+                old: Self::EL1TVT_FIELD.extract(self.bits()),
+                new: Self::EL1TVT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EL1TVCT"),
+                old: Self::EL1TVCT_FIELD.extract(self.bits()),
+                new: Self::EL1TVCT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EL1NVPCT"),
+                old: Self::EL1NVPCT_FIELD.extract(self.bits()),
+                new: Self::EL1NVPCT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EL1NVVCT"),
+                old: Self::EL1NVVCT_FIELD.extract(self.bits()),
+                new: Self::EL1NVVCT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EVNTIS"),
+                old: Self::EVNTIS_FIELD.extract(self.bits()),
+                new: Self::EVNTIS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CNTVMASK"),
+                old: Self::CNTVMASK_FIELD.extract(self.bits()),
+                new: Self::CNTVMASK_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CNTPMASK"),
+                old: Self::CNTPMASK_FIELD.extract(self.bits()),
+                new: Self::CNTPMASK_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_0000_0000_1111_0000_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_0000_0000_1111_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl CnthctlEl2E2h0 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl CnthctlEl2E2h0 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `CNTHCTL_EL2` system register value, when `HCR_EL2.E2H` is 1.
+    ///
+    /// Counter-timer Hypervisor Control Register
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct CnthctlEl2E2h1: u64 {
+        /// `EL0PCTEN` bit.
+        const EL0PCTEN = 1 << 0;
+        /// `EL0VCTEN` bit.
+        const EL0VCTEN = 1 << 1;
+        /// `EVNTEN` bit.
+        const EVNTEN = 1 << 2;
+        /// `EVNTDIR` bit.
+        const EVNTDIR = 1 << 3;
+        /// `EL0VTEN` bit.
+        const EL0VTEN = 1 << 8;
+        /// `EL0PTEN` bit.
+        const EL0PTEN = 1 << 9;
+        /// `EL1PCTEN` bit.
+        const EL1PCTEN = 1 << 10;
+        /// `EL1PTEN` bit.
+        const EL1PTEN = 1 << 11;
+        /// `ECV` bit.
+        const ECV = 1 << 12;
+        /// `EL1TVT` bit.
+        const EL1TVT = 1 << 13;
+        /// `EL1TVCT` bit.
+        const EL1TVCT = 1 << 14;
+        /// `EL1NVPCT` bit.
+        const EL1NVPCT = 1 << 15;
+        /// `EL1NVVCT` bit.
+        const EL1NVVCT = 1 << 16;
+        /// `EVNTIS` bit.
+        const EVNTIS = 1 << 17;
+        /// `CNTVMASK` bit.
+        const CNTVMASK = 1 << 18;
+        /// `CNTPMASK` bit.
+        const CNTPMASK = 1 << 19;
+    }
+}
+
+#[cfg(feature = "el2")]
+impl CnthctlEl2E2h1 {
+    /// Descriptor of the `EL0PCTEN` field, for use with [`FieldAccess`].
+    pub const EL0PCTEN_FIELD: Field<Self, bool, 0, 1> = Field::new("CNTHCTL_EL2", "EL0PCTEN");
+    /// Descriptor of the `EL0VCTEN` field, for use with [`FieldAccess`].
+    pub const EL0VCTEN_FIELD: Field<Self, bool, 1, 1> = Field::new("CNTHCTL_EL2", "EL0VCTEN");
+    /// Descriptor of the `EVNTEN` field, for use with [`FieldAccess`].
+    pub const EVNTEN_FIELD: Field<Self, bool, 2, 1> = Field::new("CNTHCTL_EL2", "EVNTEN");
+    /// Descriptor of the `EVNTDIR` field, for use with [`FieldAccess`].
+    pub const EVNTDIR_FIELD: Field<Self, bool, 3, 1> = Field::new("CNTHCTL_EL2", "EVNTDIR");
+    /// Descriptor of the `EVNTI` field, for use with [`FieldAccess`].
+    pub const EVNTI_FIELD: Field<Self, u8, 4, 4> = Field::new("CNTHCTL_EL2", "EVNTI");
+    /// Descriptor of the `EL0VTEN` field, for use with [`FieldAccess`].
+    pub const EL0VTEN_FIELD: Field<Self, bool, 8, 1> = Field::new("CNTHCTL_EL2", "EL0VTEN");
+    /// Descriptor of the `EL0PTEN` field, for use with [`FieldAccess`].
+    pub const EL0PTEN_FIELD: Field<Self, bool, 9, 1> = Field::new("CNTHCTL_EL2", "EL0PTEN");
+    /// Descriptor of the `EL1PCTEN` field, for use with [`FieldAccess`].
+    pub const EL1PCTEN_FIELD: Field<Self, bool, 10, 1> = Field::new("CNTHCTL_EL2", "EL1PCTEN");
+    /// Descriptor of the `EL1PTEN` field, for use with [`FieldAccess`].
+    pub const EL1PTEN_FIELD: Field<Self, bool, 11, 1> = Field::new("CNTHCTL_EL2", "EL1PTEN");
+    /// Descriptor of the `ECV` field, for use with [`FieldAccess`].
+    pub const ECV_FIELD: Field<Self, bool, 12, 1> = Field::new("CNTHCTL_EL2", "ECV");
+    /// Descriptor of the `EL1TVT` field, for use with [`FieldAccess`].
+    pub const EL1TVT_FIELD: Field<Self, bool, 13, 1> = Field::new("CNTHCTL_EL2", "EL1TVT");
+    /// Descriptor of the `EL1TVCT` field, for use with [`FieldAccess`].
+    pub const EL1TVCT_FIELD: Field<Self, bool, 14, 1> = Field::new("CNTHCTL_EL2", "EL1TVCT");
+    /// Descriptor of the `EL1NVPCT` field, for use with [`FieldAccess`].
+    pub const EL1NVPCT_FIELD: Field<Self, bool, 15, 1> = Field::new("CNTHCTL_EL2", "EL1NVPCT");
+    /// Descriptor of the `EL1NVVCT` field, for use with [`FieldAccess`].
+    pub const EL1NVVCT_FIELD: Field<Self, bool, 16, 1> = Field::new("CNTHCTL_EL2", "EL1NVVCT");
+    /// Descriptor of the `EVNTIS` field, for use with [`FieldAccess`].
+    pub const EVNTIS_FIELD: Field<Self, bool, 17, 1> = Field::new("CNTHCTL_EL2", "EVNTIS");
+    /// Descriptor of the `CNTVMASK` field, for use with [`FieldAccess`].
+    pub const CNTVMASK_FIELD: Field<Self, bool, 18, 1> = Field::new("CNTHCTL_EL2", "CNTVMASK");
+    /// Descriptor of the `CNTPMASK` field, for use with [`FieldAccess`].
+    pub const CNTPMASK_FIELD: Field<Self, bool, 19, 1> = Field::new("CNTHCTL_EL2", "CNTPMASK");
+
+    /// Returns the value of the `EVNTI` field.
+    pub const fn evnti(self) -> u8 {
+        Self::EVNTI_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `EVNTI` field.
+    pub const fn set_evnti(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::EVNTI_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `EVNTI` field set to the given value.
+    pub const fn with_evnti(mut self, value: u8) -> Self {
+        self.set_evnti(value);
+        self
+    }
+
+    /// Sets the value of the `EVNTI` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_evnti(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_evnti(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `EVNTI` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_evnti(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::EVNTI_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Display for CnthctlEl2E2h1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("CnthctlEl2E2h1");
+        let bits = self.bits();
+        s.field("EL0PCTEN", &Self::EL0PCTEN_FIELD.extract(bits));
+        s.field("EL0VCTEN", &Self::EL0VCTEN_FIELD.extract(bits));
+        s.field("EVNTEN", &Self::EVNTEN_FIELD.extract(bits));
+        s.field("EVNTDIR", &Self::EVNTDIR_FIELD.extract(bits));
+        s.field("EVNTI", &Self::EVNTI_FIELD.extract(bits));
+        s.field("EL0VTEN", &Self::EL0VTEN_FIELD.extract(bits));
+        s.field("EL0PTEN", &Self::EL0PTEN_FIELD.extract(bits));
+        s.field("EL1PCTEN", &Self::EL1PCTEN_FIELD.extract(bits));
+        s.field("EL1PTEN", &Self::EL1PTEN_FIELD.extract(bits));
+        s.field("ECV", &Self::ECV_FIELD.extract(bits));
+        s.field("EL1TVT", &Self::EL1TVT_FIELD.extract(bits));
+        s.field("EL1TVCT", &Self::EL1TVCT_FIELD.extract(bits));
+        s.field("EL1NVPCT", &Self::EL1NVPCT_FIELD.extract(bits));
+        s.field("EL1NVVCT", &Self::EL1NVVCT_FIELD.extract(bits));
+        s.field("EVNTIS", &Self::EVNTIS_FIELD.extract(bits));
+        s.field("CNTVMASK", &Self::CNTVMASK_FIELD.extract(bits));
+        s.field("CNTPMASK", &Self::CNTPMASK_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for CnthctlEl2E2h1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "CnthctlEl2E2h1 {{ EL0PCTEN: {=u64}, EL0VCTEN: {=u64}, EVNTEN: {=u64}, EVNTDIR: {=u64}, EVNTI: {=u64}, EL0VTEN: {=u64}, EL0PTEN: {=u64}, EL1PCTEN: {=u64}, EL1PTEN: {=u64}, ECV: {=u64}, EL1TVT: {=u64}, EL1TVCT: {=u64}, EL1NVPCT: {=u64}, EL1NVVCT: {=u64}, EVNTIS: {=u64}, CNTVMASK: {=u64}, CNTPMASK: {=u64} }}",
+            Self::EL0PCTEN_FIELD.extract(self.bits()),
+            Self::EL0VCTEN_FIELD.extract(self.bits()),
+            Self::EVNTEN_FIELD.extract(self.bits()),
+            Self::EVNTDIR_FIELD.extract(self.bits()),
+            Self::EVNTI_FIELD.extract(self.bits()),
+            Self::EL0VTEN_FIELD.extract(self.bits()),
+            Self::EL0PTEN_FIELD.extract(self.bits()),
+            Self::EL1PCTEN_FIELD.extract(self.bits()),
+            Self::EL1PTEN_FIELD.extract(self.bits()),
+            Self::ECV_FIELD.extract(self.bits()),
+            Self::EL1TVT_FIELD.extract(self.bits()),
+            Self::EL1TVCT_FIELD.extract(self.bits()),
+            Self::EL1NVPCT_FIELD.extract(self.bits()),
+            Self::EL1NVVCT_FIELD.extract(self.bits()),
+            Self::EVNTIS_FIELD.extract(self.bits()),
+            Self::CNTVMASK_FIELD.extract(self.bits()),
+            Self::CNTPMASK_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for CnthctlEl2E2h1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for CnthctlEl2E2h1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl CnthctlEl2E2h1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("EL0PCTEN"),
+                old: Self::EL0PCTEN_FIELD.extract(self.bits()),
+                new: Self::EL0PCTEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EL0VCTEN"),
+                old: Self::EL0VCTEN_FIELD.extract(self.bits()),
+                new: Self::EL0VCTEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EVNTEN"),
+                old: Self::EVNTEN_FIELD.extract(self.bits()),
+                new: Self::EVNTEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EVNTDIR"),
+                old: Self::EVNTDIR_FIELD.extract(self.bits()),
+                new: Self::EVNTDIR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EVNTI"),
+                old: Self::EVNTI_FIELD.extract(self.bits()),
+                new: Self::EVNTI_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EL0VTEN"),
+                old: Self::EL0VTEN_FIELD.extract(self.bits()),
+                new: Self::EL0VTEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EL0PTEN"),
+                old: Self::EL0PTEN_FIELD.extract(self.bits()),
+                new: Self::EL0PTEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EL1PCTEN"),
+                old: Self::EL1PCTEN_FIELD.extract(self.bits()),
+                new: Self::EL1PCTEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EL1PTEN"),
+                old: Self::EL1PTEN_FIELD.extract(self.bits()),
+                new: Self::EL1PTEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ECV"),
+                old: Self::ECV_FIELD.extract(self.bits()),
+                new: Self::ECV_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EL1TVT"),
+                old: Self::EL1TVT_FIELD.extract(self.bits()),
+                new: Self::EL1TVT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EL1TVCT"),
+                old: Self::EL1TVCT_FIELD.extract(self.bits()),
+                new: Self::EL1TVCT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EL1NVPCT"),
+                old: Self::EL1NVPCT_FIELD.extract(self.bits()),
+                new: Self::EL1NVPCT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EL1NVVCT"),
+                old: Self::EL1NVVCT_FIELD.extract(self.bits()),
+                new: Self::EL1NVVCT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EVNTIS"),
+                old: Self::EVNTIS_FIELD.extract(self.bits()),
+                new: Self::EVNTIS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CNTVMASK"),
+                old: Self::CNTVMASK_FIELD.extract(self.bits()),
+                new: Self::CNTVMASK_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CNTPMASK"),
+                old: Self::CNTPMASK_FIELD.extract(self.bits()),
+                new: Self::CNTPMASK_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_0000_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_0000_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl CnthctlEl2E2h1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl CnthctlEl2E2h1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl CnthctlEl2 {
+    /// Returns the value as a `CnthctlEl2E2h0`, for use when `HCR_EL2.E2H` is 0.
+    pub const fn e2h0(self) -> CnthctlEl2E2h0 {
+        CnthctlEl2E2h0::from_bits_retain(self.bits())
+    }
+
+    /// Returns the value as a `CnthctlEl2E2h1`, for use when `HCR_EL2.E2H` is 1.
+    pub const fn e2h1(self) -> CnthctlEl2E2h1 {
+        CnthctlEl2E2h1::from_bits_retain(self.bits())
+    }
+}
+
+#[cfg(feature = "el2")]
+impl From<CnthctlEl2E2h0> for CnthctlEl2 {
+    fn from(value: CnthctlEl2E2h0) -> Self {
+        Self::from_bits_retain(value.bits())
+    }
+}
+
+#[cfg(feature = "el2")]
+impl From<CnthctlEl2E2h1> for CnthctlEl2 {
+    fn from(value: CnthctlEl2E2h1) -> Self {
+        Self::from_bits_retain(value.bits())
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `CNTHPS_CTL_EL2` system register value.
+    ///
+    /// Counter-timer Secure Physical Timer Control Register (EL2)
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct CnthpsCtlEl2: u64 {
+        /// `ENABLE` bit.
+        const ENABLE = 1 << 0;
+        /// `IMASK` bit.
+        const IMASK = 1 << 1;
+        /// `ISTATUS` bit.
+        const ISTATUS = 1 << 2;
+    }
+}
+
+#[cfg(feature = "el2")]
+impl CnthpsCtlEl2 {
+    /// Descriptor of the `ENABLE` field, for use with [`FieldAccess`].
+    pub const ENABLE_FIELD: Field<Self, bool, 0, 1> = Field::new("CNTHPS_CTL_EL2", "ENABLE");
+    /// Descriptor of the `IMASK` field, for use with [`FieldAccess`].
+    pub const IMASK_FIELD: Field<Self, bool, 1, 1> = Field::new("CNTHPS_CTL_EL2", "IMASK");
+    /// Descriptor of the `ISTATUS` field, for use with [`FieldAccess`].
+    pub const ISTATUS_FIELD: Field<Self, bool, 2, 1> = Field::new("CNTHPS_CTL_EL2", "ISTATUS");
+}
+
+#[cfg(feature = "el2")]
+impl Display for CnthpsCtlEl2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("CnthpsCtlEl2");
+        let bits = self.bits();
+        s.field("ENABLE", &Self::ENABLE_FIELD.extract(bits));
+        s.field("IMASK", &Self::IMASK_FIELD.extract(bits));
+        s.field("ISTATUS", &Self::ISTATUS_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for CnthpsCtlEl2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "CnthpsCtlEl2 {{ ENABLE: {=u64}, IMASK: {=u64}, ISTATUS: {=u64} }}",
+            Self::ENABLE_FIELD.extract(self.bits()),
+            Self::IMASK_FIELD.extract(self.bits()),
+            Self::ISTATUS_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for CnthpsCtlEl2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for CnthpsCtlEl2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl CnthpsCtlEl2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("ENABLE"),
+                old: Self::ENABLE_FIELD.extract(self.bits()),
+                new: Self::ENABLE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IMASK"),
+                old: Self::IMASK_FIELD.extract(self.bits()),
+                new: Self::IMASK_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ISTATUS"),
+                old: Self::ISTATUS_FIELD.extract(self.bits()),
+                new: Self::ISTATUS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl CnthpsCtlEl2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl CnthpsCtlEl2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `CNTHPS_CVAL_EL2` system register value.
+    ///
+    /// Counter-timer Secure Physical Timer CompareValue Register (EL2)
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct CnthpsCvalEl2: u64 {
+    }
+}
+
+#[cfg(feature = "el2")]
+impl CnthpsCvalEl2 {
+    /// Descriptor of the `CompareValue` field, for use with [`FieldAccess`].
+    pub const COMPAREVALUE_FIELD: Field<Self, u64, 0, 64> =
+        Field::new("CNTHPS_CVAL_EL2", "CompareValue");
+
+    /// Returns the value of the `CompareValue` field.
+    pub const fn comparevalue(self) -> u64 {
+        Self::COMPAREVALUE_FIELD.extract(self.bits())
+    }
+
+    /// Sets the value of the `CompareValue` field.
+    pub const fn set_comparevalue(&mut self, value: u64) {
+        *self = Self::from_bits_retain(Self::COMPAREVALUE_FIELD.insert(self.bits(), value));
+    }
+
+    /// Returns a copy with the `CompareValue` field set to the given value.
+    pub const fn with_comparevalue(mut self, value: u64) -> Self {
+        self.set_comparevalue(value);
+        self
+    }
+
+    /// Sets the value of the `CompareValue` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_comparevalue(&mut self, value: u64) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_comparevalue(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `CompareValue` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_comparevalue(self, value: u64) -> Result<Self, FieldOverflow> {
+        match Self::COMPAREVALUE_FIELD.try_insert(self.bits(), value) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Display for CnthpsCvalEl2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("CnthpsCvalEl2");
+        let bits = self.bits();
+        s.field(
+            "CompareValue",
+            &format_args!("{:#x}", Self::COMPAREVALUE_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for CnthpsCvalEl2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "CnthpsCvalEl2 {{ CompareValue: {=u64:#x} }}",
+            Self::COMPAREVALUE_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for CnthpsCvalEl2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for CnthpsCvalEl2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl CnthpsCvalEl2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [FieldChange {
+            register,
+            field: Some("CompareValue"),
+            old: Self::COMPAREVALUE_FIELD.extract(self.bits()),
+            new: Self::COMPAREVALUE_FIELD.extract(other.bits()),
+        }]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl CnthpsCvalEl2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl CnthpsCvalEl2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `CNTHPS_TVAL_EL2` system register value.
+    ///
+    /// Counter-timer Secure Physical Timer TimerValue Register (EL2)
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct CnthpsTvalEl2: u64 {
+    }
+}
+
+#[cfg(feature = "el2")]
+impl CnthpsTvalEl2 {
+    /// Descriptor of the `TimerValue` field, for use with [`FieldAccess`].
+    pub const TIMERVALUE_FIELD: Field<Self, u32, 0, 32> =
+        Field::new("CNTHPS_TVAL_EL2", "TimerValue");
+
+    /// Returns the value of the `TimerValue` field.
+    pub const fn timervalue(self) -> u32 {
+        Self::TIMERVALUE_FIELD.extract(self.bits()) as u32
+    }
+
+    /// Sets the value of the `TimerValue` field.
+    pub const fn set_timervalue(&mut self, value: u32) {
+        *self = Self::from_bits_retain(Self::TIMERVALUE_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `TimerValue` field set to the given value.
+    pub const fn with_timervalue(mut self, value: u32) -> Self {
+        self.set_timervalue(value);
+        self
+    }
+
+    /// Sets the value of the `TimerValue` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_timervalue(&mut self, value: u32) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_timervalue(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `TimerValue` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_timervalue(self, value: u32) -> Result<Self, FieldOverflow> {
+        match Self::TIMERVALUE_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Display for CnthpsTvalEl2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("CnthpsTvalEl2");
+        let bits = self.bits();
+        s.field(
+            "TimerValue",
+            &format_args!("{:#x}", Self::TIMERVALUE_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for CnthpsTvalEl2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "CnthpsTvalEl2 {{ TimerValue: {=u64:#x} }}",
+            Self::TIMERVALUE_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for CnthpsTvalEl2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for CnthpsTvalEl2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl CnthpsTvalEl2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("TimerValue"),
+                old: Self::TIMERVALUE_FIELD.extract(self.bits()),
+                new: Self::TIMERVALUE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_0000_0000_0000_0000_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_0000_0000_0000_0000_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl CnthpsTvalEl2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl CnthpsTvalEl2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el2")]
+/// `CNTHP_CTL_EL2` system register value.
+///
+/// Counter-timer Hypervisor Physical Timer Control Register
+pub type CnthpCtlEl2 = CnthpsCtlEl2;
+
+#[cfg(feature = "el2")]
+/// `CNTHP_CVAL_EL2` system register value.
+///
+/// Counter-timer Physical Timer CompareValue Register (EL2)
+pub type CnthpCvalEl2 = CnthpsCvalEl2;
+
+#[cfg(feature = "el2")]
+/// `CNTHP_TVAL_EL2` system register value.
+///
+/// Counter-timer Physical Timer TimerValue Register (EL2)
+pub type CnthpTvalEl2 = CnthpsTvalEl2;
+
+#[cfg(feature = "el2")]
+/// `CNTHVS_CTL_EL2` system register value.
+///
+/// Counter-timer Secure Virtual Timer Control Register (EL2)
+pub type CnthvsCtlEl2 = CnthpsCtlEl2;
+
+#[cfg(feature = "el2")]
+/// `CNTHVS_CVAL_EL2` system register value.
+///
+/// Counter-timer Secure Virtual Timer CompareValue Register (EL2)
+pub type CnthvsCvalEl2 = CnthpsCvalEl2;
+
+#[cfg(feature = "el2")]
+/// `CNTHVS_TVAL_EL2` system register value.
+///
+/// Counter-timer Secure Virtual Timer TimerValue Register (EL2)
+pub type CnthvsTvalEl2 = CnthpsTvalEl2;
+
+#[cfg(feature = "el2")]
+/// `CNTHV_CTL_EL2` system register value.
+///
+/// Counter-timer Virtual Timer Control Register (EL2)
+pub type CnthvCtlEl2 = CnthpsCtlEl2;
+
+#[cfg(feature = "el2")]
+/// `CNTHV_CVAL_EL2` system register value.
+///
+/// Counter-timer Virtual Timer CompareValue Register (EL2)
+pub type CnthvCvalEl2 = CnthpsCvalEl2;
+
+#[cfg(feature = "el2")]
+/// `CNTHV_TVAL_EL2` system register value.
+///
+/// Counter-timer Virtual Timer TimerValue Register (EL2)
+pub type CnthvTvalEl2 = CnthpsTvalEl2;
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `CNTKCTL_EL1` system register value.
+    ///
+    /// Counter-timer Kernel Control Register
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct CntkctlEl1: u64 {
+        /// `EL0PCTEN` bit.
+        const EL0PCTEN = 1 << 0;
+        /// `EL0VCTEN` bit.
+        const EL0VCTEN = 1 << 1;
+        /// `EVNTEN` bit.
+        const EVNTEN = 1 << 2;
+        /// `EVNTDIR` bit.
+        const EVNTDIR = 1 << 3;
+        /// `EL0VTEN` bit.
+        const EL0VTEN = 1 << 8;
+        /// `EL0PTEN` bit.
+        const EL0PTEN = 1 << 9;
+        /// `EL1PCTEN` bit.
+        const EL1PCTEN = 1 << 10;
+        /// `EL1PTEN` bit.
+        const EL1PTEN = 1 << 11;
+        /// `ECV` bit.
+        const ECV = 1 << 12;
+        /// `EL1TVT` bit.
+        const EL1TVT = 1 << 13;
+        /// `EL1TVCT` bit.
+        const EL1TVCT = 1 << 14;
+        /// `EL1NVPCT` bit.
+        const EL1NVPCT = 1 << 15;
+        /// `EL1NVVCT` bit.
+        const EL1NVVCT = 1 << 16;
+        /// `EVNTIS` bit.
+        const EVNTIS = 1 << 17;
+        /// `CNTVMASK` bit.
+        const CNTVMASK = 1 << 18;
+        /// `CNTPMASK` bit.
+        const CNTPMASK = 1 << 19;
+    }
+}
+
+#[cfg(feature = "el1")]
+impl CntkctlEl1 {
+    /// Descriptor of the `EL0PCTEN` field, for use with [`FieldAccess`].
+    pub const EL0PCTEN_FIELD: Field<Self, bool, 0, 1> = Field::new("CNTKCTL_EL1", "EL0PCTEN");
+    /// Descriptor of the `EL0VCTEN` field, for use with [`FieldAccess`].
+    pub const EL0VCTEN_FIELD: Field<Self, bool, 1, 1> = Field::new("CNTKCTL_EL1", "EL0VCTEN");
+    /// Descriptor of the `EVNTEN` field, for use with [`FieldAccess`].
+    pub const EVNTEN_FIELD: Field<Self, bool, 2, 1> = Field::new("CNTKCTL_EL1", "EVNTEN");
+    /// Descriptor of the `EVNTDIR` field, for use with [`FieldAccess`].
+    pub const EVNTDIR_FIELD: Field<Self, bool, 3, 1> = Field::new("CNTKCTL_EL1", "EVNTDIR");
+    /// Descriptor of the `EVNTI` field, for use with [`FieldAccess`].
+    pub const EVNTI_FIELD: Field<Self, u8, 4, 4> = Field::new("CNTKCTL_EL1", "EVNTI");
+    /// Descriptor of the `EL0VTEN` field, for use with [`FieldAccess`].
+    pub const EL0VTEN_FIELD: Field<Self, bool, 8, 1> = Field::new("CNTKCTL_EL1", "EL0VTEN");
+    /// Descriptor of the `EL0PTEN` field, for use with [`FieldAccess`].
+    pub const EL0PTEN_FIELD: Field<Self, bool, 9, 1> = Field::new("CNTKCTL_EL1", "EL0PTEN");
+    /// Descriptor of the `EL1PCTEN` field, for use with [`FieldAccess`].
+    pub const EL1PCTEN_FIELD: Field<Self, bool, 10, 1> = Field::new("CNTKCTL_EL1", "EL1PCTEN");
+    /// Descriptor of the `EL1PTEN` field, for use with [`FieldAccess`].
+    pub const EL1PTEN_FIELD: Field<Self, bool, 11, 1> = Field::new("CNTKCTL_EL1", "EL1PTEN");
+    /// Descriptor of the `ECV` field, for use with [`FieldAccess`].
+    pub const ECV_FIELD: Field<Self, bool, 12, 1> = Field::new("CNTKCTL_EL1", "ECV");
+    /// Descriptor of the `EL1TVT` field, for use with [`FieldAccess`].
+    pub const EL1TVT_FIELD: Field<Self, bool, 13, 1> = Field::new("CNTKCTL_EL1", "EL1TVT");
+    /// Descriptor of the `EL1TVCT` field, for use with [`FieldAccess`].
+    pub const EL1TVCT_FIELD: Field<Self, bool, 14, 1> = Field::new("CNTKCTL_EL1", "EL1TVCT");
+    /// Descriptor of the `EL1NVPCT` field, for use with [`FieldAccess`].
+    pub const EL1NVPCT_FIELD: Field<Self, bool, 15, 1> = Field::new("CNTKCTL_EL1", "EL1NVPCT");
+    /// Descriptor of the `EL1NVVCT` field, for use with [`FieldAccess`].
+    pub const EL1NVVCT_FIELD: Field<Self, bool, 16, 1> = Field::new("CNTKCTL_EL1", "EL1NVVCT");
+    /// Descriptor of the `EVNTIS` field, for use with [`FieldAccess`].
+    pub const EVNTIS_FIELD: Field<Self, bool, 17, 1> = Field::new("CNTKCTL_EL1", "EVNTIS");
+    /// Descriptor of the `CNTVMASK` field, for use with [`FieldAccess`].
+    pub const CNTVMASK_FIELD: Field<Self, bool, 18, 1> = Field::new("CNTKCTL_EL1", "CNTVMASK");
+    /// Descriptor of the `CNTPMASK` field, for use with [`FieldAccess`].
+    pub const CNTPMASK_FIELD: Field<Self, bool, 19, 1> = Field::new("CNTKCTL_EL1", "CNTPMASK");
+
+    /// Returns the value of the `EVNTI` field.
+    pub const fn evnti(self) -> u8 {
+        Self::EVNTI_FIELD.extract(self.bits()) as u8
+    }
+
+    /// Sets the value of the `EVNTI` field.
+    pub const fn set_evnti(&mut self, value: u8) {
+        *self = Self::from_bits_retain(Self::EVNTI_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `EVNTI` field set to the given value.
+    pub const fn with_evnti(mut self, value: u8) -> Self {
+        self.set_evnti(value);
+        self
+    }
+
+    /// Sets the value of the `EVNTI` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_evnti(&mut self, value: u8) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_evnti(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `EVNTI` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_evnti(self, value: u8) -> Result<Self, FieldOverflow> {
+        match Self::EVNTI_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for CntkctlEl1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("CntkctlEl1");
+        let bits = self.bits();
+        s.field("EL0PCTEN", &Self::EL0PCTEN_FIELD.extract(bits));
+        s.field("EL0VCTEN", &Self::EL0VCTEN_FIELD.extract(bits));
+        s.field("EVNTEN", &Self::EVNTEN_FIELD.extract(bits));
+        s.field("EVNTDIR", &Self::EVNTDIR_FIELD.extract(bits));
+        s.field("EVNTI", &Self::EVNTI_FIELD.extract(bits));
+        s.field("EL0VTEN", &Self::EL0VTEN_FIELD.extract(bits));
+        s.field("EL0PTEN", &Self::EL0PTEN_FIELD.extract(bits));
+        s.field("EL1PCTEN", &Self::EL1PCTEN_FIELD.extract(bits));
+        s.field("EL1PTEN", &Self::EL1PTEN_FIELD.extract(bits));
+        s.field("ECV", &Self::ECV_FIELD.extract(bits));
+        s.field("EL1TVT", &Self::EL1TVT_FIELD.extract(bits));
+        s.field("EL1TVCT", &Self::EL1TVCT_FIELD.extract(bits));
+        s.field("EL1NVPCT", &Self::EL1NVPCT_FIELD.extract(bits));
+        s.field("EL1NVVCT", &Self::EL1NVVCT_FIELD.extract(bits));
+        s.field("EVNTIS", &Self::EVNTIS_FIELD.extract(bits));
+        s.field("CNTVMASK", &Self::CNTVMASK_FIELD.extract(bits));
+        s.field("CNTPMASK", &Self::CNTPMASK_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for CntkctlEl1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "CntkctlEl1 {{ EL0PCTEN: {=u64}, EL0VCTEN: {=u64}, EVNTEN: {=u64}, EVNTDIR: {=u64}, EVNTI: {=u64}, EL0VTEN: {=u64}, EL0PTEN: {=u64}, EL1PCTEN: {=u64}, EL1PTEN: {=u64}, ECV: {=u64}, EL1TVT: {=u64}, EL1TVCT: {=u64}, EL1NVPCT: {=u64}, EL1NVVCT: {=u64}, EVNTIS: {=u64}, CNTVMASK: {=u64}, CNTPMASK: {=u64} }}",
+            Self::EL0PCTEN_FIELD.extract(self.bits()),
+            Self::EL0VCTEN_FIELD.extract(self.bits()),
+            Self::EVNTEN_FIELD.extract(self.bits()),
+            Self::EVNTDIR_FIELD.extract(self.bits()),
+            Self::EVNTI_FIELD.extract(self.bits()),
+            Self::EL0VTEN_FIELD.extract(self.bits()),
+            Self::EL0PTEN_FIELD.extract(self.bits()),
+            Self::EL1PCTEN_FIELD.extract(self.bits()),
+            Self::EL1PTEN_FIELD.extract(self.bits()),
+            Self::ECV_FIELD.extract(self.bits()),
+            Self::EL1TVT_FIELD.extract(self.bits()),
+            Self::EL1TVCT_FIELD.extract(self.bits()),
+            Self::EL1NVPCT_FIELD.extract(self.bits()),
+            Self::EL1NVVCT_FIELD.extract(self.bits()),
+            Self::EVNTIS_FIELD.extract(self.bits()),
+            Self::CNTVMASK_FIELD.extract(self.bits()),
+            Self::CNTPMASK_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for CntkctlEl1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for CntkctlEl1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl CntkctlEl1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("EL0PCTEN"),
+                old: Self::EL0PCTEN_FIELD.extract(self.bits()),
+                new: Self::EL0PCTEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EL0VCTEN"),
+                old: Self::EL0VCTEN_FIELD.extract(self.bits()),
+                new: Self::EL0VCTEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EVNTEN"),
+                old: Self::EVNTEN_FIELD.extract(self.bits()),
+                new: Self::EVNTEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EVNTDIR"),
+                old: Self::EVNTDIR_FIELD.extract(self.bits()),
+                new: Self::EVNTDIR_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EVNTI"),
+                old: Self::EVNTI_FIELD.extract(self.bits()),
+                new: Self::EVNTI_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EL0VTEN"),
+                old: Self::EL0VTEN_FIELD.extract(self.bits()),
+                new: Self::EL0VTEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EL0PTEN"),
+                old: Self::EL0PTEN_FIELD.extract(self.bits()),
+                new: Self::EL0PTEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EL1PCTEN"),
+                old: Self::EL1PCTEN_FIELD.extract(self.bits()),
+                new: Self::EL1PCTEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EL1PTEN"),
+                old: Self::EL1PTEN_FIELD.extract(self.bits()),
+                new: Self::EL1PTEN_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ECV"),
+                old: Self::ECV_FIELD.extract(self.bits()),
+                new: Self::ECV_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EL1TVT"),
+                old: Self::EL1TVT_FIELD.extract(self.bits()),
+                new: Self::EL1TVT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EL1TVCT"),
+                old: Self::EL1TVCT_FIELD.extract(self.bits()),
+                new: Self::EL1TVCT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EL1NVPCT"),
+                old: Self::EL1NVPCT_FIELD.extract(self.bits()),
+                new: Self::EL1NVPCT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EL1NVVCT"),
+                old: Self::EL1NVVCT_FIELD.extract(self.bits()),
+                new: Self::EL1NVVCT_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("EVNTIS"),
+                old: Self::EVNTIS_FIELD.extract(self.bits()),
+                new: Self::EVNTIS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CNTVMASK"),
+                old: Self::CNTVMASK_FIELD.extract(self.bits()),
+                new: Self::CNTVMASK_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("CNTPMASK"),
+                old: Self::CNTPMASK_FIELD.extract(self.bits()),
+                new: Self::CNTPMASK_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_0000_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_0000_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl CntkctlEl1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl CntkctlEl1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+bitflags! {
+    /// `CNTPCTSS_EL0` system register value.
+    ///
+    /// Counter-timer Self-Synchronized Physical Count Register
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct CntpctssEl0: u64 {
+    }
+}
+
+impl CntpctssEl0 {
+    /// Descriptor of the `SSPhysicalCount` field, for use with [`FieldAccess`].
+    pub const SSPHYSICALCOUNT_FIELD: Field<Self, u64, 0, 64> =
+        Field::new("CNTPCTSS_EL0", "SSPhysicalCount");
+
+    /// Returns the value of the `SSPhysicalCount` field.
+    pub const fn ssphysicalcount(self) -> u64 {
+        Self::SSPHYSICALCOUNT_FIELD.extract(self.bits())
+    }
+
+    /// Sets the value of the `SSPhysicalCount` field.
+    pub const fn set_ssphysicalcount(&mut self, value: u64) {
+        *self = Self::from_bits_retain(Self::SSPHYSICALCOUNT_FIELD.insert(self.bits(), value));
+    }
+
+    /// Returns a copy with the `SSPhysicalCount` field set to the given value.
+    pub const fn with_ssphysicalcount(mut self, value: u64) -> Self {
+        self.set_ssphysicalcount(value);
+        self
+    }
+
+    /// Sets the value of the `SSPhysicalCount` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ssphysicalcount(&mut self, value: u64) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ssphysicalcount(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SSPhysicalCount` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ssphysicalcount(self, value: u64) -> Result<Self, FieldOverflow> {
+        match Self::SSPHYSICALCOUNT_FIELD.try_insert(self.bits(), value) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl Display for CntpctssEl0 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("CntpctssEl0");
+        let bits = self.bits();
+        s.field(
+            "SSPhysicalCount",
+            &format_args!("{:#x}", Self::SSPHYSICALCOUNT_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for CntpctssEl0 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "CntpctssEl0 {{ SSPhysicalCount: {=u64:#x} }}",
+            Self::SSPHYSICALCOUNT_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for CntpctssEl0 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CntpctssEl0 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+impl CntpctssEl0 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [FieldChange {
+            register,
+            field: Some("SSPhysicalCount"),
+            old: Self::SSPHYSICALCOUNT_FIELD.extract(self.bits()),
+            new: Self::SSPHYSICALCOUNT_FIELD.extract(other.bits()),
+        }]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+impl CntpctssEl0 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+impl CntpctssEl0 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+bitflags! {
+    /// `CNTPCT_EL0` system register value.
+    ///
+    /// Counter-timer Physical Count Register
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct CntpctEl0: u64 {
+    }
+}
+
+impl CntpctEl0 {
+    /// Descriptor of the `PhysicalCount` field, for use with [`FieldAccess`].
+    pub const PHYSICALCOUNT_FIELD: Field<Self, u64, 0, 64> =
+        Field::new("CNTPCT_EL0", "PhysicalCount");
+
+    /// Returns the value of the `PhysicalCount` field.
+    pub const fn physicalcount(self) -> u64 {
+        Self::PHYSICALCOUNT_FIELD.extract(self.bits())
+    }
+
+    /// Sets the value of the `PhysicalCount` field.
+    pub const fn set_physicalcount(&mut self, value: u64) {
+        *self = Self::from_bits_retain(Self::PHYSICALCOUNT_FIELD.insert(self.bits(), value));
+    }
+
+    /// Returns a copy with the `PhysicalCount` field set to the given value.
+    pub const fn with_physicalcount(mut self, value: u64) -> Self {
+        self.set_physicalcount(value);
+        self
+    }
+
+    /// Sets the value of the `PhysicalCount` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_physicalcount(&mut self, value: u64) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_physicalcount(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PhysicalCount` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_physicalcount(self, value: u64) -> Result<Self, FieldOverflow> {
+        match Self::PHYSICALCOUNT_FIELD.try_insert(self.bits(), value) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl Display for CntpctEl0 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("CntpctEl0");
+        let bits = self.bits();
+        s.field(
+            "PhysicalCount",
+            &format_args!("{:#x}", Self::PHYSICALCOUNT_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for CntpctEl0 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "CntpctEl0 {{ PhysicalCount: {=u64:#x} }}",
+            Self::PHYSICALCOUNT_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for CntpctEl0 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CntpctEl0 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+impl CntpctEl0 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [FieldChange {
+            register,
+            field: Some("PhysicalCount"),
+            old: Self::PHYSICALCOUNT_FIELD.extract(self.bits()),
+            new: Self::PHYSICALCOUNT_FIELD.extract(other.bits()),
+        }]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+impl CntpctEl0 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+impl CntpctEl0 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `CNTPOFF_EL2` system register value.
+    ///
+    /// Counter-timer Physical Offset Register
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct CntpoffEl2: u64 {
+    }
+}
+
+#[cfg(feature = "el2")]
+impl CntpoffEl2 {
+    /// Descriptor of the `PO` field, for use with [`FieldAccess`].
+    pub const PO_FIELD: Field<Self, u64, 0, 64> = Field::new("CNTPOFF_EL2", "PO");
+
+    /// Returns the value of the `PO` field.
+    pub const fn po(self) -> u64 {
+        Self::PO_FIELD.extract(self.bits())
+    }
+
+    /// Sets the value of the `PO` field.
+    pub const fn set_po(&mut self, value: u64) {
+        *self = Self::from_bits_retain(Self::PO_FIELD.insert(self.bits(), value));
+    }
+
+    /// Returns a copy with the `PO` field set to the given value.
+    pub const fn with_po(mut self, value: u64) -> Self {
+        self.set_po(value);
+        self
+    }
+
+    /// Sets the value of the `PO` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_po(&mut self, value: u64) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_po(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `PO` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_po(self, value: u64) -> Result<Self, FieldOverflow> {
+        match Self::PO_FIELD.try_insert(self.bits(), value) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Display for CntpoffEl2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("CntpoffEl2");
+        let bits = self.bits();
+        s.field("PO", &format_args!("{:#x}", Self::PO_FIELD.extract(bits)));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for CntpoffEl2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "CntpoffEl2 {{ PO: {=u64:#x} }}",
+            Self::PO_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for CntpoffEl2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for CntpoffEl2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl CntpoffEl2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [FieldChange {
+            register,
+            field: Some("PO"),
+            old: Self::PO_FIELD.extract(self.bits()),
+            new: Self::PO_FIELD.extract(other.bits()),
+        }]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl CntpoffEl2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl CntpoffEl2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `CNTPS_CTL_EL1` system register value.
+    ///
+    /// Counter-timer Physical Secure Timer Control Register
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct CntpsCtlEl1: u64 {
+        /// `ENABLE` bit.
+        const ENABLE = 1 << 0;
+        /// `IMASK` bit.
+        const IMASK = 1 << 1;
+        /// `ISTATUS` bit.
+        const ISTATUS = 1 << 2;
+    }
+}
+
+#[cfg(feature = "el1")]
+impl CntpsCtlEl1 {
+    /// Descriptor of the `ENABLE` field, for use with [`FieldAccess`].
+    pub const ENABLE_FIELD: Field<Self, bool, 0, 1> = Field::new("CNTPS_CTL_EL1", "ENABLE");
+    /// Descriptor of the `IMASK` field, for use with [`FieldAccess`].
+    pub const IMASK_FIELD: Field<Self, bool, 1, 1> = Field::new("CNTPS_CTL_EL1", "IMASK");
+    /// Descriptor of the `ISTATUS` field, for use with [`FieldAccess`].
+    pub const ISTATUS_FIELD: Field<Self, bool, 2, 1> = Field::new("CNTPS_CTL_EL1", "ISTATUS");
+}
+
+#[cfg(feature = "el1")]
+impl Display for CntpsCtlEl1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("CntpsCtlEl1");
+        let bits = self.bits();
+        s.field("ENABLE", &Self::ENABLE_FIELD.extract(bits));
+        s.field("IMASK", &Self::IMASK_FIELD.extract(bits));
+        s.field("ISTATUS", &Self::ISTATUS_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for CntpsCtlEl1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "CntpsCtlEl1 {{ ENABLE: {=u64}, IMASK: {=u64}, ISTATUS: {=u64} }}",
+            Self::ENABLE_FIELD.extract(self.bits()),
+            Self::IMASK_FIELD.extract(self.bits()),
+            Self::ISTATUS_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for CntpsCtlEl1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for CntpsCtlEl1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl CntpsCtlEl1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("ENABLE"),
+                old: Self::ENABLE_FIELD.extract(self.bits()),
+                new: Self::ENABLE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IMASK"),
+                old: Self::IMASK_FIELD.extract(self.bits()),
+                new: Self::IMASK_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ISTATUS"),
+                old: Self::ISTATUS_FIELD.extract(self.bits()),
+                new: Self::ISTATUS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl CntpsCtlEl1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl CntpsCtlEl1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `CNTPS_CVAL_EL1` system register value.
+    ///
+    /// Counter-timer Physical Secure Timer CompareValue Register
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct CntpsCvalEl1: u64 {
+    }
+}
+
+#[cfg(feature = "el1")]
+impl CntpsCvalEl1 {
+    /// Descriptor of the `CompareValue` field, for use with [`FieldAccess`].
+    pub const COMPAREVALUE_FIELD: Field<Self, u64, 0, 64> =
+        Field::new("CNTPS_CVAL_EL1", "CompareValue");
+
+    /// Returns the value of the `CompareValue` field.
+    pub const fn comparevalue(self) -> u64 {
+        Self::COMPAREVALUE_FIELD.extract(self.bits())
+    }
+
+    /// Sets the value of the `CompareValue` field.
+    pub const fn set_comparevalue(&mut self, value: u64) {
+        *self = Self::from_bits_retain(Self::COMPAREVALUE_FIELD.insert(self.bits(), value));
+    }
+
+    /// Returns a copy with the `CompareValue` field set to the given value.
+    pub const fn with_comparevalue(mut self, value: u64) -> Self {
+        self.set_comparevalue(value);
+        self
+    }
+
+    /// Sets the value of the `CompareValue` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_comparevalue(&mut self, value: u64) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_comparevalue(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `CompareValue` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_comparevalue(self, value: u64) -> Result<Self, FieldOverflow> {
+        match Self::COMPAREVALUE_FIELD.try_insert(self.bits(), value) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for CntpsCvalEl1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("CntpsCvalEl1");
+        let bits = self.bits();
+        s.field(
+            "CompareValue",
+            &format_args!("{:#x}", Self::COMPAREVALUE_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for CntpsCvalEl1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "CntpsCvalEl1 {{ CompareValue: {=u64:#x} }}",
+            Self::COMPAREVALUE_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for CntpsCvalEl1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for CntpsCvalEl1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl CntpsCvalEl1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [FieldChange {
+            register,
+            field: Some("CompareValue"),
+            old: Self::COMPAREVALUE_FIELD.extract(self.bits()),
+            new: Self::COMPAREVALUE_FIELD.extract(other.bits()),
+        }]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl CntpsCvalEl1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl CntpsCvalEl1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el1")]
+bitflags! {
+    /// `CNTPS_TVAL_EL1` system register value.
+    ///
+    /// Counter-timer Physical Secure Timer TimerValue Register
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct CntpsTvalEl1: u64 {
+    }
+}
+
+#[cfg(feature = "el1")]
+impl CntpsTvalEl1 {
+    /// Descriptor of the `TimerValue` field, for use with [`FieldAccess`].
+    pub const TIMERVALUE_FIELD: Field<Self, u32, 0, 32> =
+        Field::new("CNTPS_TVAL_EL1", "TimerValue");
+
+    /// Returns the value of the `TimerValue` field.
+    pub const fn timervalue(self) -> u32 {
+        Self::TIMERVALUE_FIELD.extract(self.bits()) as u32
+    }
+
+    /// Sets the value of the `TimerValue` field.
+    pub const fn set_timervalue(&mut self, value: u32) {
+        *self = Self::from_bits_retain(Self::TIMERVALUE_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `TimerValue` field set to the given value.
+    pub const fn with_timervalue(mut self, value: u32) -> Self {
+        self.set_timervalue(value);
+        self
+    }
+
+    /// Sets the value of the `TimerValue` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_timervalue(&mut self, value: u32) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_timervalue(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `TimerValue` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_timervalue(self, value: u32) -> Result<Self, FieldOverflow> {
+        match Self::TIMERVALUE_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el1")]
+impl Display for CntpsTvalEl1 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("CntpsTvalEl1");
+        let bits = self.bits();
+        s.field(
+            "TimerValue",
+            &format_args!("{:#x}", Self::TIMERVALUE_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el1")]
+impl defmt::Format for CntpsTvalEl1 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "CntpsTvalEl1 {{ TimerValue: {=u64:#x} }}",
+            Self::TIMERVALUE_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl serde::Serialize for CntpsTvalEl1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el1")]
+impl<'de> serde::Deserialize<'de> for CntpsTvalEl1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl CntpsTvalEl1 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("TimerValue"),
+                old: Self::TIMERVALUE_FIELD.extract(self.bits()),
+                new: Self::TIMERVALUE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_0000_0000_0000_0000_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_0000_0000_0000_0000_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el1")]
+impl CntpsTvalEl1 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el1")]
+impl CntpsTvalEl1 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+bitflags! {
+    /// `CNTP_CTL_EL0` system register value.
+    ///
+    /// Counter-timer Physical Timer Control Register
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct CntpCtlEl0: u64 {
+        /// `ENABLE` bit.
+        const ENABLE = 1 << 0;
+        /// `IMASK` bit.
+        const IMASK = 1 << 1;
+        /// `ISTATUS` bit.
+        const ISTATUS = 1 << 2;
+    }
+}
+
+impl CntpCtlEl0 {
+    /// Descriptor of the `ENABLE` field, for use with [`FieldAccess`].
+    pub const ENABLE_FIELD: Field<Self, bool, 0, 1> = Field::new("CNTP_CTL_EL0", "ENABLE");
+    /// Descriptor of the `IMASK` field, for use with [`FieldAccess`].
+    pub const IMASK_FIELD: Field<Self, bool, 1, 1> = Field::new("CNTP_CTL_EL0", "IMASK");
+    /// Descriptor of the `ISTATUS` field, for use with [`FieldAccess`].
+    pub const ISTATUS_FIELD: Field<Self, bool, 2, 1> = Field::new("CNTP_CTL_EL0", "ISTATUS");
+}
+
+impl Display for CntpCtlEl0 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("CntpCtlEl0");
+        let bits = self.bits();
+        s.field("ENABLE", &Self::ENABLE_FIELD.extract(bits));
+        s.field("IMASK", &Self::IMASK_FIELD.extract(bits));
+        s.field("ISTATUS", &Self::ISTATUS_FIELD.extract(bits));
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for CntpCtlEl0 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "CntpCtlEl0 {{ ENABLE: {=u64}, IMASK: {=u64}, ISTATUS: {=u64} }}",
+            Self::ENABLE_FIELD.extract(self.bits()),
+            Self::IMASK_FIELD.extract(self.bits()),
+            Self::ISTATUS_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for CntpCtlEl0 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CntpCtlEl0 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+impl CntpCtlEl0 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("ENABLE"),
+                old: Self::ENABLE_FIELD.extract(self.bits()),
+                new: Self::ENABLE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("IMASK"),
+                old: Self::IMASK_FIELD.extract(self.bits()),
+                new: Self::IMASK_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: Some("ISTATUS"),
+                old: Self::ISTATUS_FIELD.extract(self.bits()),
+                new: Self::ISTATUS_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1111_1000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+impl CntpCtlEl0 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+impl CntpCtlEl0 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+bitflags! {
+    /// `CNTP_CVAL_EL0` system register value.
+    ///
+    /// Counter-timer Physical Timer CompareValue Register
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct CntpCvalEl0: u64 {
+    }
+}
+
+impl CntpCvalEl0 {
+    /// Descriptor of the `CompareValue` field, for use with [`FieldAccess`].
+    pub const COMPAREVALUE_FIELD: Field<Self, u64, 0, 64> =
+        Field::new("CNTP_CVAL_EL0", "CompareValue");
+
+    /// Returns the value of the `CompareValue` field.
+    pub const fn comparevalue(self) -> u64 {
+        Self::COMPAREVALUE_FIELD.extract(self.bits())
+    }
+
+    /// Sets the value of the `CompareValue` field.
+    pub const fn set_comparevalue(&mut self, value: u64) {
+        *self = Self::from_bits_retain(Self::COMPAREVALUE_FIELD.insert(self.bits(), value));
+    }
+
+    /// Returns a copy with the `CompareValue` field set to the given value.
+    pub const fn with_comparevalue(mut self, value: u64) -> Self {
+        self.set_comparevalue(value);
+        self
+    }
+
+    /// Sets the value of the `CompareValue` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_comparevalue(&mut self, value: u64) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_comparevalue(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `CompareValue` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_comparevalue(self, value: u64) -> Result<Self, FieldOverflow> {
+        match Self::COMPAREVALUE_FIELD.try_insert(self.bits(), value) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl Display for CntpCvalEl0 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("CntpCvalEl0");
+        let bits = self.bits();
+        s.field(
+            "CompareValue",
+            &format_args!("{:#x}", Self::COMPAREVALUE_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for CntpCvalEl0 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "CntpCvalEl0 {{ CompareValue: {=u64:#x} }}",
+            Self::COMPAREVALUE_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for CntpCvalEl0 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CntpCvalEl0 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+impl CntpCvalEl0 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [FieldChange {
+            register,
+            field: Some("CompareValue"),
+            old: Self::COMPAREVALUE_FIELD.extract(self.bits()),
+            new: Self::COMPAREVALUE_FIELD.extract(other.bits()),
+        }]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+impl CntpCvalEl0 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+impl CntpCvalEl0 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+bitflags! {
+    /// `CNTP_TVAL_EL0` system register value.
+    ///
+    /// Counter-timer Physical Timer TimerValue Register
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct CntpTvalEl0: u64 {
+    }
+}
+
+impl CntpTvalEl0 {
+    /// Descriptor of the `TimerValue` field, for use with [`FieldAccess`].
+    pub const TIMERVALUE_FIELD: Field<Self, u32, 0, 32> = Field::new("CNTP_TVAL_EL0", "TimerValue");
+
+    /// Returns the value of the `TimerValue` field.
+    pub const fn timervalue(self) -> u32 {
+        Self::TIMERVALUE_FIELD.extract(self.bits()) as u32
+    }
+
+    /// Sets the value of the `TimerValue` field.
+    pub const fn set_timervalue(&mut self, value: u32) {
+        *self = Self::from_bits_retain(Self::TIMERVALUE_FIELD.insert(self.bits(), value as u64));
+    }
+
+    /// Returns a copy with the `TimerValue` field set to the given value.
+    pub const fn with_timervalue(mut self, value: u32) -> Self {
+        self.set_timervalue(value);
+        self
+    }
+
+    /// Sets the value of the `TimerValue` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_timervalue(&mut self, value: u32) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_timervalue(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `TimerValue` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_timervalue(self, value: u32) -> Result<Self, FieldOverflow> {
+        match Self::TIMERVALUE_FIELD.try_insert(self.bits(), value as u64) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl Display for CntpTvalEl0 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("CntpTvalEl0");
+        let bits = self.bits();
+        s.field(
+            "TimerValue",
+            &format_args!("{:#x}", Self::TIMERVALUE_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for CntpTvalEl0 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "CntpTvalEl0 {{ TimerValue: {=u64:#x} }}",
+            Self::TIMERVALUE_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for CntpTvalEl0 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CntpTvalEl0 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+impl CntpTvalEl0 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [
+            FieldChange {
+                register,
+                field: Some("TimerValue"),
+                old: Self::TIMERVALUE_FIELD.extract(self.bits()),
+                new: Self::TIMERVALUE_FIELD.extract(other.bits()),
+            },
+            FieldChange {
+                register,
+                field: None,
+                old: self.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_0000_0000_0000_0000_0000_0000_0000_0000,
+                new: other.bits() & 0b1111_1111_1111_1111_1111_1111_1111_1111_0000_0000_0000_0000_0000_0000_0000_0000,
+            },
+        ]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+impl CntpTvalEl0 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+impl CntpTvalEl0 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+bitflags! {
+    /// `CNTVCTSS_EL0` system register value.
+    ///
+    /// Counter-timer Self-Synchronized Virtual Count Register
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct CntvctssEl0: u64 {
+    }
+}
+
+impl CntvctssEl0 {
+    /// Descriptor of the `SSVirtualCount` field, for use with [`FieldAccess`].
+    pub const SSVIRTUALCOUNT_FIELD: Field<Self, u64, 0, 64> =
+        Field::new("CNTVCTSS_EL0", "SSVirtualCount");
+
+    /// Returns the value of the `SSVirtualCount` field.
+    pub const fn ssvirtualcount(self) -> u64 {
+        Self::SSVIRTUALCOUNT_FIELD.extract(self.bits())
+    }
+
+    /// Sets the value of the `SSVirtualCount` field.
+    pub const fn set_ssvirtualcount(&mut self, value: u64) {
+        *self = Self::from_bits_retain(Self::SSVIRTUALCOUNT_FIELD.insert(self.bits(), value));
+    }
+
+    /// Returns a copy with the `SSVirtualCount` field set to the given value.
+    pub const fn with_ssvirtualcount(mut self, value: u64) -> Self {
+        self.set_ssvirtualcount(value);
+        self
+    }
+
+    /// Sets the value of the `SSVirtualCount` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_ssvirtualcount(&mut self, value: u64) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_ssvirtualcount(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `SSVirtualCount` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_ssvirtualcount(self, value: u64) -> Result<Self, FieldOverflow> {
+        match Self::SSVIRTUALCOUNT_FIELD.try_insert(self.bits(), value) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl Display for CntvctssEl0 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("CntvctssEl0");
+        let bits = self.bits();
+        s.field(
+            "SSVirtualCount",
+            &format_args!("{:#x}", Self::SSVIRTUALCOUNT_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for CntvctssEl0 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "CntvctssEl0 {{ SSVirtualCount: {=u64:#x} }}",
+            Self::SSVIRTUALCOUNT_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for CntvctssEl0 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CntvctssEl0 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+impl CntvctssEl0 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [FieldChange {
+            register,
+            field: Some("SSVirtualCount"),
+            old: Self::SSVIRTUALCOUNT_FIELD.extract(self.bits()),
+            new: Self::SSVIRTUALCOUNT_FIELD.extract(other.bits()),
+        }]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+impl CntvctssEl0 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+impl CntvctssEl0 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+bitflags! {
+    /// `CNTVCT_EL0` system register value.
+    ///
+    /// Counter-timer Virtual Count Register
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct CntvctEl0: u64 {
+    }
+}
+
+impl CntvctEl0 {
+    /// Descriptor of the `VirtualCount` field, for use with [`FieldAccess`].
+    pub const VIRTUALCOUNT_FIELD: Field<Self, u64, 0, 64> =
+        Field::new("CNTVCT_EL0", "VirtualCount");
+
+    /// Returns the value of the `VirtualCount` field.
+    pub const fn virtualcount(self) -> u64 {
+        Self::VIRTUALCOUNT_FIELD.extract(self.bits())
+    }
+
+    /// Sets the value of the `VirtualCount` field.
+    pub const fn set_virtualcount(&mut self, value: u64) {
+        *self = Self::from_bits_retain(Self::VIRTUALCOUNT_FIELD.insert(self.bits(), value));
+    }
+
+    /// Returns a copy with the `VirtualCount` field set to the given value.
+    pub const fn with_virtualcount(mut self, value: u64) -> Self {
+        self.set_virtualcount(value);
+        self
+    }
+
+    /// Sets the value of the `VirtualCount` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_virtualcount(&mut self, value: u64) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_virtualcount(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `VirtualCount` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_virtualcount(self, value: u64) -> Result<Self, FieldOverflow> {
+        match Self::VIRTUALCOUNT_FIELD.try_insert(self.bits(), value) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl Display for CntvctEl0 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("CntvctEl0");
+        let bits = self.bits();
+        s.field(
+            "VirtualCount",
+            &format_args!("{:#x}", Self::VIRTUALCOUNT_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for CntvctEl0 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "CntvctEl0 {{ VirtualCount: {=u64:#x} }}",
+            Self::VIRTUALCOUNT_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for CntvctEl0 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CntvctEl0 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+impl CntvctEl0 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [FieldChange {
+            register,
+            field: Some("VirtualCount"),
+            old: Self::VIRTUALCOUNT_FIELD.extract(self.bits()),
+            new: Self::VIRTUALCOUNT_FIELD.extract(other.bits()),
+        }]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+impl CntvctEl0 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+impl CntvctEl0 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+#[cfg(feature = "el2")]
+bitflags! {
+    /// `CNTVOFF_EL2` system register value.
+    ///
+    /// Counter-timer Virtual Offset Register
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[repr(transparent)]
+    pub struct CntvoffEl2: u64 {
+    }
+}
+
+#[cfg(feature = "el2")]
+impl CntvoffEl2 {
+    /// Descriptor of the `VOffset` field, for use with [`FieldAccess`].
+    pub const VOFFSET_FIELD: Field<Self, u64, 0, 64> = Field::new("CNTVOFF_EL2", "VOffset");
+
+    /// Returns the value of the `VOffset` field.
+    pub const fn voffset(self) -> u64 {
+        Self::VOFFSET_FIELD.extract(self.bits())
+    }
+
+    /// Sets the value of the `VOffset` field.
+    pub const fn set_voffset(&mut self, value: u64) {
+        *self = Self::from_bits_retain(Self::VOFFSET_FIELD.insert(self.bits(), value));
+    }
+
+    /// Returns a copy with the `VOffset` field set to the given value.
+    pub const fn with_voffset(mut self, value: u64) -> Self {
+        self.set_voffset(value);
+        self
+    }
+
+    /// Sets the value of the `VOffset` field, or returns an error if the value doesn't fit.
+    pub const fn try_set_voffset(&mut self, value: u64) -> Result<(), FieldOverflow> {
+        *self = match self.try_with_voffset(value) {
+            Ok(value) => value,
+            Err(e) => return Err(e),
+        };
+        Ok(())
+    }
+
+    /// Returns a copy with the `VOffset` field set to the given value, or an error if the value
+    /// doesn't fit.
+    pub const fn try_with_voffset(self, value: u64) -> Result<Self, FieldOverflow> {
+        match Self::VOFFSET_FIELD.try_insert(self.bits(), value) {
+            Ok(bits) => Ok(Self::from_bits_retain(bits)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(feature = "el2")]
+impl Display for CntvoffEl2 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("CntvoffEl2");
+        let bits = self.bits();
+        s.field(
+            "VOffset",
+            &format_args!("{:#x}", Self::VOFFSET_FIELD.extract(bits)),
+        );
+        s.finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[cfg(feature = "el2")]
+impl defmt::Format for CntvoffEl2 {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "CntvoffEl2 {{ VOffset: {=u64:#x} }}",
+            Self::VOFFSET_FIELD.extract(self.bits())
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl serde::Serialize for CntvoffEl2 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&self.bits(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg(feature = "el2")]
+impl<'de> serde::Deserialize<'de> for CntvoffEl2 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl CntvoffEl2 {
+    /// Returns the fields whose values differ between `self` and `other`, as changes to the
+    /// given register.
+    ///
+    /// Changes to any bits outside the named fields are returned as a single change with no field
+    /// name.
+    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {
+        [FieldChange {
+            register,
+            field: Some("VOffset"),
+            old: Self::VOFFSET_FIELD.extract(self.bits()),
+            new: Self::VOFFSET_FIELD.extract(other.bits()),
+        }]
+        .into_iter()
+        .filter(|change| change.old != change.new)
+    }
+}
+
+#[cfg(feature = "el2")]
+impl CntvoffEl2 {
+    /// Mask of the bits which are RES0, regardless of which features are implemented.
+    pub const RES0_MASK: u64 = 0b0;
+    /// Mask of the bits which are RES1.
+    pub const RES1_MASK: u64 = 0b0;
+
+    /// Returns a copy with all RES0 bits cleared and all RES1 bits set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented are also cleared.
+    pub const fn sanitize(self, _features: Option<&CpuFeatures>) -> Self {
+        let bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;
+        Self::from_bits_retain(bits)
+    }
+
+    /// Returns whether all RES0 bits are clear and all RES1 bits are set.
+    ///
+    /// If `features` is given then fields which are RES0 because the features they depend on aren't
+    /// implemented must also be clear.
+    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {
+        self.bits() == self.sanitize(features).bits()
+    }
+}
+
+#[cfg(feature = "el2")]
+impl CntvoffEl2 {
+    /// Returns the fields which are set although a CPU with the given features doesn't implement
+    /// them.
+    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {
+        core::iter::empty()
+    }
+}
+
+/// `CNTV_CTL_EL0` system register value.
+///
+/// Counter-timer Virtual Timer Control Register
+pub type CntvCtlEl0 = CntpCtlEl0;
+
+/// `CNTV_CVAL_EL0` system register value.
+///
+/// Counter-timer Virtual Timer CompareValue Register
+pub type CntvCvalEl0 = CntpCvalEl0;
+
+/// `CNTV_TVAL_EL0` system register value.
+///
+/// Counter-timer Virtual Timer TimerValue Register
+pub type CntvTvalEl0 = CntpTvalEl0;
+
+#[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+read_write_sysreg!(cntfrq_el0, u64: CntfrqEl0, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg!(cnthctl_el2, u64: CnthctlEl2, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg!(cnthps_ctl_el2: s3_4_c14_c5_1, u64: CnthpsCtlEl2, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg!(cnthps_cval_el2: s3_4_c14_c5_2, u64: CnthpsCvalEl2, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg!(cnthps_tval_el2: s3_4_c14_c5_0, u64: CnthpsTvalEl2, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg!(cnthp_ctl_el2: s3_4_c14_c2_1, u64: CnthpCtlEl2, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg!(cnthp_cval_el2: s3_4_c14_c2_2, u64: CnthpCvalEl2, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg!(cnthp_tval_el2: s3_4_c14_c2_0, u64: CnthpTvalEl2, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg!(cnthvs_ctl_el2: s3_4_c14_c4_1, u64: CnthvsCtlEl2, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg!(cnthvs_cval_el2: s3_4_c14_c4_2, u64: CnthvsCvalEl2, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg!(cnthvs_tval_el2: s3_4_c14_c4_0, u64: CnthvsTvalEl2, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg!(cnthv_ctl_el2: s3_4_c14_c3_1, u64: CnthvCtlEl2, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg!(cnthv_cval_el2: s3_4_c14_c3_2, u64: CnthvCvalEl2, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg!(cnthv_tval_el2: s3_4_c14_c3_0, u64: CnthvTvalEl2, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_write_sysreg!(cntkctl_el1, u64: CntkctlEl1, safe_read, safe_write, fake::SYSREGS);
+#[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+read_sysreg!(cntpctss_el0: s3_3_c14_c0_5, u64: CntpctssEl0, safe, fake::SYSREGS);
+#[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+read_sysreg!(cntpct_el0, u64: CntpctEl0, safe, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg!(cntpoff_el2: s3_4_c14_c0_6, u64: CntpoffEl2, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_write_sysreg!(cntps_ctl_el1, u64: CntpsCtlEl1, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_write_sysreg!(cntps_cval_el1, u64: CntpsCvalEl1, safe_read, safe_write, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el1"))]
+read_write_sysreg!(cntps_tval_el1, u64: CntpsTvalEl1, safe_read, safe_write, fake::SYSREGS);
+#[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+read_write_sysreg!(cntp_ctl_el0, u64: CntpCtlEl0, safe_read, safe_write, fake::SYSREGS);
+#[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+read_write_sysreg!(cntp_cval_el0, u64: CntpCvalEl0, safe_read, safe_write, fake::SYSREGS);
+#[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+read_write_sysreg!(cntp_tval_el0, u64: CntpTvalEl0, safe_read, safe_write, fake::SYSREGS);
+#[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+read_sysreg!(cntvctss_el0: s3_3_c14_c0_6, u64: CntvctssEl0, safe, fake::SYSREGS);
+#[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+read_sysreg!(cntvct_el0, u64: CntvctEl0, safe, fake::SYSREGS);
+#[cfg(all(any(test, feature = "fakes", target_arch = "aarch64"), feature = "el2"))]
+read_write_sysreg!(cntvoff_el2, u64: CntvoffEl2, safe_read, safe_write, fake::SYSREGS);
+#[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+read_write_sysreg!(cntv_ctl_el0, u64: CntvCtlEl0, safe_read, safe_write, fake::SYSREGS);
+#[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+read_write_sysreg!(cntv_cval_el0, u64: CntvCvalEl0, safe_read, safe_write, fake::SYSREGS);
+#[cfg(any(test, feature = "fakes", target_arch = "aarch64"))]
+read_write_sysreg!(cntv_tval_el0, u64: CntvTvalEl0, safe_read, safe_write, fake::SYSREGS);
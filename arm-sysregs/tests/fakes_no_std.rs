@@ -0,0 +1,28 @@
+// SPDX-FileCopyrightText: Copyright The arm-sysregs Contributors.
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Tests for the fake system registers with the `fakes-no-std` feature, which uses spin locks and a
+//! single current PE for the whole program. The crate's own unit tests always use the `std`
+//! versions.
+
+#![cfg(feature = "fakes-no-std")]
+
+use arm_sysregs::{
+    Tpidrurw,
+    fake::{BOOT_PE, SYSREGS, current_pe, with_current_pe},
+    read_tpidrurw,
+};
+
+#[test]
+fn current_pe_and_locking() {
+    assert_eq!(current_pe(), BOOT_PE);
+    with_current_pe(0x100, || {
+        assert_eq!(current_pe(), 0x100);
+        SYSREGS.lock().unwrap().tpidrurw = Tpidrurw::from_bits_retain(42);
+        assert_eq!(read_tpidrurw().bits(), 42);
+    });
+    assert_eq!(current_pe(), BOOT_PE);
+    assert_eq!(read_tpidrurw().bits(), 0);
+    assert_eq!(SYSREGS.lock_pe(0x100).unwrap().tpidrurw.bits(), 42);
+    assert_eq!(SYSREGS.pes(), [BOOT_PE, 0x100]);
+}
@@ -0,0 +1,171 @@
+// SPDX-FileCopyrightText: Copyright The arm-sysregs Contributors.
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Checking that generated files on disk are up to date.
+
+use eyre::{Report, eyre};
+use similar::TextDiff;
+use std::{
+    cell::RefCell,
+    fs::{read_dir, read_to_string},
+    io::{self, ErrorKind, Write},
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    thread,
+};
+
+/// An in-memory output file, which can be written through shared references like a `File`.
+#[derive(Debug, Default)]
+pub struct OutputBuffer(RefCell<Vec<u8>>);
+
+impl OutputBuffer {
+    /// Returns the contents written to the buffer.
+    pub fn into_inner(self) -> Vec<u8> {
+        self.0.into_inner()
+    }
+}
+
+impl Write for &OutputBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.borrow_mut().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Compares the given generated files with those under `output_directory`, after formatting both,
+/// and prints a unified diff for each which differs.
+///
+/// Also reports any Rust files in `generated_directories` which aren't among the generated files,
+/// such as the module for a register family which no longer has any registers.
+///
+/// Returns the number of files which differ, are missing or are no longer generated.
+pub fn check_files(
+    output_directory: &Path,
+    files: &[(PathBuf, Vec<u8>)],
+    generated_directories: &[PathBuf],
+) -> Result<usize, Report> {
+    let mut outdated = 0;
+    for (path, contents) in files {
+        let generated = rustfmt(&String::from_utf8(contents.clone())?)?;
+        let existing = match read_to_string(output_directory.join(path)) {
+            Ok(existing) => rustfmt(&existing)?,
+            Err(e) if e.kind() == ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e.into()),
+        };
+        if existing != generated {
+            outdated += 1;
+            let path = path.display();
+            print!(
+                "{}",
+                TextDiff::from_lines(&existing, &generated)
+                    .unified_diff()
+                    .header(&format!("a/{path}"), &format!("b/{path}"))
+            );
+        }
+    }
+    for directory in generated_directories {
+        for path in stale_files(output_directory, directory, files)? {
+            outdated += 1;
+            println!("{} is no longer generated.", path.display());
+        }
+    }
+    Ok(outdated)
+}
+
+/// Returns the paths of any Rust files in `directory` under `output_directory` which aren't among
+/// the given generated files, relative to `output_directory`.
+///
+/// A missing directory is treated as empty.
+fn stale_files(
+    output_directory: &Path,
+    directory: &Path,
+    files: &[(PathBuf, Vec<u8>)],
+) -> Result<Vec<PathBuf>, Report> {
+    let entries = match read_dir(output_directory.join(directory)) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+    let mut stale = Vec::new();
+    for entry in entries {
+        let path = directory.join(entry?.file_name());
+        if path.extension().is_some_and(|extension| extension == "rs")
+            && !files.iter().any(|(generated, _)| *generated == path)
+        {
+            stale.push(path);
+        }
+    }
+    stale.sort();
+    Ok(stale)
+}
+
+/// Formats the given Rust source code with `rustfmt`.
+fn rustfmt(source: &str) -> Result<String, Report> {
+    let mut child = Command::new("rustfmt")
+        .args(["--edition", "2024", "--emit", "stdout"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| eyre!("Failed to run rustfmt: {e}"))?;
+    let mut stdin = child.stdin.take().unwrap();
+    // Write from another thread so that rustfmt can't block on a full stdout pipe.
+    let output = thread::scope(|scope| {
+        let writer = scope.spawn(move || stdin.write_all(source.as_bytes()));
+        let output = child.wait_with_output();
+        writer.join().unwrap()?;
+        output
+    })?;
+    if !output.status.success() {
+        return Err(eyre!(
+            "rustfmt failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+    Ok(String::from_utf8(output.stdout)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::{create_dir_all, write};
+
+    #[test]
+    fn output_buffer() {
+        let buffer = OutputBuffer::default();
+        write!(&buffer, "foo").unwrap();
+        writeln!(&buffer, " {}", 42).unwrap();
+        assert_eq!(buffer.into_inner(), b"foo 42\n");
+    }
+
+    #[test]
+    fn stale_registers_files() {
+        let temp_directory = tempfile::tempdir().unwrap();
+        let output_directory = temp_directory.path();
+        let registers = Path::new("src").join("registers");
+        create_dir_all(output_directory.join(&registers)).unwrap();
+        for name in ["gic.rs", "old.rs", "notes.txt"] {
+            write(output_directory.join(&registers).join(name), "").unwrap();
+        }
+
+        let files = [(registers.join("gic.rs"), Vec::new())];
+        assert_eq!(
+            stale_files(output_directory, &registers, &files).unwrap(),
+            [registers.join("old.rs")]
+        );
+    }
+
+    #[test]
+    fn stale_files_missing_directory() {
+        let output_directory = tempfile::tempdir().unwrap();
+        let registers = Path::new("src").join("registers");
+
+        assert_eq!(
+            stale_files(output_directory.path(), &registers, &[]).unwrap(),
+            [] as [PathBuf; 0]
+        );
+    }
+}
@@ -2,16 +2,171 @@
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
 use crate::Safety;
+use eyre::{Report, bail};
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct Config {
+    /// Cargo feature groups, mapped to the architecture features whose registers they gate.
+    ///
+    /// A register which requires any of the listed features, e.g. `FEAT_SVE`, is only available
+    /// when the `feat-<group>` cargo feature is enabled.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub feature_groups: BTreeMap<String, Vec<String>>,
+    /// Settings for all registers whose names match a pattern, where `*` matches any sequence of
+    /// characters, e.g. `ICC_*_EL1`.
+    ///
+    /// Settings in a register's own entry take precedence over those from groups, and groups with
+    /// more specific patterns take precedence over less specific ones. No two groups of equal
+    /// specificity may match the same register; see [`Config::check_groups`].
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub groups: BTreeMap<String, GroupConfig>,
     pub registers: BTreeMap<String, RegisterConfig>,
 }
 
-#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+impl Config {
+    /// Returns the configuration for the register with the given name, combining its own entry
+    /// with the settings of any groups which match it.
+    ///
+    /// Returns `None` if the register has no entry and doesn't match any group.
+    pub fn register_config(&self, name: &str) -> Option<RegisterConfig> {
+        let groups = self.matching_groups(name);
+        let register = self.registers.get(name);
+        if groups.is_empty() {
+            return register.cloned();
+        }
+
+        let mut config = RegisterConfig::default();
+        for (_, group) in groups {
+            group.apply_to(&mut config);
+        }
+        if let Some(register) = register {
+            let mut field_descriptions = config.field_descriptions;
+            field_descriptions.extend(register.field_descriptions.clone());
+            let mut types = config.types;
+            types.extend(register.types.clone());
+            let mut layouts = config.layouts;
+            layouts.extend(register.layouts.clone());
+            config = RegisterConfig {
+                field_descriptions,
+                types,
+                layouts,
+                read: register.read.or(config.read),
+                write: register.write.or(config.write),
+                write_safety_doc: register
+                    .write_safety_doc
+                    .clone()
+                    .or(config.write_safety_doc),
+                ..register.clone()
+            };
+        }
+        Some(config)
+    }
+
+    /// Returns the groups which match the given register name, least specific first so that more
+    /// specific ones can override them.
+    fn matching_groups(&self, name: &str) -> Vec<(&String, &GroupConfig)> {
+        let mut groups = self
+            .groups
+            .iter()
+            .filter(|(pattern, _)| pattern_matches(pattern, name))
+            .collect::<Vec<_>>();
+        groups.sort_by_key(|(pattern, _)| specificity(pattern));
+        groups
+    }
+
+    /// Checks that no two groups of equal specificity match any of the given register names, as
+    /// neither would take precedence.
+    pub fn check_groups(&self, names: &[&str]) -> Result<(), Report> {
+        for name in names {
+            for pair in self.matching_groups(name).windows(2) {
+                if specificity(pair[0].0) == specificity(pair[1].0) {
+                    bail!(
+                        "Groups {} and {} both match register {name} with equal precedence.",
+                        pair[0].0,
+                        pair[1].0
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the patterns of any groups which don't match any of the given register names.
+    pub fn unused_groups<'a>(&'a self, names: &[&str]) -> Vec<&'a str> {
+        self.groups
+            .keys()
+            .filter(|pattern| !names.iter().any(|name| pattern_matches(pattern, name)))
+            .map(String::as_str)
+            .collect()
+    }
+}
+
+/// Settings which apply to all registers in a group.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct GroupConfig {
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub field_descriptions: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub types: BTreeMap<String, String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub read: Option<AccessType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub write: Option<AccessType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub write_safety_doc: Option<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub layouts: BTreeMap<String, u64>,
+}
+
+impl GroupConfig {
+    /// Applies the group's settings to the given register configuration, overriding any which are
+    /// already set.
+    fn apply_to(&self, config: &mut RegisterConfig) {
+        config
+            .field_descriptions
+            .extend(self.field_descriptions.clone());
+        config.types.extend(self.types.clone());
+        if self.read.is_some() {
+            config.read = self.read;
+        }
+        if self.write.is_some() {
+            config.write = self.write;
+        }
+        if self.write_safety_doc.is_some() {
+            config.write_safety_doc = self.write_safety_doc.clone();
+        }
+        config.layouts.extend(self.layouts.clone());
+    }
+}
+
+/// Returns how specific the given group pattern is, as the number of characters other than `*`.
+fn specificity(pattern: &str) -> usize {
+    pattern.chars().filter(|&c| c != '*').count()
+}
+
+/// Returns whether the given name matches the pattern, where `*` matches any sequence of
+/// characters and everything else must match exactly.
+fn pattern_matches(pattern: &str, name: &str) -> bool {
+    match pattern.split_once('*') {
+        None => pattern == name,
+        Some((prefix, rest)) => {
+            let Some(name) = name.strip_prefix(prefix) else {
+                return false;
+            };
+            // Try every possible length for the sequence matched by the `*`.
+            name.char_indices()
+                .map(|(index, _)| index)
+                .chain([name.len()])
+                .any(|index| pattern_matches(rest, &name[index..]))
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct RegisterConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -20,9 +175,10 @@ pub struct RegisterConfig {
     pub field_descriptions: BTreeMap<String, String>,
     /// Types to use for each register fields.
     ///
-    /// They must be absolute paths and implement `TryFrom<uXX>`, where `uXX` is the smallest
-    /// unsigned type able to store the field. E.g. a 3-bits wide field must implement
-    /// `TryFrom<u8>`, while a 15-bits one needs `TryFrom<u16>`.
+    /// They must be absolute paths, have `const fn from_raw(value: uXX) -> Option<Self>` and
+    /// `const fn to_raw(self) -> uXX` methods and implement `FieldValue`, where `uXX` is the
+    /// smallest unsigned type able to store the field. E.g. a 3-bits wide field needs `u8`, while a
+    /// 15-bits one needs `u16`.
     #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
     pub types: BTreeMap<String, String>,
     /// If this is set it overrides the read access from the JSON input.
@@ -39,6 +195,24 @@ pub struct RegisterConfig {
     pub use_raw_name: bool,
     #[serde(default)]
     pub disable_alias: bool,
+    /// Whether the register has a single value shared by all PEs, rather than being banked per PE.
+    ///
+    /// This only affects the fake implementation, where a write on one simulated PE is seen by all
+    /// others.
+    #[serde(default)]
+    pub shared: bool,
+    /// Whether reading the register has side effects, such as acknowledging an interrupt, so it
+    /// shouldn't be read just to record its value.
+    #[serde(default)]
+    pub read_side_effects: bool,
+    /// The name of the register array which this register is part of, e.g. `MPAMVPM<n>_EL2`, for
+    /// registers which the JSON input lists separately rather than as an array.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub array: Option<String>,
+    /// Names for the layouts of a register with several, mapped to the value of the field which
+    /// selects each.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub layouts: BTreeMap<String, u64>,
 }
 
 #[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
@@ -58,3 +232,88 @@ impl From<AccessType> for Option<Safety> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn patterns() {
+        assert!(pattern_matches("ICC_*_EL1", "ICC_PMR_EL1"));
+        assert!(pattern_matches("ICC_*_EL1", "ICC__EL1"));
+        assert!(!pattern_matches("ICC_*_EL1", "ICC_PMR_EL2"));
+        assert!(pattern_matches("AMEVCNTR*", "AMEVCNTR0<n>_EL0"));
+        assert!(pattern_matches("*_EL*", "TTBR0_EL1"));
+        assert!(!pattern_matches("TTBR*_EL*", "TTBR0"));
+        assert!(pattern_matches("SCTLR_EL1", "SCTLR_EL1"));
+        assert!(!pattern_matches("SCTLR_EL1", "SCTLR_EL12"));
+    }
+
+    #[test]
+    fn group_precedence() {
+        let config: Config = toml::from_str(
+            r#"
+            [groups."ICC_*"]
+            write = "safe"
+            field_descriptions = { A = "Group A.", B = "Group B." }
+
+            [groups."ICC_SRE_*"]
+            write_safety_doc = "Group safety."
+
+            [groups."ICC_SRE_EL*"]
+            write = "unsafe"
+
+            [registers.ICC_PMR_EL1]
+
+            [registers.ICC_SRE_EL3]
+            write_safety_doc = "Register safety."
+            field_descriptions = { B = "Register B." }
+            "#,
+        )
+        .unwrap();
+
+        let pmr = config.register_config("ICC_PMR_EL1").unwrap();
+        assert_eq!(pmr.write, Some(AccessType::Safe));
+        assert_eq!(pmr.write_safety_doc, None);
+
+        let sre = config.register_config("ICC_SRE_EL3").unwrap();
+        assert_eq!(sre.write, Some(AccessType::Unsafe));
+        assert_eq!(sre.write_safety_doc.as_deref(), Some("Register safety."));
+        assert_eq!(sre.field_descriptions["A"], "Group A.");
+        assert_eq!(sre.field_descriptions["B"], "Register B.");
+
+        let sre2 = config.register_config("ICC_SRE_EL2").unwrap();
+        assert_eq!(sre2.write_safety_doc.as_deref(), Some("Group safety."));
+
+        assert_eq!(config.register_config("SCTLR_EL1"), None);
+        assert_eq!(
+            config.unused_groups(&["ICC_PMR_EL1", "ICC_SRE_EL3"]),
+            Vec::<&str>::new()
+        );
+        assert_eq!(
+            config.unused_groups(&["ICC_PMR_EL1"]),
+            ["ICC_SRE_*", "ICC_SRE_EL*"]
+        );
+    }
+
+    #[test]
+    fn check_groups() {
+        let config: Config = toml::from_str(
+            r#"
+            [groups."ICC_*"]
+            write = "unsafe"
+
+            [groups."ICC_SRE_*"]
+            write_safety_doc = "Group safety."
+
+            [groups."*_SRE_EL1"]
+            write_safety_doc = "Other group safety."
+
+            [registers.ICC_SRE_EL1]
+            "#,
+        )
+        .unwrap();
+        assert!(config.check_groups(&["ICC_SRE_EL2"]).is_ok());
+        assert!(config.check_groups(&["ICC_SRE_EL1"]).is_err());
+    }
+}
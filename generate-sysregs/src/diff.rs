@@ -0,0 +1,329 @@
+// SPDX-FileCopyrightText: Copyright The arm-sysregs Contributors.
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Comparison of the registers parsed from two versions of the JSON input.
+
+use crate::{RegisterField, RegisterInfo, Safety};
+use std::{
+    collections::BTreeMap,
+    io::{self, Write},
+};
+
+/// Changes between two versions of the registers, as changelog entries.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RegisterChanges {
+    /// Changes which don't break existing users of the generated crate.
+    pub features: Vec<String>,
+    /// Changes which may break existing users of the generated crate.
+    pub breaking: Vec<String>,
+}
+
+impl RegisterChanges {
+    /// Compares the old and new versions of the registers.
+    pub fn new(old: &[RegisterInfo], new: &[RegisterInfo]) -> Self {
+        let old = old
+            .iter()
+            .map(|register| (register.name.as_str(), register))
+            .collect::<BTreeMap<_, _>>();
+        let new = new
+            .iter()
+            .map(|register| (register.name.as_str(), register))
+            .collect::<BTreeMap<_, _>>();
+        let mut changes = Self::default();
+
+        let added = new
+            .keys()
+            .filter(|name| !old.contains_key(*name))
+            .copied()
+            .collect::<Vec<_>>();
+        if !added.is_empty() {
+            changes.features.push(format!(
+                "Added {} {}.",
+                name_list(&added),
+                plural("register", added.len())
+            ));
+        }
+        let removed = old
+            .keys()
+            .filter(|name| !new.contains_key(*name))
+            .copied()
+            .collect::<Vec<_>>();
+        if !removed.is_empty() {
+            changes.breaking.push(format!(
+                "{} {} removed.",
+                name_list(&removed),
+                plural("register", removed.len())
+            ));
+        }
+
+        for (name, old_register) in &old {
+            if let Some(new_register) = new.get(name) {
+                changes.add_register(old_register, new_register);
+            }
+        }
+        changes
+    }
+
+    /// Adds the changes between two versions of the same register.
+    fn add_register(&mut self, old: &RegisterInfo, new: &RegisterInfo) {
+        let name = &new.name;
+        if old.width != new.width {
+            self.breaking.push(format!(
+                "`{name}` changed from {} to {} bits.",
+                old.width, new.width
+            ));
+        }
+        self.add_access(new, "read", old.read, new.read);
+        self.add_access(new, "write", old.write, new.write);
+
+        let added = new
+            .fields
+            .iter()
+            .filter(|field| find_field(&old.fields, &field.name).is_none())
+            .map(|field| field.name.as_str())
+            .collect::<Vec<_>>();
+        if !added.is_empty() {
+            self.features.push(format!(
+                "`{name}` {} {} added.",
+                plain_list(&added),
+                plural("field", added.len())
+            ));
+        }
+        let removed = old
+            .fields
+            .iter()
+            .filter(|field| find_field(&new.fields, &field.name).is_none())
+            .map(|field| field.name.as_str())
+            .collect::<Vec<_>>();
+        if !removed.is_empty() {
+            self.breaking.push(format!(
+                "`{name}` {} {} removed.",
+                plain_list(&removed),
+                plural("field", removed.len())
+            ));
+        }
+
+        for old_field in &old.fields {
+            let Some(new_field) = find_field(&new.fields, &old_field.name) else {
+                continue;
+            };
+            let field_name = &old_field.name;
+            if old_field.width != new_field.width && old_field.index == new_field.index {
+                self.breaking.push(format!(
+                    "`{name}` {field_name} field resized from {} to {} bits.",
+                    old_field.width, new_field.width
+                ));
+            } else if old_field.width != new_field.width || old_field.index != new_field.index {
+                self.breaking.push(format!(
+                    "`{name}` {field_name} field moved from {} to {}.",
+                    bit_range(old_field),
+                    bit_range(new_field)
+                ));
+            }
+        }
+    }
+
+    /// Adds the change, if any, in whether and how safely the register can be read or written.
+    fn add_access(
+        &mut self,
+        register: &RegisterInfo,
+        access: &str,
+        old: Option<Safety>,
+        new: Option<Safety>,
+    ) {
+        let accessor = format!("{access}_{}", register.variable_name());
+        match (old, new) {
+            (None, Some(_)) => self.features.push(format!("Added `{accessor}` accessor.")),
+            (Some(_), None) => self
+                .breaking
+                .push(format!("`{accessor}` accessor removed.")),
+            (Some(Safety::Unsafe), Some(Safety::Safe)) => {
+                self.features.push(format!("`{accessor}` is now safe."));
+            }
+            (Some(Safety::Safe), Some(Safety::Unsafe)) => {
+                self.breaking.push(format!("`{accessor}` is now unsafe."));
+            }
+            _ => {}
+        }
+    }
+
+    /// Writes the changes as markdown sections suitable for the changelog.
+    pub fn write_markdown(&self, mut writer: impl Write) -> io::Result<()> {
+        let sections = [
+            ("New features", &self.features),
+            ("Breaking changes", &self.breaking),
+        ];
+        let mut first = true;
+        for (title, entries) in sections {
+            if entries.is_empty() {
+                continue;
+            }
+            if !first {
+                writeln!(writer)?;
+            }
+            first = false;
+            writeln!(writer, "### {title}")?;
+            writeln!(writer)?;
+            for entry in entries {
+                writeln!(writer, "- {entry}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns the field with the given name, if any.
+fn find_field<'a>(fields: &'a [RegisterField], name: &str) -> Option<&'a RegisterField> {
+    fields.iter().find(|field| field.name == name)
+}
+
+/// Returns the bits of the field in the notation of the Arm ARM, e.g. `[7:4]` or `[3]`.
+fn bit_range(field: &RegisterField) -> String {
+    if field.width == 1 {
+        format!("[{}]", field.index)
+    } else {
+        format!("[{}:{}]", field.index + field.width - 1, field.index)
+    }
+}
+
+/// Returns the given noun, pluralised if there isn't exactly one.
+fn plural(noun: &str, count: usize) -> String {
+    if count == 1 {
+        noun.to_owned()
+    } else {
+        format!("{noun}s")
+    }
+}
+
+/// Joins the given names in code spans into an English list, e.g. "`A`, `B` and `C`".
+fn name_list(names: &[&str]) -> String {
+    plain_list(
+        &names
+            .iter()
+            .map(|name| format!("`{name}`"))
+            .collect::<Vec<_>>(),
+    )
+}
+
+/// Joins the given items into an English list, e.g. "A, B and C".
+fn plain_list(items: &[impl AsRef<str>]) -> String {
+    match items {
+        [] => String::new(),
+        [item] => item.as_ref().to_owned(),
+        [rest @ .., last] => format!(
+            "{} and {}",
+            rest.iter()
+                .map(AsRef::as_ref)
+                .collect::<Vec<_>>()
+                .join(", "),
+            last.as_ref()
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(name: &str, index: u32, width: u32) -> RegisterField {
+        RegisterField {
+            name: name.to_owned(),
+            type_name: None,
+            description: None,
+            index,
+            width,
+            writable: true,
+            array_info: None,
+            values: None,
+            condition: None,
+        }
+    }
+
+    fn register(name: &str, fields: Vec<RegisterField>) -> RegisterInfo {
+        RegisterInfo {
+            name: name.to_owned(),
+            width: 64,
+            fields,
+            read: Some(Safety::Safe),
+            write: Some(Safety::Unsafe),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn register_changes() {
+        let old = [
+            register(
+                "SCTLR_EL1",
+                vec![field("M", 0, 1), field("A", 1, 1), field("EE", 25, 1)],
+            ),
+            register("TCR_EL2", vec![field("T0SZ", 0, 6), field("TVAD", 35, 1)]),
+            register("FOO_EL1", vec![]),
+        ];
+        let mut contextidr = register("CONTEXTIDR_EL1", vec![]);
+        contextidr.write = Some(Safety::Safe);
+        let new = [
+            register(
+                "SCTLR_EL1",
+                vec![field("M", 0, 1), field("EE", 26, 1), field("EPAN", 57, 1)],
+            ),
+            register("TCR_EL2", vec![field("T0SZ", 0, 7)]),
+            contextidr,
+        ];
+        let changes = RegisterChanges::new(&old, &new);
+        assert_eq!(
+            changes.features,
+            [
+                "Added `CONTEXTIDR_EL1` register.",
+                "`SCTLR_EL1` EPAN field added.",
+            ]
+        );
+        assert_eq!(
+            changes.breaking,
+            [
+                "`FOO_EL1` register removed.",
+                "`SCTLR_EL1` A field removed.",
+                "`SCTLR_EL1` EE field moved from [25] to [26].",
+                "`TCR_EL2` TVAD field removed.",
+                "`TCR_EL2` T0SZ field resized from 6 to 7 bits.",
+            ]
+        );
+    }
+
+    #[test]
+    fn access_changes() {
+        let old = register("CONTEXTIDR_EL1", vec![]);
+        let mut new = old.clone();
+        new.read = None;
+        new.write = Some(Safety::Safe);
+        let changes = RegisterChanges::new(&[old], &[new]);
+        assert_eq!(changes.features, ["`write_contextidr_el1` is now safe."]);
+        assert_eq!(
+            changes.breaking,
+            ["`read_contextidr_el1` accessor removed."]
+        );
+    }
+
+    #[test]
+    fn markdown() {
+        let changes = RegisterChanges {
+            features: vec!["Added `A` and `B` registers.".to_owned()],
+            breaking: vec!["`C` register removed.".to_owned()],
+        };
+        let mut output = Vec::new();
+        changes.write_markdown(&mut output).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "\
+### New features
+
+- Added `A` and `B` registers.
+
+### Breaking changes
+
+- `C` register removed.
+"
+        );
+        assert_eq!(name_list(&["A", "B", "C"]), "`A`, `B` and `C`");
+    }
+}
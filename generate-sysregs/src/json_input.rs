@@ -7,19 +7,20 @@
 mod conditions;
 
 use crate::{
-    AArch32Encoding, ArrayInfo, ExceptionLevel, RegisterField, RegisterInfo, Safety,
-    json_input::conditions::{Environment, EvalValue, Evaluable},
+    AArch32Encoding, ArrayInfo, ExceptionLevel, FeatureCondition, LayoutInfo, RegisterField,
+    RegisterInfo, Safety,
+    json_input::conditions::{Environment, EvalValue, Evaluable, FeatureValue, effective_field},
     ones,
 };
 use arm_sysregs_json::{
     Accessor, ArrayField, AstBinaryOp, AstBool, AstFunction, AstIdentifier, ConditionalField,
-    ConstantField, DynamicField, Encoding, Expression, Field, FieldEntry, Fieldset, Register,
-    RegisterArray, RegisterEntry, ValueEntry, Values, VectorField,
+    ConstantField, DynamicField, Encoding, Expression, Field, FieldEntry, Fieldset, Range,
+    Register, RegisterArray, RegisterEntry, ValueEntry, Values, VectorField,
 };
 use eyre::{Report, bail, eyre};
 use log::{info, trace};
 use regex::Regex;
-use std::{collections::BTreeMap, fmt::Write, sync::LazyLock};
+use std::{collections::BTreeMap, fmt::Write, slice, sync::LazyLock};
 
 static STANDARD_CONDITIONS: LazyLock<Vec<Expression>> = LazyLock::new(|| {
     vec![
@@ -116,8 +117,6 @@ impl RegisterInfo {
         if !STANDARD_CONDITIONS.contains(&register.condition) {
             trace!("condition for {}: {:#?}", register.name, register.condition);
         }
-        let exception_level = get_exception_level(&register.name);
-
         register
             .indexes
             .iter()
@@ -131,14 +130,16 @@ impl RegisterInfo {
                         width,
                         assembly_name,
                         aarch32_encoding,
+                        exception_level,
                     } = AccessorDetails::from_json_accessors(&register.accessors, Some(i));
+                    let exception_level = get_exception_level(&register.name, exception_level);
 
-                    let (fields, res1) =
+                    let (fields, res1, res0) =
                         convert_fields(&register.fieldsets, Some((&register.index_variable, i)));
                     let name = register
                         .name
                         .replace(&format!("<{}>", register.index_variable), &format!("{i}"));
-                    RegisterInfo {
+                    let register_info = RegisterInfo {
                         name,
                         original_name: register.name.clone(),
                         description: None,
@@ -147,6 +148,7 @@ impl RegisterInfo {
                         aarch64,
                         fields,
                         res1,
+                        res0,
                         read: readable.then_some(Safety::Safe),
                         write: writable.then_some(Safety::Unsafe),
                         write_safety_doc: None,
@@ -154,9 +156,27 @@ impl RegisterInfo {
                         assembly_name,
                         aarch32_encoding,
                         has_special_conditions: !STANDARD_CONDITIONS.contains(&register.condition),
+                        condition: feature_condition(
+                            &register.condition,
+                            &index_environment(Some((&register.index_variable, i))),
+                        ),
                         exception_level,
+                        feature_group: None,
                         alias: None,
                         disable_alias: false,
+                        shared: false,
+                        read_side_effects: false,
+                        array: Some((register.name.clone(), i)),
+                        layouts: Vec::new(),
+                        layout: None,
+                    };
+                    RegisterInfo {
+                        layouts: layouts(
+                            &register_info,
+                            &register.fieldsets,
+                            Some((&register.index_variable, i)),
+                        ),
+                        ..register_info
                     }
                 })
             })
@@ -167,8 +187,7 @@ impl RegisterInfo {
         if !STANDARD_CONDITIONS.contains(&register.condition) {
             trace!("condition for {}: {:#?}", register.name, register.condition);
         }
-        let (fields, res1) = convert_fields(&register.fieldsets, None);
-        let exception_level = get_exception_level(&register.name);
+        let (fields, res1, res0) = convert_fields(&register.fieldsets, None);
         let AccessorDetails {
             readable,
             writable,
@@ -177,9 +196,11 @@ impl RegisterInfo {
             width,
             assembly_name,
             aarch32_encoding,
+            exception_level,
         } = AccessorDetails::from_json_accessors(&register.accessors, None);
+        let exception_level = get_exception_level(&register.name, exception_level);
 
-        RegisterInfo {
+        let register_info = RegisterInfo {
             name: register.name.clone(),
             original_name: register.name.clone(),
             description: None,
@@ -188,6 +209,7 @@ impl RegisterInfo {
             aarch64,
             fields,
             res1,
+            res0,
             read: readable.then_some(Safety::Safe),
             write: writable.then_some(Safety::Unsafe),
             write_safety_doc: None,
@@ -195,50 +217,418 @@ impl RegisterInfo {
             assembly_name,
             aarch32_encoding,
             has_special_conditions: !STANDARD_CONDITIONS.contains(&register.condition),
+            condition: feature_condition(&register.condition, &Environment::default()),
             exception_level,
+            feature_group: None,
             alias: None,
             disable_alias: false,
+            shared: false,
+            read_side_effects: false,
+            array: None,
+            layouts: Vec::new(),
+            layout: None,
+        };
+        RegisterInfo {
+            layouts: layouts(&register_info, &register.fieldsets, None),
+            ..register_info
         }
     }
 }
 
+/// Converts the given fieldsets to a list of fields, and masks of the RES1 and RES0 bits.
 fn convert_fields(
     fieldsets: &[Fieldset],
     index_value: Option<(&str, u32)>,
-) -> (Vec<RegisterField>, u64) {
+) -> (Vec<RegisterField>, u64, u64) {
+    let environment = index_environment(index_value);
     let mut fields = Vec::new();
-    let mut res1 = 0;
+    // Bits are only RES1 or RES0 if they are so in every fieldset.
+    let mut res1 = if fieldsets.is_empty() { 0 } else { u64::MAX };
+    let mut res0 = res1;
     for fieldset in fieldsets {
+        let mut fieldset_res1 = 0;
+        let mut fieldset_res0 = 0;
         for field_entry in &fieldset.values {
             fields.extend(RegisterField::from_field_entry(field_entry, 0, index_value));
-            if let FieldEntry::Reserved(field) = field_entry
-                && field.value == "RES1"
-            {
-                for range in &field.rangeset {
-                    res1 |= ones(range.width) << range.start
+            match field_entry {
+                FieldEntry::Reserved(field) if field.value == "RES1" => {
+                    fieldset_res1 |= ranges_mask(&field.rangeset);
+                }
+                FieldEntry::Reserved(field) if field.value == "RES0" => {
+                    fieldset_res0 |= ranges_mask(&field.rangeset);
                 }
+                FieldEntry::ConditionalField(field)
+                    if field.reservedtype == "RES0"
+                        && field.fields.iter().all(|option| {
+                            !option.condition.eval(&environment).unwrap().could_be_true()
+                        }) =>
+                {
+                    fieldset_res0 |= ranges_mask(&field.rangeset);
+                }
+                _ => {}
             }
         }
+        res1 &= fieldset_res1;
+        res0 &= fieldset_res0;
     }
     fields.sort_by_key(|field| field.index);
     fields.dedup();
+    for field in &fields {
+        res0 &= !field.mask();
+    }
+
+    (fields, res1, res0)
+}
+
+/// Returns views of the given register for each of its layouts, if it has several which are
+/// selected by the value of the same field.
+///
+/// The layouts may be whole fieldsets, such as those of `PAR_EL1` which depend on `F`, or the
+/// options of a `ConditionalField` within a single fieldset, such as the ISS field of `ESR_EL1`
+/// which depends on `EC`. Registers whose fieldsets are selected by something other than the value
+/// of a single field, such as `ERXSTATUS_EL1` and `DBGDSCRext`, don't get views.
+fn layouts(
+    register: &RegisterInfo,
+    fieldsets: &[Fieldset],
+    index_value: Option<(&str, u32)>,
+) -> Vec<RegisterInfo> {
+    if let [fieldset] = fieldsets {
+        return conditional_field_layouts(register, fieldset, index_value);
+    }
+    let Some(layouts) = fieldsets
+        .iter()
+        .map(|fieldset| layout_info(&fieldset.condition, &register.name))
+        .collect::<Option<Vec<_>>>()
+    else {
+        return Vec::new();
+    };
+    if layouts.len() < 2
+        || layouts
+            .iter()
+            .any(|layout| layout.discriminant != layouts[0].discriminant)
+    {
+        return Vec::new();
+    }
+    let conditions = fieldsets
+        .iter()
+        .map(|fieldset| &fieldset.condition)
+        .collect::<Vec<_>>();
+    if !layouts
+        .iter()
+        .enumerate()
+        .all(|(i, layout)| selects_only(&register.name, layout, &conditions, i))
+    {
+        return Vec::new();
+    }
+
+    fieldsets
+        .iter()
+        .zip(layouts)
+        .map(|(fieldset, layout)| {
+            let (fields, res1, res0) = convert_fields(slice::from_ref(fieldset), index_value);
+            RegisterInfo {
+                fields,
+                res1,
+                res0,
+                layout: Some(layout),
+                ..register.clone()
+            }
+        })
+        .collect()
+}
+
+/// Returns views of the given register for each option of the first `ConditionalField` in its
+/// fieldset whose options are selected by the value of another field of the register.
+///
+/// Each option giving a `Dynamic` field with a single instance gets a view for every value of the
+/// discriminant which selects it, with the fields of the instance in place of the conditional
+/// field. Other options, such as those where the field is reserved, don't get views.
+fn conditional_field_layouts(
+    register: &RegisterInfo,
+    fieldset: &Fieldset,
+    index_value: Option<(&str, u32)>,
+) -> Vec<RegisterInfo> {
+    for (i, field_entry) in fieldset.values.iter().enumerate() {
+        let FieldEntry::ConditionalField(field) = field_entry else {
+            continue;
+        };
+        let [range] = field.rangeset.as_slice() else {
+            continue;
+        };
+        // The layouts selected by each option, one for each value of the discriminant.
+        let Some(option_layouts) = field
+            .fields
+            .iter()
+            .map(|option| {
+                disjuncts(&option.condition)
+                    .into_iter()
+                    .map(|condition| layout_info(condition, &register.name))
+                    .collect::<Option<Vec<_>>>()
+            })
+            .collect::<Option<Vec<_>>>()
+        else {
+            continue;
+        };
+        let Some(first_layout) = option_layouts.iter().flatten().next() else {
+            continue;
+        };
+        let conditions = field
+            .fields
+            .iter()
+            .map(|option| &option.condition)
+            .collect::<Vec<_>>();
+        if option_layouts.iter().enumerate().any(|(j, layouts)| {
+            layouts.iter().any(|layout| {
+                layout.external
+                    || layout.discriminant != first_layout.discriminant
+                    || !selects_only(&register.name, layout, &conditions, j)
+            })
+        }) {
+            continue;
+        }
+
+        let mut other_fields = fieldset.clone();
+        other_fields.values.remove(i);
+        let (fields, res1, res0) = convert_fields(slice::from_ref(&other_fields), index_value);
+        return field
+            .fields
+            .iter()
+            .zip(option_layouts)
+            .flat_map(|(option, layouts)| {
+                let FieldEntry::Dynamic(DynamicField { instances, .. }) = &option.field else {
+                    return Vec::new();
+                };
+                let [instance] = instances.as_slice() else {
+                    return Vec::new();
+                };
+                let (option_fields, option_res1, option_res0) =
+                    convert_fields(slice::from_ref(instance), index_value);
+                let mut fields = fields.clone();
+                fields.extend(option_fields.into_iter().map(|field| RegisterField {
+                    index: field.index + range.start,
+                    ..field
+                }));
+                fields.sort_by_key(|field| field.index);
+                layouts
+                    .into_iter()
+                    .map(|layout| RegisterInfo {
+                        fields: fields.clone(),
+                        res1: res1 | option_res1 << range.start,
+                        res0: res0 | option_res0 << range.start,
+                        layout: Some(layout),
+                        ..register.clone()
+                    })
+                    .collect()
+            })
+            .collect();
+    }
+    Vec::new()
+}
+
+/// Returns whether the value of the discriminant for the given layout makes only the condition at
+/// index `selected` of the given conditions true.
+fn selects_only(
+    register_name: &str,
+    layout: &LayoutInfo,
+    conditions: &[&Expression],
+    selected: usize,
+) -> bool {
+    let discriminant = if layout.external {
+        layout.discriminant.clone()
+    } else {
+        format!("{register_name}.{}", layout.discriminant)
+    };
+    // The value was converted from an `i64`, so converting it back can't fail.
+    let value = layout.value.try_into().unwrap();
+    let environment = Environment {
+        fields: [(discriminant, EvalValue::Integer(value))]
+            .into_iter()
+            .collect(),
+        ..Default::default()
+    };
+    conditions.iter().enumerate().all(|(i, condition)| {
+        condition
+            .eval(&environment)
+            .is_ok_and(|selected_value| selected_value.could_be_true() == (i == selected))
+    })
+}
+
+/// Returns the operands of the given condition if it is a chain of `||` operators, or otherwise
+/// just the condition itself.
+fn disjuncts(condition: &Expression) -> Vec<&Expression> {
+    match condition {
+        Expression::BinaryOp(binary_op) if binary_op.op == "||" => {
+            let mut operands = disjuncts(&binary_op.left);
+            operands.extend(disjuncts(&binary_op.right));
+            operands
+        }
+        _ => vec![condition],
+    }
+}
+
+/// Returns the layout selected by the given fieldset condition of the given register, if it
+/// requires some field to have a particular value.
+///
+/// The field may be part of the register itself, or of some other register such as `HCR_EL2.E2H`,
+/// either directly or through a function giving its effective value such as `EffectiveHCR_EL2_E2H`.
+fn layout_info(condition: &Expression, register_name: &str) -> Option<LayoutInfo> {
+    match condition {
+        Expression::BinaryOp(binary_op) if binary_op.op == "==" => {
+            let Expression::Value(value) = &*binary_op.right else {
+                return None;
+            };
+            let (discriminant, external) = match &*binary_op.left {
+                Expression::Field(field) if field.value.name == register_name => {
+                    (field.value.field.clone(), false)
+                }
+                Expression::Field(field) => {
+                    (format!("{}.{}", field.value.name, field.value.field), true)
+                }
+                Expression::Function(function) if function.arguments.is_empty() => {
+                    (effective_field(&function.name)?, true)
+                }
+                _ => return None,
+            };
+            let EvalValue::Integer(value) = value.eval(&Environment::default()).ok()? else {
+                return None;
+            };
+            let value = value.try_into().ok()?;
+            let field_name = discriminant
+                .rsplit_once('.')
+                .map_or(discriminant.as_str(), |(_, field)| field);
+            Some(LayoutInfo {
+                name: format!("{}{value}", field_name.to_lowercase()),
+                discriminant,
+                value,
+                external,
+            })
+        }
+        Expression::BinaryOp(binary_op) if binary_op.op == "&&" => {
+            layout_info(&binary_op.left, register_name)
+                .or_else(|| layout_info(&binary_op.right, register_name))
+        }
+        _ => None,
+    }
+}
 
-    (fields, res1)
+/// Returns a mask of the bits in the given ranges, ignoring any beyond the lower 64 bits.
+fn ranges_mask(ranges: &[Range]) -> u64 {
+    ranges
+        .iter()
+        .filter(|range| range.start < 64)
+        .map(|range| ones(range.width.min(64 - range.start)) << range.start)
+        .fold(0, |mask, range_mask| mask | range_mask)
 }
 
-fn get_exception_level(name: &str) -> ExceptionLevel {
-    if name.ends_with("_EL3") || name.ends_with("_mon") {
-        ExceptionLevel::El3
+/// Returns the features which must be implemented for the given condition to be true, if it depends
+/// only on features.
+fn feature_condition(
+    condition: &Expression,
+    environment: &Environment,
+) -> Option<FeatureCondition> {
+    match FeatureValue::from_expression(condition, environment)? {
+        FeatureValue::Condition(condition) => Some(condition),
+        FeatureValue::Known(_) => None,
+    }
+}
+
+/// Returns an environment for evaluating conditions with the given array index variable, if any.
+fn index_environment(index_value: Option<(&str, u32)>) -> Environment {
+    Environment {
+        variables: index_value
+            .into_iter()
+            .map(|(name, value)| (name.to_owned(), EvalValue::Integer(value.into())))
+            .collect(),
+        ..Default::default()
+    }
+}
+
+/// AArch32 registers in the CP14 and CP15 encoding spaces which can be accessed from EL0, subject to
+/// the enables and traps controlling them. These correspond to AArch64 registers with an `_EL0`
+/// suffix. All other AArch32 registers in those spaces need at least EL1.
+const AARCH32_EL0_REGISTERS: &[&str] = &[
+    "AMCFGR",
+    "AMCGCR",
+    "AMCNTENCLR0",
+    "AMCNTENCLR1",
+    "AMCNTENSET0",
+    "AMCNTENSET1",
+    "AMCR",
+    "AMEVCNTR0<n>",
+    "AMEVCNTR1<n>",
+    "AMEVTYPER0<n>",
+    "AMEVTYPER1<n>",
+    "AMUSERENR",
+    "CNTFRQ",
+    "CNTPCT",
+    "CNTPCTSS",
+    "CNTP_CTL",
+    "CNTP_CVAL",
+    "CNTP_TVAL",
+    "CNTVCT",
+    "CNTVCTSS",
+    "CNTV_CTL",
+    "CNTV_CVAL",
+    "CNTV_TVAL",
+    "DBGDIDR",
+    "DBGDRAR",
+    "DBGDSAR",
+    "DBGDSCRint",
+    "DBGDTRRXint",
+    "DBGDTRTXint",
+    "DLR",
+    "DSPSR",
+    "PMCCFILTR",
+    "PMCCNTR",
+    "PMCEID0",
+    "PMCEID1",
+    "PMCEID2",
+    "PMCEID3",
+    "PMCNTENCLR",
+    "PMCNTENSET",
+    "PMCR",
+    "PMEVCNTR<n>",
+    "PMEVTYPER<n>",
+    "PMOVSR",
+    "PMOVSSET",
+    "PMSELR",
+    "PMSWINC",
+    "PMUSERENR",
+    "PMXEVCNTR",
+    "PMXEVTYPER",
+    "TPIDRURO",
+    "TPIDRURW",
+];
+
+/// Returns the lowest exception level from which the register with the given name can be accessed,
+/// given the level derived from its accessor encodings if known.
+///
+/// Some registers are only accessible above the level in their name, such as `SP_EL0` which can
+/// only be accessed from EL1, while others have encodings which are redirected to them from a
+/// lower level, such as `BRBCR_EL2` being accessed as `BRBCR_EL1` from EL2 when `HCR_EL2.E2H` is
+/// set. So the higher of the two levels is used.
+///
+/// AArch32 registers without a suffix get EL1 from their encoding, unless they are listed in
+/// [`AARCH32_EL0_REGISTERS`].
+fn get_exception_level(name: &str, accessor_level: Option<ExceptionLevel>) -> ExceptionLevel {
+    if AARCH32_EL0_REGISTERS.contains(&name) {
+        return ExceptionLevel::El0;
+    }
+    let name_level = if name.ends_with("_EL3") || name.ends_with("_mon") {
+        Some(ExceptionLevel::El3)
     } else if name.ends_with("_EL2") || name.ends_with("_hyp") {
-        ExceptionLevel::El2
+        Some(ExceptionLevel::El2)
     } else if name.ends_with("_EL1") || name.ends_with("_svc") {
-        ExceptionLevel::El1
+        Some(ExceptionLevel::El1)
     } else if name.ends_with("_EL0") {
-        ExceptionLevel::El0
+        Some(ExceptionLevel::El0)
     } else {
+        None
+    };
+    name_level.max(accessor_level).unwrap_or_else(|| {
         info!("Assuming {} is available to EL0.", name);
         ExceptionLevel::El0
-    }
+    })
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
@@ -250,6 +640,8 @@ struct AccessorDetails {
     width: u32,
     assembly_name: Option<String>,
     aarch32_encoding: Option<AArch32Encoding>,
+    /// The lowest exception level from which any of the accessors can be used, if known.
+    exception_level: Option<ExceptionLevel>,
 }
 
 impl AccessorDetails {
@@ -267,6 +659,7 @@ impl AccessorDetails {
                         &system_accessor_array.encoding[0],
                         &values,
                     );
+                    details.add_exception_level(&system_accessor_array.encoding, &values);
                 }
                 Accessor::SystemAccessor(system_accessor) => {
                     details.add_from_name(
@@ -274,6 +667,7 @@ impl AccessorDetails {
                         &system_accessor.encoding[0],
                         &BTreeMap::new(),
                     );
+                    details.add_exception_level(&system_accessor.encoding, &BTreeMap::new());
                 }
                 _ => {}
             }
@@ -347,6 +741,51 @@ impl AccessorDetails {
             self.assembly_name = encoding_to_assembly_name(encoding, values);
         }
     }
+
+    /// Lowers `exception_level` to the lowest level from which any of the given encodings can be
+    /// accessed, if known.
+    fn add_exception_level(&mut self, encodings: &[Encoding], values: &BTreeMap<String, u32>) {
+        self.exception_level = encodings
+            .iter()
+            .filter_map(|encoding| encoding_exception_level(encoding, values))
+            .chain(self.exception_level)
+            .min();
+    }
+}
+
+/// Returns the lowest exception level from which a system register with the given encoding can be
+/// accessed, if the encoding determines it.
+///
+/// For AArch64 this is given by `op1`. For AArch32, Hyp and Monitor mode registers in the CP15
+/// encoding space use `opc1` 4 and 6 for 32-bit registers, and `opc1` 4 or 6 for 64-bit Hyp mode
+/// registers. All other registers in the CP14 and CP15 encoding spaces are treated as needing EL1,
+/// as only the few listed in [`AARCH32_EL0_REGISTERS`] can be accessed from EL0.
+fn encoding_exception_level(
+    encoding: &Encoding,
+    values: &BTreeMap<String, u32>,
+) -> Option<ExceptionLevel> {
+    if let Some(op1) = encoding.encodings.get("op1") {
+        return Some(match parse_binary_value(op1, values).ok()? {
+            3 => ExceptionLevel::El0,
+            4 | 5 => ExceptionLevel::El2,
+            6 => ExceptionLevel::El3,
+            _ => ExceptionLevel::El1,
+        });
+    }
+    match parse_binary_value(encoding.encodings.get("coproc")?, values).ok()? {
+        14 => return Some(ExceptionLevel::El1),
+        15 => {}
+        _ => return None,
+    }
+    let double = !encoding.encodings.contains_key("CRn");
+    Some(
+        match parse_binary_value(encoding.encodings.get("opc1")?, values).ok()? {
+            4 => ExceptionLevel::El2,
+            6 if double => ExceptionLevel::El2,
+            6 => ExceptionLevel::El3,
+            _ => ExceptionLevel::El1,
+        },
+    )
 }
 
 fn parse_binary_value(
@@ -500,30 +939,44 @@ impl RegisterField {
         index_value: Option<(&str, u32)>,
     ) -> Option<Self> {
         if let [range] = field.rangeset.as_slice() {
-            let environment = Environment {
-                variables: index_value
-                    .into_iter()
-                    .map(|(name, value)| (name.to_owned(), EvalValue::Integer(value.into())))
-                    .collect(),
-            };
+            let environment = index_environment(index_value);
             let mut bit = None;
-            for field in &field.fields {
-                if !field.condition.eval(&environment).unwrap().could_be_true() {
+            // The condition under which any of the options giving the field applies, if it can be
+            // determined and all options give the field.
+            let mut condition = Some(FeatureValue::Known(false));
+            for option in &field.fields {
+                if !option.condition.eval(&environment).unwrap().could_be_true() {
                     // Skip the field.
                     continue;
                 }
+                let option_field =
+                    Self::from_field_entry(&option.field, offset + range.start, index_value);
+                if option_field.is_none() {
+                    condition = None;
+                } else if let Some(current) = condition {
+                    condition = FeatureValue::from_expression(&option.condition, &environment)
+                        .map(|option_condition| current.or(option_condition));
+                }
                 if bit.is_none() {
-                    bit = Self::from_field_entry(&field.field, offset + range.start, index_value);
-                } else if Self::from_field_entry(&field.field, offset + range.start, index_value)
-                    .map(|r| r.key())
-                    != bit.as_ref().map(|r| r.key())
-                {
+                    bit = option_field;
+                } else if option_field.map(|r| r.key()) != bit.as_ref().map(|r| r.key()) {
                     // If different options give a different RegisterField, ignore them all to be
                     // safe.
                     return None;
                 }
             }
-            bit
+            // If no option applies then the field is reserved, so it only has a feature condition
+            // if it is RES0 otherwise.
+            if field.reservedtype == "RES0"
+                && let Some(FeatureValue::Condition(condition)) = condition
+            {
+                bit.map(|bit| RegisterField {
+                    condition: Some(condition),
+                    ..bit
+                })
+            } else {
+                bit
+            }
         } else {
             info!(
                 "Skipping conditional field with multiple ranges {:?}",
@@ -544,6 +997,7 @@ impl RegisterField {
                 writable: true,
                 array_info: None,
                 type_name: None,
+                condition: None,
                 values: field.values.clone(),
             })
         } else {
@@ -570,6 +1024,7 @@ impl RegisterField {
                         index_variable: field.index_variable.clone(),
                     }),
                     type_name: None,
+                    condition: None,
                     values: field.values.clone(),
                 })
             } else {
@@ -599,6 +1054,7 @@ impl RegisterField {
                 writable: false,
                 array_info: None,
                 type_name: None,
+                condition: None,
                 values: Some(Values {
                     values: vec![field.value.clone()],
                 }),
@@ -623,6 +1079,7 @@ impl RegisterField {
                 writable: true,
                 array_info: None,
                 type_name: None,
+                condition: None,
                 values: None,
             })
         } else {
@@ -649,6 +1106,7 @@ impl RegisterField {
                         index_variable: field.index_variable.clone(),
                     }),
                     type_name: None,
+                    condition: None,
                     values: field.values.clone(),
                 })
             } else {
@@ -671,7 +1129,249 @@ impl RegisterField {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use arm_sysregs_json::{EquationValue, Group, Range, Value};
+    use arm_sysregs_json::{
+        EquationValue, ExecutionState, FieldValue, Group, Range, TypesField, Value,
+    };
+
+    #[test]
+    fn exception_level_from_encoding() {
+        let value = |value: &str| {
+            ValueEntry::Value(Value {
+                meaning: None,
+                value: value.to_owned(),
+            })
+        };
+        let aarch64 = |op1| Encoding {
+            asmvalue: None,
+            encodings: [
+                ("op0".to_owned(), value("'11'")),
+                ("op1".to_owned(), value(op1)),
+                ("CRn".to_owned(), value("'0100'")),
+                ("CRm".to_owned(), value("'0001'")),
+                ("op2".to_owned(), value("'000'")),
+            ]
+            .into_iter()
+            .collect(),
+        };
+
+        // SP_EL1 is only accessible from EL2.
+        let level = encoding_exception_level(&aarch64("'100'"), &BTreeMap::new());
+        assert_eq!(level, Some(ExceptionLevel::El2));
+        assert_eq!(get_exception_level("SP_EL1", level), ExceptionLevel::El2);
+        // SP_EL0 is only accessible from EL1.
+        let level = encoding_exception_level(&aarch64("'000'"), &BTreeMap::new());
+        assert_eq!(get_exception_level("SP_EL0", level), ExceptionLevel::El1);
+        // The suffix is used if it is higher than the encoding.
+        assert_eq!(get_exception_level("BRBCR_EL2", level), ExceptionLevel::El2);
+        assert_eq!(get_exception_level("ACTLR", None), ExceptionLevel::El0);
+
+        let hcr = Encoding {
+            asmvalue: None,
+            encodings: [
+                ("coproc".to_owned(), value("'1111'")),
+                ("opc1".to_owned(), value("'100'")),
+                ("CRn".to_owned(), value("'0001'")),
+                ("CRm".to_owned(), value("'0001'")),
+                ("opc2".to_owned(), value("'000'")),
+            ]
+            .into_iter()
+            .collect(),
+        };
+        assert_eq!(
+            encoding_exception_level(&hcr, &BTreeMap::new()),
+            Some(ExceptionLevel::El2)
+        );
+
+        // PL1 registers in the CP15 encoding space have no suffix, but need EL1.
+        let sctlr = Encoding {
+            asmvalue: None,
+            encodings: [
+                ("coproc".to_owned(), value("'1111'")),
+                ("opc1".to_owned(), value("'000'")),
+                ("CRn".to_owned(), value("'0001'")),
+                ("CRm".to_owned(), value("'0000'")),
+                ("opc2".to_owned(), value("'000'")),
+            ]
+            .into_iter()
+            .collect(),
+        };
+        let level = encoding_exception_level(&sctlr, &BTreeMap::new());
+        assert_eq!(get_exception_level("SCTLR", level), ExceptionLevel::El1);
+        assert_eq!(get_exception_level("TPIDRURO", level), ExceptionLevel::El0);
+    }
+
+    #[test]
+    fn fieldset_layout_info() {
+        let field_equals = Expression::BinaryOp(AstBinaryOp {
+            op: "==".to_owned(),
+            left: Box::new(Expression::Field(TypesField {
+                value: FieldValue {
+                    field: "F".to_owned(),
+                    instance: None,
+                    name: "PAR_EL1".to_owned(),
+                    slices: None,
+                    state: ExecutionState::AArch64,
+                },
+            })),
+            right: Box::new(Expression::Value(Value {
+                meaning: None,
+                value: "'1'".to_owned(),
+            })),
+        });
+        assert_eq!(
+            layout_info(&field_equals, "PAR_EL1"),
+            Some(LayoutInfo {
+                name: "f1".to_owned(),
+                discriminant: "F".to_owned(),
+                value: 1,
+                external: false,
+            })
+        );
+
+        let feature_and_field_equals = Expression::BinaryOp(AstBinaryOp {
+            op: "&&".to_owned(),
+            left: Box::new(Expression::Function(AstFunction {
+                arguments: vec![Expression::Identifier(AstIdentifier {
+                    value: "FEAT_D128".to_owned(),
+                })],
+                name: "IsFeatureImplemented".to_owned(),
+                parameters: vec![],
+            })),
+            right: Box::new(field_equals),
+        });
+        assert_eq!(
+            layout_info(&feature_and_field_equals, "PAR_EL1")
+                .unwrap()
+                .discriminant,
+            "F"
+        );
+        assert_eq!(
+            layout_info(&feature_and_field_equals, "PAR").unwrap(),
+            LayoutInfo {
+                name: "f1".to_owned(),
+                discriminant: "PAR_EL1.F".to_owned(),
+                value: 1,
+                external: true,
+            }
+        );
+
+        assert_eq!(
+            layout_info(&Expression::Bool(AstBool { value: true }), "PAR_EL1"),
+            None
+        );
+    }
+
+    #[test]
+    fn conditional_field_layouts_by_value() {
+        let ec_equals = |ec: &str| {
+            serde_json::json!({
+                "_type": "AST.BinaryOp",
+                "left": {
+                    "_type": "Types.Field",
+                    "value": {
+                        "field": "EC",
+                        "instance": null,
+                        "name": "ESR_EL1",
+                        "slices": null,
+                        "state": "AArch64",
+                    },
+                },
+                "op": "==",
+                "right": { "_type": "Values.Value", "meaning": null, "value": ec },
+            })
+        };
+        let fieldset = |svc_condition| {
+            serde_json::from_value::<Fieldset>(serde_json::json!({
+                "condition": { "_type": "AST.Bool", "value": true },
+                "description": {},
+                "display": null,
+                "name": null,
+                "values": [
+                    {
+                        "_type": "Fields.ConditionalField",
+                        "description": {},
+                        "fields": [
+                            {
+                                "condition": ec_equals("'000000'"),
+                                "field": {
+                                    "_type": "Fields.Reserved",
+                                    "description": {},
+                                    "rangeset": [{ "start": 0, "width": 25 }],
+                                    "value": "RES0",
+                                },
+                            },
+                            {
+                                "condition": svc_condition,
+                                "field": {
+                                    "_type": "Fields.Dynamic",
+                                    "description": {},
+                                    "instances": [{
+                                        "condition": { "_type": "AST.Bool", "value": true },
+                                        "description": {},
+                                        "display": null,
+                                        "name": null,
+                                        "values": [{
+                                            "_type": "Fields.Field",
+                                            "description": {},
+                                            "name": "imm16",
+                                            "rangeset": [{ "start": 0, "width": 16 }],
+                                        }],
+                                        "width": 25,
+                                    }],
+                                    "name": "ISS",
+                                    "rangeset": [{ "start": 0, "width": 25 }],
+                                },
+                            },
+                        ],
+                        "name": "ISS",
+                        "rangeset": [{ "start": 0, "width": 25 }],
+                        "reservedtype": "UNKNOWN",
+                    },
+                    {
+                        "_type": "Fields.Field",
+                        "description": {},
+                        "name": "EC",
+                        "rangeset": [{ "start": 26, "width": 6 }],
+                    },
+                ],
+                "width": 64,
+            }))
+            .unwrap()
+        };
+        let register = RegisterInfo {
+            name: "ESR_EL1".to_owned(),
+            ..Default::default()
+        };
+
+        // Each value of `EC` selecting the option gets its own view, while the reserved option
+        // gets none.
+        let svc_or_hvc = serde_json::json!({
+            "_type": "AST.BinaryOp",
+            "left": ec_equals("'010101'"),
+            "op": "||",
+            "right": ec_equals("'010110'"),
+        });
+        let views = layouts(&register, &[fieldset(svc_or_hvc)], None);
+        assert_eq!(
+            views
+                .iter()
+                .map(|view| view.layout.as_ref().unwrap().name.as_str())
+                .collect::<Vec<_>>(),
+            ["ec21", "ec22"]
+        );
+        assert_eq!(
+            views[0]
+                .fields
+                .iter()
+                .map(|field| (field.name.as_str(), field.index))
+                .collect::<Vec<_>>(),
+            [("imm16", 0), ("EC", 26)]
+        );
+
+        // Options which overlap aren't selected by the value alone.
+        let views = layouts(&register, &[fieldset(ec_equals("'000000'"))], None);
+        assert_eq!(views, []);
+    }
 
     #[test]
     fn parse_value() {
@@ -3,18 +3,23 @@
 
 //! Logic for evaluating conditions.
 
+use crate::FeatureCondition;
 use arm_sysregs_json::{
     AstBinaryOp, AstBool, AstFunction, AstIdentifier, AstInteger, AstUnaryOp, Expression,
     TypesField, Value,
 };
 use eyre::{Context, Report, bail};
-use std::collections::BTreeMap;
+use std::{collections::BTreeMap, ops::Not};
 
 /// Environment for evaluating AST expressions.
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct Environment {
     /// Values to use for identifiers in the expression.
     pub variables: BTreeMap<String, EvalValue>,
+    /// Values to use for register fields in the expression, keyed by `REGISTER.FIELD`. These are
+    /// also used for the functions returning the effective value of a field, such as
+    /// `EffectiveHCR_EL2_E2H`.
+    pub fields: BTreeMap<String, EvalValue>,
 }
 
 /// The result of an AST expression.
@@ -55,6 +60,90 @@ impl Evaluable for Expression {
     }
 }
 
+/// The result of converting an AST expression to a condition on which features are implemented.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FeatureValue {
+    /// The expression has the given value regardless of which features are implemented.
+    Known(bool),
+    /// The expression is true if the given condition on features is met.
+    Condition(FeatureCondition),
+}
+
+impl FeatureValue {
+    /// Converts an AST expression to a condition on which features are implemented.
+    ///
+    /// Sub-expressions other than `IsFeatureImplemented` calls and boolean operators are evaluated
+    /// in the given environment. Returns `None` if any of them doesn't have a known boolean value.
+    pub fn from_expression(expression: &Expression, environment: &Environment) -> Option<Self> {
+        match expression {
+            Expression::Function(function) if function.name == "IsFeatureImplemented" => {
+                let [Expression::Identifier(feature)] = function.arguments.as_slice() else {
+                    return None;
+                };
+                if feature.value == "FEAT_AA64" {
+                    // AArch64 support is assumed for all registers.
+                    Some(Self::Known(true))
+                } else {
+                    Some(Self::Condition(FeatureCondition::Implemented(
+                        feature.value.clone(),
+                    )))
+                }
+            }
+            Expression::BinaryOp(binary_op) if binary_op.op == "&&" || binary_op.op == "||" => {
+                let left = Self::from_expression(&binary_op.left, environment)?;
+                let right = Self::from_expression(&binary_op.right, environment)?;
+                if binary_op.op == "&&" {
+                    Some(left.and(right))
+                } else {
+                    Some(left.or(right))
+                }
+            }
+            Expression::UnaryOp(unary_op) if unary_op.op == "!" => {
+                Some(!Self::from_expression(&unary_op.expr, environment)?)
+            }
+            _ => match expression.eval(environment) {
+                Ok(EvalValue::Boolean(value)) => Some(Self::Known(value)),
+                _ => None,
+            },
+        }
+    }
+
+    /// Returns a value which is true if both `self` and `other` are.
+    pub fn and(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::Known(true), value) | (value, Self::Known(true)) => value,
+            (Self::Known(false), _) | (_, Self::Known(false)) => Self::Known(false),
+            (Self::Condition(left), Self::Condition(right)) => {
+                Self::Condition(FeatureCondition::And(Box::new(left), Box::new(right)))
+            }
+        }
+    }
+
+    /// Returns a value which is true if either `self` or `other` is.
+    pub fn or(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::Known(false), value) | (value, Self::Known(false)) => value,
+            (Self::Known(true), _) | (_, Self::Known(true)) => Self::Known(true),
+            (Self::Condition(left), Self::Condition(right)) => {
+                Self::Condition(FeatureCondition::Or(Box::new(left), Box::new(right)))
+            }
+        }
+    }
+}
+
+impl Not for FeatureValue {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        match self {
+            Self::Known(value) => Self::Known(!value),
+            Self::Condition(condition) => {
+                Self::Condition(FeatureCondition::Not(Box::new(condition)))
+            }
+        }
+    }
+}
+
 impl Evaluable for AstBinaryOp {
     fn eval(&self, environment: &Environment) -> Result<EvalValue, Report> {
         let left = self.left.eval(environment)?;
@@ -110,8 +199,21 @@ impl Evaluable for AstIdentifier {
     }
 }
 
+/// Returns the `REGISTER.FIELD` name of the field whose effective value the given function returns,
+/// such as `HCR_EL2.E2H` for `EffectiveHCR_EL2_E2H`.
+pub fn effective_field(function_name: &str) -> Option<String> {
+    let (register, field) = function_name.strip_prefix("Effective")?.rsplit_once('_')?;
+    Some(format!("{register}.{field}"))
+}
+
 impl Evaluable for AstFunction {
-    fn eval(&self, _environment: &Environment) -> Result<EvalValue, Report> {
+    fn eval(&self, environment: &Environment) -> Result<EvalValue, Report> {
+        if self.arguments.is_empty()
+            && let Some(value) =
+                effective_field(&self.name).and_then(|field| environment.fields.get(&field))
+        {
+            return Ok(*value);
+        }
         match self.name.as_str() {
             "EffectiveHCR_EL2_E2H"
             | "HaveEL"
@@ -137,8 +239,12 @@ impl Evaluable for AstBool {
 }
 
 impl Evaluable for TypesField {
-    fn eval(&self, _environment: &Environment) -> Result<EvalValue, Report> {
-        Ok(EvalValue::Unknown)
+    fn eval(&self, environment: &Environment) -> Result<EvalValue, Report> {
+        Ok(environment
+            .fields
+            .get(&format!("{}.{}", self.value.name, self.value.field))
+            .copied()
+            .unwrap_or_default())
     }
 }
 
@@ -186,6 +292,7 @@ mod tests {
                     variables: [("n".to_owned(), EvalValue::Integer(0))]
                         .into_iter()
                         .collect(),
+                    ..Default::default()
                 })
                 .unwrap(),
             EvalValue::Unknown
@@ -196,9 +303,97 @@ mod tests {
                     variables: [("n".to_owned(), EvalValue::Integer(1))]
                         .into_iter()
                         .collect(),
+                    ..Default::default()
                 })
                 .unwrap(),
             EvalValue::Boolean(false)
         );
     }
+
+    #[test]
+    fn nmi_feature_condition() {
+        let expression = Expression::BinaryOp(AstBinaryOp {
+            op: "&&".to_owned(),
+            left: Box::new(Expression::Function(AstFunction {
+                arguments: vec![Expression::Identifier(AstIdentifier {
+                    value: "FEAT_GICv3_NMI".to_owned(),
+                })],
+                name: "IsFeatureImplemented".to_owned(),
+                parameters: vec![],
+            })),
+            right: Box::new(Expression::BinaryOp(AstBinaryOp {
+                op: "==".to_owned(),
+                left: Box::new(Expression::Identifier(AstIdentifier {
+                    value: "n".to_owned(),
+                })),
+                right: Box::new(Expression::Integer(AstInteger { value: 0 })),
+            })),
+        });
+
+        assert_eq!(
+            FeatureValue::from_expression(
+                &expression,
+                &Environment {
+                    variables: [("n".to_owned(), EvalValue::Integer(0))]
+                        .into_iter()
+                        .collect(),
+                    ..Default::default()
+                }
+            ),
+            Some(FeatureValue::Condition(FeatureCondition::Implemented(
+                "FEAT_GICv3_NMI".to_owned()
+            )))
+        );
+        assert_eq!(
+            FeatureValue::from_expression(
+                &expression,
+                &Environment {
+                    variables: [("n".to_owned(), EvalValue::Integer(1))]
+                        .into_iter()
+                        .collect(),
+                    ..Default::default()
+                }
+            ),
+            Some(FeatureValue::Known(false))
+        );
+        assert_eq!(
+            FeatureValue::from_expression(&expression, &Environment::default()),
+            None
+        );
+    }
+
+    #[test]
+    fn effective_field_value() {
+        let expression = Expression::BinaryOp(AstBinaryOp {
+            op: "==".to_owned(),
+            left: Box::new(Expression::Function(AstFunction {
+                arguments: vec![],
+                name: "EffectiveHCR_EL2_E2H".to_owned(),
+                parameters: vec![],
+            })),
+            right: Box::new(Expression::Integer(AstInteger { value: 1 })),
+        });
+
+        assert_eq!(
+            effective_field("EffectiveHCR_EL2_E2H").as_deref(),
+            Some("HCR_EL2.E2H")
+        );
+        assert_eq!(
+            expression.eval(&Environment::default()).unwrap(),
+            EvalValue::Unknown
+        );
+        for (value, expected) in [(0, false), (1, true)] {
+            assert_eq!(
+                expression
+                    .eval(&Environment {
+                        fields: [("HCR_EL2.E2H".to_owned(), EvalValue::Integer(value))]
+                            .into_iter()
+                            .collect(),
+                        ..Default::default()
+                    })
+                    .unwrap(),
+                EvalValue::Boolean(expected)
+            );
+        }
+    }
 }
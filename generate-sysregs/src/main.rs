@@ -1,26 +1,34 @@
 // SPDX-FileCopyrightText: Copyright The arm-sysregs Contributors.
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+mod check;
 mod config;
+mod diff;
 mod enums;
 mod json_input;
 mod output;
 
 use crate::{
+    check::{OutputBuffer, check_files},
     config::Config,
+    diff::RegisterChanges,
     enums::identify_enums,
     json_input::register_entries_to_register_infos,
-    output::{write_example, write_fake, write_lib},
+    output::{
+        register_families, write_backend, write_example, write_fake, write_family, write_features,
+        write_info_module, write_lib, write_registers, write_snapshot_module,
+    },
 };
 use arm_sysregs_json::{RegisterEntry, Values};
 use clap::{Parser, Subcommand};
-use eyre::Report;
+use eyre::{Report, eyre};
 use log::{info, warn};
 use std::{
-    collections::HashMap,
-    fs::{File, read_to_string},
+    collections::{BTreeSet, HashMap},
+    fs::{File, create_dir_all, read_to_string, write},
+    io,
     ops::Range,
-    path::PathBuf,
+    path::{Path, PathBuf},
 };
 
 fn main() -> Result<(), Report> {
@@ -34,23 +42,34 @@ fn main() -> Result<(), Report> {
     };
 
     println!("Parsed {} registers in total.", register_infos.len());
+    config.check_groups(
+        &register_infos
+            .iter()
+            .map(|register_info| register_info.original_name.as_str())
+            .collect::<Vec<_>>(),
+    )?;
 
     match args.command {
         Command::Generate { output_directory } => {
-            let output_lib = File::create(output_directory.join("src").join("lib.rs"))?;
-            let output_fake = File::create(
-                output_directory
-                    .join("src")
-                    .join("fake")
-                    .join("generated.rs"),
-            )?;
-            let output_example =
-                File::create(output_directory.join("examples").join("log_all.rs"))?;
-
             warn_missing(&register_infos, &config);
-            write_lib(&output_lib, &register_infos)?;
-            write_fake(&output_fake, &register_infos)?;
-            write_example(&output_example, &register_infos)?;
+            for (path, contents) in generate_files(&register_infos)? {
+                let path = output_directory.join(path);
+                if let Some(parent) = path.parent() {
+                    create_dir_all(parent)?;
+                }
+                write(path, contents)?;
+            }
+        }
+        Command::Check { output_directory } => {
+            let outdated = check_files(
+                &output_directory,
+                &generate_files(&register_infos)?,
+                &[registers_directory()],
+            )?;
+            if outdated > 0 {
+                return Err(eyre!("{outdated} of the generated files are out of date."));
+            }
+            println!("Generated files are up to date.");
         }
         Command::Enums {
             generate_stubs,
@@ -58,11 +77,76 @@ fn main() -> Result<(), Report> {
         } => {
             identify_enums(&register_infos, generate_stubs, skip_existing);
         }
+        Command::Diff {
+            new_registers_json,
+            output_markdown,
+        } => {
+            let new_register_infos = parse_registers(&config, new_registers_json, args.all)?;
+            let changes = RegisterChanges::new(&register_infos, &new_register_infos);
+            println!(
+                "Found {} changes, {} of them breaking.",
+                changes.features.len() + changes.breaking.len(),
+                changes.breaking.len()
+            );
+            changes.write_markdown(File::create(output_markdown)?)?;
+        }
     }
 
     Ok(())
 }
 
+/// Returns the directory for register family modules, relative to the output directory. All Rust
+/// files in it are generated.
+fn registers_directory() -> PathBuf {
+    Path::new("src").join("registers")
+}
+
+/// Generates the contents of all generated files, with their paths relative to the output
+/// directory.
+fn generate_files(register_infos: &[RegisterInfo]) -> io::Result<Vec<(PathBuf, Vec<u8>)>> {
+    let src = Path::new("src");
+    let registers_directory = registers_directory();
+    let mut files = Vec::new();
+    let mut add_file =
+        |path: PathBuf, write_file: &dyn Fn(&OutputBuffer) -> io::Result<()>| -> io::Result<()> {
+            let buffer = OutputBuffer::default();
+            write_file(&buffer)?;
+            files.push((path, buffer.into_inner()));
+            Ok(())
+        };
+
+    add_file(src.join("lib.rs"), &|writer| write_lib(writer))?;
+    let families = register_families(register_infos);
+    add_file(src.join("registers.rs"), &|writer| {
+        write_registers(writer, &families.keys().copied().collect::<Vec<_>>())
+    })?;
+    for (family, registers) in &families {
+        add_file(
+            registers_directory.join(format!("{family}.rs")),
+            &|writer| write_family(writer, family, registers),
+        )?;
+    }
+    add_file(registers_directory.join("info.rs"), &|writer| {
+        write_info_module(writer, register_infos)
+    })?;
+    add_file(registers_directory.join("snapshot.rs"), &|writer| {
+        write_snapshot_module(writer, register_infos)
+    })?;
+    add_file(src.join("fake").join("generated.rs"), &|writer| {
+        write_fake(writer, register_infos)
+    })?;
+    add_file(src.join("backend").join("generated.rs"), &|writer| {
+        write_backend(writer, register_infos)
+    })?;
+    add_file(src.join("features").join("generated.rs"), &|writer| {
+        write_features(writer, register_infos)
+    })?;
+    add_file(Path::new("examples").join("log_all.rs"), &|writer| {
+        write_example(writer, register_infos)
+    })?;
+    Ok(files)
+}
+
 fn parse_and_alias_registers(
     config: &Config,
     registers_json: PathBuf,
@@ -76,6 +160,10 @@ fn parse_and_alias_registers(
     register_map.reserve(register_infos.len());
 
     for register_info in &mut register_infos {
+        // The views of a register with several layouts are specific to it.
+        if !register_info.layouts.is_empty() {
+            continue;
+        }
         let register_type_key = RegisterTypeKey::from(&*register_info);
         if !register_info.disable_alias
             && let Some(existing_register) = register_map.get(&register_type_key)
@@ -116,6 +204,14 @@ fn parse_registers(
         remove_clashes(register);
         add_details(register, config);
         remove_over_64bit(register);
+        for layout in &mut register.layouts {
+            remove_clashes(layout);
+            add_details(layout, config);
+            remove_over_64bit(layout);
+            // Manual `Debug` implementations are only provided for the main register type.
+            layout.derive_debug = true;
+            layout.feature_group = register.feature_group.clone();
+        }
     }
 
     register_infos.sort_by_cached_key(|register| register.name.clone());
@@ -124,7 +220,8 @@ fn parse_registers(
     Ok(register_infos)
 }
 
-/// Logs warnings for any registers which are present in the config file but not the JSON file.
+/// Logs warnings for any registers which are present in the config file but not the JSON file, and
+/// any groups which don't match any registers.
 fn warn_missing(register_infos: &[RegisterInfo], config: &Config) {
     for register_name in config.registers.keys() {
         if !register_infos
@@ -137,6 +234,13 @@ fn warn_missing(register_infos: &[RegisterInfo], config: &Config) {
             );
         }
     }
+    let names = register_infos
+        .iter()
+        .map(|register_info| register_info.original_name.as_str())
+        .collect::<Vec<_>>();
+    for pattern in config.unused_groups(&names) {
+        warn!("Group {pattern} in config file doesn't match any registers.");
+    }
 }
 
 /// Removes any fields which have the same name as each other, and only keep one copy of any that
@@ -185,8 +289,32 @@ fn remove_over_64bit(register: &mut RegisterInfo) {
 }
 
 fn add_details(register: &mut RegisterInfo, config: &Config) {
-    if let Some(register_config) = config.registers.get(&register.original_name) {
+    register.feature_group = register
+        .condition
+        .as_ref()
+        .and_then(|condition| feature_group(condition, config));
+    if let Some(register_config) = config.register_config(&register.original_name) {
         register.disable_alias = register_config.disable_alias;
+        register.shared = register_config.shared;
+        register.read_side_effects = register_config.read_side_effects;
+        if let Some(layout) = &mut register.layout
+            && let Some((name, _)) = register_config
+                .layouts
+                .iter()
+                .find(|(_, value)| **value == layout.value)
+        {
+            layout.name = name.clone();
+        }
+        if let Some(array_name) = &register_config.array {
+            if let Some(index) = array_index(array_name, &register.name) {
+                register.array = Some((array_name.clone(), index));
+            } else {
+                warn!(
+                    "Register {} doesn't match the name of array {}.",
+                    register.name, array_name
+                );
+            }
+        }
 
         if let Some(description) = &register_config.description {
             register.description = Some(description.clone());
@@ -213,6 +341,36 @@ fn add_details(register: &mut RegisterInfo, config: &Config) {
     }
 }
 
+/// Returns the feature group from the config whose cargo feature gates registers with the given
+/// condition, if any.
+///
+/// Only features which the condition requires to be implemented are considered, so a feature which
+/// is negated or is one of several alternatives doesn't put the register in a group.
+fn feature_group(condition: &FeatureCondition, config: &Config) -> Option<String> {
+    let mut features = BTreeSet::new();
+    condition.add_required_features(&mut features);
+    config
+        .feature_groups
+        .iter()
+        .find(|(_, group_features)| {
+            group_features
+                .iter()
+                .any(|feature| features.contains(feature))
+        })
+        .map(|(group, _)| group.clone())
+}
+
+/// Returns the index of the register with the given name within the given register array, e.g. 3
+/// for `MPAMVPM3_EL2` in `MPAMVPM<n>_EL2`.
+fn array_index(array_name: &str, name: &str) -> Option<u32> {
+    let (prefix, rest) = array_name.split_once('<')?;
+    let (_, suffix) = rest.split_once('>')?;
+    name.strip_prefix(prefix)?
+        .strip_suffix(suffix)?
+        .parse()
+        .ok()
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 struct RegisterField {
     /// The name of the field.
@@ -231,6 +389,8 @@ struct RegisterField {
     pub array_info: Option<ArrayInfo>,
     /// Possible values that this field can hold, if specified.
     pub values: Option<Values>,
+    /// The features which must be implemented for the field to exist, if it is RES0 otherwise.
+    pub condition: Option<FeatureCondition>,
 }
 
 impl RegisterField {
@@ -245,6 +405,60 @@ impl RegisterField {
     fn is_incompatible_with(&self, other: &Self) -> bool {
         self.name == other.name && self.key() != other.key()
     }
+
+    /// Returns a mask of the bits of the field, including all elements if it is an array, ignoring
+    /// any beyond the lower 64 bits.
+    fn mask(&self) -> u64 {
+        if self.index >= 64 {
+            return 0;
+        }
+        let count = self
+            .array_info
+            .as_ref()
+            .map_or(1, |array_info| array_info.indices.len() as u32);
+        ones((self.width * count).min(64 - self.index)) << self.index
+    }
+}
+
+/// A condition on which optional architecture features are implemented.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum FeatureCondition {
+    /// The feature with the given name, e.g. `FEAT_PAuth`, is implemented.
+    Implemented(String),
+    Not(Box<FeatureCondition>),
+    And(Box<FeatureCondition>, Box<FeatureCondition>),
+    Or(Box<FeatureCondition>, Box<FeatureCondition>),
+}
+
+impl FeatureCondition {
+    /// Adds the names of all features referenced by the condition to `features`.
+    fn add_features(&self, features: &mut BTreeSet<String>) {
+        match self {
+            Self::Implemented(feature) => {
+                features.insert(feature.clone());
+            }
+            Self::Not(condition) => condition.add_features(features),
+            Self::And(left, right) | Self::Or(left, right) => {
+                left.add_features(features);
+                right.add_features(features);
+            }
+        }
+    }
+
+    /// Adds the names of all features which must be implemented for the condition to hold to
+    /// `features`.
+    fn add_required_features(&self, features: &mut BTreeSet<String>) {
+        match self {
+            Self::Implemented(feature) => {
+                features.insert(feature.clone());
+            }
+            Self::And(left, right) => {
+                left.add_required_features(features);
+                right.add_required_features(features);
+            }
+            Self::Not(_) | Self::Or(_, _) => {}
+        }
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
@@ -275,6 +489,8 @@ struct RegisterInfo {
     pub fields: Vec<RegisterField>,
     /// All the bits which are RES1.
     pub res1: u64,
+    /// All the bits which are RES0, regardless of which features are implemented.
+    pub res0: u64,
     pub read: Option<Safety>,
     pub write: Option<Safety>,
     pub write_safety_doc: Option<String>,
@@ -285,13 +501,45 @@ struct RegisterInfo {
     ///
     /// For example, it might require certain CPU features.
     pub has_special_conditions: bool,
+    /// The features which must be implemented for the register to exist, if known.
+    pub condition: Option<FeatureCondition>,
     /// The lowest exception level at which this system register is accessible.
     pub exception_level: ExceptionLevel,
+    /// The group of optional features, if any, whose cargo feature must be enabled for the register
+    /// to be available.
+    pub feature_group: Option<String>,
     /// Whether to disable type aliasing for this specific register.
     /// Populated from the register configuration.
     pub disable_alias: bool,
     /// In case of identical registers, and aliases enabled, the name of the base register.
     pub alias: Option<String>,
+    /// Whether the register has the same value on all PEs, rather than being banked per PE.
+    /// Populated from the register configuration.
+    pub shared: bool,
+    /// Whether reading the register has side effects, so it is left out of snapshots.
+    /// Populated from the register configuration.
+    pub read_side_effects: bool,
+    /// The name of the register array which this register is part of, and its index within it.
+    pub array: Option<(String, u32)>,
+    /// Views of the register for each of its layouts, if it has several selected by the value of
+    /// a field.
+    pub layouts: Vec<RegisterInfo>,
+    /// The layout which this view of another register is for.
+    pub layout: Option<LayoutInfo>,
+}
+
+/// Identifies one of several layouts of a register.
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct LayoutInfo {
+    /// The name of the layout, used to name its view type.
+    pub name: String,
+    /// The name of the field which selects the layout.
+    pub discriminant: String,
+    /// The value of the discriminating field for which the layout applies.
+    pub value: u64,
+    /// Whether the discriminating field is part of some other register, such as `HCR_EL2.E2H`, so
+    /// the layout can't be determined from the register value alone.
+    pub external: bool,
 }
 
 /// Helper struct to identify register field types.
@@ -304,6 +552,7 @@ struct RegisterFieldTypeKey {
     width: u32,
     writable: bool,
     array_info: Option<ArrayInfo>,
+    condition: Option<FeatureCondition>,
 }
 
 /// Helper struct to identify register types.
@@ -313,12 +562,14 @@ struct RegisterTypeKey {
     aarch32: bool,
     aarch64: bool,
     res1: u64,
+    res0: u64,
     read: Option<Safety>,
     write: Option<Safety>,
     write_safety_doc: Option<String>,
     derive_debug: bool,
     aarch32_encoding: Option<AArch32Encoding>,
     has_special_conditions: bool,
+    condition: Option<FeatureCondition>,
     exception_level: ExceptionLevel,
     fields: Vec<RegisterFieldTypeKey>,
 }
@@ -333,6 +584,7 @@ impl From<&RegisterField> for RegisterFieldTypeKey {
             width: value.width,
             writable: value.writable,
             array_info: value.array_info.clone(),
+            condition: value.condition.clone(),
         }
     }
 }
@@ -344,12 +596,14 @@ impl From<&RegisterInfo> for RegisterTypeKey {
             aarch32: register.aarch32,
             aarch64: register.aarch64,
             res1: register.res1,
+            res0: register.res0,
             read: register.read,
             write: register.write,
             write_safety_doc: register.write_safety_doc.clone(),
             derive_debug: register.derive_debug,
             aarch32_encoding: register.aarch32_encoding.clone(),
             has_special_conditions: register.has_special_conditions,
+            condition: register.condition.clone(),
             exception_level: register.exception_level,
             fields: register
                 .fields
@@ -376,7 +630,7 @@ enum AArch32Encoding {
     },
 }
 
-#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
 enum ExceptionLevel {
     #[default]
     El0,
@@ -398,6 +652,11 @@ enum Command {
         /// Path to output directory.
         output_directory: PathBuf,
     },
+    /// Checks that the generated files are up to date, printing a diff of any which aren't.
+    Check {
+        /// Path to the directory containing the generated files.
+        output_directory: PathBuf,
+    },
     /// Scans the register values to identify fields that could be represented as Rust enums.
     Enums {
         /// Generate a stub implementation for the encountered enums, along with the corresponding
@@ -408,6 +667,14 @@ enum Command {
         #[arg(long)]
         skip_existing: bool,
     },
+    /// Compares the registers with those in a newer JSON file, and writes the changes as changelog
+    /// markdown.
+    Diff {
+        /// Path to the newer JSON system registers file.
+        new_registers_json: PathBuf,
+        /// Path to output markdown file.
+        output_markdown: PathBuf,
+    },
 }
 
 #[derive(Clone, Debug, Parser)]
@@ -478,6 +745,87 @@ mod tests {
         Args::command().debug_assert();
     }
 
+    #[test]
+    fn register_array_index() {
+        assert_eq!(array_index("MPAMVPM<n>_EL2", "MPAMVPM3_EL2"), Some(3));
+        assert_eq!(array_index("AMEVCNTR1<n>_EL0", "AMEVCNTR115_EL0"), Some(15));
+        assert_eq!(array_index("MPAMVPM<n>_EL2", "MPAMVPMV_EL2"), None);
+        assert_eq!(array_index("MPAMVPM<n>_EL2", "MPAMIDR_EL1"), None);
+    }
+
+    #[test]
+    fn register_feature_group() {
+        let config: Config = toml::from_str(
+            r#"
+            [feature_groups]
+            gic = ["FEAT_GICv3"]
+            sve = ["FEAT_SVE", "FEAT_SME"]
+
+            [registers]
+            "#,
+        )
+        .unwrap();
+        let implemented = |feature: &str| FeatureCondition::Implemented(feature.to_owned());
+
+        assert_eq!(
+            feature_group(&implemented("FEAT_SME"), &config),
+            Some("sve".to_owned())
+        );
+        assert_eq!(
+            feature_group(
+                &FeatureCondition::And(
+                    Box::new(implemented("FEAT_AA64")),
+                    Box::new(implemented("FEAT_GICv3"))
+                ),
+                &config
+            ),
+            Some("gic".to_owned())
+        );
+        assert_eq!(feature_group(&implemented("FEAT_PAuth"), &config), None);
+    }
+
+    #[test]
+    fn register_feature_group_not_required() {
+        let config: Config = toml::from_str(
+            r#"
+            [feature_groups]
+            gic = ["FEAT_GICv3"]
+
+            [registers]
+            "#,
+        )
+        .unwrap();
+        let implemented = |feature: &str| FeatureCondition::Implemented(feature.to_owned());
+
+        assert_eq!(
+            feature_group(
+                &FeatureCondition::Not(Box::new(implemented("FEAT_GICv3"))),
+                &config
+            ),
+            None
+        );
+        assert_eq!(
+            feature_group(
+                &FeatureCondition::Or(
+                    Box::new(implemented("FEAT_AA64")),
+                    Box::new(implemented("FEAT_GICv3"))
+                ),
+                &config
+            ),
+            None
+        );
+        assert_eq!(
+            feature_group(
+                &FeatureCondition::And(
+                    Box::new(implemented("FEAT_AA64")),
+                    Box::new(FeatureCondition::Not(Box::new(implemented("FEAT_GICv3"))))
+                ),
+                &config
+            ),
+            None
+        );
+    }
+
     #[test]
     fn remove_clashing_names() {
         let mut register = RegisterInfo {
@@ -491,6 +839,7 @@ mod tests {
                     array_info: None,
                     type_name: None,
                     values: None,
+                    condition: None,
                 },
                 RegisterField {
                     name: "FOO".to_string(),
@@ -501,6 +850,7 @@ mod tests {
                     array_info: None,
                     type_name: None,
                     values: None,
+                    condition: None,
                 },
                 RegisterField {
                     name: "BAR".to_string(),
@@ -511,6 +861,7 @@ mod tests {
                     array_info: None,
                     type_name: None,
                     values: None,
+                    condition: None,
                 },
                 RegisterField {
                     name: "BAZ".to_string(),
@@ -521,6 +872,7 @@ mod tests {
                     array_info: None,
                     type_name: None,
                     values: None,
+                    condition: None,
                 },
             ],
             ..Default::default()
@@ -539,6 +891,7 @@ mod tests {
                         array_info: None,
                         type_name: None,
                         values: None,
+                        condition: None,
                     },
                     RegisterField {
                         name: "BAZ".to_string(),
@@ -549,6 +902,7 @@ mod tests {
                         array_info: None,
                         type_name: None,
                         values: None,
+                        condition: None,
                     },
                 ],
                 ..Default::default()
@@ -4,14 +4,79 @@
 //! Logic for writing out a Rust source file with system register types and accessors.
 
 use crate::{
-    AArch32Encoding, ExceptionLevel, RegisterField, RegisterInfo, Safety, ones,
-    separated_binary_literal,
+    AArch32Encoding, ArrayInfo, ExceptionLevel, FeatureCondition, RegisterField, RegisterInfo,
+    Safety, ones, separated_binary_literal,
+};
+use log::warn;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    io::{self, Write},
 };
-use std::io::{self, Write};
-
-const RESERVED_NAMES: &[&str] = &["extend", "type"];
 
-pub fn write_lib(mut writer: impl Write + Copy, registers: &[RegisterInfo]) -> io::Result<()> {
+const RESERVED_NAMES: &[&str] = &["extend", "set", "type"];
+
+/// Families which registers are grouped into for the generated modules, with the module
+/// documentation and register name prefixes for each.
+///
+/// A register goes in the family with the longest prefix of its name. Registers which only exist in
+/// AArch32 state go in the `aarch32` family, and those matching no prefix in `control`.
+const FAMILIES: &[(&str, &str, &[&str])] = &[
+    ("amu", "Activity monitors", &["AM"]),
+    (
+        "debug",
+        "Self-hosted debug and trace",
+        &[
+            "BRB", "DBG", "DLR", "DSPSR", "MDCCINT", "MDCR", "MDSCR", "OSDLR", "OSECCR", "OSLAR",
+            "OSLSR", "TRFCR",
+        ],
+    ),
+    (
+        "exception",
+        "Exception handling",
+        &[
+            "AFSR", "ELR", "ESR", "FAR", "HPFAR", "ISR", "PFAR", "RVBAR", "SPSR", "VBAR",
+        ],
+    ),
+    ("gic", "GIC CPU interface", &["ICC_", "ICH_", "ICV_"]),
+    (
+        "id",
+        "Identification",
+        &[
+            "AIDR", "CCSIDR", "CLIDR", "CSSELR", "CTR", "DCZID", "GMID", "ID_", "MIDR", "MPIDR",
+            "REVIDR", "SMIDR", "VMPIDR", "VPIDR",
+        ],
+    ),
+    (
+        "mmu",
+        "Memory management and address translation",
+        &[
+            "AMAIR", "GPCCR", "GPTBR", "MAIR", "PAR", "PIR", "POR", "S2PIR", "S2POR", "TCR",
+            "TTBR", "VTCR", "VTTBR",
+        ],
+    ),
+    ("mpam", "Memory partitioning and monitoring", &["MPAM"]),
+    ("mte", "Memory tagging", &["GCR_", "RGSR", "TFSR"]),
+    (
+        "pauth",
+        "Pointer authentication",
+        &["APDA", "APDB", "APGA", "APIA", "APIB"],
+    ),
+    ("pmu", "Performance monitors", &["PM"]),
+    (
+        "ras",
+        "Reliability, availability, and serviceability",
+        &["DISR", "ERR", "ERX", "VDISR", "VSESR"],
+    ),
+    (
+        "sve",
+        "Scalable vector and matrix extensions",
+        &["SMCR", "SMPRI", "SVCR", "ZCR"],
+    ),
+    ("timer", "Generic timer", &["CNT"]),
+];
+
+/// Writes the crate root, which declares the modules and re-exports their public items.
+pub fn write_lib(mut writer: impl Write) -> io::Result<()> {
     writer.write_all(
         "\
 // SPDX-FileCopyrightText: Copyright The arm-sysregs Contributors.
@@ -21,74 +86,528 @@ pub fn write_lib(mut writer: impl Write + Copy, registers: &[RegisterInfo]) -> i
 
 // This file is generated, do not edit manually.
 
-#![cfg_attr(not(any(test, feature = \"fakes\")), no_std)]
+#![cfg_attr(
+    not(any(test, all(feature = \"fakes\", not(feature = \"fakes-no-std\")))),
+    no_std
+)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+#[cfg(any(test, feature = \"fakes\"))]
+extern crate alloc;
+
 #[cfg(all(not(any(test, feature = \"fakes\")), target_arch = \"arm\"))]
 mod aarch32;
 #[cfg(all(not(any(test, feature = \"fakes\")), target_arch = \"aarch64\"))]
 mod aarch64;
+#[cfg(feature = \"backend\")]
+pub mod backend;
 #[cfg(any(test, feature = \"fakes\"))]
 pub mod fake;
+mod features;
+mod field;
 mod macros;
 mod manual;
+mod registers;
 
-use bitflags::bitflags;
+#[cfg(feature = \"backend\")]
+pub use backend::SysregId;
+pub use features::{CpuFeatures, Feature};
+pub use field::{Field, FieldAccess, FieldValue};
 pub use manual::*;
 #[doc(hidden)]
 pub use paste as _paste;
+pub use registers::*;
+"
+        .as_bytes(),
+    )
+}
+
+/// Groups registers by the family module which they are generated in.
+pub fn register_families(registers: &[RegisterInfo]) -> BTreeMap<&'static str, Vec<RegisterInfo>> {
+    let mut families = BTreeMap::<_, Vec<_>>::new();
+    for register in registers {
+        families
+            .entry(register.family())
+            .or_default()
+            .push(register.clone());
+    }
+    families
+}
+
+/// Writes the `registers` module, which declares the module for each register family and
+/// re-exports their contents.
+pub fn write_registers(mut writer: impl Write, families: &[&str]) -> io::Result<()> {
+    writer.write_all(
+        "\
+// SPDX-FileCopyrightText: Copyright The arm-sysregs Contributors.
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! System register types and accessors, in a module for each family of registers.
+
+// This file is generated, do not edit manually.
+
+// Depending on the enabled features, some families might not have any registers.
+#![allow(unused_imports)]
+
 "
         .as_bytes(),
     )?;
+    for family in families {
+        writeln!(writer, "mod {family};")?;
+    }
+    writeln!(writer, "mod info;")?;
+    writeln!(writer, "mod snapshot;")?;
+    writeln!(writer)?;
+    for family in families {
+        writeln!(writer, "pub use {family}::*;")?;
+    }
+    writeln!(writer, "pub use snapshot::RegisterSnapshot;")?;
+    Ok(())
+}
+
+/// Writes the module with the types and accessors for the given family of registers.
+pub fn write_family(
+    mut writer: impl Write + Copy,
+    family: &str,
+    registers: &[RegisterInfo],
+) -> io::Result<()> {
+    let description = if family == "aarch32" {
+        "AArch32"
+    } else {
+        FAMILIES
+            .iter()
+            .find(|(name, _, _)| *name == family)
+            .map_or("Other", |(_, description, _)| description)
+    };
+    writeln!(
+        writer,
+        "\
+// SPDX-FileCopyrightText: Copyright The arm-sysregs Contributors.
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! {description} system registers.
+
+// This file is generated, do not edit manually.
+
+// Depending on the enabled features, the module might not use all of these imports.
+#![allow(unused_imports)]
+
+use crate::*;
+use bitflags::bitflags;
+use core::fmt::{{self, Display, Formatter}};"
+    )?;
 
     for register in registers {
         if register.use_struct() {
             writeln!(writer)?;
             register.write_lib(writer)?;
+            for layout in &register.layouts {
+                writeln!(writer)?;
+                layout.write_lib(writer)?;
+            }
+            register.write_layout_views(writer)?;
         }
     }
     writeln!(writer)?;
     for register in registers {
         register.write_accessor(writer)?;
     }
+    write_indexed_accessors(writer, registers)?;
 
     Ok(())
 }
 
-pub fn write_fake(mut writer: impl Write + Copy, registers: &[RegisterInfo]) -> io::Result<()> {
+/// Writes the module with the `RegisterSnapshot` struct.
+pub fn write_snapshot_module(mut writer: impl Write, registers: &[RegisterInfo]) -> io::Result<()> {
+    writer.write_all(
+        "\
+// SPDX-FileCopyrightText: Copyright The arm-sysregs Contributors.
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Snapshots of the values of all safely readable system registers.
+
+// This file is generated, do not edit manually.
+
+// Depending on the enabled features, some registers might not be included, so the features passed
+// to `capture` might not be used.
+#![allow(unused_imports, unused_variables)]
+
+use crate::*;
+
+"
+        .as_bytes(),
+    )?;
+    write_snapshot(&mut writer, registers)?;
+    writer.write_all(
+        "
+/// Deserialises the version of a snapshot, rejecting versions newer than
+/// [`RegisterSnapshot::VERSION`] as they might store registers differently.
+#[cfg(feature = \"serde\")]
+fn deserialize_version<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<u32, D::Error> {
+    let version = <u32 as serde::Deserialize>::deserialize(deserializer)?;
+    if version > RegisterSnapshot::VERSION {
+        return Err(serde::de::Error::custom(format_args!(
+            \"unsupported snapshot version {version}, expected at most {}\",
+            RegisterSnapshot::VERSION
+        )));
+    }
+    Ok(version)
+}
+"
+        .as_bytes(),
+    )
+}
+
+/// Writes the module with the `_sysreg_info!` macro, which `read_many!` and `write_many!` use to
+/// look up the assembly name and type of each AArch64 register.
+pub fn write_info_module(mut writer: impl Write, registers: &[RegisterInfo]) -> io::Result<()> {
     writer.write_all(
         "\
 // SPDX-FileCopyrightText: Copyright The arm-sysregs Contributors.
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
+//! Details of AArch64 system registers for accessing several at once.
+
 // This file is generated, do not edit manually.
 
+/// Expands to the assembly name of the given system register, or converts a value of it to or from
+/// a `u64`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _sysreg_info {
 "
         .as_bytes(),
     )?;
+    for register in registers {
+        if register.aarch32_encoding.is_some()
+            || (register.read.is_none() && register.write.is_none())
+        {
+            continue;
+        }
+        let name = register.variable_name();
+        let assembly_name = register.assembly_name.as_deref().unwrap_or(&name);
+        let crate_prefix = if register.use_struct() {
+            "$crate::"
+        } else {
+            ""
+        };
+        writeln!(
+            writer,
+            "    (asm_name {name}) => {{ \"{assembly_name}\" }};"
+        )?;
+        writeln!(
+            writer,
+            "    (from_u64 {name}, $value:expr) => {{ {crate_prefix}{} }};",
+            register.expr_from_u64("$value")
+        )?;
+        writeln!(
+            writer,
+            "    (to_u64 {name}, $value:expr) => {{ {} }};",
+            register.expr_to_u64("$value")
+        )?;
+    }
+    writeln!(writer, "}}")
+}
+
+/// Writes functions to read and write each register array by index, dispatching to the accessor
+/// for the individual register.
+fn write_indexed_accessors(mut writer: impl Write, registers: &[RegisterInfo]) -> io::Result<()> {
+    let mut arrays = BTreeMap::<&str, Vec<(u32, &RegisterInfo)>>::new();
+    for register in registers {
+        if let Some((array_name, index)) = &register.array {
+            arrays
+                .entry(array_name)
+                .or_default()
+                .push((*index, register));
+        }
+    }
 
-    for exception_level in [
-        ExceptionLevel::El0,
-        ExceptionLevel::El1,
-        ExceptionLevel::El2,
-        ExceptionLevel::El3,
-    ] {
-        let struct_names = registers
+    for (array_name, mut elements) in arrays {
+        elements.sort_by_key(|(index, _)| *index);
+        let (_, first) = elements[0];
+        if elements.iter().any(|(_, register)| {
+            register.width != first.width || register.cfg_guard() != first.cfg_guard()
+        }) {
+            warn!("Not generating indexed accessors for inconsistent register array {array_name}.");
+            continue;
+        }
+        // Remove the index placeholder, e.g. `AMEVCNTR1<n>_EL0` becomes `amevcntr1_el0`.
+        let (prefix, rest) = array_name.split_once('<').unwrap_or((array_name, ""));
+        let (_, suffix) = rest.split_once('>').unwrap_or_default();
+        let function_name = lowercase_name(&format!("{prefix}{suffix}"));
+        // Use the register type if all elements share it, otherwise the raw value.
+        let element_type = |register: &RegisterInfo| {
+            register.use_struct().then(|| {
+                register
+                    .alias_struct_name()
+                    .unwrap_or_else(|| register.struct_name())
+            })
+        };
+        let shared_type = element_type(first);
+        let value_type = if elements
             .iter()
-            .filter(|register| register.use_struct() && register.exception_level == exception_level)
-            .map(RegisterInfo::struct_name)
-            .collect::<Vec<_>>()
-            .join(", ");
-        if let Some(guard) = exception_level.cfg_guard() {
-            writeln!(writer, "{guard}")?;
+            .all(|(_, register)| element_type(register) == shared_type)
+        {
+            shared_type.clone()
+        } else {
+            None
+        };
+        let convert = value_type.is_none() && shared_type.is_some();
+        let value_type = value_type.unwrap_or_else(|| format!("u{}", first.width));
+
+        if let Some(read_safety) = first.read
+            && elements
+                .iter()
+                .all(|(_, register)| register.read == Some(read_safety))
+        {
+            writeln!(writer)?;
+            writeln!(
+                writer,
+                "/// Returns the value of the `{array_name}` system register with the given index, or `None` if"
+            )?;
+            writeln!(writer, "/// there is no such register.")?;
+            let unsafe_fn = if read_safety == Safety::Unsafe {
+                writeln!(writer, "///")?;
+                writeln!(writer, "/// # Safety")?;
+                writeln!(writer, "///")?;
+                writeln!(
+                    writer,
+                    "/// The caller must ensure that it is safe to read the `{array_name}` system register."
+                )?;
+                "unsafe "
+            } else {
+                ""
+            };
+            if let Some(guard) = first.cfg_guard() {
+                writeln!(writer, "{guard}")?;
+            }
+            writeln!(
+                writer,
+                "pub {unsafe_fn}fn read_{function_name}(n: usize) -> Option<{value_type}> {{"
+            )?;
+            if read_safety == Safety::Unsafe {
+                writeln!(
+                    writer,
+                    "    // SAFETY: The caller promises that it is safe to read the register."
+                )?;
+                writeln!(writer, "    unsafe {{")?;
+            }
+            // The register is encoded in the instruction, so a match is needed to pick the right
+            // one. This compiles to a jump table.
+            writeln!(writer, "    match n {{")?;
+            for (index, register) in &elements {
+                let bits = if convert && register.use_struct() {
+                    ".bits()"
+                } else {
+                    ""
+                };
+                writeln!(
+                    writer,
+                    "        {index} => Some(read_{}(){bits}),",
+                    register.variable_name()
+                )?;
+            }
+            writeln!(writer, "        _ => None,")?;
+            writeln!(writer, "    }}")?;
+            if read_safety == Safety::Unsafe {
+                writeln!(writer, "    }}")?;
+            }
+            writeln!(writer, "}}")?;
+        }
+
+        if let Some(write_safety) = first.write
+            && elements
+                .iter()
+                .all(|(_, register)| register.write == Some(write_safety))
+        {
+            writeln!(writer)?;
+            writeln!(
+                writer,
+                "/// Writes `value` to the `{array_name}` system register with the given index."
+            )?;
+            writeln!(writer, "///")?;
+            writeln!(writer, "/// # Panics")?;
+            writeln!(writer, "///")?;
+            writeln!(
+                writer,
+                "/// Panics if there is no register with the given index."
+            )?;
+            let unsafe_fn = if write_safety == Safety::Unsafe {
+                let safety_doc = first.write_safety_doc.clone().unwrap_or_else(|| {
+                    format!(
+                        "The caller must ensure that `value` is a correct and safe value to write to the `{array_name}` system register."
+                    )
+                });
+                writeln!(writer, "///")?;
+                writeln!(writer, "/// # Safety")?;
+                writeln!(writer, "///")?;
+                writeln!(writer, "/// {safety_doc}")?;
+                "unsafe "
+            } else {
+                ""
+            };
+            if let Some(guard) = first.cfg_guard() {
+                writeln!(writer, "{guard}")?;
+            }
+            writeln!(
+                writer,
+                "pub {unsafe_fn}fn write_{function_name}(n: usize, value: {value_type}) {{"
+            )?;
+            if write_safety == Safety::Unsafe {
+                writeln!(
+                    writer,
+                    "    // SAFETY: The caller promises that `value` is safe to write to the register."
+                )?;
+                writeln!(writer, "    unsafe {{")?;
+            }
+            writeln!(writer, "    match n {{")?;
+            for (index, register) in &elements {
+                let value = if convert && register.use_struct() {
+                    format!("{}::from_bits_retain(value)", register.struct_name())
+                } else {
+                    "value".to_owned()
+                };
+                writeln!(
+                    writer,
+                    "        {index} => write_{}({value}),",
+                    register.variable_name()
+                )?;
+            }
+            writeln!(
+                writer,
+                "        _ => panic!(\"Invalid index {{n}} for {array_name}\"),"
+            )?;
+            writeln!(writer, "    }}")?;
+            if write_safety == Safety::Unsafe {
+                writeln!(writer, "    }}")?;
+            }
+            writeln!(writer, "}}")?;
+        }
+    }
+    Ok(())
+}
+
+/// Writes the `RegisterSnapshot` struct, with a field for every safely readable register.
+fn write_snapshot(mut writer: impl Write, registers: &[RegisterInfo]) -> io::Result<()> {
+    // Registers which depend on conditions other than features can't be checked before reading
+    // them, so are left out along with those which can't be read without side effects.
+    let readable = registers
+        .iter()
+        .filter(|register| {
+            register.read == Some(Safety::Safe)
+                && !register.read_side_effects
+                && (!register.has_special_conditions || register.condition.is_some())
+        })
+        .collect::<Vec<_>>();
+
+    writer.write_all(
+        "\
+/// A snapshot of the values of all system registers which can safely be read, for the enabled
+/// exception levels and current architecture.
+///
+/// Registers which can't be read without side effects, such as `ICC_IAR1_EL1`, aren't included.
+///
+/// With the `serde` feature this can be serialised, with each register stored as its raw value
+/// under its lowercase name. Registers missing when deserialising are set to 0, so snapshots can be
+/// loaded by later versions of the crate which add registers. Snapshots with a version newer than
+/// [`RegisterSnapshot::VERSION`] are rejected.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = \"serde\", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = \"serde\", serde(default))]
+#[non_exhaustive]
+pub struct RegisterSnapshot {
+    /// The version of the snapshot format, which is [`RegisterSnapshot::VERSION`] for snapshots
+    /// captured by this version of the crate.
+    #[cfg_attr(feature = \"serde\", serde(deserialize_with = \"deserialize_version\"))]
+    pub version: u32,
+"
+        .as_bytes(),
+    )?;
+    for register in &readable {
+        if let Some(guard) = register.cfg_guard() {
+            writeln!(writer, "    {guard}")?;
+        }
+        writeln!(
+            writer,
+            "    /// Value of the `{}` system register.",
+            register.name
+        )?;
+        let register_type = if register.use_struct() {
+            register.struct_name()
+        } else {
+            format!("u{}", register.width)
+        };
+        writeln!(
+            writer,
+            "    pub {}: {register_type},",
+            register.variable_name()
+        )?;
+    }
+    writer.write_all(
+        "\
+}
+
+impl RegisterSnapshot {
+    /// The current version of the snapshot format.
+    pub const VERSION: u32 = 1;
+
+    /// Reads the current values of all registers in the snapshot.
+    ///
+    /// Registers which only exist if some feature is implemented are only read if `features`
+    /// contains it, and are otherwise left as 0.
+    pub fn capture(features: &CpuFeatures) -> Self {
+        Self {
+            version: Self::VERSION,
+"
+        .as_bytes(),
+    )?;
+    for register in &readable {
+        if let Some(guard) = register.cfg_guard() {
+            writeln!(writer, "            {guard}")?;
+        }
+        let name = register.variable_name();
+        if let Some(condition) = &register.condition {
+            writeln!(
+                writer,
+                "            {name}: if {} {{ read_{name}() }} else {{ Default::default() }},",
+                condition_expression(condition)
+            )?;
+        } else {
+            writeln!(writer, "            {name}: read_{name}(),")?;
         }
-        writeln!(writer, "use crate::{{{struct_names}}};")?;
     }
+    writeln!(writer, "        }}")?;
+    writeln!(writer, "    }}")?;
+    writeln!(writer, "}}")?;
+    Ok(())
+}
+
+pub fn write_fake(mut writer: impl Write + Copy, registers: &[RegisterInfo]) -> io::Result<()> {
+    writer.write_all(
+        "\
+// SPDX-FileCopyrightText: Copyright The arm-sysregs Contributors.
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated, do not edit manually.
+
+use super::{DumpParseError, parse_dump_flags, parse_dump_int};
+#[cfg(feature = \"backend\")]
+use crate::SysregId;
+use crate::{CpuFeatures, ExceptionLevel, Feature, FieldChange};
+use alloc::vec::Vec;
+use core::fmt::{self, Formatter};
+"
+        .as_bytes(),
+    )?;
+
+    write_struct_imports(writer, registers)?;
 
     writer.write_all(
         "
 /// A set of fake system registers.
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = \"serde\", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = \"serde\", serde(default))]
 #[non_exhaustive]
 pub struct SystemRegisters {
 "
@@ -96,7 +615,7 @@ pub struct SystemRegisters {
     )?;
 
     for register in registers {
-        if let Some(guard) = register.exception_level.cfg_guard() {
+        if let Some(guard) = register.feature_cfg_guard() {
             writeln!(writer, "    {guard}")?;
         }
         writeln!(
@@ -122,7 +641,7 @@ pub struct SystemRegisters {
     writeln!(writer, "    pub(crate) const fn new() -> Self {{")?;
     writeln!(writer, "        Self {{")?;
     for register in registers {
-        if let Some(guard) = register.exception_level.cfg_guard() {
+        if let Some(guard) = register.feature_cfg_guard() {
             writeln!(writer, "            {guard}")?;
         }
         if register.use_struct() {
@@ -138,57 +657,410 @@ pub struct SystemRegisters {
     }
     writeln!(writer, "        }}")?;
     writeln!(writer, "    }}")?;
-    writeln!(writer, "}}")?;
-
-    Ok(())
-}
-
-pub fn write_example(mut writer: impl Write + Copy, registers: &[RegisterInfo]) -> io::Result<()> {
+    writeln!(writer)?;
     writeln!(
         writer,
-        "\
-// SPDX-FileCopyrightText: Copyright The arm-sysregs Contributors.
-// SPDX-License-Identifier: MIT OR Apache-2.0
-
-//! Example to log all readable system register values.
-
-// This file is generated, do not edit manually.
-
-#![no_std]
-#![cfg_attr(not(any(test, feature = \"fakes\")), no_main)]
-
-#[cfg(all(target_arch = \"aarch64\", target_os = \"none\"))]
-use aarch64_rt::entry;
-#[cfg(not(any(test, feature = \"fakes\")))]
-use core::panic::PanicInfo;
-use log::info;
-
-#[cfg(all(target_arch = \"aarch64\", target_os = \"none\"))]
-entry!(entry);
-#[cfg_attr(any(test, feature = \"fakes\"), allow(unused))]
-fn entry(_: u64, _: u64, _: u64, _: u64) -> ! {{
-"
+        "    /// Sets the fake register with the given name to the given value from a dump."
     )?;
-    for register in registers {
-        if register.read.is_some() {
-            if let Some(guard) = register.cfg_guard() {
-                writeln!(writer, "    {guard}")?;
-            }
-            let name = register.variable_name();
-            writeln!(
-                writer,
-                "    info!(\"{name} = {{:?}}\", arm_sysregs::read_{name}());"
-            )?;
-        }
-    }
     writeln!(
         writer,
-        "\
-    loop {{}}
-}}
-
-#[cfg(any(test, feature = \"fakes\"))]
-fn main() {{}}
+        "    pub(crate) fn set_dump_value(&mut self, line: usize, name: &str, value: &str) -> Result<(), DumpParseError> {{"
+    )?;
+    writeln!(writer, "        match name {{")?;
+    for register in registers {
+        if let Some(guard) = register.feature_cfg_guard() {
+            writeln!(writer, "            {guard}")?;
+        }
+        let parse_function = if register.use_struct() {
+            "parse_dump_flags"
+        } else {
+            "parse_dump_int"
+        };
+        writeln!(
+            writer,
+            "            \"{0}\" => self.{0} = {parse_function}(line, value)?,",
+            register.variable_name(),
+        )?;
+    }
+    writeln!(writer, "            _ => {{")?;
+    writeln!(
+        writer,
+        "                return Err(DumpParseError::UnknownRegister {{ line, name: name.into() }});"
+    )?;
+    writeln!(writer, "            }}")?;
+    writeln!(writer, "        }}")?;
+    writeln!(writer, "        Ok(())")?;
+    writeln!(writer, "    }}")?;
+    writeln!(writer)?;
+    writeln!(
+        writer,
+        "    /// Writes the values of all fake registers, one `name = value` pair per line."
+    )?;
+    writeln!(
+        writer,
+        "    pub(crate) fn fmt_dump(&self, f: &mut Formatter) -> fmt::Result {{"
+    )?;
+    for register in registers {
+        if let Some(guard) = register.feature_cfg_guard() {
+            writeln!(writer, "        {guard}")?;
+        }
+        let bits = if register.use_struct() { ".bits()" } else { "" };
+        writeln!(
+            writer,
+            "        writeln!(f, \"{0} = {{:#x}}\", self.{0}{bits})?;",
+            register.variable_name(),
+        )?;
+    }
+    writeln!(writer, "        Ok(())")?;
+    writeln!(writer, "    }}")?;
+    writeln!(writer)?;
+    writeln!(
+        writer,
+        "    /// Copies the values of all registers shared between PEs from `other`."
+    )?;
+    writeln!(
+        writer,
+        "    pub(crate) fn copy_shared_from(&mut self, other: &Self) {{"
+    )?;
+    for register in registers.iter().filter(|register| register.shared) {
+        if let Some(guard) = register.feature_cfg_guard() {
+            writeln!(writer, "        {guard}")?;
+        }
+        writeln!(
+            writer,
+            "        self.{0} = other.{0};",
+            register.variable_name(),
+        )?;
+    }
+    writeln!(writer, "    }}")?;
+    writeln!(writer)?;
+    writeln!(
+        writer,
+        "    /// Returns the changes to the values of all registers and their fields between `self` and"
+    )?;
+    writeln!(writer, "    /// `other`.")?;
+    writeln!(writer, "    ///")?;
+    writeln!(
+        writer,
+        "    /// Registers with named fields are compared field by field, others by their whole value."
+    )?;
+    writeln!(
+        writer,
+        "    pub fn diff(&self, other: &Self) -> Vec<FieldChange> {{"
+    )?;
+    writeln!(writer, "        let mut changes = Vec::new();")?;
+    for register in registers {
+        if let Some(guard) = register.feature_cfg_guard() {
+            writeln!(writer, "        {guard}")?;
+        }
+        let name = register.variable_name();
+        if register.use_struct() {
+            writeln!(
+                writer,
+                "        changes.extend(self.{name}.diff(other.{name}, \"{}\"));",
+                register.name
+            )?;
+        } else {
+            writeln!(writer, "        if self.{name} != other.{name} {{")?;
+            writeln!(writer, "            changes.push(FieldChange {{")?;
+            writeln!(writer, "                register: \"{}\",", register.name)?;
+            writeln!(writer, "                field: None,")?;
+            writeln!(
+                writer,
+                "                old: {},",
+                register.expr_to_u64(&format!("self.{name}"))
+            )?;
+            writeln!(
+                writer,
+                "                new: {},",
+                register.expr_to_u64(&format!("other.{name}"))
+            )?;
+            writeln!(writer, "            }});")?;
+            writeln!(writer, "        }}")?;
+        }
+    }
+    writeln!(writer, "        changes")?;
+    writeln!(writer, "    }}")?;
+    writeln!(writer, "}}")?;
+    writeln!(writer)?;
+    writeln!(
+        writer,
+        "/// Returns the lowest exception level at which the fake register with the given name is accessible."
+    )?;
+    writeln!(
+        writer,
+        "pub(crate) fn register_exception_level(name: &str) -> Option<ExceptionLevel> {{"
+    )?;
+    writeln!(writer, "    Some(match name {{")?;
+    for register in registers {
+        if let Some(guard) = register.feature_cfg_guard() {
+            writeln!(writer, "        {guard}")?;
+        }
+        writeln!(
+            writer,
+            "        \"{}\" => ExceptionLevel::{:?},",
+            register.variable_name(),
+            register.exception_level,
+        )?;
+    }
+    writeln!(writer, "        _ => return None,")?;
+    writeln!(writer, "    }})")?;
+    writeln!(writer, "}}")?;
+    writeln!(writer)?;
+    writeln!(
+        writer,
+        "/// Returns whether the fake register with the given name is implemented by a PE with the given"
+    )?;
+    writeln!(writer, "/// features.")?;
+    writeln!(writer, "///")?;
+    writeln!(
+        writer,
+        "/// Registers which depend on conditions other than features are always treated as implemented."
+    )?;
+    writeln!(
+        writer,
+        "pub(crate) fn register_implemented(name: &str, features: &CpuFeatures) -> bool {{"
+    )?;
+    writeln!(writer, "    match name {{")?;
+    for register in registers {
+        let Some(condition) = &register.condition else {
+            continue;
+        };
+        if let Some(guard) = register.feature_cfg_guard() {
+            writeln!(writer, "        {guard}")?;
+        }
+        writeln!(
+            writer,
+            "        \"{}\" => {},",
+            register.variable_name(),
+            condition_expression(condition),
+        )?;
+    }
+    writeln!(writer, "        _ => true,")?;
+    writeln!(writer, "    }}")?;
+    writeln!(writer, "}}")?;
+    writeln!(writer)?;
+
+    writeln!(writer, "#[cfg(feature = \"backend\")]")?;
+    writeln!(writer, "impl SystemRegisters {{")?;
+    writeln!(
+        writer,
+        "    /// Returns the value of the fake register with the given ID, zero-extended to 64 bits."
+    )?;
+    writeln!(
+        writer,
+        "    pub(crate) fn read_by_id(&self, id: SysregId) -> u64 {{"
+    )?;
+    writeln!(writer, "        match id {{")?;
+    for register in registers {
+        if let Some(guard) = register.feature_cfg_guard() {
+            writeln!(writer, "            {guard}")?;
+        }
+        writeln!(
+            writer,
+            "            SysregId::{} => {},",
+            register.id_name(),
+            register.expr_to_u64(&format!("self.{}", register.variable_name())),
+        )?;
+    }
+    writeln!(writer, "        }}")?;
+    writeln!(writer, "    }}")?;
+    writeln!(writer)?;
+    writeln!(
+        writer,
+        "    /// Sets the fake register with the given ID, truncating the value to its width."
+    )?;
+    writeln!(
+        writer,
+        "    pub(crate) fn write_by_id(&mut self, id: SysregId, value: u64) {{"
+    )?;
+    writeln!(writer, "        match id {{")?;
+    for register in registers {
+        if let Some(guard) = register.feature_cfg_guard() {
+            writeln!(writer, "            {guard}")?;
+        }
+        writeln!(
+            writer,
+            "            SysregId::{} => self.{} = {},",
+            register.id_name(),
+            register.variable_name(),
+            register.expr_from_u64("value"),
+        )?;
+    }
+    writeln!(writer, "        }}")?;
+    writeln!(writer, "    }}")?;
+    writeln!(writer, "}}")?;
+
+    Ok(())
+}
+
+/// Writes `use` declarations for the struct types of all registers, guarded by exception level and
+/// feature group.
+fn write_struct_imports(mut writer: impl Write, registers: &[RegisterInfo]) -> io::Result<()> {
+    let mut imports = BTreeMap::<_, (Option<String>, Vec<String>)>::new();
+    for register in registers.iter().filter(|register| register.use_struct()) {
+        imports
+            .entry((register.exception_level, register.feature_group.as_deref()))
+            .or_insert_with(|| (register.feature_cfg_guard(), Vec::new()))
+            .1
+            .push(register.struct_name());
+    }
+    for (guard, struct_names) in imports.values() {
+        if let Some(guard) = guard {
+            writeln!(writer, "{guard}")?;
+        }
+        writeln!(writer, "use crate::{{{}}};", struct_names.join(", "))?;
+    }
+    Ok(())
+}
+
+pub fn write_backend(mut writer: impl Write + Copy, registers: &[RegisterInfo]) -> io::Result<()> {
+    writer.write_all(
+        "\
+// SPDX-FileCopyrightText: Copyright The arm-sysregs Contributors.
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated, do not edit manually.
+
+use super::{SysregBackend, Sysregs};
+"
+        .as_bytes(),
+    )?;
+    write_struct_imports(writer, registers)?;
+
+    writer.write_all(
+        "
+/// Identifies a system register.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum SysregId {
+"
+        .as_bytes(),
+    )?;
+    for register in registers {
+        if let Some(guard) = register.feature_cfg_guard() {
+            writeln!(writer, "    {guard}")?;
+        }
+        writeln!(writer, "    /// The `{}` system register.", register.name)?;
+        writeln!(writer, "    {},", register.id_name())?;
+    }
+    writeln!(writer, "}}")?;
+    writeln!(writer)?;
+    writeln!(writer, "impl<B: SysregBackend> Sysregs<B> {{")?;
+    for register in registers {
+        let register_type = if register.use_struct() {
+            register.struct_name()
+        } else {
+            format!("u{}", register.width)
+        };
+        if register.read.is_some() {
+            if let Some(guard) = register.feature_cfg_guard() {
+                writeln!(writer, "    {guard}")?;
+            }
+            writeln!(
+                writer,
+                "    /// Returns the value of the `{}` system register.",
+                register.name
+            )?;
+            writeln!(
+                writer,
+                "    pub fn read_{}(&self) -> {register_type} {{",
+                register.variable_name()
+            )?;
+            writeln!(
+                writer,
+                "        {}",
+                register.expr_from_u64(&format!("self.0.read(SysregId::{})", register.id_name()))
+            )?;
+            writeln!(writer, "    }}")?;
+            writeln!(writer)?;
+        }
+        if register.write.is_some() {
+            if let Some(guard) = register.feature_cfg_guard() {
+                writeln!(writer, "    {guard}")?;
+            }
+            writeln!(
+                writer,
+                "    /// Writes `value` to the `{}` system register.",
+                register.name
+            )?;
+            writeln!(
+                writer,
+                "    pub fn write_{}(&self, value: {register_type}) {{",
+                register.variable_name()
+            )?;
+            writeln!(
+                writer,
+                "        self.0.write(SysregId::{}, {});",
+                register.id_name(),
+                register.expr_to_u64("value"),
+            )?;
+            writeln!(writer, "    }}")?;
+            writeln!(writer)?;
+        }
+    }
+    writeln!(writer, "}}")?;
+
+    Ok(())
+}
+
+pub fn write_example(mut writer: impl Write + Copy, registers: &[RegisterInfo]) -> io::Result<()> {
+    writeln!(
+        writer,
+        "\
+// SPDX-FileCopyrightText: Copyright The arm-sysregs Contributors.
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Example to log all readable system register values.
+
+// This file is generated, do not edit manually.
+
+#![no_std]
+#![cfg_attr(not(any(test, feature = \"fakes\")), no_main)]
+
+#[cfg(all(target_arch = \"aarch64\", target_os = \"none\"))]
+use aarch64_rt::entry;
+#[cfg(not(any(test, feature = \"fakes\")))]
+use core::panic::PanicInfo;
+use log::info;
+#[cfg(any(test, feature = \"fakes\"))]
+extern crate std;
+
+#[cfg(all(target_arch = \"aarch64\", target_os = \"none\"))]
+entry!(entry);
+#[cfg_attr(any(test, feature = \"fakes\"), allow(unused, clippy::empty_loop))]
+fn entry(_: u64, _: u64, _: u64, _: u64) -> ! {{
+"
+    )?;
+    for register in registers {
+        if register.read.is_some() {
+            if let Some(guard) = register.cfg_guard() {
+                writeln!(writer, "    {guard}")?;
+            }
+            let name = register.variable_name();
+            if register.use_struct() {
+                // Log the value of each field on a separate line, which `SystemRegisters::from_str`
+                // skips as it has no ` = `.
+                writeln!(
+                    writer,
+                    "    info!(\"{name} = {{0:?}}\\n    {{0}}\", arm_sysregs::read_{name}());"
+                )?;
+            } else {
+                writeln!(
+                    writer,
+                    "    info!(\"{name} = {{:?}}\", arm_sysregs::read_{name}());"
+                )?;
+            }
+        }
+    }
+    writeln!(
+        writer,
+        "\
+    loop {{}}
+}}
+
+#[cfg(any(test, feature = \"fakes\"))]
+fn main() {{}}
 
 #[cfg(not(any(test, feature = \"fakes\")))]
 #[panic_handler]
@@ -201,15 +1073,33 @@ fn panic(_panic: &PanicInfo) -> ! {{
 }
 
 impl RegisterInfo {
+    /// Returns the name of the family module which the register is generated in.
+    fn family(&self) -> &'static str {
+        if self.aarch32 && !self.aarch64 {
+            return "aarch32";
+        }
+        FAMILIES
+            .iter()
+            .flat_map(|(name, _, prefixes)| prefixes.iter().map(move |prefix| (*name, *prefix)))
+            .filter(|(_, prefix)| self.name.starts_with(prefix))
+            .max_by_key(|(_, prefix)| prefix.len())
+            .map_or("control", |(name, _)| name)
+    }
+
     /// Whether to use a wrapper bitflags struct type for the register, rather than just a raw
     /// primitive type.
     fn use_struct(&self) -> bool {
         !self.fields.is_empty()
     }
 
-    /// The name to use for the struct type for the register.
+    /// The name to use for the struct type for the register, or for the view of one of its
+    /// layouts.
     fn struct_name(&self) -> String {
-        camel_case(&self.name)
+        if let Some(layout) = &self.layout {
+            format!("{}{}", camel_case(&self.name), camel_case(&layout.name))
+        } else {
+            camel_case(&self.name)
+        }
     }
 
     /// The struct name of the base type for the register, if it is type aliased.
@@ -217,113 +1107,674 @@ impl RegisterInfo {
         Some(camel_case(self.alias.as_ref()?))
     }
 
-    /// Writes the declaration - bitflags and impl - of this register.
-    /// If this register is aliased, only writes a type alias.
-    fn write_lib(&self, writer: impl Write + Copy) -> io::Result<()> {
-        match self.alias {
-            Some(_) => {
-                self.write_alias(writer)?;
+    /// Writes the declaration - bitflags and impl - of this register.
+    /// If this register is aliased, only writes a type alias.
+    fn write_lib(&self, writer: impl Write + Copy) -> io::Result<()> {
+        match self.alias {
+            Some(_) => {
+                self.write_alias(writer)?;
+            }
+            None => {
+                self.write_bitflags(writer)?;
+                self.write_impl(writer)?;
+                self.write_display(writer)?;
+                self.write_defmt(writer)?;
+                self.write_serde(writer)?;
+                self.write_diff(writer)?;
+                self.write_reserved(writer)?;
+                self.write_validate(writer)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes methods to convert the register value to the view type for each of its layouts, and
+    /// conversions back from them.
+    fn write_layout_views(&self, mut writer: impl Write) -> io::Result<()> {
+        let Some(first_layout) = self
+            .layouts
+            .first()
+            .and_then(|layout| layout.layout.as_ref())
+        else {
+            return Ok(());
+        };
+        let discriminant = if first_layout.external {
+            None
+        } else if let Some(field) = self
+            .fields
+            .iter()
+            .find(|field| field.name == first_layout.discriminant)
+        {
+            Some(field)
+        } else {
+            return Ok(());
+        };
+
+        writeln!(writer)?;
+        if let Some(guard) = self.feature_cfg_guard() {
+            writeln!(writer, "{guard}")?;
+        }
+        writeln!(writer, "impl {} {{", self.struct_name())?;
+        for (i, layout) in self.layouts.iter().enumerate() {
+            let layout_info = layout.layout.as_ref().unwrap();
+            if i > 0 {
+                writeln!(writer)?;
+            }
+            let Some(discriminant) = discriminant else {
+                writeln!(
+                    writer,
+                    "    /// Returns the value as a `{}`, for use when `{}` is {}.",
+                    layout.struct_name(),
+                    layout_info.discriminant,
+                    layout_info.value
+                )?;
+                writeln!(
+                    writer,
+                    "    pub const fn {}(self) -> {} {{",
+                    lowercase_name(&layout_info.name),
+                    layout.struct_name()
+                )?;
+                writeln!(
+                    writer,
+                    "        {}::from_bits_retain(self.bits())",
+                    layout.struct_name()
+                )?;
+                writeln!(writer, "    }}")?;
+                continue;
+            };
+            writeln!(
+                writer,
+                "    /// Returns the value as a `{}` if `{}` is {}, or `None` if it has another layout.",
+                layout.struct_name(),
+                discriminant.name,
+                layout_info.value
+            )?;
+            writeln!(
+                writer,
+                "    pub const fn as_{}(self) -> Option<{}> {{",
+                lowercase_name(&layout_info.name),
+                layout.struct_name()
+            )?;
+            writeln!(
+                writer,
+                "        if Self::{}_FIELD.extract({}) == {} {{",
+                discriminant.constant_name(),
+                if self.width == 64 {
+                    "self.bits()"
+                } else {
+                    "self.bits() as u64"
+                },
+                layout_info.value
+            )?;
+            writeln!(
+                writer,
+                "            Some({}::from_bits_retain(self.bits()))",
+                layout.struct_name()
+            )?;
+            writeln!(writer, "        }} else {{")?;
+            writeln!(writer, "            None")?;
+            writeln!(writer, "        }}")?;
+            writeln!(writer, "    }}")?;
+        }
+        writeln!(writer, "}}")?;
+
+        for layout in &self.layouts {
+            writeln!(writer)?;
+            if let Some(guard) = self.feature_cfg_guard() {
+                writeln!(writer, "{guard}")?;
+            }
+            writeln!(
+                writer,
+                "impl From<{}> for {} {{",
+                layout.struct_name(),
+                self.struct_name()
+            )?;
+            writeln!(
+                writer,
+                "    fn from(value: {}) -> Self {{",
+                layout.struct_name()
+            )?;
+            writeln!(writer, "        Self::from_bits_retain(value.bits())")?;
+            writeln!(writer, "    }}")?;
+            writeln!(writer, "}}")?;
+        }
+        Ok(())
+    }
+
+    /// Writes a type alias using self.alias as the base struct name.
+    /// Expects self.alias to be Some(String).
+    fn write_alias(&self, mut writer: impl Write) -> io::Result<()> {
+        if let Some(guard) = self.feature_cfg_guard() {
+            writeln!(writer, "{guard}")?;
+        }
+
+        writeln!(writer, "/// `{}` system register value.", self.name)?;
+        if let Some(description) = &self.description {
+            writeln!(writer, "///")?;
+            writeln!(writer, "/// {description}")?;
+        }
+
+        writeln!(
+            writer,
+            "pub type {} = {};",
+            self.struct_name(),
+            self.alias_struct_name().unwrap()
+        )?;
+
+        Ok(())
+    }
+
+    fn write_bitflags(&self, mut writer: impl Write) -> io::Result<()> {
+        if let Some(guard) = self.feature_cfg_guard() {
+            writeln!(writer, "{guard}")?;
+        }
+        writeln!(writer, "bitflags! {{")?;
+        if let Some(layout) = &self.layout {
+            writeln!(
+                writer,
+                "    /// `{}` system register value, when `{}` is {}.",
+                self.name, layout.discriminant, layout.value
+            )?;
+        } else {
+            writeln!(writer, "    /// `{}` system register value.", self.name)?;
+        }
+        if let Some(description) = &self.description {
+            writeln!(writer, "    ///")?;
+            writeln!(writer, "    /// {description}")?;
+        }
+        if self.derive_debug {
+            writeln!(
+                writer,
+                "    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]"
+            )?;
+        } else {
+            writeln!(writer, "    #[derive(Clone, Copy, Eq, Default, PartialEq)]")?;
+        }
+        writeln!(writer, "    #[repr(transparent)]")?;
+        writeln!(
+            writer,
+            "    pub struct {}: u{} {{",
+            self.struct_name(),
+            self.width
+        )?;
+        if self.res1 != 0 {
+            writeln!(
+                writer,
+                "        /// RES1 bits in the `{}` register.",
+                self.name
+            )?;
+            writeln!(
+                writer,
+                "        const RES1 = {};",
+                separated_binary_literal(self.res1)
+            )?;
+        }
+        for field in &self.fields {
+            if field.width == 1 {
+                if let Some(array_info) = &field.array_info {
+                    let placeholder = array_info.placeholder();
+                    for i in array_info.indices.clone() {
+                        writeln!(writer, "        /// `{}` bit {}.", field.name, i)?;
+                        if let Some(description) = &field.description {
+                            writeln!(writer, "        ///")?;
+                            writeln!(writer, "        /// {description}")?;
+                        }
+                        writeln!(
+                            writer,
+                            "        const {} = 1 << {};",
+                            uppercase_name(&field.name.replace(&placeholder, &format!("{}", i))),
+                            field.index + i - array_info.indices.start,
+                        )?;
+                    }
+                } else {
+                    if let Some(description) = &field.description {
+                        writeln!(writer, "        /// {description}")?;
+                    } else {
+                        writeln!(writer, "        /// `{}` bit.", field.name)?;
+                    }
+                    writeln!(
+                        writer,
+                        "        const {} = 1 << {};",
+                        field.constant_name(),
+                        field.index,
+                    )?;
+                }
+            }
+        }
+        writeln!(writer, "    }}")?;
+        writeln!(writer, "}}")?;
+        Ok(())
+    }
+
+    /// Returns an expression for the raw value of the given register value as a `u64`.
+    fn bits_u64(&self, value: &str) -> String {
+        if self.width < 64 {
+            format!("u64::from({value}.bits())")
+        } else {
+            format!("{value}.bits()")
+        }
+    }
+
+    /// Returns the values of all fields of the register, with one entry for each element of array
+    /// fields.
+    fn field_values(&self) -> Vec<FieldValue<'_>> {
+        let mut values = Vec::new();
+        for field in &self.fields {
+            if let Some(array_info) = &field.array_info {
+                let mask = if field.width == 1 {
+                    "1".to_owned()
+                } else if self.width < 64 {
+                    format!("u64::from(Self::{}_MASK)", field.constant_name())
+                } else {
+                    format!("Self::{}_MASK", field.constant_name())
+                };
+                for i in array_info.indices.clone() {
+                    let offset = (i - array_info.indices.start) * field.width;
+                    let shift = if offset == 0 {
+                        format!("Self::{}_SHIFT", field.constant_name())
+                    } else {
+                        format!("(Self::{}_SHIFT + {offset})", field.constant_name())
+                    };
+                    values.push(FieldValue {
+                        name: field
+                            .name
+                            .replace(&array_info.placeholder(), &i.to_string()),
+                        array_element: Some((shift, mask.clone())),
+                        field,
+                    });
+                }
+            } else {
+                values.push(FieldValue {
+                    name: field.name.clone(),
+                    array_element: None,
+                    field,
+                });
+            }
+        }
+        values
+    }
+
+    /// Writes a `Display` implementation showing the value of each field.
+    fn write_display(&self, mut writer: impl Write) -> io::Result<()> {
+        writeln!(writer)?;
+        if let Some(guard) = self.feature_cfg_guard() {
+            writeln!(writer, "{guard}")?;
+        }
+        writeln!(writer, "impl Display for {} {{", self.struct_name())?;
+        writeln!(
+            writer,
+            "    fn fmt(&self, f: &mut Formatter) -> fmt::Result {{"
+        )?;
+        writeln!(
+            writer,
+            "        let mut s = f.debug_struct(\"{}\");",
+            self.struct_name()
+        )?;
+        let values = self.field_values();
+        if !values.is_empty() {
+            writeln!(writer, "        let bits = {};", self.bits_u64("self"))?;
+        }
+        for value in values {
+            let raw_format = if value.hex() { "{:#x}" } else { "{}" };
+            if let Some(type_name) = value
+                .field
+                .type_name
+                .as_ref()
+                .filter(|_| value.field.width > 1)
+            {
+                let (int_ty, _) = type_for_width(value.field.width);
+                writeln!(writer, "        let raw = {};", value.raw("bits"))?;
+                writeln!(
+                    writer,
+                    "        if let Some(value) = {type_name}::from_raw(raw as {int_ty}) {{"
+                )?;
+                writeln!(writer, "            s.field(\"{}\", &value);", value.name)?;
+                writeln!(writer, "        }} else {{")?;
+                writeln!(
+                    writer,
+                    "            s.field(\"{}\", &format_args!(\"{raw_format}\", raw));",
+                    value.name
+                )?;
+                writeln!(writer, "        }}")?;
+            } else if value.hex() {
+                writeln!(
+                    writer,
+                    "        s.field(\"{}\", &format_args!(\"{raw_format}\", {}));",
+                    value.name,
+                    value.raw("bits")
+                )?;
+            } else {
+                // The `Debug` implementation of `u64` formats it in decimal, like `Display`.
+                let raw = value.raw("bits");
+                if value.array_element.is_some() {
+                    writeln!(writer, "        s.field(\"{}\", &({raw}));", value.name)?;
+                } else {
+                    writeln!(writer, "        s.field(\"{}\", &{raw});", value.name)?;
+                }
+            }
+        }
+        writeln!(writer, "        s.finish()")?;
+        writeln!(writer, "    }}")?;
+        writeln!(writer, "}}")?;
+        Ok(())
+    }
+
+    /// Writes a `defmt::Format` implementation showing the value of each field, in the same form as
+    /// the `Display` implementation.
+    fn write_defmt(&self, mut writer: impl Write) -> io::Result<()> {
+        writeln!(writer)?;
+        writeln!(writer, "#[cfg(feature = \"defmt\")]")?;
+        if let Some(guard) = self.feature_cfg_guard() {
+            writeln!(writer, "{guard}")?;
+        }
+        writeln!(writer, "impl defmt::Format for {} {{", self.struct_name())?;
+        writeln!(writer, "    fn format(&self, f: defmt::Formatter) {{")?;
+        let bits = self.bits_u64("self");
+        let mut format = String::new();
+        let mut arguments = Vec::new();
+        for (i, value) in self.field_values().into_iter().enumerate() {
+            if i > 0 {
+                format.push_str(", ");
             }
-            None => {
-                self.write_bitflags(writer)?;
-                self.write_impl(writer)?;
+            if let Some(type_name) = value
+                .field
+                .type_name
+                .as_ref()
+                .filter(|_| value.field.width > 1)
+            {
+                let (int_ty, _) = type_for_width(value.field.width);
+                let raw = value.raw(&bits);
+                format.push_str(&format!("{}: {{}}", value.name));
+                arguments.push(format!(
+                    "crate::manual::DefmtField::new({raw}, {type_name}::from_raw(({raw}) as {int_ty}))"
+                ));
+            } else {
+                let raw_format = if value.hex() { "{=u64:#x}" } else { "{=u64}" };
+                format.push_str(&format!("{}: {raw_format}", value.name));
+                arguments.push(value.raw(&bits));
             }
         }
+        write!(
+            writer,
+            "        defmt::write!(f, \"{} {{{{ {format} }}}}\"",
+            self.struct_name()
+        )?;
+        for argument in arguments {
+            write!(writer, ", {argument}")?;
+        }
+        writeln!(writer, ");")?;
+        writeln!(writer, "    }}")?;
+        writeln!(writer, "}}")?;
         Ok(())
     }
 
-    /// Writes a type alias using self.alias as the base struct name.
-    /// Expects self.alias to be Some(String).
-    fn write_alias(&self, mut writer: impl Write) -> io::Result<()> {
-        if let Some(guard) = self.exception_level.cfg_guard() {
+    /// Writes `serde::Serialize` and `serde::Deserialize` implementations using the raw value.
+    fn write_serde(&self, mut writer: impl Write) -> io::Result<()> {
+        writeln!(writer)?;
+        writeln!(writer, "#[cfg(feature = \"serde\")]")?;
+        if let Some(guard) = self.feature_cfg_guard() {
             writeln!(writer, "{guard}")?;
         }
-
-        writeln!(writer, "/// `{}` system register value.", self.name)?;
-        if let Some(description) = &self.description {
-            writeln!(writer, "///")?;
-            writeln!(writer, "/// {description}")?;
+        writeln!(
+            writer,
+            "impl serde::Serialize for {} {{",
+            self.struct_name()
+        )?;
+        writeln!(
+            writer,
+            "    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{"
+        )?;
+        writeln!(
+            writer,
+            "        serde::Serialize::serialize(&self.bits(), serializer)"
+        )?;
+        writeln!(writer, "    }}")?;
+        writeln!(writer, "}}")?;
+        writeln!(writer)?;
+        writeln!(writer, "#[cfg(feature = \"serde\")]")?;
+        if let Some(guard) = self.feature_cfg_guard() {
+            writeln!(writer, "{guard}")?;
         }
-
         writeln!(
             writer,
-            "pub type {} = {};",
-            self.struct_name(),
-            self.alias_struct_name().unwrap()
+            "impl<'de> serde::Deserialize<'de> for {} {{",
+            self.struct_name()
         )?;
-
+        writeln!(
+            writer,
+            "    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{"
+        )?;
+        writeln!(
+            writer,
+            "        <u{} as serde::Deserialize>::deserialize(deserializer).map(Self::from_bits_retain)",
+            self.width
+        )?;
+        writeln!(writer, "    }}")?;
+        writeln!(writer, "}}")?;
         Ok(())
     }
 
-    fn write_bitflags(&self, mut writer: impl Write) -> io::Result<()> {
-        if let Some(guard) = self.exception_level.cfg_guard() {
+    /// Writes constants for the reserved bits, and methods to check and sanitise them.
+    fn write_reserved(&self, mut writer: impl Write) -> io::Result<()> {
+        let conditional_fields = self
+            .fields
+            .iter()
+            .filter_map(|field| Some((field, field.condition.as_ref()?)))
+            .collect::<Vec<_>>();
+
+        writeln!(writer)?;
+        if let Some(guard) = self.feature_cfg_guard() {
             writeln!(writer, "{guard}")?;
         }
-        writeln!(writer, "bitflags! {{")?;
-        writeln!(writer, "    /// `{}` system register value.", self.name)?;
-        if let Some(description) = &self.description {
-            writeln!(writer, "    ///")?;
-            writeln!(writer, "    /// {description}")?;
+        writeln!(writer, "impl {} {{", self.struct_name())?;
+        writeln!(
+            writer,
+            "    /// Mask of the bits which are RES0, regardless of which features are implemented."
+        )?;
+        writeln!(
+            writer,
+            "    pub const RES0_MASK: u{} = {};",
+            self.width,
+            separated_binary_literal(self.res0 & ones(self.width))
+        )?;
+        writeln!(writer, "    /// Mask of the bits which are RES1.")?;
+        writeln!(
+            writer,
+            "    pub const RES1_MASK: u{} = {};",
+            self.width,
+            separated_binary_literal(self.res1 & ones(self.width))
+        )?;
+        writeln!(writer)?;
+        writeln!(
+            writer,
+            "    /// Returns a copy with all RES0 bits cleared and all RES1 bits set."
+        )?;
+        writeln!(writer, "    ///")?;
+        writeln!(
+            writer,
+            "    /// If `features` is given then fields which are RES0 because the features they depend on aren't"
+        )?;
+        writeln!(writer, "    /// implemented are also cleared.")?;
+        let features_parameter = if conditional_fields.is_empty() {
+            "_features"
+        } else {
+            "features"
+        };
+        writeln!(
+            writer,
+            "    pub const fn sanitize(self, {features_parameter}: Option<&CpuFeatures>) -> Self {{"
+        )?;
+        let mutability = if conditional_fields.is_empty() {
+            ""
+        } else {
+            "mut "
+        };
+        writeln!(
+            writer,
+            "        let {mutability}bits = (self.bits() & !Self::RES0_MASK) | Self::RES1_MASK;"
+        )?;
+        for (field, condition) in conditional_fields {
+            let mask = separated_binary_literal(field.mask());
+            writeln!(
+                writer,
+                "        if let Some(features) = features && !({}) {{",
+                condition_expression(condition)
+            )?;
+            writeln!(writer, "            bits &= !{mask};")?;
+            writeln!(writer, "        }}")?;
         }
-        if self.derive_debug {
+        writeln!(writer, "        Self::from_bits_retain(bits)")?;
+        writeln!(writer, "    }}")?;
+        writeln!(writer)?;
+        writeln!(
+            writer,
+            "    /// Returns whether all RES0 bits are clear and all RES1 bits are set."
+        )?;
+        writeln!(writer, "    ///")?;
+        writeln!(
+            writer,
+            "    /// If `features` is given then fields which are RES0 because the features they depend on aren't"
+        )?;
+        writeln!(writer, "    /// implemented must also be clear.")?;
+        writeln!(
+            writer,
+            "    pub const fn is_valid(self, features: Option<&CpuFeatures>) -> bool {{"
+        )?;
+        writeln!(
+            writer,
+            "        self.bits() == self.sanitize(features).bits()"
+        )?;
+        writeln!(writer, "    }}")?;
+        writeln!(writer, "}}")?;
+        Ok(())
+    }
+
+    /// Writes a `validate` method checking for fields which are set although the features they
+    /// depend on aren't implemented.
+    fn write_validate(&self, mut writer: impl Write) -> io::Result<()> {
+        let conditional_values = self
+            .field_values()
+            .into_iter()
+            .filter_map(|value| {
+                let condition = match (&self.condition, &value.field.condition) {
+                    (Some(register), Some(field)) => {
+                        FeatureCondition::And(Box::new(register.clone()), Box::new(field.clone()))
+                    }
+                    (Some(condition), None) | (None, Some(condition)) => condition.clone(),
+                    (None, None) => return None,
+                };
+                Some((value, condition))
+            })
+            .collect::<Vec<_>>();
+
+        writeln!(writer)?;
+        if let Some(guard) = self.feature_cfg_guard() {
+            writeln!(writer, "{guard}")?;
+        }
+        writeln!(writer, "impl {} {{", self.struct_name())?;
+        writeln!(
+            writer,
+            "    /// Returns the fields which are set although a CPU with the given features doesn't implement"
+        )?;
+        writeln!(writer, "    /// them.")?;
+        if conditional_values.is_empty() {
             writeln!(
                 writer,
-                "    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]"
+                "    pub fn validate(self, _features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {{"
             )?;
-        } else {
-            writeln!(writer, "    #[derive(Clone, Copy, Eq, Default, PartialEq)]")?;
+            writeln!(writer, "        core::iter::empty()")?;
+            writeln!(writer, "    }}")?;
+            writeln!(writer, "}}")?;
+            return Ok(());
         }
-        writeln!(writer, "    #[repr(transparent)]")?;
         writeln!(
             writer,
-            "    pub struct {}: u{} {{",
-            self.struct_name(),
-            self.width
+            "    pub fn validate(self, features: &CpuFeatures) -> impl Iterator<Item = UnimplementedField> {{"
         )?;
-        if self.res1 != 0 {
+        writeln!(writer, "        [")?;
+        let bits = self.bits_u64("self");
+        for (value, condition) in conditional_values {
+            writeln!(writer, "            (")?;
             writeln!(
                 writer,
-                "        /// RES1 bits in the `{}` register.",
-                self.name
+                "                {} != 0 && !{},",
+                value.raw(&bits),
+                condition_expression(&condition)
             )?;
+            writeln!(writer, "                UnimplementedField {{")?;
+            writeln!(writer, "                    register: \"{}\",", self.name)?;
+            writeln!(writer, "                    field: \"{}\",", value.name)?;
             writeln!(
                 writer,
-                "        const RES1 = {};",
-                separated_binary_literal(self.res1)
+                "                    requires: \"{}\",",
+                condition_text(&condition)
             )?;
+            writeln!(writer, "                }},")?;
+            writeln!(writer, "            ),")?;
         }
-        for field in &self.fields {
-            if field.width == 1 {
-                if let Some(array_info) = &field.array_info {
-                    let placeholder = array_info.placeholder();
-                    for i in array_info.indices.clone() {
-                        writeln!(writer, "        /// `{}` bit {}.", field.name, i)?;
-                        if let Some(description) = &field.description {
-                            writeln!(writer, "        ///")?;
-                            writeln!(writer, "        /// {description}")?;
-                        }
-                        writeln!(
-                            writer,
-                            "        const {} = 1 << {};",
-                            uppercase_name(&field.name.replace(&placeholder, &format!("{}", i))),
-                            field.index + i - array_info.indices.start,
-                        )?;
-                    }
-                } else {
-                    if let Some(description) = &field.description {
-                        writeln!(writer, "        /// {description}")?;
-                    } else {
-                        writeln!(writer, "        /// `{}` bit.", field.name)?;
-                    }
-                    writeln!(
-                        writer,
-                        "        const {} = 1 << {};",
-                        field.constant_name(),
-                        field.index,
-                    )?;
-                }
-            }
+        writeln!(writer, "        ]")?;
+        writeln!(writer, "        .into_iter()")?;
+        writeln!(
+            writer,
+            "        .filter_map(|(unimplemented, field)| unimplemented.then_some(field))"
+        )?;
+        writeln!(writer, "    }}")?;
+        writeln!(writer, "}}")?;
+        Ok(())
+    }
+
+    /// Writes a `diff` method comparing the value of each field, and of the bits outside all
+    /// fields.
+    fn write_diff(&self, mut writer: impl Write) -> io::Result<()> {
+        writeln!(writer)?;
+        if let Some(guard) = self.feature_cfg_guard() {
+            writeln!(writer, "{guard}")?;
+        }
+        writeln!(writer, "impl {} {{", self.struct_name())?;
+        writeln!(
+            writer,
+            "    /// Returns the fields whose values differ between `self` and `other`, as changes to the"
+        )?;
+        writeln!(writer, "    /// given register.")?;
+        writeln!(writer, "    ///")?;
+        writeln!(
+            writer,
+            "    /// Changes to any bits outside the named fields are returned as a single change with no field"
+        )?;
+        writeln!(writer, "    /// name.")?;
+        writeln!(
+            writer,
+            "    pub fn diff(self, other: Self, register: &'static str) -> impl Iterator<Item = FieldChange> {{"
+        )?;
+        let old_bits = self.bits_u64("self");
+        let new_bits = self.bits_u64("other");
+        writeln!(writer, "        [")?;
+        for value in self.field_values() {
+            writeln!(writer, "            FieldChange {{")?;
+            writeln!(writer, "                register,")?;
+            writeln!(writer, "                field: Some(\"{}\"),", value.name)?;
+            writeln!(writer, "                old: {},", value.raw(&old_bits))?;
+            writeln!(writer, "                new: {},", value.raw(&new_bits))?;
+            writeln!(writer, "            }},")?;
+        }
+        let remainder = self
+            .fields
+            .iter()
+            .fold(ones(self.width), |remainder, field| {
+                remainder & !field.mask()
+            });
+        if remainder != 0 {
+            let mask = separated_binary_literal(remainder);
+            writeln!(writer, "            FieldChange {{")?;
+            writeln!(writer, "                register,")?;
+            writeln!(writer, "                field: None,")?;
+            writeln!(writer, "                old: {old_bits} & {mask},")?;
+            writeln!(writer, "                new: {new_bits} & {mask},")?;
+            writeln!(writer, "            }},")?;
         }
+        writeln!(writer, "        ]")?;
+        writeln!(writer, "        .into_iter()")?;
+        writeln!(writer, "        .filter(|change| change.old != change.new)")?;
         writeln!(writer, "    }}")?;
         writeln!(writer, "}}")?;
         Ok(())
@@ -331,13 +1782,17 @@ impl RegisterInfo {
 
     fn write_impl(&self, mut writer: impl Write) -> io::Result<()> {
         writeln!(writer)?;
-        if let Some(guard) = self.exception_level.cfg_guard() {
+        if let Some(guard) = self.feature_cfg_guard() {
             writeln!(writer, "{guard}")?;
         }
         writeln!(writer, "impl {} {{", self.struct_name())?;
 
-        // Constants for field offsets and masks.
-        for field in &self.fields {
+        // Constants for the offsets and masks of array fields, which have no `Field` descriptor.
+        for field in self
+            .fields
+            .iter()
+            .filter(|field| field.array_info.is_some())
+        {
             let constant_name = field.constant_name();
 
             writeln!(writer, "    /// Offset of the `{}` field.", field.name)?;
@@ -358,6 +1813,40 @@ impl RegisterInfo {
             }
         }
 
+        // Descriptors for generic field access.
+        for field in self
+            .fields
+            .iter()
+            .filter(|field| field.array_info.is_none())
+        {
+            let field_type = field.type_name.as_deref().unwrap_or(if field.width == 1 {
+                "bool"
+            } else {
+                type_for_width(field.width).0
+            });
+            writeln!(
+                writer,
+                "    /// Descriptor of the `{}` field, for use with [`FieldAccess`].",
+                field.name
+            )?;
+            writeln!(
+                writer,
+                "    pub const {}_FIELD: Field<Self, {field_type}, {}, {}> = Field::new(\"{}\", \"{}\");",
+                field.constant_name(),
+                field.index,
+                field.width,
+                self.name,
+                field.name,
+            )?;
+        }
+
+        // The register value as a `u64`, as taken by `Field` methods.
+        let bits = if self.width == 64 {
+            "self.bits()"
+        } else {
+            "self.bits() as u64"
+        };
+
         // Methods to access fields.
         for field in &self.fields {
             if field.width <= 1 {
@@ -367,16 +1856,8 @@ impl RegisterInfo {
             writeln!(writer)?;
 
             let (int_ty, effective_width) = type_for_width(field.width);
-            let constness;
-            let field_type: &str;
             let use_custom_type = field.type_name.is_some();
-            if let Some(ty) = &field.type_name {
-                constness = "";
-                field_type = ty;
-            } else {
-                constness = "const ";
-                field_type = int_ty;
-            };
+            let field_type = field.type_name.as_deref().unwrap_or(int_ty);
 
             if let Some(array_info) = &field.array_info {
                 writeln!(
@@ -388,9 +1869,13 @@ impl RegisterInfo {
                     writeln!(writer, "    ///")?;
                     writeln!(writer, "    /// {description}")?;
                 }
+                if array_info.indices.start > 0 {
+                    // `Range::contains` isn't usable in a const fn.
+                    writeln!(writer, "    #[allow(clippy::manual_range_contains)]")?;
+                }
                 writeln!(
                     writer,
-                    "    pub {constness}fn {}(self, {}: u32) -> {} {{",
+                    "    pub const fn {}(self, {}: u32) -> {} {{",
                     field.function_name().replace(&array_info.placeholder(), ""),
                     array_info.index_variable,
                     field_type,
@@ -414,7 +1899,7 @@ impl RegisterInfo {
 
                 write!(writer, "        ")?;
                 if use_custom_type {
-                    write!(writer, "{}::try_from(", field_type)?;
+                    write!(writer, "{}::from_raw(", field_type)?;
                 }
 
                 if array_info.indices.start != 0 {
@@ -455,36 +1940,107 @@ impl RegisterInfo {
                     writeln!(writer, "    ///")?;
                     writeln!(writer, "    /// {description}")?;
                 }
+                if field.function_name() == "len" {
+                    // The `is_empty` method generated by `bitflags!` is unrelated to this field.
+                    writeln!(writer, "    #[allow(clippy::len_without_is_empty)]")?;
+                }
                 writeln!(
                     writer,
-                    "    pub {constness}fn {}(self) -> {} {{",
+                    "    pub const fn {}(self) -> {} {{",
                     field.function_name(),
                     field_type
                 )?;
 
                 write!(writer, "        ")?;
                 if use_custom_type {
-                    write!(writer, "{}::try_from(", field_type)?;
-                }
-                if effective_width != self.width {
-                    write!(writer, "(")?;
+                    write!(writer, "{}::from_raw(", field_type)?;
                 }
                 write!(
                     writer,
-                    "(self.bits() >> Self::{0}_SHIFT) & Self::{0}_MASK",
+                    "Self::{}_FIELD.extract({bits})",
                     field.constant_name(),
                 )?;
-                if effective_width != self.width {
-                    write!(writer, ") as {}", int_ty)?;
+                if effective_width != 64 {
+                    write!(writer, " as {}", int_ty)?;
                 }
                 if use_custom_type {
                     write!(writer, ").unwrap()")?;
                 }
-                writeln!(writer)?;
-
+                writeln!(writer)?;
+
+                writeln!(writer, "    }}")?;
+            }
+
+            // Emit a fallible `try_<field>()` getter for fields with a custom type.
+            if use_custom_type {
+                writeln!(writer)?;
+                if let Some(array_info) = &field.array_info {
+                    writeln!(
+                        writer,
+                        "    /// Returns the value of the `{}` field, or an error if it isn't valid for the field type or the",
+                        field.name,
+                    )?;
+                    writeln!(writer, "    /// index is out of range.")?;
+                    if array_info.indices.start > 0 {
+                        writeln!(writer, "    #[allow(clippy::manual_range_contains)]")?;
+                    }
+                    writeln!(
+                        writer,
+                        "    pub const fn try_{}(self, {}: u32) -> Result<{field_type}, InvalidFieldValue> {{",
+                        self.field_function_name(field),
+                        array_info.index_variable,
+                    )?;
+                    if let Some(out_of_range) = index_out_of_range(field) {
+                        writeln!(writer, "        if {out_of_range} {{")?;
+                        writeln!(writer, "            return Err(InvalidFieldValue {{")?;
+                        self.write_field_error_fields(&mut writer, field, "                ", "0")?;
+                        writeln!(writer, "            }});")?;
+                        writeln!(writer, "        }}")?;
+                    }
+                    writeln!(
+                        writer,
+                        "        let value = ((self.bits() >> {}) & Self::{}_MASK) as {int_ty};",
+                        self.array_shift(field, array_info),
+                        field.constant_name(),
+                    )?;
+                    writeln!(writer, "        match {field_type}::from_raw(value) {{")?;
+                    writeln!(writer, "            Some(value) => Ok(value),")?;
+                    writeln!(writer, "            None => Err(InvalidFieldValue {{")?;
+                    self.write_field_error_fields(&mut writer, field, "                ", "value")?;
+                    writeln!(writer, "            }}),")?;
+                    writeln!(writer, "        }}")?;
+                } else {
+                    writeln!(
+                        writer,
+                        "    /// Returns the value of the `{}` field, or an error if it isn't valid for the field type.",
+                        field.name,
+                    )?;
+                    writeln!(
+                        writer,
+                        "    pub const fn try_{}(self) -> Result<{field_type}, InvalidFieldValue> {{",
+                        field.function_name(),
+                    )?;
+                    writeln!(
+                        writer,
+                        "        let raw = Self::{}_FIELD.extract({bits});",
+                        field.constant_name()
+                    )?;
+                    let raw = if effective_width == 64 {
+                        "raw".to_owned()
+                    } else {
+                        format!("raw as {int_ty}")
+                    };
+                    writeln!(writer, "        match {field_type}::from_raw({raw}) {{")?;
+                    writeln!(writer, "            Some(value) => Ok(value),")?;
+                    writeln!(
+                        writer,
+                        "            None => Err(Self::{}_FIELD.invalid_value(raw)),",
+                        field.constant_name()
+                    )?;
+                    writeln!(writer, "        }}")?;
+                }
                 writeln!(writer, "    }}")?;
             }
-
             // Emit a `set_<field>()` method.
             writeln!(writer)?;
             writeln!(
@@ -498,9 +2054,12 @@ impl RegisterInfo {
             }
 
             if let Some(array_info) = &field.array_info {
+                if array_info.indices.start > 0 {
+                    writeln!(writer, "    #[allow(clippy::manual_range_contains)]")?;
+                }
                 writeln!(
                     writer,
-                    "    pub {constness}fn set_{}(&mut self, {}: u32, value: {field_type}) {{",
+                    "    pub const fn set_{}(&mut self, {}: u32, value: {field_type}) {{",
                     field.function_name().replace(&array_info.placeholder(), ""),
                     array_info.index_variable,
                 )?;
@@ -540,45 +2099,58 @@ impl RegisterInfo {
                         field.width,
                     )?;
                 }
-            } else {
-                writeln!(
-                    writer,
-                    "    pub {constness}fn set_{}(&mut self, value: {field_type}) {{",
-                    field.function_name()
-                )?;
-                writeln!(
-                    writer,
-                    "        let offset = Self::{}_SHIFT;",
-                    field.constant_name(),
-                )?;
-            }
 
-            if use_custom_type {
-                writeln!(writer, "        let value: {int_ty} = value.into();")?;
-            }
+                if use_custom_type {
+                    writeln!(writer, "        let value = value.to_raw();")?;
+                }
 
-            if effective_width != self.width {
-                writeln!(
-                    writer,
-                    "        assert!(value & (Self::{}_MASK as {int_ty}) == value);",
-                    field.constant_name(),
-                )?;
-                writeln!(
-                    writer,
-                    "        *self = Self::from_bits_retain((self.bits() & !(Self::{}_MASK << offset)) | ((value as u{}) << offset));",
-                    field.constant_name(),
-                    self.width,
-                )?;
+                if effective_width != self.width {
+                    writeln!(
+                        writer,
+                        "        assert!(value & (Self::{}_MASK as {int_ty}) == value);",
+                        field.constant_name(),
+                    )?;
+                    writeln!(
+                        writer,
+                        "        *self = Self::from_bits_retain((self.bits() & !(Self::{}_MASK << offset)) | ((value as u{}) << offset));",
+                        field.constant_name(),
+                        self.width,
+                    )?;
+                } else {
+                    // Omit as u[width] if the field has the same width as the register.
+                    writeln!(
+                        writer,
+                        "        assert!(value & Self::{}_MASK == value);",
+                        field.constant_name(),
+                    )?;
+                    writeln!(
+                        writer,
+                        "        *self = Self::from_bits_retain((self.bits() & !(Self::{}_MASK << offset)) | (value << offset));",
+                        field.constant_name(),
+                    )?;
+                }
             } else {
-                // Omit as u[width] if the field has the same width as the register.
                 writeln!(
                     writer,
-                    "        assert!(value & Self::{}_MASK == value);",
-                    field.constant_name(),
+                    "    pub const fn set_{}(&mut self, value: {field_type}) {{",
+                    field.function_name()
                 )?;
+                let mut value = if use_custom_type {
+                    "value.to_raw()".to_owned()
+                } else {
+                    "value".to_owned()
+                };
+                if effective_width != 64 {
+                    value = format!("{value} as u64");
+                }
+                let bits_type = if self.width == 64 {
+                    String::new()
+                } else {
+                    format!(" as u{}", self.width)
+                };
                 writeln!(
                     writer,
-                    "        *self = Self::from_bits_retain((self.bits() & !(Self::{}_MASK << offset)) | (value << offset));",
+                    "        *self = Self::from_bits_retain(Self::{}_FIELD.insert({bits}, {value}){bits_type});",
                     field.constant_name(),
                 )?;
             }
@@ -602,7 +2174,7 @@ impl RegisterInfo {
                     field.function_name().replace(&array_info.placeholder(), "");
                 writeln!(
                     writer,
-                    "    pub {constness}fn with_{}(mut self, {}: u32, value: {field_type}) -> Self {{",
+                    "    pub const fn with_{}(mut self, {}: u32, value: {field_type}) -> Self {{",
                     array_function_name, array_info.index_variable,
                 )?;
                 writeln!(
@@ -613,13 +2185,137 @@ impl RegisterInfo {
             } else {
                 writeln!(
                     writer,
-                    "    pub {constness}fn with_{}(mut self, value: {field_type}) -> Self {{",
+                    "    pub const fn with_{}(mut self, value: {field_type}) -> Self {{",
                     field.function_name()
                 )?;
                 writeln!(writer, "        self.set_{}(value);", field.function_name())?;
             }
             writeln!(writer, "        self")?;
             writeln!(writer, "    }}")?;
+
+            // Emit fallible `try_set_<field>()` and `try_with_<field>()` methods.
+            let function_name = self.field_function_name(field);
+            writeln!(writer)?;
+            let Some(array_info) = &field.array_info else {
+                writeln!(
+                    writer,
+                    "    /// Sets the value of the `{}` field, or returns an error if the value doesn't fit.",
+                    field.name
+                )?;
+                writeln!(
+                    writer,
+                    "    pub const fn try_set_{function_name}(&mut self, value: {field_type}) -> Result<(), FieldOverflow> {{",
+                )?;
+                writeln!(
+                    writer,
+                    "        *self = match self.try_with_{function_name}(value) {{"
+                )?;
+                writeln!(writer, "            Ok(value) => value,")?;
+                writeln!(writer, "            Err(e) => return Err(e),")?;
+                writeln!(writer, "        }};")?;
+                writeln!(writer, "        Ok(())")?;
+                writeln!(writer, "    }}")?;
+                writeln!(writer)?;
+                writeln!(
+                    writer,
+                    "    /// Returns a copy with the `{}` field set to the given value, or an error if the value",
+                    field.name
+                )?;
+                writeln!(writer, "    /// doesn't fit.")?;
+                writeln!(
+                    writer,
+                    "    pub const fn try_with_{function_name}(self, value: {field_type}) -> Result<Self, FieldOverflow> {{",
+                )?;
+                let mut raw = if use_custom_type {
+                    "value.to_raw()".to_owned()
+                } else {
+                    "value".to_owned()
+                };
+                if effective_width != 64 {
+                    raw = format!("{raw} as u64");
+                }
+                writeln!(
+                    writer,
+                    "        match Self::{}_FIELD.try_insert({bits}, {raw}) {{",
+                    field.constant_name()
+                )?;
+                if self.width == 64 {
+                    writeln!(
+                        writer,
+                        "            Ok(bits) => Ok(Self::from_bits_retain(bits)),"
+                    )?;
+                } else {
+                    writeln!(
+                        writer,
+                        "            Ok(bits) => Ok(Self::from_bits_retain(bits as u{})),",
+                        self.width
+                    )?;
+                }
+                writeln!(writer, "            Err(e) => Err(e),")?;
+                writeln!(writer, "        }}")?;
+                writeln!(writer, "    }}")?;
+                continue;
+            };
+            let index_variable = &array_info.index_variable;
+            writeln!(
+                writer,
+                "    /// Sets the value of the `{}` field, or returns an error if the value doesn't fit or the",
+                field.name
+            )?;
+            writeln!(writer, "    /// index is out of range.")?;
+            if array_info.indices.start > 0 {
+                writeln!(writer, "    #[allow(clippy::manual_range_contains)]")?;
+            }
+            writeln!(
+                writer,
+                "    pub const fn try_set_{function_name}(&mut self, {index_variable}: u32, value: {field_type}) -> Result<(), FieldOverflow> {{",
+            )?;
+            let raw = if use_custom_type {
+                writeln!(writer, "        let raw = value.to_raw();")?;
+                "raw"
+            } else {
+                "value"
+            };
+            let mask = if effective_width != self.width {
+                format!("(Self::{}_MASK as {int_ty})", field.constant_name())
+            } else {
+                format!("Self::{}_MASK", field.constant_name())
+            };
+            writeln!(
+                writer,
+                "        if {} || {raw} & {mask} != {raw} {{",
+                index_out_of_range(field).unwrap()
+            )?;
+            writeln!(writer, "            return Err(FieldOverflow {{")?;
+            self.write_field_error_fields(&mut writer, field, "                ", raw)?;
+            writeln!(writer, "            }});")?;
+            writeln!(writer, "        }}")?;
+            writeln!(
+                writer,
+                "        self.set_{function_name}({index_variable}, value);"
+            )?;
+            writeln!(writer, "        Ok(())")?;
+            writeln!(writer, "    }}")?;
+
+            writeln!(writer)?;
+            writeln!(
+                writer,
+                "    /// Returns a copy with the `{}` field set to the given value, or an error if the value",
+                field.name
+            )?;
+            writeln!(writer, "    /// doesn't fit or the index is out of range.")?;
+            writeln!(
+                writer,
+                "    pub const fn try_with_{function_name}(mut self, {index_variable}: u32, value: {field_type}) -> Result<Self, FieldOverflow> {{",
+            )?;
+            writeln!(
+                writer,
+                "        match self.try_set_{function_name}({index_variable}, value) {{"
+            )?;
+            writeln!(writer, "            Ok(()) => Ok(self),")?;
+            writeln!(writer, "            Err(e) => Err(e),")?;
+            writeln!(writer, "        }}")?;
+            writeln!(writer, "    }}")?;
         }
 
         writeln!(writer, "}}")?;
@@ -627,55 +2323,109 @@ impl RegisterInfo {
         Ok(())
     }
 
-    fn cfg_guard(&self) -> Option<&'static str> {
-        match (&self.aarch32, self.aarch64) {
-            (false, true) => match self.exception_level {
-                ExceptionLevel::El0 => {
-                    Some("#[cfg(any(test, feature = \"fakes\", target_arch = \"aarch64\"))]")
-                }
-                ExceptionLevel::El1 => Some(
-                    "#[cfg(all(any(test, feature = \"fakes\", target_arch = \"aarch64\"), feature = \"el1\"))]",
-                ),
-                ExceptionLevel::El2 => Some(
-                    "#[cfg(all(any(test, feature = \"fakes\", target_arch = \"aarch64\"), feature = \"el2\"))]",
-                ),
-                ExceptionLevel::El3 => Some(
-                    "#[cfg(all(any(test, feature = \"fakes\", target_arch = \"aarch64\"), feature = \"el3\"))]",
-                ),
-            },
-            (false, false) => match self.exception_level {
-                ExceptionLevel::El0 => Some("#[cfg(any(test, feature = \"fakes\"))]"),
-                ExceptionLevel::El1 => {
-                    Some("#[cfg(all(any(test, feature = \"fakes\"), feature = \"el1\"))]")
-                }
-                ExceptionLevel::El2 => {
-                    Some("#[cfg(all(any(test, feature = \"fakes\"), feature = \"el2\"))]")
-                }
-                ExceptionLevel::El3 => {
-                    Some("#[cfg(all(any(test, feature = \"fakes\"), feature = \"el3\"))]")
-                }
-            },
-            (true, true) => match self.exception_level {
-                ExceptionLevel::El0 => None,
-                ExceptionLevel::El1 => Some("#[cfg(feature = \"el1\")]"),
-                ExceptionLevel::El2 => Some("#[cfg(feature = \"el2\")]"),
-                ExceptionLevel::El3 => Some("#[cfg(feature = \"el3\")]"),
-            },
-            (true, false) => match self.exception_level {
-                ExceptionLevel::El0 => {
-                    Some("#[cfg(any(test, feature = \"fakes\", target_arch = \"arm\"))]")
-                }
-                ExceptionLevel::El1 => Some(
-                    "#[cfg(all(any(test, feature = \"fakes\", target_arch = \"arm\"), feature = \"el1\"))]",
-                ),
-                ExceptionLevel::El2 => Some(
-                    "#[cfg(all(any(test, feature = \"fakes\", target_arch = \"arm\"), feature = \"el2\"))]",
-                ),
-                ExceptionLevel::El3 => Some(
-                    "#[cfg(all(any(test, feature = \"fakes\", target_arch = \"arm\"), feature = \"el3\"))]",
-                ),
-            },
+    /// Returns the name used in accessor method names for the given field, without any array index
+    /// placeholder.
+    fn field_function_name(&self, field: &RegisterField) -> String {
+        if let Some(array_info) = &field.array_info {
+            field.function_name().replace(&array_info.placeholder(), "")
+        } else {
+            field.function_name()
+        }
+    }
+
+    /// Returns an expression for the offset of the element of the given array field selected by
+    /// its index variable.
+    fn array_shift(&self, field: &RegisterField, array_info: &ArrayInfo) -> String {
+        if array_info.indices.start != 0 {
+            format!(
+                "(Self::{}_SHIFT + ({} - {}) * {})",
+                field.constant_name(),
+                array_info.index_variable,
+                array_info.indices.start,
+                field.width,
+            )
+        } else {
+            format!(
+                "(Self::{}_SHIFT + {} * {})",
+                field.constant_name(),
+                array_info.index_variable,
+                field.width,
+            )
+        }
+    }
+
+    /// Writes the fields of a `FieldOverflow` or `InvalidFieldValue` for the given field, with the
+    /// raw value in the given variable or literal.
+    fn write_field_error_fields(
+        &self,
+        mut writer: impl Write,
+        field: &RegisterField,
+        indent: &str,
+        value: &str,
+    ) -> io::Result<()> {
+        writeln!(writer, "{indent}register: \"{}\",", self.name)?;
+        if let Some(array_info) = &field.array_info {
+            writeln!(
+                writer,
+                "{indent}field: \"{}\",",
+                field.name.replace(&array_info.placeholder(), "")
+            )?;
+            writeln!(
+                writer,
+                "{indent}index: Some({}),",
+                array_info.index_variable
+            )?;
+        } else {
+            writeln!(writer, "{indent}field: \"{}\",", field.name)?;
+            writeln!(writer, "{indent}index: None,")?;
         }
+        if type_for_width(field.width).0 == "u64" && value == "value" {
+            writeln!(writer, "{indent}value,")?;
+        } else if type_for_width(field.width).0 == "u64" || value.parse::<u64>().is_ok() {
+            writeln!(writer, "{indent}value: {value},")?;
+        } else {
+            writeln!(writer, "{indent}value: {value} as u64,")?;
+        }
+        Ok(())
+    }
+
+    /// Returns the `#[cfg(...)]` attribute for the register accessors, if they are conditional.
+    fn cfg_guard(&self) -> Option<String> {
+        let architecture = match (self.aarch32, self.aarch64) {
+            (false, true) => Some("any(test, feature = \"fakes\", target_arch = \"aarch64\")"),
+            (false, false) => Some("any(test, feature = \"fakes\")"),
+            (true, true) => None,
+            (true, false) => Some("any(test, feature = \"fakes\", target_arch = \"arm\")"),
+        };
+        cfg_attribute(
+            architecture
+                .into_iter()
+                .map(str::to_owned)
+                .chain(self.feature_cfgs()),
+        )
+    }
+
+    /// Returns the `#[cfg(...)]` attribute for the register type and fakes, if they are
+    /// conditional.
+    ///
+    /// Unlike [`Self::cfg_guard`], this doesn't depend on the target architecture.
+    fn feature_cfg_guard(&self) -> Option<String> {
+        cfg_attribute(self.feature_cfgs())
+    }
+
+    /// Returns the cargo feature predicates which must all hold for the register to be available.
+    fn feature_cfgs(&self) -> impl Iterator<Item = String> {
+        let exception_level = match self.exception_level {
+            ExceptionLevel::El0 => None,
+            ExceptionLevel::El1 => Some("feature = \"el1\"".to_owned()),
+            ExceptionLevel::El2 => Some("feature = \"el2\"".to_owned()),
+            ExceptionLevel::El3 => Some("feature = \"el3\"".to_owned()),
+        };
+        let feature_group = self
+            .feature_group
+            .as_ref()
+            .map(|group| format!("feature = \"feat-{group}\""));
+        exception_level.into_iter().chain(feature_group)
     }
 
     fn write_accessor(&self, mut writer: impl Write) -> io::Result<()> {
@@ -706,6 +2456,14 @@ impl RegisterInfo {
                 .map(|register_name| format!(": {register_name}"))
                 .unwrap_or_default()
         };
+        let write_safety_doc = self.write_safety_doc.clone().or_else(|| {
+            (self.write == Some(Safety::Unsafe)).then(|| {
+                format!(
+                    "The caller must ensure that `value` is a correct and safe value to write to the `{}` system register.",
+                    self.name
+                )
+            })
+        });
         match (self.read, self.write) {
             (None, None) => {}
             (None, Some(write_safety)) => {
@@ -713,7 +2471,7 @@ impl RegisterInfo {
                     Safety::Safe => ", safe",
                     Safety::Unsafe => "",
                 };
-                if let Some(safety_doc) = &self.write_safety_doc {
+                if let Some(safety_doc) = &write_safety_doc {
                     writeln!(
                         writer,
                         "\
@@ -763,7 +2521,7 @@ write_sysreg! {{
                     Safety::Safe => ", safe_write",
                     Safety::Unsafe => "",
                 };
-                if let Some(safety_doc) = &self.write_safety_doc {
+                if let Some(safety_doc) = &write_safety_doc {
                     writeln!(
                         writer,
                         "\
@@ -797,9 +2555,38 @@ read_write_sysreg! {{
     }
 
     /// Returns the name of the field formatted to be a valid Rust variable name.
-    fn variable_name(&self) -> String {
+    pub fn variable_name(&self) -> String {
         lowercase_name(&self.name)
     }
+
+    /// The name of the `SysregId` variant for the register.
+    fn id_name(&self) -> String {
+        camel_case(&self.variable_name())
+    }
+
+    /// Returns an expression converting `value`, of the register's type, to a `u64`.
+    fn expr_to_u64(&self, value: &str) -> String {
+        let bits = if self.use_struct() { ".bits()" } else { "" };
+        if self.width < 64 {
+            format!("u64::from({value}{bits})")
+        } else {
+            format!("{value}{bits}")
+        }
+    }
+
+    /// Returns an expression converting `value`, a `u64`, to the register's type.
+    fn expr_from_u64(&self, value: &str) -> String {
+        let value = if self.width < 64 {
+            format!("{value} as u{}", self.width)
+        } else {
+            value.to_owned()
+        };
+        if self.use_struct() {
+            format!("{}::from_bits_retain({value})", self.struct_name())
+        } else {
+            value
+        }
+    }
 }
 
 impl RegisterField {
@@ -823,15 +2610,169 @@ impl RegisterField {
     }
 }
 
-impl ExceptionLevel {
-    fn cfg_guard(self) -> Option<&'static str> {
-        match self {
-            ExceptionLevel::El0 => None,
-            ExceptionLevel::El1 => Some("#[cfg(feature = \"el1\")]"),
-            ExceptionLevel::El2 => Some("#[cfg(feature = \"el2\")]"),
-            ExceptionLevel::El3 => Some("#[cfg(feature = \"el3\")]"),
+/// Returns an expression for whether the index variable of the given field is out of range, if it
+/// is an array field.
+fn index_out_of_range(field: &RegisterField) -> Option<String> {
+    let array_info = field.array_info.as_ref()?;
+    Some(if array_info.indices.start > 0 {
+        format!(
+            "{} < {} || {} >= {}",
+            array_info.index_variable,
+            array_info.indices.start,
+            array_info.index_variable,
+            array_info.indices.end,
+        )
+    } else {
+        format!(
+            "{} >= {}",
+            array_info.index_variable, array_info.indices.end
+        )
+    })
+}
+
+/// Returns a `#[cfg(...)]` attribute requiring all of the given predicates, or `None` if there are
+/// none.
+fn cfg_attribute(predicates: impl IntoIterator<Item = String>) -> Option<String> {
+    let predicates = predicates.into_iter().collect::<Vec<_>>();
+    match predicates.as_slice() {
+        [] => None,
+        [predicate] => Some(format!("#[cfg({predicate})]")),
+        _ => Some(format!("#[cfg(all({}))]", predicates.join(", "))),
+    }
+}
+
+/// Returns a Rust expression for whether the given condition is met by `features`.
+fn condition_expression(condition: &FeatureCondition) -> String {
+    match condition {
+        FeatureCondition::Implemented(feature) => {
+            format!("features.contains(Feature::{})", feature_variant(feature))
+        }
+        FeatureCondition::Not(condition) => format!("!({})", condition_expression(condition)),
+        FeatureCondition::And(left, right) => format!(
+            "({} && {})",
+            condition_expression(left),
+            condition_expression(right)
+        ),
+        FeatureCondition::Or(left, right) => format!(
+            "({} || {})",
+            condition_expression(left),
+            condition_expression(right)
+        ),
+    }
+}
+
+/// Returns a human-readable description of the given condition.
+fn condition_text(condition: &FeatureCondition) -> String {
+    match condition {
+        FeatureCondition::Implemented(feature) => feature.clone(),
+        FeatureCondition::Not(condition) => {
+            let compound = !matches!(
+                **condition,
+                FeatureCondition::Implemented(_) | FeatureCondition::Not(_)
+            );
+            format!("!{}", operand_text(condition, compound))
+        }
+        FeatureCondition::And(left, right) => format!(
+            "{} && {}",
+            operand_text(left, matches!(**left, FeatureCondition::Or(..))),
+            operand_text(right, matches!(**right, FeatureCondition::Or(..)))
+        ),
+        FeatureCondition::Or(left, right) => {
+            format!("{} || {}", condition_text(left), condition_text(right))
+        }
+    }
+}
+
+/// Returns a human-readable description of the given operand of a condition, in parentheses if
+/// `parenthesise` is true because it binds less tightly than the operator.
+fn operand_text(condition: &FeatureCondition, parenthesise: bool) -> String {
+    if parenthesise {
+        format!("({})", condition_text(condition))
+    } else {
+        condition_text(condition)
+    }
+}
+
+/// Returns the name of the `Feature` variant for the feature with the given name.
+fn feature_variant(feature: &str) -> String {
+    camel_case(feature.strip_prefix("FEAT_").unwrap_or(feature))
+}
+
+/// Writes the `Feature` enum, with a variant for each feature referenced by a register or field
+/// condition.
+pub fn write_features(mut writer: impl Write, registers: &[RegisterInfo]) -> io::Result<()> {
+    let mut features = BTreeSet::new();
+    for register in registers {
+        if let Some(condition) = &register.condition {
+            condition.add_features(&mut features);
         }
+        for field in &register.fields {
+            if let Some(condition) = &field.condition {
+                condition.add_features(&mut features);
+            }
+        }
+    }
+
+    writer.write_all(
+        "\
+// SPDX-FileCopyrightText: Copyright The arm-sysregs Contributors.
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+// This file is generated, do not edit manually.
+
+use core::fmt::{self, Display, Formatter};
+
+/// An optional architecture feature which registers or register fields may depend on.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = \"defmt\", derive(defmt::Format))]
+#[non_exhaustive]
+pub enum Feature {
+"
+        .as_bytes(),
+    )?;
+    for feature in &features {
+        writeln!(writer, "    /// `{feature}`.")?;
+        writeln!(writer, "    {},", feature_variant(feature))?;
+    }
+    writeln!(writer, "}}")?;
+    writeln!(writer)?;
+    writeln!(writer, "impl Feature {{")?;
+    writeln!(writer, "    /// The number of features.")?;
+    writeln!(writer, "    pub const COUNT: usize = {};", features.len())?;
+    writeln!(writer)?;
+    writeln!(writer, "    /// All features, in order.")?;
+    writeln!(writer, "    pub const ALL: [Self; Self::COUNT] = [")?;
+    for feature in &features {
+        writeln!(writer, "        Self::{},", feature_variant(feature))?;
+    }
+    writeln!(writer, "    ];")?;
+    writeln!(writer)?;
+    writeln!(
+        writer,
+        "    /// Returns the architectural name of the feature, e.g. `FEAT_PAuth`."
+    )?;
+    writeln!(writer, "    pub const fn name(self) -> &'static str {{")?;
+    writeln!(writer, "        match self {{")?;
+    for feature in &features {
+        writeln!(
+            writer,
+            "            Self::{} => \"{feature}\",",
+            feature_variant(feature)
+        )?;
     }
+    writeln!(writer, "        }}")?;
+    writeln!(writer, "    }}")?;
+    writeln!(writer, "}}")?;
+    writeln!(writer)?;
+    writeln!(writer, "impl Display for Feature {{")?;
+    writeln!(
+        writer,
+        "    fn fmt(&self, f: &mut Formatter) -> fmt::Result {{"
+    )?;
+    writeln!(writer, "        f.write_str(self.name())")?;
+    writeln!(writer, "    }}")?;
+    writeln!(writer, "}}")?;
+    Ok(())
 }
 
 fn camel_case(name: &str) -> String {
@@ -852,6 +2793,34 @@ fn uppercase_name(name: &str) -> String {
         .to_uppercase()
 }
 
+/// A single value of a register field, as shown when formatting a register value.
+struct FieldValue<'a> {
+    /// The name of the field, with the array index if any.
+    name: String,
+    /// For an element of an array field, expressions for its offset and for the mask of the field
+    /// before it is shifted into place, as a `u64`.
+    array_element: Option<(String, String)>,
+    /// The field, for its type and width.
+    field: &'a RegisterField,
+}
+
+impl FieldValue<'_> {
+    /// Returns an expression for the raw value of the field, as a `u64`, given an expression for
+    /// the raw value of the register as a `u64`.
+    fn raw(&self, bits: &str) -> String {
+        if let Some((shift, mask)) = &self.array_element {
+            format!("({bits} >> {shift}) & {mask}")
+        } else {
+            format!("Self::{}_FIELD.extract({bits})", self.field.constant_name())
+        }
+    }
+
+    /// Returns whether the raw value should be formatted in hexadecimal rather than decimal.
+    fn hex(&self) -> bool {
+        self.field.width > 8
+    }
+}
+
 /// Returns the smallest unsigned type that can hold at least the given number of bits and the width
 /// of the type in bits.
 fn type_for_width(width: u32) -> (&'static str, u32) {
@@ -872,9 +2841,88 @@ fn type_for_width(width: u32) -> (&'static str, u32) {
 mod tests {
     use super::*;
 
+    #[test]
+    fn condition_text_precedence() {
+        let feature = |name: &str| Box::new(FeatureCondition::Implemented(name.to_owned()));
+        let or = FeatureCondition::Or(feature("FEAT_A"), feature("FEAT_B"));
+        let and = FeatureCondition::And(feature("FEAT_A"), feature("FEAT_B"));
+        assert_eq!(condition_text(&or), "FEAT_A || FEAT_B");
+        assert_eq!(
+            condition_text(&FeatureCondition::And(
+                Box::new(or.clone()),
+                feature("FEAT_C")
+            )),
+            "(FEAT_A || FEAT_B) && FEAT_C"
+        );
+        assert_eq!(
+            condition_text(&FeatureCondition::Or(
+                Box::new(and.clone()),
+                feature("FEAT_C")
+            )),
+            "FEAT_A && FEAT_B || FEAT_C"
+        );
+        assert_eq!(
+            condition_text(&FeatureCondition::Not(Box::new(and))),
+            "!(FEAT_A && FEAT_B)"
+        );
+        assert_eq!(
+            condition_text(&FeatureCondition::Not(Box::new(or))),
+            "!(FEAT_A || FEAT_B)"
+        );
+        assert_eq!(
+            condition_text(&FeatureCondition::Not(feature("FEAT_A"))),
+            "!FEAT_A"
+        );
+    }
+
     #[test]
     fn test_camel_case() {
         assert_eq!(camel_case("SCR_EL3"), "ScrEl3");
         assert_eq!(camel_case("aBc_de_FGh_3a"), "AbcDeFgh3a");
     }
+
+    #[test]
+    fn register_family() {
+        let family = |name: &str, aarch32: bool| {
+            RegisterInfo {
+                name: name.to_owned(),
+                aarch32,
+                aarch64: !aarch32,
+                ..Default::default()
+            }
+            .family()
+        };
+        assert_eq!(family("CNTHCTL_EL2", false), "timer");
+        assert_eq!(family("AMCFGR_EL0", false), "amu");
+        assert_eq!(family("AMAIR_EL1", false), "mmu");
+        assert_eq!(family("ICC_SRE_EL1", false), "gic");
+        assert_eq!(family("SCTLR_EL1", false), "control");
+        assert_eq!(family("HSCTLR", true), "aarch32");
+    }
+
+    #[test]
+    fn info_module() {
+        let registers = [
+            RegisterInfo {
+                name: "TPIDR_EL2".to_owned(),
+                width: 32,
+                read: Some(Safety::Safe),
+                write: Some(Safety::Safe),
+                assembly_name: Some("s3_4_c13_c0_2".to_owned()),
+                ..Default::default()
+            },
+            RegisterInfo {
+                name: "FOO_EL1".to_owned(),
+                width: 64,
+                ..Default::default()
+            },
+        ];
+        let mut output = Vec::new();
+        write_info_module(&mut output, &registers).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("(asm_name tpidr_el2) => { \"s3_4_c13_c0_2\" };"));
+        assert!(output.contains("(from_u64 tpidr_el2, $value:expr) => { $value as u32 };"));
+        assert!(output.contains("(to_u64 tpidr_el2, $value:expr) => { u64::from($value) };"));
+        assert!(!output.contains("foo_el1"));
+    }
 }